@@ -3,13 +3,63 @@
 //! ユースケースを提供する。
 
 mod catalog;
+mod conversion_check;
+mod dependencies;
+mod diagnostics;
+mod files;
 mod info;
 mod lifecycle;
+mod lockfile;
+mod orphans;
+mod pin;
+mod placement_rules;
+mod preview;
+mod project_targets;
+mod refresh;
+mod sandbox;
+mod stats;
+mod summary;
+mod warnings;
 
 pub use crate::plugin::InstalledPlugin;
 pub use crate::target::OperationOutcome;
 pub use catalog::list_installed_plugins;
-pub use info::{get_plugin_info, PluginInfo, Source};
+pub use conversion_check::{check_conversions, CheckStatus, ConversionCheck, SourceFormats};
+pub use dependencies::{find_dependents, plan_dependencies, DependencyState};
+pub use diagnostics::{
+    check_marketplace_caches, find_broken_manifests, find_missing_files, BrokenManifest,
+    MarketplaceIssue, MissingFile,
+};
+pub use files::{list_plugin_files, PluginFile};
+pub use info::{get_plugin_info, ComponentDetail, DeploymentEntry, PluginInfo, Source};
 pub use lifecycle::{
-    disable_plugin, enable_plugin, get_uninstall_info, uninstall_plugin, UninstallInfo,
+    backup_paths, disable_component, disable_plugin, disable_plugin_filtered, enable_component,
+    enable_plugin, enable_plugin_checked, enable_plugin_filtered, get_uninstall_info,
+    migrate_command_prefix, remaining_deployed_files, uninstall_plugin, ComponentSelector,
+    DeployFilter, RemainingFile, UninstallInfo,
+};
+pub use lockfile::{
+    build_lock_file, diff_lock, matches_cache, read_lock_file, write_lock_file, LockDiff,
+    LockDifference, LockStatus, LockedPlugin, LOCK_FILE,
+};
+pub use orphans::{find_orphans, remove_orphans, OrphanedFile};
+pub use pin::{pin_plugin, unpin_plugin, PinOutcome};
+pub use placement_rules::{placement_rules, PlacementRule};
+pub use preview::{
+    preview_marketplace_removal, preview_uninstall, preview_update, ImpactPreview, VersionChange,
+};
+pub use project_targets::{
+    config_path, enabled_targets, project_enabled_targets, skip_disabled_targets, ProjectConfig,
+    PROJECT_CONFIG_FILE,
+};
+pub use refresh::{apply_refresh, plan_refresh, RefreshChange, RefreshPlan};
+pub use sandbox::{
+    prepare_sandbox, record_sandbox, remove_sandbox, remove_sandbox_command, sandbox_path,
+    stale_sandbox_warning,
 };
+pub use stats::{collect_stats, PluginStats};
+// Re-exported for tests
+#[cfg(test)]
+pub use stats::{TopPlugin, UpdateAgeBuckets};
+pub use summary::{summarize_plugins, PluginTotals};
+pub use warnings::{render_warnings, Warning, WarningCategory, WarningCollector};
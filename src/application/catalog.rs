@@ -3,10 +3,11 @@
 //! インストール済みプラグインの一覧取得ユースケースを提供する。
 
 use crate::error::Result;
-use crate::plugin::{list_installed, meta, InstalledPlugin, PackageCacheAccess, Plugin};
-use crate::target::{list_all_placed, PluginOrigin};
+use crate::plugin::meta::PluginMeta;
+use crate::plugin::{list_installed, meta, InstalledPlugin, PackageCacheAccess};
+use crate::target::list_all_placed;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// インストール済みプラグインの一覧を取得
 ///
@@ -16,9 +17,39 @@ use std::path::PathBuf;
 ///
 /// * `cache` - インストール済みプラグインを列挙するためのパッケージキャッシュアクセサ
 pub fn list_installed_plugins(cache: &dyn PackageCacheAccess) -> Result<Vec<InstalledPlugin>> {
+    let project_root = crate::env::project_root().unwrap_or_else(|_| PathBuf::from("."));
+    Ok(scan_catalog(cache, &project_root)?
+        .into_iter()
+        .map(|entry| entry.plugin)
+        .collect())
+}
+
+/// 一覧取得時に得られるプラグインごとの付帯情報
+///
+/// 集計（`plm stats`）が同じ走査結果を再利用するために使う。
+#[derive(Debug)]
+pub(crate) struct CatalogEntry {
+    pub plugin: InstalledPlugin,
+    /// `.plm-meta.json`（無い・壊れている場合は `None`）
+    pub meta: Option<PluginMeta>,
+    /// `project_root` から見える配置先にコンポーネントが 1 件以上あるか
+    pub deployed: bool,
+}
+
+/// キャッシュと配置先を 1 回ずつ走査してプラグイン一覧を組み立てる
+///
+/// `.plm-meta.json` もプラグインごとに 1 回だけ読み、有効判定と付帯情報で共有する。
+///
+/// # Arguments
+///
+/// * `cache` - インストール済みプラグインを列挙するためのパッケージキャッシュアクセサ
+/// * `project_root` - 配置済みコンポーネントを探すプロジェクトルート
+pub(crate) fn scan_catalog(
+    cache: &dyn PackageCacheAccess,
+    project_root: &Path,
+) -> Result<Vec<CatalogEntry>> {
     // デプロイ済みコンポーネントの flattened_name 集合を事前取得（パフォーマンス改善）
-    let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    let deployed: HashSet<String> = list_all_placed(&project_root);
+    let deployed: HashSet<String> = list_all_placed(project_root);
 
     // 一覧取得経路ではスキャン失敗（重複検出など）は握りつぶして列挙を続行する。
     // TUI 経由でも呼ばれるため stderr への直接出力は避ける。
@@ -34,28 +65,59 @@ pub fn list_installed_plugins(cache: &dyn PackageCacheAccess) -> Result<Vec<Inst
         .collect();
     let deployed_plugins = meta::build_deployed_plugin_set(&deployed, &known_plugin_names);
 
-    let plugins = packages
+    let entries = packages
         .into_iter()
-        .filter_map(|pkg| {
+        .map(|pkg| {
             let name = pkg.manifest().name.clone();
-            let ops_key = pkg.id().unwrap_or(&name);
-            let origin = PluginOrigin::from_cached_plugin(pkg.marketplace(), ops_key);
-            let plugin =
-                Plugin::new(pkg.manifest().clone(), pkg.path().to_path_buf(), origin).ok()?;
+            // list_installed がスキャン済み（またはインデックスから復元済み）の Plugin を再利用する
+            let plugin = pkg.primary().clone();
             // flatten_name の prefix は manifest.name に基づくため
-            // is_enabled_indexed には manifest.name を渡す。
-            let enabled = meta::is_enabled_indexed(pkg.path(), name.as_str(), &deployed_plugins);
-
-            Some(InstalledPlugin::from_cached_package(
-                plugin,
-                pkg.id().map(str::to_string),
-                pkg.marketplace().map(str::to_string),
-                enabled,
-            ))
+            // 有効判定には manifest.name を渡す。
+            let plugin_meta = meta::load_meta(pkg.path());
+            let enabled =
+                meta::is_enabled_with_meta(plugin_meta.as_ref(), name.as_str(), &deployed_plugins);
+
+            let sandbox = plugin_meta.as_ref().and_then(|m| m.sandbox.clone());
+            let channel = plugin_meta.as_ref().and_then(|m| m.channel);
+            let pinned = plugin_meta.as_ref().is_some_and(|m| m.pinned);
+            let installed_at = plugin_meta.as_ref().and_then(|m| m.installed_at.clone());
+            let updated_at = plugin_meta.as_ref().and_then(|m| m.updated_at.clone());
+            let update_history = plugin_meta
+                .as_ref()
+                .map(|m| m.update_history.clone())
+                .unwrap_or_default();
+            let kept_kinds = plugin_meta.as_ref().and_then(|m| m.partial_kinds());
+            let disabled_components = plugin_meta
+                .as_ref()
+                .map(|m| m.disabled_components.clone())
+                .unwrap_or_default();
+            let scopes = plugin_meta
+                .as_ref()
+                .map(|m| m.deployed_scopes(project_root))
+                .unwrap_or_default();
+
+            CatalogEntry {
+                plugin: InstalledPlugin::from_cached_package(
+                    plugin,
+                    pkg.id().map(str::to_string),
+                    pkg.marketplace().map(str::to_string),
+                    enabled,
+                )
+                .with_sandbox(sandbox)
+                .with_channel(channel)
+                .with_pinned(pinned)
+                .with_timestamps(installed_at, updated_at)
+                .with_update_history(update_history)
+                .with_kept_kinds(kept_kinds)
+                .with_disabled_components(disabled_components)
+                .with_scopes(scopes),
+                meta: plugin_meta,
+                deployed: deployed_plugins.contains(&name),
+            }
         })
         .collect();
 
-    Ok(plugins)
+    Ok(entries)
 }
 
 #[cfg(test)]
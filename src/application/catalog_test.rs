@@ -75,3 +75,18 @@ fn test_list_installed_plugins_no_manifest_excluded() {
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].name(), "valid-plugin");
 }
+
+#[test]
+fn test_list_installed_plugins_reads_timestamps_from_meta() {
+    let (temp_dir, cache) = create_test_cache();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    fs::write(
+        temp_dir.path().join("github/my-plugin/.plm-meta.json"),
+        r#"{"installedAt":"2024-01-01T00:00:00Z","updatedAt":"2024-02-01T00:00:00Z"}"#,
+    )
+    .unwrap();
+
+    let result = list_installed_plugins(&cache).unwrap();
+    assert_eq!(result[0].installed_at(), Some("2024-01-01T00:00:00Z"));
+    assert_eq!(result[0].last_updated(), Some("2024-02-01T00:00:00Z"));
+}
@@ -0,0 +1,235 @@
+//! コンポーネント変換の事前検証
+//!
+//! ファイルを書き込まずに、プラグインの Command / Agent / Hook を各ターゲット向けに
+//! 変換し、パースエラー・変換エラー・変換で失われる属性をターゲットごとに集める。
+//! 変換処理は配置時と同じ関数（`component::convert` / `hooks::converter`）を使う。
+
+use crate::component::convert::{convert_agent_content, convert_content};
+use crate::component::{AgentFormat, CommandFormat, Component, ComponentKind};
+use crate::hooks::converter;
+use crate::parser::{ClaudeCodeAgent, ClaudeCodeCommand, TargetType};
+use crate::target::{Target, TargetKind};
+use std::fs;
+
+/// 検証結果の種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CheckStatus {
+    Ok,
+    /// 変換はできるが属性が失われる
+    Warning,
+    /// パースまたは変換に失敗する
+    Error,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Warning => "warning",
+            CheckStatus::Error => "error",
+        }
+    }
+}
+
+/// プラグイン × コンポーネント × ターゲット 1 件の検証結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionCheck {
+    pub plugin: String,
+    pub kind: ComponentKind,
+    /// コンポーネントの元名（ファイル stem / ディレクトリ名）
+    pub component: String,
+    pub target: TargetKind,
+    pub status: CheckStatus,
+    /// エラー・警告の内容（`Ok` なら空）
+    pub messages: Vec<String>,
+}
+
+/// Command / Agent のソース形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceFormats {
+    pub command: CommandFormat,
+    pub agent: AgentFormat,
+}
+
+impl Default for SourceFormats {
+    fn default() -> Self {
+        Self {
+            command: CommandFormat::ClaudeCode,
+            agent: AgentFormat::ClaudeCode,
+        }
+    }
+}
+
+/// プラグインのコンポーネントを各ターゲット向けに変換して検証する
+///
+/// Skill / Instruction は変換を伴わないため対象外。ターゲットが扱わない種別も除く。
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin name used in the report.
+/// * `components` - Components scanned from the plugin.
+/// * `formats` - Source formats of commands and agents.
+/// * `targets` - Targets to convert for.
+pub fn check_conversions(
+    plugin: &str,
+    components: &[Component],
+    formats: SourceFormats,
+    targets: &[Box<dyn Target>],
+) -> Vec<ConversionCheck> {
+    let mut checks = Vec::new();
+    for component in components {
+        if !matches!(
+            component.kind,
+            ComponentKind::Command | ComponentKind::Agent | ComponentKind::Hook
+        ) {
+            continue;
+        }
+        let content = fs::read_to_string(&component.path)
+            .map_err(|e| format!("failed to read {}: {}", component.path.display(), e));
+
+        for target in targets.iter().filter(|t| t.supports(component.kind)) {
+            let kind = target.kind();
+            let (status, messages) = match &content {
+                Err(e) => (CheckStatus::Error, vec![e.clone()]),
+                Ok(content) => match component.kind {
+                    ComponentKind::Command => {
+                        check_command(content, formats.command, kind.command_format())
+                    }
+                    ComponentKind::Agent => {
+                        check_agent(content, formats.agent, kind.agent_format())
+                    }
+                    _ => check_hook(content, kind),
+                },
+            };
+            checks.push(ConversionCheck {
+                plugin: plugin.to_string(),
+                kind: component.kind,
+                component: component
+                    .original_name
+                    .clone()
+                    .unwrap_or_else(|| component.name.clone()),
+                target: kind,
+                status,
+                messages,
+            });
+        }
+    }
+    checks
+}
+
+/// 失われる属性を警告にまとめる
+///
+/// # Arguments
+///
+/// * `dropped` - Frontmatter fields lost on conversion.
+/// * `target` - Destination format.
+fn dropped_warnings(dropped: &[&str], target: TargetType) -> (CheckStatus, Vec<String>) {
+    if dropped.is_empty() {
+        return (CheckStatus::Ok, Vec::new());
+    }
+    let label = match target {
+        TargetType::Copilot => "Copilot",
+        TargetType::Codex => "Codex",
+    };
+    let messages = dropped
+        .iter()
+        .map(|field| {
+            format!(
+                "'{}' is not supported by {} and will be dropped",
+                field, label
+            )
+        })
+        .collect();
+    (CheckStatus::Warning, messages)
+}
+
+/// # Arguments
+///
+/// * `content` - Command file content.
+/// * `source` - Source format.
+/// * `dest` - Format expected by the target.
+fn check_command(
+    content: &str,
+    source: CommandFormat,
+    dest: CommandFormat,
+) -> (CheckStatus, Vec<String>) {
+    let parsed = if source == CommandFormat::ClaudeCode {
+        match ClaudeCodeCommand::parse(content) {
+            Ok(cmd) => Some(cmd),
+            Err(e) => return (CheckStatus::Error, vec![format!("parse error: {}", e)]),
+        }
+    } else {
+        None
+    };
+    if source == dest {
+        return (CheckStatus::Ok, Vec::new());
+    }
+    if let Err(e) = convert_content(content, source, dest) {
+        return (CheckStatus::Error, vec![e.to_string()]);
+    }
+
+    let target = match dest {
+        CommandFormat::Copilot => TargetType::Copilot,
+        CommandFormat::Codex => TargetType::Codex,
+        CommandFormat::ClaudeCode => return (CheckStatus::Ok, Vec::new()),
+    };
+    match parsed {
+        Some(cmd) => dropped_warnings(&cmd.dropped_fields(target), target),
+        None => (CheckStatus::Ok, Vec::new()),
+    }
+}
+
+/// # Arguments
+///
+/// * `content` - Agent file content.
+/// * `source` - Source format.
+/// * `dest` - Format expected by the target.
+fn check_agent(
+    content: &str,
+    source: AgentFormat,
+    dest: AgentFormat,
+) -> (CheckStatus, Vec<String>) {
+    let parsed = if source == AgentFormat::ClaudeCode {
+        match ClaudeCodeAgent::parse(content) {
+            Ok(agent) => Some(agent),
+            Err(e) => return (CheckStatus::Error, vec![format!("parse error: {}", e)]),
+        }
+    } else {
+        None
+    };
+    if source == dest {
+        return (CheckStatus::Ok, Vec::new());
+    }
+    if let Err(e) = convert_agent_content(content, source, dest) {
+        return (CheckStatus::Error, vec![e.to_string()]);
+    }
+
+    let target = match dest {
+        AgentFormat::Copilot => TargetType::Copilot,
+        AgentFormat::Codex => TargetType::Codex,
+        AgentFormat::ClaudeCode => return (CheckStatus::Ok, Vec::new()),
+    };
+    match parsed {
+        Some(agent) => dropped_warnings(&agent.dropped_fields(target), target),
+        None => (CheckStatus::Ok, Vec::new()),
+    }
+}
+
+/// # Arguments
+///
+/// * `content` - Hook configuration content.
+/// * `target` - Target the hooks are converted for.
+fn check_hook(content: &str, target: TargetKind) -> (CheckStatus, Vec<String>) {
+    match converter::convert(content, target) {
+        Err(e) => (CheckStatus::Error, vec![e.to_string()]),
+        Ok(outcome) if outcome.warnings.is_empty() => (CheckStatus::Ok, Vec::new()),
+        Ok(outcome) => (
+            CheckStatus::Warning,
+            outcome.warnings.iter().map(|w| w.to_string()).collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+#[path = "conversion_check_test.rs"]
+mod tests;
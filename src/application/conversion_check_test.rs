@@ -0,0 +1,104 @@
+use super::*;
+use crate::target::all_targets;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write_component(dir: &Path, kind: ComponentKind, name: &str, content: &str) -> Component {
+    let path = dir.join(format!("{}.md", name));
+    fs::write(&path, content).unwrap();
+    Component::flattened(kind, "demo", name, path)
+}
+
+fn find(checks: &[ConversionCheck], component: &str, target: TargetKind) -> ConversionCheck {
+    checks
+        .iter()
+        .find(|c| c.component == component && c.target == target)
+        .cloned()
+        .unwrap_or_else(|| panic!("no check for {} on {}", component, target.as_str()))
+}
+
+#[test]
+fn command_reports_dropped_fields_per_target() {
+    let dir = TempDir::new().unwrap();
+    let command = write_component(
+        dir.path(),
+        ComponentKind::Command,
+        "fmt",
+        "---\ndescription: Format\nmodel: opus\n---\nFormat $ARGUMENTS",
+    );
+
+    let checks = check_conversions("demo", &[command], SourceFormats::default(), &all_targets());
+
+    let copilot = find(&checks, "fmt", TargetKind::Copilot);
+    assert_eq!(copilot.status, CheckStatus::Ok);
+    assert!(copilot.messages.is_empty());
+
+    let codex = find(&checks, "fmt", TargetKind::Codex);
+    assert_eq!(codex.status, CheckStatus::Warning);
+    assert_eq!(
+        codex.messages,
+        vec!["'model' is not supported by Codex and will be dropped"]
+    );
+}
+
+#[test]
+fn parse_error_is_reported_for_every_target() {
+    let dir = TempDir::new().unwrap();
+    let agent = write_component(
+        dir.path(),
+        ComponentKind::Agent,
+        "broken",
+        "---\ndescription: [unclosed\n---\nBody",
+    );
+
+    let checks = check_conversions("demo", &[agent], SourceFormats::default(), &all_targets());
+
+    assert!(!checks.is_empty());
+    for check in &checks {
+        assert_eq!(check.status, CheckStatus::Error, "{:?}", check.target);
+        assert!(check.messages[0].starts_with("parse error:"));
+    }
+}
+
+#[test]
+fn non_claude_source_cannot_be_converted() {
+    let dir = TempDir::new().unwrap();
+    let command = write_component(dir.path(), ComponentKind::Command, "p", "Body");
+    let formats = SourceFormats {
+        command: CommandFormat::Copilot,
+        ..SourceFormats::default()
+    };
+
+    let checks = check_conversions("demo", &[command], formats, &all_targets());
+
+    assert_eq!(
+        find(&checks, "p", TargetKind::Copilot).status,
+        CheckStatus::Ok
+    );
+    assert_eq!(
+        find(&checks, "p", TargetKind::Codex).status,
+        CheckStatus::Error
+    );
+}
+
+#[test]
+fn skills_and_unreadable_files() {
+    let dir = TempDir::new().unwrap();
+    let skill = Component::flattened(ComponentKind::Skill, "demo", "s", dir.path().join("s"));
+    let missing = Component::flattened(
+        ComponentKind::Hook,
+        "demo",
+        "missing",
+        dir.path().join("missing.json"),
+    );
+
+    let checks = check_conversions(
+        "demo",
+        &[skill, missing],
+        SourceFormats::default(),
+        &all_targets(),
+    );
+
+    assert!(checks.iter().all(|c| c.kind == ComponentKind::Hook));
+    assert!(checks.iter().all(|c| c.status == CheckStatus::Error));
+}
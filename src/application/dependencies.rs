@@ -0,0 +1,154 @@
+//! 依存プラグインの解決
+//!
+//! plugin.json の `dependencies` から依存グラフを辿り、未インストールの依存先を
+//! インストールすべき順（依存される側が先）に並べる。既にインストール済みの依存先は
+//! その依存を辿らずにスキップし、バージョン条件を満たしているかだけを確認する。
+//! 循環があればどの経路で循環しているかを示してエラーにする。
+
+use crate::plugin::meta::Dependency;
+use crate::plugin::PackageCacheAccess;
+
+/// 依存先 1 件の状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyState {
+    /// インストール済み（その依存はインストール時に解決済みのため辿らない）
+    Installed { version: String },
+    /// 未インストール（取得したマニフェストのバージョンと依存）
+    Missing {
+        version: String,
+        dependencies: Vec<Dependency>,
+    },
+}
+
+/// 依存解決の結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyPlan {
+    /// インストールする依存先（依存される側が先）
+    pub install: Vec<Dependency>,
+    /// インストール済みでスキップする依存先と、そのバージョン
+    pub satisfied: Vec<(Dependency, String)>,
+}
+
+/// 依存グラフを辿ってインストール順を決める
+///
+/// # Arguments
+///
+/// * `root` - Label of the plugin being installed (start of cycle paths).
+/// * `dependencies` - Direct dependencies of the plugin being installed.
+/// * `state` - Looks up whether a dependency is installed, or fetches its manifest.
+pub fn plan_dependencies(
+    root: &str,
+    dependencies: &[Dependency],
+    mut state: impl FnMut(&Dependency) -> Result<DependencyState, String>,
+) -> Result<DependencyPlan, String> {
+    let mut plan = DependencyPlan::default();
+    let mut visited = Vec::new();
+    let mut path = vec![root.to_string()];
+    for dependency in dependencies {
+        visit(dependency, &mut state, &mut path, &mut visited, &mut plan)?;
+    }
+    Ok(plan)
+}
+
+/// 深さ優先で依存を辿り、依存先を全て積んでから自身を積む
+///
+/// # Arguments
+///
+/// * `dependency` - Dependency to visit.
+/// * `state` - Looks up whether a dependency is installed, or fetches its manifest.
+/// * `path` - Plugins on the current path from the root (for cycle detection).
+/// * `visited` - Keys of dependencies already planned.
+/// * `plan` - Plan being built.
+fn visit(
+    dependency: &Dependency,
+    state: &mut impl FnMut(&Dependency) -> Result<DependencyState, String>,
+    path: &mut Vec<String>,
+    visited: &mut Vec<String>,
+    plan: &mut DependencyPlan,
+) -> Result<(), String> {
+    let key = dependency.key();
+    if path.contains(&key) {
+        path.push(key);
+        return Err(format!("Circular dependency: {}", path.join(" -> ")));
+    }
+    if visited.contains(&key) {
+        return Ok(());
+    }
+
+    match state(dependency)? {
+        DependencyState::Installed { version } => {
+            if !dependency.is_satisfied_by(&version) {
+                return Err(format!(
+                    "'{}' {} is installed but {} is required by '{}'; run `plm update {}`",
+                    key,
+                    version,
+                    dependency,
+                    path.last().map(String::as_str).unwrap_or_default(),
+                    dependency.source()
+                ));
+            }
+            plan.satisfied.push((dependency.clone(), version));
+        }
+        DependencyState::Missing {
+            version,
+            dependencies,
+        } => {
+            if !dependency.is_satisfied_by(&version) {
+                return Err(format!(
+                    "'{}' requires {} but the marketplace provides version {}",
+                    path.last().map(String::as_str).unwrap_or_default(),
+                    dependency,
+                    version
+                ));
+            }
+            path.push(key.clone());
+            for child in &dependencies {
+                visit(child, state, path, visited, plan)?;
+            }
+            path.pop();
+            plan.install.push(dependency.clone());
+        }
+    }
+    visited.push(key);
+    Ok(())
+}
+
+/// 指定したプラグインに依存しているインストール済みプラグイン（`<marketplace>/<plugin>`）
+///
+/// plugin.json が読めない・依存の指定が不正なプラグインは無視する。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache to scan.
+/// * `name` - Plugin about to be uninstalled.
+/// * `marketplace` - Marketplace the plugin was installed from.
+pub fn find_dependents(
+    cache: &dyn PackageCacheAccess,
+    name: &str,
+    marketplace: &str,
+) -> Vec<String> {
+    let installed = cache.list().unwrap_or_default();
+    installed
+        .into_iter()
+        .filter(|(_, id)| !id.starts_with('.'))
+        .filter(|(mp, id)| !(mp.as_deref() == Some(marketplace) && id == name))
+        .filter(|(mp, id)| {
+            cache
+                .load_manifest(mp.as_deref(), id)
+                .ok()
+                .and_then(|manifest| manifest.parsed_dependencies().ok())
+                .is_some_and(|deps| {
+                    deps.iter()
+                        .any(|d| d.marketplace == marketplace && d.name == name)
+                })
+        })
+        .map(|(mp, id)| match mp {
+            Some(mp) => format!("{}/{}", mp, id),
+            None => id,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "dependencies_test.rs"]
+mod tests;
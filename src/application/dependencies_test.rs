@@ -0,0 +1,144 @@
+use super::*;
+use crate::plugin::PackageCache;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+fn dep(spec: &str) -> Dependency {
+    spec.parse().unwrap()
+}
+
+fn missing(version: &str, dependencies: &[&str]) -> DependencyState {
+    DependencyState::Missing {
+        version: version.to_string(),
+        dependencies: dependencies.iter().map(|d| dep(d)).collect(),
+    }
+}
+
+fn installed(version: &str) -> DependencyState {
+    DependencyState::Installed {
+        version: version.to_string(),
+    }
+}
+
+/// `<marketplace>/<plugin>` → 状態 の表から依存を解決する
+fn plan(
+    root_dependencies: &[&str],
+    states: &[(&str, DependencyState)],
+) -> Result<DependencyPlan, String> {
+    let states: HashMap<&str, DependencyState> = states.iter().cloned().collect();
+    let root: Vec<Dependency> = root_dependencies.iter().map(|d| dep(d)).collect();
+    plan_dependencies("app", &root, |d| {
+        states
+            .get(d.key().as_str())
+            .cloned()
+            .ok_or_else(|| format!("unknown {}", d))
+    })
+}
+
+fn keys(dependencies: &[Dependency]) -> Vec<String> {
+    dependencies.iter().map(Dependency::key).collect()
+}
+
+#[test]
+fn nested_dependencies_are_installed_deepest_first() {
+    // app -> a -> b -> c -> d（深さ 4）、a は e にも依存し、e も d に依存する
+    let plan = plan(
+        &["mp/a"],
+        &[
+            ("mp/a", missing("1.0.0", &["mp/b", "mp/e"])),
+            ("mp/b", missing("1.0.0", &["mp/c"])),
+            ("mp/c", missing("1.0.0", &["mp/d"])),
+            ("mp/d", missing("1.0.0", &[])),
+            ("mp/e", missing("1.0.0", &["mp/d"])),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(
+        keys(&plan.install),
+        ["mp/d", "mp/c", "mp/b", "mp/e", "mp/a"]
+    );
+    assert!(plan.satisfied.is_empty());
+}
+
+#[test]
+fn installed_dependencies_are_skipped_without_visiting_their_dependencies() {
+    let plan = plan(
+        &["mp/a", "mp/base>=1.2.0"],
+        &[
+            ("mp/a", missing("1.0.0", &["mp/base"])),
+            ("mp/base", installed("1.3.0")),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(keys(&plan.install), ["mp/a"]);
+    assert_eq!(plan.satisfied.len(), 1);
+    assert_eq!(plan.satisfied[0].0.key(), "mp/base");
+    assert_eq!(plan.satisfied[0].1, "1.3.0");
+}
+
+#[test]
+fn cycle_is_reported_with_its_path() {
+    let error = plan(
+        &["mp/a"],
+        &[
+            ("mp/a", missing("1.0.0", &["mp/b"])),
+            ("mp/b", missing("1.0.0", &["mp/c"])),
+            ("mp/c", missing("1.0.0", &["mp/a"])),
+        ],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        "Circular dependency: app -> mp/a -> mp/b -> mp/c -> mp/a"
+    );
+}
+
+#[test]
+fn installed_version_below_the_requirement_is_an_error() {
+    let error = plan(&["mp/base>=1.2.0"], &[("mp/base", installed("1.1.0"))]).unwrap_err();
+    assert!(error.contains("'mp/base' 1.1.0 is installed but mp/base>=1.2.0 is required"));
+    assert!(error.contains("plm update base@mp"));
+}
+
+#[test]
+fn fetched_version_below_the_requirement_is_an_error() {
+    let error = plan(
+        &["mp/a"],
+        &[
+            ("mp/a", missing("1.0.0", &["mp/b>=2.0.0"])),
+            ("mp/b", missing("1.5.0", &[])),
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "'mp/a' requires mp/b>=2.0.0 but the marketplace provides version 1.5.0"
+    );
+}
+
+fn write_plugin(cache_dir: &std::path::Path, marketplace: &str, name: &str, deps: &[&str]) {
+    let dir = cache_dir.join(marketplace).join(name);
+    fs::create_dir_all(&dir).unwrap();
+    let manifest = serde_json::json!({
+        "name": name,
+        "version": "1.0.0",
+        "dependencies": deps,
+    });
+    fs::write(dir.join("plugin.json"), manifest.to_string()).unwrap();
+}
+
+#[test]
+fn find_dependents_lists_plugins_that_depend_on_the_target() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    write_plugin(temp.path(), "mp", "base", &[]);
+    write_plugin(temp.path(), "mp", "app", &["mp/base>=1.0.0"]);
+    write_plugin(temp.path(), "other", "tool", &["other/base"]);
+
+    assert_eq!(find_dependents(&cache, "base", "mp"), ["mp/app"]);
+    assert!(find_dependents(&cache, "app", "mp").is_empty());
+}
@@ -0,0 +1,176 @@
+//! 環境の整合性診断（`plm doctor`）
+//!
+//! 孤立ファイル（[`super::orphans`]）以外の不整合を検出する。
+//!
+//! - キャッシュ内のプラグインで plugin.json を読めないもの
+//! - 配置記録（`deployedFiles`）にあるのにプロジェクト内から消えている配置物
+//! - 登録済みマーケットプレイスとマーケットプレイスキャッシュの食い違い
+
+use crate::component::ComponentKind;
+use crate::marketplace::MarketplaceRegistry;
+use crate::plugin::{meta, PackageCacheAccess};
+use std::path::{Path, PathBuf};
+
+/// plugin.json を読めないキャッシュ内のプラグイン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenManifest {
+    /// プラグイン（`plugin@marketplace` 形式）
+    pub plugin: String,
+    /// 読み込みエラー
+    pub message: String,
+}
+
+/// 配置記録にあるのにデプロイ先に存在しない配置物
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFile {
+    /// プラグインの id（キャッシュディレクトリ名）
+    pub plugin: String,
+    /// マーケットプレイス名
+    pub marketplace: Option<String>,
+    /// ターゲット名
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// 配置先の絶対パス
+    pub path: PathBuf,
+}
+
+impl MissingFile {
+    /// 所有プラグイン（`plugin@marketplace` 形式）
+    pub fn owner(&self) -> String {
+        plugin_label(self.marketplace.as_deref(), &self.plugin)
+    }
+}
+
+/// マーケットプレイスの登録とキャッシュの食い違い
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarketplaceIssue {
+    /// 登録済みだがキャッシュが無い（`plm marketplace update` で取得し直せる）
+    NotCached(String),
+    /// キャッシュを読めない
+    Unreadable { name: String, message: String },
+    /// キャッシュだけが残っていて登録が無い
+    Unregistered(String),
+}
+
+/// # Arguments
+///
+/// * `marketplace` - Marketplace name (`None` for plugins installed from GitHub).
+/// * `plugin` - Plugin id (cache directory name).
+fn plugin_label(marketplace: Option<&str>, plugin: &str) -> String {
+    match marketplace {
+        Some(marketplace) => format!("{}@{}", plugin, marketplace),
+        None => plugin.to_string(),
+    }
+}
+
+/// 一覧の対象にするキャッシュ内のプラグイン（隠しディレクトリは除く）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache to enumerate.
+fn cached_plugins(cache: &dyn PackageCacheAccess) -> Vec<(Option<String>, String)> {
+    let mut plugins: Vec<(Option<String>, String)> = cache
+        .list()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, name)| !name.starts_with('.'))
+        .collect();
+    plugins.sort();
+    plugins
+}
+
+/// plugin.json を読めないプラグインを列挙する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache to check.
+pub fn find_broken_manifests(cache: &dyn PackageCacheAccess) -> Vec<BrokenManifest> {
+    cached_plugins(cache)
+        .into_iter()
+        .filter_map(|(marketplace, name)| {
+            let error = cache.load_package(marketplace.as_deref(), &name).err()?;
+            Some(BrokenManifest {
+                plugin: plugin_label(marketplace.as_deref(), &name),
+                message: error.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// プロジェクト内で消えている配置物を列挙する
+///
+/// 各プラグインの配置記録（`deployedFiles`）のうち、`project_root` 配下にあって
+/// 存在しないものを返す。プロジェクト外（ユーザースコープなど）の配置は対象外。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache whose plugins are checked.
+/// * `project_root` - Only placements under this directory are checked.
+pub fn find_missing_files(cache: &dyn PackageCacheAccess, project_root: &Path) -> Vec<MissingFile> {
+    let mut missing = Vec::new();
+    for (marketplace, name) in cached_plugins(cache) {
+        let plugin_path = cache.plugin_path(marketplace.as_deref(), &name);
+        let Some(plugin_meta) = meta::load_meta(&plugin_path) else {
+            continue;
+        };
+        for (target, files) in plugin_meta.deployed_files {
+            for file in files {
+                let path = PathBuf::from(file.path);
+                if !path.starts_with(project_root) || path.exists() {
+                    continue;
+                }
+                missing.push(MissingFile {
+                    plugin: name.clone(),
+                    marketplace: marketplace.clone(),
+                    target: target.clone(),
+                    kind: file.kind,
+                    path,
+                });
+            }
+        }
+    }
+    missing.sort_by(|a, b| a.path.cmp(&b.path));
+    missing
+}
+
+/// マーケットプレイスの登録とキャッシュの対応を確認する
+///
+/// 結果は登録済みマーケットプレイスの問題、登録の無いキャッシュの順に、それぞれ名前順で返す。
+///
+/// # Arguments
+///
+/// * `registered` - Marketplace names in `marketplaces.json`.
+/// * `registry` - Marketplace cache registry.
+pub fn check_marketplace_caches(
+    registered: &[String],
+    registry: &MarketplaceRegistry,
+) -> Vec<MarketplaceIssue> {
+    let mut registered: Vec<&String> = registered.iter().collect();
+    registered.sort();
+    let mut issues: Vec<MarketplaceIssue> = registered
+        .iter()
+        .filter_map(|name| match registry.get(name) {
+            Ok(Some(_)) => None,
+            Ok(None) => Some(MarketplaceIssue::NotCached(name.to_string())),
+            Err(e) => Some(MarketplaceIssue::Unreadable {
+                name: name.to_string(),
+                message: e.to_string(),
+            }),
+        })
+        .collect();
+
+    let mut cached = registry.list().unwrap_or_default();
+    cached.sort();
+    issues.extend(
+        cached
+            .into_iter()
+            .filter(|name| !registered.contains(&name))
+            .map(MarketplaceIssue::Unregistered),
+    );
+    issues
+}
+
+#[cfg(test)]
+#[path = "diagnostics_test.rs"]
+mod tests;
@@ -0,0 +1,116 @@
+use super::*;
+use crate::marketplace::MarketplaceCache;
+use crate::plugin::meta::{DeployedFile, PluginMeta};
+use crate::plugin::PackageCache;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_plugin(cache_dir: &Path, marketplace: &str, name: &str, manifest: &str) -> PathBuf {
+    let dir = cache_dir.join(marketplace).join(name);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("plugin.json"), manifest).unwrap();
+    dir
+}
+
+fn store_marketplace(registry: &MarketplaceRegistry, name: &str) {
+    registry
+        .store(&MarketplaceCache {
+            name: name.to_string(),
+            fetched_at: Utc::now(),
+            source: "github:owner/repo".parse().unwrap(),
+            git_ref: None,
+            owner: None,
+            plugins: vec![],
+        })
+        .unwrap();
+}
+
+#[test]
+fn broken_manifests_are_reported_with_their_owner() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    write_plugin(
+        temp.path(),
+        "company-tools",
+        "ok",
+        r#"{"name":"ok","version":"1.0.0"}"#,
+    );
+    write_plugin(temp.path(), "company-tools", "broken", "{ not json");
+
+    let broken = find_broken_manifests(&cache);
+
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].plugin, "broken@company-tools");
+    assert!(!broken[0].message.is_empty());
+}
+
+#[test]
+fn only_missing_placements_inside_the_project_are_reported() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().join("cache")).unwrap();
+    let plugin_dir = write_plugin(
+        &temp.path().join("cache"),
+        "company-tools",
+        "formatter",
+        r#"{"name":"formatter","version":"1.0.0"}"#,
+    );
+    let project = temp.path().join("project");
+    let present = project.join(".claude/skills/present");
+    fs::create_dir_all(&present).unwrap();
+    let missing = project.join(".claude/skills/lint");
+    let outside = temp.path().join("elsewhere/.claude/skills/lint");
+    let file = |path: &Path| DeployedFile {
+        path: path.to_string_lossy().into_owned(),
+        kind: ComponentKind::Skill,
+    };
+    let plugin_meta = PluginMeta {
+        deployed_files: HashMap::from([(
+            "claude".to_string(),
+            vec![file(&present), file(&missing), file(&outside)],
+        )]),
+        ..Default::default()
+    };
+    meta::write_meta(&plugin_dir, &plugin_meta).unwrap();
+
+    let found = find_missing_files(&cache, &project);
+
+    assert_eq!(
+        found,
+        vec![MissingFile {
+            plugin: "formatter".to_string(),
+            marketplace: Some("company-tools".to_string()),
+            target: "claude".to_string(),
+            kind: ComponentKind::Skill,
+            path: missing,
+        }]
+    );
+    assert_eq!(found[0].owner(), "formatter@company-tools");
+}
+
+#[test]
+fn marketplace_registrations_are_matched_against_the_cache() {
+    let temp = TempDir::new().unwrap();
+    let registry = MarketplaceRegistry::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    store_marketplace(&registry, "official");
+    store_marketplace(&registry, "removed");
+    fs::write(temp.path().join("broken.json"), "{ not json").unwrap();
+
+    let registered = vec![
+        "official".to_string(),
+        "company-tools".to_string(),
+        "broken".to_string(),
+    ];
+    let issues = check_marketplace_caches(&registered, &registry);
+
+    assert_eq!(issues.len(), 3, "{:?}", issues);
+    assert!(matches!(&issues[0], MarketplaceIssue::Unreadable { name, .. } if name == "broken"));
+    assert_eq!(
+        issues[1..],
+        [
+            MarketplaceIssue::NotCached("company-tools".to_string()),
+            MarketplaceIssue::Unregistered("removed".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+//! プラグインのファイル一覧取得
+//!
+//! キャッシュ内のプラグインディレクトリを走査し、スキャン済みコンポーネントと
+//! 突き合わせて各エントリに種別を付ける（`plm info --files` 用）。
+
+use crate::component::{Component, ComponentKind};
+use crate::error::Result;
+use crate::plugin::meta::META_FILE;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// プラグインディレクトリ内の 1 エントリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginFile {
+    /// プラグインルートからの相対パス
+    pub path: PathBuf,
+    /// 階層の深さ（ルート直下が 1）
+    pub depth: usize,
+    /// ディレクトリか
+    pub is_dir: bool,
+    /// ファイルサイズ（ディレクトリは 0）
+    pub size: u64,
+    /// このエントリを含むコンポーネントの種別
+    pub kind: Option<ComponentKind>,
+    /// コンポーネントのルート（Skill ディレクトリや Agent ファイル自身）か
+    pub is_component_root: bool,
+}
+
+/// プラグインディレクトリを名前順（深さ優先）に走査する
+///
+/// PLM 自身のメタデータ（`.plm-meta.json`）は一覧に含めない。
+/// シンボリックリンクは辿らない。
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory in the cache.
+/// * `components` - Components recognized by the scanner.
+pub fn list_plugin_files(root: &Path, components: &[Component]) -> Result<Vec<PluginFile>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(root)
+        .min_depth(1)
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()));
+    for entry in walker {
+        let entry = entry.map_err(std::io::Error::from)?;
+        let Ok(relative) = entry.path().strip_prefix(root) else {
+            continue;
+        };
+        if entry.depth() == 1 && relative == Path::new(META_FILE) {
+            continue;
+        }
+        let owner = components
+            .iter()
+            .find(|c| entry.path().starts_with(&c.path));
+        let is_dir = entry.file_type().is_dir();
+        let size = if is_dir {
+            0
+        } else {
+            entry.metadata().map(|m| m.len()).unwrap_or(0)
+        };
+        files.push(PluginFile {
+            path: relative.to_path_buf(),
+            depth: entry.depth(),
+            is_dir,
+            size,
+            kind: owner.map(|c| c.kind),
+            is_component_root: owner.is_some_and(|c| c.path == entry.path()),
+        });
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+#[path = "files_test.rs"]
+mod tests;
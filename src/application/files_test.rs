@@ -0,0 +1,95 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_file(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn paths(files: &[PluginFile]) -> Vec<String> {
+    files
+        .iter()
+        .map(|f| f.path.to_string_lossy().replace('\\', "/"))
+        .collect()
+}
+
+#[test]
+fn lists_entries_in_name_order_depth_first() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_file(&root.join("skills/b/SKILL.md"), "b");
+    write_file(&root.join("skills/a/SKILL.md"), "a");
+    write_file(&root.join("README.md"), "readme");
+
+    let files = list_plugin_files(root, &[]).unwrap();
+
+    assert_eq!(
+        paths(&files),
+        vec![
+            "README.md",
+            "skills",
+            "skills/a",
+            "skills/a/SKILL.md",
+            "skills/b",
+            "skills/b/SKILL.md",
+        ]
+    );
+    assert_eq!(files[0].depth, 1);
+    assert_eq!(files[3].depth, 3);
+}
+
+#[test]
+fn records_file_sizes_and_zero_for_directories() {
+    let temp = TempDir::new().unwrap();
+    write_file(&temp.path().join("docs/guide.md"), "12345");
+
+    let files = list_plugin_files(temp.path(), &[]).unwrap();
+
+    assert!(files[0].is_dir);
+    assert_eq!(files[0].size, 0);
+    assert!(!files[1].is_dir);
+    assert_eq!(files[1].size, 5);
+}
+
+#[test]
+fn marks_component_roots_and_contained_files() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_file(&root.join("skills/s1/SKILL.md"), "s");
+    write_file(&root.join("agents/a1.md"), "a");
+    write_file(&root.join("notes.txt"), "n");
+    let components = vec![
+        Component::new(ComponentKind::Skill, "s1", root.join("skills/s1")),
+        Component::new(ComponentKind::Agent, "a1", root.join("agents/a1.md")),
+    ];
+
+    let files = list_plugin_files(root, &components).unwrap();
+    let find = |p: &str| files.iter().find(|f| f.path == Path::new(p)).unwrap();
+
+    assert_eq!(find("skills/s1").kind, Some(ComponentKind::Skill));
+    assert!(find("skills/s1").is_component_root);
+    assert_eq!(find("skills/s1/SKILL.md").kind, Some(ComponentKind::Skill));
+    assert!(!find("skills/s1/SKILL.md").is_component_root);
+    assert_eq!(find("agents/a1.md").kind, Some(ComponentKind::Agent));
+    assert!(find("agents/a1.md").is_component_root);
+    assert_eq!(find("skills").kind, None);
+    assert_eq!(find("notes.txt").kind, None);
+}
+
+#[test]
+fn skips_plm_meta_file_at_root() {
+    let temp = TempDir::new().unwrap();
+    write_file(&temp.path().join(".plm-meta.json"), "{}");
+    write_file(&temp.path().join("plugin.json"), "{}");
+
+    let files = list_plugin_files(temp.path(), &[]).unwrap();
+
+    assert_eq!(paths(&files), vec!["plugin.json"]);
+}
+
+#[test]
+fn missing_root_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    assert!(list_plugin_files(&temp.path().join("missing"), &[]).is_err());
+}
@@ -2,13 +2,19 @@
 //!
 //! 特定のプラグインの詳細情報を取得するユースケースを提供する。
 
+use crate::component::{
+    CommandPrefix, Component, ComponentKind, ComponentRef, PlacementContext, PlacementScope,
+    ProjectContext, PromptNaming, Scope,
+};
 use crate::error::{PlmError, Result};
 use crate::marketplace::MarketplaceRef;
+use crate::parser::parse_frontmatter;
 use crate::plugin::{
-    list_installed, meta, GithubCacheId, InstalledPlugin, MarketplaceContent, PackageCacheAccess,
-    Plugin,
+    list_installed, meta, Channel, GithubCacheId, InstalledPlugin, MarketplaceContent,
+    PackageCacheAccess, Plugin,
 };
-use crate::target::{list_all_placed, PluginOrigin};
+use crate::target::{all_targets, list_all_placed, PluginOrigin, Target, TargetKind};
+use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
 /// プラグイン詳細情報（composition）
@@ -19,6 +25,12 @@ pub struct PluginInfo {
     pub installed: InstalledPlugin,
     pub installed_at: Option<String>,
     pub source: Source,
+    /// 追従している更新チャンネル（未指定なら `None`）
+    pub channel: Option<Channel>,
+    /// ターゲットごとの配置先（disable 済みなど配置物が無いものも含む）
+    pub deployments: Vec<DeploymentEntry>,
+    /// コンポーネントごとの詳細（`installed.components()` と同じ順）
+    pub component_details: Vec<ComponentDetail>,
 }
 
 impl PluginInfo {
@@ -31,6 +43,35 @@ impl PluginInfo {
     }
 }
 
+/// コンポーネント 1 件の配置先
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentEntry {
+    pub target: TargetKind,
+    pub kind: ComponentKind,
+    pub name: String,
+    pub path: PathBuf,
+    /// 配置先が実在するか
+    pub exists: bool,
+}
+
+/// コンポーネント 1 件の詳細
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentDetail {
+    pub kind: ComponentKind,
+    pub name: String,
+    /// キャッシュディレクトリからの相対パス
+    pub path: PathBuf,
+    /// frontmatter の `description`（Skill / Agent / Command のみ。無ければ `None`）
+    pub description: Option<String>,
+}
+
+/// 説明の取得に必要な frontmatter のフィールド
+#[derive(Debug, Default, Deserialize)]
+struct DescriptionFrontmatter {
+    #[serde(default)]
+    description: Option<String>,
+}
+
 /// プラグインソース情報
 pub enum Source {
     GitHub { repository: String },
@@ -210,24 +251,177 @@ fn build_plugin_info(content: MarketplaceContent) -> Result<PluginInfo> {
     let source = determine_source(&marketplace_ref, &dir_name);
 
     let installed_at = meta::resolve_installed_at(&cache_path);
+    let plugin_meta = meta::load_meta(&cache_path);
+    let channel = plugin_meta.as_ref().and_then(|m| m.channel);
+    let disabled_components = plugin_meta
+        .as_ref()
+        .map(|m| m.disabled_components.clone())
+        .unwrap_or_default();
 
     // flatten_name の prefix は manifest.name に基づくため
     // is_enabled には manifest.name を渡す。
-    let enabled = resolve_enabled(&cache_path, manifest.name.as_str());
+    let project_root = crate::env::project_root().unwrap_or_else(|_| PathBuf::from("."));
+    let enabled = resolve_enabled(&cache_path, manifest.name.as_str(), &project_root);
+    // 配置済みのコマンドは deploy 時のプレフィクス付きの名前で探す
+    let command_prefix = CommandPrefix::from_stored(
+        plugin_meta
+            .as_ref()
+            .and_then(|m| m.deployed_command_prefix.as_deref()),
+    );
 
     // InstalledPlugin を組み立てる（list_installed_plugins と同じく marketplace は Option<String> を保つ）
     let id = Some(dir_name.clone());
     let origin = PluginOrigin::from_cached_plugin(marketplace_opt.as_deref(), &dir_name);
     let plugin = Plugin::new(manifest, cache_path, origin)?;
-    let installed = InstalledPlugin::from_cached_package(plugin, id, marketplace_opt, enabled);
+    let deployments = resolve_deployments(
+        plugin.components(),
+        plugin.origin(),
+        command_prefix.as_ref(),
+        PromptNaming::configured().unwrap_or_default(),
+        &project_root,
+    );
+    let component_details = resolve_component_details(plugin.components(), plugin.path());
+    let updated_at = plugin_meta.as_ref().and_then(|m| m.updated_at.clone());
+    let update_history = plugin_meta
+        .as_ref()
+        .map(|m| m.update_history.clone())
+        .unwrap_or_default();
+    let installed = InstalledPlugin::from_cached_package(plugin, id, marketplace_opt, enabled)
+        .with_disabled_components(disabled_components)
+        .with_timestamps(installed_at.clone(), updated_at)
+        .with_update_history(update_history);
 
     Ok(PluginInfo {
         installed,
         installed_at,
         source,
+        channel,
+        deployments,
+        component_details,
     })
 }
 
+/// コンポーネントごとにキャッシュ内の相対パスと説明を解決する
+///
+/// # Arguments
+///
+/// * `components` - Components of the plugin.
+/// * `cache_path` - Plugin cache directory the paths are made relative to.
+fn resolve_component_details(components: &[Component], cache_path: &Path) -> Vec<ComponentDetail> {
+    components
+        .iter()
+        .map(|component| ComponentDetail {
+            kind: component.kind,
+            name: component.name.clone(),
+            path: component
+                .path
+                .strip_prefix(cache_path)
+                .unwrap_or(&component.path)
+                .to_path_buf(),
+            description: read_description(component),
+        })
+        .collect()
+}
+
+/// コンポーネントの定義ファイルの frontmatter から `description` を読む
+///
+/// 定義ファイルが無い・frontmatter が壊れている・空文字の場合は `None`。
+///
+/// # Arguments
+///
+/// * `component` - Component whose definition file is read.
+fn read_description(component: &Component) -> Option<String> {
+    let file = match component.kind {
+        ComponentKind::Skill => component.path.join("SKILL.md"),
+        ComponentKind::Agent | ComponentKind::Command => component.path.clone(),
+        ComponentKind::Instruction | ComponentKind::Hook => return None,
+    };
+    let content = std::fs::read_to_string(file).ok()?;
+    let parsed = parse_frontmatter::<DescriptionFrontmatter>(&content).ok()?;
+    parsed
+        .frontmatter?
+        .description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty())
+}
+
+/// 全ターゲットについてコンポーネントの配置先を解決し、実在するかを調べる
+///
+/// ターゲットが配置場所を持たないコンポーネントは含めない。
+///
+/// # Arguments
+///
+/// * `components` - Components of the plugin.
+/// * `origin` - Plugin origin used to build the placement context.
+/// * `command_prefix` - Prefix the commands were deployed with.
+/// * `prompt_naming` - File naming convention for commands deployed to Copilot.
+/// * `project_root` - Project root the placements are resolved against.
+fn resolve_deployments(
+    components: &[Component],
+    origin: &PluginOrigin,
+    command_prefix: Option<&CommandPrefix>,
+    prompt_naming: PromptNaming,
+    project_root: &Path,
+) -> Vec<DeploymentEntry> {
+    let mut deployments = Vec::new();
+    for target in all_targets() {
+        for component in components.iter().filter(|c| target.supports(c.kind)) {
+            let Some(path) = placement_path(
+                target.as_ref(),
+                component,
+                origin,
+                command_prefix,
+                prompt_naming,
+                project_root,
+            ) else {
+                continue;
+            };
+            deployments.push(DeploymentEntry {
+                target: target.kind(),
+                kind: component.kind,
+                name: component.name.clone(),
+                exists: path.exists(),
+                path,
+            });
+        }
+    }
+    deployments
+}
+
+/// コンポーネントのプロジェクトスコープでの配置先を解決する
+///
+/// ターゲットが配置場所を持たない場合は `None`。
+///
+/// # Arguments
+///
+/// * `target` - Target whose placement rules are consulted.
+/// * `component` - Component to resolve.
+/// * `origin` - Plugin origin used to build the placement context.
+/// * `command_prefix` - Prefix the commands were deployed with.
+/// * `prompt_naming` - File naming convention for commands deployed to Copilot.
+/// * `project_root` - Project root the placements are resolved against.
+pub(super) fn placement_path(
+    target: &dyn Target,
+    component: &Component,
+    origin: &PluginOrigin,
+    command_prefix: Option<&CommandPrefix>,
+    prompt_naming: PromptNaming,
+    project_root: &Path,
+) -> Option<PathBuf> {
+    let context = PlacementContext {
+        component: CommandPrefix::apply(
+            command_prefix,
+            ComponentRef::from(component),
+            target.kind(),
+        ),
+        origin,
+        scope: PlacementScope::new(Scope::Project),
+        project: ProjectContext::new(project_root),
+    };
+    let location = target.placement_location(&context)?;
+    Some(PromptNaming::apply(prompt_naming, target.kind(), &context, location).into_path())
+}
+
 /// デプロイ状態を判定
 ///
 /// `meta::is_enabled()` に委譲する。
@@ -236,9 +430,9 @@ fn build_plugin_info(content: MarketplaceContent) -> Result<PluginInfo> {
 ///
 /// * `cache_path` - Path to the plugin's cache directory.
 /// * `plugin_name` - `PluginManifest.name`。`flattened_name` の prefix 判定に使う。
-fn resolve_enabled(cache_path: &Path, plugin_name: &str) -> bool {
-    let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    let deployed = list_all_placed(&project_root);
+/// * `project_root` - Project root to scan for placed components.
+fn resolve_enabled(cache_path: &Path, plugin_name: &str, project_root: &Path) -> bool {
+    let deployed = list_all_placed(project_root);
     meta::is_enabled(cache_path, plugin_name, &deployed)
 }
 
@@ -218,6 +218,8 @@ fn create_marketplace_content(marketplace: &str, name: &str) -> MarketplaceConte
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
     let cached = CachedPackage {
         name: name.to_string(),
@@ -323,3 +325,91 @@ fn test_determine_source_marketplace() {
         _ => panic!("Expected Marketplace source"),
     }
 }
+
+// ========================================
+// resolve_deployments tests
+// ========================================
+
+#[test]
+fn resolve_deployments_lists_missing_placements_without_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let components = vec![Component::new(
+        ComponentKind::Skill,
+        "formatter".to_string(),
+        temp_dir.path().join("cache/skills/formatter"),
+    )];
+    let origin = PluginOrigin::from_cached_plugin(Some("company-tools"), "tools");
+    let resolve = || {
+        resolve_deployments(
+            &components,
+            &origin,
+            None,
+            PromptNaming::default(),
+            temp_dir.path(),
+        )
+    };
+
+    // 未配置（disable 済み）でもターゲットごとに exists=false で列挙される
+    let deployments = resolve();
+    assert!(!deployments.is_empty());
+    assert!(deployments.iter().all(|d| !d.exists));
+    assert!(deployments
+        .iter()
+        .all(|d| d.kind == ComponentKind::Skill && d.name == "formatter"));
+
+    let placed = deployments[0].path.clone();
+    fs::create_dir_all(&placed).unwrap();
+    for d in resolve() {
+        assert_eq!(d.exists, d.path == placed, "{}", d.path.display());
+    }
+}
+
+// ========================================
+// resolve_component_details tests
+// ========================================
+
+#[test]
+fn resolve_component_details_reads_relative_path_and_description() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("skills/formatter")).unwrap();
+    fs::write(
+        root.join("skills/formatter/SKILL.md"),
+        "---\nname: formatter\ndescription: Format source files\n---\n\nBody\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("commands")).unwrap();
+    fs::write(root.join("commands/lint.md"), "No frontmatter\n").unwrap();
+
+    let components = vec![
+        Component::new(
+            ComponentKind::Skill,
+            "formatter".to_string(),
+            root.join("skills/formatter"),
+        ),
+        Component::new(
+            ComponentKind::Command,
+            "lint".to_string(),
+            root.join("commands/lint.md"),
+        ),
+        Component::new(
+            ComponentKind::Agent,
+            "missing".to_string(),
+            root.join("agents/missing.md"),
+        ),
+    ];
+
+    let details = resolve_component_details(&components, root);
+    assert_eq!(
+        details[0],
+        ComponentDetail {
+            kind: ComponentKind::Skill,
+            name: "formatter".to_string(),
+            path: PathBuf::from("skills/formatter"),
+            description: Some("Format source files".to_string()),
+        }
+    );
+    assert_eq!(details[1].path, PathBuf::from("commands/lint.md"));
+    assert_eq!(details[1].description, None);
+    assert_eq!(details[2].description, None);
+}
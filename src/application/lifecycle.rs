@@ -9,13 +9,40 @@
 //! 2. Functional Core: `PluginIntent::expand()` で操作を展開（パス検証時にFS参照あり）
 //! 3. Imperative Shell: `PluginIntent::apply()` で実行（I/O）
 
-use crate::component::Component;
+use super::project_targets::project_enabled_targets;
+use crate::component::{CommandPrefix, Component, ComponentKind, PromptNaming};
 use crate::plugin::{
-    cleanup_legacy_hierarchy, cleanup_plugin_directories, load_plugin, PackageCacheAccess,
-    PluginAction, PluginIntent,
+    cleanup_legacy_hierarchy, cleanup_plugin_directories, load_plugin, meta, update_placements,
+    ComponentConflict, PackageCacheAccess, PlacementLedger, PlacementRecord, PluginAction,
+    PluginIntent, PluginLock,
 };
-use crate::target::{all_targets, OperationOutcome};
-use std::path::Path;
+use crate::target::{all_targets, OperationOutcome, TargetKind};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// キャッシュ済みのプラグインの操作ロックを取る
+///
+/// キャッシュに無い場合と、別プロセスが操作中の場合は失敗結果を返す。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+fn lock_cached(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+) -> Result<PluginLock, OperationOutcome> {
+    if !cache.is_cached(marketplace, plugin_name) {
+        return Err(OperationOutcome::error(format!(
+            "Plugin '{}' not found in cache",
+            plugin_name
+        )));
+    }
+    PluginLock::acquire(cache, marketplace, plugin_name)
+        .map_err(|e| OperationOutcome::error(e.to_string()))
+}
 
 /// プラグインを Disable（デプロイ先から削除、キャッシュは残す）
 ///
@@ -33,16 +60,60 @@ pub fn disable_plugin(
     project_root: &Path,
     target_filter: Option<&str>,
 ) -> OperationOutcome {
-    if !cache.is_cached(marketplace, plugin_name) {
-        return OperationOutcome::error(format!("Plugin '{}' not found in cache", plugin_name));
-    }
+    let filter = DeployFilter {
+        target: target_filter,
+        kinds: None,
+    };
+    disable_plugin_filtered(cache, plugin_name, marketplace, project_root, filter)
+}
+
+/// 削除対象を絞り込んでプラグインを Disable する
+///
+/// 種別を絞った場合（`plm disable --keep`）、対象外の種別の配置物と配置記録は残る。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `filter` - ターゲットと削除するコンポーネント種別の絞り込み
+pub fn disable_plugin_filtered(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    filter: DeployFilter<'_>,
+) -> OperationOutcome {
+    let target_filter = filter.target;
+    let _lock = match lock_cached(cache, plugin_name, marketplace) {
+        Ok(lock) => lock,
+        Err(outcome) => return outcome,
+    };
 
     // Imperative Shell: コンポーネントをスキャン（I/O）
     let plugin = match load_plugin(cache, marketplace, plugin_name) {
         Ok(p) => p,
         Err(e) => return OperationOutcome::error(e),
     };
-    let components = plugin.components().to_vec();
+    let mut components = plugin.components().to_vec();
+    let plugin_path = cache.plugin_path(marketplace, plugin_name);
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    if let Some(kinds) = filter.kinds {
+        components.retain(|c| kinds.contains(&c.kind));
+        for files in plugin_meta.deployed_files.values_mut() {
+            files.retain(|f| kinds.contains(&f.kind));
+        }
+    }
+    // 配置済みのコマンドは deploy 時のプレフィクス付きの名前で探す
+    let deployed_prefix =
+        CommandPrefix::from_stored(plugin_meta.deployed_command_prefix.as_deref());
+
+    // --target で明示したターゲットは、プロジェクトで無効でも配置物を削除できるようにする
+    let enabled = match target_filter {
+        Some(_) => None,
+        None => project_enabled_targets(project_root),
+    };
 
     // Functional Core: 意図を生成（純粋）
     let intent = PluginIntent::with_target_filter(
@@ -53,20 +124,22 @@ pub fn disable_plugin(
         components,
         project_root.to_path_buf(),
         target_filter,
-    );
+    )
+    .with_enabled_targets(enabled.clone())
+    .with_command_prefix(deployed_prefix)
+    .with_prompt_naming(PromptNaming::configured().unwrap_or_default())
+    .with_recorded_files(plugin_meta.deployed_files);
 
     // Imperative Shell: 実行（I/O）
-    let result = intent.apply();
+    let result = apply_and_record(intent, &plugin_path);
 
     // 後処理: 空になったディレクトリをクリーンアップ
     if result.success {
-        let targets_to_cleanup: Vec<_> = match target_filter {
-            Some(filter) => all_targets()
-                .into_iter()
-                .filter(|t| t.name() == filter)
-                .collect(),
-            None => all_targets(),
-        };
+        let targets_to_cleanup: Vec<_> = all_targets()
+            .into_iter()
+            .filter(|t| target_filter.is_none_or(|filter| t.name() == filter))
+            .filter(|t| enabled.as_ref().is_none_or(|e| e.contains(&t.kind())))
+            .collect();
         for target in &targets_to_cleanup {
             cleanup_plugin_directories(target.kind(), plugin.origin(), project_root);
             cleanup_legacy_hierarchy(target.kind(), plugin.origin(), project_root);
@@ -78,6 +151,10 @@ pub fn disable_plugin(
 
 /// プラグインを Enable（キャッシュからデプロイ先に配置）
 ///
+/// コマンドのプレフィクス設定がデプロイ済みの状態と異なる場合は、先に
+/// [`migrate_command_prefix`] で旧名のコマンドを新しい名前へ移行する。
+/// 別プラグインの配置物と衝突したコンポーネントは配置せず、結果の `conflicts` に返す。
+///
 /// # Arguments
 ///
 /// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
@@ -91,17 +168,128 @@ pub fn enable_plugin(
     marketplace: Option<&str>,
     project_root: &Path,
     target_filter: Option<&str>,
-) -> OperationOutcome {
-    if !cache.is_cached(marketplace, plugin_name) {
-        return OperationOutcome::error(format!("Plugin '{}' not found in cache", plugin_name));
+) -> EnableOutcome {
+    let filter = DeployFilter {
+        target: target_filter,
+        kinds: None,
+    };
+    enable_plugin_filtered(cache, plugin_name, marketplace, project_root, filter)
+}
+
+/// Enable / Disable で操作する対象の絞り込み
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeployFilter<'a> {
+    /// ターゲットフィルタ（None で全ターゲット）
+    pub target: Option<&'a str>,
+    /// 配置 / 削除するコンポーネント種別（None で全種別）
+    pub kinds: Option<&'a [ComponentKind]>,
+}
+
+/// 配置対象を絞り込んでプラグインを Enable する
+///
+/// 種別を絞った場合、配置記録（`deployedFiles`）も同じ種別だけを参照するため、
+/// 対象外の種別の配置物は古い配置物として削除されずに残る。
+/// 別プラグインの配置物と衝突したコンポーネントは配置せず、結果の `conflicts` に返す
+/// （[`enable_plugin_checked`]）。表示は呼び出し元が行う。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `filter` - ターゲットとコンポーネント種別の絞り込み
+pub fn enable_plugin_filtered(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    filter: DeployFilter<'_>,
+) -> EnableOutcome {
+    enable_plugin_checked(cache, plugin_name, marketplace, project_root, filter, false)
+}
+
+/// Enable の結果
+#[derive(Debug)]
+pub struct EnableOutcome {
+    /// ターゲットごとの配置結果
+    pub outcome: OperationOutcome,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
+}
+
+impl From<OperationOutcome> for EnableOutcome {
+    fn from(outcome: OperationOutcome) -> Self {
+        Self {
+            outcome,
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+/// 別プラグインとの衝突を検出しながらプラグインを Enable する
+///
+/// 配置先が既に存在し、配置記録（`placements.json`）上で別のプラグインが所有している
+/// コンポーネントは配置せずに `conflicts` へ集計する。同じプラグインの再デプロイと
+/// 記録の無い既存ファイルは衝突として扱わない。`overwrite` なら所有者を問わず上書きする。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `filter` - ターゲットとコンポーネント種別の絞り込み
+/// * `overwrite` - Replace destinations owned by other plugins (`--overwrite`).
+pub fn enable_plugin_checked(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    filter: DeployFilter<'_>,
+    overwrite: bool,
+) -> EnableOutcome {
+    let _lock = match lock_cached(cache, plugin_name, marketplace) {
+        Ok(lock) => lock,
+        Err(outcome) => return outcome.into(),
+    };
+
+    if let Err(e) = migrate_command_prefix(cache, plugin_name, marketplace, project_root) {
+        return OperationOutcome::error(format!("Failed to rename deployed commands: {}", e))
+            .into();
     }
 
     // Imperative Shell: コンポーネントをスキャン（I/O）
     let plugin = match load_plugin(cache, marketplace, plugin_name) {
         Ok(p) => p,
-        Err(e) => return OperationOutcome::error(e),
+        Err(e) => return OperationOutcome::error(e).into(),
     };
-    let components = plugin.components().to_vec();
+    let plugin_path = cache.plugin_path(marketplace, plugin_name);
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    let prefix = CommandPrefix::from_stored(plugin_meta.command_prefix.as_deref());
+    let prompt_naming = match PromptNaming::configured() {
+        Ok(naming) => naming,
+        Err(e) => return OperationOutcome::error(e).into(),
+    };
+    // 所有者を読めない場合は衝突を検出できないため、従来どおり上書きする
+    let owners = if overwrite {
+        None
+    } else {
+        PlacementLedger::load().ok()
+    };
+    // コンポーネント単位で無効化したものは配置しない（配置が残っていれば古い配置物として消える）
+    let mut components: Vec<Component> = plugin
+        .components()
+        .iter()
+        .filter(|c| !plugin_meta.is_component_disabled(c.kind, &c.name))
+        .cloned()
+        .collect();
+    if let Some(kinds) = filter.kinds {
+        components.retain(|c| kinds.contains(&c.kind));
+        for files in plugin_meta.deployed_files.values_mut() {
+            files.retain(|f| kinds.contains(&f.kind));
+        }
+    }
 
     // Functional Core: 意図を生成（純粋）
     let intent = PluginIntent::with_target_filter(
@@ -111,11 +299,394 @@ pub fn enable_plugin(
         },
         components,
         project_root.to_path_buf(),
-        target_filter,
-    );
+        filter.target,
+    )
+    .with_enabled_targets(project_enabled_targets(project_root))
+    .with_command_prefix(prefix)
+    .with_prompt_naming(prompt_naming)
+    .with_recorded_files(plugin_meta.deployed_files)
+    .with_placement_owners(owners);
+    let conflicts = intent.conflicts();
 
     // Imperative Shell: 実行（I/O）
-    intent.apply()
+    EnableOutcome {
+        outcome: apply_and_record(intent, &plugin_path),
+        conflicts,
+    }
+}
+
+/// コンポーネント単位の enable / disable の対象（`--component <kind>:<name>`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentSelector {
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// コンポーネント名（フラット化済みの識別子か、プラグイン内での元の名前）
+    pub name: String,
+}
+
+impl ComponentSelector {
+    /// 指定に一致するコンポーネントを探す
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - Components of the plugin.
+    fn find<'a>(&self, components: &'a [Component]) -> Option<&'a Component> {
+        components.iter().find(|c| {
+            c.kind == self.kind
+                && (c.name == self.name || c.original_name.as_deref() == Some(self.name.as_str()))
+        })
+    }
+}
+
+impl std::fmt::Display for ComponentSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.kind.as_str(), self.name)
+    }
+}
+
+/// キャッシュ済みのプラグインから指定のコンポーネントを探す
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `selector` - Component to look up.
+fn find_cached_component(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    selector: &ComponentSelector,
+) -> Result<Component, String> {
+    let plugin = load_plugin(cache, marketplace, plugin_name)?;
+    selector.find(plugin.components()).cloned().ok_or_else(|| {
+        format!(
+            "Component '{}' not found in plugin '{}'",
+            selector, plugin_name
+        )
+    })
+}
+
+/// プラグインの 1 コンポーネントだけを Disable する（`plm disable --component`）
+///
+/// 全ターゲットから削除し、`.plm-meta.json` の `disabledComponents` に記録する。
+/// 記録したコンポーネントはプラグイン全体の enable / update でも配置しない。
+/// 削除したターゲット名を返す。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `selector` - Component to disable.
+pub fn disable_component(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    selector: &ComponentSelector,
+) -> Result<Vec<String>, String> {
+    let _lock = lock_cached(cache, plugin_name, marketplace).map_err(outcome_error)?;
+    let component = find_cached_component(cache, plugin_name, marketplace, selector)?;
+    let plugin_path = cache.plugin_path(marketplace, plugin_name);
+    let plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    let deployed_prefix =
+        CommandPrefix::from_stored(plugin_meta.deployed_command_prefix.as_deref());
+
+    // 配置記録はコンポーネント名を持たないため、配置先はスキャン結果から解決する
+    let intent = PluginIntent::new(
+        PluginAction::Disable {
+            plugin_name: plugin_name.to_string(),
+            marketplace: marketplace.map(|s| s.to_string()),
+        },
+        vec![component.clone()],
+        project_root.to_path_buf(),
+    )
+    .with_enabled_targets(project_enabled_targets(project_root))
+    .with_command_prefix(deployed_prefix)
+    .with_prompt_naming(PromptNaming::configured().unwrap_or_default());
+    let result = apply_and_record(intent, &plugin_path);
+    if !result.success {
+        return Err(outcome_error(result));
+    }
+
+    // 配置記録の更新を上書きしないよう読み直してから書き込む
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    if plugin_meta.disable_component(component.kind, &component.name) {
+        meta::write_meta(&plugin_path, &plugin_meta)
+            .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))?;
+    }
+    Ok(result
+        .affected_targets
+        .target_names()
+        .into_iter()
+        .map(String::from)
+        .collect())
+}
+
+/// コンポーネント単位で無効化したコンポーネントを Enable する（`plm enable --component`）
+///
+/// `disabledComponents` から外し、プラグインを配置しているターゲット（部分 disable 中の
+/// ターゲットは残している種別に含まれる場合だけ）へ配置する。配置したターゲット名を返す。
+/// プラグイン自体が無効なら記録を外すだけで、次回のプラグインの enable で配置される。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `selector` - Component to enable.
+pub fn enable_component(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    selector: &ComponentSelector,
+) -> Result<Vec<String>, String> {
+    let _lock = lock_cached(cache, plugin_name, marketplace).map_err(outcome_error)?;
+    let component = find_cached_component(cache, plugin_name, marketplace, selector)?;
+    let plugin_path = cache.plugin_path(marketplace, plugin_name);
+    let plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    // 他のコマンドと同じ名前で並ぶよう、配置済みのプレフィクスで配置する
+    let prefix = CommandPrefix::from_stored(plugin_meta.deployed_command_prefix.as_deref());
+    let prompt_naming = PromptNaming::configured()?;
+    let mut targets: Vec<String> = plugin_meta
+        .deployed_targets()
+        .into_iter()
+        .filter(|t| {
+            plugin_meta
+                .deploy_kinds(t)
+                .is_none_or(|kinds| kinds.contains(&component.kind))
+        })
+        .map(String::from)
+        .collect();
+    targets.sort();
+
+    let mut deployed = Vec::new();
+    for target in &targets {
+        let intent = PluginIntent::with_target_filter(
+            PluginAction::Enable {
+                plugin_name: plugin_name.to_string(),
+                marketplace: marketplace.map(|s| s.to_string()),
+            },
+            vec![component.clone()],
+            project_root.to_path_buf(),
+            Some(target),
+        )
+        .with_enabled_targets(project_enabled_targets(project_root))
+        .with_command_prefix(prefix.clone())
+        .with_prompt_naming(prompt_naming);
+        let placed = apply_and_record(intent, &plugin_path);
+        if !placed.success {
+            return Err(outcome_error(placed));
+        }
+        deployed.extend(
+            placed
+                .affected_targets
+                .target_names()
+                .into_iter()
+                .map(String::from),
+        );
+    }
+
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    if plugin_meta.enable_component(component.kind, &component.name) {
+        meta::write_meta(&plugin_path, &plugin_meta)
+            .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))?;
+    }
+    Ok(deployed)
+}
+
+/// 失敗した操作結果のエラーメッセージ
+///
+/// # Arguments
+///
+/// * `outcome` - Failed operation outcome.
+fn outcome_error(outcome: OperationOutcome) -> String {
+    outcome.error.unwrap_or_else(|| "Unknown error".to_string())
+}
+
+/// コマンドのプレフィクス設定の変更をデプロイ先に反映する
+///
+/// `.plm-meta.json` の `commandPrefix`（設定値）と `deployedCommandPrefix`（配置済みの値）が
+/// 異なる場合、旧名のコマンドを全ターゲットから撤去し、有効なターゲットへ新しい名前で
+/// 配置し直す。撤去に成功した時点で `deployedCommandPrefix` を更新するため、再配置に
+/// 失敗しても次回の enable で再試行される。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+pub fn migrate_command_prefix(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+) -> Result<(), String> {
+    let plugin_path = cache.plugin_path(marketplace, plugin_name);
+    let Some(plugin_meta) = meta::load_meta(&plugin_path) else {
+        return Ok(());
+    };
+    if plugin_meta.command_prefix == plugin_meta.deployed_command_prefix {
+        return Ok(());
+    }
+
+    let plugin = load_plugin(cache, marketplace, plugin_name)?;
+    let commands: Vec<Component> = plugin
+        .components()
+        .iter()
+        .filter(|c| c.kind == ComponentKind::Command)
+        .filter(|c| !plugin_meta.is_component_disabled(c.kind, &c.name))
+        .cloned()
+        .collect();
+    let old_prefix = CommandPrefix::from_stored(plugin_meta.deployed_command_prefix.as_deref());
+    let new_prefix = CommandPrefix::from_stored(plugin_meta.command_prefix.as_deref());
+    let prompt_naming = PromptNaming::configured()?;
+    // 部分 disable 中のターゲットはコマンドを残している場合だけ配置し直す
+    let targets: Vec<String> = plugin_meta
+        .deployed_targets()
+        .into_iter()
+        .filter(|t| {
+            plugin_meta
+                .deploy_kinds(t)
+                .is_none_or(|kinds| kinds.contains(&ComponentKind::Command))
+        })
+        .map(String::from)
+        .collect();
+
+    // 旧名のコマンドを全ターゲットから撤去
+    if !commands.is_empty() {
+        let intent = PluginIntent::new(
+            PluginAction::Disable {
+                plugin_name: plugin_name.to_string(),
+                marketplace: marketplace.map(|s| s.to_string()),
+            },
+            commands.clone(),
+            project_root.to_path_buf(),
+        )
+        .with_command_prefix(old_prefix)
+        .with_prompt_naming(prompt_naming);
+        let removed = apply_and_record(intent, &plugin_path);
+        if !removed.success {
+            return Err(removed.error.unwrap_or_default());
+        }
+    }
+
+    // 撤去で更新された配置記録を上書きしないよう読み直してから書き込む
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or(plugin_meta);
+    plugin_meta.deployed_command_prefix = plugin_meta.command_prefix.clone();
+    meta::write_meta(&plugin_path, &plugin_meta).map_err(|e| e.to_string())?;
+
+    // 有効なターゲットへ新しい名前で配置
+    if commands.is_empty() {
+        return Ok(());
+    }
+    for target in &targets {
+        let intent = PluginIntent::with_target_filter(
+            PluginAction::Enable {
+                plugin_name: plugin_name.to_string(),
+                marketplace: marketplace.map(|s| s.to_string()),
+            },
+            commands.clone(),
+            project_root.to_path_buf(),
+            Some(target),
+        )
+        .with_command_prefix(new_prefix.clone())
+        .with_prompt_naming(prompt_naming);
+        let placed = apply_and_record(intent, &plugin_path);
+        if !placed.success {
+            return Err(placed.error.unwrap_or_default());
+        }
+    }
+    Ok(())
+}
+
+/// 意図を実行し、成功したターゲットの配置記録（`placements.json` と `.plm-meta.json` の
+/// `deployedFiles`）を更新する
+///
+/// # Arguments
+///
+/// * `intent` - Intent to apply.
+/// * `plugin_path` - Filesystem path of the cached plugin.
+fn apply_and_record(intent: PluginIntent, plugin_path: &Path) -> OperationOutcome {
+    let destinations = intent.destinations();
+    let replaced = intent.replaced_destinations();
+    let deploy = intent.action().is_deploy();
+    let marketplace = intent.action().marketplace().map(String::from);
+    let plugin = intent.action().plugin_name().to_string();
+    let project_root = intent.project_root().to_path_buf();
+
+    let result = intent.apply();
+
+    let succeeded = result.affected_targets.target_names();
+    let done: Vec<_> = destinations
+        .into_iter()
+        .filter(|(target, _, _)| succeeded.contains(&target.as_str()))
+        .collect();
+    let replaced: Vec<_> = replaced
+        .into_iter()
+        .filter(|(target, _, _)| succeeded.contains(&target.as_str()))
+        .collect();
+    if deploy {
+        let placed = done
+            .iter()
+            .filter(|(_, kind, _)| PlacementRecord::is_tracked(*kind))
+            .map(|(target, kind, path)| PlacementRecord {
+                path: path.clone(),
+                target: target.as_str().to_string(),
+                kind: *kind,
+                marketplace: marketplace.clone(),
+                plugin: plugin.clone(),
+            })
+            .collect();
+        let removed: Vec<PathBuf> = replaced.iter().map(|(_, _, path)| path.clone()).collect();
+        update_placements(placed, &removed, &project_root);
+        record_deployed_files(plugin_path, &done, &replaced);
+    } else {
+        let removed: Vec<PathBuf> = done.iter().map(|(_, _, path)| path.clone()).collect();
+        update_placements(Vec::new(), &removed, &project_root);
+        record_deployed_files(plugin_path, &[], &done);
+    }
+
+    result
+}
+
+/// `.plm-meta.json` の `deployedFiles` を更新する（best-effort）
+///
+/// 記録が変わらない場合は書き込まない。書き込みに失敗しても操作自体は成功のまま
+/// 警告だけ出す（次回の disable は記録の残りとスキャン結果で解決される）。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+/// * `placed` - Destinations written by this operation.
+/// * `removed` - Destinations removed or replaced by this operation.
+fn record_deployed_files(
+    plugin_path: &Path,
+    placed: &[(TargetKind, ComponentKind, PathBuf)],
+    removed: &[(TargetKind, ComponentKind, PathBuf)],
+) {
+    let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    let mut changed = false;
+    for (target, _, path) in removed {
+        changed |= plugin_meta.forget_deployed_file(target.as_str(), path);
+    }
+    for (target, kind, path) in placed
+        .iter()
+        .filter(|(_, kind, _)| PlacementRecord::is_tracked(*kind))
+    {
+        changed |= plugin_meta.record_deployed_file(target.as_str(), *kind, path);
+    }
+    if !changed {
+        return;
+    }
+    if let Err(e) = meta::write_meta(plugin_path, &plugin_meta) {
+        eprintln!("Warning: Failed to update .plm-meta.json: {}", e);
+    }
 }
 
 /// アンインストール前の情報取得
@@ -187,10 +758,36 @@ pub fn uninstall_plugin(
     marketplace: Option<&str>,
     project_root: &Path,
 ) -> OperationOutcome {
+    let _lock = match lock_cached(cache, plugin_name, marketplace) {
+        Ok(lock) => lock,
+        Err(outcome) => return outcome,
+    };
+
+    // 途中失敗時の復元先を決めるため、削除前に配置記録のあるターゲットを控えておく
+    let recorded_targets: BTreeSet<String> =
+        meta::load_meta(&cache.plugin_path(marketplace, plugin_name))
+            .map(|m| m.deployed_files.into_keys().collect())
+            .unwrap_or_default();
+
     // まずデプロイ先から削除（全ターゲット）
     let disable_result = disable_plugin(cache, plugin_name, marketplace, project_root, None);
     if !disable_result.success {
-        return disable_result;
+        let removed = disable_result.affected_targets.target_names();
+        if removed.is_empty() {
+            return disable_result;
+        }
+        let mut targets = recorded_targets;
+        targets.extend(removed.into_iter().map(String::from));
+        let error = disable_result
+            .error
+            .unwrap_or_else(|| "Unknown error".to_string());
+        let failures = restore_targets(cache, plugin_name, marketplace, project_root, &targets);
+        return OperationOutcome::error(rollback_message(&error, &failures));
+    }
+
+    // sandbox の場所はキャッシュ内の .plm-meta.json にしか記録が無いため、先に消す
+    if let Err(e) = super::sandbox::remove_sandbox(&cache.plugin_path(marketplace, plugin_name)) {
+        return OperationOutcome::error(e);
     }
 
     if let Err(e) = cache.remove(marketplace, plugin_name) {
@@ -200,6 +797,114 @@ pub fn uninstall_plugin(
     disable_result
 }
 
+/// uninstall の途中失敗で削除済みになった配置物をキャッシュから再デプロイする
+///
+/// 失敗したターゲットも途中まで削除されている場合があるため、削除に成功した
+/// ターゲットに限らず削除前に配置記録のあったターゲットすべてへ配置し直す。
+/// 再デプロイに失敗したターゲットとそのエラーを返す。
+///
+/// # Arguments
+///
+/// * `cache` - プラグインを検索するためのパッケージキャッシュアクセサ
+/// * `plugin_name` - プラグインの id（キャッシュディレクトリ名）
+/// * `marketplace` - マーケットプレイス名（任意）
+/// * `project_root` - プロジェクトルートパス
+/// * `targets` - Targets to redeploy.
+fn restore_targets(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: Option<&str>,
+    project_root: &Path,
+    targets: &BTreeSet<String>,
+) -> Vec<(String, String)> {
+    targets
+        .iter()
+        .filter_map(|target| {
+            let outcome =
+                enable_plugin(cache, plugin_name, marketplace, project_root, Some(target)).outcome;
+            (!outcome.success).then(|| (target.clone(), outcome.error.unwrap_or_default()))
+        })
+        .collect()
+}
+
+/// ロールバック結果を uninstall のエラーメッセージに添える
+///
+/// # Arguments
+///
+/// * `error` - Error that aborted the uninstall.
+/// * `failures` - Targets whose redeploy failed, with the error.
+fn rollback_message(error: &str, failures: &[(String, String)]) -> String {
+    if failures.is_empty() {
+        return format!("{} (removed components were restored from cache)", error);
+    }
+    let details: Vec<String> = failures
+        .iter()
+        .map(|(target, e)| format!("{}: {}", target, e))
+        .collect();
+    format!("{} (rollback failed for {})", error, details.join("; "))
+}
+
+/// 配置記録にあり、デプロイ先に実際に残っている配置物
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemainingFile {
+    /// ターゲット名
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// 配置先のパス
+    pub path: PathBuf,
+}
+
+/// 配置記録（`deployedFiles`）のうちデプロイ先に残っているものを列挙する
+///
+/// disable / uninstall が途中で失敗したときに、どのファイルが残っているかを
+/// 示すために使う。ターゲット名とパスの順に並べて返す。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+pub fn remaining_deployed_files(plugin_path: &Path) -> Vec<RemainingFile> {
+    let plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    let mut remaining: Vec<RemainingFile> = plugin_meta
+        .deployed_files
+        .into_iter()
+        .flat_map(|(target, files)| {
+            files.into_iter().map(move |file| RemainingFile {
+                target: target.clone(),
+                kind: file.kind,
+                path: PathBuf::from(file.path),
+            })
+        })
+        .filter(|file| file.path.exists())
+        .collect();
+    remaining.sort_by(|a, b| (&a.target, &a.path).cmp(&(&b.target, &b.path)));
+    remaining
+}
+
+/// 破壊的操作の前に退避するパス（`--backup`）
+///
+/// 配置記録に残っている配置先に加え、キャッシュを書き換える操作（uninstall / update）
+/// ではキャッシュ全体を、それ以外では `.plm-meta.json` だけを含める。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+/// * `target` - Only include files deployed to this target, if given.
+/// * `include_cache` - Include the whole cached plugin instead of only its metadata.
+pub fn backup_paths(plugin_path: &Path, target: Option<&str>, include_cache: bool) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = remaining_deployed_files(plugin_path)
+        .into_iter()
+        .filter(|file| target.is_none_or(|t| file.target == t))
+        .map(|file| file.path)
+        .collect();
+    if include_cache {
+        paths.push(plugin_path.to_path_buf());
+    } else {
+        paths.push(plugin_path.join(meta::META_FILE));
+    }
+    paths
+}
+
 #[cfg(test)]
 #[path = "lifecycle_test.rs"]
 mod tests;
@@ -1,7 +1,7 @@
 use super::*;
 use crate::plugin::PackageCache;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 fn create_test_cache() -> (TempDir, PackageCache) {
@@ -91,11 +91,419 @@ fn test_enable_plugin_success() {
         Some("github"),
         project_root.path(),
         None,
-    );
+    )
+    .outcome;
     assert!(result.success);
     assert!(result.error.is_none());
 }
 
+// ========================================
+// deployedFiles tests
+// ========================================
+
+/// キャッシュ内のプラグインに Skill を追加
+fn add_skill(cache_dir: &Path, name: &str, skill: &str) {
+    let skill_dir = cache_dir
+        .join("github")
+        .join(name)
+        .join("skills")
+        .join(skill);
+    fs::create_dir_all(&skill_dir).unwrap();
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        format!("---\nname: {}\n---\n", skill),
+    )
+    .unwrap();
+}
+
+/// 記録済みの配置先（ターゲット別）
+fn recorded_paths(plugin_dir: &Path, target: &str) -> Vec<PathBuf> {
+    meta::load_meta(plugin_dir)
+        .unwrap_or_default()
+        .deployed_files
+        .get(target)
+        .map(|files| files.iter().map(|f| PathBuf::from(&f.path)).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_enable_plugin_records_deployed_files() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+
+    let result = enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    )
+    .outcome;
+
+    assert!(result.success, "{:?}", result.error);
+    let recorded = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(recorded.len(), 1);
+    assert!(recorded[0].ends_with("review"));
+    assert!(recorded[0].exists());
+}
+
+#[test]
+fn test_disable_plugin_removes_recorded_files_after_cache_changed() {
+    // 記録があればキャッシュの構成が変わっていても、実際に配置したものを削除する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    let deployed = recorded_paths(&plugin_dir, "cursor");
+    fs::remove_dir_all(plugin_dir.join("skills").join("review")).unwrap();
+    add_skill(temp_dir.path(), "my-plugin", "lint");
+
+    let result = disable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(!deployed[0].exists());
+    assert!(recorded_paths(&plugin_dir, "cursor").is_empty());
+    assert!(meta::load_meta(&plugin_dir)
+        .unwrap()
+        .deployed_files
+        .is_empty());
+}
+
+#[test]
+fn test_enable_plugin_removes_files_no_longer_in_cache() {
+    // 再 enable（update の再デプロイ）では記録にあって今回配置しないものを削除する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    let old = recorded_paths(&plugin_dir, "cursor");
+    fs::remove_dir_all(plugin_dir.join("skills").join("review")).unwrap();
+    add_skill(temp_dir.path(), "my-plugin", "lint");
+
+    let result = enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    )
+    .outcome;
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(!old[0].exists());
+    let recorded = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(recorded.len(), 1);
+    assert!(recorded[0].ends_with("lint"));
+    assert!(recorded[0].exists());
+}
+
+#[test]
+fn test_disable_plugin_falls_back_to_scan_without_record() {
+    // 古いバージョンで配置した（記録の無い）プラグインはスキャン結果で削除する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    let deployed = recorded_paths(&plugin_dir, "cursor");
+    meta::write_meta(&plugin_dir, &meta::PluginMeta::default()).unwrap();
+
+    let result = disable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(!deployed[0].exists());
+}
+
+#[test]
+fn test_disable_plugin_mixes_recorded_and_scanned_targets() {
+    // 移行期: 記録のあるターゲットは記録で、無いターゲットはスキャンで削除する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        None,
+    );
+    let mut plugin_meta = meta::load_meta(&plugin_dir).unwrap();
+    let deployed: Vec<PathBuf> = plugin_meta
+        .deployed_files
+        .values()
+        .flatten()
+        .map(|f| PathBuf::from(&f.path))
+        .collect();
+    assert!(plugin_meta.deployed_files.len() > 1);
+    let legacy_target = plugin_meta.deployed_files.keys().next().unwrap().clone();
+    plugin_meta.deployed_files.remove(&legacy_target);
+    meta::write_meta(&plugin_dir, &plugin_meta).unwrap();
+
+    let result = disable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        None,
+    );
+
+    assert!(result.success, "{:?}", result.error);
+    for path in &deployed {
+        assert!(!path.exists(), "{} should be removed", path.display());
+    }
+    assert!(meta::load_meta(&plugin_dir)
+        .unwrap()
+        .deployed_files
+        .is_empty());
+}
+
+#[test]
+fn test_disable_plugin_filtered_keeps_other_kinds_deployed() {
+    // 種別を絞った disable（`plm disable --keep`）は対象外の種別の配置物と記録を残す
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    fs::create_dir_all(plugin_dir.join("commands")).unwrap();
+    fs::write(plugin_dir.join("commands/deploy.md"), "# Deploy").unwrap();
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    let deployed = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(deployed.len(), 2);
+
+    let filter = DeployFilter {
+        target: Some("cursor"),
+        kinds: Some(&[ComponentKind::Command]),
+    };
+    let result = disable_plugin_filtered(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        filter,
+    );
+
+    assert!(result.success, "{:?}", result.error);
+    let remaining = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(remaining.len(), 1);
+    assert!(remaining[0].ends_with("review"));
+    assert!(remaining[0].exists());
+    assert!(deployed
+        .iter()
+        .filter(|p| !p.ends_with("review"))
+        .all(|p| !p.exists()));
+}
+
+// ========================================
+// component disable/enable tests
+// ========================================
+
+fn skill_selector(name: &str) -> ComponentSelector {
+    ComponentSelector {
+        kind: ComponentKind::Skill,
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn test_disable_component_removes_only_that_component() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    add_skill(temp_dir.path(), "my-plugin", "lint");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    let deployed = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(deployed.len(), 2);
+
+    let targets = disable_component(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        &skill_selector("review"),
+    )
+    .unwrap();
+
+    assert!(targets.iter().any(|t| t == "cursor"), "{:?}", targets);
+    let remaining = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(remaining.len(), 1);
+    assert!(remaining[0].ends_with("lint"));
+    assert!(remaining[0].exists());
+    assert!(deployed
+        .iter()
+        .filter(|p| p.ends_with("review"))
+        .all(|p| !p.exists()));
+    assert!(meta::load_meta(&plugin_dir)
+        .unwrap()
+        .is_component_disabled(ComponentKind::Skill, "review"));
+}
+
+#[test]
+fn test_enable_plugin_skips_disabled_component_until_enabled() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    add_skill(temp_dir.path(), "my-plugin", "lint");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    );
+    disable_component(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        &skill_selector("review"),
+    )
+    .unwrap();
+
+    // プラグイン全体の再 enable では無効化したコンポーネントを配置しない
+    let result = enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        Some("cursor"),
+    )
+    .outcome;
+    assert!(result.success, "{:?}", result.error);
+    let recorded = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(recorded.len(), 1);
+    assert!(recorded[0].ends_with("lint"));
+
+    let targets = enable_component(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        &skill_selector("review"),
+    )
+    .unwrap();
+
+    assert_eq!(targets, vec!["cursor".to_string()]);
+    let recorded = recorded_paths(&plugin_dir, "cursor");
+    assert_eq!(recorded.len(), 2);
+    assert!(recorded.iter().all(|p| p.exists()));
+    assert!(meta::load_meta(&plugin_dir)
+        .unwrap()
+        .disabled_components
+        .is_empty());
+}
+
+#[test]
+fn test_disable_component_not_found() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+
+    let result = disable_component(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        &skill_selector("missing"),
+    );
+
+    assert!(result.unwrap_err().contains("skill:missing"));
+}
+
+// ========================================
+// migrate_command_prefix tests
+// ========================================
+
+#[test]
+fn test_migrate_command_prefix_records_deployed_prefix() {
+    // 設定値と配置済みの値が異なる場合は移行後に配置済みの値を更新する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    fs::create_dir_all(plugin_dir.join("commands")).unwrap();
+    fs::write(plugin_dir.join("commands/deploy.md"), "# Deploy").unwrap();
+    let plugin_meta = meta::PluginMeta {
+        command_prefix: Some("foo:".to_string()),
+        ..Default::default()
+    };
+    meta::write_meta(&plugin_dir, &plugin_meta).unwrap();
+
+    migrate_command_prefix(&cache, "my-plugin", Some("github"), project_root.path()).unwrap();
+
+    let migrated = meta::load_meta(&plugin_dir).unwrap();
+    assert_eq!(migrated.deployed_command_prefix.as_deref(), Some("foo:"));
+}
+
+#[test]
+fn test_migrate_command_prefix_without_meta_is_noop() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+
+    assert!(
+        migrate_command_prefix(&cache, "my-plugin", Some("github"), project_root.path()).is_ok()
+    );
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    assert!(meta::load_meta(&plugin_dir).is_none());
+}
+
 // ========================================
 // Error tests
 // ========================================
@@ -157,8 +565,153 @@ fn test_enable_plugin_not_found() {
         Some("github"),
         &temp_dir,
         None,
-    );
+    )
+    .outcome;
     assert!(!result.success);
     assert!(result.error.is_some());
     assert!(result.error.unwrap().contains("not found"));
 }
+
+// ========================================
+// 操作ロック
+// ========================================
+
+#[test]
+fn test_uninstall_plugin_fails_while_another_process_holds_lock() {
+    // 別プロセス（PID 1 は常に存在する）が操作中ならキャッシュに触れずに失敗する
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    let lock_file = temp_dir.path().join("github").join(".my-plugin.lock");
+    fs::write(&lock_file, "1").unwrap();
+
+    let result = uninstall_plugin(&cache, "my-plugin", Some("github"), project_root.path());
+
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("plugin 'my-plugin' is being modified by another process")
+    );
+    assert!(cache.is_cached(Some("github"), "my-plugin"));
+    assert!(lock_file.exists());
+}
+
+#[test]
+fn test_uninstall_plugin_releases_lock() {
+    // uninstall → disable の再取得を通り、終了後はロックファイルが残らない
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+
+    let result = uninstall_plugin(&cache, "my-plugin", Some("github"), project_root.path());
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(!temp_dir
+        .path()
+        .join("github")
+        .join(".my-plugin.lock")
+        .exists());
+}
+
+// ========================================
+// uninstall の途中失敗とロールバック
+// ========================================
+
+#[test]
+fn test_uninstall_plugin_restores_removed_targets_on_partial_failure() {
+    // 1 ターゲットの削除が失敗したら、削除済みのターゲットへキャッシュから配置し直す
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        None,
+    );
+    let plugin_meta = meta::load_meta(&plugin_dir).unwrap();
+    assert!(plugin_meta.deployed_files.len() > 1);
+    // Skill のディレクトリをファイルに差し替えて、そのターゲットの削除を失敗させる
+    let broken_target = plugin_meta.deployed_files.keys().next().unwrap().clone();
+    let broken = recorded_paths(&plugin_dir, &broken_target).remove(0);
+    fs::remove_dir_all(&broken).unwrap();
+    fs::write(&broken, "not a directory").unwrap();
+    let restored: Vec<PathBuf> = plugin_meta
+        .deployed_files
+        .iter()
+        .filter(|(target, _)| **target != broken_target)
+        .flat_map(|(_, files)| files.iter().map(|f| PathBuf::from(&f.path)))
+        .collect();
+
+    let result = uninstall_plugin(&cache, "my-plugin", Some("github"), project_root.path());
+
+    assert!(!result.success);
+    assert!(result
+        .error
+        .as_deref()
+        .unwrap()
+        .contains(&format!("rollback failed for {}", broken_target)));
+    assert!(cache.is_cached(Some("github"), "my-plugin"));
+    for path in &restored {
+        assert!(
+            path.join("SKILL.md").exists(),
+            "{} should be restored",
+            path.display()
+        );
+    }
+    let remaining = remaining_deployed_files(&plugin_dir);
+    assert_eq!(remaining.len(), restored.len() + 1);
+    assert!(remaining
+        .iter()
+        .any(|file| file.target == broken_target && file.path == broken));
+}
+
+#[test]
+fn test_rollback_message_reports_restore_result() {
+    assert_eq!(
+        rollback_message("codex: denied", &[]),
+        "codex: denied (removed components were restored from cache)"
+    );
+    assert_eq!(
+        rollback_message(
+            "codex: denied",
+            &[("codex".to_string(), "busy".to_string())]
+        ),
+        "codex: denied (rollback failed for codex: busy)"
+    );
+}
+
+#[test]
+fn test_backup_paths_includes_deployed_files_and_meta_or_cache() {
+    let (temp_dir, cache) = create_test_cache();
+    let project_root = TempDir::new().unwrap();
+    setup_plugin_fixture(temp_dir.path(), "github", "my-plugin", "1.0.0");
+    add_skill(temp_dir.path(), "my-plugin", "review");
+    let plugin_dir = temp_dir.path().join("github").join("my-plugin");
+    enable_plugin(
+        &cache,
+        "my-plugin",
+        Some("github"),
+        project_root.path(),
+        None,
+    );
+    let target = meta::load_meta(&plugin_dir)
+        .unwrap()
+        .deployed_files
+        .keys()
+        .next()
+        .unwrap()
+        .clone();
+
+    let mut expected = recorded_paths(&plugin_dir, &target);
+    expected.sort();
+    expected.push(plugin_dir.join(meta::META_FILE));
+    assert_eq!(backup_paths(&plugin_dir, Some(&target), false), expected);
+
+    let paths = backup_paths(&plugin_dir, None, true);
+    assert_eq!(paths.last(), Some(&plugin_dir));
+    assert!(!paths.contains(&plugin_dir.join(meta::META_FILE)));
+}
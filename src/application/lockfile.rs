@@ -0,0 +1,389 @@
+//! プロジェクトのロックファイル（`plm.lock`）
+//!
+//! `plm sync --lock` はインストール済みプラグインの marketplace・名前・バージョンと、
+//! 取得元のコミット SHA（取得できない場合はキャッシュ内容のハッシュ）をプロジェクトルートの
+//! `plm.lock` に書き出す。`plm sync --frozen` はこれを読み、記録どおりのバージョンを
+//! 取得して配置する（取得と配置は commands 層が行う）。`plm sync --check` と `--frozen` は
+//! 同じ [`diff_lock`] で実際のキャッシュ・デプロイ状態との差分を判定する。
+
+use crate::error::{PlmError, Result};
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::meta::{self, META_FILE};
+use crate::plugin::{relative_key, PackageCacheAccess};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// プロジェクトルートに置くロックファイル名
+pub const LOCK_FILE: &str = "plm.lock";
+
+/// このバージョンの plm が読み書きするロックファイルのフォーマットバージョン
+pub const LOCK_FORMAT_VERSION: u32 = 1;
+
+/// `plm.lock` のスキーマ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockFile {
+    /// フォーマットのバージョン（互換性のない変更で上げる）
+    pub version: u32,
+    /// ロックしたプラグイン（marketplace・名前の順）
+    pub plugins: Vec<LockedPlugin>,
+}
+
+/// ロックしたプラグイン 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPlugin {
+    /// マーケットプレイス名（直接 GitHub インストールは `github`）
+    pub marketplace: String,
+    /// プラグイン ID（キャッシュディレクトリ名）
+    pub name: String,
+    /// `plugin.json` のバージョン
+    pub version: String,
+    /// 直接 GitHub インストールの取得元（`owner/repo`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// 取得元のコミット SHA
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    /// コミット SHA が不明な場合のキャッシュ内容のハッシュ（`sha256:<hex>`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    /// 有効化されているターゲット
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+}
+
+impl LockedPlugin {
+    /// マーケットプレイス参照
+    pub fn marketplace_ref(&self) -> MarketplaceRef {
+        MarketplaceRef::parse(&self.marketplace)
+    }
+
+    /// 表示用の名前（`name@marketplace`。直接 GitHub は `owner/repo`）
+    pub fn display_name(&self) -> String {
+        match (&self.marketplace_ref(), &self.repository) {
+            (MarketplaceRef::Github, Some(repository)) => repository.clone(),
+            _ => format!("{}@{}", self.name, self.marketplace),
+        }
+    }
+}
+
+/// インストール済みプラグインからロックファイルを組み立てる
+///
+/// # Arguments
+///
+/// * `cache` - Package cache listing the installed plugins.
+pub fn build_lock_file(cache: &dyn PackageCacheAccess) -> Result<LockFile> {
+    let mut plugins = Vec::new();
+    for (marketplace, name) in cache.list()? {
+        let marketplace = MarketplaceRef::from_option(marketplace.as_deref());
+        let path = cache.plugin_path(Some(marketplace.dir_name()), &name);
+        let manifest = cache.load_manifest(Some(marketplace.dir_name()), &name)?;
+        let plugin_meta = meta::load_meta(&path).unwrap_or_default();
+
+        let commit_sha = plugin_meta.commit_sha.clone().filter(|sha| !sha.is_empty());
+        let content_hash = match commit_sha {
+            Some(_) => None,
+            None => Some(content_hash(&path)?),
+        };
+        let repository = plugin_meta
+            .get_source_repo()
+            .filter(|_| marketplace.is_github())
+            .map(|(owner, repo)| format!("{}/{}", owner, repo));
+        let mut targets: Vec<String> = plugin_meta
+            .enabled_targets()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        targets.sort();
+
+        plugins.push(LockedPlugin {
+            marketplace: marketplace.dir_name().to_string(),
+            name,
+            version: manifest.version,
+            repository,
+            commit_sha,
+            content_hash,
+            targets,
+        });
+    }
+    plugins.sort_by(|a, b| (&a.marketplace, &a.name).cmp(&(&b.marketplace, &b.name)));
+
+    Ok(LockFile {
+        version: LOCK_FORMAT_VERSION,
+        plugins,
+    })
+}
+
+/// ロックファイルを書き出す
+///
+/// # Arguments
+///
+/// * `path` - Path of the lock file.
+/// * `lock` - Lock file contents.
+pub fn write_lock_file(path: &Path, lock: &LockFile) -> Result<()> {
+    let mut json = serde_json::to_string_pretty(lock)?;
+    json.push('\n');
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// ロックファイルを読み込む
+///
+/// このバージョンの plm より新しいフォーマットはエラーにする。
+///
+/// # Arguments
+///
+/// * `path` - Path of the lock file.
+pub fn read_lock_file(path: &Path) -> Result<LockFile> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| PlmError::Parse(format!("failed to read {}: {}", path.display(), e)))?;
+    let lock: LockFile = serde_json::from_str(&content)
+        .map_err(|e| PlmError::Parse(format!("{}: {}", path.display(), e)))?;
+    if lock.version > LOCK_FORMAT_VERSION {
+        return Err(PlmError::Parse(format!(
+            "{} uses format version {}, but this plm supports up to version {}; upgrade plm",
+            path.display(),
+            lock.version,
+            LOCK_FORMAT_VERSION
+        )));
+    }
+    Ok(lock)
+}
+
+/// キャッシュ済みのプラグインがロックの記録と一致するか
+///
+/// バージョンに加えて、コミット SHA（無ければ内容のハッシュ）が一致する必要がある。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache to look the plugin up in.
+/// * `locked` - Locked plugin entry.
+pub fn matches_cache(cache: &dyn PackageCacheAccess, locked: &LockedPlugin) -> bool {
+    let marketplace = Some(locked.marketplace.as_str());
+    if !cache.is_cached(marketplace, &locked.name) {
+        return false;
+    }
+    let version_matches = cache
+        .load_manifest(marketplace, &locked.name)
+        .is_ok_and(|m| m.version == locked.version);
+    if !version_matches {
+        return false;
+    }
+
+    let path = cache.plugin_path(marketplace, &locked.name);
+    match (&locked.commit_sha, &locked.content_hash) {
+        (Some(sha), _) => meta::load_meta(&path).and_then(|m| m.commit_sha).as_ref() == Some(sha),
+        (None, Some(hash)) => content_hash(&path).is_ok_and(|h| &h == hash),
+        (None, None) => true,
+    }
+}
+
+/// ロックと実際の状態の差分の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockStatus {
+    /// キャッシュに無い、またはロックのターゲットにデプロイされていない
+    Missing,
+    /// キャッシュのバージョン・コミット・内容がロックと異なる
+    Mismatch,
+    /// ロックに無いデプロイ
+    Extra,
+}
+
+impl LockStatus {
+    /// 表示・JSON 用の名前
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LockStatus::Missing => "missing",
+            LockStatus::Mismatch => "mismatch",
+            LockStatus::Extra => "extra",
+        }
+    }
+}
+
+/// ロックと実際の状態の差分 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockDifference {
+    pub status: LockStatus,
+    /// 表示用の名前（[`LockedPlugin::display_name`]）
+    pub plugin: String,
+    pub marketplace: String,
+    pub name: String,
+    /// `plm.lock` のバージョン（ロックに無いデプロイは `None`）
+    pub locked_version: Option<String>,
+    /// キャッシュのバージョン（キャッシュに無ければ `None`）
+    pub installed_version: Option<String>,
+    /// デプロイの差分ならそのターゲット（プラグイン自体の差分は `None`）
+    pub target: Option<String>,
+}
+
+/// ロックと実際のキャッシュ・デプロイ状態の差分
+///
+/// `plm sync --frozen` はこの差分から取得し直すプラグインを決め、`plm sync --check` は
+/// この差分をそのまま報告する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockDiff {
+    /// ロックの順（marketplace・名前の順）に、プラグイン自体の差分 → デプロイの差分、
+    /// 最後にロックに無いデプロイ
+    pub differences: Vec<LockDifference>,
+}
+
+impl LockDiff {
+    /// 差分が無いか
+    pub fn is_in_sync(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// 指定した種類の差分の件数
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Kind of difference to count.
+    pub fn count(&self, status: LockStatus) -> usize {
+        self.differences
+            .iter()
+            .filter(|d| d.status == status)
+            .count()
+    }
+
+    /// 記録どおりのプラグインを取得し直す必要があるか（キャッシュに無い・記録と異なる）
+    ///
+    /// # Arguments
+    ///
+    /// * `locked` - Locked plugin entry.
+    pub fn needs_fetch(&self, locked: &LockedPlugin) -> bool {
+        self.differences.iter().any(|d| {
+            d.status != LockStatus::Extra
+                && d.target.is_none()
+                && d.marketplace == locked.marketplace
+                && d.name == locked.name
+        })
+    }
+}
+
+/// ロックと実際のキャッシュ・デプロイ状態（`.plm-meta.json` の有効なターゲット）を比較する
+///
+/// プラグインの一致は [`matches_cache`] で判定する。ファイルは変更しない。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache listing the installed plugins.
+/// * `lock` - Lock file contents.
+pub fn diff_lock(cache: &dyn PackageCacheAccess, lock: &LockFile) -> Result<LockDiff> {
+    let installed = build_lock_file(cache)?.plugins;
+    let find = |marketplace: &str, name: &str| {
+        installed
+            .iter()
+            .find(|p| p.marketplace == marketplace && p.name == name)
+    };
+
+    let mut differences = Vec::new();
+    for locked in &lock.plugins {
+        let difference =
+            |status, actual: Option<&LockedPlugin>, target: Option<&str>| LockDifference {
+                status,
+                plugin: locked.display_name(),
+                marketplace: locked.marketplace.clone(),
+                name: locked.name.clone(),
+                locked_version: Some(locked.version.clone()),
+                installed_version: actual.map(|p| p.version.clone()),
+                target: target.map(str::to_string),
+            };
+        let Some(actual) = find(&locked.marketplace, &locked.name) else {
+            differences.push(difference(LockStatus::Missing, None, None));
+            continue;
+        };
+        if !matches_cache(cache, locked) {
+            differences.push(difference(LockStatus::Mismatch, Some(actual), None));
+        }
+        for target in locked
+            .targets
+            .iter()
+            .filter(|t| !actual.targets.contains(t))
+        {
+            differences.push(difference(
+                LockStatus::Missing,
+                Some(actual),
+                Some(target.as_str()),
+            ));
+        }
+        for target in actual
+            .targets
+            .iter()
+            .filter(|t| !locked.targets.contains(t))
+        {
+            differences.push(difference(
+                LockStatus::Extra,
+                Some(actual),
+                Some(target.as_str()),
+            ));
+        }
+    }
+
+    for actual in &installed {
+        let locked = lock
+            .plugins
+            .iter()
+            .any(|p| p.marketplace == actual.marketplace && p.name == actual.name);
+        if locked {
+            continue;
+        }
+        for target in &actual.targets {
+            differences.push(LockDifference {
+                status: LockStatus::Extra,
+                plugin: actual.display_name(),
+                marketplace: actual.marketplace.clone(),
+                name: actual.name.clone(),
+                locked_version: None,
+                installed_version: Some(actual.version.clone()),
+                target: Some(target.clone()),
+            });
+        }
+    }
+    Ok(LockDiff { differences })
+}
+
+/// キャッシュ内容のハッシュ（`sha256:<hex>`）
+///
+/// プラグインルートからの相対パス順にパスと内容をハッシュする。
+/// PLM 自身のメタデータ（`.plm-meta.json`）は含めない。
+///
+/// # Arguments
+///
+/// * `root` - Plugin cache directory.
+pub fn content_hash(root: &Path) -> Result<String> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(root).min_depth(1) {
+        let entry = entry.map_err(|e| PlmError::Cache(e.to_string()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = relative_key(root, entry.path());
+        if relative == META_FILE {
+            continue;
+        }
+        files.push((relative, entry.into_path()));
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for (relative, path) in files {
+        hasher.update(relative.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(fs::read(path)?);
+        hasher.update([0u8]);
+    }
+    let digest: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Ok(format!("sha256:{}", digest))
+}
+
+#[cfg(test)]
+#[path = "lockfile_test.rs"]
+mod tests;
@@ -0,0 +1,159 @@
+use super::*;
+use crate::plugin::meta::{write_meta, PluginMeta, TargetStatus};
+use crate::plugin::PackageCache;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn write_plugin(dir: &TempDir, marketplace: &str, name: &str, version: &str) -> PathBuf {
+    let plugin_dir = dir.path().join(marketplace).join(name);
+    fs::create_dir_all(plugin_dir.join("skills").join("fmt")).unwrap();
+    fs::write(
+        plugin_dir.join("plugin.json"),
+        format!(r#"{{"name":"{}","version":"{}"}}"#, name, version),
+    )
+    .unwrap();
+    fs::write(plugin_dir.join("skills/fmt/SKILL.md"), "# fmt\n").unwrap();
+    plugin_dir
+}
+
+#[test]
+fn build_lock_file_records_sha_or_content_hash() {
+    let dir = TempDir::new().unwrap();
+    let github_dir = write_plugin(&dir, "github", "owner--formatter", "1.2.0");
+    let mut github_meta = PluginMeta::default();
+    github_meta.set_git_info("main", "abc123");
+    github_meta.set_source_repo("owner", "formatter");
+    github_meta.set_status("codex", TargetStatus::Enabled);
+    write_meta(&github_dir, &github_meta).unwrap();
+    write_plugin(&dir, "company", "linter", "0.3.0");
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+
+    let lock = build_lock_file(&cache).unwrap();
+
+    assert_eq!(lock.version, LOCK_FORMAT_VERSION);
+    assert_eq!(lock.plugins.len(), 2);
+    let linter = &lock.plugins[0];
+    assert_eq!(linter.display_name(), "linter@company");
+    assert_eq!(linter.version, "0.3.0");
+    assert!(linter.commit_sha.is_none());
+    assert!(linter
+        .content_hash
+        .as_deref()
+        .is_some_and(|h| h.starts_with("sha256:")));
+    let formatter = &lock.plugins[1];
+    assert_eq!(formatter.display_name(), "owner/formatter");
+    assert_eq!(formatter.commit_sha.as_deref(), Some("abc123"));
+    assert!(formatter.content_hash.is_none());
+    assert_eq!(formatter.targets, vec!["codex".to_string()]);
+}
+
+#[test]
+fn lock_file_round_trips_and_matches_cache() {
+    let dir = TempDir::new().unwrap();
+    let plugin_dir = write_plugin(&dir, "company", "linter", "0.3.0");
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+    let lock = build_lock_file(&cache).unwrap();
+    let lock_path = dir.path().join(LOCK_FILE);
+
+    write_lock_file(&lock_path, &lock).unwrap();
+    let read = read_lock_file(&lock_path).unwrap();
+
+    assert_eq!(read, lock);
+    assert!(matches_cache(&cache, &read.plugins[0]));
+    fs::write(plugin_dir.join("skills/fmt/SKILL.md"), "# changed\n").unwrap();
+    assert!(!matches_cache(&cache, &read.plugins[0]));
+}
+
+#[test]
+fn content_hash_ignores_plm_meta() {
+    let dir = TempDir::new().unwrap();
+    let plugin_dir = write_plugin(&dir, "company", "linter", "0.3.0");
+    let before = content_hash(&plugin_dir).unwrap();
+
+    write_meta(&plugin_dir, &PluginMeta::default()).unwrap();
+
+    assert_eq!(content_hash(&plugin_dir).unwrap(), before);
+}
+
+#[test]
+fn read_lock_file_rejects_newer_format() {
+    let dir = TempDir::new().unwrap();
+    let lock_path = dir.path().join(LOCK_FILE);
+    fs::write(&lock_path, r#"{"version":99,"plugins":[]}"#).unwrap();
+
+    let err = read_lock_file(&lock_path).unwrap_err().to_string();
+
+    assert!(err.contains("format version 99"), "{}", err);
+}
+
+/// プラグインのターゲットを有効として記録する
+fn enable(plugin_dir: &Path, targets: &[&str]) {
+    let mut plugin_meta = PluginMeta::default();
+    for target in targets {
+        plugin_meta.set_status(target, TargetStatus::Enabled);
+    }
+    write_meta(plugin_dir, &plugin_meta).unwrap();
+}
+
+#[test]
+fn diff_lock_is_in_sync_with_a_freshly_built_lock() {
+    let dir = TempDir::new().unwrap();
+    let plugin_dir = write_plugin(&dir, "company", "linter", "0.3.0");
+    enable(&plugin_dir, &["codex"]);
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+    let lock = build_lock_file(&cache).unwrap();
+
+    let diff = diff_lock(&cache, &lock).unwrap();
+
+    assert!(diff.is_in_sync(), "{:?}", diff);
+    assert!(!diff.needs_fetch(&lock.plugins[0]));
+}
+
+#[test]
+fn diff_lock_reports_missing_mismatched_and_extra_deployments() {
+    let dir = TempDir::new().unwrap();
+    let linter_dir = write_plugin(&dir, "company", "linter", "0.3.0");
+    enable(&linter_dir, &["codex"]);
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+    let mut lock = build_lock_file(&cache).unwrap();
+    let mut formatter = lock.plugins[0].clone();
+    formatter.name = "formatter".to_string();
+    lock.plugins.push(formatter);
+    lock.plugins[0].targets = vec!["copilot".to_string()];
+
+    // linter は lock より新しいバージョン、未ロックの reviewer は codex にデプロイ済み
+    fs::write(
+        linter_dir.join("plugin.json"),
+        r#"{"name":"linter","version":"0.4.0"}"#,
+    )
+    .unwrap();
+    let reviewer_dir = write_plugin(&dir, "company", "reviewer", "1.0.0");
+    enable(&reviewer_dir, &["codex"]);
+
+    let diff = diff_lock(&cache, &lock).unwrap();
+
+    let summary: Vec<(LockStatus, &str, Option<&str>)> = diff
+        .differences
+        .iter()
+        .map(|d| (d.status, d.name.as_str(), d.target.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (LockStatus::Mismatch, "linter", None),
+            (LockStatus::Missing, "linter", Some("copilot")),
+            (LockStatus::Extra, "linter", Some("codex")),
+            (LockStatus::Missing, "formatter", None),
+            (LockStatus::Extra, "reviewer", Some("codex")),
+        ]
+    );
+    assert_eq!(diff.differences[0].locked_version.as_deref(), Some("0.3.0"));
+    assert_eq!(
+        diff.differences[0].installed_version.as_deref(),
+        Some("0.4.0")
+    );
+    assert_eq!(diff.count(LockStatus::Missing), 2);
+    // --frozen はプラグイン自体の差分（キャッシュに無い・記録と異なる）だけを取得し直す
+    assert!(diff.needs_fetch(&lock.plugins[0]));
+    assert!(diff.needs_fetch(&lock.plugins[1]));
+}
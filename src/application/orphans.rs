@@ -0,0 +1,113 @@
+//! 孤立ファイルの検出と削除
+//!
+//! 配置記録（`~/.plm/placements.json`）にあるプロジェクト内の配置物のうち、
+//! 所有プラグインがキャッシュに存在しないものを孤立ファイル（orphaned）とする。
+//! 配置記録の無いファイルは plm 管理外とみなし、検出・削除の対象にしない。
+
+use crate::component::ComponentKind;
+use crate::plugin::{PackageCacheAccess, PlacementLedger, PlacementRecord};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 孤立ファイル 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedFile {
+    /// 配置先の絶対パス
+    pub path: PathBuf,
+    /// ターゲット名
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// 所有していたプラグイン（`plugin@marketplace` 形式）
+    pub owner: String,
+}
+
+impl OrphanedFile {
+    /// # Arguments
+    ///
+    /// * `record` - Placement record of the orphaned file.
+    fn from_record(record: &PlacementRecord) -> Self {
+        let owner = match &record.marketplace {
+            Some(marketplace) => format!("{}@{}", record.plugin, marketplace),
+            None => record.plugin.clone(),
+        };
+        Self {
+            path: record.path.clone(),
+            target: record.target.clone(),
+            kind: record.kind,
+            owner,
+        }
+    }
+}
+
+/// 孤立ファイル削除の結果
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct OrphanRemoval {
+    /// 削除したパス
+    pub removed: Vec<PathBuf>,
+    /// 削除できなかったパスと理由
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// プロジェクト内の孤立ファイルを列挙する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache used to check whether the owner is still installed.
+/// * `ledger` - Placement records.
+/// * `project_root` - Only placements under this directory are checked.
+pub fn find_orphans(
+    cache: &dyn PackageCacheAccess,
+    ledger: &PlacementLedger,
+    project_root: &Path,
+) -> Vec<OrphanedFile> {
+    let mut orphans: Vec<OrphanedFile> = ledger
+        .records()
+        .iter()
+        .filter(|r| r.path.starts_with(project_root) && r.path.exists())
+        .filter(|r| !cache.is_cached(r.marketplace.as_deref(), &r.plugin))
+        .map(OrphanedFile::from_record)
+        .collect();
+    orphans.sort_by(|a, b| a.path.cmp(&b.path));
+    orphans
+}
+
+/// 孤立ファイルを削除し、配置記録から外す
+///
+/// 配置記録に無いパスは削除しない（呼び出し側で作った一覧が古い場合の保護）。
+/// 配置記録の保存は呼び出し側で行う。
+///
+/// # Arguments
+///
+/// * `ledger` - Placement records to update.
+/// * `orphans` - Orphaned files returned by [`find_orphans`].
+pub fn remove_orphans(ledger: &mut PlacementLedger, orphans: &[OrphanedFile]) -> OrphanRemoval {
+    let mut removal = OrphanRemoval::default();
+    for orphan in orphans {
+        if !ledger.records().iter().any(|r| r.path == orphan.path) {
+            removal.failed.push((
+                orphan.path.clone(),
+                "not recorded as placed by plm".to_string(),
+            ));
+            continue;
+        }
+
+        let result = if orphan.path.is_dir() {
+            fs::remove_dir_all(&orphan.path)
+        } else {
+            fs::remove_file(&orphan.path)
+        };
+        match result {
+            Ok(()) => {
+                ledger.forget(&orphan.path);
+                removal.removed.push(orphan.path.clone());
+            }
+            Err(e) => removal.failed.push((orphan.path.clone(), e.to_string())),
+        }
+    }
+    removal
+}
+
+#[cfg(test)]
+#[path = "orphans_test.rs"]
+mod tests;
@@ -0,0 +1,118 @@
+use super::*;
+use crate::plugin::PackageCache;
+use tempfile::TempDir;
+
+struct Fixture {
+    _cache_dir: TempDir,
+    cache: PackageCache,
+    project: TempDir,
+    ledger: PlacementLedger,
+}
+
+fn fixture() -> Fixture {
+    let cache_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(cache_dir.path().to_path_buf()).unwrap();
+    let installed = cache_dir.path().join("company-tools").join("installed");
+    fs::create_dir_all(&installed).unwrap();
+    fs::write(
+        installed.join("plugin.json"),
+        r#"{"name":"installed","version":"1.0.0"}"#,
+    )
+    .unwrap();
+
+    let project = TempDir::new().unwrap();
+    let ledger = PlacementLedger::load_from(project.path().join("placements.json")).unwrap();
+    Fixture {
+        _cache_dir: cache_dir,
+        cache,
+        project,
+        ledger,
+    }
+}
+
+/// プロジェクト内にファイルを作成して配置記録に追加する
+fn place(fixture: &mut Fixture, relative: &str, plugin: &str) -> PathBuf {
+    let path = fixture.project.path().join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "# Command").unwrap();
+    fixture.ledger.record(PlacementRecord {
+        path: path.clone(),
+        target: "copilot".to_string(),
+        kind: ComponentKind::Command,
+        marketplace: Some("company-tools".to_string()),
+        plugin: plugin.to_string(),
+    });
+    path
+}
+
+#[test]
+fn find_orphans_reports_files_whose_plugin_is_gone() {
+    let mut fx = fixture();
+    place(
+        &mut fx,
+        ".github/prompts/company-tools/installed/a.prompt.md",
+        "installed",
+    );
+    let orphan = place(
+        &mut fx,
+        ".github/prompts/company-tools/removed/b.prompt.md",
+        "removed",
+    );
+
+    let orphans = find_orphans(&fx.cache, &fx.ledger, fx.project.path());
+
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].path, orphan);
+    assert_eq!(orphans[0].owner, "removed@company-tools");
+}
+
+#[test]
+fn find_orphans_ignores_unrecorded_and_missing_files() {
+    let mut fx = fixture();
+    let unmanaged = fx.project.path().join(".github/prompts/mine.prompt.md");
+    fs::create_dir_all(unmanaged.parent().unwrap()).unwrap();
+    fs::write(&unmanaged, "# Mine").unwrap();
+    let gone = place(
+        &mut fx,
+        ".github/prompts/company-tools/removed/gone.prompt.md",
+        "removed",
+    );
+    fs::remove_file(&gone).unwrap();
+
+    assert!(find_orphans(&fx.cache, &fx.ledger, fx.project.path()).is_empty());
+}
+
+#[test]
+fn find_orphans_ignores_other_projects() {
+    let mut fx = fixture();
+    place(&mut fx, "sub/.github/prompts/x.prompt.md", "removed");
+    let other = TempDir::new().unwrap();
+
+    assert!(find_orphans(&fx.cache, &fx.ledger, other.path()).is_empty());
+}
+
+#[test]
+fn remove_orphans_deletes_recorded_files_only() {
+    let mut fx = fixture();
+    let orphan = place(
+        &mut fx,
+        ".github/prompts/company-tools/removed/b.prompt.md",
+        "removed",
+    );
+    let orphans = find_orphans(&fx.cache, &fx.ledger, fx.project.path());
+    let unmanaged = fx.project.path().join("mine.md");
+    fs::write(&unmanaged, "# Mine").unwrap();
+    let mut forged = orphans.clone();
+    forged.push(OrphanedFile {
+        path: unmanaged.clone(),
+        ..orphans[0].clone()
+    });
+
+    let removal = remove_orphans(&mut fx.ledger, &forged);
+
+    assert_eq!(removal.removed, vec![orphan.clone()]);
+    assert_eq!(removal.failed.len(), 1);
+    assert!(!orphan.exists());
+    assert!(unmanaged.exists());
+    assert!(fx.ledger.records().is_empty());
+}
@@ -0,0 +1,97 @@
+//! バージョン固定（pin）
+//!
+//! `plm pin` は `.plm-meta.json` に `pinned` と固定時点のバージョン（`pinnedVersion`）を
+//! 記録する。固定中のプラグインは `plm update`（単体・`--all`）と TUI の更新で
+//! `Skipped (pinned)` として扱われ、キャッシュもデプロイ先も変更されない。
+
+use crate::plugin::{meta, PackageCacheAccess, PluginLock};
+
+/// `pin_plugin` の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PinOutcome {
+    /// 新たに固定した
+    Pinned { version: String },
+    /// 既に固定されていた（固定時のバージョンは変えない）
+    AlreadyPinned { version: Option<String> },
+}
+
+/// プラグインを現在のバージョンで固定する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+pub fn pin_plugin(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: &str,
+) -> Result<PinOutcome, String> {
+    let _lock = lock(cache, plugin_name, marketplace)?;
+    let plugin_path = cache.plugin_path(Some(marketplace), plugin_name);
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    if plugin_meta.pinned {
+        return Ok(PinOutcome::AlreadyPinned {
+            version: plugin_meta.pinned_version,
+        });
+    }
+
+    let version = cache
+        .load_manifest(Some(marketplace), plugin_name)
+        .map_err(|e| e.to_string())?
+        .version;
+    plugin_meta.pin(&version);
+    meta::write_meta(&plugin_path, &plugin_meta).map_err(|e| e.to_string())?;
+    Ok(PinOutcome::Pinned { version })
+}
+
+/// プラグインの固定を解除する
+///
+/// 固定を解除した場合は `true`、固定されていなかった場合は `false` を返す。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+pub fn unpin_plugin(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: &str,
+) -> Result<bool, String> {
+    let _lock = lock(cache, plugin_name, marketplace)?;
+    let plugin_path = cache.plugin_path(Some(marketplace), plugin_name);
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    if !plugin_meta.pinned {
+        return Ok(false);
+    }
+
+    plugin_meta.unpin();
+    meta::write_meta(&plugin_path, &plugin_meta).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// キャッシュ済みか確認して操作ロックを取る（update と `.plm-meta.json` の書き込みが競合しないように）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+fn lock(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: &str,
+) -> Result<PluginLock, String> {
+    if !cache.is_cached(Some(marketplace), plugin_name) {
+        return Err(format!(
+            "Plugin '{}' not found in cache (marketplace: {})",
+            plugin_name, marketplace
+        ));
+    }
+    PluginLock::acquire(cache, Some(marketplace), plugin_name).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+#[path = "pin_test.rs"]
+mod tests;
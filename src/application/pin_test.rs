@@ -0,0 +1,52 @@
+use super::*;
+use crate::plugin::PackageCache;
+use std::fs;
+use tempfile::TempDir;
+
+fn cache_with_plugin(dir: &TempDir) -> PackageCache {
+    let plugin_dir = dir.path().join("github").join("formatter");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    fs::write(
+        plugin_dir.join("plugin.json"),
+        r#"{"name":"formatter","version":"1.2.0"}"#,
+    )
+    .unwrap();
+    PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap()
+}
+
+#[test]
+fn pin_records_current_version_and_unpin_clears_it() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with_plugin(&dir);
+    let plugin_path = cache.plugin_path(Some("github"), "formatter");
+
+    assert_eq!(
+        pin_plugin(&cache, "formatter", "github"),
+        Ok(PinOutcome::Pinned {
+            version: "1.2.0".to_string()
+        })
+    );
+    let pinned = meta::load_meta(&plugin_path).unwrap();
+    assert!(pinned.pinned);
+    assert_eq!(pinned.pinned_version.as_deref(), Some("1.2.0"));
+    assert_eq!(
+        pin_plugin(&cache, "formatter", "github"),
+        Ok(PinOutcome::AlreadyPinned {
+            version: Some("1.2.0".to_string())
+        })
+    );
+
+    assert_eq!(unpin_plugin(&cache, "formatter", "github"), Ok(true));
+    assert!(!meta::load_meta(&plugin_path).unwrap().pinned);
+    assert_eq!(unpin_plugin(&cache, "formatter", "github"), Ok(false));
+}
+
+#[test]
+fn pin_unknown_plugin_fails() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with_plugin(&dir);
+
+    let err = pin_plugin(&cache, "missing", "github").unwrap_err();
+
+    assert!(err.contains("not found in cache"), "{}", err);
+}
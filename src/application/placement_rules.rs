@@ -0,0 +1,124 @@
+//! 配置ルールの一覧
+//!
+//! `plm placements` 用に、全ターゲット × 全コンポーネント種別 × 両スコープについて
+//! サポート有無と配置パスのテンプレートを求める。テンプレートはダミーの
+//! コンポーネント・出自で `Target::placement_location` を呼び、得られた実パスを
+//! プレースホルダへ逆置換して作る（ターゲット実装を直接なぞるので、ドキュメントと
+//! 実装のずれがそのまま表に出る）。
+
+use crate::component::{
+    ComponentKind, ComponentRef, PlacementContext, PlacementScope, ProjectContext, PromptNaming,
+    Scope,
+};
+use crate::target::{all_targets, PluginOrigin, Target, TargetKind};
+use std::path::Path;
+
+/// テンプレート導出用のダミープロジェクトルート
+const PROJECT_ROOT: &str = "/__plm_project__";
+/// ダミーのマーケットプレイス名
+const MARKETPLACE: &str = "__plm_marketplace__";
+/// ダミーのプラグイン名
+const PLUGIN: &str = "__plm_plugin__";
+/// ダミーのコンポーネント名（フラット化前）
+const NAME: &str = "__plm_name__";
+
+/// 配置ルール 1 件（ターゲット × 種別 × スコープ）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacementRule {
+    pub target: TargetKind,
+    pub kind: ComponentKind,
+    pub scope: Scope,
+    /// 配置パスのテンプレート（未サポートなら `None`）
+    ///
+    /// `{project}` / `~` / `{marketplace}` / `{plugin}` / `{name}` を含み、
+    /// ディレクトリ配置は末尾が `/`。
+    pub template: Option<String>,
+}
+
+impl PlacementRule {
+    /// この組み合わせに配置できるか
+    pub fn supported(&self) -> bool {
+        self.template.is_some()
+    }
+}
+
+/// 全ターゲット × 全コンポーネント種別 × 両スコープの配置ルール
+///
+/// 並びは `all_targets()` → `ComponentKind::all()` → Personal / Project の順。
+pub fn placement_rules() -> Vec<PlacementRule> {
+    let home = std::env::var_os("HOME")
+        .map(std::path::PathBuf::from)
+        .filter(|home| home.is_absolute());
+    let mut rules = Vec::new();
+    for target in all_targets() {
+        for &kind in ComponentKind::all() {
+            for scope in [Scope::Personal, Scope::Project] {
+                rules.push(PlacementRule {
+                    target: target.kind(),
+                    kind,
+                    scope,
+                    template: placement_template(target.as_ref(), kind, scope, home.as_deref()),
+                });
+            }
+        }
+    }
+    rules
+}
+
+/// ダミー入力で配置先を求め、テンプレートへ逆置換する
+///
+/// # Arguments
+///
+/// * `target` - Target to resolve the placement for.
+/// * `kind` - Component kind.
+/// * `scope` - Deployment scope.
+/// * `home` - Home directory to abbreviate as `~` (personal scope).
+fn placement_template(
+    target: &dyn Target,
+    kind: ComponentKind,
+    scope: Scope,
+    home: Option<&Path>,
+) -> Option<String> {
+    if !target.supports_scope(kind, scope) {
+        return None;
+    }
+    let origin = PluginOrigin::from_marketplace(MARKETPLACE, PLUGIN);
+    let context = PlacementContext {
+        component: ComponentRef::with_names(kind, format!("{}_{}", PLUGIN, NAME), NAME, PLUGIN),
+        origin: &origin,
+        scope: PlacementScope::new(scope),
+        project: ProjectContext::new(Path::new(PROJECT_ROOT)),
+    };
+    let location = target.placement_location(&context)?;
+    let location = PromptNaming::apply(PromptNaming::default(), target.kind(), &context, location);
+    let is_dir = location.is_dir();
+    let mut template = to_template(location.as_path(), home);
+    if is_dir {
+        template.push('/');
+    }
+    Some(template)
+}
+
+/// 実パスのダミー値をプレースホルダへ置き換える
+///
+/// # Arguments
+///
+/// * `path` - Path resolved from the dummy inputs.
+/// * `home` - Home directory to abbreviate as `~`.
+fn to_template(path: &Path, home: Option<&Path>) -> String {
+    let path = if let Ok(rest) = path.strip_prefix(PROJECT_ROOT) {
+        Path::new("{project}").join(rest)
+    } else if let Some(rest) = home.and_then(|home| path.strip_prefix(home).ok()) {
+        Path::new("~").join(rest)
+    } else {
+        path.to_path_buf()
+    };
+    path.to_string_lossy()
+        .replace(MARKETPLACE, "{marketplace}")
+        .replace(PLUGIN, "{plugin}")
+        .replace(NAME, "{name}")
+}
+
+#[cfg(test)]
+#[path = "placement_rules_test.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn to_template_replaces_project_root_and_dummy_names() {
+    let path = Path::new(PROJECT_ROOT)
+        .join(".github/prompts")
+        .join(MARKETPLACE)
+        .join(PLUGIN)
+        .join(format!("{}_{}.prompt.md", PLUGIN, NAME));
+
+    assert_eq!(
+        to_template(&path, None),
+        "{project}/.github/prompts/{marketplace}/{plugin}/{plugin}_{name}.prompt.md"
+    );
+}
+
+#[test]
+fn to_template_abbreviates_home() {
+    let path = Path::new("/home/user/.codex/skills").join(NAME);
+
+    assert_eq!(
+        to_template(&path, Some(Path::new("/home/user"))),
+        "~/.codex/skills/{name}"
+    );
+}
+
+#[test]
+fn placement_rules_cover_every_combination() {
+    let rules = placement_rules();
+
+    assert_eq!(
+        rules.len(),
+        all_targets().len() * ComponentKind::all().len() * 2
+    );
+    for rule in &rules {
+        let Some(template) = &rule.template else {
+            continue;
+        };
+        assert!(!template.contains("__plm_"), "{:?}", rule);
+        if rule.scope == Scope::Project {
+            assert!(template.starts_with("{project}"), "{:?}", rule);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! 破壊的操作の影響プレビュー
+//!
+//! TUI の確認ダイアログに、実行前に何が起きるか（CLI の `--dry-run` 相当）を表示するための
+//! 見積もり。削除されるファイルは配置記録（[`remaining_deployed_files`]）から数え、
+//! 更新は現在のバージョンと更新チェックで見つかった新しいバージョンを並べる。
+//! 所要時間は件数から求めた目安で、実測ではない。
+
+use super::lifecycle::remaining_deployed_files;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// プラグイン 1 件の更新にかかる目安（取得と再デプロイ）
+const UPDATE_ESTIMATE_PER_PLUGIN: Duration = Duration::from_secs(3);
+
+/// 配置済みファイル 1 件の削除にかかる目安
+const REMOVE_ESTIMATE_PER_FILE: Duration = Duration::from_millis(20);
+
+/// マーケットプレイス 1 件の登録解除（設定とキャッシュの削除）にかかる目安
+const UNREGISTER_ESTIMATE_PER_MARKETPLACE: Duration = Duration::from_millis(100);
+
+/// 更新されるプラグイン 1 件のバージョン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionChange {
+    /// 表示名（`name@marketplace` など）
+    pub plugin: String,
+    /// インストール済みのバージョン
+    pub current: String,
+    /// マーケットプレイスにある新しいバージョン（見つからなければ `None`）
+    pub latest: Option<String>,
+}
+
+/// 破壊的操作の影響プレビュー
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImpactPreview {
+    /// ターゲット別の削除されるファイル数（ファイルを削除しない操作では `None`）
+    pub removed_files: Option<BTreeMap<String, usize>>,
+    /// 更新されるプラグインのバージョン
+    pub versions: Vec<VersionChange>,
+    /// 補足（削除されずに残るものなど）
+    pub notes: Vec<String>,
+    /// 推定所要時間の目安
+    pub estimate: Duration,
+}
+
+impl ImpactPreview {
+    /// 削除されるファイルの総数
+    pub fn removed_file_count(&self) -> usize {
+        self.removed_files
+            .as_ref()
+            .map_or(0, |files| files.values().sum())
+    }
+
+    /// 確認ダイアログに表示する箇条書き（先頭の `• ` は付けない）
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(files) = &self.removed_files {
+            lines.push(if files.is_empty() {
+                "Deployed files removed: none".to_string()
+            } else {
+                let per_target: Vec<String> = files
+                    .iter()
+                    .map(|(target, count)| format!("{} {}", target, count))
+                    .collect();
+                format!(
+                    "Deployed files removed: {} ({})",
+                    self.removed_file_count(),
+                    per_target.join(", ")
+                )
+            });
+        }
+        for change in &self.versions {
+            lines.push(match &change.latest {
+                Some(latest) => format!("{}: {} -> {}", change.plugin, change.current, latest),
+                None => format!(
+                    "{}: {} (no newer version known)",
+                    change.plugin, change.current
+                ),
+            });
+        }
+        lines.extend(self.notes.iter().cloned());
+        lines.push(format!(
+            "Estimated time: {}",
+            format_estimate(self.estimate)
+        ));
+        lines
+    }
+}
+
+/// 推定所要時間を表示用に丸める
+///
+/// # Arguments
+///
+/// * `estimate` - Estimated duration.
+pub fn format_estimate(estimate: Duration) -> String {
+    let secs = estimate.as_secs();
+    if secs == 0 {
+        "under a second".to_string()
+    } else if secs < 60 {
+        format!("about {}s", secs)
+    } else {
+        format!("about {}m", secs.div_ceil(60))
+    }
+}
+
+/// アンインストールの影響（配置記録に残っているファイルをターゲット別に数える）
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+pub fn preview_uninstall(plugin_path: &Path) -> ImpactPreview {
+    let mut removed_files = BTreeMap::new();
+    for file in remaining_deployed_files(plugin_path) {
+        *removed_files.entry(file.target).or_insert(0) += 1;
+    }
+    let count: usize = removed_files.values().sum();
+    ImpactPreview {
+        removed_files: Some(removed_files),
+        estimate: REMOVE_ESTIMATE_PER_FILE * count as u32,
+        ..Default::default()
+    }
+}
+
+/// 更新の影響（更新対象のプラグインと現在のバージョン）
+///
+/// # Arguments
+///
+/// * `versions` - Plugins to update with their current and latest known versions.
+pub fn preview_update(versions: Vec<VersionChange>) -> ImpactPreview {
+    let estimate = UPDATE_ESTIMATE_PER_PLUGIN * versions.len() as u32;
+    ImpactPreview {
+        versions,
+        estimate,
+        ..Default::default()
+    }
+}
+
+/// マーケットプレイス削除の影響
+///
+/// 登録とキャッシュを消すだけで、そのマーケットプレイスからインストールした
+/// プラグインと配置済みファイルは残る。
+///
+/// # Arguments
+///
+/// * `marketplaces` - Number of marketplaces to remove.
+/// * `kept_plugins` - Installed plugins from those marketplaces (display names).
+pub fn preview_marketplace_removal(marketplaces: usize, kept_plugins: &[String]) -> ImpactPreview {
+    let mut notes = Vec::new();
+    if !kept_plugins.is_empty() {
+        notes.push(format!(
+            "{} installed plugin(s) stay installed but can no longer be updated: {}",
+            kept_plugins.len(),
+            kept_plugins.join(", ")
+        ));
+    }
+    ImpactPreview {
+        removed_files: Some(BTreeMap::new()),
+        notes,
+        estimate: UNREGISTER_ESTIMATE_PER_MARKETPLACE * marketplaces as u32,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+#[path = "preview_test.rs"]
+mod tests;
@@ -0,0 +1,113 @@
+use super::*;
+use crate::component::ComponentKind;
+use crate::plugin::meta::{self, DeployedFile, PluginMeta};
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+fn change(plugin: &str, current: &str, latest: Option<&str>) -> VersionChange {
+    VersionChange {
+        plugin: plugin.to_string(),
+        current: current.to_string(),
+        latest: latest.map(str::to_string),
+    }
+}
+
+#[test]
+fn preview_uninstall_counts_remaining_files_per_target() {
+    let temp = TempDir::new().unwrap();
+    let plugin_dir = temp.path().join("cache/plugin");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    let file = |rel: &str| {
+        let path = temp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "x").unwrap();
+        DeployedFile {
+            path: path.to_string_lossy().into_owned(),
+            kind: ComponentKind::Command,
+        }
+    };
+    let already_gone = DeployedFile {
+        path: temp.path().join("gone.md").to_string_lossy().into_owned(),
+        kind: ComponentKind::Command,
+    };
+    let plugin_meta = PluginMeta {
+        deployed_files: HashMap::from([
+            (
+                "codex".to_string(),
+                vec![file(".codex/prompts/a.md"), already_gone],
+            ),
+            (
+                "claude".to_string(),
+                vec![file(".claude/commands/a.md"), file(".claude/commands/b.md")],
+            ),
+        ]),
+        ..Default::default()
+    };
+    meta::write_meta(&plugin_dir, &plugin_meta).unwrap();
+
+    let preview = preview_uninstall(&plugin_dir);
+
+    assert_eq!(preview.removed_file_count(), 3);
+    assert_eq!(
+        preview.lines()[0],
+        "Deployed files removed: 3 (claude 2, codex 1)"
+    );
+}
+
+#[test]
+fn preview_uninstall_without_meta_removes_nothing() {
+    let temp = TempDir::new().unwrap();
+
+    let preview = preview_uninstall(temp.path());
+
+    assert_eq!(
+        preview.lines(),
+        vec![
+            "Deployed files removed: none",
+            "Estimated time: under a second"
+        ]
+    );
+}
+
+#[test]
+fn preview_update_lists_current_versions_and_scales_estimate() {
+    let preview = preview_update(vec![
+        change("alpha@official", "1.0.0", Some("1.2.0")),
+        change("beta", "0.3.0", None),
+    ]);
+
+    assert_eq!(
+        preview.lines(),
+        vec![
+            "alpha@official: 1.0.0 -> 1.2.0",
+            "beta: 0.3.0 (no newer version known)",
+            "Estimated time: about 6s",
+        ]
+    );
+}
+
+#[test]
+fn preview_marketplace_removal_notes_plugins_that_stay_installed() {
+    let preview = preview_marketplace_removal(2, &["lint@company-tools".to_string()]);
+
+    assert_eq!(preview.removed_file_count(), 0);
+    assert_eq!(
+        preview.lines(),
+        vec![
+            "Deployed files removed: none",
+            "1 installed plugin(s) stay installed but can no longer be updated: lint@company-tools",
+            "Estimated time: under a second",
+        ]
+    );
+}
+
+#[test]
+fn format_estimate_rounds_minutes_up() {
+    assert_eq!(
+        format_estimate(Duration::from_millis(400)),
+        "under a second"
+    );
+    assert_eq!(format_estimate(Duration::from_secs(59)), "about 59s");
+    assert_eq!(format_estimate(Duration::from_secs(61)), "about 2m");
+}
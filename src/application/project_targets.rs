@@ -0,0 +1,175 @@
+//! プロジェクト単位のターゲット有効/無効設定（`.plm/config.json`）
+//!
+//! `plm target enable` / `plm target disable` がプロジェクトルートの `.plm/config.json` に
+//! `enabledTargets` を書き込み、install / enable / disable / uninstall / update は
+//! 無効なターゲットをスキップする。設定ファイルが無い、または `enabledTargets` が
+//! 無い場合は全ターゲットを有効として扱う（従来どおり）。
+
+use crate::error::{PlmError, Result};
+use crate::target::{all_targets, Target, TargetKind};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// プロジェクト設定ファイル（プロジェクトルートからの相対パス）
+pub const PROJECT_CONFIG_FILE: &str = ".plm/config.json";
+
+/// `.plm/config.json` のスキーマ
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConfig {
+    /// 有効なターゲット（`None` なら全ターゲット有効）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_targets: Option<Vec<TargetKind>>,
+}
+
+impl ProjectConfig {
+    /// プロジェクト設定を読み込む（ファイルが無ければ既定値）
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root containing `.plm/config.json`.
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = config_path(project_root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(|e| PlmError::Parse(format!("failed to read {}: {}", path.display(), e)))?;
+        serde_json::from_str(&content)
+            .map_err(|e| PlmError::Parse(format!("{}: {}", path.display(), e)))
+    }
+
+    /// プロジェクト設定を書き出す（`.plm/` が無ければ作成する）
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root containing `.plm/config.json`.
+    pub fn save(&self, project_root: &Path) -> Result<()> {
+        let path = config_path(project_root);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        fs::write(&path, json)?;
+        Ok(())
+    }
+
+    /// ターゲットが有効か
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Target to check.
+    pub fn is_enabled(&self, kind: TargetKind) -> bool {
+        self.enabled_targets
+            .as_ref()
+            .is_none_or(|enabled| enabled.contains(&kind))
+    }
+
+    /// ターゲットを有効にする（変更があれば `true`）
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Target to enable.
+    pub fn enable(&mut self, kind: TargetKind) -> bool {
+        let Some(enabled) = &mut self.enabled_targets else {
+            return false;
+        };
+        if enabled.contains(&kind) {
+            return false;
+        }
+        enabled.push(kind);
+        enabled.sort();
+        true
+    }
+
+    /// ターゲットを無効にする（変更があれば `true`）
+    ///
+    /// 設定が無い（全ターゲット有効）状態からは、指定以外の全ターゲットを有効として記録する。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Target to disable.
+    pub fn disable(&mut self, kind: TargetKind) -> bool {
+        let enabled = self
+            .enabled_targets
+            .get_or_insert_with(|| all_targets().iter().map(|t| t.kind()).collect());
+        let before = enabled.len();
+        enabled.retain(|k| *k != kind);
+        enabled.len() != before
+    }
+
+    /// 指定したターゲットだけを有効にする（`plm target enable --only`）
+    ///
+    /// # Arguments
+    ///
+    /// * `kinds` - Targets to keep enabled.
+    pub fn enable_only(&mut self, kinds: &[TargetKind]) {
+        let mut enabled = kinds.to_vec();
+        enabled.sort();
+        enabled.dedup();
+        self.enabled_targets = Some(enabled);
+    }
+}
+
+/// プロジェクト設定ファイルのパス
+///
+/// # Arguments
+///
+/// * `project_root` - Project root directory.
+pub fn config_path(project_root: &Path) -> PathBuf {
+    project_root.join(PROJECT_CONFIG_FILE)
+}
+
+/// プロジェクトで有効なターゲット種別（`None` なら全ターゲット有効）
+///
+/// 設定ファイルが読めない場合も全ターゲット有効として扱う（`plm target list` がエラーを表示する）。
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/config.json`.
+pub fn project_enabled_targets(project_root: &Path) -> Option<Vec<TargetKind>> {
+    ProjectConfig::load(project_root)
+        .ok()
+        .and_then(|config| config.enabled_targets)
+}
+
+/// プロジェクトで有効なターゲット（`all_targets()` 順）
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/config.json`.
+pub fn enabled_targets(project_root: &Path) -> Vec<Box<dyn Target>> {
+    let enabled = project_enabled_targets(project_root);
+    all_targets()
+        .into_iter()
+        .filter(|t| enabled.as_ref().is_none_or(|e| e.contains(&t.kind())))
+        .collect()
+}
+
+/// ターゲット名の一覧から、プロジェクトで無効なものを除く
+///
+/// `(有効なターゲット名, 除いたターゲット名)` を返す。未知の名前はそのまま残す
+/// （後段の `parse_target` がエラーにする）。
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/config.json`.
+/// * `names` - Requested target names.
+pub fn skip_disabled_targets(
+    project_root: &Path,
+    names: Vec<String>,
+) -> (Vec<String>, Vec<String>) {
+    let Some(enabled) = project_enabled_targets(project_root) else {
+        return (names, Vec::new());
+    };
+    names.into_iter().partition(|name| {
+        enabled.iter().any(|k| k.as_str() == name.as_str())
+            || !all_targets().iter().any(|t| t.name() == name.as_str())
+    })
+}
+
+#[cfg(test)]
+#[path = "project_targets_test.rs"]
+mod tests;
@@ -0,0 +1,114 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn missing_config_enables_every_target() {
+    let dir = TempDir::new().unwrap();
+
+    let config = ProjectConfig::load(dir.path()).unwrap();
+
+    assert_eq!(config, ProjectConfig::default());
+    assert!(config.is_enabled(TargetKind::Codex));
+    assert_eq!(project_enabled_targets(dir.path()), None);
+    assert_eq!(enabled_targets(dir.path()).len(), all_targets().len());
+}
+
+#[test]
+fn disable_from_default_records_remaining_targets() {
+    let mut config = ProjectConfig::default();
+
+    assert!(config.disable(TargetKind::Codex));
+    assert!(!config.disable(TargetKind::Codex));
+
+    assert!(!config.is_enabled(TargetKind::Codex));
+    assert!(config.is_enabled(TargetKind::Copilot));
+    assert_eq!(
+        config.enabled_targets.as_ref().map(Vec::len),
+        Some(all_targets().len() - 1)
+    );
+}
+
+#[test]
+fn enable_adds_target_in_canonical_order() {
+    let mut config = ProjectConfig::default();
+    assert!(
+        !config.enable(TargetKind::Codex),
+        "already enabled by default"
+    );
+
+    config.enable_only(&[TargetKind::Cursor, TargetKind::Copilot, TargetKind::Cursor]);
+    assert_eq!(
+        config.enabled_targets,
+        Some(vec![TargetKind::Copilot, TargetKind::Cursor])
+    );
+
+    assert!(config.enable(TargetKind::Codex));
+    assert_eq!(
+        config.enabled_targets,
+        Some(vec![
+            TargetKind::Codex,
+            TargetKind::Copilot,
+            TargetKind::Cursor
+        ])
+    );
+}
+
+#[test]
+fn config_round_trips_and_filters_targets() {
+    let dir = TempDir::new().unwrap();
+    let mut config = ProjectConfig::default();
+    config.enable_only(&[TargetKind::Copilot]);
+
+    config.save(dir.path()).unwrap();
+
+    let json = fs::read_to_string(config_path(dir.path())).unwrap();
+    assert!(
+        json.contains("\"enabledTargets\": [\n    \"copilot\"\n  ]"),
+        "{}",
+        json
+    );
+    assert_eq!(ProjectConfig::load(dir.path()).unwrap(), config);
+    let names: Vec<&str> = enabled_targets(dir.path())
+        .iter()
+        .map(|t| t.name())
+        .collect();
+    assert_eq!(names, vec!["copilot"]);
+}
+
+#[test]
+fn skip_disabled_targets_keeps_enabled_and_unknown_names() {
+    let dir = TempDir::new().unwrap();
+    let requested = vec![
+        "codex".to_string(),
+        "copilot".to_string(),
+        "vim".to_string(),
+    ];
+    assert_eq!(
+        skip_disabled_targets(dir.path(), requested.clone()),
+        (requested.clone(), Vec::new())
+    );
+
+    let mut config = ProjectConfig::default();
+    config.enable_only(&[TargetKind::Copilot]);
+    config.save(dir.path()).unwrap();
+
+    assert_eq!(
+        skip_disabled_targets(dir.path(), requested),
+        (
+            vec!["copilot".to_string(), "vim".to_string()],
+            vec!["codex".to_string()]
+        )
+    );
+}
+
+#[test]
+fn broken_config_is_reported_by_load() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".plm")).unwrap();
+    fs::write(config_path(dir.path()), "{ not json").unwrap();
+
+    let err = ProjectConfig::load(dir.path()).unwrap_err();
+
+    assert!(err.to_string().contains("config.json"), "{}", err);
+    assert_eq!(project_enabled_targets(dir.path()), None);
+}
@@ -0,0 +1,341 @@
+//! デプロイ先からキャッシュへの逆同期（`plm refresh --from-deployed`）
+//!
+//! プロジェクトに配置したファイルを直接編集して改善した場合に、その変更をキャッシュ内の
+//! プラグインへ書き戻す。正とするターゲットを 1 つ選び、配置時の変換を逆にたどって
+//! キャッシュの元ファイルと比較する。
+//!
+//! - Command / Agent: 元ファイルをターゲット形式へ変換した結果と配置物を比較し、
+//!   差分があれば編集を元ファイルに取り込む（ターゲット固有のフィールドは書き戻せない）
+//! - Skill: ディレクトリ内のファイルごとに比較する。`SKILL.md` はデプロイ時に除去した
+//!   frontmatter フィールドを元ファイルから補う
+//! - Instruction: ファイルをそのまま比較する
+//! - Hook: ターゲットの設定ファイルへ統合されるため対象外
+
+use super::info::placement_path;
+use crate::component::convert::{
+    convert_agent_content, convert_content, restore_skill_frontmatter_fields,
+    reverse_convert_agent_content, reverse_convert_content, skill_allowed_fields,
+    strip_skill_frontmatter_fields,
+};
+use crate::component::{CommandPrefix, Component, ComponentKind, PromptNaming};
+use crate::plugin::{meta, MarketplaceContent, PackageCacheAccess, PluginLock};
+use crate::target::{parse_target, PluginOrigin, Target, TargetKind};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// キャッシュへ書き戻すファイル 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshChange {
+    pub kind: ComponentKind,
+    /// コンポーネント名
+    pub name: String,
+    /// 正とするデプロイ先のファイル
+    pub deployed: PathBuf,
+    /// 書き戻すキャッシュ内のファイル
+    pub cached: PathBuf,
+    /// 書き戻す内容
+    pub content: Vec<u8>,
+    /// ターゲット形式から逆変換したか（`false` はそのままコピー）
+    pub converted: bool,
+    /// 逆変換で失われる情報
+    pub warnings: Vec<String>,
+}
+
+/// 書き戻しの計画
+#[derive(Debug, Default)]
+pub struct RefreshPlan {
+    /// デプロイ先で変更されていたファイル
+    pub changes: Vec<RefreshChange>,
+    /// 比較できなかったコンポーネント（種別, 名前, 理由）
+    pub skipped: Vec<(ComponentKind, String, String)>,
+}
+
+/// デプロイ先とキャッシュを比較し、書き戻す内容を求める（ファイルは変更しない）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache holding the plugin.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+/// * `target` - Target whose deployed files are taken as the source of truth.
+/// * `project_root` - Project root the deployments are resolved against.
+pub fn plan_refresh(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: &str,
+    target: TargetKind,
+    project_root: &Path,
+) -> Result<RefreshPlan, String> {
+    if !cache.is_cached(Some(marketplace), plugin_name) {
+        return Err(format!(
+            "Plugin '{}' not found in cache (marketplace: {})",
+            plugin_name, marketplace
+        ));
+    }
+    let content = cache
+        .load_package(Some(marketplace), plugin_name)
+        .and_then(MarketplaceContent::try_from)
+        .map_err(|e| format!("Failed to load plugin: {}", e))?;
+    let target = parse_target(target.as_str()).map_err(|e| e.to_string())?;
+
+    // 配置済みのコマンドは deploy 時のプレフィクス付きの名前で探す
+    let command_prefix = CommandPrefix::from_stored(
+        meta::load_meta(content.path())
+            .and_then(|m| m.deployed_command_prefix)
+            .as_deref(),
+    );
+    let origin = PluginOrigin::from_cached_plugin(Some(marketplace), plugin_name);
+    let prompt_naming = PromptNaming::configured().unwrap_or_default();
+
+    let mut plan = RefreshPlan::default();
+    for component in content
+        .components()
+        .iter()
+        .filter(|c| target.supports(c.kind))
+    {
+        let Some(deployed) = placement_path(
+            target.as_ref(),
+            component,
+            &origin,
+            command_prefix.as_ref(),
+            prompt_naming,
+            project_root,
+        ) else {
+            continue;
+        };
+        let compared = match component.kind {
+            ComponentKind::Hook => Err(format!(
+                "hooks are merged into the {} configuration and cannot be refreshed",
+                target.name()
+            )),
+            _ if !deployed.exists() => Err(format!("not deployed to {}", target.name())),
+            ComponentKind::Skill => compare_skill(component, &deployed, target.kind()),
+            ComponentKind::Command | ComponentKind::Agent => {
+                compare_converted(&content, component, &deployed, target.as_ref())
+                    .map(|change| change.into_iter().collect())
+            }
+            ComponentKind::Instruction => {
+                compare_file(component, &deployed, &component.path).map(|c| c.into_iter().collect())
+            }
+        };
+        match compared {
+            Ok(changes) => plan.changes.extend(changes),
+            Err(reason) => plan
+                .skipped
+                .push((component.kind, component.name.clone(), reason)),
+        }
+    }
+    Ok(plan)
+}
+
+/// 計画どおりにキャッシュへ書き戻す
+///
+/// # Arguments
+///
+/// * `cache` - Package cache holding the plugin.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+/// * `plan` - Plan returned by [`plan_refresh`].
+pub fn apply_refresh(
+    cache: &dyn PackageCacheAccess,
+    plugin_name: &str,
+    marketplace: &str,
+    plan: &RefreshPlan,
+) -> Result<(), String> {
+    let _lock =
+        PluginLock::acquire(cache, Some(marketplace), plugin_name).map_err(|e| e.to_string())?;
+    for change in &plan.changes {
+        if let Some(parent) = change.cached.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&change.cached, &change.content)
+            .map_err(|e| format!("Failed to write {}: {}", change.cached.display(), e))?;
+    }
+    Ok(())
+}
+
+/// ファイルをそのまま比較する
+///
+/// # Arguments
+///
+/// * `component` - Component the file belongs to.
+/// * `deployed` - Deployed file.
+/// * `cached` - Corresponding file in the cache (may not exist yet).
+fn compare_file(
+    component: &Component,
+    deployed: &Path,
+    cached: &Path,
+) -> Result<Option<RefreshChange>, String> {
+    let content = read(deployed)?;
+    if fs::read(cached).is_ok_and(|original| original == content) {
+        return Ok(None);
+    }
+    Ok(Some(RefreshChange {
+        kind: component.kind,
+        name: component.name.clone(),
+        deployed: deployed.to_path_buf(),
+        cached: cached.to_path_buf(),
+        content,
+        converted: false,
+        warnings: Vec::new(),
+    }))
+}
+
+/// Skill ディレクトリをファイルごとに比較する
+///
+/// 配置先にだけあるファイルは追加として扱い、キャッシュにだけあるファイルは残す
+/// （除外ルールで配置しなかったファイルと区別できないため）。
+///
+/// # Arguments
+///
+/// * `component` - Skill component.
+/// * `deployed` - Deployed skill directory.
+/// * `target` - Target the skill was deployed to.
+fn compare_skill(
+    component: &Component,
+    deployed: &Path,
+    target: TargetKind,
+) -> Result<Vec<RefreshChange>, String> {
+    let mut changes = Vec::new();
+    for entry in WalkDir::new(deployed).min_depth(1).sort_by_file_name() {
+        let entry = entry.map_err(|e| e.to_string())?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(deployed)
+            .map_err(|e| e.to_string())?;
+        let cached = component.path.join(relative);
+
+        let allowed = skill_allowed_fields(target).filter(|_| relative == Path::new("SKILL.md"));
+        let change = match allowed {
+            Some(allowed) => compare_skill_manifest(component, entry.path(), &cached, allowed)?,
+            None => compare_file(component, entry.path(), &cached)?,
+        };
+        changes.extend(change);
+    }
+    Ok(changes)
+}
+
+/// frontmatter を除去して配置した `SKILL.md` を比較する
+///
+/// # Arguments
+///
+/// * `component` - Skill component.
+/// * `deployed` - Deployed `SKILL.md`.
+/// * `cached` - `SKILL.md` in the cache.
+/// * `allowed` - Frontmatter fields kept on deployment.
+fn compare_skill_manifest(
+    component: &Component,
+    deployed: &Path,
+    cached: &Path,
+    allowed: &[&str],
+) -> Result<Option<RefreshChange>, String> {
+    let edited = read_to_string(deployed)?;
+    let original = read_to_string(cached)?;
+    if strip_skill_frontmatter_fields(&original, allowed) == edited {
+        return Ok(None);
+    }
+    let restored = restore_skill_frontmatter_fields(&edited, &original, allowed);
+    Ok(Some(RefreshChange {
+        kind: component.kind,
+        name: component.name.clone(),
+        deployed: deployed.to_path_buf(),
+        cached: cached.to_path_buf(),
+        converted: restored != edited,
+        content: restored.into_bytes(),
+        warnings: Vec::new(),
+    }))
+}
+
+/// ターゲット形式へ変換して配置した Command / Agent を比較する
+///
+/// # Arguments
+///
+/// * `content` - Plugin content providing the source formats.
+/// * `component` - Command or agent component.
+/// * `deployed` - Deployed file.
+/// * `target` - Target the component was deployed to.
+fn compare_converted(
+    content: &MarketplaceContent,
+    component: &Component,
+    deployed: &Path,
+    target: &dyn Target,
+) -> Result<Option<RefreshChange>, String> {
+    let original = read_to_string(&component.path)?;
+    let edited = read_to_string(deployed)?;
+
+    let (expected, reversed) = match component.kind {
+        ComponentKind::Command => {
+            let (source, dest) = (content.command_format(), target.command_format());
+            let expected = if source == dest {
+                original.clone()
+            } else {
+                convert_content(&original, source, dest).map_err(|e| e.to_string())?
+            };
+            (
+                expected,
+                reverse_convert_content(&original, &edited, source, dest),
+            )
+        }
+        _ => {
+            let (source, dest) = (content.agent_format(), target.agent_format());
+            let expected = if source == dest {
+                original.clone()
+            } else {
+                convert_agent_content(&original, source, dest).map_err(|e| e.to_string())?
+            };
+            (
+                expected,
+                reverse_convert_agent_content(&original, &edited, source, dest),
+            )
+        }
+    };
+    if expected == edited {
+        return Ok(None);
+    }
+
+    let (restored, lost) = reversed.map_err(|e| e.to_string())?;
+    if restored == original {
+        return Ok(None);
+    }
+    Ok(Some(RefreshChange {
+        kind: component.kind,
+        name: component.name.clone(),
+        deployed: deployed.to_path_buf(),
+        cached: component.path.clone(),
+        converted: restored != edited,
+        content: restored.into_bytes(),
+        warnings: lost
+            .iter()
+            .map(|field| {
+                format!(
+                    "'{}' is specific to {} and cannot be written back",
+                    field,
+                    target.name()
+                )
+            })
+            .collect(),
+    }))
+}
+
+/// # Arguments
+///
+/// * `path` - File to read.
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// # Arguments
+///
+/// * `path` - File to read.
+fn read_to_string(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+#[path = "refresh_test.rs"]
+mod tests;
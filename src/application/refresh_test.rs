@@ -0,0 +1,129 @@
+use super::*;
+use crate::component::AgentFormat;
+use crate::plugin::PackageCache;
+use tempfile::TempDir;
+
+const SKILL: &str =
+    "---\nname: formatter\ndescription: Format code\nallowed-tools: Read\n---\nFormat the file.\n";
+const AGENT: &str =
+    "---\nname: reviewer\ndescription: Review code\nmodel: opus\n---\nReview the diff.\n";
+
+fn write_file(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn cache_with_plugin(dir: &TempDir) -> PackageCache {
+    let plugin_dir = dir.path().join("cache/github/tools");
+    write_file(
+        &plugin_dir.join("plugin.json"),
+        r#"{"name":"tools","version":"1.0.0"}"#,
+    );
+    write_file(&plugin_dir.join("skills/formatter/SKILL.md"), SKILL);
+    write_file(&plugin_dir.join("agents/reviewer.md"), AGENT);
+    PackageCache::with_cache_dir(dir.path().join("cache")).unwrap()
+}
+
+/// Codex へ配置した場合のパス
+fn deployed_path(cache: &PackageCache, kind: ComponentKind, project: &Path) -> PathBuf {
+    let content =
+        MarketplaceContent::try_from(cache.load_package(Some("github"), "tools").unwrap()).unwrap();
+    let component = content
+        .components()
+        .into_iter()
+        .find(|c| c.kind == kind)
+        .unwrap();
+    placement_path(
+        parse_target("codex").unwrap().as_ref(),
+        &component,
+        &PluginOrigin::from_cached_plugin(Some("github"), "tools"),
+        None,
+        PromptNaming::default(),
+        project,
+    )
+    .unwrap()
+}
+
+#[test]
+fn plan_refresh_without_deployment_skips_components() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with_plugin(&dir);
+
+    let plan = plan_refresh(&cache, "tools", "github", TargetKind::Codex, dir.path()).unwrap();
+
+    assert!(plan.changes.is_empty());
+    assert_eq!(plan.skipped.len(), 2);
+    assert!(plan
+        .skipped
+        .iter()
+        .all(|(_, _, reason)| reason == "not deployed to codex"));
+}
+
+#[test]
+fn refresh_writes_deployed_edits_back_to_cache() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with_plugin(&dir);
+    let project = dir.path().join("project");
+
+    // Skill: 本文を編集し、ファイルを追加
+    let skill_dir = deployed_path(&cache, ComponentKind::Skill, &project);
+    let stripped =
+        strip_skill_frontmatter_fields(SKILL, skill_allowed_fields(TargetKind::Codex).unwrap());
+    write_file(
+        &skill_dir.join("SKILL.md"),
+        &stripped.replace("Format the file.", "Format every file."),
+    );
+    write_file(&skill_dir.join("reference.md"), "notes");
+    // Agent: Codex 形式へ変換したものの本文を編集
+    let agent_path = deployed_path(&cache, ComponentKind::Agent, &project);
+    let converted =
+        convert_agent_content(AGENT, AgentFormat::ClaudeCode, AgentFormat::Codex).unwrap();
+    write_file(
+        &agent_path,
+        &converted.replace("Review the diff.", "Review the whole diff."),
+    );
+
+    let plan = plan_refresh(&cache, "tools", "github", TargetKind::Codex, &project).unwrap();
+    let plugin_dir = cache.plugin_path(Some("github"), "tools");
+    let mut cached: Vec<PathBuf> = plan.changes.iter().map(|c| c.cached.clone()).collect();
+    cached.sort();
+    assert_eq!(
+        cached,
+        vec![
+            plugin_dir.join("agents/reviewer.md"),
+            plugin_dir.join("skills/formatter/SKILL.md"),
+            plugin_dir.join("skills/formatter/reference.md"),
+        ]
+    );
+    assert!(plan.skipped.is_empty());
+
+    apply_refresh(&cache, "tools", "github", &plan).unwrap();
+
+    // 配置時に除去した frontmatter は元ファイルから補われる
+    assert_eq!(
+        fs::read_to_string(plugin_dir.join("skills/formatter/SKILL.md")).unwrap(),
+        SKILL.replace("Format the file.", "Format every file.")
+    );
+    assert_eq!(
+        fs::read_to_string(plugin_dir.join("skills/formatter/reference.md")).unwrap(),
+        "notes"
+    );
+    // Codex で落ちる model は元の値が残る
+    let agent = fs::read_to_string(plugin_dir.join("agents/reviewer.md")).unwrap();
+    assert!(agent.contains("model: opus"), "{}", agent);
+    assert!(agent.contains("Review the whole diff."), "{}", agent);
+
+    // 書き戻した後は差分なし
+    let plan = plan_refresh(&cache, "tools", "github", TargetKind::Codex, &project).unwrap();
+    assert!(plan.changes.is_empty(), "{:?}", plan.changes);
+}
+
+#[test]
+fn plan_refresh_unknown_plugin_fails() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with_plugin(&dir);
+
+    let err = plan_refresh(&cache, "missing", "github", TargetKind::Codex, dir.path()).unwrap_err();
+
+    assert!(err.contains("not found in cache"), "{}", err);
+}
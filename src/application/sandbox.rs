@@ -0,0 +1,133 @@
+//! 試用インストール（sandbox）
+//!
+//! `plm install --sandbox` はプロジェクト本体ではなく `.plm/sandbox/<plugin>/` を
+//! プロジェクトルートとみなして配置する。配置先と作成日時は `.plm-meta.json` の
+//! `sandbox` に記録し、`--promote`（正式デプロイへの昇格）や `uninstall --sandbox` で
+//! ディレクトリごと削除する。statusByTarget と配置記録には反映しないため、
+//! sandbox だけのプラグインは list 上 disabled のまま扱われる。
+
+use crate::marketplace::DEFAULT_MARKETPLACE;
+use crate::plugin::meta::{self, SandboxInfo};
+use crate::plugin::InstalledPlugin;
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// プロジェクトルートから見た sandbox ディレクトリ
+const SANDBOX_DIR: &str = ".plm/sandbox";
+
+/// プラグインの sandbox ディレクトリ
+///
+/// # Arguments
+///
+/// * `project_root` - Project the sandbox belongs to.
+/// * `plugin_name` - Plugin name.
+pub fn sandbox_path(project_root: &Path, plugin_name: &str) -> PathBuf {
+    project_root.join(SANDBOX_DIR).join(plugin_name)
+}
+
+/// 前回の内容を消して空の sandbox ディレクトリを用意する
+///
+/// # Arguments
+///
+/// * `path` - Sandbox directory.
+pub fn prepare_sandbox(path: &Path) -> Result<(), String> {
+    remove_dir_if_exists(path)
+        .and_then(|()| fs::create_dir_all(path))
+        .map_err(|e| format!("Failed to prepare sandbox {}: {}", path.display(), e))
+}
+
+/// sandbox の配置先と作成日時を `.plm-meta.json` に記録する
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+/// * `sandbox_path` - Sandbox directory the plugin was placed into.
+/// * `targets` - Targets placed successfully.
+/// * `now` - Creation time.
+pub fn record_sandbox(
+    plugin_path: &Path,
+    sandbox_path: &Path,
+    targets: Vec<String>,
+    now: DateTime<Utc>,
+) -> Result<SandboxInfo, String> {
+    let info = SandboxInfo {
+        path: sandbox_path.to_string_lossy().into_owned(),
+        created_at: now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        targets,
+    };
+    let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    plugin_meta.sandbox = Some(info.clone());
+    meta::write_meta(plugin_path, &plugin_meta)
+        .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))?;
+    Ok(info)
+}
+
+/// sandbox ディレクトリを削除し、`.plm-meta.json` の記録を消す
+///
+/// sandbox が記録されていなければ何もせず `None` を返す。ディレクトリが既に
+/// 無い場合も記録だけ消す。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+pub fn remove_sandbox(plugin_path: &Path) -> Result<Option<PathBuf>, String> {
+    let Some(mut plugin_meta) = meta::load_meta(plugin_path) else {
+        return Ok(None);
+    };
+    let Some(info) = plugin_meta.sandbox.take() else {
+        return Ok(None);
+    };
+
+    let path = PathBuf::from(&info.path);
+    remove_dir_if_exists(&path)
+        .map_err(|e| format!("Failed to remove sandbox {}: {}", path.display(), e))?;
+    meta::write_meta(plugin_path, &plugin_meta)
+        .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))?;
+    Ok(Some(path))
+}
+
+/// sandbox だけを削除するコマンド（案内表示用）
+///
+/// # Arguments
+///
+/// * `plugin_id` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace of origin.
+pub fn remove_sandbox_command(plugin_id: &str, marketplace: Option<&str>) -> String {
+    match marketplace.filter(|m| *m != DEFAULT_MARKETPLACE) {
+        Some(marketplace) => format!("plm uninstall {} -m {} --sandbox", plugin_id, marketplace),
+        None => format!("plm uninstall {} --sandbox", plugin_id),
+    }
+}
+
+/// prune 対象の sandbox に対する警告文（sandbox が無い・新しい場合は `None`）
+///
+/// # Arguments
+///
+/// * `plugin` - Installed plugin.
+/// * `now` - Current time.
+pub fn stale_sandbox_warning(plugin: &InstalledPlugin, now: DateTime<Utc>) -> Option<String> {
+    let info = plugin.sandbox().filter(|info| info.is_stale(now))?;
+    let days = info.age_days(now)?;
+    Some(format!(
+        "Sandbox of '{}' was created {} days ago and can be pruned: {}",
+        plugin.name(),
+        days,
+        remove_sandbox_command(plugin.id(), plugin.marketplace())
+    ))
+}
+
+/// # Arguments
+///
+/// * `path` - Directory to remove.
+fn remove_dir_if_exists(path: &Path) -> std::io::Result<()> {
+    match fs::remove_dir_all(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+#[path = "sandbox_test.rs"]
+mod tests;
@@ -0,0 +1,98 @@
+use super::*;
+use tempfile::TempDir;
+
+fn at(rfc3339: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(rfc3339)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn sandbox_path_is_under_project_plm_dir() {
+    assert_eq!(
+        sandbox_path(Path::new("/project"), "formatter"),
+        PathBuf::from("/project/.plm/sandbox/formatter")
+    );
+}
+
+#[test]
+fn prepare_sandbox_clears_previous_contents() {
+    let project = TempDir::new().unwrap();
+    let sandbox = sandbox_path(project.path(), "formatter");
+    fs::create_dir_all(sandbox.join(".codex")).unwrap();
+    fs::write(sandbox.join(".codex/stale.md"), "old").unwrap();
+
+    prepare_sandbox(&sandbox).unwrap();
+
+    assert!(sandbox.is_dir());
+    assert!(!sandbox.join(".codex").exists());
+}
+
+#[test]
+fn record_then_remove_sandbox() {
+    let plugin = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let sandbox = sandbox_path(project.path(), "formatter");
+    prepare_sandbox(&sandbox).unwrap();
+
+    let info = record_sandbox(
+        plugin.path(),
+        &sandbox,
+        vec!["codex".to_string()],
+        at("2026-01-01T00:00:00Z"),
+    )
+    .unwrap();
+    assert_eq!(info.created_at, "2026-01-01T00:00:00Z");
+    assert_eq!(meta::load_meta(plugin.path()).unwrap().sandbox, Some(info));
+
+    let removed = remove_sandbox(plugin.path()).unwrap();
+
+    assert_eq!(removed, Some(sandbox.clone()));
+    assert!(!sandbox.exists());
+    assert!(meta::load_meta(plugin.path()).unwrap().sandbox.is_none());
+}
+
+#[test]
+fn remove_sandbox_without_record_is_noop() {
+    let plugin = TempDir::new().unwrap();
+    assert_eq!(remove_sandbox(plugin.path()).unwrap(), None);
+
+    meta::write_meta(plugin.path(), &meta::PluginMeta::default()).unwrap();
+    assert_eq!(remove_sandbox(plugin.path()).unwrap(), None);
+}
+
+#[test]
+fn remove_sandbox_command_adds_marketplace_unless_github() {
+    assert_eq!(
+        remove_sandbox_command("owner--repo", Some("github")),
+        "plm uninstall owner--repo --sandbox"
+    );
+    assert_eq!(
+        remove_sandbox_command("formatter", Some("company-tools")),
+        "plm uninstall formatter -m company-tools --sandbox"
+    );
+}
+
+#[test]
+fn stale_sandbox_warning_after_seven_days() {
+    let info = SandboxInfo {
+        path: "/project/.plm/sandbox/formatter".to_string(),
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        targets: vec![],
+    };
+    let plugin = InstalledPlugin::new_for_test(
+        "formatter",
+        "1.0.0",
+        vec![],
+        None,
+        Some("company-tools".to_string()),
+        false,
+    );
+    assert!(stale_sandbox_warning(&plugin, at("2026-01-10T00:00:00Z")).is_none());
+
+    let plugin = plugin.with_sandbox(Some(info));
+    assert!(stale_sandbox_warning(&plugin, at("2026-01-05T00:00:00Z")).is_none());
+    let warning = stale_sandbox_warning(&plugin, at("2026-01-10T00:00:00Z")).unwrap();
+    assert!(warning.contains("9 days ago"));
+    assert!(warning.contains("plm uninstall formatter -m company-tools --sandbox"));
+}
@@ -0,0 +1,188 @@
+//! インストール状況の統計
+//!
+//! `plm stats` 用に、マーケットプレイス数・プラグイン数・コンポーネント数・
+//! キャッシュサイズ・最終更新からの経過日数・コンポーネント数の多いプラグインを集計する。
+//! キャッシュと配置先の走査は [`scan_catalog`] の 1 回だけで、プラグインごとの
+//! 追加の FS アクセスはキャッシュディレクトリのサイズ計測のみ。
+
+use super::catalog::{scan_catalog, CatalogEntry};
+use super::summary::{summarize_plugins, PluginTotals};
+use crate::error::Result;
+use crate::plugin::meta::PluginMeta;
+use crate::plugin::{InstalledPlugin, PackageCacheAccess};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Top N に含めるプラグイン数
+pub const TOP_PLUGINS: usize = 5;
+
+/// 最終更新からの経過日数の分布
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateAgeBuckets {
+    /// 7 日以内
+    pub within_7_days: usize,
+    /// 8〜30 日
+    pub within_30_days: usize,
+    /// 31〜90 日
+    pub within_90_days: usize,
+    /// 91 日以上
+    pub older: usize,
+    /// 日時不明（メタデータなし・形式不正）
+    pub unknown: usize,
+}
+
+impl UpdateAgeBuckets {
+    /// 最終更新日時を該当する区間に数える
+    ///
+    /// # Arguments
+    ///
+    /// * `last_updated` - Last update (or install) time, if known.
+    /// * `now` - Current time.
+    fn record(&mut self, last_updated: Option<DateTime<Utc>>, now: DateTime<Utc>) {
+        let Some(at) = last_updated else {
+            self.unknown += 1;
+            return;
+        };
+        match (now - at).num_days() {
+            ..=7 => self.within_7_days += 1,
+            8..=30 => self.within_30_days += 1,
+            31..=90 => self.within_90_days += 1,
+            _ => self.older += 1,
+        }
+    }
+}
+
+/// コンポーネント数の多いプラグイン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopPlugin {
+    /// プラグイン ID（`plm info` などに渡せる名前）
+    pub id: String,
+    /// コンポーネント数
+    pub components: usize,
+}
+
+/// `plm stats` の集計結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginStats {
+    /// 登録済みマーケットプレイス数
+    pub marketplaces: usize,
+    /// プラグイン数（有効/無効別）とコンポーネント数（種別別）
+    pub totals: PluginTotals,
+    /// 集計対象プラグインのキャッシュ合計サイズ（バイト）
+    pub cache_bytes: u64,
+    /// 最終更新からの経過日数の分布
+    pub update_age: UpdateAgeBuckets,
+    /// コンポーネント数の多い順に最大 [`TOP_PLUGINS`] 件
+    pub top_plugins: Vec<TopPlugin>,
+}
+
+/// 集計 1 件分の入力
+#[derive(Debug)]
+pub(crate) struct StatsSample<'a> {
+    pub plugin: &'a InstalledPlugin,
+    pub last_updated: Option<DateTime<Utc>>,
+    pub cache_bytes: u64,
+}
+
+/// インストール状況を集計する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor used to enumerate installed plugins.
+/// * `project_root` - Project root used to find deployed components.
+/// * `project_only` - Only count plugins with components deployed in `project_root`.
+/// * `marketplaces` - Number of registered marketplaces.
+pub fn collect_stats(
+    cache: &dyn PackageCacheAccess,
+    project_root: &Path,
+    project_only: bool,
+    marketplaces: usize,
+) -> Result<PluginStats> {
+    let entries: Vec<CatalogEntry> = scan_catalog(cache, project_root)?
+        .into_iter()
+        .filter(|entry| !project_only || entry.deployed)
+        .collect();
+
+    let samples: Vec<StatsSample> = entries
+        .iter()
+        .map(|entry| StatsSample {
+            plugin: &entry.plugin,
+            last_updated: last_updated(entry.meta.as_ref()),
+            cache_bytes: dir_size(entry.plugin.cache_path()),
+        })
+        .collect();
+
+    Ok(aggregate(&samples, marketplaces, Utc::now()))
+}
+
+/// 走査済みの入力から統計を組み立てる
+///
+/// # Arguments
+///
+/// * `samples` - One sample per plugin to count.
+/// * `marketplaces` - Number of registered marketplaces.
+/// * `now` - Current time used for update age.
+pub(crate) fn aggregate(
+    samples: &[StatsSample],
+    marketplaces: usize,
+    now: DateTime<Utc>,
+) -> PluginStats {
+    let mut update_age = UpdateAgeBuckets::default();
+    for sample in samples {
+        update_age.record(sample.last_updated, now);
+    }
+
+    // コンポーネント数の多い順（同数は ID 順）
+    let mut top_plugins: Vec<TopPlugin> = samples
+        .iter()
+        .filter(|s| !s.plugin.components().is_empty())
+        .map(|s| TopPlugin {
+            id: s.plugin.id().to_string(),
+            components: s.plugin.components().len(),
+        })
+        .collect();
+    top_plugins.sort_by(|a, b| b.components.cmp(&a.components).then(a.id.cmp(&b.id)));
+    top_plugins.truncate(TOP_PLUGINS);
+
+    PluginStats {
+        marketplaces,
+        totals: summarize_plugins(samples.iter().map(|s| s.plugin)),
+        cache_bytes: samples.iter().map(|s| s.cache_bytes).sum(),
+        update_age,
+        top_plugins,
+    }
+}
+
+/// 最終更新日時（`updatedAt`、無ければ `installedAt`）
+///
+/// # Arguments
+///
+/// * `plugin_meta` - Plugin metadata, if any.
+pub(crate) fn last_updated(plugin_meta: Option<&PluginMeta>) -> Option<DateTime<Utc>> {
+    let plugin_meta = plugin_meta?;
+    [&plugin_meta.updated_at, &plugin_meta.installed_at]
+        .into_iter()
+        .flatten()
+        .find_map(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// ディレクトリ配下のファイルサイズ合計（シンボリックリンクは辿らない）
+///
+/// # Arguments
+///
+/// * `dir` - Directory to measure.
+fn dir_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+#[cfg(test)]
+#[path = "stats_test.rs"]
+mod tests;
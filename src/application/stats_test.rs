@@ -0,0 +1,159 @@
+use super::*;
+use crate::component::{Component, ComponentKind};
+use crate::plugin::PackageCache;
+use chrono::Duration;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn comp(kind: ComponentKind, name: &str) -> Component {
+    Component::new(
+        kind,
+        name.to_string(),
+        PathBuf::from(format!("dummy/{}", name)),
+    )
+}
+
+fn plugin(name: &str, components: usize, enabled: bool) -> InstalledPlugin {
+    let components = (0..components)
+        .map(|i| comp(ComponentKind::Skill, &format!("s{}", i)))
+        .collect();
+    InstalledPlugin::new_for_test(name, "1.0.0", components, None, None, enabled)
+}
+
+fn sample(plugin: &InstalledPlugin, days_ago: Option<i64>, bytes: u64) -> StatsSample<'_> {
+    StatsSample {
+        plugin,
+        last_updated: days_ago.map(|d| now() - Duration::days(d)),
+        cache_bytes: bytes,
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-06-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn aggregate_empty() {
+    let stats = aggregate(&[], 2, now());
+    assert_eq!(stats.marketplaces, 2);
+    assert_eq!(stats.totals.plugins, 0);
+    assert_eq!(stats.cache_bytes, 0);
+    assert_eq!(stats.update_age, UpdateAgeBuckets::default());
+    assert!(stats.top_plugins.is_empty());
+}
+
+#[test]
+fn aggregate_counts_totals_and_cache_size() {
+    let a = plugin("a", 2, true);
+    let b = plugin("b", 1, false);
+    let stats = aggregate(&[sample(&a, Some(1), 100), sample(&b, None, 23)], 1, now());
+
+    assert_eq!(stats.totals.plugins, 2);
+    assert_eq!(stats.totals.enabled, 1);
+    assert_eq!(stats.totals.disabled, 1);
+    assert_eq!(stats.totals.count(ComponentKind::Skill), 3);
+    assert_eq!(stats.cache_bytes, 123);
+}
+
+#[test]
+fn aggregate_buckets_update_age() {
+    let p = plugin("p", 0, true);
+    let samples: Vec<StatsSample> = [
+        Some(0),
+        Some(7),
+        Some(8),
+        Some(30),
+        Some(90),
+        Some(91),
+        None,
+    ]
+    .into_iter()
+    .map(|d| sample(&p, d, 0))
+    .collect();
+
+    let stats = aggregate(&samples, 0, now());
+
+    assert_eq!(
+        stats.update_age,
+        UpdateAgeBuckets {
+            within_7_days: 2,
+            within_30_days: 2,
+            within_90_days: 1,
+            older: 1,
+            unknown: 1,
+        }
+    );
+}
+
+#[test]
+fn aggregate_top_plugins_sorted_and_truncated() {
+    let plugins: Vec<InstalledPlugin> =
+        [("f", 1), ("e", 3), ("d", 3), ("c", 5), ("b", 2), ("a", 4)]
+            .into_iter()
+            .chain([("empty", 0)])
+            .map(|(name, n)| plugin(name, n, true))
+            .collect();
+    let samples: Vec<StatsSample> = plugins.iter().map(|p| sample(p, None, 0)).collect();
+
+    let stats = aggregate(&samples, 0, now());
+
+    let top: Vec<(&str, usize)> = stats
+        .top_plugins
+        .iter()
+        .map(|t| (t.id.as_str(), t.components))
+        .collect();
+    assert_eq!(top, vec![("c", 5), ("a", 4), ("d", 3), ("e", 3), ("b", 2)]);
+}
+
+#[test]
+fn last_updated_prefers_updated_at() {
+    let meta = PluginMeta {
+        installed_at: Some("2026-01-01T00:00:00Z".to_string()),
+        updated_at: Some("2026-03-01T00:00:00Z".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        last_updated(Some(&meta)).unwrap().to_rfc3339(),
+        "2026-03-01T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn last_updated_falls_back_to_installed_at() {
+    let meta = PluginMeta {
+        installed_at: Some("2026-01-01T00:00:00Z".to_string()),
+        updated_at: Some("not a date".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        last_updated(Some(&meta)).unwrap().to_rfc3339(),
+        "2026-01-01T00:00:00+00:00"
+    );
+    assert!(last_updated(None).is_none());
+}
+
+#[test]
+fn collect_stats_measures_cache_and_filters_project() {
+    let cache_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let plugin_dir = cache_dir.path().join("github").join("my-plugin");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    let manifest = r#"{"name":"my-plugin","version":"1.0.0"}"#;
+    fs::write(plugin_dir.join("plugin.json"), manifest).unwrap();
+    fs::write(plugin_dir.join("README.md"), "0123456789").unwrap();
+    let cache = PackageCache::with_cache_dir(cache_dir.path().to_path_buf()).unwrap();
+
+    let all = collect_stats(&cache, project.path(), false, 3).unwrap();
+    assert_eq!(all.marketplaces, 3);
+    assert_eq!(all.totals.plugins, 1);
+    assert_eq!(all.cache_bytes, manifest.len() as u64 + 10);
+    assert_eq!(all.update_age.unknown, 1);
+
+    // 何もデプロイしていないプロジェクトでは対象外
+    let project_only = collect_stats(&cache, project.path(), true, 3).unwrap();
+    assert_eq!(project_only.totals.plugins, 0);
+    assert_eq!(project_only.cache_bytes, 0);
+}
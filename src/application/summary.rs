@@ -0,0 +1,128 @@
+//! プラグイン集計
+//!
+//! インストール済みプラグインのプラグイン数・コンポーネント数を集計する。
+//! `plm list` のフッター / `--json` の `summary` と TUI の Installed タブで共有する。
+
+use crate::component::ComponentKind;
+use crate::plugin::InstalledPlugin;
+use std::fmt;
+
+/// プラグイン・コンポーネント数の集計結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginTotals {
+    /// プラグイン数
+    pub plugins: usize,
+    /// 有効なプラグイン数
+    pub enabled: usize,
+    /// 無効なプラグイン数
+    pub disabled: usize,
+    /// コンポーネント総数
+    pub components: usize,
+    /// 種別ごとのコンポーネント数（`ComponentKind::all()` 順、0 件も含む）
+    pub by_kind: Vec<(ComponentKind, usize)>,
+}
+
+impl PluginTotals {
+    /// 指定種別のコンポーネント数
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind to look up.
+    pub fn count(&self, kind: ComponentKind) -> usize {
+        self.by_kind
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(0, |(_, n)| *n)
+    }
+
+    /// 一覧末尾に表示するフッター行
+    ///
+    /// `shown` が総数と異なる（フィルタ適用中）場合は `showing N of M` を併記する。
+    ///
+    /// # Arguments
+    ///
+    /// * `shown` - Number of plugins currently displayed.
+    pub fn footer(&self, shown: usize) -> String {
+        if shown == self.plugins {
+            self.to_string()
+        } else {
+            format!("{}, showing {} of {}", self, shown, self.plugins)
+        }
+    }
+}
+
+impl fmt::Display for PluginTotals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Total: {} ({} enabled, {} disabled), {}",
+            plural(self.plugins, "plugin", "plugins"),
+            self.enabled,
+            self.disabled,
+            plural(self.components, "component", "components"),
+        )?;
+
+        // 件数の多い順（同数は ComponentKind::all() 順）
+        let mut kinds: Vec<&(ComponentKind, usize)> =
+            self.by_kind.iter().filter(|(_, n)| *n > 0).collect();
+        if kinds.is_empty() {
+            return Ok(());
+        }
+        kinds.sort_by(|a, b| b.1.cmp(&a.1));
+        let parts: Vec<String> = kinds
+            .iter()
+            .map(|(kind, n)| plural(*n, kind.as_str(), kind.plural()))
+            .collect();
+        write!(f, " ({})", parts.join(", "))
+    }
+}
+
+/// 件数に応じて単数形/複数形を付ける
+///
+/// # Arguments
+///
+/// * `n` - Count.
+/// * `singular` - Noun used when `n == 1`.
+/// * `plural` - Noun used otherwise.
+fn plural(n: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", n, if n == 1 { singular } else { plural })
+}
+
+/// プラグイン一覧を集計する
+///
+/// # Arguments
+///
+/// * `plugins` - Plugins to aggregate.
+pub fn summarize_plugins<'a>(
+    plugins: impl IntoIterator<Item = &'a InstalledPlugin>,
+) -> PluginTotals {
+    let mut totals = PluginTotals {
+        by_kind: ComponentKind::all().iter().map(|&k| (k, 0)).collect(),
+        ..Default::default()
+    };
+
+    for plugin in plugins {
+        totals.plugins += 1;
+        if plugin.enabled() {
+            totals.enabled += 1;
+        } else {
+            totals.disabled += 1;
+        }
+        for component in plugin.components() {
+            totals.components += 1;
+            if let Some((_, n)) = totals
+                .by_kind
+                .iter_mut()
+                .find(|(k, _)| *k == component.kind)
+            {
+                *n += 1;
+            }
+        }
+    }
+
+    totals
+}
+
+#[cfg(test)]
+#[path = "summary_test.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+use crate::component::Component;
+use std::path::PathBuf;
+
+fn comp(kind: ComponentKind, name: &str) -> Component {
+    Component::new(
+        kind,
+        name.to_string(),
+        PathBuf::from(format!("dummy/{}", name)),
+    )
+}
+
+fn plugin(name: &str, components: Vec<Component>, enabled: bool) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(name, "1.0.0", components, None, None, enabled)
+}
+
+#[test]
+fn summarize_empty_list() {
+    let totals = summarize_plugins(&Vec::<InstalledPlugin>::new());
+    assert_eq!(totals.plugins, 0);
+    assert_eq!(totals.components, 0);
+    assert_eq!(totals.by_kind.len(), ComponentKind::all().len());
+    assert_eq!(
+        totals.to_string(),
+        "Total: 0 plugins (0 enabled, 0 disabled), 0 components"
+    );
+}
+
+#[test]
+fn summarize_counts_plugins_status_and_components() {
+    let plugins = vec![
+        plugin(
+            "a",
+            vec![
+                comp(ComponentKind::Command, "c1"),
+                comp(ComponentKind::Command, "c2"),
+                comp(ComponentKind::Skill, "s1"),
+            ],
+            true,
+        ),
+        plugin("b", vec![comp(ComponentKind::Hook, "h1")], false),
+        plugin("c", vec![], true),
+    ];
+
+    let totals = summarize_plugins(&plugins);
+
+    assert_eq!(totals.plugins, 3);
+    assert_eq!(totals.enabled, 2);
+    assert_eq!(totals.disabled, 1);
+    assert_eq!(totals.components, 4);
+    assert_eq!(totals.count(ComponentKind::Command), 2);
+    assert_eq!(totals.count(ComponentKind::Skill), 1);
+    assert_eq!(totals.count(ComponentKind::Hook), 1);
+    assert_eq!(totals.count(ComponentKind::Agent), 0);
+}
+
+#[test]
+fn display_orders_kinds_by_count_and_uses_singular() {
+    let plugins = vec![plugin(
+        "a",
+        vec![
+            comp(ComponentKind::Skill, "s1"),
+            comp(ComponentKind::Command, "c1"),
+            comp(ComponentKind::Command, "c2"),
+            comp(ComponentKind::Agent, "a1"),
+        ],
+        true,
+    )];
+
+    assert_eq!(
+        summarize_plugins(&plugins).to_string(),
+        "Total: 1 plugin (1 enabled, 0 disabled), 4 components (2 commands, 1 skill, 1 agent)"
+    );
+}
+
+#[test]
+fn footer_mentions_showing_only_when_filtered() {
+    let plugins = vec![plugin("a", vec![], true), plugin("b", vec![], false)];
+    let totals = summarize_plugins(&plugins);
+
+    assert_eq!(
+        totals.footer(2),
+        "Total: 2 plugins (1 enabled, 1 disabled), 0 components"
+    );
+    assert_eq!(
+        totals.footer(1),
+        "Total: 2 plugins (1 enabled, 1 disabled), 0 components, showing 1 of 2"
+    );
+}
@@ -0,0 +1,180 @@
+//! 警告の集約
+//!
+//! install / update の途中で出る非致命的な警告（Hook 変換・予約名・巨大アセットなど）を
+//! その場で stderr に流すと、配置結果の行に埋もれて読み飛ばされやすい。
+//! 操作中は [`WarningCollector`] に溜めておき、完了後に [`render_warnings`] で
+//! 種別ごとにまとめて 1 回だけ表示する。TUI では直前の操作の警告として保持する。
+
+use crate::component::ComponentKind;
+use crate::install::format::format_empty_hooks_warning;
+use crate::install::PlaceSuccess;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// 種別ごとに表示する警告の最大数（超過分は件数だけ表示する）
+const SHOWN_PER_CATEGORY: usize = 3;
+
+/// 警告の種別（まとめて表示するときの見出し）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarningCategory {
+    /// Hook 変換で失われた・書き換えが必要な設定
+    Conversion,
+    /// ターゲットの予約名と衝突するコンポーネント
+    ReservedName,
+    /// 巨大な添付アセット
+    LargeAssets,
+    /// スキップしたコピー元のシンボリックリンク
+    Symlink,
+    /// ソースより古い事前変換の成果物
+    Precompile,
+    /// 更新後の再デプロイに失敗したターゲットと、衝突で配置しなかったコンポーネント
+    Deploy,
+    /// マーケットプレイス側でのリネーム
+    Rename,
+}
+
+impl WarningCategory {
+    /// 見出しの表示名
+    pub fn label(&self) -> &'static str {
+        match self {
+            WarningCategory::Conversion => "Hook conversion",
+            WarningCategory::ReservedName => "Reserved names",
+            WarningCategory::LargeAssets => "Large assets",
+            WarningCategory::Symlink => "Symlinks",
+            WarningCategory::Precompile => "Precompiled files",
+            WarningCategory::Deploy => "Deploy",
+            WarningCategory::Rename => "Renamed plugins",
+        }
+    }
+}
+
+/// 集約した警告 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub category: WarningCategory,
+    pub message: String,
+}
+
+/// 操作中の警告を溜めるコレクタ
+///
+/// clone しても同じ警告リストを共有するため、並列に配置するスレッドへ渡せる。
+#[derive(Debug, Clone, Default)]
+pub struct WarningCollector {
+    warnings: Arc<Mutex<Vec<Warning>>>,
+}
+
+impl WarningCollector {
+    /// 警告を追加する
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Heading the warning is grouped under.
+    /// * `message` - Warning text (without a `Warning:` prefix).
+    pub fn push(&self, category: WarningCategory, message: impl Into<String>) {
+        self.lock().push(Warning {
+            category,
+            message: message.into(),
+        });
+    }
+
+    /// 配置結果 1 件に含まれる警告を追加する
+    ///
+    /// 各警告には `<target> <kind>: <name>` を前置して、どのコンポーネントの警告か分かるようにする。
+    ///
+    /// # Arguments
+    ///
+    /// * `success` - Placement result of a single component.
+    pub fn record_placement(&self, success: &PlaceSuccess) {
+        let context = format!(
+            "{} {}: {}",
+            success.target, success.component_kind, success.component_name
+        );
+        let with_context = |message: &dyn std::fmt::Display| format!("{}: {}", context, message);
+
+        if success.component_kind == ComponentKind::Hook {
+            for warning in &success.hook_warnings {
+                self.push(WarningCategory::Conversion, with_context(warning));
+            }
+            if format_empty_hooks_warning(success.hook_count, success.hook_source_format).is_some()
+            {
+                self.push(
+                    WarningCategory::Conversion,
+                    with_context(
+                        &"an empty hooks.json was placed; no hooks remained after conversion",
+                    ),
+                );
+            }
+        }
+        let optional = [
+            (WarningCategory::LargeAssets, &success.assets_warning),
+            (WarningCategory::Symlink, &success.symlink_warning),
+            (WarningCategory::Precompile, &success.precompile_warning),
+        ];
+        for (category, warning) in optional {
+            if let Some(warning) = warning {
+                self.push(category, with_context(warning));
+            }
+        }
+    }
+
+    /// 溜まっている警告のコピー（追加順）
+    pub fn snapshot(&self) -> Vec<Warning> {
+        self.lock().clone()
+    }
+
+    /// 他のスレッドが panic してもそれまでの警告は失わずに使う
+    fn lock(&self) -> MutexGuard<'_, Vec<Warning>> {
+        self.warnings.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 警告を種別ごとにまとめた表示テキスト（警告が無ければ `None`）
+///
+/// 1 行目は `<title> (<件数>):` の見出し。
+///
+/// ```text
+/// Warnings (7):
+///   Hook conversion (5):
+///     - codex Hook: lint: ...
+///     - ...
+///     ... and 2 more similar warnings
+///   Large assets (2):
+///     - ...
+/// ```
+///
+/// 種別は [`WarningCategory`] の定義順、種別内は追加順に並べる。
+///
+/// # Arguments
+///
+/// * `title` - Heading of the first line (e.g. `Warnings`).
+/// * `warnings` - Collected warnings.
+pub fn render_warnings(title: &str, warnings: &[Warning]) -> Option<String> {
+    if warnings.is_empty() {
+        return None;
+    }
+    let mut categories: Vec<WarningCategory> = warnings.iter().map(|w| w.category).collect();
+    categories.sort();
+    categories.dedup();
+
+    let mut lines = vec![format!("{} ({}):", title, warnings.len())];
+    for category in categories {
+        let messages: Vec<&str> = warnings
+            .iter()
+            .filter(|w| w.category == category)
+            .map(|w| w.message.as_str())
+            .collect();
+        lines.push(format!("  {} ({}):", category.label(), messages.len()));
+        for message in messages.iter().take(SHOWN_PER_CATEGORY) {
+            lines.push(format!("    - {}", message));
+        }
+        let hidden = messages.len().saturating_sub(SHOWN_PER_CATEGORY);
+        if hidden > 0 {
+            let noun = if hidden == 1 { "warning" } else { "warnings" };
+            lines.push(format!("    ... and {} more similar {}", hidden, noun));
+        }
+    }
+    Some(lines.join("\n"))
+}
+
+#[cfg(test)]
+#[path = "warnings_test.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+use super::*;
+use crate::hooks::converter::{ConversionWarning, SourceFormat};
+use crate::target::TargetKind;
+use std::path::PathBuf;
+
+fn success(kind: ComponentKind, name: &str) -> PlaceSuccess {
+    PlaceSuccess {
+        target: "codex".to_string(),
+        target_kind: TargetKind::Codex,
+        component_name: name.to_string(),
+        component_kind: kind,
+        target_path: PathBuf::from("/dest"),
+        source_format: None,
+        dest_format: None,
+        hook_warnings: Vec::new(),
+        script_count: 0,
+        hook_count: 1,
+        hook_source_format: None,
+        assets_warning: None,
+        symlink_warning: None,
+        precompile_warning: None,
+        unchanged_files: 0,
+        file_diffs: Vec::new(),
+    }
+}
+
+fn warning(category: WarningCategory, message: &str) -> Warning {
+    Warning {
+        category,
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn record_placement_prefixes_each_warning_with_the_component() {
+    let collector = WarningCollector::default();
+    let mut placed = success(ComponentKind::Hook, "guard");
+    placed.hook_warnings = vec![ConversionWarning::UnsupportedEvent {
+        event: "Notification".to_string(),
+    }];
+    placed.symlink_warning = Some("skipped 1 symlink".to_string());
+
+    collector.record_placement(&placed);
+
+    let warnings = collector.snapshot();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].category, WarningCategory::Conversion);
+    assert!(warnings[0].message.starts_with("codex Hook: guard: "));
+    assert_eq!(
+        warnings[1],
+        warning(
+            WarningCategory::Symlink,
+            "codex Hook: guard: skipped 1 symlink"
+        )
+    );
+}
+
+#[test]
+fn record_placement_reports_an_empty_converted_hooks_file() {
+    let collector = WarningCollector::default();
+    let mut placed = success(ComponentKind::Hook, "guard");
+    placed.hook_count = 0;
+    placed.hook_source_format = Some(SourceFormat::ClaudeCode);
+
+    collector.record_placement(&placed);
+
+    let warnings = collector.snapshot();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.contains("empty hooks.json"));
+}
+
+#[test]
+fn record_placement_ignores_clean_results() {
+    let collector = WarningCollector::default();
+    collector.record_placement(&success(ComponentKind::Skill, "lint"));
+    assert!(collector.snapshot().is_empty());
+}
+
+#[test]
+fn clones_share_warnings_across_threads() {
+    let collector = WarningCollector::default();
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let collector = collector.clone();
+            std::thread::spawn(move || {
+                for j in 0..10 {
+                    collector.push(WarningCategory::Deploy, format!("{}-{}", i, j));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(collector.snapshot().len(), 40);
+}
+
+#[test]
+fn render_warnings_is_none_without_warnings() {
+    assert_eq!(render_warnings("Warnings", &[]), None);
+}
+
+#[test]
+fn render_warnings_groups_by_category_and_folds_the_rest() {
+    let mut warnings: Vec<Warning> = (1..=5)
+        .map(|i| warning(WarningCategory::Conversion, &format!("hook {}", i)))
+        .collect();
+    warnings.insert(0, warning(WarningCategory::LargeAssets, "big.bin"));
+    warnings.push(warning(WarningCategory::ReservedName, "help"));
+
+    let rendered = render_warnings("Warnings", &warnings).unwrap();
+
+    assert_eq!(
+        rendered,
+        "Warnings (7):\n\
+         \x20 Hook conversion (5):\n\
+         \x20   - hook 1\n\
+         \x20   - hook 2\n\
+         \x20   - hook 3\n\
+         \x20   ... and 2 more similar warnings\n\
+         \x20 Reserved names (1):\n\
+         \x20   - help\n\
+         \x20 Large assets (1):\n\
+         \x20   - big.bin"
+    );
+}
+
+#[test]
+fn render_warnings_uses_singular_for_one_hidden_warning() {
+    let warnings: Vec<Warning> = (1..=4)
+        .map(|i| warning(WarningCategory::Deploy, &format!("target {}", i)))
+        .collect();
+    let rendered = render_warnings("Warnings", &warnings).unwrap();
+    assert!(rendered.ends_with("... and 1 more similar warning"));
+}
@@ -0,0 +1,360 @@
+//! 破壊的操作前のスナップショット（`--backup`）
+//!
+//! disable / uninstall / update / sync が変更・削除するファイルを、操作の前に
+//! `~/.local/state/plm/backups/<id>.zip` へ退避する。アーカイブには退避したファイルと、
+//! 操作内容と元のパスを記録した `backup.json` を入れる。
+//!
+//! ## レイアウト
+//!
+//! ```text
+//! 20261016T120000Z-uninstall.zip
+//! ├── backup.json      # BackupManifest
+//! └── files/
+//!     ├── 000000       # BackupFile.entry → 元の絶対パスは backup.json に記録
+//!     └── 000001
+//! ```
+//!
+//! アーカイブ内のエントリ名は plm が採番した `files/<連番>` だけで、元のパスを
+//! エントリ名に使わない（`../` を含むエントリを作らない）。復元時もエントリ名と
+//! 元のパスの両方を検証し、`..` を含むものがあれば何も書き込まずに失敗する。
+
+use crate::env::PlmPaths;
+use crate::error::{PlmError, Result};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// アーカイブ内のメタ情報ファイル名
+pub const BACKUP_MANIFEST: &str = "backup.json";
+
+/// 残すバックアップの世代数の既定値（設定ファイルの `[backup] keep` で変更できる）
+pub const DEFAULT_KEEP: usize = 10;
+
+/// 退避したファイルを置くアーカイブ内のディレクトリ
+const FILES_DIR: &str = "files";
+
+/// バックアップを取る操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupOperation {
+    Disable,
+    Uninstall,
+    Update,
+    Sync,
+}
+
+impl BackupOperation {
+    /// バックアップ ID とメタ情報に使う名前
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackupOperation::Disable => "disable",
+            BackupOperation::Uninstall => "uninstall",
+            BackupOperation::Update => "update",
+            BackupOperation::Sync => "sync",
+        }
+    }
+}
+
+/// 退避したファイル 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// 元の絶対パス（復元先）
+    pub path: PathBuf,
+    /// アーカイブ内のエントリ名（`files/<連番>`）
+    pub entry: String,
+}
+
+/// `backup.json` の内容
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    /// バックアップ ID（アーカイブのファイル名から拡張子を除いたもの）
+    pub id: String,
+    /// 操作名（`disable` / `uninstall` / `update` / `sync`）
+    pub operation: String,
+    /// 操作の対象（例: `plm uninstall formatter`）
+    pub description: String,
+    /// 作成日時（RFC 3339、UTC）
+    pub created_at: String,
+    /// 退避したファイル
+    pub files: Vec<BackupFile>,
+}
+
+/// バックアップの保存先
+pub struct BackupStore {
+    dir: PathBuf,
+}
+
+impl BackupStore {
+    /// 既定の保存先（`~/.local/state/plm/backups`）を使う
+    pub fn new() -> Result<Self> {
+        Ok(Self::with_dir(PlmPaths::new()?.backups_dir()))
+    }
+
+    /// 保存先を指定して構築する（テスト用）
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory holding the backup archives.
+    pub fn with_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// 指定パスを退避したバックアップを作る
+    ///
+    /// ディレクトリは配下の通常ファイルをすべて退避する。存在しないパスと
+    /// シンボリックリンクは対象外（`plm link` のリンクは実体を持たないため）。
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Operation about to run.
+    /// * `description` - Human readable summary of the operation.
+    /// * `paths` - Files or directories the operation may change or remove.
+    pub fn create(
+        &self,
+        operation: BackupOperation,
+        description: &str,
+        paths: &[PathBuf],
+    ) -> Result<BackupManifest> {
+        let now = Utc::now();
+        fs::create_dir_all(&self.dir)?;
+        let id = self.unused_id(&format!(
+            "{}-{}",
+            now.format("%Y%m%dT%H%M%SZ"),
+            operation.as_str()
+        ));
+
+        let mut files = Vec::new();
+        for path in collect_files(paths)? {
+            files.push(BackupFile {
+                entry: format!("{}/{:06}", FILES_DIR, files.len()),
+                path,
+            });
+        }
+        let manifest = BackupManifest {
+            id,
+            operation: operation.as_str().to_string(),
+            description: description.to_string(),
+            created_at: now.to_rfc3339_opts(SecondsFormat::Micros, true),
+            files,
+        };
+
+        let archive_path = self.archive_path(&manifest.id);
+        if let Err(e) = write_archive(&archive_path, &manifest) {
+            let _ = fs::remove_file(&archive_path);
+            return Err(e);
+        }
+        Ok(manifest)
+    }
+
+    /// バックアップの一覧（古い順）
+    ///
+    /// 読めないアーカイブは一覧から外す。
+    pub fn list(&self) -> Result<Vec<BackupManifest>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(PlmError::Io(e)),
+        };
+        let mut manifests: Vec<BackupManifest> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "zip"))
+            .filter_map(|path| read_manifest(&path).ok())
+            .collect();
+        manifests.sort_by(|a, b| (&a.created_at, &a.id).cmp(&(&b.created_at, &b.id)));
+        Ok(manifests)
+    }
+
+    /// ID でバックアップを読む
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Backup ID shown by `plm backups list`.
+    pub fn get(&self, id: &str) -> Result<BackupManifest> {
+        read_manifest(&self.existing_archive(id)?)
+    }
+
+    /// バックアップのファイルを元の場所へ書き戻す
+    ///
+    /// 書き込む前に全エントリを検証し、安全でないパスが 1 つでもあれば何も書き込まない。
+    /// 復元したパスを返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Backup ID shown by `plm backups list`.
+    pub fn restore(&self, id: &str) -> Result<Vec<PathBuf>> {
+        let archive_path = self.existing_archive(id)?;
+        let manifest = read_manifest(&archive_path)?;
+        for file in &manifest.files {
+            validate_entry(&file.entry)?;
+            validate_destination(&file.path)?;
+        }
+
+        let mut zip = ZipArchive::new(File::open(&archive_path)?)?;
+        let mut restored = Vec::new();
+        for file in &manifest.files {
+            let mut content = Vec::new();
+            zip.by_name(&file.entry)?.read_to_end(&mut content)?;
+            if let Some(parent) = file.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&file.path, content)?;
+            restored.push(file.path.clone());
+        }
+        Ok(restored)
+    }
+
+    /// 新しいものから `keep` 件を残して古いバックアップを削除し、削除した ID を返す
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Number of backups to keep.
+    pub fn prune(&self, keep: usize) -> Result<Vec<String>> {
+        let manifests = self.list()?;
+        let excess = manifests.len().saturating_sub(keep);
+        let mut removed = Vec::new();
+        for manifest in manifests.into_iter().take(excess) {
+            fs::remove_file(self.archive_path(&manifest.id))?;
+            removed.push(manifest.id);
+        }
+        Ok(removed)
+    }
+
+    /// ID に対応するアーカイブのパス
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Backup ID.
+    fn archive_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.zip", id))
+    }
+
+    /// 存在するアーカイブのパス（ID にパス区切りを含む場合も見つからない扱い）
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Backup ID given on the command line.
+    fn existing_archive(&self, id: &str) -> Result<PathBuf> {
+        let path = self.archive_path(id);
+        if id.contains(['/', '\\']) || id.starts_with('.') || !path.is_file() {
+            return Err(PlmError::General(format!("Backup not found: {}", id)));
+        }
+        Ok(path)
+    }
+
+    /// 同じ秒に作られたバックアップと衝突しない ID
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - `<timestamp>-<operation>`.
+    fn unused_id(&self, base: &str) -> String {
+        let mut id = base.to_string();
+        let mut n = 2;
+        while self.archive_path(&id).exists() {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+        id
+    }
+}
+
+/// 退避対象の通常ファイルを絶対パスで列挙する（重複は除く）
+///
+/// # Arguments
+///
+/// * `paths` - Files or directories to back up.
+fn collect_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let path = std::path::absolute(path)?;
+        validate_destination(&path)?;
+        for entry in WalkDir::new(&path).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) if e.io_error().map(|e| e.kind()) == Some(std::io::ErrorKind::NotFound) => {
+                    continue
+                }
+                Err(e) => return Err(PlmError::Io(e.into())),
+            };
+            if entry.file_type().is_file() && !files.contains(&entry.path().to_path_buf()) {
+                files.push(entry.into_path());
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// アーカイブを書き出す
+///
+/// # Arguments
+///
+/// * `archive_path` - Archive to create.
+/// * `manifest` - Backup metadata listing the files to store.
+fn write_archive(archive_path: &Path, manifest: &BackupManifest) -> Result<()> {
+    let mut zip = ZipWriter::new(File::create(archive_path)?);
+    let options = SimpleFileOptions::default();
+    for file in &manifest.files {
+        zip.start_file(file.entry.as_str(), options)?;
+        zip.write_all(&fs::read(&file.path)?)?;
+    }
+    zip.start_file(BACKUP_MANIFEST, options)?;
+    zip.write_all(serde_json::to_string_pretty(manifest)?.as_bytes())?;
+    zip.finish()?;
+    Ok(())
+}
+
+/// アーカイブから `backup.json` を読む
+///
+/// # Arguments
+///
+/// * `archive_path` - Backup archive.
+fn read_manifest(archive_path: &Path) -> Result<BackupManifest> {
+    let mut zip = ZipArchive::new(File::open(archive_path)?)?;
+    let mut content = String::new();
+    zip.by_name(BACKUP_MANIFEST)?.read_to_string(&mut content)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// エントリ名が `files/` 配下の相対パスか
+///
+/// # Arguments
+///
+/// * `entry` - Entry name recorded in `backup.json`.
+fn validate_entry(entry: &str) -> Result<()> {
+    let path = Path::new(entry);
+    let safe =
+        path.starts_with(FILES_DIR) && path.components().all(|c| matches!(c, Component::Normal(_)));
+    if safe {
+        Ok(())
+    } else {
+        Err(PlmError::Validation(format!(
+            "unsafe entry in backup: {}",
+            entry
+        )))
+    }
+}
+
+/// 復元先が `..` を含まない絶対パスか
+///
+/// # Arguments
+///
+/// * `path` - Original path recorded in `backup.json`.
+fn validate_destination(path: &Path) -> Result<()> {
+    if path.is_absolute() && !path.components().any(|c| c == Component::ParentDir) {
+        Ok(())
+    } else {
+        Err(PlmError::Validation(format!(
+            "unsafe path in backup: {}",
+            path.display()
+        )))
+    }
+}
+
+#[cfg(test)]
+#[path = "backup_test.rs"]
+mod tests;
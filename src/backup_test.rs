@@ -0,0 +1,194 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write_file(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// 任意の `backup.json` とエントリを持つアーカイブを作る（改ざんされたバックアップの再現用）
+fn write_raw_backup(dir: &Path, manifest: &BackupManifest, entries: &[(&str, &str)]) {
+    fs::create_dir_all(dir).unwrap();
+    let file = File::create(dir.join(format!("{}.zip", manifest.id))).unwrap();
+    let mut zip = ZipWriter::new(file);
+    for (name, content) in entries {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.start_file(BACKUP_MANIFEST, SimpleFileOptions::default())
+        .unwrap();
+    zip.write_all(serde_json::to_string(manifest).unwrap().as_bytes())
+        .unwrap();
+    zip.finish().unwrap();
+}
+
+fn raw_manifest(id: &str, path: PathBuf, entry: &str) -> BackupManifest {
+    BackupManifest {
+        id: id.to_string(),
+        operation: "sync".to_string(),
+        description: "plm sync --from codex --to copilot".to_string(),
+        created_at: "2026-10-16T12:00:00.000000Z".to_string(),
+        files: vec![BackupFile {
+            path,
+            entry: entry.to_string(),
+        }],
+    }
+}
+
+#[test]
+fn backup_and_restore_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join("project");
+    let skill = project.join(".codex/skills/formatter");
+    let prompt = project.join(".codex/prompts/review.md");
+    write_file(&skill.join("SKILL.md"), "skill");
+    write_file(&skill.join("scripts/run.sh"), "#!/bin/sh\n");
+    write_file(&prompt, "prompt");
+    let store = BackupStore::with_dir(dir.path().join("backups"));
+
+    let manifest = store
+        .create(
+            BackupOperation::Uninstall,
+            "plm uninstall formatter",
+            &[skill.clone(), prompt.clone(), project.join("missing.md")],
+        )
+        .unwrap();
+
+    assert!(manifest.id.ends_with("-uninstall"), "{}", manifest.id);
+    assert_eq!(manifest.operation, "uninstall");
+    let paths: Vec<&Path> = manifest.files.iter().map(|f| f.path.as_path()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            skill.join("SKILL.md").as_path(),
+            skill.join("scripts/run.sh").as_path(),
+            prompt.as_path(),
+        ]
+    );
+    assert!(manifest.files.iter().all(|f| f.entry.starts_with("files/")));
+    assert_eq!(store.list().unwrap(), vec![manifest.clone()]);
+
+    // 操作でファイルが消えた・書き換わった後に復元する
+    fs::remove_dir_all(&skill).unwrap();
+    fs::write(&prompt, "changed").unwrap();
+
+    let restored = store.restore(&manifest.id).unwrap();
+
+    assert_eq!(restored.len(), 3);
+    assert_eq!(fs::read_to_string(skill.join("SKILL.md")).unwrap(), "skill");
+    assert_eq!(
+        fs::read_to_string(skill.join("scripts/run.sh")).unwrap(),
+        "#!/bin/sh\n"
+    );
+    assert_eq!(fs::read_to_string(&prompt).unwrap(), "prompt");
+}
+
+#[test]
+fn create_uses_unique_ids_within_the_same_second() {
+    let dir = TempDir::new().unwrap();
+    let store = BackupStore::with_dir(dir.path().join("backups"));
+
+    let first = store.create(BackupOperation::Sync, "first", &[]).unwrap();
+    let second = store.create(BackupOperation::Sync, "second", &[]).unwrap();
+
+    assert_ne!(first.id, second.id);
+    assert_eq!(store.list().unwrap().len(), 2);
+}
+
+#[test]
+fn prune_removes_oldest_backups_beyond_keep() {
+    let dir = TempDir::new().unwrap();
+    let backups = dir.path().join("backups");
+    let file = dir.path().join("a.md");
+    write_file(&file, "a");
+    for (id, created_at) in [
+        ("b-update", "2026-10-16T12:00:02.000000Z"),
+        ("a-disable", "2026-10-16T12:00:01.000000Z"),
+        ("c-sync", "2026-10-16T12:00:03.000000Z"),
+    ] {
+        let mut manifest = raw_manifest(id, file.clone(), "files/000000");
+        manifest.created_at = created_at.to_string();
+        write_raw_backup(&backups, &manifest, &[("files/000000", "a")]);
+    }
+    let store = BackupStore::with_dir(backups);
+
+    let removed = store.prune(2).unwrap();
+
+    assert_eq!(removed, vec!["a-disable".to_string()]);
+    let ids: Vec<String> = store.list().unwrap().into_iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec!["b-update".to_string(), "c-sync".to_string()]);
+}
+
+#[test]
+fn restore_rejects_parent_dir_in_original_path() {
+    let dir = TempDir::new().unwrap();
+    let backups = dir.path().join("backups");
+    let escaped = dir.path().join("project/../escaped.md");
+    write_raw_backup(
+        &backups,
+        &raw_manifest("evil", escaped, "files/000000"),
+        &[("files/000000", "x")],
+    );
+    let store = BackupStore::with_dir(backups);
+
+    let err = store.restore("evil").unwrap_err();
+
+    assert!(err.to_string().contains("unsafe path"), "{}", err);
+    assert!(!dir.path().join("escaped.md").exists());
+}
+
+#[test]
+fn restore_rejects_relative_original_path() {
+    let dir = TempDir::new().unwrap();
+    let backups = dir.path().join("backups");
+    write_raw_backup(
+        &backups,
+        &raw_manifest("relative", PathBuf::from("escaped.md"), "files/000000"),
+        &[("files/000000", "x")],
+    );
+    let store = BackupStore::with_dir(backups);
+
+    let err = store.restore("relative").unwrap_err();
+
+    assert!(err.to_string().contains("unsafe path"), "{}", err);
+}
+
+#[test]
+fn restore_rejects_entry_outside_files_dir_before_writing() {
+    let dir = TempDir::new().unwrap();
+    let backups = dir.path().join("backups");
+    let safe = dir.path().join("safe.md");
+    let mut manifest = raw_manifest("evil-entry", safe.clone(), "files/000000");
+    manifest.files.push(BackupFile {
+        path: dir.path().join("other.md"),
+        entry: "files/../../other".to_string(),
+    });
+    write_raw_backup(&backups, &manifest, &[("files/000000", "x")]);
+    let store = BackupStore::with_dir(backups);
+
+    let err = store.restore("evil-entry").unwrap_err();
+
+    assert!(err.to_string().contains("unsafe entry"), "{}", err);
+    // 検証は書き込みより先に行う
+    assert!(!safe.exists());
+}
+
+#[test]
+fn get_unknown_or_path_like_id_is_not_found() {
+    let dir = TempDir::new().unwrap();
+    let store = BackupStore::with_dir(dir.path().join("backups"));
+    store.create(BackupOperation::Disable, "x", &[]).unwrap();
+
+    for id in ["missing", "../backups/x", ".hidden"] {
+        let err = store.get(id).unwrap_err();
+        assert!(err.to_string().contains("Backup not found"), "{}", err);
+    }
+}
+
+#[test]
+fn list_without_backup_dir_is_empty() {
+    let dir = TempDir::new().unwrap();
+    let store = BackupStore::with_dir(dir.path().join("backups"));
+
+    assert!(store.list().unwrap().is_empty());
+}
@@ -1,11 +1,16 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use std::path::PathBuf;
 
 use crate::commands::{
-    deploy::{import, install, link, sync, unlink},
-    info,
-    lifecycle::{disable, enable, uninstall, update},
+    deploy::{import, install, link, refresh, sync, unlink},
+    examples, info,
+    lifecycle::{disable, enable, pin, uninstall, unpin, update},
     list,
-    manage::{init, marketplace, pack, target},
+    manage::{
+        backups, cache, doctor, env, gitignore, hooks, init, managed, marketplace, pack, pick,
+        placements, stats, status, target, test_plugin, verify_conversions,
+    },
+    search,
 };
 
 #[derive(Debug, Parser)]
@@ -24,10 +29,46 @@ Install plugins from GitHub or marketplaces, manage their lifecycle, and keep th
 )]
 #[command(subcommand_required = false, arg_required_else_help = false)]
 pub struct Cli {
-    /// Show detailed error information including cause and remediation
+    /// Show detailed output and error information including cause and remediation
     #[arg(long, global = true)]
     pub verbose: bool,
 
+    /// Disable the short-lived GitHub API response cache (same as PLM_NO_HTTP_CACHE=1)
+    #[arg(long, global = true)]
+    pub no_http_cache: bool,
+
+    /// Never access the network; only cached data is used (same as PLM_OFFLINE=1)
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Suppress follow-up hints such as how to reload targets after deploying (same as PLM_QUIET=1)
+    #[arg(long, global = true)]
+    pub quiet: bool,
+
+    /// Wait for other plm processes modifying the same plugin instead of failing (same as PLM_LOCK_WAIT=1)
+    #[arg(long, global = true)]
+    pub wait: bool,
+
+    /// Wait up to SECONDS for other plm processes modifying the same plugin, then fail (same as PLM_WAIT_LOCK=SECONDS)
+    #[arg(long, global = true, value_name = "SECONDS")]
+    pub wait_lock: Option<u64>,
+
+    /// Project root to deploy into and inspect; defaults to the nearest ancestor with .git or .plm/config.json, else the current directory (same as PLM_PROJECT_ROOT)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub project_root: Option<PathBuf>,
+
+    /// Maximum number of concurrent downloads (same as PLM_JOBS)
+    #[arg(long, global = true, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Maximum download rate per connection, e.g. 500k or 2m bytes per second (same as PLM_LIMIT_RATE)
+    #[arg(long, global = true, value_name = "RATE")]
+    pub limit_rate: Option<String>,
+
+    /// Minimum interval in milliseconds between HTTP requests (same as PLM_REQUEST_INTERVAL_MS)
+    #[arg(long, global = true, value_name = "MS")]
+    pub request_interval: Option<u64>,
+
     /// 省略時はデフォルトで managed TUI を起動する（非TTYなら --help を出して終了）。
     #[command(subcommand)]
     pub command: Option<Command>,
@@ -54,12 +95,27 @@ pub enum Command {
 SOURCE FORMATS:
   owner/repo              GitHub repository (e.g., user/my-plugin)
   plugin@marketplace      Plugin from a registered marketplace
+  plugin@owner/repo       Plugin from a marketplace given by its source; the
+                          marketplace is registered first unless it already is
+                          (disable with --no-add-marketplace)
+  marketplace/*           Every plugin in a registered marketplace
+  ./plugin.zip            Local archive created by `plm pack` (verified against
+                          its checksums.json)
+
+Several sources can be given at once; already installed plugins are skipped and a
+summary table is printed. The exit code is non-zero if any plugin failed.
 
 OPTIONS:
   --type    Filter which component types to install (skill, agent, command, instruction)
   --target  Specify which environments to deploy to (codex, copilot)
   --scope   Choose personal or project scope
-  --force   Re-download even if cached"#
+  --force   Re-download even if cached
+  --overwrite Replace components at the same destination deployed by another plugin
+            (by default they are skipped with a warning)
+  --dry-run Show where each component would be placed without writing (add --json for CI)
+  --notify  Send a desktop notification when the install finishes
+  --add-marketplace  Treat owner/repo/plugin as a plugin of the marketplace owner/repo
+            and register the marketplace if needed"#
     )]
     Install(install::Args),
 
@@ -71,6 +127,7 @@ OUTPUT FORMATS:
   (default)   Table format with Name, Version, Components, Status, Marketplace
   --json      JSON array for scripting
   --simple    Plugin names only, one per line
+  --format    table, json or yaml (json / yaml: one entry per plugin with component counts)
 
 FILTERING:
   --type      Filter by component type
@@ -92,18 +149,46 @@ SECTIONS DISPLAYED:
 OUTPUT FORMATS:
   -f, --format table  Default table view
   -f, --format json   JSON for scripting
-  -f, --format yaml   YAML format"#)]
+  -f, --format yaml   YAML format
+
+  JSON / YAML list each component with its cache-relative path, description
+  and deployed targets. Pass --json-schema v1 for the former name-only arrays."#)]
     Info(info::Args),
 
+    /// Search the registered marketplaces for plugins
+    #[command(
+        long_about = r#"Search the cached manifests of every registered marketplace for plugins
+whose name or description contains KEYWORD (case-insensitive).
+
+Matches are highlighted in a table with the name, marketplace, version,
+an installed mark and the first 60 characters of the description.
+Marketplaces without a cache are skipped with a warning.
+
+Exits with 1 when nothing matches, 0 otherwise.
+
+OPTIONS:
+  -m, --marketplace <NAME>  Only search this marketplace
+  --installed-only          Only show plugins that are already installed
+  --refresh                 Update the marketplace caches before searching
+  --sort <KEY>              name (default), downloads, stars or updated;
+                            plugins without the metadata come last
+  --json                    Print machine-readable JSON"#
+    )]
+    Search(search::Args),
+
     /// Enable a component
     #[command(
         long_about = r#"Enable a plugin by deploying its components from cache to target environments.
 
-The plugin must already be installed (cached). Components are copied from the cache directory to the appropriate target locations.
+The plugin must already be installed (cached). Components are copied from the cache directory to the appropriate target locations. A partially disabled plugin (plm disable --keep) is restored in full. Components disabled with plm disable --component stay off unless --all is given.
 
 OPTIONS:
   --target           Enable for a specific environment only (codex, copilot)
-  -m, --marketplace  Specify marketplace name (default: github)"#
+  --component        Enable a single component (KIND:NAME, e.g. skill:code-review)
+  --all              Also enable components disabled with --component
+  --overwrite        Replace components at the same destination deployed by another plugin
+  -m, --marketplace  Specify marketplace name (default: github)
+  --stdin            Read plugin names from stdin (one per line, marketplace/name allowed)"#
     )]
     Enable(enable::Args),
 
@@ -113,9 +198,17 @@ OPTIONS:
 
 Components are removed from target locations but the cache is preserved, allowing the plugin to be re-enabled without re-downloading.
 
+With --keep, only the other component kinds are removed and the plugin is shown as "partially disabled". Run plm enable to restore everything.
+
+With --component, only that one component is removed from every target and it stays off until plm enable --component (or --all) turns it back on.
+
 OPTIONS:
   --target           Disable for a specific environment only (codex, copilot)
-  -m, --marketplace  Specify marketplace name (default: github)"#
+  --keep             Keep these component kinds deployed (comma-separated, e.g. instructions,skills)
+  --component        Disable a single component (KIND:NAME, e.g. skill:code-review)
+  -m, --marketplace  Specify marketplace name (default: github)
+  --stdin            Read plugin names from stdin (one per line, marketplace/name allowed)
+  --backup           Save the removed files first (see plm backups)"#
     )]
     Disable(disable::Args),
 
@@ -125,9 +218,15 @@ OPTIONS:
 
 The plugin and all its components will be removed from all target environments. This action cannot be undone.
 
+With --stdin, plugin names are read from standard input (one per line; blank lines and
+# comments are ignored) and each result is printed as "name: ok" or "name: failed (reason)".
+The exit code is 1 if any plugin failed. --stdin requires --force.
+
 OPTIONS:
   -m, --marketplace  Specify marketplace name
-  -f, --force        Skip confirmation prompt"#
+  -f, --force        Skip confirmation prompt
+  --stdin            Read plugin names from stdin (one per line, marketplace/name allowed)
+  --backup           Save the deployed files and cache first (see plm backups)"#
     )]
     Uninstall(uninstall::Args),
 
@@ -135,24 +234,123 @@ OPTIONS:
     #[command(long_about = r#"Check for and apply updates to installed plugins.
 
 Specify a plugin name to update a single plugin, or use --all to update all installed plugins.
+Plugins whose upstream commit SHA matches the one recorded at the last fetch are reported
+as up to date and are not fetched or redeployed; use --force to update them anyway.
+
+When a marketplace plugin was renamed (listed in `renamedFrom` of the new entry),
+plm asks whether to migrate the installed copy to the new name before updating.
 
 OPTIONS:
-  --all     Update all installed plugins
-  --target  Filter by target environment (codex, copilot)"#)]
+  --all             Update all installed plugins
+  --stdin           Read plugin names from stdin (one per line, marketplace/name allowed)
+  --target          Filter by target environment (codex, copilot)
+  --follow-renames  Migrate renamed plugins without prompting
+  --force           Fetch and redeploy even when the commit SHA is unchanged
+  --redeploy-only   Redeploy from cache without fetching (filter kinds with --only)
+  --notify          Send a desktop notification when the update finishes
+  --backup          Save the deployed files and cache first (see plm backups)"#)]
     Update(update::Args),
 
+    /// Pin a plugin to its current version
+    #[command(long_about = r#"Pin an installed plugin to its current version.
+
+Pinned plugins are skipped by `plm update` (including --all) and by updates from the TUI,
+and are reported as "Skipped (pinned)". The pin is stored in the plugin's .plm-meta.json.
+
+OPTIONS:
+  -m, --marketplace  Specify marketplace name (default: github)"#)]
+    Pin(pin::Args),
+
+    /// Unpin a plugin
+    #[command(
+        long_about = r#"Remove the pin set by `plm pin` so that `plm update` upgrades the plugin again.
+
+OPTIONS:
+  -m, --marketplace  Specify marketplace name (default: github)"#
+    )]
+    Unpin(unpin::Args),
+
     /// Generate templates
     #[command(long_about = r#"Generate plugin templates for creating new plugins.
 
 Creates a new plugin skeleton with the specified component type.
 
 OPTIONS:
-  --type  Component type to generate (skill, agent, prompt, instruction)"#)]
+  --type        Component type to generate (skill, agent, prompt, instruction)
+  --components  Scaffold sample components (hooks: per-event sample scripts
+                under scripts/ and a hooks/hooks.json wiring them)"#)]
     Init(init::Args),
 
     /// Create distribution package
+    #[command(
+        long_about = r#"Create a distribution package (zip) from a plugin directory.
+
+The archive contains the plugin under <name>/ plus a checksums.json with the
+SHA-256 of every file. `plm install` and `plm import` verify it when the
+archive is extracted and abort if any file was modified, removed or added.
+
+Before packing, plugin.json must have a name and version and every declared
+component path must exist (skip with --no-verify). A plugin without any
+components is packed with a warning.
+
+Use --precompile to convert commands and agents for Copilot and Codex ahead
+of time into dist/ and ship them in the archive. Install uses these files
+instead of converting when their recorded source digests still match, and
+falls back to converting (with a warning) when a source is newer.
+
+Use --check to only list detected components, excluded paths and reserved
+name conflicts without creating an archive. If dist/ exists, --check also
+fails when it is out of date with the sources."#
+    )]
     Pack(pack::Args),
 
+    /// List and restore backups taken with --backup
+    #[command(
+        long_about = r#"List and restore snapshots taken before destructive operations.
+
+disable, uninstall, update and sync accept --backup. Before changing
+anything they save the affected deployed files and plugin cache metadata
+(the whole cached plugin for uninstall and update) to
+~/.local/state/plm/backups/<timestamp>-<operation>.zip, together with a
+backup.json describing the operation and the original paths.
+
+Only the newest 10 backups are kept; change this with `[backup] keep` in
+~/.plm/config.toml.
+
+SUBCOMMANDS:
+  list            List backups (oldest first)
+  restore <ID>    Write the files back to their original locations
+                  (asks for confirmation; use --yes in scripts)"#
+    )]
+    Backups(backups::Args),
+
+    /// Maintain the plugin cache
+    #[command(long_about = r#"Maintain the plugin cache.
+
+plm list and the TUI read plugins-index.json in the plugin cache directory
+(~/.plm/cache/plugins) instead of rescanning every plugin. The index keeps
+each plugin's manifest and component names together with the modification
+times of its directories; plugins whose directories changed are rescanned
+and written back. install, update and uninstall update their entries right
+away, and a missing or unreadable index is rebuilt automatically.
+
+SUBCOMMANDS:
+  rebuild-index   Rescan every cached plugin and rewrite the index"#)]
+    Cache(cache::Args),
+
+    /// Develop and test hooks locally
+    #[command(long_about = r#"Develop and test hooks locally.
+
+TEST:
+  plm hooks test <SCRIPT> --event <EVENT> [--payload <FILE>] [--target copilot]
+
+  Runs the hook script with the event payload on stdin and prints the exit
+  code, stdout and stderr. Without --payload, a built-in sample payload for
+  the event is used. With --target copilot, the script runs through the
+  wrapper script that plm install generates for Copilot CLI, with the payload
+  reshaped into Copilot's hook input format."#)]
+    Hooks(hooks::Args),
+
     /// Create a symbolic link
     #[command(long_about = r#"Create a symbolic link from dest to src.
 
@@ -185,10 +383,34 @@ OPTIONS:
   --to       Destination target environment (codex, copilot)
   --type     Component type to sync (all if not specified)
   --scope    Scope to sync (personal, project, or both)
-  --dry-run  Preview changes without applying them"#
+  --dry-run  Preview changes without applying them
+  --backup   Save the overwritten and deleted files first (see plm backups)
+  --lock     Record the installed plugins (version and commit SHA or content hash)
+             in plm.lock at the project root
+  --frozen   Install exactly the versions recorded in plm.lock and deploy them to the
+             recorded targets; fails listing every plugin that cannot be resolved"#
     )]
     Sync(sync::Args),
 
+    /// Write edits made in a deployment back to the cache
+    #[command(
+        long_about = r#"Write edits made directly to deployed files back to the plugin in the cache.
+
+The deployed files of one target are taken as the source of truth. Each file is
+compared with what `plm install` would deploy from the cache; changed files are
+listed and, after confirmation, written back. Commands and agents converted for
+the target are converted back to the Claude Code format; fields that only exist
+in the target format (e.g. Copilot handoffs) cannot be written back and are
+reported as warnings. Hooks are not refreshed.
+
+OPTIONS:
+  --from-deployed  Take the deployed files as the source of truth (required)
+  --target         Target whose deployed files are written back (codex, copilot, ...)
+  --dry-run        List the differences without writing to the cache
+  --yes, -y        Skip the confirmation prompt"#
+    )]
+    Refresh(refresh::Args),
+
     /// Import from Claude Code Plugin
     #[command(
         long_about = r#"Import components from a Claude Code Plugin repository.
@@ -199,6 +421,7 @@ Use --component to specify exact components or --type to filter by component typ
 SOURCE FORMATS:
   owner/repo              GitHub repository (e.g., DIO0550/sample-plugin)
   owner/repo@ref          Specific branch, tag, or commit
+  ./plugin.zip            Local archive created by `plm pack`
 
 COMPONENT SELECTION:
   --component skills/pdf        Import specific component (plural kind/name)
@@ -211,6 +434,13 @@ Valid types for --type: skill, agent, command, instruction, hook
 
 Note: --component and --type cannot be used together.
 
+BULK IMPORT FROM PROJECT FILES:
+  --from copilot       Bundle .github/prompts/*.prompt.md and .github/agents/*.agent.md
+  --from claude-code   Bundle .claude/commands/*.md and .claude/agents/*.md
+  --output <DIR>       Directory to write the plugin (required with --from)
+  --name <NAME>        Plugin name (defaults to the --output directory name)
+  --dry-run            Only print the file tree to be generated
+
 OPTIONS:
   --target     Target environments to deploy to (codex, copilot)
   --scope      Deployment scope (personal, project)
@@ -219,8 +449,177 @@ OPTIONS:
     Import(import::Args),
 
     /// Plugin management (TUI)
-    #[command(long_about = "Open interactive TUI for managing plugins visually.")]
-    Managed,
+    #[command(long_about = r#"Open interactive TUI for managing plugins visually.
+
+DEV MODE:
+  --dev <PATH>  Show a local plugin directory as a [dev] row in the Installed tab.
+                The directory is rescanned automatically when files change.
+                Press r to reload and d to redeploy it to the current project.
+                Dev plugins are never cached and are independent of install/uninstall.
+
+SCRIPTS:
+  --record <FILE>  Save the keys you press as a JSON script
+  --replay <FILE>  Replay a script without a terminal and print the final
+                   state and screen (write it to a file with --dump <FILE>)"#)]
+    Managed(managed::Args),
+
+    /// Pick an installed plugin interactively and run a command on it
+    #[command(
+        long_about = r#"Search installed plugins incrementally and run a command on the selected one.
+
+Type to narrow the list (characters match in order, case-insensitive),
+move with Up/Down (Ctrl+P / Ctrl+N), press Enter to run the command and
+Esc to cancel. Requires an interactive terminal.
+
+COMMANDS:
+  info       Show plugin details (plm info)
+  update     Update the plugin (plm update)
+  disable    Disable the plugin (plm disable)
+  uninstall  Uninstall the plugin (plm uninstall)"#
+    )]
+    Pick(pick::Args),
+
+    /// Show resolved environment settings
+    #[command(long_about = r#"Show how PLM resolves its environment.
+
+Prints the PLM state directory, the config file (~/.plm/config.toml),
+proxy environment variables (HTTPS_PROXY, HTTP_PROXY, ALL_PROXY, NO_PROXY)
+and the extra CA bundle (PLM_CA_BUNDLE or [network] ca_bundle).
+Credentials embedded in proxy URLs are masked."#)]
+    Env(env::Args),
+
+    /// Show statistics about installed plugins
+    #[command(
+        long_about = r#"Summarize installed plugins in one pass over the cache.
+
+Shows the number of registered marketplaces, plugins (enabled/disabled),
+components by kind, total cache size, how long ago plugins were last
+updated, and the plugins providing the most components (top 5).
+
+OPTIONS:
+  --project  Only count plugins deployed in the current project
+  --json     Print machine-readable JSON"#
+    )]
+    Stats(stats::Args),
+
+    /// Show placement rules for every target, component kind and scope
+    #[command(
+        long_about = r#"Show where each component kind is deployed for every target.
+
+Lists every combination of target, component kind and scope with the
+placement path template derived from the target implementations.
+Unsupported combinations are shown as "-".
+
+PLACEHOLDERS:
+  {project}      Project root
+  ~              Home directory
+  {marketplace}  Marketplace name
+  {plugin}       Plugin name
+  {name}         Component name
+  Directory placements end with "/".
+
+OPTIONS:
+  --json  Print machine-readable JSON"#
+    )]
+    Placements(placements::Args),
+
+    /// Show how many files in the current project are managed by plm
+    #[command(
+        long_about = r#"Show the files plm has deployed into the current project.
+
+Counts the files and directories listed in .plm/deployed.json, a manifest
+(plugin -> deployed paths relative to the project root) that plm rewrites
+whenever it deploys or removes components, and tells whether .gitignore
+already contains the block generated by `plm gitignore`.
+
+Shared files such as AGENTS.md or hooks.json are not listed because several
+plugins may write to them."#
+    )]
+    Status(status::Args),
+
+    /// Add the files managed by plm to .gitignore
+    #[command(
+        long_about = r#"Write the files listed in .plm/deployed.json to .gitignore.
+
+The patterns are placed in a block between marker comments. Running the
+command again replaces only that block, and removes it when nothing is
+deployed anymore. Teams that commit the deployed files can simply not use
+this command.
+
+OPTIONS:
+  --print  Print the block instead of writing .gitignore"#
+    )]
+    Gitignore(gitignore::Args),
+
+    /// Check the cache, deployed files and marketplaces for problems
+    #[command(
+        long_about = r#"Check the plugin cache, the files deployed into the current project and the
+registered marketplaces for inconsistencies, and list the problems by severity.
+
+Errors:
+  - a cached plugin whose plugin.json cannot be read
+  - a component recorded as deployed into this project that no longer exists
+  - a marketplace cache that cannot be read
+Warnings:
+  - orphaned files: files plm deployed into this project whose plugin is no
+    longer in the cache (only files recorded in ~/.plm/placements.json)
+  - a registered marketplace without a cache, or a cache without a registration
+
+Exits with 1 when any error remains, 0 otherwise (warnings only), so it can
+run in CI.
+
+OPTIONS:
+  --fix          Remove orphaned files and redeploy missing components after confirmation
+  --fix-orphans  Only remove the orphaned files after confirmation
+  -y, --yes      Skip the confirmation prompt"#
+    )]
+    Doctor(doctor::Args),
+
+    /// Check that plugins convert cleanly for every target (no files are written)
+    #[command(
+        long_about = r#"Convert the commands, agents and hooks of every plugin for all targets
+without writing any files, and report the result as a
+plugin x component x target matrix.
+
+Parse errors and conversion errors fail the check (exit 1). Fields that the
+target format cannot represent (e.g. `model` for Codex prompts) are reported
+as warnings.
+
+OPTIONS:
+  -m, --marketplace <NAME>  Verify every plugin listed in a registered marketplace
+  --root <DIR>              Verify a local plugin directory, or every plugin under it
+  --json                    Print machine-readable JSON (for CI)"#
+    )]
+    VerifyConversions(verify_conversions::Args),
+
+    /// Install, verify, disable and uninstall a plugin in a temporary project
+    #[command(
+        long_about = r#"Run a local plugin through its whole lifecycle in a temporary project.
+
+Run this in the plugin repository root. plm scans the plugin and checks its
+conversions (like `pack --check` and `verify-conversions`), installs it for
+every target into a temporary dummy project, checks that the placed files
+exist and that their frontmatter matches each target's format, then disables
+the plugin (the placed files must be gone) and uninstalls it. Your ~/.plm and
+your projects are never touched.
+
+Any failed check makes plm exit with status 1 and lists the failed items.
+
+OPTIONS:
+  --json       Print machine-readable JSON (for CI)
+  --keep-temp  Keep the temporary project and print its location"#
+    )]
+    Test(test_plugin::Args),
+}
+
+/// 引数のパースとヘルプ表示に使う clap コマンド
+///
+/// 各サブコマンドの `--help` 末尾に、[`examples`] の使用例を EXAMPLES セクションとして付ける。
+pub fn command() -> clap::Command {
+    Cli::command().mut_subcommands(|sub| {
+        let help = examples::render_help(examples::examples_for(sub.get_name()));
+        sub.after_help(help)
+    })
 }
 
 #[cfg(test)]
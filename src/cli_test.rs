@@ -207,6 +207,28 @@ fn test_list_outdated_allows_json() {
         .success();
 }
 
+#[test]
+fn cli_list_tree_accepts_depth_and_kind() {
+    let cli = Cli::try_parse_from(["plm", "list", "--tree", "--depth", "1", "--kind", "skill"])
+        .expect("list --tree --depth 1 --kind skill はパース成功する");
+    let Some(CliCommand::List(args)) = cli.command else {
+        panic!("expected list command");
+    };
+    assert!(args.output.tree);
+    assert_eq!(args.output.depth, Some(1));
+    assert_eq!(
+        args.component_type,
+        Some(crate::component::ComponentKind::Skill)
+    );
+}
+
+#[test]
+fn cli_list_depth_requires_tree() {
+    assert!(Cli::try_parse_from(["plm", "list", "--depth", "1"]).is_err());
+    assert!(Cli::try_parse_from(["plm", "list", "--tree", "--depth", "0"]).is_err());
+    assert!(Cli::try_parse_from(["plm", "list", "--tree", "--json"]).is_err());
+}
+
 #[test]
 fn cli_no_args_yields_command_none() {
     let cli = Cli::try_parse_from(["plm"]).expect("plm 単体起動はパース成功する");
@@ -225,7 +247,57 @@ fn cli_verbose_only_yields_command_none() {
 #[test]
 fn cli_managed_explicit_yields_command_managed() {
     let cli = Cli::try_parse_from(["plm", "managed"]).expect("plm managed はパース成功する");
-    assert!(matches!(cli.command, Some(CliCommand::Managed)));
+    assert!(matches!(cli.command, Some(CliCommand::Managed(_))));
+}
+
+#[test]
+fn cli_managed_without_dev_has_no_dev_path() {
+    let cli = Cli::try_parse_from(["plm", "managed"]).expect("plm managed はパース成功する");
+    let Some(CliCommand::Managed(args)) = cli.command else {
+        panic!("expected Managed");
+    };
+    assert!(args.dev.is_none());
+}
+
+#[test]
+fn cli_managed_dev_parses_path() {
+    let cli = Cli::try_parse_from(["plm", "managed", "--dev", "./my-plugin"])
+        .expect("plm managed --dev はパース成功する");
+    let Some(CliCommand::Managed(args)) = cli.command else {
+        panic!("expected Managed");
+    };
+    assert_eq!(args.dev, Some(std::path::PathBuf::from("./my-plugin")));
+}
+
+#[test]
+fn cli_managed_replay_and_record_parse() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "managed",
+        "--replay",
+        "steps.json",
+        "--dump",
+        "screen.txt",
+    ])
+    .expect("plm managed --replay はパース成功する");
+    let Some(CliCommand::Managed(args)) = cli.command else {
+        panic!("expected Managed");
+    };
+    assert_eq!(args.replay, Some(std::path::PathBuf::from("steps.json")));
+    assert_eq!(args.dump, Some(std::path::PathBuf::from("screen.txt")));
+
+    assert!(Cli::try_parse_from(["plm", "managed", "--record", "steps.json"]).is_ok());
+    assert!(Cli::try_parse_from(["plm", "managed", "--dump", "screen.txt"]).is_err());
+    assert!(
+        Cli::try_parse_from(["plm", "managed", "--replay", "a.json", "--record", "b.json"])
+            .is_err()
+    );
+}
+
+#[test]
+fn cli_env_subcommand_parses() {
+    let cli = Cli::try_parse_from(["plm", "env"]).expect("plm env はパース成功する");
+    assert!(matches!(cli.command, Some(CliCommand::Env(_))));
 }
 
 #[test]
@@ -235,6 +307,22 @@ fn cli_install_subcommand_still_parses() {
     assert!(matches!(cli.command, Some(CliCommand::Install(_))));
 }
 
+#[test]
+fn cli_install_dry_run_parses_with_json() {
+    let cli = Cli::try_parse_from(["plm", "install", "owner/repo", "--dry-run", "--json"])
+        .expect("plm install --dry-run --json はパース成功する");
+    let Some(CliCommand::Install(args)) = cli.command else {
+        panic!("expected Install");
+    };
+    assert!(args.dry_run);
+    assert!(args.json);
+
+    assert!(Cli::try_parse_from(["plm", "install", "owner/repo", "--json"]).is_err());
+    assert!(
+        Cli::try_parse_from(["plm", "install", "owner/repo", "--dry-run", "--sandbox"]).is_err()
+    );
+}
+
 #[test]
 fn cli_unknown_flag_still_errors() {
     let result = Cli::try_parse_from(["plm", "--no-such-flag"]);
@@ -258,3 +346,601 @@ fn cli_managed_help_displays_help() {
     let err = Cli::try_parse_from(["plm", "managed", "--help"]).unwrap_err();
     assert_eq!(err.kind(), clap::error::ErrorKind::DisplayHelp);
 }
+
+#[test]
+fn cli_init_components_without_type_parses() {
+    let cli = Cli::try_parse_from(["plm", "init", "my-plugin", "--components", "hooks"])
+        .expect("plm init --components はパース成功する");
+    let Some(CliCommand::Init(args)) = cli.command else {
+        panic!("expected Init");
+    };
+    assert!(args.component_type.is_none());
+    assert_eq!(
+        args.components,
+        vec![crate::commands::manage::init::ScaffoldComponent::Hooks]
+    );
+}
+
+#[test]
+fn cli_init_requires_type_or_components() {
+    assert!(Cli::try_parse_from(["plm", "init", "my-plugin"]).is_err());
+}
+
+#[test]
+fn cli_import_from_parses_without_source() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "import",
+        "--from",
+        "claude-code",
+        "--output",
+        "./out",
+        "--dry-run",
+    ])
+    .expect("plm import --from はソース無しでパース成功する");
+    let Some(CliCommand::Import(args)) = cli.command else {
+        panic!("expected Import");
+    };
+    assert!(args.source.is_none());
+    assert!(args.from.is_some());
+    assert!(args.dry_run);
+}
+
+#[test]
+fn cli_import_from_requires_output_and_rejects_source() {
+    assert!(Cli::try_parse_from(["plm", "import", "--from", "copilot"]).is_err());
+    assert!(Cli::try_parse_from([
+        "plm",
+        "import",
+        "owner/repo",
+        "--from",
+        "copilot",
+        "--output",
+        "./out"
+    ])
+    .is_err());
+    assert!(Cli::try_parse_from(["plm", "import"]).is_err());
+}
+
+#[test]
+fn cli_hooks_test_parses_event_payload_and_target() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "hooks",
+        "test",
+        "./scripts/pre-tool-use.sh",
+        "--event",
+        "PreToolUse",
+        "--payload",
+        "sample.json",
+        "--target",
+        "copilot",
+    ])
+    .expect("plm hooks test はパース成功する");
+    let Some(CliCommand::Hooks(args)) = cli.command else {
+        panic!("expected Hooks");
+    };
+    let crate::commands::manage::hooks::Command::Test {
+        script,
+        event,
+        payload,
+        target,
+    } = args.command;
+    assert_eq!(script, "./scripts/pre-tool-use.sh");
+    assert_eq!(event, "PreToolUse");
+    assert_eq!(payload.as_deref(), Some("sample.json"));
+    assert_eq!(target, Some(crate::target::TargetKind::Copilot));
+}
+
+#[test]
+fn cli_update_follow_renames_parses() {
+    let cli = Cli::try_parse_from(["plm", "update", "--all", "--follow-renames"])
+        .expect("plm update --follow-renames はパース成功する");
+    let Some(CliCommand::Update(args)) = cli.command else {
+        panic!("expected Update");
+    };
+    assert!(args.all);
+    assert!(args.follow_renames);
+}
+
+#[test]
+fn cli_update_channel_parses_and_requires_single_plugin() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "update",
+        "formatter",
+        "--channel",
+        "stable",
+        "--allow-downgrade",
+    ])
+    .expect("plm update --channel はパース成功する");
+    let Some(CliCommand::Update(args)) = cli.command else {
+        panic!("expected Update");
+    };
+    assert_eq!(args.channel, Some(crate::plugin::Channel::Stable));
+    assert!(args.allow_downgrade);
+
+    assert!(Cli::try_parse_from(["plm", "update", "--all", "--channel", "beta"]).is_err());
+    assert!(Cli::try_parse_from(["plm", "update", "formatter", "--allow-downgrade"]).is_err());
+    assert!(Cli::try_parse_from(["plm", "install", "owner/repo", "--channel", "nightly"]).is_err());
+}
+
+#[test]
+fn cli_update_redeploy_only_parses_only_kinds() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "update",
+        "formatter",
+        "--redeploy-only",
+        "--only",
+        "commands,skill",
+    ])
+    .expect("plm update --redeploy-only --only はパース成功する");
+    let Some(CliCommand::Update(args)) = cli.command else {
+        panic!("expected Update");
+    };
+    assert!(args.redeploy_only);
+    assert_eq!(
+        args.only,
+        Some(vec![
+            crate::component::ComponentKind::Command,
+            crate::component::ComponentKind::Skill
+        ])
+    );
+
+    assert!(Cli::try_parse_from(["plm", "update", "--all", "--redeploy-only"]).is_ok());
+    assert!(Cli::try_parse_from(["plm", "update", "formatter", "--only", "commands"]).is_err());
+    assert!(Cli::try_parse_from([
+        "plm",
+        "update",
+        "formatter",
+        "--redeploy-only",
+        "--channel",
+        "beta"
+    ])
+    .is_err());
+}
+
+#[test]
+fn cli_install_target_auto_parses() {
+    let cli = Cli::try_parse_from([
+        "plm",
+        "install",
+        "owner/repo",
+        "--target",
+        "auto",
+        "--target",
+        "codex",
+    ])
+    .expect("plm install --target auto はパース成功する");
+    let Some(CliCommand::Install(args)) = cli.command else {
+        panic!("expected Install");
+    };
+    assert_eq!(
+        args.target.target,
+        Some(vec![
+            crate::commands::args::TargetSpec::Auto,
+            crate::commands::args::TargetSpec::Kind(crate::target::TargetKind::Codex),
+        ])
+    );
+}
+
+#[test]
+fn cli_no_http_cache_is_global() {
+    let cli = Cli::try_parse_from(["plm", "list", "--no-http-cache"])
+        .expect("--no-http-cache はサブコマンドの後でもパース成功する");
+    assert!(cli.no_http_cache);
+}
+
+#[test]
+fn cli_offline_is_global() {
+    let cli = Cli::try_parse_from(["plm", "managed", "--offline"])
+        .expect("--offline はサブコマンドの後でもパース成功する");
+    assert!(cli.offline);
+    assert!(!Cli::try_parse_from(["plm", "list"]).unwrap().offline);
+}
+
+#[test]
+fn cli_wait_is_global() {
+    let cli = Cli::try_parse_from(["plm", "uninstall", "foo", "--wait"])
+        .expect("--wait はサブコマンドの後でもパース成功する");
+    assert!(cli.wait);
+    assert!(!Cli::try_parse_from(["plm", "list"]).unwrap().wait);
+}
+
+#[test]
+fn cli_quiet_is_global() {
+    let cli = Cli::try_parse_from(["plm", "install", "owner/repo", "--quiet"])
+        .expect("--quiet はサブコマンドの後でもパース成功する");
+    assert!(cli.quiet);
+    assert!(!Cli::try_parse_from(["plm", "list"]).unwrap().quiet);
+}
+
+#[test]
+fn cli_pick_parses_action() {
+    use crate::commands::manage::pick::PickAction;
+
+    let cli = Cli::try_parse_from(["plm", "pick", "uninstall"]).expect("plm pick はパース成功する");
+    let Some(CliCommand::Pick(args)) = cli.command else {
+        panic!("expected Pick");
+    };
+    assert_eq!(args.action, PickAction::Uninstall);
+    assert!(Cli::try_parse_from(["plm", "pick", "install"]).is_err());
+}
+
+#[test]
+fn cli_update_notify_parses() {
+    let cli = Cli::try_parse_from(["plm", "update", "--all", "--notify"])
+        .expect("plm update --notify はパース成功する");
+    let Some(CliCommand::Update(args)) = cli.command else {
+        panic!("expected Update");
+    };
+    assert!(args.notify.notify);
+}
+
+#[test]
+fn cli_stats_parses_flags() {
+    let cli = Cli::try_parse_from(["plm", "stats", "--project", "--json"])
+        .expect("plm stats --project --json はパース成功する");
+    let Some(CliCommand::Stats(args)) = cli.command else {
+        panic!("expected Stats");
+    };
+    assert!(args.project);
+    assert!(args.json);
+}
+
+#[test]
+fn cli_marketplace_add_allow_duplicate_source_parses() {
+    use crate::commands::manage::marketplace::Command as MarketplaceCommand;
+
+    let cli = Cli::try_parse_from([
+        "plm",
+        "marketplace",
+        "add",
+        "owner/repo",
+        "--allow-duplicate-source",
+    ])
+    .expect("marketplace add --allow-duplicate-source はパース成功する");
+    let Some(CliCommand::Marketplace(args)) = cli.command else {
+        panic!("expected Marketplace");
+    };
+    let MarketplaceCommand::Add {
+        allow_duplicate_source,
+        ..
+    } = args.command
+    else {
+        panic!("expected Add");
+    };
+    assert!(allow_duplicate_source);
+}
+
+#[test]
+fn cli_disable_keep_parses_comma_separated_kinds() {
+    use crate::component::ComponentKind;
+
+    let cli = Cli::try_parse_from(["plm", "disable", "foo", "--keep", "instructions,skills"])
+        .expect("plm disable --keep はパース成功する");
+    let Some(CliCommand::Disable(args)) = cli.command else {
+        panic!("expected Disable");
+    };
+    assert_eq!(
+        args.keep,
+        Some(vec![ComponentKind::Instruction, ComponentKind::Skill])
+    );
+}
+
+#[test]
+fn cli_stdin_replaces_the_plugin_name_argument() {
+    for command in ["disable", "enable", "update"] {
+        let cli = Cli::try_parse_from(["plm", command, "--stdin"])
+            .unwrap_or_else(|e| panic!("plm {} --stdin はパース成功する: {}", command, e));
+        let stdin = match cli.command {
+            Some(CliCommand::Disable(args)) => args.stdin.stdin,
+            Some(CliCommand::Enable(args)) => args.stdin.stdin,
+            Some(CliCommand::Update(args)) => args.stdin.stdin,
+            other => panic!("unexpected command: {:?}", other),
+        };
+        assert!(stdin);
+
+        assert!(
+            Cli::try_parse_from(["plm", command, "formatter", "--stdin"]).is_err(),
+            "plm {} は --stdin と位置引数を同時に受け付けない",
+            command
+        );
+    }
+
+    assert!(Cli::try_parse_from(["plm", "disable"]).is_err());
+    assert!(Cli::try_parse_from(["plm", "update", "--all", "--stdin"]).is_err());
+}
+
+#[test]
+fn cli_pin_and_unpin_parse_marketplace() {
+    let cli = Cli::try_parse_from(["plm", "pin", "formatter", "-m", "company-tools"])
+        .expect("plm pin はパース成功する");
+    let Some(CliCommand::Pin(args)) = cli.command else {
+        panic!("expected Pin");
+    };
+    assert_eq!(args.name, "formatter");
+    assert_eq!(
+        args.marketplace.marketplace.as_deref(),
+        Some("company-tools")
+    );
+
+    let cli =
+        Cli::try_parse_from(["plm", "unpin", "formatter"]).expect("plm unpin はパース成功する");
+    let Some(CliCommand::Unpin(args)) = cli.command else {
+        panic!("expected Unpin");
+    };
+    assert_eq!(args.name, "formatter");
+}
+
+#[test]
+fn cli_marketplace_update_plugins_parses() {
+    use crate::commands::manage::marketplace::Command as MarketplaceCommand;
+
+    let cli = Cli::try_parse_from(["plm", "marketplace", "update", "company-tools", "--plugins"])
+        .expect("marketplace update --plugins はパース成功する");
+    let Some(CliCommand::Marketplace(args)) = cli.command else {
+        panic!("expected Marketplace");
+    };
+    let MarketplaceCommand::Update {
+        name, no_plugins, ..
+    } = args.command
+    else {
+        panic!("expected Update");
+    };
+    assert_eq!(name.as_deref(), Some("company-tools"));
+    assert!(!no_plugins);
+}
+
+#[test]
+fn cli_marketplace_update_updates_bundled_plugins_unless_opted_out() {
+    use crate::commands::manage::marketplace::Command as MarketplaceCommand;
+
+    let no_plugins = |args: &[&str]| {
+        let cli = Cli::try_parse_from(args).expect("marketplace update はパース成功する");
+        let Some(CliCommand::Marketplace(args)) = cli.command else {
+            panic!("expected Marketplace");
+        };
+        let MarketplaceCommand::Update { no_plugins, .. } = args.command else {
+            panic!("expected Update");
+        };
+        no_plugins
+    };
+    assert!(!no_plugins(&["plm", "marketplace", "update"]));
+    assert!(no_plugins(&[
+        "plm",
+        "marketplace",
+        "update",
+        "--no-plugins"
+    ]));
+    assert!(!no_plugins(&[
+        "plm",
+        "marketplace",
+        "update",
+        "--no-plugins",
+        "--plugins"
+    ]));
+}
+
+#[test]
+fn cli_install_and_enable_parse_prefix() {
+    let cli = Cli::try_parse_from(["plm", "install", "owner/repo", "--prefix", "foo:"])
+        .expect("plm install --prefix はパース成功する");
+    let Some(CliCommand::Install(args)) = cli.command else {
+        panic!("expected Install");
+    };
+    assert_eq!(args.prefix.prefix.as_deref(), Some("foo:"));
+
+    let cli = Cli::try_parse_from(["plm", "enable", "formatter", "--prefix", ""])
+        .expect("plm enable --prefix '' はパース成功する");
+    let Some(CliCommand::Enable(args)) = cli.command else {
+        panic!("expected Enable");
+    };
+    assert_eq!(args.prefix.prefix.as_deref(), Some(""));
+
+    assert!(Cli::try_parse_from(["plm", "install", "owner/repo", "--prefix", "a/b"]).is_err());
+}
+
+#[test]
+fn cli_doctor_parses_flags() {
+    let cli = Cli::try_parse_from(["plm", "doctor", "--fix-orphans", "-y"])
+        .expect("plm doctor --fix-orphans -y はパース成功する");
+    let Some(CliCommand::Doctor(args)) = cli.command else {
+        panic!("expected Doctor");
+    };
+    assert!(args.fix_orphans);
+    assert!(args.yes);
+
+    assert!(
+        Cli::try_parse_from(["plm", "doctor", "--yes"]).is_err(),
+        "--yes は --fix / --fix-orphans と併用する"
+    );
+
+    let cli = Cli::try_parse_from(["plm", "doctor", "--fix", "--yes"])
+        .expect("plm doctor --fix --yes はパース成功する");
+    let Some(CliCommand::Doctor(args)) = cli.command else {
+        panic!("expected Doctor");
+    };
+    assert!(args.fix && args.yes);
+    assert!(
+        Cli::try_parse_from(["plm", "doctor", "--fix", "--fix-orphans"]).is_err(),
+        "--fix は --fix-orphans を含む"
+    );
+}
+
+#[test]
+fn cli_verify_conversions_requires_one_source() {
+    let cli = Cli::try_parse_from(["plm", "verify-conversions", "-m", "mp-a", "--json"])
+        .expect("plm verify-conversions -m mp-a --json はパース成功する");
+    let Some(CliCommand::VerifyConversions(args)) = cli.command else {
+        panic!("expected VerifyConversions");
+    };
+    assert_eq!(args.marketplace.as_deref(), Some("mp-a"));
+    assert!(args.json);
+
+    assert!(Cli::try_parse_from(["plm", "verify-conversions", "--root", "./plugins"]).is_ok());
+    assert!(
+        Cli::try_parse_from(["plm", "verify-conversions"]).is_err(),
+        "--marketplace か --root のどちらかが必要"
+    );
+    assert!(
+        Cli::try_parse_from(["plm", "verify-conversions", "-m", "a", "--root", "."]).is_err(),
+        "--marketplace と --root は同時に指定できない"
+    );
+}
+
+#[test]
+fn cli_install_update_sync_parse_github_output() {
+    let cli = Cli::try_parse_from(["plm", "install", "owner/repo", "--github-output"])
+        .expect("plm install --github-output はパース成功する");
+    let Some(CliCommand::Install(args)) = cli.command else {
+        panic!("expected Install");
+    };
+    assert!(args.github.github_output);
+
+    let cli = Cli::try_parse_from(["plm", "update", "--all", "--github-output"])
+        .expect("plm update --github-output はパース成功する");
+    let Some(CliCommand::Update(args)) = cli.command else {
+        panic!("expected Update");
+    };
+    assert!(args.github.github_output);
+
+    let cli = Cli::try_parse_from([
+        "plm",
+        "sync",
+        "--from",
+        "codex",
+        "--to",
+        "copilot",
+        "--github-output",
+    ])
+    .expect("plm sync --github-output はパース成功する");
+    let Some(CliCommand::Sync(args)) = cli.command else {
+        panic!("expected Sync");
+    };
+    assert!(args.github.github_output);
+}
+
+#[test]
+fn cli_install_sandbox_conflicts_with_promote() {
+    let cli = Cli::try_parse_from(["plm", "install", "owner/repo", "--sandbox"])
+        .expect("plm install --sandbox はパース成功する");
+    let Some(CliCommand::Install(args)) = cli.command else {
+        panic!("expected Install");
+    };
+    assert!(args.sandbox);
+    assert!(!args.promote);
+
+    assert!(
+        Cli::try_parse_from(["plm", "install", "owner/repo", "--sandbox", "--promote"]).is_err()
+    );
+}
+
+#[test]
+fn cli_test_defaults_to_current_directory() {
+    let cli = Cli::try_parse_from(["plm", "test"]).expect("plm test はパース成功する");
+    let Some(CliCommand::Test(args)) = cli.command else {
+        panic!("expected Test");
+    };
+    assert_eq!(args.path, std::path::PathBuf::from("."));
+    assert!(!args.json);
+    assert!(!args.keep_temp);
+
+    let cli = Cli::try_parse_from(["plm", "test", "./my-plugin", "--json", "--keep-temp"])
+        .expect("plm test ./my-plugin --json --keep-temp はパース成功する");
+    let Some(CliCommand::Test(args)) = cli.command else {
+        panic!("expected Test");
+    };
+    assert_eq!(args.path, std::path::PathBuf::from("./my-plugin"));
+    assert!(args.json && args.keep_temp);
+}
+
+#[test]
+fn cli_sync_check_parses_and_rejects_target_options() {
+    let cli = Cli::try_parse_from(["plm", "sync", "--check", "--json"])
+        .expect("plm sync --check --json はパース成功する");
+    let Some(CliCommand::Sync(args)) = cli.command else {
+        panic!("expected Sync");
+    };
+    assert!(args.check && args.json);
+
+    assert!(
+        Cli::try_parse_from(["plm", "sync", "--check", "--from", "codex", "--to", "copilot"])
+            .is_err(),
+        "--check は plm.lock と比較するため --from / --to とは同時に指定できない"
+    );
+    assert!(
+        Cli::try_parse_from(["plm", "sync", "--check", "--frozen"]).is_err(),
+        "--check と --frozen は同時に指定できない"
+    );
+    assert!(
+        Cli::try_parse_from(["plm", "sync", "--from", "codex", "--to", "copilot", "--json"])
+            .is_err(),
+        "--json は --check と一緒にのみ指定できる"
+    );
+}
+
+#[test]
+fn cli_backup_flag_and_backups_restore_parse() {
+    let cli = Cli::try_parse_from(["plm", "uninstall", "formatter", "--backup", "--force"])
+        .expect("plm uninstall --backup はパース成功する");
+    let Some(CliCommand::Uninstall(args)) = cli.command else {
+        panic!("expected Uninstall");
+    };
+    assert!(args.backup.backup);
+
+    let cli = Cli::try_parse_from([
+        "plm",
+        "backups",
+        "restore",
+        "20261016T120000Z-uninstall",
+        "-y",
+    ])
+    .expect("plm backups restore <id> -y はパース成功する");
+    let Some(CliCommand::Backups(args)) = cli.command else {
+        panic!("expected Backups");
+    };
+    let crate::commands::manage::backups::Command::Restore { id, yes } = args.command else {
+        panic!("expected Restore");
+    };
+    assert_eq!(id, "20261016T120000Z-uninstall");
+    assert!(yes);
+}
+
+#[test]
+fn cli_cache_rebuild_index_parses() {
+    let cli = Cli::try_parse_from(["plm", "cache", "rebuild-index"])
+        .expect("plm cache rebuild-index はパース成功する");
+    let Some(CliCommand::Cache(args)) = cli.command else {
+        panic!("expected Cache");
+    };
+    assert!(matches!(
+        args.command,
+        crate::commands::manage::cache::Command::RebuildIndex
+    ));
+}
+
+#[test]
+fn cli_project_root_is_global() {
+    let cli = Cli::try_parse_from(["plm", "list", "--project-root", "/work/app"])
+        .expect("--project-root はサブコマンドの後でも受け付ける");
+    assert_eq!(
+        cli.project_root.as_deref(),
+        Some(std::path::Path::new("/work/app"))
+    );
+
+    let cli = Cli::try_parse_from(["plm", "--project-root", "/work/app", "list"])
+        .expect("--project-root はサブコマンドの前でも受け付ける");
+    assert!(cli.project_root.is_some());
+}
+
+#[test]
+fn test_missing_project_root_fails_fast() {
+    ProcessCommand::cargo_bin("plm")
+        .unwrap()
+        .args(["list", "--project-root", "/nonexistent/plm-project-root"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Project root does not exist"));
+}
@@ -2,13 +2,17 @@ use crate::cli::Command;
 use crate::error::PlmError;
 use clap::CommandFactory;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 
 pub(crate) mod args;
 pub mod deploy;
+pub(crate) mod did_you_mean;
+pub mod examples;
 pub mod info;
 pub mod lifecycle;
 pub mod list;
 pub mod manage;
+pub mod search;
 
 /// サブコマンド省略時のデフォルトアクション。
 ///
@@ -27,39 +31,119 @@ pub(crate) fn decide_default_action(stdout_is_tty: bool) -> DefaultAction {
     }
 }
 
+/// サブコマンド共通の実行コンテキスト
+///
+/// グローバル引数から 1 度だけ解決した値を各コマンドの `run()` に渡す。
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// 配置・走査の基準にするプロジェクトルート
+    pub project_root: PathBuf,
+}
+
+impl Context {
+    /// `--project-root`（`PLM_PROJECT_ROOT`）→ 自動検出 → カレントディレクトリの順に解決する
+    ///
+    /// 指定されたプロジェクトルートが存在しない場合はエラー。
+    pub fn resolve() -> Result<Self, String> {
+        let project_root = crate::env::project_root().map_err(|e| e.to_string())?;
+        Ok(Self { project_root })
+    }
+}
+
 /// Dispatch the parsed CLI command to the matching handler.
 ///
 /// # Arguments
 ///
 /// * `cli` - Parsed top-level CLI invocation containing the selected subcommand.
-pub async fn dispatch(cli: crate::cli::Cli) -> Result<(), PlmError> {
+/// * `name` - Name of the selected subcommand as parsed by clap, if any.
+pub async fn dispatch(cli: crate::cli::Cli, name: Option<&str>) -> Result<(), PlmError> {
+    let ctx = Context::resolve().map_err(PlmError::General)?;
     let result: Result<(), String> = match cli.command {
-        Some(Command::Target(args)) => manage::target::run(args).await,
-        Some(Command::Install(args)) => deploy::install::run(args).await,
+        Some(Command::Target(args)) => manage::target::run(args, &ctx).await,
+        Some(Command::Install(args)) => deploy::install::run(args, &ctx).await,
         Some(Command::List(args)) => list::run(args).await,
         Some(Command::Info(args)) => info::run(args).await,
-        Some(Command::Enable(args)) => lifecycle::enable::run(args).await,
-        Some(Command::Disable(args)) => lifecycle::disable::run(args).await,
-        Some(Command::Uninstall(args)) => lifecycle::uninstall::run(args).await,
-        Some(Command::Update(args)) => lifecycle::update::run(args).await,
+        Some(Command::Search(args)) => search::run(args).await,
+        Some(Command::Enable(args)) => lifecycle::enable::run(args, &ctx).await,
+        Some(Command::Disable(args)) => lifecycle::disable::run(args, &ctx).await,
+        Some(Command::Uninstall(args)) => lifecycle::uninstall::run(args, &ctx).await,
+        Some(Command::Update(args)) => lifecycle::update::run(args, &ctx).await,
+        Some(Command::Pin(args)) => lifecycle::pin::run(args).await,
+        Some(Command::Unpin(args)) => lifecycle::unpin::run(args).await,
         Some(Command::Init(args)) => manage::init::run(args).await,
         Some(Command::Pack(args)) => manage::pack::run(args).await,
+        Some(Command::Backups(args)) => manage::backups::run(args).await,
+        Some(Command::Cache(args)) => manage::cache::run(args).await,
+        Some(Command::Hooks(args)) => manage::hooks::run(args).await,
         Some(Command::Link(args)) => deploy::link::run(args).await,
         Some(Command::Unlink(args)) => deploy::unlink::run(args).await,
-        Some(Command::Sync(args)) => deploy::sync::run(args).await,
-        Some(Command::Import(args)) => deploy::import::run(args).await,
+        Some(Command::Sync(args)) => deploy::sync::run(args, &ctx).await,
+        Some(Command::Refresh(args)) => deploy::refresh::run(args, &ctx).await,
+        Some(Command::Import(args)) => deploy::import::run(args, &ctx).await,
         Some(Command::Marketplace(args)) => manage::marketplace::run(args).await,
         // 明示呼び出しは従来通り（非TTYでもフォールバックしない=後方互換）
-        Some(Command::Managed) => manage::managed::run().await,
+        Some(Command::Managed(args)) => manage::managed::run(args).await,
+        Some(Command::Pick(args)) => manage::pick::run(args, &ctx).await,
+        Some(Command::Env(args)) => manage::env::run(args).await,
+        Some(Command::Stats(args)) => manage::stats::run(args, &ctx).await,
+        Some(Command::Placements(args)) => manage::placements::run(args).await,
+        Some(Command::Status(args)) => manage::status::run(args, &ctx).await,
+        Some(Command::Gitignore(args)) => manage::gitignore::run(args, &ctx).await,
+        Some(Command::Doctor(args)) => manage::doctor::run(args, &ctx).await,
+        Some(Command::VerifyConversions(args)) => manage::verify_conversions::run(args).await,
+        Some(Command::Test(args)) => manage::test_plugin::run(args).await,
         // サブコマンド省略時のみ TTY 判定でフォールバック
         None => run_default(std::io::stdout().is_terminal()).await,
     };
-    result.map_err(PlmError::General)
+    result.map_err(|message| PlmError::General(with_example_hint(message, name)))
+}
+
+/// 「見つからない」系のエラーにそのコマンドの代表例を添える
+///
+/// # Arguments
+///
+/// * `message` - Error message returned by the command handler.
+/// * `subcommand` - Subcommand that failed, if any.
+pub(crate) fn with_example_hint(message: String, subcommand: Option<&str>) -> String {
+    match subcommand
+        .filter(|_| examples::is_not_found(&message))
+        .and_then(examples::hint)
+    {
+        Some(hint) => format!("{}\n\n{}", message, hint),
+        None => message,
+    }
+}
+
+/// 引数パースエラーを表示して終了する
+///
+/// `--help` / `--version` 以外のエラーでは、該当サブコマンドの代表例を添える。
+///
+/// # Arguments
+///
+/// * `err` - Error returned by `Cli::try_parse`.
+pub fn exit_with_parse_error(err: clap::Error) -> ! {
+    use clap::error::ErrorKind;
+
+    if matches!(
+        err.kind(),
+        ErrorKind::DisplayHelp
+            | ErrorKind::DisplayVersion
+            | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+    ) {
+        err.exit();
+    }
+
+    let _ = err.print();
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(hint) = examples::subcommand_in_args(&args).and_then(|name| examples::hint(&name)) {
+        eprintln!("\n{}", hint);
+    }
+    std::process::exit(err.exit_code());
 }
 
 async fn run_default(stdout_is_tty: bool) -> Result<(), String> {
     match decide_default_action(stdout_is_tty) {
-        DefaultAction::LaunchManaged => manage::managed::run().await,
+        DefaultAction::LaunchManaged => manage::managed::run(Default::default()).await,
         DefaultAction::PrintHelp => {
             crate::cli::Cli::command()
                 .print_help()
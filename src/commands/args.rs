@@ -3,12 +3,27 @@
 //! 用途別にサブモジュールへ分割し、本ファイルは再エクスポートのみを行う。
 //! 旧パス `crate::commands::args::{ListOutputArgs, ...}` は `pub use` 経由で維持する。
 
+mod backup;
+mod component;
+mod github;
 mod marketplace;
+mod notify;
 mod output;
+mod prefix;
 mod scope;
+mod stdin;
 mod target;
 
+pub use backup::BackupArgs;
+pub use component::ComponentArgs;
+pub use github::GithubOutputArgs;
 pub use marketplace::MarketplaceArgs;
-pub use output::ListOutputArgs;
+pub use notify::NotifyArgs;
+pub use output::{ListOutputArgs, OutputFormat};
+pub use prefix::CommandPrefixArgs;
 pub use scope::{InteractiveScopeArgs, SyncScopeArgs};
+pub use stdin::{PluginSpec, StdinArgs, StdinBatch};
 pub use target::{MultiTargetArgs, SingleTargetArgs};
+// Re-exported for tests
+#[cfg(test)]
+pub use target::TargetSpec;
@@ -0,0 +1,58 @@
+//! `--backup` オプション用の共通 Args 部品。
+
+use crate::backup::{BackupOperation, BackupStore, DEFAULT_KEEP};
+use crate::config::ConfigFile;
+use clap::Args as ClapArgs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct BackupArgs {
+    /// Save the files this command changes to ~/.local/state/plm/backups first
+    /// (restore with `plm backups restore <ID>`)
+    #[arg(long)]
+    pub backup: bool,
+}
+
+impl BackupArgs {
+    /// `--backup` 指定時だけ、操作前のスナップショットを作る
+    ///
+    /// バックアップに失敗した場合は操作を始めずにエラーを返す。作成後は
+    /// `[backup] keep`（既定 10）を超えた古い世代を削除する（失敗しても警告のみ）。
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Operation about to run.
+    /// * `description` - Human readable summary, e.g. `plm uninstall formatter`.
+    /// * `paths` - Returns the files or directories the operation may change or remove
+    ///   (only called with `--backup`).
+    pub fn snapshot(
+        &self,
+        operation: BackupOperation,
+        description: &str,
+        paths: impl FnOnce() -> Vec<PathBuf>,
+    ) -> Result<(), String> {
+        if !self.backup {
+            return Ok(());
+        }
+        let store = BackupStore::new().map_err(|e| format!("Failed to create backup: {}", e))?;
+        let manifest = store
+            .create(operation, description, &paths())
+            .map_err(|e| format!("Failed to create backup: {}", e))?;
+        println!(
+            "Backup: {} ({} file(s); restore with 'plm backups restore {}')",
+            manifest.id,
+            manifest.files.len(),
+            manifest.id
+        );
+
+        let keep = ConfigFile::load_default()
+            .ok()
+            .and_then(|config| config.backup.keep)
+            .unwrap_or(DEFAULT_KEEP);
+        // 今作ったバックアップは必ず残す
+        if let Err(e) = store.prune(keep.max(1)) {
+            eprintln!("Warning: Failed to remove old backups: {}", e);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! `--component` オプション用の共通 Args 部品。
+
+use crate::application::ComponentSelector;
+use crate::component::ComponentKind;
+use clap::{Args as ClapArgs, ValueEnum};
+
+/// `--component <kind>:<name>` の値をパースする
+///
+/// # Arguments
+///
+/// * `s` - Raw `--component` value (e.g. `skill:code-review`).
+fn parse_component(s: &str) -> Result<ComponentSelector, String> {
+    let (kind, name) = s
+        .split_once(':')
+        .ok_or_else(|| format!("invalid component '{}' (expected <kind>:<name>)", s))?;
+    let kind = ComponentKind::from_str(kind.trim(), true).map_err(|_| {
+        let kinds: Vec<&str> = ComponentKind::all().iter().map(|k| k.as_str()).collect();
+        format!(
+            "invalid component kind '{}' (possible values: {})",
+            kind,
+            kinds.join(", ")
+        )
+    })?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing component name in '{}'", s));
+    }
+    Ok(ComponentSelector {
+        kind,
+        name: name.to_string(),
+    })
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct ComponentArgs {
+    /// Only this component of the plugin, given as <kind>:<name> (e.g. skill:code-review)
+    #[arg(long, value_name = "KIND:NAME", value_parser = parse_component, conflicts_with = "target")]
+    pub component: Option<ComponentSelector>,
+}
+
+#[cfg(test)]
+#[path = "component_test.rs"]
+mod tests;
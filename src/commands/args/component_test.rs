@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn parse_component_accepts_kind_and_name() {
+    let selector = parse_component("skill:code-review").unwrap();
+    assert_eq!(selector.kind, ComponentKind::Skill);
+    assert_eq!(selector.name, "code-review");
+
+    let selector = parse_component("Commands:deploy").unwrap();
+    assert_eq!(selector.kind, ComponentKind::Command);
+}
+
+#[test]
+fn parse_component_rejects_malformed_values() {
+    assert!(parse_component("code-review")
+        .unwrap_err()
+        .contains("expected <kind>:<name>"));
+    assert!(parse_component("widget:x")
+        .unwrap_err()
+        .contains("possible values: skill, agent, command, instruction, hook"));
+    assert!(parse_component("skill:")
+        .unwrap_err()
+        .contains("missing component name"));
+}
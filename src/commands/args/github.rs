@@ -0,0 +1,19 @@
+//! `--github-output` オプション用の共通 Args 部品。
+
+use crate::output::github::GithubReporter;
+use clap::Args as ClapArgs;
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct GithubOutputArgs {
+    /// Emit warnings and errors as GitHub Actions annotations and append a result
+    /// table to $GITHUB_STEP_SUMMARY (enabled automatically when GITHUB_ACTIONS=true)
+    #[arg(long)]
+    pub github_output: bool,
+}
+
+impl GithubOutputArgs {
+    /// フラグと実行環境から出力先を決める
+    pub fn reporter(&self) -> GithubReporter {
+        GithubReporter::from_env(self.github_output)
+    }
+}
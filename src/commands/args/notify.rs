@@ -0,0 +1,36 @@
+//! `--notify` オプション用の共通 Args 部品。
+
+use crate::config::ConfigFile;
+use crate::output::notify::send_notification;
+use clap::Args as ClapArgs;
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct NotifyArgs {
+    /// Show a desktop notification when the command finishes
+    /// (always on with `[notify] enabled = true` in ~/.plm/config.toml)
+    #[arg(long)]
+    pub notify: bool,
+}
+
+impl NotifyArgs {
+    /// フラグまたは設定ファイルで通知が有効か
+    ///
+    /// 設定ファイルが読めない場合はフラグだけで判断する（通知のためにコマンドを失敗させない）。
+    pub fn enabled(&self) -> bool {
+        self.notify
+            || ConfigFile::load_default()
+                .map(|config| config.notify.enabled)
+                .unwrap_or(false)
+    }
+
+    /// 通知が有効なら完了通知を送る
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - Notification body, e.g. `5 plugins updated, 1 failed`.
+    pub fn send(&self, body: &str) {
+        if self.enabled() {
+            send_notification(body);
+        }
+    }
+}
@@ -1,6 +1,14 @@
 //! サブコマンド間で共有するCLI引数部品。
 
-use clap::Args as ClapArgs;
+use clap::{Args as ClapArgs, ValueEnum};
+
+/// 出力形式（`info --format` / `list --format`）
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
 
 #[derive(Debug, Clone, ClapArgs)]
 pub struct ListOutputArgs {
@@ -15,4 +23,16 @@ pub struct ListOutputArgs {
     /// Show only plugins with available updates. Note: --json includes all plugins with update info.
     #[arg(long, conflicts_with = "simple")]
     pub outdated: bool,
+
+    /// Show each plugin as a tree of component kinds and names
+    #[arg(long, conflicts_with_all = ["json", "simple", "outdated"])]
+    pub tree: bool,
+
+    /// Tree depth; 1 shows only the number of components per kind
+    #[arg(long, requires = "tree", value_parser = clap::value_parser!(u8).range(1..))]
+    pub depth: Option<u8>,
+
+    /// Output format; json / yaml print one entry per plugin with component counts
+    #[arg(long, value_enum, conflicts_with_all = ["json", "simple", "outdated", "tree"])]
+    pub format: Option<OutputFormat>,
 }
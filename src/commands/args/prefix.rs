@@ -0,0 +1,54 @@
+//! `--prefix` オプション用の共通 Args 部品。
+
+use crate::component::CommandPrefix;
+use crate::plugin::meta;
+use clap::Args as ClapArgs;
+use std::path::Path;
+
+/// `--prefix` の値を検証する（空文字はプレフィクスの解除）
+///
+/// # Arguments
+///
+/// * `s` - Raw `--prefix` value.
+fn parse_prefix(s: &str) -> Result<String, String> {
+    CommandPrefix::parse(s)?;
+    Ok(s.trim().to_string())
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct CommandPrefixArgs {
+    /// Prefix added to deployed command names (e.g. "foo:" deploys /foo-deploy);
+    /// pass "" to remove it
+    #[arg(long, value_parser = parse_prefix)]
+    pub prefix: Option<String>,
+}
+
+impl CommandPrefixArgs {
+    /// 指定があれば `.plm-meta.json` の `commandPrefix` を更新する
+    ///
+    /// 実際に設定が変わった場合は `true` を返す。デプロイ済みのコマンドは次回の
+    /// enable / update で新しい名前へ移行される。
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_path` - Filesystem path of the cached plugin.
+    pub fn apply_to(&self, plugin_path: &Path) -> Result<bool, String> {
+        let Some(prefix) = &self.prefix else {
+            return Ok(false);
+        };
+        let desired = (!prefix.is_empty()).then(|| prefix.clone());
+
+        let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+        if plugin_meta.command_prefix == desired {
+            return Ok(false);
+        }
+        plugin_meta.command_prefix = desired;
+        meta::write_meta(plugin_path, &plugin_meta)
+            .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+#[path = "prefix_test.rs"]
+mod tests;
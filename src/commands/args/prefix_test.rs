@@ -0,0 +1,36 @@
+use super::*;
+use tempfile::TempDir;
+
+fn args(prefix: Option<&str>) -> CommandPrefixArgs {
+    CommandPrefixArgs {
+        prefix: prefix.map(String::from),
+    }
+}
+
+#[test]
+fn parse_prefix_rejects_path_separators() {
+    assert!(parse_prefix("foo/").is_err());
+    assert_eq!(parse_prefix(" foo: ").unwrap(), "foo:");
+}
+
+#[test]
+fn apply_to_without_flag_keeps_meta() {
+    let temp = TempDir::new().unwrap();
+    assert!(!args(None).apply_to(temp.path()).unwrap());
+    assert!(meta::load_meta(temp.path()).is_none());
+}
+
+#[test]
+fn apply_to_sets_and_clears_prefix() {
+    let temp = TempDir::new().unwrap();
+
+    assert!(args(Some("foo:")).apply_to(temp.path()).unwrap());
+    let plugin_meta = meta::load_meta(temp.path()).unwrap();
+    assert_eq!(plugin_meta.command_prefix.as_deref(), Some("foo:"));
+
+    assert!(!args(Some("foo:")).apply_to(temp.path()).unwrap());
+
+    assert!(args(Some("")).apply_to(temp.path()).unwrap());
+    let plugin_meta = meta::load_meta(temp.path()).unwrap();
+    assert_eq!(plugin_meta.command_prefix, None);
+}
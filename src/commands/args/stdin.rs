@@ -0,0 +1,149 @@
+//! `--stdin` オプション用の共通 Args 部品。
+//!
+//! `plm list --json | jq -r '.[].name' | plm disable --stdin` のように、標準入力から
+//! 改行区切りのプラグイン名を読み込んで disable / enable / uninstall / update を一括実行する。
+
+use clap::Args as ClapArgs;
+use std::io::{self, Read};
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct StdinArgs {
+    /// Read plugin names from standard input, one per line ("marketplace/name" is
+    /// accepted; blank lines and lines starting with # are ignored)
+    #[arg(long, conflicts_with = "name")]
+    pub stdin: bool,
+}
+
+impl StdinArgs {
+    /// 標準入力を最後まで読み、プラグイン名の一覧を返す
+    pub fn read_plugins(&self) -> Result<Vec<PluginSpec>, String> {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Failed to read plugin names from stdin: {}", e))?;
+        Ok(parse_plugin_list(&input))
+    }
+}
+
+/// 標準入力で指定されたプラグイン 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginSpec {
+    /// `marketplace/name` 形式で指定されたマーケットプレイス
+    pub marketplace: Option<String>,
+    pub name: String,
+}
+
+impl PluginSpec {
+    /// 結果行に出す名前（入力どおりの `marketplace/name` または `name`）
+    pub fn label(&self) -> String {
+        match &self.marketplace {
+            Some(marketplace) => format!("{}/{}", marketplace, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// 改行区切りのプラグイン名リストを解釈する
+///
+/// 前後の空白を除き、空行と `#` で始まるコメント行は無視する。
+/// `marketplace/name` 形式はマーケットプレイス指定として扱う。
+///
+/// # Arguments
+///
+/// * `input` - Contents read from standard input.
+pub fn parse_plugin_list(input: &str) -> Vec<PluginSpec> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| match line.split_once('/') {
+            Some((marketplace, name)) if !marketplace.is_empty() && !name.is_empty() => {
+                PluginSpec {
+                    marketplace: Some(marketplace.to_string()),
+                    name: name.to_string(),
+                }
+            }
+            _ => PluginSpec {
+                marketplace: None,
+                name: line.to_string(),
+            },
+        })
+        .collect()
+}
+
+/// `--stdin` 一括実行の結果集計
+///
+/// プラグインごとに `name: ok` / `name: failed (reason)` を 1 行ずつ表示する。
+#[derive(Debug, Default)]
+pub struct StdinBatch {
+    total: usize,
+    failed: usize,
+}
+
+impl StdinBatch {
+    /// 1 プラグインぶんの結果を表示して集計する
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - Plugin that was processed.
+    /// * `result` - Result of the single-plugin command.
+    pub fn record(&mut self, spec: &PluginSpec, result: Result<(), String>) {
+        self.total += 1;
+        if result.is_err() {
+            self.failed += 1;
+        }
+        println!("{}", result_line(spec, &result));
+    }
+
+    /// 一括実行を終える（1 件でも失敗していればエラー = exit code 1）
+    ///
+    /// 入力が空だった場合は `no plugins specified` と表示するだけで成功とする。
+    pub fn finish(self) -> Result<(), String> {
+        if self.total == 0 {
+            println!("no plugins specified");
+            return Ok(());
+        }
+        if self.failed == 0 {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} of {} plugin(s) failed",
+                self.failed, self.total
+            ))
+        }
+    }
+}
+
+/// 結果の 1 行（`name: ok` / `name: failed (reason)`）
+///
+/// # Arguments
+///
+/// * `spec` - Plugin that was processed.
+/// * `result` - Result of the single-plugin command.
+pub(crate) fn result_line(spec: &PluginSpec, result: &Result<(), String>) -> String {
+    match result {
+        Ok(()) => format!("{}: ok", spec.label()),
+        Err(e) => format!("{}: failed ({})", spec.label(), failure_reason(e)),
+    }
+}
+
+/// エラーメッセージの 1 行目（`Error: ` 接頭辞は除く）
+///
+/// 近い名前の候補（`did you mean: ...?` の行）があれば続けて添える。
+///
+/// # Arguments
+///
+/// * `error` - Error returned by the single-plugin command.
+fn failure_reason(error: &str) -> String {
+    let mut lines = error.lines();
+    let first = lines.next().unwrap_or_default();
+    let first = first.strip_prefix("Error: ").unwrap_or(first);
+    match lines.find(|line| line.starts_with("did you mean: ")) {
+        Some(suggestion) => format!("{}; {}", first, suggestion),
+        None => first.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "stdin_test.rs"]
+mod tests;
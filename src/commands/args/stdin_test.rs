@@ -0,0 +1,82 @@
+use super::*;
+
+fn spec(marketplace: Option<&str>, name: &str) -> PluginSpec {
+    PluginSpec {
+        marketplace: marketplace.map(str::to_string),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn parse_plugin_list_skips_blank_and_comment_lines() {
+    let input = "formatter\n\n  # generated by jq\nlinter  \r\n   \n";
+
+    assert_eq!(
+        parse_plugin_list(input),
+        vec![spec(None, "formatter"), spec(None, "linter")]
+    );
+}
+
+#[test]
+fn parse_plugin_list_reads_marketplace_prefix() {
+    let input = "mp-a/formatter\n/odd\nodd/\n";
+
+    assert_eq!(
+        parse_plugin_list(input),
+        vec![
+            spec(Some("mp-a"), "formatter"),
+            spec(None, "/odd"),
+            spec(None, "odd/"),
+        ]
+    );
+}
+
+#[test]
+fn parse_plugin_list_of_empty_input_is_empty() {
+    assert!(parse_plugin_list("").is_empty());
+    assert!(parse_plugin_list("# nothing\n\n").is_empty());
+}
+
+#[test]
+fn result_line_shows_ok_or_first_line_of_the_error() {
+    let plugin = spec(Some("mp-a"), "formatter");
+
+    assert_eq!(result_line(&plugin, &Ok(())), "mp-a/formatter: ok");
+    assert_eq!(
+        result_line(
+            &plugin,
+            &Err(
+                "Error: Plugin 'formatter' not found in cache (marketplace: mp-a)\nHint: ..."
+                    .into()
+            )
+        ),
+        "mp-a/formatter: failed (Plugin 'formatter' not found in cache (marketplace: mp-a))"
+    );
+}
+
+#[test]
+fn result_line_keeps_the_did_you_mean_line() {
+    let plugin = spec(None, "formatter");
+
+    assert_eq!(
+        result_line(
+            &plugin,
+            &Err("Error: Plugin 'formatter' not found in cache (marketplace: github)\nHint: ...\ndid you mean: formatter-pro?".into())
+        ),
+        "formatter: failed (Plugin 'formatter' not found in cache (marketplace: github); did you mean: formatter-pro?)"
+    );
+}
+
+#[test]
+fn finish_fails_when_any_plugin_failed() {
+    let mut batch = StdinBatch::default();
+    batch.record(&spec(None, "a"), Ok(()));
+    batch.record(&spec(None, "b"), Err("boom".into()));
+
+    assert_eq!(batch.finish(), Err("1 of 2 plugin(s) failed".to_string()));
+}
+
+#[test]
+fn finish_succeeds_when_nothing_was_specified() {
+    assert_eq!(StdinBatch::default().finish(), Ok(()));
+}
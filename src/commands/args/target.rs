@@ -1,7 +1,8 @@
 //! `--target` オプション用の共通 Args 部品。
 
-use crate::target::TargetKind;
-use clap::Args as ClapArgs;
+use crate::target::{auto_targets, TargetKind};
+use clap::{Args as ClapArgs, ValueEnum};
+use std::path::Path;
 
 #[derive(Debug, Clone, ClapArgs)]
 pub struct SingleTargetArgs {
@@ -10,9 +11,87 @@ pub struct SingleTargetArgs {
     pub target: Option<TargetKind>,
 }
 
+/// `--target` に指定できる値（ターゲット名または `auto`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetSpec {
+    /// プロジェクト内の痕跡からターゲットを自動判定する
+    Auto,
+    /// 明示的なターゲット
+    Kind(TargetKind),
+}
+
+/// `--target` の値をパースする
+///
+/// # Arguments
+///
+/// * `s` - Raw `--target` value.
+fn parse_target_spec(s: &str) -> Result<TargetSpec, String> {
+    if s.eq_ignore_ascii_case("auto") {
+        return Ok(TargetSpec::Auto);
+    }
+    TargetKind::from_str(s, true)
+        .map(TargetSpec::Kind)
+        .map_err(|_| {
+            let names: Vec<&str> = TargetKind::value_variants()
+                .iter()
+                .map(|k| k.as_str())
+                .collect();
+            format!(
+                "invalid target '{}' (possible values: {}, auto)",
+                s,
+                names.join(", ")
+            )
+        })
+}
+
 #[derive(Debug, Clone, ClapArgs)]
 pub struct MultiTargetArgs {
-    /// Target environments to deploy to (if not specified, TUI selection)
-    #[arg(long, value_enum)]
-    pub target: Option<Vec<TargetKind>>,
+    /// Target environments to deploy to: antigravity, codex, copilot, cursor, gemini,
+    /// or auto to detect from project files (if not specified, TUI selection)
+    #[arg(long, value_parser = parse_target_spec)]
+    pub target: Option<Vec<TargetSpec>>,
 }
+
+impl MultiTargetArgs {
+    /// `--target` をターゲット名の一覧に解決する
+    ///
+    /// 未指定なら `None`（呼び出し側で TUI 選択）。`auto` は `target::auto_targets` で
+    /// 判定したターゲットに展開し、重複は最初の出現位置で 1 つにまとめる。
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root used for `auto` detection.
+    pub fn resolve(&self, project_root: &Path) -> Result<Option<Vec<String>>, String> {
+        let Some(specs) = &self.target else {
+            return Ok(None);
+        };
+
+        let mut kinds: Vec<TargetKind> = Vec::new();
+        for spec in specs {
+            let expanded = match spec {
+                TargetSpec::Kind(kind) => vec![*kind],
+                TargetSpec::Auto => {
+                    let detected = auto_targets(project_root);
+                    if detected.is_empty() {
+                        return Err(format!(
+                            "No target detected in {}; specify --target explicitly",
+                            project_root.display()
+                        ));
+                    }
+                    detected
+                }
+            };
+            for kind in expanded {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        }
+
+        Ok(Some(kinds.iter().map(|k| k.as_str().to_string()).collect()))
+    }
+}
+
+#[cfg(test)]
+#[path = "target_test.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::{parse_target_spec, MultiTargetArgs, TargetSpec};
+use crate::target::TargetKind;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn parse_target_spec_accepts_auto_case_insensitively() {
+    assert_eq!(parse_target_spec("auto"), Ok(TargetSpec::Auto));
+    assert_eq!(parse_target_spec("AUTO"), Ok(TargetSpec::Auto));
+}
+
+#[test]
+fn parse_target_spec_accepts_target_names() {
+    assert_eq!(
+        parse_target_spec("codex"),
+        Ok(TargetSpec::Kind(TargetKind::Codex))
+    );
+    assert_eq!(
+        parse_target_spec("gemini"),
+        Ok(TargetSpec::Kind(TargetKind::GeminiCli))
+    );
+}
+
+#[test]
+fn parse_target_spec_rejects_unknown_value() {
+    let err = parse_target_spec("vim").unwrap_err();
+    assert!(err.contains("invalid target 'vim'"));
+    assert!(err.contains("auto"));
+}
+
+#[test]
+fn resolve_returns_none_when_unspecified() {
+    let dir = TempDir::new().unwrap();
+    let args = MultiTargetArgs { target: None };
+    assert_eq!(args.resolve(dir.path()), Ok(None));
+}
+
+#[test]
+fn resolve_expands_auto_and_dedups() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join(".codex")).unwrap();
+    let args = MultiTargetArgs {
+        target: Some(vec![
+            TargetSpec::Kind(TargetKind::Codex),
+            TargetSpec::Auto,
+            TargetSpec::Kind(TargetKind::Cursor),
+        ]),
+    };
+    assert_eq!(
+        args.resolve(dir.path()),
+        Ok(Some(vec!["codex".to_string(), "cursor".to_string()]))
+    );
+}
+
+#[test]
+fn resolve_auto_errors_when_nothing_detected() {
+    let dir = TempDir::new().unwrap();
+    let args = MultiTargetArgs {
+        target: Some(vec![TargetSpec::Auto]),
+    };
+    let err = args.resolve(dir.path()).unwrap_err();
+    assert!(err.contains("No target detected"));
+}
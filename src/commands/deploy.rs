@@ -1,9 +1,10 @@
 //! plm の配置系コマンド集約モジュール。
 //!
-//! `install` / `link` / `unlink` / `sync` / `import` を束ねる。
+//! `install` / `link` / `unlink` / `sync` / `import` / `refresh` を束ねる。
 
 pub mod import;
 pub mod install;
 pub mod link;
+pub mod refresh;
 pub mod sync;
 pub mod unlink;
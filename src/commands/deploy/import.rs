@@ -3,7 +3,12 @@
 //! Claude Code Plugin形式のGitHubリポジトリから、
 //! 特定のコンポーネントを選択してインポートする。
 
+mod bulk;
+mod originals;
+
 use crate::commands::args::{InteractiveScopeArgs, MultiTargetArgs};
+use crate::commands::examples::Example;
+use crate::commands::Context;
 use crate::component::{
     AgentFormat, Component, ComponentDeployment, ComponentKind, ConversionConfig,
 };
@@ -11,19 +16,41 @@ use crate::component::{ComponentRef, PlacementContext, PlacementScope, ProjectCo
 use crate::import::{ImportRecord, ImportRegistry};
 use crate::output::CommandSummary;
 use crate::plugin::PackageCache;
+use crate::scan::ExcludeRules;
 use crate::source::parse_source;
 use crate::target::{all_targets, parse_target, PluginOrigin, Scope, Target, TargetKind};
 use crate::tui;
+use bulk::ImportFrom;
 use chrono::Utc;
 use clap::Parser;
+use originals::{OriginalFile, OriginalsPolicy};
 use std::cell::Cell;
+use std::collections::HashSet;
 use std::env;
-use std::path::Path;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Parser)]
 pub struct Args {
     /// GitHub repository in owner/repo format
-    pub source: String,
+    #[arg(required_unless_present = "from")]
+    pub source: Option<String>,
+
+    /// Bundle existing project files into a new plugin instead of importing from a repository
+    #[arg(long, value_enum, conflicts_with_all = ["source", "component", "component_type"], requires = "output")]
+    pub from: Option<ImportFrom>,
+
+    /// Directory to write the plugin generated by --from
+    #[arg(long, value_name = "DIR", requires = "from")]
+    pub output: Option<PathBuf>,
+
+    /// Plugin name written to plugin.json (defaults to the --output directory name)
+    #[arg(long, requires = "from")]
+    pub name: Option<String>,
+
+    /// Only print the file tree that --from would generate
+    #[arg(long, requires = "from")]
+    pub dry_run: bool,
 
     /// Specific component paths to import (e.g., skills/pdf, agents/review)
     /// Format: <kind>/<name> where kind is: skills, agents, commands, instructions, hooks
@@ -49,6 +76,14 @@ pub struct Args {
     /// に追記される。`--no-enable-flag` を指定すると config.toml には触れない。
     #[arg(long = "no-enable-flag", action = clap::ArgAction::SetFalse, default_value_t = true)]
     pub enable_flag: bool,
+
+    /// Keep original Claude Code files (e.g. .claude/commands/<name>.md) that duplicate imported components
+    #[arg(long, conflicts_with = "replace_originals")]
+    pub keep_originals: bool,
+
+    /// Delete original Claude Code files once the imported components are deployed
+    #[arg(long)]
+    pub replace_originals: bool,
 }
 
 /// Parse a component path string into (ComponentKind, name)
@@ -193,6 +228,8 @@ struct ImportContext<'a> {
     scope: Scope,
     project_root: &'a Path,
     plugin_root: &'a Path,
+    /// Skill ディレクトリ配置時に適用する除外ルール
+    exclude_rules: ExcludeRules,
     source_repo: &'a str,
     git_ref: &'a str,
     commit_sha: &'a str,
@@ -204,6 +241,42 @@ struct ImportContext<'a> {
     codex_flag_applied: Cell<bool>,
 }
 
+/// `place_components` の集計結果
+#[derive(Debug, Default)]
+struct PlacementTally {
+    success: usize,
+    failure: usize,
+    /// `.claude/` も読むターゲットへ配置できたコンポーネント (kind, name)
+    duplicated: HashSet<(ComponentKind, String)>,
+    /// 1 つ以上のターゲットで配置に失敗したコンポーネント (kind, name)
+    failed: HashSet<(ComponentKind, String)>,
+}
+
+impl PlacementTally {
+    fn counts(&self) -> (usize, usize) {
+        (self.success, self.failure)
+    }
+
+    /// # Arguments
+    ///
+    /// * `component` - Component whose placement failed.
+    fn record_failure(&mut self, component: &Component) {
+        self.failure += 1;
+        self.failed.insert((component.kind, component.name.clone()));
+    }
+
+    /// # Arguments
+    ///
+    /// * `target` - Target the component was placed to.
+    /// * `component` - Component that was placed.
+    fn record_placed(&mut self, target: TargetKind, component: &Component) {
+        if target.reads_claude_dir(component.kind) {
+            self.duplicated
+                .insert((component.kind, component.name.clone()));
+        }
+    }
+}
+
 enum DeployOutcome {
     Success,
     Failure,
@@ -266,6 +339,7 @@ fn build_deployment(
         .scope(ctx.scope)
         .target_path(target_path)
         .conversion(conversion)
+        .exclude(ctx.plugin_root, ctx.exclude_rules.clone())
         .build()
         .map(Some)
         .map_err(|e| e.to_string())
@@ -372,9 +446,8 @@ fn place_components(
     components: &[Component],
     ctx: &ImportContext,
     import_registry: &mut ImportRegistry,
-) -> Result<(usize, usize), String> {
-    let mut total_success = 0;
-    let mut total_failure = 0;
+) -> Result<PlacementTally, String> {
+    let mut tally = PlacementTally::default();
 
     for target_name in target_names {
         let target = parse_target(target_name).map_err(|e| e.to_string())?;
@@ -390,7 +463,7 @@ fn place_components(
                         component.name,
                         error
                     );
-                    total_failure += 1;
+                    tally.record_failure(component);
                     continue;
                 }
             }
@@ -406,7 +479,7 @@ fn place_components(
                         component.name,
                         e
                     );
-                    total_failure += 1;
+                    tally.record_failure(component);
                     continue;
                 }
             };
@@ -418,20 +491,67 @@ fn place_components(
                 ctx,
                 import_registry,
             ) {
-                DeployOutcome::Success => total_success += 1,
-                DeployOutcome::Failure => total_failure += 1,
-                DeployOutcome::Skipped => {}
+                DeployOutcome::Success => {
+                    tally.success += 1;
+                    tally.record_placed(target.kind(), component);
+                }
+                DeployOutcome::Failure => tally.record_failure(component),
+                DeployOutcome::Skipped => tally.record_placed(target.kind(), component),
             }
         }
     }
 
-    Ok((total_success, total_failure))
+    Ok(tally)
 }
 
+/// `plm import` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm import owner/claude-plugin",
+        description: "Import all components of a Claude Code plugin",
+    },
+    Example {
+        command: "plm import owner/claude-plugin --component skills/pdf",
+        description: "Import a single component",
+    },
+    Example {
+        command: "plm import owner/claude-plugin --type agent",
+        description: "Import all agents",
+    },
+    Example {
+        command:
+            "plm import owner/claude-plugin --target codex --scope project --replace-originals",
+        description: "Import and delete the duplicated .claude/ originals",
+    },
+    Example {
+        command: "plm import --from copilot --output ./my-prompts --dry-run",
+        description: "Preview a plugin built from .github/prompts and .github/agents",
+    },
+    Example {
+        command: "plm import --from claude-code --output ./team-commands --name team-commands",
+        description: "Bundle .claude/commands and .claude/agents into a plugin",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm import`.
-pub async fn run(args: Args) -> Result<(), String> {
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    if let (Some(from), Some(output)) = (args.from, args.output.as_deref()) {
+        return bulk::run(
+            &ctx.project_root,
+            from,
+            output,
+            args.name.as_deref(),
+            args.dry_run,
+        );
+    }
+    let source_repo = args
+        .source
+        .clone()
+        .ok_or_else(|| "A source repository or --from is required".to_string())?;
+
     // Parse component paths up-front so validation errors surface before the
     // potentially expensive plugin download below.
     let component_paths: Vec<(ComponentKind, String)> = args
@@ -442,8 +562,9 @@ pub async fn run(args: Args) -> Result<(), String> {
 
     // Target and scope selection happen before download so the user can cancel
     // without paying the download cost.
-    let target_names: Vec<String> = match &args.target.target {
-        Some(targets) => targets.iter().map(|t| t.as_str().to_string()).collect(),
+    let project_root = ctx.project_root.clone();
+    let target_names: Vec<String> = match args.target.resolve(&project_root)? {
+        Some(targets) => targets,
         None => {
             let available = all_targets();
             let available_refs: Vec<&dyn Target> = available.iter().map(|t| t.as_ref()).collect();
@@ -465,7 +586,7 @@ pub async fn run(args: Args) -> Result<(), String> {
     println!("\nSelected targets: {}", target_names.join(", "));
     println!("Selected scope: {}", scope);
 
-    let source = parse_source(&args.source).map_err(|e| e.to_string())?;
+    let source = parse_source(&source_repo).map_err(|e| e.to_string())?;
 
     println!("\nDownloading plugin...");
     let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
@@ -505,7 +626,12 @@ pub async fn run(args: Args) -> Result<(), String> {
         println!("  - {}: {}", c.kind, c.name);
     }
 
-    let project_root = env::current_dir().map_err(|e| e.to_string())?;
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let originals = originals::claude_dir(scope, &project_root, home.as_deref())
+        .map(|dir| originals::find_originals(&filtered_components, &dir))
+        .unwrap_or_default();
+    let replace = decide_replace_originals(&originals, &args)?;
+
     let origin =
         PluginOrigin::from_cached_plugin(cached_plugin.marketplace.as_deref(), cached_plugin.id());
 
@@ -514,7 +640,8 @@ pub async fn run(args: Args) -> Result<(), String> {
         scope,
         project_root: &project_root,
         plugin_root: &cached_plugin.path,
-        source_repo: &args.source,
+        exclude_rules: package.exclude_rules(),
+        source_repo: &source_repo,
         git_ref: &cached_plugin.git_ref,
         commit_sha: &cached_plugin.commit_sha,
         enable_codex_hooks_flag: args.enable_flag,
@@ -525,19 +652,89 @@ pub async fn run(args: Args) -> Result<(), String> {
     let mut import_registry = ImportRegistry::new().map_err(|e| e.to_string())?;
 
     println!("\nPlacement Results:");
-    let (total_success, total_failure) = place_components(
+    let tally = place_components(
         &target_names,
         &filtered_components,
         &ctx,
         &mut import_registry,
     )?;
 
+    let (total_success, total_failure) = tally.counts();
     let summary = CommandSummary::format(total_success, total_failure);
     println!("\n{} {}", summary.prefix, summary.message);
 
+    if replace {
+        let report = originals::replace_originals(&originals, &tally.duplicated, &tally.failed);
+        if !report.removed.is_empty() {
+            println!("\nRemoved original files (now provided by the plugin):");
+            for path in &report.removed {
+                println!("  - {}", path.display());
+            }
+        }
+        for (path, reason) in &report.kept {
+            eprintln!("Warning: Kept original {}: {}", path.display(), reason);
+        }
+    }
+
     Ok(())
 }
 
+/// 重複する元ファイルをデプロイ後に削除するか決める
+///
+/// 元ファイルが無ければ何もしない。フラグ未指定の非対話環境では残し、案内だけ表示する。
+///
+/// # Arguments
+///
+/// * `originals` - Original files that duplicate the imported components.
+/// * `args` - Parsed CLI arguments for `plm import`.
+fn decide_replace_originals(originals: &[OriginalFile], args: &Args) -> Result<bool, String> {
+    if originals.is_empty() {
+        return Ok(false);
+    }
+
+    println!(
+        "\nFound {} original file(s) that duplicate imported components:",
+        originals.len()
+    );
+    for original in originals {
+        println!("  - {}", original.path.display());
+    }
+
+    let policy = OriginalsPolicy::from_flags(
+        args.keep_originals,
+        args.replace_originals,
+        io::stdin().is_terminal(),
+    );
+    match policy {
+        OriginalsPolicy::Replace => Ok(true),
+        OriginalsPolicy::Keep => {
+            if !args.keep_originals {
+                eprintln!(
+                    "Note: Keeping original files. Re-run with --replace-originals to delete them after deployment."
+                );
+            }
+            Ok(false)
+        }
+        OriginalsPolicy::Ask => confirm_replace_originals(),
+    }
+}
+
+/// 元ファイル削除の確認を求める
+fn confirm_replace_originals() -> Result<bool, String> {
+    print!(
+        "Delete these original files after the plugin is deployed? \
+         (they will be replaced by the plugin deployment) [y/N]: "
+    );
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
 #[cfg(test)]
 #[path = "import_test.rs"]
 mod tests;
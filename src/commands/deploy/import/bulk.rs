@@ -0,0 +1,412 @@
+//! `plm import --from` による一括取り込み
+//!
+//! プロジェクトに直接置かれた Copilot のプロンプト・エージェント（`.github/prompts`,
+//! `.github/agents`）や Claude Code のコマンド・エージェント（`.claude/commands`,
+//! `.claude/agents`）を既存のパーサーで読み、Claude Code Plugin 形式
+//! （`.claude-plugin/plugin.json` + `commands/` + `agents/`）のディレクトリに書き出す。
+//! 生成したディレクトリはそのまま `plm pack` に渡せる。
+
+use crate::commands::manage::init::{to_pretty_json, INITIAL_VERSION};
+use crate::component::ComponentKind;
+use crate::marketplace::validate_plugin_name;
+use crate::output::tree::{render_tree, TreeEntry};
+use crate::parser::{ClaudeCodeAgent, ClaudeCodeCommand};
+use clap::ValueEnum;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 取り込み元の形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFrom {
+    /// `.github/prompts/*.prompt.md` and `.github/agents/*.agent.md`
+    Copilot,
+    /// `.claude/commands/*.md` and `.claude/agents/*.md`
+    ClaudeCode,
+}
+
+/// 取り込み元ディレクトリ 1 つ分
+struct SourceDir {
+    /// プロジェクトルートからの相対パス
+    dir: &'static str,
+    /// 生成するコンポーネント種別
+    kind: ComponentKind,
+    /// 対象ファイルの接尾辞
+    suffix: &'static str,
+}
+
+impl ImportFrom {
+    /// 表示名
+    fn label(self) -> &'static str {
+        match self {
+            ImportFrom::Copilot => "Copilot",
+            ImportFrom::ClaudeCode => "Claude Code",
+        }
+    }
+
+    /// 走査するディレクトリ
+    fn source_dirs(self) -> [SourceDir; 2] {
+        match self {
+            ImportFrom::Copilot => [
+                SourceDir {
+                    dir: ".github/prompts",
+                    kind: ComponentKind::Command,
+                    suffix: ".prompt.md",
+                },
+                SourceDir {
+                    dir: ".github/agents",
+                    kind: ComponentKind::Agent,
+                    suffix: ".agent.md",
+                },
+            ],
+            ImportFrom::ClaudeCode => [
+                SourceDir {
+                    dir: ".claude/commands",
+                    kind: ComponentKind::Command,
+                    suffix: ".md",
+                },
+                SourceDir {
+                    dir: ".claude/agents",
+                    kind: ComponentKind::Agent,
+                    suffix: ".md",
+                },
+            ],
+        }
+    }
+}
+
+/// 生成するファイル 1 つ分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PlannedFile {
+    /// 出力先ディレクトリからの相対パス
+    pub path: PathBuf,
+    pub content: String,
+    /// 取り込み元（プロジェクトルート相対。`plugin.json` は `None`）
+    pub source: Option<PathBuf>,
+}
+
+/// 取り込まなかったファイルとその理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SkippedFile {
+    /// プロジェクトルートからの相対パス
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// 一括取り込みの計画（書き出す前に組み立て、`--dry-run` ではこれを表示するだけ）
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BulkImportPlan {
+    pub files: Vec<PlannedFile>,
+    pub skipped: Vec<SkippedFile>,
+    /// 変換で落ちたフィールドの注記
+    pub notes: Vec<String>,
+}
+
+impl BulkImportPlan {
+    /// 取り込むコンポーネント数（`plugin.json` を除く）
+    pub fn component_count(&self, kind: ComponentKind) -> usize {
+        self.files
+            .iter()
+            .filter(|f| f.source.is_some() && f.path.starts_with(kind.plural()))
+            .count()
+    }
+}
+
+/// 取り込み元を走査して計画を組み立てる
+///
+/// 読めないファイル・名前が不正なファイル・同種のコンポーネントと名前が重なるファイルは
+/// 理由付きで `skipped` に入れ、残りだけを取り込む。
+///
+/// # Arguments
+///
+/// * `project_root` - Project containing `.github/` or `.claude/`.
+/// * `from` - Format of the files to import.
+/// * `plugin_name` - Name written to `plugin.json`.
+pub(crate) fn plan(
+    project_root: &Path,
+    from: ImportFrom,
+    plugin_name: &str,
+) -> Result<BulkImportPlan, String> {
+    validate_plugin_name(plugin_name).map_err(|e| e.to_string())?;
+    let manifest = serde_json::json!({ "name": plugin_name, "version": INITIAL_VERSION });
+    let mut plan = BulkImportPlan {
+        files: vec![PlannedFile {
+            path: PathBuf::from(".claude-plugin").join("plugin.json"),
+            content: to_pretty_json(&manifest)?,
+            source: None,
+        }],
+        ..Default::default()
+    };
+
+    for source in from.source_dirs() {
+        let mut seen: HashMap<String, PathBuf> = HashMap::new();
+        for path in list_entries(&project_root.join(source.dir))? {
+            let relative = path
+                .strip_prefix(project_root)
+                .unwrap_or(&path)
+                .to_path_buf();
+            let mut skip = |reason: String| {
+                plan.skipped.push(SkippedFile {
+                    path: relative.clone(),
+                    reason,
+                })
+            };
+            if path.is_dir() {
+                skip("subdirectories are not imported".to_string());
+                continue;
+            }
+            let file_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let Some(stem) = file_name
+                .strip_suffix(source.suffix)
+                .filter(|stem| !stem.is_empty())
+            else {
+                skip(format!("not a *{} file", source.suffix));
+                continue;
+            };
+
+            let (name, content, lost) = match convert(from, source.kind, &path, stem) {
+                Ok(converted) => converted,
+                Err(e) => {
+                    skip(format!("failed to parse: {}", e));
+                    continue;
+                }
+            };
+            if validate_plugin_name(&name).is_err() {
+                skip(format!("invalid {} name '{}'", source.kind.as_str(), name));
+                continue;
+            }
+            if let Some(first) = seen.get(&name) {
+                skip(format!(
+                    "duplicate {} name '{}' (already imported from {})",
+                    source.kind.as_str(),
+                    name,
+                    first.display()
+                ));
+                continue;
+            }
+
+            if !lost.is_empty() {
+                plan.notes.push(format!(
+                    "{}: dropped {}-only field(s): {}",
+                    relative.display(),
+                    from.label(),
+                    lost.join(", ")
+                ));
+            }
+            seen.insert(name.clone(), relative.clone());
+            plan.files.push(PlannedFile {
+                path: PathBuf::from(source.kind.plural()).join(format!("{}.md", name)),
+                content,
+                source: Some(relative),
+            });
+        }
+    }
+    Ok(plan)
+}
+
+/// ディレクトリ直下のエントリを名前順に返す（ディレクトリが無ければ空）
+///
+/// # Arguments
+///
+/// * `dir` - Directory to list.
+fn list_entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {}", dir.display(), e)),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// 1 ファイルを Claude Code 形式に変換し、(名前, 内容, 落ちたフィールド) を返す
+///
+/// Copilot 形式は変換して書き直す。Claude Code 形式は名前の取得と検証のためだけに
+/// パースし、未知のフィールドを落とさないよう元の内容をそのまま使う。
+///
+/// # Arguments
+///
+/// * `from` - Format of the file.
+/// * `kind` - Component kind the file becomes.
+/// * `path` - File to convert.
+/// * `stem` - File name without the suffix (fallback name).
+fn convert(
+    from: ImportFrom,
+    kind: ComponentKind,
+    path: &Path,
+    stem: &str,
+) -> Result<(String, String, Vec<&'static str>), String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let converted = match (from, kind) {
+        (ImportFrom::Copilot, ComponentKind::Agent) => {
+            ClaudeCodeAgent::from_copilot(&content).map(|(mut agent, lost)| {
+                let name = agent.name.get_or_insert_with(|| stem.to_string()).clone();
+                (name, agent.to_markdown(), lost)
+            })
+        }
+        (ImportFrom::Copilot, _) => {
+            ClaudeCodeCommand::from_copilot(&content).map(|(mut command, lost)| {
+                let name = command.name.get_or_insert_with(|| stem.to_string()).clone();
+                (name, command.to_markdown(), lost)
+            })
+        }
+        (ImportFrom::ClaudeCode, ComponentKind::Agent) => {
+            ClaudeCodeAgent::parse(&content).map(|agent| {
+                (
+                    agent.name.unwrap_or_else(|| stem.to_string()),
+                    content,
+                    Vec::new(),
+                )
+            })
+        }
+        (ImportFrom::ClaudeCode, _) => ClaudeCodeCommand::parse(&content).map(|command| {
+            (
+                command.name.unwrap_or_else(|| stem.to_string()),
+                content,
+                Vec::new(),
+            )
+        }),
+    };
+    converted.map_err(|e| e.to_string())
+}
+
+/// 生成するファイルのツリーを組み立てる（`--dry-run` の表示）
+///
+/// # Arguments
+///
+/// * `output` - Output directory of the plugin.
+/// * `plan` - Import plan.
+pub(crate) fn render_plan_tree(output: &Path, plan: &BulkImportPlan) -> String {
+    let mut dirs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for file in &plan.files {
+        let dir = file
+            .path
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let name = file
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let label = match &file.source {
+            Some(source) => format!("{}  <- {}", name, source.display()),
+            None => name,
+        };
+        dirs.entry(dir).or_default().push(label);
+    }
+
+    let mut entries = Vec::new();
+    for (dir, mut files) in dirs {
+        files.sort();
+        entries.push(TreeEntry::new(1, format!("{}/", dir)));
+        entries.extend(files.into_iter().map(|label| TreeEntry::new(2, label)));
+    }
+    render_tree(&format!("{}/", output.display()), &entries)
+}
+
+/// 計画どおりにファイルを書き出す
+///
+/// 既存ファイルは上書きしない（1 つでも衝突すれば何も書かずにエラー）。
+///
+/// # Arguments
+///
+/// * `output` - Output directory of the plugin (created if missing).
+/// * `plan` - Import plan.
+pub(crate) fn write_plan(output: &Path, plan: &BulkImportPlan) -> Result<(), String> {
+    if let Some(file) = plan.files.iter().find(|f| output.join(&f.path).exists()) {
+        return Err(format!(
+            "{} already exists; refusing to overwrite",
+            output.join(&file.path).display()
+        ));
+    }
+    for file in &plan.files {
+        let path = output.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, &file.content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// `plm import --from` の本体
+///
+/// # Arguments
+///
+/// * `project_root` - Project containing `.github/` or `.claude/`.
+/// * `from` - Format of the files to import.
+/// * `output` - Output directory of the plugin.
+/// * `name` - Plugin name (`None` uses the output directory name).
+/// * `dry_run` - Only print the file tree.
+pub(crate) fn run(
+    project_root: &Path,
+    from: ImportFrom,
+    output: &Path,
+    name: Option<&str>,
+    dry_run: bool,
+) -> Result<(), String> {
+    let plugin_name = match name {
+        Some(name) => name.to_string(),
+        None => std::path::absolute(output)
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .ok_or_else(|| {
+                format!(
+                    "Cannot derive plugin name from {}; use --name",
+                    output.display()
+                )
+            })?,
+    };
+    let plan = plan(project_root, from, &plugin_name)?;
+
+    if !plan.skipped.is_empty() {
+        println!("Skipped {} file(s):", plan.skipped.len());
+        for skipped in &plan.skipped {
+            println!("  - {}: {}", skipped.path.display(), skipped.reason);
+        }
+    }
+    for note in &plan.notes {
+        println!("Note: {}", note);
+    }
+
+    let commands = plan.component_count(ComponentKind::Command);
+    let agents = plan.component_count(ComponentKind::Agent);
+    if commands + agents == 0 {
+        let dirs: Vec<&str> = from.source_dirs().iter().map(|s| s.dir).collect();
+        return Err(format!(
+            "No {} files to import under {}",
+            from.label(),
+            dirs.join(" or ")
+        ));
+    }
+
+    if dry_run {
+        print!("{}", render_plan_tree(output, &plan));
+        println!("Dry run: nothing was written.");
+        return Ok(());
+    }
+
+    write_plan(output, &plan)?;
+    println!(
+        "✅ Imported {} command(s) and {} agent(s) into {}",
+        commands,
+        agents,
+        output.display()
+    );
+    println!("Package it with: plm pack {}", output.display());
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "bulk_test.rs"]
+mod tests;
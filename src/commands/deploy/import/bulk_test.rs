@@ -0,0 +1,213 @@
+use super::*;
+use crate::commands::manage::pack::{load_plugin, validate_for_pack, write_archive};
+use crate::component::Scope;
+use crate::install::{self, PlaceRequest};
+use crate::plugin::PackageCache;
+use crate::target::{CopilotTarget, Target};
+use tempfile::TempDir;
+
+fn write_file(root: &Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn planned_paths(plan: &BulkImportPlan) -> Vec<String> {
+    plan.files
+        .iter()
+        .map(|f| f.path.to_string_lossy().replace('\\', "/"))
+        .collect()
+}
+
+fn copilot_project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    write_file(
+        temp.path(),
+        ".github/prompts/fix.prompt.md",
+        "---\ndescription: Fix an issue\nagent: agent\n---\nFix ${arguments}",
+    );
+    write_file(
+        temp.path(),
+        ".github/agents/reviewer.agent.md",
+        "---\nname: reviewer\ndescription: Reviews code\n---\nReview carefully.",
+    );
+    temp
+}
+
+#[test]
+fn plan_converts_copilot_prompts_and_agents() {
+    let project = copilot_project();
+
+    let plan = plan(project.path(), ImportFrom::Copilot, "my-prompts").unwrap();
+
+    assert_eq!(
+        planned_paths(&plan),
+        vec![
+            ".claude-plugin/plugin.json",
+            "commands/fix.md",
+            "agents/reviewer.md"
+        ]
+    );
+    assert!(plan.files[0].content.contains("\"name\": \"my-prompts\""));
+    // 名前はファイル名（.prompt.md を除く）から補い、本文の変数は Claude Code 形式にする
+    assert!(plan.files[1].content.contains("name: fix"));
+    assert!(plan.files[1].content.contains("Fix $ARGUMENTS"));
+    assert_eq!(
+        plan.notes,
+        vec![".github/prompts/fix.prompt.md: dropped Copilot-only field(s): agent"]
+    );
+    assert!(plan.skipped.is_empty());
+}
+
+#[test]
+fn plan_lists_skipped_files_with_reasons() {
+    let project = copilot_project();
+    write_file(project.path(), ".github/prompts/README.md", "notes");
+    write_file(
+        project.path(),
+        ".github/prompts/broken.prompt.md",
+        "---\ndescription: [unclosed\n---\nBody",
+    );
+    write_file(
+        project.path(),
+        ".github/prompts/other.prompt.md",
+        "---\nname: fix\n---\nAnother fix",
+    );
+    write_file(project.path(), ".github/prompts/nested/deep.prompt.md", "x");
+
+    let plan = plan(project.path(), ImportFrom::Copilot, "my-prompts").unwrap();
+
+    let reasons: Vec<(String, &str)> = plan
+        .skipped
+        .iter()
+        .map(|s| {
+            (
+                s.path.to_string_lossy().replace('\\', "/"),
+                s.reason.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(reasons.len(), 4);
+    assert_eq!(reasons[0].0, ".github/prompts/README.md");
+    assert_eq!(reasons[0].1, "not a *.prompt.md file");
+    assert_eq!(reasons[1].0, ".github/prompts/broken.prompt.md");
+    assert!(reasons[1].1.starts_with("failed to parse"));
+    assert_eq!(reasons[2].0, ".github/prompts/nested");
+    assert_eq!(reasons[2].1, "subdirectories are not imported");
+    assert_eq!(reasons[3].0, ".github/prompts/other.prompt.md");
+    assert_eq!(
+        reasons[3].1,
+        "duplicate command name 'fix' (already imported from .github/prompts/fix.prompt.md)"
+    );
+}
+
+#[test]
+fn plan_keeps_claude_code_files_as_is() {
+    let project = TempDir::new().unwrap();
+    let command = "---\ndescription: Deploy\nunknown-field: kept\n---\nDeploy $ARGUMENTS";
+    write_file(project.path(), ".claude/commands/deploy.md", command);
+    write_file(project.path(), ".claude/agents/helper.md", "Help.");
+
+    let plan = plan(project.path(), ImportFrom::ClaudeCode, "team").unwrap();
+
+    assert_eq!(
+        planned_paths(&plan),
+        vec![
+            ".claude-plugin/plugin.json",
+            "commands/deploy.md",
+            "agents/helper.md"
+        ]
+    );
+    assert_eq!(plan.files[1].content, command);
+    assert_eq!(plan.component_count(ComponentKind::Command), 1);
+    assert_eq!(plan.component_count(ComponentKind::Agent), 1);
+}
+
+#[test]
+fn plan_rejects_invalid_plugin_name() {
+    let project = copilot_project();
+
+    assert!(plan(project.path(), ImportFrom::Copilot, "../escape").is_err());
+}
+
+#[test]
+fn render_plan_tree_groups_files_by_directory() {
+    let project = copilot_project();
+    let plan = plan(project.path(), ImportFrom::Copilot, "my-prompts").unwrap();
+
+    let tree = render_plan_tree(Path::new("out"), &plan);
+
+    assert_eq!(
+        tree,
+        "out/\n\
+         ├── .claude-plugin/\n\
+         │   └── plugin.json\n\
+         ├── agents/\n\
+         │   └── reviewer.md  <- .github/agents/reviewer.agent.md\n\
+         └── commands/\n\
+         \x20   └── fix.md  <- .github/prompts/fix.prompt.md\n"
+    );
+}
+
+#[test]
+fn write_plan_refuses_to_overwrite_existing_files() {
+    let project = copilot_project();
+    let output = TempDir::new().unwrap();
+    write_file(output.path(), "commands/fix.md", "mine");
+    let plan = plan(project.path(), ImportFrom::Copilot, "my-prompts").unwrap();
+
+    let err = write_plan(output.path(), &plan).unwrap_err();
+
+    assert!(err.contains("refusing to overwrite"));
+    assert!(!output.path().join(".claude-plugin/plugin.json").exists());
+    assert_eq!(
+        fs::read_to_string(output.path().join("commands/fix.md")).unwrap(),
+        "mine"
+    );
+}
+
+#[tokio::test]
+async fn generated_plugin_passes_pack_and_install() {
+    let project = copilot_project();
+    let temp = TempDir::new().unwrap();
+    let output = temp.path().join("my-prompts");
+    let plan = plan(project.path(), ImportFrom::Copilot, "my-prompts").unwrap();
+    write_plan(&output, &plan).unwrap();
+
+    // pack: 検証を通り、アーカイブを作れる
+    let plugin = load_plugin(&output).unwrap();
+    assert!(validate_for_pack(&output, plugin.manifest())
+        .unwrap()
+        .is_empty());
+    let archive_path = temp.path().join("my-prompts.zip");
+    write_archive(&output, plugin.manifest(), &archive_path).unwrap();
+
+    // install: `plm install <archive>` と同じ経路でダウンロード・スキャン・配置できる
+    let cache = PackageCache::with_cache_dir(temp.path().join("cache")).unwrap();
+    let package =
+        install::download_plugin_with_cache(archive_path.to_str().unwrap(), false, None, &cache)
+            .await
+            .unwrap();
+    let scanned = install::scan_plugin(&package, None).unwrap();
+    let install_root = TempDir::new().unwrap();
+    let targets: Vec<Box<dyn Target>> = vec![Box::new(CopilotTarget::new())];
+    let result = install::place_plugin(&PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: Scope::Project,
+        project_root: install_root.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    });
+
+    assert!(result.failures.is_empty(), "{:?}", result.failures);
+    assert!(result.conflicts.is_empty());
+    let kinds: Vec<ComponentKind> = result.successes.iter().map(|s| s.component_kind).collect();
+    assert_eq!(kinds.len(), 2);
+    assert!(kinds.contains(&ComponentKind::Command));
+    assert!(kinds.contains(&ComponentKind::Agent));
+    for success in &result.successes {
+        assert!(success.target_path.starts_with(install_root.path()));
+        assert!(success.target_path.exists(), "{:?}", success.target_path);
+    }
+}
@@ -0,0 +1,214 @@
+//! import 元ファイル（Claude Code 側の既存コンポーネント）の検出と置き換え
+//!
+//! `.claude/commands/<name>.md` などをプラグイン化して import すると、元ファイルと
+//! プラグインからデプロイされたファイルが両方読み込まれてコマンドが重複する。
+//! 置き換えを選んだ場合、`.claude/` も読むターゲットへのデプロイに成功し、
+//! 内容が import したコンポーネントと同じ元ファイルだけを削除する。
+
+use crate::component::{Component, ComponentKind};
+use crate::target::Scope;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// 元ファイルの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OriginalsPolicy {
+    /// 残す（`--keep-originals`、非対話環境のデフォルト）
+    Keep,
+    /// デプロイ成功後に削除する（`--replace-originals`）
+    Replace,
+    /// 対話で確認する
+    Ask,
+}
+
+impl OriginalsPolicy {
+    /// フラグと端末状態から方針を決める
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Whether `--keep-originals` was given.
+    /// * `replace` - Whether `--replace-originals` was given.
+    /// * `interactive` - Whether stdin is a terminal.
+    pub(crate) fn from_flags(keep: bool, replace: bool, interactive: bool) -> Self {
+        if replace {
+            Self::Replace
+        } else if keep || !interactive {
+            Self::Keep
+        } else {
+            Self::Ask
+        }
+    }
+}
+
+/// import 対象と重複する元ファイル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OriginalFile {
+    pub kind: ComponentKind,
+    /// コンポーネント名（`Component::name`）
+    pub name: String,
+    pub path: PathBuf,
+    /// import するコンポーネントのソース（内容の一致確認に使う）
+    pub source: PathBuf,
+}
+
+/// スコープに対応する Claude Code の設定ディレクトリ
+///
+/// # Arguments
+///
+/// * `scope` - Import scope.
+/// * `project_root` - Project root (for project scope).
+/// * `home` - Home directory (for personal scope).
+pub(crate) fn claude_dir(
+    scope: Scope,
+    project_root: &Path,
+    home: Option<&Path>,
+) -> Option<PathBuf> {
+    match scope {
+        Scope::Project => Some(project_root.join(".claude")),
+        Scope::Personal => home.map(|h| h.join(".claude")),
+    }
+}
+
+/// import するコンポーネントと同名の元ファイルを探す
+///
+/// Skills / Agents / Commands のみ対象（Instructions と Hooks は名前で対応付けられない）。
+///
+/// # Arguments
+///
+/// * `components` - Components being imported.
+/// * `claude_dir` - Claude Code configuration directory to search.
+pub(crate) fn find_originals(components: &[Component], claude_dir: &Path) -> Vec<OriginalFile> {
+    components
+        .iter()
+        .filter_map(|c| {
+            let name = c.original_name.as_deref().unwrap_or(&c.name);
+            let path = match c.kind {
+                ComponentKind::Skill => claude_dir.join("skills").join(name),
+                ComponentKind::Agent => claude_dir.join("agents").join(format!("{}.md", name)),
+                ComponentKind::Command => claude_dir.join("commands").join(format!("{}.md", name)),
+                _ => return None,
+            };
+            let exists = if c.kind == ComponentKind::Skill {
+                path.is_dir()
+            } else {
+                path.is_file()
+            };
+            exists.then(|| OriginalFile {
+                kind: c.kind,
+                name: c.name.clone(),
+                path,
+                source: c.path.clone(),
+            })
+        })
+        .collect()
+}
+
+/// 元ファイル削除の結果
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ReplaceReport {
+    /// 削除した元ファイル
+    pub removed: Vec<PathBuf>,
+    /// 残した元ファイルと理由
+    pub kept: Vec<(PathBuf, String)>,
+}
+
+/// デプロイに成功したコンポーネントの元ファイルを削除する
+///
+/// いずれかのターゲットで失敗したコンポーネント、`.claude/` も読むターゲット
+/// （[`crate::target::TargetKind::reads_claude_dir`]）へ配置されなかったコンポーネント、
+/// 内容が import したソースと異なる元ファイルは残す。
+///
+/// # Arguments
+///
+/// * `originals` - Original files detected before placement.
+/// * `duplicated` - Components placed to a target that also reads `.claude/`.
+/// * `failed` - Components that failed on at least one target.
+pub(crate) fn replace_originals(
+    originals: &[OriginalFile],
+    duplicated: &HashSet<(ComponentKind, String)>,
+    failed: &HashSet<(ComponentKind, String)>,
+) -> ReplaceReport {
+    let mut report = ReplaceReport::default();
+    for original in originals {
+        let key = (original.kind, original.name.clone());
+        if failed.contains(&key) {
+            report
+                .kept
+                .push((original.path.clone(), "deployment failed".to_string()));
+            continue;
+        }
+        if !duplicated.contains(&key) {
+            report.kept.push((
+                original.path.clone(),
+                "not deployed to a target that reads .claude/".to_string(),
+            ));
+            continue;
+        }
+        match same_content(&original.path, &original.source) {
+            Ok(true) => {}
+            Ok(false) => {
+                report.kept.push((
+                    original.path.clone(),
+                    "content differs from the imported component".to_string(),
+                ));
+                continue;
+            }
+            Err(e) => {
+                report.kept.push((original.path.clone(), e.to_string()));
+                continue;
+            }
+        }
+
+        let result = if original.path.is_dir() {
+            fs::remove_dir_all(&original.path)
+        } else {
+            fs::remove_file(&original.path)
+        };
+        match result {
+            Ok(()) => report.removed.push(original.path.clone()),
+            Err(e) => report.kept.push((original.path.clone(), e.to_string())),
+        }
+    }
+    report
+}
+
+/// 元ファイルが import したコンポーネントのソースと同じ内容か
+///
+/// ファイルはバイト列、Skill ディレクトリは相対パスと内容の組をすべて比較する。
+///
+/// # Arguments
+///
+/// * `original` - Original file or skill directory under `.claude/`.
+/// * `source` - Source of the imported component.
+fn same_content(original: &Path, source: &Path) -> io::Result<bool> {
+    if original.is_dir() {
+        Ok(source.is_dir() && dir_contents(original)? == dir_contents(source)?)
+    } else {
+        Ok(source.is_file() && fs::read(original)? == fs::read(source)?)
+    }
+}
+
+/// ディレクトリ内のファイルを（相対パス, 内容）の組で名前順に列挙する
+///
+/// # Arguments
+///
+/// * `root` - Directory to read.
+fn dir_contents(root: &Path) -> io::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(root).min_depth(1).sort_by_file_name() {
+        let entry = entry.map_err(io::Error::from)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        files.push((relative.to_path_buf(), fs::read(entry.path())?));
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+#[path = "originals_test.rs"]
+mod tests;
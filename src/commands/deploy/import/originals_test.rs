@@ -0,0 +1,219 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn command(name: &str) -> Component {
+    Component::new(
+        ComponentKind::Command,
+        name.to_string(),
+        PathBuf::from(format!("commands/{}.md", name)),
+    )
+}
+
+fn write(path: &Path) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, "# original").unwrap();
+}
+
+#[test]
+fn from_flags_prefers_explicit_flags() {
+    assert_eq!(
+        OriginalsPolicy::from_flags(false, true, false),
+        OriginalsPolicy::Replace
+    );
+    assert_eq!(
+        OriginalsPolicy::from_flags(true, false, true),
+        OriginalsPolicy::Keep
+    );
+}
+
+#[test]
+fn from_flags_asks_only_when_interactive() {
+    assert_eq!(
+        OriginalsPolicy::from_flags(false, false, true),
+        OriginalsPolicy::Ask
+    );
+    assert_eq!(
+        OriginalsPolicy::from_flags(false, false, false),
+        OriginalsPolicy::Keep
+    );
+}
+
+#[test]
+fn claude_dir_follows_scope() {
+    let project = Path::new("/project");
+    let home = Path::new("/home/user");
+    assert_eq!(
+        claude_dir(Scope::Project, project, Some(home)),
+        Some(PathBuf::from("/project/.claude"))
+    );
+    assert_eq!(
+        claude_dir(Scope::Personal, project, Some(home)),
+        Some(PathBuf::from("/home/user/.claude"))
+    );
+    assert_eq!(claude_dir(Scope::Personal, project, None), None);
+}
+
+#[test]
+fn find_originals_matches_commands_agents_and_skills() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("commands/deploy.md"));
+    write(&dir.path().join("agents/review.md"));
+    fs::create_dir_all(dir.path().join("skills/pdf")).unwrap();
+
+    let skill_src = TempDir::new().unwrap();
+    let skill = Component::flattened(ComponentKind::Skill, "tools", "pdf", skill_src.path());
+    let components = vec![
+        command("deploy"),
+        command("missing"),
+        Component::new(
+            ComponentKind::Agent,
+            "review".to_string(),
+            PathBuf::from("agents/review.md"),
+        ),
+        skill,
+    ];
+
+    let found = find_originals(&components, dir.path());
+
+    let paths: Vec<PathBuf> = found.iter().map(|o| o.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            dir.path().join("commands/deploy.md"),
+            dir.path().join("agents/review.md"),
+            dir.path().join("skills/pdf"),
+        ]
+    );
+    assert_eq!(found[2].name, "tools_pdf");
+}
+
+#[test]
+fn find_originals_ignores_instructions_and_hooks() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("instructions/guide.md"));
+    write(&dir.path().join("hooks/pre.md"));
+    let components = vec![
+        Component::new(
+            ComponentKind::Instruction,
+            "guide".to_string(),
+            PathBuf::from("instructions/guide.md"),
+        ),
+        Component::new(
+            ComponentKind::Hook,
+            "pre".to_string(),
+            PathBuf::from("hooks/pre.json"),
+        ),
+    ];
+
+    assert!(find_originals(&components, dir.path()).is_empty());
+}
+
+/// `.claude/agents/<name>.md` と同じ内容のソースを持つ Agent コンポーネント
+fn agent_with_source(sources: &Path, name: &str) -> Component {
+    let source = sources.join(format!("agents/{}.md", name));
+    write(&source);
+    Component::new(ComponentKind::Agent, name.to_string(), source)
+}
+
+fn keys(kind: ComponentKind, names: &[&str]) -> HashSet<(ComponentKind, String)> {
+    names.iter().map(|n| (kind, n.to_string())).collect()
+}
+
+#[test]
+fn replace_originals_removes_only_fully_deployed_components() {
+    let dir = TempDir::new().unwrap();
+    let sources = TempDir::new().unwrap();
+    let deployed = dir.path().join("agents/deployed.md");
+    let failed = dir.path().join("agents/failed.md");
+    let skipped = dir.path().join("agents/skipped.md");
+    for path in [&deployed, &failed, &skipped] {
+        write(path);
+    }
+    let originals = find_originals(
+        &[
+            agent_with_source(sources.path(), "deployed"),
+            agent_with_source(sources.path(), "failed"),
+            agent_with_source(sources.path(), "skipped"),
+        ],
+        dir.path(),
+    );
+    let duplicated = keys(ComponentKind::Agent, &["deployed", "failed"]);
+    let failures = keys(ComponentKind::Agent, &["failed"]);
+
+    let report = replace_originals(&originals, &duplicated, &failures);
+
+    assert_eq!(report.removed, vec![deployed.clone()]);
+    assert_eq!(
+        report.kept,
+        vec![
+            (failed.clone(), "deployment failed".to_string()),
+            (
+                skipped.clone(),
+                "not deployed to a target that reads .claude/".to_string()
+            ),
+        ]
+    );
+    assert!(!deployed.exists());
+    assert!(failed.exists());
+    assert!(skipped.exists());
+}
+
+#[test]
+fn replace_originals_keeps_originals_that_differ_from_the_source() {
+    let dir = TempDir::new().unwrap();
+    let sources = TempDir::new().unwrap();
+    let original = dir.path().join("agents/review.md");
+    write(&original);
+    let component = agent_with_source(sources.path(), "review");
+    fs::write(&component.path, "# edited after import").unwrap();
+    let originals = find_originals(&[component], dir.path());
+
+    let report = replace_originals(
+        &originals,
+        &keys(ComponentKind::Agent, &["review"]),
+        &HashSet::new(),
+    );
+
+    assert!(report.removed.is_empty());
+    assert_eq!(
+        report.kept,
+        vec![(
+            original.clone(),
+            "content differs from the imported component".to_string()
+        )]
+    );
+    assert!(original.exists());
+}
+
+#[test]
+fn replace_originals_compares_skill_directories_file_by_file() {
+    let dir = TempDir::new().unwrap();
+    let sources = TempDir::new().unwrap();
+    for root in [dir.path().join("skills/pdf"), sources.path().join("pdf")] {
+        write(&root.join("SKILL.md"));
+        write(&root.join("scripts/run.sh"));
+    }
+    let skill = Component::new(
+        ComponentKind::Skill,
+        "pdf".to_string(),
+        sources.path().join("pdf"),
+    );
+    let originals = find_originals(&[skill], dir.path());
+    fs::write(sources.path().join("pdf/scripts/extra.sh"), "extra").unwrap();
+
+    let report = replace_originals(
+        &originals,
+        &keys(ComponentKind::Skill, &["pdf"]),
+        &HashSet::new(),
+    );
+    assert!(report.removed.is_empty());
+
+    fs::remove_file(sources.path().join("pdf/scripts/extra.sh")).unwrap();
+    let report = replace_originals(
+        &originals,
+        &keys(ComponentKind::Skill, &["pdf"]),
+        &HashSet::new(),
+    );
+    assert_eq!(report.removed, vec![dir.path().join("skills/pdf")]);
+}
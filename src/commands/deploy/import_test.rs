@@ -306,6 +306,7 @@ mod place_components_tests {
             scope: Scope::Project,
             project_root: project_dir.path(),
             plugin_root: plugin_dir.path(),
+            exclude_rules: ExcludeRules::default(),
             source_repo: "owner/repo",
             git_ref: "main",
             commit_sha: "abc123",
@@ -317,7 +318,7 @@ mod place_components_tests {
 
         let result = place_components(&["codex".to_string()], &components, &ctx, &mut registry);
 
-        assert_eq!(result.unwrap(), (0, 2));
+        assert_eq!(result.unwrap().counts(), (0, 2));
         assert!(!project_dir.path().join(".codex/hooks.json").exists());
     }
 
@@ -339,6 +340,7 @@ mod place_components_tests {
             scope: Scope::Project,
             project_root: project_dir.path(),
             plugin_root: plugin_dir.path(),
+            exclude_rules: ExcludeRules::default(),
             source_repo: "owner/repo",
             git_ref: "main",
             commit_sha: "abc123",
@@ -350,7 +352,7 @@ mod place_components_tests {
 
         let result = place_components(&["codex".to_string()], &components, &ctx, &mut registry);
 
-        assert_eq!(result.unwrap(), (0, 1));
+        assert_eq!(result.unwrap().counts(), (0, 1));
         // 既存ファイルは上書きされていない
         let after = std::fs::read(codex_dir.join("hooks.json")).unwrap();
         assert_eq!(after, original);
@@ -377,6 +379,7 @@ mod place_components_tests {
             scope: Scope::Project,
             project_root: project_dir.path(),
             plugin_root: plugin_dir.path(),
+            exclude_rules: ExcludeRules::default(),
             source_repo: "owner/repo",
             git_ref: "main",
             commit_sha: "abc123",
@@ -393,7 +396,12 @@ mod place_components_tests {
 
         let result = place_components(&["cursor".to_string()], &components, &ctx, &mut registry);
 
-        assert_eq!(result.unwrap(), (1, 0));
+        let tally = result.unwrap();
+        assert_eq!(tally.counts(), (1, 0));
+        assert!(tally
+            .duplicated
+            .contains(&(ComponentKind::Skill, "test-plugin_review".to_string())));
+        assert!(tally.failed.is_empty());
         let placed = project_dir.path().join(".cursor/skills/review");
         assert!(placed.join("SKILL.md").exists());
         assert!(!legacy.exists());
@@ -7,16 +7,46 @@
 //!    - `--scope` 未指定時: TUIでスコープ選択
 //! 2. ダウンロード
 //! 3. 配置
+//!
+//! `--sandbox` ではプロジェクトの代わりに `.plm/sandbox/<plugin>/` へ配置し、
+//! `--promote` で sandbox から正式なプロジェクトデプロイへ昇格する。
+//! `--promote` で `--target` を省略した場合は sandbox に配置したターゲットを使うため、
+//! ターゲット選択をダウンロード後まで遅らせる。
+//!
+//! `--dry-run` はダウンロードを一時キャッシュに行い、配置予定を表示するだけで終了する（`dry_run`）。
+//!
+//! 複数のプラグインや `marketplace/*` を指定した場合は、ターゲットとスコープを 1 回だけ選び、
+//! 各プラグインを順にインストールする（`batch`）。
+
+mod add_marketplace;
+mod batch;
+mod dry_run;
 
-use crate::commands::args::{InteractiveScopeArgs, MultiTargetArgs};
+use crate::application::{
+    enabled_targets, migrate_command_prefix, plan_dependencies, prepare_sandbox, record_sandbox,
+    remove_sandbox, remove_sandbox_command, render_warnings, sandbox_path, skip_disabled_targets,
+    DependencyState, WarningCategory, WarningCollector, PROJECT_CONFIG_FILE,
+};
+use crate::commands::args::{
+    CommandPrefixArgs, GithubOutputArgs, InteractiveScopeArgs, MultiTargetArgs, NotifyArgs,
+};
+use crate::commands::examples::Example;
+use crate::commands::Context;
 use crate::component::ComponentKind;
 use crate::install::format::{render_hook_success, HookRenderInput};
-use crate::install::{self, PlaceRequest, PlaceSuccess};
+use crate::install::{self, PlaceOutcome, PlaceRequest, PlaceSuccess};
+use crate::output::github::{GithubReporter, StepSummary, SummaryStatus};
+use crate::output::hint::print_post_deploy_hints;
 use crate::output::CommandSummary;
-use crate::target::{all_targets, parse_target, Scope};
+use crate::plugin::meta::Dependency;
+use crate::plugin::{Channel, MarketplaceContent, PackageCache, PackageCacheAccess};
+use crate::target::{parse_target, reserved_name_conflicts, Scope, Target};
 use crate::tui;
+use add_marketplace::AddMarketplace;
+use chrono::Utc;
 use clap::Parser;
-use std::env;
+use std::collections::HashMap;
+use std::path::Path;
 
 /// `PlaceSuccess` を表示用の `(stdout 行, stderr ブロック群)` に変換する pure function。
 ///
@@ -26,7 +56,11 @@ use std::env;
 /// 2. それ以外で `source_format` / `dest_format` が両方 `Some`: ` (Converted: src → dst)`
 /// 3. その他: 空
 ///
+/// 内容が同じで書き込みを省略した生成ファイルがあれば、suffix の後ろに
+/// ` (unchanged)`（全ファイル）または ` (N of M files unchanged)` を付ける。
+///
 /// stderr ブロック群は Hook のみ `render_hook_success` の `stderr_blocks` を返す。
+/// 添付アセットが巨大な場合は、その警告ブロックを末尾に追加する。
 pub fn render_place_success_to_strings(success: &PlaceSuccess) -> (String, Vec<String>) {
     let rendered = render_hook_success(HookRenderInput {
         component_kind: success.component_kind,
@@ -46,21 +80,89 @@ pub fn render_place_success_to_strings(success: &PlaceSuccess) -> (String, Vec<S
     };
 
     let stdout_line = format!(
-        "  + {} {}: {} -> {}{}",
+        "  + {} {}: {} -> {}{}{}",
         success.target,
         success.component_kind,
         success.component_name,
         success.target_path.display(),
-        suffix
+        suffix,
+        unchanged_suffix(success)
     );
 
-    (stdout_line, rendered.stderr_blocks)
+    let mut stderr_blocks = rendered.stderr_blocks;
+    if let Some(warning) = &success.assets_warning {
+        stderr_blocks.push(format!("  Warning: {}", warning));
+    }
+    if let Some(warning) = &success.symlink_warning {
+        stderr_blocks.push(format!("  Warning: {}", warning));
+    }
+    if let Some(warning) = &success.precompile_warning {
+        stderr_blocks.push(format!("  Warning: {}", warning));
+    }
+
+    (stdout_line, stderr_blocks)
+}
+
+/// 書き込みを省略した生成ファイル数の suffix（省略が無ければ空）
+///
+/// Hook の生成ファイルは設定 JSON 1 つとラッパースクリプト `script_count` 個。
+fn unchanged_suffix(success: &PlaceSuccess) -> String {
+    let total = success.script_count + 1;
+    match success.unchanged_files {
+        0 => String::new(),
+        n if n >= total => " (unchanged)".to_string(),
+        n => format!(" ({} of {} files unchanged)", n, total),
+    }
+}
+
+/// 配置結果を `GITHUB_STEP_SUMMARY` 用のサマリーに変換する pure function。
+///
+/// 1 プラグイン 1 行で、失敗したターゲットがあれば Details に列挙する。
+pub fn install_summary(result: &PlaceOutcome) -> StepSummary {
+    let mut failed_targets: Vec<&str> = Vec::new();
+    for failure in &result.failures {
+        if !failed_targets.contains(&failure.target.as_str()) {
+            failed_targets.push(&failure.target);
+        }
+    }
+
+    let (status, details) = if failed_targets.is_empty() {
+        (
+            SummaryStatus::Success,
+            format!("{} component(s) placed", result.successes.len()),
+        )
+    } else {
+        (
+            SummaryStatus::Failure,
+            format!(
+                "{} placed, {} failed (failed targets: {})",
+                result.successes.len(),
+                result.failures.len(),
+                failed_targets.join(", ")
+            ),
+        )
+    };
+
+    let mut summary = StepSummary::new("plm install");
+    summary.push(&result.plugin_name, status, details);
+    summary
 }
 
 #[derive(Debug, Parser)]
 pub struct Args {
-    /// owner/repo 形式、または plugin@marketplace 形式
-    pub source: String,
+    /// owner/repo 形式、plugin@marketplace 形式、または marketplace/*（複数指定可）
+    #[arg(required = true, value_name = "SOURCE")]
+    pub sources: Vec<String>,
+
+    /// `owner/repo/plugin` をマーケットプレイス `owner/repo` のプラグインとして扱い、
+    /// マーケットプレイスが未登録なら登録してからインストールする
+    /// （`plugin@owner/repo` は指定しなくても自動登録する）
+    #[arg(long, overrides_with = "no_add_marketplace")]
+    pub add_marketplace: bool,
+
+    /// `plugin@owner/repo` でもマーケットプレイスを自動登録せず、未登録ならエラーにする
+    #[arg(long, overrides_with = "add_marketplace")]
+    pub no_add_marketplace: bool,
 
     /// コンポーネント種別を指定（複数指定可、未指定なら全コンポーネント）
     #[arg(long = "type", value_enum)]
@@ -72,49 +174,459 @@ pub struct Args {
     #[command(flatten)]
     pub scope: InteractiveScopeArgs,
 
+    #[command(flatten)]
+    pub prefix: CommandPrefixArgs,
+
     /// キャッシュを無視して再ダウンロード
     #[arg(long)]
     pub force: bool,
 
+    /// 更新チャンネル（stable / beta）。指定するとそのチャンネルの最新リリースタグを
+    /// インストールし、以降の `plm update` もこのチャンネルに追従する
+    #[arg(long, value_enum)]
+    pub channel: Option<Channel>,
+
     /// Codex Hook 配置時の `[features] codex_hooks = true` 自動追記を抑止する。
     /// デフォルトでは `~/.codex/config.toml`（または project の `.codex/config.toml`）
     /// に追記される。`--no-enable-flag` を指定すると config.toml には触れない。
     #[arg(long = "no-enable-flag", action = clap::ArgAction::SetFalse, default_value_t = true)]
     pub enable_flag: bool,
+
+    /// ターゲット組み込みの予約名（`/help` など）と衝突するコンポーネントや、安全でないパス
+    /// （`..`・絶対パス・シンボリックリンク）を含むコンポーネントがあればエラーにする
+    /// （既定では警告し、安全でないパスのコンポーネントだけをスキップする）
+    #[arg(long)]
+    pub strict: bool,
+
+    /// 別プラグインが配置した同じ配置先のコンポーネントを上書きする
+    /// （既定では警告し、衝突したコンポーネントだけをスキップする）
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// プロジェクトを変更せず `.plm/sandbox/<plugin>/` に隔離デプロイして試す
+    #[arg(long, conflicts_with = "promote")]
+    pub sandbox: bool,
+
+    /// sandbox で試したプラグインをプロジェクトへ正式にデプロイし、sandbox を削除する
+    #[arg(long)]
+    pub promote: bool,
+
+    /// キャッシュにもターゲットにも書き込まず、配置予定（種別・名前・配置先）を表示する
+    #[arg(long, conflicts_with_all = ["sandbox", "promote"])]
+    pub dry_run: bool,
+
+    /// `--dry-run` の配置予定を JSON で出力する
+    #[arg(long, requires = "dry_run")]
+    pub json: bool,
+
+    #[command(flatten)]
+    pub github: GithubOutputArgs,
+
+    #[command(flatten)]
+    pub notify: NotifyArgs,
+
+    /// グローバル `--verbose`（除外されたパスの一覧などを表示する）
+    #[arg(from_global)]
+    pub verbose: bool,
+}
+
+/// `plm install` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm install owner/repo",
+        description: "Install a plugin from a GitHub repository",
+    },
+    Example {
+        command: "plm install owner/repo@v1.0.0",
+        description: "Install a specific tag or branch",
+    },
+    Example {
+        command: "plm install formatter@company-tools",
+        description: "Install a plugin from a registered marketplace",
+    },
+    Example {
+        command: "plm install formatter@company-tools linter@company-tools",
+        description: "Install several plugins at once",
+    },
+    Example {
+        command: "plm install formatter@owner/tools",
+        description: "Register the marketplace owner/tools if needed, then install from it",
+    },
+    Example {
+        command: "plm install owner/tools/formatter --add-marketplace",
+        description: "Same as above, written as owner/repo/plugin",
+    },
+    Example {
+        command: "plm install company-tools/*",
+        description: "Install every plugin in a marketplace (already installed ones are skipped)",
+    },
+    Example {
+        command: "plm install owner/repo --channel beta",
+        description: "Install the latest pre-release and keep following the beta channel",
+    },
+    Example {
+        command: "plm install owner/repo --target codex --scope project",
+        description: "Deploy to Codex in this project without prompts",
+    },
+    Example {
+        command: "plm install owner/repo --target auto",
+        description: "Deploy to the targets detected in this project",
+    },
+    Example {
+        command: "plm install formatter@company-tools --prefix fmt:",
+        description: "Prefix the plugin's command names (e.g. /fmt-deploy)",
+    },
+    Example {
+        command: "plm install formatter@company-tools --sandbox",
+        description: "Try a plugin in .plm/sandbox/ without touching the project",
+    },
+    Example {
+        command: "plm install formatter@company-tools --promote",
+        description: "Deploy a sandboxed plugin to the project for real",
+    },
+    Example {
+        command: "plm install formatter@company-tools --overwrite",
+        description: "Replace same-named components deployed by another plugin",
+    },
+    Example {
+        command: "plm install owner/repo --target copilot --scope project --dry-run",
+        description: "Show where each component would be placed without writing",
+    },
+    Example {
+        command: "plm install owner/repo --target codex --scope project --github-output",
+        description: "Report failures as GitHub Actions annotations in CI",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> std::result::Result<(), String> {
+    let reporter = args.github.reporter();
+    // dry-run は何も書き込まないため通知しない
+    let notify = (!args.dry_run).then(|| (args.notify.clone(), args.sources.join(", ")));
+    let warnings = WarningCollector::default();
+    let result = execute(args, ctx, &reporter, &warnings).await;
+    // 途中で失敗しても、それまでに出た警告は末尾にまとめて表示する
+    if let Some(rendered) = render_warnings("Warnings", &warnings.snapshot()) {
+        eprintln!("\n{}", rendered);
+    }
+    if let Err(e) = &result {
+        reporter.error(e);
+    }
+    if let Some((notify, source)) = notify {
+        notify.send(&install_notification(&source, &result));
+    }
+    result
+}
+
+/// 完了通知の本文
+///
+/// # Arguments
+///
+/// * `source` - Plugin source given to `plm install`.
+/// * `result` - Result of the install.
+fn install_notification(source: &str, result: &std::result::Result<(), String>) -> String {
+    match result {
+        Ok(()) => format!("{} installed", source),
+        Err(_) => format!("{} failed to install", source),
+    }
 }
 
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm install`.
-pub async fn run(args: Args) -> std::result::Result<(), String> {
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the install finishes.
+async fn execute(
+    mut args: Args,
+    ctx: &Context,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> std::result::Result<(), String> {
+    let mode = AddMarketplace::from_flags(args.add_marketplace, args.no_add_marketplace);
+    args.sources = add_marketplace::resolve_sources(&args.sources, mode, args.dry_run).await?;
+
+    let batch = batch::is_batch(&args.sources);
+    if batch && (args.sandbox || args.promote || args.dry_run) {
+        return Err(
+            "--sandbox, --promote and --dry-run install a single plugin at a time".to_string(),
+        );
+    }
+
     // Target and scope selection happen before download so the user can cancel
     // without paying the download cost.
-    let target_names: Vec<String> = match &args.target.target {
-        Some(targets) => targets.iter().map(|t| t.as_str().to_string()).collect(),
-        None => {
-            let available = all_targets();
-            let available_refs: Vec<&dyn crate::target::Target> =
-                available.iter().map(|t| t.as_ref()).collect();
-            let all_components = ComponentKind::all().to_vec();
-
-            tui::select_targets(&available_refs, &all_components).map_err(|e| e.to_string())?
-        }
+    let project_root = ctx.project_root.clone();
+    let requested_targets = match args.target.resolve(&project_root)? {
+        Some(targets) => Some(drop_disabled_targets(&project_root, targets)?),
+        None if args.promote => None,
+        None => Some(select_targets(&project_root)?),
     };
 
-    if target_names.is_empty() {
+    if requested_targets.as_ref().is_some_and(|t| t.is_empty()) {
         return Err("No targets selected".to_string());
     }
 
     let scope: Scope = match args.scope.scope {
+        Some(Scope::Personal) if args.sandbox => {
+            return Err("--sandbox always deploys with project scope".to_string());
+        }
         Some(s) => s,
+        None if args.sandbox => Scope::Project,
         None => tui::select_scope().map_err(|e| e.to_string())?,
     };
 
-    println!("\nSelected targets: {}", target_names.join(", "));
+    if args.dry_run {
+        let targets = requested_targets.unwrap_or_default();
+        return dry_run::run(&args, &targets, scope, &project_root).await;
+    }
+
+    if let Some(targets) = &requested_targets {
+        println!("\nSelected targets: {}", targets.join(", "));
+    }
     println!("Selected scope: {}", scope);
 
+    if batch {
+        let targets = requested_targets.unwrap_or_default();
+        return batch::run(&args, &targets, scope, &project_root, reporter, warnings).await;
+    }
+
+    let result = install_one(
+        &args,
+        &args.sources[0],
+        requested_targets,
+        scope,
+        &project_root,
+        reporter,
+        warnings,
+    )
+    .await?;
+
+    // CI では配置の失敗をステップの失敗として扱う
+    if reporter.is_enabled() && !result.failures.is_empty() {
+        return Err(format!(
+            "{} component(s) failed to install",
+            result.failures.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// 依存プラグインの配置先（本体と同じ）
+struct DependencyDeploy<'a> {
+    target_names: &'a [String],
+    scope: Scope,
+    project_root: &'a Path,
+}
+
+/// 未インストールの依存プラグインを、依存される側から順にインストールする
+///
+/// 依存先は本体と同じターゲット・スコープへ配置する。依存先のインストールに
+/// 失敗したら本体のインストールを中断する。
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+/// * `cache` - Package cache the plugin was downloaded into.
+/// * `package` - Downloaded plugin whose dependencies are installed.
+/// * `dependencies` - Direct dependencies from its `plugin.json`.
+/// * `deploy` - Targets, scope and project root used for the plugin itself.
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the install finishes.
+async fn install_dependencies(
+    args: &Args,
+    cache: &PackageCache,
+    package: &MarketplaceContent,
+    dependencies: &[Dependency],
+    deploy: DependencyDeploy<'_>,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> std::result::Result<(), String> {
+    let root = match package.marketplace() {
+        Some(marketplace) => format!("{}/{}", marketplace, package.name()),
+        None => package.name().to_string(),
+    };
+    let mut fetched = Vec::new();
+    let plan = collect_dependency_states(cache, dependencies, &mut fetched)
+        .await
+        .and_then(|states| {
+            plan_dependencies(&root, dependencies, |dependency| {
+                states
+                    .get(&dependency.key())
+                    .cloned()
+                    .ok_or_else(|| format!("Dependency '{}' could not be resolved", dependency))
+            })
+        });
+    let plan = match plan {
+        Ok(plan) => plan,
+        Err(e) => {
+            discard_fetched(cache, &fetched);
+            return Err(e);
+        }
+    };
+
+    println!("\nDependencies:");
+    for (dependency, version) in &plan.satisfied {
+        println!("  = {} (installed: {})", dependency, version);
+    }
+    for dependency in &plan.install {
+        println!("  + {}", dependency);
+    }
+
+    let dependency_args = dependency_args(args);
+    for dependency in &plan.install {
+        println!("\n==> Installing dependency {}", dependency.key());
+        let outcome = Box::pin(install_one(
+            &dependency_args,
+            &dependency.source(),
+            Some(deploy.target_names.to_vec()),
+            deploy.scope,
+            deploy.project_root,
+            reporter,
+            warnings,
+        ))
+        .await
+        .map_err(|e| format!("Failed to install dependency '{}': {}", dependency.key(), e))?;
+        if !outcome.failures.is_empty() {
+            return Err(format!(
+                "Failed to install dependency '{}': {} component(s) failed to install",
+                dependency.key(),
+                outcome.failures.len()
+            ));
+        }
+    }
+    if !plan.install.is_empty() {
+        println!("\n==> Installing {}", package.name());
+    }
+    Ok(())
+}
+
+/// 依存先のインストールに使う引数
+///
+/// `--prefix` / `--type` / `--force` / `--channel` / `--overwrite` はコマンドラインで指定した
+/// プラグイン自身への指定のため引き継がない（依存先は自身の名前と全コンポーネントで配置し、
+/// キャッシュ済みなら再利用する）。配置先（ターゲット・スコープ・sandbox）と、
+/// 書き込みを控える指定（`--strict` / `--no-enable-flag`）だけを引き継ぐ。
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+fn dependency_args(args: &Args) -> Args {
+    Args {
+        sources: Vec::new(),
+        add_marketplace: false,
+        no_add_marketplace: false,
+        component_type: None,
+        target: args.target.clone(),
+        scope: args.scope.clone(),
+        prefix: CommandPrefixArgs { prefix: None },
+        force: false,
+        channel: None,
+        enable_flag: args.enable_flag,
+        strict: args.strict,
+        overwrite: false,
+        sandbox: args.sandbox,
+        promote: false,
+        dry_run: false,
+        json: false,
+        github: args.github.clone(),
+        notify: args.notify.clone(),
+        verbose: args.verbose,
+    }
+}
+
+/// 依存グラフに現れる依存先の状態を集める
+///
+/// インストール済みならそのバージョンを、未インストールならダウンロードして
+/// マニフェストのバージョンと依存を読む（ダウンロードしたものはインストール時に再利用される）。
+/// ダウンロードした依存先は、途中で失敗した場合も含めて `fetched` に積む。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache used to check installed plugins and store downloads.
+/// * `dependencies` - Direct dependencies of the plugin being installed.
+/// * `fetched` - Receives the dependencies downloaded into `cache`.
+async fn collect_dependency_states(
+    cache: &PackageCache,
+    dependencies: &[Dependency],
+    fetched: &mut Vec<Dependency>,
+) -> std::result::Result<HashMap<String, DependencyState>, String> {
+    let mut states = HashMap::new();
+    let mut queue = dependencies.to_vec();
+    while let Some(dependency) = queue.pop() {
+        let key = dependency.key();
+        if states.contains_key(&key) {
+            continue;
+        }
+        let state = if cache.is_cached(Some(&dependency.marketplace), &dependency.name) {
+            let manifest = cache
+                .load_manifest(Some(&dependency.marketplace), &dependency.name)
+                .map_err(|e| format!("Failed to read dependency '{}': {}", key, e))?;
+            DependencyState::Installed {
+                version: manifest.version,
+            }
+        } else {
+            let package =
+                install::download_plugin_with_cache(&dependency.source(), false, None, cache)
+                    .await
+                    .map_err(|e| format!("Failed to download dependency '{}': {}", key, e))?;
+            fetched.push(dependency.clone());
+            let dependencies = package.manifest().parsed_dependencies()?;
+            queue.extend(dependencies.iter().cloned());
+            DependencyState::Missing {
+                version: package.manifest().version.clone(),
+                dependencies,
+            }
+        };
+        states.insert(key, state);
+    }
+    Ok(states)
+}
+
+/// 依存解決のためにダウンロードした依存先をキャッシュから取り除く
+///
+/// 解決に失敗した場合はどれもインストールしないため、未配置のキャッシュを
+/// インストール済みとして残さないよう削除する（削除の失敗は無視する）。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache the dependencies were downloaded into.
+/// * `fetched` - Dependencies downloaded by [`collect_dependency_states`].
+fn discard_fetched(cache: &dyn PackageCacheAccess, fetched: &[Dependency]) {
+    for dependency in fetched {
+        let _ = cache.remove(Some(&dependency.marketplace), &dependency.name);
+    }
+}
+
+/// 1 プラグインをダウンロードして配置する
+///
+/// 配置の一部が失敗しても `Ok` で配置結果を返す（失敗の扱いは呼び出し元が決める）。
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+/// * `source` - Source of the plugin to install.
+/// * `requested_targets` - Selected targets, or `None` to use the sandbox targets on `--promote`.
+/// * `scope` - Selected scope.
+/// * `project_root` - Project root to deploy into.
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the install finishes.
+async fn install_one(
+    args: &Args,
+    source: &str,
+    requested_targets: Option<Vec<String>>,
+    scope: Scope,
+    project_root: &Path,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> std::result::Result<PlaceOutcome, String> {
+    let cache = crate::plugin::PackageCache::new().map_err(|e| e.to_string())?;
+    let _lock = install::lock_plugin(source, args.channel, &cache).map_err(|e| e.to_string())?;
+
     println!("\nDownloading plugin...");
-    let package = install::download_plugin(&args.source, args.force)
+    let package = install::download_plugin_with_cache(source, args.force, args.channel, &cache)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -129,12 +641,67 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
         if let Some(ref commit_sha) = meta.commit_sha {
             println!("  SHA: {}", commit_sha);
         }
+        if let Some(channel) = meta.channel {
+            println!("  Channel: {}", channel);
+        }
     }
 
     if let Some(ref desc) = package.manifest().description {
         println!("  Description: {}", desc);
     }
 
+    let sandbox = if args.promote {
+        let sandbox = crate::plugin::meta::load_meta(package.path())
+            .and_then(|m| m.sandbox)
+            .ok_or_else(|| {
+                format!(
+                    "'{}' has no sandbox to promote; install it with --sandbox first",
+                    package.name()
+                )
+            })?;
+        Some(sandbox)
+    } else {
+        None
+    };
+
+    let target_names = match requested_targets {
+        Some(targets) => targets,
+        None => {
+            let targets = match sandbox {
+                Some(sandbox) if !sandbox.targets.is_empty() => {
+                    drop_disabled_targets(project_root, sandbox.targets)?
+                }
+                _ => select_targets(project_root)?,
+            };
+            if targets.is_empty() {
+                return Err("No targets selected".to_string());
+            }
+            println!("\nPromoting to targets: {}", targets.join(", "));
+            targets
+        }
+    };
+
+    // --promote は sandbox へのインストール時に依存を解決済み
+    if !args.promote {
+        let dependencies = package.manifest().parsed_dependencies()?;
+        if !dependencies.is_empty() {
+            install_dependencies(
+                args,
+                &cache,
+                &package,
+                &dependencies,
+                DependencyDeploy {
+                    target_names: &target_names,
+                    scope,
+                    project_root,
+                },
+                reporter,
+                warnings,
+            )
+            .await?;
+        }
+    }
+
     println!("\nComponents:");
     if let Some(ref skills) = package.manifest().skills {
         println!("  - Skills: {}", skills);
@@ -146,26 +713,59 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
         println!("  - Commands: {}", commands);
     }
 
+    if args.prefix.apply_to(package.path())? {
+        // 以前の名前で配置済みのコマンドがあれば撤去してから新しい名前で配置する
+        let plugin_id = crate::plugin::resolve_id(package.id(), package.name());
+        migrate_command_prefix(&cache, plugin_id, package.marketplace(), project_root)?;
+    }
+
     let type_filter = args.component_type.as_deref();
     let scanned = install::scan_plugin(&package, type_filter)?;
 
-    let targets: Vec<Box<dyn crate::target::Target>> = target_names
+    let excluded = scanned.excluded_paths();
+    if args.verbose && !excluded.is_empty() {
+        println!("\nExcluded {} path(s) by exclude rules:", excluded.len());
+        for path in &excluded {
+            println!("  - {}", path.display());
+        }
+    }
+
+    let targets: Vec<Box<dyn Target>> = target_names
         .iter()
         .map(|name| parse_target(name).map_err(|e| e.to_string()))
         .collect::<Result<Vec<_>, _>>()?;
 
-    println!("\nPlacing to targets...");
+    check_reserved_names(&scanned, &targets, args, reporter, warnings)?;
 
-    let project_root = env::current_dir().map_err(|e| e.to_string())?;
+    let deploy_root = if args.sandbox {
+        let path = sandbox_path(project_root, package.name());
+        prepare_sandbox(&path)?;
+        path
+    } else {
+        project_root.to_path_buf()
+    };
 
-    let result = install::place_plugin(&PlaceRequest {
+    let request = PlaceRequest {
         scanned: &scanned,
         targets: &targets,
         scope,
-        project_root: &project_root,
+        project_root: &deploy_root,
         enable_codex_hooks_flag: args.enable_flag,
-    });
-    install::update_meta_after_place(package.path(), &result);
+        overwrite: args.overwrite,
+    };
+    if args.strict {
+        check_unsafe_paths(&request)?;
+    }
+
+    println!("\nPlacing to targets...");
+
+    let result = install::place_plugin(&request);
+    // sandbox への配置は正式なデプロイではないため、ステータスと配置記録には残さない
+    if !args.sandbox {
+        install::update_meta_after_place(package.path(), &result);
+        install::record_only_kinds(package.path(), type_filter);
+        install::record_placements(&scanned, &result, &deploy_root);
+    }
 
     for ffo in &result.feature_flags {
         if ffo.applied {
@@ -179,6 +779,10 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
                 ffo.target_path.display(),
                 reason
             );
+            reporter.warning(
+                &format!("Skipped codex_hooks feature flag: {}", reason),
+                Some(&ffo.target_path),
+            );
         }
     }
     // --no-enable-flag の案内は、実際に Codex Hook を配置したときのみ表示する。
@@ -202,8 +806,29 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
         for success in result.successes.iter().filter(|s| &s.target == target_name) {
             let (stdout_line, stderr_blocks) = render_place_success_to_strings(success);
             println!("{}", stdout_line);
-            for block in &stderr_blocks {
-                eprintln!("{}", block);
+            if args.verbose {
+                for diff in &success.file_diffs {
+                    print!("{}", diff);
+                }
+            }
+            // 通常は末尾の Warnings にまとめ、--verbose のときだけその場でも表示する
+            if args.verbose {
+                for block in &stderr_blocks {
+                    eprintln!("{}", block);
+                }
+            }
+            warnings.record_placement(success);
+            for warning in &success.hook_warnings {
+                reporter.warning(&warning.to_string(), Some(&success.target_path));
+            }
+            if let Some(warning) = &success.assets_warning {
+                reporter.warning(warning, Some(&success.target_path));
+            }
+            if let Some(warning) = &success.symlink_warning {
+                reporter.warning(warning, Some(&success.target_path));
+            }
+            if let Some(warning) = &success.precompile_warning {
+                reporter.warning(warning, Some(&success.target_path));
             }
         }
 
@@ -212,9 +837,18 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
                 "  x {} {}: {} - {}",
                 failure.target, failure.component_kind, failure.component_name, failure.error
             );
+            reporter.error(&format!(
+                "Failed to place {} {} to {}: {}",
+                failure.component_kind, failure.component_name, failure.target, failure.error
+            ));
             target_success = false;
         }
 
+        for conflict in result.conflicts.iter().filter(|c| &c.target == target_name) {
+            println!("  - {}", conflict.message());
+            reporter.warning(&conflict.message(), Some(&conflict.path));
+        }
+
         if !target_success {
             println!("  {} - FAILED", target_name);
         }
@@ -223,9 +857,179 @@ pub async fn run(args: Args) -> std::result::Result<(), String> {
     let summary = CommandSummary::format(result.successes.len(), result.failures.len());
     println!("\n{} {}", summary.prefix, summary.message);
 
+    let mut placed_targets: Vec<String> = Vec::new();
+    for success in &result.successes {
+        if !placed_targets.contains(&success.target) {
+            placed_targets.push(success.target.clone());
+        }
+    }
+    if args.sandbox {
+        record_sandbox(package.path(), &deploy_root, placed_targets, Utc::now())?;
+        print_sandbox_guide(
+            &deploy_root,
+            source,
+            &remove_sandbox_command(
+                crate::plugin::resolve_id(package.id(), package.name()),
+                package.marketplace(),
+            ),
+        );
+    } else {
+        print_post_deploy_hints(&placed_targets);
+    }
+    if args.promote {
+        if result.failures.is_empty() {
+            if let Some(path) = remove_sandbox(package.path())? {
+                println!("  Removed sandbox {}", path.display());
+            }
+        } else {
+            println!("  Sandbox kept because some components failed to place.");
+        }
+    }
+
+    reporter.write_summary(&install_summary(&result));
+
+    Ok(result)
+}
+
+/// ターゲットの予約名と衝突するコンポーネントを警告する
+///
+/// `--strict` の場合は配置前にエラーで中断する。
+///
+/// # Arguments
+///
+/// * `scanned` - Scanned plugin with the command prefix to apply.
+/// * `targets` - Targets the plugin is deployed to.
+/// * `args` - Parsed CLI arguments (`--strict` / `--verbose`).
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the install finishes.
+fn check_reserved_names(
+    scanned: &install::ScannedPlugin,
+    targets: &[Box<dyn Target>],
+    args: &Args,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> std::result::Result<(), String> {
+    let conflicts: Vec<_> = targets
+        .iter()
+        .flat_map(|t| {
+            reserved_name_conflicts(
+                t.as_ref(),
+                &scanned.components,
+                scanned.command_prefix.as_ref(),
+            )
+        })
+        .collect();
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    if args.strict {
+        let messages: Vec<String> = conflicts.iter().map(|c| c.message()).collect();
+        return Err(format!(
+            "Reserved name conflicts (--strict):\n  {}",
+            messages.join("\n  ")
+        ));
+    }
+    for conflict in &conflicts {
+        if args.verbose {
+            eprintln!("  Warning: {}", conflict.message());
+        }
+        reporter.warning(&conflict.message(), None);
+        warnings.push(WarningCategory::ReservedName, conflict.message());
+    }
     Ok(())
 }
 
+/// 安全でないパスを含むコンポーネントがあれば配置前にエラーで中断する（`--strict`）
+///
+/// `--strict` なしでは `place_plugin` が該当コンポーネントだけをスキップして失敗に記録し、
+/// コピー元のシンボリックリンクは辿らずにスキップして警告する。
+///
+/// # Arguments
+///
+/// * `request` - Placement request that is about to be executed.
+fn check_unsafe_paths(request: &PlaceRequest) -> std::result::Result<(), String> {
+    let unsafe_paths = install::find_unsafe_paths(request);
+    if unsafe_paths.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<String> = unsafe_paths
+        .iter()
+        .map(|f| {
+            format!(
+                "{} {} {}: {}",
+                f.target, f.component_kind, f.component_name, f.error
+            )
+        })
+        .collect();
+    Err(format!(
+        "Unsafe paths detected (--strict):\n  {}",
+        messages.join("\n  ")
+    ))
+}
+
+/// TUI でターゲットを選択する（プロジェクトで無効なターゲットは選択肢に出さない）
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/config.json`.
+fn select_targets(project_root: &Path) -> std::result::Result<Vec<String>, String> {
+    let available = enabled_targets(project_root);
+    if available.is_empty() {
+        return Err(format!(
+            "No targets are enabled in {} (see plm target list)",
+            PROJECT_CONFIG_FILE
+        ));
+    }
+    let available_refs: Vec<&dyn crate::target::Target> =
+        available.iter().map(|t| t.as_ref()).collect();
+    let all_components = ComponentKind::all().to_vec();
+
+    tui::select_targets(&available_refs, &all_components).map_err(|e| e.to_string())
+}
+
+/// プロジェクトで無効なターゲットを除き、除いたターゲットを表示する
+///
+/// 指定したターゲットがすべて無効ならエラーにする。
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/config.json`.
+/// * `targets` - Requested target names.
+fn drop_disabled_targets(
+    project_root: &Path,
+    targets: Vec<String>,
+) -> std::result::Result<Vec<String>, String> {
+    let (kept, skipped) = skip_disabled_targets(project_root, targets);
+    if skipped.is_empty() {
+        return Ok(kept);
+    }
+    println!(
+        "Skipping disabled target(s): {} (see plm target list)",
+        skipped.join(", ")
+    );
+    if kept.is_empty() {
+        return Err("No enabled targets selected".to_string());
+    }
+    Ok(kept)
+}
+
+/// sandbox の配置先と試し方を案内する
+///
+/// # Arguments
+///
+/// * `path` - Sandbox directory.
+/// * `source` - Source given to `plm install` (reused for `--promote`).
+/// * `remove_command` - Command that removes only the sandbox.
+fn print_sandbox_guide(path: &Path, source: &str, remove_command: &str) {
+    println!("\nSandbox: {}", path.display());
+    println!(
+        "  Try it with Claude Code by passing this path: claude --add-dir {}",
+        path.display()
+    );
+    println!("  Promote to the project: plm install {} --promote", source);
+    println!("  Remove the sandbox:     {}", remove_command);
+}
+
 #[cfg(test)]
 #[path = "install_test.rs"]
 mod tests;
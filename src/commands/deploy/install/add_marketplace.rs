@@ -0,0 +1,195 @@
+//! マーケットプレイスの自動登録（`--add-marketplace`）
+//!
+//! `plugin@owner/repo` のようにマーケットプレイスを名前ではなくソースで指定した場合、
+//! 未登録ならマーケットプレイスを登録してから `plugin@<name>` としてインストールする
+//! （名前は `plm marketplace add` と同じくリポジトリ名を [`normalize_name`] したもの）。
+//! 同じソースのマーケットプレイスが登録済みならそれを使う。
+//!
+//! `--add-marketplace` を付けると `owner/repo/plugin` の末尾をプラグイン名として扱う
+//! （付けなければ従来どおりリポジトリのサブディレクトリを直接インストールする）。
+//! `--no-add-marketplace` では自動登録せず、未登録のマーケットプレイスはエラーになる。
+
+use super::batch::wildcard_marketplace;
+use crate::commands::manage::marketplace::register_marketplace;
+use crate::marketplace::{
+    normalize_name, normalize_source_path, MarketplaceConfig, MarketplaceRegistration,
+    MarketplaceSourceRef, SourceLocator,
+};
+use crate::source::ArchiveSource;
+
+/// 自動登録の方針
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddMarketplace {
+    /// `plugin@owner/repo` だけ自動登録する（既定）
+    Auto,
+    /// `owner/repo/plugin` もマーケットプレイスのプラグインとして扱う（`--add-marketplace`）
+    Always,
+    /// 自動登録しない（`--no-add-marketplace`）
+    Never,
+}
+
+impl AddMarketplace {
+    /// # Arguments
+    ///
+    /// * `add` - `--add-marketplace` was given.
+    /// * `no_add` - `--no-add-marketplace` was given (the last one wins in clap).
+    pub fn from_flags(add: bool, no_add: bool) -> Self {
+        match (add, no_add) {
+            (true, _) => AddMarketplace::Always,
+            (_, true) => AddMarketplace::Never,
+            _ => AddMarketplace::Auto,
+        }
+    }
+}
+
+/// ソースで指定されたマーケットプレイスのプラグイン
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketplaceSpec {
+    /// マーケットプレイスのソース（`owner/repo[/subdir][#branch|@tag]` など）
+    pub source: String,
+    /// プラグイン名（`*` ならマーケットプレイスの全プラグイン）
+    pub plugin: String,
+}
+
+impl MarketplaceSpec {
+    /// 登録済みの名前で `plm install` に渡す形式にする
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - Registered marketplace name.
+    pub fn installable(&self, marketplace: &str) -> String {
+        if self.plugin == "*" {
+            format!("{}/*", marketplace)
+        } else {
+            format!("{}@{}", self.plugin, marketplace)
+        }
+    }
+}
+
+/// `plm install` のソースがマーケットプレイスをソースで指定しているか判定する
+///
+/// # Arguments
+///
+/// * `input` - Source given to `plm install`.
+/// * `mode` - Auto-registration policy.
+pub fn parse_spec(input: &str, mode: AddMarketplace) -> Result<Option<MarketplaceSpec>, String> {
+    if mode == AddMarketplace::Never || ArchiveSource::matches(input) {
+        return Ok(None);
+    }
+    // `plugin@owner/repo`（`owner/repo@ref` は GitHub の ref 指定なので対象外）
+    if let Some((plugin, source)) = input.split_once('@') {
+        if !plugin.is_empty() && !plugin.contains('/') && source.contains('/') {
+            return Ok(Some(MarketplaceSpec {
+                source: source.to_string(),
+                plugin: plugin.to_string(),
+            }));
+        }
+        return Ok(None);
+    }
+    if mode == AddMarketplace::Auto || wildcard_marketplace(input).is_some() {
+        return Ok(None);
+    }
+    match input.rsplit_once('/') {
+        Some((source, plugin)) if source.contains('/') && !plugin.is_empty() => {
+            Ok(Some(MarketplaceSpec {
+                source: source.to_string(),
+                plugin: plugin.to_string(),
+            }))
+        }
+        _ => Err(format!(
+            "--add-marketplace needs the plugin in the source: use owner/repo/<plugin> or <plugin>@owner/repo (got '{}')",
+            input
+        )),
+    }
+}
+
+/// マーケットプレイスをソースで指定したものを登録済みの名前に置き換える
+///
+/// 未登録なら登録する（`dry_run` ではキャッシュにも設定にも書き込まないためエラー）。
+///
+/// # Arguments
+///
+/// * `sources` - Sources given to `plm install`.
+/// * `mode` - Auto-registration policy.
+/// * `dry_run` - `--dry-run` was given.
+pub(super) async fn resolve_sources(
+    sources: &[String],
+    mode: AddMarketplace,
+    dry_run: bool,
+) -> Result<Vec<String>, String> {
+    let mut config: Option<MarketplaceConfig> = None;
+    let mut resolved = Vec::with_capacity(sources.len());
+    for source in sources {
+        let Some(spec) = parse_spec(source, mode)? else {
+            resolved.push(source.clone());
+            continue;
+        };
+        let config = match &mut config {
+            Some(config) => config,
+            None => config.insert(MarketplaceConfig::load()?),
+        };
+        let name = ensure_registered(config, &spec.source, dry_run).await?;
+        resolved.push(spec.installable(&name));
+    }
+    Ok(resolved)
+}
+
+/// 同じソースのマーケットプレイスがあればその名前を、無ければ登録して名前を返す
+///
+/// # Arguments
+///
+/// * `config` - Loaded marketplace config.
+/// * `source` - Marketplace source (`owner/repo[/subdir][#branch|@tag]`, URL or local path).
+/// * `dry_run` - `--dry-run` was given.
+async fn ensure_registered(
+    config: &mut MarketplaceConfig,
+    source: &str,
+    dry_run: bool,
+) -> Result<String, String> {
+    let SourceLocator {
+        source: source_ref,
+        git_ref,
+        subdir,
+    } = MarketplaceSourceRef::parse_locator(source).map_err(|e| e.to_string())?;
+    let source_path = subdir
+        .as_deref()
+        .map(normalize_source_path)
+        .transpose()?
+        .flatten();
+
+    if let Some(existing) = config.find_by_source(&source_ref, source_path.as_deref()) {
+        return Ok(existing.name.clone());
+    }
+    if dry_run {
+        return Err(format!(
+            "No marketplace is registered for {}; --dry-run does not register one (run `plm marketplace add {}` first)",
+            source, source
+        ));
+    }
+    let name = normalize_name(source_ref.name())?;
+    if config.exists(&name) {
+        return Err(format!(
+            "Marketplace '{}' is already registered with a different source. \
+             Register {} under another name with `plm marketplace add {} --name <name>`.",
+            name, source, source
+        ));
+    }
+
+    let full_name = source_ref.full_name();
+    register_marketplace(
+        config,
+        MarketplaceRegistration {
+            name: name.clone(),
+            source: source_ref,
+            source_path,
+            git_ref,
+        },
+    )
+    .await?;
+    println!("Registered marketplace '{}' ({})", name, full_name);
+    Ok(name)
+}
+
+#[cfg(test)]
+#[path = "add_marketplace_test.rs"]
+mod tests;
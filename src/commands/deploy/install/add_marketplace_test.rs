@@ -0,0 +1,112 @@
+use super::*;
+
+fn spec(source: &str, plugin: &str) -> Option<MarketplaceSpec> {
+    Some(MarketplaceSpec {
+        source: source.to_string(),
+        plugin: plugin.to_string(),
+    })
+}
+
+#[test]
+fn from_flags_prefers_add_marketplace() {
+    assert_eq!(
+        AddMarketplace::from_flags(false, false),
+        AddMarketplace::Auto
+    );
+    assert_eq!(
+        AddMarketplace::from_flags(true, false),
+        AddMarketplace::Always
+    );
+    assert_eq!(
+        AddMarketplace::from_flags(false, true),
+        AddMarketplace::Never
+    );
+}
+
+#[test]
+fn parse_spec_detects_plugin_at_marketplace_source() {
+    assert_eq!(
+        parse_spec("formatter@owner/tools", AddMarketplace::Auto).unwrap(),
+        spec("owner/tools", "formatter")
+    );
+    assert_eq!(
+        parse_spec("formatter@owner/tools#dev", AddMarketplace::Always).unwrap(),
+        spec("owner/tools#dev", "formatter")
+    );
+}
+
+#[test]
+fn parse_spec_leaves_other_sources_alone() {
+    for input in [
+        "formatter@company-tools",
+        "owner/repo@v1.0.0",
+        "owner/repo",
+        "owner/repo/plugins/formatter",
+        "company-tools/*",
+        "./formatter.zip",
+        "formatter",
+    ] {
+        assert_eq!(
+            parse_spec(input, AddMarketplace::Auto).unwrap(),
+            None,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn parse_spec_never_keeps_the_legacy_behavior() {
+    assert_eq!(
+        parse_spec("formatter@owner/tools", AddMarketplace::Never).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn parse_spec_with_add_marketplace_splits_the_last_segment() {
+    assert_eq!(
+        parse_spec("owner/tools/formatter", AddMarketplace::Always).unwrap(),
+        spec("owner/tools", "formatter")
+    );
+    assert_eq!(
+        parse_spec("owner/tools/market/formatter", AddMarketplace::Always).unwrap(),
+        spec("owner/tools/market", "formatter")
+    );
+    assert_eq!(
+        parse_spec("owner/tools/*", AddMarketplace::Always).unwrap(),
+        spec("owner/tools", "*")
+    );
+    // 登録済みマーケットプレイスの指定はそのまま
+    assert_eq!(
+        parse_spec("company-tools/*", AddMarketplace::Always).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn parse_spec_with_add_marketplace_requires_a_plugin() {
+    let err = parse_spec("owner/tools", AddMarketplace::Always).unwrap_err();
+
+    assert!(err.contains("owner/repo/<plugin>"));
+}
+
+#[test]
+fn installable_uses_the_registered_name() {
+    assert_eq!(
+        MarketplaceSpec {
+            source: "owner/tools".to_string(),
+            plugin: "formatter".to_string(),
+        }
+        .installable("tools"),
+        "formatter@tools"
+    );
+    assert_eq!(
+        MarketplaceSpec {
+            source: "owner/tools".to_string(),
+            plugin: "*".to_string(),
+        }
+        .installable("tools"),
+        "tools/*"
+    );
+}
@@ -0,0 +1,260 @@
+//! 複数プラグインの一括インストール（`plm install a b c` / `plm install marketplace/*`）
+//!
+//! `marketplace/*` はマーケットプレイスの全エントリ（`plugin@marketplace`）に展開する。
+//! キャッシュが無いマーケットプレイスは展開前に取得する。
+//! 1 件失敗しても残りを続け、最後にプラグインごとの結果をテーブルで表示する。
+
+use super::Args;
+use crate::application::WarningCollector;
+use crate::marketplace::{MarketplaceCache, MarketplaceConfig, MarketplaceRegistry};
+use crate::output::github::GithubReporter;
+use crate::plugin::{GithubCacheId, PackageCache, PackageCacheAccess};
+use crate::repo;
+use crate::target::Scope;
+use comfy_table::{presets::UTF8_FULL, Cell, Color, Table};
+use std::path::Path;
+
+/// マーケットプレイスの全プラグインを表すワイルドカードの接尾辞
+const WILDCARD_SUFFIX: &str = "/*";
+
+/// 1 プラグイン分の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchStatus {
+    Installed,
+    /// インストール済みのためスキップした
+    Skipped,
+    Failed(String),
+}
+
+/// 一括インストールの 1 行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    pub source: String,
+    pub status: BatchStatus,
+}
+
+/// `marketplace/*` 形式ならマーケットプレイス名を返す
+///
+/// # Arguments
+///
+/// * `source` - Source given to `plm install`.
+pub fn wildcard_marketplace(source: &str) -> Option<&str> {
+    source
+        .strip_suffix(WILDCARD_SUFFIX)
+        .filter(|name| !name.is_empty() && !name.contains('/'))
+}
+
+/// 一括インストールとして扱うか（複数指定、またはワイルドカードを含む）
+///
+/// # Arguments
+///
+/// * `sources` - Sources given to `plm install`.
+pub fn is_batch(sources: &[String]) -> bool {
+    sources.len() > 1 || sources.iter().any(|s| wildcard_marketplace(s).is_some())
+}
+
+/// マーケットプレイスの全エントリを `plugin@marketplace` の並びにする
+///
+/// # Arguments
+///
+/// * `cache` - Marketplace cache to expand.
+pub fn expand_marketplace(cache: &MarketplaceCache) -> Vec<String> {
+    cache
+        .plugins
+        .iter()
+        .map(|plugin| format!("{}@{}", plugin.name, cache.name))
+        .collect()
+}
+
+/// プラグインがインストール済み（キャッシュ済み）か
+///
+/// 名前だけの指定はマーケットプレイスを検索するまで特定できないため、未インストール扱いにする。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache to check.
+/// * `source` - Expanded source (`plugin@marketplace` or `owner/repo`).
+pub fn is_installed(cache: &dyn PackageCacheAccess, source: &str) -> bool {
+    match source.split_once('@') {
+        Some((plugin, marketplace)) if !plugin.contains('/') => {
+            cache.is_cached(Some(marketplace), plugin)
+        }
+        _ if source.contains('/') => repo::from_url(source)
+            .map(|repo| cache.is_cached(None, GithubCacheId::from_repo(&repo).as_str()))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// 結果のサマリーテーブル
+///
+/// # Arguments
+///
+/// * `entries` - Per-plugin results in install order.
+pub fn render_summary(entries: &[BatchEntry]) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec!["Plugin", "Result", "Details"]);
+    for entry in entries {
+        let (label, color, details) = match &entry.status {
+            BatchStatus::Installed => ("Installed", Color::Green, ""),
+            BatchStatus::Skipped => ("Skipped", Color::DarkGrey, "already installed"),
+            BatchStatus::Failed(e) => ("Failed", Color::Red, e.as_str()),
+        };
+        table.add_row(vec![
+            Cell::new(&entry.source),
+            Cell::new(label).fg(color),
+            Cell::new(details),
+        ]);
+    }
+    table.to_string()
+}
+
+/// 一括インストール全体の結果（1 件でも失敗していればエラー）
+///
+/// # Arguments
+///
+/// * `entries` - Per-plugin results.
+pub fn batch_result(entries: &[BatchEntry]) -> Result<(), String> {
+    let failed = entries
+        .iter()
+        .filter(|e| matches!(e.status, BatchStatus::Failed(_)))
+        .count();
+    if failed == 0 {
+        return Ok(());
+    }
+    Err(format!(
+        "{} of {} plugin(s) failed to install",
+        failed,
+        entries.len()
+    ))
+}
+
+/// 指定をワイルドカード展開し、重複を除いた順序付きのソース一覧にする
+///
+/// # Arguments
+///
+/// * `sources` - Sources given to `plm install`.
+async fn expand_sources(sources: &[String]) -> Result<Vec<String>, String> {
+    let mut expanded: Vec<String> = Vec::new();
+    for source in sources {
+        let items = match wildcard_marketplace(source) {
+            Some(marketplace) => {
+                let cache = load_or_fetch_marketplace(marketplace).await?;
+                if cache.plugins.is_empty() {
+                    println!("Marketplace '{}' has no plugins.", marketplace);
+                }
+                expand_marketplace(&cache)
+            }
+            None => vec![source.clone()],
+        };
+        for item in items {
+            if !expanded.contains(&item) {
+                expanded.push(item);
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+/// マーケットプレイスのキャッシュを読み込む（無ければ登録済みの取得元から取得して保存する）
+///
+/// # Arguments
+///
+/// * `name` - Marketplace name given as `name/*`.
+async fn load_or_fetch_marketplace(name: &str) -> Result<MarketplaceCache, String> {
+    let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
+    if let Some(cache) = registry.get(name).map_err(|e| e.to_string())? {
+        return Ok(cache);
+    }
+
+    let config = MarketplaceConfig::load()?;
+    let entry = config.get(name).ok_or_else(|| {
+        format!(
+            "Marketplace '{}' not found. Add it with `plm marketplace add` first.",
+            name
+        )
+    })?;
+    println!("Fetching marketplace '{}'...", name);
+    let cache = registry
+        .fetch_source(
+            &entry.name,
+            &entry.source,
+            entry.source_path.as_deref(),
+            entry.git_ref.as_deref(),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    registry.store(&cache).map_err(|e| e.to_string())?;
+    Ok(cache)
+}
+
+/// 展開したプラグインを順にインストールし、サマリーを表示する
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+/// * `target_names` - Targets selected once for every plugin.
+/// * `scope` - Scope selected once for every plugin.
+/// * `project_root` - Project root to deploy into.
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after every plugin is installed.
+pub(super) async fn run(
+    args: &Args,
+    target_names: &[String],
+    scope: Scope,
+    project_root: &Path,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
+    let sources = expand_sources(&args.sources).await?;
+    if sources.is_empty() {
+        println!("No plugins to install.");
+        return Ok(());
+    }
+    let cache = PackageCache::new().map_err(|e| e.to_string())?;
+
+    let mut entries = Vec::new();
+    for source in sources {
+        if !args.force && is_installed(&cache, &source) {
+            println!("\n{}: already installed", source);
+            entries.push(BatchEntry {
+                source,
+                status: BatchStatus::Skipped,
+            });
+            continue;
+        }
+
+        println!("\n==> {}", source);
+        let status = match super::install_one(
+            args,
+            &source,
+            Some(target_names.to_vec()),
+            scope,
+            project_root,
+            reporter,
+            warnings,
+        )
+        .await
+        {
+            Ok(outcome) if outcome.failures.is_empty() => BatchStatus::Installed,
+            Ok(outcome) => BatchStatus::Failed(format!(
+                "{} component(s) failed to place",
+                outcome.failures.len()
+            )),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                reporter.error(&format!("{}: {}", source, e));
+                BatchStatus::Failed(e)
+            }
+        };
+        entries.push(BatchEntry { source, status });
+    }
+
+    println!("\n{}", render_summary(&entries));
+    batch_result(&entries)
+}
+
+#[cfg(test)]
+#[path = "batch_test.rs"]
+mod tests;
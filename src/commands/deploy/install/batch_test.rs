@@ -0,0 +1,102 @@
+use super::*;
+use crate::marketplace::{MarketplacePlugin, PluginSource};
+use chrono::Utc;
+use std::fs;
+use tempfile::TempDir;
+
+fn marketplace(plugins: &[&str]) -> MarketplaceCache {
+    MarketplaceCache {
+        name: "company-tools".to_string(),
+        fetched_at: Utc::now(),
+        source: "github:company/tools".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins: plugins
+            .iter()
+            .map(|name| MarketplacePlugin {
+                name: name.to_string(),
+                source: PluginSource::Local(format!("./plugins/{}", name)),
+                description: None,
+                version: None,
+                renamed_from: vec![],
+                stats: Default::default(),
+            })
+            .collect(),
+    }
+}
+
+fn entry(source: &str, status: BatchStatus) -> BatchEntry {
+    BatchEntry {
+        source: source.to_string(),
+        status,
+    }
+}
+
+#[test]
+fn wildcard_marketplace_accepts_only_name_slash_star() {
+    assert_eq!(
+        wildcard_marketplace("company-tools/*"),
+        Some("company-tools")
+    );
+    assert_eq!(wildcard_marketplace("/*"), None);
+    assert_eq!(wildcard_marketplace("owner/repo/*"), None);
+    assert_eq!(wildcard_marketplace("owner/repo"), None);
+}
+
+#[test]
+fn is_batch_for_multiple_sources_or_wildcard() {
+    assert!(!is_batch(&["owner/repo".to_string()]));
+    assert!(is_batch(&["company-tools/*".to_string()]));
+    assert!(is_batch(&["a@mp".to_string(), "b@mp".to_string()]));
+}
+
+#[test]
+fn expand_marketplace_lists_every_entry_in_manifest_order() {
+    assert_eq!(
+        expand_marketplace(&marketplace(&["formatter", "linter"])),
+        vec!["formatter@company-tools", "linter@company-tools"]
+    );
+}
+
+#[test]
+fn is_installed_checks_marketplace_and_github_cache() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    fs::create_dir_all(cache.plugin_path(Some("company-tools"), "formatter")).unwrap();
+    fs::create_dir_all(cache.plugin_path(None, "owner--repo")).unwrap();
+
+    assert!(is_installed(&cache, "formatter@company-tools"));
+    assert!(!is_installed(&cache, "linter@company-tools"));
+    assert!(is_installed(&cache, "owner/repo"));
+    assert!(is_installed(&cache, "owner/repo@v1.0.0"));
+    assert!(!is_installed(&cache, "formatter"));
+}
+
+#[test]
+fn summary_table_shows_each_result() {
+    let table = render_summary(&[
+        entry("formatter@company-tools", BatchStatus::Installed),
+        entry("linter@company-tools", BatchStatus::Skipped),
+        entry("broken@company-tools", BatchStatus::Failed("boom".into())),
+    ]);
+
+    assert!(table.contains("formatter@company-tools"));
+    assert!(table.contains("already installed"));
+    assert!(table.contains("boom"));
+}
+
+#[test]
+fn batch_result_fails_when_any_plugin_failed() {
+    assert!(batch_result(&[
+        entry("a@mp", BatchStatus::Installed),
+        entry("b@mp", BatchStatus::Skipped),
+    ])
+    .is_ok());
+
+    let err = batch_result(&[
+        entry("a@mp", BatchStatus::Installed),
+        entry("b@mp", BatchStatus::Failed("boom".into())),
+    ])
+    .unwrap_err();
+    assert_eq!(err, "1 of 2 plugin(s) failed to install");
+}
@@ -0,0 +1,172 @@
+//! `plm install --dry-run`（配置予定の事前表示）
+//!
+//! プラグインは使い捨ての一時キャッシュへ取得し、通常のインストールと同じ
+//! `scan_plugin` と `placement_location` で配置先を求める。本来のキャッシュと
+//! ターゲットのディレクトリには一切書き込まない。
+
+use super::Args;
+use crate::install::{self, PlaceRequest, PlannedPlacement};
+use crate::plugin::PackageCache;
+use crate::target::{parse_target, Scope, Target};
+use crate::tui::output_suppress::OutputSuppressGuard;
+use serde::Serialize;
+use std::path::Path;
+
+/// 配置予定を求めて表示する
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm install`.
+/// * `target_names` - Targets selected for the install.
+/// * `scope` - Selected scope.
+/// * `project_root` - Project root the components would be deployed under.
+pub(super) async fn run(
+    args: &Args,
+    target_names: &[String],
+    scope: Scope,
+    project_root: &Path,
+) -> Result<(), String> {
+    let temp_cache = tempfile::TempDir::new().map_err(|e| e.to_string())?;
+    let cache =
+        PackageCache::with_cache_dir(temp_cache.path().to_path_buf()).map_err(|e| e.to_string())?;
+    let package = {
+        // JSON 出力に混ざらないよう、ダウンロードの進捗表示を抑制する
+        let _quiet = args.json.then(OutputSuppressGuard::new).flatten();
+        install::download_plugin_with_cache(&args.sources[0], false, args.channel, &cache)
+            .await
+            .map_err(|e| e.to_string())?
+    };
+    // プレフィクスは一時キャッシュの `.plm-meta.json` にだけ反映される
+    args.prefix.apply_to(package.path())?;
+    let scanned = install::scan_plugin(&package, args.component_type.as_deref())?;
+
+    let targets: Vec<Box<dyn Target>> = target_names
+        .iter()
+        .map(|name| parse_target(name).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let planned = install::plan_placements(&PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope,
+        project_root,
+        enable_codex_hooks_flag: args.enable_flag,
+        overwrite: args.overwrite,
+    });
+
+    if args.json {
+        println!(
+            "{}",
+            render_json(scanned.name(), scope, &planned, project_root)?
+        );
+    } else {
+        println!("\nDry run: no files will be written.");
+        for line in render_text(target_names, &planned, project_root) {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// 配置予定をターゲットごとのテキスト行にする
+///
+/// 1 行は `{kind}: {name} -> {path}` の形式で、上書きになる場合は ` (overwrite)` を付ける。
+///
+/// # Arguments
+///
+/// * `target_names` - Targets in the order they were selected.
+/// * `planned` - Planned placements.
+/// * `project_root` - Paths under this directory are shown relative to it.
+pub(crate) fn render_text(
+    target_names: &[String],
+    planned: &[PlannedPlacement],
+    project_root: &Path,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for target in target_names {
+        lines.push(format!("\n{}:", target));
+        let placements: Vec<&PlannedPlacement> =
+            planned.iter().filter(|p| &p.target == target).collect();
+        if placements.is_empty() {
+            lines.push("  (no supported components)".to_string());
+        }
+        for placement in placements {
+            lines.push(format!(
+                "  {}: {} -> {}{}",
+                placement.component_kind.as_str(),
+                placement.component_name,
+                display_path(&placement.target_path, project_root),
+                if placement.overwrite {
+                    " (overwrite)"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
+    lines
+}
+
+/// `--json` のトップレベル
+#[derive(Serialize)]
+struct PlanWire<'a> {
+    plugin: &'a str,
+    scope: &'static str,
+    placements: Vec<PlacementWire<'a>>,
+}
+
+#[derive(Serialize)]
+struct PlacementWire<'a> {
+    target: &'a str,
+    kind: &'static str,
+    name: &'a str,
+    path: String,
+    overwrite: bool,
+}
+
+/// 配置予定を JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin name.
+/// * `scope` - Selected scope.
+/// * `planned` - Planned placements.
+/// * `project_root` - Paths under this directory are shown relative to it.
+pub(crate) fn render_json(
+    plugin: &str,
+    scope: Scope,
+    planned: &[PlannedPlacement],
+    project_root: &Path,
+) -> Result<String, String> {
+    let wire = PlanWire {
+        plugin,
+        scope: scope.as_str(),
+        placements: planned
+            .iter()
+            .map(|p| PlacementWire {
+                target: &p.target,
+                kind: p.component_kind.as_str(),
+                name: &p.component_name,
+                path: display_path(&p.target_path, project_root),
+                overwrite: p.overwrite,
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| e.to_string())
+}
+
+/// プロジェクト配下のパスはプロジェクトルートからの相対パスで表示する
+///
+/// # Arguments
+///
+/// * `path` - Placement path.
+/// * `project_root` - Project root directory.
+fn display_path(path: &Path, project_root: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+#[path = "dry_run_test.rs"]
+mod tests;
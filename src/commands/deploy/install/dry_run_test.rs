@@ -0,0 +1,73 @@
+use super::*;
+use crate::component::ComponentKind;
+use std::path::PathBuf;
+
+fn planned(kind: ComponentKind, name: &str, path: &str, overwrite: bool) -> PlannedPlacement {
+    PlannedPlacement {
+        target: "copilot".to_string(),
+        component_name: name.to_string(),
+        component_kind: kind,
+        target_path: PathBuf::from(path),
+        overwrite,
+    }
+}
+
+#[test]
+fn render_text_lists_relative_paths_and_marks_overwrites() {
+    let plan = vec![
+        planned(
+            ComponentKind::Skill,
+            "foo",
+            "/work/.github/skills/mp/plugin/foo",
+            false,
+        ),
+        planned(
+            ComponentKind::Command,
+            "bar",
+            "/work/.github/prompts/bar.prompt.md",
+            true,
+        ),
+    ];
+    let targets = vec!["copilot".to_string(), "codex".to_string()];
+
+    let lines = render_text(&targets, &plan, Path::new("/work"));
+
+    assert_eq!(
+        lines,
+        vec![
+            "\ncopilot:",
+            "  skill: foo -> .github/skills/mp/plugin/foo",
+            "  command: bar -> .github/prompts/bar.prompt.md (overwrite)",
+            "\ncodex:",
+            "  (no supported components)",
+        ]
+    );
+}
+
+#[test]
+fn render_json_outputs_machine_readable_plan() {
+    let plan = vec![planned(
+        ComponentKind::Agent,
+        "reviewer",
+        "/home/me/.copilot/agents/reviewer.agent.md",
+        false,
+    )];
+
+    let json = render_json("formatter", Scope::Personal, &plan, Path::new("/work")).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "plugin": "formatter",
+            "scope": "personal",
+            "placements": [{
+                "target": "copilot",
+                "kind": "agent",
+                "name": "reviewer",
+                "path": "/home/me/.copilot/agents/reviewer.agent.md",
+                "overwrite": false
+            }]
+        })
+    );
+}
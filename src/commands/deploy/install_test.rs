@@ -42,6 +42,11 @@ fn make_success(
         script_count,
         hook_count,
         hook_source_format,
+        assets_warning: None,
+        symlink_warning: None,
+        precompile_warning: None,
+        unchanged_files: 0,
+        file_diffs: Vec::new(),
     }
 }
 
@@ -121,6 +126,34 @@ fn render_place_success_hook_passthrough_copied_no_warnings() {
     assert!(stderr.is_empty());
 }
 
+#[test]
+fn render_place_success_marks_unchanged_generated_files() {
+    let mut success = make_success(
+        ComponentKind::Hook,
+        "my-hook",
+        "copilot",
+        "/dest/copilot/hooks/my-hook.json",
+        None,
+        None,
+        vec![],
+        2,
+        2,
+        Some(SourceFormat::ClaudeCode),
+    );
+
+    success.unchanged_files = 3;
+    let (stdout, _) = render_place_success_to_strings(&success);
+    assert!(stdout.ends_with(" (unchanged)"), "{}", stdout);
+
+    success.unchanged_files = 2;
+    let (stdout, _) = render_place_success_to_strings(&success);
+    assert!(stdout.ends_with(" (2 of 3 files unchanged)"), "{}", stdout);
+
+    success.unchanged_files = 0;
+    let (stdout, _) = render_place_success_to_strings(&success);
+    assert!(!stdout.contains("unchanged"), "{}", stdout);
+}
+
 #[test]
 fn render_place_success_command_keeps_legacy_converted_suffix() {
     let success = make_success(
@@ -141,6 +174,27 @@ fn render_place_success_command_keeps_legacy_converted_suffix() {
     assert!(stderr.is_empty());
 }
 
+#[test]
+fn render_place_success_appends_large_assets_warning() {
+    let mut success = make_success(
+        ComponentKind::Command,
+        "test-plugin_deploy",
+        "copilot",
+        "/dest/copilot/prompts/deploy.prompt.md",
+        None,
+        None,
+        vec![],
+        0,
+        0,
+        None,
+    );
+    success.assets_warning = Some("attached assets in /src/deploy.assets are 12.0 MiB".into());
+
+    let (_, stderr) = render_place_success_to_strings(&success);
+    assert_eq!(stderr.len(), 1);
+    assert!(stderr[0].contains("Warning: attached assets in /src/deploy.assets"));
+}
+
 #[test]
 fn render_place_success_agent_keeps_legacy_converted_suffix() {
     let success = make_success(
@@ -240,6 +294,7 @@ fn update_status_after_install_marks_successful_targets_enabled() {
         )],
         failures: vec![],
         feature_flags: vec![],
+        conflicts: vec![],
     };
 
     crate::install::update_meta_after_place(temp.path(), &result);
@@ -281,6 +336,7 @@ fn update_meta_after_place_does_not_rewrite_when_meta_already_up_to_date() {
         )],
         failures: vec![],
         feature_flags: vec![],
+        conflicts: vec![],
     };
 
     crate::install::update_meta_after_place(temp.path(), &result);
@@ -292,6 +348,85 @@ fn update_meta_after_place_does_not_rewrite_when_meta_already_up_to_date() {
     );
 }
 
+#[test]
+fn update_meta_after_place_records_deployed_command_prefix() {
+    let temp = TempDir::new().unwrap();
+    let mut prepared = crate::plugin::meta::PluginMeta::default();
+    prepared.set_status("cursor", TargetStatus::Enabled);
+    prepared.command_prefix = Some("foo:".to_string());
+    crate::plugin::meta::write_meta(temp.path(), &prepared).unwrap();
+
+    let result = PlaceOutcome {
+        plugin_name: "test-plugin".to_string(),
+        successes: vec![make_success(
+            ComponentKind::Command,
+            "foo-deploy",
+            "cursor",
+            "/dest/.cursor/commands/foo-deploy.md",
+            None,
+            None,
+            vec![],
+            0,
+            0,
+            None,
+        )],
+        failures: vec![],
+        feature_flags: vec![],
+        conflicts: vec![],
+    };
+
+    crate::install::update_meta_after_place(temp.path(), &result);
+
+    let plugin_meta = crate::plugin::meta::load_meta(temp.path()).unwrap();
+    assert_eq!(plugin_meta.deployed_command_prefix.as_deref(), Some("foo:"));
+}
+
+#[test]
+fn update_meta_after_place_records_deployed_files() {
+    // Skill / Agent / Command は deployedFiles に記録し、Hook は記録しない
+    let temp = TempDir::new().unwrap();
+    let result = PlaceOutcome {
+        plugin_name: "test-plugin".to_string(),
+        successes: vec![
+            make_success(
+                ComponentKind::Skill,
+                "review",
+                "codex",
+                "/dest/.codex/skills/review",
+                None,
+                None,
+                vec![],
+                0,
+                0,
+                None,
+            ),
+            make_success(
+                ComponentKind::Hook,
+                "test-plugin_hooks",
+                "codex",
+                "/dest/codex/hooks.json",
+                None,
+                None,
+                vec![],
+                0,
+                1,
+                Some(SourceFormat::ClaudeCode),
+            ),
+        ],
+        failures: vec![],
+        feature_flags: vec![],
+        conflicts: vec![],
+    };
+
+    crate::install::update_meta_after_place(temp.path(), &result);
+
+    let plugin_meta = crate::plugin::meta::load_meta(temp.path()).unwrap();
+    let files = &plugin_meta.deployed_files["codex"];
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "/dest/.codex/skills/review");
+    assert_eq!(files[0].kind, ComponentKind::Skill);
+}
+
 #[test]
 fn update_meta_after_place_skips_managed_file_for_non_hook_codex_success() {
     // Skill のみ Codex に配置されたケースは statusByTarget は enabled になるが、
@@ -313,6 +448,7 @@ fn update_meta_after_place_skips_managed_file_for_non_hook_codex_success() {
         )],
         failures: vec![],
         feature_flags: vec![],
+        conflicts: vec![],
     };
 
     crate::install::update_meta_after_place(temp.path(), &result);
@@ -357,6 +493,7 @@ fn update_status_after_install_skips_status_when_target_has_failures() {
             stage: PlaceFailureStage::Deployment,
         }],
         feature_flags: vec![],
+        conflicts: vec![],
     };
 
     crate::install::update_meta_after_place(temp.path(), &result);
@@ -382,6 +519,7 @@ fn update_status_after_install_skips_write_when_all_failed() {
             stage: PlaceFailureStage::Deployment,
         }],
         feature_flags: vec![],
+        conflicts: vec![],
     };
 
     crate::install::update_meta_after_place(temp.path(), &result);
@@ -391,3 +529,110 @@ fn update_status_after_install_skips_write_when_all_failed() {
         "fully-failed install must not create .plm-meta.json"
     );
 }
+
+// ========================================
+// install_summary
+// ========================================
+
+#[test]
+fn install_summary_reports_failed_targets_once() {
+    let failure = |component_name: &str| PlaceFailure {
+        target: "codex".to_string(),
+        component_name: component_name.to_string(),
+        component_kind: ComponentKind::Skill,
+        error: "failed".to_string(),
+        stage: PlaceFailureStage::Deployment,
+    };
+    let result = PlaceOutcome {
+        plugin_name: "test-plugin".to_string(),
+        successes: vec![],
+        failures: vec![failure("a"), failure("b")],
+        feature_flags: vec![],
+        conflicts: vec![],
+    };
+
+    let summary = install_summary(&result);
+
+    let row = &summary.rows()[0];
+    assert_eq!(row.name, "test-plugin");
+    assert_eq!(row.status, SummaryStatus::Failure);
+    assert_eq!(row.details, "0 placed, 2 failed (failed targets: codex)");
+}
+
+#[test]
+fn install_summary_success_without_failures() {
+    let result = PlaceOutcome {
+        plugin_name: "test-plugin".to_string(),
+        successes: vec![],
+        failures: vec![],
+        feature_flags: vec![],
+        conflicts: vec![],
+    };
+
+    let summary = install_summary(&result);
+
+    assert_eq!(summary.rows()[0].status, SummaryStatus::Success);
+    assert_eq!(summary.rows()[0].details, "0 component(s) placed");
+}
+
+#[test]
+fn install_notification_reports_success_and_failure() {
+    assert_eq!(
+        install_notification("owner/repo", &Ok(())),
+        "owner/repo installed"
+    );
+    assert_eq!(
+        install_notification("owner/repo", &Err("network error".to_string())),
+        "owner/repo failed to install"
+    );
+}
+
+#[test]
+fn discard_fetched_removes_only_downloaded_dependencies() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    for name in ["fetched", "installed"] {
+        std::fs::create_dir_all(cache.plugin_path(Some("company-tools"), name)).unwrap();
+    }
+    let fetched: Dependency = "company-tools/fetched>=1.0.0".parse().unwrap();
+
+    discard_fetched(&cache, &[fetched]);
+
+    assert!(!cache.is_cached(Some("company-tools"), "fetched"));
+    assert!(cache.is_cached(Some("company-tools"), "installed"));
+}
+
+#[test]
+fn dependency_args_do_not_carry_the_requested_plugin_options() {
+    use clap::Parser;
+    let args = Args::parse_from([
+        "install",
+        "foo@company-tools",
+        "--prefix",
+        "foo:",
+        "--type",
+        "skill",
+        "--force",
+        "--target",
+        "codex",
+        "--scope",
+        "project",
+        "--sandbox",
+    ]);
+    let temp = TempDir::new().unwrap();
+
+    let dependency = dependency_args(&args);
+
+    // 依存先は自身のコマンド名のまま配置される
+    assert!(!dependency.prefix.apply_to(temp.path()).unwrap());
+    assert!(crate::plugin::meta::load_meta(temp.path()).is_none());
+    assert!(args.prefix.apply_to(temp.path()).unwrap());
+    assert!(dependency.component_type.is_none());
+    assert!(!dependency.force);
+    assert_eq!(
+        dependency.target.resolve(temp.path()).unwrap(),
+        Some(vec!["codex".to_string()])
+    );
+    assert_eq!(dependency.scope.scope, Some(Scope::Project));
+    assert!(dependency.sandbox);
+}
@@ -2,6 +2,7 @@
 //!
 //! ソースファイル/ディレクトリへのシンボリックリンクをデスティネーションに作成する。
 
+use crate::commands::examples::Example;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 
@@ -16,6 +17,22 @@ pub struct Args {
     pub force: bool,
 }
 
+/// `plm link` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm link ./shared/AGENTS.md ./AGENTS.md",
+        description: "Link a shared file into the project",
+    },
+    Example {
+        command: "plm link ./skills/pdf .codex/skills/pdf",
+        description: "Link a local skill into Codex's skill directory",
+    },
+    Example {
+        command: "plm link ./shared/AGENTS.md ./AGENTS.md --force",
+        description: "Replace an existing file or symlink at the destination",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm link`.
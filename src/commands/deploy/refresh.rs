@@ -0,0 +1,183 @@
+//! plm refresh コマンド
+//!
+//! デプロイ先で直接編集したファイルをキャッシュ内のプラグインへ書き戻す。
+//! 正とするターゲットを `--target` で明示し、差分を一覧してから確認のうえ書き戻す。
+
+use crate::application::{apply_refresh, plan_refresh, RefreshChange, RefreshPlan};
+use crate::commands::args::MarketplaceArgs;
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use crate::target::TargetKind;
+use clap::Parser;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Plugin name (e.g., "owner--repo" or "plugin-name")
+    pub name: String,
+
+    #[command(flatten)]
+    pub marketplace: MarketplaceArgs,
+
+    /// Take the deployed files as the source of truth
+    #[arg(long, required = true)]
+    pub from_deployed: bool,
+
+    /// Target whose deployed files are written back
+    #[arg(long, value_enum)]
+    pub target: TargetKind,
+
+    /// List the differences without writing to the cache
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip the confirmation prompt
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+/// `plm refresh` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm refresh formatter --from-deployed --target codex --dry-run",
+        description: "List files edited in .codex/ that differ from the cache",
+    },
+    Example {
+        command: "plm refresh formatter --from-deployed --target copilot",
+        description: "Write edits made in .github/ back to the cache after confirmation",
+    },
+    Example {
+        command: "plm refresh formatter -m company-tools --from-deployed --target codex --yes",
+        description: "Write back without asking (for scripts)",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm refresh`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let project_root = ctx.project_root.clone();
+    let marketplace = args.marketplace.marketplace_or_default();
+
+    let plan = plan_refresh(&cache, &args.name, marketplace, args.target, &project_root)?;
+    let plugin_root = cache.plugin_path(Some(marketplace), &args.name);
+    print_plan(&plan, &project_root, &plugin_root);
+
+    if plan.changes.is_empty() {
+        println!("No changes to write back.");
+        return Ok(());
+    }
+    if args.dry_run {
+        println!("\n(dry run) The cache was not modified.");
+        return Ok(());
+    }
+
+    if !args.yes {
+        if !io::stdin().is_terminal() {
+            return Err(
+                "Refusing to overwrite the cache without confirmation. Use --yes to write back."
+                    .to_string(),
+            );
+        }
+        if !confirm_write(plan.changes.len())? {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    apply_refresh(&cache, &args.name, marketplace, &plan)?;
+    println!(
+        "\n✓ Wrote {} file(s) back to the cache of {}",
+        plan.changes.len(),
+        args.name
+    );
+    Ok(())
+}
+
+/// 差分と対象外のコンポーネントを表示する
+///
+/// # Arguments
+///
+/// * `plan` - Plan returned by [`plan_refresh`].
+/// * `project_root` - Project root used to shorten deployed paths.
+/// * `plugin_root` - Plugin cache directory used to shorten cached paths.
+fn print_plan(plan: &RefreshPlan, project_root: &Path, plugin_root: &Path) {
+    if !plan.changes.is_empty() {
+        println!("Changed in deployment ({}):", plan.changes.len());
+        for change in &plan.changes {
+            for line in format_change(change, project_root, plugin_root) {
+                println!("{}", line);
+            }
+        }
+    }
+    if !plan.skipped.is_empty() {
+        println!("Skipped:");
+        for (kind, name, reason) in &plan.skipped {
+            println!("  - {} {}: {}", kind, name, reason);
+        }
+    }
+}
+
+/// 変更 1 件の表示行（警告があれば続けて出す）
+///
+/// # Arguments
+///
+/// * `change` - File to write back.
+/// * `project_root` - Project root used to shorten the deployed path.
+/// * `plugin_root` - Plugin cache directory used to shorten the cached path.
+pub(crate) fn format_change(
+    change: &RefreshChange,
+    project_root: &Path,
+    plugin_root: &Path,
+) -> Vec<String> {
+    let deployed = change
+        .deployed
+        .strip_prefix(project_root)
+        .unwrap_or(&change.deployed);
+    let cached = change
+        .cached
+        .strip_prefix(plugin_root)
+        .unwrap_or(&change.cached);
+    let note = if change.converted { " (converted)" } else { "" };
+
+    let mut lines = vec![format!(
+        "  {} {}: {} -> {}{}",
+        change.kind,
+        change.name,
+        deployed.display(),
+        cached.display(),
+        note
+    )];
+    lines.extend(
+        change
+            .warnings
+            .iter()
+            .map(|warning| format!("    Warning: {}", warning)),
+    );
+    lines
+}
+
+/// ユーザーに書き戻しの確認を求める
+///
+/// # Arguments
+///
+/// * `count` - Number of files to write back.
+fn confirm_write(count: usize) -> Result<bool, String> {
+    print!("Write {} file(s) back to the cache? [y/N]: ", count);
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
+#[cfg(test)]
+#[path = "refresh_test.rs"]
+mod tests;
@@ -0,0 +1,33 @@
+use super::*;
+use crate::component::ComponentKind;
+use std::path::PathBuf;
+
+#[test]
+fn format_change_shortens_paths_and_lists_warnings() {
+    let change = RefreshChange {
+        kind: ComponentKind::Agent,
+        name: "reviewer".to_string(),
+        deployed: PathBuf::from("/project/.github/agents/reviewer.agent.md"),
+        cached: PathBuf::from("/cache/github/tools/agents/reviewer.md"),
+        content: Vec::new(),
+        converted: true,
+        warnings: vec!["'handoffs' is specific to copilot and cannot be written back".to_string()],
+    };
+
+    let lines = format_change(
+        &change,
+        Path::new("/project"),
+        Path::new("/cache/github/tools"),
+    );
+
+    assert_eq!(
+        lines,
+        vec![
+            format!(
+                "  {} reviewer: .github/agents/reviewer.agent.md -> agents/reviewer.md (converted)",
+                ComponentKind::Agent
+            ),
+            "    Warning: 'handoffs' is specific to copilot and cannot be written back".to_string(),
+        ]
+    );
+}
@@ -1,24 +1,32 @@
 //! plm sync コマンド
 
-use crate::commands::args::SyncScopeArgs;
+mod check;
+mod lockfile;
+
+use crate::backup::BackupOperation;
+use crate::commands::args::{BackupArgs, GithubOutputArgs, SyncScopeArgs};
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::output::github::{GithubReporter, StepSummary, SummaryStatus};
 use crate::sync::{
-    sync, PlacedComponent, SyncDestination, SyncOptions, SyncOutcome, SyncSource, SyncableKind,
+    diff, sync, PlacedComponent, SyncDestination, SyncOptions, SyncOutcome, SyncSource,
+    SyncableKind,
 };
 use crate::target::TargetKind;
 use clap::Parser;
 use comfy_table::{presets::UTF8_FULL, Cell, Color, Table};
 use owo_colors::OwoColorize;
-use std::env;
+use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
 pub struct Args {
     /// Source target
-    #[arg(long, value_enum)]
-    pub from: TargetKind,
+    #[arg(long, value_enum, required_unless_present_any = ["lock", "frozen", "check"])]
+    pub from: Option<TargetKind>,
 
     /// Destination target
-    #[arg(long, value_enum)]
-    pub to: TargetKind,
+    #[arg(long, value_enum, required_unless_present_any = ["lock", "frozen", "check"])]
+    pub to: Option<TargetKind>,
 
     /// Component type to sync (all if not specified)
     #[arg(long = "type", value_enum)]
@@ -28,22 +36,120 @@ pub struct Args {
     pub scope: SyncScopeArgs,
 
     /// Preview only, do not actually sync
-    #[arg(long)]
+    #[arg(long, conflicts_with = "check")]
     pub dry_run: bool,
+
+    /// Only check that the installed plugins and deployments match plm.lock;
+    /// exit 1 if anything differs
+    #[arg(long, conflicts_with_all = ["from", "to", "dry_run"])]
+    pub check: bool,
+
+    /// Output the --check result as JSON
+    #[arg(long, requires = "check")]
+    pub json: bool,
+
+    /// Write the installed plugins to plm.lock in the project root
+    #[arg(long, conflicts_with_all = ["from", "to", "frozen", "dry_run", "check"])]
+    pub lock: bool,
+
+    /// Install exactly the plugin versions recorded in plm.lock and deploy them
+    #[arg(long, conflicts_with_all = ["from", "to", "dry_run", "check"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub github: GithubOutputArgs,
+
+    #[command(flatten)]
+    pub backup: BackupArgs,
+}
+
+/// `plm sync` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm sync --from codex --to copilot",
+        description: "Copy every component from Codex to Copilot",
+    },
+    Example {
+        command: "plm sync --from codex --to copilot --type skill",
+        description: "Sync skills only",
+    },
+    Example {
+        command: "plm sync --from copilot --to cursor --scope project --dry-run",
+        description: "Preview project-scope changes without applying them",
+    },
+    Example {
+        command: "plm sync --from codex --to copilot --backup",
+        description: "Save the overwritten and deleted Copilot files first",
+    },
+    Example {
+        command: "plm sync --from codex --to copilot --github-output",
+        description: "Report failed items as GitHub Actions annotations in CI",
+    },
+    Example {
+        command: "plm sync --lock",
+        description: "Record the installed plugin versions in plm.lock",
+    },
+    Example {
+        command: "plm sync --frozen",
+        description: "Install exactly the versions recorded in plm.lock",
+    },
+    Example {
+        command: "plm sync --check",
+        description: "Fail CI when the installed plugins differ from plm.lock",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm sync`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let reporter = args.github.reporter();
+    let result = if args.lock || args.frozen || args.check {
+        run_lock_mode(&args, ctx).await
+    } else {
+        execute(args, ctx, &reporter)
+    };
+    if let Err(e) = &result {
+        reporter.error(e);
+    }
+    result
+}
+
+/// `--lock` / `--frozen` / `--check` を処理する
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm sync`.
+/// * `ctx` - Shared command context (resolved project root).
+async fn run_lock_mode(args: &Args, ctx: &Context) -> Result<(), String> {
+    let project_root = ctx.project_root.clone();
+    if args.check {
+        check::run(&project_root, args.json)
+    } else if args.frozen {
+        lockfile::install_frozen(&project_root).await
+    } else {
+        lockfile::write_lock(&project_root)
+    }
 }
 
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm sync`.
-pub async fn run(args: Args) -> Result<(), String> {
-    if args.from == args.to {
+/// * `ctx` - Shared command context (resolved project root).
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+fn execute(args: Args, ctx: &Context, reporter: &GithubReporter) -> Result<(), String> {
+    let (Some(from), Some(to)) = (args.from, args.to) else {
+        return Err("--from and --to are required".to_string());
+    };
+    if from == to {
         return Err("Cannot sync to the same target".to_string());
     }
 
-    let project_root = env::current_dir().map_err(|e| e.to_string())?;
+    let project_root = ctx.project_root.clone();
 
-    let source = SyncSource::new(args.from, &project_root).map_err(|e| e.to_string())?;
-    let dest = SyncDestination::new(args.to, &project_root).map_err(|e| e.to_string())?;
+    let source = SyncSource::new(from, &project_root).map_err(|e| e.to_string())?;
+    let dest = SyncDestination::new(to, &project_root).map_err(|e| e.to_string())?;
 
     let options = SyncOptions {
         component_type: args.component_type,
@@ -51,9 +157,17 @@ pub async fn run(args: Args) -> Result<(), String> {
         dry_run: args.dry_run,
     };
 
+    if !options.dry_run {
+        args.backup.snapshot(
+            BackupOperation::Sync,
+            &format!("plm sync --from {} --to {}", source.name(), dest.name()),
+            || sync_backup_paths(&source, &dest, &options),
+        )?;
+    }
     let result = sync(&source, &dest, &options).map_err(|e| e.to_string())?;
 
     print_result(&result, source.name(), dest.name());
+    report_to_github(reporter, &result, dest.name());
 
     if result.failure_count() > 0 {
         return Err(format!("{} item(s) failed to sync", result.failure_count()));
@@ -62,6 +176,30 @@ pub async fn run(args: Args) -> Result<(), String> {
     Ok(())
 }
 
+/// `--backup` で退避するパス（同期先で上書き・削除されるコンポーネント）
+///
+/// 差分を計算できない場合は空を返す（同じエラーで直後の同期が失敗する）。
+///
+/// # Arguments
+///
+/// * `source` - Source target.
+/// * `dest` - Destination target.
+/// * `options` - Scope and component type filters.
+fn sync_backup_paths(
+    source: &SyncSource,
+    dest: &SyncDestination,
+    options: &SyncOptions,
+) -> Vec<PathBuf> {
+    let Ok(diff) = diff(source, dest, options) else {
+        return Vec::new();
+    };
+    diff.to_update
+        .iter()
+        .filter_map(|component| dest.path_for(component).ok())
+        .chain(diff.to_delete.into_iter().map(|component| component.path))
+        .collect()
+}
+
 /// # Arguments
 ///
 /// * `result` - Outcome returned by the `sync` engine.
@@ -131,6 +269,70 @@ fn print_result(result: &SyncOutcome, from_name: &str, to_name: &str) {
     }
 }
 
+/// 警告（サポート外）とエラー（失敗）をアノテーションとして出し、サマリーを追記する
+///
+/// # Arguments
+///
+/// * `reporter` - GitHub Actions output.
+/// * `result` - Outcome returned by the `sync` engine.
+/// * `to_name` - Display name of the destination target.
+fn report_to_github(reporter: &GithubReporter, result: &SyncOutcome, to_name: &str) {
+    for component in &result.unsupported {
+        reporter.warning(
+            &format!(
+                "{} '{}' is not supported by {} and was skipped",
+                component.kind().display_name(),
+                component.name(),
+                to_name
+            ),
+            Some(&component.path),
+        );
+    }
+    for failure in &result.failed {
+        reporter.error(&format!(
+            "Failed to {} {} '{}': {}",
+            failure.action.display_name().to_lowercase(),
+            failure.component.kind().display_name(),
+            failure.component.name(),
+            failure.error
+        ));
+    }
+    reporter.write_summary(&sync_summary(result));
+}
+
+/// 同期結果を `GITHUB_STEP_SUMMARY` 用のサマリーに変換する（コンポーネント 1 件 1 行）
+///
+/// # Arguments
+///
+/// * `result` - Outcome returned by the `sync` engine.
+fn sync_summary(result: &SyncOutcome) -> StepSummary {
+    let mut summary = StepSummary::new(if result.dry_run {
+        "plm sync (dry run)"
+    } else {
+        "plm sync"
+    });
+    let groups = [
+        (&result.created, SummaryStatus::Success, "Create"),
+        (&result.updated, SummaryStatus::Success, "Update"),
+        (&result.deleted, SummaryStatus::Success, "Delete"),
+        (&result.skipped, SummaryStatus::Skipped, "No change"),
+        (&result.unsupported, SummaryStatus::Skipped, "Unsupported"),
+    ];
+    for (components, status, details) in groups {
+        for component in components {
+            summary.push(component.name(), status, details);
+        }
+    }
+    for failure in &result.failed {
+        summary.push(
+            failure.component.name(),
+            SummaryStatus::Failure,
+            format!("{}: {}", failure.action.display_name(), failure.error),
+        );
+    }
+    summary
+}
+
 /// # Arguments
 ///
 /// * `table` - Table to append a row to.
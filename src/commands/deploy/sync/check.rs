@@ -0,0 +1,155 @@
+//! `plm sync --check`（`plm.lock` と実際のキャッシュ・デプロイ状態が一致しているかの検証）
+//!
+//! ファイルは一切変更せず、`plm sync --frozen` と同じ差分（[`diff_lock`]）を報告する。
+//! CI で「lock の更新し忘れ・インストールし忘れ」を検出する用途を想定し、差分があれば
+//! exit 1 にする。
+
+use crate::application::{
+    diff_lock, read_lock_file, LockDiff, LockDifference, LockStatus, LOCK_FILE,
+};
+use crate::plugin::PackageCache;
+use serde::Serialize;
+use std::path::Path;
+
+/// `plm.lock` と実際の状態を比較して表示し、一致していなければ Err（exit 1）にする
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `plm.lock`.
+/// * `json` - Print the result as JSON instead of text.
+pub(super) fn run(project_root: &Path, json: bool) -> Result<(), String> {
+    let lock = read_lock_file(&project_root.join(LOCK_FILE)).map_err(|e| e.to_string())?;
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
+    let diff = diff_lock(&cache, &lock).map_err(|e| e.to_string())?;
+
+    if json {
+        println!("{}", render_json(&diff)?);
+    } else {
+        println!("{}", render_text(&diff));
+    }
+
+    if !diff.is_in_sync() {
+        return Err(format!(
+            "installed plugins are out of sync with {}: {} difference(s)",
+            LOCK_FILE,
+            diff.differences.len()
+        ));
+    }
+    Ok(())
+}
+
+/// `--json` のトップレベル
+#[derive(Serialize)]
+struct CheckWire<'a> {
+    in_sync: bool,
+    summary: SummaryWire,
+    differences: Vec<DifferenceWire<'a>>,
+}
+
+#[derive(Serialize)]
+struct SummaryWire {
+    missing: usize,
+    mismatch: usize,
+    extra: usize,
+}
+
+#[derive(Serialize)]
+struct DifferenceWire<'a> {
+    status: &'static str,
+    plugin: &'a str,
+    marketplace: &'a str,
+    name: &'a str,
+    locked_version: Option<&'a str>,
+    installed_version: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+/// 検証結果を JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `diff` - Difference between `plm.lock` and the installed plugins.
+pub(crate) fn render_json(diff: &LockDiff) -> Result<String, String> {
+    let wire = CheckWire {
+        in_sync: diff.is_in_sync(),
+        summary: SummaryWire {
+            missing: diff.count(LockStatus::Missing),
+            mismatch: diff.count(LockStatus::Mismatch),
+            extra: diff.count(LockStatus::Extra),
+        },
+        differences: diff
+            .differences
+            .iter()
+            .map(|d| DifferenceWire {
+                status: d.status.as_str(),
+                plugin: &d.plugin,
+                marketplace: &d.marketplace,
+                name: &d.name,
+                locked_version: d.locked_version.as_deref(),
+                installed_version: d.installed_version.as_deref(),
+                target: d.target.as_deref(),
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// 検証結果を差分一覧にする
+///
+/// # Arguments
+///
+/// * `diff` - Difference between `plm.lock` and the installed plugins.
+pub(crate) fn render_text(diff: &LockDiff) -> String {
+    let mut lines = vec![format!("Sync check: {}", LOCK_FILE), String::new()];
+
+    if diff.is_in_sync() {
+        lines.push(format!("In sync: installed plugins match {}.", LOCK_FILE));
+        return lines.join("\n");
+    }
+
+    for difference in &diff.differences {
+        lines.push(format!(
+            "  {:<8} {}",
+            difference.status.as_str(),
+            describe(difference)
+        ));
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "{} missing, {} mismatch, {} extra",
+        diff.count(LockStatus::Missing),
+        diff.count(LockStatus::Mismatch),
+        diff.count(LockStatus::Extra)
+    ));
+    lines.join("\n")
+}
+
+/// 差分 1 件の説明（プラグイン・バージョン・ターゲット）
+///
+/// # Arguments
+///
+/// * `difference` - Difference to describe.
+fn describe(difference: &LockDifference) -> String {
+    let version = difference
+        .locked_version
+        .as_deref()
+        .or(difference.installed_version.as_deref())
+        .unwrap_or_default();
+    let mut text = format!("{} {}", difference.plugin, version);
+    if difference.status == LockStatus::Mismatch {
+        match difference.installed_version.as_deref() {
+            Some(installed) if Some(installed) != difference.locked_version.as_deref() => {
+                text.push_str(&format!(" (installed: {})", installed));
+            }
+            _ => text.push_str(" (installed content differs)"),
+        }
+    }
+    if let Some(target) = &difference.target {
+        text.push_str(&format!(" -> {}", target));
+    }
+    text
+}
+
+#[cfg(test)]
+#[path = "check_test.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+
+fn difference(
+    status: LockStatus,
+    locked: Option<&str>,
+    installed: Option<&str>,
+    target: Option<&str>,
+) -> LockDifference {
+    LockDifference {
+        status,
+        plugin: "formatter@company-tools".to_string(),
+        marketplace: "company-tools".to_string(),
+        name: "formatter".to_string(),
+        locked_version: locked.map(str::to_string),
+        installed_version: installed.map(str::to_string),
+        target: target.map(str::to_string),
+    }
+}
+
+fn out_of_sync() -> LockDiff {
+    LockDiff {
+        differences: vec![
+            difference(LockStatus::Missing, Some("1.2.0"), None, None),
+            difference(LockStatus::Mismatch, Some("1.2.0"), Some("1.3.0"), None),
+            difference(LockStatus::Mismatch, Some("1.2.0"), Some("1.2.0"), None),
+            difference(
+                LockStatus::Missing,
+                Some("1.2.0"),
+                Some("1.2.0"),
+                Some("copilot"),
+            ),
+            difference(LockStatus::Extra, None, Some("0.1.0"), Some("codex")),
+        ],
+    }
+}
+
+#[test]
+fn render_text_lists_each_difference() {
+    let text = render_text(&out_of_sync());
+
+    assert!(text.starts_with("Sync check: plm.lock"));
+    assert!(text.contains("  missing  formatter@company-tools 1.2.0\n"));
+    assert!(text.contains("  mismatch formatter@company-tools 1.2.0 (installed: 1.3.0)"));
+    assert!(text.contains("  mismatch formatter@company-tools 1.2.0 (installed content differs)"));
+    assert!(text.contains("  missing  formatter@company-tools 1.2.0 -> copilot"));
+    assert!(text.contains("  extra    formatter@company-tools 0.1.0 -> codex"));
+    assert!(text.contains("2 missing, 2 mismatch, 1 extra"));
+}
+
+#[test]
+fn render_text_reports_in_sync() {
+    let text = render_text(&LockDiff::default());
+
+    assert!(text.contains("In sync: installed plugins match plm.lock."));
+}
+
+#[test]
+fn render_json_has_summary_and_differences() {
+    let json = render_json(&out_of_sync()).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value["in_sync"], false);
+    assert_eq!(value["summary"]["missing"], 2);
+    assert_eq!(value["summary"]["mismatch"], 2);
+    assert_eq!(value["summary"]["extra"], 1);
+    assert_eq!(value["differences"][1]["status"], "mismatch");
+    assert_eq!(value["differences"][1]["locked_version"], "1.2.0");
+    assert_eq!(value["differences"][1]["installed_version"], "1.3.0");
+    assert_eq!(value["differences"][4]["target"], "codex");
+    assert!(value["differences"][4]["locked_version"].is_null());
+}
@@ -0,0 +1,234 @@
+//! `plm sync --lock` / `plm sync --frozen`
+//!
+//! `--lock` はインストール済みプラグインをプロジェクトルートの `plm.lock` に記録する。
+//! `--frozen` は `plm.lock` を読み、記録どおりのバージョンを取得してから記録された
+//! ターゲットへ配置する。記録と異なるプラグインは一時キャッシュへ取得して検証し、
+//! 全件が解決できてから本体のキャッシュへ移す。1 件でも解決できなければキャッシュも
+//! 配置も変更せずにエラー終了する。
+
+use crate::application::{
+    build_lock_file, diff_lock, enable_plugin, matches_cache, read_lock_file, write_lock_file,
+    LockDiff, LockedPlugin, LOCK_FILE,
+};
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::meta::{self, TargetStatus};
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use crate::repo;
+use crate::source::{GitHubSource, MarketplaceSource, PackageSource};
+use owo_colors::OwoColorize;
+use std::path::Path;
+
+/// インストール済みプラグインを `plm.lock` に書き出す
+///
+/// # Arguments
+///
+/// * `project_root` - Project root the lock file is written to.
+pub(super) fn write_lock(project_root: &Path) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
+    let lock = build_lock_file(&cache).map_err(|e| e.to_string())?;
+    let path = project_root.join(LOCK_FILE);
+    write_lock_file(&path, &lock).map_err(|e| format!("Failed to write {}: {}", LOCK_FILE, e))?;
+
+    println!(
+        "Wrote {} ({} plugin(s))",
+        path.display().to_string().cyan(),
+        lock.plugins.len()
+    );
+    Ok(())
+}
+
+/// `plm.lock` に記録されたバージョンを取得して配置する
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `plm.lock`.
+pub(super) async fn install_frozen(project_root: &Path) -> Result<(), String> {
+    let lock = read_lock_file(&project_root.join(LOCK_FILE)).map_err(|e| e.to_string())?;
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
+    let diff = diff_lock(&cache, &lock).map_err(|e| e.to_string())?;
+
+    // 記録と異なるプラグインは一時キャッシュへ取得・検証し、全件が解決できてから本体へ移す
+    let staging = cache
+        .create_staging_cache()
+        .map_err(|e| format!("Failed to prepare a staging cache: {e}"))?;
+    let adopted = match resolve_all(&staging, &lock.plugins, &diff).await {
+        Ok(downloaded) => adopt_all(&cache, &staging, &downloaded),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = cache.discard_staging_cache(staging) {
+        eprintln!("[plm warn] failed to remove the staging cache: {}", e);
+    }
+    adopted?;
+
+    let mut failures = Vec::new();
+    for locked in &lock.plugins {
+        for target in &locked.targets {
+            if let Err(e) = deploy(&cache, locked, target, project_root) {
+                failures.push(format!("  {} -> {}: {}", locked.display_name(), target, e));
+            }
+        }
+    }
+    if !failures.is_empty() {
+        return Err(format!(
+            "{} deployment(s) failed:\n{}",
+            failures.len(),
+            failures.join("\n")
+        ));
+    }
+
+    println!(
+        "\n{}: {} plugin(s) installed from {}",
+        "Frozen".bold(),
+        lock.plugins.len().to_string().green(),
+        LOCK_FILE
+    );
+    Ok(())
+}
+
+/// ロックの全エントリを解決し、一時キャッシュへ取得したエントリを返す
+///
+/// `plm sync --check` と同じ差分で、キャッシュに無い・記録と異なるエントリだけを取得する。
+/// 1 件でも解決できなければ、解決できなかったエントリを列挙したエラーを返す。
+///
+/// # Arguments
+///
+/// * `staging` - Staging cache that receives the downloads.
+/// * `plugins` - Locked plugin entries.
+/// * `diff` - Difference between the lock and the installed plugins.
+async fn resolve_all<'a>(
+    staging: &PackageCache,
+    plugins: &'a [LockedPlugin],
+    diff: &LockDiff,
+) -> Result<Vec<&'a LockedPlugin>, String> {
+    let mut downloaded = Vec::new();
+    let mut unresolved = Vec::new();
+    for locked in plugins {
+        if !diff.needs_fetch(locked) {
+            println!(
+                "  {} {} ({})",
+                locked.display_name(),
+                locked.version,
+                "cached".dimmed()
+            );
+            continue;
+        }
+        match fetch(staging, locked).await {
+            Ok(()) => {
+                println!(
+                    "  {} {} ({})",
+                    locked.display_name(),
+                    locked.version,
+                    "downloaded".dimmed()
+                );
+                downloaded.push(locked);
+            }
+            Err(reason) => unresolved.push(format!(
+                "  {} ({}): {}",
+                locked.display_name(),
+                locked.version,
+                reason
+            )),
+        }
+    }
+    if !unresolved.is_empty() {
+        return Err(format!(
+            "{} plugin(s) in {} could not be resolved:\n{}",
+            unresolved.len(),
+            LOCK_FILE,
+            unresolved.join("\n")
+        ));
+    }
+    Ok(downloaded)
+}
+
+/// 一時キャッシュへ取得したプラグインを本体のキャッシュへ移す
+///
+/// # Arguments
+///
+/// * `cache` - Package cache the plugins are installed in.
+/// * `staging` - Staging cache holding the verified downloads.
+/// * `downloaded` - Entries downloaded into `staging`.
+fn adopt_all(
+    cache: &PackageCache,
+    staging: &PackageCache,
+    downloaded: &[&LockedPlugin],
+) -> Result<(), String> {
+    for locked in downloaded {
+        cache
+            .adopt_staged(staging, Some(locked.marketplace.as_str()), &locked.name)
+            .map_err(|e| format!("Failed to install {}: {}", locked.display_name(), e))?;
+    }
+    Ok(())
+}
+
+/// 記録されたコミットを一時キャッシュへ取得して検証する（本体のキャッシュは変更しない）
+///
+/// # Arguments
+///
+/// * `staging` - Staging cache to download the plugin into.
+/// * `locked` - Locked plugin entry.
+async fn fetch(staging: &PackageCache, locked: &LockedPlugin) -> Result<(), String> {
+    let source: Box<dyn PackageSource> = match (locked.marketplace_ref(), &locked.repository) {
+        (MarketplaceRef::Github, Some(repository)) => {
+            let repo = repo::from_url(repository).map_err(|e| e.to_string())?;
+            let repo = match &locked.commit_sha {
+                Some(sha) => repo.with_ref(sha.as_str()),
+                None => repo,
+            };
+            Box::new(GitHubSource::new(repo))
+        }
+        (MarketplaceRef::Github, None) => {
+            return Err("no source repository is recorded".to_string());
+        }
+        (MarketplaceRef::Named(marketplace), _) => Box::new(
+            MarketplaceSource::new(&locked.name, &marketplace).with_ref(locked.commit_sha.clone()),
+        ),
+    };
+
+    let cached = source
+        .download(staging, true)
+        .await
+        .map_err(|e| e.to_string())?;
+    if cached.version() != locked.version {
+        return Err(format!("only version {} is available", cached.version()));
+    }
+    if !matches_cache(staging, locked) {
+        return Err(
+            "downloaded content does not match the locked commit or content hash".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// ロックに記録されたターゲットへ配置し、`.plm-meta.json` の状態を更新する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache holding the resolved plugin.
+/// * `locked` - Locked plugin entry.
+/// * `target` - Target name recorded in the lock file.
+/// * `project_root` - Project root used for project-scope placement.
+fn deploy(
+    cache: &PackageCache,
+    locked: &LockedPlugin,
+    target: &str,
+    project_root: &Path,
+) -> Result<(), String> {
+    let marketplace = Some(locked.marketplace.as_str());
+    let result = enable_plugin(cache, &locked.name, marketplace, project_root, Some(target));
+    for conflict in &result.conflicts {
+        eprintln!("  Warning: {}", conflict.message());
+    }
+    let outcome = result.outcome;
+    if !outcome.success {
+        return Err(match &outcome.error {
+            Some(error) => error.to_string(),
+            None => "failed to enable".to_string(),
+        });
+    }
+
+    let plugin_path = cache.plugin_path(marketplace, &locked.name);
+    let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+    plugin_meta.set_status(target, TargetStatus::Enabled);
+    meta::write_meta(&plugin_path, &plugin_meta).map_err(|e| e.to_string())
+}
@@ -7,6 +7,52 @@ fn test_args_parsing() {
     cmd.debug_assert();
 }
 
+#[test]
+fn test_sync_summary_lists_each_component() {
+    use crate::component::ComponentKind;
+    use crate::sync::{SyncAction, SyncFailure};
+    use crate::target::Scope;
+
+    let skill = |name: &str| {
+        PlacedComponent::new(
+            ComponentKind::Skill,
+            name,
+            Scope::Project,
+            format!("/s/{}", name),
+        )
+    };
+    let result = SyncOutcome {
+        created: vec![skill("created")],
+        unsupported: vec![skill("unsupported")],
+        failed: vec![SyncFailure::new(
+            skill("broken"),
+            SyncAction::Update,
+            "permission denied",
+        )],
+        ..Default::default()
+    };
+
+    let summary = sync_summary(&result);
+    let rows: Vec<_> = summary
+        .rows()
+        .iter()
+        .map(|r| (r.name.as_str(), r.status, r.details.as_str()))
+        .collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            ("created", SummaryStatus::Success, "Create"),
+            ("unsupported", SummaryStatus::Skipped, "Unsupported"),
+            (
+                "broken",
+                SummaryStatus::Failure,
+                "Update: permission denied"
+            ),
+        ]
+    );
+}
+
 // Integration tests (binary execution tests)
 
 use assert_cmd::Command;
@@ -105,6 +151,52 @@ fn test_sync_creates_component() {
     assert!(target_skill.exists(), "Skill should be synced to copilot");
 }
 
+#[test]
+fn test_sync_check_compares_lock_with_installed_plugins() {
+    let temp = TempDir::new().unwrap();
+    let plugin_dir = temp
+        .path()
+        .join(".plm/cache/plugins/company-tools/formatter");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    fs::write(
+        plugin_dir.join("plugin.json"),
+        r#"{"name": "formatter", "version": "1.0.0"}"#,
+    )
+    .unwrap();
+    let run = |args: &[&str]| {
+        plm()
+            .current_dir(temp.path())
+            .env("HOME", temp.path())
+            .env_remove("PLM_HOME")
+            .args(args)
+            .assert()
+    };
+
+    run(&["sync", "--lock"]).success();
+    run(&["sync", "--check", "--json"])
+        .success()
+        .stdout(predicate::str::contains("\"in_sync\": true"));
+
+    // インストール済みのバージョンが lock と異なる
+    fs::write(
+        plugin_dir.join("plugin.json"),
+        r#"{"name": "formatter", "version": "1.1.0"}"#,
+    )
+    .unwrap();
+    let lock_before = fs::read_to_string(temp.path().join("plm.lock")).unwrap();
+    run(&["sync", "--check"])
+        .failure()
+        .stdout(predicate::str::contains(
+            "mismatch formatter@company-tools 1.0.0 (installed: 1.1.0)",
+        ))
+        .stderr(predicate::str::contains("out of sync with plm.lock"));
+    assert_eq!(
+        fs::read_to_string(temp.path().join("plm.lock")).unwrap(),
+        lock_before,
+        "--check must not write anything"
+    );
+}
+
 #[test]
 fn test_sync_help_output() {
     plm()
@@ -117,3 +209,65 @@ fn test_sync_help_output() {
         .stdout(predicate::str::contains("--scope"))
         .stdout(predicate::str::contains("--dry-run"));
 }
+
+#[test]
+fn test_sync_requires_from_and_to_without_lock_mode() {
+    plm()
+        .args(["sync", "--to", "copilot"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--from"));
+}
+
+#[test]
+fn test_sync_frozen_without_lock_file_fails() {
+    let temp = TempDir::new().unwrap();
+
+    plm()
+        .current_dir(temp.path())
+        .env("HOME", temp.path())
+        .env_remove("PLM_HOME")
+        .args(["sync", "--frozen"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("plm.lock"));
+}
+
+#[test]
+fn test_sync_frozen_lists_unresolvable_plugins_and_keeps_the_cache() {
+    let temp = TempDir::new().unwrap();
+    let installed = temp
+        .path()
+        .join(".plm/cache/plugins/company-tools/formatter");
+    fs::create_dir_all(&installed).unwrap();
+    let manifest = r#"{"name": "formatter", "version": "1.0.0"}"#;
+    fs::write(installed.join("plugin.json"), manifest).unwrap();
+    fs::write(
+        temp.path().join("plm.lock"),
+        r#"{
+  "version": 1,
+  "plugins": [
+    {"marketplace": "company-tools", "name": "formatter", "version": "2.0.0", "targets": ["codex"]}
+  ]
+}"#,
+    )
+    .unwrap();
+
+    plm()
+        .current_dir(temp.path())
+        .env("HOME", temp.path())
+        .env_remove("PLM_HOME")
+        .args(["sync", "--frozen"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1 plugin(s) in plm.lock could not be resolved",
+        ))
+        .stderr(predicate::str::contains("formatter@company-tools (2.0.0)"));
+
+    assert_eq!(
+        fs::read_to_string(installed.join("plugin.json")).unwrap(),
+        manifest
+    );
+    assert!(!temp.path().join(".codex").exists());
+}
@@ -1,3 +1,4 @@
+use crate::commands::examples::Example;
 use std::fs;
 use std::path::PathBuf;
 
@@ -7,6 +8,18 @@ pub struct Args {
     pub path: PathBuf,
 }
 
+/// `plm unlink` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm unlink ./AGENTS.md",
+        description: "Remove a symbolic link (regular files are refused)",
+    },
+    Example {
+        command: "plm unlink .codex/skills/pdf",
+        description: "Remove a linked skill directory",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm unlink`.
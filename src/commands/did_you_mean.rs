@@ -0,0 +1,113 @@
+//! 見つからないプラグイン名の候補提示（info / uninstall / enable / disable / update 共通）
+//!
+//! インストール済みのプラグイン名と、キャッシュ済みマーケットプレイスのプラグイン名から
+//! 入力に近いものを [`crate::suggest`] で選ぶ。TTY で候補が 1 件だけ十分近く、
+//! その名前でコマンドを続行できる（インストール済み）場合は `Use 'x' instead? [Y/n]` と
+//! 確認し、承諾されればその名前で続行する。`--stdin` では標準入力がプラグイン一覧のため
+//! 確認せず、候補はエラーに添えるだけにする。
+
+use crate::marketplace::MarketplaceRegistry;
+use crate::plugin::PackageCacheAccess;
+use crate::suggest::{confirmable, did_you_mean, suggest, Suggestion};
+use std::io::{self, IsTerminal, Write};
+
+/// 見つからなかった名前の代わりに使う名前を決める
+///
+/// 確認で承諾された場合は候補の名前を返す。それ以外は `not_found` に
+/// `did you mean: ...?` の行を添えたエラーを返す。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache listing installed plugins.
+/// * `name` - Plugin name that was not found.
+/// * `usable` - Whether the command can continue with a suggested name.
+/// * `not_found` - Error message used when no replacement is chosen.
+/// * `prompt` - Whether the confirmation may be asked (`false` when stdin holds the plugin list).
+pub(crate) fn resolve_missing(
+    cache: &dyn PackageCacheAccess,
+    name: &str,
+    usable: impl Fn(&str) -> bool,
+    not_found: String,
+    prompt: bool,
+) -> Result<String, String> {
+    let registry = MarketplaceRegistry::new().ok();
+    let candidates = plugin_name_candidates(cache, registry.as_ref());
+    let suggestions = suggest(name, candidates.iter().map(String::as_str));
+
+    if let Some(only) = confirmable(&suggestions).filter(|s| usable(&s.name)) {
+        if prompt && is_interactive() && confirm_use(&only.name)? {
+            return Ok(only.name.clone());
+        }
+    }
+    Err(not_found_message(not_found, &suggestions))
+}
+
+/// 候補となるプラグイン名（インストール済み + キャッシュ済みマーケットプレイス）
+///
+/// 読めないキャッシュは無視する（候補提示はエラー表示の補足のため）。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache listing installed plugins.
+/// * `registry` - Marketplace registry (`None` if unavailable).
+pub(crate) fn plugin_name_candidates(
+    cache: &dyn PackageCacheAccess,
+    registry: Option<&MarketplaceRegistry>,
+) -> Vec<String> {
+    let mut names: Vec<String> = cache
+        .list()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(_, cache_id)| cache_id)
+        .collect();
+    if let Some(registry) = registry {
+        for marketplace in registry.list().unwrap_or_default() {
+            if let Ok(Some(cache)) = registry.get(&marketplace) {
+                names.extend(cache.plugins.into_iter().map(|p| p.name));
+            }
+        }
+    }
+    names
+}
+
+/// NotFound のエラーメッセージに候補の行を添える
+///
+/// # Arguments
+///
+/// * `not_found` - Original error message.
+/// * `suggestions` - Close names found by [`suggest`].
+fn not_found_message(not_found: String, suggestions: &[Suggestion]) -> String {
+    match did_you_mean(suggestions) {
+        Some(line) => format!("{}\n{}", not_found, line),
+        None => not_found,
+    }
+}
+
+/// 確認プロンプトを出せるか（stdin / stderr がともに端末）
+fn is_interactive() -> bool {
+    io::stdin().is_terminal() && io::stderr().is_terminal()
+}
+
+/// `Use 'x' instead? [Y/n]` を確認する（空入力は Yes）
+///
+/// 出力を JSON などでパイプしても混ざらないよう、プロンプトは stderr に出す。
+///
+/// # Arguments
+///
+/// * `name` - Suggested plugin name.
+fn confirm_use(name: &str) -> Result<bool, String> {
+    eprint!("Use '{}' instead? [Y/n]: ", name);
+    io::stderr().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    let answer = input.trim();
+    Ok(answer.is_empty() || answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
+
+#[cfg(test)]
+#[path = "did_you_mean_test.rs"]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+use crate::marketplace::{MarketplaceCache, MarketplacePlugin, PluginSource};
+use crate::plugin::PackageCache;
+use crate::suggest::suggest;
+use chrono::Utc;
+use std::fs;
+use tempfile::TempDir;
+
+fn marketplace(plugins: &[&str]) -> MarketplaceCache {
+    MarketplaceCache {
+        name: "company-tools".to_string(),
+        fetched_at: Utc::now(),
+        source: "github:company/tools".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins: plugins
+            .iter()
+            .map(|name| MarketplacePlugin {
+                name: name.to_string(),
+                source: PluginSource::Local(format!("./plugins/{}", name)),
+                description: None,
+                version: None,
+                renamed_from: vec![],
+                stats: Default::default(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn candidates_include_installed_and_marketplace_plugins() {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().join("plugins")).unwrap();
+    fs::create_dir_all(cache.plugin_path(Some("company-tools"), "formatter")).unwrap();
+    let registry = MarketplaceRegistry::with_cache_dir(temp.path().join("marketplaces")).unwrap();
+    registry.store(&marketplace(&["linter"])).unwrap();
+
+    let mut names = plugin_name_candidates(&cache, Some(&registry));
+    names.sort();
+    assert_eq!(names, vec!["formatter", "linter"]);
+    assert_eq!(plugin_name_candidates(&cache, None), vec!["formatter"]);
+}
+
+#[test]
+fn not_found_message_appends_suggestions() {
+    let suggestions = suggest("my-plugn", ["my-plugin"]);
+    assert_eq!(
+        not_found_message("Plugin 'my-plugn' not found".to_string(), &suggestions),
+        "Plugin 'my-plugn' not found\ndid you mean: my-plugin?"
+    );
+    assert_eq!(
+        not_found_message("Plugin 'zzz' not found".to_string(), &[]),
+        "Plugin 'zzz' not found"
+    );
+}
@@ -0,0 +1,162 @@
+//! サブコマンドの使用例
+//!
+//! 各コマンドモジュールが `EXAMPLES` 定数を持ち、`--help` の EXAMPLES セクションと
+//! エラー時のヒント表示で共有する。先頭の例をそのコマンドの代表例として扱う。
+//! サブコマンドの一覧そのものは `Cli::command()` から取り、ここでは名前と使用例の対応だけを持つ。
+
+use super::{deploy, info, lifecycle, list, manage, search};
+use crate::cli::Cli;
+use clap::CommandFactory;
+
+/// 使用例 1 件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    /// 実行例（`plm` から始まり、空白区切りでそのままパースできること）
+    pub command: &'static str,
+    /// 一言説明
+    pub description: &'static str,
+}
+
+/// サブコマンド名と使用例の対応（すべてのサブコマンドを含むことはテストで確認する）
+pub(crate) const ALL: &[(&str, &[Example])] = &[
+    ("target", manage::target::EXAMPLES),
+    ("marketplace", manage::marketplace::EXAMPLES),
+    ("install", deploy::install::EXAMPLES),
+    ("list", list::EXAMPLES),
+    ("info", info::EXAMPLES),
+    ("search", search::EXAMPLES),
+    ("enable", lifecycle::enable::EXAMPLES),
+    ("disable", lifecycle::disable::EXAMPLES),
+    ("uninstall", lifecycle::uninstall::EXAMPLES),
+    ("update", lifecycle::update::EXAMPLES),
+    ("pin", lifecycle::pin::EXAMPLES),
+    ("unpin", lifecycle::unpin::EXAMPLES),
+    ("init", manage::init::EXAMPLES),
+    ("pack", manage::pack::EXAMPLES),
+    ("backups", manage::backups::EXAMPLES),
+    ("cache", manage::cache::EXAMPLES),
+    ("hooks", manage::hooks::EXAMPLES),
+    ("link", deploy::link::EXAMPLES),
+    ("unlink", deploy::unlink::EXAMPLES),
+    ("sync", deploy::sync::EXAMPLES),
+    ("refresh", deploy::refresh::EXAMPLES),
+    ("import", deploy::import::EXAMPLES),
+    ("managed", manage::managed::EXAMPLES),
+    ("pick", manage::pick::EXAMPLES),
+    ("env", manage::env::EXAMPLES),
+    ("stats", manage::stats::EXAMPLES),
+    ("placements", manage::placements::EXAMPLES),
+    ("status", manage::status::EXAMPLES),
+    ("gitignore", manage::gitignore::EXAMPLES),
+    ("doctor", manage::doctor::EXAMPLES),
+    ("verify-conversions", manage::verify_conversions::EXAMPLES),
+    ("test", manage::test_plugin::EXAMPLES),
+];
+
+/// `--help` 末尾に付ける EXAMPLES セクションを組み立てる
+///
+/// # Arguments
+///
+/// * `examples` - Examples of one subcommand.
+pub fn render_help(examples: &[Example]) -> String {
+    let mut lines = vec!["EXAMPLES:".to_string()];
+    for example in examples {
+        lines.push(format!("  # {}", example.description));
+        lines.push(format!("  {}", example.command));
+    }
+    lines.join("\n")
+}
+
+/// サブコマンドの使用例
+///
+/// # Arguments
+///
+/// * `subcommand` - Subcommand name (e.g. `install`).
+pub(crate) fn examples_for(subcommand: &str) -> &'static [Example] {
+    ALL.iter()
+        .find(|(name, _)| *name == subcommand)
+        .map_or(&[], |(_, examples)| *examples)
+}
+
+/// エラー時に添える代表例
+///
+/// # Arguments
+///
+/// * `subcommand` - Subcommand name (e.g. `install`).
+pub(crate) fn hint(subcommand: &str) -> Option<String> {
+    examples_for(subcommand).first().map(|example| {
+        format!(
+            "Example:\n  {}\n    {}\n\nRun 'plm {} --help' for more examples.",
+            example.command, example.description, subcommand
+        )
+    })
+}
+
+/// コマンドライン引数からサブコマンド名を取り出す
+///
+/// 先頭（プログラム名）を除き、オプションとその値（`--jobs 4` の `4` など）を読み飛ばした
+/// 最初の引数が既知のサブコマンド（別名を含む）ならその名前を返す。
+///
+/// # Arguments
+///
+/// * `args` - Raw command line arguments including the program name.
+pub(crate) fn subcommand_in_args(args: &[String]) -> Option<String> {
+    let mut cli = Cli::command();
+    cli.build();
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        if arg == "--" {
+            return None;
+        }
+        if arg.starts_with('-') {
+            if takes_separate_value(&cli, arg) {
+                rest.next();
+            }
+            continue;
+        }
+        return cli
+            .find_subcommand(arg)
+            .map(|sub| sub.get_name().to_string());
+    }
+    None
+}
+
+/// オプションが次の引数を値として取るか（`--jobs=4` や `-j4` のように値が続いていれば取らない）
+///
+/// # Arguments
+///
+/// * `cli` - Built top-level command whose options are looked up.
+/// * `option` - Option as written on the command line (e.g. `--jobs`).
+fn takes_separate_value(cli: &clap::Command, option: &str) -> bool {
+    let found = match option.strip_prefix("--") {
+        Some(long) if !long.contains('=') => cli.get_arguments().find(|arg| {
+            arg.get_long_and_visible_aliases()
+                .is_some_and(|names| names.contains(&long))
+        }),
+        Some(_) => None,
+        None => {
+            let mut chars = option[1..].chars();
+            match (chars.next(), chars.next()) {
+                (Some(short), None) => cli.get_arguments().find(|arg| {
+                    arg.get_short_and_visible_aliases()
+                        .is_some_and(|names| names.contains(&short))
+                }),
+                _ => None,
+            }
+        }
+    };
+    found.is_some_and(|arg| arg.get_action().takes_values())
+}
+
+/// 「見つからない」系のエラーメッセージか
+///
+/// # Arguments
+///
+/// * `message` - Error message returned by a command handler.
+pub(crate) fn is_not_found(message: &str) -> bool {
+    message.to_lowercase().contains("not found")
+}
+
+#[cfg(test)]
+#[path = "examples_test.rs"]
+mod tests;
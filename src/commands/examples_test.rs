@@ -0,0 +1,110 @@
+use super::*;
+
+#[test]
+fn every_subcommand_has_examples() {
+    for sub in Cli::command().get_subcommands() {
+        let examples = examples_for(sub.get_name());
+        assert!(
+            !examples.is_empty(),
+            "missing examples for '{}'",
+            sub.get_name()
+        );
+    }
+}
+
+#[test]
+fn every_example_table_entry_is_a_subcommand() {
+    let cli = Cli::command();
+    for (name, _) in ALL {
+        assert!(
+            cli.find_subcommand(name).is_some(),
+            "unknown subcommand '{}'",
+            name
+        );
+    }
+}
+
+#[test]
+fn examples_parse_as_their_subcommand() {
+    for (name, examples) in ALL {
+        for example in *examples {
+            let words: Vec<&str> = example.command.split_whitespace().collect();
+            assert_eq!(words.first(), Some(&"plm"), "{}", example.command);
+            assert_eq!(words.get(1), Some(name), "{}", example.command);
+
+            let matches = crate::cli::command()
+                .try_get_matches_from(words.clone())
+                .unwrap_or_else(|e| panic!("'{}' does not parse: {}", example.command, e));
+            assert_eq!(
+                matches.subcommand_name(),
+                Some(*name),
+                "{}",
+                example.command
+            );
+        }
+    }
+}
+
+#[test]
+fn render_help_lists_description_and_command() {
+    let help = render_help(&[Example {
+        command: "plm list --json",
+        description: "Print JSON",
+    }]);
+    assert_eq!(help, "EXAMPLES:\n  # Print JSON\n  plm list --json");
+}
+
+#[test]
+fn hint_uses_first_example() {
+    let text = hint("install").unwrap();
+    assert!(text.starts_with("Example:\n  plm install owner/repo\n"));
+    assert!(text.contains("plm install --help"));
+    assert_eq!(hint("unknown"), None);
+}
+
+#[test]
+fn subcommand_in_args_skips_program_name_and_flags() {
+    let args = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        subcommand_in_args(&args(&["plm", "--verbose", "install", "x"])).as_deref(),
+        Some("install")
+    );
+    assert_eq!(subcommand_in_args(&args(&["plm", "instal"])), None);
+    assert_eq!(subcommand_in_args(&args(&["plm"])), None);
+}
+
+#[test]
+fn subcommand_in_args_skips_option_values() {
+    let args = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        subcommand_in_args(&args(&["plm", "--project-root", "list", "info", "x"])).as_deref(),
+        Some("info")
+    );
+    assert_eq!(
+        subcommand_in_args(&args(&["plm", "--jobs=4", "update"])).as_deref(),
+        Some("update")
+    );
+    assert_eq!(
+        subcommand_in_args(&args(&["plm", "--wait-lock", "30"])),
+        None
+    );
+}
+
+#[test]
+fn help_ends_with_examples_of_the_subcommand() {
+    let mut cli = crate::cli::command();
+    let help = cli
+        .find_subcommand_mut("install")
+        .unwrap()
+        .render_long_help()
+        .to_string();
+    assert!(help.contains("EXAMPLES:\n  # "));
+    assert!(help.contains("plm install owner/repo"));
+}
+
+#[test]
+fn is_not_found_matches_case_insensitively() {
+    assert!(is_not_found("Plugin not found: formatter"));
+    assert!(is_not_found("Marketplace 'x' Not Found"));
+    assert!(!is_not_found("Network error"));
+}
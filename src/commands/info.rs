@@ -2,22 +2,22 @@
 //!
 //! インストール済みプラグインの詳細情報を表示する。
 
+mod files;
 mod json;
 mod table;
 mod wire;
 mod yaml;
 
-use crate::application::get_plugin_info;
-use crate::plugin::PackageCache;
-use clap::{Parser, ValueEnum};
+pub(crate) use files::format_size;
 
-/// 出力形式
-#[derive(Debug, Clone, ValueEnum)]
-pub enum OutputFormat {
-    Table,
-    Json,
-    Yaml,
-}
+use crate::application::{get_plugin_info, list_plugin_files, PluginInfo};
+use crate::commands::args::OutputFormat;
+use crate::commands::did_you_mean;
+use crate::commands::examples::Example;
+use crate::error::PlmError;
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use clap::{Parser, ValueEnum};
 
 #[derive(Debug, Parser)]
 pub struct Args {
@@ -27,25 +27,156 @@ pub struct Args {
     /// 出力形式
     #[arg(long, short = 'f', value_enum, default_value = "table")]
     pub format: OutputFormat,
+
+    /// プラグインが持つ全ファイルをツリー表示する（JSON / YAML はフラットな配列）
+    #[arg(long)]
+    pub files: bool,
+
+    /// `--files` の深さ・件数の上限を外して全件表示する
+    #[arg(long, requires = "files")]
+    pub all: bool,
+
+    /// インストール・更新履歴（直近 5 回）を全件表示する
+    #[arg(long)]
+    pub history: bool,
+
+    /// JSON / YAML の `components` の形式（v1 は名前の配列のみ）
+    #[arg(long, value_enum, value_name = "VERSION", default_value = "v2")]
+    pub json_schema: JsonSchema,
 }
 
+/// `--format json` / `--format yaml` の出力スキーマ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum JsonSchema {
+    /// `components` は種別ごとの名前の配列
+    V1,
+    /// `components` は種別ごとの詳細オブジェクトの配列
+    V2,
+}
+
+/// `plm info` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm info formatter",
+        description: "Show details of an installed plugin",
+    },
+    Example {
+        command: "plm info company-tools/formatter",
+        description: "Pick the plugin from a specific marketplace",
+    },
+    Example {
+        command: "plm info formatter -f json",
+        description: "Print the details as JSON",
+    },
+    Example {
+        command: "plm info formatter --files",
+        description: "Show the plugin's files as a tree",
+    },
+    Example {
+        command: "plm info formatter --history",
+        description: "Show the recent install / update history",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm info`.
 pub async fn run(args: Args) -> Result<(), String> {
     let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
-    let detail = get_plugin_info(&cache, &args.name)
-        .map_err(|e| format!("Failed to get plugin info: {e}"))?;
+    let detail = match get_plugin_info(&cache, &args.name) {
+        Err(e @ PlmError::PluginNotFound(_)) => {
+            let not_found = format!("Failed to get plugin info: {e}");
+            let name = resolve_missing_name(&cache, &args.name, not_found)?;
+            get_plugin_info(&cache, &name)
+        }
+        result => result,
+    }
+    .map_err(|e| format!("Failed to get plugin info: {e}"))?;
+
+    if args.files {
+        return print_files(&detail, &args);
+    }
 
     match args.format {
-        OutputFormat::Table => table::print_table(&detail),
-        OutputFormat::Json => json::print_json(&detail)?,
-        OutputFormat::Yaml => yaml::print_yaml(&detail)?,
+        OutputFormat::Table => table::print_table(&detail, args.history),
+        OutputFormat::Json => json::print_json(&detail, args.json_schema)?,
+        OutputFormat::Yaml => yaml::print_yaml(&detail, args.json_schema)?,
     }
 
     Ok(())
 }
 
+/// 見つからなかった名前に近いインストール済みプラグインを提示する
+///
+/// `marketplace/plugin` 形式ではプラグイン名の部分だけを比較し、選ばれた名前に
+/// 同じマーケットプレイスを付け直して返す。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache listing installed plugins.
+/// * `input` - Plugin name given to `plm info`.
+/// * `not_found` - Error message used when no replacement is chosen.
+fn resolve_missing_name(
+    cache: &dyn PackageCacheAccess,
+    input: &str,
+    not_found: String,
+) -> Result<String, String> {
+    let (marketplace, plugin) = match input.split_once('/') {
+        Some((marketplace, plugin)) => (Some(marketplace), plugin),
+        None => (None, input),
+    };
+    let installed = cache.list().unwrap_or_default();
+    let usable = |name: &str| {
+        installed.iter().any(|(m, id)| {
+            id == name
+                && marketplace
+                    .is_none_or(|mp| MarketplaceRef::from_option(m.as_deref()).dir_name() == mp)
+        })
+    };
+    let chosen = did_you_mean::resolve_missing(cache, plugin, usable, not_found, true)?;
+    Ok(match marketplace {
+        Some(marketplace) => format!("{}/{}", marketplace, chosen),
+        None => chosen,
+    })
+}
+
+/// `--files` の出力
+///
+/// # Arguments
+///
+/// * `detail` - Resolved plugin information.
+/// * `args` - Parsed CLI arguments for `plm info`.
+fn print_files(detail: &PluginInfo, args: &Args) -> Result<(), String> {
+    let root = detail.installed.cache_path();
+    let entries = list_plugin_files(root, detail.installed.components())
+        .map_err(|e| format!("Failed to list plugin files: {e}"))?;
+
+    match args.format {
+        OutputFormat::Table => {
+            let limit = if args.all {
+                files::TreeLimit::unlimited()
+            } else {
+                files::TreeLimit::default_limit()
+            };
+            print!(
+                "{}",
+                files::render_tree(&root.display().to_string(), &entries, limit)
+            );
+        }
+        OutputFormat::Json => {
+            let s = serde_json::to_string_pretty(&files::to_wire(&entries))
+                .map_err(|e| format!("Failed to serialize to JSON: {}", e))?;
+            println!("{s}");
+        }
+        OutputFormat::Yaml => {
+            let s = serde_yaml::to_string(&files::to_wire(&entries))
+                .map_err(|e| format!("Failed to serialize to YAML: {}", e))?;
+            print!("{s}");
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "info/info_test.rs"]
 mod tests;
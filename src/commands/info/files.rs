@@ -0,0 +1,143 @@
+//! `plm info --files` のファイルツリー出力
+//!
+//! 人間向けには tree コマンド風のツリー（サイズ・種別マーク付き）、
+//! JSON / YAML ではファイルのフラットな配列を出力する。
+
+use crate::application::PluginFile;
+use crate::output::tree::{self, TreeEntry};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// ツリー表示の既定の深さ上限
+pub(super) const DEFAULT_MAX_DEPTH: usize = 4;
+
+/// ツリー表示の既定のエントリ数上限
+pub(super) const DEFAULT_MAX_ENTRIES: usize = 500;
+
+/// ツリー表示の打ち切り条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct TreeLimit {
+    pub(super) max_depth: Option<usize>,
+    pub(super) max_entries: Option<usize>,
+}
+
+impl TreeLimit {
+    /// 既定の上限（`--all` 未指定時）
+    pub(super) fn default_limit() -> Self {
+        Self {
+            max_depth: Some(DEFAULT_MAX_DEPTH),
+            max_entries: Some(DEFAULT_MAX_ENTRIES),
+        }
+    }
+
+    /// 上限なし（`--all`）
+    pub(super) fn unlimited() -> Self {
+        Self {
+            max_depth: None,
+            max_entries: None,
+        }
+    }
+}
+
+/// ファイル一覧をツリー表示用テキストに整形する
+///
+/// 上限を超えたエントリは表示せず、省略したファイル数を末尾に
+/// `... (N more files)` として示す。
+///
+/// # Arguments
+///
+/// * `root_label` - Label printed on the first line (plugin cache path).
+/// * `files` - Entries from `list_plugin_files` (name order, depth first).
+/// * `limit` - Depth / entry count limits.
+pub(super) fn render_tree(root_label: &str, files: &[PluginFile], limit: TreeLimit) -> String {
+    let mut entries = Vec::new();
+    let mut omitted_files = 0;
+
+    for file in files {
+        let within_depth = limit.max_depth.is_none_or(|max| file.depth <= max);
+        let within_count = limit.max_entries.is_none_or(|max| entries.len() < max);
+        if within_depth && within_count {
+            entries.push(TreeEntry::new(file.depth, entry_label(file)));
+        } else if !file.is_dir {
+            omitted_files += 1;
+        }
+    }
+
+    let mut out = tree::render_tree(root_label, &entries);
+    if omitted_files > 0 {
+        writeln!(out, "... ({} more files)", omitted_files).unwrap();
+    }
+    out
+}
+
+/// 1 エントリの表示ラベル（名前・サイズ・種別マーク）
+///
+/// # Arguments
+///
+/// * `file` - Entry to label.
+fn entry_label(file: &PluginFile) -> String {
+    let name = file
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut label = if file.is_dir {
+        format!("{}/", name)
+    } else {
+        format!("{} ({})", name, format_size(file.size))
+    };
+    if let (true, Some(kind)) = (file.is_component_root, file.kind) {
+        write!(label, " [{}]", kind.as_str()).unwrap();
+    }
+    label
+}
+
+/// バイト数を読みやすい単位に変換する（1024 進）
+///
+/// # Arguments
+///
+/// * `bytes` - Size in bytes.
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// JSON / YAML 用のファイルエントリ
+#[derive(Debug, Serialize)]
+pub(super) struct WireFile {
+    pub(super) path: String,
+    pub(super) size: u64,
+    pub(super) kind: Option<&'static str>,
+}
+
+/// ファイル（ディレクトリを除く）のフラットな配列に変換する
+///
+/// 機械処理向けのため表示上限は適用しない。
+///
+/// # Arguments
+///
+/// * `files` - Entries from `list_plugin_files`.
+pub(super) fn to_wire(files: &[PluginFile]) -> Vec<WireFile> {
+    files
+        .iter()
+        .filter(|f| !f.is_dir)
+        .map(|f| WireFile {
+            path: f.path.to_string_lossy().replace('\\', "/"),
+            size: f.size,
+            kind: f.kind.map(|k| k.as_str()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "files_test.rs"]
+mod tests;
@@ -0,0 +1,153 @@
+use super::*;
+use crate::component::ComponentKind;
+use std::path::PathBuf;
+
+fn dir(path: &str) -> PluginFile {
+    PluginFile {
+        path: PathBuf::from(path),
+        depth: path.split('/').count(),
+        is_dir: true,
+        size: 0,
+        kind: None,
+        is_component_root: false,
+    }
+}
+
+fn file(path: &str, size: u64) -> PluginFile {
+    PluginFile {
+        is_dir: false,
+        size,
+        ..dir(path)
+    }
+}
+
+fn component(mut entry: PluginFile, kind: ComponentKind, root: bool) -> PluginFile {
+    entry.kind = Some(kind);
+    entry.is_component_root = root;
+    entry
+}
+
+fn sample() -> Vec<PluginFile> {
+    vec![
+        file("README.md", 2048),
+        dir("agents"),
+        component(file("agents/review.md", 10), ComponentKind::Agent, true),
+        dir("skills"),
+        component(dir("skills/fmt"), ComponentKind::Skill, true),
+        component(
+            file("skills/fmt/SKILL.md", 300),
+            ComponentKind::Skill,
+            false,
+        ),
+        component(dir("skills/fmt/scripts"), ComponentKind::Skill, false),
+        component(
+            file("skills/fmt/scripts/run.sh", 5),
+            ComponentKind::Skill,
+            false,
+        ),
+        file("plugin.json", 64),
+    ]
+}
+
+#[test]
+fn render_tree_draws_branches_sizes_and_kind_marks() {
+    let out = render_tree("/cache/demo", &sample(), TreeLimit::unlimited());
+
+    let expected = "\
+/cache/demo
+├── README.md (2.0 KiB)
+├── agents/
+│   └── review.md (10 B) [agent]
+├── skills/
+│   └── fmt/ [skill]
+│       ├── SKILL.md (300 B)
+│       └── scripts/
+│           └── run.sh (5 B)
+└── plugin.json (64 B)
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_tree_limits_depth_and_counts_omitted_files() {
+    let limit = TreeLimit {
+        max_depth: Some(2),
+        max_entries: None,
+    };
+
+    let out = render_tree("/cache/demo", &sample(), limit);
+
+    assert!(out.contains("│   └── fmt/ [skill]\n"));
+    assert!(!out.contains("SKILL.md"));
+    assert!(out.ends_with("... (2 more files)\n"));
+}
+
+#[test]
+fn render_tree_limits_entry_count() {
+    let limit = TreeLimit {
+        max_depth: None,
+        max_entries: Some(3),
+    };
+
+    let out = render_tree("/cache/demo", &sample(), limit);
+
+    assert_eq!(out.lines().count(), 1 + 3 + 1);
+    assert!(out.ends_with("... (3 more files)\n"));
+}
+
+#[test]
+fn render_tree_marks_last_shown_sibling_after_truncation() {
+    let limit = TreeLimit {
+        max_depth: None,
+        max_entries: Some(3),
+    };
+
+    let out = render_tree("/cache/demo", &sample(), limit);
+
+    let expected = "\
+/cache/demo
+├── README.md (2.0 KiB)
+└── agents/
+    └── review.md (10 B) [agent]
+... (3 more files)
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_tree_without_omissions_has_no_trailer() {
+    let out = render_tree("/cache/demo", &sample(), TreeLimit::default_limit());
+    assert!(!out.contains("more files"));
+}
+
+#[test]
+fn default_limit_is_depth_4_and_500_entries() {
+    let limit = TreeLimit::default_limit();
+    assert_eq!(limit.max_depth, Some(4));
+    assert_eq!(limit.max_entries, Some(500));
+}
+
+#[test]
+fn format_size_uses_binary_units() {
+    assert_eq!(format_size(0), "0 B");
+    assert_eq!(format_size(1023), "1023 B");
+    assert_eq!(format_size(1536), "1.5 KiB");
+    assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+}
+
+#[test]
+fn to_wire_lists_files_only_with_kind() {
+    let wire = to_wire(&sample());
+
+    let json = serde_json::to_value(&wire).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 5);
+    assert_eq!(
+        json[1],
+        serde_json::json!({"path": "agents/review.md", "size": 10, "kind": "agent"})
+    );
+    assert_eq!(
+        json[3],
+        serde_json::json!({"path": "skills/fmt/scripts/run.sh", "size": 5, "kind": "skill"})
+    );
+    assert_eq!(json[4]["kind"], serde_json::Value::Null);
+}
@@ -1,9 +1,13 @@
 use super::json::render_json;
-use super::table::{format_list, render_table};
+use super::table::{format_list, render_table, render_update_history};
 use super::yaml::render_yaml;
-use crate::application::{InstalledPlugin, PluginInfo, Source};
+use super::JsonSchema;
+use crate::application::{ComponentDetail, DeploymentEntry, InstalledPlugin, PluginInfo, Source};
 use crate::component::{Component, ComponentKind};
-use crate::plugin::{Author, PluginManifest};
+use crate::plugin::{
+    Author, Channel, DisabledComponent, PluginManifest, UpdateRecord, UpdateResult,
+};
+use crate::target::TargetKind;
 use std::path::PathBuf;
 
 const TEST_CACHE_PATH: &str = "/home/user/.plm/cache/plugins/github/owner--repo";
@@ -17,8 +21,21 @@ fn comp(kind: ComponentKind, name: &str) -> Component {
     )
 }
 
+fn detail(kind: ComponentKind, name: &str, path: &str) -> ComponentDetail {
+    ComponentDetail {
+        kind,
+        name: name.to_string(),
+        path: PathBuf::from(path),
+        description: None,
+    }
+}
+
 fn create_test_info() -> PluginInfo {
-    let manifest = PluginManifest {
+    create_test_info_with(|_| {})
+}
+
+fn create_test_info_with(edit: impl FnOnce(&mut PluginManifest)) -> PluginInfo {
+    let mut manifest = PluginManifest {
         name: "test-plugin".to_string(),
         version: "1.0.0".to_string(),
         description: Some("A test plugin".to_string()),
@@ -38,7 +55,10 @@ fn create_test_info() -> PluginInfo {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
+    edit(&mut manifest);
     let installed = InstalledPlugin::new_for_test_full(
         manifest,
         PathBuf::from(TEST_CACHE_PATH),
@@ -57,6 +77,13 @@ fn create_test_info() -> PluginInfo {
         source: Source::GitHub {
             repository: "owner/repo".to_string(),
         },
+        channel: None,
+        deployments: vec![],
+        component_details: vec![
+            detail(ComponentKind::Skill, "skill1", "skills/skill1"),
+            detail(ComponentKind::Skill, "skill2", "skills/skill2"),
+            detail(ComponentKind::Command, "cmd1", "commands/cmd1.md"),
+        ],
     }
 }
 
@@ -78,7 +105,7 @@ fn test_format_list_multiple() {
 #[test]
 fn test_json_serialization() {
     let info = create_test_info();
-    let json = render_json(&info).unwrap();
+    let json = render_json(&info, JsonSchema::V2).unwrap();
 
     assert!(json.contains("\"name\": \"test-plugin\""));
     assert!(json.contains("\"version\": \"1.0.0\""));
@@ -119,6 +146,8 @@ fn test_json_serialization_no_author() {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
     info.installed = InstalledPlugin::new_for_test_full(
         manifest,
@@ -129,7 +158,7 @@ fn test_json_serialization_no_author() {
         TEST_ENABLED,
     );
 
-    let json = render_json(&info).unwrap();
+    let json = render_json(&info, JsonSchema::V2).unwrap();
 
     assert!(!json.contains("\"author\""));
 }
@@ -137,7 +166,7 @@ fn test_json_serialization_no_author() {
 #[test]
 fn test_yaml_serialization() {
     let info = create_test_info();
-    let yaml = render_yaml(&info).unwrap();
+    let yaml = render_yaml(&info, JsonSchema::V2).unwrap();
 
     assert!(yaml.contains("name: test-plugin"));
     assert!(yaml.contains("version: 1.0.0"));
@@ -156,7 +185,7 @@ fn test_yaml_serialization() {
 #[test]
 fn table_output_contains_cache_path() {
     let info = create_test_info();
-    let rendered = render_table(&info);
+    let rendered = render_table(&info, false);
     assert!(rendered.contains("Cache Path"));
     assert!(rendered.contains(TEST_CACHE_PATH));
 }
@@ -164,7 +193,7 @@ fn table_output_contains_cache_path() {
 #[test]
 fn table_output_contains_status() {
     let info = create_test_info();
-    let rendered = render_table(&info);
+    let rendered = render_table(&info, false);
     assert!(rendered.contains("Status"));
     assert!(rendered.contains("enabled"));
 
@@ -184,6 +213,8 @@ fn table_output_contains_status() {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
     let disabled_info = PluginInfo {
         installed: InstalledPlugin::new_for_test_full(
@@ -198,8 +229,11 @@ fn table_output_contains_status() {
         source: Source::GitHub {
             repository: "owner/repo".to_string(),
         },
+        channel: None,
+        deployments: vec![],
+        component_details: vec![],
     };
-    let rendered = render_table(&disabled_info);
+    let rendered = render_table(&disabled_info, false);
     assert!(rendered.contains("disabled"));
 }
 
@@ -221,6 +255,8 @@ fn table_output_omits_author_section_when_none() {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
     let info = PluginInfo {
         installed: InstalledPlugin::new_for_test_full(
@@ -235,32 +271,84 @@ fn table_output_omits_author_section_when_none() {
         source: Source::GitHub {
             repository: "owner/repo".to_string(),
         },
+        channel: None,
+        deployments: vec![],
+        component_details: vec![],
     };
-    let rendered = render_table(&info);
+    let rendered = render_table(&info, false);
     assert!(!rendered.contains("Author\n------"));
 }
 
 #[test]
 fn table_output_formats_source_github() {
     let info = create_test_info();
-    let rendered = render_table(&info);
+    let rendered = render_table(&info, false);
     assert!(rendered.contains("GitHub (owner/repo)"));
 }
 
+#[test]
+fn channel_is_rendered_in_table_and_json() {
+    let mut info = create_test_info();
+    assert!(!render_json(&info, JsonSchema::V2)
+        .unwrap()
+        .contains("\"channel\""));
+
+    info.channel = Some(Channel::Beta);
+    assert!(render_table(&info, false).contains("beta"));
+    assert!(render_json(&info, JsonSchema::V2)
+        .unwrap()
+        .contains("\"channel\": \"beta\""));
+}
+
+#[test]
+fn update_history_is_rendered_in_history_table_and_json() {
+    let now = chrono::DateTime::parse_from_rfc3339("2026-03-04T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let mut info = create_test_info();
+    assert!(render_update_history(&info, now).contains("No update history recorded"));
+    assert!(!render_json(&info, JsonSchema::V2)
+        .unwrap()
+        .contains("\"update_history\""));
+
+    info.installed = info.installed.with_update_history(vec![
+        UpdateRecord {
+            at: "2026-03-01T00:00:00Z".to_string(),
+            from_version: Some("1.1.0".to_string()),
+            to_version: Some("1.2.0".to_string()),
+            result: UpdateResult::Updated,
+        },
+        UpdateRecord {
+            at: "2026-01-01T00:00:00Z".to_string(),
+            from_version: None,
+            to_version: Some("1.1.0".to_string()),
+            result: UpdateResult::Installed,
+        },
+    ]);
+
+    let history = render_update_history(&info, now);
+    assert!(history.contains("3 days ago"), "{}", history);
+    assert!(history.contains("1.1.0 → 1.2.0"), "{}", history);
+    assert!(history.contains("installed 1.1.0"), "{}", history);
+    let json = render_json(&info, JsonSchema::V2).unwrap();
+    assert!(json.contains("\"from_version\": \"1.1.0\""), "{}", json);
+    assert!(json.contains("\"result\": \"installed\""), "{}", json);
+}
+
 #[test]
 fn table_output_formats_source_marketplace() {
     let mut info = create_test_info();
     info.source = Source::Marketplace {
         name: "official".to_string(),
     };
-    let rendered = render_table(&info);
+    let rendered = render_table(&info, false);
     assert!(rendered.contains("Marketplace (official)"));
 }
 
 #[test]
-fn test_json_components_nested_shape() {
+fn test_json_components_v1_shape() {
     let info = create_test_info();
-    let json_str = render_json(&info).unwrap();
+    let json_str = render_json(&info, JsonSchema::V1).unwrap();
     let json: serde_json::Value = serde_json::from_str(&json_str).unwrap();
 
     let components = &json["components"];
@@ -273,3 +361,187 @@ fn test_json_components_nested_shape() {
     assert_eq!(components["instructions"], serde_json::json!([]));
     assert_eq!(components["hooks"], serde_json::json!([]));
 }
+
+#[test]
+fn test_json_components_v2_details() {
+    let mut info = create_test_info();
+    with_deployments(&mut info);
+    info.component_details[0].description = Some("First skill".to_string());
+    info.installed = info
+        .installed
+        .with_disabled_components(vec![DisabledComponent {
+            kind: ComponentKind::Skill,
+            name: "skill2".to_string(),
+        }]);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&info, JsonSchema::V2).unwrap()).unwrap();
+    let components = &json["components"];
+    assert_eq!(
+        components["skills"],
+        serde_json::json!([
+            {
+                "name": "skill1",
+                "path": "skills/skill1",
+                "description": "First skill",
+                "disabled": false,
+                "deployed_to": ["codex"],
+            },
+            {
+                "name": "skill2",
+                "path": "skills/skill2",
+                "disabled": true,
+                "deployed_to": [],
+            },
+        ])
+    );
+    // 配置物が無いターゲットは deployed_to に含めない
+    assert_eq!(
+        components["commands"][0]["deployed_to"],
+        serde_json::json!([])
+    );
+    assert_eq!(components["agents"], serde_json::json!([]));
+
+    let yaml = render_yaml(&info, JsonSchema::V2).unwrap();
+    assert!(yaml.contains("description: First skill"));
+}
+
+#[test]
+fn disabled_components_are_marked_in_table_and_json() {
+    let mut info = create_test_info();
+    assert!(!render_json(&info, JsonSchema::V2)
+        .unwrap()
+        .contains("disabled_components"));
+
+    info.installed = info
+        .installed
+        .with_disabled_components(vec![DisabledComponent {
+            kind: ComponentKind::Skill,
+            name: "skill1".to_string(),
+        }]);
+
+    assert!(render_table(&info, false).contains("skill1 (disabled)"));
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&info, JsonSchema::V2).unwrap()).unwrap();
+    assert_eq!(
+        json["disabled_components"],
+        serde_json::json!([{ "kind": "skill", "name": "skill1" }])
+    );
+}
+
+fn with_metadata(manifest: &mut PluginManifest) {
+    manifest.homepage = Some("https://example.com/test-plugin".to_string());
+    manifest.repository = Some("https://github.com/owner/test-plugin".to_string());
+    manifest.keywords = Some(vec!["lint".to_string(), "format".to_string()]);
+}
+
+#[test]
+fn metadata_is_rendered_in_table_json_and_yaml() {
+    let info = create_test_info_with(with_metadata);
+
+    let table = render_table(&info, false);
+    assert!(table.contains("Homepage"), "{}", table);
+    assert!(
+        table.contains("https://example.com/test-plugin"),
+        "{}",
+        table
+    );
+    assert!(
+        table.contains("https://github.com/owner/test-plugin"),
+        "{}",
+        table
+    );
+    assert!(table.contains("lint, format"), "{}", table);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&info, JsonSchema::V2).unwrap()).unwrap();
+    assert_eq!(json["homepage"], "https://example.com/test-plugin");
+    assert_eq!(json["repository"], "https://github.com/owner/test-plugin");
+    assert_eq!(json["keywords"], serde_json::json!(["lint", "format"]));
+
+    let yaml = render_yaml(&info, JsonSchema::V2).unwrap();
+    assert!(
+        yaml.contains("homepage: https://example.com/test-plugin"),
+        "{}",
+        yaml
+    );
+    assert!(yaml.contains("- lint"), "{}", yaml);
+}
+
+#[test]
+fn metadata_is_omitted_when_missing_or_blank() {
+    let info = create_test_info_with(|m| m.homepage = Some(" ".to_string()));
+
+    let table = render_table(&info, false);
+    assert!(!table.contains("Homepage"), "{}", table);
+    assert!(!table.contains("Keywords"), "{}", table);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&info, JsonSchema::V2).unwrap()).unwrap();
+    assert!(json.get("homepage").is_none());
+    assert!(json.get("repository").is_none());
+    assert!(json.get("keywords").is_none());
+}
+
+fn with_deployments(info: &mut PluginInfo) {
+    info.deployments = vec![
+        DeploymentEntry {
+            target: TargetKind::Codex,
+            kind: ComponentKind::Skill,
+            name: "skill1".to_string(),
+            path: PathBuf::from("/project/.codex/skills/owner--repo/skill1"),
+            exists: true,
+        },
+        DeploymentEntry {
+            target: TargetKind::Copilot,
+            kind: ComponentKind::Command,
+            name: "cmd1".to_string(),
+            path: PathBuf::from("/project/.github/prompts/cmd1.prompt.md"),
+            exists: false,
+        },
+    ];
+}
+
+#[test]
+fn deployments_are_rendered_in_table_with_missing_mark() {
+    let mut info = create_test_info();
+    with_deployments(&mut info);
+
+    let table = render_table(&info, false);
+    assert!(table.contains("Deployed to:"));
+    assert!(table.contains("  codex skill skill1: /project/.codex/skills/owner--repo/skill1\n"));
+    assert!(table
+        .contains("  copilot command cmd1: /project/.github/prompts/cmd1.prompt.md (missing)\n"));
+
+    let colored = render_table(&info, true);
+    assert!(colored.contains("\u{1b}[32m/project/.codex/skills/owner--repo/skill1"));
+    assert!(colored.contains("\u{1b}[33m/project/.github/prompts/cmd1.prompt.md (missing)"));
+}
+
+#[test]
+fn deployments_section_is_omitted_when_empty() {
+    assert!(!render_table(&create_test_info(), false).contains("Deployed to:"));
+}
+
+#[test]
+fn deployments_are_included_in_json_and_yaml() {
+    let mut info = create_test_info();
+    with_deployments(&mut info);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&info, JsonSchema::V2).unwrap()).unwrap();
+    assert_eq!(
+        json["deployments"][1],
+        serde_json::json!({
+            "target": "copilot",
+            "kind": "command",
+            "name": "cmd1",
+            "path": "/project/.github/prompts/cmd1.prompt.md",
+            "exists": false,
+        })
+    );
+
+    let yaml = render_yaml(&info, JsonSchema::V2).unwrap();
+    assert!(yaml.contains("deployments:"));
+    assert!(yaml.contains("exists: true"));
+}
@@ -1,6 +1,7 @@
 //! JSON 出力フォーマット
 
 use super::wire::Wire;
+use super::JsonSchema;
 use crate::application::PluginInfo;
 
 /// Render `PluginInfo` as a pretty-printed JSON string.
@@ -8,8 +9,9 @@ use crate::application::PluginInfo;
 /// # Arguments
 ///
 /// * `info` - Plugin information to serialize.
-pub(super) fn render_json(info: &PluginInfo) -> Result<String, String> {
-    let wire = Wire::from(info);
+/// * `schema` - Shape of the `components` field.
+pub(super) fn render_json(info: &PluginInfo, schema: JsonSchema) -> Result<String, String> {
+    let wire = Wire::new(info, schema);
     serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize to JSON: {}", e))
 }
 
@@ -18,8 +20,9 @@ pub(super) fn render_json(info: &PluginInfo) -> Result<String, String> {
 /// # Arguments
 ///
 /// * `info` - Plugin information to print.
-pub(super) fn print_json(info: &PluginInfo) -> Result<(), String> {
-    let s = render_json(info)?;
+/// * `schema` - Shape of the `components` field.
+pub(super) fn print_json(info: &PluginInfo, schema: JsonSchema) -> Result<(), String> {
+    let s = render_json(info, schema)?;
     println!("{s}");
     Ok(())
 }
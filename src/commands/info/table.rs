@@ -1,9 +1,13 @@
 //! Table 出力フォーマット
 
-use crate::application::{PluginInfo, Source};
+use crate::application::{DeploymentEntry, PluginInfo, Source};
 use crate::component::ComponentKind;
+use crate::output::stdout_color_enabled;
+use crate::output::time::{last_updated_summary, relative_timestamp, version_change};
 use crate::plugin::Author;
+use chrono::{DateTime, Utc};
 use comfy_table::{presets::UTF8_FULL, Table};
+use owo_colors::OwoColorize;
 use std::fmt::Write;
 
 /// Render `PluginInfo` as a human-readable table string.
@@ -11,7 +15,8 @@ use std::fmt::Write;
 /// # Arguments
 ///
 /// * `info` - Plugin information to render.
-pub(super) fn render_table(info: &PluginInfo) -> String {
+/// * `color` - Whether to color the deployment paths with ANSI colors.
+pub(super) fn render_table(info: &PluginInfo, color: bool) -> String {
     let mut out = render_basic_info(info);
     if let Some(author) = info.installed.author() {
         out.push_str(&render_author(author));
@@ -19,6 +24,7 @@ pub(super) fn render_table(info: &PluginInfo) -> String {
     out.push_str(&render_installation(info));
     out.push_str(&render_components(info));
     out.push_str(&render_deployment(info));
+    out.push_str(&render_deployed_to(&info.deployments, color));
     out
 }
 
@@ -27,8 +33,12 @@ pub(super) fn render_table(info: &PluginInfo) -> String {
 /// # Arguments
 ///
 /// * `info` - Plugin information to print.
-pub(super) fn print_table(info: &PluginInfo) {
-    print!("{}", render_table(info));
+/// * `history` - Whether to append the full install / update history (`--history`).
+pub(super) fn print_table(info: &PluginInfo, history: bool) {
+    print!("{}", render_table(info, stdout_color_enabled()));
+    if history {
+        print!("{}", render_update_history(info, Utc::now()));
+    }
 }
 
 /// Format a slice of string items as a comma-separated list, or `"none"` when empty.
@@ -44,7 +54,7 @@ pub(super) fn format_list(items: &[&str]) -> String {
     }
 }
 
-/// Render the "Plugin Information" section (name/version/description).
+/// Render the "Plugin Information" section (name/version/description and optional links/keywords).
 ///
 /// # Arguments
 ///
@@ -65,6 +75,16 @@ fn render_basic_info(info: &PluginInfo) -> String {
             "Description",
             info.installed.description().unwrap_or("-"),
         ]);
+    if let Some(homepage) = info.installed.homepage() {
+        table.add_row(vec!["Homepage", homepage]);
+    }
+    if let Some(repository) = info.installed.repository() {
+        table.add_row(vec!["Repository", repository]);
+    }
+    let keywords = info.installed.keywords();
+    if !keywords.is_empty() {
+        table.add_row(vec!["Keywords".to_string(), keywords.join(", ")]);
+    }
 
     writeln!(out, "{table}").unwrap();
     writeln!(out).unwrap();
@@ -121,7 +141,53 @@ fn render_installation(info: &PluginInfo) -> String {
             "Installed At",
             info.installed_at.as_deref().unwrap_or("N/A"),
         ])
-        .add_row(vec!["Source", &source_str]);
+        .add_row(vec![
+            "Last Updated".to_string(),
+            last_updated_summary(
+                info.installed.updated_at(),
+                info.installed.last_update(),
+                Utc::now(),
+            )
+            .unwrap_or_else(|| "N/A".to_string()),
+        ])
+        .add_row(vec!["Source", &source_str])
+        .add_row(vec!["Channel", info.channel.map_or("-", |c| c.as_str())]);
+
+    writeln!(out, "{table}").unwrap();
+    writeln!(out).unwrap();
+    out
+}
+
+/// Render the "Update History" section (`plm info --history`), newest first.
+///
+/// # Arguments
+///
+/// * `info` - Plugin information to render.
+/// * `now` - Current time used for the relative timestamps.
+pub(super) fn render_update_history(info: &PluginInfo, now: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    writeln!(out, "Update History").unwrap();
+    writeln!(out, "--------------").unwrap();
+
+    let history = info.installed.update_history();
+    if history.is_empty() {
+        writeln!(out, "No update history recorded").unwrap();
+        writeln!(out).unwrap();
+        return out;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["When", "At", "Version", "Result"])
+        .add_rows(history.iter().map(|record| {
+            vec![
+                relative_timestamp(&record.at, now),
+                record.at.clone(),
+                version_change(record).unwrap_or_else(|| "-".to_string()),
+                record.result.to_string(),
+            ]
+        }));
 
     writeln!(out, "{table}").unwrap();
     writeln!(out).unwrap();
@@ -148,11 +214,18 @@ fn render_components(info: &PluginInfo) -> String {
     ]
     .into_iter()
     .map(|(kind, label)| {
-        let names: Vec<&str> = components
+        let names: Vec<String> = components
             .iter()
             .filter(|c| c.kind == kind)
-            .map(|c| c.name.as_str())
+            .map(|c| {
+                if info.installed.is_component_disabled(c.kind, &c.name) {
+                    format!("{} (disabled)", c.name)
+                } else {
+                    c.name.clone()
+                }
+            })
             .collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
         vec![label.to_string(), format_list(&names)]
     });
 
@@ -177,20 +250,53 @@ fn render_deployment(info: &PluginInfo) -> String {
     writeln!(out, "Deployment").unwrap();
     writeln!(out, "----------").unwrap();
 
-    let status = if info.installed.enabled() {
-        "enabled"
-    } else {
-        "disabled"
-    };
+    let status = info.installed.status_label();
     let cache_path = info.installed.cache_path().to_string_lossy().into_owned();
 
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec!["Field", "Value"])
-        .add_row(vec!["Status", status])
+        .add_row(vec!["Status", status.as_str()])
         .add_row(vec!["Cache Path", cache_path.as_str()]);
 
     writeln!(out, "{table}").unwrap();
     out
 }
+
+/// Render the "Deployed to" section (one line per resolved placement path).
+///
+/// Existing paths are shown in green and missing ones in yellow with a `(missing)` mark.
+///
+/// # Arguments
+///
+/// * `deployments` - Resolved placement paths.
+/// * `color` - Whether to use ANSI colors.
+fn render_deployed_to(deployments: &[DeploymentEntry], color: bool) -> String {
+    if deployments.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    writeln!(out).unwrap();
+    writeln!(out, "Deployed to:").unwrap();
+    for entry in deployments {
+        let path = entry.path.display().to_string();
+        let path = match (entry.exists, color) {
+            (true, true) => path.green().to_string(),
+            (true, false) => path,
+            (false, true) => format!("{} (missing)", path).yellow().to_string(),
+            (false, false) => format!("{} (missing)", path),
+        };
+        writeln!(
+            out,
+            "  {} {} {}: {}",
+            entry.target.as_str(),
+            entry.kind.as_str(),
+            entry.name,
+            path
+        )
+        .unwrap();
+    }
+    out
+}
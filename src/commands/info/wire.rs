@@ -3,9 +3,11 @@
 //! `PluginInfo` を serde serialize 用の表現に変換する。
 //! JSON と YAML で同一構造を共有し、format 間の意図しないドリフトを防ぐ。
 
-use crate::application::{PluginInfo, Source};
-use crate::component::{Component, ComponentKind};
-use crate::plugin::Author;
+use super::JsonSchema;
+use crate::application::{ComponentDetail, DeploymentEntry, PluginInfo, Source};
+use crate::component::ComponentKind;
+use crate::plugin::{Author, DisabledComponent, UpdateRecord, UpdateResult};
+use crate::target::TargetKind;
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
 
@@ -18,11 +20,33 @@ pub(super) struct Wire<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) author: Option<WireAuthor<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) homepage: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) repository: Option<&'a str>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub(super) keywords: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) installed_at: Option<&'a str>,
+    /// 最後にインストール・更新した日時（RFC3339）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) last_updated_at: Option<&'a str>,
+    /// 直近のインストール・更新履歴（新しい順。無ければ省略）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) update_history: Vec<WireUpdateRecord<'a>>,
     pub(super) source: WireSource<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) channel: Option<&'static str>,
     pub(super) components: WireComponents<'a>,
     pub(super) enabled: bool,
+    /// 部分 disable（`plm disable --keep`）で配置を残している種別（部分 disable 中でなければ省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) kept_kinds: Option<&'a [ComponentKind]>,
+    /// コンポーネント単位で無効化しているコンポーネント（無ければ省略）
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub(super) disabled_components: &'a [DisabledComponent],
     pub(super) cache_path: String,
+    /// ターゲットごとの配置先（配置物が無いものは `exists: false`）
+    pub(super) deployments: Vec<WireDeployment<'a>>,
 }
 
 #[derive(Serialize)]
@@ -44,6 +68,48 @@ impl<'a> From<&'a Author> for WireAuthor<'a> {
     }
 }
 
+#[derive(Serialize)]
+pub(super) struct WireUpdateRecord<'a> {
+    pub(super) at: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) from_version: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) to_version: Option<&'a str>,
+    pub(super) result: UpdateResult,
+}
+
+impl<'a> From<&'a UpdateRecord> for WireUpdateRecord<'a> {
+    fn from(r: &'a UpdateRecord) -> Self {
+        Self {
+            at: &r.at,
+            from_version: r.from_version.as_deref(),
+            to_version: r.to_version.as_deref(),
+            result: r.result,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(super) struct WireDeployment<'a> {
+    pub(super) target: TargetKind,
+    pub(super) kind: ComponentKind,
+    pub(super) name: &'a str,
+    pub(super) path: String,
+    pub(super) exists: bool,
+}
+
+impl<'a> From<&'a DeploymentEntry> for WireDeployment<'a> {
+    fn from(d: &'a DeploymentEntry) -> Self {
+        Self {
+            target: d.target,
+            kind: d.kind,
+            name: &d.name,
+            path: d.path.to_string_lossy().into_owned(),
+            exists: d.exists,
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub(super) enum WireSource<'a> {
@@ -60,7 +126,46 @@ impl<'a> From<&'a Source> for WireSource<'a> {
     }
 }
 
-pub(super) struct WireComponents<'a>(pub(super) &'a [Component]);
+/// `components` フィールド
+///
+/// v1 は種別ごとの名前の配列、v2 は種別ごとの [`WireComponentDetail`] の配列。
+pub(super) struct WireComponents<'a> {
+    pub(super) info: &'a PluginInfo,
+    pub(super) schema: JsonSchema,
+}
+
+/// v2 の `components` の要素
+#[derive(Serialize)]
+pub(super) struct WireComponentDetail<'a> {
+    pub(super) name: &'a str,
+    /// キャッシュディレクトリからの相対パス
+    pub(super) path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) description: Option<&'a str>,
+    /// `plm disable --component` で無効化されているか
+    pub(super) disabled: bool,
+    /// 配置物が実在するターゲット
+    pub(super) deployed_to: Vec<TargetKind>,
+}
+
+impl<'a> WireComponentDetail<'a> {
+    fn new(info: &'a PluginInfo, detail: &'a ComponentDetail) -> Self {
+        Self {
+            name: &detail.name,
+            path: detail.path.to_string_lossy().into_owned(),
+            description: detail.description.as_deref(),
+            disabled: info
+                .installed
+                .is_component_disabled(detail.kind, &detail.name),
+            deployed_to: info
+                .deployments
+                .iter()
+                .filter(|d| d.exists && d.kind == detail.kind && d.name == detail.name)
+                .map(|d| d.target)
+                .collect(),
+        }
+    }
+}
 
 impl Serialize for WireComponents<'_> {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -75,30 +180,64 @@ impl Serialize for WireComponents<'_> {
             (ComponentKind::Instruction, "instructions"),
             (ComponentKind::Hook, "hooks"),
         ] {
-            let names: Vec<&str> = self
-                .0
-                .iter()
-                .filter(|c| c.kind == kind)
-                .map(|c| c.name.as_str())
-                .collect();
-            map.serialize_entry(key, &names)?;
+            match self.schema {
+                JsonSchema::V1 => {
+                    let names: Vec<&str> = self
+                        .info
+                        .installed
+                        .components()
+                        .iter()
+                        .filter(|c| c.kind == kind)
+                        .map(|c| c.name.as_str())
+                        .collect();
+                    map.serialize_entry(key, &names)?;
+                }
+                JsonSchema::V2 => {
+                    let details: Vec<WireComponentDetail> = self
+                        .info
+                        .component_details
+                        .iter()
+                        .filter(|d| d.kind == kind)
+                        .map(|d| WireComponentDetail::new(self.info, d))
+                        .collect();
+                    map.serialize_entry(key, &details)?;
+                }
+            }
         }
         map.end()
     }
 }
 
-impl<'a> From<&'a PluginInfo> for Wire<'a> {
-    fn from(info: &'a PluginInfo) -> Self {
+impl<'a> Wire<'a> {
+    /// # Arguments
+    ///
+    /// * `info` - Plugin information to serialize.
+    /// * `schema` - Shape of the `components` field.
+    pub(super) fn new(info: &'a PluginInfo, schema: JsonSchema) -> Self {
         Self {
             name: info.installed.name(),
             version: info.installed.version(),
             description: info.installed.description(),
             author: info.installed.author().map(WireAuthor::from),
+            homepage: info.installed.homepage(),
+            repository: info.installed.repository(),
+            keywords: info.installed.keywords(),
             installed_at: info.installed_at.as_deref(),
+            last_updated_at: info.installed.last_updated(),
+            update_history: info
+                .installed
+                .update_history()
+                .iter()
+                .map(WireUpdateRecord::from)
+                .collect(),
             source: WireSource::from(&info.source),
-            components: WireComponents(info.installed.components()),
+            channel: info.channel.map(|c| c.as_str()),
+            components: WireComponents { info, schema },
             enabled: info.installed.enabled(),
+            kept_kinds: info.installed.kept_kinds(),
+            disabled_components: info.installed.disabled_components(),
             cache_path: info.installed.cache_path().to_string_lossy().into_owned(),
+            deployments: info.deployments.iter().map(WireDeployment::from).collect(),
         }
     }
 }
@@ -1,6 +1,7 @@
 //! YAML 出力フォーマット
 
 use super::wire::Wire;
+use super::JsonSchema;
 use crate::application::PluginInfo;
 
 /// Render `PluginInfo` as a YAML string.
@@ -8,8 +9,9 @@ use crate::application::PluginInfo;
 /// # Arguments
 ///
 /// * `info` - Plugin information to serialize.
-pub(super) fn render_yaml(info: &PluginInfo) -> Result<String, String> {
-    let wire = Wire::from(info);
+/// * `schema` - Shape of the `components` field.
+pub(super) fn render_yaml(info: &PluginInfo, schema: JsonSchema) -> Result<String, String> {
+    let wire = Wire::new(info, schema);
     serde_yaml::to_string(&wire).map_err(|e| format!("Failed to serialize to YAML: {}", e))
 }
 
@@ -18,8 +20,9 @@ pub(super) fn render_yaml(info: &PluginInfo) -> Result<String, String> {
 /// # Arguments
 ///
 /// * `info` - Plugin information to print.
-pub(super) fn print_yaml(info: &PluginInfo) -> Result<(), String> {
-    let s = render_yaml(info)?;
+/// * `schema` - Shape of the `components` field.
+pub(super) fn print_yaml(info: &PluginInfo, schema: JsonSchema) -> Result<(), String> {
+    let s = render_yaml(info, schema)?;
     print!("{s}");
     Ok(())
 }
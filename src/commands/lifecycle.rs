@@ -1,8 +1,10 @@
 //! plm のライフサイクル系コマンド集約モジュール。
 //!
-//! `enable` / `disable` / `uninstall` / `update` を束ねる。
+//! `enable` / `disable` / `uninstall` / `update` / `pin` / `unpin` を束ねる。
 
 pub mod disable;
 pub mod enable;
+pub mod pin;
 pub mod uninstall;
+pub mod unpin;
 pub mod update;
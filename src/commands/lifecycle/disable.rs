@@ -1,13 +1,24 @@
 //! plm disable コマンド
 //!
 //! プラグインを無効化する。ターゲット環境からコンポーネントを削除し（キャッシュは残す）、
-//! `.plm-meta.json` の `statusByTarget` を更新する。
+//! `.plm-meta.json` の `statusByTarget` を更新する。`--keep` 指定時は指定した種別を残す
+//! 部分 disable として `partial` と残した種別（`keptKinds`）を記録する。
+//! `--component` 指定時は 1 コンポーネントだけを全ターゲットから削除し、`disabledComponents`
+//! に記録する（プラグインのステータスは変えない）。
 
-use crate::application::{disable_plugin, OperationOutcome};
-use crate::commands::args::MarketplaceArgs;
+use super::uninstall::remaining_file_lines;
+use crate::application::{
+    backup_paths, disable_component, disable_plugin_filtered, remaining_deployed_files,
+    ComponentSelector, DeployFilter, OperationOutcome,
+};
+use crate::backup::BackupOperation;
+use crate::commands::args::{BackupArgs, ComponentArgs, MarketplaceArgs, StdinArgs, StdinBatch};
+use crate::commands::did_you_mean;
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::component::ComponentKind;
 use crate::plugin::{meta, meta::TargetStatus, PackageCache, PackageCacheAccess};
 use clap::{Parser, ValueEnum};
-use std::env;
 
 #[derive(Debug, Clone, ValueEnum)]
 pub enum TargetKind {
@@ -27,47 +38,146 @@ impl TargetKind {
 #[derive(Debug, Parser)]
 pub struct Args {
     /// Plugin name (e.g., "owner--repo" or "plugin-name")
-    pub name: String,
+    #[arg(required_unless_present = "stdin")]
+    pub name: Option<String>,
+
+    #[command(flatten)]
+    pub stdin: StdinArgs,
 
     /// Target environment to disable (codex or copilot)
     #[arg(long, value_enum)]
     pub target: Option<TargetKind>,
 
+    /// Keep these component kinds deployed and remove the rest (comma-separated, e.g. instructions,skills)
+    #[arg(long, value_enum, value_delimiter = ',', conflicts_with = "component")]
+    pub keep: Option<Vec<ComponentKind>>,
+
+    #[command(flatten)]
+    pub component: ComponentArgs,
+
     #[command(flatten)]
     pub marketplace: MarketplaceArgs,
+
+    #[command(flatten)]
+    pub backup: BackupArgs,
+}
+
+/// `plm disable` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm disable formatter",
+        description: "Remove a plugin's components from all targets (cache is kept)",
+    },
+    Example {
+        command: "plm disable formatter --target copilot",
+        description: "Remove only from Copilot",
+    },
+    Example {
+        command: "plm disable formatter --keep instructions",
+        description: "Remove everything except instructions (restore with plm enable)",
+    },
+    Example {
+        command: "plm disable formatter --component skill:code-review",
+        description: "Remove a single component and keep it out of later enables",
+    },
+    Example {
+        command: "plm disable formatter -m company-tools",
+        description: "Disable the plugin installed from a marketplace",
+    },
+    Example {
+        command: "plm disable --stdin",
+        description: "Disable the plugins named on stdin (e.g. piped from plm list --json | jq)",
+    },
+    Example {
+        command: "plm disable formatter --backup",
+        description: "Save the removed files first (see plm backups list)",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm disable`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    if args.stdin.stdin {
+        let mut batch = StdinBatch::default();
+        for spec in args.stdin.read_plugins()? {
+            let marketplace = spec
+                .marketplace
+                .as_deref()
+                .unwrap_or(args.marketplace.marketplace_or_default());
+            batch.record(&spec, disable_one(&args, &spec.name, marketplace, ctx));
+        }
+        return batch.finish();
+    }
+    let name = args.name.as_deref().unwrap_or_default();
+    disable_one(&args, name, args.marketplace.marketplace_or_default(), ctx)
 }
 
+/// 1 プラグインを無効化する
+///
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm disable`.
-pub async fn run(args: Args) -> Result<(), String> {
+/// * `requested` - Plugin name as given on the command line or stdin.
+/// * `marketplace` - Marketplace the plugin was installed from.
+/// * `ctx` - Shared command context (resolved project root).
+fn disable_one(
+    args: &Args,
+    requested: &str,
+    marketplace: &str,
+    ctx: &Context,
+) -> Result<(), String> {
     let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
-    let marketplace = args.marketplace.marketplace_or_default();
 
     // Cache is required to identify components to remove from the manifest.
-    if !cache.is_cached(Some(marketplace), &args.name) {
-        return Err(format!(
-            "Error: Plugin '{}' not found in cache (marketplace: {})\nHint: Cache is required to identify components to remove.",
-            args.name, marketplace
-        ));
-    }
+    let name = if cache.is_cached(Some(marketplace), requested) {
+        requested.to_string()
+    } else {
+        did_you_mean::resolve_missing(
+            &cache,
+            requested,
+            |n| cache.is_cached(Some(marketplace), n),
+            format!(
+                "Error: Plugin '{}' not found in cache (marketplace: {})\nHint: Cache is required to identify components to remove.",
+                requested, marketplace
+            ),
+           !args.stdin.stdin,
+        )?
+    };
 
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
+    let project_root = ctx.project_root.clone();
     let target_filter = args.target.as_ref().map(|t| t.as_str());
 
-    let result = disable_plugin(
-        &cache,
-        &args.name,
-        Some(marketplace),
-        &project_root,
-        target_filter,
-    );
+    let keep = args.keep.as_deref();
+    let removed_kinds = keep.map(removed_kinds);
+    let filter = DeployFilter {
+        target: target_filter,
+        kinds: removed_kinds.as_deref(),
+    };
+    let plugin_path = cache.plugin_path(Some(marketplace), &name);
+    args.backup.snapshot(
+        BackupOperation::Disable,
+        &format!("plm disable {}", name),
+        || backup_paths(&plugin_path, target_filter, false),
+    )?;
+    if let Some(selector) = &args.component.component {
+        let targets = disable_component(&cache, &name, Some(marketplace), &project_root, selector)
+            .map_err(|e| {
+                format!(
+                    "Error: Failed to disable component '{}' of plugin '{}': {}",
+                    selector, name, e
+                )
+            })?;
+        display_component_result(&name, selector, &targets);
+        return Ok(());
+    }
+    let result = disable_plugin_filtered(&cache, &name, Some(marketplace), &project_root, filter);
 
-    let plugin_path = cache.plugin_path(Some(marketplace), &args.name);
-    update_status_after_disable(&plugin_path, &result);
+    update_status_after_disable(&plugin_path, &result, keep);
 
     if result.success {
-        display_result(&args.name, &result, target_filter);
+        display_result(&name, &result, target_filter, keep);
         Ok(())
     } else {
         let successful_targets = result.affected_targets.target_names();
@@ -77,29 +187,53 @@ pub async fn run(args: Args) -> Result<(), String> {
                 successful_targets.len()
             );
         }
+        for line in remaining_file_lines(&remaining_deployed_files(&plugin_path)) {
+            println!("{}", line);
+        }
         if let Some(error) = &result.error {
             Err(format!(
                 "Error: Failed to disable plugin '{}': {}",
-                args.name, error
+                name, error
             ))
         } else {
-            Err(format!("Error: Failed to disable plugin '{}'", args.name))
+            Err(format!("Error: Failed to disable plugin '{}'", name))
         }
     }
 }
 
+/// `--keep` で残す種別から、削除する種別を求める
+///
+/// # Arguments
+///
+/// * `keep` - Component kinds given with `--keep`.
+fn removed_kinds(keep: &[ComponentKind]) -> Vec<ComponentKind> {
+    ComponentKind::all()
+        .iter()
+        .copied()
+        .filter(|k| !keep.contains(k))
+        .collect()
+}
+
 /// disable 後のステータス更新
 ///
 /// # Arguments
 ///
 /// * `plugin_path` - Filesystem path of the cached plugin.
-/// * `result` - Outcome returned by `disable_plugin`.
-fn update_status_after_disable(plugin_path: &std::path::Path, result: &OperationOutcome) {
+/// * `result` - Outcome returned by `disable_plugin_filtered`.
+/// * `keep` - Component kinds left deployed (`--keep`), if any.
+fn update_status_after_disable(
+    plugin_path: &std::path::Path,
+    result: &OperationOutcome,
+    keep: Option<&[ComponentKind]>,
+) {
     let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
 
     let target_names = result.affected_targets.target_names();
     for target_name in target_names {
-        plugin_meta.set_status(target_name, TargetStatus::Disabled);
+        match keep {
+            Some(kinds) => plugin_meta.set_partial(target_name, kinds),
+            None => plugin_meta.set_status(target_name, TargetStatus::Disabled),
+        }
     }
 
     // When a filter is given and the target is unsupported, it is not present
@@ -115,9 +249,15 @@ fn update_status_after_disable(plugin_path: &std::path::Path, result: &Operation
 /// # Arguments
 ///
 /// * `plugin_name` - Plugin identifier shown in the output.
-/// * `result` - Outcome returned by `disable_plugin`.
+/// * `result` - Outcome returned by `disable_plugin_filtered`.
 /// * `target_filter` - Optional target name filter that was requested.
-fn display_result(plugin_name: &str, result: &OperationOutcome, target_filter: Option<&str>) {
+/// * `keep` - Component kinds left deployed (`--keep`), if any.
+fn display_result(
+    plugin_name: &str,
+    result: &OperationOutcome,
+    target_filter: Option<&str>,
+    keep: Option<&[ComponentKind]>,
+) {
     let targets = result.affected_targets.target_names();
     if targets.is_empty() {
         if let Some(filter) = target_filter {
@@ -131,13 +271,51 @@ fn display_result(plugin_name: &str, result: &OperationOutcome, target_filter: O
     } else {
         let target_list = targets.join(", ");
         let component_count = result.affected_targets.total_components();
+        let label = match keep {
+            Some(_) => "Partially disabled",
+            None => "Disabled",
+        };
+        println!(
+            "{}: Plugin '{}' ({} component(s) removed from {})",
+            label, plugin_name, component_count, target_list
+        );
+    }
+    if let Some(kinds) = keep {
+        let kinds: Vec<_> = kinds.iter().map(|k| k.plural()).collect();
         println!(
-            "Disabled: Plugin '{}' ({} component(s) removed from {})",
-            plugin_name, component_count, target_list
+            "  Kept: {} (run 'plm enable {}' to restore the rest)",
+            kinds.join(", "),
+            plugin_name
         );
     }
 }
 
+/// `--component` 指定時の結果を表示
+///
+/// # Arguments
+///
+/// * `plugin_name` - Plugin identifier shown in the output.
+/// * `selector` - Component that was disabled.
+/// * `targets` - Targets the component was removed from.
+fn display_component_result(plugin_name: &str, selector: &ComponentSelector, targets: &[String]) {
+    let place = if targets.is_empty() {
+        "not deployed".to_string()
+    } else {
+        format!("removed from {}", targets.join(", "))
+    };
+    println!(
+        "Disabled: {} '{}' of plugin '{}' ({})",
+        selector.kind.as_str(),
+        selector.name,
+        plugin_name,
+        place
+    );
+    println!(
+        "  Run 'plm enable {} --component {}' to restore it",
+        plugin_name, selector
+    );
+}
+
 #[cfg(test)]
 #[path = "disable_test.rs"]
 mod tests;
@@ -39,3 +39,74 @@ fn test_disable_operation_failure_shows_error_once() {
         .failure()
         .stderr(predicate::str::contains("Failed to disable plugin").count(1));
 }
+
+/// PID 1（常に存在する）が `plm update` で保持しているロックファイルを置く
+fn hold_lock(home: &TempDir, plugin: &str) -> std::path::PathBuf {
+    let cache_dir = home.path().join(".plm/cache/plugins/github").join(plugin);
+    fs::create_dir_all(&cache_dir).unwrap();
+    let lock = cache_dir.with_file_name(format!(".{}.lock", plugin));
+    fs::write(&lock, "1\nplm update").unwrap();
+    lock
+}
+
+#[test]
+fn test_disable_wait_lock_times_out_while_lock_is_held() {
+    let home = TempDir::new().unwrap();
+    hold_lock(&home, "busy-plugin");
+
+    plm()
+        .env_remove("PLM_HOME")
+        .env_remove("PLM_LOCK_WAIT")
+        .env_remove("PLM_WAIT_LOCK")
+        .env("HOME", home.path())
+        .args(["disable", "busy-plugin", "--wait-lock", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Waiting for lock held by PID 1 (plm update)... 0s",
+        ))
+        .stderr(predicate::str::contains("timed out after 1s"));
+}
+
+#[test]
+fn test_disable_wait_lock_proceeds_after_other_process_releases() {
+    let home = TempDir::new().unwrap();
+    let lock = hold_lock(&home, "busy-plugin");
+    let releaser = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        fs::remove_file(lock).unwrap();
+    });
+
+    // ロック取得後はマニフェストが無いため disable 自体は失敗する
+    plm()
+        .env_remove("PLM_HOME")
+        .env_remove("PLM_LOCK_WAIT")
+        .env("PLM_WAIT_LOCK", "10")
+        .env("HOME", home.path())
+        .args(["disable", "busy-plugin"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Waiting for lock held by PID 1"))
+        .stderr(predicate::str::contains("Failed to disable plugin"))
+        .stderr(predicate::str::contains("timed out").not());
+    releaser.join().unwrap();
+}
+
+#[test]
+fn test_disable_stdin_reports_suggestion_without_prompting() {
+    let home = TempDir::new().unwrap();
+    fs::create_dir_all(home.path().join(".plm/cache/plugins/github/my-plugin")).unwrap();
+
+    // 標準入力はプラグイン一覧のため、近い名前があっても確認せず失敗理由に候補を添える
+    plm()
+        .env_remove("PLM_HOME")
+        .env("HOME", home.path())
+        .args(["disable", "--stdin"])
+        .write_stdin("my-plugn\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "my-plugn: failed (Plugin 'my-plugn' not found in cache (marketplace: github); did you mean: my-plugin?)",
+        ))
+        .stderr(predicate::str::contains("instead?").not());
+}
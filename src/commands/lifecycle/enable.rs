@@ -2,12 +2,23 @@
 //!
 //! プラグインを有効化する。キャッシュからターゲット環境にコンポーネントをデプロイし、
 //! `.plm-meta.json` の `statusByTarget` を更新する。
+//! `--prefix` を指定した場合は、配置済みのコマンドを新しい名前で配置し直す。
+//! `plm disable --component` で無効化したコンポーネントは配置しない（`--all` で解除して配置、
+//! `--component` で 1 件ずつ解除）。
+//! 別プラグインが配置した同じ配置先のコンポーネントは警告してスキップする（`--overwrite` で上書き）。
 
-use crate::application::{enable_plugin, OperationOutcome};
-use crate::commands::args::MarketplaceArgs;
+use crate::application::{
+    enable_component, enable_plugin_checked, ComponentSelector, DeployFilter, OperationOutcome,
+};
+use crate::commands::args::{
+    CommandPrefixArgs, ComponentArgs, MarketplaceArgs, StdinArgs, StdinBatch,
+};
+use crate::commands::did_you_mean;
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::output::hint::print_post_deploy_hints;
 use crate::plugin::{meta, meta::TargetStatus, PackageCache, PackageCacheAccess};
 use clap::{Parser, ValueEnum};
-use std::env;
 
 #[derive(Debug, Clone, ValueEnum)]
 pub enum TargetKind {
@@ -27,46 +38,163 @@ impl TargetKind {
 #[derive(Debug, Parser)]
 pub struct Args {
     /// Plugin name (e.g., "owner--repo" or "plugin-name")
-    pub name: String,
+    #[arg(required_unless_present = "stdin")]
+    pub name: Option<String>,
+
+    #[command(flatten)]
+    pub stdin: StdinArgs,
 
     /// Target environment to enable (codex or copilot)
     #[arg(long, value_enum)]
     pub target: Option<TargetKind>,
 
+    #[command(flatten)]
+    pub component: ComponentArgs,
+
+    /// Also enable components disabled with `plm disable --component`
+    #[arg(long, conflicts_with = "component")]
+    pub all: bool,
+
+    /// Replace components at the same destination deployed by another plugin
+    #[arg(long)]
+    pub overwrite: bool,
+
     #[command(flatten)]
     pub marketplace: MarketplaceArgs,
+
+    #[command(flatten)]
+    pub prefix: CommandPrefixArgs,
 }
 
+/// `plm enable` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm enable formatter",
+        description: "Deploy a cached plugin to all targets",
+    },
+    Example {
+        command: "plm enable formatter --target codex",
+        description: "Deploy only to Codex",
+    },
+    Example {
+        command: "plm enable formatter -m company-tools",
+        description: "Enable the plugin installed from a marketplace",
+    },
+    Example {
+        command: "plm enable formatter --component skill:code-review",
+        description: "Re-enable a single component disabled with --component",
+    },
+    Example {
+        command: "plm enable formatter --all",
+        description: "Enable the plugin including every disabled component",
+    },
+    Example {
+        command: "plm enable formatter --overwrite",
+        description: "Replace same-named components deployed by another plugin",
+    },
+    Example {
+        command: "plm enable --stdin",
+        description: "Enable the plugins named on stdin, one per line (marketplace/name allowed)",
+    },
+    Example {
+        command: "plm enable formatter --prefix fmt:",
+        description: "Redeploy the plugin's commands with a name prefix",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm enable`.
-pub async fn run(args: Args) -> Result<(), String> {
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    if args.stdin.stdin {
+        let mut batch = StdinBatch::default();
+        for spec in args.stdin.read_plugins()? {
+            let marketplace = spec
+                .marketplace
+                .as_deref()
+                .unwrap_or(args.marketplace.marketplace_or_default());
+            batch.record(&spec, enable_one(&args, &spec.name, marketplace, ctx));
+        }
+        return batch.finish();
+    }
+    let name = args.name.as_deref().unwrap_or_default();
+    enable_one(&args, name, args.marketplace.marketplace_or_default(), ctx)
+}
+
+/// 1 プラグインを有効化する
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm enable`.
+/// * `requested` - Plugin name as given on the command line or stdin.
+/// * `marketplace` - Marketplace the plugin was installed from.
+/// * `ctx` - Shared command context (resolved project root).
+fn enable_one(
+    args: &Args,
+    requested: &str,
+    marketplace: &str,
+    ctx: &Context,
+) -> Result<(), String> {
     let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
-    let marketplace = args.marketplace.marketplace_or_default();
 
-    if !cache.is_cached(Some(marketplace), &args.name) {
-        return Err(format!(
-            "Error: Plugin '{}' not found in cache (marketplace: {})",
-            args.name, marketplace
-        ));
-    }
+    let name = if cache.is_cached(Some(marketplace), requested) {
+        requested.to_string()
+    } else {
+        did_you_mean::resolve_missing(
+            &cache,
+            requested,
+            |n| cache.is_cached(Some(marketplace), n),
+            format!(
+                "Error: Plugin '{}' not found in cache (marketplace: {})",
+                requested, marketplace
+            ),
+            !args.stdin.stdin,
+        )?
+    };
 
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
+    let project_root = ctx.project_root.clone();
     let target_filter = args.target.as_ref().map(|t| t.as_str());
+    let plugin_path = cache.plugin_path(Some(marketplace), &name);
+    args.prefix.apply_to(&plugin_path)?;
 
-    let result = enable_plugin(
+    if let Some(selector) = &args.component.component {
+        let targets = enable_component(&cache, &name, Some(marketplace), &project_root, selector)
+            .map_err(|e| {
+            format!(
+                "Error: Failed to enable component '{}' of plugin '{}': {}",
+                selector, name, e
+            )
+        })?;
+        display_component_result(&name, selector, &targets);
+        return Ok(());
+    }
+    if args.all {
+        clear_disabled_components(&plugin_path)?;
+    }
+
+    let filter = DeployFilter {
+        target: target_filter,
+        kinds: None,
+    };
+    let checked = enable_plugin_checked(
         &cache,
-        &args.name,
+        &name,
         Some(marketplace),
         &project_root,
-        target_filter,
+        filter,
+        args.overwrite,
     );
+    let result = checked.outcome;
 
-    let plugin_path = cache.plugin_path(Some(marketplace), &args.name);
     update_status_after_enable(&plugin_path, &result);
+    for conflict in &checked.conflicts {
+        eprintln!("  Warning: {}", conflict.message());
+    }
 
     if result.success {
-        display_result(&args.name, &result, target_filter);
+        display_result(&name, &result, target_filter);
+        display_skipped_components(&plugin_path, &name);
         Ok(())
     } else {
         let successful_targets = result.affected_targets.target_names();
@@ -79,10 +207,10 @@ pub async fn run(args: Args) -> Result<(), String> {
         if let Some(error) = &result.error {
             Err(format!(
                 "Error: Failed to enable plugin '{}': {}",
-                args.name, error
+                name, error
             ))
         } else {
-            Err(format!("Error: Failed to enable plugin '{}'", args.name))
+            Err(format!("Error: Failed to enable plugin '{}'", name))
         }
     }
 }
@@ -134,6 +262,71 @@ fn display_result(plugin_name: &str, result: &OperationOutcome, target_filter: O
             "Enabled: Plugin '{}' ({} component(s) deployed to {})",
             plugin_name, component_count, target_list
         );
+        print_post_deploy_hints(&targets);
+    }
+}
+
+/// コンポーネント単位の無効化をすべて解除する（`--all`）
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+fn clear_disabled_components(plugin_path: &std::path::Path) -> Result<(), String> {
+    let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    if plugin_meta.disabled_components.is_empty() {
+        return Ok(());
+    }
+    plugin_meta.disabled_components.clear();
+    meta::write_meta(plugin_path, &plugin_meta)
+        .map_err(|e| format!("Failed to update .plm-meta.json: {}", e))
+}
+
+/// 無効化したまま配置しなかったコンポーネントを表示
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+/// * `plugin_name` - Plugin identifier shown in the output.
+fn display_skipped_components(plugin_path: &std::path::Path, plugin_name: &str) {
+    let plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    if plugin_meta.disabled_components.is_empty() {
+        return;
+    }
+    let names: Vec<String> = plugin_meta
+        .disabled_components
+        .iter()
+        .map(|c| format!("{}:{}", c.kind.as_str(), c.name))
+        .collect();
+    println!(
+        "  Skipped disabled component(s): {} (run 'plm enable {} --all' to enable them)",
+        names.join(", "),
+        plugin_name
+    );
+}
+
+/// `--component` 指定時の結果を表示
+///
+/// # Arguments
+///
+/// * `plugin_name` - Plugin identifier shown in the output.
+/// * `selector` - Component that was enabled.
+/// * `targets` - Targets the component was deployed to.
+fn display_component_result(plugin_name: &str, selector: &ComponentSelector, targets: &[String]) {
+    if targets.is_empty() {
+        println!(
+            "Enabled: {} '{}' of plugin '{}' (deployed the next time the plugin is enabled)",
+            selector.kind.as_str(),
+            selector.name,
+            plugin_name
+        );
+    } else {
+        println!(
+            "Enabled: {} '{}' of plugin '{}' (deployed to {})",
+            selector.kind.as_str(),
+            selector.name,
+            plugin_name,
+            targets.join(", ")
+        );
     }
 }
 
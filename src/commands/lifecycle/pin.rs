@@ -0,0 +1,49 @@
+//! plm pin コマンド
+//!
+//! プラグインを現在のバージョンで固定する。固定中は `plm update` と TUI の更新でスキップされる。
+
+use crate::application::{pin_plugin, PinOutcome};
+use crate::commands::args::MarketplaceArgs;
+use crate::commands::examples::Example;
+use crate::plugin::PackageCache;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Plugin name (e.g., "owner--repo" or "plugin-name")
+    pub name: String,
+
+    #[command(flatten)]
+    pub marketplace: MarketplaceArgs,
+}
+
+/// `plm pin` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm pin formatter",
+        description: "Keep a plugin at its current version",
+    },
+    Example {
+        command: "plm pin formatter -m company-tools",
+        description: "Pin the plugin installed from a marketplace",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm pin`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let marketplace = args.marketplace.marketplace_or_default();
+
+    match pin_plugin(&cache, &args.name, marketplace)? {
+        PinOutcome::Pinned { version } => {
+            println!("Pinned: {} at {}", args.name, version);
+        }
+        PinOutcome::AlreadyPinned { version } => match version {
+            Some(version) => println!("{} is already pinned at {}", args.name, version),
+            None => println!("{} is already pinned", args.name),
+        },
+    }
+    Ok(())
+}
@@ -1,51 +1,165 @@
-use crate::application::{self, UninstallInfo};
-use crate::commands::args::MarketplaceArgs;
-use crate::plugin::PackageCache;
+use crate::application::{self, RemainingFile, UninstallInfo};
+use crate::backup::BackupOperation;
+use crate::commands::args::{BackupArgs, MarketplaceArgs, StdinArgs, StdinBatch};
+use crate::commands::did_you_mean;
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::plugin::{PackageCache, PackageCacheAccess};
 use clap::Parser;
 use owo_colors::OwoColorize;
-use std::env;
 use std::io::{self, Write};
 
 #[derive(Debug, Parser)]
 pub struct Args {
     /// プラグイン名（キャッシュディレクトリ名、例: "DIO0550--cc-plugin"）
-    pub name: String,
+    #[arg(required_unless_present = "stdin")]
+    pub name: Option<String>,
+
+    #[command(flatten)]
+    pub stdin: StdinArgs,
 
     #[command(flatten)]
     pub marketplace: MarketplaceArgs,
 
-    /// 確認プロンプトをスキップ
+    /// 確認プロンプトをスキップ（他のプラグインが依存していてもアンインストールする）
     #[arg(long, short = 'f')]
     pub force: bool,
+
+    /// sandbox（`plm install --sandbox` の隔離デプロイ）だけを削除する
+    #[arg(long)]
+    pub sandbox: bool,
+
+    #[command(flatten)]
+    pub backup: BackupArgs,
 }
 
+/// `plm uninstall` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm uninstall formatter",
+        description: "Remove a plugin and its cache after confirmation",
+    },
+    Example {
+        command: "plm uninstall formatter -m company-tools",
+        description: "Remove the plugin installed from a marketplace",
+    },
+    Example {
+        command: "plm uninstall formatter --force",
+        description: "Remove without asking for confirmation",
+    },
+    Example {
+        command: "plm uninstall --stdin --force",
+        description: "Uninstall the plugins named on stdin (--force is required)",
+    },
+    Example {
+        command: "plm uninstall formatter -m company-tools --sandbox",
+        description: "Remove only the sandbox created by install --sandbox",
+    },
+    Example {
+        command: "plm uninstall formatter --backup",
+        description: "Save the deployed files and cache first (see plm backups list)",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm uninstall`.
-pub async fn run(args: Args) -> Result<(), String> {
-    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
-    let project_root =
-        env::current_dir().map_err(|e| format!("Failed to get current dir: {}", e))?;
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
     // デフォルト解決（未指定 = github）は CLI 境界で 1 回だけ行う（enable/disable と同じ経路）
     let marketplace = args.marketplace.marketplace_or_default();
+    if args.stdin.stdin {
+        // 標準入力はプラグイン名の一覧に使うため、確認プロンプトは出せない
+        if !args.force {
+            return Err(
+                "--stdin requires --force (stdin holds the plugin list, so uninstall cannot ask for confirmation)"
+                    .to_string(),
+            );
+        }
+        let mut batch = StdinBatch::default();
+        for spec in args.stdin.read_plugins()? {
+            let marketplace = spec.marketplace.as_deref().unwrap_or(marketplace);
+            batch.record(&spec, uninstall_one(&args, &spec.name, marketplace, ctx));
+        }
+        return batch.finish();
+    }
+    let name = args.name.as_deref().unwrap_or_default();
+    uninstall_one(&args, name, marketplace, ctx)
+}
+
+/// 1 プラグインをアンインストールする
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm uninstall`.
+/// * `requested` - Plugin name as given on the command line or stdin.
+/// * `marketplace` - Marketplace the plugin was installed from.
+/// * `ctx` - Shared command context (resolved project root).
+fn uninstall_one(
+    args: &Args,
+    requested: &str,
+    marketplace: &str,
+    ctx: &Context,
+) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let project_root = ctx.project_root.clone();
+    let name = if cache.is_cached(Some(marketplace), requested) {
+        requested.to_string()
+    } else {
+        did_you_mean::resolve_missing(
+            &cache,
+            requested,
+            |n| cache.is_cached(Some(marketplace), n),
+            format!(
+                "Plugin '{}' not found in cache (marketplace: {})",
+                requested, marketplace
+            ),
+            !args.stdin.stdin,
+        )?
+    };
+
+    if args.sandbox {
+        return uninstall_sandbox(&cache, &name, marketplace);
+    }
+
+    let dependents = application::find_dependents(&cache, &name, marketplace);
+    if !dependents.is_empty() {
+        if !args.force {
+            return Err(format!(
+                "'{}' is required by {}; uninstall them first or use --force",
+                name,
+                dependents.join(", ")
+            ));
+        }
+        eprintln!(
+            "{} '{}' is required by {}; they may stop working",
+            "Warning:".yellow(),
+            name,
+            dependents.join(", ")
+        );
+    }
 
-    let info = application::get_uninstall_info(&cache, &args.name, marketplace)?;
+    let info = application::get_uninstall_info(&cache, &name, marketplace)?;
 
     display_uninstall_info(&info);
 
-    if !args.force && !confirm_uninstall(&args.name)? {
+    if !args.force && !confirm_uninstall(&name)? {
         println!("Uninstall cancelled.");
         return Ok(());
     }
 
-    let result =
-        application::uninstall_plugin(&cache, &args.name, Some(marketplace), &project_root);
+    args.backup.snapshot(
+        BackupOperation::Uninstall,
+        &format!("plm uninstall {}", name),
+        || application::backup_paths(&cache.plugin_path(Some(marketplace), &name), None, true),
+    )?;
+    let result = application::uninstall_plugin(&cache, &name, Some(marketplace), &project_root);
 
     if result.success {
         println!(
             "{} Plugin '{}' uninstalled successfully.",
             "✓".green(),
-            args.name
+            name
         );
         let target_names = result.affected_targets.target_names();
         if !target_names.is_empty() {
@@ -57,6 +171,11 @@ pub async fn run(args: Args) -> Result<(), String> {
         }
         Ok(())
     } else {
+        let remaining =
+            application::remaining_deployed_files(&cache.plugin_path(Some(marketplace), &name));
+        for line in remaining_file_lines(&remaining) {
+            println!("{}", line);
+        }
         Err(format!(
             "Failed to uninstall: {}",
             result.error.unwrap_or_else(|| "Unknown error".to_string())
@@ -64,6 +183,39 @@ pub async fn run(args: Args) -> Result<(), String> {
     }
 }
 
+/// sandbox だけを削除する（キャッシュと正式なデプロイは残す）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `plugin_name` - Plugin id (cache directory name).
+/// * `marketplace` - Marketplace name.
+fn uninstall_sandbox(
+    cache: &PackageCache,
+    plugin_name: &str,
+    marketplace: &str,
+) -> Result<(), String> {
+    if !cache.is_cached(Some(marketplace), plugin_name) {
+        return Err(format!(
+            "Plugin '{}' not found in cache (marketplace: {})",
+            plugin_name, marketplace
+        ));
+    }
+
+    match application::remove_sandbox(&cache.plugin_path(Some(marketplace), plugin_name))? {
+        Some(path) => {
+            println!(
+                "{} Removed sandbox of '{}': {}",
+                "✓".green(),
+                plugin_name,
+                path.display()
+            );
+            Ok(())
+        }
+        None => Err(format!("Plugin '{}' has no sandbox", plugin_name)),
+    }
+}
+
 /// 削除対象の情報を表示
 ///
 /// # Arguments
@@ -93,6 +245,29 @@ fn display_uninstall_info(info: &UninstallInfo) {
     println!();
 }
 
+/// 削除しきれずデプロイ先に残っている配置物の表示行
+///
+/// 残っていなければ空を返す。disable の途中失敗の表示でも使う。
+///
+/// # Arguments
+///
+/// * `remaining` - Files still deployed, as returned by `remaining_deployed_files`.
+pub(super) fn remaining_file_lines(remaining: &[RemainingFile]) -> Vec<String> {
+    if remaining.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![format!("  Still deployed ({}):", remaining.len())];
+    lines.extend(remaining.iter().map(|file| {
+        format!(
+            "    - {}: {} ({})",
+            file.target,
+            file.path.display(),
+            file.kind
+        )
+    }));
+    lines
+}
+
 /// ユーザーに削除確認を求める
 ///
 /// # Arguments
@@ -4,7 +4,7 @@ use clap::Parser;
 #[test]
 fn test_args_parsing_defaults() {
     let args = Args::parse_from(["uninstall", "my-plugin"]);
-    assert_eq!(args.name, "my-plugin");
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
     assert_eq!(args.marketplace.marketplace, None);
     assert!(!args.force);
 }
@@ -12,7 +12,7 @@ fn test_args_parsing_defaults() {
 #[test]
 fn test_args_parsing_with_marketplace() {
     let args = Args::parse_from(["uninstall", "my-plugin", "--marketplace", "custom"]);
-    assert_eq!(args.name, "my-plugin");
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
     assert_eq!(args.marketplace.marketplace, Some("custom".to_string()));
     assert!(!args.force);
 }
@@ -20,18 +20,26 @@ fn test_args_parsing_with_marketplace() {
 #[test]
 fn test_args_parsing_with_force() {
     let args = Args::parse_from(["uninstall", "my-plugin", "--force"]);
-    assert_eq!(args.name, "my-plugin");
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
     assert!(args.force);
 }
 
 #[test]
 fn test_args_parsing_with_short_options() {
     let args = Args::parse_from(["uninstall", "my-plugin", "-m", "custom", "-f"]);
-    assert_eq!(args.name, "my-plugin");
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
     assert_eq!(args.marketplace.marketplace, Some("custom".to_string()));
     assert!(args.force);
 }
 
+#[test]
+fn test_args_parsing_with_sandbox() {
+    let args = Args::parse_from(["uninstall", "my-plugin", "--sandbox"]);
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
+    assert!(args.sandbox);
+    assert!(!args.force);
+}
+
 #[test]
 fn test_args_parsing_with_all_options() {
     let args = Args::parse_from([
@@ -41,7 +49,47 @@ fn test_args_parsing_with_all_options() {
         "custom",
         "--force",
     ]);
-    assert_eq!(args.name, "my-plugin");
+    assert_eq!(args.name.as_deref(), Some("my-plugin"));
     assert_eq!(args.marketplace.marketplace, Some("custom".to_string()));
     assert!(args.force);
 }
+
+#[test]
+fn test_args_parsing_with_stdin() {
+    let args = Args::parse_from(["uninstall", "--stdin", "--force"]);
+    assert_eq!(args.name, None);
+    assert!(args.stdin.stdin);
+    assert!(args.force);
+}
+
+#[test]
+fn test_args_stdin_conflicts_with_name() {
+    assert!(Args::try_parse_from(["uninstall", "my-plugin", "--stdin"]).is_err());
+}
+
+#[test]
+fn test_args_name_required_without_stdin() {
+    assert!(Args::try_parse_from(["uninstall"]).is_err());
+}
+
+#[test]
+fn test_remaining_file_lines_lists_files_per_target() {
+    use crate::component::ComponentKind;
+    use std::path::PathBuf;
+
+    assert!(remaining_file_lines(&[]).is_empty());
+
+    let lines = remaining_file_lines(&[RemainingFile {
+        target: "codex".to_string(),
+        kind: ComponentKind::Skill,
+        path: PathBuf::from("/project/.codex/skills/review"),
+    }]);
+
+    assert_eq!(
+        lines,
+        vec![
+            "  Still deployed (1):".to_string(),
+            "    - codex: /project/.codex/skills/review (Skill)".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,45 @@
+//! plm unpin コマンド
+//!
+//! `plm pin` による固定を解除し、`plm update` の対象に戻す。
+
+use crate::application::unpin_plugin;
+use crate::commands::args::MarketplaceArgs;
+use crate::commands::examples::Example;
+use crate::plugin::PackageCache;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Plugin name (e.g., "owner--repo" or "plugin-name")
+    pub name: String,
+
+    #[command(flatten)]
+    pub marketplace: MarketplaceArgs,
+}
+
+/// `plm unpin` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm unpin formatter",
+        description: "Let plm update upgrade the plugin again",
+    },
+    Example {
+        command: "plm unpin formatter -m company-tools",
+        description: "Unpin the plugin installed from a marketplace",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm unpin`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let marketplace = args.marketplace.marketplace_or_default();
+
+    if unpin_plugin(&cache, &args.name, marketplace)? {
+        println!("Unpinned: {}", args.name);
+    } else {
+        println!("{} is not pinned", args.name);
+    }
+    Ok(())
+}
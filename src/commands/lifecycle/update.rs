@@ -2,9 +2,25 @@
 //!
 //! プラグインを最新バージョンに更新する。
 
-use crate::plugin::{update_all_plugins, update_plugin, PackageCache, UpdateOutcome, UpdateStatus};
+use crate::application::{backup_paths, render_warnings, WarningCategory, WarningCollector};
+use crate::backup::BackupOperation;
+use crate::commands::args::{
+    BackupArgs, GithubOutputArgs, NotifyArgs, PluginSpec, StdinArgs, StdinBatch,
+};
+use crate::commands::did_you_mean;
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::component::ComponentKind;
+use crate::output::github::{GithubReporter, StepSummary, SummaryStatus};
+use crate::output::hint::print_post_deploy_hints;
+use crate::plugin::{
+    detect_renames, migrate_rename, redeploy_all_plugins, redeploy_plugin, update_all_plugins,
+    update_plugin, update_target_exists, update_target_path, Channel, PackageCache,
+    PackageCacheAccess, PluginRename, RedeployOptions, UpdateOutcome, UpdateStatus,
+};
 use clap::{Parser, ValueEnum};
-use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, ValueEnum)]
 pub enum TargetKind {
@@ -21,36 +37,235 @@ impl TargetKind {
     }
 }
 
-#[derive(Debug, Parser)]
+#[derive(Debug, Clone, Parser)]
 pub struct Args {
     /// Plugin name to update
     #[arg(conflicts_with = "all")]
     pub name: Option<String>,
 
+    #[command(flatten)]
+    pub stdin: StdinArgs,
+
     /// Update all installed plugins
-    #[arg(long, conflicts_with = "name")]
+    #[arg(long, conflicts_with_all = ["name", "stdin"])]
     pub all: bool,
 
     /// Target environment filter (codex or copilot)
     #[arg(long, value_enum)]
     pub target: Option<TargetKind>,
+
+    /// Migrate plugins renamed in their marketplace without prompting
+    #[arg(long)]
+    pub follow_renames: bool,
+
+    /// Switch the plugin to this update channel (stable or beta)
+    #[arg(long, value_enum, conflicts_with = "all")]
+    pub channel: Option<Channel>,
+
+    /// Allow a channel switch that installs a lower version without prompting
+    #[arg(long, requires = "channel")]
+    pub allow_downgrade: bool,
+
+    /// Fetch and redeploy even when the commit SHA is unchanged
+    #[arg(long, conflicts_with = "redeploy_only")]
+    pub force: bool,
+
+    /// Skip fetching and redeploy the cached plugin to its enabled targets
+    #[arg(long, conflicts_with_all = ["channel", "follow_renames"])]
+    pub redeploy_only: bool,
+
+    /// Redeploy only these component kinds (comma-separated, e.g. commands,skills)
+    #[arg(long, value_enum, value_delimiter = ',', requires = "redeploy_only")]
+    pub only: Option<Vec<ComponentKind>>,
+
+    #[command(flatten)]
+    pub github: GithubOutputArgs,
+
+    #[command(flatten)]
+    pub notify: NotifyArgs,
+
+    #[command(flatten)]
+    pub backup: BackupArgs,
+
+    /// グローバル `--verbose`（警告を末尾にまとめるだけでなく、その場でも表示する）
+    #[arg(from_global)]
+    pub verbose: bool,
+}
+
+/// `plm update` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm update formatter",
+        description: "Update a single plugin",
+    },
+    Example {
+        command: "plm update --all",
+        description: "Update every installed plugin",
+    },
+    Example {
+        command: "plm update --stdin",
+        description: "Update the plugins named on stdin, one per line",
+    },
+    Example {
+        command: "plm update --all --target codex",
+        description: "Update plugins enabled for Codex",
+    },
+    Example {
+        command: "plm update --all --force",
+        description: "Refetch and redeploy every plugin even if unchanged",
+    },
+    Example {
+        command: "plm update formatter --channel beta",
+        description: "Switch a plugin to the beta channel (pre-releases included)",
+    },
+    Example {
+        command: "plm update formatter --channel stable --allow-downgrade",
+        description: "Go back to the latest stable release without prompting",
+    },
+    Example {
+        command: "plm update formatter --redeploy-only --only commands",
+        description: "Redeploy cached commands without fetching",
+    },
+    Example {
+        command: "plm update --all --redeploy-only",
+        description: "Redeploy every plugin from cache (e.g. after upgrading plm)",
+    },
+    Example {
+        command: "plm update --all --follow-renames",
+        description: "Also migrate plugins renamed in their marketplace",
+    },
+    Example {
+        command: "plm update --all --backup",
+        description: "Save the deployed files and caches first (see plm backups list)",
+    },
+    Example {
+        command: "plm update --all --github-output",
+        description: "Report failed updates as GitHub Actions annotations in CI",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm update`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let reporter = args.github.reporter();
+    let warnings = WarningCollector::default();
+    let result = if args.stdin.stdin {
+        execute_stdin(&args, ctx, &reporter, &warnings).await
+    } else {
+        execute(args, ctx, &reporter, &warnings).await
+    };
+    if let Some(rendered) = render_warnings("Warnings", &warnings.snapshot()) {
+        eprintln!("\n{}", rendered);
+    }
+    if let Err(e) = &result {
+        reporter.error(e);
+    }
+    result
+}
+
+/// 標準入力で指定したプラグインを 1 件ずつ更新する（`--stdin`）
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm update`.
+/// * `ctx` - Shared command context (resolved project root).
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after every plugin is updated.
+async fn execute_stdin(
+    args: &Args,
+    ctx: &Context,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
+    let mut batch = StdinBatch::default();
+    for spec in args.stdin.read_plugins()? {
+        let mut single = args.clone();
+        single.name = Some(plugin_arg(&spec));
+        batch.record(&spec, execute(single, ctx, reporter, warnings).await);
+    }
+    batch.finish()
+}
+
+/// 標準入力の `marketplace/name` を `plm update` の `name@marketplace` 形式にする
+///
+/// # Arguments
+///
+/// * `spec` - Plugin read from standard input.
+fn plugin_arg(spec: &PluginSpec) -> String {
+    match &spec.marketplace {
+        Some(marketplace) => format!("{}@{}", spec.name, marketplace),
+        None => spec.name.clone(),
+    }
 }
 
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm update`.
-pub async fn run(args: Args) -> Result<(), String> {
+/// * `ctx` - Shared command context (resolved project root).
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the update finishes.
+async fn execute(
+    mut args: Args,
+    ctx: &Context,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
     if args.name.is_none() && !args.all {
-        return Err("Specify plugin name or --all".to_string());
+        return Err("Specify plugin name, --all or --stdin".to_string());
     }
 
     let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
+    if let Some(name) = &args.name {
+        args.name = Some(resolve_plugin_arg(&cache, name, !args.stdin.stdin)?);
+    }
+    let project_root = ctx.project_root.clone();
     let target_filter = args.target.as_ref().map(|t| t.as_str());
 
+    let description = match &args.name {
+        Some(name) => format!("plm update {}", name),
+        None => "plm update --all".to_string(),
+    };
+    args.backup
+        .snapshot(BackupOperation::Update, &description, || {
+            update_backup_paths(&cache, args.name.as_deref(), target_filter)
+        })?;
+
+    if args.redeploy_only {
+        let options = RedeployOptions {
+            target_filter,
+            only: args.only.as_deref(),
+        };
+        return execute_redeploy(&args, &cache, &project_root, options, reporter, warnings);
+    }
+
+    // マーケットプレイス側でリネームされたプラグインを新名へ移行してから更新する
+    let renames: Vec<PluginRename> = detect_renames(&cache)
+        .into_iter()
+        .filter(|r| args.all || args.name.as_deref().is_some_and(|n| rename_matches(r, n)))
+        .collect();
+    let migrated = follow_renames(&cache, &renames, &args, &project_root, reporter, warnings)?;
+
     if args.all {
-        let results = update_all_plugins(&cache, &project_root, target_filter).await;
+        let results = update_all_plugins(&cache, &project_root, target_filter, args.force).await;
+        for result in &results {
+            record_deploy_warnings(result, warnings, args.verbose);
+        }
         display_batch_results(&results);
+        display_deploy_hints(&results);
+        report_to_github(reporter, &results);
+        args.notify.send(&update_notification(&results));
+        for result in results
+            .iter()
+            .filter(|r| matches!(r.status, UpdateStatus::Failed))
+        {
+            reporter.error(&format!(
+                "Failed to update '{}': {}",
+                result.plugin_name,
+                result.error.as_deref().unwrap_or("unknown error")
+            ));
+        }
 
         // Atomic batch: nothing was committed when any plugin Failed and no
         // plugin was Updated (the whole batch was rolled back). Treat as error exit.
@@ -64,19 +279,53 @@ pub async fn run(args: Args) -> Result<(), String> {
             return Err("Batch update rolled back: no plugins were updated".to_string());
         }
     } else if let Some(name) = &args.name {
-        let (plugin_input, marketplace_hint) = match name.split_once('@') {
-            Some((p, m)) if !p.is_empty() && !m.is_empty() => (p, Some(m)),
-            _ => (name.as_str(), None),
-        };
-        let result = update_plugin(
+        let (plugin_input, marketplace_hint) = split_plugin_arg(name);
+        // 移行済みなら新名で更新する
+        let plugin_input = migrated
+            .iter()
+            .find(|r| r.old_name == plugin_input)
+            .map_or(plugin_input, |r| r.new_name.as_str());
+        let mut result = update_plugin(
             &cache,
             plugin_input,
             marketplace_hint,
             &project_root,
             target_filter,
+            args.channel,
+            args.allow_downgrade,
+            args.force,
         )
         .await;
-        display_single_result(&result);
+        if let UpdateStatus::DowngradeRequired { from_ref, to_ref } = &result.status {
+            let channel = args.channel.unwrap_or_default();
+            if !io::stdin().is_terminal() {
+                return Err(format!(
+                    "Switching '{}' to the {} channel would downgrade {} -> {}. \
+                     Re-run with --allow-downgrade to confirm.",
+                    result.plugin_name, channel, from_ref, to_ref
+                ));
+            }
+            if !confirm_downgrade(&result.plugin_name, channel, from_ref, to_ref)? {
+                println!("Cancelled.");
+                return Ok(());
+            }
+            result = update_plugin(
+                &cache,
+                plugin_input,
+                marketplace_hint,
+                &project_root,
+                target_filter,
+                args.channel,
+                true,
+                args.force,
+            )
+            .await;
+        }
+        display_single_result(&result, warnings, args.verbose);
+        display_deploy_hints(std::slice::from_ref(&result));
+        report_to_github(reporter, std::slice::from_ref(&result));
+        args.notify
+            .send(&update_notification(std::slice::from_ref(&result)));
 
         if matches!(result.status, UpdateStatus::Failed) {
             return Err(result.error.unwrap_or_default());
@@ -86,10 +335,293 @@ pub async fn run(args: Args) -> Result<(), String> {
     Ok(())
 }
 
+/// fetch せずキャッシュから再デプロイする（`--redeploy-only`）
+///
+/// リネーム追従は行わない（移行は fetch を伴うため）。
+///
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm update`.
+/// * `cache` - Package cache.
+/// * `project_root` - Project root path used for redeployment.
+/// * `options` - Target and component kind filters.
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the update finishes.
+fn execute_redeploy(
+    args: &Args,
+    cache: &PackageCache,
+    project_root: &Path,
+    options: RedeployOptions<'_>,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
+    let results = match &args.name {
+        Some(name) => {
+            let (plugin_input, marketplace_hint) = split_plugin_arg(name);
+            vec![redeploy_plugin(
+                cache,
+                plugin_input,
+                marketplace_hint,
+                project_root,
+                options,
+            )]
+        }
+        None => redeploy_all_plugins(cache, project_root, options),
+    };
+    for result in &results {
+        display_single_result(result, warnings, args.verbose);
+    }
+    if args.all {
+        display_batch_results(&results);
+    }
+    display_deploy_hints(&results);
+    report_to_github(reporter, &results);
+    args.notify.send(&update_notification(&results));
+
+    let failed: Vec<&UpdateOutcome> = results
+        .iter()
+        .filter(|r| matches!(r.status, UpdateStatus::Failed))
+        .collect();
+    match failed.as_slice() {
+        [] => Ok(()),
+        [single] if !args.all => Err(single.error.clone().unwrap_or_default()),
+        _ => Err(format!("Failed to redeploy {} plugin(s)", failed.len())),
+    }
+}
+
+/// `--backup` で退避するパス（対象プラグインの配置先とキャッシュ全体）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `name` - Plugin argument (`name` or `name@marketplace`), or `None` for `--all`.
+/// * `target_filter` - Only include files deployed to this target, if given.
+fn update_backup_paths(
+    cache: &PackageCache,
+    name: Option<&str>,
+    target_filter: Option<&str>,
+) -> Vec<PathBuf> {
+    let plugin_paths: Vec<PathBuf> = match name {
+        Some(name) => {
+            let (plugin_input, marketplace_hint) = split_plugin_arg(name);
+            update_target_path(cache, plugin_input, marketplace_hint)
+                .into_iter()
+                .collect()
+        }
+        None => cache
+            .list()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, id)| !id.starts_with('.'))
+            .map(|(marketplace, id)| cache.plugin_path(marketplace.as_deref(), &id))
+            .collect(),
+    };
+    plugin_paths
+        .iter()
+        .flat_map(|path| backup_paths(path, target_filter, true))
+        .collect()
+}
+
+/// 見つからないプラグイン名なら近い名前を提示し、確認されればその名前に置き換える
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `name` - Plugin argument (`name` or `name@marketplace`).
+/// * `prompt` - Whether the confirmation may be asked (`false` with `--stdin`).
+fn resolve_plugin_arg(cache: &PackageCache, name: &str, prompt: bool) -> Result<String, String> {
+    let (plugin_input, marketplace_hint) = split_plugin_arg(name);
+    if update_target_exists(cache, plugin_input, marketplace_hint) {
+        return Ok(name.to_string());
+    }
+    let chosen = did_you_mean::resolve_missing(
+        cache,
+        plugin_input,
+        |n| update_target_exists(cache, n, marketplace_hint),
+        format!("Plugin '{}' not found in cache", name),
+        prompt,
+    )?;
+    Ok(match marketplace_hint {
+        Some(marketplace) => format!("{}@{}", chosen, marketplace),
+        None => chosen,
+    })
+}
+
+/// `name@marketplace` 形式の指定をプラグイン名とマーケットプレイスに分ける
+///
+/// # Arguments
+///
+/// * `name` - Plugin argument (`name` or `name@marketplace`).
+fn split_plugin_arg(name: &str) -> (&str, Option<&str>) {
+    match name.split_once('@') {
+        Some((p, m)) if !p.is_empty() && !m.is_empty() => (p, Some(m)),
+        _ => (name, None),
+    }
+}
+
+/// `plm update <name>` の指定がリネーム対象（旧名）を指しているか
+///
+/// # Arguments
+///
+/// * `rename` - Detected rename.
+/// * `name` - Plugin argument (`name` or `name@marketplace`).
+fn rename_matches(rename: &PluginRename, name: &str) -> bool {
+    match name.split_once('@') {
+        Some((p, m)) if !p.is_empty() && !m.is_empty() => {
+            rename.old_name == p && rename.marketplace == m
+        }
+        _ => rename.old_name == name,
+    }
+}
+
+/// 検出したリネームを確認のうえ移行する
+///
+/// `--follow-renames` 指定時は確認なしで移行する。非対話環境で未指定の場合は
+/// 案内だけ表示して移行しない。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `renames` - Renames detected for the plugins being updated.
+/// * `args` - Parsed CLI arguments (`--follow-renames` / `--verbose`).
+/// * `project_root` - Project root path used for redeployment.
+/// * `reporter` - GitHub Actions output (no-op unless enabled).
+/// * `warnings` - Collects warnings shown once after the update finishes.
+///
+/// # Returns
+/// 移行に成功したリネーム
+fn follow_renames(
+    cache: &PackageCache,
+    renames: &[PluginRename],
+    args: &Args,
+    project_root: &Path,
+    reporter: &GithubReporter,
+    warnings: &WarningCollector,
+) -> Result<Vec<PluginRename>, String> {
+    let mut migrated = Vec::new();
+    for rename in renames {
+        if !args.follow_renames {
+            if !io::stdin().is_terminal() {
+                let note = format!(
+                    "'{}' was renamed to '{}' in marketplace '{}'. \
+                     Re-run with --follow-renames to migrate.",
+                    rename.old_name, rename.new_name, rename.marketplace
+                );
+                if args.verbose {
+                    eprintln!("Note: {}", note);
+                }
+                reporter.warning(&note, None);
+                warnings.push(WarningCategory::Rename, note);
+                continue;
+            }
+            if !confirm_rename(rename)? {
+                continue;
+            }
+        }
+
+        match migrate_rename(cache, rename, project_root) {
+            Ok(migration) => {
+                println!(
+                    "Migrated: {} -> {} (marketplace: {})",
+                    rename.old_name, rename.new_name, rename.marketplace
+                );
+                for target in &migration.deployed_targets {
+                    println!("  - Deployed to {}", target);
+                }
+                for conflict in &migration.conflicts {
+                    if args.verbose {
+                        eprintln!("Warning: {}", conflict.message());
+                    }
+                    reporter.warning(&conflict.message(), Some(&conflict.path));
+                    warnings.push(WarningCategory::Deploy, conflict.message());
+                }
+                for target in &migration.failed_targets {
+                    if args.verbose {
+                        eprintln!(
+                            "Warning: Failed to deploy to {} (marked as disabled)",
+                            target
+                        );
+                    }
+                    let message = format!(
+                        "Failed to deploy '{}' to {} (marked as disabled)",
+                        rename.new_name, target
+                    );
+                    reporter.warning(&message, None);
+                    warnings.push(WarningCategory::Deploy, message);
+                }
+                migrated.push(rename.clone());
+            }
+            Err(e) => {
+                let message = format!(
+                    "Failed to migrate '{}' to '{}': {}",
+                    rename.old_name, rename.new_name, e
+                );
+                eprintln!("Error: {}", message);
+                reporter.error(&message);
+            }
+        }
+    }
+    Ok(migrated)
+}
+
+/// リネーム追従の確認を求める
+///
+/// # Arguments
+///
+/// * `rename` - Rename shown in the confirmation prompt.
+fn confirm_rename(rename: &PluginRename) -> Result<bool, String> {
+    print!(
+        "'{}' was renamed to '{}'. Migrate? [y/N]: ",
+        rename.old_name, rename.new_name
+    );
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
+/// チャンネル切り替えによるダウングレードを確認する
+///
+/// # Arguments
+///
+/// * `name` - Plugin name.
+/// * `channel` - Channel being switched to.
+/// * `from_ref` - Currently installed release tag.
+/// * `to_ref` - Release tag that would be installed.
+fn confirm_downgrade(
+    name: &str,
+    channel: Channel,
+    from_ref: &str,
+    to_ref: &str,
+) -> Result<bool, String> {
+    print!(
+        "Switching '{}' to the {} channel will downgrade {} -> {}. Continue? [y/N]: ",
+        name, channel, from_ref, to_ref
+    );
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
 /// # Arguments
 ///
 /// * `result` - Single-plugin update outcome to render.
-fn display_single_result(result: &UpdateOutcome) {
+/// * `warnings` - Collects the targets that failed to redeploy and the conflicts.
+/// * `verbose` - Also print those warnings immediately (`--verbose`).
+pub(crate) fn display_single_result(
+    result: &UpdateOutcome,
+    warnings: &WarningCollector,
+    verbose: bool,
+) {
     match &result.status {
         UpdateStatus::Updated { from_sha, to_sha } => {
             let from = from_sha.as_deref().unwrap_or("unknown");
@@ -97,12 +629,14 @@ fn display_single_result(result: &UpdateOutcome) {
             for target in &result.deployed_targets {
                 println!("  - Deployed to {}", target);
             }
-            for target in &result.failed_targets {
-                eprintln!(
-                    "Warning: Failed to deploy to {} (marked as disabled)",
-                    target
-                );
+            record_deploy_warnings(result, warnings, verbose);
+        }
+        UpdateStatus::Redeployed => {
+            println!("{}: redeployed (no fetch)", result.plugin_name);
+            for target in &result.deployed_targets {
+                println!("  - Deployed to {}", target);
             }
+            record_deploy_warnings(result, warnings, verbose);
         }
         UpdateStatus::AlreadyUpToDate => {
             println!("{}: Already up to date", result.plugin_name);
@@ -110,6 +644,12 @@ fn display_single_result(result: &UpdateOutcome) {
         UpdateStatus::Skipped { reason } => {
             println!("{}: Skipped ({})", result.plugin_name, reason);
         }
+        UpdateStatus::DowngradeRequired { from_ref, to_ref } => {
+            println!(
+                "{}: Not updated (switching channel would downgrade {} -> {})",
+                result.plugin_name, from_ref, to_ref
+            );
+        }
         UpdateStatus::Failed => {
             eprintln!("Error: Failed to update '{}'", result.plugin_name);
             if let Some(e) = &result.error {
@@ -129,6 +669,77 @@ fn display_single_result(result: &UpdateOutcome) {
     }
 }
 
+/// 再デプロイに失敗したターゲットと、衝突で配置しなかったコンポーネントを警告として集約する
+///
+/// # Arguments
+///
+/// * `result` - Update outcome whose failed targets and conflicts are recorded.
+/// * `warnings` - Collects the warnings.
+/// * `verbose` - Also print the warnings immediately (`--verbose`).
+fn record_deploy_warnings(result: &UpdateOutcome, warnings: &WarningCollector, verbose: bool) {
+    for target in &result.failed_targets {
+        if verbose {
+            eprintln!(
+                "Warning: Failed to deploy to {} (marked as disabled)",
+                target
+            );
+        }
+        warnings.push(
+            WarningCategory::Deploy,
+            format!(
+                "Failed to deploy '{}' to {} (marked as disabled)",
+                result.plugin_name, target
+            ),
+        );
+    }
+    for conflict in &result.conflicts {
+        if verbose {
+            eprintln!("Warning: {}", conflict.message());
+        }
+        warnings.push(WarningCategory::Deploy, conflict.message());
+    }
+}
+
+/// 再デプロイしたターゲットの反映手順をまとめて案内する
+///
+/// # Arguments
+///
+/// * `results` - Update outcomes whose deployed targets are collected.
+pub(crate) fn display_deploy_hints(results: &[UpdateOutcome]) {
+    let targets: Vec<&str> = results
+        .iter()
+        .flat_map(|r| r.deployed_targets.iter().map(String::as_str))
+        .collect();
+    print_post_deploy_hints(&targets);
+}
+
+/// 完了通知の本文（例: `5 plugins updated, 1 failed`）
+///
+/// # Arguments
+///
+/// * `results` - Update outcomes to count.
+fn update_notification(results: &[UpdateOutcome]) -> String {
+    let updated = results
+        .iter()
+        .filter(|r| {
+            matches!(
+                r.status,
+                UpdateStatus::Updated { .. } | UpdateStatus::Redeployed
+            )
+        })
+        .count();
+    let failed = results
+        .iter()
+        .filter(|r| matches!(r.status, UpdateStatus::Failed))
+        .count();
+    let noun = if updated == 1 { "plugin" } else { "plugins" };
+    if failed == 0 {
+        format!("{} {} updated", updated, noun)
+    } else {
+        format!("{} {} updated, {} failed", updated, noun, failed)
+    }
+}
+
 /// # Arguments
 ///
 /// * `results` - Update outcomes from a batch run to summarize.
@@ -141,6 +752,10 @@ fn display_batch_results(results: &[UpdateOutcome]) {
         .iter()
         .filter(|r| matches!(r.status, UpdateStatus::AlreadyUpToDate))
         .count();
+    let redeployed = results
+        .iter()
+        .filter(|r| matches!(r.status, UpdateStatus::Redeployed))
+        .count();
     let skipped = results
         .iter()
         .filter(|r| matches!(r.status, UpdateStatus::Skipped { .. }))
@@ -155,8 +770,12 @@ fn display_batch_results(results: &[UpdateOutcome]) {
         .count();
 
     println!("\nSummary:");
-    println!("  Updated: {}", updated);
-    println!("  Up to date: {}", up_to_date);
+    if redeployed > 0 {
+        println!("  Redeployed: {}", redeployed);
+    } else {
+        println!("  Updated: {}", updated);
+        println!("  Up to date: {}", up_to_date);
+    }
     if skipped > 0 {
         println!("  Skipped: {}", skipped);
     }
@@ -167,3 +786,91 @@ fn display_batch_results(results: &[UpdateOutcome]) {
         println!("  Rolled back: {}", rolled_back);
     }
 }
+
+/// 警告（再デプロイ失敗・ロールバック）をアノテーションとして出し、サマリーを追記する
+///
+/// 更新失敗のエラーは呼び出し側で出す（単体更新ではコマンドのエラーとして 1 回だけ出す）。
+///
+/// # Arguments
+///
+/// * `reporter` - GitHub Actions output.
+/// * `results` - Update outcomes to report.
+fn report_to_github(reporter: &GithubReporter, results: &[UpdateOutcome]) {
+    for result in results {
+        for conflict in &result.conflicts {
+            reporter.warning(&conflict.message(), Some(&conflict.path));
+        }
+        for target in &result.failed_targets {
+            reporter.warning(
+                &format!(
+                    "Failed to deploy '{}' to {} (marked as disabled)",
+                    result.plugin_name, target
+                ),
+                None,
+            );
+        }
+        if matches!(result.status, UpdateStatus::RolledBack) {
+            reporter.warning(
+                &format!(
+                    "Rolled back '{}' (batch update aborted, previous version retained)",
+                    result.plugin_name
+                ),
+                None,
+            );
+        }
+    }
+    reporter.write_summary(&update_summary(results));
+}
+
+/// 更新結果を `GITHUB_STEP_SUMMARY` 用のサマリーに変換する（プラグイン 1 件 1 行）
+///
+/// # Arguments
+///
+/// * `results` - Update outcomes to summarize.
+fn update_summary(results: &[UpdateOutcome]) -> StepSummary {
+    let mut summary = StepSummary::new("plm update");
+    for result in results {
+        let (status, details) = match &result.status {
+            UpdateStatus::Updated { from_sha, to_sha } => {
+                let mut details =
+                    format!("{} -> {}", from_sha.as_deref().unwrap_or("unknown"), to_sha);
+                if !result.failed_targets.is_empty() {
+                    details.push_str(&format!(
+                        " (failed targets: {})",
+                        result.failed_targets.join(", ")
+                    ));
+                }
+                (SummaryStatus::Success, details)
+            }
+            UpdateStatus::Redeployed => {
+                let mut details = "redeployed (no fetch)".to_string();
+                if !result.failed_targets.is_empty() {
+                    details.push_str(&format!(
+                        " (failed targets: {})",
+                        result.failed_targets.join(", ")
+                    ));
+                }
+                (SummaryStatus::Success, details)
+            }
+            UpdateStatus::AlreadyUpToDate => {
+                (SummaryStatus::Success, "Already up to date".to_string())
+            }
+            UpdateStatus::Skipped { reason } => (SummaryStatus::Skipped, reason.clone()),
+            UpdateStatus::DowngradeRequired { from_ref, to_ref } => (
+                SummaryStatus::Skipped,
+                format!("Downgrade {} -> {} not confirmed", from_ref, to_ref),
+            ),
+            UpdateStatus::Failed => (
+                SummaryStatus::Failure,
+                result.error.clone().unwrap_or_default(),
+            ),
+            UpdateStatus::RolledBack => (SummaryStatus::Failure, "Rolled back".to_string()),
+        };
+        summary.push(&result.plugin_name, status, details);
+    }
+    summary
+}
+
+#[cfg(test)]
+#[path = "update_test.rs"]
+mod tests;
@@ -0,0 +1,88 @@
+use super::*;
+
+#[test]
+fn update_summary_has_one_row_per_plugin() {
+    let results = vec![
+        UpdateOutcome::updated(
+            "formatter",
+            Some("abc1234".to_string()),
+            "def5678".to_string(),
+            vec!["codex".to_string()],
+            vec!["copilot".to_string()],
+        ),
+        UpdateOutcome::up_to_date("linter"),
+        UpdateOutcome::skipped("local", "not a remote plugin".to_string()),
+        UpdateOutcome::failed("broken", "network error".to_string()),
+        UpdateOutcome::rolled_back("other", None),
+        UpdateOutcome::redeployed(
+            "cached",
+            vec!["codex".to_string()],
+            vec!["copilot".to_string()],
+        ),
+    ];
+
+    let summary = update_summary(&results);
+    let rows: Vec<_> = summary
+        .rows()
+        .iter()
+        .map(|r| (r.name.as_str(), r.status, r.details.as_str()))
+        .collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            (
+                "formatter",
+                SummaryStatus::Success,
+                "abc1234 -> def5678 (failed targets: copilot)"
+            ),
+            ("linter", SummaryStatus::Success, "Already up to date"),
+            ("local", SummaryStatus::Skipped, "not a remote plugin"),
+            ("broken", SummaryStatus::Failure, "network error"),
+            ("other", SummaryStatus::Failure, "Rolled back"),
+            (
+                "cached",
+                SummaryStatus::Success,
+                "redeployed (no fetch) (failed targets: copilot)"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn update_notification_counts_updated_and_failed() {
+    let updated =
+        |name: &str| UpdateOutcome::updated(name, None, "abc".to_string(), vec![], vec![]);
+    let results = vec![
+        updated("a"),
+        updated("b"),
+        UpdateOutcome::redeployed("c", vec![], vec![]),
+        UpdateOutcome::up_to_date("d"),
+        UpdateOutcome::failed("e", "network error".to_string()),
+    ];
+
+    assert_eq!(update_notification(&results), "3 plugins updated, 1 failed");
+    assert_eq!(update_notification(&results[..1]), "1 plugin updated");
+    assert_eq!(update_notification(&[]), "0 plugins updated");
+}
+
+#[test]
+fn deploy_warnings_include_conflicts() {
+    let conflict = crate::plugin::ComponentConflict {
+        target: "codex".to_string(),
+        kind: ComponentKind::Skill,
+        name: "review".to_string(),
+        path: PathBuf::from("/project/.codex/skills/review"),
+        owner: "market/other".to_string(),
+    };
+    let result = UpdateOutcome::redeployed("cached", vec!["codex".to_string()], vec![])
+        .with_conflicts(vec![conflict.clone()]);
+    let warnings = WarningCollector::default();
+
+    record_deploy_warnings(&result, &warnings, false);
+
+    let recorded = warnings.snapshot();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].category, WarningCategory::Deploy);
+    assert_eq!(recorded[0].message, conflict.message());
+}
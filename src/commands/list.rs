@@ -6,28 +6,91 @@ mod json;
 mod outdated;
 mod simple;
 mod table;
+mod tree;
 mod wire;
+mod yaml;
 
-use crate::application::list_installed_plugins;
-use crate::commands::args::{ListOutputArgs, SingleTargetArgs};
+pub(crate) use wire::KindCountsWire;
+
+use crate::application::{list_installed_plugins, stale_sandbox_warning, summarize_plugins};
+use crate::commands::args::{ListOutputArgs, OutputFormat, SingleTargetArgs};
+use crate::commands::examples::Example;
 use crate::component::ComponentKind;
+use crate::marketplace::DEFAULT_MARKETPLACE;
 use crate::plugin::{InstalledPlugin, PackageCache};
 use crate::target::TargetKind;
-use clap::Parser;
+use chrono::{DateTime, Utc};
+use clap::{Parser, ValueEnum};
+use std::cmp::Ordering;
 
 #[derive(Debug, Parser)]
 pub struct Args {
     /// Filter by component type
-    #[arg(long = "type", value_enum)]
+    #[arg(long = "type", visible_alias = "kind", value_enum)]
     pub component_type: Option<ComponentKind>,
 
     #[command(flatten)]
     pub target: SingleTargetArgs,
 
+    /// Show only plugins installed from this marketplace ("github" for direct GitHub installs)
+    #[arg(long, short = 'm')]
+    pub marketplace: Option<String>,
+
+    /// Sort order (dates are newest first; plugins without a recorded date come last)
+    #[arg(long, value_enum, default_value = "name")]
+    pub sort: ListSort,
+
     #[command(flatten)]
     pub output: ListOutputArgs,
 }
 
+/// `plm list --sort` の並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ListSort {
+    /// 名前順
+    Name,
+    /// インストール日時の新しい順
+    InstalledAt,
+    /// 最終更新日時（未更新ならインストール日時）の新しい順
+    UpdatedAt,
+}
+
+/// `plm list` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm list",
+        description: "Show installed plugins as a table",
+    },
+    Example {
+        command: "plm list --type skill",
+        description: "Show only plugins that contain skills",
+    },
+    Example {
+        command: "plm list --target codex",
+        description: "Show plugins enabled for Codex",
+    },
+    Example {
+        command: "plm list --json",
+        description: "Print machine-readable JSON",
+    },
+    Example {
+        command: "plm list --format yaml",
+        description: "Print name, version, status and component counts as YAML",
+    },
+    Example {
+        command: "plm list --sort updated-at",
+        description: "Show recently updated plugins first",
+    },
+    Example {
+        command: "plm list --outdated",
+        description: "Show plugins with available updates",
+    },
+    Example {
+        command: "plm list --tree --kind command",
+        description: "Show plugins and their commands as a tree",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm list`.
@@ -37,19 +100,40 @@ pub async fn run(args: Args) -> Result<(), String> {
         .map_err(|e| format!("Failed to list installed plugins: {e}"))?;
 
     let total_count = plugins.len();
+    let totals = summarize_plugins(&plugins);
 
-    plugins.sort_by(|a, b| a.name().cmp(b.name()));
+    sort_plugins(&mut plugins, args.sort);
+    // 古い sandbox はフィルタに関係なく知らせる
+    let now = Utc::now();
+    let sandbox_warnings: Vec<String> = plugins
+        .iter()
+        .filter_map(|p| stale_sandbox_warning(p, now))
+        .collect();
 
     let filtered = filter_plugins(plugins, &args);
 
-    if args.output.outdated {
+    if let Some(format) = args.output.format {
+        // 空の一覧も `[]` として出力する（スクリプトから扱えるようエラーにしない）
+        match format {
+            OutputFormat::Table => table::print_table(&filtered, &totals),
+            OutputFormat::Json => json::print_entries(&filtered)?,
+            OutputFormat::Yaml => yaml::print_entries(&filtered)?,
+        }
+    } else if args.output.outdated {
         outdated::run_outdated(&cache, &filtered, args.output.json, total_count).await?;
     } else if args.output.json {
-        json::print_json(&filtered)?;
+        json::print_json(&filtered, &totals)?;
     } else if args.output.simple {
         simple::print_simple(&filtered, total_count);
+    } else if args.output.tree {
+        tree::print_tree(&filtered, &totals, args.component_type, args.output.depth);
     } else {
-        table::print_table(&filtered, total_count);
+        table::print_table(&filtered, &totals);
+    }
+
+    // stdout の JSON を汚さないよう stderr へ出す
+    for warning in &sandbox_warnings {
+        eprintln!("Warning: {}", warning);
     }
 
     Ok(())
@@ -70,6 +154,33 @@ pub(super) fn print_empty_list(total_count: usize) {
     println!("{msg}");
 }
 
+/// 一覧を `--sort` の順に並べる（同順位は名前順）
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to sort.
+/// * `sort` - Requested sort order.
+fn sort_plugins(plugins: &mut [InstalledPlugin], sort: ListSort) {
+    let timestamp = |plugin: &InstalledPlugin| {
+        let at = match sort {
+            ListSort::Name => None,
+            ListSort::InstalledAt => plugin.installed_at(),
+            ListSort::UpdatedAt => plugin.last_updated(),
+        };
+        at.and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+    };
+    plugins.sort_by(|a, b| {
+        // 新しい順。日時の無いもの（None）は末尾
+        let by_date = match (timestamp(a), timestamp(b)) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        by_date.then_with(|| a.name().cmp(b.name()))
+    });
+}
+
 /// # Arguments
 ///
 /// * `plugins` - Installed plugins to filter.
@@ -79,6 +190,7 @@ fn filter_plugins(plugins: Vec<InstalledPlugin>, args: &Args) -> Vec<InstalledPl
         .into_iter()
         .filter(|p| filter_by_type(p, args.component_type.as_ref()))
         .filter(|p| filter_by_target(p, args.target.target.as_ref()))
+        .filter(|p| filter_by_marketplace(p, args.marketplace.as_deref()))
         .collect()
 }
 
@@ -108,6 +220,17 @@ fn filter_by_target(plugin: &InstalledPlugin, target: Option<&TargetKind>) -> bo
     }
 }
 
+/// # Arguments
+///
+/// * `plugin` - Plugin being checked against the filter.
+/// * `marketplace` - Requested marketplace name, or `None` to allow any.
+fn filter_by_marketplace(plugin: &InstalledPlugin, marketplace: Option<&str>) -> bool {
+    match marketplace {
+        None => true,
+        Some(m) => plugin.marketplace().unwrap_or(DEFAULT_MARKETPLACE) == m,
+    }
+}
+
 #[cfg(test)]
 #[path = "list/list_test.rs"]
 mod tests;
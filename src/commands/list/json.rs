@@ -1,25 +1,56 @@
 //! JSON 出力フォーマット
 
-use super::wire::Wire;
+use super::wire::{EntryWire, ListWire, SummaryWire, Wire};
+use crate::application::PluginTotals;
 use crate::plugin::InstalledPlugin;
 
-/// Serializes installed plugins into a pretty-printed JSON string.
+/// Serializes installed plugins and their totals into a pretty-printed JSON string.
 ///
 /// # Arguments
 ///
 /// * `plugins` - Installed plugins to serialize.
-pub(super) fn render_json(plugins: &[InstalledPlugin]) -> Result<String, String> {
-    let wires: Vec<Wire<'_>> = plugins.iter().map(Wire::from_installed).collect();
-    serde_json::to_string_pretty(&wires).map_err(|e| format!("Failed to serialize plugins: {}", e))
+/// * `totals` - Totals over all installed plugins (before filtering).
+pub(super) fn render_json(
+    plugins: &[InstalledPlugin],
+    totals: &PluginTotals,
+) -> Result<String, String> {
+    let wire = ListWire {
+        plugins: plugins.iter().map(Wire::from_installed).collect(),
+        summary: SummaryWire::from_totals(totals, plugins.len()),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize plugins: {}", e))
 }
 
-/// Prints installed plugins as pretty-printed JSON to stdout.
+/// Prints installed plugins and their totals as pretty-printed JSON to stdout.
 ///
 /// # Arguments
 ///
 /// * `plugins` - Installed plugins to print.
-pub(super) fn print_json(plugins: &[InstalledPlugin]) -> Result<(), String> {
-    let s = render_json(plugins)?;
+/// * `totals` - Totals over all installed plugins (before filtering).
+pub(super) fn print_json(plugins: &[InstalledPlugin], totals: &PluginTotals) -> Result<(), String> {
+    let s = render_json(plugins, totals)?;
+    println!("{s}");
+    Ok(())
+}
+
+/// Serializes installed plugins as a pretty-printed JSON array of entries (`list --format json`).
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to serialize.
+pub(super) fn render_entries(plugins: &[InstalledPlugin]) -> Result<String, String> {
+    let entries: Vec<EntryWire> = plugins.iter().map(EntryWire::from_installed).collect();
+    serde_json::to_string_pretty(&entries)
+        .map_err(|e| format!("Failed to serialize plugins: {}", e))
+}
+
+/// Prints installed plugins as a JSON array of entries to stdout.
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to print.
+pub(super) fn print_entries(plugins: &[InstalledPlugin]) -> Result<(), String> {
+    let s = render_entries(plugins)?;
     println!("{s}");
     Ok(())
 }
@@ -1,4 +1,4 @@
-use super::table::format_components;
+use super::table::{display_name, format_components, format_last_updated, format_version};
 use super::*;
 use crate::commands::args::{ListOutputArgs, SingleTargetArgs};
 use crate::component::{Component, ComponentKind};
@@ -127,6 +127,34 @@ fn test_filter_by_target_enabled_only() {
     assert!(!filter_by_target(&disabled, Some(&TargetKind::Copilot)));
 }
 
+// ========================================
+// filter_by_marketplace tests
+// ========================================
+
+#[test]
+fn test_filter_by_marketplace_matches_name() {
+    let github = create_plugin_with_skills("from-github", 1, true);
+    let company = InstalledPlugin::new_for_test(
+        "from-company",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("company-tools".to_string()),
+        true,
+    );
+
+    assert!(filter_by_marketplace(&github, None));
+    assert!(filter_by_marketplace(&company, Some("company-tools")));
+    assert!(!filter_by_marketplace(&github, Some("company-tools")));
+}
+
+#[test]
+fn test_filter_by_marketplace_missing_treated_as_github() {
+    let plugin = create_empty_plugin("legacy");
+
+    assert!(filter_by_marketplace(&plugin, Some(DEFAULT_MARKETPLACE)));
+}
+
 // ========================================
 // format_components tests
 // ========================================
@@ -152,6 +180,31 @@ fn test_format_components_multiple() {
     );
 }
 
+#[test]
+fn test_display_name_marks_sandbox() {
+    let plugin = create_empty_plugin("trial");
+    assert_eq!(display_name(&plugin), "trial");
+
+    let plugin = plugin.with_sandbox(Some(crate::plugin::SandboxInfo {
+        path: "/project/.plm/sandbox/trial".to_string(),
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        targets: vec![],
+    }));
+    assert_eq!(display_name(&plugin), "trial (sandbox)");
+}
+
+#[test]
+fn test_format_version_shows_channel() {
+    let plugin = create_empty_plugin("formatter");
+    assert_eq!(format_version(&plugin), plugin.version());
+
+    let plugin = plugin.with_channel(Some(crate::plugin::Channel::Beta));
+    assert_eq!(
+        format_version(&plugin),
+        format!("{} (beta)", plugin.version())
+    );
+}
+
 // ========================================
 // filter_plugins tests
 // ========================================
@@ -170,10 +223,15 @@ fn test_filter_plugins_combined() {
         target: SingleTargetArgs {
             target: Some(TargetKind::Codex),
         },
+        marketplace: None,
+        sort: ListSort::Name,
         output: ListOutputArgs {
             json: false,
             simple: false,
             outdated: false,
+            tree: false,
+            depth: None,
+            format: None,
         },
     };
 
@@ -183,3 +241,153 @@ fn test_filter_plugins_combined() {
     assert_eq!(filtered[0].name(), "enabled-with-skills");
     assert_eq!(filtered[1].name(), "enabled-full");
 }
+
+// ========================================
+// summary tests
+// ========================================
+
+#[test]
+fn test_summary_counts_all_plugins_while_filtered() {
+    let plugins = vec![
+        create_plugin_with_skills("enabled-with-skills", 2, true),
+        create_plugin_with_skills("disabled-with-skills", 1, false),
+        create_full_plugin("enabled-full", true),
+    ];
+    let totals = summarize_plugins(&plugins);
+
+    let args = Args {
+        component_type: Some(ComponentKind::Hook),
+        target: SingleTargetArgs { target: None },
+        marketplace: None,
+        sort: ListSort::Name,
+        output: ListOutputArgs {
+            json: true,
+            simple: false,
+            outdated: false,
+            tree: false,
+            depth: None,
+            format: None,
+        },
+    };
+    let filtered = filter_plugins(plugins, &args);
+
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(
+        totals.footer(filtered.len()),
+        "Total: 3 plugins (2 enabled, 1 disabled), 9 components \
+         (5 skills, 1 agent, 1 command, 1 instruction, 1 hook), showing 1 of 3"
+    );
+}
+
+#[test]
+fn test_render_json_includes_summary() {
+    let plugins = vec![
+        create_plugin_with_skills("a", 2, true),
+        create_empty_plugin("b"),
+    ];
+    let totals = summarize_plugins(&plugins);
+
+    let json_str = json::render_json(&plugins[..1], &totals).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    assert_eq!(value["plugins"].as_array().unwrap().len(), 1);
+    assert_eq!(value["plugins"][0]["name"], "a");
+    assert_eq!(
+        value["summary"],
+        serde_json::json!({
+            "plugins": 2,
+            "enabled": 1,
+            "disabled": 1,
+            "components": 2,
+            "components_by_kind": {
+                "skills": 2,
+                "agents": 0,
+                "commands": 0,
+                "instructions": 0,
+                "hooks": 0
+            },
+            "shown": 1
+        })
+    );
+}
+
+#[test]
+fn test_render_entries_prints_empty_array_for_no_plugins() {
+    assert_eq!(json::render_entries(&[]).unwrap(), "[]");
+    assert_eq!(yaml::render_entries(&[]).unwrap().trim(), "[]");
+}
+
+#[test]
+fn test_render_entries_yaml_counts_components() {
+    let plugins = vec![create_full_plugin("full", true)];
+
+    let yaml_str = yaml::render_entries(&plugins).unwrap();
+    let value: serde_yaml::Value = serde_yaml::from_str(&yaml_str).unwrap();
+
+    assert_eq!(value[0]["name"], serde_yaml::Value::from("full"));
+    assert_eq!(value[0]["marketplace"], serde_yaml::Value::from("github"));
+    assert_eq!(value[0]["enabled"], serde_yaml::Value::from(true));
+    assert_eq!(value[0]["components"]["hooks"], serde_yaml::Value::from(1));
+}
+
+// ========================================
+// sort / last updated tests
+// ========================================
+
+fn plugin_with_timestamps(
+    name: &str,
+    installed_at: Option<&str>,
+    updated_at: Option<&str>,
+) -> InstalledPlugin {
+    create_empty_plugin(name).with_timestamps(
+        installed_at.map(str::to_string),
+        updated_at.map(str::to_string),
+    )
+}
+
+fn sorted_names(mut plugins: Vec<InstalledPlugin>, sort: ListSort) -> Vec<String> {
+    sort_plugins(&mut plugins, sort);
+    plugins.iter().map(|p| p.name().to_string()).collect()
+}
+
+#[test]
+fn test_sort_plugins_by_updated_at_newest_first_and_missing_last() {
+    let plugins = vec![
+        plugin_with_timestamps("alpha", Some("2026-01-01T00:00:00Z"), None),
+        plugin_with_timestamps(
+            "beta",
+            Some("2025-06-01T00:00:00Z"),
+            Some("2026-02-01T00:00:00Z"),
+        ),
+        plugin_with_timestamps("gamma", None, None),
+        plugin_with_timestamps("delta", Some("2025-12-01T00:00:00Z"), None),
+    ];
+
+    assert_eq!(
+        sorted_names(plugins.clone(), ListSort::UpdatedAt),
+        vec!["beta", "alpha", "delta", "gamma"]
+    );
+    assert_eq!(
+        sorted_names(plugins.clone(), ListSort::InstalledAt),
+        vec!["alpha", "delta", "beta", "gamma"]
+    );
+    assert_eq!(
+        sorted_names(plugins, ListSort::Name),
+        vec!["alpha", "beta", "delta", "gamma"]
+    );
+}
+
+#[test]
+fn test_format_last_updated_is_relative() {
+    let now = chrono::DateTime::parse_from_rfc3339("2026-02-04T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let plugin = plugin_with_timestamps(
+        "a",
+        Some("2026-01-01T00:00:00Z"),
+        Some("2026-02-01T00:00:00Z"),
+    );
+
+    assert_eq!(format_last_updated(&plugin, now), "3 days ago");
+    assert_eq!(format_last_updated(&create_empty_plugin("b"), now), "-");
+}
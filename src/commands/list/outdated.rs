@@ -53,7 +53,7 @@ pub(super) async fn run_outdated(
         })
         .collect();
 
-    let factory = HostClientFactory::with_defaults();
+    let factory = HostClientFactory::from_env().map_err(|e| e.to_string())?;
     let client = factory.create(HostKind::GitHub);
     let remote_versions = fetch_remote_versions(&plugin_metas, client.as_ref()).await;
 
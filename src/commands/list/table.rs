@@ -1,17 +1,20 @@
 //! テーブル出力フォーマット
 
+use crate::application::PluginTotals;
+use crate::output::time::relative_timestamp;
 use crate::plugin::InstalledPlugin;
+use chrono::{DateTime, Utc};
 use comfy_table::{presets::UTF8_FULL, Table};
 
-/// Prints installed plugins as a formatted table.
+/// Prints installed plugins as a formatted table followed by the totals footer.
 ///
 /// # Arguments
 ///
 /// * `plugins` - Installed plugins to render.
-/// * `total_count` - Total number of installed plugins (for empty-state messages).
-pub(super) fn print_table(plugins: &[InstalledPlugin], total_count: usize) {
+/// * `totals` - Totals over all installed plugins (before filtering).
+pub(super) fn print_table(plugins: &[InstalledPlugin], totals: &PluginTotals) {
     if plugins.is_empty() {
-        super::print_empty_list(total_count);
+        super::print_empty_list(totals.plugins);
         return;
     }
 
@@ -24,10 +27,12 @@ pub(super) fn print_table(plugins: &[InstalledPlugin], total_count: usize) {
             "Components",
             "Status",
             "Marketplace",
+            "Last Updated",
         ])
         .add_rows(plugins.iter().map(plugin_row));
 
     println!("{table}");
+    println!("{}", totals.footer(plugins.len()));
 }
 
 /// Builds a table row for a single installed plugin.
@@ -37,24 +42,49 @@ pub(super) fn print_table(plugins: &[InstalledPlugin], total_count: usize) {
 /// * `plugin` - Plugin to render as a row.
 fn plugin_row(plugin: &InstalledPlugin) -> Vec<String> {
     vec![
-        plugin.name().to_string(),
-        plugin.version().to_string(),
+        display_name(plugin),
+        format_version(plugin),
         format_components(plugin),
-        status_label(plugin.enabled()).to_string(),
+        plugin.status_label(),
         plugin.marketplace().unwrap_or("-").to_string(),
+        format_last_updated(plugin, Utc::now()),
     ]
 }
 
-/// Returns the human-readable status label for a plugin.
+/// Formats when the plugin was last updated (or installed) relative to `now`.
 ///
 /// # Arguments
 ///
-/// * `enabled` - Whether the plugin is enabled.
-fn status_label(enabled: bool) -> &'static str {
-    if enabled {
-        "enabled"
-    } else {
-        "disabled"
+/// * `plugin` - Plugin to render.
+/// * `now` - Current time.
+pub(super) fn format_last_updated(plugin: &InstalledPlugin, now: DateTime<Utc>) -> String {
+    plugin
+        .last_updated()
+        .map(|at| relative_timestamp(at, now))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Returns the plugin name, marked with `(sandbox)` when it has a trial deployment.
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin to render.
+pub(super) fn display_name(plugin: &InstalledPlugin) -> String {
+    match plugin.sandbox() {
+        Some(_) => format!("{} (sandbox)", plugin.name()),
+        None => plugin.name().to_string(),
+    }
+}
+
+/// Returns the plugin version, followed by its update channel when one is recorded.
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin to render.
+pub(super) fn format_version(plugin: &InstalledPlugin) -> String {
+    match plugin.channel() {
+        Some(channel) => format!("{} ({})", plugin.version(), channel),
+        None => plugin.version().to_string(),
     }
 }
 
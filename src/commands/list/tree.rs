@@ -0,0 +1,139 @@
+//! ツリー出力フォーマット（`plm list --tree`）
+//!
+//! プラグインをルート、コンポーネント種別を中間ノード、コンポーネント名を葉とする。
+
+use super::table::{display_name, format_version};
+use crate::application::PluginTotals;
+use crate::component::ComponentKind;
+use crate::output::stdout_color_enabled;
+use crate::output::tree::{render_tree, TreeEntry};
+use crate::plugin::InstalledPlugin;
+use owo_colors::OwoColorize;
+
+/// ツリーの表示オプション
+#[derive(Debug, Clone, Copy)]
+pub(super) struct TreeOptions {
+    /// 表示するコンポーネント種別（`None` なら全種別）
+    pub(super) kind: Option<ComponentKind>,
+    /// `1` なら種別ごとの件数だけを表示する
+    pub(super) depth: Option<u8>,
+    /// ANSI カラーを使うか
+    pub(super) color: bool,
+}
+
+/// Prints installed plugins as component trees followed by the totals footer.
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to render.
+/// * `totals` - Totals over all installed plugins (before filtering).
+/// * `kind` - Component kind filter.
+/// * `depth` - Tree depth (`1` collapses each kind to its count).
+pub(super) fn print_tree(
+    plugins: &[InstalledPlugin],
+    totals: &PluginTotals,
+    kind: Option<ComponentKind>,
+    depth: Option<u8>,
+) {
+    if plugins.is_empty() {
+        super::print_empty_list(totals.plugins);
+        return;
+    }
+
+    let options = TreeOptions {
+        kind,
+        depth,
+        color: stdout_color_enabled(),
+    };
+    let trees: Vec<String> = plugins
+        .iter()
+        .map(|p| render_plugin_tree(p, options))
+        .collect();
+    println!("{}", trees.join("\n"));
+    println!("{}", totals.footer(plugins.len()));
+}
+
+/// 1 プラグイン分のツリーを組み立てる
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin to render as the tree root.
+/// * `options` - Kind filter, depth and color settings.
+pub(super) fn render_plugin_tree(plugin: &InstalledPlugin, options: TreeOptions) -> String {
+    let collapsed = options.depth == Some(1);
+    let mut entries = Vec::new();
+
+    for &kind in ComponentKind::all() {
+        if options.kind.is_some_and(|k| k != kind) {
+            continue;
+        }
+        let mut names: Vec<&str> = plugin
+            .components()
+            .iter()
+            .filter(|c| c.kind == kind)
+            .map(|c| c.name.as_str())
+            .collect();
+        if names.is_empty() {
+            continue;
+        }
+        names.sort_unstable();
+
+        let label = if collapsed {
+            format!("{} ({})", kind.plural(), names.len())
+        } else {
+            kind.plural().to_string()
+        };
+        entries.push(TreeEntry::new(1, paint_kind(&label, options.color)));
+        if !collapsed {
+            entries.extend(names.into_iter().map(|name| TreeEntry::new(2, name)));
+        }
+    }
+
+    render_tree(&root_label(plugin, options.color), &entries)
+}
+
+/// ルート行（名前・バージョン・マーケットプレイス・無効表示）
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin to label.
+/// * `color` - Whether to emphasize the name with ANSI colors.
+fn root_label(plugin: &InstalledPlugin, color: bool) -> String {
+    let name = display_name(plugin);
+    let mut label = if color { name.bold().to_string() } else { name };
+    label.push(' ');
+    label.push_str(&format_version(plugin));
+    if let Some(marketplace) = plugin.marketplace() {
+        label.push_str(" @ ");
+        label.push_str(marketplace);
+    }
+    if !plugin.enabled() {
+        let status = format!("({})", plugin.status_label());
+        let disabled = if color {
+            status.dimmed().to_string()
+        } else {
+            status
+        };
+        label.push(' ');
+        label.push_str(&disabled);
+    }
+    label
+}
+
+/// 種別ノードのラベルに色を付ける
+///
+/// # Arguments
+///
+/// * `label` - Kind label such as `commands` or `skills (2)`.
+/// * `color` - Whether to apply ANSI colors.
+fn paint_kind(label: &str, color: bool) -> String {
+    if color {
+        label.cyan().to_string()
+    } else {
+        label.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "tree_test.rs"]
+mod tests;
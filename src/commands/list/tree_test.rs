@@ -0,0 +1,92 @@
+use super::*;
+use crate::component::Component;
+use std::path::PathBuf;
+
+fn comp(kind: ComponentKind, name: &str) -> Component {
+    Component::new(kind, name, PathBuf::from(format!("dummy/{}", name)))
+}
+
+fn sample_plugin(enabled: bool) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(
+        "formatter",
+        "1.2.0",
+        vec![
+            comp(ComponentKind::Command, "format"),
+            comp(ComponentKind::Skill, "fmt"),
+            comp(ComponentKind::Command, "deploy"),
+        ],
+        None,
+        Some("company-tools".to_string()),
+        enabled,
+    )
+}
+
+fn options(kind: Option<ComponentKind>, depth: Option<u8>) -> TreeOptions {
+    TreeOptions {
+        kind,
+        depth,
+        color: false,
+    }
+}
+
+#[test]
+fn render_plugin_tree_groups_components_by_kind() {
+    let out = render_plugin_tree(&sample_plugin(true), options(None, None));
+
+    let expected = "\
+formatter 1.2.0 @ company-tools
+├── skills
+│   └── fmt
+└── commands
+    ├── deploy
+    └── format
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_plugin_tree_depth_1_collapses_to_counts() {
+    let out = render_plugin_tree(&sample_plugin(false), options(None, Some(1)));
+
+    let expected = "\
+formatter 1.2.0 @ company-tools (disabled)
+├── skills (1)
+└── commands (2)
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_plugin_tree_shows_only_requested_kind() {
+    let out = render_plugin_tree(
+        &sample_plugin(true),
+        options(Some(ComponentKind::Command), None),
+    );
+
+    assert!(!out.contains("skills"), "{}", out);
+    assert!(out.ends_with("└── commands\n    ├── deploy\n    └── format\n"));
+}
+
+#[test]
+fn render_plugin_tree_without_components_prints_root_only() {
+    let plugin = InstalledPlugin::new_for_test("empty", "0.1.0", Vec::new(), None, None, true);
+
+    assert_eq!(
+        render_plugin_tree(&plugin, options(None, None)),
+        "empty 0.1.0\n"
+    );
+}
+
+#[test]
+fn render_plugin_tree_with_color_keeps_structure() {
+    let out = render_plugin_tree(
+        &sample_plugin(true),
+        TreeOptions {
+            color: true,
+            ..options(None, None)
+        },
+    );
+
+    assert!(out.contains('\u{1b}'), "{:?}", out);
+    assert!(out.contains("│   └── fmt\n"), "{:?}", out);
+}
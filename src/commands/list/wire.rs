@@ -2,9 +2,12 @@
 //!
 //! `InstalledPlugin` を serde serialize 用の表現に変換する。
 //! 素の `list --json` と `list --outdated --json` で構造を共有し、
-//! キー名の意図しないドリフトを防ぐ。
+//! キー名の意図しないドリフトを防ぐ。`list --format json|yaml` は
+//! 件数だけを持つ簡易エントリ（[`EntryWire`]）の配列を JSON / YAML で共有する。
 
+use crate::application::PluginTotals;
 use crate::component::{Component, ComponentKind};
+use crate::marketplace::DEFAULT_MARKETPLACE;
 use crate::plugin::{InstalledPlugin, UpgradeState};
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
@@ -18,6 +21,18 @@ pub(super) struct Wire<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) marketplace: Option<&'a str>,
     pub(super) enabled: bool,
+    /// 部分 disable（`plm disable --keep`）で配置を残している種別（部分 disable 中でなければ省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) kept_kinds: Option<&'a [ComponentKind]>,
+    /// `plm install --sandbox` の隔離デプロイがあるか（無い場合は省略）
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub(super) sandbox: bool,
+    /// 追従している更新チャンネル（未指定なら省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) channel: Option<&'static str>,
+    /// 最後にインストール・更新した日時（RFC3339。記録が無ければ省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) last_updated_at: Option<&'a str>,
     pub(super) components: ComponentsWire<'a>,
 }
 
@@ -34,6 +49,10 @@ impl<'a> Wire<'a> {
             id: plugin.id(),
             marketplace: plugin.marketplace(),
             enabled: plugin.enabled(),
+            kept_kinds: plugin.kept_kinds(),
+            sandbox: plugin.sandbox().is_some(),
+            channel: plugin.channel().map(|c| c.as_str()),
+            last_updated_at: plugin.last_updated(),
             components: ComponentsWire(plugin.components()),
         }
     }
@@ -66,6 +85,113 @@ impl Serialize for ComponentsWire<'_> {
     }
 }
 
+/// `list --json` のトップレベル（一覧 + 集計）
+#[derive(Serialize)]
+pub(super) struct ListWire<'a> {
+    pub(super) plugins: Vec<Wire<'a>>,
+    pub(super) summary: SummaryWire<'a>,
+}
+
+/// 集計オブジェクト（`summary`）
+#[derive(Serialize)]
+pub(super) struct SummaryWire<'a> {
+    pub(super) plugins: usize,
+    pub(super) enabled: usize,
+    pub(super) disabled: usize,
+    pub(super) components: usize,
+    pub(super) components_by_kind: KindCountsWire<'a>,
+    /// フィルタ適用後に出力したプラグイン数
+    pub(super) shown: usize,
+}
+
+impl<'a> SummaryWire<'a> {
+    /// Builds a `SummaryWire` from aggregated totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals` - Totals over all installed plugins.
+    /// * `shown` - Number of plugins included in the output.
+    pub(super) fn from_totals(totals: &'a PluginTotals, shown: usize) -> Self {
+        Self {
+            plugins: totals.plugins,
+            enabled: totals.enabled,
+            disabled: totals.disabled,
+            components: totals.components,
+            components_by_kind: KindCountsWire(&totals.by_kind),
+            shown,
+        }
+    }
+}
+
+/// 種別ごとのコンポーネント数（種別の複数形をキーにした JSON オブジェクト）
+///
+/// `list --json` の `summary` と `plm stats --json` で共有する。
+pub(crate) struct KindCountsWire<'a>(pub(crate) &'a [(ComponentKind, usize)]);
+
+impl Serialize for KindCountsWire<'_> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (kind, count) in self.0 {
+            map.serialize_entry(kind.plural(), count)?;
+        }
+        map.end()
+    }
+}
+
+/// `list --format json|yaml` の 1 エントリ
+#[derive(Serialize)]
+pub(super) struct EntryWire<'a> {
+    pub(super) name: &'a str,
+    /// インストール元のマーケットプレイス（直接インストールは `github`）
+    pub(super) marketplace: &'a str,
+    pub(super) version: &'a str,
+    pub(super) enabled: bool,
+    pub(super) components: ComponentCountsWire,
+}
+
+impl<'a> EntryWire<'a> {
+    /// Builds an `EntryWire` borrowing fields from an installed plugin.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - Installed plugin to borrow from.
+    pub(super) fn from_installed(plugin: &'a InstalledPlugin) -> Self {
+        let count = |kind: ComponentKind| {
+            plugin
+                .components()
+                .iter()
+                .filter(|c| c.kind == kind)
+                .count()
+        };
+        Self {
+            name: plugin.name(),
+            marketplace: plugin.marketplace().unwrap_or(DEFAULT_MARKETPLACE),
+            version: plugin.version(),
+            enabled: plugin.enabled(),
+            components: ComponentCountsWire {
+                skills: count(ComponentKind::Skill),
+                agents: count(ComponentKind::Agent),
+                commands: count(ComponentKind::Command),
+                instructions: count(ComponentKind::Instruction),
+                hooks: count(ComponentKind::Hook),
+            },
+        }
+    }
+}
+
+/// 種別ごとのコンポーネント数
+#[derive(Serialize)]
+pub(super) struct ComponentCountsWire {
+    pub(super) skills: usize,
+    pub(super) agents: usize,
+    pub(super) commands: usize,
+    pub(super) instructions: usize,
+    pub(super) hooks: usize,
+}
+
 #[derive(Serialize)]
 pub(super) struct OutdatedWire<'a> {
     pub(super) plugin: Wire<'a>,
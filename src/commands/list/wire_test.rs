@@ -119,6 +119,32 @@ fn wire_skips_marketplace_key_when_none() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn wire_includes_sandbox_flag_only_when_sandboxed() {
+    let plugin = InstalledPlugin::new_for_test("trial", "0.1.0", Vec::new(), None, None, false)
+        .with_sandbox(Some(crate::plugin::SandboxInfo {
+            path: "/project/.plm/sandbox/trial".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            targets: vec!["codex".to_string()],
+        }));
+    let json = serde_json::to_value(Wire::from_installed(&plugin)).unwrap();
+    assert_eq!(json["sandbox"], serde_json::json!(true));
+
+    let json = serde_json::to_value(Wire::from_installed(&snapshot_plugin_full())).unwrap();
+    assert!(json.get("sandbox").is_none());
+}
+
+#[test]
+fn wire_includes_channel_only_when_recorded() {
+    let plugin = InstalledPlugin::new_for_test("fmt", "2.0.0-beta.1", Vec::new(), None, None, true)
+        .with_channel(Some(crate::plugin::Channel::Beta));
+    let json = serde_json::to_value(Wire::from_installed(&plugin)).unwrap();
+    assert_eq!(json["channel"], serde_json::json!("beta"));
+
+    let json = serde_json::to_value(Wire::from_installed(&snapshot_plugin_full())).unwrap();
+    assert!(json.get("channel").is_none());
+}
+
 #[test]
 fn wire_components_nested_shape() {
     let plugin = snapshot_plugin_full();
@@ -138,3 +164,31 @@ fn wire_components_nested_shape() {
     assert_eq!(components["hooks"], serde_json::json!(["hook-a"]));
     assert!(json.get("skills").is_none());
 }
+
+#[test]
+fn entry_wire_snapshot_counts_components() {
+    let plugin = snapshot_plugin_full();
+    let actual = serde_json::to_string_pretty(&EntryWire::from_installed(&plugin)).unwrap();
+    let expected = r#"{
+  "name": "my-plugin",
+  "marketplace": "github",
+  "version": "1.2.3",
+  "enabled": true,
+  "components": {
+    "skills": 2,
+    "agents": 1,
+    "commands": 1,
+    "instructions": 1,
+    "hooks": 1
+  }
+}"#;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn entry_wire_reports_github_for_direct_installs() {
+    let plugin = InstalledPlugin::new_for_test("direct", "0.1.0", vec![], None, None, false);
+    let json = serde_json::to_value(EntryWire::from_installed(&plugin)).unwrap();
+    assert_eq!(json["marketplace"], serde_json::json!("github"));
+    assert_eq!(json["enabled"], serde_json::json!(false));
+}
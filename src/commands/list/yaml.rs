@@ -0,0 +1,25 @@
+//! YAML 出力フォーマット
+
+use super::wire::EntryWire;
+use crate::plugin::InstalledPlugin;
+
+/// Serializes installed plugins as a YAML sequence of entries (`list --format yaml`).
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to serialize.
+pub(super) fn render_entries(plugins: &[InstalledPlugin]) -> Result<String, String> {
+    let entries: Vec<EntryWire> = plugins.iter().map(EntryWire::from_installed).collect();
+    serde_yaml::to_string(&entries).map_err(|e| format!("Failed to serialize to YAML: {}", e))
+}
+
+/// Prints installed plugins as a YAML sequence of entries to stdout.
+///
+/// # Arguments
+///
+/// * `plugins` - Installed plugins to print.
+pub(super) fn print_entries(plugins: &[InstalledPlugin]) -> Result<(), String> {
+    let s = render_entries(plugins)?;
+    print!("{s}");
+    Ok(())
+}
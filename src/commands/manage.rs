@@ -1,9 +1,21 @@
 //! plm の管理系コマンド集約モジュール。
 //!
-//! `init` / `pack` / `target` / `marketplace` / `managed` を束ねる。
+//! `init` / `pack` / `backups` / `cache` / `hooks` / `target` / `marketplace` / `managed` / `pick` / `env` / `stats` / `placements` / `status` / `gitignore` / `doctor` / `verify-conversions` / `test` を束ねる。
 
+pub mod backups;
+pub mod cache;
+pub mod doctor;
+pub mod env;
+pub mod gitignore;
+pub mod hooks;
 pub mod init;
 pub mod managed;
 pub mod marketplace;
 pub mod pack;
+pub mod pick;
+pub mod placements;
+pub mod stats;
+pub mod status;
 pub mod target;
+pub mod test_plugin;
+pub mod verify_conversions;
@@ -0,0 +1,128 @@
+//! plm backups コマンド
+//!
+//! `--backup` 付きの disable / uninstall / update / sync が作ったスナップショットを
+//! 一覧し、元の場所へ復元する。
+
+use crate::backup::{BackupManifest, BackupStore};
+use crate::commands::examples::Example;
+use clap::{Parser, Subcommand};
+use std::io::{self, IsTerminal, Write};
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List backups (oldest first)
+    List,
+
+    /// Write the files of a backup back to their original locations
+    Restore {
+        /// Backup ID shown by `plm backups list`
+        id: String,
+
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
+/// `plm backups` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm backups list",
+        description: "List snapshots taken with --backup",
+    },
+    Example {
+        command: "plm backups restore 20261016T120000Z-uninstall",
+        description: "Restore the files saved before an uninstall",
+    },
+    Example {
+        command: "plm backups restore 20261016T120000Z-uninstall --yes",
+        description: "Restore without asking (for scripts)",
+    },
+];
+
+/// 一覧の 1 行
+///
+/// # Arguments
+///
+/// * `manifest` - Backup metadata.
+pub(crate) fn format_backup(manifest: &BackupManifest) -> String {
+    format!(
+        "  {}  {} file(s)  {}",
+        manifest.id,
+        manifest.files.len(),
+        manifest.description
+    )
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm backups`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let store = BackupStore::new().map_err(|e| format!("Failed to access backups: {}", e))?;
+
+    match args.command {
+        Command::List => {
+            let backups = store.list().map_err(|e| e.to_string())?;
+            if backups.is_empty() {
+                println!("No backups. Use --backup with disable, uninstall, update or sync.");
+                return Ok(());
+            }
+            println!("Backups ({}):", backups.len());
+            for manifest in &backups {
+                println!("{}", format_backup(manifest));
+            }
+            Ok(())
+        }
+        Command::Restore { id, yes } => {
+            let manifest = store.get(&id).map_err(|e| e.to_string())?;
+            println!("{}", format_backup(&manifest));
+            for file in &manifest.files {
+                println!("    {}", file.path.display());
+            }
+
+            if !yes {
+                if !io::stdin().is_terminal() {
+                    return Err(
+                        "Refusing to overwrite files without confirmation. Use --yes to restore."
+                            .to_string(),
+                    );
+                }
+                if !confirm_restore(manifest.files.len())? {
+                    println!("Cancelled.");
+                    return Ok(());
+                }
+            }
+
+            let restored = store.restore(&id).map_err(|e| e.to_string())?;
+            println!("Restored {} file(s) from {}.", restored.len(), id);
+            Ok(())
+        }
+    }
+}
+
+/// ユーザーに復元の確認を求める
+///
+/// # Arguments
+///
+/// * `count` - Number of files to write.
+fn confirm_restore(count: usize) -> Result<bool, String> {
+    print!("Overwrite {} file(s) with the backup? [y/N]: ", count);
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
+#[cfg(test)]
+#[path = "backups_test.rs"]
+mod tests;
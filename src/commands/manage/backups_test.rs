@@ -0,0 +1,22 @@
+use super::*;
+use crate::backup::BackupFile;
+use std::path::PathBuf;
+
+#[test]
+fn format_backup_shows_id_file_count_and_description() {
+    let manifest = BackupManifest {
+        id: "20261016T120000Z-uninstall".to_string(),
+        operation: "uninstall".to_string(),
+        description: "plm uninstall formatter".to_string(),
+        created_at: "2026-10-16T12:00:00.000000Z".to_string(),
+        files: vec![BackupFile {
+            path: PathBuf::from("/work/app/.codex/skills/formatter/SKILL.md"),
+            entry: "files/000000".to_string(),
+        }],
+    };
+
+    assert_eq!(
+        format_backup(&manifest),
+        "  20261016T120000Z-uninstall  1 file(s)  plm uninstall formatter"
+    );
+}
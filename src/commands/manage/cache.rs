@@ -0,0 +1,63 @@
+//! plm cache コマンド
+//!
+//! プラグインキャッシュの保守操作。現在は一覧用インデックス（`plugins-index.json`）の
+//! 手動再構築のみを提供する。
+
+use crate::commands::examples::Example;
+use crate::plugin::{rebuild_index, PackageCache, PackageCacheAccess};
+use clap::{Parser, Subcommand};
+use std::path::Path;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Rescan every cached plugin and rewrite the plugin index
+    RebuildIndex,
+}
+
+/// `plm cache` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[Example {
+    command: "plm cache rebuild-index",
+    description: "Rescan all cached plugins and rewrite plugins-index.json",
+}];
+
+/// 再構築結果の 1 行
+///
+/// # Arguments
+///
+/// * `count` - Number of plugins written to the index.
+/// * `path` - Path of the rewritten index file.
+pub(crate) fn format_rebuilt(count: usize, path: &Path) -> String {
+    format!(
+        "Rebuilt plugin index: {} plugin(s) ({})",
+        count,
+        path.display()
+    )
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm cache`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+
+    match args.command {
+        Command::RebuildIndex => {
+            let count = rebuild_index(&cache)
+                .map_err(|e| format!("Failed to rebuild plugin index: {}", e))?;
+            if let Some(path) = cache.index_path() {
+                println!("{}", format_rebuilt(count, &path));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cache_test.rs"]
+mod tests;
@@ -0,0 +1,12 @@
+use super::*;
+use std::path::PathBuf;
+
+#[test]
+fn format_rebuilt_shows_plugin_count_and_index_path() {
+    let path = PathBuf::from("/home/u/.plm/cache/plugins/plugins-index.json");
+
+    assert_eq!(
+        format_rebuilt(3, &path),
+        "Rebuilt plugin index: 3 plugin(s) (/home/u/.plm/cache/plugins/plugins-index.json)"
+    );
+}
@@ -0,0 +1,413 @@
+//! plm doctor コマンド
+//!
+//! キャッシュ・配置先・マーケットプレイス登録の整合性を点検し、問題を重大度付きで一覧表示する。
+//!
+//! - plugin.json を読めないキャッシュ内のプラグイン（error）
+//! - 配置記録にあるのにプロジェクトから消えている配置物（error）
+//! - 孤立ファイル: plm が配置したが、所有するプラグインがキャッシュから消えたファイル（warning）
+//! - 登録済みマーケットプレイスとマーケットプレイスキャッシュの食い違い（warning / 読めなければ error）
+//!
+//! error が 1 件でもあれば exit 1 で終了する。`--fix` は確認の上で孤立ファイルを削除し、
+//! 消えている配置物をキャッシュから再デプロイする。
+
+use crate::application::{
+    check_marketplace_caches, find_broken_manifests, find_missing_files, find_orphans,
+    remove_orphans, BrokenManifest, CheckStatus, MarketplaceIssue, MissingFile, OrphanedFile,
+};
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::component::ComponentKind;
+use crate::marketplace::{MarketplaceConfig, MarketplaceRegistry};
+use crate::plugin::{
+    redeploy_plugin, PackageCache, PlacementLedger, RedeployOptions, UpdateOutcome, UpdateStatus,
+};
+use clap::Parser;
+use std::collections::BTreeSet;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+
+#[derive(Debug, Parser)]
+#[command(group(clap::ArgGroup::new("fix_mode").args(["fix", "fix_orphans"])))]
+pub struct Args {
+    /// Remove orphaned files and redeploy missing components after confirmation
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Remove orphaned files after confirmation
+    #[arg(long)]
+    pub fix_orphans: bool,
+
+    /// Skip the confirmation prompt (with --fix or --fix-orphans)
+    #[arg(long, short = 'y', requires = "fix_mode")]
+    pub yes: bool,
+}
+
+/// `plm doctor` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm doctor",
+        description: "Check the cache, deployed files and marketplaces for problems",
+    },
+    Example {
+        command: "plm doctor --fix",
+        description: "Remove orphaned files and redeploy missing components after confirmation",
+    },
+    Example {
+        command: "plm doctor --fix-orphans --yes",
+        description: "Only remove orphaned files, without asking (for scripts)",
+    },
+];
+
+/// 診断結果
+#[derive(Debug, Default)]
+pub(crate) struct DoctorReport {
+    /// plugin.json を読めないプラグイン
+    pub broken_manifests: Vec<BrokenManifest>,
+    /// プロジェクトから消えている配置物
+    pub missing: Vec<MissingFile>,
+    /// 孤立ファイル
+    pub orphans: Vec<OrphanedFile>,
+    /// マーケットプレイスの登録とキャッシュの食い違い
+    pub marketplaces: Vec<MarketplaceIssue>,
+}
+
+impl DoctorReport {
+    /// 重大度付きの問題一覧（error → warning の順）
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root used to shorten paths.
+    pub(crate) fn problems(&self, project_root: &Path) -> Vec<(CheckStatus, String)> {
+        let mut problems = Vec::new();
+        for broken in &self.broken_manifests {
+            problems.push((
+                CheckStatus::Error,
+                format!("manifest: {}: {}", broken.plugin, broken.message),
+            ));
+        }
+        for missing in &self.missing {
+            problems.push((
+                CheckStatus::Error,
+                format!(
+                    "missing: {}",
+                    describe_placement(
+                        &missing.path,
+                        &missing.target,
+                        missing.kind,
+                        &missing.owner(),
+                        project_root
+                    )
+                ),
+            ));
+        }
+        for orphan in &self.orphans {
+            problems.push((
+                CheckStatus::Warning,
+                format!("orphaned: {}", format_orphan(orphan, project_root)),
+            ));
+        }
+        for issue in &self.marketplaces {
+            problems.push(match issue {
+                MarketplaceIssue::NotCached(name) => (
+                    CheckStatus::Warning,
+                    format!(
+                        "marketplace: '{}' is registered but not cached (run 'plm marketplace update {}')",
+                        name, name
+                    ),
+                ),
+                MarketplaceIssue::Unreadable { name, message } => (
+                    CheckStatus::Error,
+                    format!("marketplace: cache of '{}' cannot be read: {}", name, message),
+                ),
+                MarketplaceIssue::Unregistered(name) => (
+                    CheckStatus::Warning,
+                    format!("marketplace: '{}' is cached but no longer registered", name),
+                ),
+            });
+        }
+        problems.sort_by_key(|(status, _)| *status != CheckStatus::Error);
+        problems
+    }
+
+    /// 指定した重大度の問題数
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Severity to count.
+    pub(crate) fn count(&self, status: CheckStatus) -> usize {
+        self.problems(Path::new(""))
+            .iter()
+            .filter(|(s, _)| *s == status)
+            .count()
+    }
+
+    /// `--fix` で直せる問題があるか
+    pub(crate) fn is_fixable(&self) -> bool {
+        !self.orphans.is_empty() || !self.missing.is_empty()
+    }
+}
+
+/// 配置物 1 件の表示（プロジェクトルートからの相対パスと所有者）
+///
+/// # Arguments
+///
+/// * `path` - Destination path.
+/// * `target` - Target name.
+/// * `kind` - Component kind.
+/// * `owner` - Owning plugin (`plugin@marketplace`).
+/// * `project_root` - Project root used to shorten the path.
+fn describe_placement(
+    path: &Path,
+    target: &str,
+    kind: ComponentKind,
+    owner: &str,
+    project_root: &Path,
+) -> String {
+    let path = path.strip_prefix(project_root).unwrap_or(path);
+    format!("{} ({} {}, from {})", path.display(), target, kind, owner)
+}
+
+/// 孤立ファイル 1 件の表示
+///
+/// # Arguments
+///
+/// * `orphan` - Orphaned file.
+/// * `project_root` - Project root used to shorten the path.
+pub(crate) fn format_orphan(orphan: &OrphanedFile, project_root: &Path) -> String {
+    describe_placement(
+        &orphan.path,
+        &orphan.target,
+        orphan.kind,
+        &orphan.owner,
+        project_root,
+    )
+}
+
+/// 診断結果を問題一覧と集計にする
+///
+/// # Arguments
+///
+/// * `report` - Diagnosis result.
+/// * `project_root` - Project root used to shorten paths.
+pub(crate) fn render_text(report: &DoctorReport, project_root: &Path) -> String {
+    let problems = report.problems(project_root);
+    if problems.is_empty() {
+        return "No problems found.".to_string();
+    }
+
+    let lines: Vec<String> = problems
+        .iter()
+        .map(|(status, message)| format!("  [{}] {}", status.as_str(), message))
+        .collect();
+    let mut sections = vec![
+        format!("Problems:\n{}", lines.join("\n")),
+        format!(
+            "Summary: {} error(s), {} warning(s)",
+            report.count(CheckStatus::Error),
+            report.count(CheckStatus::Warning)
+        ),
+    ];
+    if report.is_fixable() {
+        sections.push(
+            "Run 'plm doctor --fix' to remove orphaned files and redeploy missing components."
+                .to_string(),
+        );
+    }
+    sections.join("\n\n")
+}
+
+/// 全項目を点検する
+///
+/// 既に存在しない配置物の記録は孤立ファイルの検出前に黙って掃除する。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `ledger` - Placement records (pruned in place).
+/// * `project_root` - Project root to check.
+fn diagnose(
+    cache: &PackageCache,
+    ledger: &mut PlacementLedger,
+    project_root: &Path,
+) -> Result<DoctorReport, String> {
+    if ledger.prune_missing() > 0 {
+        ledger
+            .save()
+            .map_err(|e| format!("Failed to update placements: {}", e))?;
+    }
+
+    let registered: Vec<String> = MarketplaceConfig::load()
+        .map_err(|e| format!("Failed to read marketplaces: {}", e))?
+        .list()
+        .iter()
+        .map(|m| m.name.clone())
+        .collect();
+    let registry = MarketplaceRegistry::new()
+        .map_err(|e| format!("Failed to access marketplace cache: {}", e))?;
+
+    Ok(DoctorReport {
+        broken_manifests: find_broken_manifests(cache),
+        missing: find_missing_files(cache, project_root),
+        orphans: find_orphans(cache, ledger, project_root),
+        marketplaces: check_marketplace_caches(&registered, &registry),
+    })
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm doctor`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let project_root = ctx.project_root.clone();
+    let mut ledger =
+        PlacementLedger::load().map_err(|e| format!("Failed to read placements: {}", e))?;
+
+    let mut report = diagnose(&cache, &mut ledger, &project_root)?;
+    println!("{}", render_text(&report, &project_root));
+
+    // --fix-orphans は孤立ファイルの削除だけを行う
+    let missing: &[MissingFile] = if args.fix { &report.missing } else { &[] };
+    if (args.fix || args.fix_orphans) && !(report.orphans.is_empty() && missing.is_empty()) {
+        let failures = fix(
+            &cache,
+            &mut ledger,
+            &report.orphans,
+            missing,
+            &project_root,
+            args.yes,
+        )?;
+        if failures > 0 {
+            return Err(format!("Failed to fix {} problem(s)", failures));
+        }
+        if args.fix {
+            report.missing = find_missing_files(&cache, &project_root);
+        }
+    }
+
+    let errors = report.count(CheckStatus::Error);
+    if errors > 0 {
+        return Err(format!("plm doctor found {} error(s)", errors));
+    }
+    Ok(())
+}
+
+/// 孤立ファイルを削除し、消えている配置物を再デプロイする
+///
+/// 戻り値は直せなかった問題の件数。確認で取り消した場合は 0。
+/// 再デプロイは `plm update --redeploy-only` と同じく、記録された種別・部分 disable に従う。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache.
+/// * `ledger` - Placement records to update.
+/// * `orphans` - Orphaned files to remove.
+/// * `missing` - Missing placements to redeploy.
+/// * `project_root` - Project root to redeploy into.
+/// * `yes` - Skip the confirmation prompt.
+fn fix(
+    cache: &PackageCache,
+    ledger: &mut PlacementLedger,
+    orphans: &[OrphanedFile],
+    missing: &[MissingFile],
+    project_root: &Path,
+    yes: bool,
+) -> Result<usize, String> {
+    // 同じプラグイン・ターゲットの欠落はまとめて 1 回で再デプロイする
+    let redeploys: BTreeSet<(Option<String>, String, String)> = missing
+        .iter()
+        .map(|m| (m.marketplace.clone(), m.plugin.clone(), m.target.clone()))
+        .collect();
+
+    if !yes {
+        if !io::stdin().is_terminal() {
+            return Err(
+                "Refusing to change files without confirmation. Use --yes to proceed.".to_string(),
+            );
+        }
+        if !confirm_fix(orphans.len(), redeploys.len())? {
+            println!("Cancelled.");
+            return Ok(0);
+        }
+    }
+
+    let mut failures = 0;
+    if !orphans.is_empty() {
+        let removal = remove_orphans(ledger, orphans);
+        ledger
+            .save()
+            .map_err(|e| format!("Failed to update placements: {}", e))?;
+        println!("\nRemoved {} orphaned file(s).", removal.removed.len());
+        for (path, reason) in &removal.failed {
+            eprintln!("  x {} - {}", path.display(), reason);
+        }
+        failures += removal.failed.len();
+    }
+
+    for (marketplace, plugin, target) in &redeploys {
+        let options = RedeployOptions {
+            target_filter: Some(target.as_str()),
+            only: None,
+        };
+        let outcome = redeploy_plugin(cache, plugin, marketplace.as_deref(), project_root, options);
+        let label = match marketplace {
+            Some(marketplace) => format!("{}@{}", plugin, marketplace),
+            None => plugin.clone(),
+        };
+        match redeploy_error(&outcome) {
+            None => println!("Redeployed {} ({})", label, target),
+            Some(reason) => {
+                eprintln!("  x {} ({}) - {}", label, target, reason);
+                failures += 1;
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// 再デプロイが失敗した場合はその理由
+///
+/// # Arguments
+///
+/// * `outcome` - Result of `redeploy_plugin`.
+fn redeploy_error(outcome: &UpdateOutcome) -> Option<String> {
+    match &outcome.status {
+        UpdateStatus::Redeployed if outcome.failed_targets.is_empty() => None,
+        UpdateStatus::Skipped { reason } => Some(reason.clone()),
+        _ => Some(
+            outcome
+                .error
+                .clone()
+                .unwrap_or_else(|| "redeploy failed".to_string()),
+        ),
+    }
+}
+
+/// ユーザーに修正の確認を求める
+///
+/// # Arguments
+///
+/// * `orphans` - Number of orphaned files to remove.
+/// * `redeploys` - Number of plugin/target pairs to redeploy.
+fn confirm_fix(orphans: usize, redeploys: usize) -> Result<bool, String> {
+    let mut actions = Vec::new();
+    if orphans > 0 {
+        actions.push(format!("remove {} orphaned file(s)", orphans));
+    }
+    if redeploys > 0 {
+        actions.push(format!("redeploy {} plugin target(s)", redeploys));
+    }
+    print!("\nProceed to {}? [y/N]: ", actions.join(" and "));
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
+#[cfg(test)]
+#[path = "doctor_test.rs"]
+mod tests;
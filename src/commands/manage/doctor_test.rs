@@ -0,0 +1,73 @@
+use super::*;
+use crate::component::ComponentKind;
+use std::path::PathBuf;
+
+fn orphan() -> OrphanedFile {
+    OrphanedFile {
+        path: PathBuf::from("/work/app/.github/prompts/tools/old/deploy.prompt.md"),
+        target: "copilot".to_string(),
+        kind: ComponentKind::Command,
+        owner: "old@tools".to_string(),
+    }
+}
+
+#[test]
+fn format_orphan_shows_relative_path_and_owner() {
+    assert_eq!(
+        format_orphan(&orphan(), Path::new("/work/app")),
+        ".github/prompts/tools/old/deploy.prompt.md (copilot Command, from old@tools)"
+    );
+}
+
+#[test]
+fn clean_report_has_no_problems() {
+    let report = DoctorReport::default();
+    assert_eq!(
+        render_text(&report, Path::new("/work/app")),
+        "No problems found."
+    );
+    assert_eq!(report.count(CheckStatus::Error), 0);
+}
+
+#[test]
+fn problems_are_listed_errors_first_with_a_fix_hint() {
+    let report = DoctorReport {
+        broken_manifests: vec![],
+        missing: vec![MissingFile {
+            plugin: "formatter".to_string(),
+            marketplace: Some("tools".to_string()),
+            target: "codex".to_string(),
+            kind: ComponentKind::Skill,
+            path: PathBuf::from("/work/app/.codex/skills/formatter_lint"),
+        }],
+        orphans: vec![orphan()],
+        marketplaces: vec![MarketplaceIssue::NotCached("tools".to_string())],
+    };
+
+    let text = render_text(&report, Path::new("/work/app"));
+
+    assert_eq!(
+        text,
+        "Problems:\n\
+         \x20 [error] missing: .codex/skills/formatter_lint (codex Skill, from formatter@tools)\n\
+         \x20 [warning] orphaned: .github/prompts/tools/old/deploy.prompt.md (copilot Command, from old@tools)\n\
+         \x20 [warning] marketplace: 'tools' is registered but not cached (run 'plm marketplace update tools')\n\
+         \n\
+         Summary: 1 error(s), 2 warning(s)\n\
+         \n\
+         Run 'plm doctor --fix' to remove orphaned files and redeploy missing components."
+    );
+}
+
+#[test]
+fn warnings_alone_do_not_count_as_errors() {
+    let report = DoctorReport {
+        marketplaces: vec![MarketplaceIssue::Unregistered("old".to_string())],
+        ..Default::default()
+    };
+
+    assert_eq!(report.count(CheckStatus::Error), 0);
+    assert_eq!(report.count(CheckStatus::Warning), 1);
+    assert!(!report.is_fixable());
+    assert!(!render_text(&report, Path::new("/")).contains("--fix"));
+}
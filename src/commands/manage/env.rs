@@ -0,0 +1,195 @@
+use crate::commands::examples::Example;
+use crate::config::{
+    format_rate, redact_proxy_credentials, CaBundle, ConfigFile, NetworkLimits, ProxySettings,
+    CA_BUNDLE_ENV,
+};
+use crate::env::{EnvVar, PlmPaths};
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+/// 設定ファイルの解決結果
+#[derive(Debug)]
+pub(crate) enum ConfigFileStatus {
+    /// `PLM_HOME` / `HOME` が解決できない
+    Unresolved,
+    /// ファイルが存在しない（既定値で動作）
+    NotFound(PathBuf),
+    /// 読み込み成功
+    Loaded(PathBuf),
+    /// 読み込み・パース失敗
+    Invalid(PathBuf, String),
+}
+
+/// CA バンドルの解決結果
+#[derive(Debug)]
+pub(crate) enum CaBundleStatus {
+    /// 指定なし（OS のルート証明書のみ）
+    NotSet,
+    /// 読み込み成功（証明書の件数）
+    Loaded(CaBundle, usize),
+    /// 読み込み失敗
+    Invalid(CaBundle, String),
+}
+
+/// `plm env` の診断結果
+#[derive(Debug)]
+pub(crate) struct EnvReport {
+    pub plm_dir: Option<PathBuf>,
+    pub config_file: ConfigFileStatus,
+    pub proxy: ProxySettings,
+    pub ca_bundle: CaBundleStatus,
+    /// 通信量の制限（値が不正ならそのエラー）
+    pub limits: Result<NetworkLimits, String>,
+}
+
+impl EnvReport {
+    /// 現在の環境変数と設定ファイルから診断結果を組み立てる
+    pub(crate) fn collect() -> Self {
+        let paths = PlmPaths::new().ok();
+        let plm_dir = paths.as_ref().map(|p| p.plm_dir());
+        let (config_file, config) = match paths.map(|p| p.config_toml()) {
+            None => (ConfigFileStatus::Unresolved, ConfigFile::default()),
+            Some(path) if !path.exists() => {
+                (ConfigFileStatus::NotFound(path), ConfigFile::default())
+            }
+            Some(path) => match ConfigFile::load(&path) {
+                Ok(config) => (ConfigFileStatus::Loaded(path), config),
+                Err(e) => (
+                    ConfigFileStatus::Invalid(path, e.to_string()),
+                    ConfigFile::default(),
+                ),
+            },
+        };
+
+        let ca_bundle = match CaBundle::resolve(EnvVar::get(CA_BUNDLE_ENV), &config) {
+            None => CaBundleStatus::NotSet,
+            Some(bundle) => match bundle.load_certificates() {
+                Ok(certs) => CaBundleStatus::Loaded(bundle, certs.len()),
+                Err(e) => CaBundleStatus::Invalid(bundle, e.to_string()),
+            },
+        };
+
+        Self {
+            plm_dir,
+            config_file,
+            proxy: ProxySettings::from_env(),
+            ca_bundle,
+            limits: NetworkLimits::from_env(&config).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// 表示用テキストに整形する
+    pub(crate) fn render(&self) -> String {
+        let mut lines = vec!["Paths:".to_string()];
+        lines.push(format_row(
+            "PLM dir",
+            &self
+                .plm_dir
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "(unresolved: PLM_HOME/HOME not set)".to_string()),
+        ));
+        lines.push(format_row("Config file", &self.render_config_file()));
+
+        lines.push(String::new());
+        lines.push("Network:".to_string());
+        for (label, value) in [
+            ("HTTPS_PROXY", &self.proxy.https_proxy),
+            ("HTTP_PROXY", &self.proxy.http_proxy),
+            ("ALL_PROXY", &self.proxy.all_proxy),
+        ] {
+            let value = value
+                .as_deref()
+                .map(redact_proxy_credentials)
+                .unwrap_or_else(|| "(not set)".to_string());
+            lines.push(format_row(label, &value));
+        }
+        lines.push(format_row(
+            "NO_PROXY",
+            self.proxy.no_proxy.as_deref().unwrap_or("(not set)"),
+        ));
+        lines.push(format_row("CA bundle", &self.render_ca_bundle()));
+        match &self.limits {
+            Ok(limits) => {
+                lines.push(format_row(
+                    "Jobs",
+                    &limits
+                        .jobs
+                        .map(|jobs| jobs.to_string())
+                        .unwrap_or_else(|| "(unlimited)".to_string()),
+                ));
+                lines.push(format_row(
+                    "Limit rate",
+                    &limits
+                        .limit_rate
+                        .map(format_rate)
+                        .unwrap_or_else(|| "(unlimited)".to_string()),
+                ));
+                lines.push(format_row(
+                    "Interval",
+                    &limits
+                        .request_interval
+                        .map(|interval| format!("{} ms", interval.as_millis()))
+                        .unwrap_or_else(|| "(none)".to_string()),
+                ));
+            }
+            Err(e) => lines.push(format_row("Limits", &format!("error: {}", e))),
+        }
+
+        lines.join("\n")
+    }
+
+    fn render_config_file(&self) -> String {
+        match &self.config_file {
+            ConfigFileStatus::Unresolved => "(unresolved)".to_string(),
+            ConfigFileStatus::NotFound(path) => format!("{} (not found)", path.display()),
+            ConfigFileStatus::Loaded(path) => path.display().to_string(),
+            ConfigFileStatus::Invalid(path, e) => format!("{} (error: {})", path.display(), e),
+        }
+    }
+
+    fn render_ca_bundle(&self) -> String {
+        match &self.ca_bundle {
+            CaBundleStatus::NotSet => "(not set, system roots only)".to_string(),
+            CaBundleStatus::Loaded(bundle, count) => format!(
+                "{} (from {}, {} certificate(s))",
+                bundle.path.display(),
+                bundle.source.as_str(),
+                count
+            ),
+            CaBundleStatus::Invalid(_, e) => format!("error: {}", e),
+        }
+    }
+}
+
+/// ラベルと値を揃えて 1 行にする
+///
+/// # Arguments
+///
+/// * `label` - Row label.
+/// * `value` - Row value.
+fn format_row(label: &str, value: &str) -> String {
+    format!("  {:<12} {}", format!("{}:", label), value)
+}
+
+/// `plm env` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[Example {
+    command: "plm env",
+    description:
+        "Show the resolved state directory, config file, proxy, CA and network limit settings",
+}];
+
+/// # Arguments
+///
+/// * `_args` - Parsed CLI arguments for `plm env`.
+pub async fn run(_args: Args) -> Result<(), String> {
+    println!("{}", EnvReport::collect().render());
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "env_test.rs"]
+mod tests;
@@ -0,0 +1,187 @@
+//! plm gitignore コマンド
+//!
+//! デプロイマニフェスト（`.plm/deployed.json`）の配置物を `.gitignore` に追記する。
+//! パターンはマーカーコメントで囲んだブロックにまとめ、再実行時はブロックだけを
+//! 差し替える（ブロック外の行には触れない）。配置物をコミットする方針のプロジェクトは
+//! このコマンドを使わなければよい。
+
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::plugin::{DeployedManifest, PlacementLedger};
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Print the block instead of writing .gitignore
+    #[arg(long)]
+    pub print: bool,
+}
+
+/// `plm gitignore` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm gitignore",
+        description: "Add or refresh the block of plm-managed files in .gitignore",
+    },
+    Example {
+        command: "plm gitignore --print",
+        description: "Print the block without touching .gitignore",
+    },
+];
+
+/// ブロックの開始行
+const BLOCK_START: &str = "# >>> plm managed files >>>";
+
+/// ブロックの終了行
+const BLOCK_END: &str = "# <<< plm managed files <<<";
+
+/// 配置記録からデプロイマニフェストを作り直して返す
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/deployed.json`.
+pub(crate) fn load_manifest(project_root: &Path) -> Result<DeployedManifest, String> {
+    let ledger = PlacementLedger::load().map_err(|e| e.to_string())?;
+    DeployedManifest::sync(project_root, ledger.records()).map_err(|e| e.to_string())
+}
+
+/// plm 管理下のパスを `.gitignore` のパターンにする
+///
+/// プロジェクトルートに固定する（先頭 `/`）。ワイルドカードとして解釈される文字は
+/// エスケープする。
+///
+/// # Arguments
+///
+/// * `path` - Path relative to the project root (`/` separated).
+fn to_pattern(path: &str) -> String {
+    let mut pattern = String::from("/");
+    for c in path.chars() {
+        if matches!(c, '*' | '?' | '[' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern
+}
+
+/// マーカーコメントで囲んだブロックを組み立てる
+///
+/// # Arguments
+///
+/// * `manifest` - Deployed manifest of the project.
+pub(crate) fn render_block(manifest: &DeployedManifest) -> String {
+    let mut lines = vec![
+        BLOCK_START.to_string(),
+        "# Generated by `plm gitignore` from .plm/deployed.json; rerun it instead of editing."
+            .to_string(),
+    ];
+    lines.extend(manifest.files().into_iter().map(to_pattern));
+    lines.push(BLOCK_END.to_string());
+    lines.join("\n") + "\n"
+}
+
+/// `.gitignore` の内容にブロックがあるか
+///
+/// # Arguments
+///
+/// * `content` - Current `.gitignore` content.
+pub(crate) fn has_block(content: &str) -> bool {
+    content.lines().any(|line| line.trim_end() == BLOCK_START)
+}
+
+/// `.gitignore` のブロックを差し替える（無ければ末尾に追記、`block` が `None` なら削除）
+///
+/// # Arguments
+///
+/// * `content` - Current `.gitignore` content.
+/// * `block` - Block rendered by [`render_block`].
+pub(crate) fn replace_block(content: &str, block: Option<&str>) -> Result<String, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.iter().position(|line| line.trim_end() == BLOCK_START);
+    let Some(start) = start else {
+        let Some(block) = block else {
+            return Ok(content.to_string());
+        };
+        let mut updated = content.to_string();
+        if !updated.is_empty() && !updated.ends_with('\n') {
+            updated.push('\n');
+        }
+        if !updated.is_empty() {
+            updated.push('\n');
+        }
+        updated.push_str(block);
+        return Ok(updated);
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.trim_end() == BLOCK_END)
+        .map(|offset| start + offset)
+        .ok_or_else(|| {
+            format!(
+                "'{}' in .gitignore has no matching '{}'; fix or remove the block first",
+                BLOCK_START, BLOCK_END
+            )
+        })?;
+
+    let mut updated: Vec<String> = lines[..start].iter().map(|l| l.to_string()).collect();
+    if let Some(block) = block {
+        updated.extend(block.lines().map(String::from));
+    } else if updated.last().is_some_and(|l| l.is_empty()) {
+        // 追記時に入れた空行も一緒に取り除く
+        updated.pop();
+    }
+    updated.extend(lines[end + 1..].iter().map(|l| l.to_string()));
+    if updated.is_empty() {
+        return Ok(String::new());
+    }
+    Ok(updated.join("\n") + "\n")
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm gitignore`.
+/// * `ctx` - Shared command context (project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let manifest = load_manifest(&ctx.project_root)?;
+    let block = render_block(&manifest);
+    if args.print {
+        print!("{}", block);
+        return Ok(());
+    }
+
+    let path = ctx.project_root.join(".gitignore");
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    let count = manifest.file_count();
+    // 管理下のファイルが無ければブロックを消す（無ければ何もしない）
+    let updated = replace_block(&content, (count > 0).then_some(block.as_str()))?;
+    if updated == content {
+        if count == 0 {
+            println!("No plm-managed files in this project; .gitignore is unchanged.");
+        } else {
+            println!(".gitignore is up to date ({} plm-managed path(s)).", count);
+        }
+        return Ok(());
+    }
+
+    fs::write(&path, &updated).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    if count == 0 {
+        println!("Removed the plm block from {}.", path.display());
+    } else {
+        println!(
+            "Updated {} with {} plm-managed path(s).",
+            path.display(),
+            count
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "gitignore_test.rs"]
+mod tests;
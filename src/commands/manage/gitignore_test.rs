@@ -0,0 +1,64 @@
+use super::*;
+
+fn manifest(files: &[&str]) -> DeployedManifest {
+    let mut manifest = DeployedManifest::default();
+    manifest.plugins.insert(
+        "mp/tools".to_string(),
+        files.iter().map(|f| f.to_string()).collect(),
+    );
+    manifest
+}
+
+#[test]
+fn block_lists_root_anchored_patterns() {
+    let block = render_block(&manifest(&[
+        ".codex/skills/lint",
+        ".github/prompts/[wip].prompt.md",
+    ]));
+
+    let lines: Vec<&str> = block.lines().collect();
+    assert_eq!(lines.first(), Some(&BLOCK_START));
+    assert_eq!(lines.last(), Some(&BLOCK_END));
+    assert!(lines.contains(&"/.codex/skills/lint"));
+    assert!(lines.contains(&"/.github/prompts/\\[wip].prompt.md"));
+}
+
+#[test]
+fn block_is_appended_after_existing_rules() {
+    let block = render_block(&manifest(&[".codex/skills/lint"]));
+
+    let updated = replace_block("target/\n*.log", Some(&block)).unwrap();
+
+    assert!(updated.starts_with("target/\n*.log\n\n# >>> plm managed files >>>\n"));
+    assert!(has_block(&updated));
+}
+
+#[test]
+fn existing_block_is_replaced_in_place() {
+    let old = render_block(&manifest(&[".codex/skills/old"]));
+    let content = format!("target/\n\n{}node_modules/\n", old);
+    let new = render_block(&manifest(&[".codex/skills/new"]));
+
+    let updated = replace_block(&content, Some(&new)).unwrap();
+
+    assert_eq!(updated, format!("target/\n\n{}node_modules/\n", new));
+    assert!(!updated.contains("/.codex/skills/old"));
+}
+
+#[test]
+fn block_is_removed_when_nothing_is_deployed() {
+    let block = render_block(&manifest(&[".codex/skills/lint"]));
+    let content = replace_block("target/\n", Some(&block)).unwrap();
+
+    assert_eq!(replace_block(&content, None).unwrap(), "target/\n");
+    assert_eq!(replace_block("target/\n", None).unwrap(), "target/\n");
+}
+
+#[test]
+fn unterminated_block_is_an_error() {
+    let content = format!("{}\n/.codex/skills/lint\n", BLOCK_START);
+
+    let error = replace_block(&content, Some("x\n")).unwrap_err();
+
+    assert!(error.contains("has no matching"));
+}
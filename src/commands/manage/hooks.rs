@@ -0,0 +1,232 @@
+use crate::commands::examples::Example;
+use crate::hooks::runner::{self, HookRun};
+use crate::hooks::sample::{self, SAMPLE_EVENTS};
+use crate::target::TargetKind;
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run a hook script locally with a sample payload
+    #[command(
+        long_about = "Run a hook script with an event payload on stdin and show its exit code, stdout and stderr. Without --payload, a built-in sample payload for the event is used."
+    )]
+    Test {
+        /// Hook script to run
+        script: String,
+
+        /// Claude Code hook event (e.g. PreToolUse, PostToolUse, Stop)
+        #[arg(long)]
+        event: String,
+
+        /// JSON file with a Claude Code hook payload (defaults to the built-in sample)
+        #[arg(long)]
+        payload: Option<String>,
+
+        /// Run through the wrapper script generated for this target (copilot)
+        #[arg(long, value_enum)]
+        target: Option<TargetKind>,
+    },
+}
+
+/// 実行に使ったペイロードの出所
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PayloadSource {
+    /// 組み込みサンプル
+    Sample,
+    /// `--payload` で指定されたファイル
+    File(PathBuf),
+}
+
+/// `plm hooks` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm hooks test ./scripts/pre-tool-use.sh --event PreToolUse",
+        description: "Run a hook with the built-in sample payload",
+    },
+    Example {
+        command: "plm hooks test ./scripts/stop.sh --event Stop --payload payload.json",
+        description: "Run a hook with your own payload file",
+    },
+    Example {
+        command: "plm hooks test ./scripts/pre-tool-use.sh --event PreToolUse --target copilot",
+        description: "Run a hook through the Copilot wrapper",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm hooks`.
+pub async fn run(args: Args) -> Result<(), String> {
+    match args.command {
+        Command::Test {
+            script,
+            event,
+            payload,
+            target,
+        } => run_test(&script, &event, payload.as_deref(), target),
+    }
+}
+
+/// `plm hooks test`: フックを実行して結果を表示する
+///
+/// # Arguments
+///
+/// * `script` - Hook script path.
+/// * `event` - Event name given on the command line.
+/// * `payload_path` - Optional payload JSON file.
+/// * `target` - Optional target whose wrapper should be used.
+fn run_test(
+    script: &str,
+    event: &str,
+    payload_path: Option<&str>,
+    target: Option<TargetKind>,
+) -> Result<(), String> {
+    let event = sample::resolve_event(event).ok_or_else(|| {
+        format!(
+            "Unknown hook event: {} (expected one of: {})",
+            event,
+            SAMPLE_EVENTS.join(", ")
+        )
+    })?;
+    if let Some(kind) = target.filter(|k| *k != TargetKind::Copilot) {
+        return Err(format!(
+            "hooks test does not support target '{}' (supported: copilot)",
+            kind.as_str()
+        ));
+    }
+
+    let project_root = crate::env::project_root().map_err(|e| e.to_string())?;
+    let script_path = Path::new(script);
+    if !script_path.is_file() {
+        return Err(format!("Hook script not found: {}", script));
+    }
+
+    let (payload, source) = load_payload(event, payload_path, &project_root)?;
+    let run = match target {
+        Some(kind) => {
+            let payload = sample::to_copilot_payload(event, &payload).ok_or_else(|| {
+                format!(
+                    "Event '{}' is not supported by target '{}'",
+                    event,
+                    kind.as_str()
+                )
+            })?;
+            let script_abs = script_path
+                .canonicalize()
+                .map_err(|e| format!("Failed to resolve {}: {}", script, e))?;
+            let wrapper = runner::build_wrapper(kind, event, &script_abs, &project_root)
+                .map_err(|e| e.to_string())?;
+            runner::run_wrapped_hook(&wrapper, &payload.to_string(), &project_root)
+        }
+        None => runner::run_hook(script_path, &payload.to_string(), &project_root),
+    }
+    .map_err(|e| e.to_string())?;
+
+    println!("{}", render_run(event, target, &source, &run));
+    Ok(())
+}
+
+/// ペイロードを読み込む（未指定なら組み込みサンプル）
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+/// * `payload_path` - Optional payload JSON file.
+/// * `cwd` - Working directory embedded in the sample payload.
+fn load_payload(
+    event: &str,
+    payload_path: Option<&str>,
+    cwd: &Path,
+) -> Result<(Value, PayloadSource), String> {
+    match payload_path {
+        Some(path) => {
+            let content = fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read payload {}: {}", path, e))?;
+            let value = serde_json::from_str(&content)
+                .map_err(|e| format!("Invalid payload JSON in {}: {}", path, e))?;
+            Ok((value, PayloadSource::File(PathBuf::from(path))))
+        }
+        None => sample::sample_payload(event, cwd)
+            .map(|v| (v, PayloadSource::Sample))
+            .ok_or_else(|| format!("No built-in sample payload for event: {}", event)),
+    }
+}
+
+/// Claude Code 上での終了コードの意味
+///
+/// # Arguments
+///
+/// * `code` - Exit code (`None` when killed by a signal).
+fn describe_exit_code(code: Option<i32>) -> &'static str {
+    match code {
+        Some(0) => "success",
+        Some(2) => "blocking error",
+        Some(_) => "non-blocking error",
+        None => "terminated by signal",
+    }
+}
+
+/// 実行結果を表示用テキストに整形する
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+/// * `target` - Target whose wrapper was used, if any.
+/// * `source` - Where the payload came from.
+/// * `run` - Captured hook execution result.
+pub(crate) fn render_run(
+    event: &str,
+    target: Option<TargetKind>,
+    source: &PayloadSource,
+    run: &HookRun,
+) -> String {
+    let mut lines = vec![format!("Event:   {}", event)];
+    if let Some(kind) = target {
+        lines.push(format!("Target:  {} (via wrapper)", kind.as_str()));
+    }
+    lines.push(match source {
+        PayloadSource::Sample => "Payload: built-in sample".to_string(),
+        PayloadSource::File(path) => format!("Payload: {}", path.display()),
+    });
+    lines.push(format!(
+        "Exit:    {} ({})",
+        run.exit_code
+            .map_or_else(|| "-".to_string(), |c| c.to_string()),
+        describe_exit_code(run.exit_code)
+    ));
+
+    for (label, body) in [("stdout", &run.stdout), ("stderr", &run.stderr)] {
+        lines.push(format!("--- {} ---", label));
+        if body.trim().is_empty() {
+            lines.push("(empty)".to_string());
+        } else {
+            lines.push(pretty_json_or_raw(body));
+        }
+    }
+    lines.join("\n")
+}
+
+/// JSON として解釈できれば整形し、できなければそのまま返す
+///
+/// # Arguments
+///
+/// * `body` - Captured output.
+fn pretty_json_or_raw(body: &str) -> String {
+    serde_json::from_str::<Value>(body.trim())
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok())
+        .unwrap_or_else(|| body.trim_end().to_string())
+}
+
+#[cfg(test)]
+#[path = "hooks_test.rs"]
+mod tests;
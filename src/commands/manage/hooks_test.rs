@@ -0,0 +1,82 @@
+use super::*;
+use tempfile::TempDir;
+
+fn run_result(exit_code: Option<i32>, stdout: &str, stderr: &str) -> HookRun {
+    HookRun {
+        exit_code,
+        stdout: stdout.to_string(),
+        stderr: stderr.to_string(),
+    }
+}
+
+#[test]
+fn render_run_shows_exit_code_and_outputs() {
+    let run = run_result(Some(2), "", "blocked: rm -rf\n");
+    let out = render_run("PreToolUse", None, &PayloadSource::Sample, &run);
+
+    assert!(out.contains("Event:   PreToolUse"));
+    assert!(out.contains("Payload: built-in sample"));
+    assert!(out.contains("Exit:    2 (blocking error)"));
+    assert!(out.contains("--- stdout ---\n(empty)"));
+    assert!(out.contains("--- stderr ---\nblocked: rm -rf"));
+    assert!(!out.contains("Target:"));
+}
+
+#[test]
+fn render_run_pretty_prints_json_stdout_and_target() {
+    let run = run_result(Some(0), "{\"permissionDecision\":\"deny\"}\n", "");
+    let out = render_run(
+        "PreToolUse",
+        Some(TargetKind::Copilot),
+        &PayloadSource::File(PathBuf::from("sample.json")),
+        &run,
+    );
+
+    assert!(out.contains("Target:  copilot (via wrapper)"));
+    assert!(out.contains("Payload: sample.json"));
+    assert!(out.contains("Exit:    0 (success)"));
+    assert!(out.contains("\"permissionDecision\": \"deny\""));
+}
+
+#[test]
+fn describe_exit_code_covers_claude_semantics() {
+    assert_eq!(describe_exit_code(Some(0)), "success");
+    assert_eq!(describe_exit_code(Some(2)), "blocking error");
+    assert_eq!(describe_exit_code(Some(1)), "non-blocking error");
+    assert_eq!(describe_exit_code(None), "terminated by signal");
+}
+
+#[test]
+fn load_payload_reads_file_or_falls_back_to_sample() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("payload.json");
+    fs::write(&path, r#"{"tool_name":"Write"}"#).unwrap();
+
+    let (value, source) =
+        load_payload("PreToolUse", Some(path.to_str().unwrap()), temp.path()).unwrap();
+    assert_eq!(value["tool_name"], "Write");
+    assert_eq!(source, PayloadSource::File(path.clone()));
+
+    let (value, source) = load_payload("PreToolUse", None, temp.path()).unwrap();
+    assert_eq!(value["tool_name"], "Bash");
+    assert_eq!(source, PayloadSource::Sample);
+}
+
+#[test]
+fn load_payload_rejects_invalid_json() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("payload.json");
+    fs::write(&path, "not json").unwrap();
+
+    let err = load_payload("Stop", Some(path.to_str().unwrap()), temp.path()).unwrap_err();
+    assert!(err.contains("Invalid payload JSON"));
+}
+
+#[test]
+fn run_test_rejects_unknown_event_and_unsupported_target() {
+    let err = run_test("hook.sh", "Bogus", None, None).unwrap_err();
+    assert!(err.contains("Unknown hook event: Bogus"));
+
+    let err = run_test("hook.sh", "Stop", None, Some(TargetKind::Codex)).unwrap_err();
+    assert!(err.contains("does not support target 'codex'"));
+}
@@ -1,4 +1,10 @@
+use crate::commands::examples::Example;
+use crate::hooks::sample::{self, SAMPLE_EVENTS, SAMPLE_SCRIPTS_DIR};
+use crate::plugin::meta::has_manifest;
+use crate::scan::DEFAULT_HOOKS_DIR;
 use clap::{Parser, ValueEnum};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, ValueEnum)]
 pub enum ComponentType {
@@ -8,18 +14,162 @@ pub enum ComponentType {
     Instruction,
 }
 
+/// `--components` で雛形を生成できるコンポーネント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ScaffoldComponent {
+    /// イベント別のサンプルフックスクリプトと hooks/hooks.json
+    Hooks,
+}
+
 #[derive(Debug, Parser)]
 pub struct Args {
     pub name: String,
 
-    #[arg(long = "type", value_enum)]
-    pub component_type: ComponentType,
+    #[arg(long = "type", value_enum, required_unless_present = "components")]
+    pub component_type: Option<ComponentType>,
+
+    /// Scaffold sample components into the plugin directory (e.g. hooks)
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub components: Vec<ScaffoldComponent>,
 }
 
+/// 初期バージョン（新規 plugin.json に書き込む）
+pub(crate) const INITIAL_VERSION: &str = "0.1.0";
+
+/// `plm init` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[Example {
+    command: "plm init my-plugin --components hooks",
+    description: "Scaffold sample hook scripts and hooks/hooks.json",
+}];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm init`.
 pub async fn run(args: Args) -> Result<(), String> {
-    println!("init: {:?}", args);
-    Err("not implemented".to_string())
+    if args.components.is_empty() {
+        println!("init: {:?}", args);
+        return Err("not implemented".to_string());
+    }
+
+    let root = PathBuf::from(&args.name);
+    let created = scaffold(&root, &args.components)?;
+    println!("Initialized {}:", root.display());
+    for path in &created {
+        println!("  created {}", path.display());
+    }
+    if args.components.contains(&ScaffoldComponent::Hooks) {
+        println!(
+            "Try a hook locally: plm hooks test ./{}/{} --event PreToolUse",
+            SAMPLE_SCRIPTS_DIR,
+            sample::sample_script_name("PreToolUse")
+        );
+    }
+    Ok(())
 }
+
+/// プラグインディレクトリに雛形ファイルを生成する
+///
+/// 既存ファイルは上書きしない（1 つでも衝突すれば何も書かずにエラー）。
+/// マニフェストが無ければ `.claude-plugin/plugin.json` も生成する。
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory (created if missing).
+/// * `components` - Components to scaffold.
+///
+/// # Returns
+/// 生成したファイルの `root` からの相対パス
+pub(crate) fn scaffold(
+    root: &Path,
+    components: &[ScaffoldComponent],
+) -> Result<Vec<PathBuf>, String> {
+    let mut files: Vec<(PathBuf, String, bool)> = Vec::new();
+
+    if !has_manifest(root) {
+        let name = root
+            .canonicalize()
+            .unwrap_or_else(|_| root.to_path_buf())
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or_else(|| format!("Cannot derive plugin name from {}", root.display()))?;
+        let manifest = serde_json::json!({ "name": name, "version": INITIAL_VERSION });
+        files.push((
+            PathBuf::from(".claude-plugin").join("plugin.json"),
+            to_pretty_json(&manifest)?,
+            false,
+        ));
+    }
+
+    for component in components {
+        match component {
+            ScaffoldComponent::Hooks => {
+                files.push((
+                    PathBuf::from(DEFAULT_HOOKS_DIR).join("hooks.json"),
+                    to_pretty_json(&sample::sample_hooks_config())?,
+                    false,
+                ));
+                for event in SAMPLE_EVENTS {
+                    files.push((
+                        PathBuf::from(SAMPLE_SCRIPTS_DIR).join(sample::sample_script_name(event)),
+                        sample::sample_script(event),
+                        true,
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some((rel, _, _)) = files.iter().find(|(rel, _, _)| root.join(rel).exists()) {
+        return Err(format!(
+            "{} already exists; refusing to overwrite",
+            root.join(rel).display()
+        ));
+    }
+
+    for (rel, content, executable) in &files {
+        let path = root.join(rel);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        if *executable {
+            set_executable(&path)?;
+        }
+    }
+
+    Ok(files.into_iter().map(|(rel, _, _)| rel).collect())
+}
+
+/// JSON を末尾改行付きの整形済み文字列にする
+///
+/// # Arguments
+///
+/// * `value` - JSON value to serialize.
+pub(crate) fn to_pretty_json(value: &serde_json::Value) -> Result<String, String> {
+    serde_json::to_string_pretty(value)
+        .map(|s| s + "\n")
+        .map_err(|e| format!("Failed to serialize JSON: {}", e))
+}
+
+/// スクリプトに実行権限を付与する（Unix のみ）
+///
+/// # Arguments
+///
+/// * `path` - Script file to mark executable.
+fn set_executable(path: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to chmod {}: {}", path.display(), e))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "init_test.rs"]
+mod tests;
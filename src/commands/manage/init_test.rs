@@ -0,0 +1,61 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn scaffold_hooks_creates_manifest_config_and_scripts() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("my-plugin");
+
+    let created = scaffold(&root, &[ScaffoldComponent::Hooks]).unwrap();
+
+    assert_eq!(created.len(), 2 + SAMPLE_EVENTS.len());
+    let manifest: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(root.join(".claude-plugin/plugin.json")).unwrap())
+            .unwrap();
+    assert_eq!(manifest["name"], "my-plugin");
+    assert_eq!(manifest["version"], INITIAL_VERSION);
+
+    let config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(root.join("hooks/hooks.json")).unwrap()).unwrap();
+    assert!(config["hooks"]["PreToolUse"].is_array());
+
+    let script = fs::read_to_string(root.join("scripts/pre-tool-use.sh")).unwrap();
+    assert!(script.contains("INPUT=$(cat)"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(root.join("scripts/pre-tool-use.sh"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn scaffold_keeps_existing_manifest() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("plugin.json"), r#"{"name":"existing"}"#).unwrap();
+
+    let created = scaffold(temp.path(), &[ScaffoldComponent::Hooks]).unwrap();
+
+    assert!(!created.iter().any(|p| p.ends_with("plugin.json")));
+    assert!(!temp.path().join(".claude-plugin").exists());
+}
+
+#[test]
+fn scaffold_refuses_to_overwrite_without_writing_anything() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("hooks")).unwrap();
+    fs::write(temp.path().join("hooks/hooks.json"), "{}").unwrap();
+
+    let err = scaffold(temp.path(), &[ScaffoldComponent::Hooks]).unwrap_err();
+
+    assert!(err.contains("already exists"));
+    assert_eq!(
+        fs::read_to_string(temp.path().join("hooks/hooks.json")).unwrap(),
+        "{}"
+    );
+    assert!(!temp.path().join("scripts").exists());
+    assert!(!temp.path().join(".claude-plugin").exists());
+}
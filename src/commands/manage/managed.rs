@@ -2,8 +2,130 @@
 //!
 //! `plm managed` でインタラクティブなプラグイン管理画面を起動する。
 
-use crate::tui::manager;
+use crate::commands::examples::Example;
+use crate::config::ConfigFile;
+use crate::env::EnvVar;
+use crate::tui::manager::{self, Theme, ThemeName, COLORFGBG_ENV};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-pub async fn run() -> Result<(), String> {
-    manager::run().map_err(|e| e.to_string())
+#[derive(Debug, Default, Parser)]
+pub struct Args {
+    /// マーケットプレイスを経由しないローカルのプラグインディレクトリを dev モードで開く
+    #[arg(long, value_name = "PATH")]
+    pub dev: Option<PathBuf>,
+
+    /// カラーテーマ（未指定時は config.toml の `tui.theme`、それも無ければ dark）
+    #[arg(long, value_enum)]
+    pub theme: Option<ThemeName>,
+
+    /// 操作スクリプト（JSON のキー入力列）を実ターミナルなしで再生し、終了時の状態と画面をダンプする
+    #[arg(long, value_name = "FILE", conflicts_with = "record")]
+    pub replay: Option<PathBuf>,
+
+    /// 再生結果のダンプを標準出力ではなくファイルに書き出す
+    #[arg(long, value_name = "FILE", requires = "replay")]
+    pub dump: Option<PathBuf>,
+
+    /// 押したキーを操作スクリプトとして記録する（`--replay` で再生できる）
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+}
+
+/// `plm managed` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm managed",
+        description: "Open the plugin management TUI",
+    },
+    Example {
+        command: "plm managed --dev ./my-plugin",
+        description: "Show a local plugin directory as a [dev] row",
+    },
+    Example {
+        command: "plm managed --theme light",
+        description: "Use the color theme for light terminals",
+    },
+    Example {
+        command: "plm managed --record steps.json",
+        description: "Record key presses as a replayable script",
+    },
+    Example {
+        command: "plm managed --replay steps.json --dump screen.txt",
+        description: "Replay a script headlessly and save the final screen",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm managed`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let theme = resolve_theme(args.theme)?;
+    if let Some(script) = &args.replay {
+        return replay(script, args.dev, theme, args.dump.as_deref());
+    }
+    manager::run(args.dev, theme, args.record.as_deref()).map_err(|e| e.to_string())
 }
+
+/// 操作スクリプトを再生し、ダンプを出力する
+///
+/// # Arguments
+///
+/// * `script` - Script given via `--replay`.
+/// * `dev_root` - Plugin directory given via `--dev`.
+/// * `theme` - Resolved color theme.
+/// * `dump_path` - File given via `--dump` (stdout when `None`).
+fn replay(
+    script: &Path,
+    dev_root: Option<PathBuf>,
+    theme: Theme,
+    dump_path: Option<&Path>,
+) -> Result<(), String> {
+    let dump = manager::replay(script, dev_root, theme)
+        .map_err(|e| format!("Failed to replay {}: {}", script.display(), e))?;
+    match dump_path {
+        Some(path) => {
+            fs::write(path, dump).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+        }
+        None => {
+            print!("{}", dump);
+            Ok(())
+        }
+    }
+}
+
+/// 使用するテーマを決める（`--theme` > config の `tui.theme` > dark）
+///
+/// # Arguments
+///
+/// * `flag` - Theme given via `--theme`.
+fn resolve_theme(flag: Option<ThemeName>) -> Result<Theme, String> {
+    let name = match flag {
+        Some(name) => name,
+        None => configured_theme(&ConfigFile::load_default().map_err(|e| e.to_string())?)?,
+    };
+    Ok(Theme::from_name(
+        name,
+        EnvVar::get(COLORFGBG_ENV).as_deref(),
+    ))
+}
+
+/// 設定ファイルの `tui.theme` を解釈する（未設定なら dark）
+///
+/// # Arguments
+///
+/// * `config` - Loaded user configuration.
+fn configured_theme(config: &ConfigFile) -> Result<ThemeName, String> {
+    config
+        .tui
+        .theme
+        .as_deref()
+        .map(|value| ThemeName::parse(value).map_err(|e| format!("tui.theme: {}", e)))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+#[cfg(test)]
+#[path = "managed_test.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+fn config_with_theme(theme: Option<&str>) -> ConfigFile {
+    let mut config = ConfigFile::default();
+    config.tui.theme = theme.map(str::to_string);
+    config
+}
+
+#[test]
+fn configured_theme_defaults_to_dark() {
+    assert_eq!(
+        configured_theme(&config_with_theme(None)),
+        Ok(ThemeName::Dark)
+    );
+}
+
+#[test]
+fn configured_theme_reads_tui_theme() {
+    assert_eq!(
+        configured_theme(&config_with_theme(Some("high-contrast"))),
+        Ok(ThemeName::HighContrast)
+    );
+}
+
+#[test]
+fn configured_theme_rejects_unknown_value() {
+    let err = configured_theme(&config_with_theme(Some("solarized"))).unwrap_err();
+    assert!(err.starts_with("tui.theme: invalid theme 'solarized'"));
+    assert!(err.contains("high-contrast"));
+}
+
+#[test]
+fn theme_flag_takes_precedence_over_config() {
+    let theme = resolve_theme(Some(ThemeName::Light)).unwrap();
+    assert_eq!(theme, Theme::light());
+}
@@ -1,11 +1,20 @@
-use crate::host::HostClientFactory;
+use crate::application::{render_warnings, WarningCollector};
+use crate::commands::examples::Example;
+use crate::commands::lifecycle::update::{display_deploy_hints, display_single_result};
 use crate::marketplace::{
-    normalize_name, normalize_source_path, MarketplaceConfig, MarketplaceRegistration,
-    MarketplaceRegistry, MarketplaceSourceRef,
+    duplicate_source_warning, normalize_name, normalize_source_path, MarketplaceCache,
+    MarketplaceConfig, MarketplaceRegistration, MarketplaceRegistry, MarketplaceSourceRef,
+    PluginSource, SourceLocator, UpdateDiff,
+};
+use crate::plugin::{
+    find_renamed_entry, update_plugin, PackageCache, PackageCacheAccess, UpdateStatus,
 };
-use crate::repo;
 use clap::{Parser, Subcommand};
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+
+mod init;
 
 #[derive(Debug, Parser)]
 pub struct Args {
@@ -23,10 +32,10 @@ pub enum Command {
 
     /// Add a marketplace
     #[command(
-        long_about = "Register a GitHub repository as a plugin marketplace. Use owner/repo format or full URL."
+        long_about = "Register a GitHub repository or a local directory as a plugin marketplace. Use owner/repo format, full URL, a local path (absolute or ./relative) or a file:// URL. Append #branch or @tag to fetch a specific branch or tag, and /path/to/dir (owner/repo/path/to/dir) when marketplace.json lives in a subdirectory."
     )]
     Add {
-        /// GitHub repository (owner/repo) or URL
+        /// GitHub repository (owner/repo[/subdir][#branch|@tag]), URL, or local directory path
         source: String,
 
         /// Marketplace name (defaults to repository name if not specified)
@@ -36,6 +45,10 @@ pub enum Command {
         /// Subdirectory where marketplace.json is located
         #[arg(long)]
         path: Option<String>,
+
+        /// Add even if another marketplace already uses the same source
+        #[arg(long)]
+        allow_duplicate_source: bool,
     },
 
     /// Remove a marketplace
@@ -49,11 +62,19 @@ pub enum Command {
 
     /// Update marketplace cache
     #[command(
-        long_about = "Refresh the local cache for marketplaces. Fetches latest plugin listings from remote repositories."
+        long_about = "Refresh the local cache for marketplaces. Fetches latest plugin listings from remote repositories, or re-reads marketplace.json for local directories."
     )]
     Update {
         /// Update only a specific marketplace (updates all if not specified)
         name: Option<String>,
+
+        /// Do not update installed plugins bundled in the marketplace repository (relative-path sources)
+        #[arg(long, overrides_with = "plugins")]
+        no_plugins: bool,
+
+        /// Update installed plugins bundled in the marketplace repository (default)
+        #[arg(long, hide = true, overrides_with = "no_plugins")]
+        plugins: bool,
     },
 
     /// Show marketplace details
@@ -64,18 +85,76 @@ pub enum Command {
         /// Marketplace name
         name: String,
     },
+
+    /// Scaffold a marketplace repository
+    #[command(
+        long_about = "Generate a marketplace repository skeleton: .claude-plugin/marketplace.json, a README, a sample plugin under plugins/ and a GitHub Actions workflow that validates them. Existing files are only overwritten after confirmation."
+    )]
+    Init {
+        /// Directory to create the marketplace in
+        #[arg(default_value = ".")]
+        dir: PathBuf,
+
+        /// Marketplace name (defaults to the directory name)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Owner name written to marketplace.json
+        #[arg(long)]
+        owner: Option<String>,
+    },
 }
 
+/// `plm marketplace` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm marketplace add owner/marketplace-repo",
+        description: "Register a GitHub repository as a marketplace",
+    },
+    Example {
+        command: "plm marketplace add owner/repo --name company-tools",
+        description: "Register a marketplace under a custom name",
+    },
+    Example {
+        command: "plm marketplace add ./my-marketplace --name dev",
+        description: "Register a local directory containing .claude-plugin/marketplace.json",
+    },
+    Example {
+        command: "plm marketplace update",
+        description: "Refresh the cache of every registered marketplace",
+    },
+    Example {
+        command: "plm marketplace update company-tools --no-plugins",
+        description: "Refresh a marketplace without updating the plugins bundled in its repository",
+    },
+    Example {
+        command: "plm marketplace show company-tools",
+        description: "List the plugins offered by a marketplace",
+    },
+    Example {
+        command: "plm marketplace init my-marketplace --owner \"Company Inc.\"",
+        description: "Scaffold a marketplace repository with a sample plugin",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm marketplace`.
 pub async fn run(args: Args) -> Result<(), String> {
     match args.command {
         Command::List => run_list().await,
-        Command::Add { source, name, path } => run_add(source, name, path).await,
+        Command::Add {
+            source,
+            name,
+            path,
+            allow_duplicate_source,
+        } => run_add(source, name, path, allow_duplicate_source).await,
         Command::Remove { name } => run_remove(name).await,
-        Command::Update { name } => run_update(name).await,
+        Command::Update {
+            name, no_plugins, ..
+        } => run_update(name, !no_plugins).await,
         Command::Show { name } => run_show(name).await,
+        Command::Init { dir, name, owner } => init::run_init(dir, name, owner),
     }
 }
 
@@ -95,7 +174,10 @@ async fn run_list() -> Result<(), String> {
     table.set_header(vec!["NAME", "SOURCE", "PLUGINS", "LAST UPDATED"]);
 
     for entry in entries {
-        let source_display = entry.source.full_name();
+        let source_display = match &entry.git_ref {
+            Some(git_ref) => format!("{}#{}", entry.source.full_name(), git_ref),
+            None => entry.source.full_name(),
+        };
         let (plugins_count, last_updated) = match registry.get(&entry.name) {
             Ok(Some(cache)) => {
                 let count = cache.plugins.len().to_string();
@@ -119,13 +201,24 @@ async fn run_list() -> Result<(), String> {
 
 /// # Arguments
 ///
-/// * `source` - GitHub repository source (`owner/repo` or full URL).
+/// * `source` - GitHub repository source (`owner/repo[/subdir][#branch|@tag]` or full URL), or a
+///   local directory (absolute path, `./relative` path or `file://` URL).
 /// * `name` - Optional marketplace name override.
 /// * `path` - Optional subdirectory containing `marketplace.json`.
-async fn run_add(source: String, name: Option<String>, path: Option<String>) -> Result<(), String> {
-    let parsed_repo = repo::from_url(&source).map_err(|e| e.to_string())?;
-
-    let raw_name = name.unwrap_or_else(|| parsed_repo.name().to_string());
+/// * `allow_duplicate_source` - Skip the duplicate source check.
+async fn run_add(
+    source: String,
+    name: Option<String>,
+    path: Option<String>,
+    allow_duplicate_source: bool,
+) -> Result<(), String> {
+    let SourceLocator {
+        source: source_ref,
+        git_ref,
+        subdir,
+    } = MarketplaceSourceRef::parse_locator(&source).map_err(|e| e.to_string())?;
+
+    let raw_name = name.unwrap_or_else(|| source_ref.name().to_string());
 
     let normalized_name = normalize_name(&raw_name)?;
 
@@ -137,45 +230,133 @@ async fn run_add(source: String, name: Option<String>, path: Option<String>) ->
         ));
     }
 
-    let source_path = match path {
-        Some(p) => normalize_source_path(&p)?,
-        None => None,
-    };
+    let source_path = merge_source_path(subdir.as_deref(), path.as_deref())?;
 
+    if !allow_duplicate_source {
+        if let Some(existing) = config.find_by_source(&source_ref, source_path.as_deref()) {
+            if !io::stdin().is_terminal() {
+                return Err(format!(
+                    "Marketplace '{}' already uses this source. \
+                     Use --allow-duplicate-source to add it anyway.",
+                    existing.name
+                ));
+            }
+            if !confirm_duplicate_source(&existing.name)? {
+                println!("Cancelled.");
+                return Ok(());
+            }
+        }
+    }
+
+    let plugin_count = register_marketplace(
+        &mut config,
+        MarketplaceRegistration {
+            name: normalized_name.clone(),
+            source: source_ref,
+            source_path,
+            git_ref,
+        },
+    )
+    .await?;
+
+    println!(
+        "Added marketplace '{}' with {} plugin(s).",
+        normalized_name, plugin_count
+    );
+    Ok(())
+}
+
+/// marketplace.json を取得してマーケットプレイスを登録し、キャッシュを保存する
+///
+/// 名前の重複・ソースの重複は呼び出し側で確認しておく。登録したマーケットプレイスの
+/// プラグイン数を返す。
+///
+/// # Arguments
+///
+/// * `config` - Loaded marketplace config (saved on success).
+/// * `entry` - Registration to add.
+pub(crate) async fn register_marketplace(
+    config: &mut MarketplaceConfig,
+    entry: MarketplaceRegistration,
+) -> Result<usize, String> {
     println!(
         "Fetching marketplace.json from {}...",
-        parsed_repo.full_name()
+        entry.source.full_name()
     );
-    let factory = HostClientFactory::with_defaults();
-    let client = factory.create(parsed_repo.host());
     let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
     let cache = registry
-        .fetch_cache(
-            &*client,
-            &normalized_name,
-            &parsed_repo,
-            source_path.as_deref(),
+        .fetch_source(
+            &entry.name,
+            &entry.source,
+            entry.source_path.as_deref(),
+            entry.git_ref.as_deref(),
         )
         .await
         .map_err(|e| e.to_string())?;
+    print_validation_warnings(&cache);
 
-    let plugin_count = cache.plugins.len();
-
-    let entry = MarketplaceRegistration {
-        name: normalized_name.clone(),
-        source: MarketplaceSourceRef::from_repo(&parsed_repo),
-        source_path,
-    };
     config.add(entry)?;
     config.save()?;
 
     registry.store(&cache).map_err(|e| e.to_string())?;
+    Ok(cache.plugins.len())
+}
 
-    println!(
-        "Added marketplace '{}' with {} plugin(s).",
-        normalized_name, plugin_count
+/// ソース指定のサブディレクトリ（`owner/repo/path`）と `--path` をまとめて正規化する
+///
+/// 両方が指定されて食い違う場合はエラー。
+///
+/// # Arguments
+///
+/// * `subdir` - Subdirectory parsed from the source (`owner/repo/path/to/dir`).
+/// * `path` - Value of `--path`.
+fn merge_source_path(subdir: Option<&str>, path: Option<&str>) -> Result<Option<String>, String> {
+    let subdir = subdir.map(normalize_source_path).transpose()?.flatten();
+    let path = path.map(normalize_source_path).transpose()?.flatten();
+    match (subdir, path) {
+        (Some(subdir), Some(path)) if subdir != path => Err(format!(
+            "Conflicting subdirectories: '{}' in the source and '{}' in --path",
+            subdir, path
+        )),
+        (subdir, path) => Ok(subdir.or(path)),
+    }
+}
+
+/// marketplace.json の警告（該当プラグインだけが使えない問題）を一覧表示する
+///
+/// # Arguments
+///
+/// * `cache` - Fetched marketplace cache.
+fn print_validation_warnings(cache: &MarketplaceCache) {
+    let warnings = cache.validation_warnings();
+    if warnings.is_empty() {
+        return;
+    }
+    eprintln!(
+        "Warning: marketplace.json of '{}' has {} issue(s); the affected plugins cannot be installed:",
+        cache.name,
+        warnings.len()
     );
-    Ok(())
+    for warning in &warnings {
+        eprintln!("  {}", warning);
+    }
+}
+
+/// 同じソースを別名で登録するか確認する
+///
+/// # Arguments
+///
+/// * `existing` - Name of the marketplace that already uses the source.
+fn confirm_duplicate_source(existing: &str) -> Result<bool, String> {
+    print!("{} [y/N]: ", duplicate_source_warning(existing));
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
 }
 
 /// # Arguments
@@ -202,10 +383,11 @@ async fn run_remove(name: String) -> Result<(), String> {
 /// # Arguments
 ///
 /// * `name` - Specific marketplace to update, or `None` to update all.
-async fn run_update(name: Option<String>) -> Result<(), String> {
+/// * `plugins` - Whether to also update installed plugins bundled in the marketplace repository
+///   (on unless `--no-plugins` is given).
+async fn run_update(name: Option<String>, plugins: bool) -> Result<(), String> {
     let config = MarketplaceConfig::load()?;
     let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
-    let factory = HostClientFactory::with_defaults();
 
     let entries: Vec<_> = match &name {
         Some(n) => {
@@ -222,16 +404,20 @@ async fn run_update(name: Option<String>) -> Result<(), String> {
         return Ok(());
     }
 
-    let mut successes = 0;
+    let mut updated: Vec<MarketplaceCache> = Vec::new();
     let mut failures: Vec<(String, String)> = Vec::new();
 
     for entry in entries {
         print!("Updating '{}'... ", entry.name);
-        let repo = entry.source.to_repo();
-
-        let client = factory.create(repo.host());
+        // 初回やキャッシュ破損時は全件を追加として扱う
+        let previous = registry.get(&entry.name).ok().flatten();
         match registry
-            .fetch_cache(&*client, &entry.name, &repo, entry.source_path.as_deref())
+            .fetch_source(
+                &entry.name,
+                &entry.source,
+                entry.source_path.as_deref(),
+                entry.git_ref.as_deref(),
+            )
             .await
         {
             Ok(cache) => {
@@ -239,8 +425,9 @@ async fn run_update(name: Option<String>) -> Result<(), String> {
                     println!("FAILED");
                     failures.push((entry.name.clone(), e.to_string()));
                 } else {
-                    println!("{} plugin(s)", cache.plugins.len());
-                    successes += 1;
+                    print_update_diff(&cache, &UpdateDiff::between(previous.as_ref(), &cache));
+                    print_validation_warnings(&cache);
+                    updated.push(cache);
                 }
             }
             Err(e) => {
@@ -250,8 +437,8 @@ async fn run_update(name: Option<String>) -> Result<(), String> {
         }
     }
 
-    if successes > 0 {
-        println!("\nUpdated {} marketplace(s).", successes);
+    if !updated.is_empty() {
+        println!("\nUpdated {} marketplace(s).", updated.len());
     }
 
     if !failures.is_empty() {
@@ -259,14 +446,117 @@ async fn run_update(name: Option<String>) -> Result<(), String> {
         for (name, error) in &failures {
             eprintln!("  {}: {}", name, error);
         }
-        if successes == 0 {
+        if updated.is_empty() {
             return Err("All updates failed.".to_string());
         }
     }
 
+    if plugins {
+        update_bundled_plugins(&updated).await?;
+    }
+
     Ok(())
 }
 
+/// 更新結果のプラグイン数と、更新前からの差分（追加/削除/更新）を表示する
+///
+/// # Arguments
+///
+/// * `cache` - Marketplace cache fetched by the update.
+/// * `diff` - Plugin diff against the previously cached marketplace.
+fn print_update_diff(cache: &MarketplaceCache, diff: &UpdateDiff) {
+    println!("{} plugin(s) ({})", cache.plugins.len(), diff.summary());
+    for line in diff.detail_lines() {
+        println!("  {}", line);
+    }
+}
+
+/// 更新したマーケットプレイスのリポジトリに同梱されたインストール済みプラグインを更新する
+///
+/// プラグインの取得元はマーケットプレイスと同じリポジトリのため、マーケットプレイス本体の
+/// 更新に合わせて取り込む。外部リポジトリを参照するプラグインは対象外（`plm update` で更新する）。
+///
+/// # Arguments
+///
+/// * `updated` - Marketplace caches refreshed by this run.
+async fn update_bundled_plugins(updated: &[MarketplaceCache]) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {}", e))?;
+    let installed = cache.list().map_err(|e| e.to_string())?;
+    let project_root = crate::env::project_root().map_err(|e| e.to_string())?;
+
+    let bundled: Vec<(String, String)> = updated
+        .iter()
+        .flat_map(|mp| bundled_plugins(mp, &installed))
+        .collect();
+    if bundled.is_empty() {
+        return Ok(());
+    }
+
+    println!("\nUpdating bundled plugins...");
+    let warnings = WarningCollector::default();
+    let mut outcomes = Vec::new();
+    for (marketplace, plugin) in &bundled {
+        let outcome = update_plugin(
+            &cache,
+            plugin,
+            Some(marketplace),
+            &project_root,
+            None,
+            None,
+            false,
+            false,
+        )
+        .await;
+        display_single_result(&outcome, &warnings, false);
+        outcomes.push(outcome);
+    }
+    display_deploy_hints(&outcomes);
+    if let Some(rendered) = render_warnings("Warnings", &warnings.snapshot()) {
+        eprintln!("\n{}", rendered);
+    }
+
+    let failed = outcomes
+        .iter()
+        .filter(|o| matches!(o.status, UpdateStatus::Failed))
+        .count();
+    if failed > 0 {
+        return Err(format!("Failed to update {} bundled plugin(s).", failed));
+    }
+    Ok(())
+}
+
+/// マーケットプレイスのリポジトリ内を相対パスで参照するプラグインのうち、インストール済みのもの
+///
+/// `(marketplace, cache id)` の組を manifest の順で返す。エントリ名が変わった（`renamedFrom`）
+/// プラグインは旧名のキャッシュ id で返し、更新時にリネームの案内を出させる。
+///
+/// # Arguments
+///
+/// * `marketplace` - Refreshed marketplace cache.
+/// * `installed` - Installed plugins as `(marketplace, cache id)` pairs.
+pub(crate) fn bundled_plugins(
+    marketplace: &MarketplaceCache,
+    installed: &[(Option<String>, String)],
+) -> Vec<(String, String)> {
+    let ids: Vec<&str> = installed
+        .iter()
+        .filter(|(mp, _)| mp.as_deref() == Some(marketplace.name.as_str()))
+        .map(|(_, id)| id.as_str())
+        .collect();
+    marketplace
+        .plugins
+        .iter()
+        .filter(|plugin| matches!(plugin.source, PluginSource::Local(_)))
+        .flat_map(|plugin| {
+            ids.iter().copied().filter(move |id| {
+                *id == plugin.name
+                    || find_renamed_entry(marketplace, id).is_some_and(|e| e.name == plugin.name)
+            })
+        })
+        .map(|id| (marketplace.name.clone(), id.to_string()))
+        .collect()
+}
+
 /// # Arguments
 ///
 /// * `name` - Marketplace name whose details should be displayed.
@@ -281,6 +571,10 @@ async fn run_show(name: String) -> Result<(), String> {
     println!("Marketplace: {}", entry.name);
     println!("Source: {}", entry.source.full_name());
     println!("Path: {}", entry.source_path.as_deref().unwrap_or("(root)"));
+    println!(
+        "Ref: {}",
+        entry.git_ref.as_deref().unwrap_or("(default branch)")
+    );
 
     match registry.get(&name) {
         Ok(Some(cache)) => {
@@ -326,3 +620,7 @@ async fn run_show(name: String) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "marketplace_test.rs"]
+mod tests;
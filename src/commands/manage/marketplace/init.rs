@@ -0,0 +1,319 @@
+//! `plm marketplace init`: マーケットプレイスリポジトリの雛形生成
+//!
+//! 生成するファイル:
+//!
+//! ```text
+//! <dir>/
+//! ├── .claude-plugin/marketplace.json
+//! ├── README.md
+//! ├── plugins/example-plugin/
+//! │   ├── .claude-plugin/plugin.json
+//! │   └── skills/example-skill/SKILL.md
+//! └── .github/workflows/validate-marketplace.yml
+//! ```
+//!
+//! 既存ファイルは 1 件ずつ上書きするか確認し、断られたものは残す。
+
+use crate::commands::manage::init::{to_pretty_json, INITIAL_VERSION};
+use crate::marketplace::normalize_name;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+/// サンプルプラグインの名前
+const SAMPLE_PLUGIN: &str = "example-plugin";
+
+/// サンプルプラグインに含める Skill の名前
+const SAMPLE_SKILL: &str = "example-skill";
+
+/// 生成した・上書きした・残したファイル（`root` からの相対パス）
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct InitReport {
+    pub created: Vec<PathBuf>,
+    pub overwritten: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+}
+
+/// 雛形を生成する
+///
+/// # Arguments
+///
+/// * `dir` - Marketplace repository directory (created if missing).
+/// * `name` - Marketplace name (defaults to the directory name).
+/// * `owner` - Owner name written to `marketplace.json`.
+pub(super) fn run_init(
+    dir: PathBuf,
+    name: Option<String>,
+    owner: Option<String>,
+) -> Result<(), String> {
+    let raw_name = match name {
+        Some(name) => name,
+        None => dir_name(&dir)?,
+    };
+    let name = normalize_name(&raw_name)?;
+    let files = scaffold_files(&name, owner.as_deref())?;
+
+    let interactive = io::stdin().is_terminal();
+    let report = write_files(&dir, &files, |path| {
+        if !interactive {
+            return Ok(false);
+        }
+        confirm_overwrite(path)
+    })?;
+
+    println!("Initialized marketplace '{}' in {}:", name, dir.display());
+    for path in &report.created {
+        println!("  created {}", path.display());
+    }
+    for path in &report.overwritten {
+        println!("  overwritten {}", path.display());
+    }
+    for path in &report.skipped {
+        println!("  skipped {} (already exists)", path.display());
+    }
+    println!(
+        "Try it locally: plm marketplace add {} --name {}",
+        local_source(&dir),
+        name
+    );
+    Ok(())
+}
+
+/// ディレクトリ名（マーケットプレイス名の既定値）
+///
+/// # Arguments
+///
+/// * `dir` - Marketplace repository directory.
+fn dir_name(dir: &Path) -> Result<String, String> {
+    let absolute = if dir.is_absolute() {
+        dir.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(|e| format!("Failed to get current directory: {}", e))?
+            .join(dir)
+    };
+    absolute
+        .components()
+        .filter_map(|c| match c {
+            std::path::Component::Normal(n) => Some(n.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .next_back()
+        .ok_or_else(|| {
+            format!(
+                "Cannot derive marketplace name from {}; use --name",
+                dir.display()
+            )
+        })
+}
+
+/// `plm marketplace add` にローカルディレクトリとして渡せる表記（相対パスは `./` 始まり）
+///
+/// # Arguments
+///
+/// * `dir` - Marketplace repository directory.
+fn local_source(dir: &Path) -> String {
+    let display = dir.display().to_string();
+    if dir.is_absolute() || display.starts_with('.') {
+        display
+    } else {
+        format!("./{}", display)
+    }
+}
+
+/// 生成するファイル（`root` からの相対パスと内容）
+///
+/// # Arguments
+///
+/// * `name` - Marketplace name.
+/// * `owner` - Owner name written to `marketplace.json`.
+pub(crate) fn scaffold_files(
+    name: &str,
+    owner: Option<&str>,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    let plugin_dir = PathBuf::from("plugins").join(SAMPLE_PLUGIN);
+
+    let mut marketplace = serde_json::json!({
+        "name": name,
+        "plugins": [{
+            "name": SAMPLE_PLUGIN,
+            "source": format!("./plugins/{}", SAMPLE_PLUGIN),
+            "description": "Sample plugin generated by plm marketplace init",
+            "version": INITIAL_VERSION,
+        }],
+    });
+    if let Some(owner) = owner {
+        marketplace["owner"] = serde_json::json!({ "name": owner });
+    }
+    let plugin = serde_json::json!({
+        "name": SAMPLE_PLUGIN,
+        "version": INITIAL_VERSION,
+        "description": "Sample plugin generated by plm marketplace init",
+    });
+
+    Ok(vec![
+        (
+            PathBuf::from(".claude-plugin").join("marketplace.json"),
+            to_pretty_json(&marketplace)?,
+        ),
+        (PathBuf::from("README.md"), readme(name)),
+        (
+            plugin_dir.join(".claude-plugin").join("plugin.json"),
+            to_pretty_json(&plugin)?,
+        ),
+        (
+            plugin_dir
+                .join("skills")
+                .join(SAMPLE_SKILL)
+                .join("SKILL.md"),
+            sample_skill(),
+        ),
+        (
+            PathBuf::from(".github")
+                .join("workflows")
+                .join("validate-marketplace.yml"),
+            workflow(name),
+        ),
+    ])
+}
+
+/// 雛形を書き込む
+///
+/// 既存ファイルは `confirm` が `true` を返したものだけ上書きする。
+///
+/// # Arguments
+///
+/// * `root` - Marketplace repository directory (created if missing).
+/// * `files` - Relative paths and contents to write.
+/// * `confirm` - Asks whether to overwrite an existing file.
+pub(crate) fn write_files(
+    root: &Path,
+    files: &[(PathBuf, String)],
+    mut confirm: impl FnMut(&Path) -> Result<bool, String>,
+) -> Result<InitReport, String> {
+    let mut report = InitReport::default();
+    for (rel, content) in files {
+        let path = root.join(rel);
+        let exists = path.exists();
+        if exists && !confirm(&path)? {
+            report.skipped.push(rel.clone());
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        if exists {
+            report.overwritten.push(rel.clone());
+        } else {
+            report.created.push(rel.clone());
+        }
+    }
+    Ok(report)
+}
+
+/// 既存ファイルを上書きするか確認する
+///
+/// # Arguments
+///
+/// * `path` - Existing file.
+fn confirm_overwrite(path: &Path) -> Result<bool, String> {
+    print!("{} already exists. Overwrite? [y/N]: ", path.display());
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| e.to_string())?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes"))
+}
+
+/// README.md の内容
+///
+/// # Arguments
+///
+/// * `name` - Marketplace name.
+fn readme(name: &str) -> String {
+    format!(
+        r#"# {name}
+
+A plugin marketplace for [plm](https://github.com/DIO0550/plugin-manager).
+
+## Usage
+
+```bash
+plm marketplace add <owner>/<repo> --name {name}
+plm install {plugin}@{name}
+```
+
+## Adding a plugin
+
+1. Create `plugins/<plugin>/.claude-plugin/plugin.json` and its components
+   (see `plugins/{plugin}` for an example).
+2. Add an entry to `plugins` in `.claude-plugin/marketplace.json`:
+
+   ```json
+   {{ "name": "<plugin>", "source": "./plugins/<plugin>", "description": "..." }}
+   ```
+
+3. Check it locally with `plm pack plugins/<plugin> --check`.
+"#,
+        name = name,
+        plugin = SAMPLE_PLUGIN
+    )
+}
+
+/// サンプル Skill の SKILL.md
+fn sample_skill() -> String {
+    format!(
+        r#"---
+name: {skill}
+description: Sample skill generated by plm marketplace init. Replace it with your own.
+---
+
+# {skill}
+
+Describe when to use this skill and the steps to follow.
+"#,
+        skill = SAMPLE_SKILL
+    )
+}
+
+/// marketplace.json とプラグインを検証する GitHub Actions ワークフロー
+///
+/// # Arguments
+///
+/// * `name` - Marketplace name.
+fn workflow(name: &str) -> String {
+    format!(
+        r#"name: Validate marketplace
+
+on:
+  push:
+  pull_request:
+
+jobs:
+  validate:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - name: Install plm
+        run: cargo install plm
+      - name: Validate marketplace.json
+        run: plm marketplace add ./ --name {name}
+      - name: Check plugins
+        run: |
+          for plugin in plugins/*/; do
+            plm pack "$plugin" --check --strict
+          done
+"#,
+        name = name
+    )
+}
+
+#[cfg(test)]
+#[path = "init_test.rs"]
+mod tests;
@@ -0,0 +1,97 @@
+use super::*;
+use crate::marketplace::{MarketplaceRegistry, MarketplaceSourceRef, PluginSource};
+use crate::plugin::PluginManifest;
+use tempfile::TempDir;
+
+fn never_confirm(_: &Path) -> Result<bool, String> {
+    panic!("no file should need confirmation")
+}
+
+#[test]
+fn scaffold_writes_every_file() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("tools");
+    let files = scaffold_files("tools", None).unwrap();
+
+    let report = write_files(&root, &files, never_confirm).unwrap();
+
+    assert_eq!(report.created.len(), files.len());
+    for (rel, _) in &files {
+        assert!(root.join(rel).is_file(), "{}", rel.display());
+    }
+    let workflow =
+        fs::read_to_string(root.join(".github/workflows/validate-marketplace.yml")).unwrap();
+    assert!(workflow.contains("plm marketplace add ./ --name tools"));
+}
+
+#[test]
+fn owner_is_written_only_when_given() {
+    let manifest = |owner| -> serde_json::Value {
+        let files = scaffold_files("tools", owner).unwrap();
+        serde_json::from_str(&files[0].1).unwrap()
+    };
+    assert_eq!(
+        manifest(Some("Company Inc."))["owner"]["name"],
+        "Company Inc."
+    );
+    assert!(manifest(None).get("owner").is_none());
+}
+
+#[test]
+fn existing_files_are_overwritten_only_when_confirmed() {
+    let temp = TempDir::new().unwrap();
+    let files = scaffold_files("tools", None).unwrap();
+    fs::write(temp.path().join("README.md"), "# mine\n").unwrap();
+    fs::create_dir_all(temp.path().join(".claude-plugin")).unwrap();
+    fs::write(temp.path().join(".claude-plugin/marketplace.json"), "{}").unwrap();
+
+    let mut asked = Vec::new();
+    let report = write_files(temp.path(), &files, |path| {
+        asked.push(path.to_path_buf());
+        Ok(path.ends_with("marketplace.json"))
+    })
+    .unwrap();
+
+    assert_eq!(asked.len(), 2);
+    assert_eq!(
+        report.overwritten,
+        [PathBuf::from(".claude-plugin/marketplace.json")]
+    );
+    assert_eq!(report.skipped, [PathBuf::from("README.md")]);
+    assert_eq!(report.created.len(), files.len() - 2);
+    assert_eq!(
+        fs::read_to_string(temp.path().join("README.md")).unwrap(),
+        "# mine\n"
+    );
+}
+
+#[tokio::test]
+async fn generated_marketplace_can_be_registered() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("company-tools");
+    let files = scaffold_files("company-tools", Some("Company Inc.")).unwrap();
+    write_files(&root, &files, never_confirm).unwrap();
+
+    // plm marketplace add ./company-tools と同じ経路で読み込む
+    let locator = MarketplaceSourceRef::parse_locator(root.to_str().unwrap()).unwrap();
+    let registry = MarketplaceRegistry::with_cache_dir(temp.path().join("cache")).unwrap();
+    let cache = registry
+        .fetch_source("company-tools", &locator.source, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(cache.owner.as_ref().unwrap().name, "Company Inc.");
+    assert!(cache.validation_warnings().is_empty());
+    assert_eq!(cache.plugins.len(), 1);
+    let PluginSource::Local(ref source) = cache.plugins[0].source else {
+        panic!("sample plugin should be bundled in the repository");
+    };
+    let plugin_dir = root.join(source);
+    let manifest = PluginManifest::load(&plugin_dir.join(".claude-plugin/plugin.json")).unwrap();
+    assert_eq!(manifest.name, cache.plugins[0].name);
+    assert!(plugin_dir
+        .join("skills")
+        .join(SAMPLE_SKILL)
+        .join("SKILL.md")
+        .is_file());
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::marketplace::MarketplacePlugin;
+use chrono::Utc;
+
+fn plugin(name: &str, source: PluginSource) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source,
+        description: None,
+        version: None,
+        renamed_from: Vec::new(),
+        stats: Default::default(),
+    }
+}
+
+fn marketplace(plugins: Vec<MarketplacePlugin>) -> MarketplaceCache {
+    MarketplaceCache {
+        name: "company-tools".to_string(),
+        fetched_at: Utc::now(),
+        source: "github:company/claude-plugins".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins,
+    }
+}
+
+#[test]
+fn bundled_plugins_picks_installed_relative_path_plugins() {
+    let mp = marketplace(vec![
+        plugin(
+            "formatter",
+            PluginSource::Local("./plugins/formatter".into()),
+        ),
+        plugin(
+            "linter",
+            PluginSource::External {
+                source: "github".into(),
+                repo: "other/linter".into(),
+            },
+        ),
+        plugin("debugger", PluginSource::Local("./plugins/debugger".into())),
+    ]);
+    let installed = vec![
+        (Some("company-tools".to_string()), "formatter".to_string()),
+        (Some("company-tools".to_string()), "linter".to_string()),
+        // 別マーケットプレイスの同名プラグインは対象外
+        (Some("other-mp".to_string()), "debugger".to_string()),
+    ];
+
+    assert_eq!(
+        bundled_plugins(&mp, &installed),
+        vec![("company-tools".to_string(), "formatter".to_string())]
+    );
+}
+
+#[test]
+fn bundled_plugins_includes_plugins_installed_under_a_previous_name() {
+    let mut renamed = plugin(
+        "formatter",
+        PluginSource::Local("./plugins/formatter".into()),
+    );
+    renamed.renamed_from = vec!["fmt".to_string()];
+    let mp = marketplace(vec![renamed]);
+    let installed = vec![(Some("company-tools".to_string()), "fmt".to_string())];
+
+    assert_eq!(
+        bundled_plugins(&mp, &installed),
+        vec![("company-tools".to_string(), "fmt".to_string())]
+    );
+}
@@ -1,14 +1,371 @@
+//! plm pack コマンド
+//!
+//! プラグインを配布用の zip アーカイブにまとめる。アーカイブには各ファイルの SHA-256 を
+//! 記録した `checksums.json` を同梱し、install / import 時に改ざん・破損を検出できるようにする。
+//! `--precompile` では Command / Agent のターゲット別変換結果を `dist/` に生成して同梱する
+//! （[`crate::component::precompile`]）。
+
+use crate::commands::examples::Example;
+use crate::component::precompile;
+use crate::component::ComponentKind;
+use crate::plugin::meta::{resolve_manifest_path, META_FILE};
+use crate::plugin::{relative_key, Checksums, Plugin, PluginManifest, CHECKSUMS_FILE};
+use crate::target::{all_targets, reserved_name_conflicts, PluginOrigin, ReservedNameConflict};
 use clap::Parser;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
 
 #[derive(Debug, Parser)]
 pub struct Args {
     pub path: String,
+
+    /// パッケージを作らず、スキャン結果と除外されたパスを表示する
+    #[arg(long)]
+    pub check: bool,
+
+    /// ターゲット組み込みの予約名と衝突するコンポーネントがあればエラーにする
+    #[arg(long, requires = "check")]
+    pub strict: bool,
+
+    /// 出力する zip ファイル（省略時はカレントディレクトリの `<name>.zip`）
+    #[arg(long, short = 'o', conflicts_with = "check")]
+    pub output: Option<PathBuf>,
+
+    /// マニフェストの必須フィールドとコンポーネントの検証を省略する
+    #[arg(long, conflicts_with = "check")]
+    pub no_verify: bool,
+
+    /// Command / Agent を Copilot・Codex 向けに事前変換し `dist/` として同梱する
+    #[arg(long, conflicts_with = "check")]
+    pub precompile: bool,
 }
 
+/// `plm pack` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm pack ./my-plugin",
+        description: "Create my-plugin.zip with a checksums.json for integrity checks",
+    },
+    Example {
+        command: "plm pack ./my-plugin -o dist/my-plugin.zip",
+        description: "Write the archive to a specific path",
+    },
+    Example {
+        command: "plm pack ./my-plugin --precompile",
+        description: "Bundle Copilot/Codex conversions under dist/ so install can skip converting",
+    },
+    Example {
+        command: "plm pack ./my-plugin --check",
+        description: "Show which components would be packaged and which paths are excluded",
+    },
+    Example {
+        command: "plm pack ./my-plugin --check --strict",
+        description: "Also fail when a component reuses a target's built-in command name",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm pack`.
 pub async fn run(args: Args) -> Result<(), String> {
-    println!("pack: {:?}", args);
-    Err("not implemented".to_string())
+    if args.check {
+        return run_check(Path::new(&args.path), args.strict);
+    }
+    run_pack(
+        Path::new(&args.path),
+        args.output.as_deref(),
+        args.no_verify,
+        args.precompile,
+    )
+}
+
+/// `plm pack`: 検証のうえプラグインを zip アーカイブにまとめる
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `output` - Archive path (`None` writes `<name>.zip` to the current directory).
+/// * `no_verify` - Skip manifest and component validation (`--no-verify`).
+/// * `precompile` - Regenerate `dist/` before packaging (`--precompile`).
+fn run_pack(
+    root: &Path,
+    output: Option<&Path>,
+    no_verify: bool,
+    precompile: bool,
+) -> Result<(), String> {
+    let manifest = load_manifest(root)?;
+    if !no_verify {
+        for warning in validate_for_pack(root, &manifest)? {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+    if precompile {
+        let plugin = load_plugin(root)?;
+        let count = precompile::precompile(root, plugin.components())
+            .map_err(|e| format!("Failed to precompile: {}", e))?;
+        println!(
+            "🔧 Precompiled {} file(s) into {}/",
+            count,
+            precompile::DIST_DIR
+        );
+    }
+
+    let output = output
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(format!("{}.zip", manifest.name)));
+    println!("📦 Packaging {}...", manifest.name);
+    let count = write_archive(root, &manifest, &output)?;
+    println!(
+        "✅ Created {} ({} file(s) + {})",
+        output.display(),
+        count,
+        CHECKSUMS_FILE
+    );
+    Ok(())
 }
+
+/// pack 前の検証（失敗はエラー、空のプラグインは警告として返す）
+///
+/// - `plugin.json` の `name` / `version` が空でないこと
+/// - `plugin.json` で指定したコンポーネントのパスが存在すること
+/// - コンポーネントが 1 つ以上あること（無ければ警告）
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `manifest` - Loaded `plugin.json`.
+pub(crate) fn validate_for_pack(
+    root: &Path,
+    manifest: &PluginManifest,
+) -> Result<Vec<String>, String> {
+    let empty: Vec<&str> = [("name", &manifest.name), ("version", &manifest.version)]
+        .into_iter()
+        .filter(|(_, value)| value.trim().is_empty())
+        .map(|(field, _)| field)
+        .collect();
+    if !empty.is_empty() {
+        return Err(format!(
+            "plugin.json: required field(s) are empty: {} (use --no-verify to skip validation)",
+            empty.join(", ")
+        ));
+    }
+
+    let declared = [
+        ("commands", &manifest.commands),
+        ("agents", &manifest.agents),
+        ("skills", &manifest.skills),
+        ("instructions", &manifest.instructions),
+        ("hooks", &manifest.hooks),
+    ];
+    for (field, path) in declared {
+        if let Some(path) = path {
+            if !root.join(path).exists() {
+                return Err(format!(
+                    "plugin.json: '{}' points to a missing path: {}",
+                    field, path
+                ));
+            }
+        }
+    }
+
+    let plugin = Plugin::new(manifest.clone(), root.to_path_buf(), PluginOrigin::Unknown)
+        .map_err(|e| format!("Failed to scan plugin: {}", e))?;
+    if plugin.components().is_empty() {
+        return Ok(vec![format!(
+            "{} has no components (skills, agents, commands, instructions or hooks)",
+            manifest.name
+        )]);
+    }
+    Ok(Vec::new())
+}
+
+/// プラグインのファイルと `checksums.json` を zip に書き出す
+///
+/// エントリは `<name>/` 配下に置く（展開時にトップディレクトリを除去するため）。
+/// 除外ルールに該当するパスと `.plm-meta.json`、出力先自身は含めない。
+/// 書き出したファイル数（`checksums.json` を除く）を返す。
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `manifest` - Loaded `plugin.json`.
+/// * `output` - Archive path to create.
+pub(crate) fn write_archive(
+    root: &Path,
+    manifest: &PluginManifest,
+    output: &Path,
+) -> Result<usize, String> {
+    let rules = manifest.exclude_rules().map_err(|e| e.to_string())?;
+    let file = File::create(output)
+        .map_err(|e| format!("Failed to create {}: {}", output.display(), e))?;
+    let output = fs::canonicalize(output).map_err(|e| e.to_string())?;
+    let zip_error = |e: zip::result::ZipError| format!("Failed to write archive: {}", e);
+    let io_error = |e: std::io::Error| format!("Failed to write archive: {}", e);
+
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default();
+    let mut checksums = Checksums::default();
+    let entries = WalkDir::new(root)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !rules.is_excluded_under(root, e.path()));
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let key = relative_key(root, entry.path());
+        if key == CHECKSUMS_FILE
+            || key == META_FILE
+            || fs::canonicalize(entry.path()).is_ok_and(|p| p == output)
+        {
+            continue;
+        }
+
+        let content = fs::read(entry.path())
+            .map_err(|e| format!("Failed to read {}: {}", entry.path().display(), e))?;
+        checksums.insert(&key, &content);
+        zip.start_file(format!("{}/{}", manifest.name, key), options)
+            .map_err(zip_error)?;
+        zip.write_all(&content).map_err(io_error)?;
+    }
+
+    let json = checksums.to_json().map_err(|e| e.to_string())?;
+    zip.start_file(format!("{}/{}", manifest.name, CHECKSUMS_FILE), options)
+        .map_err(zip_error)?;
+    zip.write_all(json.as_bytes()).map_err(io_error)?;
+    zip.finish().map_err(zip_error)?;
+    Ok(checksums.files.len())
+}
+
+/// `plm pack --check`: プラグインをスキャンしてコンポーネント数と除外パスを表示する
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `strict` - Fail when components collide with reserved names (`--strict`).
+fn run_check(root: &Path, strict: bool) -> Result<(), String> {
+    let plugin = load_plugin(root)?;
+    println!("{}", render_check(&plugin));
+    let stale = precompile::check(root, plugin.components()).unwrap_or_default();
+    if !stale.is_empty() {
+        return Err(format!(
+            "{}/ is out of date with the sources ({} problem(s)); run 'plm pack --precompile'",
+            precompile::DIST_DIR,
+            stale.len()
+        ));
+    }
+    let conflicts = reserved_conflicts(&plugin);
+    if strict && !conflicts.is_empty() {
+        return Err(format!(
+            "{} component(s) collide with reserved names",
+            conflicts.len()
+        ));
+    }
+    Ok(())
+}
+
+/// 全ターゲットについて予約名と衝突するコンポーネントを集める
+///
+/// # Arguments
+///
+/// * `plugin` - Scanned plugin.
+fn reserved_conflicts(plugin: &Plugin) -> Vec<ReservedNameConflict> {
+    all_targets()
+        .iter()
+        .flat_map(|t| reserved_name_conflicts(t.as_ref(), plugin.components(), None))
+        .collect()
+}
+
+/// マニフェストを解決してプラグインをスキャンする
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+pub(crate) fn load_plugin(root: &Path) -> Result<Plugin, String> {
+    let manifest = load_manifest(root)?;
+    Plugin::new(manifest, root.to_path_buf(), PluginOrigin::Unknown)
+        .map_err(|e| format!("Failed to scan plugin: {}", e))
+}
+
+/// プラグインルートの `plugin.json` を読み込む
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+fn load_manifest(root: &Path) -> Result<PluginManifest, String> {
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+    let manifest_path = resolve_manifest_path(root)
+        .ok_or_else(|| format!("plugin.json not found in {}", root.display()))?;
+    PluginManifest::load(&manifest_path).map_err(|e| format!("Failed to load manifest: {}", e))
+}
+
+/// チェック結果を表示用テキストに整形する
+///
+/// # Arguments
+///
+/// * `plugin` - Scanned plugin.
+pub(crate) fn render_check(plugin: &Plugin) -> String {
+    let mut lines = vec![format!(
+        "Checking {} v{} ({})",
+        plugin.name(),
+        plugin.manifest().version,
+        plugin.path().display()
+    )];
+
+    lines.push("Components:".to_string());
+    for kind in ComponentKind::all() {
+        let count = plugin
+            .components()
+            .iter()
+            .filter(|c| c.kind == *kind)
+            .count();
+        if count > 0 {
+            lines.push(format!("  {}: {}", kind.plural(), count));
+        }
+    }
+    if plugin.components().is_empty() {
+        lines.push("  (none)".to_string());
+    }
+
+    let excluded = plugin.excluded_paths();
+    lines.push(format!("Excluded: {} path(s)", excluded.len()));
+    for path in excluded {
+        lines.push(format!("  - {}", path.display()));
+    }
+
+    let conflicts = reserved_conflicts(plugin);
+    if !conflicts.is_empty() {
+        lines.push(format!("Reserved names: {} conflict(s)", conflicts.len()));
+        for conflict in &conflicts {
+            lines.push(format!("  ! {}", conflict.message()));
+        }
+    }
+
+    if let Some(problems) = precompile::check(plugin.path(), plugin.components()) {
+        if problems.is_empty() {
+            lines.push(format!(
+                "Precompiled: {}/ is up to date",
+                precompile::DIST_DIR
+            ));
+        } else {
+            lines.push(format!("Precompiled: {} problem(s)", problems.len()));
+            for problem in &problems {
+                lines.push(format!("  ! {}", problem));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "pack_test.rs"]
+mod tests;
@@ -0,0 +1,173 @@
+use super::*;
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use std::fs;
+use tempfile::TempDir;
+
+fn write_plugin(root: &Path, manifest: &str) {
+    fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+    fs::write(root.join(".claude-plugin/plugin.json"), manifest).unwrap();
+}
+
+fn write_skill(root: &Path, rel: &str) {
+    let dir = root.join("skills").join(rel);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("SKILL.md"), "---\nname: s\n---\n").unwrap();
+}
+
+#[test]
+fn render_check_lists_counts_and_excluded_paths() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(
+        temp.path(),
+        r#"{"name":"demo","version":"1.2.0","exclude":["skills/*-draft"]}"#,
+    );
+    write_skill(temp.path(), "alpha");
+    write_skill(temp.path(), "beta-draft");
+
+    let plugin = load_plugin(temp.path()).unwrap();
+    let out = render_check(&plugin);
+
+    assert!(out.contains("Checking demo v1.2.0"));
+    assert!(out.contains("  skills: 1"));
+    assert!(out.contains("Excluded: 1 path(s)"));
+    assert!(out.contains("beta-draft"));
+}
+
+#[test]
+fn render_check_without_components_shows_none() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(temp.path(), r#"{"name":"empty","version":"0.1.0"}"#);
+
+    let plugin = load_plugin(temp.path()).unwrap();
+    let out = render_check(&plugin);
+
+    assert!(out.contains("  (none)"));
+    assert!(out.contains("Excluded: 0 path(s)"));
+}
+
+#[test]
+fn load_plugin_rejects_invalid_exclude_pattern() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(
+        temp.path(),
+        r#"{"name":"demo","version":"0.1.0","exclude":["docs/["]}"#,
+    );
+
+    let err = load_plugin(temp.path()).unwrap_err();
+
+    assert!(err.contains("docs/["));
+}
+
+#[test]
+fn load_plugin_requires_manifest() {
+    let temp = TempDir::new().unwrap();
+
+    let err = load_plugin(temp.path()).unwrap_err();
+
+    assert!(err.contains("plugin.json"));
+}
+
+#[test]
+fn render_check_reports_reserved_command_names() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(temp.path(), r#"{"name":"demo","version":"0.1.0"}"#);
+    fs::create_dir_all(temp.path().join("commands")).unwrap();
+    fs::write(temp.path().join("commands/init.md"), "Initialize\n").unwrap();
+    fs::write(temp.path().join("commands/deploy.md"), "Deploy\n").unwrap();
+
+    let plugin = load_plugin(temp.path()).unwrap();
+    let out = render_check(&plugin);
+
+    assert!(out.contains("Reserved names: 1 conflict(s)"), "{}", out);
+    assert!(out.contains("command 'init'"), "{}", out);
+    assert!(out.contains("built-in codex command"), "{}", out);
+    assert!(run_check(temp.path(), true).is_err());
+    assert!(run_check(temp.path(), false).is_ok());
+}
+
+#[test]
+fn validate_for_pack_requires_name_version_and_declared_paths() {
+    let temp = TempDir::new().unwrap();
+    let manifest = |json: &str| PluginManifest::parse(json).unwrap();
+
+    let err =
+        validate_for_pack(temp.path(), &manifest(r#"{"name":"demo","version":" "}"#)).unwrap_err();
+    assert!(
+        err.contains("required field(s) are empty: version"),
+        "{}",
+        err
+    );
+
+    let err = validate_for_pack(
+        temp.path(),
+        &manifest(r#"{"name":"demo","version":"1.0.0","skills":"custom/skills"}"#),
+    )
+    .unwrap_err();
+    assert!(err.contains("'skills' points to a missing path"), "{}", err);
+}
+
+#[test]
+fn validate_for_pack_warns_about_empty_plugin() {
+    let temp = TempDir::new().unwrap();
+    let manifest = PluginManifest::parse(r#"{"name":"demo","version":"1.0.0"}"#).unwrap();
+
+    let warnings = validate_for_pack(temp.path(), &manifest).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("has no components"));
+
+    write_skill(temp.path(), "alpha");
+    assert!(validate_for_pack(temp.path(), &manifest)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn write_archive_includes_checksums_that_verify_on_extract() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("demo");
+    write_plugin(
+        &root,
+        r#"{"name":"demo","version":"1.0.0","exclude":["skills/*-draft"]}"#,
+    );
+    write_skill(&root, "alpha");
+    write_skill(&root, "beta-draft");
+    fs::write(root.join(".plm-meta.json"), "{}").unwrap();
+    let output = root.join("demo.zip");
+
+    let manifest = load_plugin(&root).unwrap().manifest().clone();
+    let count = write_archive(&root, &manifest, &output).unwrap();
+
+    // plugin.json + SKILL.md（除外パス・メタ・出力先自身は含めない）
+    assert_eq!(count, 2);
+    let archive = fs::read(&output).unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().join("cache")).unwrap();
+    let extracted = cache
+        .store_from_archive(None, "demo", &archive, None)
+        .unwrap();
+    assert!(extracted.join(CHECKSUMS_FILE).exists());
+    assert!(extracted.join("skills/alpha/SKILL.md").exists());
+    assert!(!extracted.join("skills/beta-draft").exists());
+}
+
+#[test]
+fn render_check_reports_stale_precompiled_dist() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(temp.path(), r#"{"name":"demo","version":"1.0.0"}"#);
+    fs::create_dir_all(temp.path().join("commands")).unwrap();
+    fs::write(
+        temp.path().join("commands/review.md"),
+        "---\ndescription: Review\n---\nReview\n",
+    )
+    .unwrap();
+    let plugin = load_plugin(temp.path()).unwrap();
+    precompile::precompile(temp.path(), plugin.components()).unwrap();
+    assert!(render_check(&plugin).contains("Precompiled: dist/ is up to date"));
+
+    fs::write(temp.path().join("commands/review.md"), "changed").unwrap();
+    let text = render_check(&plugin);
+    assert!(text.contains("Precompiled: 1 problem(s)"));
+    assert!(text.contains("  ! commands/review.md: source changed since precompile"));
+    assert!(run_check(temp.path(), false)
+        .unwrap_err()
+        .contains("plm pack --precompile"));
+}
@@ -0,0 +1,199 @@
+//! 対話的なプラグインピッカー
+//!
+//! `plm pick <command>` でインストール済みプラグインをインクリメンタル検索で選び、
+//! 選んだプラグインに対して `info` / `update` / `disable` / `uninstall` を実行する。
+
+use crate::application::list_installed_plugins;
+use crate::commands::args::{
+    BackupArgs, ComponentArgs, GithubOutputArgs, MarketplaceArgs, NotifyArgs, OutputFormat,
+    StdinArgs,
+};
+use crate::commands::examples::Example;
+use crate::commands::info;
+use crate::commands::lifecycle::{disable, uninstall, update};
+use crate::commands::Context;
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::{InstalledPlugin, PackageCache};
+use crate::tui::picker;
+use clap::{Parser, ValueEnum};
+use std::io::{self, IsTerminal};
+
+/// 選んだプラグインに対して実行するコマンド
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PickAction {
+    Info,
+    Update,
+    Disable,
+    Uninstall,
+}
+
+impl PickAction {
+    /// ピッカーの入力行に表示するプロンプト
+    fn prompt(self) -> &'static str {
+        match self {
+            PickAction::Info => "info",
+            PickAction::Update => "update",
+            PickAction::Disable => "disable",
+            PickAction::Uninstall => "uninstall",
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Command to run for the selected plugin
+    #[arg(value_enum)]
+    pub action: PickAction,
+}
+
+/// `plm pick` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm pick info",
+        description: "Search installed plugins and show details of the selected one",
+    },
+    Example {
+        command: "plm pick uninstall",
+        description: "Search installed plugins and uninstall the selected one",
+    },
+];
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm pick`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Err(format!(
+            "plm pick requires an interactive terminal; run `plm {} <name>` instead",
+            args.action.prompt()
+        ));
+    }
+
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
+    let mut plugins = list_installed_plugins(&cache)
+        .map_err(|e| format!("Failed to list installed plugins: {e}"))?;
+    if plugins.is_empty() {
+        println!("No plugins installed.");
+        return Ok(());
+    }
+    plugins.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let labels = plugins.iter().map(candidate_label).collect();
+    let selected = picker::pick(args.action.prompt(), labels)
+        .map_err(|e| format!("Failed to run picker: {e}"))?;
+    match selected {
+        Some(index) => run_action(args.action, &plugins[index], ctx).await,
+        // キャンセルは何もせず正常終了する
+        None => Ok(()),
+    }
+}
+
+/// ピッカーに表示する候補ラベル（`name@marketplace version`）
+///
+/// # Arguments
+///
+/// * `plugin` - Installed plugin to label.
+pub(crate) fn candidate_label(plugin: &InstalledPlugin) -> String {
+    let marketplace = MarketplaceRef::from_option(plugin.marketplace());
+    let status = if plugin.enabled() { "" } else { " (disabled)" };
+    format!(
+        "{}@{} {}{}",
+        plugin.name(),
+        marketplace.dir_name(),
+        plugin.version(),
+        status
+    )
+}
+
+/// 選ばれたプラグインを引数にして各コマンドの `run` を呼ぶ
+///
+/// 同名プラグインを取り違えないよう、マーケットプレイスは各コマンドの指定方法で渡す。
+///
+/// # Arguments
+///
+/// * `action` - Command to run.
+/// * `plugin` - Selected plugin.
+/// * `ctx` - Shared command context passed on to the command.
+async fn run_action(
+    action: PickAction,
+    plugin: &InstalledPlugin,
+    ctx: &Context,
+) -> Result<(), String> {
+    let marketplace = MarketplaceRef::from_option(plugin.marketplace());
+    let name = plugin.name().to_string();
+    match action {
+        PickAction::Info => {
+            info::run(info::Args {
+                name: format!("{}/{}", marketplace.dir_name(), name),
+                format: OutputFormat::Table,
+                files: false,
+                all: false,
+                history: false,
+                json_schema: info::JsonSchema::V2,
+            })
+            .await
+        }
+        PickAction::Update => {
+            update::run(
+                update::Args {
+                    name: Some(format!("{}@{}", name, marketplace.dir_name())),
+                    stdin: StdinArgs { stdin: false },
+                    all: false,
+                    target: None,
+                    follow_renames: false,
+                    channel: None,
+                    allow_downgrade: false,
+                    force: false,
+                    redeploy_only: false,
+                    only: None,
+                    github: GithubOutputArgs {
+                        github_output: false,
+                    },
+                    notify: NotifyArgs { notify: false },
+                    backup: BackupArgs { backup: false },
+                    verbose: false,
+                },
+                ctx,
+            )
+            .await
+        }
+        PickAction::Disable => {
+            disable::run(
+                disable::Args {
+                    name: Some(name),
+                    stdin: StdinArgs { stdin: false },
+                    target: None,
+                    keep: None,
+                    component: ComponentArgs { component: None },
+                    marketplace: MarketplaceArgs {
+                        marketplace: Some(marketplace.dir_name().to_string()),
+                    },
+                    backup: BackupArgs { backup: false },
+                },
+                ctx,
+            )
+            .await
+        }
+        PickAction::Uninstall => {
+            uninstall::run(
+                uninstall::Args {
+                    name: Some(name),
+                    stdin: StdinArgs { stdin: false },
+                    marketplace: MarketplaceArgs {
+                        marketplace: Some(marketplace.dir_name().to_string()),
+                    },
+                    force: false,
+                    sandbox: false,
+                    backup: BackupArgs { backup: false },
+                },
+                ctx,
+            )
+            .await
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "pick_test.rs"]
+mod tests;
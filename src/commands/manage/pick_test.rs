@@ -0,0 +1,20 @@
+use super::*;
+
+#[test]
+fn candidate_label_includes_marketplace_and_version() {
+    let plugin = InstalledPlugin::new_for_test(
+        "formatter",
+        "1.2.0",
+        vec![],
+        None,
+        Some("company-tools".to_string()),
+        true,
+    );
+    assert_eq!(candidate_label(&plugin), "formatter@company-tools 1.2.0");
+}
+
+#[test]
+fn candidate_label_marks_direct_installs_and_disabled_plugins() {
+    let plugin = InstalledPlugin::new_for_test("linter", "0.1.0", vec![], None, None, false);
+    assert_eq!(candidate_label(&plugin), "linter@github 0.1.0 (disabled)");
+}
@@ -0,0 +1,100 @@
+//! plm placements コマンド
+//!
+//! 全ターゲット × 全コンポーネント種別 × 両スコープについて、配置できるかどうかと
+//! 配置パスのテンプレートを表示する。テンプレートはターゲット実装から導出する
+//! （[`placement_rules`] を参照）。
+
+use crate::application::{placement_rules, PlacementRule};
+use crate::commands::examples::Example;
+use crate::component::{ComponentKind, Scope};
+use crate::target::TargetKind;
+use clap::Parser;
+use comfy_table::{presets::UTF8_FULL, Table};
+use serde::Serialize;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `plm placements` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm placements",
+        description: "Show where each component kind is deployed for every target",
+    },
+    Example {
+        command: "plm placements --json",
+        description: "Print machine-readable JSON",
+    },
+];
+
+/// `placements --json` の 1 要素
+#[derive(Serialize)]
+struct PlacementRuleWire<'a> {
+    target: TargetKind,
+    kind: ComponentKind,
+    scope: Scope,
+    supported: bool,
+    path: Option<&'a str>,
+}
+
+/// 配置ルールを JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `rules` - Placement rules to render.
+pub(crate) fn render_json(rules: &[PlacementRule]) -> Result<String, String> {
+    let wire: Vec<PlacementRuleWire> = rules
+        .iter()
+        .map(|rule| PlacementRuleWire {
+            target: rule.target,
+            kind: rule.kind,
+            scope: rule.scope,
+            supported: rule.supported(),
+            path: rule.template.as_deref(),
+        })
+        .collect();
+    serde_json::to_string_pretty(&wire)
+        .map_err(|e| format!("Failed to serialize placements: {}", e))
+}
+
+/// 配置ルールを表にする（未サポートの組み合わせは `-`）
+///
+/// # Arguments
+///
+/// * `rules` - Placement rules to render.
+pub(crate) fn render_table(rules: &[PlacementRule]) -> String {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Target", "Kind", "Scope", "Path"]);
+    for rule in rules {
+        table.add_row(vec![
+            rule.target.as_str(),
+            rule.kind.as_str(),
+            rule.scope.as_str(),
+            rule.template.as_deref().unwrap_or("-"),
+        ]);
+    }
+    table.to_string()
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm placements`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let rules = placement_rules();
+    if args.json {
+        println!("{}", render_json(&rules)?);
+    } else {
+        println!("{}", render_table(&rules));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "placements_test.rs"]
+mod tests;
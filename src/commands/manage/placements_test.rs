@@ -0,0 +1,91 @@
+use super::*;
+use std::path::PathBuf;
+
+/// スナップショット更新を指示する環境変数
+const UPDATE_ENV: &str = "PLM_UPDATE_SNAPSHOTS";
+
+fn rule(
+    target: TargetKind,
+    kind: ComponentKind,
+    scope: Scope,
+    path: Option<&str>,
+) -> PlacementRule {
+    PlacementRule {
+        target,
+        kind,
+        scope,
+        template: path.map(String::from),
+    }
+}
+
+#[test]
+fn render_json_marks_unsupported_rules() {
+    let rules = vec![
+        rule(
+            TargetKind::Copilot,
+            ComponentKind::Command,
+            Scope::Project,
+            Some("{project}/.github/prompts/{marketplace}/{plugin}/{name}.prompt.md"),
+        ),
+        rule(
+            TargetKind::Copilot,
+            ComponentKind::Command,
+            Scope::Personal,
+            None,
+        ),
+    ];
+
+    let json: serde_json::Value = serde_json::from_str(&render_json(&rules).unwrap()).unwrap();
+
+    assert_eq!(json[0]["target"], "copilot");
+    assert_eq!(json[0]["kind"], "command");
+    assert_eq!(json[0]["scope"], "project");
+    assert_eq!(json[0]["supported"], true);
+    assert_eq!(
+        json[0]["path"],
+        "{project}/.github/prompts/{marketplace}/{plugin}/{name}.prompt.md"
+    );
+    assert_eq!(json[1]["supported"], false);
+    assert!(json[1]["path"].is_null());
+}
+
+#[test]
+fn render_table_shows_dash_for_unsupported_rules() {
+    let rules = vec![rule(
+        TargetKind::Antigravity,
+        ComponentKind::Hook,
+        Scope::Project,
+        None,
+    )];
+
+    let table = render_table(&rules);
+
+    assert!(table.contains("antigravity"), "{}", table);
+    assert!(table.contains(" - "), "{}", table);
+}
+
+/// 配置ルール表のスナップショット
+///
+/// ターゲットの配置実装を変えると差分になる。意図した変更なら
+/// `PLM_UPDATE_SNAPSHOTS=1` を付けて実行し、`snapshots/placements.json` を更新する。
+/// スナップショットが無い場合は現在の表で作成する。
+#[test]
+fn placement_rules_match_snapshot() {
+    let actual = format!("{}\n", render_json(&placement_rules()).unwrap());
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/commands/manage/snapshots/placements.json");
+    let update = std::env::var(UPDATE_ENV).is_ok_and(|v| v == "1");
+    if update || !path.exists() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        expected,
+        actual,
+        "placement rules differ from '{}'; run with {}=1 to update",
+        path.display(),
+        UPDATE_ENV
+    );
+}
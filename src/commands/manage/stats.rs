@@ -0,0 +1,197 @@
+//! plm stats コマンド
+//!
+//! インストール済みプラグインの統計（件数・キャッシュサイズ・更新状況）を表示する。
+
+use crate::application::{collect_stats, PluginStats};
+use crate::commands::examples::Example;
+use crate::commands::info::format_size;
+use crate::commands::list::KindCountsWire;
+use crate::commands::Context;
+use crate::marketplace::MarketplaceConfig;
+use crate::plugin::PackageCache;
+use clap::Parser;
+use serde::Serialize;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Only count plugins deployed in the current project
+    #[arg(long)]
+    pub project: bool,
+}
+
+/// `plm stats` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm stats",
+        description: "Summarize installed plugins, components and cache usage",
+    },
+    Example {
+        command: "plm stats --project",
+        description: "Only count plugins deployed in the current project",
+    },
+    Example {
+        command: "plm stats --json",
+        description: "Print machine-readable JSON",
+    },
+];
+
+/// `stats --json` のトップレベル
+#[derive(Serialize)]
+struct StatsWire<'a> {
+    /// `all` または `project`
+    scope: &'static str,
+    marketplaces: usize,
+    plugins: usize,
+    enabled: usize,
+    disabled: usize,
+    components: usize,
+    components_by_kind: KindCountsWire<'a>,
+    cache_bytes: u64,
+    last_update: UpdateAgeWire,
+    top_plugins: Vec<TopPluginWire<'a>>,
+}
+
+#[derive(Serialize)]
+struct UpdateAgeWire {
+    within_7_days: usize,
+    within_30_days: usize,
+    within_90_days: usize,
+    older: usize,
+    unknown: usize,
+}
+
+#[derive(Serialize)]
+struct TopPluginWire<'a> {
+    id: &'a str,
+    components: usize,
+}
+
+/// 集計結果を JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `stats` - Aggregated statistics.
+/// * `project` - Whether only project deployments were counted.
+pub(crate) fn render_json(stats: &PluginStats, project: bool) -> Result<String, String> {
+    let age = &stats.update_age;
+    let wire = StatsWire {
+        scope: if project { "project" } else { "all" },
+        marketplaces: stats.marketplaces,
+        plugins: stats.totals.plugins,
+        enabled: stats.totals.enabled,
+        disabled: stats.totals.disabled,
+        components: stats.totals.components,
+        components_by_kind: KindCountsWire(&stats.totals.by_kind),
+        cache_bytes: stats.cache_bytes,
+        last_update: UpdateAgeWire {
+            within_7_days: age.within_7_days,
+            within_30_days: age.within_30_days,
+            within_90_days: age.within_90_days,
+            older: age.older,
+            unknown: age.unknown,
+        },
+        top_plugins: stats
+            .top_plugins
+            .iter()
+            .map(|t| TopPluginWire {
+                id: &t.id,
+                components: t.components,
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize stats: {}", e))
+}
+
+/// 集計結果を表示用テキストにする
+///
+/// # Arguments
+///
+/// * `stats` - Aggregated statistics.
+/// * `project` - Whether only project deployments were counted.
+pub(crate) fn render_text(stats: &PluginStats, project: bool) -> String {
+    let totals = &stats.totals;
+    let mut lines = Vec::new();
+    if project {
+        lines.push("Scope: plugins deployed in the current project".to_string());
+        lines.push(String::new());
+    }
+
+    lines.push(format_row("Marketplaces", &stats.marketplaces.to_string()));
+    lines.push(format_row(
+        "Plugins",
+        &format!(
+            "{} ({} enabled, {} disabled)",
+            totals.plugins, totals.enabled, totals.disabled
+        ),
+    ));
+    lines.push(format_row("Components", &totals.components.to_string()));
+    for (kind, count) in &totals.by_kind {
+        lines.push(format_row(
+            &format!("  {}", kind.plural()),
+            &count.to_string(),
+        ));
+    }
+    lines.push(format_row("Cache size", &format_size(stats.cache_bytes)));
+
+    let age = &stats.update_age;
+    lines.push(String::new());
+    lines.push("Last update:".to_string());
+    for (label, count) in [
+        ("<= 7 days", age.within_7_days),
+        ("8-30 days", age.within_30_days),
+        ("31-90 days", age.within_90_days),
+        ("> 90 days", age.older),
+        ("unknown", age.unknown),
+    ] {
+        lines.push(format!("  {:<14} {}", label, count));
+    }
+
+    lines.push(String::new());
+    lines.push("Top plugins by components:".to_string());
+    if stats.top_plugins.is_empty() {
+        lines.push("  (none)".to_string());
+    }
+    for (i, top) in stats.top_plugins.iter().enumerate() {
+        lines.push(format!("  {}. {} ({})", i + 1, top.id, top.components));
+    }
+
+    lines.join("\n")
+}
+
+/// ラベルと値を揃えて 1 行にする
+///
+/// # Arguments
+///
+/// * `label` - Row label.
+/// * `value` - Row value.
+fn format_row(label: &str, value: &str) -> String {
+    format!("{:<16} {}", format!("{}:", label), value)
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm stats`.
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
+    let cache = PackageCache::new().map_err(|e| format!("Failed to access cache: {e}"))?;
+    let marketplaces = MarketplaceConfig::load()?.list().len();
+    let project_root = ctx.project_root.clone();
+
+    let stats = collect_stats(&cache, &project_root, args.project, marketplaces)
+        .map_err(|e| format!("Failed to collect stats: {e}"))?;
+
+    if args.json {
+        println!("{}", render_json(&stats, args.project)?);
+    } else {
+        println!("{}", render_text(&stats, args.project));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "stats_test.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::application::{summarize_plugins, TopPlugin, UpdateAgeBuckets};
+use crate::component::{Component, ComponentKind};
+use crate::plugin::InstalledPlugin;
+use std::path::PathBuf;
+
+fn stats() -> PluginStats {
+    let skill = Component::new(
+        ComponentKind::Skill,
+        "s".to_string(),
+        PathBuf::from("dummy/s"),
+    );
+    let plugins = vec![
+        InstalledPlugin::new_for_test("a", "1.0.0", vec![skill], None, None, true),
+        InstalledPlugin::new_for_test("b", "1.0.0", vec![], None, None, false),
+    ];
+    PluginStats {
+        marketplaces: 2,
+        totals: summarize_plugins(&plugins),
+        cache_bytes: 2048,
+        update_age: UpdateAgeBuckets {
+            within_7_days: 1,
+            unknown: 1,
+            ..Default::default()
+        },
+        top_plugins: vec![TopPlugin {
+            id: "a".to_string(),
+            components: 1,
+        }],
+    }
+}
+
+#[test]
+fn render_text_shows_all_sections() {
+    let out = render_text(&stats(), false);
+
+    assert!(out.contains("Marketplaces:    2"));
+    assert!(out.contains("Plugins:         2 (1 enabled, 1 disabled)"));
+    assert!(out.contains("  skills:        1"));
+    assert!(out.contains("Cache size:      2.0 KiB"));
+    assert!(out.contains("  <= 7 days      1"));
+    assert!(out.contains("  unknown        1"));
+    assert!(out.contains("  1. a (1)"));
+    assert!(!out.contains("Scope:"));
+}
+
+#[test]
+fn render_text_marks_project_scope_and_empty_top() {
+    let mut stats = stats();
+    stats.top_plugins.clear();
+
+    let out = render_text(&stats, true);
+
+    assert!(out.starts_with("Scope: plugins deployed in the current project"));
+    assert!(out.contains("  (none)"));
+}
+
+#[test]
+fn render_json_structure() {
+    let json: serde_json::Value =
+        serde_json::from_str(&render_json(&stats(), true).unwrap()).unwrap();
+
+    assert_eq!(json["scope"], "project");
+    assert_eq!(json["marketplaces"], 2);
+    assert_eq!(json["plugins"], 2);
+    assert_eq!(json["enabled"], 1);
+    assert_eq!(json["components_by_kind"]["skills"], 1);
+    assert_eq!(json["components_by_kind"]["hooks"], 0);
+    assert_eq!(json["cache_bytes"], 2048);
+    assert_eq!(json["last_update"]["within_7_days"], 1);
+    assert_eq!(json["last_update"]["unknown"], 1);
+    assert_eq!(json["top_plugins"][0]["id"], "a");
+    assert_eq!(json["top_plugins"][0]["components"], 1);
+}
@@ -0,0 +1,76 @@
+//! plm status コマンド
+//!
+//! プロジェクトの plm 管理下ファイルの件数（デプロイマニフェスト `.plm/deployed.json`）と、
+//! `.gitignore` に plm のブロックがあるかを表示する。
+
+use super::gitignore::{has_block, load_manifest};
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::plugin::{DeployedManifest, DEPLOYED_MANIFEST_FILE};
+use clap::Parser;
+use std::path::Path;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+/// `plm status` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[Example {
+    command: "plm status",
+    description: "Show how many files in this project are managed by plm",
+}];
+
+/// 表示用テキストに整形する
+///
+/// # Arguments
+///
+/// * `project_root` - Project root.
+/// * `manifest` - Deployed manifest of the project.
+/// * `gitignore` - Content of `.gitignore` (`None` if missing).
+pub(crate) fn render_status(
+    project_root: &Path,
+    manifest: &DeployedManifest,
+    gitignore: Option<&str>,
+) -> String {
+    let mut lines = vec![
+        format!("Project: {}", project_root.display()),
+        format!(
+            "plm-managed files: {} (listed in {})",
+            manifest.file_count(),
+            DEPLOYED_MANIFEST_FILE
+        ),
+    ];
+    let width = manifest.plugins.keys().map(String::len).max().unwrap_or(0);
+    for (plugin, files) in &manifest.plugins {
+        lines.push(format!(
+            "  {:<width$}  {}",
+            plugin,
+            files.len(),
+            width = width
+        ));
+    }
+    let ignored = match gitignore {
+        Some(content) if has_block(content) => "plm block present",
+        Some(_) => "no plm block (run `plm gitignore` to add one)",
+        None => "not found (run `plm gitignore` to create one)",
+    };
+    lines.push(format!(".gitignore: {}", ignored));
+    lines.join("\n")
+}
+
+/// # Arguments
+///
+/// * `_args` - Parsed CLI arguments for `plm status`.
+/// * `ctx` - Shared command context (project root).
+pub async fn run(_args: Args, ctx: &Context) -> Result<(), String> {
+    let manifest = load_manifest(&ctx.project_root)?;
+    let gitignore = std::fs::read_to_string(ctx.project_root.join(".gitignore")).ok();
+    println!(
+        "{}",
+        render_status(&ctx.project_root, &manifest, gitignore.as_deref())
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "status_test.rs"]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn render_counts_files_per_plugin_and_gitignore_state() {
+    let mut manifest = DeployedManifest::default();
+    manifest.plugins.insert(
+        "mp/tools".to_string(),
+        vec![
+            ".codex/skills/fmt".to_string(),
+            ".codex/skills/lint".to_string(),
+        ],
+    );
+    manifest.plugins.insert(
+        "mp/review-kit".to_string(),
+        vec![".github/agents/review.agent.md".to_string()],
+    );
+
+    let out = render_status(Path::new("/work/app"), &manifest, Some("target/\n"));
+
+    assert!(out.contains("Project: /work/app"));
+    assert!(out.contains("plm-managed files: 3 (listed in .plm/deployed.json)"));
+    assert!(out.contains("  mp/review-kit  1"));
+    assert!(out.contains("  mp/tools       2"));
+    assert!(out.contains(".gitignore: no plm block (run `plm gitignore` to add one)"));
+}
+
+#[test]
+fn render_reports_existing_gitignore_block() {
+    let gitignore = "# >>> plm managed files >>>\n# <<< plm managed files <<<\n";
+
+    let out = render_status(
+        Path::new("/work/app"),
+        &DeployedManifest::default(),
+        Some(gitignore),
+    );
+
+    assert!(out.contains("plm-managed files: 0"));
+    assert!(out.contains(".gitignore: plm block present"));
+}
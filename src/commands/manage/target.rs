@@ -1,5 +1,15 @@
-use crate::target::{AddOutcome, RemoveOutcome, TargetKind, TargetRegistry};
+//! plm target コマンド
+//!
+//! `list` / `add` / `remove` は PLM 全体のターゲットレジストリ（`~/.plm/targets.json`）を、
+//! `enable` / `disable` はプロジェクト設定（`.plm/config.json` の `enabledTargets`）を操作する。
+
+use crate::application::{config_path, ProjectConfig, PROJECT_CONFIG_FILE};
+use crate::commands::examples::Example;
+use crate::commands::Context;
+use crate::target::{all_targets, AddOutcome, RemoveOutcome, TargetKind, TargetRegistry};
 use clap::{Parser, Subcommand};
+use comfy_table::{presets::UTF8_FULL, Table};
+use std::path::Path;
 
 #[derive(Debug, Parser)]
 pub struct Args {
@@ -9,12 +19,34 @@ pub struct Args {
 
 #[derive(Debug, Subcommand)]
 pub enum Command {
-    /// List configured targets
+    /// List targets with their project enabled state and supported components
     #[command(
-        long_about = "Display all registered target environments. Shows which AI coding assistants (codex, copilot) are configured to receive plugin deployments."
+        long_about = "Display every target environment as a table: whether it is enabled for this project (.plm/config.json), whether it is registered, and which component kinds it supports."
     )]
     List,
 
+    /// Enable targets for this project
+    #[command(
+        long_about = "Enable targets for this project by recording them in .plm/config.json. Without a project config every target is enabled. Use --only to enable just the given targets and disable the rest."
+    )]
+    Enable {
+        #[arg(value_enum, required = true)]
+        targets: Vec<TargetKind>,
+
+        /// Enable only these targets and disable all others
+        #[arg(long)]
+        only: bool,
+    },
+
+    /// Disable targets for this project
+    #[command(
+        long_about = "Disable targets for this project by recording the remaining enabled targets in .plm/config.json. install, enable, disable, uninstall and update skip disabled targets. Existing deployments are not removed."
+    )]
+    Disable {
+        #[arg(value_enum, required = true)]
+        targets: Vec<TargetKind>,
+    },
+
     /// Add a target environment
     #[command(
         long_about = "Register a new target environment. Available targets: codex, copilot. Once added, plugins can be deployed to this target."
@@ -34,27 +66,93 @@ pub enum Command {
     },
 }
 
+/// `plm target` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm target list",
+        description: "List registered target environments",
+    },
+    Example {
+        command: "plm target enable copilot --only",
+        description: "Deploy to Copilot only in this project",
+    },
+    Example {
+        command: "plm target disable codex",
+        description: "Stop deploying to Codex in this project",
+    },
+    Example {
+        command: "plm target add codex",
+        description: "Deploy plugins to Codex as well",
+    },
+    Example {
+        command: "plm target remove copilot",
+        description: "Stop deploying plugins to Copilot",
+    },
+];
+
 /// # Arguments
 ///
 /// * `args` - Parsed CLI arguments for `plm target`.
-pub async fn run(args: Args) -> Result<(), String> {
-    let mut registry = TargetRegistry::new().map_err(|e| e.to_string())?;
-
+/// * `ctx` - Shared command context (resolved project root).
+pub async fn run(args: Args, ctx: &Context) -> Result<(), String> {
     match args.command {
         Command::List => {
-            let targets = registry.list().map_err(|e| e.to_string())?;
-
-            if targets.is_empty() {
-                println!("No targets registered. Use 'plm target add <target>' to add one.");
-            } else {
-                println!("Registered Targets:");
-                for target in targets {
-                    println!("  - {}", target.as_str());
+            let project_root = &ctx.project_root;
+            let config = ProjectConfig::load(project_root).map_err(|e| e.to_string())?;
+            let registered = TargetRegistry::new()
+                .and_then(|mut r| r.list())
+                .map_err(|e| e.to_string())?;
+            println!("{}", render_target_table(&config, &registered));
+            println!("{}", config_note(&config, project_root));
+            Ok(())
+        }
+        Command::Enable { targets, only } => {
+            let project_root = &ctx.project_root;
+            let mut config = ProjectConfig::load(project_root).map_err(|e| e.to_string())?;
+            if only {
+                config.enable_only(&targets);
+                config.save(project_root).map_err(|e| e.to_string())?;
+                println!("Enabled only: {}", target_names(&targets));
+                return Ok(());
+            }
+            let mut changed = false;
+            for target in &targets {
+                if config.enable(*target) {
+                    changed = true;
+                    println!("Target enabled: {}", target.as_str());
+                } else {
+                    println!("Target already enabled: {}", target.as_str());
                 }
             }
+            if changed {
+                config.save(project_root).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        }
+        Command::Disable { targets } => {
+            let project_root = &ctx.project_root;
+            let mut config = ProjectConfig::load(project_root).map_err(|e| e.to_string())?;
+            let mut changed = false;
+            for target in &targets {
+                if config.disable(*target) {
+                    changed = true;
+                    println!("Target disabled: {}", target.as_str());
+                } else {
+                    println!("Target already disabled: {}", target.as_str());
+                }
+            }
+            if changed {
+                config.save(project_root).map_err(|e| e.to_string())?;
+            }
+            if config.enabled_targets.as_ref().is_some_and(Vec::is_empty) {
+                println!(
+                    "Warning: no targets are enabled in this project; plugins will not be deployed"
+                );
+            }
             Ok(())
         }
         Command::Add { target } => {
+            let mut registry = TargetRegistry::new().map_err(|e| e.to_string())?;
             match registry.add(target).map_err(|e| e.to_string())? {
                 AddOutcome::Added => println!("Target added: {}", target.as_str()),
                 AddOutcome::AlreadyExists => {
@@ -64,6 +162,7 @@ pub async fn run(args: Args) -> Result<(), String> {
             Ok(())
         }
         Command::Remove { target } => {
+            let mut registry = TargetRegistry::new().map_err(|e| e.to_string())?;
             match registry.remove(target).map_err(|e| e.to_string())? {
                 RemoveOutcome::Removed => println!("Target removed: {}", target.as_str()),
                 RemoveOutcome::NotFound => println!("Target not found: {}", target.as_str()),
@@ -72,3 +171,63 @@ pub async fn run(args: Args) -> Result<(), String> {
         }
     }
 }
+
+/// 全ターゲットの有効状態・登録状態・サポートするコンポーネント種別の表
+///
+/// # Arguments
+///
+/// * `config` - Project config holding the enabled targets.
+/// * `registered` - Targets registered in `~/.plm/targets.json`.
+fn render_target_table(config: &ProjectConfig, registered: &[TargetKind]) -> String {
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec!["Target", "Enabled", "Registered", "Components"])
+        .add_rows(all_targets().iter().map(|target| {
+            let components: Vec<&str> = target
+                .supported_components()
+                .iter()
+                .map(|kind| kind.plural())
+                .collect();
+            vec![
+                target.name().to_string(),
+                yes_no(config.is_enabled(target.kind())).to_string(),
+                yes_no(registered.contains(&target.kind())).to_string(),
+                components.join(", "),
+            ]
+        }));
+    table.to_string()
+}
+
+/// 有効状態の出どころの説明（設定ファイルの有無）
+///
+/// # Arguments
+///
+/// * `config` - Project config holding the enabled targets.
+/// * `project_root` - Project root containing `.plm/config.json`.
+fn config_note(config: &ProjectConfig, project_root: &Path) -> String {
+    match &config.enabled_targets {
+        Some(_) => format!(
+            "Enabled targets from {}",
+            config_path(project_root).display()
+        ),
+        None => format!(
+            "No {} in this project; all targets are enabled",
+            PROJECT_CONFIG_FILE
+        ),
+    }
+}
+
+/// ターゲット名をカンマ区切りで連結する
+fn target_names(targets: &[TargetKind]) -> String {
+    targets
+        .iter()
+        .map(|t| t.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+#[path = "target_test.rs"]
+mod tests;
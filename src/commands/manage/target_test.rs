@@ -0,0 +1,47 @@
+use super::*;
+
+fn row<'a>(table: &'a str, target: &str) -> &'a str {
+    table
+        .lines()
+        .find(|line| line.contains(&format!(" {} ", target)))
+        .unwrap_or_else(|| panic!("no row for {} in\n{}", target, table))
+}
+
+#[test]
+fn table_shows_enabled_state_and_components() {
+    let mut config = ProjectConfig::default();
+    config.enable_only(&[TargetKind::Codex]);
+
+    let table = render_target_table(&config, &[TargetKind::Codex]);
+
+    let codex = row(&table, "codex");
+    assert!(codex.contains("yes"));
+    assert!(codex.contains("skills"));
+    assert!(!row(&table, "copilot").contains("yes"));
+}
+
+#[test]
+fn table_without_config_enables_every_target() {
+    let table = render_target_table(&ProjectConfig::default(), &[]);
+
+    for target in all_targets() {
+        assert!(row(&table, target.name()).contains("yes"));
+    }
+}
+
+#[test]
+fn config_note_names_the_source_of_enabled_state() {
+    let root = Path::new("/work/app");
+    let mut config = ProjectConfig::default();
+
+    assert_eq!(
+        config_note(&config, root),
+        "No .plm/config.json in this project; all targets are enabled"
+    );
+
+    config.disable(TargetKind::Codex);
+    assert_eq!(
+        config_note(&config, root),
+        "Enabled targets from /work/app/.plm/config.json"
+    );
+}
@@ -0,0 +1,658 @@
+//! plm test コマンド
+//!
+//! プラグインのリポジトリルートで実行し、一時ディレクトリのダミープロジェクトに対して
+//! install → 配置物の検証 → disable → uninstall を一通り行って結果を報告する。
+//! 利用者の `~/.plm` やプロジェクトには触れない。
+
+use crate::application::{
+    check_conversions, disable_plugin, enable_plugin, uninstall_plugin, CheckStatus, SourceFormats,
+};
+use crate::commands::examples::Example;
+use crate::commands::manage::pack::load_plugin;
+use crate::component::convert::{convert_agent_content, convert_content, skill_allowed_fields};
+use crate::component::{AgentFormat, CommandFormat, Component, ComponentKind};
+use crate::parser::parse_frontmatter;
+use crate::path_ext::PathExt;
+use crate::plugin::meta::{self, DeployedFile};
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use crate::target::{all_targets, TargetKind};
+use clap::Parser;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 一時キャッシュ上でプラグインを置くマーケットプレイス名
+const TEST_MARKETPLACE: &str = "plm-test";
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Plugin root directory
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Keep the temporary project and print its location
+    #[arg(long)]
+    pub keep_temp: bool,
+}
+
+/// `plm test` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm test",
+        description: "Install, verify, disable and uninstall the plugin in a temp project",
+    },
+    Example {
+        command: "plm test ./my-plugin --json",
+        description: "Print machine-readable JSON for CI",
+    },
+    Example {
+        command: "plm test --keep-temp",
+        description: "Keep the temp project to inspect the placed files",
+    },
+];
+
+/// 検証の段階
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Stage {
+    /// `pack --check` 相当のスキャンと変換の事前検証
+    Static,
+    Install,
+    /// 配置物の存在と frontmatter の形式
+    Placement,
+    Disable,
+    Uninstall,
+}
+
+impl Stage {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Static => "static",
+            Stage::Install => "install",
+            Stage::Placement => "placement",
+            Stage::Disable => "disable",
+            Stage::Uninstall => "uninstall",
+        }
+    }
+}
+
+/// 検証項目 1 件の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TestItem {
+    pub stage: Stage,
+    /// 検証対象（ターゲット名や配置先パスなど）
+    pub subject: String,
+    /// 失敗理由（成功なら `None`）
+    pub error: Option<String>,
+}
+
+/// 検証結果
+#[derive(Debug, Default)]
+pub(crate) struct TestReport {
+    /// プラグイン名（読み込めなかった場合は空）
+    pub plugin: String,
+    pub items: Vec<TestItem>,
+    /// `--keep-temp` で残した一時ディレクトリ
+    pub kept_temp: Option<PathBuf>,
+}
+
+impl TestReport {
+    /// # Arguments
+    ///
+    /// * `stage` - Stage the item belongs to.
+    /// * `subject` - What was checked.
+    fn pass(&mut self, stage: Stage, subject: impl Into<String>) {
+        self.items.push(TestItem {
+            stage,
+            subject: subject.into(),
+            error: None,
+        });
+    }
+
+    /// # Arguments
+    ///
+    /// * `stage` - Stage the item belongs to.
+    /// * `subject` - What was checked.
+    /// * `error` - Why the check failed.
+    fn fail(&mut self, stage: Stage, subject: impl Into<String>, error: impl Into<String>) {
+        self.items.push(TestItem {
+            stage,
+            subject: subject.into(),
+            error: Some(error.into()),
+        });
+    }
+
+    /// 失敗した項目
+    pub(crate) fn failures(&self) -> impl Iterator<Item = &TestItem> {
+        self.items.iter().filter(|item| item.error.is_some())
+    }
+
+    /// exit 1 にすべきか
+    pub(crate) fn has_failures(&self) -> bool {
+        self.failures().next().is_some()
+    }
+}
+
+/// `--json` のトップレベル
+#[derive(Serialize)]
+struct ReportWire<'a> {
+    ok: bool,
+    plugin: &'a str,
+    summary: SummaryWire,
+    results: Vec<ItemWire<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temp_dir: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SummaryWire {
+    passed: usize,
+    failed: usize,
+}
+
+#[derive(Serialize)]
+struct ItemWire<'a> {
+    stage: &'static str,
+    subject: &'a str,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+/// 検証結果を JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `report` - Test report.
+pub(crate) fn render_json(report: &TestReport) -> Result<String, String> {
+    let failed = report.failures().count();
+    let wire = ReportWire {
+        ok: failed == 0,
+        plugin: &report.plugin,
+        summary: SummaryWire {
+            passed: report.items.len() - failed,
+            failed,
+        },
+        results: report
+            .items
+            .iter()
+            .map(|item| ItemWire {
+                stage: item.stage.as_str(),
+                subject: &item.subject,
+                ok: item.error.is_none(),
+                error: item.error.as_deref(),
+            })
+            .collect(),
+        temp_dir: report.kept_temp.as_ref().map(|p| p.display().to_string()),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// 検証結果を段階ごとの一覧と失敗一覧にする
+///
+/// # Arguments
+///
+/// * `report` - Test report.
+pub(crate) fn render_text(report: &TestReport) -> String {
+    let mut sections = vec![format!("Testing {}", report.plugin)];
+
+    let lines: Vec<String> = report
+        .items
+        .iter()
+        .map(|item| {
+            format!(
+                "  [{}] {}: {}",
+                if item.error.is_none() { "ok" } else { "FAIL" },
+                item.stage.as_str(),
+                item.subject
+            )
+        })
+        .collect();
+    sections.push(lines.join("\n"));
+
+    let failures: Vec<String> = report
+        .failures()
+        .map(|item| {
+            format!(
+                "  {}: {}: {}",
+                item.stage.as_str(),
+                item.subject,
+                item.error.as_deref().unwrap_or_default()
+            )
+        })
+        .collect();
+    if !failures.is_empty() {
+        sections.push(format!("Failed:\n{}", failures.join("\n")));
+    }
+    if let Some(temp) = &report.kept_temp {
+        sections.push(format!("Temp project kept at {}", temp.display()));
+    }
+
+    let failed = failures.len();
+    sections.push(format!(
+        "Summary: {} passed, {} failed",
+        report.items.len() - failed,
+        failed
+    ));
+    sections.join("\n\n")
+}
+
+/// プラグインを一時ディレクトリで install / disable / uninstall して検証する
+///
+/// `work_dir` 配下に `cache/`（一時キャッシュ）と `project/`（ダミープロジェクト）を作る。
+/// 前の段階が失敗した場合、それ以降の段階は実行しない。
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `work_dir` - Empty directory to hold the temp cache and project.
+pub(crate) fn run_test(root: &Path, work_dir: &Path) -> Result<TestReport, String> {
+    let mut report = TestReport::default();
+    let plugin = match load_plugin(root) {
+        Ok(plugin) => plugin,
+        Err(e) => {
+            report.fail(Stage::Static, "scan", e);
+            return Ok(report);
+        }
+    };
+    report.plugin = plugin.name().to_string();
+    report.pass(
+        Stage::Static,
+        format!("scan ({} component(s))", plugin.components().len()),
+    );
+    check_static(&mut report, plugin.name(), plugin.components());
+    if report.has_failures() {
+        return Ok(report);
+    }
+
+    let cache = PackageCache::with_cache_dir(work_dir.join("cache"))
+        .map_err(|e| format!("Failed to prepare temp cache: {}", e))?;
+    let project = work_dir.join("project");
+    fs::create_dir_all(&project)
+        .map_err(|e| format!("Failed to create {}: {}", project.display(), e))?;
+    let sandbox = Sandbox {
+        cache: &cache,
+        name: plugin.name(),
+        project: &project,
+    };
+
+    let Some(placed) = install(&mut report, &sandbox, root, plugin.components()) else {
+        return Ok(report);
+    };
+    let outcome = disable_plugin(&cache, sandbox.name, Some(TEST_MARKETPLACE), &project, None);
+    if !outcome.success {
+        report.fail(Stage::Disable, "disable", outcome_error(outcome.error));
+        return Ok(report);
+    }
+    check_removed(&mut report, &placed);
+    uninstall(&mut report, &sandbox);
+    Ok(report)
+}
+
+/// 検証に使う一時キャッシュとダミープロジェクト
+struct Sandbox<'a> {
+    cache: &'a PackageCache,
+    /// キャッシュ上のプラグイン名
+    name: &'a str,
+    project: &'a Path,
+}
+
+/// プラグインを一時キャッシュへコピーして enable し、配置物を検証する
+///
+/// 成功した場合は配置された (ターゲット, 配置物) を返す。
+///
+/// # Arguments
+///
+/// * `report` - Report to add items to.
+/// * `sandbox` - Temp cache and project.
+/// * `root` - Plugin root directory.
+/// * `components` - Components scanned from the plugin.
+fn install(
+    report: &mut TestReport,
+    sandbox: &Sandbox,
+    root: &Path,
+    components: &[Component],
+) -> Option<Vec<(TargetKind, DeployedFile)>> {
+    let plugin_path = sandbox
+        .cache
+        .plugin_path(Some(TEST_MARKETPLACE), sandbox.name);
+    if let Err(e) = root.copy_dir_to(&plugin_path) {
+        report.fail(Stage::Install, "copy to cache", e.to_string());
+        return None;
+    }
+    let result = enable_plugin(
+        sandbox.cache,
+        sandbox.name,
+        Some(TEST_MARKETPLACE),
+        sandbox.project,
+        None,
+    );
+    for conflict in &result.conflicts {
+        report.fail(Stage::Install, "enable", conflict.message());
+    }
+    let outcome = result.outcome;
+    if !outcome.success {
+        report.fail(Stage::Install, "enable", outcome_error(outcome.error));
+        return None;
+    }
+    report.pass(Stage::Install, "enable");
+
+    let deployed = meta::load_meta(&plugin_path)
+        .unwrap_or_default()
+        .deployed_files;
+    let mut placed = Vec::new();
+    for target in all_targets() {
+        let kind = target.kind();
+        let files = deployed.get(kind.as_str()).map_or(&[][..], Vec::as_slice);
+        check_placed_files(report, kind, files, components);
+        placed.extend(files.iter().map(|file| (kind, file.clone())));
+    }
+    Some(placed)
+}
+
+/// uninstall してキャッシュから消えたことを検証する
+///
+/// # Arguments
+///
+/// * `report` - Report to add items to.
+/// * `sandbox` - Temp cache and project.
+fn uninstall(report: &mut TestReport, sandbox: &Sandbox) {
+    let outcome = uninstall_plugin(
+        sandbox.cache,
+        sandbox.name,
+        Some(TEST_MARKETPLACE),
+        sandbox.project,
+    );
+    if !outcome.success {
+        report.fail(Stage::Uninstall, "uninstall", outcome_error(outcome.error));
+    } else if sandbox
+        .cache
+        .is_cached(Some(TEST_MARKETPLACE), sandbox.name)
+    {
+        report.fail(Stage::Uninstall, "cache", "plugin is still in the cache");
+    } else {
+        report.pass(Stage::Uninstall, "uninstall");
+    }
+}
+
+/// # Arguments
+///
+/// * `error` - Error reported by a lifecycle operation.
+fn outcome_error(error: Option<String>) -> String {
+    error.unwrap_or_else(|| "Unknown error".to_string())
+}
+
+/// 全ターゲット向けの変換を事前検証する（エラーのみ失敗として記録）
+///
+/// # Arguments
+///
+/// * `report` - Report to add items to.
+/// * `plugin` - Plugin name.
+/// * `components` - Components scanned from the plugin.
+fn check_static(report: &mut TestReport, plugin: &str, components: &[Component]) {
+    let checks = check_conversions(plugin, components, SourceFormats::default(), &all_targets());
+    let errors: Vec<_> = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Error)
+        .collect();
+    for check in &errors {
+        report.fail(
+            Stage::Static,
+            format!(
+                "convert {}:{} -> {}",
+                check.kind.as_str(),
+                check.component,
+                check.target.as_str()
+            ),
+            check.messages.join("; "),
+        );
+    }
+    if errors.is_empty() {
+        report.pass(
+            Stage::Static,
+            format!("conversions ({} check(s))", checks.len()),
+        );
+    }
+}
+
+/// 1 ターゲット分の配置物を検証する
+///
+/// # Arguments
+///
+/// * `report` - Report to add items to.
+/// * `target` - Target the files were placed for.
+/// * `files` - Files recorded in `deployedFiles` for the target.
+/// * `components` - Components scanned from the plugin.
+fn check_placed_files(
+    report: &mut TestReport,
+    target: TargetKind,
+    files: &[DeployedFile],
+    components: &[Component],
+) {
+    let mut failed = false;
+    for file in files {
+        let path = Path::new(&file.path);
+        let result = if path.exists() {
+            check_frontmatter(path, file.kind, target, components)
+        } else {
+            Err("placed file is missing".to_string())
+        };
+        if let Err(e) = result {
+            report.fail(
+                Stage::Placement,
+                format!("{} {}", target.as_str(), file.path),
+                e,
+            );
+            failed = true;
+        }
+    }
+    if !failed {
+        report.pass(
+            Stage::Placement,
+            format!("{} ({} file(s))", target.as_str(), files.len()),
+        );
+    }
+}
+
+/// disable 後に配置物が残っていないことを検証する
+///
+/// # Arguments
+///
+/// * `report` - Report to add items to.
+/// * `placed` - Files placed on install, with their target.
+fn check_removed(report: &mut TestReport, placed: &[(TargetKind, DeployedFile)]) {
+    let mut failed = false;
+    for (target, file) in placed {
+        if Path::new(&file.path).exists() {
+            report.fail(
+                Stage::Disable,
+                format!("{} {}", target.as_str(), file.path),
+                "placed file remains after disable",
+            );
+            failed = true;
+        }
+    }
+    if !failed {
+        report.pass(Stage::Disable, format!("removed {} file(s)", placed.len()));
+    }
+}
+
+/// 配置物の frontmatter がターゲットの期待する形式かを検証する
+///
+/// - Command / Agent: 元ファイルをターゲット形式へ変換した結果と frontmatter が一致すること
+/// - Skill: `SKILL.md` にターゲットが扱わない top-level フィールドが残っていないこと
+///
+/// # Arguments
+///
+/// * `path` - Placed file (or skill directory).
+/// * `kind` - Component kind of the placed file.
+/// * `target` - Target the file was placed for.
+/// * `components` - Components scanned from the plugin.
+fn check_frontmatter(
+    path: &Path,
+    kind: ComponentKind,
+    target: TargetKind,
+    components: &[Component],
+) -> Result<(), String> {
+    if kind == ComponentKind::Skill {
+        return check_skill_fields(&path.join("SKILL.md"), target);
+    }
+    if !matches!(kind, ComponentKind::Command | ComponentKind::Agent) {
+        return Ok(());
+    }
+    // プレフィクス付きなど元のコンポーネントを特定できない配置物は存在確認のみ
+    let Some(source) = find_source(path, kind, components) else {
+        return Ok(());
+    };
+    let content = read(&source.path)?;
+    let expected = match kind {
+        ComponentKind::Command => expected_command(&content, target.command_format()),
+        _ => expected_agent(&content, target.agent_format()),
+    }
+    .map_err(|e| format!("failed to convert {}: {}", source.path.display(), e))?;
+
+    if frontmatter_of(&read(path)?)? != frontmatter_of(&expected)? {
+        return Err(format!(
+            "frontmatter does not match the {} format",
+            target.as_str()
+        ));
+    }
+    Ok(())
+}
+
+/// # Arguments
+///
+/// * `content` - Source command content (Claude Code format).
+/// * `dest` - Format expected by the target.
+fn expected_command(content: &str, dest: CommandFormat) -> crate::error::Result<String> {
+    if dest == CommandFormat::ClaudeCode {
+        return Ok(content.to_string());
+    }
+    convert_content(content, CommandFormat::ClaudeCode, dest)
+}
+
+/// # Arguments
+///
+/// * `content` - Source agent content (Claude Code format).
+/// * `dest` - Format expected by the target.
+fn expected_agent(content: &str, dest: AgentFormat) -> crate::error::Result<String> {
+    if dest == AgentFormat::ClaudeCode {
+        return Ok(content.to_string());
+    }
+    convert_agent_content(content, AgentFormat::ClaudeCode, dest)
+}
+
+/// 配置先のファイル名から元のコンポーネントを探す
+///
+/// ファイル名が `<name>` または `<name>.<拡張子...>` のコンポーネントのうち、最も長い名前を選ぶ。
+///
+/// # Arguments
+///
+/// * `path` - Placed file.
+/// * `kind` - Component kind of the placed file.
+/// * `components` - Components scanned from the plugin.
+fn find_source<'a>(
+    path: &Path,
+    kind: ComponentKind,
+    components: &'a [Component],
+) -> Option<&'a Component> {
+    let file_name = path.file_name()?.to_str()?;
+    let matches = |name: &str| {
+        file_name
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    };
+    components
+        .iter()
+        .filter(|c| c.kind == kind)
+        .filter_map(|c| {
+            [Some(c.name.as_str()), c.original_name.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter(|name| matches(name))
+                .map(|name| (name.len(), c))
+                .max_by_key(|(len, _)| *len)
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, c)| c)
+}
+
+/// `SKILL.md` の top-level フィールドがターゲットの許可リストに収まっているか
+///
+/// # Arguments
+///
+/// * `skill_md` - Placed `SKILL.md`.
+/// * `target` - Target the skill was placed for.
+fn check_skill_fields(skill_md: &Path, target: TargetKind) -> Result<(), String> {
+    let Some(allowed) = skill_allowed_fields(target) else {
+        return Ok(());
+    };
+    let frontmatter = frontmatter_of(&read(skill_md)?)?;
+    let unsupported: Vec<&str> = frontmatter
+        .as_ref()
+        .and_then(serde_yaml::Value::as_mapping)
+        .into_iter()
+        .flat_map(|mapping| mapping.keys())
+        .filter_map(serde_yaml::Value::as_str)
+        .filter(|key| !allowed.contains(key))
+        .collect();
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "SKILL.md has fields not supported by {}: {}",
+        target.as_str(),
+        unsupported.join(", ")
+    ))
+}
+
+/// # Arguments
+///
+/// * `content` - Markdown document.
+fn frontmatter_of(content: &str) -> Result<Option<serde_yaml::Value>, String> {
+    parse_frontmatter::<serde_yaml::Value>(content)
+        .map(|doc| doc.frontmatter)
+        .map_err(|e| format!("invalid frontmatter: {}", e))
+}
+
+/// # Arguments
+///
+/// * `path` - File to read.
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm test`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let temp = tempfile::tempdir().map_err(|e| format!("Failed to create temp dir: {}", e))?;
+    // 配置記録や設定の読み書きを一時ディレクトリに閉じ込める
+    std::env::set_var("PLM_HOME", temp.path().join("home"));
+
+    let mut report = run_test(&args.path, temp.path())?;
+    if args.keep_temp {
+        report.kept_temp = Some(temp.keep());
+    }
+
+    if args.json {
+        println!("{}", render_json(&report)?);
+    } else {
+        println!("{}", render_text(&report));
+    }
+
+    if report.has_failures() {
+        return Err(format!(
+            "Plugin test failed: {} check(s) failed",
+            report.failures().count()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "test_plugin_test.rs"]
+mod tests;
@@ -0,0 +1,143 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write_plugin(root: &Path, command: &str) {
+    fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+    fs::write(
+        root.join(".claude-plugin/plugin.json"),
+        r#"{"name":"demo","version":"1.0.0"}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("commands")).unwrap();
+    fs::write(root.join("commands/run.md"), command).unwrap();
+    fs::create_dir_all(root.join("skills/review")).unwrap();
+    fs::write(
+        root.join("skills/review/SKILL.md"),
+        "---\nname: review\ndescription: Review code\nallowed-tools: Read\n---\nBody",
+    )
+    .unwrap();
+}
+
+fn item(stage: Stage, subject: &str, error: Option<&str>) -> TestItem {
+    TestItem {
+        stage,
+        subject: subject.to_string(),
+        error: error.map(String::from),
+    }
+}
+
+#[test]
+fn run_test_installs_verifies_and_removes_plugin() {
+    let plugin = TempDir::new().unwrap();
+    write_plugin(
+        plugin.path(),
+        "---\ndescription: Run it\nmodel: opus\n---\nBody",
+    );
+    let work = TempDir::new().unwrap();
+
+    let report = run_test(plugin.path(), work.path()).unwrap();
+
+    assert_eq!(report.plugin, "demo");
+    assert!(!report.has_failures(), "{:?}", report.items);
+    for stage in [
+        Stage::Static,
+        Stage::Install,
+        Stage::Placement,
+        Stage::Disable,
+        Stage::Uninstall,
+    ] {
+        assert!(report.items.iter().any(|i| i.stage == stage), "{:?}", stage);
+    }
+    assert!(!work
+        .path()
+        .join("cache")
+        .join(TEST_MARKETPLACE)
+        .join("demo")
+        .exists());
+}
+
+#[test]
+fn run_test_stops_after_static_failure() {
+    let plugin = TempDir::new().unwrap();
+    write_plugin(plugin.path(), "---\ndescription: [unclosed\n---\nBody");
+    let work = TempDir::new().unwrap();
+
+    let report = run_test(plugin.path(), work.path()).unwrap();
+
+    assert!(report.has_failures());
+    assert!(report.items.iter().all(|i| i.stage == Stage::Static));
+    assert!(!work.path().join("project").exists());
+}
+
+#[test]
+fn check_skill_fields_rejects_fields_the_target_drops() {
+    let temp = TempDir::new().unwrap();
+    let skill_md = temp.path().join("SKILL.md");
+    fs::write(
+        &skill_md,
+        "---\nname: review\nallowed-tools: Read\n---\nBody",
+    )
+    .unwrap();
+
+    let err = check_skill_fields(&skill_md, TargetKind::Codex).unwrap_err();
+    assert!(err.contains("allowed-tools"), "{}", err);
+
+    fs::write(&skill_md, "---\nname: review\ndescription: d\n---\nBody").unwrap();
+    assert!(check_skill_fields(&skill_md, TargetKind::Codex).is_ok());
+}
+
+#[test]
+fn find_source_matches_name_before_extension() {
+    let plugin = TempDir::new().unwrap();
+    write_plugin(plugin.path(), "Body");
+    let scanned = load_plugin(plugin.path()).unwrap();
+    let command = scanned
+        .components()
+        .iter()
+        .find(|c| c.kind == ComponentKind::Command)
+        .unwrap();
+
+    for file in [command.name.clone(), format!("{}.prompt.md", command.name)] {
+        let found = find_source(
+            Path::new(&file),
+            ComponentKind::Command,
+            scanned.components(),
+        );
+        assert_eq!(found.map(|c| &c.path), Some(&command.path), "{}", file);
+    }
+    assert!(find_source(
+        Path::new("other.md"),
+        ComponentKind::Command,
+        scanned.components()
+    )
+    .is_none());
+}
+
+#[test]
+fn render_text_and_json_list_failed_items() {
+    let report = TestReport {
+        plugin: "demo".to_string(),
+        items: vec![
+            item(Stage::Install, "enable", None),
+            item(
+                Stage::Disable,
+                "codex /p/run.md",
+                Some("placed file remains after disable"),
+            ),
+        ],
+        kept_temp: Some(PathBuf::from("/tmp/plm-test")),
+    };
+
+    let text = render_text(&report);
+    assert!(text.contains("[ok] install: enable"));
+    assert!(text.contains("Failed:\n  disable: codex /p/run.md: placed file remains after disable"));
+    assert!(text.contains("Temp project kept at /tmp/plm-test"));
+    assert!(text.ends_with("Summary: 1 passed, 1 failed"));
+
+    let json: serde_json::Value = serde_json::from_str(&render_json(&report).unwrap()).unwrap();
+    assert_eq!(json["ok"], false);
+    assert_eq!(json["summary"]["failed"], 1);
+    assert_eq!(json["temp_dir"], "/tmp/plm-test");
+    assert_eq!(json["results"][1]["stage"], "disable");
+    assert!(json["results"][0].get("error").is_none());
+}
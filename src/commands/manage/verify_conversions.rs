@@ -0,0 +1,355 @@
+//! plm verify-conversions コマンド
+//!
+//! マーケットプレイス（またはローカルディレクトリ）の全プラグインについて、
+//! Command / Agent / Hook を各ターゲット向けに変換し、結果をプラグイン × コンポーネント
+//! × ターゲットのマトリクスで報告する。配置先へのファイル書き込みは行わない。
+
+use crate::application::{check_conversions, CheckStatus, ConversionCheck, SourceFormats};
+use crate::commands::examples::Example;
+use crate::commands::manage::pack::load_plugin;
+use crate::marketplace::{download_marketplace_plugin_with_cache, MarketplaceRegistry};
+use crate::plugin::meta::resolve_manifest_path;
+use crate::plugin::PackageCache;
+use crate::target::{all_targets, TargetKind};
+use clap::Parser;
+use comfy_table::{presets::UTF8_FULL, Cell, Color, Table};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Verify every plugin listed in this marketplace
+    #[arg(
+        long,
+        short = 'm',
+        conflicts_with = "root",
+        required_unless_present = "root"
+    )]
+    pub marketplace: Option<String>,
+
+    /// Verify a local plugin directory, or every plugin directly under it
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `plm verify-conversions` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm verify-conversions --marketplace mp-a",
+        description: "Check that every plugin in a marketplace converts for all targets",
+    },
+    Example {
+        command: "plm verify-conversions --root ./plugins",
+        description: "Check local plugin directories before publishing",
+    },
+    Example {
+        command: "plm verify-conversions --root ./plugins --json",
+        description: "Print machine-readable JSON for CI",
+    },
+];
+
+/// 検証結果
+#[derive(Debug, Default)]
+pub(crate) struct VerifyReport {
+    /// プラグイン × コンポーネント × ターゲットの結果
+    pub checks: Vec<ConversionCheck>,
+    /// 読み込み・ダウンロードできなかったプラグインと理由
+    pub failures: Vec<(String, String)>,
+}
+
+impl VerifyReport {
+    /// 種別ごとの件数
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Status to count.
+    pub(crate) fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// exit 1 にすべきか（変換エラーまたは読み込み失敗がある）
+    pub(crate) fn has_errors(&self) -> bool {
+        self.count(CheckStatus::Error) > 0 || !self.failures.is_empty()
+    }
+}
+
+/// `--json` のトップレベル
+#[derive(Serialize)]
+struct ReportWire<'a> {
+    ok: bool,
+    summary: SummaryWire,
+    results: Vec<CheckWire<'a>>,
+    failures: Vec<FailureWire<'a>>,
+}
+
+#[derive(Serialize)]
+struct SummaryWire {
+    ok: usize,
+    warning: usize,
+    error: usize,
+    failed_plugins: usize,
+}
+
+#[derive(Serialize)]
+struct CheckWire<'a> {
+    plugin: &'a str,
+    kind: &'static str,
+    component: &'a str,
+    target: &'static str,
+    status: &'static str,
+    messages: &'a [String],
+}
+
+#[derive(Serialize)]
+struct FailureWire<'a> {
+    plugin: &'a str,
+    message: &'a str,
+}
+
+/// 検証結果を JSON 文字列にする
+///
+/// # Arguments
+///
+/// * `report` - Verification report.
+pub(crate) fn render_json(report: &VerifyReport) -> Result<String, String> {
+    let wire = ReportWire {
+        ok: !report.has_errors(),
+        summary: SummaryWire {
+            ok: report.count(CheckStatus::Ok),
+            warning: report.count(CheckStatus::Warning),
+            error: report.count(CheckStatus::Error),
+            failed_plugins: report.failures.len(),
+        },
+        results: report
+            .checks
+            .iter()
+            .map(|c| CheckWire {
+                plugin: &c.plugin,
+                kind: c.kind.as_str(),
+                component: &c.component,
+                target: c.target.as_str(),
+                status: c.status.as_str(),
+                messages: &c.messages,
+            })
+            .collect(),
+        failures: report
+            .failures
+            .iter()
+            .map(|(plugin, message)| FailureWire { plugin, message })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&wire).map_err(|e| format!("Failed to serialize report: {}", e))
+}
+
+/// 検証結果をマトリクス表と問題一覧にする
+///
+/// # Arguments
+///
+/// * `report` - Verification report.
+pub(crate) fn render_text(report: &VerifyReport) -> String {
+    let mut sections = Vec::new();
+
+    if !report.checks.is_empty() {
+        let targets: BTreeSet<TargetKind> = report.checks.iter().map(|c| c.target).collect();
+        let mut rows: Vec<(&str, &str, &str)> = Vec::new();
+        for check in &report.checks {
+            let row = (
+                check.plugin.as_str(),
+                check.kind.as_str(),
+                check.component.as_str(),
+            );
+            if !rows.contains(&row) {
+                rows.push(row);
+            }
+        }
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        let mut header = vec!["Plugin".to_string(), "Component".to_string()];
+        header.extend(targets.iter().map(|t| t.as_str().to_string()));
+        table.set_header(header);
+        for (plugin, kind, component) in rows {
+            let mut cells = vec![
+                Cell::new(plugin),
+                Cell::new(format!("{}:{}", kind, component)),
+            ];
+            for target in &targets {
+                let check = report.checks.iter().find(|c| {
+                    c.plugin == plugin
+                        && c.kind.as_str() == kind
+                        && c.component == component
+                        && c.target == *target
+                });
+                cells.push(match check.map(|c| c.status) {
+                    Some(CheckStatus::Ok) => Cell::new("ok").fg(Color::Green),
+                    Some(CheckStatus::Warning) => Cell::new("warning").fg(Color::Yellow),
+                    Some(CheckStatus::Error) => Cell::new("error").fg(Color::Red),
+                    None => Cell::new("-").fg(Color::DarkGrey),
+                });
+            }
+            table.add_row(cells);
+        }
+        sections.push(table.to_string());
+    }
+
+    let mut problems = Vec::new();
+    for (plugin, message) in &report.failures {
+        problems.push(format!("  [error] {}: {}", plugin, message));
+    }
+    for check in report.checks.iter().filter(|c| c.status != CheckStatus::Ok) {
+        for message in &check.messages {
+            problems.push(format!(
+                "  [{}] {} {}:{} -> {}: {}",
+                check.status.as_str(),
+                check.plugin,
+                check.kind.as_str(),
+                check.component,
+                check.target.as_str(),
+                message
+            ));
+        }
+    }
+    if !problems.is_empty() {
+        sections.push(format!("Problems:\n{}", problems.join("\n")));
+    }
+
+    sections.push(format!(
+        "Summary: {} ok, {} warning(s), {} error(s){}",
+        report.count(CheckStatus::Ok),
+        report.count(CheckStatus::Warning),
+        report.count(CheckStatus::Error),
+        if report.failures.is_empty() {
+            String::new()
+        } else {
+            format!(", {} plugin(s) could not be loaded", report.failures.len())
+        }
+    ));
+    sections.join("\n\n")
+}
+
+/// `--root` 配下の検証対象プラグインディレクトリ
+///
+/// `root` 自体がプラグインならそれだけを、そうでなければ直下のプラグインを名前順に返す。
+///
+/// # Arguments
+///
+/// * `root` - Plugin directory or a directory containing plugins.
+pub(crate) fn plugin_dirs(root: &Path) -> Result<Vec<PathBuf>, String> {
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+    if resolve_manifest_path(root).is_some() {
+        return Ok(vec![root.to_path_buf()]);
+    }
+
+    let entries =
+        fs::read_dir(root).map_err(|e| format!("Failed to read {}: {}", root.display(), e))?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir() && resolve_manifest_path(p).is_some())
+        .collect();
+    dirs.sort();
+    if dirs.is_empty() {
+        return Err(format!("No plugins found under {}", root.display()));
+    }
+    Ok(dirs)
+}
+
+/// ローカルディレクトリのプラグインを検証する
+///
+/// # Arguments
+///
+/// * `root` - Plugin directory or a directory containing plugins.
+pub(crate) fn verify_root(root: &Path) -> Result<VerifyReport, String> {
+    let targets = all_targets();
+    let mut report = VerifyReport::default();
+    for dir in plugin_dirs(root)? {
+        match load_plugin(&dir) {
+            Ok(plugin) => report.checks.extend(check_conversions(
+                plugin.name(),
+                plugin.components(),
+                SourceFormats::default(),
+                &targets,
+            )),
+            Err(e) => report.failures.push((dir.display().to_string(), e)),
+        }
+    }
+    Ok(report)
+}
+
+/// マーケットプレイスの全プラグインをダウンロードして検証する
+///
+/// ダウンロード先は一時ディレクトリのキャッシュで、インストール済みキャッシュには触れない。
+///
+/// # Arguments
+///
+/// * `marketplace` - Registered marketplace name.
+async fn verify_marketplace(marketplace: &str) -> Result<VerifyReport, String> {
+    let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
+    let mp_cache = registry
+        .get(marketplace)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Marketplace not found: {}", marketplace))?;
+
+    let temp = tempfile::tempdir().map_err(|e| format!("Failed to create temp dir: {}", e))?;
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf())
+        .map_err(|e| format!("Failed to prepare temp cache: {}", e))?;
+    let targets = all_targets();
+
+    let mut report = VerifyReport::default();
+    for entry in &mp_cache.plugins {
+        match download_marketplace_plugin_with_cache(&entry.name, marketplace, true, &cache).await {
+            Ok(content) => {
+                let formats = SourceFormats {
+                    command: content.command_format(),
+                    agent: content.agent_format(),
+                };
+                report.checks.extend(check_conversions(
+                    content.name(),
+                    &content.components(),
+                    formats,
+                    &targets,
+                ));
+            }
+            Err(e) => report.failures.push((entry.name.clone(), e.to_string())),
+        }
+    }
+    Ok(report)
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm verify-conversions`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let report = match (&args.marketplace, &args.root) {
+        (Some(marketplace), _) => verify_marketplace(marketplace).await?,
+        (None, Some(root)) => verify_root(root)?,
+        (None, None) => return Err("Specify --marketplace or --root".to_string()),
+    };
+
+    if args.json {
+        println!("{}", render_json(&report)?);
+    } else {
+        println!("{}", render_text(&report));
+    }
+
+    if report.has_errors() {
+        return Err(format!(
+            "Conversion check failed: {} error(s), {} plugin(s) could not be loaded",
+            report.count(CheckStatus::Error),
+            report.failures.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "verify_conversions_test.rs"]
+mod tests;
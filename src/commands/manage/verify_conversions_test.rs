@@ -0,0 +1,87 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write_plugin(root: &Path, name: &str, command: &str) {
+    fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+    fs::write(
+        root.join(".claude-plugin/plugin.json"),
+        format!(r#"{{"name":"{}","version":"1.0.0"}}"#, name),
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("commands")).unwrap();
+    fs::write(root.join("commands/run.md"), command).unwrap();
+}
+
+#[test]
+fn plugin_dirs_accepts_single_plugin_or_parent() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(&temp.path().join("b"), "b", "Body");
+    write_plugin(&temp.path().join("a"), "a", "Body");
+    fs::create_dir_all(temp.path().join("not-a-plugin")).unwrap();
+
+    let dirs = plugin_dirs(temp.path()).unwrap();
+    assert_eq!(dirs, vec![temp.path().join("a"), temp.path().join("b")]);
+
+    let single = plugin_dirs(&temp.path().join("a")).unwrap();
+    assert_eq!(single, vec![temp.path().join("a")]);
+
+    let empty = TempDir::new().unwrap();
+    assert!(plugin_dirs(empty.path()).is_err());
+}
+
+#[test]
+fn verify_root_reports_errors_and_warnings() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(
+        &temp.path().join("good"),
+        "good",
+        "---\nmodel: opus\n---\nBody",
+    );
+    write_plugin(
+        &temp.path().join("bad"),
+        "bad",
+        "---\ndescription: [unclosed\n---\nBody",
+    );
+
+    let report = verify_root(temp.path()).unwrap();
+
+    assert!(report.has_errors());
+    assert!(report
+        .checks
+        .iter()
+        .filter(|c| c.plugin == "bad")
+        .all(|c| c.status == CheckStatus::Error));
+    assert!(report
+        .checks
+        .iter()
+        .any(|c| c.plugin == "good" && c.status == CheckStatus::Warning));
+}
+
+#[test]
+fn render_text_and_json_summarize_matrix() {
+    let temp = TempDir::new().unwrap();
+    write_plugin(temp.path(), "demo", "---\nmodel: opus\n---\nBody");
+    let mut report = verify_root(temp.path()).unwrap();
+    report
+        .failures
+        .push(("other".to_string(), "download failed".to_string()));
+
+    let text = render_text(&report);
+    assert!(text.contains("command:run"));
+    assert!(text.contains("[warning] demo command:run -> codex: 'model'"));
+    assert!(text.contains("[error] other: download failed"));
+    assert!(text.contains("1 plugin(s) could not be loaded"));
+
+    let json: serde_json::Value = serde_json::from_str(&render_json(&report).unwrap()).unwrap();
+    assert_eq!(json["ok"], false);
+    assert_eq!(json["summary"]["failed_plugins"], 1);
+    let codex = json["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["target"] == "codex")
+        .unwrap();
+    assert_eq!(codex["status"], "warning");
+    assert_eq!(codex["kind"], "command");
+    assert_eq!(json["failures"][0]["plugin"], "other");
+}
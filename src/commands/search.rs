@@ -0,0 +1,368 @@
+//! plm search コマンド
+//!
+//! 登録済みマーケットプレイスのキャッシュ（marketplace.json）を横断して、プラグイン名と
+//! description をキーワードで部分一致検索する（大文字小文字は区別しない）。
+//! TUI の Discover タブと同じキャッシュを読むだけで、`--refresh` を付けない限り通信しない。
+//! ヒットが 0 件なら exit 1 で終了する（スクリプトから判定しやすくするため）。
+//! `--sort` でマニフェストの任意メタデータ（downloads / stars / updatedAt）の順に並べ替える。
+
+use crate::commands::examples::Example;
+use crate::marketplace::{
+    sort_plugins, MarketplaceCache, MarketplaceConfig, MarketplaceRegistry, PluginSortKey,
+    PluginStats,
+};
+use crate::plugin::{PackageCache, PackageCacheAccess};
+use clap::Parser;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::io::IsTerminal;
+use std::ops::Range;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Keyword matched against plugin names and descriptions (case-insensitive)
+    #[arg(value_name = "KEYWORD")]
+    pub query: String,
+
+    /// Only search this marketplace
+    #[arg(long, short = 'm')]
+    pub marketplace: Option<String>,
+
+    /// Only show plugins that are already installed
+    #[arg(long)]
+    pub installed_only: bool,
+
+    /// Update the marketplace caches before searching
+    #[arg(long)]
+    pub refresh: bool,
+
+    /// Sort order (plugins without the metadata come last)
+    #[arg(long, value_enum, default_value_t = PluginSortKey::Name)]
+    pub sort: PluginSortKey,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `plm search` の使用例（`--help` の EXAMPLES とエラー時のヒントで共有）
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        command: "plm search review",
+        description: "Search every registered marketplace for plugins about review",
+    },
+    Example {
+        command: "plm search lint --marketplace company-tools",
+        description: "Search a single marketplace",
+    },
+    Example {
+        command: "plm search lint --installed-only",
+        description: "Only show matching plugins that are already installed",
+    },
+    Example {
+        command: "plm search lint --sort downloads",
+        description: "Show the most installed matching plugins first",
+    },
+    Example {
+        command: "plm search review --refresh --json",
+        description: "Update the marketplace caches first and print machine-readable JSON",
+    },
+];
+
+/// テーブルに表示する description の最大文字数
+const DESCRIPTION_WIDTH: usize = 60;
+
+/// 検索結果 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SearchHit {
+    pub name: String,
+    pub marketplace: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// このマーケットプレイスからインストール済みか
+    pub installed: bool,
+    /// 並び替え用のメタデータ
+    #[serde(flatten)]
+    pub stats: PluginStats,
+}
+
+/// キャッシュ群からキーワードに一致するプラグインを探す（名前 → マーケットプレイス順）
+///
+/// # Arguments
+///
+/// * `caches` - Cached manifests of the marketplaces to search.
+/// * `query` - Keyword matched against names and descriptions.
+/// * `is_installed` - Whether a plugin (marketplace, name) is installed.
+pub(crate) fn search(
+    caches: &[MarketplaceCache],
+    query: &str,
+    is_installed: impl Fn(&str, &str) -> bool,
+) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = caches
+        .iter()
+        .flat_map(|cache| {
+            cache
+                .plugins
+                .iter()
+                .map(move |plugin| (cache.name.as_str(), plugin))
+        })
+        .filter(|(_, plugin)| {
+            query.is_empty()
+                || !match_ranges(&plugin.name, query).is_empty()
+                || plugin
+                    .description
+                    .as_deref()
+                    .is_some_and(|d| !match_ranges(d, query).is_empty())
+        })
+        .map(|(marketplace, plugin)| SearchHit {
+            name: plugin.name.clone(),
+            marketplace: marketplace.to_string(),
+            version: plugin.version.clone(),
+            description: plugin.description.clone(),
+            installed: is_installed(marketplace, &plugin.name),
+            stats: plugin.stats.clone(),
+        })
+        .collect();
+    hits.sort_by(|a, b| (&a.name, &a.marketplace).cmp(&(&b.name, &b.marketplace)));
+    hits
+}
+
+/// `text` 中で `query` に一致する範囲（バイト位置）を返す
+///
+/// 1 文字ずつ小文字化して比較する。重なる一致は先頭側だけを返す。
+///
+/// # Arguments
+///
+/// * `text` - Text to search in.
+/// * `query` - Keyword to find.
+fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let same = |a: char, b: char| a.to_lowercase().eq(b.to_lowercase());
+
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + query.len() <= chars.len() {
+        let matched = query
+            .iter()
+            .zip(&chars[i..])
+            .all(|(q, (_, c))| same(*q, *c));
+        if matched {
+            let end = chars
+                .get(i + query.len())
+                .map_or(text.len(), |(byte, _)| *byte);
+            ranges.push(chars[i].0..end);
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+/// 一致箇所を強調する（`highlight` が `false` ならそのまま返す）
+///
+/// # Arguments
+///
+/// * `text` - Text to render.
+/// * `query` - Keyword to highlight.
+/// * `highlight` - Whether to emit color codes.
+fn highlight_matches(text: &str, query: &str, highlight: bool) -> String {
+    if !highlight {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut last = 0;
+    for range in match_ranges(text, query) {
+        out.push_str(&text[last..range.start]);
+        out.push_str(&(&text[range.clone()]).yellow().bold().to_string());
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// description を 1 行にまとめ、先頭 [`DESCRIPTION_WIDTH`] 文字に切り詰める
+///
+/// # Arguments
+///
+/// * `description` - Plugin description.
+fn truncate_description(description: &str) -> String {
+    let line = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= DESCRIPTION_WIDTH {
+        return line;
+    }
+    let head: String = line.chars().take(DESCRIPTION_WIDTH - 1).collect();
+    format!("{}…", head.trim_end())
+}
+
+/// 検索結果を列を揃えたテーブルにする
+///
+/// 一致箇所を色付けするため comfy-table は使わず、幅は色付け前の文字数で揃える
+/// （description は最終列なので揃えない）。
+///
+/// # Arguments
+///
+/// * `hits` - Search results.
+/// * `query` - Keyword to highlight.
+/// * `highlight` - Whether to emit color codes.
+pub(crate) fn render_table(hits: &[SearchHit], query: &str, highlight: bool) -> String {
+    let rows: Vec<[String; 5]> = hits
+        .iter()
+        .map(|hit| {
+            [
+                hit.name.clone(),
+                hit.marketplace.clone(),
+                hit.version.clone().unwrap_or_else(|| "-".to_string()),
+                if hit.installed { "✓" } else { "" }.to_string(),
+                hit.description
+                    .as_deref()
+                    .map(truncate_description)
+                    .unwrap_or_default(),
+            ]
+        })
+        .collect();
+    let header = ["NAME", "MARKETPLACE", "VERSION", "INSTALLED", "DESCRIPTION"].map(String::from);
+    let widths: Vec<usize> = (0..4)
+        .map(|col| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let render_row = |row: &[String; 5], is_header: bool| {
+        let mut line = String::new();
+        for (col, cell) in row.iter().enumerate() {
+            // 名前と description だけが検索対象なので、その 2 列だけ強調する
+            let text = if !is_header && (col == 0 || col == 4) {
+                highlight_matches(cell, query, highlight)
+            } else {
+                cell.clone()
+            };
+            line.push_str(&text);
+            if col < 4 {
+                let pad = widths[col] - cell.chars().count() + 2;
+                line.push_str(&" ".repeat(pad));
+            }
+        }
+        line.trim_end().to_string()
+    };
+
+    std::iter::once(render_row(&header, true))
+        .chain(rows.iter().map(|row| render_row(row, false)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 登録済みマーケットプレイスのキャッシュを更新する（失敗したものは警告して古いキャッシュを使う）
+///
+/// # Arguments
+///
+/// * `registry` - Marketplace cache registry.
+/// * `config` - Registered marketplaces.
+/// * `only` - Update only this marketplace.
+async fn refresh_caches(
+    registry: &MarketplaceRegistry,
+    config: &MarketplaceConfig,
+    only: Option<&str>,
+) {
+    for entry in config
+        .list()
+        .iter()
+        .filter(|e| only.is_none_or(|name| e.name == name))
+    {
+        eprintln!("Updating '{}'...", entry.name);
+        let fetched = registry
+            .fetch_source(
+                &entry.name,
+                &entry.source,
+                entry.source_path.as_deref(),
+                entry.git_ref.as_deref(),
+            )
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|cache| registry.store(&cache).map_err(|e| e.to_string()));
+        if let Err(e) = fetched {
+            eprintln!(
+                "{} failed to update marketplace '{}': {}",
+                "Warning:".yellow(),
+                entry.name,
+                e
+            );
+        }
+    }
+}
+
+/// # Arguments
+///
+/// * `args` - Parsed CLI arguments for `plm search`.
+pub async fn run(args: Args) -> Result<(), String> {
+    let config = MarketplaceConfig::load()?;
+    let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
+    if let Some(name) = &args.marketplace {
+        if config.get(name).is_none() {
+            return Err(format!("Marketplace '{}' not found.", name));
+        }
+    }
+    if args.refresh {
+        refresh_caches(&registry, &config, args.marketplace.as_deref()).await;
+    }
+
+    let mut caches = Vec::new();
+    for entry in config
+        .list()
+        .iter()
+        .filter(|e| args.marketplace.as_ref().is_none_or(|name| &e.name == name))
+    {
+        match registry.get(&entry.name) {
+            Ok(Some(cache)) => caches.push(cache),
+            Ok(None) => eprintln!(
+                "{} marketplace '{}' has no cache; skipped (run `plm marketplace update {}`)",
+                "Warning:".yellow(),
+                entry.name,
+                entry.name
+            ),
+            Err(e) => eprintln!(
+                "{} failed to read the cache of marketplace '{}': {}; skipped",
+                "Warning:".yellow(),
+                entry.name,
+                e
+            ),
+        }
+    }
+
+    let package_cache = PackageCache::new().ok();
+    let mut hits = search(&caches, &args.query, |marketplace, name| {
+        package_cache
+            .as_ref()
+            .is_some_and(|cache| cache.is_cached(Some(marketplace), name))
+    });
+    if args.installed_only {
+        hits.retain(|hit| hit.installed);
+    }
+    sort_plugins(&mut hits, args.sort, |hit| (hit.name.as_str(), &hit.stats));
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&hits)
+            .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+        println!("{}", json);
+    } else if !hits.is_empty() {
+        let highlight = std::io::stdout().is_terminal();
+        println!("{}", render_table(&hits, &args.query, highlight));
+    }
+
+    if hits.is_empty() {
+        return Err(format!("No plugins matched '{}'", args.query));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "search_test.rs"]
+mod tests;
@@ -0,0 +1,136 @@
+use super::*;
+use crate::marketplace::{MarketplacePlugin, PluginSource};
+
+fn plugin(name: &str, description: Option<&str>) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source: PluginSource::Local(format!("./plugins/{}", name)),
+        description: description.map(String::from),
+        version: Some("1.0.0".to_string()),
+        renamed_from: vec![],
+        stats: Default::default(),
+    }
+}
+
+fn cache(name: &str, plugins: Vec<MarketplacePlugin>) -> MarketplaceCache {
+    MarketplaceCache {
+        name: name.to_string(),
+        fetched_at: chrono::Utc::now(),
+        source: "owner/repo".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins,
+    }
+}
+
+fn caches() -> Vec<MarketplaceCache> {
+    vec![
+        cache(
+            "team",
+            vec![
+                plugin("code-review", Some("Review pull requests")),
+                plugin("formatter", Some("Format code before REVIEW")),
+                plugin("linter", None),
+            ],
+        ),
+        cache(
+            "company",
+            vec![plugin("Reviewer", Some("Company review rules"))],
+        ),
+    ]
+}
+
+fn labels(hits: &[SearchHit]) -> Vec<String> {
+    hits.iter()
+        .map(|h| format!("{}@{}", h.name, h.marketplace))
+        .collect()
+}
+
+#[test]
+fn search_matches_name_and_description_case_insensitively() {
+    let hits = search(&caches(), "review", |_, _| false);
+
+    assert_eq!(
+        labels(&hits),
+        ["Reviewer@company", "code-review@team", "formatter@team"]
+    );
+}
+
+#[test]
+fn search_flags_installed_plugins_per_marketplace() {
+    let hits = search(&caches(), "review", |marketplace, name| {
+        marketplace == "team" && name == "code-review"
+    });
+
+    let installed: Vec<&str> = hits
+        .iter()
+        .filter(|h| h.installed)
+        .map(|h| h.name.as_str())
+        .collect();
+    assert_eq!(installed, ["code-review"]);
+}
+
+#[test]
+fn search_hits_carry_sort_metadata_into_json() {
+    let mut caches = caches();
+    caches[0].plugins[0].stats.downloads = Some(42);
+
+    let hits = search(&caches, "code-review", |_, _| false);
+    let json = serde_json::to_value(&hits).unwrap();
+
+    assert_eq!(json[0]["downloads"], 42);
+    assert!(json[0].get("stars").is_none());
+}
+
+#[test]
+fn search_without_matches_is_empty() {
+    assert!(search(&caches(), "deploy", |_, _| false).is_empty());
+}
+
+#[test]
+fn match_ranges_finds_every_occurrence() {
+    assert_eq!(match_ranges("Review the review", "REVIEW"), [0..6, 11..17]);
+    assert_eq!(match_ranges("レビュー用 review", "review"), [16..22]);
+    assert!(match_ranges("lint", "linter").is_empty());
+}
+
+#[test]
+fn highlight_wraps_only_the_matches() {
+    assert_eq!(
+        highlight_matches("code-review", "review", false),
+        "code-review"
+    );
+
+    let highlighted = highlight_matches("code-review", "review", true);
+    assert!(highlighted.starts_with("code-"));
+    assert!(highlighted.contains("\u{1b}["));
+    assert_ne!(highlighted, "code-review");
+}
+
+#[test]
+fn long_descriptions_are_truncated_to_60_characters() {
+    let long = "a".repeat(80);
+    let truncated = truncate_description(&long);
+    assert_eq!(truncated.chars().count(), DESCRIPTION_WIDTH);
+    assert!(truncated.ends_with('…'));
+
+    assert_eq!(
+        truncate_description("Line one\n  line two"),
+        "Line one line two"
+    );
+}
+
+#[test]
+fn table_aligns_columns_and_marks_installed_plugins() {
+    let mut hits = search(&caches(), "review", |_, name| name == "formatter");
+    hits.truncate(2);
+
+    let table = render_table(&hits, "review", false);
+
+    assert_eq!(
+        table,
+        "NAME         MARKETPLACE  VERSION  INSTALLED  DESCRIPTION\n\
+         Reviewer     company      1.0.0               Company review rules\n\
+         code-review  team         1.0.0               Review pull requests"
+    );
+}
@@ -1,4 +1,4 @@
-use super::{decide_default_action, DefaultAction};
+use super::{decide_default_action, with_example_hint, DefaultAction};
 
 #[test]
 fn decide_default_action_returns_launch_managed_when_tty() {
@@ -9,3 +9,21 @@ fn decide_default_action_returns_launch_managed_when_tty() {
 fn decide_default_action_returns_print_help_when_non_tty() {
     assert_eq!(decide_default_action(false), DefaultAction::PrintHelp);
 }
+
+#[test]
+fn with_example_hint_appends_example_to_not_found_errors() {
+    let message = with_example_hint("Plugin not found: fmt".to_string(), Some("info"));
+    assert!(message.starts_with("Plugin not found: fmt\n\nExample:\n  plm info formatter"));
+}
+
+#[test]
+fn with_example_hint_keeps_other_errors_unchanged() {
+    assert_eq!(
+        with_example_hint("Network error".to_string(), Some("info")),
+        "Network error"
+    );
+    assert_eq!(
+        with_example_hint("Plugin not found: fmt".to_string(), None),
+        "Plugin not found: fmt"
+    );
+}
@@ -5,10 +5,14 @@
 pub mod convert;
 mod deployment;
 mod model;
+pub mod precompile;
 
 pub use convert::{AgentFormat, CommandFormat};
 pub use deployment::{ComponentDeployment, ConversionConfig, DeploymentOutput};
+pub(crate) use model::symlinks_under;
 pub use model::{
-    Component, ComponentKind, ComponentRef, FileOperation, PlacementContext, PlacementLocation,
-    PlacementScope, ProjectContext, Scope, ScopedPath,
+    assets_dir_for, check_relative_path, ensure_within, large_assets_warning, source_symlinks,
+    symlink_warning, CommandPrefix, Component, ComponentKind, ComponentRef, FileOperation,
+    PlacementContext, PlacementLocation, PlacementScope, ProjectContext, PromptNaming, Scope,
+    ScopedPath,
 };
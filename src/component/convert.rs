@@ -132,7 +132,7 @@ pub fn convert_and_write(
 /// * `content` - Raw markdown content to convert.
 /// * `source_format` - Format of the provided `content`.
 /// * `dest_format` - Target format to convert into.
-fn convert_content(
+pub(crate) fn convert_content(
     content: &str,
     source_format: CommandFormat,
     dest_format: CommandFormat,
@@ -208,7 +208,7 @@ pub fn convert_agent_and_write(
 /// * `content` - Raw markdown content to convert.
 /// * `source_format` - Format of the provided `content`.
 /// * `dest_format` - Target format to convert into.
-fn convert_agent_content(
+pub(crate) fn convert_agent_content(
     content: &str,
     source_format: AgentFormat,
     dest_format: AgentFormat,
@@ -230,6 +230,80 @@ fn convert_agent_content(
     Ok(agent.to_format(target_type)?.to_markdown())
 }
 
+/// デプロイ先で編集された Command をソース形式へ書き戻した内容を返す（内部用）
+///
+/// 同一形式ならデプロイ先の内容をそのまま返す。ClaudeCode からの変換は元の内容に
+/// 編集を取り込む（[`ClaudeCodeCommand::merge_deployed`]）。他の形式へは戻せない。
+///
+/// 戻り値の 2 要素目は、デプロイ先にだけ存在し書き戻せなかったフィールド名。
+///
+/// # Arguments
+///
+/// * `original` - Content of the source (cached) command file.
+/// * `deployed` - Content of the deployed command file.
+/// * `source_format` - Format of `original`.
+/// * `dest_format` - Format of `deployed`.
+pub(crate) fn reverse_convert_content(
+    original: &str,
+    deployed: &str,
+    source_format: CommandFormat,
+    dest_format: CommandFormat,
+) -> Result<(String, Vec<&'static str>)> {
+    if source_format == dest_format {
+        return Ok((deployed.to_string(), Vec::new()));
+    }
+    if source_format != CommandFormat::ClaudeCode {
+        return Err(PlmError::UnsupportedConversion {
+            from: dest_format.to_string(),
+            to: source_format.to_string(),
+        });
+    }
+
+    let target_type = match dest_format {
+        CommandFormat::Copilot => TargetType::Copilot,
+        CommandFormat::Codex => TargetType::Codex,
+        CommandFormat::ClaudeCode => unreachable!("Same format is handled above"),
+    };
+    let (merged, lost) =
+        ClaudeCodeCommand::parse(original)?.merge_deployed(deployed, target_type)?;
+    Ok((merged.to_markdown(), lost))
+}
+
+/// デプロイ先で編集された Agent をソース形式へ書き戻した内容を返す（内部用）
+///
+/// [`reverse_convert_content`] の Agent 版。
+///
+/// # Arguments
+///
+/// * `original` - Content of the source (cached) agent file.
+/// * `deployed` - Content of the deployed agent file.
+/// * `source_format` - Format of `original`.
+/// * `dest_format` - Format of `deployed`.
+pub(crate) fn reverse_convert_agent_content(
+    original: &str,
+    deployed: &str,
+    source_format: AgentFormat,
+    dest_format: AgentFormat,
+) -> Result<(String, Vec<&'static str>)> {
+    if source_format == dest_format {
+        return Ok((deployed.to_string(), Vec::new()));
+    }
+    if source_format != AgentFormat::ClaudeCode {
+        return Err(PlmError::UnsupportedConversion {
+            from: dest_format.to_string(),
+            to: source_format.to_string(),
+        });
+    }
+
+    let target_type = match dest_format {
+        AgentFormat::Copilot => TargetType::Copilot,
+        AgentFormat::Codex => TargetType::Codex,
+        AgentFormat::ClaudeCode => unreachable!("Same format is handled above"),
+    };
+    let (merged, lost) = ClaudeCodeAgent::parse(original)?.merge_deployed(deployed, target_type)?;
+    Ok((merged.to_markdown(), lost))
+}
+
 /// ファイルをコピー（親ディレクトリを作成）
 ///
 /// # Arguments
@@ -328,19 +402,7 @@ pub fn strip_skill_frontmatter_fields(content: &str, allowed: &[&str]) -> String
     // LF / CRLF を問わず正確な本文オフセットを算出できる。
     let segments: Vec<&str> = stripped.split_inclusive('\n').collect();
 
-    let first_is_fence = segments.first().map(|s| line_text(s).trim()).unwrap_or("") == "---";
-    if !first_is_fence {
-        return content.to_string();
-    }
-
-    let closing_index = segments
-        .iter()
-        .enumerate()
-        .skip(1)
-        .find(|(_, seg)| line_text(seg).trim() == "---")
-        .map(|(i, _)| i);
-
-    let Some(closing_index) = closing_index else {
+    let Some(closing_index) = closing_fence_index(&segments) else {
         return content.to_string();
     };
 
@@ -384,6 +446,95 @@ pub fn strip_skill_frontmatter_fields(content: &str, allowed: &[&str]) -> String
     result
 }
 
+/// デプロイ時に [`strip_skill_frontmatter_fields`] で除去された top-level フィールドを、
+/// 元の `SKILL.md` から編集後の内容へ戻す。
+///
+/// 除去されたブロックは元の行のまま、編集後の frontmatter の末尾（閉じ `---` の直前）に
+/// 追加する。編集後の内容に frontmatter が無い場合はそのまま返す。
+///
+/// # Arguments
+///
+/// * `deployed` - Edited `SKILL.md` content taken from the deployment.
+/// * `original` - `SKILL.md` content before deployment.
+/// * `allowed` - Fields kept on deployment (see [`skill_allowed_fields`]).
+pub fn restore_skill_frontmatter_fields(
+    deployed: &str,
+    original: &str,
+    allowed: &[&str],
+) -> String {
+    let removed = removed_frontmatter_lines(original, allowed);
+    if removed.is_empty() {
+        return deployed.to_string();
+    }
+
+    let stripped = deployed.strip_prefix('\u{feff}').unwrap_or(deployed);
+    let bom_len = deployed.len() - stripped.len();
+    let segments: Vec<&str> = stripped.split_inclusive('\n').collect();
+    let Some(closing_index) = closing_fence_index(&segments) else {
+        return deployed.to_string();
+    };
+
+    let insert_at = bom_len
+        + segments[..closing_index]
+            .iter()
+            .map(|s| s.len())
+            .sum::<usize>();
+    let mut result = String::with_capacity(deployed.len());
+    result.push_str(&deployed[..insert_at]);
+    for line in removed {
+        result.push_str(line);
+        result.push('\n');
+    }
+    result.push_str(&deployed[insert_at..]);
+    result
+}
+
+/// `allowed` に含まれない top-level フィールドのブロックに属する frontmatter 行
+///
+/// [`strip_skill_frontmatter_fields`] が除去する行と同じ判定を行う。
+///
+/// # Arguments
+///
+/// * `content` - `SKILL.md` content.
+/// * `allowed` - Fields that are kept.
+fn removed_frontmatter_lines<'a>(content: &'a str, allowed: &[&str]) -> Vec<&'a str> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let segments: Vec<&str> = content.split_inclusive('\n').collect();
+    let Some(closing_index) = closing_fence_index(&segments) else {
+        return Vec::new();
+    };
+
+    let mut removed = Vec::new();
+    let mut keep_current_block = true;
+    for seg in &segments[1..closing_index] {
+        let line = line_text(seg);
+        if let Some(key) = top_level_frontmatter_key(line) {
+            keep_current_block = allowed.contains(&key);
+        }
+        if !keep_current_block {
+            removed.push(line);
+        }
+    }
+    removed
+}
+
+/// frontmatter の閉じ `---` のセグメント位置（frontmatter が無ければ `None`）
+///
+/// # Arguments
+///
+/// * `segments` - Content split with `split_inclusive('\n')` (without BOM).
+fn closing_fence_index(segments: &[&str]) -> Option<usize> {
+    if segments.first().map(|s| line_text(s).trim()) != Some("---") {
+        return None;
+    }
+    segments
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, seg)| line_text(seg).trim() == "---")
+        .map(|(i, _)| i)
+}
+
 /// `split_inclusive('\n')` のセグメントから、末尾の改行 (`\r\n` / `\n`) を除いた
 /// 行内容を取り出す。
 fn line_text(segment: &str) -> &str {
@@ -644,3 +644,61 @@ fn test_strip_skill_frontmatter_preserves_body_with_fence_lines() {
     assert!(!result.contains("allowed-tools"));
     assert!(result.ends_with("\n\nbody line\n\n---\n\nmore body\n"));
 }
+
+#[test]
+fn test_restore_skill_frontmatter_reinserts_stripped_fields() {
+    let original = "---\nname: demo\ndescription: hi\nallowed-tools: Read\nargument-hint: [a] [b]\n---\nbody\n";
+    let allowed = ["name", "description", "metadata"];
+    let deployed = strip_skill_frontmatter_fields(original, &allowed)
+        .replace("description: hi", "description: edited")
+        .replace("body", "new body");
+
+    let result = restore_skill_frontmatter_fields(&deployed, original, &allowed);
+
+    assert_eq!(
+        result,
+        "---\nname: demo\ndescription: edited\nallowed-tools: Read\nargument-hint: [a] [b]\n---\nnew body\n"
+    );
+    // 除去するフィールドが無ければ配置物のまま
+    assert_eq!(
+        restore_skill_frontmatter_fields(
+            "---\nname: x\n---\nb\n",
+            "---\nname: demo\n---\nbody\n",
+            &allowed
+        ),
+        "---\nname: x\n---\nb\n"
+    );
+}
+
+#[test]
+fn test_reverse_convert_content_merges_edits_into_claude_code_source() {
+    let original = sample_claude_code_content();
+    let deployed = convert_content(original, CommandFormat::ClaudeCode, CommandFormat::Copilot)
+        .unwrap()
+        .replace("Please generate", "Always generate");
+
+    let (restored, lost) = reverse_convert_content(
+        original,
+        &deployed,
+        CommandFormat::ClaudeCode,
+        CommandFormat::Copilot,
+    )
+    .unwrap();
+
+    assert!(restored.contains("Always generate a commit message for $ARGUMENTS."));
+    assert!(restored.contains(r#"allowed-tools: "Bash(git:*), Read""#));
+    assert!(lost.is_empty());
+
+    let same = reverse_convert_content(
+        original,
+        "edited",
+        CommandFormat::Copilot,
+        CommandFormat::Copilot,
+    )
+    .unwrap();
+    assert_eq!(same, ("edited".to_string(), vec![]));
+    assert!(matches!(
+        reverse_convert_content(original, "x", CommandFormat::Codex, CommandFormat::Copilot),
+        Err(PlmError::UnsupportedConversion { .. })
+    ));
+}
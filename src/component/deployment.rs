@@ -1,6 +1,11 @@
 //! コンポーネントのデプロイ処理
 //!
 //! `ComponentDeployment` 構造体本体と配置実行 (`execute()` / `deploy_*`) を定義する。
+//! Command / Agent の添付アセット（`<名前>.assets/`）も配置先ファイルの隣へ変換せずに配置する。
+//! コピー元のシンボリックリンクは辿らない（定義ファイル自体がリンクなら配置を拒否し、
+//! ディレクトリ内のリンクはスキップする）。
+//! Command / Agent は `plm pack --precompile` の変換済み成果物（`dist/`）がソースと
+//! 一致すればそれを書き出し、変換を省略する。
 //! Hook 変換のような大きめの処理は `hook_deploy` サブモジュールへ分離。
 
 mod bash;
@@ -8,12 +13,18 @@ mod builder;
 mod conversion;
 mod hook_deploy;
 mod output;
+mod write;
 
-use crate::component::convert;
-use crate::component::{Component, ComponentKind, Scope};
-use crate::error::Result;
+use crate::component::convert::{self, AgentConversionOutcome, ConversionOutcome};
+use crate::component::precompile::{self, Precompiled, StaleReason};
+use crate::component::{
+    assets_dir_for, large_assets_warning, symlinks_under, Component, ComponentKind, Scope,
+};
+use crate::error::{PlmError, Result};
 use crate::fs::{FileSystem, RealFs};
+use crate::scan::ExcludeRules;
 use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 pub use builder::ComponentDeploymentBuilder;
 pub use conversion::ConversionConfig;
@@ -29,6 +40,16 @@ pub struct ComponentDeployment {
     pub scope: Scope,
     pub(super) target_path: PathBuf,
     pub(super) conversion: ConversionConfig,
+    pub(super) exclude: Option<DeployExclude>,
+}
+
+/// デプロイ時に適用する除外ルール
+///
+/// ルールはプラグインルートからの相対パスで評価するため、基準ディレクトリも併せて持つ。
+#[derive(Debug, Clone)]
+pub(crate) struct DeployExclude {
+    pub(crate) plugin_root: PathBuf,
+    pub(crate) rules: ExcludeRules,
 }
 
 impl ComponentDeployment {
@@ -62,6 +83,45 @@ impl ComponentDeployment {
         &self.target_path
     }
 
+    /// 添付アセットが巨大な場合の警告（[`large_assets_warning`] 参照）
+    pub fn assets_warning(&self) -> Option<String> {
+        large_assets_warning(self.kind(), self.source_path())
+    }
+
+    /// コピー元のシンボリックリンクをスキップした場合の警告（[`crate::component::symlink_warning`] 参照）
+    pub fn symlink_warning(&self) -> Option<String> {
+        crate::component::symlink_warning(self.kind(), self.source_path())
+    }
+
+    /// 事前変換した成果物が記録と一致せず使えない場合の警告（[`precompile`] 参照）
+    pub fn precompile_warning(&self) -> Option<String> {
+        let Precompiled::Stale { artifact, reason } = self.precompiled() else {
+            return None;
+        };
+        let why = match reason {
+            StaleReason::PlmVersion(version) => format!("was precompiled by plm {}", version),
+            StaleReason::SourceChanged => "is older than its source".to_string(),
+            StaleReason::ArtifactModified => "was modified after precompile".to_string(),
+        };
+        Some(format!(
+            "Precompiled {} {}; converting again",
+            artifact.display(),
+            why
+        ))
+    }
+
+    /// 配置する変換に対応する事前変換済み成果物を探す
+    ///
+    /// プラグインルートが分からない（除外ルール未設定の）場合は探さない。
+    fn precompiled(&self) -> Precompiled {
+        let (Some(exclude), Some(dist_name)) =
+            (&self.exclude, precompile::dist_name(&self.conversion))
+        else {
+            return Precompiled::Absent;
+        };
+        precompile::lookup(&exclude.plugin_root, self.source_path(), dist_name)
+    }
+
     /// 配置を実行
     ///
     /// `ComponentKind` ごとに専用の `deploy_*` メソッドへディスパッチする。
@@ -71,13 +131,22 @@ impl ComponentDeployment {
 
     /// テスト用エントリポイント（`FileSystem` を注入）
     pub fn execute_with_fs(&self, fs: &dyn FileSystem) -> Result<DeploymentOutput> {
-        match self.kind() {
+        // リンク先（プロジェクト外のファイルなど）を配置しないよう、リンク自体の配置は拒否する
+        if self.source_path().is_symlink() {
+            return Err(PlmError::Validation(format!(
+                "Refusing to deploy symlinked source '{}'",
+                self.source_path().display()
+            )));
+        }
+        let output = match self.kind() {
             ComponentKind::Skill => self.deploy_skill(fs),
             ComponentKind::Command => self.deploy_command(fs),
             ComponentKind::Agent => self.deploy_agent(fs),
             ComponentKind::Instruction => self.deploy_instruction(fs),
             ComponentKind::Hook => self.deploy_hook(fs),
-        }
+        }?;
+        self.deploy_assets(fs)?;
+        Ok(output)
     }
 
     /// Skill ディレクトリを丸ごと配置する。
@@ -87,7 +156,12 @@ impl ComponentDeployment {
     /// 余剰ファイルは削除される。frontmatter 変換がある場合も触るのは `SKILL.md` のみ。
     fn deploy_skill(&self, fs: &dyn FileSystem) -> Result<DeploymentOutput> {
         // Skills are directories — replace target to avoid stale files.
-        fs.replace_dir(self.source_path(), &self.target_path)?;
+        replace_dir_filtered(
+            fs,
+            self.source_path(),
+            &self.target_path,
+            self.exclude.as_ref(),
+        )?;
 
         // ターゲットがサポートしない frontmatter フィールドを SKILL.md から除去する。
         if let ConversionConfig::Skill { target_kind } = &self.conversion {
@@ -108,9 +182,34 @@ impl ComponentDeployment {
         Ok(DeploymentOutput::Copied)
     }
 
+    /// Command / Agent の添付アセット（`<名前>.assets/`）を配置先ファイルの隣へ丸ごと配置する。
+    ///
+    /// アセット内のファイルは変換しない。ソースにアセットが無い場合は、以前の配置で
+    /// 残った配置先のアセットを削除する。それ以外の種別では何もしない。
+    fn deploy_assets(&self, fs: &dyn FileSystem) -> Result<()> {
+        let (Some(source), Some(target)) = (
+            assets_dir_for(self.kind(), self.source_path()),
+            assets_dir_for(self.kind(), &self.target_path),
+        ) else {
+            return Ok(());
+        };
+        if !fs.is_dir(&source) {
+            return fs.remove(&target);
+        }
+        replace_dir_filtered(fs, &source, &target, self.exclude.as_ref())
+    }
+
     fn deploy_command(&self, fs: &dyn FileSystem) -> Result<DeploymentOutput> {
         match &self.conversion {
             ConversionConfig::Command { source, dest } => {
+                if let Precompiled::Fresh(content) = self.precompiled() {
+                    convert::atomic_write(&self.target_path, &content)?;
+                    return Ok(DeploymentOutput::CommandConverted(ConversionOutcome {
+                        converted: true,
+                        source_format: *source,
+                        dest_format: *dest,
+                    }));
+                }
                 let result = convert::convert_and_write(
                     self.source_path(),
                     &self.target_path,
@@ -129,6 +228,14 @@ impl ComponentDeployment {
     fn deploy_agent(&self, fs: &dyn FileSystem) -> Result<DeploymentOutput> {
         match &self.conversion {
             ConversionConfig::Agent { source, dest } => {
+                if let Precompiled::Fresh(content) = self.precompiled() {
+                    convert::atomic_write(&self.target_path, &content)?;
+                    return Ok(DeploymentOutput::AgentConverted(AgentConversionOutcome {
+                        converted: true,
+                        source_format: *source,
+                        dest_format: *dest,
+                    }));
+                }
                 let result = convert::convert_agent_and_write(
                     self.source_path(),
                     &self.target_path,
@@ -163,6 +270,62 @@ impl ComponentDeployment {
     }
 }
 
+/// ディレクトリを置き換える（シンボリックリンクと除外エントリはコピーしない）
+///
+/// リンクも除外ルールも無ければ `FileSystem::replace_dir` に任せる。それ以外は配置先を作り直し、
+/// リンク以外で除外ルールにマッチしないファイル・ディレクトリだけを同じ相対構造でコピーする。
+/// マッチしたディレクトリ配下には潜らない。走査に失敗した場合はエラーを返す。
+///
+/// # Arguments
+///
+/// * `fs` - File system used to copy the entries.
+/// * `source` - Source directory to copy.
+/// * `target` - Destination directory to replace.
+/// * `exclude` - Exclude rules of the plugin, if any.
+fn replace_dir_filtered(
+    fs: &dyn FileSystem,
+    source: &Path,
+    target: &Path,
+    exclude: Option<&DeployExclude>,
+) -> Result<()> {
+    if exclude.is_none() && symlinks_under(source).is_empty() {
+        return fs.replace_dir(source, target);
+    }
+    fs.remove(target)?;
+    fs.create_dir_all(target)?;
+    let mut walker = WalkDir::new(source)
+        .min_depth(1)
+        .follow_links(false)
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let entry = entry.map_err(|e| PlmError::Io(e.into()))?;
+        if entry.path_is_symlink() {
+            continue;
+        }
+        let excluded = exclude.is_some_and(|exclude| {
+            exclude
+                .rules
+                .is_excluded_under(&exclude.plugin_root, entry.path())
+        });
+        if excluded {
+            if entry.file_type().is_dir() {
+                walker.skip_current_dir();
+            }
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(source) else {
+            continue;
+        };
+        let dest = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs.create_dir_all(&dest)?;
+        } else {
+            fs.copy_file(entry.path(), &dest)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "deployment_test.rs"]
 mod tests;
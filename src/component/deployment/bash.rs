@@ -1,26 +1,34 @@
 //! Bash スクリプト書き出しと double-quote エスケープのユーティリティ
 
+use super::write::{write_if_changed, FileWrite};
 use crate::error::Result;
-use std::fs;
+use crate::fs::FileSystem;
 use std::path::Path;
 
 /// スクリプトファイルを書き出し、Unix では実行権限 (0o755) を設定する。
 ///
-/// 親ディレクトリは作成しない（呼び出し側の責任）。`fs::write` が Err を返した
+/// 既存ファイルと内容が同じなら書き込まない（[`write_if_changed`]）。実行権限は
+/// 内容にかかわらず設定する（mtime は変わらない）。
+/// 親ディレクトリは作成しない（呼び出し側の責任）。書き込みが Err を返した
 /// 場合はそのまま伝播させる。
 ///
 /// # Arguments
 ///
+/// * `fs` - File system used to compare and write the script.
 /// * `path` - File path to write the script to.
 /// * `content` - Script contents to write.
-pub(super) fn write_executable_script(path: &Path, content: &str) -> Result<()> {
-    fs::write(path, content)?;
+pub(super) fn write_executable_script(
+    fs: &dyn FileSystem,
+    path: &Path,
+    content: &str,
+) -> Result<FileWrite> {
+    let write = write_if_changed(fs, path, content)?;
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
-        fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
     }
-    Ok(())
+    Ok(write)
 }
 
 /// bash ダブルクォート内で特別な意味を持つ文字をエスケープする。
@@ -1,4 +1,5 @@
 use super::*;
+use crate::fs::RealFs;
 use std::fs;
 use tempfile::TempDir;
 
@@ -69,7 +70,7 @@ fn test_write_executable_script_creates_file_with_content() {
     let path = dir.path().join("test.sh");
     let content = "#!/bin/bash\necho hello";
 
-    write_executable_script(&path, content).unwrap();
+    write_executable_script(&RealFs, &path, content).unwrap();
 
     assert_eq!(fs::read_to_string(&path).unwrap(), content);
 }
@@ -82,7 +83,7 @@ fn test_write_executable_script_sets_executable_permission() {
     let dir = TempDir::new().unwrap();
     let path = dir.path().join("test.sh");
 
-    write_executable_script(&path, "#!/bin/bash").unwrap();
+    write_executable_script(&RealFs, &path, "#!/bin/bash").unwrap();
 
     let mode = fs::metadata(&path).unwrap().permissions().mode();
     assert_eq!(mode & 0o777, 0o755);
@@ -92,5 +93,22 @@ fn test_write_executable_script_sets_executable_permission() {
 fn test_write_executable_script_nonexistent_dir_returns_err() {
     let dir = TempDir::new().unwrap();
     let path = dir.path().join("no_such_subdir").join("test.sh");
-    assert!(write_executable_script(&path, "content").is_err());
+    assert!(write_executable_script(&RealFs, &path, "content").is_err());
+}
+
+#[test]
+fn test_write_executable_script_skips_unchanged_content() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("test.sh");
+    fs::write(&path, "#!/bin/bash\r\necho hello\r\n").unwrap();
+    let before = fs::metadata(&path).unwrap().modified().unwrap();
+
+    let write = write_executable_script(&RealFs, &path, "#!/bin/bash\necho hello\n").unwrap();
+
+    assert_eq!(write, FileWrite::Unchanged);
+    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), before);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "#!/bin/bash\r\necho hello\r\n"
+    );
 }
@@ -2,12 +2,14 @@
 //!
 //! 必須フィールドは `component` / `scope` / `target_path` の 3 個。
 //! 変換設定は `ConversionConfig::None` を default とする任意フィールド。
+//! 除外ルールも任意（未指定なら Skill ディレクトリを丸ごとコピーする）。
 
 use super::conversion::ConversionConfig;
-use super::ComponentDeployment;
+use super::{ComponentDeployment, DeployExclude};
 use crate::component::{Component, Scope};
 use crate::error::{PlmError, Result};
-use std::path::PathBuf;
+use crate::scan::ExcludeRules;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Default)]
 pub struct ComponentDeploymentBuilder {
@@ -15,6 +17,7 @@ pub struct ComponentDeploymentBuilder {
     scope: Option<Scope>,
     target_path: Option<PathBuf>,
     conversion: ConversionConfig,
+    exclude: Option<DeployExclude>,
 }
 
 impl ComponentDeploymentBuilder {
@@ -38,6 +41,15 @@ impl ComponentDeploymentBuilder {
         self
     }
 
+    /// 除外ルールを設定（`plugin_root` はルールの基準ディレクトリ）
+    pub fn exclude(mut self, plugin_root: &Path, rules: ExcludeRules) -> Self {
+        self.exclude = Some(DeployExclude {
+            plugin_root: plugin_root.to_path_buf(),
+            rules,
+        });
+        self
+    }
+
     pub fn build(self) -> Result<ComponentDeployment> {
         let component = self
             .component
@@ -54,6 +66,7 @@ impl ComponentDeploymentBuilder {
             scope,
             target_path,
             conversion: self.conversion,
+            exclude: self.exclude,
         })
     }
 }
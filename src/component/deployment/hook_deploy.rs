@@ -2,11 +2,13 @@
 
 use super::bash::{escape_for_bash_double_quote, write_executable_script};
 use super::output::{DeploymentOutput, HookConvertOutput};
+use super::write::{write_if_changed, FileWrite, WriteSummary};
 use super::ComponentDeployment;
 use crate::error::{PlmError, Result};
 use crate::fs::FileSystem;
 use crate::hooks::converter::{self, SourceFormat, SCRIPTS_DIR};
 use crate::hooks::name::HookName;
+use crate::parser::{ClaudeCodeHookConfig, CopilotHookConfig};
 use crate::target::TargetKind;
 use std::collections::HashSet;
 use std::path::Path;
@@ -74,10 +76,32 @@ impl ComponentDeployment {
         }
     }
 
+    /// Copilot CLI 形式の hooks 設定を Claude Code 形式に戻す
+    ///
+    /// converter は Claude Code 形式を入力とするため、Copilot CLI 形式のまま
+    /// 取り込まれたプラグインは他ターゲット向けに変換する前に逆変換する。
+    /// Copilot ターゲットではそのまま passthrough させる。
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Raw hooks config read from the plugin.
+    /// * `target_kind` - Target the hooks are deployed to.
+    fn normalize_copilot_source(input: String, target_kind: TargetKind) -> Result<String> {
+        if target_kind == TargetKind::Copilot {
+            return Ok(input);
+        }
+        match CopilotHookConfig::parse_if_copilot(&input) {
+            Some(config) => ClaudeCodeHookConfig::from(config).to_json(),
+            None => Ok(input),
+        }
+    }
+
     /// Hook 変換デプロイを実行
     ///
     /// `target_kind` と `plugin_root` は呼び出し側 (executor の `deploy_hook`) が
     /// `ConversionConfig::Hook` から取り出した値を渡す。
+    /// 設定 JSON・ラッパースクリプトとも、既存ファイルと内容が同じなら書き込まない
+    /// （[`write_if_changed`]）。
     pub(super) fn deploy_hook_converted(
         &self,
         fs: &dyn FileSystem,
@@ -85,6 +109,7 @@ impl ComponentDeployment {
         plugin_root: Option<&Path>,
     ) -> Result<DeploymentOutput> {
         let input = fs.read_to_string(self.source_path())?;
+        let input = Self::normalize_copilot_source(input, target_kind)?;
 
         let mut convert_result = converter::convert(&input, target_kind)?;
 
@@ -95,8 +120,13 @@ impl ComponentDeployment {
             && convert_result.scripts.is_empty()
             && convert_result.warnings.is_empty()
         {
-            fs.copy_file(self.source_path(), &self.target_path)?;
-            return Ok(DeploymentOutput::Copied);
+            if let Some(parent) = self.target_path.parent() {
+                fs.create_dir_all(parent)?;
+            }
+            return Ok(match write_if_changed(fs, &self.target_path, &input)? {
+                FileWrite::Unchanged => DeploymentOutput::Unchanged,
+                FileWrite::Created | FileWrite::Updated(_) => DeploymentOutput::Copied,
+            });
         }
 
         // Hook 名をサニタイズ（パスセグメント・シェルコマンドで安全に使えるようにする）
@@ -129,7 +159,8 @@ impl ComponentDeployment {
             fs.create_dir_all(parent)?;
         }
 
-        fs.write(&self.target_path, json_str.as_bytes())?;
+        let mut writes = WriteSummary::default();
+        writes.record(write_if_changed(fs, &self.target_path, &json_str)?);
 
         let script_count = convert_result.scripts.len();
         if script_count == 0 {
@@ -138,6 +169,7 @@ impl ComponentDeployment {
                 script_count: 0,
                 hook_count,
                 source_format: convert_result.source_format,
+                writes,
             }));
         }
 
@@ -160,7 +192,7 @@ impl ComponentDeployment {
 
         let escaped = escape_for_bash_double_quote(&plugin_root_str);
 
-        convert_result.scripts.iter().try_for_each(|script| {
+        for script in &convert_result.scripts {
             let filename = Path::new(&script.path)
                 .file_name()
                 .map(|f| f.to_string_lossy().to_string())
@@ -169,14 +201,15 @@ impl ComponentDeployment {
             let script_path = script_dir.join(&filename);
             let content = script.content.replace("@@PLUGIN_ROOT@@", &escaped);
 
-            write_executable_script(&script_path, &content)
-        })?;
+            writes.record(write_executable_script(fs, &script_path, &content)?);
+        }
 
         Ok(DeploymentOutput::HookConverted(HookConvertOutput {
             warnings: convert_result.warnings,
             script_count,
             hook_count,
             source_format: convert_result.source_format,
+            writes,
         }))
     }
 }
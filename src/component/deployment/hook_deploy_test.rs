@@ -154,6 +154,84 @@ fn test_hook_convert_true_deploys_converted() {
     assert!(json_content.contains("./wrappers/my-hook/"));
 }
 
+/// 2 回目の配置では内容が同じ設定 JSON・スクリプトを書き直さない
+#[test]
+fn test_hook_convert_redeploy_skips_unchanged_files() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("hook.json");
+    let target = temp.path().join("dest/my-hook.json");
+    let plugin_root = temp.path().join("cache/plugin");
+    fs::create_dir_all(&plugin_root).unwrap();
+    fs::write(&source, sample_claude_code_hook_json()).unwrap();
+
+    let deployment = hook_deployment(source, "my-hook", target.clone(), Some(plugin_root));
+    let DeploymentOutput::HookConverted(first) = deployment.execute().unwrap() else {
+        panic!("Expected HookConverted");
+    };
+    assert_eq!(first.writes.unchanged_count, 0);
+    let before = fs::metadata(&target).unwrap().modified().unwrap();
+
+    let DeploymentOutput::HookConverted(second) = deployment.execute().unwrap() else {
+        panic!("Expected HookConverted");
+    };
+    assert_eq!(second.writes.unchanged_count, second.script_count + 1);
+    assert!(second.writes.diffs.is_empty());
+    assert_eq!(fs::metadata(&target).unwrap().modified().unwrap(), before);
+}
+
+/// 設定 JSON が書き換えられていれば書き直し、差分を返す
+#[test]
+fn test_hook_convert_redeploy_reports_diff_for_modified_json() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("hook.json");
+    let target = temp.path().join("dest/my-hook.json");
+    let plugin_root = temp.path().join("cache/plugin");
+    fs::create_dir_all(&plugin_root).unwrap();
+    fs::write(&source, sample_claude_code_hook_json()).unwrap();
+
+    let deployment = hook_deployment(source, "my-hook", target.clone(), Some(plugin_root));
+    deployment.execute().unwrap();
+    let deployed = fs::read_to_string(&target).unwrap();
+    fs::write(
+        &target,
+        deployed.replace("\"version\": 1", "\"version\": 0"),
+    )
+    .unwrap();
+
+    let DeploymentOutput::HookConverted(hr) = deployment.execute().unwrap() else {
+        panic!("Expected HookConverted");
+    };
+    assert_eq!(hr.writes.unchanged_count, hr.script_count);
+    assert_eq!(hr.writes.diffs.len(), 1);
+    assert!(
+        hr.writes.diffs[0].contains("+  \"version\": 1"),
+        "{}",
+        hr.writes.diffs[0]
+    );
+    assert_eq!(fs::read_to_string(&target).unwrap(), deployed);
+}
+
+/// 配置先が同じ内容の passthrough Hook は Unchanged を返す
+#[test]
+fn test_hook_passthrough_redeploy_is_unchanged() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("hook.json");
+    let target = temp.path().join("dest/hook.json");
+    let json = r#"{"version":1,"hooks":{"preToolUse":[{"type":"command","bash":"echo hi"}]}}"#;
+    fs::write(&source, json).unwrap();
+
+    let deployment = hook_deployment(source, "copilot-hook", target.clone(), None);
+
+    assert!(matches!(
+        deployment.execute().unwrap(),
+        DeploymentOutput::Copied
+    ));
+    assert!(matches!(
+        deployment.execute().unwrap(),
+        DeploymentOutput::Unchanged
+    ));
+}
+
 /// Copilot CLI 形式入力 → そのまま配置（version があるので変換されない）
 #[test]
 fn test_hook_convert_copilot_format_passthrough() {
@@ -187,6 +265,48 @@ fn test_hook_convert_copilot_format_passthrough() {
     assert_eq!(fs::read_to_string(&target).unwrap(), copilot_json);
 }
 
+/// Copilot CLI 形式のソースは Claude Code 形式に戻してから他ターゲットへ変換されること
+#[test]
+fn test_hook_convert_copilot_source_to_codex() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("hook.json");
+    let target = temp.path().join("dest/hooks.json");
+    let plugin_root = temp.path().join("cache/plugin");
+    fs::create_dir_all(&plugin_root).unwrap();
+
+    let copilot_json = r#"{"version":1,"hooks":{"preToolUse":[{"type":"command","bash":"echo hello","matcher":"bash"}]}}"#;
+    fs::write(&source, copilot_json).unwrap();
+
+    let deployment = ComponentDeployment::builder()
+        .component(Component::new(
+            ComponentKind::Hook,
+            "copilot-hook".to_string(),
+            source,
+        ))
+        .scope(Scope::Project)
+        .target_path(target.clone())
+        .conversion(ConversionConfig::Hook {
+            target_kind: TargetKind::Codex,
+            plugin_root: Some(plugin_root),
+        })
+        .build()
+        .unwrap();
+
+    let result = deployment.execute().unwrap();
+    match result {
+        DeploymentOutput::HookConverted(hr) => {
+            assert_eq!(hr.source_format, SourceFormat::ClaudeCode);
+        }
+        _ => panic!("Expected HookConverted"),
+    }
+
+    let written: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&target).unwrap()).unwrap();
+    let group = &written["hooks"]["PreToolUse"][0];
+    assert_eq!(group["matcher"], "Bash");
+    assert!(written.get("version").is_none());
+}
+
 /// @@PLUGIN_ROOT@@ が plugin_root の実パスに置換されること
 #[test]
 fn test_hook_convert_plugin_root_replacement() {
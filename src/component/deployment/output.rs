@@ -4,6 +4,7 @@
 //! `Result` 系名（`std::result::Result`）との混同を避けるため
 //! `~Output` 命名を採用している（`std::process::Output` などの慣用に倣う）。
 
+use super::write::WriteSummary;
 use crate::component::convert::{AgentConversionOutcome, ConversionOutcome};
 use crate::hooks::converter::{ConversionWarning, SourceFormat};
 
@@ -18,6 +19,8 @@ pub enum DeploymentOutput {
     AgentConverted(AgentConversionOutcome),
     /// Hook 変換が行われた
     HookConverted(HookConvertOutput),
+    /// 配置先が既に同じ内容だったので書き込まなかった（Hook の passthrough）
+    Unchanged,
 }
 
 /// Hook 変換結果
@@ -29,12 +32,15 @@ pub struct HookConvertOutput {
     /// 入力 JSON が Claude Code 形式だったか Copilot 形式（passthrough）だったか。
     /// `(converted from Claude Code format)` サフィックスの判定に使う。
     pub source_format: SourceFormat,
+    /// 設定 JSON・ラッパースクリプトの書き込み結果（内容が同じで省略した数と差分）
+    pub writes: WriteSummary,
 }
 
 impl std::fmt::Display for DeploymentOutput {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DeploymentOutput::Copied => write!(f, "Copied"),
+            DeploymentOutput::Unchanged => write!(f, "Unchanged"),
             DeploymentOutput::CommandConverted(conv) => {
                 if conv.converted {
                     write!(
@@ -14,6 +14,7 @@ fn test_hook_convert_result_has_expected_fields() {
         script_count: 2,
         hook_count: 2,
         source_format: SourceFormat::ClaudeCode,
+        writes: WriteSummary::default(),
     };
 
     assert_eq!(result.warnings.len(), 1);
@@ -29,6 +30,7 @@ fn test_deployment_result_hook_converted_variant() {
         script_count: 0,
         hook_count: 0,
         source_format: SourceFormat::ClaudeCode,
+        writes: WriteSummary::default(),
     };
     let result = DeploymentOutput::HookConverted(hook_result);
 
@@ -98,6 +100,7 @@ fn test_display_hook_converted() {
         script_count: 3,
         hook_count: 3,
         source_format: SourceFormat::ClaudeCode,
+        writes: WriteSummary::default(),
     });
     assert_eq!(result.to_string(), "Hook converted (3 scripts, 1 warning)");
 }
@@ -0,0 +1,101 @@
+//! 生成物の書き込み（内容が変わったときだけ書き込む）
+//!
+//! Hook のラッパースクリプトや変換後の設定 JSON は install のたびに同じ内容で再生成される。
+//! 既存ファイルと比較して同一なら書き込まず、mtime を変えないことで配置先を監視している
+//! 他ツールの不要な再読込を避ける。改行コード（CRLF / CR / LF）の違いだけなら同一とみなす。
+
+use crate::error::Result;
+use crate::fs::FileSystem;
+use crate::output::diff::unified_diff;
+use std::path::Path;
+
+/// 1 ファイルぶんの書き込み結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileWrite {
+    /// 新規に作成した
+    Created,
+    /// 内容が変わったので書き込んだ（既存内容からの unified diff）
+    Updated(String),
+    /// 内容が同じなので書き込まなかった
+    Unchanged,
+}
+
+/// 複数ファイルの書き込み結果の集計
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteSummary {
+    /// 内容が同じで書き込みを省略したファイル数
+    pub unchanged_count: usize,
+    /// 内容が変わって書き込んだファイルの unified diff（`--verbose` で表示する）
+    pub diffs: Vec<String>,
+}
+
+impl WriteSummary {
+    /// 書き込み結果を集計に加える
+    pub(super) fn record(&mut self, write: FileWrite) {
+        match write {
+            FileWrite::Created => {}
+            FileWrite::Updated(diff) => {
+                if !diff.is_empty() {
+                    self.diffs.push(diff);
+                }
+            }
+            FileWrite::Unchanged => self.unchanged_count += 1,
+        }
+    }
+}
+
+/// 既存ファイルと内容が異なるときだけ書き込む
+///
+/// 既存ファイルが UTF-8 として読めない場合は比較せずに書き込む。
+/// 親ディレクトリは作成しない（呼び出し側の責任）。
+///
+/// # Arguments
+///
+/// * `fs` - File system used to read and write.
+/// * `path` - Destination file.
+/// * `content` - Contents to write.
+pub(super) fn write_if_changed(
+    fs: &dyn FileSystem,
+    path: &Path,
+    content: &str,
+) -> Result<FileWrite> {
+    if !fs.exists(path) {
+        fs.write(path, content.as_bytes())?;
+        return Ok(FileWrite::Created);
+    }
+
+    let existing = fs.read_to_string(path).ok();
+    if existing
+        .as_deref()
+        .is_some_and(|existing| same_content(existing, content))
+    {
+        return Ok(FileWrite::Unchanged);
+    }
+
+    fs.write(path, content.as_bytes())?;
+    let diff = unified_diff(
+        &path.display().to_string(),
+        existing.as_deref().unwrap_or_default(),
+        content,
+    );
+    Ok(FileWrite::Updated(diff))
+}
+
+/// 改行コードの違いを無視して同じ内容か
+///
+/// # Arguments
+///
+/// * `existing` - Contents currently on disk.
+/// * `new` - Contents about to be written.
+fn same_content(existing: &str, new: &str) -> bool {
+    existing == new || normalize_newlines(existing) == normalize_newlines(new)
+}
+
+/// CRLF / CR を LF にそろえる
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+#[cfg(test)]
+#[path = "write_test.rs"]
+mod tests;
@@ -0,0 +1,66 @@
+use super::*;
+use crate::fs::mock::MockFs;
+
+#[test]
+fn write_if_changed_creates_missing_file() {
+    let fs = MockFs::new();
+    let path = Path::new("/dest/run.sh");
+
+    let write = write_if_changed(&fs, path, "echo hi\n").unwrap();
+
+    assert_eq!(write, FileWrite::Created);
+    assert_eq!(fs.read_to_string(path).unwrap(), "echo hi\n");
+}
+
+#[test]
+fn write_if_changed_skips_identical_content() {
+    let fs = MockFs::new();
+    fs.add_file("/dest/run.sh", "echo hi\n");
+
+    let write = write_if_changed(&fs, Path::new("/dest/run.sh"), "echo hi\n").unwrap();
+
+    assert_eq!(write, FileWrite::Unchanged);
+}
+
+#[test]
+fn write_if_changed_treats_newline_style_as_identical() {
+    let fs = MockFs::new();
+    fs.add_file("/dest/hooks.json", "{\r\n  \"hooks\": {}\r\n}\r\n");
+    let path = Path::new("/dest/hooks.json");
+
+    let write = write_if_changed(&fs, path, "{\n  \"hooks\": {}\n}\n").unwrap();
+
+    assert_eq!(write, FileWrite::Unchanged);
+    assert_eq!(
+        fs.read_to_string(path).unwrap(),
+        "{\r\n  \"hooks\": {}\r\n}\r\n"
+    );
+}
+
+#[test]
+fn write_if_changed_writes_and_reports_diff() {
+    let fs = MockFs::new();
+    fs.add_file("/dest/run.sh", "echo old\n");
+    let path = Path::new("/dest/run.sh");
+
+    let write = write_if_changed(&fs, path, "echo new\n").unwrap();
+
+    let FileWrite::Updated(diff) = write else {
+        panic!("expected Updated, got {:?}", write);
+    };
+    assert!(diff.contains("-echo old\n+echo new\n"), "{}", diff);
+    assert_eq!(fs.read_to_string(path).unwrap(), "echo new\n");
+}
+
+#[test]
+fn write_summary_counts_unchanged_and_keeps_diffs() {
+    let mut summary = WriteSummary::default();
+    summary.record(FileWrite::Created);
+    summary.record(FileWrite::Unchanged);
+    summary.record(FileWrite::Unchanged);
+    summary.record(FileWrite::Updated("--- a\n".to_string()));
+    summary.record(FileWrite::Updated(String::new()));
+
+    assert_eq!(summary.unchanged_count, 2);
+    assert_eq!(summary.diffs, vec!["--- a\n".to_string()]);
+}
@@ -134,6 +134,41 @@ fn test_execute_copies_directory_for_skill() {
     assert!(target.join("helper.py").exists());
 }
 
+#[test]
+fn test_execute_skill_prunes_excluded_entries() {
+    let temp = TempDir::new().unwrap();
+    let plugin_root = temp.path().join("plugin");
+    let source = plugin_root.join("skills/my-skill");
+    let target = temp.path().join("dest/my-skill");
+
+    fs::create_dir_all(source.join("node_modules/pkg")).unwrap();
+    fs::create_dir_all(source.join("docs")).unwrap();
+    fs::write(source.join("SKILL.md"), "skill content").unwrap();
+    fs::write(source.join("node_modules/pkg/index.js"), "x").unwrap();
+    fs::write(source.join("docs/notes.md"), "notes").unwrap();
+    fs::write(source.join("draft.md"), "draft").unwrap();
+
+    let rules = ExcludeRules::new(&["skills/*/draft.md".to_string()]).unwrap();
+    let deployment = ComponentDeployment::builder()
+        .component(Component::new(
+            ComponentKind::Skill,
+            "my-skill".to_string(),
+            source,
+        ))
+        .scope(Scope::Project)
+        .target_path(target.clone())
+        .exclude(&plugin_root, rules)
+        .build()
+        .unwrap();
+
+    deployment.execute().unwrap();
+
+    assert!(target.join("SKILL.md").exists());
+    assert!(target.join("docs/notes.md").exists());
+    assert!(!target.join("node_modules").exists());
+    assert!(!target.join("draft.md").exists());
+}
+
 #[test]
 fn test_execute_skill_replaces_existing_directory() {
     let temp = TempDir::new().unwrap();
@@ -704,3 +739,176 @@ fn test_execute_agent_with_mock_fs_copies_file() {
         "agent content"
     );
 }
+
+// ========================================
+// Attached assets tests
+// ========================================
+
+#[test]
+fn test_execute_command_copies_attached_assets_without_conversion() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("commands/commit.md");
+    let assets = temp.path().join("commands/commit.assets");
+    let target = temp.path().join("dest/p_commit.prompt.md");
+
+    fs::create_dir_all(assets.join("scripts")).unwrap();
+    fs::write(&source, sample_claude_code_content()).unwrap();
+    // アセット内の Markdown は変換対象にしない
+    fs::write(assets.join("template.md"), sample_claude_code_content()).unwrap();
+    fs::write(assets.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+
+    let deployment = make_deployment(
+        Component::new(ComponentKind::Command, "commit".to_string(), source),
+        target.clone(),
+        ConversionConfig::Command {
+            source: CommandFormat::ClaudeCode,
+            dest: CommandFormat::Copilot,
+        },
+    );
+
+    deployment.execute().unwrap();
+
+    let deployed_assets = temp.path().join("dest/p_commit.assets");
+    assert!(fs::read_to_string(&target).unwrap().contains("tools:"));
+    assert_eq!(
+        fs::read_to_string(deployed_assets.join("template.md")).unwrap(),
+        sample_claude_code_content()
+    );
+    assert!(deployed_assets.join("scripts/run.sh").exists());
+}
+
+#[test]
+fn test_execute_command_skips_excluded_asset_entries() {
+    let temp = TempDir::new().unwrap();
+    let plugin_root = temp.path().join("plugin");
+    let source = plugin_root.join("commands/commit.md");
+    let assets = plugin_root.join("commands/commit.assets");
+    let target = temp.path().join("dest/commit.md");
+
+    fs::create_dir_all(assets.join("node_modules/pkg")).unwrap();
+    fs::write(&source, "command").unwrap();
+    fs::write(assets.join("template.md"), "template").unwrap();
+    fs::write(assets.join("notes.draft.md"), "draft").unwrap();
+    fs::write(assets.join("node_modules/pkg/index.js"), "x").unwrap();
+
+    let rules = ExcludeRules::new(&["**/*.draft.md".to_string()]).unwrap();
+    let deployment = ComponentDeployment::builder()
+        .component(Component::new(
+            ComponentKind::Command,
+            "commit".to_string(),
+            source,
+        ))
+        .scope(Scope::Project)
+        .target_path(target.clone())
+        .exclude(&plugin_root, rules)
+        .build()
+        .unwrap();
+
+    deployment.execute().unwrap();
+
+    let deployed_assets = temp.path().join("dest/commit.assets");
+    assert!(deployed_assets.join("template.md").exists());
+    assert!(!deployed_assets.join("notes.draft.md").exists());
+    assert!(!deployed_assets.join("node_modules").exists());
+}
+
+#[test]
+fn test_execute_agent_removes_stale_assets_when_source_has_none() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("agents/review.md");
+    let target = temp.path().join("dest/review.agent.md");
+    let stale = temp.path().join("dest/review.assets");
+
+    fs::create_dir_all(source.parent().unwrap()).unwrap();
+    fs::create_dir_all(&stale).unwrap();
+    fs::write(&source, "agent content").unwrap();
+    fs::write(stale.join("old.json"), "{}").unwrap();
+
+    let deployment = make_deployment(
+        Component::new(ComponentKind::Agent, "review".to_string(), source),
+        target.clone(),
+        ConversionConfig::None,
+    );
+
+    deployment.execute().unwrap();
+
+    assert!(target.exists());
+    assert!(!stale.exists());
+}
+
+#[test]
+fn test_assets_warning_reports_large_attached_assets() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("deploy.md");
+    let assets = temp.path().join("deploy.assets");
+    fs::create_dir_all(&assets).unwrap();
+    fs::write(&source, "deploy").unwrap();
+
+    let deployment = make_deployment(
+        Component::new(ComponentKind::Command, "deploy".to_string(), source),
+        temp.path().join("dest/deploy.md"),
+        ConversionConfig::None,
+    );
+    assert_eq!(deployment.assets_warning(), None);
+
+    fs::File::create(assets.join("video.mp4"))
+        .unwrap()
+        .set_len(20 * 1024 * 1024)
+        .unwrap();
+    assert!(deployment.assets_warning().is_some());
+}
+
+// ========================================
+// Symlink tests
+// ========================================
+
+#[cfg(unix)]
+#[test]
+fn test_execute_rejects_symlinked_source_file() {
+    let temp = TempDir::new().unwrap();
+    let outside = temp.path().join("outside.md");
+    fs::write(&outside, "secret").unwrap();
+    let source = temp.path().join("agent.md");
+    std::os::unix::fs::symlink(&outside, &source).unwrap();
+    let target = temp.path().join("dest/agent.md");
+
+    let deployment = make_deployment(
+        Component::new(ComponentKind::Agent, "test-agent".to_string(), source),
+        target.clone(),
+        ConversionConfig::None,
+    );
+
+    assert!(deployment.execute().is_err());
+    assert!(!target.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_execute_skill_skips_symlinks_and_replaces_stale_files() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("my-skill");
+    fs::create_dir_all(source.join("references")).unwrap();
+    fs::write(source.join("SKILL.md"), "# Skill").unwrap();
+    fs::write(source.join("references/guide.md"), "guide").unwrap();
+    std::os::unix::fs::symlink("/etc", source.join("references/etc")).unwrap();
+    let target = temp.path().join("dest/my-skill");
+    fs::create_dir_all(&target).unwrap();
+    fs::write(target.join("stale.md"), "stale").unwrap();
+
+    let deployment = make_deployment(
+        Component::new(ComponentKind::Skill, "my-skill".to_string(), source),
+        target.clone(),
+        ConversionConfig::None,
+    );
+
+    deployment.execute().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(target.join("SKILL.md")).unwrap(),
+        "# Skill"
+    );
+    assert!(target.join("references/guide.md").exists());
+    assert!(!target.join("references/etc").exists());
+    assert!(!target.join("stale.md").exists());
+    assert!(deployment.symlink_warning().is_some());
+}
@@ -1,18 +1,31 @@
 //! コンポーネントモデル（値オブジェクト群）
 //!
 //! `kind`: ComponentKind / Component / Scope
+//! `assets`: 添付アセット（`<名前>.assets/`）の解決とサイズ警告
+//! `command_prefix`: CommandPrefix
+//! `prompt_naming`: PromptNaming（Copilot プロンプトのファイル名規約）
+//! `path_safety`: 配置パスの安全性検査（トラバーサル・シンボリックリンク）
 //! `placement`: ComponentRef / PlacementContext / PlacementLocation
 //! `scoped_path`: ScopedPath
 //! `file_operation`: FileOperation
 
+mod assets;
+mod command_prefix;
 mod file_operation;
 mod kind;
+mod path_safety;
 mod placement;
+mod prompt_naming;
 mod scoped_path;
 
+pub use assets::{assets_dir_for, large_assets_warning};
+pub use command_prefix::CommandPrefix;
 pub use file_operation::FileOperation;
 pub use kind::{Component, ComponentKind, Scope};
+pub(crate) use path_safety::symlinks_under;
+pub use path_safety::{check_relative_path, ensure_within, source_symlinks, symlink_warning};
 pub use placement::{
     ComponentRef, PlacementContext, PlacementLocation, PlacementScope, ProjectContext,
 };
+pub use prompt_naming::PromptNaming;
 pub use scoped_path::ScopedPath;
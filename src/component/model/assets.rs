@@ -0,0 +1,86 @@
+//! 添付アセット（コンポーネントが実行時に参照する補助ファイル）
+//!
+//! テンプレート `.json`、シェルスクリプト、画像などを変換せずにそのまま配置する。
+//!
+//! - Skill: ディレクトリ全体が添付アセット（`SKILL.md` 以外も同じ相対構造で配置）
+//! - Command / Agent: 定義ファイルと同じディレクトリにある `<名前>.assets/`
+//!   （例: `commands/deploy.md` → `commands/deploy.assets/`）。配置先でも配置した
+//!   ファイルの隣に `<配置名>.assets/` として丸ごとコピーし、削除時も一緒に消す
+//! - アセット内のファイルは変換対象にもコンポーネントのスキャン対象にもしない
+//! - 合計が [`LARGE_ASSETS_BYTES`] を超えるアセットは配置時に警告する
+
+use super::kind::ComponentKind;
+use crate::scan::ASSETS_DIR_SUFFIX;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// 配置時に警告する添付アセットの合計サイズ（10 MiB）
+pub const LARGE_ASSETS_BYTES: u64 = 10 * 1024 * 1024;
+
+/// アセットディレクトリ名を決める際に取り除く定義ファイルの拡張子（長いものから判定）
+const DEFINITION_SUFFIXES: &[&str] = &[".agent.md", ".prompt.md", ".md"];
+
+/// Command / Agent の定義ファイルに対応する添付アセットディレクトリ
+///
+/// ソース側・配置先側のどちらのパスにも使える。Skill などディレクトリ単位で
+/// 配置する種別は `None`（ディレクトリ自体が添付アセットを含むため）。
+///
+/// # Arguments
+///
+/// * `kind` - Component kind of the definition file.
+/// * `path` - Path of the command / agent definition file.
+pub fn assets_dir_for(kind: ComponentKind, path: &Path) -> Option<PathBuf> {
+    if !matches!(kind, ComponentKind::Command | ComponentKind::Agent) {
+        return None;
+    }
+    let file_name = path.file_name()?.to_str()?;
+    let stem = DEFINITION_SUFFIXES
+        .iter()
+        .find_map(|suffix| file_name.strip_suffix(suffix))
+        .or_else(|| path.file_stem().and_then(|s| s.to_str()))?;
+    Some(path.with_file_name(format!("{}{}", stem, ASSETS_DIR_SUFFIX)))
+}
+
+/// 添付アセットが巨大な場合の警告メッセージ
+///
+/// Skill はディレクトリ全体、Command / Agent は `<名前>.assets/` の合計サイズを測る。
+/// アセットが無い、または [`LARGE_ASSETS_BYTES`] 以下なら `None`。
+///
+/// # Arguments
+///
+/// * `kind` - Component kind.
+/// * `source` - Source path of the component (skill directory or definition file).
+pub fn large_assets_warning(kind: ComponentKind, source: &Path) -> Option<String> {
+    let dir = match kind {
+        ComponentKind::Skill => source.to_path_buf(),
+        _ => assets_dir_for(kind, source)?,
+    };
+    let bytes = dir_size(&dir);
+    (bytes > LARGE_ASSETS_BYTES).then(|| {
+        format!(
+            "attached assets in {} are {:.1} MiB (over {} MiB); consider trimming them",
+            dir.display(),
+            bytes as f64 / (1024.0 * 1024.0),
+            LARGE_ASSETS_BYTES / (1024 * 1024)
+        )
+    })
+}
+
+/// ディレクトリ配下のファイルサイズ合計（シンボリックリンクは辿らない）
+///
+/// # Arguments
+///
+/// * `dir` - Directory to measure.
+fn dir_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+#[cfg(test)]
+#[path = "assets_test.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn assets_dir_for_strips_definition_suffix() {
+    let cases = [
+        (
+            ComponentKind::Command,
+            "commands/deploy.md",
+            "commands/deploy.assets",
+        ),
+        (
+            ComponentKind::Command,
+            ".github/prompts/p_deploy.prompt.md",
+            ".github/prompts/p_deploy.assets",
+        ),
+        (
+            ComponentKind::Agent,
+            "agents/review.agent.md",
+            "agents/review.assets",
+        ),
+        (
+            ComponentKind::Agent,
+            ".codex/agents/review.toml",
+            ".codex/agents/review.assets",
+        ),
+    ];
+    for (kind, path, expected) in cases {
+        assert_eq!(
+            assets_dir_for(kind, Path::new(path)),
+            Some(PathBuf::from(expected)),
+            "{}",
+            path
+        );
+    }
+}
+
+#[test]
+fn assets_dir_for_is_none_for_directory_and_other_kinds() {
+    assert_eq!(
+        assets_dir_for(ComponentKind::Skill, Path::new("skills/pdf")),
+        None
+    );
+    assert_eq!(
+        assets_dir_for(ComponentKind::Instruction, Path::new("AGENTS.md")),
+        None
+    );
+    assert_eq!(
+        assets_dir_for(ComponentKind::Hook, Path::new("hooks/a.json")),
+        None
+    );
+}
+
+#[test]
+fn large_assets_warning_measures_attached_assets_only() {
+    let temp = TempDir::new().unwrap();
+    let command = temp.path().join("deploy.md");
+    fs::write(&command, "x".repeat(64)).unwrap();
+    assert_eq!(large_assets_warning(ComponentKind::Command, &command), None);
+
+    let assets = temp.path().join("deploy.assets");
+    fs::create_dir(&assets).unwrap();
+    fs::write(assets.join("small.json"), "{}").unwrap();
+    assert_eq!(large_assets_warning(ComponentKind::Command, &command), None);
+
+    let big = fs::File::create(assets.join("big.bin")).unwrap();
+    big.set_len(LARGE_ASSETS_BYTES + 1).unwrap();
+    let warning = large_assets_warning(ComponentKind::Command, &command).unwrap();
+    assert!(warning.contains("deploy.assets"), "{}", warning);
+    assert!(warning.contains("over 10 MiB"), "{}", warning);
+}
+
+#[test]
+fn large_assets_warning_measures_whole_skill_directory() {
+    let temp = TempDir::new().unwrap();
+    let skill = temp.path().join("pdf");
+    fs::create_dir(&skill).unwrap();
+    fs::write(skill.join("SKILL.md"), "# PDF").unwrap();
+    let big = fs::File::create(skill.join("sample.pdf")).unwrap();
+    big.set_len(LARGE_ASSETS_BYTES + 1).unwrap();
+
+    assert!(large_assets_warning(ComponentKind::Skill, &skill).is_some());
+}
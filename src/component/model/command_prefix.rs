@@ -0,0 +1,126 @@
+//! コマンド呼び出し名のプレフィクス（名前空間）
+//!
+//! デプロイ時に Command の呼び出し名へプレフィクスを付け、スラッシュコマンド一覧で
+//! 出所を区別できるようにする（例: `foo:` + `deploy` → `/foo-deploy`）。
+//! 呼び出し名はターゲットごとにファイル名から決まるため、ファイル名に使えない文字は
+//! ターゲットの規約に合わせて `-` に置き換える。
+
+use super::placement::ComponentRef;
+use super::ComponentKind;
+use crate::target::TargetKind;
+
+/// プレフィクスの最大長
+const MAX_PREFIX_LENGTH: usize = 32;
+
+/// コマンド呼び出し名のプレフィクス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandPrefix(String);
+
+impl CommandPrefix {
+    /// ユーザー入力からプレフィクスを作成する
+    ///
+    /// 空文字（前後の空白のみを含む）は「プレフィクスなし」として `None` を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Raw prefix (e.g. `foo:`).
+    pub fn parse(input: &str) -> Result<Option<Self>, String> {
+        let prefix = input.trim();
+        if prefix.is_empty() {
+            return Ok(None);
+        }
+        if prefix.chars().count() > MAX_PREFIX_LENGTH {
+            return Err(format!(
+                "Command prefix is too long (max {} characters)",
+                MAX_PREFIX_LENGTH
+            ));
+        }
+        if prefix.starts_with('.') {
+            return Err("Command prefix cannot start with a period".to_string());
+        }
+        if let Some(c) = prefix
+            .chars()
+            .find(|c| c.is_control() || c.is_whitespace() || matches!(c, '/' | '\\'))
+        {
+            return Err(format!("Invalid character {:?} in command prefix", c));
+        }
+        Ok(Some(Self(prefix.to_string())))
+    }
+
+    /// `.plm-meta.json` に保存された値から復元する（不正な値は無視）
+    ///
+    /// # Arguments
+    ///
+    /// * `stored` - Value stored in metadata.
+    pub fn from_stored(stored: Option<&str>) -> Option<Self> {
+        stored.and_then(|s| Self::parse(s).ok().flatten())
+    }
+
+    /// 設定値（ユーザー入力そのまま）
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// ターゲットでの呼び出し名（= 配置ファイル名の stem）
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target the command is deployed to.
+    /// * `original_name` - Command name before prefixing.
+    pub fn invocation_name(&self, target: TargetKind, original_name: &str) -> String {
+        format!("{}{}", self.0, original_name)
+            .chars()
+            .map(|c| if is_safe_char(target, c) { c } else { '-' })
+            .collect()
+    }
+
+    /// 配置用のコンポーネント参照にプレフィクスを適用する
+    ///
+    /// Command 以外と、元名を持たない（フラット化されていない）コンポーネントは変更しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Prefix to apply (`None` keeps the default name).
+    /// * `component` - Component reference built from the scanned component.
+    /// * `target` - Target the component is deployed to.
+    pub fn apply(
+        prefix: Option<&Self>,
+        component: ComponentRef,
+        target: TargetKind,
+    ) -> ComponentRef {
+        let Some(prefix) = prefix else {
+            return component;
+        };
+        if component.kind != ComponentKind::Command {
+            return component;
+        }
+        let Some(original) = component.original_name.as_deref() else {
+            return component;
+        };
+        let name = prefix.invocation_name(target, original);
+        ComponentRef {
+            name: name.clone(),
+            original_name: Some(name),
+            ..component
+        }
+    }
+}
+
+/// 呼び出し名に使える文字か
+///
+/// Copilot は `<name>.prompt.md` の stem がそのまま呼び出し名になるため `.` も避ける。
+///
+/// # Arguments
+///
+/// * `target` - Target the command is deployed to.
+/// * `c` - Character to check.
+fn is_safe_char(target: TargetKind, c: char) -> bool {
+    match target {
+        TargetKind::Copilot => c.is_ascii_alphanumeric() || matches!(c, '-' | '_'),
+        _ => c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'),
+    }
+}
+
+#[cfg(test)]
+#[path = "command_prefix_test.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn parse_empty_means_no_prefix() {
+    assert_eq!(CommandPrefix::parse("").unwrap(), None);
+    assert_eq!(CommandPrefix::parse("  ").unwrap(), None);
+}
+
+#[test]
+fn parse_accepts_namespace_style_prefix() {
+    let prefix = CommandPrefix::parse("foo:").unwrap().unwrap();
+    assert_eq!(prefix.as_str(), "foo:");
+}
+
+#[test]
+fn parse_rejects_unsafe_prefixes() {
+    assert!(CommandPrefix::parse("foo/bar").is_err());
+    assert!(CommandPrefix::parse("a b").is_err());
+    assert!(CommandPrefix::parse(".hidden").is_err());
+    assert!(CommandPrefix::parse(&"x".repeat(33)).is_err());
+}
+
+#[test]
+fn from_stored_ignores_invalid_values() {
+    assert_eq!(CommandPrefix::from_stored(None), None);
+    assert_eq!(CommandPrefix::from_stored(Some("a/b")), None);
+    assert_eq!(
+        CommandPrefix::from_stored(Some("foo:")).map(|p| p.as_str().to_string()),
+        Some("foo:".to_string())
+    );
+}
+
+#[test]
+fn invocation_name_replaces_unsafe_characters_per_target() {
+    let prefix = CommandPrefix::parse("foo.v2:").unwrap().unwrap();
+    assert_eq!(
+        prefix.invocation_name(TargetKind::Copilot, "deploy"),
+        "foo-v2-deploy"
+    );
+    assert_eq!(
+        prefix.invocation_name(TargetKind::Cursor, "deploy"),
+        "foo.v2-deploy"
+    );
+}
+
+#[test]
+fn apply_renames_commands_only() {
+    let prefix = CommandPrefix::parse("foo:").unwrap().unwrap();
+    let command = ComponentRef::with_names(ComponentKind::Command, "plg_deploy", "deploy", "plg");
+    let applied = CommandPrefix::apply(Some(&prefix), command, TargetKind::Cursor);
+    assert_eq!(applied.name, "foo-deploy");
+    assert_eq!(applied.original_name.as_deref(), Some("foo-deploy"));
+    assert_eq!(applied.plugin_name, "plg");
+
+    let skill = ComponentRef::with_names(ComponentKind::Skill, "plg_lint", "lint", "plg");
+    let applied = CommandPrefix::apply(Some(&prefix), skill.clone(), TargetKind::Cursor);
+    assert_eq!(applied, skill);
+}
+
+#[test]
+fn apply_without_prefix_keeps_component() {
+    let command = ComponentRef::with_names(ComponentKind::Command, "plg_deploy", "deploy", "plg");
+    assert_eq!(
+        CommandPrefix::apply(None, command.clone(), TargetKind::Copilot),
+        command
+    );
+}
@@ -11,14 +11,19 @@ use std::path::PathBuf;
 #[serde(rename_all = "lowercase")]
 pub enum ComponentKind {
     /// スキル（SKILL.md形式）
+    #[value(alias = "skills")]
     Skill,
     /// エージェント（.agent.md形式）
+    #[value(alias = "agents")]
     Agent,
     /// コマンド（.prompt.md形式）
+    #[value(alias = "commands")]
     Command,
     /// インストラクション（AGENTS.md, copilot-instructions.md形式）
+    #[value(alias = "instructions")]
     Instruction,
     /// フック（任意のスクリプト）
+    #[value(alias = "hooks")]
     Hook,
 }
 
@@ -0,0 +1,142 @@
+//! 配置パスの安全性検査（ディレクトリトラバーサル・シンボリックリンク対策）
+//!
+//! 悪意ある（または不注意な）プラグインがプロジェクト外・ホーム配下の任意ファイルを
+//! 上書き・読み出ししないよう、配置前に次を検査する。
+//!
+//! - コンポーネント名・`plugin.json` のカスタムパス指定に `..` や絶対パスを含まない
+//! - 解決後の配置先がベースディレクトリ（プロジェクトルート / user スコープのホーム）配下に収まる
+//! - コピー元のシンボリックリンク（配置時は辿らずにスキップする）
+
+use super::assets::assets_dir_for;
+use super::kind::ComponentKind;
+use super::scoped_path::ScopedPath;
+use crate::error::{PlmError, Result};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// コンポーネント名・カスタムパス指定が安全な相対パスか検査する
+///
+/// 絶対パス（`/etc`、`C:\`、`\\server` など）と `..` セグメントを含むものを拒否する。
+/// 区切り文字は `/` と `\` の両方を区切りとして扱う（OS によって解釈が変わるため）。
+///
+/// # Arguments
+///
+/// * `label` - What the value is, used in the error message (e.g. `component name`).
+/// * `value` - Name or path given by the plugin.
+pub fn check_relative_path(label: &str, value: &str) -> Result<()> {
+    let reason = if value.contains('\0') {
+        Some("contains a NUL byte")
+    } else if is_absolute_like(value) {
+        Some("must be a relative path")
+    } else if value.split(['/', '\\']).any(|segment| segment == "..") {
+        Some("must not contain '..'")
+    } else {
+        None
+    };
+    match reason {
+        Some(reason) => Err(PlmError::Validation(format!(
+            "Unsafe {} '{}': {}",
+            label,
+            value.escape_debug(),
+            reason
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// 絶対パス（またはドライブ指定）として解釈されうるか
+///
+/// # Arguments
+///
+/// * `value` - Name or path given by the plugin.
+fn is_absolute_like(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    value.starts_with(['/', '\\'])
+        || Path::new(value).has_root()
+        || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+}
+
+/// 解決後のパスがベースディレクトリ配下に収まるか検査する
+///
+/// 既存のシンボリックリンク（配置先ディレクトリ自体がリンクになっている場合など）も
+/// 解決したうえで判定する（[`ScopedPath`] 参照）。
+///
+/// # Arguments
+///
+/// * `path` - Resolved path to check.
+/// * `base` - Directory the path must stay under.
+pub fn ensure_within(path: &Path, base: &Path) -> Result<()> {
+    ScopedPath::new(path.to_path_buf(), base)
+        .map(|_| ())
+        .map_err(|e| {
+            let reason = match e {
+                PlmError::Validation(reason) => reason,
+                other => other.to_string(),
+            };
+            PlmError::Validation(format!(
+                "Unsafe path '{}' (must stay under '{}'): {}",
+                path.display(),
+                base.display(),
+                reason
+            ))
+        })
+}
+
+/// コンポーネントのコピー元に含まれるシンボリックリンク
+///
+/// Skill はディレクトリ全体、Command / Agent は定義ファイルと `<名前>.assets/`、
+/// それ以外は定義ファイル自体を調べる。リンク先は辿らない。
+///
+/// # Arguments
+///
+/// * `kind` - Component kind.
+/// * `source` - Source path of the component (skill directory or definition file).
+pub fn source_symlinks(kind: ComponentKind, source: &Path) -> Vec<PathBuf> {
+    let mut links = symlinks_under(source);
+    if let Some(assets) = assets_dir_for(kind, source) {
+        links.extend(symlinks_under(&assets));
+    }
+    links
+}
+
+/// ディレクトリ（またはファイル）配下のシンボリックリンクを列挙する（リンク先は辿らない）
+///
+/// `path` 自体がリンクならそれも含む。存在しなければ空。
+///
+/// # Arguments
+///
+/// * `path` - File or directory to inspect.
+pub(crate) fn symlinks_under(path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path_is_symlink())
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// コピー元のシンボリックリンクをスキップした旨の警告メッセージ
+///
+/// リンクが無ければ `None`。
+///
+/// # Arguments
+///
+/// * `kind` - Component kind.
+/// * `source` - Source path of the component (skill directory or definition file).
+pub fn symlink_warning(kind: ComponentKind, source: &Path) -> Option<String> {
+    let links = source_symlinks(kind, source);
+    if links.is_empty() {
+        return None;
+    }
+    let listed: Vec<String> = links.iter().map(|p| p.display().to_string()).collect();
+    Some(format!(
+        "skipped {} symlink(s) without following them: {}",
+        links.len(),
+        listed.join(", ")
+    ))
+}
+
+#[cfg(test)]
+#[path = "path_safety_test.rs"]
+mod tests;
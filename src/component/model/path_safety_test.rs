@@ -0,0 +1,164 @@
+use super::*;
+use std::fs;
+
+// =============================================================================
+// check_relative_path
+// =============================================================================
+
+#[test]
+fn check_relative_path_rejects_traversal_and_absolute_inputs() {
+    let attacks = [
+        "..",
+        "../x",
+        "../../.bashrc",
+        "a/../../b",
+        "skills/..",
+        "..\\x",
+        "a\\..\\..\\b",
+        "/etc/passwd",
+        "/",
+        "\\\\server\\share",
+        "\\windows\\system32",
+        "C:\\Windows",
+        "c:foo",
+        "Z:/x",
+        "evil\0name",
+    ];
+    for value in attacks {
+        let result = check_relative_path("component name", value);
+        assert!(
+            result.is_err(),
+            "'{}' should be rejected",
+            value.escape_debug()
+        );
+    }
+}
+
+#[test]
+fn check_relative_path_accepts_plain_relative_inputs() {
+    for value in [
+        "my-skill",
+        "nested/skill",
+        "./skills",
+        "a..b",
+        "..hidden",
+        ".hidden",
+        "v1.0",
+    ] {
+        assert!(
+            check_relative_path("component name", value).is_ok(),
+            "'{}' should be accepted",
+            value
+        );
+    }
+}
+
+#[test]
+fn check_relative_path_error_names_label_and_value() {
+    let err = check_relative_path("skills path in plugin.json", "../outside")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("skills path in plugin.json"), "{}", err);
+    assert!(err.contains("'../outside'"), "{}", err);
+    assert!(err.contains("'..'"), "{}", err);
+}
+
+// =============================================================================
+// ensure_within
+// =============================================================================
+
+#[test]
+fn ensure_within_accepts_path_under_base() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join(".codex").join("skills").join("my-skill");
+    assert!(ensure_within(&path, temp.path()).is_ok());
+}
+
+#[test]
+fn ensure_within_rejects_dotdot_escape() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("..").join("outside");
+    let err = ensure_within(&path, temp.path()).unwrap_err().to_string();
+    assert!(err.contains("must stay under"), "{}", err);
+}
+
+#[cfg(unix)]
+#[test]
+fn ensure_within_rejects_symlinked_directory_pointing_outside() {
+    let project = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    std::os::unix::fs::symlink(outside.path(), project.path().join(".codex")).unwrap();
+
+    let path = project
+        .path()
+        .join(".codex")
+        .join("skills")
+        .join("my-skill");
+    assert!(ensure_within(&path, project.path()).is_err());
+}
+
+// =============================================================================
+// symlinks_under / source_symlinks / symlink_warning
+// =============================================================================
+
+#[test]
+fn symlinks_under_missing_path_is_empty() {
+    let temp = tempfile::tempdir().unwrap();
+    assert!(symlinks_under(&temp.path().join("missing")).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinks_under_finds_links_without_following_them() {
+    let temp = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    fs::create_dir_all(outside.path().join("secrets")).unwrap();
+    fs::write(outside.path().join("secrets").join("id_rsa"), "key").unwrap();
+
+    let skill = temp.path().join("my-skill");
+    fs::create_dir_all(&skill).unwrap();
+    fs::write(skill.join("SKILL.md"), "# Skill").unwrap();
+    std::os::unix::fs::symlink(outside.path().join("secrets"), skill.join("linked")).unwrap();
+
+    let links = symlinks_under(&skill);
+    // リンク先ディレクトリの中身（id_rsa）は列挙されない
+    assert_eq!(links, vec![skill.join("linked")]);
+}
+
+#[cfg(unix)]
+#[test]
+fn source_symlinks_includes_command_assets_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let command = temp.path().join("deploy.md");
+    fs::write(&command, "# Deploy").unwrap();
+    let assets = temp.path().join("deploy.assets");
+    fs::create_dir_all(&assets).unwrap();
+    std::os::unix::fs::symlink("/etc/passwd", assets.join("passwd")).unwrap();
+
+    let links = source_symlinks(ComponentKind::Command, &command);
+    assert_eq!(links, vec![assets.join("passwd")]);
+}
+
+#[test]
+fn symlink_warning_is_none_without_links() {
+    let temp = tempfile::tempdir().unwrap();
+    let skill = temp.path().join("my-skill");
+    fs::create_dir_all(&skill).unwrap();
+    fs::write(skill.join("SKILL.md"), "# Skill").unwrap();
+
+    assert!(symlink_warning(ComponentKind::Skill, &skill).is_none());
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_warning_lists_skipped_links() {
+    let temp = tempfile::tempdir().unwrap();
+    let skill = temp.path().join("my-skill");
+    fs::create_dir_all(&skill).unwrap();
+    fs::write(skill.join("SKILL.md"), "# Skill").unwrap();
+    std::os::unix::fs::symlink("/etc/passwd", skill.join("passwd")).unwrap();
+
+    let warning = symlink_warning(ComponentKind::Skill, &skill).unwrap();
+    assert!(warning.starts_with("skipped 1 symlink(s)"), "{}", warning);
+    assert!(warning.contains("passwd"), "{}", warning);
+}
@@ -0,0 +1,142 @@
+//! Copilot プロンプトファイルの命名規約
+//!
+//! Copilot へ配置する Command のファイル名（`<name>.prompt.md` 既定）を、ユーザー設定
+//! （`~/.plm/config.toml` の `[targets.copilot] prompt_naming`）で切り替える。
+//! 配置ディレクトリは変えず、ターゲットが決めた配置先のファイル名だけを置き換える。
+//! 規約を変えた後の enable / update では、配置記録（`deployedFiles`）にある旧規約の
+//! ファイルが古い配置物として削除される。
+
+use super::placement::{PlacementContext, PlacementLocation};
+use super::ComponentKind;
+use crate::config::ConfigFile;
+use crate::scan::{MARKDOWN_SUFFIX, PROMPT_SUFFIX};
+use crate::target::TargetKind;
+
+/// Copilot プロンプトファイルの命名規約
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PromptNaming {
+    /// `<name>.prompt.md`（既定）
+    #[default]
+    PromptMd,
+    /// `<name>.md`
+    Md,
+    /// `<plugin>-<name>.prompt.md`
+    PluginPromptMd,
+}
+
+impl PromptNaming {
+    /// 設定値から作成する
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value of `targets.copilot.prompt_naming` (`prompt-md`, `md`, or `plugin-prompt-md`).
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "prompt-md" => Ok(Self::PromptMd),
+            "md" => Ok(Self::Md),
+            "plugin-prompt-md" => Ok(Self::PluginPromptMd),
+            other => Err(format!(
+                "unknown prompt naming '{}' (expected prompt-md, md, or plugin-prompt-md)",
+                other
+            )),
+        }
+    }
+
+    /// 設定ファイルの `targets.copilot.prompt_naming` を解釈する（未設定なら既定）
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Loaded user configuration.
+    pub fn from_config(config: &ConfigFile) -> Result<Self, String> {
+        config
+            .targets
+            .copilot
+            .prompt_naming
+            .as_deref()
+            .map(|value| {
+                Self::parse(value).map_err(|e| format!("targets.copilot.prompt_naming: {}", e))
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+
+    /// 既定の設定ファイルから命名規約を読み込む
+    pub fn configured() -> Result<Self, String> {
+        let config = ConfigFile::load_default().map_err(|e| e.to_string())?;
+        Self::from_config(&config)
+    }
+
+    /// 規約に沿ったファイル名
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - Plugin name used by `plugin-prompt-md`.
+    /// * `name` - Command name (file name without suffix).
+    pub fn file_name(&self, plugin: &str, name: &str) -> String {
+        match self {
+            Self::PromptMd => format!("{}{}", name, PROMPT_SUFFIX),
+            Self::Md => format!("{}{}", name, MARKDOWN_SUFFIX),
+            Self::PluginPromptMd if plugin.is_empty() => format!("{}{}", name, PROMPT_SUFFIX),
+            Self::PluginPromptMd => {
+                let plugin: String = plugin
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() || matches!(c, '-' | '_') {
+                            c
+                        } else {
+                            '-'
+                        }
+                    })
+                    .collect();
+                format!("{}-{}{}", plugin, name, PROMPT_SUFFIX)
+            }
+        }
+    }
+
+    /// ターゲットが決めた配置先に命名規約を適用する
+    ///
+    /// Copilot の Command（ファイル配置）以外と、既定の規約では配置先を変更しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `naming` - Naming convention to apply.
+    /// * `target` - Target the component is deployed to.
+    /// * `context` - Placement context the location was resolved from.
+    /// * `location` - Location returned by `Target::placement_location`.
+    pub fn apply(
+        naming: Self,
+        target: TargetKind,
+        context: &PlacementContext,
+        location: PlacementLocation,
+    ) -> PlacementLocation {
+        if naming == Self::PromptMd
+            || target != TargetKind::Copilot
+            || context.kind() != ComponentKind::Command
+        {
+            return location;
+        }
+        let path = match location {
+            PlacementLocation::File(path) => path,
+            dir => return dir,
+        };
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()).map(String::from) else {
+            return PlacementLocation::File(path);
+        };
+        let stem = file_name
+            .strip_suffix(PROMPT_SUFFIX)
+            .or_else(|| file_name.strip_suffix(MARKDOWN_SUFFIX))
+            .unwrap_or(&file_name);
+        // ターゲットはフラット化名（`<plugin>_<name>`）を使うため、プラグイン名を前置する
+        // 規約では元名に付け直す
+        let name = match naming {
+            Self::PluginPromptMd => context.original_name().unwrap_or(stem),
+            _ => stem,
+        };
+        let renamed = naming.file_name(context.plugin_name(), name);
+        PlacementLocation::File(path.with_file_name(renamed))
+    }
+}
+
+#[cfg(test)]
+#[path = "prompt_naming_test.rs"]
+mod tests;
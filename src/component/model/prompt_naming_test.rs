@@ -0,0 +1,104 @@
+use super::*;
+use crate::component::{ComponentRef, PlacementScope, ProjectContext, Scope};
+use crate::target::PluginOrigin;
+use std::path::{Path, PathBuf};
+
+fn apply_to(naming: PromptNaming, target: TargetKind, kind: ComponentKind, file: &str) -> PathBuf {
+    let origin = PluginOrigin::from_marketplace("company", "tools");
+    let ctx = PlacementContext {
+        component: ComponentRef::with_names(kind, "tools_deploy", "deploy", "tools"),
+        origin: &origin,
+        scope: PlacementScope::new(Scope::Project),
+        project: ProjectContext::new(Path::new("/project")),
+    };
+    let location = PlacementLocation::file(format!("/project/.github/prompts/{file}"));
+    PromptNaming::apply(naming, target, &ctx, location).into_path()
+}
+
+#[test]
+fn parse_accepts_each_convention() {
+    assert_eq!(
+        PromptNaming::parse("prompt-md").unwrap(),
+        PromptNaming::PromptMd
+    );
+    assert_eq!(PromptNaming::parse("md").unwrap(), PromptNaming::Md);
+    assert_eq!(
+        PromptNaming::parse("plugin-prompt-md").unwrap(),
+        PromptNaming::PluginPromptMd
+    );
+    assert!(PromptNaming::parse(".prompt.md").is_err());
+}
+
+#[test]
+fn golden_prompt_md_keeps_target_file_name() {
+    assert_eq!(
+        apply_to(
+            PromptNaming::PromptMd,
+            TargetKind::Copilot,
+            ComponentKind::Command,
+            "tools_deploy.prompt.md"
+        ),
+        PathBuf::from("/project/.github/prompts/tools_deploy.prompt.md")
+    );
+}
+
+#[test]
+fn golden_md_uses_plain_markdown_suffix() {
+    assert_eq!(
+        apply_to(
+            PromptNaming::Md,
+            TargetKind::Copilot,
+            ComponentKind::Command,
+            "tools_deploy.prompt.md"
+        ),
+        PathBuf::from("/project/.github/prompts/tools_deploy.md")
+    );
+}
+
+#[test]
+fn golden_plugin_prompt_md_prefixes_plugin_name() {
+    assert_eq!(
+        apply_to(
+            PromptNaming::PluginPromptMd,
+            TargetKind::Copilot,
+            ComponentKind::Command,
+            "tools_deploy.prompt.md"
+        ),
+        PathBuf::from("/project/.github/prompts/tools-deploy.prompt.md")
+    );
+}
+
+#[test]
+fn apply_leaves_other_targets_and_kinds_unchanged() {
+    assert_eq!(
+        apply_to(
+            PromptNaming::Md,
+            TargetKind::Codex,
+            ComponentKind::Command,
+            "tools_deploy.prompt.md"
+        ),
+        PathBuf::from("/project/.github/prompts/tools_deploy.prompt.md")
+    );
+    assert_eq!(
+        apply_to(
+            PromptNaming::Md,
+            TargetKind::Copilot,
+            ComponentKind::Agent,
+            "tools_deploy.agent.md"
+        ),
+        PathBuf::from("/project/.github/prompts/tools_deploy.agent.md")
+    );
+}
+
+#[test]
+fn from_config_reports_invalid_values_with_key() {
+    let mut config = ConfigFile::default();
+    assert_eq!(
+        PromptNaming::from_config(&config).unwrap(),
+        PromptNaming::PromptMd
+    );
+
+    config.targets.copilot.prompt_naming = Some("bogus".to_string());
+    let err = PromptNaming::from_config(&config).unwrap_err();
+    assert!(err.starts_with("targets.copilot.prompt_naming:"));
+}
@@ -0,0 +1,295 @@
+//! ターゲット別の変換済み成果物（`dist/`）
+//!
+//! `plm pack --precompile` は Claude Code 形式の Command / Agent を Copilot・Codex 向けに
+//! 変換した結果を `dist/<形式>/<プラグインルートからの相対パス>` に書き出し、変換した
+//! plm のバージョンと変換元・成果物の SHA-256 を `dist/sources.json` に記録する。
+//! install はバージョンと両方のダイジェストが記録と一致する成果物だけを変換せずに
+//! 書き出し、一致しなければ警告して変換し直す。
+
+use crate::component::convert::{self, AgentFormat, CommandFormat};
+use crate::component::{Component, ComponentKind, ConversionConfig};
+use crate::error::Result;
+use crate::plugin::{relative_key, Checksums};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 変換済み成果物を置くディレクトリ（プラグインルート直下）
+pub const DIST_DIR: &str = "dist";
+
+/// 変換元のダイジェストを記録するファイル（`dist/` 直下）
+const DIST_SOURCES_FILE: &str = "sources.json";
+
+/// 成果物を変換した plm のバージョン（変換処理はバージョンで変わりうる）
+const PLM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 事前変換する出力形式（`dist/` 直下のディレクトリ名と Command / Agent の形式）
+const DIST_FORMATS: &[(&str, CommandFormat, AgentFormat)] = &[
+    ("copilot", CommandFormat::Copilot, AgentFormat::Copilot),
+    ("codex", CommandFormat::Codex, AgentFormat::Codex),
+];
+
+/// 変換済み成果物の参照結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Precompiled {
+    /// 成果物が無い（事前変換されていない・対象外の変換）
+    Absent,
+    /// 記録と一致する成果物の内容
+    Fresh(String),
+    /// 成果物はあるが記録と一致しない（変換し直す）
+    Stale {
+        /// 成果物のパス
+        artifact: PathBuf,
+        /// 一致しなかった理由
+        reason: StaleReason,
+    },
+}
+
+/// 成果物を使わない理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaleReason {
+    /// 別のバージョンの plm で変換された（値は記録されたバージョン）
+    PlmVersion(String),
+    /// 変換元が記録後に変更されている
+    SourceChanged,
+    /// 成果物が記録後に変更されている
+    ArtifactModified,
+}
+
+/// `dist/sources.json` のスキーマ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DistSources {
+    /// 成果物を変換した plm のバージョン
+    plm_version: String,
+    /// 変換元のダイジェスト（プラグインルートからの相対パス）
+    sources: Checksums,
+    /// 成果物のダイジェスト（`dist/` からの相対パス）
+    artifacts: Checksums,
+}
+
+/// 変換設定に対応する `dist/` 直下のディレクトリ名
+///
+/// Claude Code 形式からの Command / Agent 変換のみ対象（それ以外は `None`）。
+///
+/// # Arguments
+///
+/// * `conversion` - Conversion applied when deploying the component.
+pub fn dist_name(conversion: &ConversionConfig) -> Option<&'static str> {
+    DIST_FORMATS
+        .iter()
+        .find(|(_, command, agent)| match conversion {
+            ConversionConfig::Command { source, dest } => {
+                *source == CommandFormat::ClaudeCode && dest == command
+            }
+            ConversionConfig::Agent { source, dest } => {
+                *source == AgentFormat::ClaudeCode && dest == agent
+            }
+            _ => false,
+        })
+        .map(|(name, _, _)| *name)
+}
+
+/// 変換済み成果物を探す
+///
+/// `dist/sources.json` が無い・読めない（旧形式を含む）場合は事前変換されていないものとして扱う。
+/// 成果物は検証した内容を返すため、呼び出し側で読み直さない。
+///
+/// # Arguments
+///
+/// * `plugin_root` - Plugin root directory.
+/// * `source` - Source component file under `plugin_root`.
+/// * `dist_name` - Directory under `dist/` (see [`dist_name`]).
+pub fn lookup(plugin_root: &Path, source: &Path, dist_name: &str) -> Precompiled {
+    let key = relative_key(plugin_root, source);
+    let artifact = plugin_root.join(DIST_DIR).join(dist_name).join(&key);
+    let Some(recorded) = load_sources(plugin_root) else {
+        return Precompiled::Absent;
+    };
+    if !artifact.is_file() {
+        return Precompiled::Absent;
+    }
+    let reason = if recorded.plm_version != PLM_VERSION {
+        StaleReason::PlmVersion(recorded.plm_version)
+    } else if !fs::read(source).is_ok_and(|content| recorded.sources.matches(&key, &content)) {
+        StaleReason::SourceChanged
+    } else {
+        let content = fs::read(&artifact).ok().filter(|content| {
+            recorded
+                .artifacts
+                .matches(&artifact_key(dist_name, &key), content)
+        });
+        match content.map(String::from_utf8) {
+            Some(Ok(content)) => return Precompiled::Fresh(content),
+            _ => StaleReason::ArtifactModified,
+        }
+    };
+    Precompiled::Stale { artifact, reason }
+}
+
+/// Command / Agent を事前変換して `dist/` に書き出す
+///
+/// 既存の `dist/<形式>/` と `dist/sources.json` は作り直す（`dist/` 直下のそれ以外の
+/// ファイルには触れない）。書き出した成果物の数を返す。
+///
+/// # Arguments
+///
+/// * `plugin_root` - Plugin root directory.
+/// * `components` - Scanned components of the plugin.
+pub fn precompile(plugin_root: &Path, components: &[Component]) -> Result<usize> {
+    let dist = plugin_root.join(DIST_DIR);
+    for (name, _, _) in DIST_FORMATS {
+        let dir = dist.join(name);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+    }
+
+    let mut recorded = DistSources {
+        plm_version: PLM_VERSION.to_string(),
+        sources: Checksums::default(),
+        artifacts: Checksums::default(),
+    };
+    let mut written = 0;
+    for component in precompilable(components) {
+        let content = fs::read_to_string(&component.path)?;
+        let key = relative_key(plugin_root, &component.path);
+        for (name, command, agent) in DIST_FORMATS {
+            let converted = convert_for(component.kind, &content, *command, *agent)?;
+            convert::atomic_write(&dist.join(name).join(&key), &converted)?;
+            recorded
+                .artifacts
+                .insert(&artifact_key(name, &key), converted.as_bytes());
+            written += 1;
+        }
+        recorded.sources.insert(&key, content.as_bytes());
+    }
+    let json = serde_json::to_string_pretty(&recorded)?;
+    convert::atomic_write(&dist.join(DIST_SOURCES_FILE), &json)?;
+    Ok(written)
+}
+
+/// `dist/` とソースの整合を検証する
+///
+/// 事前変換されていない（`dist/sources.json` が無い）場合は `None`。
+/// それ以外は不整合の一覧（空なら整合）を返す。
+///
+/// # Arguments
+///
+/// * `plugin_root` - Plugin root directory.
+/// * `components` - Scanned components of the plugin.
+pub fn check(plugin_root: &Path, components: &[Component]) -> Option<Vec<String>> {
+    let recorded = load_sources(plugin_root)?;
+    let sources = &recorded.sources;
+    let dist = plugin_root.join(DIST_DIR);
+    let mut problems = Vec::new();
+    let mut keys = Vec::new();
+    if recorded.plm_version != PLM_VERSION {
+        problems.push(format!(
+            "{}/: precompiled by plm {} (current: {})",
+            DIST_DIR, recorded.plm_version, PLM_VERSION
+        ));
+    }
+
+    for component in precompilable(components) {
+        let key = relative_key(plugin_root, &component.path);
+        keys.push(key.clone());
+        let content = match fs::read_to_string(&component.path) {
+            Ok(content) => content,
+            Err(e) => {
+                problems.push(format!("{}: {}", key, e));
+                continue;
+            }
+        };
+        if !sources.files.contains_key(&key) {
+            problems.push(format!("{}: not precompiled", key));
+            continue;
+        }
+        if !sources.matches(&key, content.as_bytes()) {
+            problems.push(format!("{}: source changed since precompile", key));
+            continue;
+        }
+        for (name, command, agent) in DIST_FORMATS {
+            let artifact = format!("{}/{}/{}", DIST_DIR, name, key);
+            match fs::read_to_string(dist.join(name).join(&key)) {
+                Err(_) => problems.push(format!("{}: missing", artifact)),
+                Ok(existing) => {
+                    let expected = convert_for(component.kind, &content, *command, *agent);
+                    if !recorded
+                        .artifacts
+                        .matches(&artifact_key(name, &key), existing.as_bytes())
+                    {
+                        problems.push(format!("{}: modified since precompile", artifact));
+                    } else if expected.ok() != Some(existing) {
+                        problems.push(format!("{}: differs from a fresh conversion", artifact));
+                    }
+                }
+            }
+        }
+    }
+
+    for key in sources.files.keys() {
+        if !keys.contains(key) {
+            problems.push(format!("{}: no longer in the plugin", key));
+        }
+    }
+    Some(problems)
+}
+
+/// 事前変換の対象（Command / Agent）
+///
+/// # Arguments
+///
+/// * `components` - Scanned components of the plugin.
+fn precompilable(components: &[Component]) -> impl Iterator<Item = &Component> {
+    components
+        .iter()
+        .filter(|c| matches!(c.kind, ComponentKind::Command | ComponentKind::Agent))
+}
+
+/// Claude Code 形式の内容を出力形式へ変換する（install 時の変換と同じ処理）
+///
+/// # Arguments
+///
+/// * `kind` - Component kind (Command or Agent).
+/// * `content` - Source content in Claude Code format.
+/// * `command` - Output format used for commands.
+/// * `agent` - Output format used for agents.
+fn convert_for(
+    kind: ComponentKind,
+    content: &str,
+    command: CommandFormat,
+    agent: AgentFormat,
+) -> Result<String> {
+    match kind {
+        ComponentKind::Agent => {
+            convert::convert_agent_content(content, AgentFormat::ClaudeCode, agent)
+        }
+        _ => convert::convert_content(content, CommandFormat::ClaudeCode, command),
+    }
+}
+
+/// 成果物のダイジェストを記録するキー（`<形式>/<プラグインルートからの相対パス>`）
+///
+/// # Arguments
+///
+/// * `dist_name` - Directory under `dist/`.
+/// * `key` - Source path relative to the plugin root.
+fn artifact_key(dist_name: &str, key: &str) -> String {
+    format!("{}/{}", dist_name, key)
+}
+
+/// `dist/sources.json` を読み込む（無い・読めない場合は `None`）
+///
+/// # Arguments
+///
+/// * `plugin_root` - Plugin root directory.
+fn load_sources(plugin_root: &Path) -> Option<DistSources> {
+    let path = plugin_root.join(DIST_DIR).join(DIST_SOURCES_FILE);
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+#[cfg(test)]
+#[path = "precompile_test.rs"]
+mod tests;
@@ -0,0 +1,319 @@
+use super::*;
+use crate::component::{ComponentDeployment, Scope};
+use crate::scan::ExcludeRules;
+use tempfile::TempDir;
+
+const COMMAND: &str =
+    "---\ndescription: Review changes\nallowed-tools: Read, Bash\n---\nReview $ARGUMENTS\n";
+const AGENT: &str =
+    "---\nname: helper\ndescription: Helps out\ntools: Read, Grep\nmodel: sonnet\n---\nYou help.\n";
+
+/// `commands/review.md` と `agents/helper.md` を持つプラグインを作る
+fn setup() -> (TempDir, Vec<Component>) {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("plugin");
+    fs::create_dir_all(root.join("commands")).unwrap();
+    fs::create_dir_all(root.join("agents")).unwrap();
+    fs::write(root.join("commands/review.md"), COMMAND).unwrap();
+    fs::write(root.join("agents/helper.md"), AGENT).unwrap();
+    let components = vec![
+        Component::new(
+            ComponentKind::Command,
+            "review".to_string(),
+            root.join("commands/review.md"),
+        ),
+        Component::new(
+            ComponentKind::Agent,
+            "helper".to_string(),
+            root.join("agents/helper.md"),
+        ),
+    ];
+    (temp, components)
+}
+
+fn conversions() -> Vec<ConversionConfig> {
+    vec![
+        ConversionConfig::Command {
+            source: CommandFormat::ClaudeCode,
+            dest: CommandFormat::Copilot,
+        },
+        ConversionConfig::Command {
+            source: CommandFormat::ClaudeCode,
+            dest: CommandFormat::Codex,
+        },
+        ConversionConfig::Agent {
+            source: AgentFormat::ClaudeCode,
+            dest: AgentFormat::Copilot,
+        },
+        ConversionConfig::Agent {
+            source: AgentFormat::ClaudeCode,
+            dest: AgentFormat::Codex,
+        },
+    ]
+}
+
+fn deployment(
+    root: &Path,
+    component: &Component,
+    conversion: ConversionConfig,
+    target: PathBuf,
+) -> ComponentDeployment {
+    ComponentDeployment::builder()
+        .component(component.clone())
+        .scope(Scope::Project)
+        .target_path(target)
+        .conversion(conversion)
+        .exclude(root, ExcludeRules::default())
+        .build()
+        .unwrap()
+}
+
+/// 全コンポーネント × 全変換を `dest` 配下に配置し、配置したファイルの内容を返す
+fn deploy_all(root: &Path, components: &[Component], dest: &Path) -> Vec<String> {
+    let mut contents = Vec::new();
+    for component in components {
+        for (i, conversion) in conversions().into_iter().enumerate() {
+            let is_agent = matches!(conversion, ConversionConfig::Agent { .. });
+            if is_agent != (component.kind == ComponentKind::Agent) {
+                continue;
+            }
+            let target = dest.join(format!("{}-{}.md", component.name, i));
+            deployment(root, component, conversion, target.clone())
+                .execute()
+                .unwrap();
+            contents.push(fs::read_to_string(target).unwrap());
+        }
+    }
+    contents
+}
+
+/// `dist/sources.json` の記録を書き換える
+fn rewrite_sources(root: &Path, update: impl FnOnce(&mut DistSources)) {
+    let mut recorded = load_sources(root).unwrap();
+    update(&mut recorded);
+    fs::write(
+        root.join(DIST_DIR).join(DIST_SOURCES_FILE),
+        serde_json::to_string(&recorded).unwrap(),
+    )
+    .unwrap();
+}
+
+/// 先頭のコンポーネント（Command）を Copilot 向けに配置する
+fn copilot_command(root: &Path, components: &[Component], target: &Path) -> ComponentDeployment {
+    deployment(
+        root,
+        &components[0],
+        conversions().remove(0),
+        target.to_path_buf(),
+    )
+}
+
+#[test]
+fn precompile_writes_artifacts_for_each_format() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+
+    let count = precompile(&root, &components).unwrap();
+
+    assert_eq!(count, 4);
+    for name in ["copilot", "codex"] {
+        assert!(root
+            .join(DIST_DIR)
+            .join(name)
+            .join("commands/review.md")
+            .is_file());
+        assert!(root
+            .join(DIST_DIR)
+            .join(name)
+            .join("agents/helper.md")
+            .is_file());
+    }
+    let recorded = load_sources(&root).unwrap();
+    assert_eq!(recorded.plm_version, PLM_VERSION);
+    assert!(recorded
+        .sources
+        .matches("commands/review.md", COMMAND.as_bytes()));
+    let artifact = fs::read(root.join("dist/codex/agents/helper.md")).unwrap();
+    assert!(recorded
+        .artifacts
+        .matches("codex/agents/helper.md", &artifact));
+    assert_eq!(check(&root, &components), Some(Vec::new()));
+}
+
+#[test]
+fn installed_files_are_identical_with_and_without_precompile() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+
+    let converted = deploy_all(&root, &components, &temp.path().join("converted"));
+    precompile(&root, &components).unwrap();
+    let precompiled = deploy_all(&root, &components, &temp.path().join("precompiled"));
+
+    assert_eq!(converted.len(), 4);
+    assert_eq!(precompiled, converted);
+}
+
+#[test]
+fn fresh_artifact_is_written_without_converting() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+    // 成果物が使われたことを確かめるため、成果物と記録のダイジェストを揃えて差し替える
+    fs::write(root.join("dist/copilot/commands/review.md"), "precompiled").unwrap();
+    rewrite_sources(&root, |recorded| {
+        recorded
+            .artifacts
+            .insert("copilot/commands/review.md", b"precompiled")
+    });
+
+    let target = temp.path().join("out/review.prompt.md");
+    let deploy = copilot_command(&root, &components, &target);
+    assert_eq!(deploy.precompile_warning(), None);
+    let output = deploy.execute().unwrap();
+
+    assert!(matches!(
+        output,
+        crate::component::DeploymentOutput::CommandConverted(ref c) if c.converted
+    ));
+    assert_eq!(fs::read_to_string(target).unwrap(), "precompiled");
+}
+
+#[test]
+fn modified_artifact_warns_and_converts_again() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+    fs::write(root.join("dist/copilot/commands/review.md"), "tampered").unwrap();
+
+    let target = temp.path().join("out/review.prompt.md");
+    let deploy = copilot_command(&root, &components, &target);
+
+    assert!(deploy
+        .precompile_warning()
+        .unwrap()
+        .contains("was modified after precompile"));
+    deploy.execute().unwrap();
+    let deployed = fs::read_to_string(target).unwrap();
+    assert_ne!(deployed, "tampered");
+    assert!(deployed.contains("Review changes"));
+}
+
+#[test]
+fn artifact_from_another_plm_version_is_converted_again() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+    rewrite_sources(&root, |recorded| recorded.plm_version = "0.0.1".to_string());
+
+    let target = temp.path().join("out/review.prompt.md");
+    let deploy = copilot_command(&root, &components, &target);
+
+    assert_eq!(
+        lookup(&root, &components[0].path, "copilot"),
+        Precompiled::Stale {
+            artifact: root.join("dist/copilot/commands/review.md"),
+            reason: StaleReason::PlmVersion("0.0.1".to_string()),
+        }
+    );
+    assert!(deploy
+        .precompile_warning()
+        .unwrap()
+        .contains("precompiled by plm 0.0.1"));
+    assert!(check(&root, &components)
+        .unwrap()
+        .iter()
+        .any(|p| p.contains("precompiled by plm 0.0.1")));
+}
+
+#[test]
+fn stale_artifact_warns_and_converts_again() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+    fs::write(
+        root.join("commands/review.md"),
+        COMMAND.replace("changes", "diff"),
+    )
+    .unwrap();
+
+    let target = temp.path().join("out/review.prompt.md");
+    let deploy = copilot_command(&root, &components, &target);
+
+    assert!(deploy
+        .precompile_warning()
+        .unwrap()
+        .contains("older than its source"));
+    deploy.execute().unwrap();
+    assert!(fs::read_to_string(target).unwrap().contains("Review diff"));
+}
+
+#[test]
+fn lookup_ignores_dist_without_sources_file() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    fs::create_dir_all(root.join("dist/copilot/commands")).unwrap();
+    fs::write(root.join("dist/copilot/commands/review.md"), "other").unwrap();
+
+    assert_eq!(
+        lookup(&root, &components[0].path, "copilot"),
+        Precompiled::Absent
+    );
+    assert_eq!(check(&root, &components), None);
+}
+
+#[test]
+fn lookup_ignores_sources_file_without_versions() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+    // バージョンと成果物のダイジェストを持たない形式は事前変換されていないものとして扱う
+    let mut legacy = Checksums::default();
+    legacy.insert("commands/review.md", COMMAND.as_bytes());
+    fs::write(
+        root.join(DIST_DIR).join(DIST_SOURCES_FILE),
+        legacy.to_json().unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        lookup(&root, &components[0].path, "copilot"),
+        Precompiled::Absent
+    );
+}
+
+#[test]
+fn dist_name_only_covers_conversions_from_claude_code() {
+    assert_eq!(dist_name(&conversions()[0]), Some("copilot"));
+    assert_eq!(dist_name(&conversions()[3]), Some("codex"));
+    assert_eq!(
+        dist_name(&ConversionConfig::Command {
+            source: CommandFormat::Copilot,
+            dest: CommandFormat::Codex,
+        }),
+        None
+    );
+    assert_eq!(dist_name(&ConversionConfig::None), None);
+}
+
+#[test]
+fn check_reports_changed_missing_and_removed_entries() {
+    let (temp, components) = setup();
+    let root = temp.path().join("plugin");
+    precompile(&root, &components).unwrap();
+
+    fs::write(root.join("commands/review.md"), "changed").unwrap();
+    fs::remove_file(root.join("dist/codex/agents/helper.md")).unwrap();
+    fs::write(root.join("dist/copilot/agents/helper.md"), "tampered").unwrap();
+    let problems = check(&root, &components[..]).unwrap();
+    assert_eq!(
+        problems,
+        vec![
+            "commands/review.md: source changed since precompile".to_string(),
+            "dist/copilot/agents/helper.md: modified since precompile".to_string(),
+            "dist/codex/agents/helper.md: missing".to_string(),
+        ]
+    );
+
+    let problems = check(&root, &components[1..]).unwrap();
+    assert!(problems.contains(&"commands/review.md: no longer in the plugin".to_string()));
+}
@@ -1,28 +1,519 @@
 //! HTTP設定と認証プロバイダー
+//!
+//! ## プロキシ / カスタム CA
+//!
+//! - プロキシ: reqwest が Client 構築時に `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY` /
+//!   `NO_PROXY`（大文字優先、小文字も可）を自動で読む。[`ProxySettings`] は同じ規則で
+//!   解決した結果を診断表示（`plm env`）するためのもの。
+//! - CA: `PLM_CA_BUNDLE` または設定ファイルの `[network] ca_bundle` で指定した PEM を
+//!   追加ルート証明書として読み込む（[`CaBundle`]）。
+//! - `gh` など外部コマンドを起動する経路では、これらの環境変数は子プロセスに
+//!   そのまま引き継がれるだけで、plm 側では解釈しない。
+//!
+//! ## レスポンスキャッシュ
+//!
+//! GitHub API の GET レスポンスは短期キャッシュ（[`ResponseCacheConfig`]）される。
+//! `--no-http-cache` または `PLM_NO_HTTP_CACHE` で無効化でき、TTL は `PLM_HTTP_CACHE_TTL`（秒）で変更できる。
+//!
+//! ## タイムアウト
+//!
+//! 接続確立は 10 秒、リクエスト全体は 120 秒で打ち切る。全体のタイムアウトは
+//! `PLM_TIMEOUT_SECS`（秒）で変更できる。
+//!
+//! ## リトライ
+//!
+//! 通信エラー・タイムアウト・5xx は指数バックオフで 3 回まで再試行する。
+//! 回数は設定ファイルの `[network] retries` で変更できる（`0` で再試行しない）。
+//!
+//! ## 通信量の制御
+//!
+//! 同時接続数・1 接続あたりの帯域・リクエスト間の最小間隔を [`NetworkLimits`] で制限できる。
+//! CLI フラグ（`--jobs` / `--limit-rate` / `--request-interval`）と対応する環境変数が、
+//! 設定ファイルの `[network] jobs` / `limit_rate` / `request_interval_ms` より優先される。
+//!
+//! ## オフラインモード
+//!
+//! `--offline` または `PLM_OFFLINE` で有効になる。ネットワーク操作はすべて
+//! `HostClientFactory::create` が返すオフライン用クライアントを通り、通信せずに
+//! [`PlmError::Offline`] を返す。
 
-use crate::env::EnvVar;
+mod file;
+mod global;
+
+pub use file::ConfigFile;
+pub use global::GlobalOptions;
+
+use crate::env::{EnvVar, PlmPaths};
+use crate::error::{PlmError, Result};
 use reqwest::Client;
+use std::path::PathBuf;
 use std::time::Duration;
 
+/// 追加 CA バンドルを指定する環境変数
+pub const CA_BUNDLE_ENV: &str = "PLM_CA_BUNDLE";
+
+/// レスポンスキャッシュを無効化する環境変数（`--no-http-cache` が優先）
+pub const NO_HTTP_CACHE_ENV: &str = "PLM_NO_HTTP_CACHE";
+
+/// レスポンスキャッシュの TTL（秒）を指定する環境変数
+pub const HTTP_CACHE_TTL_ENV: &str = "PLM_HTTP_CACHE_TTL";
+
+/// リクエスト全体のタイムアウト（秒）を指定する環境変数
+pub const TIMEOUT_SECS_ENV: &str = "PLM_TIMEOUT_SECS";
+
+/// 詳細ログを有効にする環境変数（`--verbose` が優先）
+pub const VERBOSE_ENV: &str = "PLM_VERBOSE";
+
+/// 補足の案内（反映手順など）を出さない環境変数（`--quiet` が優先）
+pub const QUIET_ENV: &str = "PLM_QUIET";
+
+/// オフラインモードを有効にする環境変数（`--offline` が優先）
+pub const OFFLINE_ENV: &str = "PLM_OFFLINE";
+
+/// 同時接続数の上限を指定する環境変数（`--jobs` が優先）
+pub const JOBS_ENV: &str = "PLM_JOBS";
+
+/// 1 接続あたりのダウンロード帯域上限を指定する環境変数（`--limit-rate` が優先）
+pub const LIMIT_RATE_ENV: &str = "PLM_LIMIT_RATE";
+
+/// リクエスト間の最小間隔（ミリ秒）を指定する環境変数（`--request-interval` が優先）
+pub const REQUEST_INTERVAL_ENV: &str = "PLM_REQUEST_INTERVAL_MS";
+
+/// フラグ用の環境変数の値が有効を表すか（`0` / `false` 以外なら有効）
+///
+/// # Arguments
+///
+/// * `value` - Value of the environment variable (`None` if unset or empty).
+pub fn flag_enabled(value: Option<String>) -> bool {
+    value
+        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "0" | "false"))
+        .unwrap_or(false)
+}
+
+/// CA バンドル設定の取得元
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaBundleSource {
+    /// 環境変数 `PLM_CA_BUNDLE`
+    Env,
+    /// 設定ファイルの `[network] ca_bundle`
+    ConfigFile,
+}
+
+impl CaBundleSource {
+    /// 表示用の取得元ラベル
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CaBundleSource::Env => CA_BUNDLE_ENV,
+            CaBundleSource::ConfigFile => "config.toml [network] ca_bundle",
+        }
+    }
+}
+
+/// 追加ルート証明書として読み込む PEM バンドル
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaBundle {
+    /// PEM ファイルのパス
+    pub path: PathBuf,
+    /// 設定の取得元
+    pub source: CaBundleSource,
+}
+
+impl CaBundle {
+    /// CA バンドルの指定を解決する（環境変数 > 設定ファイル）
+    ///
+    /// # Arguments
+    ///
+    /// * `env_value` - Value of `PLM_CA_BUNDLE` (empty is treated as unset by the caller).
+    /// * `config` - Loaded user configuration file.
+    pub fn resolve(env_value: Option<String>, config: &ConfigFile) -> Option<Self> {
+        if let Some(path) = env_value {
+            return Some(Self {
+                path: PathBuf::from(path),
+                source: CaBundleSource::Env,
+            });
+        }
+        config.network.ca_bundle.clone().map(|path| Self {
+            path,
+            source: CaBundleSource::ConfigFile,
+        })
+    }
+
+    /// PEM を読み込んで証明書一覧を返す
+    ///
+    /// 読めない・PEM として不正・証明書が 1 件も無い場合は `PlmError::Config` を返す。
+    pub fn load_certificates(&self) -> Result<Vec<reqwest::Certificate>> {
+        let origin = || format!("'{}' (from {})", self.path.display(), self.source.as_str());
+        let pem = std::fs::read(&self.path)
+            .map_err(|e| PlmError::Config(format!("cannot read CA bundle {}: {}", origin(), e)))?;
+        let certs = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+            PlmError::Config(format!("invalid PEM in CA bundle {}: {}", origin(), e))
+        })?;
+        if certs.is_empty() {
+            return Err(PlmError::Config(format!(
+                "no certificates found in CA bundle {}",
+                origin()
+            )));
+        }
+        Ok(certs)
+    }
+}
+
+/// プロキシ関連環境変数の解決結果（診断表示用）
+///
+/// 実際のプロキシ適用は reqwest が行うため、`HttpConfig::build_client` では使わない。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettings {
+    /// `HTTPS_PROXY` / `https_proxy`
+    pub https_proxy: Option<String>,
+    /// `HTTP_PROXY` / `http_proxy`
+    pub http_proxy: Option<String>,
+    /// `ALL_PROXY` / `all_proxy`
+    pub all_proxy: Option<String>,
+    /// `NO_PROXY` / `no_proxy`
+    pub no_proxy: Option<String>,
+}
+
+impl ProxySettings {
+    /// 環境変数から解決する（大文字を優先）
+    pub fn from_env() -> Self {
+        let first = |keys: [&str; 2]| keys.into_iter().find_map(EnvVar::get);
+        Self {
+            https_proxy: first(["HTTPS_PROXY", "https_proxy"]),
+            http_proxy: first(["HTTP_PROXY", "http_proxy"]),
+            all_proxy: first(["ALL_PROXY", "all_proxy"]),
+            no_proxy: first(["NO_PROXY", "no_proxy"]),
+        }
+    }
+}
+
+/// プロキシ URL 中の認証情報（`user:pass@`）を伏せ字にする
+///
+/// # Arguments
+///
+/// * `url` - Proxy URL as given in the environment.
+pub fn redact_proxy_credentials(url: &str) -> String {
+    let (scheme, rest) = match url.split_once("://") {
+        Some((scheme, rest)) => (Some(scheme), rest),
+        None => (None, url),
+    };
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let Some(at) = rest[..authority_end].rfind('@') else {
+        return url.to_string();
+    };
+    let redacted = format!("***@{}", &rest[at + 1..]);
+    match scheme {
+        Some(scheme) => format!("{}://{}", scheme, redacted),
+        None => redacted,
+    }
+}
+
+/// 通信量の制限（同時接続数・帯域・リクエスト間隔）
+///
+/// reqwest で通信する経路（マニフェスト・tarball の取得）にだけ適用する
+/// （[`crate::http::Throttle`]）。`gh` など外部コマンドが行う通信は plm から
+/// 制御できないため、この制限は効かない。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NetworkLimits {
+    /// 同時接続数の上限（`None` なら無制限）
+    pub jobs: Option<usize>,
+    /// 1 接続あたりのダウンロード帯域上限（バイト/秒。`None` なら無制限）
+    pub limit_rate: Option<u64>,
+    /// リクエスト間の最小間隔（`None` なら間隔を空けない）
+    pub request_interval: Option<Duration>,
+}
+
+impl NetworkLimits {
+    /// 環境変数と設定ファイルから解決する
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Loaded user configuration file.
+    pub fn from_env(config: &ConfigFile) -> Result<Self> {
+        let options = GlobalOptions::current();
+        Self::resolve(
+            GlobalOptions::or_env(options.jobs, JOBS_ENV),
+            GlobalOptions::or_env(options.limit_rate.as_deref(), LIMIT_RATE_ENV),
+            GlobalOptions::or_env(options.request_interval, REQUEST_INTERVAL_ENV),
+            config,
+        )
+    }
+
+    /// 環境変数の値と設定ファイルから解決する（環境変数 > 設定ファイル）
+    ///
+    /// 値が不正な場合は `PlmError::Config` を返す（指定した制限が黙って無視されないように）。
+    ///
+    /// # Arguments
+    ///
+    /// * `jobs` - Value of `PLM_JOBS`.
+    /// * `limit_rate` - Value of `PLM_LIMIT_RATE`.
+    /// * `request_interval_ms` - Value of `PLM_REQUEST_INTERVAL_MS`.
+    /// * `config` - Loaded user configuration file.
+    pub fn resolve(
+        jobs: Option<String>,
+        limit_rate: Option<String>,
+        request_interval_ms: Option<String>,
+        config: &ConfigFile,
+    ) -> Result<Self> {
+        let invalid = |key: &str, raw: &str, expected: &str| {
+            PlmError::Config(format!("invalid {} '{}': expected {}", key, raw, expected))
+        };
+
+        let jobs = match jobs {
+            Some(raw) => Some(
+                raw.trim()
+                    .parse::<usize>()
+                    .map_err(|_| invalid(JOBS_ENV, &raw, "a positive integer"))?,
+            ),
+            None => config.network.jobs,
+        };
+        if jobs == Some(0) {
+            return Err(invalid("jobs", "0", "a positive integer"));
+        }
+
+        let (rate_key, rate) = match limit_rate {
+            Some(raw) => (LIMIT_RATE_ENV, Some(raw)),
+            None => ("[network] limit_rate", config.network.limit_rate.clone()),
+        };
+        let limit_rate = rate
+            .map(|raw| {
+                parse_rate(&raw)
+                    .ok_or_else(|| invalid(rate_key, &raw, "bytes per second such as 500k or 2m"))
+            })
+            .transpose()?;
+
+        let interval_ms = match request_interval_ms {
+            Some(raw) => Some(
+                raw.trim()
+                    .parse::<u64>()
+                    .map_err(|_| invalid(REQUEST_INTERVAL_ENV, &raw, "milliseconds"))?,
+            ),
+            None => config.network.request_interval_ms,
+        };
+
+        Ok(Self {
+            jobs,
+            limit_rate,
+            request_interval: interval_ms.filter(|ms| *ms > 0).map(Duration::from_millis),
+        })
+    }
+}
+
+/// 帯域の指定（`500k` / `2m` / `1024` など）をバイト/秒に変換する
+///
+/// 接尾辞 `k` / `m` / `g`（大文字小文字を区別しない）は 1024 倍単位。0 や不正な値は `None`。
+///
+/// # Arguments
+///
+/// * `raw` - Rate as given on the command line or in the config file.
+pub fn parse_rate(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    let (number, multiplier) = match raw.chars().last()?.to_ascii_lowercase() {
+        'k' => (&raw[..raw.len() - 1], 1024),
+        'm' => (&raw[..raw.len() - 1], 1024 * 1024),
+        'g' => (&raw[..raw.len() - 1], 1024 * 1024 * 1024),
+        _ => (raw, 1),
+    };
+    let value = number.parse::<u64>().ok()?.checked_mul(multiplier)?;
+    (value > 0).then_some(value)
+}
+
+/// バイト/秒を表示用の文字列にする（`500 KiB/s` など）
+///
+/// # Arguments
+///
+/// * `bytes_per_sec` - Rate in bytes per second.
+pub fn format_rate(bytes_per_sec: u64) -> String {
+    const UNITS: [(u64, &str); 3] = [
+        (1024 * 1024 * 1024, "GiB"),
+        (1024 * 1024, "MiB"),
+        (1024, "KiB"),
+    ];
+    for (size, unit) in UNITS {
+        if bytes_per_sec >= size && bytes_per_sec % size == 0 {
+            return format!("{} {}/s", bytes_per_sec / size, unit);
+        }
+    }
+    format!("{} B/s", bytes_per_sec)
+}
+
+/// 短期レスポンスキャッシュの設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseCacheConfig {
+    /// ディスクキャッシュの保存先（`None` ならメモリのみ）
+    pub dir: Option<PathBuf>,
+    /// エントリの有効期間
+    pub ttl: Duration,
+    /// 保持するエントリ数の上限（メモリ・ディスクそれぞれ）
+    pub max_entries: usize,
+    /// ヒット/ミスを stderr に出力するか
+    pub verbose: bool,
+}
+
+impl ResponseCacheConfig {
+    /// デフォルトの TTL（5 分）
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+    /// デフォルトのエントリ数上限
+    pub const DEFAULT_MAX_ENTRIES: usize = 256;
+
+    /// 環境変数から構築する（無効化されていれば `None`）
+    ///
+    /// `HOME` / `PLM_HOME` が解決できない場合はメモリのみのキャッシュになる。
+    pub fn from_env() -> Option<Self> {
+        let options = GlobalOptions::current();
+        Self::resolve(
+            GlobalOptions::or_env(options.no_http_cache.then_some("1"), NO_HTTP_CACHE_ENV),
+            EnvVar::get(HTTP_CACHE_TTL_ENV),
+            PlmPaths::new().ok().map(|p| p.http_cache_dir()),
+            options.verbose || EnvVar::get(VERBOSE_ENV).is_some(),
+        )
+    }
+
+    /// 環境変数の値から設定を解決する
+    ///
+    /// `PLM_NO_HTTP_CACHE` が `0` / `false` 以外なら無効。TTL が `0` の場合も無効。
+    /// TTL が数値として解釈できない場合は警告してデフォルトを使う。
+    ///
+    /// # Arguments
+    ///
+    /// * `no_cache` - Value of `PLM_NO_HTTP_CACHE`.
+    /// * `ttl_secs` - Value of `PLM_HTTP_CACHE_TTL`.
+    /// * `dir` - Disk cache directory, if available.
+    /// * `verbose` - Whether verbose logging is enabled.
+    pub fn resolve(
+        no_cache: Option<String>,
+        ttl_secs: Option<String>,
+        dir: Option<PathBuf>,
+        verbose: bool,
+    ) -> Option<Self> {
+        if flag_enabled(no_cache) {
+            return None;
+        }
+
+        let ttl = match ttl_secs {
+            Some(raw) => match raw.trim().parse::<u64>() {
+                Ok(0) => return None,
+                Ok(secs) => Duration::from_secs(secs),
+                Err(_) => {
+                    eprintln!(
+                        "[plm warn] invalid {} '{}'; using {}s",
+                        HTTP_CACHE_TTL_ENV,
+                        raw,
+                        Self::DEFAULT_TTL.as_secs()
+                    );
+                    Self::DEFAULT_TTL
+                }
+            },
+            None => Self::DEFAULT_TTL,
+        };
+
+        Some(Self {
+            dir,
+            ttl,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            verbose,
+        })
+    }
+}
+
 /// HTTP設定
 #[derive(Debug, Clone)]
 pub struct HttpConfig {
-    /// タイムアウト（秒）
+    /// リクエスト全体のタイムアウト
     pub timeout: Option<Duration>,
+    /// 接続確立のタイムアウト
+    pub connect_timeout: Option<Duration>,
     /// User-Agent
     pub user_agent: String,
+    /// 追加ルート証明書（`PLM_CA_BUNDLE` / 設定ファイル）
+    pub ca_bundle: Option<CaBundle>,
+    /// `ca_bundle` から読み込み済みの証明書（`from_env` で 1 度だけ読み込む）
+    pub root_certificates: Vec<reqwest::Certificate>,
+    /// GET レスポンスの短期キャッシュ（`None` なら無効）
+    pub response_cache: Option<ResponseCacheConfig>,
+    /// オフラインモード（`--offline` / `PLM_OFFLINE`）。有効なら一切通信しない
+    pub offline: bool,
+    /// 通信エラー・5xx の再試行回数（`[network] retries`）
+    pub max_retries: u32,
+    /// 同時接続数・帯域・リクエスト間隔の制限
+    pub limits: NetworkLimits,
 }
 
 impl Default for HttpConfig {
     fn default() -> Self {
         Self {
-            timeout: Some(Duration::from_secs(30)),
+            timeout: Some(Self::DEFAULT_TIMEOUT),
+            connect_timeout: Some(Self::DEFAULT_CONNECT_TIMEOUT),
             user_agent: "plm-cli".to_string(),
+            ca_bundle: None,
+            root_certificates: Vec::new(),
+            response_cache: None,
+            offline: false,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            limits: NetworkLimits::default(),
         }
     }
 }
 
 impl HttpConfig {
+    /// デフォルトのリクエスト全体のタイムアウト（120 秒）
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+    /// デフォルトの接続タイムアウト（10 秒）
+    pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+    /// デフォルトの再試行回数
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// 環境変数と設定ファイルから構築する
+    ///
+    /// CA バンドルが指定されている場合はこの時点で読み込み、
+    /// 読めなければエラーを返す（証明書エラーで接続が失敗する前に原因を示すため）。
+    /// 読み込んだ証明書は `build_client` でそのまま使う。
+    pub fn from_env() -> Result<Self> {
+        let config = ConfigFile::load_default()?;
+        let ca_bundle = CaBundle::resolve(EnvVar::get(CA_BUNDLE_ENV), &config);
+        let root_certificates = match &ca_bundle {
+            Some(bundle) => bundle.load_certificates()?,
+            None => Vec::new(),
+        };
+        Ok(Self {
+            timeout: Some(Self::resolve_timeout(EnvVar::get(TIMEOUT_SECS_ENV))),
+            ca_bundle,
+            root_certificates,
+            response_cache: ResponseCacheConfig::from_env(),
+            offline: flag_enabled(GlobalOptions::or_env(
+                GlobalOptions::current().offline.then_some("1"),
+                OFFLINE_ENV,
+            )),
+            max_retries: config.network.retries.unwrap_or(Self::DEFAULT_MAX_RETRIES),
+            limits: NetworkLimits::from_env(&config)?,
+            ..Self::default()
+        })
+    }
+
+    /// `PLM_TIMEOUT_SECS` の値からリクエスト全体のタイムアウトを解決する
+    ///
+    /// 正の整数として解釈できない場合は警告してデフォルトを使う。
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout_secs` - Value of `PLM_TIMEOUT_SECS`.
+    pub fn resolve_timeout(timeout_secs: Option<String>) -> Duration {
+        let Some(raw) = timeout_secs else {
+            return Self::DEFAULT_TIMEOUT;
+        };
+        match raw.trim().parse::<u64>() {
+            Ok(secs) if secs > 0 => Duration::from_secs(secs),
+            _ => {
+                eprintln!(
+                    "[plm warn] invalid {} '{}'; using {}s",
+                    TIMEOUT_SECS_ENV,
+                    raw,
+                    Self::DEFAULT_TIMEOUT.as_secs()
+                );
+                Self::DEFAULT_TIMEOUT
+            }
+        }
+    }
+
     /// reqwest::Client を構築
     pub fn build_client(&self) -> Client {
         let mut builder = Client::builder().user_agent(&self.user_agent);
@@ -30,6 +521,13 @@ impl HttpConfig {
         if let Some(timeout) = self.timeout {
             builder = builder.timeout(timeout);
         }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        for cert in &self.root_certificates {
+            builder = builder.add_root_certificate(cert.clone());
+        }
 
         // 保険: 環境変数から CA を明示追加する。
         // SSL_CERT_FILE は rustls-native-certs でも読まれるが、CODEX_PROXY_CERT 単独設定の
@@ -0,0 +1,134 @@
+//! ユーザー設定ファイル（`~/.plm/config.toml`）
+//!
+//! 現時点で読み込むのは `[network]` / `[tui]` / `[targets.*]` / `[notify]` / `[backup]` セクションのみ。
+//! 未知のセクション・キーは無視する（将来仕様のキーを書いてもエラーにしない）。
+
+use crate::env::PlmPaths;
+use crate::error::{PlmError, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// ユーザー設定ファイルの内容
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigFile {
+    /// `[network]` セクション
+    #[serde(default)]
+    pub network: NetworkSection,
+    /// `[tui]` セクション
+    #[serde(default)]
+    pub tui: TuiSection,
+    /// `[targets.*]` セクション
+    #[serde(default)]
+    pub targets: TargetsSection,
+    /// `[notify]` セクション
+    #[serde(default)]
+    pub notify: NotifySection,
+    /// `[backup]` セクション
+    #[serde(default)]
+    pub backup: BackupSection,
+}
+
+/// `[network]` セクション
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NetworkSection {
+    /// 追加ルート証明書として読み込む PEM ファイル（単一またはバンドル）
+    ///
+    /// 相対パスは設定ファイルのあるディレクトリを基準に解決する。
+    pub ca_bundle: Option<PathBuf>,
+    /// 通信エラー・5xx の再試行回数（未指定なら 3 回）
+    pub retries: Option<u32>,
+    /// 同時接続数の上限（未指定なら無制限）
+    pub jobs: Option<usize>,
+    /// 1 接続あたりのダウンロード帯域上限（`500k` / `2m` など。未指定なら無制限）
+    pub limit_rate: Option<String>,
+    /// リクエスト間の最小間隔（ミリ秒。未指定なら間隔を空けない）
+    pub request_interval_ms: Option<u64>,
+}
+
+/// `[tui]` セクション
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TuiSection {
+    /// カラーテーマ名（`dark` / `light` / `auto` / `high-contrast`）
+    ///
+    /// 値の検証は TUI 起動時に行う（未知の値でも他コマンドの設定読み込みは妨げない）。
+    pub theme: Option<String>,
+}
+
+/// `[targets.*]` セクション（ターゲット別の軽量な配置設定）
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TargetsSection {
+    /// `[targets.copilot]` セクション
+    #[serde(default)]
+    pub copilot: CopilotSection,
+}
+
+/// `[targets.copilot]` セクション
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CopilotSection {
+    /// プロンプトファイルの命名規約（`prompt-md` / `md` / `plugin-prompt-md`）
+    ///
+    /// 値の検証は配置時に行う（[`crate::component::PromptNaming`]）。
+    pub prompt_naming: Option<String>,
+}
+
+/// `[notify]` セクション
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NotifySection {
+    /// `install` / `update` の完了を常にデスクトップ通知する（`--notify` を省略できる）
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// `[backup]` セクション
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BackupSection {
+    /// 残すバックアップの世代数（未指定なら 10）
+    pub keep: Option<usize>,
+}
+
+impl ConfigFile {
+    /// 既定の場所（`{plm_dir}/config.toml`）から読み込む
+    ///
+    /// ファイルが存在しない場合、および `PLM_HOME` / `HOME` が解決できない場合は
+    /// 空の設定を返す（設定ファイルは任意のため、HTTP 通信自体は妨げない）。
+    pub fn load_default() -> Result<Self> {
+        match Self::default_path() {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 既定の設定ファイルパス（`PLM_HOME` / `HOME` が解決できない場合は `None`）
+    pub fn default_path() -> Option<PathBuf> {
+        PlmPaths::new().ok().map(|paths| paths.config_toml())
+    }
+
+    /// 指定パスから読み込む
+    ///
+    /// ファイルが存在しない場合は空の設定を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the TOML configuration file.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| PlmError::Config(format!("cannot read '{}': {}", path.display(), e)))?;
+        let mut config: Self = toml::from_str(&content).map_err(|e| {
+            PlmError::Config(format!("invalid TOML in '{}': {}", path.display(), e))
+        })?;
+
+        if let (Some(ca_bundle), Some(base)) = (&config.network.ca_bundle, path.parent()) {
+            if ca_bundle.is_relative() {
+                config.network.ca_bundle = Some(base.join(ca_bundle));
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+#[path = "file_test.rs"]
+mod tests;
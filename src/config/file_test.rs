@@ -0,0 +1,133 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn load_returns_default_when_file_missing() {
+    let tmp = TempDir::new().unwrap();
+
+    let config = ConfigFile::load(&tmp.path().join("config.toml")).unwrap();
+
+    assert!(config.network.ca_bundle.is_none());
+}
+
+#[test]
+fn load_reads_network_ca_bundle() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[network]\nca_bundle = \"/etc/ssl/corp.pem\"\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(
+        config.network.ca_bundle,
+        Some(PathBuf::from("/etc/ssl/corp.pem"))
+    );
+}
+
+#[test]
+fn load_reads_network_retries() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[network]\nretries = 5\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(config.network.retries, Some(5));
+}
+
+#[test]
+fn load_reads_network_limits() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(
+        &path,
+        "[network]\njobs = 2\nlimit_rate = \"500k\"\nrequest_interval_ms = 200\n",
+    )
+    .unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(config.network.jobs, Some(2));
+    assert_eq!(config.network.limit_rate.as_deref(), Some("500k"));
+    assert_eq!(config.network.request_interval_ms, Some(200));
+}
+
+#[test]
+fn load_resolves_relative_ca_bundle_against_config_dir() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[network]\nca_bundle = \"certs/corp.pem\"\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(
+        config.network.ca_bundle,
+        Some(tmp.path().join("certs/corp.pem"))
+    );
+}
+
+#[test]
+fn load_ignores_unknown_sections() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[general]\ndefault_scope = \"project\"\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert!(config.network.ca_bundle.is_none());
+}
+
+#[test]
+fn load_reports_invalid_toml_as_config_error() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[network\n").unwrap();
+
+    let err = ConfigFile::load(&path).unwrap_err();
+
+    assert!(matches!(err, PlmError::Config(msg) if msg.contains("invalid TOML")));
+}
+
+#[test]
+fn load_reads_tui_theme() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[tui]\ntheme = \"light\"\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(config.tui.theme.as_deref(), Some("light"));
+    assert!(config.network.ca_bundle.is_none());
+}
+
+#[test]
+fn load_reads_copilot_prompt_naming() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[targets.copilot]\nprompt_naming = \"md\"\n").unwrap();
+
+    let config = ConfigFile::load(&path).unwrap();
+
+    assert_eq!(config.targets.copilot.prompt_naming.as_deref(), Some("md"));
+}
+
+#[test]
+fn load_reads_notify_enabled() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[notify]\nenabled = true\n").unwrap();
+
+    assert!(ConfigFile::load(&path).unwrap().notify.enabled);
+    assert!(!ConfigFile::default().notify.enabled);
+}
+
+#[test]
+fn load_reads_backup_keep() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("config.toml");
+    fs::write(&path, "[backup]\nkeep = 3\n").unwrap();
+
+    assert_eq!(ConfigFile::load(&path).unwrap().backup.keep, Some(3));
+    assert_eq!(ConfigFile::default().backup.keep, None);
+}
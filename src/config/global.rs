@@ -0,0 +1,65 @@
+//! コマンドラインのグローバルオプション
+//!
+//! `--offline` や `--jobs` などのグローバルフラグを、HTTP 設定やロック待ちなどの下位レイヤーへ渡す。
+//! `main` がコマンド実行前に 1 度だけ [`GlobalOptions::install`] し、各設定は対応する
+//! 環境変数より先にここを参照する（[`GlobalOptions::or_env`]）。
+
+use crate::env::EnvVar;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// プロセス全体で共有するグローバルオプション
+static GLOBAL: OnceLock<GlobalOptions> = OnceLock::new();
+
+/// コマンドラインで指定されたグローバルオプション
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlobalOptions {
+    /// `--no-http-cache`
+    pub no_http_cache: bool,
+    /// `--verbose`
+    pub verbose: bool,
+    /// `--offline`
+    pub offline: bool,
+    /// `--quiet`
+    pub quiet: bool,
+    /// `--wait`
+    pub wait: bool,
+    /// `--wait-lock <SECONDS>`
+    pub wait_lock: Option<u64>,
+    /// `--project-root <PATH>`
+    pub project_root: Option<PathBuf>,
+    /// `--jobs <N>`
+    pub jobs: Option<usize>,
+    /// `--limit-rate <RATE>`
+    pub limit_rate: Option<String>,
+    /// `--request-interval <MS>`
+    pub request_interval: Option<u64>,
+}
+
+impl GlobalOptions {
+    /// プロセス全体のグローバルオプションとして設定する（2 回目以降は無視する）
+    pub fn install(self) {
+        let _ = GLOBAL.set(self);
+    }
+
+    /// 設定済みのグローバルオプション（未設定ならすべて未指定）
+    pub fn current() -> &'static GlobalOptions {
+        GLOBAL.get_or_init(GlobalOptions::default)
+    }
+
+    /// コマンドラインの値があればそれを、無ければ環境変数の値を返す
+    ///
+    /// 値を取らないフラグは `flag.then_some("1")` のように渡す。
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value given on the command line, if any.
+    /// * `env` - Name of the environment variable to fall back to.
+    pub fn or_env(value: Option<impl ToString>, env: &str) -> Option<String> {
+        value.map(|v| v.to_string()).or_else(|| EnvVar::get(env))
+    }
+}
+
+#[cfg(test)]
+#[path = "global_test.rs"]
+mod tests;
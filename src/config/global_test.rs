@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn or_env_prefers_command_line_value() {
+    assert_eq!(
+        GlobalOptions::or_env(Some(4), "PATH"),
+        Some("4".to_string())
+    );
+}
+
+#[test]
+fn or_env_falls_back_to_environment() {
+    assert_eq!(
+        GlobalOptions::or_env(None::<u64>, "PLM_TEST_GLOBAL_OPTIONS_UNSET"),
+        None
+    );
+    assert_eq!(
+        GlobalOptions::or_env(false.then_some("1"), "PATH"),
+        EnvVar::get("PATH")
+    );
+}
+
+#[test]
+fn default_options_leave_everything_unset() {
+    let options = GlobalOptions::default();
+    assert!(!options.offline);
+    assert_eq!(options.jobs, None);
+    assert_eq!(options.project_root, None);
+}
@@ -12,8 +12,15 @@ impl EnvVar {
     }
 }
 
+use crate::config::GlobalOptions;
 use crate::error::{PlmError, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// プロジェクトルートを指定する環境変数（`--project-root` が優先）
+pub const PROJECT_ROOT_ENV: &str = "PLM_PROJECT_ROOT";
+
+/// 自動検出でプロジェクトルートとみなす目印（いずれかを直下に持つディレクトリ）
+const PROJECT_ROOT_MARKERS: &[&str] = &[".plm/config.json", ".git"];
 
 /// PLM の状態ルートディレクトリを返す。
 ///
@@ -41,6 +48,57 @@ pub(crate) fn plm_root() -> Result<PathBuf> {
     Ok(path)
 }
 
+/// 配置・走査の基準にするプロジェクトルートを返す
+///
+/// `PLM_PROJECT_ROOT`（`--project-root`）→ カレントディレクトリから親方向の自動検出 →
+/// カレントディレクトリ、の順に解決する。
+/// - 相対パスはカレントディレクトリ基準
+/// - 指定されたパスが存在しない・ディレクトリでない場合はエラー
+pub(crate) fn project_root() -> Result<PathBuf> {
+    let cwd = std::env::current_dir()
+        .map_err(|e| PlmError::General(format!("Failed to get current dir: {}", e)))?;
+    let option = GlobalOptions::current()
+        .project_root
+        .as_ref()
+        .map(|p| p.display());
+    if let Some(raw) =
+        GlobalOptions::or_env(option, PROJECT_ROOT_ENV).filter(|s| !s.trim().is_empty())
+    {
+        let path = cwd.join(raw.trim());
+        if !path.is_dir() {
+            return Err(PlmError::General(format!(
+                "Project root does not exist or is not a directory: {} (from --project-root / {})",
+                path.display(),
+                PROJECT_ROOT_ENV
+            )));
+        }
+        return Ok(path);
+    }
+    let home = EnvVar::get("HOME").map(PathBuf::from);
+    Ok(detect_project_root(&cwd, home.as_deref()).unwrap_or(cwd))
+}
+
+/// `start` から親方向にたどり、目印（`.plm/config.json` / `.git`）を持つ最初のディレクトリを返す
+///
+/// ホームディレクトリとその上は対象外（`~/.git` で dotfiles を管理している場合に
+/// ホーム全体をプロジェクトとみなさないため）。
+///
+/// # Arguments
+///
+/// * `start` - Directory to start from (usually the current directory).
+/// * `home` - Home directory, where the search stops.
+pub(crate) fn detect_project_root(start: &Path, home: Option<&Path>) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .take_while(|dir| home.is_none_or(|home| !home.starts_with(dir)))
+        .find(|dir| {
+            PROJECT_ROOT_MARKERS
+                .iter()
+                .any(|marker| dir.join(marker).exists())
+        })
+        .map(Path::to_path_buf)
+}
+
 /// PLM 状態ファイル群のパスを集約する値オブジェクト
 ///
 /// `new()` で本番環境変数から構築、`with_root()` でテスト用パスを注入する。
@@ -78,11 +136,26 @@ impl PlmPaths {
         self.plm_dir().join("marketplaces.json")
     }
 
+    /// ユーザー設定ファイルのパス: `{plm_dir}/config.toml`
+    pub(crate) fn config_toml(&self) -> PathBuf {
+        self.plm_dir().join("config.toml")
+    }
+
     /// インポートレジストリのパス: `{plm_dir}/imports.json`
     pub(crate) fn imports_json(&self) -> PathBuf {
         self.plm_dir().join("imports.json")
     }
 
+    /// 配置記録のパス: `{plm_dir}/placements.json`
+    pub(crate) fn placements_json(&self) -> PathBuf {
+        self.plm_dir().join("placements.json")
+    }
+
+    /// TUI の表示設定のパス: `{plm_dir}/tui_state.json`
+    pub(crate) fn tui_state_json(&self) -> PathBuf {
+        self.plm_dir().join("tui_state.json")
+    }
+
     /// プラグインキャッシュディレクトリ: `{plm_dir}/cache/plugins`
     pub(crate) fn plugins_cache_dir(&self) -> PathBuf {
         self.plm_dir().join("cache").join("plugins")
@@ -92,6 +165,22 @@ impl PlmPaths {
     pub(crate) fn marketplaces_cache_dir(&self) -> PathBuf {
         self.plm_dir().join("cache").join("marketplaces")
     }
+
+    /// HTTP レスポンスキャッシュディレクトリ: `{plm_dir}/cache/http`
+    pub(crate) fn http_cache_dir(&self) -> PathBuf {
+        self.plm_dir().join("cache").join("http")
+    }
+
+    /// 破壊的操作前のバックアップ置き場: `{root}/.local/state/plm/backups`
+    ///
+    /// キャッシュと違い消えても再取得できないため、XDG の state ディレクトリに置く。
+    pub(crate) fn backups_dir(&self) -> PathBuf {
+        self.root
+            .join(".local")
+            .join("state")
+            .join("plm")
+            .join("backups")
+    }
 }
 
 #[cfg(test)]
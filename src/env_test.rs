@@ -69,6 +69,15 @@ fn plm_paths_with_root_plm_dir() {
     assert_eq!(paths.plm_dir(), PathBuf::from("/tmp/foo/.plm"));
 }
 
+#[test]
+fn plm_paths_with_root_config_toml() {
+    let paths = PlmPaths::with_root(PathBuf::from("/tmp/foo"));
+    assert_eq!(
+        paths.config_toml(),
+        PathBuf::from("/tmp/foo/.plm/config.toml")
+    );
+}
+
 #[test]
 fn plm_paths_with_root_targets_json() {
     let paths = PlmPaths::with_root(PathBuf::from("/tmp/foo"));
@@ -114,6 +123,24 @@ fn plm_paths_with_root_marketplaces_cache_dir() {
     );
 }
 
+#[test]
+fn plm_paths_with_root_http_cache_dir() {
+    let paths = PlmPaths::with_root(PathBuf::from("/tmp/foo"));
+    assert_eq!(
+        paths.http_cache_dir(),
+        PathBuf::from("/tmp/foo/.plm/cache/http")
+    );
+}
+
+#[test]
+fn plm_paths_with_root_backups_dir() {
+    let paths = PlmPaths::with_root(PathBuf::from("/tmp/foo"));
+    assert_eq!(
+        paths.backups_dir(),
+        PathBuf::from("/tmp/foo/.local/state/plm/backups")
+    );
+}
+
 #[test]
 fn plm_paths_five_accessors_share_plm_prefix() {
     let root = PathBuf::from("/tmp/unify");
@@ -262,3 +289,63 @@ fn plm_paths_new_uses_plm_root() {
         plm_home.path().join(".plm").join("targets.json")
     );
 }
+
+#[test]
+fn project_root_uses_existing_env_path() {
+    let _lock = env_lock().lock().unwrap();
+    let project = TempDir::new().unwrap();
+    let guard = EnvGuard::clear(&[PROJECT_ROOT_ENV]);
+    guard.set(PROJECT_ROOT_ENV, project.path());
+
+    assert_eq!(project_root().unwrap(), project.path());
+}
+
+#[test]
+fn project_root_rejects_missing_env_path() {
+    let _lock = env_lock().lock().unwrap();
+    let project = TempDir::new().unwrap();
+    let missing = project.path().join("missing");
+    let guard = EnvGuard::clear(&[PROJECT_ROOT_ENV]);
+    guard.set(PROJECT_ROOT_ENV, &missing);
+
+    let err = project_root().unwrap_err().to_string();
+    assert!(err.contains("Project root does not exist"), "{}", err);
+    assert!(err.contains(&missing.display().to_string()), "{}", err);
+}
+
+#[test]
+fn detect_project_root_finds_nearest_marker_above_start() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join("repo");
+    let nested = project.join("src").join("deep");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::create_dir_all(project.join(".git")).unwrap();
+
+    assert_eq!(detect_project_root(&nested, None), Some(project.clone()));
+    assert_eq!(detect_project_root(&project, None), Some(project));
+}
+
+#[test]
+fn detect_project_root_accepts_project_config_marker() {
+    let dir = TempDir::new().unwrap();
+    let nested = dir.path().join("sub");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::create_dir_all(dir.path().join(".plm")).unwrap();
+    std::fs::write(dir.path().join(".plm").join("config.json"), "{}").unwrap();
+
+    assert_eq!(
+        detect_project_root(&nested, None),
+        Some(dir.path().to_path_buf())
+    );
+}
+
+#[test]
+fn detect_project_root_stops_at_home() {
+    let home = TempDir::new().unwrap();
+    let nested = home.path().join("projects").join("app");
+    std::fs::create_dir_all(&nested).unwrap();
+    // dotfiles 管理の ~/.git はプロジェクトの目印にしない
+    std::fs::create_dir_all(home.path().join(".git")).unwrap();
+
+    assert_eq!(detect_project_root(&nested, Some(home.path())), None);
+}
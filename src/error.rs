@@ -58,6 +58,17 @@ pub enum PlmError {
         message: String,
     },
 
+    /// API のレート制限に達した（値はリセット時刻の `HH:MM`）
+    #[error("rate limit exceeded, resets at {resets_at}")]
+    RateLimited { resets_at: String },
+
+    #[error("timed out after {secs}s while fetching {resource}")]
+    Timeout { secs: u64, resource: String },
+
+    /// オフラインモードでネットワーク操作を要求された（値は操作の説明）
+    #[error("{0} requires network (running in offline mode)")]
+    Offline(String),
+
     #[error("Invalid repository format: {0}. Expected 'owner/repo' or 'owner/repo@ref'")]
     InvalidRepoFormat(String),
 
@@ -79,6 +90,20 @@ pub enum PlmError {
         candidates: Vec<String>,
     },
 
+    /// 同じプラグインを別プロセスが操作中（値はプラグイン名）
+    #[error("plugin '{0}' is being modified by another process")]
+    PluginBusy(String),
+
+    /// 別プロセスのロック解放を待ったがタイムアウトした
+    #[error(
+        "timed out after {seconds}s waiting for plugin '{name}' to be released by another process"
+    )]
+    LockTimeout { name: String, seconds: u64 },
+
+    /// アーカイブの内容が checksums.json と一致しない（値は不一致の詳細）
+    #[error("checksum verification failed: {0}")]
+    ChecksumMismatch(String),
+
     #[error("Invalid plugin name: {0}")]
     InvalidPluginName(String),
 
@@ -139,6 +164,9 @@ pub enum PlmError {
     #[error("Hook conversion error: {0}")]
     HookConversion(String),
 
+    #[error("Configuration error: {0}")]
+    Config(String),
+
     /// コマンドハンドラの String エラーを PlmError として伝搬するためのバリアント
     #[error("{0}")]
     General(String),
@@ -150,7 +178,7 @@ impl PlmError {
     /// リトライ可能なエラーかどうか
     pub fn is_retryable(&self) -> bool {
         match self {
-            PlmError::Network(_) => true,
+            PlmError::Network(_) | PlmError::Timeout { .. } => true,
             PlmError::RepoApi { status, .. } => {
                 // 5xx エラーはリトライ可能
                 *status >= 500 && *status < 600
@@ -182,6 +210,13 @@ impl From<PlmError> for RichError {
         let (code, message, context) = match &err {
             PlmError::Network(_) => unreachable!("Network handled above"),
             PlmError::General(s) => (ErrorCode::Cli001, s.clone(), ErrorContext::default()),
+            PlmError::Timeout { .. } => {
+                (ErrorCode::Net002, err.to_string(), ErrorContext::default())
+            }
+            PlmError::Offline(_) => (ErrorCode::Net003, err.to_string(), ErrorContext::default()),
+            PlmError::RateLimited { .. } => {
+                (ErrorCode::Api001, err.to_string(), ErrorContext::default())
+            }
             PlmError::RepoApi {
                 url,
                 status,
@@ -234,6 +269,13 @@ impl From<PlmError> for RichError {
                 );
                 (ErrorCode::Plg003, msg, ctx)
             }
+            PlmError::PluginBusy(name) | PlmError::LockTimeout { name, .. } => {
+                let ctx = ErrorContext::new().with_plugin_name(name.clone());
+                (ErrorCode::Plg004, err.to_string(), ctx)
+            }
+            PlmError::ChecksumMismatch(_) => {
+                (ErrorCode::Plg005, err.to_string(), ErrorContext::default())
+            }
             PlmError::InvalidPluginName(name) => {
                 let ctx = ErrorContext::new().with_plugin_name(name.clone());
                 (
@@ -341,6 +383,11 @@ impl From<PlmError> for RichError {
                 format!("Hook conversion error: {}", s),
                 ErrorContext::default(),
             ),
+            PlmError::Config(s) => (
+                ErrorCode::Cfg001,
+                format!("Configuration error: {}", s),
+                ErrorContext::default(),
+            ),
         };
 
         RichError::new(code, message).with_context(context)
@@ -485,6 +532,7 @@ mod tests {
                 to: "Codex".to_string(),
             },
             PlmError::HookConversion("test".to_string()),
+            PlmError::Config("test".to_string()),
             PlmError::General("test".to_string()),
         ];
 
@@ -503,6 +551,80 @@ mod tests {
         assert_eq!(rich.message(), "handler failed");
     }
 
+    #[test]
+    fn plm_error_to_rich_error_timeout() {
+        let error = PlmError::Timeout {
+            secs: 120,
+            resource: "owner/repo".to_string(),
+        };
+        assert!(error.is_retryable());
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Net002);
+        assert_eq!(
+            rich.message(),
+            "timed out after 120s while fetching owner/repo"
+        );
+    }
+
+    #[test]
+    fn plm_error_to_rich_error_offline() {
+        let error = PlmError::Offline("downloading owner/repo".to_string());
+        assert!(!error.is_retryable());
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Net003);
+        assert_eq!(
+            rich.message(),
+            "downloading owner/repo requires network (running in offline mode)"
+        );
+    }
+
+    #[test]
+    fn plm_error_to_rich_error_rate_limited() {
+        let error = PlmError::RateLimited {
+            resets_at: "14:05".to_string(),
+        };
+        assert!(!error.is_retryable());
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Api001);
+        assert_eq!(rich.message(), "rate limit exceeded, resets at 14:05");
+    }
+
+    #[test]
+    fn plm_error_to_rich_error_plugin_busy() {
+        let error = PlmError::PluginBusy("foo".to_string());
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Plg004);
+        assert_eq!(
+            rich.message(),
+            "plugin 'foo' is being modified by another process"
+        );
+    }
+
+    #[test]
+    fn plm_error_to_rich_error_lock_timeout() {
+        let error = PlmError::LockTimeout {
+            name: "foo".to_string(),
+            seconds: 30,
+        };
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Plg004);
+        assert_eq!(
+            rich.message(),
+            "timed out after 30s waiting for plugin 'foo' to be released by another process"
+        );
+    }
+
+    #[test]
+    fn plm_error_to_rich_error_checksum_mismatch() {
+        let error = PlmError::ChecksumMismatch("skills/a/SKILL.md".to_string());
+        let rich: RichError = error.into();
+        assert_eq!(rich.code(), ErrorCode::Plg005);
+        assert_eq!(
+            rich.message(),
+            "checksum verification failed: skills/a/SKILL.md"
+        );
+    }
+
     #[test]
     fn format_error_with_sources_joins_cause_chain() {
         #[derive(Debug)]
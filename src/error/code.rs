@@ -19,6 +19,8 @@ pub enum ErrorCode {
     Net001,
     /// Request timeout
     Net002,
+    /// Network access requested in offline mode
+    Net003,
 
     // API errors (API001-API099)
     /// Rate limit exceeded
@@ -51,6 +53,10 @@ pub enum ErrorCode {
     Plg002,
     /// Ambiguous plugin name
     Plg003,
+    /// Plugin is locked by another process
+    Plg004,
+    /// Plugin archive failed checksum verification
+    Plg005,
 
     // Marketplace errors (MKT001-MKT099)
     /// Marketplace not found
@@ -86,6 +92,7 @@ impl ErrorCode {
             // Network
             ErrorCode::Net001 => "NET001",
             ErrorCode::Net002 => "NET002",
+            ErrorCode::Net003 => "NET003",
             // API
             ErrorCode::Api001 => "API001",
             ErrorCode::Api002 => "API002",
@@ -102,6 +109,8 @@ impl ErrorCode {
             ErrorCode::Plg001 => "PLG001",
             ErrorCode::Plg002 => "PLG002",
             ErrorCode::Plg003 => "PLG003",
+            ErrorCode::Plg004 => "PLG004",
+            ErrorCode::Plg005 => "PLG005",
             // Marketplace
             ErrorCode::Mkt001 => "MKT001",
             // TUI
@@ -124,6 +133,7 @@ impl ErrorCode {
             // Network
             ErrorCode::Net001 => "Unable to establish network connection to the server",
             ErrorCode::Net002 => "The request timed out while waiting for a response",
+            ErrorCode::Net003 => "Network access is disabled in offline mode",
             // API
             ErrorCode::Api001 => "API rate limit has been exceeded",
             ErrorCode::Api002 => "Authentication failed or access denied",
@@ -140,6 +150,8 @@ impl ErrorCode {
             ErrorCode::Plg001 => "The specified plugin was not found",
             ErrorCode::Plg002 => "The plugin manifest is invalid or corrupted",
             ErrorCode::Plg003 => "Multiple plugins with the same name were found",
+            ErrorCode::Plg004 => "The plugin is being modified by another plm process",
+            ErrorCode::Plg005 => "The plugin archive does not match its checksums.json",
             // Marketplace
             ErrorCode::Mkt001 => "The specified marketplace was not found",
             // TUI
@@ -162,6 +174,7 @@ impl ErrorCode {
             // Network
             ErrorCode::Net001 => "1. Check your internet connection\n2. Verify the URL is correct\n3. Try again later if the server is down",
             ErrorCode::Net002 => "1. Check your internet connection speed\n2. Try again with a longer timeout\n3. The server may be overloaded, try later",
+            ErrorCode::Net003 => "1. Run the command again without --offline\n2. Unset PLM_OFFLINE (or set it to 0)\n3. Use cached data only: list / info / enable / disable work offline",
             // API
             ErrorCode::Api001 => "1. Wait a few minutes before retrying\n2. Check your API usage quota\n3. Consider using authenticated requests",
             ErrorCode::Api002 => "1. Verify your credentials are correct\n2. Check if your token has expired\n3. Ensure you have the required permissions",
@@ -178,6 +191,10 @@ impl ErrorCode {
             ErrorCode::Plg001 => "1. Verify the plugin name is correct\n2. Check if the plugin is installed\n3. Use 'plm list' to see available plugins",
             ErrorCode::Plg002 => "1. Re-download the plugin\n2. Check if the plugin is compatible\n3. Report the issue to the plugin author",
             ErrorCode::Plg003 => "1. Use the full plugin identifier\n2. Specify the marketplace explicitly\n3. Use 'plm info' to see available options",
+            ErrorCode::Plg004 if cfg!(unix) => "1. Wait for the other plm process to finish and try again\n2. Re-run with --wait (or PLM_LOCK_WAIT=1) to wait automatically, or --wait-lock <seconds> (PLM_WAIT_LOCK) to wait up to a limit\n3. Locks left by a killed process are removed automatically",
+            // PID の生存確認ができない環境では stale ロックを自動で取り除けない
+            ErrorCode::Plg004 => "1. Wait for the other plm process to finish and try again\n2. Re-run with --wait (or PLM_LOCK_WAIT=1) to wait automatically, or --wait-lock <seconds> (PLM_WAIT_LOCK) to wait up to a limit\n3. If no plm process is running, delete the .<plugin>.lock file next to the plugin under ~/.plm/cache/plugins/<marketplace>/",
+            ErrorCode::Plg005 => "1. Download the archive again\n2. Ask the plugin author to re-create it with 'plm pack'\n3. Do not install archives from untrusted sources",
             // Marketplace
             ErrorCode::Mkt001 => "1. Verify the marketplace name\n2. Register the marketplace first\n3. Use 'plm marketplace list' to see available marketplaces",
             // TUI
@@ -204,6 +221,7 @@ mod tests {
         let codes = [
             ErrorCode::Net001,
             ErrorCode::Net002,
+            ErrorCode::Net003,
             ErrorCode::Api001,
             ErrorCode::Api002,
             ErrorCode::Api003,
@@ -216,6 +234,8 @@ mod tests {
             ErrorCode::Plg001,
             ErrorCode::Plg002,
             ErrorCode::Plg003,
+            ErrorCode::Plg004,
+            ErrorCode::Plg005,
             ErrorCode::Mkt001,
             ErrorCode::Tui001,
             ErrorCode::Val001,
@@ -241,6 +261,7 @@ mod tests {
         let codes = [
             ErrorCode::Net001,
             ErrorCode::Net002,
+            ErrorCode::Net003,
             ErrorCode::Api001,
             ErrorCode::Api002,
             ErrorCode::Api003,
@@ -253,6 +274,8 @@ mod tests {
             ErrorCode::Plg001,
             ErrorCode::Plg002,
             ErrorCode::Plg003,
+            ErrorCode::Plg004,
+            ErrorCode::Plg005,
             ErrorCode::Mkt001,
             ErrorCode::Tui001,
             ErrorCode::Val001,
@@ -277,6 +300,7 @@ mod tests {
         let codes = [
             ErrorCode::Net001,
             ErrorCode::Net002,
+            ErrorCode::Net003,
             ErrorCode::Api001,
             ErrorCode::Api002,
             ErrorCode::Api003,
@@ -289,6 +313,8 @@ mod tests {
             ErrorCode::Plg001,
             ErrorCode::Plg002,
             ErrorCode::Plg003,
+            ErrorCode::Plg004,
+            ErrorCode::Plg005,
             ErrorCode::Mkt001,
             ErrorCode::Tui001,
             ErrorCode::Val001,
@@ -313,6 +339,7 @@ mod tests {
         let codes = [
             (ErrorCode::Net001, "NET"),
             (ErrorCode::Net002, "NET"),
+            (ErrorCode::Net003, "NET"),
             (ErrorCode::Api001, "API"),
             (ErrorCode::Api002, "API"),
             (ErrorCode::Api003, "API"),
@@ -325,6 +352,8 @@ mod tests {
             (ErrorCode::Plg001, "PLG"),
             (ErrorCode::Plg002, "PLG"),
             (ErrorCode::Plg003, "PLG"),
+            (ErrorCode::Plg004, "PLG"),
+            (ErrorCode::Plg005, "PLG"),
             (ErrorCode::Mkt001, "MKT"),
             (ErrorCode::Tui001, "TUI"),
             (ErrorCode::Val001, "VAL"),
@@ -1,13 +1,21 @@
 //! Hooks module for hook configuration conversion.
 //!
 //! Provides polymorphic conversion layers for Claude Code hooks
-//! to various target formats (Copilot CLI, Codex, etc.).
+//! to various target formats (Copilot CLI, Codex, etc.), plus the sample
+//! payloads and local runner behind `plm hooks test`.
 
 pub mod converter;
 pub(crate) mod event;
 mod model;
+pub(crate) mod runner;
+pub(crate) mod sample;
 pub(crate) mod tool;
 
 // `name` モジュール経路据置のためのモジュール再エクスポート
 // （rustc E0365 回避: model.rs 側で `pub(crate) mod name;` 宣言済み）
 pub(crate) use model::name;
+
+#[cfg(test)]
+mod runner_test;
+#[cfg(test)]
+mod sample_test;
@@ -44,6 +44,23 @@ impl HookEvent {
             other => Self::Other(other.to_string()),
         }
     }
+
+    /// Return the Claude Code name for this event.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SessionStart => "SessionStart",
+            Self::SessionEnd => "SessionEnd",
+            Self::PreToolUse => "PreToolUse",
+            Self::PostToolUse => "PostToolUse",
+            Self::PostToolUseFailure => "PostToolUseFailure",
+            Self::UserPromptSubmit => "UserPromptSubmit",
+            Self::Stop => "Stop",
+            Self::SubagentStart => "SubagentStart",
+            Self::SubagentStop => "SubagentStop",
+            Self::PreCompact => "PreCompact",
+            Self::Other(s) => s,
+        }
+    }
 }
 
 /// A single event name mapping (always 1:1).
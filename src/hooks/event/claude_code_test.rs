@@ -63,6 +63,13 @@ fn test_from_str_unknown_and_empty() {
     assert_eq!(HookEvent::from_str(""), HookEvent::Other("".into()));
 }
 
+#[test]
+fn test_as_str_roundtrips_from_str() {
+    for name in ["PreToolUse", "Stop", "PreCompact", "Notification"] {
+        assert_eq!(HookEvent::from_str(name).as_str(), name);
+    }
+}
+
 // ============================================================================
 // to_target_event
 // ============================================================================
@@ -1,7 +1,7 @@
 use crate::hooks::converter::EventMap;
 use crate::hooks::event::claude_code::{to_target_event, EventBridge, HookEvent};
 
-const COPILOT_EVENT_ENTRIES: &[EventBridge] = &[
+pub(crate) const COPILOT_EVENT_ENTRIES: &[EventBridge] = &[
     EventBridge {
         event: HookEvent::SessionStart,
         target: "sessionStart",
@@ -0,0 +1,161 @@
+//! Local hook test runner.
+//!
+//! Executes a hook script with a JSON payload on stdin and captures the
+//! exit code, stdout and stderr. For Copilot, the script is wrapped in the
+//! same wrapper `plm install` deploys, so the conversion is exercised too.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde_json::json;
+
+use crate::error::PlmError;
+use crate::hooks::converter::{self, shell_escape, ConversionWarning};
+use crate::target::TargetKind;
+
+/// Captured result of a single hook execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HookRun {
+    /// Exit code (`None` when terminated by a signal).
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Run `program` with `payload` on stdin.
+///
+/// `CLAUDE_PROJECT_DIR` / `CLAUDE_PLUGIN_ROOT` are set to `project_root`,
+/// mirroring what Claude Code exports for plugin hooks.
+///
+/// # Arguments
+///
+/// * `program` - Executable to spawn.
+/// * `args` - Arguments passed to `program`.
+/// * `payload` - Hook input written to stdin.
+/// * `project_root` - Directory used as the working directory and hook env root.
+fn run_with_stdin(
+    program: &Path,
+    args: &[&Path],
+    payload: &str,
+    project_root: &Path,
+) -> Result<HookRun, PlmError> {
+    let mut child = Command::new(program)
+        .args(args)
+        .current_dir(project_root)
+        .env("CLAUDE_PROJECT_DIR", project_root)
+        .env("CLAUDE_PLUGIN_ROOT", project_root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!(
+                    "failed to execute {}: {} (is it executable?)",
+                    program.display(),
+                    e
+                ),
+            )
+        })?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // フックが stdin を読まずに終了した場合の BrokenPipe は無視する
+        if let Err(e) = stdin.write_all(payload.as_bytes()) {
+            if e.kind() != std::io::ErrorKind::BrokenPipe {
+                return Err(e.into());
+            }
+        }
+    }
+
+    let output = child.wait_with_output()?;
+    Ok(HookRun {
+        exit_code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+/// Run a hook script directly, as Claude Code would.
+///
+/// # Arguments
+///
+/// * `script` - Hook script to execute.
+/// * `payload` - Claude Code hook input written to stdin.
+/// * `project_root` - Directory used as the working directory and hook env root.
+pub(crate) fn run_hook(
+    script: &Path,
+    payload: &str,
+    project_root: &Path,
+) -> Result<HookRun, PlmError> {
+    run_with_stdin(script, &[], payload, project_root)
+}
+
+/// Generate the wrapper script `plm install` would deploy for `script`.
+///
+/// # Arguments
+///
+/// * `target` - Target whose wrapper should be generated.
+/// * `event` - Canonical Claude Code event name.
+/// * `script` - Hook script the wrapper invokes.
+/// * `plugin_root` - Value substituted for `@@PLUGIN_ROOT@@`.
+pub(crate) fn build_wrapper(
+    target: TargetKind,
+    event: &str,
+    script: &Path,
+    plugin_root: &Path,
+) -> Result<String, PlmError> {
+    // ラッパーは ORIGINAL_CMD を eval するため、空白入りパスに備えてクォートする
+    let command = format!("'{}'", shell_escape(&script.display().to_string()));
+    let config = json!({
+        "hooks": {
+            event: [{
+                "hooks": [{ "type": "command", "command": command }]
+            }]
+        }
+    });
+    let outcome = converter::convert(&config.to_string(), target)?;
+
+    if let Some(warning) = outcome
+        .warnings
+        .iter()
+        .find(|w| matches!(w, ConversionWarning::UnsupportedEvent { .. }))
+    {
+        return Err(PlmError::HookConversion(warning.to_string()));
+    }
+    let wrapper = outcome.scripts.into_iter().next().ok_or_else(|| {
+        PlmError::HookConversion(format!(
+            "Target '{}' does not use wrapper scripts for command hooks",
+            target.as_str()
+        ))
+    })?;
+
+    Ok(wrapper
+        .content
+        .replace("@@PLUGIN_ROOT@@", &plugin_root.display().to_string()))
+}
+
+/// Run a hook script through a target wrapper script.
+///
+/// The wrapper is written to a temporary file and executed with `bash`.
+///
+/// # Arguments
+///
+/// * `wrapper` - Wrapper script content (see [`build_wrapper`]).
+/// * `payload` - Target-format hook input written to stdin.
+/// * `project_root` - Directory used as the working directory and hook env root.
+pub(crate) fn run_wrapped_hook(
+    wrapper: &str,
+    payload: &str,
+    project_root: &Path,
+) -> Result<HookRun, PlmError> {
+    let mut file = tempfile::Builder::new()
+        .prefix("plm-hook-wrapper-")
+        .suffix(".sh")
+        .tempfile()?;
+    file.write_all(wrapper.as_bytes())?;
+    file.flush()?;
+
+    run_with_stdin(Path::new("bash"), &[file.path()], payload, project_root)
+}
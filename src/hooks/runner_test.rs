@@ -0,0 +1,102 @@
+//! Unit tests for the local hook test runner.
+
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use super::runner::{build_wrapper, run_hook, run_wrapped_hook};
+use super::sample::{sample_payload, to_copilot_payload};
+use crate::target::TargetKind;
+
+fn write_script(dir: &Path, name: &str, body: &str) -> std::path::PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, format!("#!/bin/bash\n{}", body)).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    path
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_hook_captures_exit_code_and_output() {
+    let temp = TempDir::new().unwrap();
+    let script = write_script(
+        temp.path(),
+        "hook.sh",
+        "INPUT=$(cat)\necho \"out:$INPUT\"\necho err >&2\nexit 2\n",
+    );
+
+    let run = run_hook(&script, "{\"a\":1}", temp.path()).unwrap();
+
+    assert_eq!(run.exit_code, Some(2));
+    assert_eq!(run.stdout, "out:{\"a\":1}\n");
+    assert_eq!(run.stderr, "err\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_hook_sets_plugin_root_env() {
+    let temp = TempDir::new().unwrap();
+    let script = write_script(temp.path(), "hook.sh", "echo \"$CLAUDE_PLUGIN_ROOT\"\n");
+
+    let run = run_hook(&script, "{}", temp.path()).unwrap();
+
+    assert_eq!(run.stdout.trim(), temp.path().display().to_string());
+}
+
+#[test]
+fn test_run_hook_missing_script_is_error() {
+    let temp = TempDir::new().unwrap();
+    assert!(run_hook(&temp.path().join("missing.sh"), "{}", temp.path()).is_err());
+}
+
+#[test]
+fn test_build_wrapper_substitutes_plugin_root() {
+    let wrapper = build_wrapper(
+        TargetKind::Copilot,
+        "PreToolUse",
+        Path::new("/plugin/scripts/pre.sh"),
+        Path::new("/plugin"),
+    )
+    .unwrap();
+
+    assert!(wrapper.contains("HOOK_EVENT='preToolUse'"));
+    assert!(wrapper.contains("export CLAUDE_PLUGIN_ROOT=\"/plugin\""));
+    assert!(!wrapper.contains("@@PLUGIN_ROOT@@"));
+}
+
+#[test]
+fn test_build_wrapper_rejects_unsupported_event() {
+    let err = build_wrapper(
+        TargetKind::Copilot,
+        "PreCompact",
+        Path::new("/plugin/scripts/pre.sh"),
+        Path::new("/plugin"),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("PreCompact"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_wrapped_hook_converts_block_to_copilot_deny() {
+    let temp = TempDir::new().unwrap();
+    let script = write_script(
+        temp.path(),
+        "hook.sh",
+        "cat >/dev/null\necho blocked >&2\nexit 2\n",
+    );
+    let wrapper = build_wrapper(TargetKind::Copilot, "PreToolUse", &script, temp.path()).unwrap();
+    let payload = sample_payload("PreToolUse", temp.path()).unwrap();
+    let copilot = to_copilot_payload("PreToolUse", &payload).unwrap();
+
+    let run = run_wrapped_hook(&wrapper, &copilot.to_string(), temp.path()).unwrap();
+
+    // Copilot ラッパーは exit 2 を permissionDecision=deny の JSON に変換して exit 0 する
+    assert_eq!(run.exit_code, Some(0));
+    assert!(run.stdout.contains("deny"), "stdout: {}", run.stdout);
+}
@@ -0,0 +1,244 @@
+//! Built-in sample hook payloads and scaffold templates.
+//!
+//! `plm init --components hooks` uses the templates to generate per-event
+//! sample scripts, and `plm hooks test` feeds the sample payloads to a hook
+//! script on stdin. Payloads follow the Claude Code hook input schema.
+
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+
+use crate::hooks::converter::{EventMap, ToolMap};
+use crate::hooks::event::claude_code::HookEvent;
+use crate::hooks::event::copilot::CopilotEventMap;
+use crate::hooks::tool::copilot::CopilotToolMap;
+
+/// Events that have a built-in sample payload (and a scaffold template).
+pub(crate) const SAMPLE_EVENTS: &[&str] = &[
+    "SessionStart",
+    "SessionEnd",
+    "PreToolUse",
+    "PostToolUse",
+    "PostToolUseFailure",
+    "UserPromptSubmit",
+    "Stop",
+    "SubagentStart",
+    "SubagentStop",
+    "PreCompact",
+];
+
+/// Session id embedded in every sample payload.
+pub(crate) const SAMPLE_SESSION_ID: &str = "plm-sample-session";
+
+/// Directory (relative to the plugin root) that holds generated sample scripts.
+///
+/// Kept outside `hooks/` because every file there is scanned as a hook config.
+pub(crate) const SAMPLE_SCRIPTS_DIR: &str = "scripts";
+
+/// Fixed Copilot payload timestamp so generated payloads stay deterministic.
+const SAMPLE_TIMESTAMP_MS: i64 = 1_700_000_000_000;
+
+/// Resolve a user-supplied event name to its canonical Claude Code spelling.
+///
+/// Matching is case-insensitive (`pretooluse` → `PreToolUse`).
+/// Returns `None` for events without a built-in sample.
+///
+/// # Arguments
+///
+/// * `name` - Event name given on the command line.
+pub(crate) fn resolve_event(name: &str) -> Option<&'static str> {
+    SAMPLE_EVENTS
+        .iter()
+        .copied()
+        .find(|e| e.eq_ignore_ascii_case(name.trim()))
+}
+
+/// Whether the event carries `tool_name` / `tool_input` in its payload.
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+pub(crate) fn is_tool_event(event: &str) -> bool {
+    matches!(
+        HookEvent::from_str(event),
+        HookEvent::PreToolUse | HookEvent::PostToolUse | HookEvent::PostToolUseFailure
+    )
+}
+
+/// Build the sample Claude Code payload for an event.
+///
+/// Returns `None` for events without a built-in sample.
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+/// * `cwd` - Working directory reported in the payload.
+pub(crate) fn sample_payload(event: &str, cwd: &Path) -> Option<Value> {
+    let tool_input = json!({
+        "command": "echo hello",
+        "description": "Print hello",
+    });
+    let specific = match HookEvent::from_str(event) {
+        HookEvent::SessionStart => json!({ "source": "startup" }),
+        HookEvent::SessionEnd => json!({ "reason": "exit" }),
+        HookEvent::PreToolUse => json!({
+            "tool_name": "Bash",
+            "tool_input": tool_input,
+        }),
+        HookEvent::PostToolUse => json!({
+            "tool_name": "Bash",
+            "tool_input": tool_input,
+            "tool_response": {
+                "stdout": "hello\n",
+                "stderr": "",
+                "interrupted": false,
+            },
+        }),
+        HookEvent::PostToolUseFailure => json!({
+            "tool_name": "Bash",
+            "tool_input": tool_input,
+            "error": "Command exited with non-zero status code 1",
+        }),
+        HookEvent::UserPromptSubmit => json!({
+            "prompt": "Write a function that reverses a string",
+        }),
+        HookEvent::Stop | HookEvent::SubagentStop => json!({ "stop_hook_active": false }),
+        HookEvent::SubagentStart => json!({
+            "agent_id": "plm-sample-agent",
+            "agent_type": "general-purpose",
+        }),
+        HookEvent::PreCompact => json!({
+            "trigger": "manual",
+            "custom_instructions": "",
+        }),
+        HookEvent::Other(_) => return None,
+    };
+
+    let mut payload = Map::new();
+    payload.insert("session_id".to_string(), json!(SAMPLE_SESSION_ID));
+    payload.insert(
+        "transcript_path".to_string(),
+        json!(format!("/tmp/{}.jsonl", SAMPLE_SESSION_ID)),
+    );
+    payload.insert("cwd".to_string(), json!(cwd.display().to_string()));
+    payload.insert("hook_event_name".to_string(), json!(event));
+    if let Value::Object(fields) = specific {
+        payload.extend(fields);
+    }
+    Some(Value::Object(payload))
+}
+
+/// Reshape a Claude Code payload into the Copilot CLI hook input.
+///
+/// This is the inverse of the stdin bridge embedded in Copilot wrapper
+/// scripts, so feeding the result to a wrapper reproduces `payload`.
+/// Returns `None` when the event has no Copilot counterpart.
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+/// * `payload` - Claude Code hook input.
+pub(crate) fn to_copilot_payload(event: &str, payload: &Value) -> Option<Value> {
+    CopilotEventMap.map_event(event)?;
+    let mut out = payload.as_object().cloned().unwrap_or_default();
+
+    out.remove("hook_event_name");
+    out.remove("transcript_path");
+    if let Some(session_id) = out.remove("session_id") {
+        out.insert("sessionId".to_string(), session_id);
+    }
+    out.insert("timestamp".to_string(), json!(SAMPLE_TIMESTAMP_MS));
+
+    if let Some(tool_name) = out.remove("tool_name") {
+        let mapped = tool_name
+            .as_str()
+            .map(|name| CopilotToolMap.map_tool(name))
+            .map_or(tool_name, Value::from);
+        out.insert("toolName".to_string(), mapped);
+    }
+    if let Some(tool_input) = out.remove("tool_input") {
+        out.insert("toolArgs".to_string(), json!(tool_input.to_string()));
+    }
+    if let Some(tool_response) = out.remove("tool_response") {
+        out.insert("toolResult".to_string(), tool_response);
+    }
+    if out.get("source").and_then(Value::as_str) == Some("startup") {
+        out.insert("source".to_string(), json!("new"));
+    }
+
+    Some(Value::Object(out))
+}
+
+/// File name of the sample script generated for an event (`pre-tool-use.sh`).
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+pub(crate) fn sample_script_name(event: &str) -> String {
+    let mut name = String::new();
+    for (i, ch) in event.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i > 0 {
+                name.push('-');
+            }
+            name.push(ch.to_ascii_lowercase());
+        } else {
+            name.push(ch);
+        }
+    }
+    format!("{}.sh", name)
+}
+
+/// Content of the sample script generated for an event.
+///
+/// The script reads the hook input from stdin and echoes it to stderr so
+/// that stdout (which Claude Code parses for some events) stays empty.
+///
+/// # Arguments
+///
+/// * `event` - Canonical Claude Code event name.
+pub(crate) fn sample_script(event: &str) -> String {
+    format!(
+        r#"#!/bin/bash
+# Sample {event} hook generated by `plm init`.
+#
+# Claude Code passes the hook input as JSON on stdin.
+# Try it locally with:
+#   plm hooks test ./{dir}/{file} --event {event}
+set -euo pipefail
+
+INPUT=$(cat)
+echo "[{event}] $INPUT" >&2
+
+# exit 0: continue / exit 2: block (stderr is fed back to Claude)
+exit 0
+"#,
+        event = event,
+        dir = SAMPLE_SCRIPTS_DIR,
+        file = sample_script_name(event),
+    )
+}
+
+/// Claude Code hooks config (`hooks/hooks.json`) wiring every sample script.
+pub(crate) fn sample_hooks_config() -> Value {
+    let hooks: Map<String, Value> = SAMPLE_EVENTS
+        .iter()
+        .map(|event| {
+            let command = format!(
+                "${{CLAUDE_PLUGIN_ROOT}}/{}/{}",
+                SAMPLE_SCRIPTS_DIR,
+                sample_script_name(event)
+            );
+            let mut group = Map::new();
+            if is_tool_event(event) {
+                group.insert("matcher".to_string(), json!("*"));
+            }
+            group.insert(
+                "hooks".to_string(),
+                json!([{ "type": "command", "command": command }]),
+            );
+            (event.to_string(), json!([Value::Object(group)]))
+        })
+        .collect();
+    json!({ "hooks": hooks })
+}
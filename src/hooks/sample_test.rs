@@ -0,0 +1,94 @@
+//! Unit tests for built-in sample payloads and scaffold templates.
+
+use std::path::Path;
+
+use serde_json::json;
+
+use super::sample::{
+    resolve_event, sample_hooks_config, sample_payload, sample_script, sample_script_name,
+    to_copilot_payload, SAMPLE_EVENTS, SAMPLE_SESSION_ID,
+};
+
+#[test]
+fn test_resolve_event_is_case_insensitive() {
+    assert_eq!(resolve_event("PreToolUse"), Some("PreToolUse"));
+    assert_eq!(resolve_event("pretooluse"), Some("PreToolUse"));
+    assert_eq!(resolve_event("Notification"), None);
+}
+
+#[test]
+fn test_sample_payload_exists_for_every_sample_event() {
+    for event in SAMPLE_EVENTS {
+        let payload = sample_payload(event, Path::new("/work")).unwrap();
+        assert_eq!(payload["hook_event_name"], json!(event));
+        assert_eq!(payload["session_id"], json!(SAMPLE_SESSION_ID));
+        assert_eq!(payload["cwd"], json!("/work"));
+    }
+}
+
+#[test]
+fn test_sample_payload_pre_tool_use_has_tool_fields() {
+    let payload = sample_payload("PreToolUse", Path::new("/work")).unwrap();
+    assert_eq!(payload["tool_name"], json!("Bash"));
+    assert_eq!(payload["tool_input"]["command"], json!("echo hello"));
+}
+
+#[test]
+fn test_sample_payload_unknown_event_is_none() {
+    assert!(sample_payload("Notification", Path::new("/work")).is_none());
+}
+
+#[test]
+fn test_to_copilot_payload_reshapes_tool_fields() {
+    let payload = sample_payload("PostToolUse", Path::new("/work")).unwrap();
+    let copilot = to_copilot_payload("PostToolUse", &payload).unwrap();
+
+    assert_eq!(copilot["toolName"], json!("bash"));
+    let args: serde_json::Value =
+        serde_json::from_str(copilot["toolArgs"].as_str().unwrap()).unwrap();
+    assert_eq!(args["command"], json!("echo hello"));
+    assert_eq!(copilot["toolResult"]["stdout"], json!("hello\n"));
+    assert_eq!(copilot["sessionId"], json!(SAMPLE_SESSION_ID));
+    assert!(copilot.get("tool_name").is_none());
+    assert!(copilot.get("hook_event_name").is_none());
+}
+
+#[test]
+fn test_to_copilot_payload_maps_session_start_source() {
+    let payload = sample_payload("SessionStart", Path::new("/work")).unwrap();
+    let copilot = to_copilot_payload("SessionStart", &payload).unwrap();
+    assert_eq!(copilot["source"], json!("new"));
+}
+
+#[test]
+fn test_to_copilot_payload_unsupported_event_is_none() {
+    let payload = sample_payload("PreCompact", Path::new("/work")).unwrap();
+    assert!(to_copilot_payload("PreCompact", &payload).is_none());
+}
+
+#[test]
+fn test_sample_script_name_is_kebab_case() {
+    assert_eq!(sample_script_name("PreToolUse"), "pre-tool-use.sh");
+    assert_eq!(sample_script_name("Stop"), "stop.sh");
+}
+
+#[test]
+fn test_sample_script_reads_stdin_and_mentions_test_command() {
+    let script = sample_script("PreToolUse");
+    assert!(script.starts_with("#!/bin/bash\n"));
+    assert!(script.contains("INPUT=$(cat)"));
+    assert!(script.contains("plm hooks test ./scripts/pre-tool-use.sh --event PreToolUse"));
+}
+
+#[test]
+fn test_sample_hooks_config_wires_every_event() {
+    let config = sample_hooks_config();
+    let hooks = config["hooks"].as_object().unwrap();
+    assert_eq!(hooks.len(), SAMPLE_EVENTS.len());
+    assert_eq!(hooks["PreToolUse"][0]["matcher"], json!("*"));
+    assert!(hooks["Stop"][0].get("matcher").is_none());
+    assert_eq!(
+        hooks["Stop"][0]["hooks"][0]["command"],
+        json!("${CLAUDE_PLUGIN_ROOT}/scripts/stop.sh")
+    );
+}
@@ -3,8 +3,10 @@
 //! GitHub, GitLab, Bitbucket 等のホスティングサービス用クライアント。
 
 pub mod github;
+pub mod offline;
 
 pub use github::GitHubClient;
+pub use offline::OfflineClient;
 
 use crate::config::{AuthProvider, HttpConfig};
 use crate::error::Result;
@@ -81,6 +83,13 @@ pub trait HostClient: Send + Sync {
     /// * `repo` - Target repository.
     /// * `path` - File path inside the repository.
     fn fetch_file<'a>(&'a self, repo: &'a Repo, path: &'a str) -> BoxFuture<'a, String>;
+
+    /// タグ名の一覧を取得
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - Target repository.
+    fn list_tags<'a>(&'a self, repo: &'a Repo) -> BoxFuture<'a, Vec<String>>;
 }
 
 /// ホストクライアントファクトリー
@@ -107,12 +116,25 @@ impl HostClientFactory {
         Self::new(HttpConfig::default(), AuthProvider::new())
     }
 
+    /// 環境変数と設定ファイル（CA バンドル等）を反映したファクトリーを作成
+    ///
+    /// CA バンドルが読めない場合はエラーを返す。
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::new(HttpConfig::from_env()?, AuthProvider::new()))
+    }
+
     /// ホスト種別に応じたクライアントを生成
     ///
+    /// ネットワーク操作の唯一の入口。オフラインモードでは通信しない
+    /// [`OfflineClient`] を返す。
+    ///
     /// # Arguments
     ///
     /// * `host` - Host kind that selects the concrete `HostClient` implementation.
     pub fn create(&self, host: HostKind) -> Box<dyn HostClient> {
+        if self.config.offline {
+            return Box::new(OfflineClient);
+        }
         match host {
             HostKind::GitHub => Box::new(GitHubClient::new(&self.config, &self.auth)),
             HostKind::GitLab => {
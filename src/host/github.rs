@@ -4,19 +4,33 @@ use crate::config::{AuthProvider, HttpConfig};
 use crate::env::EnvVar;
 use crate::error::{PlmError, Result};
 use crate::host::HostClient;
-use crate::http;
+use crate::http::{
+    with_retry, HttpResponse, HttpTransport, ReqwestTransport, ResponseCache, RetryPolicy, Throttle,
+};
 use crate::repo::Repo;
-use reqwest::Client;
+use chrono::{Local, TimeZone};
 use std::future::Future;
 use std::pin::Pin;
 use std::process::Command;
+use std::time::Duration;
 
 const API_BASE: &str = "https://api.github.com";
 
 /// GitHub クライアント
+///
+/// 通信エラー・タイムアウト・5xx は [`RetryPolicy`] に従って再試行する。
+/// レート制限（403 / 429 かつ `X-RateLimit-Remaining: 0`）は再試行せず
+/// [`PlmError::RateLimited`] を返す。
 pub struct GitHubClient {
-    http: Client,
+    transport: Box<dyn HttpTransport>,
     auth: AuthProvider,
+    retry: RetryPolicy,
+    /// GET レスポンスの短期キャッシュ（無効化時は `None`）
+    cache: Option<ResponseCache>,
+    /// リクエスト全体のタイムアウト（エラーメッセージ用）
+    timeout: Option<Duration>,
+    /// 接続タイムアウト（エラーメッセージ用）
+    connect_timeout: Option<Duration>,
 }
 
 impl GitHubClient {
@@ -28,8 +42,65 @@ impl GitHubClient {
     /// * `auth` - Authentication provider supplying the GitHub token.
     pub fn new(config: &HttpConfig, auth: &AuthProvider) -> Self {
         Self {
-            http: config.build_client(),
+            transport: Box::new(ReqwestTransport::new(
+                config.build_client(),
+                Throttle::shared(config.limits),
+            )),
+            auth: auth.clone(),
+            retry: RetryPolicy::new(config.max_retries),
+            cache: config.response_cache.clone().map(ResponseCache::new),
+            timeout: config.timeout,
+            connect_timeout: config.connect_timeout,
+        }
+    }
+
+    /// トランスポートとリトライ方針を差し替えたクライアントを作成（テスト用）
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - Transport that serves the requests.
+    /// * `auth` - Authentication provider supplying the GitHub token.
+    /// * `retry` - Retry count and backoff.
+    #[cfg(test)]
+    fn with_transport(
+        transport: Box<dyn HttpTransport>,
+        auth: &AuthProvider,
+        retry: RetryPolicy,
+    ) -> Self {
+        Self {
+            transport,
             auth: auth.clone(),
+            retry,
+            cache: None,
+            timeout: None,
+            connect_timeout: None,
+        }
+    }
+
+    /// タイムアウトした通信エラーを、取得対象を示すエラーに置き換える
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - Error returned by a request.
+    /// * `repo` - Repository being fetched.
+    fn describe_timeout(&self, err: PlmError, repo: &Repo) -> PlmError {
+        let PlmError::Network(e) = &err else {
+            return err;
+        };
+        if !e.is_timeout() {
+            return err;
+        }
+        let limit = if e.is_connect() {
+            self.connect_timeout
+        } else {
+            self.timeout
+        };
+        match limit {
+            Some(limit) => PlmError::Timeout {
+                secs: limit.as_secs(),
+                resource: repo.full_name(),
+            },
+            None => err,
         }
     }
 
@@ -52,6 +123,10 @@ impl GitHubClient {
     }
 
     /// gh CLIからトークンを取得
+    ///
+    /// `gh` は子プロセスとして起動するため、`HTTPS_PROXY` / `NO_PROXY` / `SSL_CERT_FILE` 等の
+    /// 環境変数はそのまま引き継がれる。`PLM_CA_BUNDLE` や設定ファイルの CA 指定は
+    /// reqwest 経路専用で、外部コマンドには反映されない。
     fn get_token_from_cli(&self) -> Option<String> {
         Command::new("gh")
             .args(["auth", "token"])
@@ -68,6 +143,72 @@ impl GitHubClient {
             .map(|t| ("Authorization", format!("Bearer {}", t)))
     }
 
+    /// GET リクエストを送り、成功時の本文を返す
+    ///
+    /// 同じ URL + ヘッダのレスポンスは短期キャッシュから返す。失敗レスポンスは保存しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Request URL.
+    /// * `accept` - Optional `Accept` header value.
+    async fn get_text(&self, url: &str, accept: Option<&str>) -> Result<String> {
+        self.fetch_text(url, accept, true).await
+    }
+
+    /// GET リクエストを送り、成功時の本文を返す
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Request URL.
+    /// * `accept` - Optional `Accept` header value.
+    /// * `use_cache` - Read from and write to the response cache. Pass `false` for responses
+    ///   that must reflect the latest state (e.g. the commit a branch points to).
+    async fn fetch_text(&self, url: &str, accept: Option<&str>, use_cache: bool) -> Result<String> {
+        let auth = self.auth_header();
+        let mut headers: Vec<(&str, &str)> = Vec::new();
+        if let Some(accept) = accept {
+            headers.push(("Accept", accept));
+        }
+        if let Some((name, value)) = &auth {
+            headers.push((*name, value.as_str()));
+        }
+
+        let cache = self.cache.as_ref().filter(|_| use_cache);
+        let key = ResponseCache::key(url, &headers);
+        if let Some(body) = cache.and_then(|c| c.get(&key, url)) {
+            return Ok(body);
+        }
+
+        let body = self.get_bytes(url, &headers, false).await?;
+        let body = String::from_utf8_lossy(&body).into_owned();
+        if let Some(cache) = cache {
+            cache.put(&key, url, &body);
+        }
+        Ok(body)
+    }
+
+    /// GET リクエストをリトライ付きで送り、成功時の本文を返す
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Request URL.
+    /// * `headers` - Additional request headers.
+    /// * `progress` - Show a download progress bar while reading the body.
+    async fn get_bytes(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        progress: bool,
+    ) -> Result<Vec<u8>> {
+        with_retry(
+            || async move {
+                let response = self.transport.get(url, headers, progress).await?;
+                check_response(url, response)
+            },
+            &self.retry,
+        )
+        .await
+    }
     /// リポジトリAPI URL
     ///
     /// # Arguments
@@ -109,6 +250,23 @@ impl GitHubClient {
         )
     }
 
+    /// タグ一覧URL
+    ///
+    /// 1 ページ（最大 100 件）のみ取得する。チャンネルの最新リリースは新しいタグに
+    /// 含まれるため、古いタグを取りこぼしても選択結果には影響しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - Target repository.
+    fn tags_url(&self, repo: &Repo) -> String {
+        format!(
+            "{}/repos/{}/{}/tags?per_page=100",
+            API_BASE,
+            repo.owner(),
+            repo.name()
+        )
+    }
+
     /// コンテンツURL
     ///
     /// # Arguments
@@ -135,26 +293,12 @@ impl HostClient for GitHubClient {
     ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
         Box::pin(async move {
             let url = self.repo_api_url(repo);
+            let body = self
+                .get_text(&url, None)
+                .await
+                .map_err(|e| self.describe_timeout(e, repo))?;
 
-            let mut req = self.http.get(&url).header("User-Agent", "plm-cli");
-
-            if let Some((name, value)) = self.auth_header() {
-                req = req.header(name, value);
-            }
-
-            let response = req.send().await?;
-            let status = response.status().as_u16();
-
-            if !response.status().is_success() {
-                let message = response.text().await.unwrap_or_default();
-                return Err(PlmError::RepoApi {
-                    url: url.clone(),
-                    status,
-                    message,
-                });
-            }
-
-            let json: serde_json::Value = response.json().await?;
+            let json: serde_json::Value = serde_json::from_str(&body)?;
             let default_branch = json["default_branch"]
                 .as_str()
                 .unwrap_or("main")
@@ -170,31 +314,14 @@ impl HostClient for GitHubClient {
         git_ref: &'a str,
     ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
         Box::pin(async move {
+            // ブランチの指す先は変わるため、更新確認が古い SHA を見ないようキャッシュしない
             let url = self.commit_url(repo, git_ref);
+            let sha = self
+                .fetch_text(&url, Some("application/vnd.github.sha"), false)
+                .await
+                .map_err(|e| self.describe_timeout(e, repo))?;
 
-            let mut req = self
-                .http
-                .get(&url)
-                .header("User-Agent", "plm-cli")
-                .header("Accept", "application/vnd.github.sha");
-
-            if let Some((name, value)) = self.auth_header() {
-                req = req.header(name, value);
-            }
-
-            let response = req.send().await?;
-            let status = response.status().as_u16();
-
-            if !response.status().is_success() {
-                let message = response.text().await.unwrap_or_default();
-                return Err(PlmError::RepoApi {
-                    url: url.clone(),
-                    status,
-                    message,
-                });
-            }
-
-            Ok(response.text().await?.trim().to_string())
+            Ok(sha.trim().to_string())
         })
     }
 
@@ -210,7 +337,14 @@ impl HostClient for GitHubClient {
             };
 
             let url = self.zipball_url(repo, &git_ref);
-            http::download_with_progress(&self.http, &url, self.auth_header()).await
+            let auth = self.auth_header();
+            let headers: Vec<(&str, &str)> = auth
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect();
+            self.get_bytes(&url, &headers, true)
+                .await
+                .map_err(|e| self.describe_timeout(e, repo))
         })
     }
 
@@ -240,34 +374,80 @@ impl HostClient for GitHubClient {
         Box::pin(async move {
             let git_ref = repo.ref_or_default();
             let url = self.contents_url(repo, path, git_ref);
+            self.get_text(&url, Some("application/vnd.github.raw"))
+                .await
+                .map_err(|e| self.describe_timeout(e, repo))
+        })
+    }
 
-            let mut req = self
-                .http
-                .get(&url)
-                .header("User-Agent", "plm-cli")
-                .header("Accept", "application/vnd.github.raw");
-
-            if let Some((name, value)) = self.auth_header() {
-                req = req.header(name, value);
-            }
-
-            let response = req.send().await?;
-            let status = response.status().as_u16();
-
-            if !response.status().is_success() {
-                let message = response.text().await.unwrap_or_default();
-                return Err(PlmError::RepoApi {
-                    url: url.clone(),
-                    status,
-                    message,
-                });
-            }
-
-            Ok(response.text().await?)
+    fn list_tags<'a>(
+        &'a self,
+        repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>> {
+        Box::pin(async move {
+            let url = self.tags_url(repo);
+            let body = self
+                .get_text(&url, None)
+                .await
+                .map_err(|e| self.describe_timeout(e, repo))?;
+
+            let json: serde_json::Value = serde_json::from_str(&body)?;
+            let tags = json
+                .as_array()
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(|t| t["name"].as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(tags)
         })
     }
 }
 
+/// レスポンスのステータスを検査し、成功時の本文を返す
+///
+/// # Arguments
+///
+/// * `url` - Request URL (used in error messages).
+/// * `response` - Response returned by the transport.
+fn check_response(url: &str, response: HttpResponse) -> Result<Vec<u8>> {
+    if response.is_success() {
+        return Ok(response.body);
+    }
+    if let Some(err) = rate_limit_error(&response) {
+        return Err(err);
+    }
+    Err(PlmError::RepoApi {
+        url: url.to_string(),
+        status: response.status,
+        message: String::from_utf8_lossy(&response.body).into_owned(),
+    })
+}
+
+/// レート制限に達したレスポンスなら、リセット時刻を示すエラーを返す
+///
+/// 403 / 429 で `X-RateLimit-Remaining: 0` のときだけ対象にする（権限不足の 403 と区別する）。
+/// `X-RateLimit-Reset` を読めない場合は `None`（通常の API エラーとして扱う）。
+///
+/// # Arguments
+///
+/// * `response` - Failed response returned by the transport.
+fn rate_limit_error(response: &HttpResponse) -> Option<PlmError> {
+    if response.status != 403 && response.status != 429 {
+        return None;
+    }
+    if response.header("x-ratelimit-remaining")?.trim() != "0" {
+        return None;
+    }
+    let reset: i64 = response.header("x-ratelimit-reset")?.trim().parse().ok()?;
+    let resets_at = Local.timestamp_opt(reset, 0).single()?;
+    Some(PlmError::RateLimited {
+        resets_at: resets_at.format("%H:%M").to_string(),
+    })
+}
+
 /// GitHub用のリポジトリパスパーサ
 ///
 /// 対応フォーマット:
@@ -1,4 +1,161 @@
 use super::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+const URL: &str = "https://api.github.com/repos/owner/repo";
+
+/// 用意した応答を順に返すトランスポート
+struct ScriptedTransport {
+    responses: Mutex<VecDeque<Result<HttpResponse>>>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl HttpTransport for ScriptedTransport {
+    fn get<'a>(
+        &'a self,
+        _url: &'a str,
+        _headers: &'a [(&'a str, &'a str)],
+        _progress: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected request");
+        Box::pin(async move { response })
+    }
+}
+
+fn response(status: u16, headers: &[(&str, &str)], body: &str) -> Result<HttpResponse> {
+    Ok(HttpResponse {
+        status,
+        headers: headers
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        body: body.as_bytes().to_vec(),
+    })
+}
+
+/// 応答列を返すクライアントと、リクエスト回数のカウンタを作る
+fn scripted_client(responses: Vec<Result<HttpResponse>>) -> (GitHubClient, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let transport = ScriptedTransport {
+        responses: Mutex::new(responses.into()),
+        calls: Arc::clone(&calls),
+    };
+    let retry = RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::ZERO,
+    };
+    let auth = AuthProvider::new().with_github_token("token");
+    let client = GitHubClient::with_transport(Box::new(transport), &auth, retry);
+    (client, calls)
+}
+
+#[tokio::test]
+async fn test_get_text_retries_server_errors_until_success() {
+    let (client, calls) = scripted_client(vec![
+        response(502, &[], "bad gateway"),
+        response(503, &[], "unavailable"),
+        response(200, &[], "ok"),
+    ]);
+
+    assert_eq!(client.get_text(URL, None).await.unwrap(), "ok");
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_get_text_retries_network_errors() {
+    // トランスポートの通信エラー（タイムアウト）もリトライ対象
+    let (client, calls) = scripted_client(vec![
+        Err(PlmError::Timeout {
+            secs: 10,
+            resource: URL.to_string(),
+        }),
+        response(200, &[], "ok"),
+    ]);
+
+    assert_eq!(client.get_text(URL, None).await.unwrap(), "ok");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_get_text_gives_up_after_max_retries() {
+    let (client, calls) = scripted_client((0..4).map(|_| response(500, &[], "boom")).collect());
+
+    let err = client.get_text(URL, None).await.unwrap_err();
+    assert!(matches!(err, PlmError::RepoApi { status: 500, .. }));
+    // 初回 + 3 回リトライ
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_get_text_fails_immediately_on_client_errors() {
+    for status in [401, 404] {
+        let (client, calls) = scripted_client(vec![response(status, &[], "nope")]);
+
+        let err = client.get_text(URL, None).await.unwrap_err();
+        assert!(matches!(err, PlmError::RepoApi { status: s, .. } if s == status));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[tokio::test]
+async fn test_get_text_reports_rate_limit_reset_time() {
+    let reset = 1_700_000_000;
+    let (client, calls) = scripted_client(vec![response(
+        403,
+        &[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &reset.to_string()),
+        ],
+        "API rate limit exceeded",
+    )]);
+
+    let err = client.get_text(URL, None).await.unwrap_err();
+    let expected = Local.timestamp_opt(reset, 0).unwrap().format("%H:%M");
+    assert_eq!(
+        err.to_string(),
+        format!("rate limit exceeded, resets at {}", expected)
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_get_commit_sha_bypasses_response_cache() {
+    let (mut client, calls) = scripted_client(vec![
+        response(200, &[], "aaa\n"),
+        response(200, &[], "bbb\n"),
+    ]);
+    client.cache = Some(ResponseCache::new(crate::config::ResponseCacheConfig {
+        dir: None,
+        ttl: Duration::from_secs(300),
+        max_entries: 10,
+        verbose: false,
+    }));
+    let repo = Repo::new(crate::host::HostKind::GitHub, "owner", "repo", None);
+
+    assert_eq!(client.get_commit_sha(&repo, "main").await.unwrap(), "aaa");
+    assert_eq!(client.get_commit_sha(&repo, "main").await.unwrap(), "bbb");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_forbidden_with_remaining_quota_is_not_rate_limit() {
+    let response = HttpResponse {
+        status: 403,
+        headers: vec![
+            ("X-RateLimit-Remaining".to_string(), "42".to_string()),
+            ("X-RateLimit-Reset".to_string(), "1700000000".to_string()),
+        ],
+        body: Vec::new(),
+    };
+    assert!(rate_limit_error(&response).is_none());
+}
 
 #[test]
 fn test_parse_repo_path_simple() {
@@ -0,0 +1,77 @@
+//! オフラインモード用クライアント
+//!
+//! `--offline` / `PLM_OFFLINE` が有効なとき [`HostClientFactory::create`](super::HostClientFactory::create)
+//! が返す。どのメソッドも通信せずに [`PlmError::Offline`] を返す。
+
+use crate::error::{PlmError, Result};
+use crate::host::HostClient;
+use crate::repo::Repo;
+use std::future::Future;
+use std::pin::Pin;
+
+/// 通信せずに常にオフラインエラーを返すクライアント
+pub struct OfflineClient;
+
+/// 要求された操作をオフラインエラーとして返す
+///
+/// # Arguments
+///
+/// * `action` - What was requested, e.g. `"downloading owner/repo"`.
+fn refuse<'a, T: Send + 'a>(
+    action: String,
+) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>> {
+    Box::pin(async move { Err(PlmError::Offline(action)) })
+}
+
+impl HostClient for OfflineClient {
+    fn get_default_branch<'a>(
+        &'a self,
+        repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
+        refuse(format!(
+            "resolving the default branch of {}",
+            repo.full_name()
+        ))
+    }
+
+    fn get_commit_sha<'a>(
+        &'a self,
+        repo: &'a Repo,
+        git_ref: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
+        refuse(format!("resolving {}@{}", repo.full_name(), git_ref))
+    }
+
+    fn download_archive<'a>(
+        &'a self,
+        repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>> {
+        refuse(format!("downloading {}", repo.full_name()))
+    }
+
+    fn download_archive_with_sha<'a>(
+        &'a self,
+        repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<(Vec<u8>, String, String)>> + Send + 'a>> {
+        refuse(format!("downloading {}", repo.full_name()))
+    }
+
+    fn fetch_file<'a>(
+        &'a self,
+        repo: &'a Repo,
+        path: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
+        refuse(format!("fetching {} from {}", path, repo.full_name()))
+    }
+
+    fn list_tags<'a>(
+        &'a self,
+        repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>> {
+        refuse(format!("listing tags of {}", repo.full_name()))
+    }
+}
+
+#[cfg(test)]
+#[path = "offline_test.rs"]
+mod tests;
@@ -0,0 +1,28 @@
+use super::*;
+use crate::host::HostKind;
+
+#[tokio::test]
+async fn every_operation_fails_with_offline_error() {
+    let client = OfflineClient;
+    let repo = Repo::new(HostKind::GitHub, "owner", "repo", None);
+
+    let err = client.download_archive(&repo).await.unwrap_err();
+    assert!(matches!(err, PlmError::Offline(_)));
+    assert_eq!(
+        err.to_string(),
+        "downloading owner/repo requires network (running in offline mode)"
+    );
+
+    assert!(matches!(
+        client.fetch_file(&repo, "README.md").await,
+        Err(PlmError::Offline(_))
+    ));
+    assert!(matches!(
+        client.list_tags(&repo).await,
+        Err(PlmError::Offline(_))
+    ));
+    assert!(matches!(
+        client.get_default_branch(&repo).await,
+        Err(PlmError::Offline(_))
+    ));
+}
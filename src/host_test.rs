@@ -34,3 +34,17 @@ fn test_factory_bitbucket_panics() {
     let factory = HostClientFactory::with_defaults();
     let _client = factory.create(HostKind::Bitbucket);
 }
+
+#[tokio::test]
+async fn test_factory_offline_never_touches_network() {
+    let config = HttpConfig {
+        offline: true,
+        ..HttpConfig::default()
+    };
+    let factory = HostClientFactory::new(config, AuthProvider::new());
+    let client = factory.create(HostKind::GitHub);
+    let repo = Repo::new(HostKind::GitHub, "owner", "repo", None);
+
+    let err = client.get_commit_sha(&repo, "main").await.unwrap_err();
+    assert!(matches!(err, crate::error::PlmError::Offline(_)));
+}
@@ -1,67 +1,18 @@
 //! 共通HTTPヘルパー
 
-use crate::error::{PlmError, Result};
+mod cache;
+mod throttle;
+mod transport;
+
+pub use cache::ResponseCache;
+pub use throttle::Throttle;
+pub use transport::{HttpResponse, HttpTransport, ReqwestTransport};
+
+use crate::error::Result;
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::Client;
 use std::future::Future;
 use std::time::Duration;
 
-/// プログレスバー付きダウンロード
-///
-/// # Arguments
-///
-/// * `client` - HTTP client used to issue the download request.
-/// * `url` - URL of the resource to download.
-/// * `auth_header` - Optional `(name, value)` pair appended as an HTTP header.
-pub async fn download_with_progress(
-    client: &Client,
-    url: &str,
-    auth_header: Option<(&str, String)>,
-) -> Result<Vec<u8>> {
-    download_with_progress_impl(client, url, auth_header, "unknown").await
-}
-
-/// プログレスバー付きダウンロード実装
-///
-/// # Arguments
-///
-/// * `client` - HTTP client used to issue the download request.
-/// * `url` - URL of the resource to download.
-/// * `auth_header` - Optional `(name, value)` pair appended as an HTTP header.
-/// * `_host` - Host label for future telemetry; currently unused.
-async fn download_with_progress_impl(
-    client: &Client,
-    url: &str,
-    auth_header: Option<(&str, String)>,
-    _host: &str,
-) -> Result<Vec<u8>> {
-    let mut req = client.get(url).header("User-Agent", "plm-cli");
-
-    if let Some((name, value)) = auth_header {
-        req = req.header(name, value);
-    }
-
-    let response = req.send().await?;
-    let status = response.status().as_u16();
-
-    if !response.status().is_success() {
-        let message = response.text().await.unwrap_or_default();
-        return Err(PlmError::RepoApi {
-            url: url.to_string(),
-            status,
-            message,
-        });
-    }
-
-    let total_size = response.content_length().unwrap_or(0);
-
-    let pb = create_progress_bar(total_size);
-    let bytes = response.bytes().await?;
-    pb.finish_and_clear();
-
-    Ok(bytes.to_vec())
-}
-
 /// サイズに応じたプログレスバーを作成
 ///
 /// # Arguments
@@ -88,84 +39,65 @@ fn create_progress_bar(total_size: u64) -> ProgressBar {
     }
 }
 
-/// リトライ可能なエラーかどうかを判定
-///
-/// PlmError::is_retryable の判定に加え、レート制限エラー（429, 403 rate limit）も対象とする。
+/// リトライ方針
 ///
-/// # Arguments
-///
-/// * `e` - Error to classify for retry eligibility.
-pub fn is_retriable_error(e: &PlmError) -> bool {
-    if e.is_retryable() {
-        return true;
+/// 待ち時間は指数バックオフ（`base_delay` × 2^(attempt-1)。既定では 1s, 2s, 4s, ...）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 初回の後に再試行する最大回数
+    pub max_retries: u32,
+    /// 1 回目の再試行までの待ち時間
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// デフォルトの 1 回目の待ち時間（1 秒）
+    pub const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+
+    /// # Arguments
+    ///
+    /// * `max_retries` - Maximum additional retries after the initial attempt.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay: Self::DEFAULT_BASE_DELAY,
+        }
     }
 
-    match e {
-        PlmError::RepoApi { status: 429, .. } => true,
-        PlmError::RepoApi {
-            status: 403,
-            message,
-            ..
-        } => message.to_lowercase().contains("rate limit"),
-        _ => false,
+    /// `attempt` 回目の再試行の前に待つ時間
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Retry attempt index (1-based).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor)
     }
 }
 
 /// リトライ付きで非同期処理を実行
 ///
-/// 指数バックオフ（1s, 2s, 4s, ... = 2^(attempt-1) 秒）で最大 `max_retries` 回再試行する。
-/// 初回 + max_retries 回 = 最大 (max_retries + 1) 回試行。
+/// [`crate::error::PlmError::is_retryable`] なエラー（通信エラー・タイムアウト・5xx）だけを再試行する。
+/// 404 / 401 やレート制限は即座に返す。初回 + max_retries 回 = 最大 (max_retries + 1) 回試行。
 ///
 /// # Arguments
 ///
 /// * `f` - Closure that produces the future to execute on each attempt.
-/// * `max_retries` - Maximum additional retries after the initial attempt.
-pub async fn with_retry<F, Fut, T>(mut f: F, max_retries: u32) -> Result<T>
+/// * `policy` - Retry count and backoff.
+pub async fn with_retry<F, Fut, T>(mut f: F, policy: &RetryPolicy) -> Result<T>
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T>>,
 {
-    let mut last_error: Option<PlmError> = None;
-
-    for attempt in 0..=max_retries {
-        if attempt > 0 {
-            let delay = Duration::from_secs(1 << (attempt - 1));
-            warn_if_rate_limited(&last_error, delay.as_secs(), attempt, max_retries);
-            tokio::time::sleep(delay).await;
-        }
-
+    let mut attempt = 0;
+    loop {
         match f().await {
             Ok(result) => return Ok(result),
-            Err(e) => {
-                let can_retry = is_retriable_error(&e) && attempt < max_retries;
-                if can_retry {
-                    last_error = Some(e);
-                } else {
-                    return Err(e);
-                }
+            Err(e) if e.is_retryable() && attempt < policy.max_retries => {
+                attempt += 1;
+                tokio::time::sleep(policy.delay(attempt)).await;
             }
-        }
-    }
-
-    // ループ終了時は最後のエラーを返す（理論上到達しない）
-    Err(last_error.expect("retry loop should have returned"))
-}
-
-/// レート制限時の警告表示
-///
-/// # Arguments
-///
-/// * `last_error` - Previous error used to detect rate-limit status codes.
-/// * `delay_secs` - Number of seconds the next retry will wait.
-/// * `attempt` - Current retry attempt index (1-based).
-/// * `max` - Total number of retries permitted.
-fn warn_if_rate_limited(last_error: &Option<PlmError>, delay_secs: u64, attempt: u32, max: u32) {
-    if let Some(PlmError::RepoApi { status, .. }) = last_error {
-        if *status == 403 || *status == 429 {
-            eprintln!(
-                "Warning: Rate limited. Waiting {}s before retry ({}/{})...",
-                delay_secs, attempt, max
-            );
+            Err(e) => return Err(e),
         }
     }
 }
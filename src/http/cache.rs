@@ -0,0 +1,291 @@
+//! 短期 HTTP レスポンスキャッシュ
+//!
+//! 同じ GET リクエスト（URL + ヘッダ）を短時間に繰り返さないよう、成功レスポンスの
+//! 本文をメモリとディスク（`~/.plm/cache/http/`）に TTL 付きで保持する。
+//!
+//! - キーは URL とヘッダから計算したハッシュで、認証トークンそのものは保存しない
+//! - ディスク上のエントリが壊れている場合は削除して再取得する
+//! - エントリ数が上限を超えたら古いものから捨てる
+
+use crate::config::ResponseCacheConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// キャッシュエントリ（ディスク上の JSON と同じ形）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    /// リクエスト URL（ログ・調査用）
+    url: String,
+    /// 保存時刻（UNIX 秒）
+    stored_at: u64,
+    /// レスポンス本文
+    body: String,
+}
+
+/// GET レスポンスの短期キャッシュ
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: ResponseCacheConfig,
+    memory: Mutex<HashMap<String, Entry>>,
+}
+
+impl ResponseCache {
+    /// 新しいキャッシュを作成
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - TTL, size limit and disk location.
+    pub fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            config,
+            memory: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// リクエストのキャッシュキーを計算する
+    ///
+    /// ヘッダは名前の大文字小文字と順序に依存しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Request URL.
+    /// * `headers` - Request headers that affect the response (Accept, Authorization, ...).
+    pub fn key(url: &str, headers: &[(&str, &str)]) -> String {
+        let mut headers: Vec<(String, &str)> = headers
+            .iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), *value))
+            .collect();
+        headers.sort();
+
+        let mut material = format!("GET {}\n", url);
+        for (name, value) in headers {
+            material.push_str(&format!("{}: {}\n", name, value));
+        }
+        format!("{:016x}", fnv1a64(material.as_bytes()))
+    }
+
+    /// 有効なエントリがあれば本文を返す
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key computed by [`ResponseCache::key`].
+    /// * `url` - Request URL (for logging).
+    pub fn get(&self, key: &str, url: &str) -> Option<String> {
+        self.get_at(key, url, now_secs())
+    }
+
+    /// 成功レスポンスの本文を保存する
+    ///
+    /// ディスクへの書き込みに失敗してもメモリには残る（警告は verbose 時のみ）。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key computed by [`ResponseCache::key`].
+    /// * `url` - Request URL.
+    /// * `body` - Response body.
+    pub fn put(&self, key: &str, url: &str, body: &str) {
+        self.put_at(key, url, body, now_secs());
+    }
+
+    /// # Arguments
+    ///
+    /// * `key` - Cache key.
+    /// * `url` - Request URL (for logging).
+    /// * `now` - Current time in UNIX seconds.
+    fn get_at(&self, key: &str, url: &str, now: u64) -> Option<String> {
+        if let Some(entry) = self.lock_memory().get(key) {
+            if self.is_fresh(entry, now) {
+                self.log(&format!("hit (memory) {}", url));
+                return Some(entry.body.clone());
+            }
+        }
+
+        if let Some(entry) = self.read_disk(key, url) {
+            if self.is_fresh(&entry, now) {
+                self.log(&format!("hit (disk) {}", url));
+                let body = entry.body.clone();
+                self.insert_memory(key, entry);
+                return Some(body);
+            }
+        }
+
+        self.log(&format!("miss {}", url));
+        None
+    }
+
+    /// # Arguments
+    ///
+    /// * `key` - Cache key.
+    /// * `url` - Request URL.
+    /// * `body` - Response body.
+    /// * `now` - Current time in UNIX seconds.
+    fn put_at(&self, key: &str, url: &str, body: &str, now: u64) {
+        let entry = Entry {
+            url: url.to_string(),
+            stored_at: now,
+            body: body.to_string(),
+        };
+
+        if let Some(path) = self.entry_path(key) {
+            if let Err(e) = write_entry(&path, &entry) {
+                self.log(&format!("failed to write {}: {}", path.display(), e));
+            } else {
+                self.evict_disk();
+            }
+        }
+        self.insert_memory(key, entry);
+    }
+
+    /// # Arguments
+    ///
+    /// * `entry` - Entry to check.
+    /// * `now` - Current time in UNIX seconds.
+    fn is_fresh(&self, entry: &Entry, now: u64) -> bool {
+        now.saturating_sub(entry.stored_at) < self.config.ttl.as_secs()
+    }
+
+    fn lock_memory(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.memory.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 上限を超える場合は最も古いエントリを捨ててから追加する
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Cache key.
+    /// * `entry` - Entry to insert.
+    fn insert_memory(&self, key: &str, entry: Entry) {
+        let mut memory = self.lock_memory();
+        if !memory.contains_key(key) && memory.len() >= self.config.max_entries {
+            let oldest = memory
+                .iter()
+                .min_by_key(|(_, e)| e.stored_at)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                memory.remove(&oldest);
+            }
+        }
+        memory.insert(key.to_string(), entry);
+    }
+
+    /// # Arguments
+    ///
+    /// * `key` - Cache key.
+    fn entry_path(&self, key: &str) -> Option<PathBuf> {
+        self.config
+            .dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", key)))
+    }
+
+    /// ディスクからエントリを読む（壊れていれば削除して `None`）
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Cache key.
+    /// * `url` - Request URL (for logging).
+    fn read_disk(&self, key: &str, url: &str) -> Option<Entry> {
+        let path = self.entry_path(key)?;
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<Entry>(&content) {
+            Ok(entry) => Some(entry),
+            Err(_) => {
+                self.log(&format!("ignoring corrupt entry for {}", url));
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// ディスク上のエントリ数を上限以内に保つ（更新時刻の古いものから削除）
+    fn evict_disk(&self) {
+        let Some(dir) = &self.config.dir else {
+            return;
+        };
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return;
+        };
+        let mut files: Vec<(SystemTime, PathBuf)> = read_dir
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            .map(|p| {
+                let modified = fs::metadata(&p)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(UNIX_EPOCH);
+                (modified, p)
+            })
+            .collect();
+        if files.len() <= self.config.max_entries {
+            return;
+        }
+        files.sort();
+        let excess = files.len() - self.config.max_entries;
+        for (_, path) in files.into_iter().take(excess) {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// * `message` - Message printed when verbose logging is enabled.
+    fn log(&self, message: &str) {
+        if self.config.verbose {
+            eprintln!("[plm http-cache] {}", message);
+        }
+    }
+}
+
+/// 一時ファイルに書いてから rename する（途中で落ちても壊れたエントリを残さない）
+///
+/// 本文には非公開リポジトリの内容が含まれうるため、Unix では所有者のみ読み書きできる
+/// （0600）ファイルとして作成する。
+///
+/// # Arguments
+///
+/// * `path` - Destination path.
+/// * `entry` - Entry to write.
+fn write_entry(path: &std::path::Path, entry: &Entry) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let _ = fs::remove_file(&tmp);
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(&tmp)?.write_all(&serde_json::to_vec(entry)?)?;
+    fs::rename(&tmp, path)
+}
+
+/// 現在時刻（UNIX 秒）
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// FNV-1a (64bit)。Rust のバージョンをまたいでもキーが変わらないよう自前で計算する。
+///
+/// # Arguments
+///
+/// * `bytes` - Input bytes.
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+#[path = "cache_test.rs"]
+mod tests;
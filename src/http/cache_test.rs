@@ -0,0 +1,128 @@
+use super::*;
+use std::time::Duration;
+use tempfile::TempDir;
+
+const URL: &str = "https://api.github.com/repos/owner/repo";
+
+fn cache_in(dir: Option<&TempDir>, max_entries: usize) -> ResponseCache {
+    ResponseCache::new(ResponseCacheConfig {
+        dir: dir.map(|d| d.path().to_path_buf()),
+        ttl: Duration::from_secs(300),
+        max_entries,
+        verbose: false,
+    })
+}
+
+#[test]
+fn key_depends_on_url_and_headers() {
+    let base = ResponseCache::key(URL, &[("Accept", "application/vnd.github.sha")]);
+    assert_eq!(
+        base,
+        ResponseCache::key(URL, &[("accept", "application/vnd.github.sha")])
+    );
+    assert_ne!(base, ResponseCache::key(URL, &[]));
+    assert_ne!(
+        base,
+        ResponseCache::key(
+            URL,
+            &[
+                ("Accept", "application/vnd.github.sha"),
+                ("Authorization", "Bearer token")
+            ]
+        )
+    );
+    assert_ne!(
+        base,
+        ResponseCache::key(
+            "https://api.github.com/repos/owner/other",
+            &[("Accept", "application/vnd.github.sha")]
+        )
+    );
+}
+
+#[test]
+fn key_is_order_independent_and_hides_header_values() {
+    let a = ResponseCache::key(URL, &[("Accept", "x"), ("Authorization", "Bearer secret")]);
+    let b = ResponseCache::key(URL, &[("Authorization", "Bearer secret"), ("Accept", "x")]);
+    assert_eq!(a, b);
+    assert!(!a.contains("secret"));
+}
+
+#[test]
+fn get_returns_fresh_entry_from_memory() {
+    let cache = cache_in(None, 10);
+    let key = ResponseCache::key(URL, &[]);
+    assert_eq!(cache.get_at(&key, URL, 1_000), None);
+
+    cache.put_at(&key, URL, "{\"default_branch\":\"main\"}", 1_000);
+
+    assert_eq!(
+        cache.get_at(&key, URL, 1_299).as_deref(),
+        Some("{\"default_branch\":\"main\"}")
+    );
+    assert_eq!(cache.get_at(&key, URL, 1_300), None);
+}
+
+#[test]
+fn get_reads_entry_written_by_another_instance() {
+    let dir = TempDir::new().unwrap();
+    let key = ResponseCache::key(URL, &[]);
+    cache_in(Some(&dir), 10).put_at(&key, URL, "abc123", 1_000);
+
+    let fresh = cache_in(Some(&dir), 10);
+    assert_eq!(fresh.get_at(&key, URL, 1_010).as_deref(), Some("abc123"));
+}
+
+#[test]
+fn corrupt_disk_entry_is_ignored_and_removed() {
+    let dir = TempDir::new().unwrap();
+    let key = ResponseCache::key(URL, &[]);
+    let path = dir.path().join(format!("{}.json", key));
+    fs::write(&path, "{not json").unwrap();
+
+    let cache = cache_in(Some(&dir), 10);
+    assert_eq!(cache.get_at(&key, URL, 1_000), None);
+    assert!(!path.exists());
+}
+
+#[test]
+fn disk_entries_are_capped() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_in(Some(&dir), 2);
+    for i in 0..4 {
+        let url = format!("{}/{}", URL, i);
+        cache.put_at(&ResponseCache::key(&url, &[]), &url, "body", 1_000 + i);
+    }
+
+    let count = fs::read_dir(dir.path()).unwrap().count();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn memory_entries_are_capped_by_age() {
+    let cache = cache_in(None, 2);
+    let keys: Vec<String> = (0..3)
+        .map(|i| ResponseCache::key(&format!("{}/{}", URL, i), &[]))
+        .collect();
+    for (i, key) in keys.iter().enumerate() {
+        cache.put_at(key, URL, "body", 1_000 + i as u64);
+    }
+
+    assert_eq!(cache.get_at(&keys[0], URL, 1_010), None);
+    assert!(cache.get_at(&keys[1], URL, 1_010).is_some());
+    assert!(cache.get_at(&keys[2], URL, 1_010).is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn disk_entries_are_private_to_the_owner() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    let key = ResponseCache::key(URL, &[]);
+    cache_in(Some(&dir), 10).put_at(&key, URL, "secret", 1_000);
+
+    let path = dir.path().join(format!("{}.json", key));
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
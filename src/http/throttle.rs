@@ -0,0 +1,185 @@
+//! 通信量の制御（同時接続数・帯域・リクエスト間隔）
+//!
+//! [`NetworkLimits`] に従って [`super::ReqwestTransport`] の GET を絞る。
+//!
+//! - 同時接続数: プロセス全体で共有するセマフォで、同時に通信する GET の数を制限する
+//! - リクエスト間隔: 直前のリクエストの開始から最小間隔が空くまで待つ
+//! - 帯域: 本文をチャンクごとに読み、上限より速く読めた分だけ sleep を挟む（素朴な実装）
+//!
+//! reqwest で通信する経路（マニフェスト・tarball の取得）にだけ効く。
+//! `gh` など外部コマンドが行う通信は plm から制御できないため対象外。
+
+use crate::config::NetworkLimits;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
+
+/// 時刻の取得と待機（テストでは仮想時計に差し替える）
+pub trait Clock: Send + Sync {
+    /// 現在時刻
+    fn now(&self) -> Instant;
+
+    /// `duration` だけ待つ
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Time to wait.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+/// 実時間の時計
+struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// 通信量の制御
+pub struct Throttle {
+    limits: NetworkLimits,
+    /// 同時接続数の枠（無制限なら `None`）
+    connections: Option<Semaphore>,
+    /// 直前のリクエストの開始時刻
+    last_request: Mutex<Option<Instant>>,
+    clock: Box<dyn Clock>,
+}
+
+impl Throttle {
+    /// # Arguments
+    ///
+    /// * `limits` - Resolved network limits.
+    pub fn new(limits: NetworkLimits) -> Self {
+        Self::with_clock(limits, Box::new(SystemClock))
+    }
+
+    /// 時計を差し替えて作成する
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Resolved network limits.
+    /// * `clock` - Clock used to measure and wait.
+    pub fn with_clock(limits: NetworkLimits, clock: Box<dyn Clock>) -> Self {
+        Self {
+            limits,
+            connections: limits.jobs.map(Semaphore::new),
+            last_request: Mutex::new(None),
+            clock,
+        }
+    }
+
+    /// プロセス全体で共有する制御
+    ///
+    /// クライアントは取得のたびに作り直されるため、同時接続数とリクエスト間隔は
+    /// プロセスで 1 つの制御を共有しないと効かない。最初に渡された制限を使う
+    /// （制限は起動時の CLI フラグ・環境変数・設定ファイルで決まり、途中で変わらない）。
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Resolved network limits.
+    pub fn shared(limits: NetworkLimits) -> Arc<Self> {
+        static SHARED: OnceLock<Arc<Throttle>> = OnceLock::new();
+        SHARED.get_or_init(|| Arc::new(Self::new(limits))).clone()
+    }
+
+    /// リクエストを始めてよくなるまで待つ
+    ///
+    /// 返した枠を drop するまでは同時接続数に数える。
+    pub async fn begin(&self) -> Option<SemaphorePermit<'_>> {
+        let permit = match &self.connections {
+            // セマフォは close しないため acquire は失敗しない
+            Some(connections) => connections.acquire().await.ok(),
+            None => None,
+        };
+        if let Some(interval) = self.limits.request_interval {
+            let mut last = self.last_request.lock().await;
+            let wait = interval_delay(*last, self.clock.now(), interval);
+            if !wait.is_zero() {
+                self.clock.sleep(wait).await;
+            }
+            *last = Some(self.clock.now());
+        }
+        permit
+    }
+
+    /// 本文 1 件分の帯域制御を始める（帯域が無制限なら `None`）
+    pub fn download(&self) -> Option<RateLimiter> {
+        self.limits
+            .limit_rate
+            .map(|rate| RateLimiter::new(rate, self.clock.now()))
+    }
+
+    /// チャンクを読んだ後、上限を超える速さで読めていれば追いつくまで待つ
+    ///
+    /// # Arguments
+    ///
+    /// * `limiter` - Rate limiter of the current download.
+    /// * `chunk_len` - Size of the chunk just read.
+    pub async fn pace(&self, limiter: &mut RateLimiter, chunk_len: usize) {
+        let wait = limiter.record(chunk_len as u64, self.clock.now());
+        if !wait.is_zero() {
+            self.clock.sleep(wait).await;
+        }
+    }
+}
+
+/// 1 接続分の帯域制御
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bytes_per_sec: u64,
+    started: Instant,
+    received: u64,
+}
+
+impl RateLimiter {
+    /// # Arguments
+    ///
+    /// * `bytes_per_sec` - Maximum download rate.
+    /// * `started` - When the download started.
+    pub fn new(bytes_per_sec: u64, started: Instant) -> Self {
+        Self {
+            bytes_per_sec,
+            started,
+            received: 0,
+        }
+    }
+
+    /// 受信したバイト数を記録し、上限に収まるまでに待つべき時間を返す
+    ///
+    /// 受信済みの総量を上限で読むのにかかる時間と、実際の経過時間の差だけ待つ。
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Bytes just received.
+    /// * `now` - Current time.
+    pub fn record(&mut self, bytes: u64, now: Instant) -> Duration {
+        self.received = self.received.saturating_add(bytes);
+        let expected = Duration::from_secs_f64(self.received as f64 / self.bytes_per_sec as f64);
+        expected.saturating_sub(now.saturating_duration_since(self.started))
+    }
+}
+
+/// 直前のリクエストから最小間隔が空くまでに待つべき時間
+///
+/// # Arguments
+///
+/// * `last` - When the previous request started (`None` for the first request).
+/// * `now` - Current time.
+/// * `interval` - Minimum interval between requests.
+fn interval_delay(last: Option<Instant>, now: Instant, interval: Duration) -> Duration {
+    match last {
+        Some(last) => interval.saturating_sub(now.saturating_duration_since(last)),
+        None => Duration::ZERO,
+    }
+}
+
+#[cfg(test)]
+#[path = "throttle_test.rs"]
+mod tests;
@@ -0,0 +1,123 @@
+use super::*;
+use std::sync::Mutex as StdMutex;
+
+/// sleep した時間を記録し、その分だけ仮想時刻を進める時計
+#[derive(Clone)]
+struct MockClock {
+    state: Arc<StdMutex<(Instant, Vec<Duration>)>>,
+}
+
+impl MockClock {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(StdMutex::new((Instant::now(), Vec::new()))),
+        }
+    }
+
+    fn advance(&self, duration: Duration) {
+        self.state.lock().unwrap().0 += duration;
+    }
+
+    fn sleeps(&self) -> Vec<Duration> {
+        self.state.lock().unwrap().1.clone()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap().0
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        let mut state = self.state.lock().unwrap();
+        state.0 += duration;
+        state.1.push(duration);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+fn throttle(limits: NetworkLimits) -> (Throttle, MockClock) {
+    let clock = MockClock::new();
+    (Throttle::with_clock(limits, Box::new(clock.clone())), clock)
+}
+
+#[tokio::test]
+async fn download_is_paced_to_the_rate_limit() {
+    let (throttle, clock) = throttle(NetworkLimits {
+        limit_rate: Some(1000),
+        ..NetworkLimits::default()
+    });
+    let mut rate = throttle.download().unwrap();
+
+    // 1000 B/s で 500 B ずつ読むと、1 チャンクあたり 0.5 秒かかるはず
+    throttle.pace(&mut rate, 500).await;
+    clock.advance(Duration::from_millis(200));
+    throttle.pace(&mut rate, 500).await;
+
+    assert_eq!(
+        clock.sleeps(),
+        [Duration::from_millis(500), Duration::from_millis(300)]
+    );
+}
+
+#[tokio::test]
+async fn slow_download_is_not_delayed() {
+    let (throttle, clock) = throttle(NetworkLimits {
+        limit_rate: Some(1000),
+        ..NetworkLimits::default()
+    });
+    let mut rate = throttle.download().unwrap();
+
+    clock.advance(Duration::from_secs(2));
+    throttle.pace(&mut rate, 1000).await;
+
+    assert!(clock.sleeps().is_empty());
+}
+
+#[tokio::test]
+async fn requests_wait_for_the_minimum_interval() {
+    let (throttle, clock) = throttle(NetworkLimits {
+        request_interval: Some(Duration::from_millis(300)),
+        ..NetworkLimits::default()
+    });
+
+    drop(throttle.begin().await);
+    clock.advance(Duration::from_millis(100));
+    drop(throttle.begin().await);
+    clock.advance(Duration::from_millis(500));
+    drop(throttle.begin().await);
+
+    assert_eq!(clock.sleeps(), [Duration::from_millis(200)]);
+}
+
+#[tokio::test]
+async fn connections_are_limited_to_jobs() {
+    let (throttle, _clock) = throttle(NetworkLimits {
+        jobs: Some(2),
+        ..NetworkLimits::default()
+    });
+
+    let first = throttle.begin().await;
+    let _second = throttle.begin().await;
+    assert!(first.is_some());
+    assert_eq!(
+        throttle.connections.as_ref().unwrap().available_permits(),
+        0
+    );
+
+    drop(first);
+    assert_eq!(
+        throttle.connections.as_ref().unwrap().available_permits(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn unlimited_throttle_does_nothing() {
+    let (throttle, clock) = throttle(NetworkLimits::default());
+
+    assert!(throttle.begin().await.is_none());
+    assert!(throttle.begin().await.is_none());
+    assert!(throttle.download().is_none());
+    assert!(clock.sleeps().is_empty());
+}
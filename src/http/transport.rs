@@ -0,0 +1,132 @@
+//! HTTP リクエストの実行部
+//!
+//! ホストクライアントは 1 回分の GET を [`HttpTransport`] に任せ、ステータスの解釈と
+//! リトライは自身で行う。テストでは応答を差し替えたトランスポートを注入する。
+
+use super::{create_progress_bar, Throttle};
+use crate::error::Result;
+use reqwest::Client;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// 非同期メソッドの戻り値型エイリアス
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// 1 回分の GET レスポンス（ステータスに関わらず本文まで読み込んだもの）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// レスポンスヘッダ（名前は小文字）
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// 2xx かどうか
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// ヘッダ値を取得する（名前の大文字小文字は区別しない）
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Header name to look up.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// GET リクエストを 1 回実行するトランスポート
+pub trait HttpTransport: Send + Sync {
+    /// GET リクエストを送り、レスポンスを返す
+    ///
+    /// 接続できない・本文を読めないなどの通信エラーだけを `Err` にし、
+    /// 4xx / 5xx はそのまま `Ok` で返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Request URL.
+    /// * `headers` - Additional request headers.
+    /// * `progress` - Show a download progress bar while reading the body.
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+        headers: &'a [(&'a str, &'a str)],
+        progress: bool,
+    ) -> BoxFuture<'a, HttpResponse>;
+}
+
+/// reqwest で通信するトランスポート
+///
+/// 同時接続数・リクエスト間隔・帯域は [`Throttle`] に従って絞る。
+pub struct ReqwestTransport {
+    client: Client,
+    throttle: Arc<Throttle>,
+}
+
+impl ReqwestTransport {
+    /// # Arguments
+    ///
+    /// * `client` - HTTP client used to issue requests.
+    /// * `throttle` - Limits on connections, request interval and bandwidth.
+    pub fn new(client: Client, throttle: Arc<Throttle>) -> Self {
+        Self { client, throttle }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+        headers: &'a [(&'a str, &'a str)],
+        progress: bool,
+    ) -> BoxFuture<'a, HttpResponse> {
+        Box::pin(async move {
+            let _connection = self.throttle.begin().await;
+            let mut req = self.client.get(url).header("User-Agent", "plm-cli");
+            for (name, value) in headers {
+                req = req.header(*name, *value);
+            }
+
+            let mut response = req.send().await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    let value = value.to_str().ok()?;
+                    Some((name.as_str().to_string(), value.to_string()))
+                })
+                .collect();
+
+            let pb = (progress && response.status().is_success())
+                .then(|| create_progress_bar(response.content_length().unwrap_or(0)));
+            // 帯域制限のため本文はチャンクごとに読む
+            let mut rate = self.throttle.download();
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                body.extend_from_slice(&chunk);
+                if let Some(pb) = &pb {
+                    pb.inc(chunk.len() as u64);
+                }
+                if let Some(rate) = rate.as_mut() {
+                    self.throttle.pace(rate, chunk.len()).await;
+                }
+            }
+            if let Some(pb) = pb {
+                pb.finish_and_clear();
+            }
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
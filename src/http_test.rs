@@ -1,98 +1,41 @@
 use super::*;
 
-// =========================================================================
-// is_retriable_error tests
-// =========================================================================
-
-#[test]
-fn test_is_retriable_error_429() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 429,
-        message: "Too Many Requests".to_string(),
-    };
-    assert!(is_retriable_error(&error));
-}
-
-#[test]
-fn test_is_retriable_error_403_rate_limit() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 403,
-        message: "API rate limit exceeded".to_string(),
-    };
-    assert!(is_retriable_error(&error));
+use crate::error::PlmError;
+
+/// 待ち時間なしのリトライ方針
+fn no_wait(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        base_delay: Duration::ZERO,
+    }
 }
 
-#[test]
-fn test_is_retriable_error_403_rate_limit_case_insensitive() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 403,
-        message: "RATE LIMIT exceeded".to_string(),
-    };
-    assert!(is_retriable_error(&error));
-}
-
-#[test]
-fn test_is_retriable_error_403_not_rate_limit() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 403,
-        message: "Forbidden".to_string(),
-    };
-    assert!(!is_retriable_error(&error));
-}
-
-#[test]
-fn test_is_retriable_error_500() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 500,
-        message: "Internal Server Error".to_string(),
-    };
-    // 500 is retryable via PlmError::is_retryable()
-    assert!(is_retriable_error(&error));
-}
-
-#[test]
-fn test_is_retriable_error_502() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 502,
-        message: "Bad Gateway".to_string(),
-    };
-    assert!(is_retriable_error(&error));
-}
+// =========================================================================
+// RetryPolicy tests
+// =========================================================================
 
 #[test]
-fn test_is_retriable_error_503() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 503,
-        message: "Service Unavailable".to_string(),
-    };
-    assert!(is_retriable_error(&error));
+fn test_retry_policy_default_backoff_doubles() {
+    let policy = RetryPolicy::new(3);
+    assert_eq!(policy.delay(1), Duration::from_secs(1));
+    assert_eq!(policy.delay(2), Duration::from_secs(2));
+    assert_eq!(policy.delay(3), Duration::from_secs(4));
 }
 
 #[test]
-fn test_is_retriable_error_404() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 404,
-        message: "Not Found".to_string(),
+fn test_retry_policy_delay_scales_with_base() {
+    let policy = RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(250),
     };
-    assert!(!is_retriable_error(&error));
+    assert_eq!(policy.delay(1), Duration::from_millis(250));
+    assert_eq!(policy.delay(3), Duration::from_secs(1));
 }
 
 #[test]
-fn test_is_retriable_error_401() {
-    let error = PlmError::RepoApi {
-        url: "https://github.com".to_string(),
-        status: 401,
-        message: "Unauthorized".to_string(),
-    };
-    assert!(!is_retriable_error(&error));
+fn test_retry_policy_delay_does_not_overflow() {
+    let policy = RetryPolicy::new(100);
+    assert_eq!(policy.delay(64), Duration::from_secs(u64::from(u32::MAX)));
 }
 
 // =========================================================================
@@ -107,7 +50,7 @@ async fn test_with_retry_success_first_try() {
             call_count += 1;
             async { Ok::<_, PlmError>(42) }
         },
-        3,
+        &no_wait(3),
     )
     .await;
 
@@ -134,7 +77,7 @@ async fn test_with_retry_success_after_retries() {
                 }
             }
         },
-        3,
+        &no_wait(3),
     )
     .await;
 
@@ -157,7 +100,7 @@ async fn test_with_retry_fails_after_max_retries() {
                 })
             }
         },
-        2,
+        &no_wait(2),
     )
     .await;
 
@@ -180,7 +123,7 @@ async fn test_with_retry_non_retriable_error_fails_immediately() {
                 })
             }
         },
-        3,
+        &no_wait(3),
     )
     .await;
 
@@ -188,3 +131,26 @@ async fn test_with_retry_non_retriable_error_fails_immediately() {
     // 404 はリトライ不可なので1回で終了
     assert_eq!(call_count, 1);
 }
+
+#[tokio::test]
+async fn test_with_retry_rate_limit_fails_immediately() {
+    let mut call_count = 0;
+    let result: Result<i32> = with_retry(
+        || {
+            call_count += 1;
+            async {
+                Err(PlmError::RepoApi {
+                    url: "https://test.com".to_string(),
+                    status: 429,
+                    message: "Too Many Requests".to_string(),
+                })
+            }
+        },
+        &no_wait(3),
+    )
+    .await;
+
+    assert!(result.is_err());
+    // 4xx はレート制限でもリトライしない
+    assert_eq!(call_count, 1);
+}
@@ -1,14 +1,20 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
-use crate::component::{AgentFormat, CommandFormat, ComponentKind, Scope};
+use crate::component::PromptNaming;
+use crate::component::{check_relative_path, ensure_within, source_symlinks};
+use crate::component::{AgentFormat, CommandFormat, CommandPrefix, ComponentKind, Scope};
 use crate::component::{Component, ComponentDeployment, ConversionConfig, DeploymentOutput};
 use crate::component::{ComponentRef, PlacementContext, PlacementScope, ProjectContext};
+use crate::env::EnvVar;
+use crate::error::PlmError;
 use crate::plugin::{
-    cleanup_legacy_hierarchy, meta, meta::TargetStatus, MarketplaceContent, PackageCache,
-    PackageCacheAccess,
+    cleanup_legacy_hierarchy, meta, meta::TargetStatus, update_placements, Channel,
+    ComponentConflict, MarketplaceContent, PackageCache, PackageCacheAccess, PlacementLedger,
+    PlacementRecord, PluginLock,
 };
-use crate::source::parse_source;
+use crate::scan::ExcludeRules;
+use crate::source::parse_source_with_channel;
 use crate::target::{PluginOrigin, Target, TargetKind};
 
 pub mod format;
@@ -21,6 +27,10 @@ pub use crate::hooks::converter::{ConversionWarning, SourceFormat};
 pub struct ScannedPlugin {
     package: MarketplaceContent,
     pub components: Vec<Component>,
+    /// コマンド呼び出し名のプレフィクス（`.plm-meta.json` の `commandPrefix`）
+    pub command_prefix: Option<CommandPrefix>,
+    /// Copilot プロンプトファイルの命名規約（`config.toml` の `targets.copilot.prompt_naming`）
+    pub prompt_naming: PromptNaming,
 }
 
 impl ScannedPlugin {
@@ -53,6 +63,32 @@ impl ScannedPlugin {
     pub fn plugin_root(&self) -> &Path {
         self.package.path()
     }
+
+    /// 除外ルールによりスキャン対象外となったパス（プラグインルートからの相対パス）
+    pub fn excluded_paths(&self) -> Vec<PathBuf> {
+        self.package.excluded_paths()
+    }
+
+    /// 除外ルールを取得（組み込みリスト + `plugin.json` の `exclude`）
+    pub fn exclude_rules(&self) -> ExcludeRules {
+        self.package.exclude_rules()
+    }
+
+    /// `plugin.json` で指定された種別ごとのカスタムパス（未指定なら `None`）
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind whose custom path is looked up.
+    pub fn custom_path(&self, kind: ComponentKind) -> Option<&str> {
+        let manifest = self.package.manifest();
+        match kind {
+            ComponentKind::Skill => manifest.skills.as_deref(),
+            ComponentKind::Agent => manifest.agents.as_deref(),
+            ComponentKind::Command => manifest.commands.as_deref(),
+            ComponentKind::Instruction => manifest.instructions.as_deref(),
+            ComponentKind::Hook => manifest.hooks.as_deref(),
+        }
+    }
 }
 
 /// 配置リクエスト
@@ -65,6 +101,9 @@ pub struct PlaceRequest<'a> {
     /// `[features] codex_hooks = true` を自動追記するか。
     /// `false` の場合（`--no-enable-flag` 指定時）は config.toml に触れない。
     pub enable_codex_hooks_flag: bool,
+    /// 別プラグインが所有する既存の配置先も上書きするか（`--overwrite`）。
+    /// `false` の場合は衝突として `PlaceOutcome::conflicts` に記録し、配置しない。
+    pub overwrite: bool,
 }
 
 /// 配置結果（成果レポート）
@@ -76,6 +115,8 @@ pub struct PlaceOutcome {
     /// Codex Hook 配置時に適用された feature flag の結果。
     /// 1 回の `place_plugin` で 1 scope につき最大 1 件。
     pub feature_flags: Vec<crate::target::FeatureFlagOutcome>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 /// 配置成功
@@ -99,16 +140,28 @@ pub struct PlaceSuccess {
     ///
     /// `None` になるケース:
     /// - **Hook 以外**（Skill / Agent / Command / Instruction）
-    /// - **Hook だが `DeploymentOutput::Copied` 経路**を通った場合（version 付き
+    /// - **Hook だが `DeploymentOutput::Copied` / `Unchanged` 経路**を通った場合（version 付き
     ///   Copilot 形式の完全 passthrough。`HookConvertOutput` を経由しないため
     ///   `source_format` を保持しない）
     pub hook_source_format: Option<SourceFormat>,
+    /// 添付アセットが巨大な場合の警告（[`crate::component::large_assets_warning`]）。
+    pub assets_warning: Option<String>,
+    /// コピー元のシンボリックリンクをスキップした警告（[`crate::component::symlink_warning`]）。
+    pub symlink_warning: Option<String>,
+    /// 事前変換した成果物がソースより古く変換し直した警告（[`crate::component::precompile`]）。
+    pub precompile_warning: Option<String>,
+    /// 内容が同じで書き込みを省略した生成ファイル数（Hook の設定 JSON・ラッパースクリプト）。
+    pub unchanged_files: usize,
+    /// 内容が変わって書き直した生成ファイルの unified diff（`--verbose` で表示する）。
+    pub file_diffs: Vec<String>,
 }
 
 /// 配置失敗の段階
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlaceFailureStage {
     Resolution,
+    /// 安全でないパス（`..`・絶対パス・ベースディレクトリ外の配置先）を検出した
+    UnsafePath,
     Deployment,
 }
 
@@ -131,12 +184,14 @@ pub struct PlaceFailure {
 ///
 /// * `source_str` - Plugin source locator (GitHub or marketplace format).
 /// * `force` - When true, ignore the existing cache entry and re-download.
+/// * `channel` - Update channel to install from, or `None` for the default branch / given ref.
 pub async fn download_plugin(
     source_str: &str,
     force: bool,
+    channel: Option<Channel>,
 ) -> crate::error::Result<MarketplaceContent> {
     let cache = PackageCache::new()?;
-    download_plugin_with_cache(source_str, force, &cache).await
+    download_plugin_with_cache(source_str, force, channel, &cache).await
 }
 
 /// キャッシュを注入可能な汎用プラグインダウンロード
@@ -147,20 +202,43 @@ pub async fn download_plugin(
 ///
 /// * `source_str` - Plugin source locator (GitHub or marketplace format).
 /// * `force` - When true, ignore the existing cache entry and re-download.
+/// * `channel` - Update channel to install from, or `None` for the default branch / given ref.
 /// * `cache` - Package cache implementation used to resolve and store downloads.
 pub async fn download_plugin_with_cache(
     source_str: &str,
     force: bool,
+    channel: Option<Channel>,
     cache: &dyn PackageCacheAccess,
 ) -> crate::error::Result<MarketplaceContent> {
-    let source = parse_source(source_str)?;
+    let source = parse_source_with_channel(source_str, channel)?;
     let cached = source.download(cache, force).await?;
     MarketplaceContent::try_from(cached)
 }
 
+/// ダウンロードするプラグインの操作ロックを取る
+///
+/// ダウンロード自体がキャッシュを書き換えるため、[`download_plugin`] より前に取る。
+/// 配置とメタデータ更新が終わるまで保持し、同じプラグインへの他プロセスの
+/// update / uninstall などと並行しないようにする。
+///
+/// # Arguments
+///
+/// * `source_str` - Plugin source locator (GitHub or marketplace format).
+/// * `channel` - Update channel to install from, or `None` for the default branch / given ref.
+/// * `cache` - Package cache the plugin will be downloaded into.
+pub fn lock_plugin(
+    source_str: &str,
+    channel: Option<Channel>,
+    cache: &dyn PackageCacheAccess,
+) -> crate::error::Result<PluginLock> {
+    parse_source_with_channel(source_str, channel)?.lock(cache)
+}
+
 /// プラグインのコンポーネントをスキャン
 ///
 /// `type_filter` が指定された場合、該当する種別のコンポーネントのみを返す。
+/// コマンドのプレフィクスは `.plm-meta.json` の `commandPrefix` から、Copilot プロンプトの
+/// 命名規約はユーザー設定の `targets.copilot.prompt_naming` から読み込む。
 ///
 /// # Arguments
 ///
@@ -176,20 +254,49 @@ pub fn scan_plugin(
         components.retain(|c| filter.contains(&c.kind));
     }
 
+    let command_prefix = meta::load_meta(package.path())
+        .and_then(|m| CommandPrefix::from_stored(m.command_prefix.as_deref()));
+    let prompt_naming = PromptNaming::configured()?;
+
     Ok(ScannedPlugin {
         package: package.clone(),
         components,
+        command_prefix,
+        prompt_naming,
     })
 }
 
 /// プラグインのコンポーネントをターゲットに配置
 ///
+/// 配置先が既に存在し、配置記録（`~/.plm/placements.json`）上で別のプラグインが
+/// 所有しているコンポーネントは、`request.overwrite` でなければ配置せずに
+/// `conflicts` へ記録する。記録を読めない場合は従来どおり上書きする。
+///
 /// # Arguments
 ///
 /// * `request` - Placement request describing the scanned plugin, targets, scope, and project root.
 pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
+    let owners = if request.overwrite {
+        None
+    } else {
+        PlacementLedger::load().ok()
+    };
+    place_plugin_with_owners(request, owners.as_ref())
+}
+
+/// 所有者の判定に使う配置記録を指定してコンポーネントを配置する
+///
+/// # Arguments
+///
+/// * `request` - Placement request describing the scanned plugin, targets, scope, and project root.
+/// * `owners` - Placement ledger used to detect conflicts (`None` overwrites unconditionally).
+fn place_plugin_with_owners(
+    request: &PlaceRequest,
+    owners: Option<&PlacementLedger>,
+) -> PlaceOutcome {
     let mut successes = Vec::new();
     let mut failures = Vec::new();
+    let mut conflicts = Vec::new();
     let mut feature_flags = Vec::new();
     let mut target_feature_flag_applied = false;
 
@@ -218,18 +325,41 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
                 }
             }
 
-            let ctx = PlacementContext {
-                component: ComponentRef::from(component),
-                origin: &origin,
-                scope: PlacementScope::new(request.scope),
-                project: ProjectContext::new(request.project_root),
-            };
+            let ctx = placement_context(request, target.as_ref(), component, &origin);
 
-            let target_path = match target.placement_location(&ctx) {
-                Some(location) => location.into_path(),
+            let target_path = match placement_path(request, target.as_ref(), &ctx) {
+                Some(path) => path,
                 None => continue,
             };
 
+            if let Err(e) = check_path_safety(request, component, &ctx, &target_path) {
+                failures.push(PlaceFailure {
+                    target: target.name().to_string(),
+                    component_name: component.name.clone(),
+                    component_kind: component.kind,
+                    error: e.to_string(),
+                    stage: PlaceFailureStage::UnsafePath,
+                });
+                continue;
+            }
+
+            if let Some(owner) = owners.and_then(|ledger| {
+                ledger.foreign_owner(
+                    &target_path,
+                    request.scanned.marketplace(),
+                    request.scanned.id(),
+                )
+            }) {
+                conflicts.push(ComponentConflict {
+                    target: target.name().to_string(),
+                    kind: component.kind,
+                    name: component.name.clone(),
+                    path: target_path.clone(),
+                    owner: owner.owner_label(),
+                });
+                continue;
+            }
+
             if let Err(error) =
                 target.pre_place_check(&ctx, &target_path, request.scanned.plugin_root())
             {
@@ -274,6 +404,10 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
                 .scope(request.scope)
                 .target_path(&target_path)
                 .conversion(conversion)
+                .exclude(
+                    request.scanned.plugin_root(),
+                    request.scanned.exclude_rules(),
+                )
                 .build()
             {
                 Ok(d) => d,
@@ -289,6 +423,7 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
                 }
             };
 
+            let precompile_warning = deployment.precompile_warning();
             match deployment.execute() {
                 Ok(result) => {
                     let (source_format, dest_format) = match &result {
@@ -314,6 +449,14 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
                             _ => (Vec::new(), 0, 0, None),
                         };
 
+                    let (unchanged_files, file_diffs) = match &result {
+                        DeploymentOutput::HookConverted(hr) => {
+                            (hr.writes.unchanged_count, hr.writes.diffs.clone())
+                        }
+                        DeploymentOutput::Unchanged => (1, Vec::new()),
+                        _ => (0, Vec::new()),
+                    };
+
                     successes.push(PlaceSuccess {
                         target: target.name().to_string(),
                         target_kind: target.kind(),
@@ -326,6 +469,11 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
                         script_count,
                         hook_count,
                         hook_source_format,
+                        assets_warning: deployment.assets_warning(),
+                        symlink_warning: deployment.symlink_warning(),
+                        precompile_warning,
+                        unchanged_files,
+                        file_diffs,
                     });
 
                     let post_place = target.post_place(
@@ -369,7 +517,194 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
         successes,
         failures,
         feature_flags,
+        conflicts,
+    }
+}
+
+/// 配置予定（`plm install --dry-run`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedPlacement {
+    pub target: String,
+    pub component_name: String,
+    pub component_kind: ComponentKind,
+    pub target_path: PathBuf,
+    /// 配置先に既存のファイル・ディレクトリがあり、上書きになる
+    pub overwrite: bool,
+}
+
+/// 配置せずに配置予定を求める
+///
+/// `place_plugin` と同じ対応判定と配置先解決を行い、ファイルシステムには書き込まない。
+///
+/// # Arguments
+///
+/// * `request` - Placement request describing the scanned plugin, targets, scope, and project root.
+pub fn plan_placements(request: &PlaceRequest) -> Vec<PlannedPlacement> {
+    let origin =
+        PluginOrigin::from_cached_plugin(request.scanned.marketplace(), request.scanned.id());
+
+    let mut planned = Vec::new();
+    for target in request.targets {
+        for component in &request.scanned.components {
+            if !target.supports(component.kind) {
+                continue;
+            }
+            let ctx = placement_context(request, target.as_ref(), component, &origin);
+            if let Some(target_path) = placement_path(request, target.as_ref(), &ctx) {
+                planned.push(PlannedPlacement {
+                    target: target.name().to_string(),
+                    component_name: component.name.clone(),
+                    component_kind: component.kind,
+                    overwrite: target_path.exists(),
+                    target_path,
+                });
+            }
+        }
+    }
+    planned
+}
+
+/// 配置せずに安全でないパスを検出する（`plm install --strict` の事前検査）
+///
+/// `place_plugin` がスキップするコンポーネント（[`PlaceFailureStage::UnsafePath`]）に加え、
+/// コピー元にシンボリックリンクを含むコンポーネントも列挙する。
+///
+/// # Arguments
+///
+/// * `request` - Placement request describing the scanned plugin, targets, scope, and project root.
+pub fn find_unsafe_paths(request: &PlaceRequest) -> Vec<PlaceFailure> {
+    let origin =
+        PluginOrigin::from_cached_plugin(request.scanned.marketplace(), request.scanned.id());
+
+    let mut unsafe_paths = Vec::new();
+    for target in request.targets {
+        for component in &request.scanned.components {
+            if !target.supports(component.kind) {
+                continue;
+            }
+            let ctx = placement_context(request, target.as_ref(), component, &origin);
+            let Some(target_path) = placement_path(request, target.as_ref(), &ctx) else {
+                continue;
+            };
+            let symlinks = source_symlinks(component.kind, &component.path);
+            let error = match check_path_safety(request, component, &ctx, &target_path) {
+                Err(e) => e.to_string(),
+                Ok(()) if !symlinks.is_empty() => format!(
+                    "Source contains symlink(s): {}",
+                    symlinks
+                        .iter()
+                        .map(|p| p.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Ok(()) => continue,
+            };
+            unsafe_paths.push(PlaceFailure {
+                target: target.name().to_string(),
+                component_name: component.name.clone(),
+                component_kind: component.kind,
+                error,
+                stage: PlaceFailureStage::UnsafePath,
+            });
+        }
     }
+    unsafe_paths
+}
+
+/// 配置前の安全性検査
+///
+/// 次のいずれかに当たるコンポーネントを拒否する。
+/// - コンポーネント名・`plugin.json` のカスタムパス指定に `..` や絶対パスを含む
+/// - コピー元がプラグインのディレクトリ外に解決される
+/// - 配置先がベースディレクトリ（Project はプロジェクトルート、Personal はホーム）外に解決される
+///
+/// # Arguments
+///
+/// * `request` - Placement request providing the plugin root, scope, and project root.
+/// * `component` - Component to place.
+/// * `ctx` - Placement context of the component (command prefix applied).
+/// * `target_path` - Resolved placement path.
+fn check_path_safety(
+    request: &PlaceRequest,
+    component: &Component,
+    ctx: &PlacementContext,
+    target_path: &Path,
+) -> crate::error::Result<()> {
+    check_relative_path("component name", ctx.name())?;
+    if let Some(original_name) = ctx.original_name() {
+        check_relative_path("component name", original_name)?;
+    }
+    if let Some(custom_path) = request.scanned.custom_path(component.kind) {
+        check_relative_path(
+            &format!("{} path in plugin.json", component.kind.as_str()),
+            custom_path,
+        )?;
+    }
+    ensure_within(&component.path, request.scanned.plugin_root())?;
+    ensure_within(target_path, &placement_base(request)?)
+}
+
+/// 配置先が収まるべきベースディレクトリ（Project はプロジェクトルート、Personal はホーム）
+///
+/// # Arguments
+///
+/// * `request` - Placement request providing the scope and project root.
+fn placement_base(request: &PlaceRequest) -> crate::error::Result<PathBuf> {
+    match request.scope {
+        Scope::Project => Ok(request.project_root.to_path_buf()),
+        Scope::Personal => EnvVar::get("HOME")
+            .map(|home| PathBuf::from(home.trim()))
+            .filter(|home| home.is_absolute())
+            .ok_or_else(|| {
+                PlmError::Validation(
+                    "Cannot verify personal placement path: HOME is not an absolute path"
+                        .to_string(),
+                )
+            }),
+    }
+}
+
+/// コンポーネントの配置コンテキストを組み立てる（コマンドのプレフィクスを適用済み）
+///
+/// # Arguments
+///
+/// * `request` - Placement request providing the scope and project root.
+/// * `target` - Target the component is placed to.
+/// * `component` - Component to place.
+/// * `origin` - Origin of the plugin being placed.
+fn placement_context<'a>(
+    request: &PlaceRequest<'a>,
+    target: &dyn Target,
+    component: &Component,
+    origin: &'a PluginOrigin,
+) -> PlacementContext<'a> {
+    PlacementContext {
+        component: CommandPrefix::apply(
+            request.scanned.command_prefix.as_ref(),
+            ComponentRef::from(component),
+            target.kind(),
+        ),
+        origin,
+        scope: PlacementScope::new(request.scope),
+        project: ProjectContext::new(request.project_root),
+    }
+}
+
+/// 配置先のパスを求める（Copilot プロンプトの命名規約を適用済み）
+///
+/// # Arguments
+///
+/// * `request` - Placement request providing the naming convention.
+/// * `target` - Target the component is placed to.
+/// * `ctx` - Placement context of the component.
+fn placement_path(
+    request: &PlaceRequest,
+    target: &dyn Target,
+    ctx: &PlacementContext,
+) -> Option<PathBuf> {
+    target.placement_location(ctx).map(|location| {
+        PromptNaming::apply(request.scanned.prompt_naming, target.kind(), ctx, location).into_path()
+    })
 }
 
 /// place_plugin 後のステータス更新（CLI / TUI 共通）
@@ -378,6 +713,11 @@ pub fn place_plugin(request: &PlaceRequest) -> PlaceOutcome {
 /// （例: Codex の `.codex/hooks.json`）もあるため、target 全体が成功した場合だけ
 /// `.plm-meta.json` の `statusByTarget` に記録して enabled 判定を安定させる。
 ///
+/// Command を配置した場合は `commandPrefix` を `deployedCommandPrefix` に写し、
+/// disable / uninstall が同じ名前で配置先を解決できるようにする。
+/// 配置した Skill / Agent / Command は `deployedFiles` にも記録し、disable / uninstall は
+/// スキャン結果ではなくこの記録どおりに削除する。
+///
 /// 実際にステータス更新が発生しなかった場合（全 target が失敗した、`successes`
 /// が空など）は `.plm-meta.json` を書き換えない。失敗 install で不要な
 /// メタデータ更新を避け、ファイル mtime の汚染も防ぐ。
@@ -408,6 +748,27 @@ pub fn update_meta_after_place(plugin_path: &Path, result: &PlaceOutcome) {
         }
     }
 
+    for success in result
+        .successes
+        .iter()
+        .filter(|success| PlacementRecord::is_tracked(success.component_kind))
+    {
+        updated |= plugin_meta.record_deployed_file(
+            &success.target,
+            success.component_kind,
+            &success.target_path,
+        );
+    }
+
+    let placed_command = result
+        .successes
+        .iter()
+        .any(|success| success.component_kind == ComponentKind::Command);
+    if placed_command && plugin_meta.deployed_command_prefix != plugin_meta.command_prefix {
+        plugin_meta.deployed_command_prefix = plugin_meta.command_prefix.clone();
+        updated = true;
+    }
+
     if !updated {
         return;
     }
@@ -417,6 +778,53 @@ pub fn update_meta_after_place(plugin_path: &Path, result: &PlaceOutcome) {
     }
 }
 
+/// 選択インストール（`--type`）の種別を `.plm-meta.json` の `onlyKinds` に記録する
+///
+/// `plm update --redeploy-only` が同じ種別だけを配置し直せるようにする。
+/// 種別を指定しないインストールでは記録を消す。内容が変わらなければ書き込まない。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the cached plugin.
+/// * `type_filter` - Kinds given with `--type`; `None` means all kinds.
+pub fn record_only_kinds(plugin_path: &Path, type_filter: Option<&[ComponentKind]>) {
+    let mut plugin_meta = meta::load_meta(plugin_path).unwrap_or_default();
+    let only_kinds = type_filter.map(<[ComponentKind]>::to_vec);
+    if plugin_meta.only_kinds == only_kinds {
+        return;
+    }
+    plugin_meta.only_kinds = only_kinds;
+    if let Err(e) = meta::write_meta(plugin_path, &plugin_meta) {
+        eprintln!("Warning: Failed to update .plm-meta.json: {}", e);
+    }
+}
+
+/// place_plugin で配置したパスを配置記録（`~/.plm/placements.json`）へ追加する
+///
+/// キャッシュが消えた後でも配置物の所有プラグインを特定できるようにするため、
+/// `.plm-meta.json` とは別にキャッシュ外へ記録する（孤立ファイル検出で使用）。
+///
+/// # Arguments
+///
+/// * `scanned` - Scanned plugin that was placed.
+/// * `result` - Outcome returned by `place_plugin`.
+/// * `project_root` - Project root the plugin was placed into.
+pub fn record_placements(scanned: &ScannedPlugin, result: &PlaceOutcome, project_root: &Path) {
+    let records = result
+        .successes
+        .iter()
+        .filter(|success| PlacementRecord::is_tracked(success.component_kind))
+        .map(|success| PlacementRecord {
+            path: success.target_path.clone(),
+            target: success.target.clone(),
+            kind: success.component_kind,
+            marketplace: scanned.marketplace().map(String::from),
+            plugin: scanned.id().to_string(),
+        })
+        .collect();
+    update_placements(records, &[], project_root);
+}
+
 /// 配置成功時に所有権を `.plm-meta.json` の `managedFiles[target]` へ記録する。
 ///
 /// `plm import` は `place_plugin` を経由しないため `update_meta_after_place`
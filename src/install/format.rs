@@ -22,7 +22,7 @@ use std::collections::BTreeSet;
 ///
 /// 1. **Hook 以外**（Skill / Agent / Command / Instruction）。`PlaceSuccess` 構築時に
 ///    `hook_source_format` が `None` で初期化される
-/// 2. **Hook だが `DeploymentOutput::Copied` 経路**を通ったケース（version 付き
+/// 2. **Hook だが `DeploymentOutput::Copied` / `Unchanged` 経路**を通ったケース（version 付き
 ///    Copilot 形式の完全 passthrough。`HookConvertOutput` を経由しないため
 ///    `source_format` を保持しない）
 ///
@@ -174,7 +174,7 @@ pub fn format_manual_rewrite_section(stubs: &[(String, String)]) -> Option<Strin
 /// ここでは警告を出さない。
 ///
 /// **`source_format == None` の扱い**: Hook 以外（Skill / Agent / ...）または
-/// `DeploymentOutput::Copied` 経路の Hook（version 付き Copilot 完全 passthrough）。
+/// `DeploymentOutput::Copied` / `Unchanged` 経路の Hook（version 付き Copilot 完全 passthrough）。
 /// どちらも警告対象外。
 pub fn format_empty_hooks_warning(
     hook_count: usize,
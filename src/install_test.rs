@@ -161,6 +161,7 @@ fn test_place_plugin_skill_to_codex() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert_eq!(result.plugin_name, "test-plugin");
@@ -171,6 +172,97 @@ fn test_place_plugin_skill_to_codex() {
     assert_eq!(result.successes[0].target, "codex");
 }
 
+#[test]
+fn test_place_plugin_skips_destination_owned_by_another_plugin() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let ledger_dir = TempDir::new().unwrap();
+    let cached = create_test_cached_package(temp.path(), &["my-skill"], &[], &[]);
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+    let request = PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    };
+    let destination = plan_placements(&request).remove(0).target_path;
+    fs::create_dir_all(&destination).unwrap();
+
+    let mut ledger = PlacementLedger::load_from(ledger_dir.path().join("placements.json")).unwrap();
+    ledger.record(PlacementRecord {
+        path: destination.clone(),
+        target: "codex".to_string(),
+        kind: ComponentKind::Skill,
+        marketplace: Some("other-market".to_string()),
+        plugin: "other-plugin".to_string(),
+    });
+
+    let result = place_plugin_with_owners(&request, Some(&ledger));
+    assert!(result.successes.is_empty());
+    assert!(result.failures.is_empty());
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].owner, "other-market/other-plugin");
+    assert_eq!(result.conflicts[0].path, destination);
+
+    // 所有者を判定しない（--overwrite）なら上書きする
+    let result = place_plugin_with_owners(&request, None);
+    assert_eq!(result.successes.len(), 1);
+    assert!(result.conflicts.is_empty());
+}
+
+/// ディレクトリ配下の全パス（ファイル・ディレクトリ）を列挙する
+fn list_tree(root: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = walkdir::WalkDir::new(root)
+        .into_iter()
+        .map(|e| e.unwrap().path().to_path_buf())
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn test_plan_placements_matches_place_plugin_without_writing() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let cached = create_test_cached_package(temp.path(), &["my-skill"], &[], &["my-cmd"]);
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+    let request = PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    };
+    let cache_before = list_tree(temp.path());
+    let project_before = list_tree(project_dir.path());
+
+    let planned = plan_placements(&request);
+
+    assert_eq!(list_tree(temp.path()), cache_before);
+    assert_eq!(list_tree(project_dir.path()), project_before);
+    assert!(planned.iter().all(|p| !p.overwrite));
+
+    // 実際の配置と同じパスを予定し、配置後の再計画では上書きとして扱う
+    let result = place_plugin(&request);
+    let mut placed: Vec<&Path> = result
+        .successes
+        .iter()
+        .map(|s| s.target_path.as_path())
+        .collect();
+    let mut expected: Vec<&Path> = planned.iter().map(|p| p.target_path.as_path()).collect();
+    placed.sort();
+    expected.sort();
+    assert_eq!(placed, expected);
+    assert!(plan_placements(&request).iter().all(|p| p.overwrite));
+}
+
 #[test]
 fn test_place_plugin_unsupported_component_skipped() {
     let temp = TempDir::new().unwrap();
@@ -189,6 +281,7 @@ fn test_place_plugin_unsupported_component_skipped() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     // Antigravity doesn't support Agent, so it should be skipped
@@ -212,6 +305,7 @@ fn test_place_plugin_empty_components() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert!(result.successes.is_empty());
@@ -235,6 +329,7 @@ fn test_place_plugin_multiple_targets() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert_eq!(result.successes.len(), 2);
@@ -267,6 +362,7 @@ fn test_place_plugin_cursor_skill_uses_original_name_and_removes_legacy() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert!(result.failures.is_empty(), "{:?}", result.failures);
@@ -312,6 +408,25 @@ fn test_scan_plugin_id_none_falls_back_to_name() {
     assert_eq!(result.id(), "test-plugin");
 }
 
+#[test]
+fn test_scan_plugin_reads_command_prefix_from_meta() {
+    let temp = TempDir::new().unwrap();
+    let cached = create_test_cached_package(temp.path(), &[], &[], &["deploy"]);
+    let plugin_meta = crate::plugin::meta::PluginMeta {
+        command_prefix: Some("foo:".to_string()),
+        ..Default::default()
+    };
+    crate::plugin::meta::write_meta(temp.path(), &plugin_meta).unwrap();
+    let package = MarketplaceContent::try_from(cached).unwrap();
+
+    let result = scan_plugin(&package, None).unwrap();
+
+    assert_eq!(
+        result.command_prefix.as_ref().map(|p| p.as_str()),
+        Some("foo:")
+    );
+}
+
 /// place_plugin が id を使って PluginOrigin を構成することを検証
 #[test]
 fn test_place_plugin_uses_id_for_origin() {
@@ -337,6 +452,7 @@ fn test_place_plugin_uses_id_for_origin() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     // フラット配置: target_path 末尾は flattened_name (= "{plugin}_{original}")
@@ -371,10 +487,26 @@ async fn test_download_plugin_with_cache_invalid_source_returns_error() {
         crate::plugin::PackageCache::with_cache_dir(temp_cache.path().to_path_buf()).unwrap();
 
     // "/" のような不正なソース文字列は parse_source で確実に失敗し、早期にエラーとなる
-    let result = download_plugin_with_cache("/", false, &cache).await;
+    let result = download_plugin_with_cache("/", false, None, &cache).await;
     assert!(result.is_err());
 }
 
+#[test]
+fn test_lock_plugin_locks_cache_entry_before_download() {
+    let temp_cache = TempDir::new().unwrap();
+    let cache =
+        crate::plugin::PackageCache::with_cache_dir(temp_cache.path().to_path_buf()).unwrap();
+
+    // ダウンロードせずに、ダウンロード先のキャッシュエントリのロックが取れる
+    let lock = lock_plugin("owner/repo", None, &cache).unwrap();
+    let lock_file = temp_cache.path().join("github").join(".owner--repo.lock");
+    assert!(lock_file.exists());
+    assert!(!temp_cache.path().join("github").join("owner--repo").exists());
+
+    drop(lock);
+    assert!(!lock_file.exists());
+}
+
 // =============================================================================
 // Hook 配置時の hook_source_format 伝播テスト
 // =============================================================================
@@ -438,6 +570,7 @@ fn test_place_plugin_claude_code_hook_propagates_source_format() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert_eq!(result.successes.len(), 1, "failures: {:?}", result.failures);
@@ -475,6 +608,7 @@ fn test_place_plugin_copilot_hook_with_missing_version_propagates_target_format(
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert_eq!(result.successes.len(), 1, "failures: {:?}", result.failures);
@@ -528,6 +662,7 @@ fn test_place_plugin_codex_hook_installs_inline_hooks_json() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert_eq!(result.successes.len(), 1, "failures: {:?}", result.failures);
@@ -613,6 +748,7 @@ fn test_place_plugin_codex_rejects_multiple_hook_components() {
         scope: crate::component::Scope::Project,
         project_root: project_dir.path(),
         enable_codex_hooks_flag: false,
+        overwrite: false,
     });
 
     assert!(result.successes.is_empty());
@@ -678,3 +814,194 @@ fn record_codex_hook_ownership_unblocks_re_import() {
         "managedFiles に記録された path への再 import は許可されるべき"
     );
 }
+
+#[test]
+fn record_only_kinds_records_and_clears_selection() {
+    let plugin_dir = TempDir::new().unwrap();
+
+    record_only_kinds(plugin_dir.path(), Some(&[ComponentKind::Command]));
+    let plugin_meta = crate::plugin::meta::load_meta(plugin_dir.path()).unwrap();
+    assert_eq!(plugin_meta.only_kinds, Some(vec![ComponentKind::Command]));
+
+    // 種別を指定しない再インストールでは全種別に戻る
+    record_only_kinds(plugin_dir.path(), None);
+    let plugin_meta = crate::plugin::meta::load_meta(plugin_dir.path()).unwrap();
+    assert_eq!(plugin_meta.only_kinds, None);
+}
+
+// =============================================================================
+// 安全でないパスの検査
+// =============================================================================
+
+/// `plugin.json` に任意のフィールドを足してキャッシュ済みパッケージを構築する
+fn create_cached_package_with_manifest(
+    base_dir: &Path,
+    manifest_json: serde_json::Value,
+) -> CachedPackage {
+    fs::create_dir_all(base_dir).unwrap();
+    fs::write(base_dir.join("plugin.json"), manifest_json.to_string()).unwrap();
+    let manifest = PluginManifest::load(&base_dir.join("plugin.json")).unwrap();
+    CachedPackage {
+        name: "test-plugin".to_string(),
+        id: None,
+        marketplace: Some("test-marketplace".to_string()),
+        path: base_dir.to_path_buf(),
+        manifest,
+        git_ref: "main".to_string(),
+        commit_sha: "abc123".to_string(),
+        marketplace_manifest: None,
+    }
+}
+
+#[test]
+fn test_place_plugin_rejects_custom_path_escaping_plugin_root() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let plugin_root = temp.path().join("plugin");
+    // プラグインの外に置いた Skill を "../outside" で参照させる
+    let outside_skill = temp.path().join("outside").join("my-skill");
+    fs::create_dir_all(&outside_skill).unwrap();
+    fs::write(outside_skill.join("SKILL.md"), "# Outside").unwrap();
+    let cached = create_cached_package_with_manifest(
+        &plugin_root,
+        serde_json::json!({
+            "name": "test-plugin",
+            "version": "1.0.0",
+            "skills": "../outside"
+        }),
+    );
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+    let request = PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    };
+
+    let result = place_plugin(&request);
+
+    assert!(result.successes.is_empty());
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].stage, PlaceFailureStage::UnsafePath);
+    assert!(
+        result.failures[0]
+            .error
+            .contains("skill path in plugin.json"),
+        "{}",
+        result.failures[0].error
+    );
+    assert_eq!(
+        list_tree(project_dir.path()),
+        vec![project_dir.path().to_path_buf()]
+    );
+    assert_eq!(find_unsafe_paths(&request).len(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_place_plugin_rejects_target_dir_symlinked_outside_project() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let outside = TempDir::new().unwrap();
+    let cached = create_test_cached_package(temp.path(), &["my-skill"], &[], &[]);
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+    let request = PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    };
+
+    // 配置先のトップレベルディレクトリ（.codex など）をプロジェクト外へのリンクにする
+    let planned = plan_placements(&request);
+    let top = planned[0]
+        .target_path
+        .strip_prefix(project_dir.path())
+        .unwrap()
+        .components()
+        .next()
+        .unwrap();
+    std::os::unix::fs::symlink(outside.path(), project_dir.path().join(top)).unwrap();
+
+    let result = place_plugin(&request);
+
+    assert!(result.successes.is_empty());
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].stage, PlaceFailureStage::UnsafePath);
+    assert_eq!(
+        list_tree(outside.path()),
+        vec![outside.path().to_path_buf()]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_place_plugin_skips_symlinks_in_skill_source() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let secrets = TempDir::new().unwrap();
+    fs::write(secrets.path().join("id_rsa"), "private key").unwrap();
+    let cached = create_test_cached_package(temp.path(), &["my-skill"], &[], &[]);
+    let skill_dir = temp.path().join("skills").join("my-skill");
+    std::os::unix::fs::symlink(secrets.path(), skill_dir.join("secrets")).unwrap();
+    std::os::unix::fs::symlink(secrets.path().join("id_rsa"), skill_dir.join("id_rsa")).unwrap();
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+    let request = PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    };
+
+    let unsafe_paths = find_unsafe_paths(&request);
+    assert_eq!(unsafe_paths.len(), 1);
+    assert!(
+        unsafe_paths[0].error.contains("symlink"),
+        "{}",
+        unsafe_paths[0].error
+    );
+
+    let result = place_plugin(&request);
+
+    assert!(result.failures.is_empty());
+    assert_eq!(result.successes.len(), 1);
+    let placed = &result.successes[0].target_path;
+    assert!(placed.join("SKILL.md").exists());
+    assert!(!placed.join("secrets").exists());
+    assert!(!placed.join("id_rsa").exists());
+    let warning = result.successes[0].symlink_warning.as_deref().unwrap();
+    assert!(warning.contains("skipped 2 symlink(s)"), "{}", warning);
+}
+
+#[test]
+fn test_find_unsafe_paths_empty_for_clean_plugin() {
+    let temp = TempDir::new().unwrap();
+    let project_dir = TempDir::new().unwrap();
+    let cached = create_test_cached_package(temp.path(), &["my-skill"], &["my-agent"], &["my-cmd"]);
+    let package = MarketplaceContent::try_from(cached).unwrap();
+    let scanned = scan_plugin(&package, None).unwrap();
+    let targets: Vec<Box<dyn crate::target::Target>> = vec![Box::new(CodexTarget::new())];
+
+    let unsafe_paths = find_unsafe_paths(&PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: crate::component::Scope::Project,
+        project_root: project_dir.path(),
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    });
+
+    assert!(unsafe_paths.is_empty());
+}
@@ -0,0 +1,95 @@
+//! Ctrl-C (SIGINT) による中断の後始末
+//!
+//! コマンドは確認プロンプト（`[y/N]`）の `read_line` や大きなコピーで同期的にブロックするため、
+//! 中断はコマンドの future とは別の専用スレッドで待つ。中断されたらこのプロセスの
+//! staging 領域と保持中の操作ロックを削除してから終了コード 130 で終了する。
+//! `std::process::exit` は `Drop` を実行しないため、[`crate::plugin::PluginLock`] も
+//! ここで明示的に解放する。
+
+use crate::plugin::{release_all_locks, PackageCache};
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+
+/// 中断時の終了コード（128 + SIGINT）
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// 中断を待つ専用スレッドを起動する
+///
+/// ハンドラの登録が終わるまで戻らない（以降の SIGINT は必ず後始末を通る）。
+/// 登録に失敗した場合は警告し、既定の SIGINT の動作（即時終了）のままにする。
+pub fn spawn_handler() {
+    let (registered_tx, registered_rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name("plm-signal".to_string())
+        .spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = registered_tx.send(Err(e));
+                    return;
+                }
+            };
+            runtime.block_on(async {
+                let mut interrupt = match register() {
+                    Ok(interrupt) => interrupt,
+                    Err(e) => {
+                        let _ = registered_tx.send(Err(e));
+                        return;
+                    }
+                };
+                let _ = registered_tx.send(Ok(()));
+                if interrupt.recv().await.is_some() {
+                    cleanup();
+                    eprintln!("interrupted, partial files cleaned up");
+                    std::process::exit(INTERRUPTED_EXIT_CODE);
+                }
+            });
+        });
+
+    let registered = match spawned {
+        Ok(_) => registered_rx
+            .recv()
+            .unwrap_or_else(|_| Err(io::Error::other("signal thread exited"))),
+        Err(e) => Err(e),
+    };
+    if let Err(e) = registered {
+        eprintln!("[plm warn] failed to install the Ctrl-C handler: {}", e);
+    }
+}
+
+/// 中断時に残るもの（キャッシュの staging 領域と操作ロック）を削除する
+///
+/// staging の削除に失敗しても、ロックは必ず解放する。
+pub fn cleanup() {
+    let cleaned = PackageCache::new().and_then(|cache| cache.discard_all_staged());
+    if let Err(e) = cleaned {
+        eprintln!("[plm warn] failed to clean up partial files: {}", e);
+    }
+    release_all_locks();
+}
+
+#[cfg(unix)]
+type Interrupt = tokio::signal::unix::Signal;
+
+#[cfg(windows)]
+type Interrupt = tokio::signal::windows::CtrlC;
+
+/// SIGINT のハンドラを登録する（登録した時点から既定の動作では終了しなくなる）
+#[cfg(unix)]
+fn register() -> io::Result<Interrupt> {
+    tokio::signal::unix::signal(tokio::signal::unix::SignalKind::interrupt())
+}
+
+/// Ctrl-C のハンドラを登録する（登録した時点から既定の動作では終了しなくなる）
+#[cfg(windows)]
+fn register() -> io::Result<Interrupt> {
+    tokio::signal::windows::ctrl_c()
+}
+
+#[cfg(all(test, unix))]
+#[path = "interrupt_test.rs"]
+mod tests;
@@ -0,0 +1,78 @@
+use assert_cmd::cargo::CommandCargoExt;
+use std::fs;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// 確認プロンプトで止まっている `plm uninstall` に SIGINT を送る
+#[test]
+fn ctrl_c_interrupts_a_confirmation_prompt() {
+    let home = TempDir::new().unwrap();
+    let plugin_dir = home.path().join(".plm/cache/plugins/github/my-plugin");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    fs::write(
+        plugin_dir.join("plugin.json"),
+        r#"{"name": "my-plugin", "version": "1.0.0"}"#,
+    )
+    .unwrap();
+
+    let mut child = Command::cargo_bin("plm")
+        .unwrap()
+        .env_remove("PLM_HOME")
+        .env("HOME", home.path())
+        .args(["uninstall", "my-plugin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // プロンプトは改行なしで出るため、バイト単位で読んで待つ
+    let mut stdout = child.stdout.take().unwrap();
+    let (prompted_tx, prompted_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut seen = Vec::new();
+        let mut byte = [0u8; 1];
+        while stdout.read(&mut byte).unwrap_or(0) == 1 {
+            seen.push(byte[0]);
+            if seen.ends_with(b"[y/N]: ") {
+                let _ = prompted_tx.send(());
+            }
+        }
+    });
+    prompted_rx
+        .recv_timeout(Duration::from_secs(30))
+        .expect("uninstall did not reach the confirmation prompt");
+
+    let pid = libc::pid_t::try_from(child.id()).unwrap();
+    assert_eq!(unsafe { libc::kill(pid, libc::SIGINT) }, 0);
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if started.elapsed() > Duration::from_secs(10) {
+            child.kill().unwrap();
+            panic!("plm kept waiting at the prompt after Ctrl-C");
+        }
+        thread::sleep(Duration::from_millis(50));
+    };
+    let mut stderr = String::new();
+    child
+        .stderr
+        .take()
+        .unwrap()
+        .read_to_string(&mut stderr)
+        .unwrap();
+
+    assert_eq!(status.code(), Some(super::INTERRUPTED_EXIT_CODE));
+    assert!(stderr.contains("interrupted"), "{}", stderr);
+    assert!(
+        plugin_dir.exists(),
+        "interrupted uninstall must not remove the plugin"
+    );
+}
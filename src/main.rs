@@ -2,6 +2,7 @@
 #![allow(dead_code)]
 
 mod application;
+mod backup;
 mod cli;
 mod commands;
 mod component;
@@ -15,6 +16,7 @@ mod host;
 mod http;
 mod import;
 mod install;
+mod interrupt;
 mod marketplace;
 mod output;
 mod parser;
@@ -23,20 +25,50 @@ mod plugin;
 mod repo;
 mod scan;
 mod source;
+mod suggest;
 mod sync;
 mod target;
 mod tui;
 
 use crate::cli::Cli;
 use crate::error::{ErrorFormatter, RichError};
-use clap::Parser;
+use clap::FromArgMatches;
 
 #[tokio::main]
 async fn main() {
-    let cli = Cli::parse();
+    let matches = match cli::command().try_get_matches() {
+        Ok(matches) => matches,
+        Err(e) => commands::exit_with_parse_error(e),
+    };
+    let cli = match Cli::from_arg_matches(&matches) {
+        Ok(cli) => cli,
+        Err(e) => commands::exit_with_parse_error(e),
+    };
     let verbose = cli.verbose;
 
-    if let Err(plm_err) = commands::dispatch(cli).await {
+    // 下位レイヤー（HttpConfig::from_env 等）へはグローバルフラグを GlobalOptions で伝える
+    config::GlobalOptions {
+        no_http_cache: cli.no_http_cache,
+        verbose,
+        offline: cli.offline,
+        quiet: cli.quiet,
+        wait: cli.wait,
+        wait_lock: cli.wait_lock,
+        project_root: cli.project_root.clone(),
+        jobs: cli.jobs,
+        limit_rate: cli.limit_rate.clone(),
+        request_interval: cli.request_interval,
+    }
+    .install();
+    let name = matches.subcommand_name();
+    let command = name.unwrap_or("managed");
+    plugin::set_lock_operation(&format!("plm {}", command));
+
+    // Ctrl-C (SIGINT) で中断された場合は展開途中のファイルとロックを片付けてから終了する
+    interrupt::spawn_handler();
+    let result = commands::dispatch(cli, name).await;
+
+    if let Err(plm_err) = result {
         let rich: RichError = plm_err.into();
         let formatted = ErrorFormatter::new(verbose).format(&rich);
         eprintln!("{formatted}");
@@ -1,23 +1,29 @@
+mod cache_meta;
 mod config;
+mod diff;
 mod download;
 mod path;
 mod reference;
 mod registry;
+mod schema;
+mod sort;
 mod source_ref;
 
+pub use cache_meta::MarketplaceCacheMeta;
 pub use config::{
-    normalize_name, normalize_source_path, MarketplaceConfig, MarketplaceRegistration,
+    duplicate_source_warning, normalize_name, normalize_source_path, source_key, MarketplaceConfig,
+    MarketplaceRegistration,
 };
 pub use reference::{MarketplaceRef, DEFAULT_MARKETPLACE};
 // Re-exported for tests
 #[cfg(test)]
 pub use config::validate_name;
+pub use diff::UpdateDiff;
 pub use download::download_marketplace_plugin_with_cache;
 pub use path::PluginSourcePath;
 pub use registry::{
-    validate_plugin_names, MarketplaceCache, MarketplaceManifest, MarketplaceRegistry, PluginSource,
+    validate_plugin_name, validate_plugin_names, MarketplaceCache, MarketplaceManifest,
+    MarketplacePlugin, MarketplaceRegistry, PluginSource, PluginStats, ValidationIssue,
 };
-pub use source_ref::MarketplaceSourceRef;
-// Re-exported for tests
-#[cfg(test)]
-pub use registry::MarketplacePlugin;
+pub use sort::{sort_plugins, PluginSortKey};
+pub use source_ref::{MarketplaceSourceRef, SourceLocator};
@@ -0,0 +1,43 @@
+//! マーケットプレイスキャッシュのメタデータ
+//!
+//! 一覧表示に必要な情報（取得日時・プラグイン数・取得元）だけを
+//! `<cache_dir>/<name>/meta.json` に書き出す。add / update でキャッシュを保存するたびに
+//! 書き込むため、キャッシュ本体を読まなくても最終更新日時を確実に表示できる。
+
+use super::registry::MarketplaceCache;
+use super::source_ref::MarketplaceSourceRef;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// マーケットプレイスキャッシュのメタデータ（`meta.json`）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketplaceCacheMeta {
+    /// marketplace.json を取得した日時
+    pub fetched_at: DateTime<Utc>,
+    /// 取得したプラグイン数
+    pub plugin_count: usize,
+    pub source: MarketplaceSourceRef,
+    /// marketplace.json を取得したブランチ/タグ
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+}
+
+impl MarketplaceCacheMeta {
+    /// キャッシュ本体からメタデータを作る（`meta.json` が無い旧キャッシュのフォールバックにも使う）
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Marketplace cache entry.
+    pub fn from_cache(cache: &MarketplaceCache) -> Self {
+        Self {
+            fetched_at: cache.fetched_at,
+            plugin_count: cache.plugins.len(),
+            source: cache.source.clone(),
+            git_ref: cache.git_ref.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cache_meta_test.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+use crate::marketplace::MarketplaceRegistry;
+use chrono::TimeZone;
+
+fn sample_cache() -> MarketplaceCache {
+    MarketplaceCache {
+        name: "company-tools".to_string(),
+        fetched_at: Utc.with_ymd_and_hms(2026, 1, 1, 3, 34, 0).unwrap(),
+        source: "github:company/claude-plugins".parse().unwrap(),
+        git_ref: Some("v2".to_string()),
+        owner: None,
+        plugins: Vec::new(),
+    }
+}
+
+#[test]
+fn meta_round_trips_through_serde() {
+    let meta = MarketplaceCacheMeta::from_cache(&sample_cache());
+
+    let json = serde_json::to_value(&meta).unwrap();
+    assert_eq!(json["fetched_at"], "2026-01-01T03:34:00Z");
+    assert_eq!(json["plugin_count"], 0);
+    assert_eq!(json["source"], "github:company/claude-plugins");
+    assert_eq!(json["ref"], "v2");
+
+    let parsed: MarketplaceCacheMeta = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, meta);
+}
+
+#[test]
+fn meta_without_ref_omits_the_field() {
+    let meta = MarketplaceCacheMeta {
+        git_ref: None,
+        ..MarketplaceCacheMeta::from_cache(&sample_cache())
+    };
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(!json.contains("\"ref\""));
+    assert_eq!(
+        serde_json::from_str::<MarketplaceCacheMeta>(&json).unwrap(),
+        meta
+    );
+}
+
+#[test]
+fn store_writes_meta_and_remove_deletes_it() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let registry = MarketplaceRegistry::with_cache_dir(tmp.path().to_path_buf()).unwrap();
+    assert_eq!(registry.get_meta("company-tools").unwrap(), None);
+
+    let cache = sample_cache();
+    registry.store(&cache).unwrap();
+    assert_eq!(
+        registry.get_meta("company-tools").unwrap(),
+        Some(MarketplaceCacheMeta::from_cache(&cache))
+    );
+    // メタデータのディレクトリはマーケットプレイス一覧に含めない
+    assert_eq!(registry.list().unwrap(), ["company-tools"]);
+
+    registry.remove("company-tools").unwrap();
+    assert_eq!(registry.get_meta("company-tools").unwrap(), None);
+    assert!(!tmp.path().join("company-tools").exists());
+}
@@ -12,6 +12,9 @@ pub struct MarketplaceRegistration {
     pub source: MarketplaceSourceRef,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_path: Option<String>,
+    /// 取得するブランチ/タグ（未指定ならデフォルトブランチ）
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
 }
 
 /// marketplaces.json のルート構造
@@ -126,6 +129,25 @@ impl MarketplaceConfig {
         &self.marketplaces
     }
 
+    /// Look up a marketplace registration by source (owner/repo + path).
+    ///
+    /// 比較は [`source_key`] で正規化した上で行う。
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source repository to look up.
+    /// * `source_path` - Subdirectory where `marketplace.json` is located.
+    pub fn find_by_source(
+        &self,
+        source: &MarketplaceSourceRef,
+        source_path: Option<&str>,
+    ) -> Option<&MarketplaceRegistration> {
+        let key = source_key(&source.full_name(), source_path);
+        self.marketplaces
+            .iter()
+            .find(|e| source_key(&e.source.full_name(), e.source_path.as_deref()) == key)
+    }
+
     /// Check whether a marketplace with the given name exists.
     ///
     /// # Arguments
@@ -187,6 +209,37 @@ pub fn validate_name(name: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// ソース重複判定用のキー
+///
+/// owner/repo は大文字小文字と `.git` suffix を無視し、path は前後の `/` と `./` を無視する。
+///
+/// # Arguments
+///
+/// * `full_name` - Source repository in `owner/repo` form.
+/// * `source_path` - Subdirectory where `marketplace.json` is located.
+pub fn source_key(full_name: &str, source_path: Option<&str>) -> String {
+    let repo = full_name.trim().to_ascii_lowercase();
+    let repo = repo.strip_suffix(".git").unwrap_or(&repo);
+    let path = source_path
+        .map(|p| p.trim_matches('/'))
+        .map(|p| p.strip_prefix("./").unwrap_or(p).trim_matches('/'))
+        .filter(|p| !p.is_empty() && *p != ".")
+        .unwrap_or("");
+    format!("{}:{}", repo, path)
+}
+
+/// 同じソースを別名で登録しようとしたときの警告文
+///
+/// # Arguments
+///
+/// * `existing` - Name of the marketplace that already uses the source.
+pub fn duplicate_source_warning(existing: &str) -> String {
+    format!(
+        "'{}' already uses this source. Add anyway? (it will be fetched twice)",
+        existing
+    )
+}
+
 /// source_path の正規化
 ///
 /// # Arguments
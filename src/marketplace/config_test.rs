@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::marketplace::{
-        normalize_name, normalize_source_path, validate_name, MarketplaceConfig,
+        normalize_name, normalize_source_path, source_key, validate_name, MarketplaceConfig,
         MarketplaceRegistration,
     };
     use tempfile::TempDir;
@@ -168,6 +168,38 @@ mod tests {
         assert_eq!(config.list()[0].name, "test-mp");
     }
 
+    #[test]
+    fn config_ref_roundtrips_and_defaults_to_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("marketplaces.json");
+        std::fs::write(
+            &path,
+            r#"{"marketplaces":[{"name":"old","source":"owner/repo","source_path":"plugins"}]}"#,
+        )
+        .unwrap();
+
+        let mut config = MarketplaceConfig::load_from(path.clone()).unwrap();
+        assert_eq!(config.list()[0].git_ref, None);
+        config
+            .add(MarketplaceRegistration {
+                name: "pinned".to_string(),
+                source: "owner/mono".parse().unwrap(),
+                source_path: Some("plugins".to_string()),
+                git_ref: Some("v2".to_string()),
+            })
+            .unwrap();
+        config.save().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains(r#""ref": "v2""#), "{}", content);
+        assert_eq!(content.matches(r#""ref""#).count(), 1, "{}", content);
+        let reloaded = MarketplaceConfig::load_from(path).unwrap();
+        assert_eq!(
+            reloaded.get("pinned").unwrap().git_ref.as_deref(),
+            Some("v2")
+        );
+    }
+
     #[test]
     fn config_save_writes_to_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -178,6 +210,7 @@ mod tests {
             name: "my-mp".to_string(),
             source: "owner/repo".parse().unwrap(),
             source_path: None,
+            git_ref: None,
         };
         config.add(entry).unwrap();
         config.save().unwrap();
@@ -197,6 +230,7 @@ mod tests {
             name: "test-mp".to_string(),
             source: "owner/repo".parse().unwrap(),
             source_path: None,
+            git_ref: None,
         };
         config.add(entry).unwrap();
 
@@ -214,6 +248,7 @@ mod tests {
             name: "test-mp".to_string(),
             source: "owner/repo1".parse().unwrap(),
             source_path: None,
+            git_ref: None,
         };
         config.add(entry1).unwrap();
 
@@ -221,6 +256,7 @@ mod tests {
             name: "test-mp".to_string(),
             source: "owner/repo2".parse().unwrap(),
             source_path: None,
+            git_ref: None,
         };
         assert!(config.add(entry2).is_err());
     }
@@ -235,6 +271,7 @@ mod tests {
             name: "test-mp".to_string(),
             source: "owner/repo".parse().unwrap(),
             source_path: None,
+            git_ref: None,
         };
         config.add(entry).unwrap();
         config.remove("test-mp").unwrap();
@@ -262,6 +299,7 @@ mod tests {
             name: "test-mp".to_string(),
             source: "owner/repo".parse().unwrap(),
             source_path: Some("plugins".to_string()),
+            git_ref: None,
         };
         config.add(entry).unwrap();
 
@@ -291,6 +329,7 @@ mod tests {
                 name: "mp1".to_string(),
                 source: "owner/repo1".parse().unwrap(),
                 source_path: None,
+                git_ref: None,
             })
             .unwrap();
         config
@@ -298,6 +337,7 @@ mod tests {
                 name: "mp2".to_string(),
                 source: "owner/repo2".parse().unwrap(),
                 source_path: None,
+                git_ref: None,
             })
             .unwrap();
 
@@ -305,6 +345,47 @@ mod tests {
         assert_eq!(list.len(), 2);
     }
 
+    // ==================== find_by_source tests ====================
+
+    #[test]
+    fn source_key_ignores_case_git_suffix_and_path_slashes() {
+        assert_eq!(
+            source_key("Owner/Repo.git", Some("./plugins/")),
+            source_key("owner/repo", Some("plugins"))
+        );
+        assert_eq!(
+            source_key("owner/repo", Some("/")),
+            source_key("owner/repo", None)
+        );
+        assert_ne!(
+            source_key("owner/repo", Some("a")),
+            source_key("owner/repo", None)
+        );
+    }
+
+    #[test]
+    fn config_find_by_source_matches_normalized_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("marketplaces.json");
+        let mut config = MarketplaceConfig::load_from(path).unwrap();
+        config
+            .add(MarketplaceRegistration {
+                name: "mp-a".to_string(),
+                source: "owner/repo".parse().unwrap(),
+                source_path: Some("plugins".to_string()),
+                git_ref: None,
+            })
+            .unwrap();
+
+        let same = "OWNER/REPO".parse().unwrap();
+        let found = config.find_by_source(&same, Some("/plugins/"));
+        assert_eq!(found.map(|e| e.name.as_str()), Some("mp-a"));
+        assert!(config.find_by_source(&same, None).is_none());
+
+        let other = "owner/other".parse().unwrap();
+        assert!(config.find_by_source(&other, Some("plugins")).is_none());
+    }
+
     // ---- PLM_HOME path resolution (#344) ----
 
     use std::sync::{Mutex, OnceLock};
@@ -0,0 +1,112 @@
+//! マーケットプレイス更新前後のプラグイン差分
+//!
+//! 更新前にキャッシュしていた `MarketplaceCache` と取得し直した内容を比べ、
+//! 追加・削除・バージョン変更されたプラグインを求める。
+
+use crate::marketplace::registry::MarketplaceCache;
+use std::collections::BTreeMap;
+
+/// バージョン未記載のプラグインを表示するときの表記
+const NO_VERSION: &str = "-";
+
+/// 更新前後のプラグイン差分（各リストはプラグイン名順）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateDiff {
+    /// 新しく追加されたプラグイン
+    pub added: Vec<String>,
+    /// 削除されたプラグイン
+    pub removed: Vec<String>,
+    /// バージョンが変わったプラグイン（名前, 旧バージョン, 新バージョン）
+    pub changed: Vec<(String, String, String)>,
+}
+
+impl UpdateDiff {
+    /// 更新前後のキャッシュを比較する
+    ///
+    /// 更新前のキャッシュが無い（初回の取得）場合は全件を追加として扱う。
+    ///
+    /// # Arguments
+    ///
+    /// * `before` - Cache stored before the update, if any.
+    /// * `after` - Cache fetched by the update.
+    pub fn between(before: Option<&MarketplaceCache>, after: &MarketplaceCache) -> Self {
+        let old = before.map(plugin_versions).unwrap_or_default();
+        let new = plugin_versions(after);
+
+        let mut diff = Self::default();
+        for (name, version) in &new {
+            match old.get(name) {
+                None => diff.added.push(name.to_string()),
+                Some(old_version) if old_version != version => diff.changed.push((
+                    name.to_string(),
+                    old_version.to_string(),
+                    version.to_string(),
+                )),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .keys()
+            .filter(|name| !new.contains_key(*name))
+            .map(|name| name.to_string())
+            .collect();
+        diff
+    }
+
+    /// 差分が無いか
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// 別の差分を取り込む（複数マーケットプレイスの件数をまとめる用）
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Diff of another marketplace.
+    pub fn merge(&mut self, other: UpdateDiff) {
+        self.added.extend(other.added);
+        self.removed.extend(other.removed);
+        self.changed.extend(other.changed);
+    }
+
+    /// 件数の要約（例: `+3 new, 1 removed, 2 updated`）
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no changes".to_string();
+        }
+        format!(
+            "+{} new, {} removed, {} updated",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+
+    /// プラグインごとの差分行（`+ name` / `- name` / `~ name 1.0.0 -> 1.1.0`）
+    pub fn detail_lines(&self) -> Vec<String> {
+        let added = self.added.iter().map(|name| format!("+ {}", name));
+        let removed = self.removed.iter().map(|name| format!("- {}", name));
+        let changed = self
+            .changed
+            .iter()
+            .map(|(name, from, to)| format!("~ {} {} -> {}", name, from, to));
+        added.chain(removed).chain(changed).collect()
+    }
+}
+
+/// プラグイン名 → バージョンの対応表
+///
+/// # Arguments
+///
+/// * `cache` - Marketplace cache to index.
+fn plugin_versions(cache: &MarketplaceCache) -> BTreeMap<&str, &str> {
+    cache
+        .plugins
+        .iter()
+        .map(|p| (p.name.as_str(), p.version.as_deref().unwrap_or(NO_VERSION)))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "diff_test.rs"]
+mod tests;
@@ -0,0 +1,88 @@
+use super::*;
+use crate::marketplace::{MarketplacePlugin, PluginSource};
+use chrono::Utc;
+
+fn plugin(name: &str, version: Option<&str>) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source: PluginSource::Local(format!("./plugins/{}", name)),
+        description: None,
+        version: version.map(str::to_string),
+        renamed_from: vec![],
+        stats: Default::default(),
+    }
+}
+
+fn cache(plugins: Vec<MarketplacePlugin>) -> MarketplaceCache {
+    MarketplaceCache {
+        name: "company-tools".to_string(),
+        fetched_at: Utc::now(),
+        source: "github:company/tools".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins,
+    }
+}
+
+#[test]
+fn between_reports_added_removed_and_changed() {
+    let before = cache(vec![
+        plugin("formatter", Some("1.0.0")),
+        plugin("linter", Some("0.1.0")),
+        plugin("old", None),
+    ]);
+    let after = cache(vec![
+        plugin("formatter", Some("1.1.0")),
+        plugin("linter", Some("0.1.0")),
+        plugin("reviewer", Some("2.0.0")),
+    ]);
+
+    let diff = UpdateDiff::between(Some(&before), &after);
+
+    assert_eq!(diff.added, vec!["reviewer".to_string()]);
+    assert_eq!(diff.removed, vec!["old".to_string()]);
+    assert_eq!(
+        diff.changed,
+        vec![(
+            "formatter".to_string(),
+            "1.0.0".to_string(),
+            "1.1.0".to_string()
+        )]
+    );
+    assert_eq!(diff.summary(), "+1 new, 1 removed, 1 updated");
+    assert_eq!(
+        diff.detail_lines(),
+        vec!["+ reviewer", "- old", "~ formatter 1.0.0 -> 1.1.0"]
+    );
+}
+
+#[test]
+fn between_without_previous_cache_treats_all_as_added() {
+    let after = cache(vec![plugin("b", None), plugin("a", Some("1.0.0"))]);
+
+    let diff = UpdateDiff::between(None, &after);
+
+    assert_eq!(diff.added, vec!["a".to_string(), "b".to_string()]);
+    assert!(diff.removed.is_empty());
+    assert!(diff.changed.is_empty());
+}
+
+#[test]
+fn between_empty_caches_has_no_changes() {
+    let diff = UpdateDiff::between(Some(&cache(vec![])), &cache(vec![]));
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.summary(), "no changes");
+    assert!(diff.detail_lines().is_empty());
+}
+
+#[test]
+fn merge_sums_counts() {
+    let mut diff = UpdateDiff::between(None, &cache(vec![plugin("a", None)]));
+    diff.merge(UpdateDiff::between(
+        Some(&cache(vec![plugin("b", None)])),
+        &cache(vec![]),
+    ));
+
+    assert_eq!(diff.summary(), "+1 new, 1 removed, 0 updated");
+}
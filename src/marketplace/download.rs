@@ -54,7 +54,7 @@ async fn download_marketplace_plugin_with_registry(
 
     let cached = match &plugin_entry.source {
         MpPluginSource::Local(path) => {
-            let repo = mp_cache.source.to_repo();
+            let repo = mp_cache.source_repo()?;
             let source_path: PluginSourcePath = path.parse()?;
 
             GitHubSource::with_marketplace_plugin(
@@ -38,6 +38,7 @@ async fn test_download_with_registry_plugin_not_found() {
             name: "test-marketplace".to_string(),
             fetched_at: Utc::now(),
             source: "github:test/repo".parse().unwrap(),
+            git_ref: None,
             owner: None,
             plugins: vec![],
         })
@@ -60,3 +61,48 @@ async fn test_download_with_registry_plugin_not_found() {
         result
     );
 }
+
+#[tokio::test]
+async fn test_download_with_registry_rejects_relative_path_outside_repository() {
+    // 相対パスソースがマーケットプレイスのリポジトリ外を指す場合は取得前にエラーにする
+    use crate::marketplace::{MarketplacePlugin, PluginSource};
+    use crate::plugin::PackageCacheAccess;
+
+    let temp_dir = TempDir::new().unwrap();
+    let registry = MarketplaceRegistry::with_cache_dir(temp_dir.path().join("mkt")).unwrap();
+    registry
+        .store(&MarketplaceCache {
+            name: "test-marketplace".to_string(),
+            fetched_at: Utc::now(),
+            source: "github:test/repo".parse().unwrap(),
+            git_ref: None,
+            owner: None,
+            plugins: vec![MarketplacePlugin {
+                name: "escape".to_string(),
+                source: PluginSource::Local("./plugins/../../escape".to_string()),
+                description: None,
+                version: None,
+                renamed_from: Vec::new(),
+                stats: Default::default(),
+            }],
+        })
+        .unwrap();
+
+    let cache = PackageCache::with_cache_dir(temp_dir.path().join("plugins")).unwrap();
+
+    let result = download_marketplace_plugin_with_registry(
+        "escape",
+        "test-marketplace",
+        false,
+        &cache,
+        &registry,
+    )
+    .await;
+
+    assert!(
+        matches!(result, Err(PlmError::InvalidSource(_))),
+        "Expected InvalidSource but got: {:?}",
+        result
+    );
+    assert!(!cache.is_cached(Some("test-marketplace"), "escape"));
+}
@@ -1,16 +1,22 @@
 use crate::error::{PlmError, Result};
-use crate::host::HostClient;
+use crate::host::{HostClient, HostClientFactory};
 use crate::marketplace::config::normalize_name;
-use crate::marketplace::MarketplaceSourceRef;
-use crate::repo::Repo;
+use crate::marketplace::schema;
+use crate::marketplace::{MarketplaceCacheMeta, MarketplaceSourceRef, PluginSourcePath};
+use crate::repo::{self, Repo};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const MARKETPLACE_MANIFEST_FILE: &str = ".claude-plugin/marketplace.json";
 
+/// キャッシュのメタデータファイル名（`<cache_dir>/<name>/` 配下）
+const CACHE_META_FILE: &str = "meta.json";
+
 /// マーケットプレイスオーナー情報
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketplaceOwner {
@@ -38,6 +44,26 @@ pub struct MarketplacePlugin {
     pub description: Option<String>,
     #[serde(default)]
     pub version: Option<String>,
+    /// 旧プラグイン名（マーケットプレイス側でリネームされた場合）
+    #[serde(default, rename = "renamedFrom", skip_serializing_if = "Vec::is_empty")]
+    pub renamed_from: Vec<String>,
+    /// 並び替え用の任意メタデータ（PLM 拡張）
+    #[serde(flatten)]
+    pub stats: PluginStats,
+}
+
+/// 並び替え用のプラグインメタデータ（すべて任意。無いエントリはソートで末尾に回す）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginStats {
+    /// インストール数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<u64>,
+    /// スター数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stars: Option<u64>,
+    /// 最終更新日時（RFC 3339）
+    #[serde(default, rename = "updatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 /// プラグイン検索結果（marketplace + plugin のペア）
@@ -62,6 +88,9 @@ pub struct MarketplaceCache {
     pub name: String,
     pub fetched_at: DateTime<Utc>,
     pub source: MarketplaceSourceRef,
+    /// marketplace.json を取得したブランチ/タグ（リポジトリ内のプラグインも同じ ref から取得する）
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
     #[serde(default)]
     pub owner: Option<MarketplaceOwner>,
     pub plugins: Vec<MarketplacePlugin>,
@@ -103,7 +132,337 @@ pub fn validate_plugin_names(plugins: &[MarketplacePlugin]) -> Result<()> {
     Ok(())
 }
 
+/// marketplace.json の検証で見つかった問題の重大度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    /// 該当エントリだけが使えない。一覧表示して続行する
+    Warning,
+    /// マニフェスト全体を受け付けない。add / update を中断する
+    Error,
+}
+
+/// marketplace.json の検証で見つかった問題（`plugins[3].source: ...` の形で表示する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: IssueSeverity,
+    /// 問題のある位置（`plugins[3].source` など）
+    pub location: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    /// # Arguments
+    ///
+    /// * `location` - JSON location of the problem.
+    /// * `message` - Description of the problem.
+    pub(crate) fn error(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// * `location` - JSON location of the problem.
+    /// * `message` - Description of the problem.
+    pub(crate) fn warning(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+
+    /// エラーレベルか
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// marketplace.json の内容を検証する
+///
+/// - エラー: 名前の形式（[`validate_plugin_name`] / `normalize_name` と同じ規則）、
+///   `normalize_name` 後の重複
+/// - 警告: source のパス・リポジトリ指定が不正、未対応の source 種別、`renamedFrom` の名前が不正
+///
+/// JSON の型や必須フィールドの欠落は、デシリアライズ前に同じ形式で報告する。
+///
+/// # Arguments
+///
+/// * `manifest` - Parsed marketplace manifest.
+pub fn validate_manifest(manifest: &MarketplaceManifest) -> Vec<ValidationIssue> {
+    validate_plugins(&manifest.plugins)
+}
+
+/// プラグインエントリを検証する（[`validate_manifest`] の本体）
+///
+/// # Arguments
+///
+/// * `plugins` - Plugin entries in manifest order.
+fn validate_plugins(plugins: &[MarketplacePlugin]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for (i, plugin) in plugins.iter().enumerate() {
+        let at = |field: &str| format!("plugins[{}].{}", i, field);
+
+        match check_name(&plugin.name) {
+            Err(message) => issues.push(ValidationIssue::error(at("name"), message)),
+            Ok(normalized) => {
+                if let Some(first) = seen.get(&normalized) {
+                    issues.push(ValidationIssue::error(
+                        at("name"),
+                        format!(
+                            "duplicate plugin name '{}' (same as plugins[{}])",
+                            plugin.name, first
+                        ),
+                    ));
+                } else {
+                    seen.insert(normalized, i);
+                }
+            }
+        }
+
+        match &plugin.source {
+            PluginSource::Local(path) => {
+                if let Err(e) = path.parse::<PluginSourcePath>() {
+                    issues.push(ValidationIssue::warning(at("source"), error_detail(e)));
+                }
+            }
+            PluginSource::External { source, repo } => {
+                if source != "github" {
+                    issues.push(ValidationIssue::warning(
+                        at("source.source"),
+                        format!("unsupported source type '{}' (expected 'github')", source),
+                    ));
+                }
+                if let Err(e) = repo::from_url(repo) {
+                    issues.push(ValidationIssue::warning(at("source.repo"), error_detail(e)));
+                }
+            }
+        }
+
+        for (j, old) in plugin.renamed_from.iter().enumerate() {
+            if let Err(message) = check_name(old) {
+                issues.push(ValidationIssue::warning(
+                    at(&format!("renamedFrom[{}]", j)),
+                    message,
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// プラグイン名を検証し、正規化した名前を返す
+///
+/// # Arguments
+///
+/// * `name` - Plugin name from the manifest.
+fn check_name(name: &str) -> std::result::Result<String, String> {
+    validate_plugin_name(name).map_err(|_| {
+        format!(
+            "invalid plugin name '{}': must not be empty or contain path separators",
+            name
+        )
+    })?;
+    normalize_name(name).map_err(|msg| format!("invalid plugin name '{}': {}", name, msg))
+}
+
+/// エラーの説明部分（`Invalid source: ` などの接頭辞を除く）
+///
+/// # Arguments
+///
+/// * `error` - Error returned by a source parser.
+fn error_detail(error: PlmError) -> String {
+    match error {
+        PlmError::InvalidSource(msg) | PlmError::Validation(msg) => msg,
+        e => e.to_string(),
+    }
+}
+
+/// JSON の型や必須フィールドを検証する（デシリアライズ前）
+///
+/// serde のエラーは最初の 1 件しか分からず位置も曖昧なため、マイグレーション後の
+/// JSON を走査して問題をすべて位置付きで集める。
+///
+/// # Arguments
+///
+/// * `value` - Marketplace manifest JSON in the current schema.
+fn check_structure(value: &Value) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let Some(object) = value.as_object() else {
+        issues.push(ValidationIssue::error("$", "expected an object"));
+        return issues;
+    };
+
+    require_string(object, "name", "name", &mut issues);
+    match object.get("owner") {
+        None | Some(Value::Null) => {}
+        Some(Value::Object(owner)) => require_string(owner, "name", "owner.name", &mut issues),
+        Some(other) => issues.push(type_mismatch("owner", "an object", other)),
+    }
+
+    let plugins = match object.get("plugins") {
+        None => {
+            issues.push(ValidationIssue::error("plugins", "missing required field"));
+            return issues;
+        }
+        Some(Value::Array(plugins)) => plugins,
+        Some(other) => {
+            issues.push(type_mismatch("plugins", "an array", other));
+            return issues;
+        }
+    };
+    for (i, entry) in plugins.iter().enumerate() {
+        let at = format!("plugins[{}]", i);
+        let Some(entry) = entry.as_object() else {
+            issues.push(type_mismatch(&at, "an object", entry));
+            continue;
+        };
+        require_string(entry, "name", &format!("{}.name", at), &mut issues);
+        check_source(entry.get("source"), &format!("{}.source", at), &mut issues);
+        for field in ["description", "version", "updatedAt"] {
+            match entry.get(field) {
+                None | Some(Value::Null) | Some(Value::String(_)) => {}
+                Some(other) => issues.push(type_mismatch(
+                    &format!("{}.{}", at, field),
+                    "a string",
+                    other,
+                )),
+            }
+        }
+        for field in ["downloads", "stars"] {
+            match entry.get(field) {
+                None | Some(Value::Null) => {}
+                Some(Value::Number(n)) if n.is_u64() => {}
+                Some(other) => issues.push(type_mismatch(
+                    &format!("{}.{}", at, field),
+                    "a non-negative integer",
+                    other,
+                )),
+            }
+        }
+        match entry.get("renamedFrom") {
+            None | Some(Value::Null) => {}
+            Some(Value::Array(names)) => {
+                for (j, name) in names.iter().enumerate() {
+                    if !name.is_string() {
+                        issues.push(type_mismatch(
+                            &format!("{}.renamedFrom[{}]", at, j),
+                            "a string",
+                            name,
+                        ));
+                    }
+                }
+            }
+            Some(other) => issues.push(type_mismatch(
+                &format!("{}.renamedFrom", at),
+                "an array",
+                other,
+            )),
+        }
+    }
+    issues
+}
+
+/// `source`（相対パス文字列か `{ "source", "repo" }`）を検証する
+///
+/// # Arguments
+///
+/// * `source` - Value of the `source` field, if present.
+/// * `at` - Location of the field.
+/// * `issues` - Issues found so far.
+fn check_source(source: Option<&Value>, at: &str, issues: &mut Vec<ValidationIssue>) {
+    match source {
+        None => issues.push(ValidationIssue::error(at, "missing required field")),
+        Some(Value::String(_)) => {}
+        Some(Value::Object(external)) => {
+            require_string(external, "source", &format!("{}.source", at), issues);
+            require_string(external, "repo", &format!("{}.repo", at), issues);
+        }
+        Some(other) => issues.push(type_mismatch(
+            at,
+            "a path string or an object with 'source' and 'repo'",
+            other,
+        )),
+    }
+}
+
+/// 必須の文字列フィールドを検証する
+///
+/// # Arguments
+///
+/// * `object` - JSON object containing the field.
+/// * `key` - Field name.
+/// * `at` - Location of the field.
+/// * `issues` - Issues found so far.
+fn require_string(
+    object: &Map<String, Value>,
+    key: &str,
+    at: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    match object.get(key) {
+        None => issues.push(ValidationIssue::error(at, "missing required field")),
+        Some(Value::String(_)) => {}
+        Some(other) => issues.push(type_mismatch(at, "a string", other)),
+    }
+}
+
+/// 型の不一致
+///
+/// # Arguments
+///
+/// * `at` - Location of the value.
+/// * `expected` - Description of the expected type.
+/// * `found` - Actual value.
+fn type_mismatch(at: &str, expected: &str, found: &Value) -> ValidationIssue {
+    let found = match found {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+    ValidationIssue::error(at, format!("expected {}, found {}", expected, found))
+}
+
+/// エラーレベルの問題を 1 つのエラーにまとめる
+///
+/// # Arguments
+///
+/// * `issues` - Issues found in the manifest.
+fn invalid_manifest(issues: &[ValidationIssue]) -> PlmError {
+    let lines: Vec<String> = issues
+        .iter()
+        .filter(|issue| issue.is_error())
+        .map(|issue| format!("  {}", issue))
+        .collect();
+    PlmError::InvalidManifest(format!(
+        "marketplace.json has {} error(s):\n{}",
+        lines.len(),
+        lines.join("\n")
+    ))
+}
+
 impl MarketplaceCache {
+    /// 警告レベルの問題（add / update で一覧表示し、TUI の Errors タブに表示する）
+    pub fn validation_warnings(&self) -> Vec<ValidationIssue> {
+        validate_plugins(&self.plugins)
+            .into_iter()
+            .filter(|issue| !issue.is_error())
+            .collect()
+    }
+
     /// MarketplaceManifest とメタ情報から MarketplaceCache を構築する。
     ///
     /// - `source` は `repo` 引数の owner / name から構築（保存形式は `"github:{owner}/{name}"`）
@@ -115,14 +474,40 @@ impl MarketplaceCache {
     /// * `name` - Marketplace name assigned to the resulting cache entry.
     /// * `repo` - Source repository used to compose the `source` field.
     pub fn from_manifest(manifest: MarketplaceManifest, name: &str, repo: &Repo) -> Self {
+        Self {
+            git_ref: repo.git_ref().map(str::to_string),
+            ..Self::from_source(manifest, name, MarketplaceSourceRef::from_repo(repo))
+        }
+    }
+
+    /// 取得元を指定して MarketplaceCache を構築する（ローカルディレクトリ用）
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Source manifest to convert (consumes `owner` / `plugins`).
+    /// * `name` - Marketplace name assigned to the resulting cache entry.
+    /// * `source` - Source the manifest was read from.
+    pub fn from_source(
+        manifest: MarketplaceManifest,
+        name: &str,
+        source: MarketplaceSourceRef,
+    ) -> Self {
         Self {
             name: name.to_string(),
             fetched_at: Utc::now(),
-            source: MarketplaceSourceRef::from_repo(repo),
+            source,
+            git_ref: None,
             owner: manifest.owner,
             plugins: manifest.plugins,
         }
     }
+
+    /// marketplace.json を取得したリポジトリ（登録時の ref 付き）
+    ///
+    /// ローカルディレクトリの場合はエラー。
+    pub fn source_repo(&self) -> Result<Repo> {
+        self.source.to_repo_with_ref(self.git_ref.clone())
+    }
 }
 
 /// マーケットプレイスレジストリ
@@ -167,6 +552,15 @@ impl MarketplaceRegistry {
         self.cache_dir.join(format!("{}.json", name))
     }
 
+    /// メタデータファイルパスを取得（`<cache_dir>/<name>/meta.json`）
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Marketplace name whose metadata path is requested.
+    fn meta_path(&self, name: &str) -> PathBuf {
+        self.cache_dir.join(name).join(CACHE_META_FILE)
+    }
+
     /// キャッシュを取得
     ///
     /// # Arguments
@@ -179,24 +573,49 @@ impl MarketplaceRegistry {
         }
 
         let content = fs::read_to_string(&path)?;
-        let cache: MarketplaceCache = serde_json::from_str(&content)?;
+        let cache: MarketplaceCache = schema::parse(&content)?;
         validate_plugin_names(&cache.plugins)?;
         Ok(Some(cache))
     }
 
     /// キャッシュを保存
     ///
+    /// 読み込んだ形式にかかわらず、常に最新の `schemaVersion` の形式で書き込む。
+    ///
     /// # Arguments
     ///
     /// * `cache` - Marketplace cache entry to persist to disk.
     pub fn store(&self, cache: &MarketplaceCache) -> Result<()> {
         validate_plugin_names(&cache.plugins)?;
         let path = self.cache_path(&cache.name);
-        let content = serde_json::to_string_pretty(cache)?;
+        let content = schema::to_string_pretty(cache)?;
         fs::write(path, content)?;
+
+        let meta_path = self.meta_path(&cache.name);
+        if let Some(parent) = meta_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let meta = serde_json::to_string_pretty(&MarketplaceCacheMeta::from_cache(cache))?;
+        fs::write(meta_path, meta)?;
         Ok(())
     }
 
+    /// キャッシュのメタデータを取得
+    ///
+    /// `meta.json` が無い（メタデータ導入前に保存された）場合は `Ok(None)`。
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Marketplace name whose metadata should be loaded.
+    pub fn get_meta(&self, name: &str) -> Result<Option<MarketplaceCacheMeta>> {
+        let path = self.meta_path(name);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
     /// キャッシュを削除
     ///
     /// # Arguments
@@ -207,6 +626,10 @@ impl MarketplaceRegistry {
         if path.exists() {
             fs::remove_file(path)?;
         }
+        let meta_dir = self.cache_dir.join(name);
+        if meta_dir.is_dir() {
+            fs::remove_dir_all(meta_dir)?;
+        }
         Ok(())
     }
 
@@ -288,6 +711,7 @@ impl MarketplaceRegistry {
     /// MarketplaceCache に変換して返す。永続化（store）はしない。
     ///
     /// 呼び出し元が必要なタイミングで `self.store(&cache)` を呼ぶ。
+    /// 旧形式の marketplace.json は最新形式へマイグレーションしてから変換する。
     ///
     /// # Arguments
     ///
@@ -313,12 +737,99 @@ impl MarketplaceRegistry {
         };
 
         let content = client.fetch_file(repo, &path).await?;
-        let manifest: MarketplaceManifest = serde_json::from_str(&content).map_err(|e| {
-            PlmError::InvalidManifest(format!("Failed to parse marketplace.json: {}", e))
-        })?;
+        let manifest = parse_manifest(&content)?;
 
         Ok(MarketplaceCache::from_manifest(manifest, name, repo))
     }
+
+    /// ローカルディレクトリの marketplace.json を読み込み、MarketplaceCache に変換して返す。
+    /// 永続化（store）はしない。
+    ///
+    /// marketplace.json が無い場合はエラー。
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - 登録するマーケットプレイス名。
+    /// * `dir` - マーケットプレイスのディレクトリ（絶対パス）。
+    /// * `source_path` - サブディレクトリパス（[`Self::fetch_cache`] と同じ扱い）。
+    pub fn read_local_cache(
+        &self,
+        name: &str,
+        dir: &Path,
+        source_path: Option<&str>,
+    ) -> Result<MarketplaceCache> {
+        let base = match source_path {
+            Some(sub) => dir.join(sub),
+            None => dir.to_path_buf(),
+        };
+        let path = base.join(MARKETPLACE_MANIFEST_FILE);
+        if !path.is_file() {
+            return Err(PlmError::InvalidSource(format!(
+                "marketplace.json not found: {}",
+                path.display()
+            )));
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let manifest = parse_manifest(&content)?;
+        let source = MarketplaceSourceRef::from_local_dir(dir)?;
+
+        Ok(MarketplaceCache::from_source(manifest, name, source))
+    }
+
+    /// 登録済みの取得元から MarketplaceCache を取得する（永続化はしない）
+    ///
+    /// ローカルディレクトリは GitHub API を使わずに marketplace.json を読み直し、
+    /// GitHub リポジトリは [`Self::fetch_cache`] で取得する。
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - 登録するマーケットプレイス名。
+    /// * `source` - マーケットプレイスの取得元。
+    /// * `source_path` - サブディレクトリパス。
+    /// * `git_ref` - ブランチ/タグ（`None` ならデフォルトブランチ。ローカルディレクトリでは無視）。
+    pub async fn fetch_source(
+        &self,
+        name: &str,
+        source: &MarketplaceSourceRef,
+        source_path: Option<&str>,
+        git_ref: Option<&str>,
+    ) -> Result<MarketplaceCache> {
+        if let Some(dir) = source.local_path() {
+            return self.read_local_cache(name, dir, source_path);
+        }
+        let repo = source.to_repo_with_ref(git_ref.map(str::to_string))?;
+        let client = HostClientFactory::from_env()?.create(repo.host());
+        self.fetch_cache(&*client, name, &repo, source_path).await
+    }
+}
+
+/// marketplace.json をパースする（旧形式は最新形式へマイグレーションする）
+///
+/// 型の不一致・必須フィールドの欠落・[`validate_manifest`] のエラーは位置付きで
+/// まとめて [`PlmError::InvalidManifest`] にする。警告はここでは扱わない。
+///
+/// # Arguments
+///
+/// * `content` - Raw marketplace.json content.
+fn parse_manifest(content: &str) -> Result<MarketplaceManifest> {
+    let value: Value = serde_json::from_str(content).map_err(|e| {
+        PlmError::InvalidManifest(format!("Failed to parse marketplace.json: {}", e))
+    })?;
+    let value = schema::migrate(value)?;
+
+    let issues = check_structure(&value);
+    if !issues.is_empty() {
+        return Err(invalid_manifest(&issues));
+    }
+    let manifest: MarketplaceManifest = serde_json::from_value(value).map_err(|e| {
+        PlmError::InvalidManifest(format!("Failed to parse marketplace.json: {}", e))
+    })?;
+    let issues = validate_manifest(&manifest);
+    if issues.iter().any(ValidationIssue::is_error) {
+        return Err(invalid_manifest(&issues));
+    }
+    Ok(manifest)
 }
 
 impl Default for MarketplaceRegistry {
@@ -89,6 +89,13 @@ impl HostClient for MockHostClient {
         };
         Box::pin(async move { result })
     }
+
+    fn list_tags<'a>(
+        &'a self,
+        _repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>> {
+        Box::pin(async { Ok(vec![]) })
+    }
 }
 
 fn sample_repo() -> Repo {
@@ -107,6 +114,8 @@ fn sample_manifest() -> MarketplaceManifest {
             source: PluginSource::Local("./plugins/plugin-a".to_string()),
             description: Some("A test plugin".to_string()),
             version: Some("0.1.0".to_string()),
+            renamed_from: vec![],
+            stats: Default::default(),
         }],
     }
 }
@@ -135,6 +144,24 @@ fn from_manifest_sets_source_as_github_owner_name() {
     assert_eq!(cache.source.to_string(), "github:acme/catalog");
 }
 
+#[test]
+fn from_manifest_keeps_git_ref_for_bundled_plugins() {
+    let cache = MarketplaceCache::from_manifest(
+        sample_manifest(),
+        "catalog",
+        &sample_repo().with_ref("v2"),
+    );
+    assert_eq!(cache.git_ref.as_deref(), Some("v2"));
+    assert_eq!(cache.source_repo().unwrap().git_ref(), Some("v2"));
+
+    let json = serde_json::to_string(&cache).unwrap();
+    assert!(json.contains(r#""ref":"v2""#), "{}", json);
+
+    let unpinned = MarketplaceCache::from_manifest(sample_manifest(), "catalog", &sample_repo());
+    let json = serde_json::to_string(&unpinned).unwrap();
+    assert!(!json.contains(r#""ref""#), "{}", json);
+}
+
 #[test]
 fn from_manifest_propagates_owner_and_plugins() {
     let manifest = sample_manifest();
@@ -287,6 +314,44 @@ async fn fetch_cache_does_not_persist_anything() {
     );
 }
 
+#[tokio::test]
+async fn fetch_source_reads_local_marketplace_without_host_client() {
+    let (registry, _tmp) = temp_registry();
+    let dir = TempDir::new().unwrap();
+    let manifest_dir = dir.path().join("sub").join(".claude-plugin");
+    fs::create_dir_all(&manifest_dir).unwrap();
+    fs::write(
+        manifest_dir.join("marketplace.json"),
+        sample_manifest_json(),
+    )
+    .unwrap();
+    let source = MarketplaceSourceRef::from_local_dir(dir.path()).unwrap();
+
+    let cache = registry
+        .fetch_source("local", &source, Some("sub"), None)
+        .await
+        .expect("local marketplace should be read");
+
+    assert_eq!(cache.source, source);
+    assert_eq!(cache.plugins[0].name, "plugin-a");
+}
+
+#[test]
+fn read_local_cache_errors_without_marketplace_json() {
+    let (registry, _tmp) = temp_registry();
+    let dir = TempDir::new().unwrap();
+
+    let err = registry
+        .read_local_cache("local", dir.path(), None)
+        .expect_err("missing marketplace.json should fail");
+
+    assert!(
+        err.to_string().contains("marketplace.json not found"),
+        "{}",
+        err
+    );
+}
+
 #[tokio::test]
 async fn fetch_cache_with_empty_source_path_preserves_legacy_path() {
     let (registry, _tmp) = temp_registry();
@@ -336,6 +401,258 @@ fn parse_legacy_cache_with_original_manifest_ignores_unknown_field() {
     assert!(cache.plugins.is_empty());
 }
 
+#[tokio::test]
+async fn fetch_cache_rejects_newer_schema_version() {
+    let (registry, _tmp) = temp_registry();
+    let client =
+        MockHostClient::with_body(r#"{"schemaVersion": 99, "name": "catalog", "plugins": []}"#);
+
+    let err = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .expect_err("newer schemaVersion should fail");
+
+    assert!(
+        err.to_string()
+            .contains("this marketplace requires a newer plm"),
+        "got: {err}"
+    );
+}
+
+#[tokio::test]
+async fn fetch_cache_reads_optional_sort_metadata() {
+    let (registry, _tmp) = temp_registry();
+    let client = MockHostClient::with_body(
+        r#"{
+          "name": "catalog",
+          "plugins": [
+            {"name": "a", "source": "./a", "downloads": 120, "stars": 8, "updatedAt": "2024-06-01T00:00:00Z"},
+            {"name": "b", "source": "./b"}
+          ]
+        }"#,
+    );
+
+    let cache = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(cache.plugins[0].stats.downloads, Some(120));
+    assert_eq!(cache.plugins[0].stats.stars, Some(8));
+    assert_eq!(
+        cache.plugins[0].stats.updated_at.as_deref(),
+        Some("2024-06-01T00:00:00Z")
+    );
+    assert_eq!(cache.plugins[1].stats, PluginStats::default());
+}
+
+// ---- validate_manifest / parse_manifest ----
+
+fn plugin(name: &str, source: PluginSource) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source,
+        description: None,
+        version: None,
+        renamed_from: vec![],
+        stats: Default::default(),
+    }
+}
+
+fn issue_lines(issues: &[ValidationIssue]) -> Vec<String> {
+    issues.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn validate_manifest_accepts_sample_manifest() {
+    assert!(validate_manifest(&sample_manifest()).is_empty());
+}
+
+#[test]
+fn validate_manifest_reports_names_as_errors_with_location() {
+    let mut manifest = sample_manifest();
+    manifest.plugins = vec![
+        plugin("plugin-a", PluginSource::Local("./a".to_string())),
+        plugin("bad name", PluginSource::Local("./b".to_string())),
+        plugin("Plugin-A", PluginSource::Local("./c".to_string())),
+        plugin("../escape", PluginSource::Local("./d".to_string())),
+    ];
+
+    let issues = validate_manifest(&manifest);
+
+    assert!(issues.iter().all(ValidationIssue::is_error));
+    let lines = issue_lines(&issues);
+    assert_eq!(lines.len(), 3, "{lines:?}");
+    assert!(lines[0].starts_with("plugins[1].name: invalid plugin name 'bad name'"));
+    assert_eq!(
+        lines[1],
+        "plugins[2].name: duplicate plugin name 'Plugin-A' (same as plugins[0])"
+    );
+    assert!(lines[2].starts_with("plugins[3].name: invalid plugin name '../escape'"));
+}
+
+#[test]
+fn validate_manifest_reports_sources_as_warnings() {
+    let mut manifest = sample_manifest();
+    manifest.plugins = vec![
+        plugin("outside", PluginSource::Local("../outside".to_string())),
+        plugin(
+            "gitlab",
+            PluginSource::External {
+                source: "gitlab".to_string(),
+                repo: "org/repo".to_string(),
+            },
+        ),
+        plugin(
+            "no-repo",
+            PluginSource::External {
+                source: "github".to_string(),
+                repo: "just-a-name".to_string(),
+            },
+        ),
+    ];
+
+    let issues = validate_manifest(&manifest);
+
+    assert!(issues.iter().all(|i| i.severity == IssueSeverity::Warning));
+    let locations: Vec<&str> = issues.iter().map(|i| i.location.as_str()).collect();
+    assert_eq!(
+        locations,
+        vec![
+            "plugins[0].source",
+            "plugins[1].source.source",
+            "plugins[2].source.repo"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn fetch_cache_reports_structure_errors_with_location() {
+    let (registry, _tmp) = temp_registry();
+    let client = MockHostClient::with_body(
+        r#"{
+          "name": "catalog",
+          "plugins": [
+            {"name": "ok", "source": "./ok"},
+            {"name": "no-source"},
+            {"name": 3, "source": 42, "version": 1}
+          ]
+        }"#,
+    );
+
+    let err = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .expect_err("invalid entries should fail");
+
+    let msg = err.to_string();
+    assert!(msg.contains("marketplace.json has 4 error(s)"), "{msg}");
+    assert!(
+        msg.contains("plugins[1].source: missing required field"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("plugins[2].name: expected a string, found a number"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("plugins[2].source: expected a path string"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("plugins[2].version: expected a string, found a number"),
+        "{msg}"
+    );
+}
+
+#[tokio::test]
+async fn fetch_cache_rejects_negative_download_counts() {
+    let (registry, _tmp) = temp_registry();
+    let client = MockHostClient::with_body(
+        r#"{"name": "catalog", "plugins": [{"name": "a", "source": "./a", "downloads": -1}]}"#,
+    );
+
+    let err = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .expect_err("negative downloads should fail");
+
+    assert!(
+        err.to_string()
+            .contains("plugins[0].downloads: expected a non-negative integer, found a number"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn fetch_cache_reports_plugins_object_instead_of_array() {
+    let (registry, _tmp) = temp_registry();
+    let client =
+        MockHostClient::with_body(r#"{"name": "catalog", "plugins": {"a": {"name": "a"}}}"#);
+
+    let err = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .expect_err("plugins object should fail");
+
+    assert!(
+        err.to_string()
+            .contains("plugins: expected an array, found an object"),
+        "got: {err}"
+    );
+}
+
+#[tokio::test]
+async fn fetch_cache_keeps_warnings_for_callers() {
+    let (registry, _tmp) = temp_registry();
+    let client = MockHostClient::with_body(
+        r#"{
+          "name": "catalog",
+          "plugins": [
+            {"name": "ok", "source": "./ok"},
+            {"name": "outside", "source": "../outside"}
+          ]
+        }"#,
+    );
+
+    let cache = registry
+        .fetch_cache(&client, "catalog", &sample_repo(), None)
+        .await
+        .expect("warnings must not fail the fetch");
+
+    assert_eq!(cache.plugins.len(), 2);
+    let warnings = cache.validation_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].location, "plugins[1].source");
+}
+
+#[test]
+fn get_migrates_legacy_cache_and_store_writes_current_schema() {
+    let (registry, tmp) = temp_registry();
+    let path = tmp.path().join("legacy.json");
+    fs::write(
+        &path,
+        r#"{
+            "name": "legacy",
+            "fetched_at": "2025-01-15T10:30:00Z",
+            "source": "github:o/n",
+            "plugins": [{ "name": "a", "source": "./a" }]
+        }"#,
+    )
+    .unwrap();
+
+    let cache = registry.get("legacy").unwrap().expect("cache exists");
+    assert_eq!(cache.plugins[0].name, "a");
+    registry.store(&cache).unwrap();
+
+    let stored: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(
+        stored["schemaVersion"],
+        serde_json::json!(schema::CURRENT_SCHEMA_VERSION)
+    );
+}
+
 // ---- PLM_HOME path resolution (#344) ----
 
 use std::sync::OnceLock;
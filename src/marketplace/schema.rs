@@ -0,0 +1,118 @@
+//! marketplace.json / マーケットプレイスキャッシュのスキーマバージョン
+//!
+//! `schemaVersion` で形式を識別し、旧形式の JSON を最新形式まで順にマイグレーション
+//! してから構造体へデシリアライズする。
+//!
+//! - `schemaVersion` が無い JSON はバージョン 1（フィールド導入前の形式）として扱う
+//! - この plm が知らない新しいバージョンは "this marketplace requires a newer plm" エラー
+//! - この plm が知らないフィールドは警告なしで無視する
+//! - キャッシュへは常に [`CURRENT_SCHEMA_VERSION`] の形式で書き込む
+
+use crate::error::{PlmError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// JSON 上のバージョンフィールド名
+const SCHEMA_VERSION_KEY: &str = "schemaVersion";
+
+/// この plm が読み書きする最新のスキーマバージョン
+pub const CURRENT_SCHEMA_VERSION: u64 = 2;
+
+/// バージョン `n` から `n + 1` へのマイグレーション（先頭が 1 → 2）
+///
+/// 形式を変更する場合は [`CURRENT_SCHEMA_VERSION`] を上げ、末尾に関数を追加する。
+const MIGRATIONS: &[fn(Map<String, Value>) -> Map<String, Value>] = &[migrate_v1_to_v2];
+
+/// 最新形式で書き出すときの包み（`schemaVersion` を先頭に付ける）
+#[derive(Serialize)]
+struct Versioned<'a, T> {
+    #[serde(rename = "schemaVersion")]
+    schema_version: u64,
+    #[serde(flatten)]
+    data: &'a T,
+}
+
+/// JSON 文字列を最新形式へマイグレーションしてからデシリアライズする
+///
+/// JSON として不正な場合は [`PlmError::Json`]、バージョンが不正・未対応の場合は
+/// [`PlmError::InvalidManifest`] を返す。
+///
+/// # Arguments
+///
+/// * `content` - Raw JSON of a marketplace manifest or cache file.
+pub fn parse<T: DeserializeOwned>(content: &str) -> Result<T> {
+    let value: Value = serde_json::from_str(content)?;
+    Ok(serde_json::from_value(migrate(value)?)?)
+}
+
+/// 最新の `schemaVersion` を付けて整形済み JSON にする
+///
+/// # Arguments
+///
+/// * `data` - Value to serialize in the current format.
+pub fn to_string_pretty<T: Serialize>(data: &T) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&Versioned {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        data,
+    })?)
+}
+
+/// JSON を最新形式へマイグレーションする
+///
+/// # Arguments
+///
+/// * `value` - Parsed JSON of a marketplace manifest or cache file.
+pub fn migrate(value: Value) -> Result<Value> {
+    let Value::Object(mut object) = value else {
+        return Err(PlmError::InvalidManifest(
+            "expected a JSON object at the top level".to_string(),
+        ));
+    };
+
+    let version = schema_version(&object)?;
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(PlmError::InvalidManifest(format!(
+            "this marketplace requires a newer plm (schemaVersion {}, this plm supports up to {})",
+            version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        object = migration(object);
+    }
+    object.insert(
+        SCHEMA_VERSION_KEY.to_string(),
+        Value::from(CURRENT_SCHEMA_VERSION),
+    );
+    Ok(Value::Object(object))
+}
+
+/// `schemaVersion` を読む（無ければ 1）
+///
+/// # Arguments
+///
+/// * `object` - Top-level JSON object.
+fn schema_version(object: &Map<String, Value>) -> Result<u64> {
+    match object.get(SCHEMA_VERSION_KEY) {
+        None => Ok(1),
+        Some(value) => value.as_u64().filter(|v| *v >= 1).ok_or_else(|| {
+            PlmError::InvalidManifest(format!("invalid {}: {}", SCHEMA_VERSION_KEY, value))
+        }),
+    }
+}
+
+/// v1 → v2
+///
+/// v2 はフィールド構成を変えずに `schemaVersion` を導入しただけなので内容をそのまま引き継ぐ。
+///
+/// # Arguments
+///
+/// * `object` - Top-level JSON object in the v1 format.
+fn migrate_v1_to_v2(object: Map<String, Value>) -> Map<String, Value> {
+    object
+}
+
+#[cfg(test)]
+#[path = "schema_test.rs"]
+mod tests;
@@ -0,0 +1,130 @@
+use super::*;
+use crate::marketplace::{MarketplaceCache, MarketplaceManifest, PluginSource};
+use serde_json::json;
+
+#[test]
+fn migrations_cover_every_version_up_to_current() {
+    assert_eq!(MIGRATIONS.len() as u64, CURRENT_SCHEMA_VERSION - 1);
+}
+
+#[test]
+fn parse_migrates_v1_manifest_without_schema_version() {
+    let json = r#"{
+        "name": "company-tools",
+        "owner": { "name": "Dev Team" },
+        "plugins": [
+            { "name": "formatter", "source": "./plugins/formatter", "version": "1.0.0" }
+        ]
+    }"#;
+
+    let manifest: MarketplaceManifest = parse(json).unwrap();
+
+    assert_eq!(manifest.name, "company-tools");
+    assert_eq!(manifest.owner.unwrap().name, "Dev Team");
+    assert_eq!(manifest.plugins.len(), 1);
+    assert_eq!(manifest.plugins[0].version.as_deref(), Some("1.0.0"));
+    assert!(matches!(
+        &manifest.plugins[0].source,
+        PluginSource::Local(path) if path == "./plugins/formatter"
+    ));
+}
+
+#[test]
+fn parse_accepts_relative_and_external_sources_in_one_manifest() {
+    let json = r#"{
+        "name": "company-tools",
+        "plugins": [
+            { "name": "formatter", "source": "./plugins/formatter" },
+            { "name": "linter", "source": { "source": "github", "repo": "company/linter" } }
+        ]
+    }"#;
+
+    let manifest: MarketplaceManifest = parse(json).unwrap();
+
+    assert!(matches!(
+        &manifest.plugins[0].source,
+        PluginSource::Local(path) if path == "./plugins/formatter"
+    ));
+    assert!(matches!(
+        &manifest.plugins[1].source,
+        PluginSource::External { repo, .. } if repo == "company/linter"
+    ));
+}
+
+#[test]
+fn migrate_stamps_current_version() {
+    let migrated = migrate(json!({ "name": "mp", "plugins": [] })).unwrap();
+    assert_eq!(migrated["schemaVersion"], json!(CURRENT_SCHEMA_VERSION));
+}
+
+#[test]
+fn parse_accepts_current_version_and_ignores_unknown_fields() {
+    let json = r#"{
+        "schemaVersion": 2,
+        "name": "mp",
+        "futureField": { "anything": true },
+        "plugins": [
+            { "name": "a", "source": "./a", "futureFlag": 1 }
+        ]
+    }"#;
+
+    let manifest: MarketplaceManifest = parse(json).unwrap();
+
+    assert_eq!(manifest.name, "mp");
+    assert_eq!(manifest.plugins[0].name, "a");
+}
+
+#[test]
+fn parse_rejects_newer_schema_version() {
+    let json = r#"{ "schemaVersion": 99, "name": "mp", "plugins": [] }"#;
+
+    let err = parse::<MarketplaceManifest>(json).unwrap_err();
+
+    assert!(matches!(err, PlmError::InvalidManifest(_)));
+    assert!(err
+        .to_string()
+        .contains("this marketplace requires a newer plm"));
+}
+
+#[test]
+fn parse_rejects_invalid_schema_version() {
+    for version in [json!(0), json!(-1), json!("2"), json!(1.5)] {
+        let json = json!({ "schemaVersion": version, "name": "mp", "plugins": [] }).to_string();
+        let err = parse::<MarketplaceManifest>(&json).unwrap_err();
+        assert!(
+            err.to_string().contains("invalid schemaVersion"),
+            "{}: {}",
+            version,
+            err
+        );
+    }
+}
+
+#[test]
+fn parse_reports_malformed_json_as_json_error() {
+    let err = parse::<MarketplaceManifest>("{ not json").unwrap_err();
+    assert!(matches!(err, PlmError::Json(_)));
+}
+
+#[test]
+fn to_string_pretty_writes_current_version_first() {
+    let cache: MarketplaceCache = parse(
+        r#"{
+            "name": "mp",
+            "fetched_at": "2025-01-15T10:30:00Z",
+            "source": "github:owner/repo",
+            "plugins": []
+        }"#,
+    )
+    .unwrap();
+
+    let written = to_string_pretty(&cache).unwrap();
+
+    let first_key = written.lines().nth(1).unwrap().trim();
+    assert_eq!(
+        first_key,
+        format!("\"schemaVersion\": {},", CURRENT_SCHEMA_VERSION)
+    );
+    let reparsed: MarketplaceCache = parse(&written).unwrap();
+    assert_eq!(reparsed.name, "mp");
+}
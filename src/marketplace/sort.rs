@@ -0,0 +1,109 @@
+//! マーケットプレイスのプラグインの並び替え
+//!
+//! marketplace.json の任意メタデータ（[`PluginStats`]）でマーケットプレイスのプラグイン一覧を
+//! 並べ替える。メタデータが無いエントリは末尾に回し、同値は名前順にする。
+
+use super::PluginStats;
+use chrono::{DateTime, FixedOffset};
+use clap::ValueEnum;
+use std::cmp::Ordering;
+
+/// マーケットプレイスのプラグインのソートキー
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum PluginSortKey {
+    /// 名前順（既定）
+    #[default]
+    Name,
+    /// インストール数の多い順
+    Downloads,
+    /// スター数の多い順
+    Stars,
+    /// 更新日時の新しい順
+    Updated,
+}
+
+impl PluginSortKey {
+    /// 切替順で次のソートキー（最後の次は名前順に戻る）
+    pub fn next(self) -> Self {
+        match self {
+            PluginSortKey::Name => PluginSortKey::Downloads,
+            PluginSortKey::Downloads => PluginSortKey::Stars,
+            PluginSortKey::Stars => PluginSortKey::Updated,
+            PluginSortKey::Updated => PluginSortKey::Name,
+        }
+    }
+
+    /// 表示用のラベル（`--sort` の値と同じ）
+    pub fn label(self) -> &'static str {
+        match self {
+            PluginSortKey::Name => "name",
+            PluginSortKey::Downloads => "downloads",
+            PluginSortKey::Stars => "stars",
+            PluginSortKey::Updated => "updated",
+        }
+    }
+}
+
+/// プラグインをソートキーの順に並べ替える
+///
+/// 同値は名前順（大文字小文字を区別する）にし、それも同じなら元の順序
+/// （名前 → マーケットプレイス順に並べた一覧ならマーケットプレイス順）を保つ（安定ソート）。
+///
+/// # Arguments
+///
+/// * `plugins` - Plugins to sort.
+/// * `key` - Sort key to apply.
+/// * `entry` - Returns the name and metadata of a plugin.
+pub fn sort_plugins<T>(
+    plugins: &mut [T],
+    key: PluginSortKey,
+    entry: impl Fn(&T) -> (&str, &PluginStats),
+) {
+    plugins.sort_by(|a, b| {
+        let (a_name, a_stats) = entry(a);
+        let (b_name, b_stats) = entry(b);
+        compare(a_stats, b_stats, key).then_with(|| a_name.cmp(b_name))
+    });
+}
+
+/// ソートキーのメタデータだけで比較する（名前順のキーでは常に等しい）
+///
+/// # Arguments
+///
+/// * `a` - Metadata to compare.
+/// * `b` - Metadata to compare with.
+/// * `key` - Sort key to compare by.
+fn compare(a: &PluginStats, b: &PluginStats, key: PluginSortKey) -> Ordering {
+    match key {
+        PluginSortKey::Name => Ordering::Equal,
+        PluginSortKey::Downloads => descending(a.downloads, b.downloads),
+        PluginSortKey::Stars => descending(a.stars, b.stars),
+        PluginSortKey::Updated => descending(updated_at(a), updated_at(b)),
+    }
+}
+
+/// 大きい順。値の無いもの（`None`）は末尾
+///
+/// # Arguments
+///
+/// * `a` - Value to compare.
+/// * `b` - Value to compare with.
+fn descending<V: Ord>(a: Option<V>, b: Option<V>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// # Arguments
+///
+/// * `stats` - Metadata whose update time is parsed (unparsable values count as missing).
+fn updated_at(stats: &PluginStats) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(stats.updated_at.as_deref()?).ok()
+}
+
+#[cfg(test)]
+#[path = "sort_test.rs"]
+mod tests;
@@ -0,0 +1,92 @@
+use super::*;
+
+fn entry(
+    name: &str,
+    downloads: Option<u64>,
+    stars: Option<u64>,
+    updated_at: Option<&str>,
+) -> (String, PluginStats) {
+    (
+        name.to_string(),
+        PluginStats {
+            downloads,
+            stars,
+            updated_at: updated_at.map(str::to_string),
+        },
+    )
+}
+
+fn fixtures() -> Vec<(String, PluginStats)> {
+    vec![
+        entry("zeta", Some(10), None, Some("2024-01-01T00:00:00Z")),
+        entry("alpha", None, Some(5), None),
+        entry("beta", Some(300), Some(5), Some("2024-06-01T00:00:00Z")),
+        entry("gamma", Some(10), Some(1), Some("not a date")),
+    ]
+}
+
+fn sorted_names(key: PluginSortKey) -> Vec<String> {
+    let mut plugins = fixtures();
+    sort_plugins(&mut plugins, key, |(name, stats)| (name.as_str(), stats));
+    plugins.into_iter().map(|(name, _)| name).collect()
+}
+
+#[test]
+fn name_sort_orders_by_name() {
+    assert_eq!(
+        sorted_names(PluginSortKey::Name),
+        vec!["alpha", "beta", "gamma", "zeta"]
+    );
+}
+
+#[test]
+fn downloads_sort_is_descending_with_ties_by_name_and_missing_last() {
+    assert_eq!(
+        sorted_names(PluginSortKey::Downloads),
+        vec!["beta", "gamma", "zeta", "alpha"]
+    );
+}
+
+#[test]
+fn stars_sort_breaks_ties_by_name() {
+    assert_eq!(
+        sorted_names(PluginSortKey::Stars),
+        vec!["alpha", "beta", "gamma", "zeta"]
+    );
+}
+
+#[test]
+fn updated_sort_treats_unparsable_dates_as_missing() {
+    assert_eq!(
+        sorted_names(PluginSortKey::Updated),
+        vec!["beta", "zeta", "alpha", "gamma"]
+    );
+}
+
+#[test]
+fn equal_names_keep_their_original_order() {
+    let mut plugins = vec![
+        ("dup".to_string(), "company-tools"),
+        ("dup".to_string(), "community"),
+    ];
+    let stats = PluginStats::default();
+    sort_plugins(&mut plugins, PluginSortKey::Downloads, |(name, _)| {
+        (name.as_str(), &stats)
+    });
+
+    assert_eq!(plugins[0].1, "company-tools");
+    assert_eq!(plugins[1].1, "community");
+}
+
+#[test]
+fn next_cycles_through_all_keys() {
+    let mut key = PluginSortKey::default();
+    let mut labels = Vec::new();
+    for _ in 0..4 {
+        labels.push(key.label());
+        key = key.next();
+    }
+
+    assert_eq!(labels, vec!["name", "downloads", "stars", "updated"]);
+    assert_eq!(key, PluginSortKey::Name);
+}
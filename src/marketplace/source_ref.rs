@@ -1,35 +1,59 @@
 //! マーケットプレイスソース参照の値オブジェクト
 //!
-//! 「マーケットプレイスの取得元 GitHub リポジトリへの参照」を単一の型で表現する。
-//! 保存（内部）形式は `github:owner/repo` に正規化し、読み込みは
-//! プレフィックスなし（`owner/repo`）や URL 形式も受け付ける。
+//! 「マーケットプレイスの取得元（GitHub リポジトリまたはローカルディレクトリ）への参照」を
+//! 単一の型で表現する。保存（内部）形式は `github:owner/repo` / `file:///abs/path` に正規化し、
+//! 読み込みはプレフィックスなし（`owner/repo`）や URL 形式も受け付ける。
+//!
+//! ブランチ/タグ（`owner/repo#branch` / `owner/repo@tag`）とサブディレクトリ
+//! （`owner/repo/path/to/dir`）は参照自体には含めず、[`SourceLocator`] として別に取り出す。
 
-use crate::error::PlmError;
+use crate::error::{PlmError, Result};
 use crate::host::HostKind;
 use crate::repo::{self, Repo};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 /// 内部（保存）形式のプレフィックス
 const GITHUB_PREFIX: &str = "github:";
 
-/// マーケットプレイスの取得元 GitHub リポジトリへの参照
+/// ローカルディレクトリの内部（保存）形式のプレフィックス
+const FILE_PREFIX: &str = "file://";
+
+/// マーケットプレイスの取得元への参照
 ///
 /// # 不変条件
 ///
-/// - `owner` / `name` は空でない
-/// - `owner` / `name` はパス安全（`.` / `..` / パス区切りを含まない）
+/// - GitHub: `owner` / `name` は空でなく、パス安全（`.` / `..` / パス区切りを含まない）
+/// - ローカル: パスは絶対パス
 ///
 /// # 形式
 ///
-/// - 内部（保存）形式: `github:owner/repo`（`Display` / `Serialize`）
-/// - 表示形式: `owner/repo`（`full_name()`）
-/// - パース: `github:` プレフィックスあり/なし、URL 形式のいずれも受理
+/// - 内部（保存）形式: `github:owner/repo` / `file:///abs/path`（`Display` / `Serialize`）
+/// - 表示形式: `owner/repo` / `/abs/path`（`full_name()`）
+/// - パース: `github:` プレフィックスあり/なし、URL 形式、`file://` 形式を受理
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MarketplaceSourceRef {
-    owner: String,
-    name: String,
+    location: Location,
+}
+
+/// ユーザー入力をパースした取得元（参照 + ブランチ/タグ + サブディレクトリ）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocator {
+    /// 取得元への参照
+    pub source: MarketplaceSourceRef,
+    /// ブランチ/タグ（`#branch` / `@tag`。未指定ならデフォルトブランチ）
+    pub git_ref: Option<String>,
+    /// marketplace.json があるサブディレクトリ（`owner/repo/path/to/dir`）
+    pub subdir: Option<String>,
+}
+
+/// 取得元の種別
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Location {
+    GitHub { owner: String, name: String },
+    Local(PathBuf),
 }
 
 impl MarketplaceSourceRef {
@@ -40,28 +64,119 @@ impl MarketplaceSourceRef {
     /// * `repo` - Source repository whose owner / name are captured.
     pub fn from_repo(repo: &Repo) -> Self {
         Self {
-            owner: repo.owner().to_string(),
-            name: repo.name().to_string(),
+            location: Location::GitHub {
+                owner: repo.owner().to_string(),
+                name: repo.name().to_string(),
+            },
+        }
+    }
+
+    /// ローカルディレクトリから参照を作成する
+    ///
+    /// 相対パスはカレントディレクトリ基準で絶対パスに解決する。ディレクトリが存在しない場合はエラー。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory containing `.claude-plugin/marketplace.json`.
+    pub fn from_local_dir(dir: &Path) -> Result<Self> {
+        let resolved = dir.canonicalize().map_err(|_| {
+            PlmError::InvalidSource(format!(
+                "local marketplace directory not found: {}",
+                dir.display()
+            ))
+        })?;
+        if !resolved.is_dir() {
+            return Err(PlmError::InvalidSource(format!(
+                "local marketplace source is not a directory: {}",
+                dir.display()
+            )));
+        }
+        Ok(Self {
+            location: Location::Local(resolved),
+        })
+    }
+
+    /// ユーザー入力（`owner/repo`、GitHub URL、ローカルパス、`file://` URL）から参照を作成する
+    ///
+    /// 絶対パス・`./` / `../` で始まる相対パス・`file://` はローカルディレクトリとして扱い、
+    /// 存在を確認する。それ以外は GitHub リポジトリとしてパースする。
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Source given to `plm marketplace add` or the TUI add form.
+    pub fn from_input(input: &str) -> Result<Self> {
+        Self::parse_locator(input).map(|locator| locator.source)
+    }
+
+    /// ユーザー入力から参照とブランチ/タグ・サブディレクトリを取り出す
+    ///
+    /// GitHub リポジトリは `owner/repo#branch`・`owner/repo@tag`・`owner/repo/path/to/dir`
+    /// （併用可）を受け付ける。ローカルディレクトリは ref / サブディレクトリを持たない。
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Source given to `plm marketplace add` or the TUI add form.
+    pub fn parse_locator(input: &str) -> Result<SourceLocator> {
+        let input = input.trim();
+        let local = match input.strip_prefix(FILE_PREFIX) {
+            Some(path) => Some(path),
+            None => is_local_path(input).then_some(input),
+        };
+        if let Some(path) = local {
+            return Ok(SourceLocator {
+                source: Self::from_local_dir(Path::new(path))?,
+                git_ref: None,
+                subdir: None,
+            });
+        }
+
+        let repo = parse_github(input)?;
+        Ok(SourceLocator {
+            source: Self::from_repo(&repo),
+            git_ref: repo.git_ref().map(str::to_string),
+            subdir: repo.subdir().map(str::to_string),
+        })
+    }
+
+    /// ローカルディレクトリの場合はそのパス
+    pub fn local_path(&self) -> Option<&Path> {
+        match &self.location {
+            Location::Local(path) => Some(path),
+            Location::GitHub { .. } => None,
         }
     }
 
-    /// オーナー名
+    /// オーナー名（ローカルディレクトリは空文字）
     pub fn owner(&self) -> &str {
-        &self.owner
+        match &self.location {
+            Location::GitHub { owner, .. } => owner,
+            Location::Local(_) => "",
+        }
     }
 
-    /// リポジトリ名
+    /// リポジトリ名（ローカルディレクトリはディレクトリ名）
     pub fn name(&self) -> &str {
-        &self.name
+        match &self.location {
+            Location::GitHub { name, .. } => name,
+            Location::Local(path) => path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default(),
+        }
     }
 
-    /// ユーザー表示用の `owner/repo` 形式
+    /// ユーザー表示用の `owner/repo` 形式（ローカルディレクトリは絶対パス）
     pub fn full_name(&self) -> String {
-        format!("{}/{}", self.owner, self.name)
+        match &self.location {
+            Location::GitHub { owner, name } => format!("{}/{}", owner, name),
+            Location::Local(path) => path.display().to_string(),
+        }
     }
 
     /// GitHub の `Repo` へ変換する（git ref なし）
-    pub fn to_repo(&self) -> Repo {
+    ///
+    /// ローカルディレクトリの場合はエラー。
+    pub fn to_repo(&self) -> Result<Repo> {
         self.to_repo_with_ref(None)
     }
 
@@ -73,52 +188,94 @@ impl MarketplaceSourceRef {
     /// # Arguments
     ///
     /// * `git_ref` - Git reference to embed into the resulting `Repo`.
-    pub fn to_repo_with_ref(&self, git_ref: Option<String>) -> Repo {
-        Repo::new(
-            HostKind::GitHub,
-            self.owner.clone(),
-            self.name.clone(),
-            git_ref,
-        )
+    pub fn to_repo_with_ref(&self, git_ref: Option<String>) -> Result<Repo> {
+        match &self.location {
+            Location::GitHub { owner, name } => Ok(Repo::new(
+                HostKind::GitHub,
+                owner.clone(),
+                name.clone(),
+                git_ref,
+            )),
+            Location::Local(path) => Err(PlmError::InvalidSource(format!(
+                "local marketplace '{}' has no GitHub repository",
+                path.display()
+            ))),
+        }
     }
 }
 
+/// ローカルパスとして扱う入力か（絶対パス、`./` / `../` で始まる相対パス、`.` / `..`）
+///
+/// # Arguments
+///
+/// * `input` - Trimmed source input.
+fn is_local_path(input: &str) -> bool {
+    matches!(input, "." | "..")
+        || input.starts_with("./")
+        || input.starts_with("../")
+        || Path::new(input).is_absolute()
+}
+
 impl FromStr for MarketplaceSourceRef {
     type Err = PlmError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let stripped = s.strip_prefix(GITHUB_PREFIX).unwrap_or(s);
-        // 正規パーサー（repo::from_url）へ委譲し、パースを一元化する
-        let parsed = repo::from_url(stripped)?;
-
-        // キャッシュディレクトリ名等に使われるため、パス安全性を保証する
-        for part in [parsed.owner(), parsed.name()] {
-            if part == "." || part == ".." || part.contains('\\') {
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(path) = s.strip_prefix(FILE_PREFIX) {
+            let path = PathBuf::from(path);
+            if !path.is_absolute() {
                 return Err(PlmError::InvalidSource(format!(
-                    "path-unsafe repository reference: {}",
+                    "local marketplace path must be absolute: {}",
                     s
                 )));
             }
+            return Ok(Self {
+                location: Location::Local(path),
+            });
         }
 
-        Ok(Self::from_repo(&parsed))
+        Ok(Self::from_repo(&parse_github(s)?))
+    }
+}
+
+/// GitHub リポジトリ参照（`github:` プレフィックスあり/なし）をパースする
+///
+/// # Arguments
+///
+/// * `s` - Repository reference such as `github:owner/repo` or `owner/repo#branch`.
+fn parse_github(s: &str) -> Result<Repo> {
+    let stripped = s.strip_prefix(GITHUB_PREFIX).unwrap_or(s);
+    // 正規パーサー（repo::from_url）へ委譲し、パースを一元化する
+    let parsed = repo::from_url(stripped)?;
+
+    // キャッシュディレクトリ名等に使われるため、パス安全性を保証する
+    for part in [parsed.owner(), parsed.name()] {
+        if part == "." || part == ".." || part.contains('\\') {
+            return Err(PlmError::InvalidSource(format!(
+                "path-unsafe repository reference: {}",
+                s
+            )));
+        }
     }
+    Ok(parsed)
 }
 
 impl fmt::Display for MarketplaceSourceRef {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}/{}", GITHUB_PREFIX, self.owner, self.name)
+        match &self.location {
+            Location::GitHub { owner, name } => write!(f, "{}{}/{}", GITHUB_PREFIX, owner, name),
+            Location::Local(path) => write!(f, "{}{}", FILE_PREFIX, path.display()),
+        }
     }
 }
 
 impl Serialize for MarketplaceSourceRef {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.to_string())
     }
 }
 
 impl<'de> Deserialize<'de> for MarketplaceSourceRef {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
         let s = String::deserialize(deserializer)?;
         s.parse().map_err(serde::de::Error::custom)
     }
@@ -64,7 +64,7 @@ fn full_name_returns_display_form() {
 #[test]
 fn to_repo_builds_github_repo_without_ref() {
     let source: MarketplaceSourceRef = "github:owner/repo".parse().unwrap();
-    let repo = source.to_repo();
+    let repo = source.to_repo().unwrap();
     assert_eq!(repo.owner(), "owner");
     assert_eq!(repo.name(), "repo");
     assert!(repo.git_ref().is_none());
@@ -73,14 +73,14 @@ fn to_repo_builds_github_repo_without_ref() {
 #[test]
 fn to_repo_with_ref_embeds_git_ref() {
     let source: MarketplaceSourceRef = "github:owner/repo".parse().unwrap();
-    let repo = source.to_repo_with_ref(Some("main".to_string()));
+    let repo = source.to_repo_with_ref(Some("main".to_string())).unwrap();
     assert_eq!(repo.git_ref(), Some("main"));
 }
 
 #[test]
 fn from_repo_roundtrips_through_to_repo() {
     let source: MarketplaceSourceRef = "owner/repo".parse().unwrap();
-    let roundtripped = MarketplaceSourceRef::from_repo(&source.to_repo());
+    let roundtripped = MarketplaceSourceRef::from_repo(&source.to_repo().unwrap());
     assert_eq!(roundtripped, source);
 }
 
@@ -110,3 +110,65 @@ fn deserializes_legacy_display_form() {
 fn deserialize_rejects_invalid_source() {
     assert!(serde_json::from_str::<MarketplaceSourceRef>(r#""not-a-repo""#).is_err());
 }
+
+// ==================== local directory ====================
+
+#[test]
+fn from_input_accepts_local_paths_and_file_urls() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let canonical = dir.path().canonicalize().unwrap();
+
+    let source = MarketplaceSourceRef::from_input(dir.path().to_str().unwrap()).unwrap();
+    assert_eq!(source.local_path(), Some(canonical.as_path()));
+    assert_eq!(source.full_name(), canonical.display().to_string());
+    assert!(source.to_repo().is_err());
+
+    let url = format!("file://{}", dir.path().display());
+    assert_eq!(MarketplaceSourceRef::from_input(&url).unwrap(), source);
+
+    let github = MarketplaceSourceRef::from_input("owner/repo").unwrap();
+    assert_eq!(github.local_path(), None);
+}
+
+#[test]
+fn from_input_rejects_missing_local_directory() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let missing = dir.path().join("missing");
+    let err = MarketplaceSourceRef::from_input(missing.to_str().unwrap()).unwrap_err();
+    assert!(err.to_string().contains("not found"), "{}", err);
+}
+
+#[test]
+fn local_source_roundtrips_through_serde() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let source = MarketplaceSourceRef::from_local_dir(dir.path()).unwrap();
+
+    let json = serde_json::to_string(&source).unwrap();
+    assert!(json.starts_with(r#""file:///"#), "{}", json);
+    let parsed: MarketplaceSourceRef = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, source);
+}
+
+// ==================== ref / subdir ====================
+
+#[test]
+fn parse_locator_extracts_ref_and_subdir() {
+    let locator = MarketplaceSourceRef::parse_locator("owner/mono/plugins#v2").unwrap();
+    assert_eq!(locator.source.full_name(), "owner/mono");
+    assert_eq!(locator.git_ref.as_deref(), Some("v2"));
+    assert_eq!(locator.subdir.as_deref(), Some("plugins"));
+
+    let tagged = MarketplaceSourceRef::parse_locator("github:owner/repo@v1.0.0").unwrap();
+    assert_eq!(tagged.source.to_string(), "github:owner/repo");
+    assert_eq!(tagged.git_ref.as_deref(), Some("v1.0.0"));
+    assert_eq!(tagged.subdir, None);
+}
+
+#[test]
+fn parse_locator_local_directory_has_no_ref_or_subdir() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let locator = MarketplaceSourceRef::parse_locator(dir.path().to_str().unwrap()).unwrap();
+    assert!(locator.source.local_path().is_some());
+    assert_eq!(locator.git_ref, None);
+    assert_eq!(locator.subdir, None);
+}
@@ -1,4 +1,17 @@
+pub mod diff;
+pub mod github;
+pub mod hint;
+pub mod notify;
+pub mod time;
+pub mod tree;
+
 use owo_colors::OwoColorize;
+use std::io::IsTerminal;
+
+/// 標準出力に色を付けるか（TTY かつ `NO_COLOR` 未設定のとき）
+pub fn stdout_color_enabled() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
 
 pub struct CommandSummary {
     pub prefix: String,
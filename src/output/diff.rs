@@ -0,0 +1,147 @@
+//! unified diff 形式の差分表示
+//!
+//! 行単位の最長共通部分列（LCS）から差分を求め、前後 [`CONTEXT_LINES`] 行の文脈を付けた
+//! hunk にまとめる。`plm install --verbose` で再生成したファイルの変更内容を示すのに使う。
+
+/// hunk の前後に付ける文脈の行数
+const CONTEXT_LINES: usize = 3;
+
+/// LCS を求める行数の積の上限（超えたら旧内容をすべて削除・新内容をすべて追加として扱う）
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// unified diff を組み立てる
+///
+/// 改行コード（CRLF / LF）の違いは無視する。差分が無ければ空文字列を返す。
+///
+/// # Arguments
+///
+/// * `label` - Name shown in the `---` / `+++` header (usually the file path).
+/// * `old` - Current contents.
+/// * `new` - Contents about to be written.
+pub fn unified_diff(label: &str, old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let edits = diff_lines(&old_lines, &new_lines);
+    if edits.iter().all(|(op, _)| *op == Op::Equal) {
+        return String::new();
+    }
+
+    // 各 edit の直前までに消費した旧・新の行数
+    let mut positions = Vec::with_capacity(edits.len() + 1);
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for (op, _) in &edits {
+        positions.push((old_pos, new_pos));
+        match op {
+            Op::Equal => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Op::Delete => old_pos += 1,
+            Op::Insert => new_pos += 1,
+        }
+    }
+    positions.push((old_pos, new_pos));
+
+    let changes: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut out = format!("--- {} (current)\n+++ {} (new)\n", label, label);
+    let mut k = 0;
+    while k < changes.len() {
+        let start = changes[k].saturating_sub(CONTEXT_LINES);
+        let mut last = changes[k];
+        while k + 1 < changes.len() && changes[k + 1] - last <= 2 * CONTEXT_LINES + 1 {
+            k += 1;
+            last = changes[k];
+        }
+        let end = (last + CONTEXT_LINES + 1).min(edits.len());
+
+        let (old_start, new_start) = positions[start];
+        let old_len = positions[end].0 - old_start;
+        let new_len = positions[end].1 - new_start;
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_len),
+            hunk_range(new_start, new_len)
+        ));
+        for (op, line) in &edits[start..end] {
+            let marker = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+            out.push(marker);
+            out.push_str(line);
+            out.push('\n');
+        }
+        k += 1;
+    }
+    out
+}
+
+/// hunk ヘッダの範囲表記（`start,len`。行が無い側は直前の行番号を start にする）
+fn hunk_range(start: usize, len: usize) -> String {
+    if len == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, len)
+    }
+}
+
+/// 行単位の編集列（旧→新）を求める
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let (n, m) = (old.len(), new.len());
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        return old
+            .iter()
+            .map(|l| (Op::Delete, *l))
+            .chain(new.iter().map(|l| (Op::Insert, *l)))
+            .collect();
+    }
+
+    // lcs[i][j] = old[i..] と new[j..] の LCS 長
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            edits.push((Op::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            edits.push((Op::Delete, old[i]));
+            i += 1;
+        } else {
+            edits.push((Op::Insert, new[j]));
+            j += 1;
+        }
+    }
+    edits.extend(old[i..].iter().map(|l| (Op::Delete, *l)));
+    edits.extend(new[j..].iter().map(|l| (Op::Insert, *l)));
+    edits
+}
+
+#[cfg(test)]
+#[path = "diff_test.rs"]
+mod tests;
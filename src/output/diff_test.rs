@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn identical_contents_produce_no_diff() {
+    assert_eq!(unified_diff("a.sh", "x\ny\n", "x\ny\n"), "");
+}
+
+#[test]
+fn crlf_only_difference_produces_no_diff() {
+    assert_eq!(unified_diff("a.sh", "x\r\ny\r\n", "x\ny\n"), "");
+}
+
+#[test]
+fn changed_line_is_shown_with_context() {
+    let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+    let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+
+    assert_eq!(
+        unified_diff("run.sh", old, new),
+        "--- run.sh (current)\n+++ run.sh (new)\n\
+         @@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+    );
+}
+
+#[test]
+fn distant_changes_are_split_into_hunks() {
+    let line = |i: usize| match i {
+        2 => "two\n".to_string(),
+        19 => "nineteen\n".to_string(),
+        _ => format!("{}\n", i),
+    };
+    let old: String = (1..=20).map(|i| format!("{}\n", i)).collect();
+    let new: String = (1..=20).map(line).collect();
+
+    let diff = unified_diff("f", &old, &new);
+
+    assert_eq!(diff.matches("@@ -").count(), 2, "{}", diff);
+    assert!(diff.contains("@@ -1,5 +1,5 @@\n"), "{}", diff);
+    assert!(diff.contains("@@ -16,5 +16,5 @@\n"), "{}", diff);
+}
+
+#[test]
+fn insertion_into_empty_file_uses_zero_range() {
+    assert_eq!(
+        unified_diff("f", "", "a\n"),
+        "--- f (current)\n+++ f (new)\n@@ -0,0 +1,1 @@\n+a\n"
+    );
+}
@@ -0,0 +1,254 @@
+//! GitHub Actions 向けの出力
+//!
+//! `--github-output` 指定時（または `GITHUB_ACTIONS=true` の環境）に、警告とエラーを
+//! ワークフローコマンド（`::warning::` / `::error::`）として stderr へ出力し、
+//! ジョブのアノテーションとして表示させる。`GITHUB_STEP_SUMMARY` が設定されていれば
+//! コマンド結果の Markdown 表をそのファイルへ追記する。
+//!
+//! 通常の出力と重複しすぎないよう、アノテーションは警告とエラーに限る。
+
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// `::warning::` コマンド行を組み立てる
+///
+/// # Arguments
+///
+/// * `message` - Warning message.
+/// * `file` - File the warning relates to, if any.
+pub fn warning_command(message: &str, file: Option<&Path>) -> String {
+    match file {
+        Some(path) => format!(
+            "::warning file={}::{}",
+            escape_property(&path.display().to_string()),
+            escape_data(message)
+        ),
+        None => format!("::warning::{}", escape_data(message)),
+    }
+}
+
+/// `::error::` コマンド行を組み立てる
+///
+/// # Arguments
+///
+/// * `message` - Error message.
+pub fn error_command(message: &str) -> String {
+    format!("::error::{}", escape_data(message))
+}
+
+/// メッセージ部のエスケープ（改行を含むメッセージも 1 行のコマンドにする）
+///
+/// # Arguments
+///
+/// * `value` - Raw message.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// プロパティ値（`file=` など）のエスケープ
+///
+/// # Arguments
+///
+/// * `value` - Raw property value.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// サマリー表の 1 行の成否
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryStatus {
+    Success,
+    Failure,
+    Skipped,
+}
+
+impl SummaryStatus {
+    fn label(self) -> &'static str {
+        match self {
+            SummaryStatus::Success => "✅ Success",
+            SummaryStatus::Failure => "❌ Failure",
+            SummaryStatus::Skipped => "⏭️ Skipped",
+        }
+    }
+}
+
+/// サマリー表の 1 行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryRow {
+    /// プラグイン名（sync ではコンポーネント名）
+    pub name: String,
+    pub status: SummaryStatus,
+    /// 補足（配置数、エラー内容など）
+    pub details: String,
+}
+
+/// `GITHUB_STEP_SUMMARY` へ追記するコマンド結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepSummary {
+    title: String,
+    rows: Vec<SummaryRow>,
+}
+
+impl StepSummary {
+    /// # Arguments
+    ///
+    /// * `title` - Heading of the summary section (e.g. `plm install`).
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// 行を追加する
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Plugin (or component) name.
+    /// * `status` - Outcome of the row.
+    /// * `details` - Free-form details.
+    pub fn push(
+        &mut self,
+        name: impl Into<String>,
+        status: SummaryStatus,
+        details: impl Into<String>,
+    ) {
+        self.rows.push(SummaryRow {
+            name: name.into(),
+            status,
+            details: details.into(),
+        });
+    }
+
+    pub fn rows(&self) -> &[SummaryRow] {
+        &self.rows
+    }
+
+    /// Markdown の見出しと表に変換する
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![
+            format!("### {}", self.title),
+            String::new(),
+            "| Name | Result | Details |".to_string(),
+            "| --- | --- | --- |".to_string(),
+        ];
+        for row in &self.rows {
+            lines.push(format!(
+                "| {} | {} | {} |",
+                escape_cell(&row.name),
+                row.status.label(),
+                escape_cell(&row.details)
+            ));
+        }
+        lines.push(String::new());
+        lines.join("\n") + "\n"
+    }
+}
+
+/// 表のセルを壊さないよう `|` と改行をエスケープする
+///
+/// # Arguments
+///
+/// * `value` - Raw cell text.
+fn escape_cell(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// GitHub Actions 向けの出力先
+///
+/// 無効な場合はすべてのメソッドが何もしない。
+#[derive(Debug, Clone, Default)]
+pub struct GithubReporter {
+    enabled: bool,
+    summary_path: Option<PathBuf>,
+}
+
+impl GithubReporter {
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether annotations are emitted.
+    /// * `summary_path` - File the step summary is appended to.
+    pub fn new(enabled: bool, summary_path: Option<PathBuf>) -> Self {
+        Self {
+            enabled,
+            summary_path,
+        }
+    }
+
+    /// フラグと環境変数から作成する
+    ///
+    /// `GITHUB_ACTIONS=true` なら `--github-output` が無くても有効になる。
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Whether `--github-output` was given.
+    pub fn from_env(flag: bool) -> Self {
+        let on_actions = env::var("GITHUB_ACTIONS").is_ok_and(|v| v == "true");
+        let summary_path = env::var_os("GITHUB_STEP_SUMMARY")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from);
+        Self::new(flag || on_actions, summary_path)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// # Arguments
+    ///
+    /// * `message` - Warning message.
+    /// * `file` - File the warning relates to, if any.
+    pub fn warning(&self, message: &str, file: Option<&Path>) {
+        if self.enabled {
+            eprintln!("{}", warning_command(message, file));
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// * `message` - Error message.
+    pub fn error(&self, message: &str) {
+        if self.enabled {
+            eprintln!("{}", error_command(message));
+        }
+    }
+
+    /// サマリーを `GITHUB_STEP_SUMMARY` に追記する
+    ///
+    /// 書き込みに失敗してもコマンド自体は失敗させず、警告のみ出す。
+    ///
+    /// # Arguments
+    ///
+    /// * `summary` - Summary to append.
+    pub fn write_summary(&self, summary: &StepSummary) {
+        if !self.enabled {
+            return;
+        }
+        let Some(path) = &self.summary_path else {
+            return;
+        };
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(summary.to_markdown().as_bytes()));
+        if let Err(e) = result {
+            self.warning(
+                &format!("Failed to write step summary to {}: {}", path.display(), e),
+                None,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "github_test.rs"]
+mod tests;
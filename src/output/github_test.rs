@@ -0,0 +1,68 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn warning_command_without_file() {
+    assert_eq!(
+        warning_command("Hook event dropped", None),
+        "::warning::Hook event dropped"
+    );
+}
+
+#[test]
+fn warning_command_with_file_escapes_property() {
+    let line = warning_command("dropped", Some(Path::new("C:/a,b/hooks.json")));
+    assert_eq!(line, "::warning file=C%3A/a%2Cb/hooks.json::dropped");
+}
+
+#[test]
+fn error_command_escapes_newlines_and_percent() {
+    assert_eq!(
+        error_command("100% failed\nsecond line\r"),
+        "::error::100%25 failed%0Asecond line%0D"
+    );
+}
+
+#[test]
+fn step_summary_renders_markdown_table() {
+    let mut summary = StepSummary::new("plm install");
+    summary.push("formatter", SummaryStatus::Success, "3 placed, 0 failed");
+    summary.push("linter", SummaryStatus::Failure, "a | b\nc");
+
+    assert_eq!(
+        summary.to_markdown(),
+        "### plm install\n\n\
+         | Name | Result | Details |\n\
+         | --- | --- | --- |\n\
+         | formatter | ✅ Success | 3 placed, 0 failed |\n\
+         | linter | ❌ Failure | a \\| b<br>c |\n\n"
+    );
+}
+
+#[test]
+fn write_summary_appends_to_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("summary.md");
+    fs::write(&path, "existing\n").unwrap();
+
+    let reporter = GithubReporter::new(true, Some(path.clone()));
+    let mut summary = StepSummary::new("plm sync");
+    summary.push("skill-a", SummaryStatus::Skipped, "No change");
+    reporter.write_summary(&summary);
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("existing\n### plm sync\n"));
+    assert!(content.contains("| skill-a | ⏭️ Skipped | No change |"));
+}
+
+#[test]
+fn disabled_reporter_does_not_write_summary() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("summary.md");
+
+    let reporter = GithubReporter::new(false, Some(path.clone()));
+    reporter.write_summary(&StepSummary::new("plm update"));
+
+    assert!(!path.exists());
+}
@@ -0,0 +1,67 @@
+//! 配置後の反映手順の案内
+//!
+//! 起動中のツールは新しく配置したコマンドやスキルを読み込まないことがあるため、
+//! `install` / `update` / `enable` の完了後にターゲットごとの操作（再起動・再読込）を案内する。
+//! 文言は `Target::post_deploy_hint` が返す。`--quiet`（`PLM_QUIET`）では表示しない。
+
+use crate::config::{flag_enabled, GlobalOptions, QUIET_ENV};
+use crate::target::post_deploy_hints;
+
+/// 案内の見出し
+const HINT_HEADER: &str = "To apply the changes";
+
+/// 配置先ターゲットの案内を箇条書きの行にする（案内が無ければ空）
+///
+/// # Arguments
+///
+/// * `target_names` - Names of the targets components were deployed to.
+pub fn post_deploy_hint_lines<S: AsRef<str>>(target_names: &[S]) -> Vec<String> {
+    let hints = post_deploy_hints(target_names);
+    if hints.is_empty() {
+        return Vec::new();
+    }
+    std::iter::once(format!("{}:", HINT_HEADER))
+        .chain(
+            hints
+                .iter()
+                .map(|(target, hint)| format!("  - {}: {}", target, hint)),
+        )
+        .collect()
+}
+
+/// 配置先ターゲットの案内を 1 行にまとめる（TUI 用）
+///
+/// # Arguments
+///
+/// * `target_names` - Names of the targets components were deployed to.
+pub fn post_deploy_hint_line<S: AsRef<str>>(target_names: &[S]) -> Option<String> {
+    let hints = post_deploy_hints(target_names);
+    if hints.is_empty() {
+        return None;
+    }
+    let body = hints
+        .iter()
+        .map(|(target, hint)| format!("{}: {}", target, hint))
+        .collect::<Vec<_>>()
+        .join("; ");
+    Some(format!("{}: {}", HINT_HEADER, body))
+}
+
+/// 配置先ターゲットの案内を標準出力に表示する（`--quiet` では何もしない）
+///
+/// # Arguments
+///
+/// * `target_names` - Names of the targets components were deployed to.
+pub fn print_post_deploy_hints<S: AsRef<str>>(target_names: &[S]) {
+    let quiet = GlobalOptions::current().quiet.then_some("1");
+    if flag_enabled(GlobalOptions::or_env(quiet, QUIET_ENV)) {
+        return;
+    }
+    for line in post_deploy_hint_lines(target_names) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+#[path = "hint_test.rs"]
+mod tests;
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn hint_lines_snapshot_for_multiple_targets() {
+    assert_eq!(
+        post_deploy_hint_lines(&["copilot", "codex", "cursor", "gemini", "antigravity"]),
+        vec![
+            "To apply the changes:",
+            "  - copilot: reload the VS Code window (Developer: Reload Window)",
+            "  - codex: restart the Codex session",
+            "  - cursor: reload the Cursor window (Developer: Reload Window)",
+            "  - gemini: restart the Gemini CLI session",
+            "  - antigravity: restart the Antigravity session",
+        ]
+    );
+}
+
+#[test]
+fn hint_lines_skip_duplicates_and_unknown_targets() {
+    assert_eq!(
+        post_deploy_hint_lines(&["codex", "unknown", "codex"]),
+        vec![
+            "To apply the changes:",
+            "  - codex: restart the Codex session",
+        ]
+    );
+    assert!(post_deploy_hint_lines::<&str>(&[]).is_empty());
+}
+
+#[test]
+fn hint_line_snapshot_joins_targets() {
+    assert_eq!(
+        post_deploy_hint_line(&["copilot", "codex"]).as_deref(),
+        Some(
+            "To apply the changes: copilot: reload the VS Code window (Developer: Reload Window); \
+             codex: restart the Codex session"
+        )
+    );
+    assert_eq!(post_deploy_hint_line::<&str>(&[]), None);
+}
@@ -0,0 +1,104 @@
+//! 操作完了のデスクトップ通知
+//!
+//! 時間のかかる `install` / `update` を裏で実行しているときに、完了をデスクトップ通知で
+//! 知らせる（`--notify` または `~/.plm/config.toml` の `[notify] enabled = true`）。
+//! macOS は `osascript`、Linux は `notify-send` を呼ぶ。通知コマンドが無い・失敗した場合は
+//! 何もしない（通知の成否でコマンドの結果を変えない）。
+
+use std::io;
+use std::process::{Command, Stdio};
+
+/// 通知のタイトル
+pub const NOTIFY_TITLE: &str = "plm";
+
+/// 外部コマンドの起動
+///
+/// テストでは呼び出し引数を記録するモックに差し替える。
+pub trait CommandRunner {
+    /// コマンドを実行し、終了を待つ
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - Program to run.
+    /// * `args` - Arguments passed to the program.
+    fn run(&self, program: &str, args: &[String]) -> io::Result<()>;
+}
+
+/// 子プロセスとして実行する [`CommandRunner`]（入出力は捨てる）
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[String]) -> io::Result<()> {
+        Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|_| ())
+    }
+}
+
+/// OS ごとの通知コマンドと引数（通知に対応していない OS では `None`）
+///
+/// # Arguments
+///
+/// * `os` - Value of `std::env::consts::OS`.
+/// * `title` - Notification title.
+/// * `body` - Notification body.
+pub fn notification_command(
+    os: &str,
+    title: &str,
+    body: &str,
+) -> Option<(&'static str, Vec<String>)> {
+    match os {
+        "macos" => Some((
+            "osascript",
+            vec![
+                "-e".to_string(),
+                format!(
+                    "display notification {} with title {}",
+                    applescript_string(body),
+                    applescript_string(title)
+                ),
+            ],
+        )),
+        "linux" => Some(("notify-send", vec![title.to_string(), body.to_string()])),
+        _ => None,
+    }
+}
+
+/// AppleScript の文字列リテラルにする
+///
+/// # Arguments
+///
+/// * `value` - Text to quote.
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// 通知を送る（通知コマンドが無い・失敗した場合は静かにスキップする）
+///
+/// # Arguments
+///
+/// * `runner` - Runs the notification command.
+/// * `os` - Value of `std::env::consts::OS`.
+/// * `body` - Notification body.
+pub fn notify_with(runner: &dyn CommandRunner, os: &str, body: &str) {
+    if let Some((program, args)) = notification_command(os, NOTIFY_TITLE, body) {
+        let _ = runner.run(program, &args);
+    }
+}
+
+/// 実行中の OS でデスクトップ通知を送る
+///
+/// # Arguments
+///
+/// * `body` - Notification body.
+pub fn send_notification(body: &str) {
+    notify_with(&SystemRunner, std::env::consts::OS, body);
+}
+
+#[cfg(test)]
+#[path = "notify_test.rs"]
+mod tests;
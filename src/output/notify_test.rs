@@ -0,0 +1,85 @@
+use super::*;
+use std::cell::RefCell;
+
+/// 呼び出し引数を記録するモック
+#[derive(Default)]
+struct RecordingRunner {
+    calls: RefCell<Vec<(String, Vec<String>)>>,
+    missing: bool,
+}
+
+impl CommandRunner for RecordingRunner {
+    fn run(&self, program: &str, args: &[String]) -> io::Result<()> {
+        self.calls
+            .borrow_mut()
+            .push((program.to_string(), args.to_vec()));
+        if self.missing {
+            return Err(io::Error::from(io::ErrorKind::NotFound));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn notify_on_macos_runs_osascript() {
+    let runner = RecordingRunner::default();
+
+    notify_with(&runner, "macos", "5 plugins updated, 1 failed");
+
+    assert_eq!(
+        runner.calls.into_inner(),
+        vec![(
+            "osascript".to_string(),
+            vec![
+                "-e".to_string(),
+                r#"display notification "5 plugins updated, 1 failed" with title "plm""#
+                    .to_string()
+            ]
+        )]
+    );
+}
+
+#[test]
+fn notify_on_linux_runs_notify_send() {
+    let runner = RecordingRunner::default();
+
+    notify_with(&runner, "linux", "1 plugin updated");
+
+    assert_eq!(
+        runner.calls.into_inner(),
+        vec![(
+            "notify-send".to_string(),
+            vec!["plm".to_string(), "1 plugin updated".to_string()]
+        )]
+    );
+}
+
+#[test]
+fn notify_on_unsupported_os_does_nothing() {
+    let runner = RecordingRunner::default();
+
+    notify_with(&runner, "windows", "1 plugin updated");
+
+    assert!(runner.calls.into_inner().is_empty());
+}
+
+#[test]
+fn notify_ignores_missing_command() {
+    let runner = RecordingRunner {
+        missing: true,
+        ..Default::default()
+    };
+
+    notify_with(&runner, "linux", "1 plugin updated");
+
+    assert_eq!(runner.calls.into_inner().len(), 1);
+}
+
+#[test]
+fn osascript_body_escapes_quotes() {
+    let (_, args) = notification_command("macos", "plm", r#"failed: "a\b""#).unwrap();
+    assert_eq!(
+        args[1],
+        r#"display notification "failed: \"a\\b\"" with title "plm""#
+    );
+}
@@ -0,0 +1,87 @@
+//! 日時の表示
+//!
+//! `plm info` / `plm list` / TUI で「最後に更新された日時」を
+//! `3 days ago (1.1.0 → 1.2.0)` のような相対表記で示す。
+
+use crate::plugin::UpdateRecord;
+use chrono::{DateTime, Utc};
+
+/// 現在時刻からの経過時間を `3 days ago` のような英語表記にする
+///
+/// 1 分未満（および未来の日時）は `just now`。月は 30 日、年は 365 日として数える。
+///
+/// # Arguments
+///
+/// * `at` - Time to describe.
+/// * `now` - Current time.
+pub fn relative_time(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now - at;
+    let (count, unit) = match elapsed.num_minutes() {
+        ..=0 => return "just now".to_string(),
+        m @ 1..=59 => (m, "minute"),
+        _ => match elapsed.num_hours() {
+            h @ ..=23 => (h, "hour"),
+            _ => match elapsed.num_days() {
+                d @ ..=29 => (d, "day"),
+                d @ 30..=364 => (d / 30, "month"),
+                d => (d / 365, "year"),
+            },
+        },
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{} {}{} ago", count, unit, plural)
+}
+
+/// RFC3339 の日時文字列を相対表記にする（解釈できなければ元の文字列のまま）
+///
+/// # Arguments
+///
+/// * `at` - RFC3339 timestamp.
+/// * `now` - Current time.
+pub fn relative_timestamp(at: &str, now: DateTime<Utc>) -> String {
+    match DateTime::parse_from_rfc3339(at) {
+        Ok(at) => relative_time(at.with_timezone(&Utc), now),
+        Err(_) => at.to_string(),
+    }
+}
+
+/// 最後の更新の要約（`3 days ago (1.1.0 → 1.2.0)`）
+///
+/// 履歴が無い旧形式のメタデータでは日時だけを返す。日時も無ければ `None`。
+///
+/// # Arguments
+///
+/// * `last_updated` - Last update (or install) time recorded for the plugin.
+/// * `last_update` - Latest entry of the update history, if any.
+/// * `now` - Current time.
+pub fn last_updated_summary(
+    last_updated: Option<&str>,
+    last_update: Option<&UpdateRecord>,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let at = last_update.map(|r| r.at.as_str()).or(last_updated)?;
+    let when = relative_timestamp(at, now);
+    Some(match last_update.and_then(version_change) {
+        Some(change) => format!("{} ({})", when, change),
+        None => when,
+    })
+}
+
+/// 履歴 1 件のバージョン変化（`1.1.0 → 1.2.0`、新規インストールは `installed 1.2.0`）
+///
+/// # Arguments
+///
+/// * `record` - Update history entry.
+pub fn version_change(record: &UpdateRecord) -> Option<String> {
+    match (record.from_version.as_deref(), record.to_version.as_deref()) {
+        (Some(from), Some(to)) if from != to => Some(format!("{} → {}", from, to)),
+        (_, Some(to)) if record.from_version.is_none() => Some(format!("installed {}", to)),
+        (_, Some(to)) => Some(to.to_string()),
+        (Some(from), None) => Some(format!("{} → ?", from)),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "time_test.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::plugin::UpdateResult;
+use chrono::{Duration, TimeZone};
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()
+}
+
+fn record(from: Option<&str>, to: Option<&str>, result: UpdateResult) -> UpdateRecord {
+    UpdateRecord {
+        at: "2026-03-07T12:00:00Z".to_string(),
+        from_version: from.map(str::to_string),
+        to_version: to.map(str::to_string),
+        result,
+    }
+}
+
+#[test]
+fn relative_time_picks_the_largest_unit() {
+    let now = now();
+    let cases = [
+        (Duration::seconds(30), "just now"),
+        (Duration::minutes(1), "1 minute ago"),
+        (Duration::minutes(45), "45 minutes ago"),
+        (Duration::hours(1), "1 hour ago"),
+        (Duration::hours(23), "23 hours ago"),
+        (Duration::days(3), "3 days ago"),
+        (Duration::days(45), "1 month ago"),
+        (Duration::days(200), "6 months ago"),
+        (Duration::days(800), "2 years ago"),
+    ];
+    for (elapsed, expected) in cases {
+        assert_eq!(relative_time(now - elapsed, now), expected);
+    }
+}
+
+#[test]
+fn relative_time_treats_future_as_just_now() {
+    let now = now();
+    assert_eq!(relative_time(now + Duration::hours(2), now), "just now");
+}
+
+#[test]
+fn relative_timestamp_keeps_unparsable_input() {
+    assert_eq!(relative_timestamp("yesterday", now()), "yesterday");
+    assert_eq!(
+        relative_timestamp("2026-03-07T12:00:00Z", now()),
+        "3 days ago"
+    );
+}
+
+#[test]
+fn last_updated_summary_shows_version_change() {
+    let last = record(Some("1.1.0"), Some("1.2.0"), UpdateResult::Updated);
+    assert_eq!(
+        last_updated_summary(None, Some(&last), now()).as_deref(),
+        Some("3 days ago (1.1.0 → 1.2.0)")
+    );
+
+    let installed = record(None, Some("1.0.0"), UpdateResult::Installed);
+    assert_eq!(
+        last_updated_summary(None, Some(&installed), now()).as_deref(),
+        Some("3 days ago (installed 1.0.0)")
+    );
+}
+
+#[test]
+fn last_updated_summary_falls_back_to_timestamp_without_history() {
+    assert_eq!(
+        last_updated_summary(Some("2026-03-09T12:00:00Z"), None, now()).as_deref(),
+        Some("1 day ago")
+    );
+    assert_eq!(last_updated_summary(None, None, now()), None);
+}
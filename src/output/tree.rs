@@ -0,0 +1,79 @@
+//! 罫線付きツリー表示
+//!
+//! 深さ優先順に並べたエントリを `├──` / `│   ` / `└──` の罫線でつないだテキストにする。
+//! `plm list --tree` と `plm info --files` で共用する。
+
+/// ツリーの 1 行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeEntry {
+    /// ルート直下を 1 とする深さ
+    pub depth: usize,
+    /// 罫線の後ろに表示するラベル
+    pub label: String,
+}
+
+impl TreeEntry {
+    /// # Arguments
+    ///
+    /// * `depth` - Depth of the entry (`1` for direct children of the root).
+    /// * `label` - Text printed after the branch characters.
+    pub fn new(depth: usize, label: impl Into<String>) -> Self {
+        Self {
+            depth,
+            label: label.into(),
+        }
+    }
+}
+
+/// ルートラベルと深さ優先順のエントリからツリー表示用テキストを組み立てる
+///
+/// 各行は改行で終わる。ラベルに色付けが必要な場合は呼び出し側で済ませておく。
+///
+/// # Arguments
+///
+/// * `root_label` - Label printed on the first line.
+/// * `entries` - Entries in depth-first order; a child directly follows its parent.
+pub fn render_tree(root_label: &str, entries: &[TreeEntry]) -> String {
+    let mut out = String::new();
+    out.push_str(root_label);
+    out.push('\n');
+
+    let is_last = last_sibling_flags(entries);
+    // 祖先ごとに「まだ後続の兄弟がいるか」を保持し、罫線の継続に使う
+    let mut open_ancestors: Vec<bool> = Vec::new();
+
+    for (entry, &last) in entries.iter().zip(&is_last) {
+        open_ancestors.truncate(entry.depth.saturating_sub(1));
+        for &open in &open_ancestors {
+            out.push_str(if open { "│   " } else { "    " });
+        }
+        out.push_str(if last { "└── " } else { "├── " });
+        out.push_str(&entry.label);
+        out.push('\n');
+        open_ancestors.push(!last);
+    }
+    out
+}
+
+/// 各エントリが同じ親の中で最後の兄弟かを求める
+///
+/// 末尾から走査し、深さごとに「後続の兄弟を見たか」を記録する。
+/// 浅いエントリに戻った時点で、それより深い記録は別の親のものなので破棄する。
+///
+/// # Arguments
+///
+/// * `entries` - Entries in depth-first order.
+fn last_sibling_flags(entries: &[TreeEntry]) -> Vec<bool> {
+    let mut flags = vec![false; entries.len()];
+    let mut seen: Vec<bool> = Vec::new();
+    for (i, entry) in entries.iter().enumerate().rev() {
+        seen.resize(entry.depth + 1, false);
+        flags[i] = !seen[entry.depth];
+        seen[entry.depth] = true;
+    }
+    flags
+}
+
+#[cfg(test)]
+#[path = "tree_test.rs"]
+mod tests;
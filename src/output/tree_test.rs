@@ -0,0 +1,54 @@
+use super::*;
+
+fn entries(items: &[(usize, &str)]) -> Vec<TreeEntry> {
+    items
+        .iter()
+        .map(|(depth, label)| TreeEntry::new(*depth, *label))
+        .collect()
+}
+
+#[test]
+fn render_tree_draws_nested_branches() {
+    let out = render_tree(
+        "formatter",
+        &entries(&[
+            (1, "commands"),
+            (2, "deploy"),
+            (2, "format"),
+            (1, "skills"),
+            (2, "fmt"),
+        ]),
+    );
+
+    let expected = "\
+formatter
+├── commands
+│   ├── deploy
+│   └── format
+└── skills
+    └── fmt
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_tree_continues_lines_past_deeper_entries() {
+    let out = render_tree(
+        "root",
+        &entries(&[(1, "a"), (2, "a1"), (3, "a1x"), (1, "b")]),
+    );
+
+    let expected = "\
+root
+├── a
+│   └── a1
+│       └── a1x
+└── b
+";
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn render_tree_without_entries_prints_root_only() {
+    assert_eq!(render_tree("empty", &[]), "empty\n");
+}
@@ -1,4 +1,4 @@
-//! Parser module for command/prompt/agent files.
+//! Parser module for command/prompt/agent files and hooks configs.
 
 mod claude_code;
 mod codex;
@@ -6,8 +6,10 @@ pub mod convert;
 mod copilot;
 mod frontmatter;
 
-pub use claude_code::{ClaudeCodeAgent, ClaudeCodeCommand};
+pub use claude_code::{ClaudeCodeAgent, ClaudeCodeCommand, ClaudeCodeHookConfig};
 pub use convert::TargetType;
+pub(crate) use copilot::CopilotHookConfig;
+pub use frontmatter::parse_frontmatter;
 
 #[cfg(test)]
 mod convert_test;
@@ -1,12 +1,16 @@
-//! Claude Code parser subgroup (commands and agents).
+//! Claude Code parser subgroup (commands, agents and hooks config).
 
 mod agent;
 mod command;
+mod hook;
 
 pub use agent::ClaudeCodeAgent;
 pub use command::ClaudeCodeCommand;
+pub use hook::{ClaudeCodeHookConfig, ClaudeCodeMatcherGroup};
 
 #[cfg(test)]
 mod agent_test;
 #[cfg(test)]
 mod command_test;
+#[cfg(test)]
+mod hook_test;
@@ -11,8 +11,8 @@ use super::super::codex::CodexAgent;
 use super::super::convert::{self, TargetFormat, TargetType};
 use super::super::copilot::CopilotAgent;
 use super::super::frontmatter::{
-    emit_frontmatter, normalize_optional_name, parse_frontmatter, stem_without_suffixes,
-    ParsedDocument,
+    deployed_body, emit_frontmatter, normalize_optional_name, parse_frontmatter,
+    stem_without_suffixes, ParsedDocument,
 };
 
 /// Claude Code Agent frontmatter fields.
@@ -126,6 +126,115 @@ impl ClaudeCodeAgent {
         }
     }
 
+    /// Returns the frontmatter fields that are set but cannot be represented
+    /// in the target format (lost on conversion).
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Destination format the agent would be converted into.
+    pub fn dropped_fields(&self, target: TargetType) -> Vec<&'static str> {
+        let mut dropped = Vec::new();
+        if target == TargetType::Codex {
+            if self.tools.is_some() {
+                dropped.push("tools");
+            }
+            if self.model.is_some() {
+                dropped.push("model");
+            }
+        }
+        dropped
+    }
+
+    /// Applies edits made to a deployed (converted) copy back onto this agent.
+    ///
+    /// Fields whose deployed value still equals the forward conversion of `self` keep
+    /// their original value. Changed fields are converted back to Claude Code format.
+    ///
+    /// Returns the merged agent and the target-only fields that were set in the
+    /// deployed file but cannot be represented in Claude Code format.
+    ///
+    /// # Arguments
+    ///
+    /// * `deployed` - Content of the deployed file in the target format.
+    /// * `target` - Format the deployed file is written in.
+    pub fn merge_deployed(
+        &self,
+        deployed: &str,
+        target: TargetType,
+    ) -> Result<(Self, Vec<&'static str>)> {
+        let mut merged = self.clone();
+        let mut lost = Vec::new();
+        match target {
+            TargetType::Copilot => {
+                let forward = CopilotAgent::parse(&self.to_copilot().to_markdown())?;
+                let agent = CopilotAgent::parse(deployed)?;
+                if agent.name != forward.name {
+                    merged.name = agent.name;
+                }
+                if agent.description != forward.description {
+                    merged.description = agent.description;
+                }
+                if agent.tools != forward.tools {
+                    merged.tools = agent
+                        .tools
+                        .map(|tools| {
+                            convert::map_tools(
+                                &tools,
+                                convert::Format::Copilot,
+                                convert::Format::ClaudeCode,
+                            )
+                        })
+                        .filter(|tools| !tools.is_empty())
+                        .map(|tools| convert::format_allowed_tools(&tools));
+                }
+                if agent.model != forward.model {
+                    merged.model = agent.model.map(|m| {
+                        convert::map_model(
+                            &m,
+                            convert::Format::Copilot,
+                            convert::Format::ClaudeCode,
+                        )
+                    });
+                }
+                if agent.body != forward.body {
+                    merged.body = deployed_body(&agent.body).to_string();
+                }
+                // target を消しただけなら Claude Code 形式でも表せる
+                if agent.target.is_some() && agent.target != forward.target {
+                    lost.push("target");
+                }
+                if agent.handoffs.is_some() {
+                    lost.push("handoffs");
+                }
+            }
+            TargetType::Codex => {
+                let forward = CodexAgent::parse(&self.to_codex().to_markdown())?;
+                let agent = CodexAgent::parse(deployed)?;
+                if agent.description != forward.description {
+                    merged.description = agent.description;
+                }
+                if agent.body != forward.body {
+                    merged.body = deployed_body(&agent.body).to_string();
+                }
+            }
+        }
+        Ok((merged, lost))
+    }
+
+    /// Converts a Copilot agent file into a Claude Code agent.
+    ///
+    /// The name field is taken directly from frontmatter (no filename fallback).
+    /// Returns the agent and the Copilot-only fields that cannot be represented in
+    /// Claude Code format.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Content of the Copilot agent file.
+    pub fn from_copilot(content: &str) -> Result<(Self, Vec<&'static str>)> {
+        // 空のエージェントに重ねると、全フィールドが Copilot 形式から変換される
+        Self::parse("")?.merge_deployed(content, TargetType::Copilot)
+    }
+
     /// Converts to Copilot Agent format (internal).
     fn to_copilot(&self) -> CopilotAgent {
         // Tool conversion: comma-separated string -> array -> convert -> deduplicate
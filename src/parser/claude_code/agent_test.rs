@@ -405,3 +405,74 @@ fn to_codex_preserves_body() {
     // Body should be unchanged
     assert!(md.contains("$ARGUMENTS"));
 }
+
+#[test]
+fn dropped_fields_only_for_codex() {
+    let agent = ClaudeCodeAgent::parse("---\ntools: Read, Grep\nmodel: opus\n---\nBody").unwrap();
+
+    assert!(agent.dropped_fields(TargetType::Copilot).is_empty());
+    assert_eq!(
+        agent.dropped_fields(TargetType::Codex),
+        vec!["tools", "model"]
+    );
+}
+
+#[test]
+fn merge_deployed_applies_edits_and_reports_handoffs() {
+    let agent =
+        ClaudeCodeAgent::parse("---\ndescription: Reviewer\ntools: Read, Grep\n---\nReview code.")
+            .unwrap();
+    let deployed = agent
+        .to_format(TargetType::Copilot)
+        .unwrap()
+        .to_markdown()
+        .replace(
+            "description: Reviewer",
+            "description: Strict reviewer\nhandoffs:\n- agent: fixer",
+        );
+
+    let (merged, lost) = agent
+        .merge_deployed(&deployed, TargetType::Copilot)
+        .unwrap();
+
+    assert_eq!(merged.description.as_deref(), Some("Strict reviewer"));
+    assert_eq!(merged.tools.as_deref(), Some("Read, Grep"));
+    assert_eq!(merged.body, "Review code.");
+    assert_eq!(lost, vec!["handoffs"]);
+}
+
+#[test]
+fn merge_deployed_codex_updates_body() {
+    let agent = ClaudeCodeAgent::parse("---\nmodel: opus\n---\nOld prompt").unwrap();
+    let deployed = agent
+        .to_format(TargetType::Codex)
+        .unwrap()
+        .to_markdown()
+        .replace("Old prompt", "New prompt");
+
+    let (merged, lost) = agent.merge_deployed(&deployed, TargetType::Codex).unwrap();
+
+    assert_eq!(merged.model.as_deref(), Some("opus"));
+    assert_eq!(merged.body, "New prompt");
+    assert!(lost.is_empty());
+}
+
+#[test]
+fn from_copilot_without_frontmatter_keeps_body() {
+    let (agent, lost) = ClaudeCodeAgent::from_copilot("Review code.").unwrap();
+
+    assert!(agent.name.is_none());
+    assert!(agent.description.is_none());
+    assert_eq!(agent.body, "Review code.");
+    assert!(lost.is_empty());
+}
+
+#[test]
+fn from_copilot_reports_non_vscode_target() {
+    let (agent, lost) =
+        ClaudeCodeAgent::from_copilot("---\nname: reviewer\ntarget: github-copilot\n---\nReview.")
+            .unwrap();
+
+    assert_eq!(agent.name.as_deref(), Some("reviewer"));
+    assert_eq!(lost, vec!["target"]);
+}
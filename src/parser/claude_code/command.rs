@@ -11,8 +11,8 @@ use super::super::codex::CodexPrompt;
 use super::super::convert::{self, TargetFormat, TargetType};
 use super::super::copilot::CopilotPrompt;
 use super::super::frontmatter::{
-    emit_frontmatter, normalize_optional_name, parse_frontmatter, stem_without_suffixes,
-    ParsedDocument,
+    deployed_body, emit_frontmatter, normalize_optional_name, parse_frontmatter,
+    stem_without_suffixes, ParsedDocument,
 };
 
 /// Claude Code Command frontmatter fields.
@@ -156,6 +156,124 @@ impl ClaudeCodeCommand {
         }
     }
 
+    /// Returns the frontmatter fields that are set but cannot be represented
+    /// in the target format (lost on conversion).
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Destination format the command would be converted into.
+    pub fn dropped_fields(&self, target: TargetType) -> Vec<&'static str> {
+        let mut dropped = Vec::new();
+        if target == TargetType::Codex {
+            if self.allowed_tools.is_some() {
+                dropped.push("allowed-tools");
+            }
+            if self.argument_hint.is_some() {
+                dropped.push("argument-hint");
+            }
+            if self.model.is_some() {
+                dropped.push("model");
+            }
+        }
+        if self.disable_model_invocation.is_some() {
+            dropped.push("disable-model-invocation");
+        }
+        if self.user_invocable.is_some() {
+            dropped.push("user-invocable");
+        }
+        dropped
+    }
+
+    /// Applies edits made to a deployed (converted) copy back onto this command.
+    ///
+    /// Fields whose deployed value still equals the forward conversion of `self` keep
+    /// their original value, so lossy mappings (tools, model) and fields the target
+    /// cannot represent are preserved. Changed fields are converted back to Claude Code
+    /// format.
+    ///
+    /// Returns the merged command and the target-only fields that were set in the
+    /// deployed file but cannot be represented in Claude Code format.
+    ///
+    /// # Arguments
+    ///
+    /// * `deployed` - Content of the deployed file in the target format.
+    /// * `target` - Format the deployed file is written in.
+    pub fn merge_deployed(
+        &self,
+        deployed: &str,
+        target: TargetType,
+    ) -> Result<(Self, Vec<&'static str>)> {
+        let mut merged = self.clone();
+        let mut lost = Vec::new();
+        match target {
+            TargetType::Copilot => {
+                let forward = CopilotPrompt::parse(&self.to_copilot().to_markdown())?;
+                let prompt = CopilotPrompt::parse(deployed)?;
+                if prompt.name != forward.name {
+                    merged.name = prompt.name;
+                }
+                if prompt.description != forward.description {
+                    merged.description = prompt.description;
+                }
+                if prompt.tools != forward.tools {
+                    merged.allowed_tools = prompt.tools.map(|tools| {
+                        convert::format_allowed_tools(&convert::map_tools(
+                            &tools,
+                            convert::Format::Copilot,
+                            convert::Format::ClaudeCode,
+                        ))
+                    });
+                }
+                if prompt.hint != forward.hint {
+                    // Hint conversion: "Enter message" -> [message]
+                    merged.argument_hint = prompt
+                        .hint
+                        .map(|h| format!("[{}]", h.strip_prefix("Enter ").unwrap_or(&h)));
+                }
+                if prompt.model != forward.model {
+                    merged.model = prompt.model.map(|m| {
+                        convert::map_model(
+                            &m,
+                            convert::Format::Copilot,
+                            convert::Format::ClaudeCode,
+                        )
+                    });
+                }
+                if prompt.body != forward.body {
+                    merged.body = convert::body_copilot_to_claude(deployed_body(&prompt.body));
+                }
+                if prompt.agent.is_some() {
+                    lost.push("agent");
+                }
+            }
+            TargetType::Codex => {
+                let forward = CodexPrompt::parse(&self.to_codex().to_markdown())?;
+                let prompt = CodexPrompt::parse(deployed)?;
+                if prompt.description != forward.description {
+                    merged.description = prompt.description;
+                }
+                if prompt.body != forward.body {
+                    merged.body = deployed_body(&prompt.body).to_string();
+                }
+            }
+        }
+        Ok((merged, lost))
+    }
+
+    /// Converts a Copilot prompt file into a Claude Code command.
+    ///
+    /// The name field is taken directly from frontmatter (no filename fallback).
+    /// Returns the command and the Copilot-only fields that cannot be represented in
+    /// Claude Code format.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Content of the Copilot prompt file.
+    pub fn from_copilot(content: &str) -> Result<(Self, Vec<&'static str>)> {
+        // 空のコマンドに重ねると、全フィールドが Copilot 形式から変換される
+        Self::parse("")?.merge_deployed(content, TargetType::Copilot)
+    }
+
     /// Converts to Copilot format (internal).
     fn to_copilot(&self) -> CopilotPrompt {
         // Tool conversion: comma-separated string -> array -> convert -> deduplicate
@@ -341,3 +341,70 @@ fn to_format_copilot_deduplicates_tools() {
     // Read, Write, Edit all map to "codebase" and should be deduplicated
     assert!(md.contains("tools: ['codebase']"));
 }
+
+#[test]
+fn dropped_fields_depend_on_target() {
+    let cmd = ClaudeCodeCommand::parse(
+        "---\nallowed-tools: Read\nargument-hint: \"[msg]\"\nmodel: opus\nuser-invocable: false\n---\nBody",
+    )
+    .unwrap();
+
+    assert_eq!(
+        cmd.dropped_fields(TargetType::Copilot),
+        vec!["user-invocable"]
+    );
+    assert_eq!(
+        cmd.dropped_fields(TargetType::Codex),
+        vec!["allowed-tools", "argument-hint", "model", "user-invocable"]
+    );
+}
+
+#[test]
+fn merge_deployed_keeps_fields_unchanged_in_deployment() {
+    let cmd = ClaudeCodeCommand::parse(
+        "---\nallowed-tools: Bash(git:*), Read\nmodel: sonnet\n---\nOld body",
+    )
+    .unwrap();
+    let deployed = cmd
+        .to_format(TargetType::Copilot)
+        .unwrap()
+        .to_markdown()
+        .replace("Old body", "New body");
+
+    let (merged, lost) = cmd.merge_deployed(&deployed, TargetType::Copilot).unwrap();
+
+    // tools / model は変換で情報が落ちるため、未編集なら元の値を残す
+    assert_eq!(merged.allowed_tools.as_deref(), Some("Bash(git:*), Read"));
+    assert_eq!(merged.model.as_deref(), Some("sonnet"));
+    assert_eq!(merged.body, "New body");
+    assert!(lost.is_empty());
+}
+
+#[test]
+fn merge_deployed_reports_copilot_only_fields() {
+    let cmd = ClaudeCodeCommand::parse("Body").unwrap();
+
+    let (merged, lost) = cmd
+        .merge_deployed(
+            "---\ndescription: Edited\nagent: agent\n---\nBody",
+            TargetType::Copilot,
+        )
+        .unwrap();
+
+    assert_eq!(merged.description.as_deref(), Some("Edited"));
+    assert_eq!(lost, vec!["agent"]);
+}
+
+#[test]
+fn from_copilot_converts_every_field() {
+    let (cmd, lost) = ClaudeCodeCommand::from_copilot(
+        "---\nname: fix\ndescription: Fix it\nhint: Enter issue\nagent: agent\n---\nFix ${arguments}",
+    )
+    .unwrap();
+
+    assert_eq!(cmd.name.as_deref(), Some("fix"));
+    assert_eq!(cmd.description.as_deref(), Some("Fix it"));
+    assert_eq!(cmd.argument_hint.as_deref(), Some("[issue]"));
+    assert_eq!(cmd.body, "Fix $ARGUMENTS");
+    assert_eq!(lost, vec!["agent"]);
+}
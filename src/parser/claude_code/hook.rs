@@ -0,0 +1,125 @@
+//! Claude Code hooks config parser.
+//!
+//! Parses `hooks/hooks.json` (`{"hooks": {"PreToolUse": [{"matcher": ..., "hooks": [...]}]}}`).
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::super::convert::{self, Format};
+use super::super::copilot::{CopilotHook, CopilotHookConfig};
+
+/// Claude Code hooks config (top level of `hooks.json`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaudeCodeHookConfig {
+    /// Disables every hook when `true` (no Copilot counterpart).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_all_hooks: Option<bool>,
+
+    /// Event name -> matcher groups.
+    #[serde(default)]
+    pub hooks: BTreeMap<String, Vec<ClaudeCodeMatcherGroup>>,
+}
+
+/// Matcher group: hooks that run when `matcher` matches the tool name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClaudeCodeMatcherGroup {
+    /// Tool name alternation such as `Write|Edit` (`None` matches everything).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<String>,
+
+    /// Hook definitions in this group.
+    #[serde(default)]
+    pub hooks: Vec<ClaudeCodeHook>,
+}
+
+/// Single Claude Code hook definition.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaudeCodeHook {
+    /// Hook type (`command`, `prompt`, ...).
+    #[serde(rename = "type")]
+    pub hook_type: String,
+
+    /// Shell command to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Timeout in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
+    /// Message shown while the hook runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_message: Option<String>,
+
+    /// Runs only once per session (no Copilot counterpart).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub once: Option<bool>,
+
+    /// Runs in the background (no Copilot counterpart).
+    #[serde(default, rename = "async", skip_serializing_if = "Option::is_none")]
+    pub run_async: Option<bool>,
+}
+
+impl ClaudeCodeHookConfig {
+    /// Serializes to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Copilot CLI -> Claude Code.
+///
+/// Copilot hooks have no matcher groups, so consecutive hooks with the same
+/// matcher are merged into one group. `version`, `powershell` (when `bash` is
+/// present), `cwd` and `env` have no Claude Code counterpart and are dropped.
+impl From<CopilotHookConfig> for ClaudeCodeHookConfig {
+    fn from(config: CopilotHookConfig) -> Self {
+        let hooks = config
+            .hooks
+            .into_iter()
+            .map(|(event, entries)| {
+                let event = convert::map_hook_event(&event, Format::Copilot, Format::ClaudeCode);
+                (event, group_by_matcher(entries))
+            })
+            .collect();
+
+        ClaudeCodeHookConfig {
+            disable_all_hooks: None,
+            hooks,
+        }
+    }
+}
+
+/// Groups consecutive Copilot hooks sharing the same matcher.
+///
+/// # Arguments
+///
+/// * `entries` - Copilot hooks of a single event, in declaration order.
+fn group_by_matcher(entries: Vec<CopilotHook>) -> Vec<ClaudeCodeMatcherGroup> {
+    let mut groups: Vec<ClaudeCodeMatcherGroup> = Vec::new();
+    for entry in entries {
+        let matcher = entry
+            .matcher
+            .as_deref()
+            .map(|m| convert::map_hook_matcher(m, Format::Copilot, Format::ClaudeCode));
+        let hook = ClaudeCodeHook {
+            hook_type: entry.hook_type,
+            command: entry.bash.or(entry.powershell),
+            timeout: entry.timeout_sec,
+            status_message: entry.comment,
+            once: None,
+            run_async: None,
+        };
+        match groups.last_mut() {
+            Some(group) if group.matcher == matcher => group.hooks.push(hook),
+            _ => groups.push(ClaudeCodeMatcherGroup {
+                matcher,
+                hooks: vec![hook],
+            }),
+        }
+    }
+    groups
+}
@@ -0,0 +1,128 @@
+//! Tests for Claude Code hooks config (Copilot CLI -> Claude Code).
+
+use super::hook::{ClaudeCodeHook, ClaudeCodeHookConfig, ClaudeCodeMatcherGroup};
+use crate::parser::copilot::{CopilotHook, CopilotHookConfig};
+use std::collections::BTreeMap;
+
+fn copilot_hook(bash: &str, matcher: Option<&str>) -> CopilotHook {
+    CopilotHook {
+        hook_type: "command".to_string(),
+        bash: Some(bash.to_string()),
+        matcher: matcher.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn copilot_config(event: &str, hooks: Vec<CopilotHook>) -> CopilotHookConfig {
+    CopilotHookConfig {
+        version: 1,
+        hooks: BTreeMap::from([(event.to_string(), hooks)]),
+    }
+}
+
+#[test]
+fn parse_claude_code_config() {
+    let json = r#"{
+        "disableAllHooks": false,
+        "hooks": {
+            "PreToolUse": [
+                {"matcher": "Write|Edit", "hooks": [
+                    {"type": "command", "command": "./lint.sh", "timeout": 30, "statusMessage": "Linting", "async": true}
+                ]}
+            ]
+        }
+    }"#;
+    let config: ClaudeCodeHookConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(config.disable_all_hooks, Some(false));
+    let groups = &config.hooks["PreToolUse"];
+    assert_eq!(groups[0].matcher.as_deref(), Some("Write|Edit"));
+    let hook = &groups[0].hooks[0];
+    assert_eq!(hook.command.as_deref(), Some("./lint.sh"));
+    assert_eq!(hook.timeout, Some(30));
+    assert_eq!(hook.status_message.as_deref(), Some("Linting"));
+    assert_eq!(hook.run_async, Some(true));
+    assert_eq!(hook.once, None);
+}
+
+#[test]
+fn from_copilot_maps_event_and_fields() {
+    let mut hook = copilot_hook("./lint.sh", Some("create|edit"));
+    hook.timeout_sec = Some(30);
+    hook.comment = Some("Linting".to_string());
+
+    let config = ClaudeCodeHookConfig::from(copilot_config("preToolUse", vec![hook]));
+
+    assert_eq!(
+        config.hooks["PreToolUse"],
+        vec![ClaudeCodeMatcherGroup {
+            matcher: Some("Write|Edit".to_string()),
+            hooks: vec![ClaudeCodeHook {
+                hook_type: "command".to_string(),
+                command: Some("./lint.sh".to_string()),
+                timeout: Some(30),
+                status_message: Some("Linting".to_string()),
+                once: None,
+                run_async: None,
+            }],
+        }]
+    );
+}
+
+#[test]
+fn from_copilot_keeps_unknown_event_name() {
+    let config = ClaudeCodeHookConfig::from(copilot_config(
+        "errorOccurred",
+        vec![copilot_hook("./report.sh", None)],
+    ));
+    assert!(config.hooks.contains_key("errorOccurred"));
+}
+
+#[test]
+fn from_copilot_merges_consecutive_hooks_with_same_matcher() {
+    let config = ClaudeCodeHookConfig::from(copilot_config(
+        "postToolUse",
+        vec![
+            copilot_hook("./a.sh", Some("bash")),
+            copilot_hook("./b.sh", Some("bash")),
+            copilot_hook("./c.sh", None),
+            copilot_hook("./d.sh", Some("bash")),
+        ],
+    ));
+    let groups = &config.hooks["PostToolUse"];
+    let shape: Vec<(Option<&str>, usize)> = groups
+        .iter()
+        .map(|g| (g.matcher.as_deref(), g.hooks.len()))
+        .collect();
+    assert_eq!(shape, vec![(Some("Bash"), 2), (None, 1), (Some("Bash"), 1)]);
+}
+
+#[test]
+fn from_copilot_falls_back_to_powershell_command() {
+    let hook = CopilotHook {
+        hook_type: "command".to_string(),
+        powershell: Some("./lint.ps1".to_string()),
+        ..Default::default()
+    };
+    let config = ClaudeCodeHookConfig::from(copilot_config("sessionStart", vec![hook]));
+    assert_eq!(
+        config.hooks["SessionStart"][0].hooks[0].command.as_deref(),
+        Some("./lint.ps1")
+    );
+}
+
+#[test]
+fn to_json_uses_claude_code_field_names() {
+    let config = ClaudeCodeHookConfig::from(copilot_config(
+        "agentStop",
+        vec![copilot_hook("./done.sh", None)],
+    ));
+    let value: serde_json::Value = serde_json::from_str(&config.to_json().unwrap()).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "hooks": {
+                "Stop": [{"hooks": [{"type": "command", "command": "./done.sh"}]}]
+            }
+        })
+    );
+}
@@ -1,6 +1,7 @@
 //! Conversion utilities for SlashCommand formats.
 //!
-//! Provides mappings between Claude Code, Copilot, and Codex tool/model names.
+//! Provides mappings between Claude Code, Copilot, and Codex tool/model names,
+//! plus hook event/matcher names between Claude Code and Copilot CLI.
 
 /// Target format trait for conversion output.
 ///
@@ -20,6 +21,10 @@ pub enum TargetType {
 }
 
 pub use crate::format::Format;
+use crate::hooks::converter::ToolMap;
+use crate::hooks::event::claude_code::{to_source_event, to_target_event, HookEvent};
+use crate::hooks::event::copilot::COPILOT_EVENT_ENTRIES;
+use crate::hooks::tool::copilot::CopilotToolMap;
 
 /// Record-oriented row of [`TOOL_TABLE`].
 ///
@@ -182,6 +187,59 @@ pub(crate) fn map_tools(tools: &[String], from: Format, to: Format) -> Vec<Strin
     result
 }
 
+/// Hook event name conversion between Claude Code and Copilot CLI.
+///
+/// Uses the same event table as the hooks converter (`PreToolUse` <->
+/// `preToolUse`, `Stop` <-> `agentStop`, ...). Events without a counterpart
+/// are returned unchanged so that unknown names survive the conversion.
+///
+/// # Arguments
+///
+/// * `event` - Event name to convert (leading/trailing whitespace is trimmed).
+/// * `from` - Source format of the input event name.
+/// * `to` - Destination format to convert the event name into.
+pub(crate) fn map_hook_event(event: &str, from: Format, to: Format) -> String {
+    let trimmed = event.trim();
+    match (from, to) {
+        (Format::ClaudeCode, Format::Copilot) => {
+            to_target_event(COPILOT_EVENT_ENTRIES, &HookEvent::from_str(trimmed))
+                .map(str::to_string)
+                .unwrap_or_else(|| trimmed.to_string())
+        }
+        (Format::Copilot, Format::ClaudeCode) => to_source_event(COPILOT_EVENT_ENTRIES, trimmed)
+            .map(|e| e.as_str().to_string())
+            .unwrap_or_else(|| trimmed.to_string()),
+        _ => trimmed.to_string(),
+    }
+}
+
+/// Hook matcher conversion between Claude Code and Copilot CLI.
+///
+/// Matchers are `|`-separated tool name alternations (e.g. `Write|Edit`).
+/// Each segment is mapped with the hooks tool table and duplicates produced
+/// by N:1 mappings are collapsed while keeping the original order
+/// (`Edit|MultiEdit` -> `edit`). Unknown tool names are passed through.
+///
+/// # Arguments
+///
+/// * `matcher` - Matcher pattern to convert.
+/// * `from` - Source format of the matcher.
+/// * `to` - Destination format to convert the matcher into.
+pub(crate) fn map_hook_matcher(matcher: &str, from: Format, to: Format) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for segment in matcher.split('|') {
+        let mapped = match (from, to) {
+            (Format::ClaudeCode, Format::Copilot) => CopilotToolMap.map_tool(segment),
+            (Format::Copilot, Format::ClaudeCode) => CopilotToolMap.reverse_map_tool(segment),
+            _ => segment.trim().to_string(),
+        };
+        if !parts.contains(&mapped) {
+            parts.push(mapped);
+        }
+    }
+    parts.join("|")
+}
+
 /// Record-oriented row of [`MODEL_TABLE`].
 ///
 /// Each row represents a single logical model with its name in every supported
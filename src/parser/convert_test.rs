@@ -327,6 +327,107 @@ fn test_map_model_unsupported_pair_passthrough_lowercase() {
     );
 }
 
+// ============================================================================
+// Hook event / matcher conversion tests
+// ============================================================================
+
+#[test]
+fn test_map_hook_event_claude_to_copilot() {
+    assert_eq!(
+        map_hook_event("PreToolUse", Format::ClaudeCode, Format::Copilot),
+        "preToolUse"
+    );
+    assert_eq!(
+        map_hook_event("UserPromptSubmit", Format::ClaudeCode, Format::Copilot),
+        "userPromptSubmitted"
+    );
+    assert_eq!(
+        map_hook_event("Stop", Format::ClaudeCode, Format::Copilot),
+        "agentStop"
+    );
+}
+
+#[test]
+fn test_map_hook_event_copilot_to_claude() {
+    assert_eq!(
+        map_hook_event("preToolUse", Format::Copilot, Format::ClaudeCode),
+        "PreToolUse"
+    );
+    assert_eq!(
+        map_hook_event("userPromptSubmitted", Format::Copilot, Format::ClaudeCode),
+        "UserPromptSubmit"
+    );
+    assert_eq!(
+        map_hook_event("agentStop", Format::Copilot, Format::ClaudeCode),
+        "Stop"
+    );
+}
+
+#[test]
+fn test_map_hook_event_unknown_passthrough() {
+    assert_eq!(
+        map_hook_event("Notification", Format::ClaudeCode, Format::Copilot),
+        "Notification"
+    );
+    assert_eq!(
+        map_hook_event("errorOccurred", Format::Copilot, Format::ClaudeCode),
+        "errorOccurred"
+    );
+    assert_eq!(map_hook_event("", Format::Copilot, Format::ClaudeCode), "");
+}
+
+#[test]
+fn test_map_hook_event_unsupported_pair_passthrough() {
+    assert_eq!(
+        map_hook_event("PreToolUse", Format::ClaudeCode, Format::Codex),
+        "PreToolUse"
+    );
+}
+
+#[test]
+fn test_map_hook_matcher_claude_to_copilot() {
+    assert_eq!(
+        map_hook_matcher("Bash", Format::ClaudeCode, Format::Copilot),
+        "bash"
+    );
+    assert_eq!(
+        map_hook_matcher("Write|Edit", Format::ClaudeCode, Format::Copilot),
+        "create|edit"
+    );
+}
+
+#[test]
+fn test_map_hook_matcher_claude_to_copilot_deduplicates() {
+    assert_eq!(
+        map_hook_matcher("Edit|MultiEdit|Read", Format::ClaudeCode, Format::Copilot),
+        "edit|view"
+    );
+}
+
+#[test]
+fn test_map_hook_matcher_copilot_to_claude() {
+    assert_eq!(
+        map_hook_matcher("create|edit", Format::Copilot, Format::ClaudeCode),
+        "Write|Edit"
+    );
+    assert_eq!(
+        map_hook_matcher("bash|powershell", Format::Copilot, Format::ClaudeCode),
+        "Bash"
+    );
+}
+
+#[test]
+fn test_map_hook_matcher_unknown_passthrough() {
+    assert_eq!(
+        map_hook_matcher("mcp__github|Bash", Format::ClaudeCode, Format::Copilot),
+        "mcp__github|bash"
+    );
+    assert_eq!(
+        map_hook_matcher("custom_tool", Format::Copilot, Format::ClaudeCode),
+        "custom_tool"
+    );
+}
+
 // ============================================================================
 // Body variable conversion tests
 // ============================================================================
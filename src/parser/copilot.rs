@@ -1,12 +1,16 @@
-//! Copilot parser subgroup (prompts and agents).
+//! Copilot parser subgroup (prompts, agents and hooks config).
 
 mod agent;
+mod hook;
 mod prompt;
 
 pub(crate) use agent::CopilotAgent;
+pub(crate) use hook::{CopilotHook, CopilotHookConfig};
 pub(crate) use prompt::CopilotPrompt;
 
 #[cfg(test)]
 mod agent_test;
 #[cfg(test)]
+mod hook_test;
+#[cfg(test)]
 mod prompt_test;
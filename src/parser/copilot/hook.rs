@@ -0,0 +1,156 @@
+//! Copilot CLI hooks config parser.
+//!
+//! Parses `hooks.json` in Copilot CLI format
+//! (`{"version": 1, "hooks": {"preToolUse": [{"type": "command", "bash": ...}]}}`).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::super::claude_code::{ClaudeCodeHookConfig, ClaudeCodeMatcherGroup};
+use super::super::convert::{self, Format};
+
+/// Copilot CLI hooks config format version.
+const COPILOT_HOOKS_VERSION: u32 = 1;
+
+fn default_version() -> u32 {
+    COPILOT_HOOKS_VERSION
+}
+
+/// Copilot CLI hooks config (top level of `hooks.json`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CopilotHookConfig {
+    /// Config format version.
+    #[serde(default = "default_version")]
+    pub version: u32,
+
+    /// Event name -> hooks (flat list, no matcher groups).
+    #[serde(default)]
+    pub hooks: BTreeMap<String, Vec<CopilotHook>>,
+}
+
+impl Default for CopilotHookConfig {
+    fn default() -> Self {
+        Self {
+            version: COPILOT_HOOKS_VERSION,
+            hooks: BTreeMap::new(),
+        }
+    }
+}
+
+/// Single Copilot CLI hook definition.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopilotHook {
+    /// Hook type (`command`).
+    #[serde(rename = "type")]
+    pub hook_type: String,
+
+    /// Command run on bash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bash: Option<String>,
+
+    /// Command run on PowerShell.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub powershell: Option<String>,
+
+    /// Tool name alternation in Copilot names (e.g. `create|edit`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matcher: Option<String>,
+
+    /// Working directory of the command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Extra environment variables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<BTreeMap<String, String>>,
+
+    /// Timeout in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_sec: Option<u64>,
+
+    /// Free-form description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl CopilotHookConfig {
+    /// Parses `content` only when it is a Copilot CLI hooks config.
+    ///
+    /// Cursor also writes a top-level `version`, so the config is treated as
+    /// Copilot CLI format only when at least one hook has a `bash` or
+    /// `powershell` command. Returns `None` for anything else (including
+    /// invalid JSON).
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Raw `hooks.json` content.
+    pub fn parse_if_copilot(content: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(content).ok()?;
+        value.get("version")?;
+        let has_copilot_command = value
+            .get("hooks")?
+            .as_object()?
+            .values()
+            .filter_map(|entries| entries.as_array())
+            .flatten()
+            .any(|hook| hook.get("bash").is_some() || hook.get("powershell").is_some());
+        if !has_copilot_command {
+            return None;
+        }
+        serde_json::from_value(value).ok()
+    }
+}
+
+/// Claude Code -> Copilot CLI.
+///
+/// Matcher groups are flattened and the matcher is copied onto every hook.
+/// `disableAllHooks`, `once` and `async` have no Copilot counterpart and are
+/// dropped.
+impl From<ClaudeCodeHookConfig> for CopilotHookConfig {
+    fn from(config: ClaudeCodeHookConfig) -> Self {
+        let hooks = config
+            .hooks
+            .into_iter()
+            .map(|(event, groups)| {
+                let event = convert::map_hook_event(&event, Format::ClaudeCode, Format::Copilot);
+                (event, flatten_groups(groups))
+            })
+            .collect();
+
+        CopilotHookConfig {
+            version: COPILOT_HOOKS_VERSION,
+            hooks,
+        }
+    }
+}
+
+/// Flattens Claude Code matcher groups into Copilot hooks.
+///
+/// # Arguments
+///
+/// * `groups` - Matcher groups of a single event.
+fn flatten_groups(groups: Vec<ClaudeCodeMatcherGroup>) -> Vec<CopilotHook> {
+    let mut result = Vec::new();
+    for group in groups {
+        let matcher = group
+            .matcher
+            .as_deref()
+            .filter(|m| !m.is_empty())
+            .map(|m| convert::map_hook_matcher(m, Format::ClaudeCode, Format::Copilot));
+        for hook in group.hooks {
+            result.push(CopilotHook {
+                hook_type: hook.hook_type,
+                bash: hook.command,
+                powershell: None,
+                matcher: matcher.clone(),
+                cwd: None,
+                env: None,
+                timeout_sec: hook.timeout,
+                comment: hook.status_message,
+            });
+        }
+    }
+    result
+}
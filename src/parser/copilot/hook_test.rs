@@ -0,0 +1,185 @@
+//! Tests for Copilot CLI hooks config (Claude Code -> Copilot CLI, roundtrip).
+
+use super::hook::{CopilotHook, CopilotHookConfig};
+use crate::parser::claude_code::ClaudeCodeHookConfig;
+use std::collections::BTreeMap;
+
+const CLAUDE_CODE_HOOKS: &str = r#"{
+    "hooks": {
+        "PreToolUse": [
+            {"matcher": "Write|Edit", "hooks": [
+                {"type": "command", "command": "./lint.sh", "timeout": 30, "statusMessage": "Linting"},
+                {"type": "command", "command": "./fmt.sh"}
+            ]},
+            {"hooks": [{"type": "command", "command": "./audit.sh"}]}
+        ],
+        "UserPromptSubmit": [
+            {"hooks": [{"type": "command", "command": "./prompt.sh"}]}
+        ]
+    }
+}"#;
+
+fn claude_code_config(json: &str) -> ClaudeCodeHookConfig {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn from_claude_code_maps_events_and_flattens_groups() {
+    let config = CopilotHookConfig::from(claude_code_config(CLAUDE_CODE_HOOKS));
+
+    assert_eq!(config.version, 1);
+    let keys: Vec<&str> = config.hooks.keys().map(String::as_str).collect();
+    assert_eq!(keys, vec!["preToolUse", "userPromptSubmitted"]);
+
+    let pre = &config.hooks["preToolUse"];
+    assert_eq!(pre.len(), 3);
+    assert_eq!(
+        pre[0],
+        CopilotHook {
+            hook_type: "command".to_string(),
+            bash: Some("./lint.sh".to_string()),
+            matcher: Some("create|edit".to_string()),
+            timeout_sec: Some(30),
+            comment: Some("Linting".to_string()),
+            ..Default::default()
+        }
+    );
+    assert_eq!(pre[1].matcher.as_deref(), Some("create|edit"));
+    assert_eq!(pre[2].matcher, None);
+}
+
+#[test]
+fn from_claude_code_keeps_unknown_event_name() {
+    let config = CopilotHookConfig::from(claude_code_config(
+        r#"{"hooks": {"Notification": [{"hooks": [{"type": "command", "command": "./n.sh"}]}]}}"#,
+    ));
+    assert_eq!(
+        config.hooks["Notification"][0].bash.as_deref(),
+        Some("./n.sh")
+    );
+}
+
+#[test]
+fn from_claude_code_treats_empty_matcher_as_none() {
+    let config = CopilotHookConfig::from(claude_code_config(
+        r#"{"hooks": {"PostToolUse": [{"matcher": "", "hooks": [{"type": "command", "command": "./x.sh"}]}]}}"#,
+    ));
+    assert_eq!(config.hooks["postToolUse"][0].matcher, None);
+}
+
+#[test]
+fn serialized_output_uses_copilot_field_names() {
+    let config = CopilotHookConfig::from(claude_code_config(
+        r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "./done.sh", "timeout": 5}]}]}}"#,
+    ));
+    assert_eq!(
+        serde_json::to_value(&config).unwrap(),
+        serde_json::json!({
+            "version": 1,
+            "hooks": {
+                "agentStop": [{"type": "command", "bash": "./done.sh", "timeoutSec": 5}]
+            }
+        })
+    );
+}
+
+// ============================================================================
+// Roundtrip tests
+// ============================================================================
+
+#[test]
+fn roundtrip_claude_code_preserves_supported_fields() {
+    let original = claude_code_config(CLAUDE_CODE_HOOKS);
+    let back = ClaudeCodeHookConfig::from(CopilotHookConfig::from(original.clone()));
+    assert_eq!(back, original);
+}
+
+#[test]
+fn roundtrip_claude_code_loses_claude_only_fields() {
+    let original = claude_code_config(
+        r#"{
+            "disableAllHooks": true,
+            "hooks": {
+                "SessionStart": [{"hooks": [{"type": "command", "command": "./s.sh", "once": true, "async": true}]}]
+            }
+        }"#,
+    );
+    let back = ClaudeCodeHookConfig::from(CopilotHookConfig::from(original));
+
+    // disableAllHooks / once / async have no Copilot counterpart.
+    assert_eq!(back.disable_all_hooks, None);
+    let hook = &back.hooks["SessionStart"][0].hooks[0];
+    assert_eq!(hook.command.as_deref(), Some("./s.sh"));
+    assert_eq!(hook.once, None);
+    assert_eq!(hook.run_async, None);
+}
+
+#[test]
+fn roundtrip_claude_code_normalizes_n_to_1_tools() {
+    // Edit and MultiEdit both map to `edit`, which maps back to `Edit` only.
+    let original = claude_code_config(
+        r#"{"hooks": {"PreToolUse": [{"matcher": "Edit|MultiEdit", "hooks": [{"type": "command", "command": "./x.sh"}]}]}}"#,
+    );
+    let back = ClaudeCodeHookConfig::from(CopilotHookConfig::from(original));
+    assert_eq!(back.hooks["PreToolUse"][0].matcher.as_deref(), Some("Edit"));
+}
+
+#[test]
+fn roundtrip_copilot_loses_copilot_only_fields() {
+    let original = CopilotHookConfig {
+        version: 1,
+        hooks: BTreeMap::from([(
+            "preToolUse".to_string(),
+            vec![CopilotHook {
+                hook_type: "command".to_string(),
+                bash: Some("./lint.sh".to_string()),
+                powershell: Some("./lint.ps1".to_string()),
+                matcher: Some("bash".to_string()),
+                cwd: Some("scripts".to_string()),
+                env: Some(BTreeMap::from([("CI".to_string(), "1".to_string())])),
+                timeout_sec: Some(10),
+                comment: Some("Lint".to_string()),
+            }],
+        )]),
+    };
+    let back = CopilotHookConfig::from(ClaudeCodeHookConfig::from(original));
+
+    // powershell / cwd / env have no Claude Code counterpart.
+    let hook = &back.hooks["preToolUse"][0];
+    assert_eq!(
+        hook,
+        &CopilotHook {
+            hook_type: "command".to_string(),
+            bash: Some("./lint.sh".to_string()),
+            matcher: Some("bash".to_string()),
+            timeout_sec: Some(10),
+            comment: Some("Lint".to_string()),
+            ..Default::default()
+        }
+    );
+}
+
+// ============================================================================
+// Detection tests
+// ============================================================================
+
+#[test]
+fn parse_if_copilot_accepts_copilot_config() {
+    let config = CopilotHookConfig::parse_if_copilot(
+        r#"{"version": 1, "hooks": {"sessionStart": [{"type": "command", "bash": "./s.sh", "cwd": "."}]}}"#,
+    )
+    .unwrap();
+    assert_eq!(config.hooks["sessionStart"][0].cwd.as_deref(), Some("."));
+}
+
+#[test]
+fn parse_if_copilot_rejects_other_formats() {
+    // Claude Code (no version)
+    assert!(CopilotHookConfig::parse_if_copilot(CLAUDE_CODE_HOOKS).is_none());
+    // Cursor (version but `command` instead of `bash`)
+    assert!(CopilotHookConfig::parse_if_copilot(
+        r#"{"version": 1, "hooks": {"beforeShellExecution": [{"command": "./c.sh"}]}}"#,
+    )
+    .is_none());
+    assert!(CopilotHookConfig::parse_if_copilot("{ not json").is_none());
+}
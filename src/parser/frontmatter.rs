@@ -165,6 +165,19 @@ pub(crate) fn emit_frontmatter(fields: &[String], body: &str) -> String {
     }
 }
 
+/// Returns a body parsed from converted output without the blank lines that
+/// [`emit_frontmatter`] inserts after the closing `---`.
+///
+/// Used when writing an edited body back, so the separator is not doubled when the
+/// document is emitted again.
+///
+/// # Arguments
+///
+/// * `body` - Body as returned by [`parse_frontmatter`].
+pub(crate) fn deployed_body(body: &str) -> &str {
+    body.trim_start_matches(['\r', '\n'])
+}
+
 /// Formats a YAML flow sequence using single-quoted scalars.
 ///
 /// Single quotes inside items are escaped by doubling (`'` → `''`).
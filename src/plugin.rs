@@ -1,18 +1,31 @@
 mod cache;
 mod content;
+mod deployed;
 mod lifecycle;
 pub mod meta;
+mod placements;
 
-pub(crate) use cache::{cleanup_legacy_hierarchy, cleanup_plugin_directories, list_installed};
+pub(crate) use cache::{
+    cleanup_legacy_hierarchy, cleanup_plugin_directories, list_installed, rebuild_index,
+    release_all_locks, set_lock_operation, UNKNOWN_GIT_VALUE,
+};
 pub use cache::{
-    CachedPackage, GithubCacheId, LegacyCacheCleaner, PackageCache, PackageCacheAccess,
+    relative_key, CachedPackage, Checksums, GithubCacheId, LegacyCacheCleaner, PackageCache,
+    PackageCacheAccess, PluginLock, CHECKSUMS_FILE,
 };
+#[cfg(test)]
+pub(crate) use content::component_scan_count;
 pub(crate) use content::{load_plugin, Plugin};
 pub use content::{InstalledPlugin, MarketplaceContent};
+pub use deployed::{DeployedManifest, DEPLOYED_MANIFEST_FILE};
+pub(crate) use lifecycle::find_renamed_entry;
 pub use lifecycle::{
-    update_all_plugins, update_plugin, PluginAction, PluginIntent, UpdateOutcome, UpdateStatus,
+    detect_renames, migrate_rename, redeploy_all_plugins, redeploy_plugin, update_all_plugins,
+    update_plugin, update_target_exists, update_target_path, PluginAction, PluginIntent,
+    PluginRename, RedeployOptions, UpdateOutcome, UpdateStatus,
 };
 pub use meta::manifest::{Author, PluginManifest};
+pub use placements::{update_placements, ComponentConflict, PlacementLedger, PlacementRecord};
 
 /// id フォールバック: id が None なら name を返す
 ///
@@ -25,4 +38,4 @@ pub(crate) fn resolve_id<'a>(id: Option<&'a str>, name: &'a str) -> &'a str {
 }
 pub(crate) use meta::version;
 pub use meta::version::{fetch_remote_versions, UpgradeState};
-pub use meta::PluginMeta;
+pub use meta::{Channel, DisabledComponent, PluginMeta, SandboxInfo, UpdateRecord, UpdateResult};
@@ -1,13 +1,19 @@
 #[allow(clippy::module_inception)]
 mod cache;
 mod cached_package;
+mod checksum;
 mod cleanup;
 mod github_cache_id;
+mod index;
 mod legacy_cache_cleaner;
+mod lock;
 
-pub(crate) use cache::list_installed;
+pub(crate) use cache::{list_installed, rebuild_index};
 pub use cache::{PackageCache, PackageCacheAccess};
 pub use cached_package::CachedPackage;
+pub(crate) use cached_package::UNKNOWN_GIT_VALUE;
+pub use checksum::{relative_key, Checksums, CHECKSUMS_FILE};
 pub(crate) use cleanup::{cleanup_legacy_hierarchy, cleanup_plugin_directories};
 pub use github_cache_id::GithubCacheId;
 pub use legacy_cache_cleaner::LegacyCacheCleaner;
+pub use lock::{release_all_locks, set_lock_operation, PluginLock};
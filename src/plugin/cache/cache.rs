@@ -2,6 +2,8 @@
 //!
 //! GitHubやマーケットプレイスからダウンロードしたパッケージのキャッシュ管理を行う。
 
+use super::checksum::verify_checksums;
+use super::index::{PluginIndex, INDEX_FILE};
 use crate::error::{PlmError, Result};
 use crate::fs::{FileSystem, RealFs};
 use crate::marketplace::MarketplaceRef;
@@ -10,8 +12,38 @@ use crate::plugin::MarketplaceContent;
 use crate::plugin::{meta, PluginManifest};
 use std::io::{Cursor, Read};
 use std::path::{Component as PathComponent, Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
 use zip::ZipArchive;
 
+/// このプロセスが展開・保持している staging temp（中断時はこれだけを破棄する）
+///
+/// `.temp` は他の plm プロセスと共有するため、まとめて消すと並行する更新の途中経過を壊す。
+static STAGED: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// 不正な名前に対して `plugin_path` が返す、作成されることのないエントリ
+const INVALID_ENTRY_DIR: &str = ".invalid";
+
+fn staged_dirs() -> MutexGuard<'static, Vec<PathBuf>> {
+    STAGED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// # Arguments
+///
+/// * `dir` - Staging directory this process is about to populate.
+fn track_staged(dir: &Path) {
+    let mut staged = staged_dirs();
+    if !staged.iter().any(|d| d == dir) {
+        staged.push(dir.to_path_buf());
+    }
+}
+
+/// # Arguments
+///
+/// * `dir` - Staging directory that was committed or discarded.
+fn untrack_staged(dir: &Path) {
+    staged_dirs().retain(|d| d != dir);
+}
+
 // Re-export
 pub use super::cached_package::CachedPackage;
 pub(crate) use crate::plugin::meta::has_manifest;
@@ -37,21 +69,91 @@ pub(crate) fn list_installed(cache: &dyn PackageCacheAccess) -> Result<Vec<Marke
     // 一覧取得経路では個別プラグインのスキャン失敗を握りつぶし、列挙自体は続行する。
     // TUI 経由でも呼ばれるため stderr への直接出力は避ける。スキャン失敗を厳密に
     // 検出する必要のある経路（install 等）は `Plugin::new` / `try_from` を直接呼ぶこと。
-    let packages = cache
+    let cached: Vec<(Option<String>, String)> = cache
         .list()?
         .into_iter()
-        .map(PluginCacheKey::from)
-        .filter(|key| !key.name.starts_with('.'))
-        .filter_map(|key| {
-            cache
-                .load_package(key.marketplace.as_deref(), &key.name)
-                .ok()
-                .and_then(|pkg| MarketplaceContent::try_from(pkg).ok())
-        })
+        .filter(|(_, name)| !name.starts_with('.'))
         .collect();
+
+    let Some(index_path) = cache.index_path() else {
+        return Ok(cached
+            .into_iter()
+            .map(PluginCacheKey::from)
+            .filter_map(|key| scan_package(cache, &key))
+            .collect());
+    };
+
+    // mtime が変わっていないプラグインはインデックスから復元し、残りだけ再スキャンする
+    let loaded = PluginIndex::load(&index_path);
+    let mut dirty = loaded.is_none();
+    let mut index = loaded.unwrap_or_else(PluginIndex::new);
+    dirty |= index.retain_cached(&cached);
+
+    let mut packages = Vec::with_capacity(cached.len());
+    for key in cached.into_iter().map(PluginCacheKey::from) {
+        let marketplace = key.marketplace.as_deref();
+        let path = cache.plugin_path(marketplace, &key.name);
+        if let Some(content) = index.restore(marketplace, &key.name, &path) {
+            packages.push(content);
+            continue;
+        }
+        dirty |= index.remove(marketplace, &key.name);
+        if let Some(content) = scan_package(cache, &key) {
+            index.insert(&content);
+            dirty = true;
+            packages.push(content);
+        }
+    }
+
+    // インデックスの書き込み失敗は一覧取得を妨げない（次回また再スキャンされるだけ）
+    if dirty {
+        let _ = index.save(&index_path);
+    }
     Ok(packages)
 }
 
+/// インデックスを使わずに全プラグインを再スキャンし、インデックスを作り直す
+///
+/// 作り直したインデックスに登録したプラグイン数を返す。
+///
+/// # Arguments
+///
+/// * `cache` - package cache access used to enumerate stored plugins
+pub(crate) fn rebuild_index(cache: &dyn PackageCacheAccess) -> Result<usize> {
+    let index_path = cache
+        .index_path()
+        .ok_or_else(|| PlmError::Cache("This cache does not keep a plugin index".to_string()))?;
+    let mut index = PluginIndex::new();
+    for key in cache
+        .list()?
+        .into_iter()
+        .filter(|(_, name)| !name.starts_with('.'))
+        .map(PluginCacheKey::from)
+    {
+        if let Some(content) = scan_package(cache, &key) {
+            index.insert(&content);
+        }
+    }
+    index.save(&index_path)?;
+    Ok(index.len())
+}
+
+/// キャッシュ上のプラグインを読み込み、コンポーネントをスキャンする（失敗時は `None`）
+///
+/// # Arguments
+///
+/// * `cache` - package cache access
+/// * `key` - marketplace and cache directory name of the plugin
+fn scan_package(
+    cache: &dyn PackageCacheAccess,
+    key: &PluginCacheKey,
+) -> Option<MarketplaceContent> {
+    cache
+        .load_package(key.marketplace.as_deref(), &key.name)
+        .ok()
+        .and_then(|pkg| MarketplaceContent::try_from(pkg).ok())
+}
+
 /// パッケージキャッシュアクセスの抽象化トレイト
 ///
 /// 消費者はこの trait 経由でキャッシュ操作を行う。
@@ -60,6 +162,9 @@ pub(crate) fn list_installed(cache: &dyn PackageCacheAccess) -> Result<Vec<Marke
 pub trait PackageCacheAccess: Send + Sync {
     /// プラグインのキャッシュパスを取得（階層型: marketplace/plugin）
     ///
+    /// 名前が単一のパス要素でない場合はキャッシュ外を指さないよう、存在しないエントリのパスを返す
+    /// （書き込みを伴う操作はエラーで拒否する）。
+    ///
     /// # Arguments
     ///
     /// * `marketplace` - マーケットプレイス名（None の場合は "github" を使用）
@@ -112,6 +217,26 @@ pub trait PackageCacheAccess: Send + Sync {
     /// キャッシュされているプラグイン一覧を取得
     fn list(&self) -> Result<Vec<(Option<String>, String)>>;
 
+    /// 一覧用インデックス（`plugins-index.json`）のパス
+    ///
+    /// `None` のキャッシュではインデックスを使わず、一覧取得のたびに全プラグインをスキャンする。
+    fn index_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// キャッシュディレクトリを別名へ移動する（マーケットプレイス側のリネーム追従用）
+    ///
+    /// 同一ファイルシステム上の `rename` 1 回で行うため、途中失敗しても
+    /// 旧名・新名のどちらか一方にだけ存在する状態が保たれる。
+    /// 移動先が既に存在する場合はエラー。
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - marketplace name (`None` uses `"github"`)
+    /// * `from` - current cache directory name
+    /// * `to` - new cache directory name
+    fn rename(&self, marketplace: Option<&str>, from: &str, to: &str) -> Result<PathBuf>;
+
     /// プラグインをバックアップ
     ///
     /// 注意: 実行ビット/シンボリックリンクは保持されない。
@@ -297,7 +422,7 @@ impl PackageCache {
         &self.cache_dir
     }
 
-    /// カスタムキャッシュディレクトリで初期化（テスト用）
+    /// カスタムキャッシュディレクトリで初期化（テストや `install --dry-run` の一時キャッシュ用）
     ///
     /// # Arguments
     ///
@@ -321,10 +446,96 @@ impl PackageCache {
         Ok(())
     }
 
+    /// このプロセスが作った staging temp をすべて破棄する
+    ///
+    /// 中断（Ctrl-C）時の後始末用。他のプロセスが展開中の temp には触れない。
+    /// 破棄対象が無ければ no-op。
+    pub fn discard_all_staged(&self) -> Result<()> {
+        let fs = RealFs;
+        let temp_root = self.cache_dir.join(".temp");
+        let mine: Vec<PathBuf> = {
+            let mut staged = staged_dirs();
+            let (mine, others) = staged.drain(..).partition(|d| d.starts_with(&temp_root));
+            *staged = others;
+            mine
+        };
+        for dir in mine {
+            if fs.exists(&dir) {
+                fs.remove_dir_all(&dir)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 本体を変更せずに取得・検証するための一時キャッシュを作る
+    ///
+    /// 本体と同じファイルシステム上（`.temp` 配下）に作るため、[`Self::adopt_staged`] は
+    /// `rename` で移せる。中断（Ctrl-C）時は [`Self::discard_all_staged`] で破棄される。
+    pub fn create_staging_cache(&self) -> Result<PackageCache> {
+        let fs = RealFs;
+        let temp_root = self.cache_dir.join(".temp");
+        fs.create_dir_all(&temp_root)?;
+        let cache_dir = tempfile::Builder::new()
+            .prefix("staging-")
+            .tempdir_in(&temp_root)?
+            .keep();
+        track_staged(&cache_dir);
+        Ok(PackageCache { cache_dir })
+    }
+
+    /// 一時キャッシュに取得したプラグインを本体へ移す（本体に同名があれば置き換える）
+    ///
+    /// # Arguments
+    ///
+    /// * `staging` - Cache created by [`Self::create_staging_cache`].
+    /// * `marketplace` - marketplace name (`None` uses `"github"`)
+    /// * `name` - plugin name or repository identifier
+    pub fn adopt_staged(
+        &self,
+        staging: &PackageCache,
+        marketplace: Option<&str>,
+        name: &str,
+    ) -> Result<PathBuf> {
+        let fs = RealFs;
+        let source = staging.entry_path(None, marketplace, name)?;
+        let target = self.entry_path(None, marketplace, name)?;
+
+        if !fs.exists(&source) {
+            return Err(PlmError::Cache(format!(
+                "Staged plugin not found: {}",
+                source.display()
+            )));
+        }
+        if fs.exists(&target) {
+            fs.remove_dir_all(&target)?;
+        }
+        if let Some(parent) = target.parent() {
+            fs.create_dir_all(parent)?;
+        }
+        fs.rename(&source, &target)?;
+        self.refresh_index_entry(marketplace, name);
+        Ok(target)
+    }
+
+    /// [`Self::create_staging_cache`] で作った一時キャッシュを破棄する
+    ///
+    /// # Arguments
+    ///
+    /// * `staging` - Cache created by [`Self::create_staging_cache`].
+    pub fn discard_staging_cache(&self, staging: PackageCache) -> Result<()> {
+        let fs = RealFs;
+        if fs.exists(&staging.cache_dir) {
+            fs.remove_dir_all(&staging.cache_dir)?;
+        }
+        untrack_staged(&staging.cache_dir);
+        Ok(())
+    }
+
     /// `<cache_dir>[/<namespace>]/<marketplace>/<name>` の join 規則を一元化する
     ///
     /// marketplace 未指定時のデフォルト解決（`None` → `"github"`）を含め、
     /// キャッシュ階層のパス構築はすべてこのメソッドを経由する。
+    /// marketplace 名・プラグイン名が単一のパス要素でなければキャッシュ外を指しうるため拒否する。
     ///
     /// # Arguments
     ///
@@ -336,13 +547,15 @@ impl PackageCache {
         namespace: Option<&str>,
         marketplace: Option<&str>,
         name: &str,
-    ) -> PathBuf {
+    ) -> Result<PathBuf> {
         let base = match namespace {
             Some(ns) => self.cache_dir.join(ns),
             None => self.cache_dir.clone(),
         };
-        base.join(MarketplaceRef::from_option(marketplace).dir_name())
-            .join(name)
+        let marketplace = MarketplaceRef::from_option(marketplace);
+        validate_entry_component(marketplace.dir_name())?;
+        validate_entry_component(name)?;
+        Ok(base.join(marketplace.dir_name()).join(name))
     }
 
     /// バックアップパスを取得
@@ -351,7 +564,7 @@ impl PackageCache {
     ///
     /// * `marketplace` - marketplace name (`None` uses `"github"`)
     /// * `name` - plugin name or repository identifier
-    fn backup_path(&self, marketplace: Option<&str>, name: &str) -> PathBuf {
+    fn backup_path(&self, marketplace: Option<&str>, name: &str) -> Result<PathBuf> {
         self.entry_path(Some(".backup"), marketplace, name)
     }
 
@@ -361,20 +574,50 @@ impl PackageCache {
     ///
     /// * `marketplace` - marketplace name (`None` uses `"github"`)
     /// * `name` - plugin name or repository identifier
-    fn temp_path(&self, marketplace: Option<&str>, name: &str) -> PathBuf {
+    fn temp_path(&self, marketplace: Option<&str>, name: &str) -> Result<PathBuf> {
         self.entry_path(Some(".temp"), marketplace, name)
     }
+
+    /// 一覧用インデックスの該当エントリを現在のキャッシュ内容で更新する
+    ///
+    /// インデックスが無い・壊れている場合は何もしない（次回の一覧取得で作り直す）。
+    /// 更新の失敗はキャッシュ操作自体を失敗させない。
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - marketplace name (`None` uses `"github"`)
+    /// * `name` - plugin name or repository identifier
+    fn refresh_index_entry(&self, marketplace: Option<&str>, name: &str) {
+        let index_path = self.cache_dir.join(INDEX_FILE);
+        let Some(mut index) = PluginIndex::load(&index_path) else {
+            return;
+        };
+        index.remove(marketplace, name);
+        let key = PluginCacheKey {
+            marketplace: marketplace.map(String::from),
+            name: name.to_string(),
+        };
+        if let Some(content) = scan_package(self, &key) {
+            index.insert(&content);
+        }
+        let _ = index.save(&index_path);
+    }
 }
 
 impl PackageCacheAccess for PackageCache {
     fn plugin_path(&self, marketplace: Option<&str>, name: &str) -> PathBuf {
         self.entry_path(None, marketplace, name)
+            .unwrap_or_else(|_| self.cache_dir.join(INVALID_ENTRY_DIR))
+    }
+
+    fn index_path(&self) -> Option<PathBuf> {
+        Some(self.cache_dir.join(INDEX_FILE))
     }
 
     fn is_cached(&self, marketplace: Option<&str>, name: &str) -> bool {
         let fs = RealFs;
-        let plugin_path = self.plugin_path(marketplace, name);
-        fs.exists(&plugin_path)
+        self.entry_path(None, marketplace, name)
+            .is_ok_and(|plugin_path| fs.exists(&plugin_path))
     }
 
     fn store_from_archive(
@@ -389,7 +632,7 @@ impl PackageCacheAccess for PackageCache {
         // source_path の防御的検証
         validate_source_path(source_path)?;
 
-        let plugin_dir = self.plugin_path(marketplace, name);
+        let plugin_dir = self.entry_path(None, marketplace, name)?;
 
         // 既存のキャッシュがあれば削除
         if fs.exists(&plugin_dir) {
@@ -404,11 +647,12 @@ impl PackageCacheAccess for PackageCache {
             eprintln!("Warning: Failed to write installedAt: {}", e);
         }
 
+        self.refresh_index_entry(marketplace, name);
         Ok(plugin_dir)
     }
 
     fn load_manifest(&self, marketplace: Option<&str>, name: &str) -> Result<PluginManifest> {
-        let plugin_dir = self.plugin_path(marketplace, name);
+        let plugin_dir = self.entry_path(None, marketplace, name)?;
         let manifest_path = resolve_manifest_path(&plugin_dir).ok_or_else(|| {
             PlmError::InvalidManifest(format!("plugin.json not found in {:?}", plugin_dir))
         })?;
@@ -418,10 +662,11 @@ impl PackageCacheAccess for PackageCache {
 
     fn remove(&self, marketplace: Option<&str>, name: &str) -> Result<()> {
         let fs = RealFs;
-        let plugin_dir = self.plugin_path(marketplace, name);
+        let plugin_dir = self.entry_path(None, marketplace, name)?;
         if fs.exists(&plugin_dir) {
             fs.remove_dir_all(&plugin_dir)?;
         }
+        self.refresh_index_entry(marketplace, name);
         Ok(())
     }
 
@@ -463,10 +708,35 @@ impl PackageCacheAccess for PackageCache {
         Ok(plugins)
     }
 
+    fn rename(&self, marketplace: Option<&str>, from: &str, to: &str) -> Result<PathBuf> {
+        let fs = RealFs;
+        let source = self.entry_path(None, marketplace, from)?;
+        let target = self.entry_path(None, marketplace, to)?;
+
+        if !fs.exists(&source) {
+            return Err(PlmError::Cache(format!(
+                "Plugin not found: {}",
+                source.display()
+            )));
+        }
+        if fs.exists(&target) {
+            return Err(PlmError::Cache(format!(
+                "Rename target already exists: {}",
+                target.display()
+            )));
+        }
+
+        // リネーム（同一ファイルシステム上でのアトミック操作）
+        fs.rename(&source, &target)?;
+        self.refresh_index_entry(marketplace, from);
+        self.refresh_index_entry(marketplace, to);
+        Ok(target)
+    }
+
     fn backup(&self, marketplace: Option<&str>, name: &str) -> Result<PathBuf> {
         let fs = RealFs;
-        let source = self.plugin_path(marketplace, name);
-        let backup_dir = self.backup_path(marketplace, name);
+        let source = self.entry_path(None, marketplace, name)?;
+        let backup_dir = self.backup_path(marketplace, name)?;
 
         if !fs.exists(&source) {
             return Err(PlmError::Cache(format!(
@@ -491,8 +761,8 @@ impl PackageCacheAccess for PackageCache {
 
     fn restore(&self, marketplace: Option<&str>, name: &str) -> Result<()> {
         let fs = RealFs;
-        let backup_dir = self.backup_path(marketplace, name);
-        let target = self.plugin_path(marketplace, name);
+        let backup_dir = self.backup_path(marketplace, name)?;
+        let target = self.entry_path(None, marketplace, name)?;
 
         if !fs.exists(&backup_dir) {
             return Err(PlmError::Cache("Backup not found".to_string()));
@@ -509,12 +779,13 @@ impl PackageCacheAccess for PackageCache {
         // バックアップを削除
         fs.remove_dir_all(&backup_dir)?;
 
+        self.refresh_index_entry(marketplace, name);
         Ok(())
     }
 
     fn remove_backup(&self, marketplace: Option<&str>, name: &str) -> Result<()> {
         let fs = RealFs;
-        let backup_dir = self.backup_path(marketplace, name);
+        let backup_dir = self.backup_path(marketplace, name)?;
         if fs.exists(&backup_dir) {
             fs.remove_dir_all(&backup_dir)?;
         }
@@ -538,8 +809,8 @@ impl PackageCacheAccess for PackageCache {
         source_path: Option<&str>,
     ) -> Result<PathBuf> {
         let fs = RealFs;
-        let target = self.plugin_path(marketplace, name);
-        let temp_dir = self.temp_path(marketplace, name);
+        let target = self.entry_path(None, marketplace, name)?;
+        let temp_dir = self.temp_path(marketplace, name)?;
 
         // temp ディレクトリをクリーンアップ
         if fs.exists(&temp_dir) {
@@ -552,14 +823,17 @@ impl PackageCacheAccess for PackageCache {
         }
 
         // temp に展開（source_path 適用）— 失敗時は temp を破棄
+        track_staged(&temp_dir);
         if let Err(e) = extract_archive_with_source_path(&temp_dir, archive, source_path) {
             let _ = fs.remove_dir_all(&temp_dir);
+            untrack_staged(&temp_dir);
             return Err(e);
         }
 
         // 検証: plugin.json の存在確認
         if !has_manifest(&temp_dir) {
             let _ = fs.remove_dir_all(&temp_dir);
+            untrack_staged(&temp_dir);
             return Err(PlmError::InvalidManifest("plugin.json not found".into()));
         }
 
@@ -570,7 +844,9 @@ impl PackageCacheAccess for PackageCache {
 
         // リネーム（同一ファイルシステム上でのアトミック操作）
         fs.rename(&temp_dir, &target)?;
+        untrack_staged(&temp_dir);
 
+        self.refresh_index_entry(marketplace, name);
         Ok(target)
     }
 
@@ -587,7 +863,7 @@ impl PackageCacheAccess for PackageCache {
         // 強制し、`..`/絶対パス等の非正規化 source_path が展開処理に到達するのを防ぐ）
         validate_source_path(source_path)?;
 
-        let temp_dir = self.temp_path(marketplace, name);
+        let temp_dir = self.temp_path(marketplace, name)?;
 
         // temp ディレクトリをクリーンアップ
         if fs.exists(&temp_dir) {
@@ -598,13 +874,16 @@ impl PackageCacheAccess for PackageCache {
         }
 
         // temp に展開（失敗時は temp 破棄）
+        track_staged(&temp_dir);
         if let Err(e) = extract_archive_with_source_path(&temp_dir, archive, source_path) {
             let _ = fs.remove_dir_all(&temp_dir);
+            untrack_staged(&temp_dir);
             return Err(e);
         }
         // plugin.json 検証
         if !has_manifest(&temp_dir) {
             let _ = fs.remove_dir_all(&temp_dir);
+            untrack_staged(&temp_dir);
             return Err(PlmError::InvalidManifest("plugin.json not found".into()));
         }
         Ok(temp_dir)
@@ -612,8 +891,8 @@ impl PackageCacheAccess for PackageCache {
 
     fn commit_staged(&self, marketplace: Option<&str>, name: &str) -> Result<PathBuf> {
         let fs = RealFs;
-        let temp_dir = self.temp_path(marketplace, name);
-        let target = self.plugin_path(marketplace, name);
+        let temp_dir = self.temp_path(marketplace, name)?;
+        let target = self.entry_path(None, marketplace, name)?;
 
         if !fs.exists(&temp_dir) {
             return Err(PlmError::Cache(format!(
@@ -625,30 +904,34 @@ impl PackageCacheAccess for PackageCache {
             fs.remove_dir_all(&target)?;
         }
         fs.rename(&temp_dir, &target)?;
+        untrack_staged(&temp_dir);
+        self.refresh_index_entry(marketplace, name);
         Ok(target)
     }
 
     fn discard_staged(&self, marketplace: Option<&str>, name: &str) -> Result<()> {
         let fs = RealFs;
-        let temp_dir = self.temp_path(marketplace, name);
+        let temp_dir = self.temp_path(marketplace, name)?;
         if fs.exists(&temp_dir) {
             fs.remove_dir_all(&temp_dir)?;
         }
+        untrack_staged(&temp_dir);
         Ok(())
     }
 
     fn has_marketplace_entry(&self, marketplace: &str, entry: &str) -> Result<bool> {
         let fs = RealFs;
-        let path = self.entry_path(None, Some(marketplace), entry);
+        let path = self.entry_path(None, Some(marketplace), entry)?;
         Ok(fs.exists(&path))
     }
 
     fn remove_marketplace_entry(&self, marketplace: &str, entry: &str) -> Result<()> {
         let fs = RealFs;
-        let path = self.entry_path(None, Some(marketplace), entry);
+        let path = self.entry_path(None, Some(marketplace), entry)?;
         if fs.exists(&path) {
             fs.remove_dir_all(&path)?;
         }
+        self.refresh_index_entry(Some(marketplace), entry);
         Ok(())
     }
 
@@ -670,6 +953,25 @@ impl PackageCacheAccess for PackageCache {
     }
 }
 
+/// キャッシュ階層の 1 要素（marketplace 名・プラグイン名）として使えるか検証する
+///
+/// 単一の通常パス要素以外（空文字、`.`・`..`、区切り文字や絶対パスを含む名前）を拒否する。
+///
+/// # Arguments
+///
+/// * `component` - directory name to place under the cache
+fn validate_entry_component(component: &str) -> Result<()> {
+    let mut components = Path::new(component).components();
+    let is_single_normal = matches!(
+        (components.next(), components.next()),
+        (Some(PathComponent::Normal(c)), None) if c == component
+    );
+    if !is_single_normal || component.contains('\\') {
+        return Err(PlmError::InvalidPluginName(component.to_string()));
+    }
+    Ok(())
+}
+
 /// source_path の防御的検証
 ///
 /// # Arguments
@@ -726,6 +1028,9 @@ fn get_archive_prefix(zip: &mut ZipArchive<Cursor<&[u8]>>) -> Result<String> {
 
 /// アーカイブを展開（source_path 指定対応）
 ///
+/// 展開したプラグインに `checksums.json` があれば内容を検証し、一致しなければ
+/// 展開先を削除してエラーにする。
+///
 /// # Arguments
 ///
 /// * `dest` - destination directory to extract into
@@ -794,6 +1099,10 @@ fn extract_archive_with_source_path(
         }
     }
 
+    if let Err(e) = verify_checksums(dest) {
+        let _ = RealFs.remove_dir_all(dest);
+        return Err(e);
+    }
     Ok(())
 }
 
@@ -97,6 +97,49 @@ fn create_test_archive(entries: &[(&str, &str)]) -> Vec<u8> {
     buf
 }
 
+#[test]
+fn test_store_from_archive_rejects_names_outside_the_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().join("cache")).unwrap();
+    let archive = create_test_archive(&[(
+        "repo-main/plugin.json",
+        r#"{"name":"test","version":"1.0.0"}"#,
+    )]);
+
+    for name in ["../escape", "a/b", "..", ".", "", "a\\b"] {
+        assert!(
+            matches!(
+                cache.store_from_archive(None, name, &archive, None),
+                Err(PlmError::InvalidPluginName(_))
+            ),
+            "{name}"
+        );
+    }
+    assert!(matches!(
+        cache.store_from_archive(Some("../mp"), "test", &archive, None),
+        Err(PlmError::InvalidPluginName(_))
+    ));
+    assert!(!temp_dir.path().join("escape").exists());
+    assert!(!temp_dir.path().join("cache/github/a").exists());
+}
+
+#[test]
+fn test_invalid_names_never_resolve_outside_the_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().join("cache")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("escape")).unwrap();
+
+    let path = cache.plugin_path(None, "../../escape");
+
+    assert!(path.starts_with(temp_dir.path().join("cache")));
+    assert!(!cache.is_cached(None, "../../escape"));
+    assert!(matches!(
+        cache.remove(None, "../../escape"),
+        Err(PlmError::InvalidPluginName(_))
+    ));
+    assert!(temp_dir.path().join("escape").exists());
+}
+
 #[test]
 fn test_store_from_archive_with_source_path_extracts_to_root() {
     // テストケース14: source_path 指定時、そのパス配下の内容がキャッシュ直下に展開される
@@ -190,6 +233,34 @@ fn test_store_from_archive_source_path_not_found() {
     }
 }
 
+#[test]
+fn test_store_from_archive_rejects_checksum_mismatch() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    let mut checksums = crate::plugin::Checksums::default();
+    checksums.insert("plugin.json", br#"{"name":"demo","version":"1.0.0"}"#);
+    let json = checksums.to_json().unwrap();
+
+    // 記録どおりの内容なら展開できる
+    let archive = create_test_archive(&[
+        ("demo/plugin.json", r#"{"name":"demo","version":"1.0.0"}"#),
+        ("demo/checksums.json", json.as_str()),
+    ]);
+    assert!(cache
+        .store_from_archive(None, "demo", &archive, None)
+        .is_ok());
+
+    // 内容が書き換えられていればエラーにし、展開したものは残さない
+    let tampered = create_test_archive(&[
+        ("demo/plugin.json", r#"{"name":"demo","version":"9.9.9"}"#),
+        ("demo/checksums.json", json.as_str()),
+    ]);
+    let result = cache.store_from_archive(None, "demo", &tampered, None);
+
+    assert!(matches!(result, Err(PlmError::ChecksumMismatch(_))));
+    assert!(!cache.is_cached(None, "demo"));
+}
+
 #[test]
 fn test_store_from_archive_source_path_validation_dotdot() {
     // テストケース21: source_path に .. が含まれる場合 → エラー
@@ -840,6 +911,110 @@ fn test_discard_staged_removes_temp() {
     assert!(!temp_path.exists());
 }
 
+#[test]
+fn test_discard_all_staged_removes_only_own_staging_dirs() {
+    // 中断時の後始末: 自分の staging temp だけを破棄する（無い場合も Ok）
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    cache.discard_all_staged().unwrap();
+
+    let archive = create_test_archive(&[(
+        "repo-main/plugin.json",
+        r#"{"name":"test","version":"1.0.0"}"#,
+    )]);
+    let staged = cache
+        .stage_from_archive(Some("github"), "test-plugin", &archive, None)
+        .unwrap();
+    // 他のプロセスが展開中の temp
+    let foreign = temp_dir.path().join(".temp").join("github").join("other");
+    fs::create_dir_all(&foreign).unwrap();
+
+    cache.discard_all_staged().unwrap();
+    assert!(!staged.exists());
+    assert!(foreign.exists());
+}
+
+#[test]
+fn test_discard_all_staged_keeps_committed_plugins() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    let archive = create_test_archive(&[(
+        "repo-main/plugin.json",
+        r#"{"name":"test","version":"1.0.0"}"#,
+    )]);
+    cache
+        .stage_from_archive(Some("github"), "test-plugin", &archive, None)
+        .unwrap();
+    let committed = cache.commit_staged(Some("github"), "test-plugin").unwrap();
+
+    // 同名の temp を後から別プロセスが作っても、コミット済みの追跡は残っていない
+    let foreign = temp_dir
+        .path()
+        .join(".temp")
+        .join("github")
+        .join("test-plugin");
+    fs::create_dir_all(&foreign).unwrap();
+    cache.discard_all_staged().unwrap();
+    assert!(committed.exists());
+    assert!(foreign.exists());
+}
+
+#[test]
+fn test_staging_cache_adopts_into_main_cache_only_when_asked() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    let installed = cache.plugin_path(Some("mp"), "test-plugin");
+    fs::create_dir_all(&installed).unwrap();
+    fs::write(
+        installed.join("plugin.json"),
+        r#"{"name":"test","version":"1.0.0"}"#,
+    )
+    .unwrap();
+
+    let staging = cache.create_staging_cache().unwrap();
+    let archive = create_test_archive(&[(
+        "repo-main/plugin.json",
+        r#"{"name":"test","version":"2.0.0"}"#,
+    )]);
+    staging
+        .store_from_archive(Some("mp"), "test-plugin", &archive, None)
+        .unwrap();
+    // 一時キャッシュへの取得は本体を変えない
+    assert_eq!(
+        cache
+            .load_manifest(Some("mp"), "test-plugin")
+            .unwrap()
+            .version,
+        "1.0.0"
+    );
+
+    cache
+        .adopt_staged(&staging, Some("mp"), "test-plugin")
+        .unwrap();
+    assert_eq!(
+        cache
+            .load_manifest(Some("mp"), "test-plugin")
+            .unwrap()
+            .version,
+        "2.0.0"
+    );
+
+    let staging_dir = staging.cache_dir().to_path_buf();
+    cache.discard_staging_cache(staging).unwrap();
+    assert!(!staging_dir.exists());
+}
+
+#[test]
+fn test_discard_all_staged_removes_staging_caches() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    let staging = cache.create_staging_cache().unwrap();
+
+    cache.discard_all_staged().unwrap();
+
+    assert!(!staging.cache_dir().exists());
+}
+
 // =============================================================================
 // load_package() tests
 // =============================================================================
@@ -1002,3 +1177,45 @@ fn new_errors_when_both_unset() {
         other => panic!("expected Cache error, got {:?}", other),
     }
 }
+
+// =============================================================================
+// rename
+// =============================================================================
+
+#[test]
+fn test_rename_moves_cache_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    let old_dir = cache.plugin_path(Some("mp"), "old-name");
+    fs::create_dir_all(old_dir.join(".claude-plugin")).unwrap();
+    fs::write(old_dir.join(".plm-meta.json"), "{}").unwrap();
+
+    let new_dir = cache.rename(Some("mp"), "old-name", "new-name").unwrap();
+
+    assert_eq!(new_dir, cache.plugin_path(Some("mp"), "new-name"));
+    assert!(!old_dir.exists());
+    assert!(new_dir.join(".claude-plugin").is_dir());
+    assert!(new_dir.join(".plm-meta.json").is_file());
+}
+
+#[test]
+fn test_rename_refuses_existing_target() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+    fs::create_dir_all(cache.plugin_path(Some("mp"), "old-name")).unwrap();
+    fs::create_dir_all(cache.plugin_path(Some("mp"), "new-name")).unwrap();
+
+    let result = cache.rename(Some("mp"), "old-name", "new-name");
+
+    assert!(result.is_err());
+    assert!(cache.is_cached(Some("mp"), "old-name"));
+}
+
+#[test]
+fn test_rename_missing_source_is_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp_dir.path().to_path_buf()).unwrap();
+
+    assert!(cache.rename(Some("mp"), "old-name", "new-name").is_err());
+    assert!(!cache.is_cached(Some("mp"), "new-name"));
+}
@@ -0,0 +1,168 @@
+//! プラグインアーカイブの整合性チェックサム（`checksums.json`）
+//!
+//! `plm pack` はアーカイブ内の各ファイルの SHA-256 を `checksums.json` に記録して同梱する。
+//! キャッシュへの展開時（install / import / update）にこのファイルがあれば内容を検証し、
+//! 一致しない・記録に無いファイルがあれば [`PlmError::ChecksumMismatch`] で失敗する。
+//! `checksums.json` を持たないアーカイブ（GitHub の zipball や古い pack 出力）は検証しない。
+
+use crate::error::{PlmError, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// プラグインルートに置くチェックサムファイル名
+pub const CHECKSUMS_FILE: &str = "checksums.json";
+
+/// 対応しているハッシュアルゴリズム
+const ALGORITHM: &str = "sha256";
+
+/// `checksums.json` のスキーマ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checksums {
+    /// ハッシュアルゴリズム（現在は `sha256` のみ）
+    pub algorithm: String,
+    /// プラグインルートからの相対パス（`/` 区切り） → 16 進のダイジェスト
+    pub files: BTreeMap<String, String>,
+}
+
+impl Default for Checksums {
+    fn default() -> Self {
+        Self {
+            algorithm: ALGORITHM.to_string(),
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl Checksums {
+    /// ファイルのダイジェストを記録する
+    ///
+    /// # Arguments
+    ///
+    /// * `relative` - Path relative to the plugin root, `/`-separated.
+    /// * `content` - File content.
+    pub fn insert(&mut self, relative: &str, content: &[u8]) {
+        self.files.insert(relative.to_string(), sha256_hex(content));
+    }
+
+    /// 記録済みのダイジェストと内容が一致するか（記録が無ければ `false`）
+    ///
+    /// # Arguments
+    ///
+    /// * `relative` - Path relative to the plugin root, `/`-separated.
+    /// * `content` - File content.
+    pub fn matches(&self, relative: &str, content: &[u8]) -> bool {
+        self.files
+            .get(relative)
+            .is_some_and(|digest| *digest == sha256_hex(content))
+    }
+
+    /// JSON 文字列に変換する
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// プラグインルートの `checksums.json` を読み込む
+    ///
+    /// ファイルが無い場合と、このスキーマとして読めない場合（リポジトリが別用途で
+    /// 同名のファイルを持っている場合など）は `None` を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Plugin root directory.
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(CHECKSUMS_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content).ok())
+    }
+
+    /// プラグインルート配下のファイルが記録と一致するか検証する
+    ///
+    /// 記録されたファイルの欠落・内容の不一致に加え、記録に無いファイルの追加も
+    /// 不一致として扱う。
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Plugin root directory.
+    pub fn verify(&self, root: &Path) -> Result<()> {
+        if self.algorithm != ALGORITHM {
+            return Err(PlmError::ChecksumMismatch(format!(
+                "unsupported algorithm '{}'",
+                self.algorithm
+            )));
+        }
+
+        let mut problems = Vec::new();
+        for (relative, expected) in &self.files {
+            match fs::read(root.join(relative)) {
+                Ok(content) if sha256_hex(&content) == *expected => {}
+                Ok(_) => problems.push(format!("{} (modified)", relative)),
+                Err(_) => problems.push(format!("{} (missing)", relative)),
+            }
+        }
+        for entry in WalkDir::new(root).min_depth(1).sort_by_file_name() {
+            let entry = entry.map_err(|e| PlmError::Io(e.into()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = relative_key(root, entry.path());
+            if relative != CHECKSUMS_FILE && !self.files.contains_key(&relative) {
+                problems.push(format!("{} (unexpected)", relative));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(PlmError::ChecksumMismatch(problems.join(", ")))
+        }
+    }
+}
+
+/// `checksums.json` があれば検証する
+///
+/// 検証した場合は `true`、`checksums.json` が無い場合は `false` を返す。
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+pub fn verify_checksums(root: &Path) -> Result<bool> {
+    match Checksums::load(root)? {
+        Some(checksums) => checksums.verify(root).map(|()| true),
+        None => Ok(false),
+    }
+}
+
+/// `checksums.json` のキーとなる相対パス（`/` 区切り）
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+/// * `path` - File under `root`.
+pub fn relative_key(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// SHA-256 ダイジェストの 16 進表記
+///
+/// # Arguments
+///
+/// * `content` - Bytes to hash.
+fn sha256_hex(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+#[cfg(test)]
+#[path = "checksum_test.rs"]
+mod tests;
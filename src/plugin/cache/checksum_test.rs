@@ -0,0 +1,81 @@
+use super::*;
+use tempfile::TempDir;
+
+fn write_file(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// `files` を書き、その内容の checksums.json を置いたプラグインルート
+fn plugin_with_checksums(files: &[(&str, &str)]) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let mut checksums = Checksums::default();
+    for (relative, content) in files {
+        write_file(temp.path(), relative, content);
+        checksums.insert(relative, content.as_bytes());
+    }
+    write_file(temp.path(), CHECKSUMS_FILE, &checksums.to_json().unwrap());
+    temp
+}
+
+#[test]
+fn sha256_hex_matches_known_digest() {
+    assert_eq!(
+        sha256_hex(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn verify_checksums_accepts_untouched_files() {
+    let temp = plugin_with_checksums(&[("plugin.json", "{}"), ("skills/a/SKILL.md", "body")]);
+
+    assert!(verify_checksums(temp.path()).unwrap());
+}
+
+#[test]
+fn verify_checksums_without_file_is_skipped() {
+    let temp = TempDir::new().unwrap();
+    write_file(temp.path(), "plugin.json", "{}");
+
+    assert!(!verify_checksums(temp.path()).unwrap());
+}
+
+#[test]
+fn verify_checksums_ignores_unrelated_checksums_file() {
+    let temp = TempDir::new().unwrap();
+    write_file(temp.path(), CHECKSUMS_FILE, r#"["not", "ours"]"#);
+
+    assert!(!verify_checksums(temp.path()).unwrap());
+}
+
+#[test]
+fn verify_checksums_reports_modified_missing_and_unexpected_files() {
+    let temp = plugin_with_checksums(&[("a.md", "a"), ("b.md", "b")]);
+    write_file(temp.path(), "a.md", "tampered");
+    fs::remove_file(temp.path().join("b.md")).unwrap();
+    write_file(temp.path(), "skills/x/run.sh", "echo");
+
+    let err = verify_checksums(temp.path()).unwrap_err();
+
+    assert!(matches!(err, PlmError::ChecksumMismatch(_)));
+    assert_eq!(
+        err.to_string(),
+        "checksum verification failed: a.md (modified), b.md (missing), skills/x/run.sh (unexpected)"
+    );
+}
+
+#[test]
+fn verify_checksums_rejects_unknown_algorithm() {
+    let temp = TempDir::new().unwrap();
+    write_file(
+        temp.path(),
+        CHECKSUMS_FILE,
+        r#"{"algorithm":"md5","files":{}}"#,
+    );
+
+    let err = verify_checksums(temp.path()).unwrap_err();
+
+    assert!(err.to_string().contains("unsupported algorithm 'md5'"));
+}
@@ -0,0 +1,265 @@
+//! 一覧用のプラグインインデックス（`plugins-index.json`）
+//!
+//! `plm list` や TUI 起動のたびに全プラグインの plugin.json 読み込みとコンポーネント
+//! スキャンを行うと、プラグインが多い環境では時間がかかる。キャッシュルートに
+//! スキャン結果（manifest・コンポーネント一覧・除外パス）と構築時の mtime を保存し、
+//! mtime が変わっていないプラグインは再スキャンせずに復元する。
+//!
+//! mtime はプラグインルート配下の全ディレクトリと plugin.json・`AGENTS.md`・
+//! manifest がファイルを指すコンポーネントパスについて記録する。プラグインルート自体は
+//! `.plm-meta.json` の書き換えで mtime が変わるため対象外とする。
+//! index が壊れている・形式が古い場合は読み込まずに全プラグインを再スキャンする。
+
+use crate::component::{Component, ComponentKind};
+use crate::error::Result;
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::meta::resolve_manifest_path;
+use crate::plugin::{MarketplaceContent, Plugin, PluginManifest};
+use crate::target::PluginOrigin;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use walkdir::WalkDir;
+
+/// インデックスファイル名（キャッシュルート直下）
+pub const INDEX_FILE: &str = "plugins-index.json";
+
+/// インデックスの形式バージョン（一致しなければ全再構築する）
+const INDEX_VERSION: u32 = 1;
+
+/// `plugins-index.json` のスキーマ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PluginIndex {
+    version: u32,
+    /// `<marketplace>/<キャッシュディレクトリ名>` → エントリ
+    #[serde(default)]
+    plugins: BTreeMap<String, IndexEntry>,
+}
+
+/// 1 プラグインぶんのスキャン結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexEntry {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    marketplace: Option<String>,
+    version: String,
+    manifest: PluginManifest,
+    components: Vec<IndexedComponent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    excluded: Vec<PathBuf>,
+    /// プラグインルートからの相対パス → 構築時の mtime（UNIX エポックからのナノ秒）
+    mtimes: BTreeMap<String, u64>,
+}
+
+/// コンポーネント（パスはプラグインルートからの相対パス）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexedComponent {
+    kind: ComponentKind,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    original_name: Option<String>,
+    #[serde(default)]
+    plugin_name: String,
+    path: PathBuf,
+}
+
+impl PluginIndex {
+    /// 空のインデックスを作る
+    pub(crate) fn new() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            plugins: BTreeMap::new(),
+        }
+    }
+
+    /// インデックスを読み込む
+    ///
+    /// ファイルが無い・壊れている・形式バージョンが異なる場合は `None`（全再構築）。
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of `plugins-index.json`.
+    pub(crate) fn load(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let index: Self = serde_json::from_str(&content).ok()?;
+        (index.version == INDEX_VERSION).then_some(index)
+    }
+
+    /// インデックスを書き出す（一時ファイル → rename）
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of `plugins-index.json`.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        crate::component::convert::atomic_write(path, &json)
+    }
+
+    /// 登録済みのプラグイン数
+    pub(crate) fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// mtime が変わっていなければ保存済みのスキャン結果からパッケージを復元する
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - Marketplace name (`None` uses `"github"`).
+    /// * `id` - Cache directory name.
+    /// * `plugin_dir` - Plugin root directory in the cache.
+    pub(crate) fn restore(
+        &self,
+        marketplace: Option<&str>,
+        id: &str,
+        plugin_dir: &Path,
+    ) -> Option<MarketplaceContent> {
+        let entry = self.plugins.get(&entry_key(marketplace, id))?;
+        if snapshot_mtimes(plugin_dir, &entry.manifest) != entry.mtimes {
+            return None;
+        }
+        let components = entry
+            .components
+            .iter()
+            .map(|c| Component {
+                kind: c.kind,
+                name: c.name.clone(),
+                original_name: c.original_name.clone(),
+                plugin_name: c.plugin_name.clone(),
+                path: plugin_dir.join(&c.path),
+            })
+            .collect();
+        let plugin = Plugin::from_index(
+            entry.manifest.clone(),
+            plugin_dir.to_path_buf(),
+            PluginOrigin::from_cached_plugin(marketplace, id),
+            components,
+            entry.excluded.clone(),
+        );
+        Some(MarketplaceContent::from_plugin(
+            Some(id.to_string()),
+            marketplace.map(str::to_string),
+            plugin,
+        ))
+    }
+
+    /// スキャン済みのパッケージでエントリを追加・更新する
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Package scanned with `Plugin::new`.
+    pub(crate) fn insert(&mut self, content: &MarketplaceContent) {
+        let plugin = content.primary();
+        let root = plugin.path();
+        let components = plugin
+            .components()
+            .iter()
+            .map(|c| IndexedComponent {
+                kind: c.kind,
+                name: c.name.clone(),
+                original_name: c.original_name.clone(),
+                plugin_name: c.plugin_name.clone(),
+                path: c.path.strip_prefix(root).unwrap_or(&c.path).to_path_buf(),
+            })
+            .collect();
+        let id = content.id().unwrap_or(content.name());
+        self.plugins.insert(
+            entry_key(content.marketplace(), id),
+            IndexEntry {
+                name: content.name().to_string(),
+                marketplace: content.marketplace().map(str::to_string),
+                version: content.manifest().version.clone(),
+                manifest: content.manifest().clone(),
+                components,
+                excluded: plugin.excluded_paths().to_vec(),
+                mtimes: snapshot_mtimes(root, content.manifest()),
+            },
+        );
+    }
+
+    /// エントリを削除する（削除したら `true`）
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - Marketplace name (`None` uses `"github"`).
+    /// * `id` - Cache directory name.
+    pub(crate) fn remove(&mut self, marketplace: Option<&str>, id: &str) -> bool {
+        self.plugins.remove(&entry_key(marketplace, id)).is_some()
+    }
+
+    /// キャッシュに存在しないプラグインのエントリを取り除く（削除したら `true`）
+    ///
+    /// # Arguments
+    ///
+    /// * `cached` - `(marketplace, id)` pairs currently in the cache.
+    pub(crate) fn retain_cached(&mut self, cached: &[(Option<String>, String)]) -> bool {
+        let keys: Vec<String> = cached
+            .iter()
+            .map(|(marketplace, id)| entry_key(marketplace.as_deref(), id))
+            .collect();
+        let before = self.plugins.len();
+        self.plugins.retain(|key, _| keys.contains(key));
+        self.plugins.len() != before
+    }
+}
+
+/// インデックスのキー（`<marketplace>/<キャッシュディレクトリ名>`）
+///
+/// # Arguments
+///
+/// * `marketplace` - Marketplace name (`None` uses `"github"`).
+/// * `id` - Cache directory name.
+fn entry_key(marketplace: Option<&str>, id: &str) -> String {
+    format!(
+        "{}/{}",
+        MarketplaceRef::from_option(marketplace).dir_name(),
+        id
+    )
+}
+
+/// スキャン結果に影響するパスの mtime を集める
+///
+/// プラグインルート配下の全ディレクトリ（シンボリックリンクは辿らない）、plugin.json、
+/// `AGENTS.md`、manifest で指定されたコンポーネントパス（ファイル指定の場合）が対象。
+/// 存在しないパスは含めないため、追加・削除もマップの差分として検出できる。
+///
+/// # Arguments
+///
+/// * `plugin_dir` - Plugin root directory.
+/// * `manifest` - Manifest used to resolve custom component paths.
+fn snapshot_mtimes(plugin_dir: &Path, manifest: &PluginManifest) -> BTreeMap<String, u64> {
+    let mut paths: Vec<PathBuf> = WalkDir::new(plugin_dir)
+        .min_depth(1)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_dir())
+        .map(|e| e.into_path())
+        .collect();
+    paths.extend(resolve_manifest_path(plugin_dir));
+    paths.push(plugin_dir.join("AGENTS.md"));
+    paths.extend([
+        manifest.skills_dir(plugin_dir),
+        manifest.agents_dir(plugin_dir),
+        manifest.commands_dir(plugin_dir),
+        manifest.hooks_dir(plugin_dir),
+        manifest.instructions_path(plugin_dir),
+    ]);
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let modified = std::fs::symlink_metadata(&path).ok()?.modified().ok()?;
+            let nanos = modified.duration_since(UNIX_EPOCH).ok()?.as_nanos();
+            let relative = path.strip_prefix(plugin_dir).unwrap_or(&path);
+            Some((relative.to_string_lossy().into_owned(), nanos as u64))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "index_test.rs"]
+mod tests;
@@ -0,0 +1,215 @@
+use super::*;
+use crate::plugin::{
+    component_scan_count, list_installed, rebuild_index, PackageCache, PackageCacheAccess,
+};
+use std::fs;
+use tempfile::TempDir;
+
+/// `<cache>/github/<name>` にスキルを持つプラグインを作る
+fn write_plugin(cache_dir: &Path, name: &str, skills: &[&str]) -> PathBuf {
+    let dir = cache_dir.join("github").join(name);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("plugin.json"),
+        format!(r#"{{"name":"{}","version":"1.0.0"}}"#, name),
+    )
+    .unwrap();
+    for skill in skills {
+        add_skill(&dir, skill);
+    }
+    dir
+}
+
+fn add_skill(plugin_dir: &Path, skill: &str) {
+    let skill_dir = plugin_dir.join("skills").join(skill);
+    fs::create_dir_all(&skill_dir).unwrap();
+    fs::write(skill_dir.join("SKILL.md"), "# skill\n").unwrap();
+}
+
+/// `f` の実行中に行われたコンポーネントスキャンの回数
+fn scans_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = component_scan_count();
+    let result = f();
+    (result, component_scan_count() - before)
+}
+
+fn setup() -> (TempDir, PackageCache) {
+    let temp = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(temp.path().to_path_buf()).unwrap();
+    write_plugin(temp.path(), "alpha", &["lint"]);
+    write_plugin(temp.path(), "beta", &["format", "review"]);
+    (temp, cache)
+}
+
+fn component_names(packages: &[MarketplaceContent], plugin: &str) -> Vec<String> {
+    packages
+        .iter()
+        .find(|p| p.name() == plugin)
+        .unwrap()
+        .components()
+        .into_iter()
+        .map(|c| c.name)
+        .collect()
+}
+
+#[test]
+fn second_listing_restores_every_plugin_from_the_index() {
+    let (temp, cache) = setup();
+
+    let (first, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 2);
+    assert!(temp.path().join(INDEX_FILE).is_file());
+
+    let (second, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 0);
+    assert_eq!(second.len(), first.len());
+    assert_eq!(
+        component_names(&second, "beta"),
+        component_names(&first, "beta")
+    );
+}
+
+#[test]
+fn restored_components_point_into_the_plugin_directory() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    let packages = list_installed(&cache).unwrap();
+    let alpha = packages.iter().find(|p| p.name() == "alpha").unwrap();
+    let components = alpha.components();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].name, "alpha_lint");
+    assert_eq!(components[0].original_name.as_deref(), Some("lint"));
+    assert_eq!(
+        components[0].path,
+        temp.path().join("github/alpha/skills/lint")
+    );
+    assert_eq!(alpha.id(), Some("alpha"));
+    assert_eq!(alpha.marketplace(), None);
+}
+
+#[test]
+fn only_the_changed_plugin_is_rescanned() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    add_skill(&temp.path().join("github/alpha"), "audit");
+
+    let (packages, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 1);
+    assert_eq!(
+        component_names(&packages, "alpha"),
+        vec!["alpha_audit", "alpha_lint"]
+    );
+
+    let (_, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 0);
+}
+
+#[test]
+fn corrupt_index_falls_back_to_a_full_rescan() {
+    let (temp, cache) = setup();
+    fs::write(temp.path().join(INDEX_FILE), "{ not json").unwrap();
+
+    let (packages, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 2);
+    assert_eq!(packages.len(), 2);
+    assert_eq!(
+        PluginIndex::load(&temp.path().join(INDEX_FILE))
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn index_with_another_format_version_is_rebuilt() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+    let path = temp.path().join(INDEX_FILE);
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(
+        &path,
+        content.replacen("\"version\": 1", "\"version\": 0", 1),
+    )
+    .unwrap();
+    assert!(PluginIndex::load(&path).is_none());
+
+    let (_, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 2);
+    assert!(PluginIndex::load(&path).is_some());
+}
+
+#[test]
+fn plugins_deleted_outside_plm_are_dropped_from_the_index() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    fs::remove_dir_all(temp.path().join("github/beta")).unwrap();
+
+    let (packages, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 0);
+    assert_eq!(packages.len(), 1);
+    assert_eq!(
+        PluginIndex::load(&temp.path().join(INDEX_FILE))
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn removing_from_the_cache_updates_the_index_immediately() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    cache.remove(None, "beta").unwrap();
+
+    let index = PluginIndex::load(&temp.path().join(INDEX_FILE)).unwrap();
+    assert_eq!(index.len(), 1);
+    assert!(index
+        .restore(None, "alpha", &temp.path().join("github/alpha"))
+        .is_some());
+}
+
+#[test]
+fn renaming_in_the_cache_moves_the_index_entry() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    cache.rename(None, "alpha", "alpha-renamed").unwrap();
+
+    let index = PluginIndex::load(&temp.path().join(INDEX_FILE)).unwrap();
+    assert!(index
+        .restore(
+            None,
+            "alpha-renamed",
+            &temp.path().join("github/alpha-renamed")
+        )
+        .is_some());
+    let (_, scans) = scans_during(|| list_installed(&cache).unwrap());
+    assert_eq!(scans, 0);
+}
+
+#[test]
+fn rebuild_index_rescans_every_plugin() {
+    let (temp, cache) = setup();
+    list_installed(&cache).unwrap();
+
+    let (count, scans) = scans_during(|| rebuild_index(&cache).unwrap());
+    assert_eq!(count, 2);
+    assert_eq!(scans, 2);
+    assert_eq!(
+        PluginIndex::load(&temp.path().join(INDEX_FILE))
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn entry_key_treats_missing_marketplace_as_github() {
+    assert_eq!(entry_key(None, "alpha"), "github/alpha");
+    assert_eq!(entry_key(Some("github"), "alpha"), "github/alpha");
+    assert_eq!(entry_key(Some("acme"), "alpha"), "acme/alpha");
+}
@@ -38,7 +38,8 @@ impl LegacyCacheCleaner {
         marketplace_name: &str,
         mp_cache: &MarketplaceCache,
     ) -> Result<bool> {
-        if mp_cache.plugins.len() <= 1 {
+        // ローカルディレクトリのマーケットプレイスは旧レイアウトで配置されたことがない
+        if mp_cache.plugins.len() <= 1 || mp_cache.source.local_path().is_some() {
             return Ok(false);
         }
 
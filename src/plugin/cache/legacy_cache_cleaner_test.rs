@@ -10,6 +10,7 @@ fn make_cache(plugins: Vec<&str>) -> MarketplaceCache {
         name: "d-market".to_string(),
         fetched_at: Utc::now(),
         source: "github:owner/d-market-git".parse().unwrap(),
+        git_ref: None,
         owner: None,
         plugins: plugins
             .into_iter()
@@ -18,6 +19,8 @@ fn make_cache(plugins: Vec<&str>) -> MarketplaceCache {
                 source: PluginSource::Local(format!("./plugins/{}", n)),
                 description: None,
                 version: None,
+                renamed_from: vec![],
+                stats: Default::default(),
             })
             .collect(),
     }
@@ -0,0 +1,422 @@
+//! プラグイン単位の操作ロック
+//!
+//! install / update / uninstall / enable / disable が同じプラグインのキャッシュを
+//! 並行して書き換えないよう、キャッシュ内の `<marketplace>/.<plugin>.lock` を
+//! advisory ロックとして使う。プラグインディレクトリ自体は uninstall で消えるため、
+//! ロックファイルはその隣に置く。
+//!
+//! - ロックファイルには保持プロセスの PID と操作名（`plm update` など）を書く。
+//!   PID のプロセスが存在しなければ強制終了で残った stale ロックとみなして取り直す。
+//!   stale ロックの削除は `.<plugin>.lock.takeover` ガードを取ったプロセスだけが、
+//!   内容を読み直してから行う（別プロセスが取り直した直後のロックを消さない）。
+//!   PID の生存確認ができない環境（Unix 以外）では取り直さない
+//! - 同一プロセス内での再取得（uninstall → disable、update → enable など）は
+//!   参照カウントで通す
+//! - 取得できない場合は [`PlmError::PluginBusy`] で即座に失敗する。
+//!   `--wait` / `PLM_LOCK_WAIT` が有効なら解放されるまで待ち、`--wait-lock <秒>` /
+//!   `PLM_WAIT_LOCK` なら指定時間まで待って [`PlmError::LockTimeout`] で失敗する
+
+use super::PackageCacheAccess;
+use crate::config::{flag_enabled, GlobalOptions};
+use crate::error::{PlmError, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// ロック解放を待つ環境変数（`--wait` が優先）
+pub const LOCK_WAIT_ENV: &str = "PLM_LOCK_WAIT";
+
+/// ロック解放を待つ秒数の環境変数（`--wait-lock` が優先）
+pub const WAIT_LOCK_SECS_ENV: &str = "PLM_WAIT_LOCK";
+
+/// 待機時にロックファイルを確認する間隔
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 待機中の進捗を表示する間隔
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// stale ロック削除用のガードを放棄されたとみなすまでの時間
+///
+/// ガードは削除の間だけ保持されるため、これより古ければ保持プロセスが異常終了している。
+const TAKEOVER_GUARD_TTL: Duration = Duration::from_secs(10);
+
+/// このプロセスが保持しているロックファイルと参照カウント
+static HELD: Mutex<BTreeMap<PathBuf, usize>> = Mutex::new(BTreeMap::new());
+
+/// ロックファイルに記録する、このプロセスの操作名
+static OPERATION: OnceLock<String> = OnceLock::new();
+
+/// ロックファイルに記録する操作名を設定する（起動時に 1 回だけ有効）
+///
+/// # Arguments
+///
+/// * `operation` - Operation name such as `plm update`.
+pub fn set_lock_operation(operation: &str) {
+    let _ = OPERATION.set(operation.to_string());
+}
+
+/// ロックが取得できないときの振る舞い
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockWait {
+    /// 即座に [`PlmError::PluginBusy`] で失敗する
+    Fail,
+    /// 解放されるまで待つ
+    Forever,
+    /// 指定時間まで待ち、解放されなければ [`PlmError::LockTimeout`] で失敗する
+    Timeout(Duration),
+}
+
+impl LockWait {
+    /// `--wait` / `--wait-lock` と環境変数から解決する
+    pub fn from_env() -> Self {
+        let options = GlobalOptions::current();
+        Self::resolve(
+            GlobalOptions::or_env(options.wait.then_some("1"), LOCK_WAIT_ENV),
+            GlobalOptions::or_env(options.wait_lock, WAIT_LOCK_SECS_ENV),
+        )
+    }
+
+    /// 環境変数の値から解決する
+    ///
+    /// 秒数の指定が `PLM_LOCK_WAIT` より優先される。`0` は待たない。
+    /// 秒数として解釈できない場合は警告して `PLM_LOCK_WAIT` の指定に従う。
+    ///
+    /// # Arguments
+    ///
+    /// * `wait` - Value of `PLM_LOCK_WAIT`.
+    /// * `wait_secs` - Value of `PLM_WAIT_LOCK`.
+    pub fn resolve(wait: Option<String>, wait_secs: Option<String>) -> Self {
+        if let Some(raw) = wait_secs {
+            match raw.trim().parse::<u64>() {
+                Ok(0) => return Self::Fail,
+                Ok(secs) => return Self::Timeout(Duration::from_secs(secs)),
+                Err(_) => eprintln!(
+                    "[plm warn] invalid {} '{}'; expected seconds",
+                    WAIT_LOCK_SECS_ENV, raw
+                ),
+            }
+        }
+        if flag_enabled(wait) {
+            Self::Forever
+        } else {
+            Self::Fail
+        }
+    }
+}
+
+/// ロックファイルに記録された保持者
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockOwner {
+    /// 保持プロセスの PID
+    pub pid: u32,
+    /// 保持プロセスの操作名（記録されていなければ `None`）
+    pub operation: Option<String>,
+}
+
+impl LockOwner {
+    /// このプロセス
+    fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            operation: OPERATION.get().cloned(),
+        }
+    }
+
+    /// ロックファイルの内容（1 行目に PID、2 行目に操作名）を解釈する
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Lock file content.
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut lines = content.lines();
+        let pid = lines.next()?.trim().parse::<u32>().ok()?;
+        let operation = lines
+            .next()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from);
+        Some(Self { pid, operation })
+    }
+
+    /// ロックファイルに書く内容
+    fn render(&self) -> String {
+        match &self.operation {
+            Some(operation) => format!("{}\n{}", self.pid, operation),
+            None => self.pid.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for LockOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PID {}", self.pid)?;
+        if let Some(operation) = &self.operation {
+            write!(f, " ({})", operation)?;
+        }
+        Ok(())
+    }
+}
+
+/// プラグイン単位の操作ロック（drop で解放）
+#[derive(Debug)]
+pub struct PluginLock {
+    path: PathBuf,
+}
+
+impl PluginLock {
+    /// プラグインの操作ロックを取得する
+    ///
+    /// 待機するかどうかは [`LockWait::from_env`] で決まる。
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Package cache the plugin is stored in.
+    /// * `marketplace` - Marketplace name (`None` uses `"github"`).
+    /// * `name` - Plugin id (cache directory name).
+    pub fn acquire(
+        cache: &dyn PackageCacheAccess,
+        marketplace: Option<&str>,
+        name: &str,
+    ) -> Result<Self> {
+        Self::acquire_at(
+            lock_path(&cache.plugin_path(marketplace, name)),
+            name,
+            LockWait::from_env(),
+        )
+    }
+
+    /// 指定したロックファイルでロックを取得する
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Lock file path.
+    /// * `name` - Plugin name used in the busy error.
+    /// * `wait` - What to do while another process holds the lock.
+    pub(crate) fn acquire_at(path: PathBuf, name: &str, wait: LockWait) -> Result<Self> {
+        if let Some(count) = held().get_mut(&path) {
+            *count += 1;
+            return Ok(Self { path });
+        }
+
+        let started = Instant::now();
+        let mut next_progress = Duration::ZERO;
+        while !try_create(&path, &LockOwner::current())? {
+            let owner = match check_owner(&path)? {
+                Some(owner) => owner,
+                None => continue,
+            };
+            let elapsed = started.elapsed();
+            match wait {
+                LockWait::Fail => return Err(PlmError::PluginBusy(name.to_string())),
+                LockWait::Timeout(limit) if elapsed >= limit => {
+                    return Err(PlmError::LockTimeout {
+                        name: name.to_string(),
+                        seconds: limit.as_secs(),
+                    })
+                }
+                _ => {}
+            }
+            if elapsed >= next_progress {
+                eprintln!(
+                    "Waiting for lock held by {}... {}s",
+                    owner,
+                    elapsed.as_secs()
+                );
+                next_progress += PROGRESS_INTERVAL;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        held().insert(path.clone(), 1);
+        Ok(Self { path })
+    }
+}
+
+impl Drop for PluginLock {
+    fn drop(&mut self) {
+        let mut held = held();
+        let Some(count) = held.get_mut(&self.path) else {
+            return;
+        };
+        *count -= 1;
+        if *count == 0 {
+            held.remove(&self.path);
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// このプロセスが保持しているロックをすべて解放する
+///
+/// 中断（Ctrl-C）時の後始末用。`std::process::exit` では [`PluginLock`] の `Drop` が
+/// 実行されないため、参照カウントに関わらずロックファイルを削除する。
+pub fn release_all_locks() {
+    release_held(&HELD);
+}
+
+/// 保持中ロックの表を空にし、記録されていたロックファイルを削除する
+///
+/// # Arguments
+///
+/// * `table` - Table of held lock files and their reference counts.
+fn release_held(table: &Mutex<BTreeMap<PathBuf, usize>>) {
+    let held = std::mem::take(&mut *table.lock().unwrap_or_else(PoisonError::into_inner));
+    for path in held.into_keys() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// 保持中ロックの表（poison しても中身はそのまま使う）
+fn held() -> std::sync::MutexGuard<'static, BTreeMap<PathBuf, usize>> {
+    HELD.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// プラグインディレクトリに対応するロックファイルのパス
+///
+/// # Arguments
+///
+/// * `plugin_path` - Cache directory of the plugin.
+fn lock_path(plugin_path: &Path) -> PathBuf {
+    let name = plugin_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    plugin_path.with_file_name(format!(".{}.lock", name))
+}
+
+/// ロックファイルを作成する（既に存在すれば `false`）
+///
+/// 保持者を書いた一時ファイルをハードリンクで置くため、PID が未記入の
+/// ロックファイルが他プロセスから見えることはない。
+///
+/// # Arguments
+///
+/// * `path` - Lock file path.
+/// * `owner` - Owner recorded in the lock file.
+fn try_create(path: &Path, owner: &LockOwner) -> Result<bool> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temp = path.with_extension(format!("lock.{}", owner.pid));
+    fs::File::create(&temp)?.write_all(owner.render().as_bytes())?;
+
+    let linked = fs::hard_link(&temp, path);
+    let _ = fs::remove_file(&temp);
+    match linked {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// ロックの保持者を確認する
+///
+/// 保持プロセスが存在しないロックファイルは削除し、取り直せる状態なら `None` を返す。
+///
+/// # Arguments
+///
+/// * `path` - Lock file path.
+fn check_owner(path: &Path) -> Result<Option<LockOwner>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        // 読む前に解放された
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    if let Some(owner) = LockOwner::parse(&content).filter(|o| is_process_alive(o.pid)) {
+        return Ok(Some(owner));
+    }
+    remove_if_stale(path, &content)?;
+    Ok(None)
+}
+
+/// stale と判定したロックファイルを、まだ同じ内容なら削除する
+///
+/// 判定から削除までの間に別プロセスが stale ロックを消して取り直すと、そのロックを
+/// 誤って消してしまう。削除はガードファイルを取ったプロセスだけが行い、ガードの内側で
+/// 内容を読み直す。ガードが取れなければ何もしない（呼び出し側が確認からやり直す）。
+///
+/// # Arguments
+///
+/// * `path` - Lock file path.
+/// * `stale` - Content read when the lock was judged stale.
+fn remove_if_stale(path: &Path, stale: &str) -> Result<()> {
+    let guard = path.with_extension("lock.takeover");
+    if !try_create(&guard, &LockOwner::current())? {
+        if is_abandoned(&guard) {
+            let _ = fs::remove_file(&guard);
+        } else {
+            // 他のプロセスが削除中。終わるのを少し待ってから確認し直させる
+            thread::sleep(POLL_INTERVAL / 10);
+        }
+        return Ok(());
+    }
+
+    let removed = match fs::read_to_string(path) {
+        Ok(content) if content == stale => match fs::remove_file(path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    };
+    let _ = fs::remove_file(&guard);
+    removed
+}
+
+/// ガードを持ったまま強制終了したプロセスが残したガードか
+///
+/// # Arguments
+///
+/// * `guard` - Takeover guard file path.
+fn is_abandoned(guard: &Path) -> bool {
+    let owner_dead = fs::read_to_string(guard)
+        .ok()
+        .and_then(|content| LockOwner::parse(&content))
+        .is_some_and(|owner| !is_process_alive(owner.pid));
+    let expired = fs::metadata(guard)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age >= TAKEOVER_GUARD_TTL);
+    owner_dead || expired
+}
+
+/// PID のプロセスが存在するか
+///
+/// # Arguments
+///
+/// * `pid` - Process id recorded in the lock file.
+#[cfg(unix)]
+fn is_process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        return false;
+    }
+    // シグナル 0 は存在確認のみ。EPERM は他ユーザーのプロセスが存在することを表す
+    let result = unsafe { libc::kill(pid, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// PID のプロセスが存在するか（確認手段が無いため常に存在するとみなす）
+///
+/// stale ロックは自動で取り除かれないため、PLG004 のヒントで手動削除を案内する。
+///
+/// # Arguments
+///
+/// * `_pid` - Process id recorded in the lock file.
+#[cfg(not(unix))]
+fn is_process_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+#[path = "lock_test.rs"]
+mod tests;
@@ -0,0 +1,266 @@
+use super::*;
+use crate::plugin::PackageCache;
+use tempfile::TempDir;
+
+/// 存在しないはずの PID
+const DEAD_PID: u32 = i32::MAX as u32;
+
+fn lock_file(dir: &TempDir) -> PathBuf {
+    dir.path().join("mp").join(".foo.lock")
+}
+
+#[test]
+fn acquire_writes_pid_and_drop_releases() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+
+    let lock = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        std::process::id().to_string()
+    );
+
+    drop(lock);
+    assert!(!path.exists());
+}
+
+#[test]
+fn acquire_uses_file_next_to_plugin_cache_dir() {
+    let dir = TempDir::new().unwrap();
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+
+    let _lock = PluginLock::acquire(&cache, Some("mp"), "foo").unwrap();
+
+    let expected = lock_path(&cache.plugin_path(Some("mp"), "foo"));
+    assert_eq!(expected.file_name().unwrap(), ".foo.lock");
+    assert!(expected.exists());
+    assert!(cache.list().unwrap().is_empty());
+}
+
+#[test]
+fn reacquire_in_same_process_is_reentrant() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+
+    let outer = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap();
+    let inner = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap();
+
+    drop(inner);
+    assert!(path.exists(), "outer lock must still be held");
+    drop(outer);
+    assert!(!path.exists());
+}
+
+#[test]
+fn lock_held_by_live_process_is_busy() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    // PID 1 は常に存在する
+    fs::write(&path, "1").unwrap();
+
+    let err = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap_err();
+
+    assert!(matches!(err, PlmError::PluginBusy(_)));
+    assert_eq!(
+        err.to_string(),
+        "plugin 'foo' is being modified by another process"
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1");
+}
+
+#[test]
+fn stale_lock_is_taken_over() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+    for stale in [DEAD_PID.to_string(), "not a pid".to_string()] {
+        fs::write(&path, &stale).unwrap();
+        let lock = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            std::process::id().to_string(),
+            "{}",
+            stale
+        );
+        drop(lock);
+    }
+}
+
+#[test]
+fn stale_lock_replaced_by_a_live_lock_is_kept() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    // stale と判定した後に、別プロセスが取り直した
+    fs::write(&path, "1").unwrap();
+
+    remove_if_stale(&path, &DEAD_PID.to_string()).unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1");
+    assert!(!path.with_extension("lock.takeover").exists());
+}
+
+#[test]
+fn stale_lock_is_not_removed_while_another_process_takes_over() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, DEAD_PID.to_string()).unwrap();
+    let guard = path.with_extension("lock.takeover");
+    fs::write(&guard, "1").unwrap();
+
+    remove_if_stale(&path, &DEAD_PID.to_string()).unwrap();
+
+    assert!(path.exists());
+    assert!(guard.exists());
+}
+
+#[test]
+fn abandoned_takeover_guard_is_removed() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, DEAD_PID.to_string()).unwrap();
+    fs::write(path.with_extension("lock.takeover"), DEAD_PID.to_string()).unwrap();
+
+    let lock = PluginLock::acquire_at(path.clone(), "foo", LockWait::Fail).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        std::process::id().to_string()
+    );
+    drop(lock);
+}
+
+#[test]
+fn wait_blocks_until_lock_is_released() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "1").unwrap();
+
+    let releaser = {
+        let path = path.clone();
+        thread::spawn(move || {
+            thread::sleep(POLL_INTERVAL * 3);
+            fs::remove_file(path).unwrap();
+        })
+    };
+    let lock = PluginLock::acquire_at(path.clone(), "foo", LockWait::Forever).unwrap();
+    releaser.join().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        std::process::id().to_string()
+    );
+    drop(lock);
+}
+
+#[test]
+fn wait_with_timeout_fails_while_lock_is_held() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "1\nplm update").unwrap();
+
+    let started = Instant::now();
+    let err = PluginLock::acquire_at(path.clone(), "foo", LockWait::Timeout(POLL_INTERVAL * 3))
+        .unwrap_err();
+
+    assert!(started.elapsed() >= POLL_INTERVAL * 3);
+    assert!(matches!(err, PlmError::LockTimeout { .. }));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1\nplm update");
+}
+
+#[test]
+fn wait_with_timeout_acquires_when_released_in_time() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "1\nplm update").unwrap();
+
+    let releaser = {
+        let path = path.clone();
+        thread::spawn(move || {
+            thread::sleep(POLL_INTERVAL * 3);
+            fs::remove_file(path).unwrap();
+        })
+    };
+    let lock = PluginLock::acquire_at(
+        path.clone(),
+        "foo",
+        LockWait::Timeout(Duration::from_secs(10)),
+    )
+    .unwrap();
+    releaser.join().unwrap();
+
+    assert_eq!(
+        LockOwner::parse(&fs::read_to_string(&path).unwrap())
+            .unwrap()
+            .pid,
+        std::process::id()
+    );
+    drop(lock);
+}
+
+#[test]
+fn lock_file_records_pid_and_operation() {
+    let dir = TempDir::new().unwrap();
+    let path = lock_file(&dir);
+    let owner = LockOwner {
+        pid: 1234,
+        operation: Some("plm update".to_string()),
+    };
+
+    assert!(try_create(&path, &owner).unwrap());
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert_eq!(content, "1234\nplm update");
+    assert_eq!(LockOwner::parse(&content), Some(owner.clone()));
+    assert_eq!(owner.to_string(), "PID 1234 (plm update)");
+}
+
+#[test]
+fn lock_owner_parses_pid_only_files() {
+    let owner = LockOwner::parse("1234").unwrap();
+
+    assert_eq!(owner.operation, None);
+    assert_eq!(owner.to_string(), "PID 1234");
+    assert_eq!(LockOwner::parse("not a pid"), None);
+}
+
+#[test]
+fn lock_wait_resolves_from_env_values() {
+    let some = |s: &str| Some(s.to_string());
+
+    assert_eq!(LockWait::resolve(None, None), LockWait::Fail);
+    assert_eq!(LockWait::resolve(some("1"), None), LockWait::Forever);
+    assert_eq!(
+        LockWait::resolve(some("1"), some("15")),
+        LockWait::Timeout(Duration::from_secs(15))
+    );
+    assert_eq!(LockWait::resolve(None, some("0")), LockWait::Fail);
+    assert_eq!(
+        LockWait::resolve(some("1"), some("soon")),
+        LockWait::Forever
+    );
+}
+
+#[test]
+fn release_held_removes_every_lock_regardless_of_count() {
+    let dir = TempDir::new().unwrap();
+    let outer = lock_file(&dir);
+    let other = dir.path().join("mp").join(".bar.lock");
+    fs::create_dir_all(outer.parent().unwrap()).unwrap();
+    fs::write(&outer, "1").unwrap();
+    fs::write(&other, "1").unwrap();
+    let table = Mutex::new(BTreeMap::from([(outer.clone(), 2), (other.clone(), 1)]));
+
+    release_held(&table);
+
+    assert!(!outer.exists());
+    assert!(!other.exists());
+    assert!(table.lock().unwrap().is_empty());
+}
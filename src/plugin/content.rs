@@ -6,4 +6,6 @@ mod plugin_content;
 pub use installed::InstalledPlugin;
 pub(crate) use loader::load_plugin;
 pub use marketplace_content::MarketplaceContent;
+#[cfg(test)]
+pub(crate) use plugin_content::component_scan_count;
 pub(crate) use plugin_content::Plugin;
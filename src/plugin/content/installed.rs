@@ -1,11 +1,13 @@
 //! インストール済みプラグイン DTO
 //!
 //! `Plugin`（manifest + path + components）を内部に所有し、
-//! 起源情報（marketplace / id）とデプロイ状態（enabled）を追加で保持する。
+//! 起源情報（marketplace / id）とデプロイ状態（enabled / 部分 disable / 配置スコープ / sandbox）、
+//! 更新チャンネル、インストール・更新日時を追加で保持する。
 //! serde 属性は持たず、wire format は commands 層が責任を持つ。
 
-use crate::component::{Component, ComponentKind};
-use crate::plugin::{Author, Plugin};
+use crate::component::{Component, ComponentKind, Scope};
+use crate::plugin::{Author, Channel, DisabledComponent, Plugin, SandboxInfo, UpdateRecord};
+use crate::scan::{scan_hooks_config, HookConfigScan};
 use std::path::Path;
 
 #[derive(Debug, Clone)]
@@ -14,6 +16,15 @@ pub struct InstalledPlugin {
     id: Option<String>,
     marketplace: Option<String>,
     enabled: bool,
+    kept_kinds: Option<Vec<ComponentKind>>,
+    disabled_components: Vec<DisabledComponent>,
+    sandbox: Option<SandboxInfo>,
+    channel: Option<Channel>,
+    pinned: bool,
+    scopes: Vec<Scope>,
+    installed_at: Option<String>,
+    updated_at: Option<String>,
+    update_history: Vec<UpdateRecord>,
 }
 
 impl InstalledPlugin {
@@ -36,6 +47,15 @@ impl InstalledPlugin {
             id,
             marketplace,
             enabled,
+            kept_kinds: None,
+            disabled_components: Vec::new(),
+            sandbox: None,
+            channel: None,
+            pinned: false,
+            scopes: Vec::new(),
+            installed_at: None,
+            updated_at: None,
+            update_history: Vec::new(),
         }
     }
 
@@ -64,13 +84,184 @@ impl InstalledPlugin {
         self.enabled
     }
 
+    /// 部分 disable（`plm disable --keep`）で配置を残している種別（部分 disable 中でなければ `None`）
+    pub fn kept_kinds(&self) -> Option<&[ComponentKind]> {
+        self.kept_kinds.as_deref()
+    }
+
+    /// 部分 disable の記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `kept_kinds` - Kinds kept deployed by a partial disable, if any.
+    pub(crate) fn with_kept_kinds(mut self, kept_kinds: Option<Vec<ComponentKind>>) -> Self {
+        self.kept_kinds = kept_kinds;
+        self
+    }
+
+    /// コンポーネント単位で無効化しているコンポーネント（`plm disable --component`）
+    pub fn disabled_components(&self) -> &[DisabledComponent] {
+        &self.disabled_components
+    }
+
+    /// 指定コンポーネントをコンポーネント単位で無効化しているか
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind.
+    /// * `name` - Component name (flattened identifier).
+    pub fn is_component_disabled(&self, kind: ComponentKind, name: &str) -> bool {
+        self.disabled_components
+            .iter()
+            .any(|c| c.kind == kind && c.name == name)
+    }
+
+    /// コンポーネント単位の無効化の記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `disabled_components` - Components disabled with `plm disable --component`.
+    pub(crate) fn with_disabled_components(
+        mut self,
+        disabled_components: Vec<DisabledComponent>,
+    ) -> Self {
+        self.disabled_components = disabled_components;
+        self
+    }
+
+    /// 状態の表示ラベル（`"enabled"` / `"disabled"` / `"partially disabled (instructions only)"`）
+    pub fn status_label(&self) -> String {
+        match (self.enabled, self.kept_kinds()) {
+            (true, _) => "enabled".to_string(),
+            (false, Some(kinds)) => {
+                let kinds: Vec<_> = kinds.iter().map(|k| k.plural()).collect();
+                format!("partially disabled ({} only)", kinds.join(", "))
+            }
+            (false, None) => "disabled".to_string(),
+        }
+    }
+
+    /// 配置記録から求めた配置先のスコープ（記録が無ければ空）
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    /// 配置先のスコープを付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `scopes` - Scopes the plugin is deployed to.
+    pub(crate) fn with_scopes(mut self, scopes: Vec<Scope>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// 試用インストール（sandbox）の記録
+    pub fn sandbox(&self) -> Option<&SandboxInfo> {
+        self.sandbox.as_ref()
+    }
+
+    /// sandbox の記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `sandbox` - Sandbox recorded in `.plm-meta.json`, if any.
+    pub(crate) fn with_sandbox(mut self, sandbox: Option<SandboxInfo>) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// 追従している更新チャンネル
+    pub fn channel(&self) -> Option<Channel> {
+        self.channel
+    }
+
+    /// 更新チャンネルの記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Channel recorded in `.plm-meta.json`, if any.
+    pub(crate) fn with_channel(mut self, channel: Option<Channel>) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// バージョン固定（`plm pin`）されているか
+    pub fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// バージョン固定の記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `pinned` - Whether `.plm-meta.json` records the plugin as pinned.
+    pub(crate) fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// インストール日時（`.plm-meta.json` の `installedAt`、RFC3339）
+    pub fn installed_at(&self) -> Option<&str> {
+        self.installed_at.as_deref()
+    }
+
+    /// 更新日時（`.plm-meta.json` の `updatedAt`、RFC3339）
+    pub fn updated_at(&self) -> Option<&str> {
+        self.updated_at.as_deref()
+    }
+
+    /// 最後にキャッシュへ取り込んだ日時（更新日時、未更新ならインストール日時）
+    pub fn last_updated(&self) -> Option<&str> {
+        self.updated_at().or(self.installed_at())
+    }
+
+    /// インストール・更新日時の記録を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `installed_at` - `installedAt` recorded in `.plm-meta.json`, if any.
+    /// * `updated_at` - `updatedAt` recorded in `.plm-meta.json`, if any.
+    pub(crate) fn with_timestamps(
+        mut self,
+        installed_at: Option<String>,
+        updated_at: Option<String>,
+    ) -> Self {
+        self.installed_at = installed_at;
+        self.updated_at = updated_at;
+        self
+    }
+
+    /// 直近のインストール・更新履歴（`.plm-meta.json` の `updateHistory`、新しい順）
+    pub fn update_history(&self) -> &[UpdateRecord] {
+        &self.update_history
+    }
+
+    /// 直近のインストール・更新（履歴が無ければ `None`）
+    pub fn last_update(&self) -> Option<&UpdateRecord> {
+        self.update_history.first()
+    }
+
+    /// インストール・更新履歴を付与する
+    ///
+    /// # Arguments
+    ///
+    /// * `update_history` - `updateHistory` recorded in `.plm-meta.json` (newest first).
+    pub(crate) fn with_update_history(mut self, update_history: Vec<UpdateRecord>) -> Self {
+        self.update_history = update_history;
+        self
+    }
+
     /// 内部的な有効状態の設定（TUI からの状態更新用）
     ///
+    /// enable / disable はどちらも全種別を操作するため、部分 disable の記録は外す。
+    ///
     /// # Arguments
     ///
     /// * `enabled` - new enabled state to assign
     pub(crate) fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
+        self.kept_kinds = None;
     }
 
     /// プラグインID（`id` が `None` の場合は `name` にフォールバック）
@@ -102,6 +293,11 @@ impl InstalledPlugin {
             .collect()
     }
 
+    /// `hooks/hooks.json`（Claude Code 形式）のフック定義（hooks.json が無ければ `None`）
+    pub fn hooks_config(&self) -> Option<HookConfigScan> {
+        scan_hooks_config(&self.plugin.hooks_dir())
+    }
+
     /// プラグインの説明文
     pub fn description(&self) -> Option<&str> {
         self.plugin.manifest().description.as_deref()
@@ -117,6 +313,25 @@ impl InstalledPlugin {
             .filter(|a| !a.name.is_empty())
     }
 
+    /// ホームページ URL（空文字は `None`）
+    pub fn homepage(&self) -> Option<&str> {
+        non_empty(self.plugin.manifest().homepage.as_deref())
+    }
+
+    /// リポジトリ URL（空文字は `None`）
+    pub fn repository(&self) -> Option<&str> {
+        non_empty(self.plugin.manifest().repository.as_deref())
+    }
+
+    /// キーワード一覧（未指定なら空）
+    pub fn keywords(&self) -> &[String] {
+        self.plugin
+            .manifest()
+            .keywords
+            .as_deref()
+            .unwrap_or_default()
+    }
+
     /// キャッシュ上のプラグインパス
     pub fn cache_path(&self) -> &Path {
         self.plugin.path()
@@ -150,6 +365,8 @@ impl InstalledPlugin {
             hooks: None,
             mcp_servers: None,
             lsp_servers: None,
+            exclude: None,
+            dependencies: None,
         };
         let plugin = Plugin::new_for_test(manifest, PathBuf::from("/test"), components);
         Self {
@@ -157,6 +374,15 @@ impl InstalledPlugin {
             id,
             marketplace,
             enabled,
+            kept_kinds: None,
+            disabled_components: Vec::new(),
+            sandbox: None,
+            channel: None,
+            pinned: false,
+            scopes: Vec::new(),
+            installed_at: None,
+            updated_at: None,
+            update_history: Vec::new(),
         }
     }
 
@@ -177,10 +403,28 @@ impl InstalledPlugin {
             id,
             marketplace,
             enabled,
+            kept_kinds: None,
+            disabled_components: Vec::new(),
+            sandbox: None,
+            channel: None,
+            pinned: false,
+            scopes: Vec::new(),
+            installed_at: None,
+            updated_at: None,
+            update_history: Vec::new(),
         }
     }
 }
 
+/// 空文字を `None` として扱う
+///
+/// # Arguments
+///
+/// * `value` - Optional manifest field.
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.filter(|v| !v.trim().is_empty())
+}
+
 #[cfg(test)]
 #[path = "installed_test.rs"]
 mod tests;
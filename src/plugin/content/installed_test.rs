@@ -216,6 +216,8 @@ fn installed_plugin_with_author(author: Option<Author>) -> InstalledPlugin {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     };
     InstalledPlugin::new_for_test_full(
         manifest,
@@ -248,3 +250,17 @@ fn author_returns_none_when_name_is_empty() {
     }));
     assert!(summary.author().is_none());
 }
+
+#[test]
+fn status_label_describes_partial_disable_until_state_is_reset() {
+    let mut plugin = InstalledPlugin::new_for_test("a", "1.0.0", Vec::new(), None, None, false)
+        .with_kept_kinds(Some(vec![ComponentKind::Skill, ComponentKind::Instruction]));
+    assert_eq!(
+        plugin.status_label(),
+        "partially disabled (skills, instructions only)"
+    );
+
+    plugin.set_enabled(true);
+    assert_eq!(plugin.status_label(), "enabled");
+    assert_eq!(plugin.kept_kinds(), None);
+}
@@ -7,6 +7,7 @@ use crate::component::{AgentFormat, CommandFormat, Component};
 use crate::error::PlmError;
 use crate::marketplace::MarketplaceManifest;
 use crate::plugin::PluginManifest;
+use crate::scan::ExcludeRules;
 use crate::target::PluginOrigin;
 use std::path::{Path, PathBuf};
 
@@ -30,6 +31,34 @@ pub struct MarketplaceContent {
 }
 
 impl MarketplaceContent {
+    /// 構築済みの Plugin からキャッシュ上のパッケージを組み立てる
+    ///
+    /// 一覧用インデックスから復元した Plugin を包むために使う（再スキャンしない）。
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Cache directory name.
+    /// * `marketplace` - Marketplace name (`None` for direct GitHub installs).
+    /// * `primary` - Plugin built from (or restored for) the cache directory.
+    pub(crate) fn from_plugin(
+        id: Option<String>,
+        marketplace: Option<String>,
+        primary: Plugin,
+    ) -> Self {
+        Self {
+            id,
+            marketplace,
+            marketplace_manifest: None,
+            primary,
+            extra_plugins: Vec::new(),
+        }
+    }
+
+    /// 代表プラグインを取得
+    pub(crate) fn primary(&self) -> &Plugin {
+        &self.primary
+    }
+
     /// プラグイン名を取得
     pub fn name(&self) -> &str {
         self.primary.name()
@@ -105,6 +134,20 @@ impl MarketplaceContent {
         self.primary.hooks_dir()
     }
 
+    /// 除外ルールにより対象外となったパス（各プラグインルートからの相対パス）
+    pub fn excluded_paths(&self) -> Vec<PathBuf> {
+        std::iter::once(&self.primary)
+            .chain(self.extra_plugins.iter())
+            .flat_map(|p| p.excluded_paths())
+            .cloned()
+            .collect()
+    }
+
+    /// 代表プラグインの除外ルールを取得
+    pub fn exclude_rules(&self) -> ExcludeRules {
+        self.primary.exclude_rules()
+    }
+
     /// プラグイン内のコンポーネントを取得
     pub fn components(&self) -> Vec<Component> {
         std::iter::once(&self.primary)
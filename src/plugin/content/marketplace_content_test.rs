@@ -22,6 +22,8 @@ fn make_manifest(name: &str) -> PluginManifest {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     }
 }
 
@@ -7,7 +7,7 @@ use crate::error::{PlmError, Result};
 use crate::plugin::PluginManifest;
 use crate::scan::{
     file_stem_name, list_agent_names, list_command_names, list_hook_names, list_markdown_names,
-    list_skill_names,
+    list_skill_names, ExcludeRules,
 };
 use crate::target::PluginOrigin;
 use std::collections::HashMap;
@@ -49,6 +49,18 @@ fn validate_path_segment(label: &str, name: &str) -> Result<()> {
     Ok(())
 }
 
+#[cfg(test)]
+thread_local! {
+    /// `Plugin::build_components` の呼び出し回数（スキャン回数を検証するテスト用）
+    static COMPONENT_SCANS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// 現在のスレッドで行ったコンポーネントスキャンの回数（テスト用）
+#[cfg(test)]
+pub(crate) fn component_scan_count() -> usize {
+    COMPONENT_SCANS.with(|count| count.get())
+}
+
 /// パッケージ内の個別プラグイン
 ///
 /// `manifest`, `path` を保持し、構築時にコンポーネントを一度だけスキャンしてキャッシュする。
@@ -63,6 +75,8 @@ pub struct Plugin {
     path: PathBuf,
     origin: PluginOrigin,
     components: Vec<Component>,
+    /// 除外ルールにより対象外となったコンポーネント候補（プラグインルートからの相対パス）
+    excluded: Vec<PathBuf>,
 }
 
 impl Plugin {
@@ -74,15 +88,46 @@ impl Plugin {
     /// * `path` - Root directory path of the plugin on disk.
     /// * `origin` - Plugin origin (marketplace and plugin identifier).
     pub fn new(manifest: PluginManifest, path: PathBuf, origin: PluginOrigin) -> Result<Self> {
-        let components = Self::build_components(&path, &manifest)?;
+        let rules = manifest.exclude_rules()?;
+        let mut excluded = Vec::new();
+        let components = Self::build_components(&path, &manifest, &rules, &mut excluded)?;
         Ok(Self {
             manifest,
             path,
             origin,
             components,
+            excluded,
         })
     }
 
+    /// 保存済みのスキャン結果から Plugin を復元する（FS スキャンを行わない）
+    ///
+    /// `components` / `excluded` は `path` を `Plugin::new` でスキャンした結果であること。
+    /// 一覧用インデックス（`plugins-index.json`）からの復元専用。
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Plugin manifest saved with the scan result.
+    /// * `path` - Root directory path of the plugin on disk.
+    /// * `origin` - Plugin origin (marketplace and plugin identifier).
+    /// * `components` - Components found by the saved scan.
+    /// * `excluded` - Paths excluded by the saved scan (relative to `path`).
+    pub(crate) fn from_index(
+        manifest: PluginManifest,
+        path: PathBuf,
+        origin: PluginOrigin,
+        components: Vec<Component>,
+        excluded: Vec<PathBuf>,
+    ) -> Self {
+        Self {
+            manifest,
+            path,
+            origin,
+            components,
+            excluded,
+        }
+    }
+
     /// テスト専用コンストラクタ: FS スキャンをバイパスしてコンポーネントを直接注入する
     #[cfg(test)]
     pub(crate) fn new_for_test(
@@ -95,6 +140,7 @@ impl Plugin {
             path,
             origin: PluginOrigin::from_marketplace("test", "test"),
             components,
+            excluded: Vec::new(),
         }
     }
 
@@ -120,13 +166,37 @@ impl Plugin {
 
     /// プラグインのコンポーネントをスキャンして Vec<Component> に変換する
     ///
+    /// 除外ルールにマッチしたパスは `excluded` に積み、コンポーネントには含めない。
+    ///
     /// # Arguments
     ///
     /// * `path` - Plugin root directory used to resolve component directories.
     /// * `manifest` - Plugin manifest that defines component layout and names.
-    fn build_components(path: &Path, manifest: &PluginManifest) -> Result<Vec<Component>> {
+    /// * `rules` - Exclude rules built from the manifest.
+    /// * `excluded` - Output buffer that receives excluded relative paths.
+    fn build_components(
+        path: &Path,
+        manifest: &PluginManifest,
+        rules: &ExcludeRules,
+        excluded: &mut Vec<PathBuf>,
+    ) -> Result<Vec<Component>> {
+        #[cfg(test)]
+        COMPONENT_SCANS.with(|count| count.set(count.get() + 1));
+
         let plugin_name = manifest.name.as_str();
         let mut components = Vec::new();
+        let mut retain = |items: Vec<(String, PathBuf)>| -> Vec<(String, PathBuf)> {
+            items
+                .into_iter()
+                .filter(|(_, p)| match p.strip_prefix(path) {
+                    Ok(relative) if rules.is_excluded(relative) => {
+                        excluded.push(relative.to_path_buf());
+                        false
+                    }
+                    _ => true,
+                })
+                .collect()
+        };
 
         for (kind, items) in [
             (
@@ -146,12 +216,21 @@ impl Plugin {
                 list_hook_names(&manifest.hooks_dir(path)),
             ),
         ] {
-            let flattened = flatten_components(kind, plugin_name, items)?;
+            let flattened = flatten_components(kind, plugin_name, retain(items))?;
             detect_name_collisions(&flattened)?;
             components.extend(flattened);
         }
 
-        Self::build_instructions(path, manifest, &mut components);
+        let mut instructions = Vec::new();
+        Self::build_instructions(path, manifest, &mut instructions);
+        for component in instructions {
+            match component.path.strip_prefix(path) {
+                Ok(relative) if rules.is_excluded(relative) => {
+                    excluded.push(relative.to_path_buf());
+                }
+                _ => components.push(component),
+            }
+        }
 
         Ok(components)
     }
@@ -223,7 +302,23 @@ impl Plugin {
         self.manifest.hooks_dir(&self.path)
     }
 
+    /// 除外ルールにより対象外となったパス（プラグインルートからの相対パス）
+    pub fn excluded_paths(&self) -> &[PathBuf] {
+        &self.excluded
+    }
+
+    /// 除外ルールを取得（組み込みリスト + `plugin.json` の `exclude`）
+    ///
+    /// `Plugin::new` で検証済みのため失敗しない。
+    pub fn exclude_rules(&self) -> ExcludeRules {
+        self.manifest.exclude_rules().unwrap_or_default()
+    }
+
     /// プラグイン内のコンポーネントを取得（構築時のスナップショット）
+    ///
+    /// 種別ごと（Skill → Agent → Command → Hook → Instruction）にまとまり、
+    /// 各種別内は `scan::list_*` の名前順に並ぶ。TUI・`plm info`・デプロイ処理は
+    /// この順序をそのまま使う。
     pub fn components(&self) -> &[Component] {
         &self.components
     }
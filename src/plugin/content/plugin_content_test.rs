@@ -26,6 +26,8 @@ fn make_manifest(name: &str) -> PluginManifest {
         hooks: None,
         mcp_servers: None,
         lsp_servers: None,
+        exclude: None,
+        dependencies: None,
     }
 }
 
@@ -612,3 +614,74 @@ fn test_plugin_new_rejects_plugin_name_dotdot() {
     );
     assert!(matches!(result, Err(PlmError::Validation(_))));
 }
+
+#[test]
+fn test_plugin_new_drops_components_matching_exclude() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().to_path_buf();
+    write_file(&path.join("skills/keep/SKILL.md"), "# Skill");
+    write_file(&path.join("skills/wip-draft/SKILL.md"), "# Skill");
+    write_file(&path.join("agents/review.md"), "# Agent");
+    write_file(&path.join("agents/review.draft.md"), "# Agent");
+
+    let mut manifest = make_manifest("test");
+    manifest.exclude = Some(vec![
+        "skills/*-draft".to_string(),
+        "**/*.draft.md".to_string(),
+    ]);
+    let plugin = Plugin::new(
+        manifest,
+        path,
+        PluginOrigin::from_marketplace("test", "test"),
+    )
+    .unwrap();
+
+    let names: Vec<&str> = plugin
+        .components()
+        .iter()
+        .filter_map(|c| c.original_name.as_deref())
+        .collect();
+    assert_eq!(names, vec!["keep", "review"]);
+    assert_eq!(
+        plugin.excluded_paths(),
+        &[
+            Path::new("skills/wip-draft").to_path_buf(),
+            Path::new("agents/review.draft.md").to_path_buf(),
+        ]
+    );
+}
+
+#[test]
+fn test_plugin_new_applies_builtin_excludes_without_manifest_field() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().to_path_buf();
+    write_file(&path.join("skills/keep/SKILL.md"), "# Skill");
+    write_file(&path.join("skills/node_modules/SKILL.md"), "# Skill");
+
+    let plugin = Plugin::new(
+        make_manifest("test"),
+        path,
+        PluginOrigin::from_marketplace("test", "test"),
+    )
+    .unwrap();
+
+    assert_eq!(plugin.components().len(), 1);
+    assert_eq!(
+        plugin.excluded_paths(),
+        &[Path::new("skills/node_modules").to_path_buf()]
+    );
+}
+
+#[test]
+fn test_plugin_new_rejects_invalid_exclude_pattern() {
+    let temp = TempDir::new().unwrap();
+    let mut manifest = make_manifest("test");
+    manifest.exclude = Some(vec!["docs/[".to_string()]);
+
+    let result = Plugin::new(
+        manifest,
+        temp.path().to_path_buf(),
+        PluginOrigin::from_marketplace("test", "test"),
+    );
+    assert!(matches!(result, Err(PlmError::InvalidManifest(_))));
+}
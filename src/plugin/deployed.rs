@@ -0,0 +1,137 @@
+//! プロジェクトのデプロイマニフェスト（`<project>/.plm/deployed.json`）
+//!
+//! 配置記録（`~/.plm/placements.json`）のうちプロジェクト内の配置物を、プラグインごとの
+//! プロジェクトルート相対パスとして書き出す。「どのファイルが plm 管理下か」を
+//! リポジトリ内で機械的に示すためのもので、`plm status` の件数表示と `plm gitignore` の
+//! パターン生成に使う。
+//!
+//! 配置記録を更新するたびに作り直す（[`super::update_placements`]）。共有ファイル
+//! （`AGENTS.md` や `hooks.json`）は配置記録に無いため含まない。
+
+use super::PlacementRecord;
+use crate::error::{PlmError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// デプロイマニフェスト（プロジェクトルートからの相対パス）
+pub const DEPLOYED_MANIFEST_FILE: &str = ".plm/deployed.json";
+
+/// `.plm/deployed.json` のスキーマ
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedManifest {
+    /// プラグイン（`<marketplace>/<plugin>`）→ 配置したファイル・ディレクトリ
+    ///
+    /// パスはプロジェクトルートからの相対パス（`/` 区切り、ソート済み）。
+    #[serde(default)]
+    pub plugins: BTreeMap<String, Vec<String>>,
+}
+
+impl DeployedManifest {
+    /// 配置記録からプロジェクト内の配置物を集める
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Only placements under this directory are included.
+    /// * `records` - Placement records.
+    pub fn from_records(project_root: &Path, records: &[PlacementRecord]) -> Self {
+        let mut plugins: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for record in records {
+            let Ok(relative) = record.path.strip_prefix(project_root) else {
+                continue;
+            };
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if relative.is_empty() {
+                continue;
+            }
+            plugins
+                .entry(record.owner_label())
+                .or_default()
+                .push(relative);
+        }
+        for files in plugins.values_mut() {
+            files.sort();
+            files.dedup();
+        }
+        Self { plugins }
+    }
+
+    /// マニフェストを読み込む（ファイルが無ければ空）
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root containing `.plm/deployed.json`.
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = manifest_path(project_root);
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| PlmError::Parse(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(PlmError::Io(e)),
+        }
+    }
+
+    /// 配置記録からマニフェストを作り直し、内容が変わっていれば書き出す
+    ///
+    /// プロジェクト内に配置物が無く、マニフェストもまだ無い場合は作らない
+    /// （個人スコープにだけ配置したプロジェクトに `.plm/` を作らないため）。
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root containing `.plm/deployed.json`.
+    /// * `records` - Placement records.
+    pub fn sync(project_root: &Path, records: &[PlacementRecord]) -> Result<Self> {
+        let manifest = Self::from_records(project_root, records);
+        let path = manifest_path(project_root);
+        let unchanged = if path.exists() {
+            Self::load(project_root).ok().as_ref() == Some(&manifest)
+        } else {
+            manifest.plugins.is_empty()
+        };
+        if !unchanged {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut json = serde_json::to_string_pretty(&manifest)?;
+            json.push('\n');
+            fs::write(&path, json)?;
+        }
+        Ok(manifest)
+    }
+
+    /// plm 管理下のファイル・ディレクトリ数
+    pub fn file_count(&self) -> usize {
+        self.plugins.values().map(Vec::len).sum()
+    }
+
+    /// plm 管理下のファイル・ディレクトリ（プラグインをまたいでソート済み）
+    pub fn files(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = self
+            .plugins
+            .values()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        files.sort_unstable();
+        files.dedup();
+        files
+    }
+}
+
+/// マニフェストのパス
+///
+/// # Arguments
+///
+/// * `project_root` - Project root containing `.plm/deployed.json`.
+fn manifest_path(project_root: &Path) -> PathBuf {
+    project_root.join(DEPLOYED_MANIFEST_FILE)
+}
+
+#[cfg(test)]
+#[path = "deployed_test.rs"]
+mod tests;
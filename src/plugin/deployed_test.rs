@@ -0,0 +1,75 @@
+use super::*;
+use crate::component::ComponentKind;
+use tempfile::TempDir;
+
+fn record(path: PathBuf, marketplace: &str, plugin: &str) -> PlacementRecord {
+    PlacementRecord {
+        path,
+        target: "codex".to_string(),
+        kind: ComponentKind::Skill,
+        marketplace: Some(marketplace.to_string()),
+        plugin: plugin.to_string(),
+    }
+}
+
+#[test]
+fn from_records_groups_project_files_by_plugin() {
+    let project = Path::new("/work/app");
+    let records = [
+        record(project.join(".codex/skills/lint"), "mp", "tools"),
+        record(project.join(".codex/skills/fmt"), "mp", "tools"),
+        record(
+            project.join(".github/agents/review.agent.md"),
+            "mp",
+            "review",
+        ),
+        record(PathBuf::from("/home/u/.codex/skills/lint"), "mp", "tools"),
+    ];
+
+    let manifest = DeployedManifest::from_records(project, &records);
+
+    assert_eq!(manifest.plugins.len(), 2);
+    assert_eq!(
+        manifest.plugins["mp/tools"],
+        [".codex/skills/fmt", ".codex/skills/lint"]
+    );
+    assert_eq!(
+        manifest.plugins["mp/review"],
+        [".github/agents/review.agent.md"]
+    );
+    assert_eq!(manifest.file_count(), 3);
+    assert_eq!(
+        manifest.files(),
+        [
+            ".codex/skills/fmt",
+            ".codex/skills/lint",
+            ".github/agents/review.agent.md"
+        ]
+    );
+}
+
+#[test]
+fn sync_writes_and_updates_the_manifest() {
+    let temp = TempDir::new().unwrap();
+    let project = temp.path();
+    let skill = record(project.join(".codex/skills/lint"), "mp", "tools");
+
+    let written = DeployedManifest::sync(project, std::slice::from_ref(&skill)).unwrap();
+    assert_eq!(DeployedManifest::load(project).unwrap(), written);
+    assert_eq!(written.file_count(), 1);
+
+    // 全て外れたら空のマニフェストに更新する（ファイルは残す）
+    DeployedManifest::sync(project, &[]).unwrap();
+    assert!(project.join(DEPLOYED_MANIFEST_FILE).exists());
+    assert_eq!(DeployedManifest::load(project).unwrap().file_count(), 0);
+}
+
+#[test]
+fn sync_does_not_create_manifest_without_project_files() {
+    let temp = TempDir::new().unwrap();
+    let elsewhere = record(PathBuf::from("/home/u/.codex/skills/lint"), "mp", "tools");
+
+    DeployedManifest::sync(temp.path(), &[elsewhere]).unwrap();
+
+    assert!(!temp.path().join(".plm").exists());
+}
@@ -1,8 +1,16 @@
 mod action;
 mod intent;
 pub(crate) mod plugin_resolver;
+mod redeploy;
+mod rename;
 mod update;
 
 pub use action::PluginAction;
 pub use intent::PluginIntent;
-pub use update::{update_all_plugins, update_plugin, UpdateOutcome, UpdateStatus};
+pub use redeploy::{redeploy_all_plugins, redeploy_plugin, RedeployOptions};
+pub(crate) use rename::find_renamed_entry;
+pub use rename::{detect_renames, migrate_rename, PluginRename};
+pub use update::{
+    update_all_plugins, update_plugin, update_target_exists, update_target_path, UpdateOutcome,
+    UpdateStatus,
+};
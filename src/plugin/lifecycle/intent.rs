@@ -9,18 +9,54 @@ pub use super::action::PluginAction;
 #[cfg(not(test))]
 use super::action::PluginAction;
 use crate::component::{
-    Component, ComponentKind, ComponentRef, FileOperation, PlacementContext, PlacementScope,
-    ProjectContext, Scope, ScopedPath,
+    assets_dir_for, CommandPrefix, Component, ComponentKind, ComponentRef, FileOperation,
+    PlacementContext, PlacementScope, ProjectContext, PromptNaming, Scope, ScopedPath,
 };
+use crate::plugin::meta::DeployedFile;
+use crate::plugin::{ComponentConflict, PlacementLedger, PlacementRecord};
 use crate::target::{
     all_targets, AffectedTargets, OperationOutcome, PluginOrigin, Target, TargetKind,
 };
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 /// 単一コンポーネントの操作生成結果
 type CreateOperationResult =
     std::result::Result<Option<(TargetKind, FileOperation)>, (TargetKind, String)>;
 
+/// 展開先の一覧（ターゲット種別, コンポーネント種別, 配置先）
+type Destinations = Vec<(TargetKind, ComponentKind, PathBuf)>;
+
+/// ターゲット 1 件分の操作（レガシー階層のクリーンアップを除く）
+struct TargetPlan {
+    /// スキャン済みコンポーネントから生成した操作（コンポーネント種別, 生成結果）
+    components: Vec<(ComponentKind, CreateOperationResult)>,
+    /// 配置記録に基づく削除（Disable では記録どおりの配置物、Enable では今回配置しない古い配置物）
+    recorded_removals: Vec<RecordedRemoval>,
+    /// 別プラグインの配置物と衝突して配置しないコンポーネント（Enable のみ）
+    conflicts: Vec<ComponentConflict>,
+}
+
+/// 配置記録に基づく削除対象 1 件
+struct RecordedRemoval {
+    kind: ComponentKind,
+    /// 記録どおりのパス（記録の更新に使う）
+    recorded: PathBuf,
+    /// 検証済みのパス（削除に使う）
+    path: ScopedPath,
+}
+
+impl RecordedRemoval {
+    /// 展開先 1 件として返す
+    ///
+    /// # Arguments
+    ///
+    /// * `target_kind` - target the record belongs to
+    fn into_destination(self, target_kind: TargetKind) -> (TargetKind, ComponentKind, PathBuf) {
+        (target_kind, self.kind, self.recorded)
+    }
+}
+
 /// `expand()` の結果
 #[derive(Debug)]
 pub struct ExpandOutcome {
@@ -28,6 +64,8 @@ pub struct ExpandOutcome {
     pub operations: Vec<(TargetKind, FileOperation)>,
     /// パス検証エラー（ターゲット種別, エラーメッセージ）
     pub validation_errors: Vec<(TargetKind, String)>,
+    /// 別プラグインの配置物と衝突してスキップしたコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 /// プラグイン操作意図（事前スキャン済みデータを保持）
@@ -37,6 +75,11 @@ pub struct PluginIntent {
     components: Vec<Component>,
     project_root: PathBuf,
     target_filter: Option<String>,
+    enabled_targets: Option<Vec<TargetKind>>,
+    command_prefix: Option<CommandPrefix>,
+    prompt_naming: PromptNaming,
+    recorded_files: HashMap<String, Vec<DeployedFile>>,
+    owners: Option<PlacementLedger>,
 }
 
 impl PluginIntent {
@@ -53,6 +96,11 @@ impl PluginIntent {
             components,
             project_root,
             target_filter: None,
+            enabled_targets: None,
+            command_prefix: None,
+            prompt_naming: PromptNaming::default(),
+            recorded_files: HashMap::new(),
+            owners: None,
         }
     }
 
@@ -75,14 +123,87 @@ impl PluginIntent {
             components,
             project_root,
             target_filter: target_filter.map(String::from),
+            enabled_targets: None,
+            command_prefix: None,
+            prompt_naming: PromptNaming::default(),
+            recorded_files: HashMap::new(),
+            owners: None,
         }
     }
 
+    /// プロジェクトで有効なターゲットを設定する（`.plm/config.json` の `enabledTargets`）
+    ///
+    /// `None` なら全ターゲットを対象とする。無効なターゲットは展開しない。
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled_targets` - targets enabled for the project, or `None` for all
+    pub fn with_enabled_targets(mut self, enabled_targets: Option<Vec<TargetKind>>) -> Self {
+        self.enabled_targets = enabled_targets;
+        self
+    }
+
+    /// コマンド呼び出し名のプレフィクスを設定する
+    ///
+    /// # Arguments
+    ///
+    /// * `command_prefix` - prefix applied to command names on every target
+    pub fn with_command_prefix(mut self, command_prefix: Option<CommandPrefix>) -> Self {
+        self.command_prefix = command_prefix;
+        self
+    }
+
+    /// Copilot プロンプトファイルの命名規約を設定する
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt_naming` - file naming convention for commands deployed to Copilot
+    pub fn with_prompt_naming(mut self, prompt_naming: PromptNaming) -> Self {
+        self.prompt_naming = prompt_naming;
+        self
+    }
+
+    /// デプロイ時の配置記録（`.plm-meta.json` の `deployedFiles`）を設定する
+    ///
+    /// プロジェクト配下に記録のあるターゲットでは、Disable は Skill / Agent / Command を
+    /// スキャン結果ではなく記録どおりに削除し、Enable は今回配置しない記録済みのパスを
+    /// 古い配置物として削除する。記録の無いターゲットは従来どおりスキャン結果だけを使う。
+    ///
+    /// # Arguments
+    ///
+    /// * `recorded_files` - deployed files recorded per target name
+    pub fn with_recorded_files(
+        mut self,
+        recorded_files: HashMap<String, Vec<DeployedFile>>,
+    ) -> Self {
+        self.recorded_files = recorded_files;
+        self
+    }
+
+    /// 配置先の所有者の判定に使う配置記録（`placements.json`）を設定する
+    ///
+    /// 設定すると、Enable で配置先が既に存在し別のプラグインが所有している
+    /// コンポーネントは配置せず、衝突（[`ComponentConflict`]）として報告する。
+    /// `None`（`--overwrite`）なら所有者を問わず上書きする。
+    ///
+    /// # Arguments
+    ///
+    /// * `owners` - placement ledger used to look up destination owners
+    pub fn with_placement_owners(mut self, owners: Option<PlacementLedger>) -> Self {
+        self.owners = owners;
+        self
+    }
+
     /// アクションを取得
     pub fn action(&self) -> &PluginAction {
         &self.action
     }
 
+    /// 配置先のプロジェクトルートを取得
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
     /// コンポーネント数を取得
     pub fn component_count(&self) -> usize {
         self.components.len()
@@ -93,6 +214,7 @@ impl PluginIntent {
     /// 主に保持済みデータを使用して展開を行うが、`create_operation` 内での
     /// パスの検証や正規化などに伴い、ファイルシステムを参照することがある。
     /// target_filter が設定されている場合は、そのターゲットのみを対象とする。
+    /// プロジェクトで無効なターゲット（`with_enabled_targets`）は対象外とする。
     ///
     /// パス検証エラーが発生した場合は `ExpandOutcome::validation_errors` に記録される。
     pub fn expand(&self) -> ExpandOutcome {
@@ -102,18 +224,29 @@ impl PluginIntent {
 
         let mut operations = Vec::new();
         let mut validation_errors = Vec::new();
+        let mut conflicts = Vec::new();
 
-        for target in targets.iter().filter(|target| match &self.target_filter {
-            Some(filter) => target.name() == filter,
-            None => true,
-        }) {
-            for component in self.components.iter().filter(|c| target.supports(c.kind)) {
-                match self.create_operation(target.as_ref(), component, &origin) {
-                    Ok(Some(op)) => operations.push(op),
+        for target in targets
+            .iter()
+            .filter(|target| self.includes(target.as_ref()))
+        {
+            let plan = self.plan_target(target.as_ref(), &origin);
+            conflicts.extend(plan.conflicts);
+            for removal in plan.recorded_removals {
+                let op = remove_operation(removal.kind, removal.path);
+                self.push_with_assets(&mut operations, target.kind(), removal.kind, op);
+            }
+            for (kind, result) in plan.components {
+                match result {
+                    Ok(Some((target_kind, op))) => {
+                        self.push_with_assets(&mut operations, target_kind, kind, op)
+                    }
                     Ok(None) => {} // placement not applicable
                     Err((target_id, msg)) => validation_errors.push((target_id, msg)),
                 }
+            }
 
+            for component in self.components.iter().filter(|c| target.supports(c.kind)) {
                 let legacy_ctx = PlacementContext {
                     component: ComponentRef::from(component),
                     origin: &origin,
@@ -134,7 +267,250 @@ impl PluginIntent {
         ExpandOutcome {
             operations,
             validation_errors,
+            conflicts,
+        }
+    }
+
+    /// 操作と、それに伴う添付アセット（`<名前>.assets/`）の操作を追加する
+    ///
+    /// Command / Agent の配置では定義ファイルの隣にあるアセットディレクトリを丸ごとコピーし
+    /// （ソースに無ければ配置先に残った古いアセットを削除）、削除ではアセットも一緒に消す。
+    /// アセットは配置記録に含めず、記録された定義ファイルのパスから導出する。
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - operation list to append to
+    /// * `target_kind` - target the operation belongs to
+    /// * `kind` - kind of the component the operation is for
+    /// * `op` - operation on the component itself
+    fn push_with_assets(
+        &self,
+        operations: &mut Vec<(TargetKind, FileOperation)>,
+        target_kind: TargetKind,
+        kind: ComponentKind,
+        op: FileOperation,
+    ) {
+        let assets = assets_dir_for(kind, op_path(&op))
+            .and_then(|dir| ScopedPath::new(dir, &self.project_root).ok())
+            .map(|target| match &op {
+                FileOperation::CopyFile { source, .. } => {
+                    match assets_dir_for(kind, source).filter(|dir| dir.is_dir()) {
+                        Some(source) => FileOperation::CopyDir { source, target },
+                        None => FileOperation::RemoveDir { path: target },
+                    }
+                }
+                _ => FileOperation::RemoveDir { path: target },
+            });
+        operations.push((target_kind, op));
+        operations.extend(assets.map(|assets| (target_kind, assets)));
+    }
+
+    /// 展開先のパス一覧（ターゲット種別, コンポーネント種別, 配置先）
+    ///
+    /// 配置記録の更新に使う。パス検証に失敗したコンポーネントは含まない。
+    /// Disable では記録に基づいて削除するパスも含む。
+    pub fn destinations(&self) -> Destinations {
+        let deploy = self.action.is_deploy();
+        self.collect_plans(|target_kind, plan, destinations| {
+            if !deploy {
+                destinations.extend(
+                    plan.recorded_removals
+                        .into_iter()
+                        .map(|removal| removal.into_destination(target_kind)),
+                );
+            }
+            destinations.extend(plan.components.into_iter().filter_map(
+                |(kind, result)| match result {
+                    Ok(Some((target_kind, op))) => {
+                        Some((target_kind, kind, op_path(&op).to_path_buf()))
+                    }
+                    _ => None,
+                },
+            ));
+        })
+    }
+
+    /// Enable で置き換えられる古い配置先（記録済みだが今回は配置しないパス）
+    ///
+    /// 配置記録から外すために使う。Disable では常に空。
+    pub fn replaced_destinations(&self) -> Destinations {
+        if !self.action.is_deploy() {
+            return Vec::new();
         }
+        self.collect_plans(|target_kind, plan, destinations| {
+            destinations.extend(
+                plan.recorded_removals
+                    .into_iter()
+                    .map(|removal| removal.into_destination(target_kind)),
+            );
+        })
+    }
+
+    /// 別プラグインの配置物と衝突して配置しないコンポーネント
+    ///
+    /// 所有者の判定（[`PluginIntent::with_placement_owners`]）を設定していない場合と
+    /// Disable では常に空。
+    pub fn conflicts(&self) -> Vec<ComponentConflict> {
+        let origin =
+            PluginOrigin::from_cached_plugin(self.action.marketplace(), self.action.plugin_name());
+
+        all_targets()
+            .iter()
+            .filter(|target| self.includes(target.as_ref()))
+            .flat_map(|target| self.plan_target(target.as_ref(), &origin).conflicts)
+            .collect()
+    }
+
+    /// 対象ターゲットごとの [`TargetPlan`] から展開先を集める
+    ///
+    /// # Arguments
+    ///
+    /// * `collect` - appends the destinations of one target's plan
+    fn collect_plans(
+        &self,
+        collect: impl Fn(TargetKind, TargetPlan, &mut Destinations),
+    ) -> Destinations {
+        let origin =
+            PluginOrigin::from_cached_plugin(self.action.marketplace(), self.action.plugin_name());
+
+        let mut destinations = Vec::new();
+        for target in all_targets()
+            .iter()
+            .filter(|target| self.includes(target.as_ref()))
+        {
+            let plan = self.plan_target(target.as_ref(), &origin);
+            collect(target.kind(), plan, &mut destinations);
+        }
+        destinations
+    }
+
+    /// ターゲットフィルタとプロジェクトの有効ターゲットの両方を満たすか
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - candidate target
+    fn includes(&self, target: &dyn Target) -> bool {
+        self.target_filter
+            .as_deref()
+            .is_none_or(|filter| target.name() == filter)
+            && self
+                .enabled_targets
+                .as_ref()
+                .is_none_or(|enabled| enabled.contains(&target.kind()))
+    }
+
+    /// ターゲット 1 件分の操作を組み立てる
+    ///
+    /// プロジェクト配下に配置記録がある場合、Disable は記録済みの種別（Skill / Agent /
+    /// Command）をスキャン結果から外して記録どおりに削除し、Enable は今回の配置先に
+    /// 含まれない記録済みのパスを削除対象にする。別プラグインが所有する既存の配置先は
+    /// 配置も削除もせず、衝突として記録する。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - target environment to plan for
+    /// * `origin` - plugin origin used to build the placement context
+    fn plan_target(&self, target: &dyn Target, origin: &PluginOrigin) -> TargetPlan {
+        let recorded = self.recorded_in_project(target.name());
+        let deploy = self.action.is_deploy();
+
+        let mut conflicts = Vec::new();
+        let components: Vec<(ComponentKind, CreateOperationResult)> = self
+            .components
+            .iter()
+            .filter(|c| target.supports(c.kind))
+            .filter(|c| deploy || recorded.is_empty() || !PlacementRecord::is_tracked(c.kind))
+            .filter_map(|c| {
+                let result = self.create_operation(target, c, origin);
+                if let Ok(Some((_, op))) = &result {
+                    if let Some(conflict) = self.conflict(target, c, op_path(op)) {
+                        conflicts.push(conflict);
+                        return None;
+                    }
+                }
+                Some((c.kind, result))
+            })
+            .collect();
+
+        let recorded_removals = if deploy {
+            let placed: Vec<&Path> = components
+                .iter()
+                .filter_map(|(_, result)| match result {
+                    Ok(Some((_, op))) => Some(op_path(op)),
+                    _ => None,
+                })
+                .collect();
+            recorded
+                .into_iter()
+                .filter(|removal| !placed.contains(&removal.path.as_path()))
+                .filter(|removal| !conflicts.iter().any(|c| c.path == removal.recorded))
+                .collect()
+        } else {
+            recorded
+        };
+
+        TargetPlan {
+            components,
+            recorded_removals,
+            conflicts,
+        }
+    }
+
+    /// 配置先が別プラグインの所有する既存の配置物なら衝突を返す
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - target environment the component is deployed to
+    /// * `component` - component about to be deployed
+    /// * `path` - destination path of the component
+    fn conflict(
+        &self,
+        target: &dyn Target,
+        component: &Component,
+        path: &Path,
+    ) -> Option<ComponentConflict> {
+        if !self.action.is_deploy() {
+            return None;
+        }
+        let owner = self.owners.as_ref()?.foreign_owner(
+            path,
+            self.action.marketplace(),
+            self.action.plugin_name(),
+        )?;
+        Some(ComponentConflict {
+            target: target.name().to_string(),
+            kind: component.kind,
+            name: component.name.clone(),
+            path: path.to_path_buf(),
+            owner: owner.owner_label(),
+        })
+    }
+
+    /// プロジェクト配下にある `target` の配置記録
+    ///
+    /// 別プロジェクトで配置したパスはプロジェクトルートの検証に通らないため含めない。
+    /// 空の場合は記録が無いものとして扱う（スキャン結果へフォールバック）。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - target name whose record is looked up
+    fn recorded_in_project(&self, target: &str) -> Vec<RecordedRemoval> {
+        self.recorded_files
+            .get(target)
+            .into_iter()
+            .flatten()
+            .filter(|file| PlacementRecord::is_tracked(file.kind))
+            .filter_map(|file| {
+                let recorded = PathBuf::from(&file.path);
+                ScopedPath::new(recorded.clone(), &self.project_root)
+                    .ok()
+                    .map(|path| RecordedRemoval {
+                        kind: file.kind,
+                        recorded,
+                        path,
+                    })
+            })
+            .collect()
     }
 
     /// ドライラン: 実行予定の操作を確認
@@ -181,7 +557,11 @@ impl PluginIntent {
         origin: &PluginOrigin,
     ) -> CreateOperationResult {
         let context = PlacementContext {
-            component: ComponentRef::from(component),
+            component: CommandPrefix::apply(
+                self.command_prefix.as_ref(),
+                ComponentRef::from(component),
+                target.kind(),
+            ),
             origin,
             scope: PlacementScope::new(Scope::Project),
             project: ProjectContext::new(&self.project_root),
@@ -191,7 +571,8 @@ impl PluginIntent {
             Some(loc) => loc,
             None => return Ok(None),
         };
-        let target_path = location.into_path();
+        let target_path =
+            PromptNaming::apply(self.prompt_naming, target.kind(), &context, location).into_path();
         let scoped = ScopedPath::new(target_path, &self.project_root)
             .map_err(|e| (target.kind(), format!("Path validation failed: {}", e)))?;
 
@@ -205,6 +586,33 @@ impl PluginIntent {
     }
 }
 
+/// 配置物を削除する操作（Skill はディレクトリ、それ以外はファイル）
+///
+/// # Arguments
+///
+/// * `kind` - kind of the deployed component
+/// * `path` - validated path of the deployed component
+fn remove_operation(kind: ComponentKind, path: ScopedPath) -> FileOperation {
+    match kind {
+        ComponentKind::Skill => FileOperation::RemoveDir { path },
+        _ => FileOperation::RemoveFile { path },
+    }
+}
+
+/// 操作の対象パス（配置先または削除対象）
+///
+/// # Arguments
+///
+/// * `op` - file operation to inspect
+fn op_path(op: &FileOperation) -> &Path {
+    match op {
+        FileOperation::CopyFile { target, .. } | FileOperation::CopyDir { target, .. } => {
+            target.as_path()
+        }
+        FileOperation::RemoveFile { path } | FileOperation::RemoveDir { path } => path.as_path(),
+    }
+}
+
 /// ファイル操作を実行
 ///
 /// # Arguments
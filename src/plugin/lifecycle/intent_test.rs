@@ -98,3 +98,357 @@ fn test_plugin_intent_expand_skips_cursor_legacy_cleanup_when_legacy_dir_missing
         matches!(op, FileOperation::RemoveDir { path } if path.as_path().ends_with("test-plugin_review"))
     }));
 }
+
+#[test]
+fn test_plugin_intent_destinations_excludes_legacy_cleanup() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let skill_dir = source_root.path().join("skills").join("review");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(skill_dir.join("SKILL.md"), "---\nname: review\n---\n").unwrap();
+    let legacy = project_root
+        .path()
+        .join(".cursor")
+        .join("skills")
+        .join("test-plugin_review");
+    std::fs::create_dir_all(&legacy).unwrap();
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Enable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Skill,
+            "test-plugin",
+            "review",
+            &skill_dir,
+        )],
+        project_root.path().to_path_buf(),
+        Some("cursor"),
+    );
+
+    let destinations = intent.destinations();
+
+    assert_eq!(
+        destinations,
+        vec![(
+            crate::target::TargetKind::Cursor,
+            ComponentKind::Skill,
+            project_root
+                .path()
+                .join(".cursor")
+                .join("skills")
+                .join("review"),
+        )]
+    );
+}
+
+fn recorded(target: &str, kind: ComponentKind, path: &Path) -> HashMap<String, Vec<DeployedFile>> {
+    HashMap::from([(
+        target.to_string(),
+        vec![DeployedFile {
+            path: path.to_string_lossy().into_owned(),
+            kind,
+        }],
+    )])
+}
+
+#[test]
+fn test_plugin_intent_disable_uses_recorded_files() {
+    // 記録のあるターゲットは記録どおりに削除し、同種のスキャン結果は使わない
+    let project_root = TempDir::new().unwrap();
+    let old_skill = project_root
+        .path()
+        .join(".cursor")
+        .join("skills")
+        .join("old");
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Disable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Skill,
+            "test-plugin",
+            "review",
+            Path::new("/cache/skills/review"),
+        )],
+        project_root.path().to_path_buf(),
+        Some("cursor"),
+    )
+    .with_recorded_files(recorded("cursor", ComponentKind::Skill, &old_skill));
+
+    assert_eq!(
+        intent.destinations(),
+        vec![(
+            crate::target::TargetKind::Cursor,
+            ComponentKind::Skill,
+            old_skill
+        )]
+    );
+    assert!(intent.replaced_destinations().is_empty());
+}
+
+#[test]
+fn test_plugin_intent_enable_replaces_stale_recorded_files() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let skill_dir = source_root.path().join("skills").join("review");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skills = project_root.path().join(".cursor").join("skills");
+    let old_skill = skills.join("old");
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Enable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Skill,
+            "test-plugin",
+            "review",
+            &skill_dir,
+        )],
+        project_root.path().to_path_buf(),
+        Some("cursor"),
+    )
+    .with_recorded_files(recorded("cursor", ComponentKind::Skill, &old_skill));
+
+    assert_eq!(
+        intent.replaced_destinations(),
+        vec![(
+            crate::target::TargetKind::Cursor,
+            ComponentKind::Skill,
+            old_skill.clone()
+        )]
+    );
+    assert!(intent.expand().operations.iter().any(
+        |(_, op)| matches!(op, FileOperation::RemoveDir { path } if path.as_path() == old_skill)
+    ));
+}
+
+#[test]
+fn test_plugin_intent_ignores_records_outside_project() {
+    // 別プロジェクトで配置した記録は対象外（スキャン結果へフォールバック）
+    let project_root = TempDir::new().unwrap();
+    let other_project = TempDir::new().unwrap();
+    let elsewhere = other_project
+        .path()
+        .join(".cursor")
+        .join("skills")
+        .join("review");
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Disable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Skill,
+            "test-plugin",
+            "review",
+            Path::new("/cache/skills/review"),
+        )],
+        project_root.path().to_path_buf(),
+        Some("cursor"),
+    )
+    .with_recorded_files(recorded("cursor", ComponentKind::Skill, &elsewhere));
+
+    assert_eq!(
+        intent.destinations(),
+        vec![(
+            crate::target::TargetKind::Cursor,
+            ComponentKind::Skill,
+            project_root
+                .path()
+                .join(".cursor")
+                .join("skills")
+                .join("review"),
+        )]
+    );
+}
+
+#[test]
+fn test_plugin_intent_enable_copies_attached_assets() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let command = source_root.path().join("commands").join("deploy.md");
+    let assets = source_root.path().join("commands").join("deploy.assets");
+    std::fs::create_dir_all(&assets).unwrap();
+    std::fs::write(&command, "# Deploy").unwrap();
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Enable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Command,
+            "test-plugin",
+            "deploy",
+            &command,
+        )],
+        project_root.path().to_path_buf(),
+        Some("copilot"),
+    );
+
+    let operations = intent.expand().operations;
+    assert!(operations.iter().any(|(_, op)| matches!(
+        op,
+        FileOperation::CopyDir { source, target }
+            if source == &assets && target.as_path().to_string_lossy().ends_with(".assets")
+    )));
+    // アセットは配置記録に含めない
+    assert_eq!(intent.destinations().len(), 1);
+}
+
+#[test]
+fn test_plugin_intent_disable_removes_attached_assets_with_recorded_file() {
+    let project_root = TempDir::new().unwrap();
+    let prompts = project_root.path().join(".github").join("prompts");
+    let deployed = prompts.join("test-plugin_deploy.prompt.md");
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Disable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        Vec::new(),
+        project_root.path().to_path_buf(),
+        Some("copilot"),
+    )
+    .with_recorded_files(recorded("copilot", ComponentKind::Command, &deployed));
+
+    let operations = intent.expand().operations;
+    let assets = prompts.join("test-plugin_deploy.assets");
+    assert!(operations.iter().any(
+        |(_, op)| matches!(op, FileOperation::RemoveFile { path } if path.as_path() == deployed)
+    ));
+    assert!(operations.iter().any(
+        |(_, op)| matches!(op, FileOperation::RemoveDir { path } if path.as_path() == assets)
+    ));
+}
+
+#[test]
+fn test_plugin_intent_enable_with_md_naming_removes_prompt_md_file() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let command = source_root.path().join("commands").join("deploy.md");
+    let prompts = project_root.path().join(".github").join("prompts");
+    let old = prompts.join("test-plugin_deploy.prompt.md");
+
+    let intent = PluginIntent::with_target_filter(
+        PluginAction::Enable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Command,
+            "test-plugin",
+            "deploy",
+            &command,
+        )],
+        project_root.path().to_path_buf(),
+        Some("copilot"),
+    )
+    .with_prompt_naming(PromptNaming::Md)
+    .with_recorded_files(recorded("copilot", ComponentKind::Command, &old));
+
+    let destinations = intent.destinations();
+    assert_eq!(destinations.len(), 1);
+    assert_eq!(destinations[0].2, prompts.join("test-plugin_deploy.md"));
+    assert_eq!(intent.replaced_destinations().len(), 1);
+    assert!(intent
+        .expand()
+        .operations
+        .iter()
+        .any(|(_, op)| matches!(op, FileOperation::RemoveFile { path } if path.as_path() == old)));
+}
+
+#[test]
+fn test_plugin_intent_expand_skips_targets_disabled_for_project() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let skill_dir = source_root.path().join("skills").join("review");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(skill_dir.join("SKILL.md"), "---\nname: review\n---\n").unwrap();
+
+    let intent = PluginIntent::new(
+        PluginAction::Enable {
+            plugin_name: "test-plugin".to_string(),
+            marketplace: None,
+        },
+        vec![Component::flattened(
+            ComponentKind::Skill,
+            "test-plugin",
+            "review",
+            &skill_dir,
+        )],
+        project_root.path().to_path_buf(),
+    )
+    .with_enabled_targets(Some(vec![TargetKind::Codex]));
+
+    let result = intent.expand();
+
+    assert!(!result.operations.is_empty());
+    assert!(result
+        .operations
+        .iter()
+        .all(|(kind, _)| *kind == TargetKind::Codex));
+}
+
+#[test]
+fn test_plugin_intent_enable_skips_destination_owned_by_another_plugin() {
+    let project_root = TempDir::new().unwrap();
+    let source_root = TempDir::new().unwrap();
+    let skill_dir = source_root.path().join("skills").join("review");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let enable = |owners: Option<PlacementLedger>| {
+        PluginIntent::with_target_filter(
+            PluginAction::Enable {
+                plugin_name: "test-plugin".to_string(),
+                marketplace: None,
+            },
+            vec![Component::flattened(
+                ComponentKind::Skill,
+                "test-plugin",
+                "review",
+                &skill_dir,
+            )],
+            project_root.path().to_path_buf(),
+            Some("cursor"),
+        )
+        .with_placement_owners(owners)
+    };
+    let (_, _, destination) = enable(None).destinations().remove(0);
+    std::fs::create_dir_all(&destination).unwrap();
+
+    let mut ledger =
+        PlacementLedger::load_from(source_root.path().join("placements.json")).unwrap();
+    ledger.record(PlacementRecord {
+        path: destination.clone(),
+        target: "cursor".to_string(),
+        kind: ComponentKind::Skill,
+        marketplace: Some("other-market".to_string()),
+        plugin: "other-plugin".to_string(),
+    });
+
+    let intent = enable(Some(ledger));
+    let conflicts = intent.conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].owner, "other-market/other-plugin");
+    assert_eq!(conflicts[0].path, destination);
+    assert!(intent.destinations().is_empty());
+    let expanded = intent.expand();
+    assert_eq!(expanded.conflicts, conflicts);
+    assert!(!expanded
+        .operations
+        .iter()
+        .any(|(_, op)| op_path(op) == destination));
+
+    // --overwrite 相当（所有者を判定しない）なら配置する
+    assert!(enable(None).conflicts().is_empty());
+    assert_eq!(enable(None).destinations().len(), 1);
+}
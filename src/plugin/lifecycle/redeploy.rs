@@ -0,0 +1,188 @@
+//! キャッシュからの再デプロイ（`plm update --redeploy-only`）
+//!
+//! fetch は行わず、キャッシュ済みのプラグインを有効なターゲットへ配置し直す。
+//! plm のバージョンアップでターゲットの配置規約が変わったときに使う。
+//! 配置する種別は `--only` の明示指定、選択インストールの記録（`onlyKinds`）、全種別の順に決め、
+//! 部分 disable（`plm disable --keep`）中のターゲットでは残している種別（`keptKinds`）に絞る。
+
+use super::plugin_resolver::ResolvedPlugin;
+use super::update::{load_resolved, resolve_or_fail, UpdateOutcome};
+use crate::application::{enable_plugin_filtered, DeployFilter};
+use crate::component::ComponentKind;
+use crate::plugin::{meta, meta::TargetStatus, PackageCacheAccess};
+use std::path::Path;
+
+/// 再デプロイの絞り込み
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RedeployOptions<'a> {
+    /// ターゲットフィルタ（None で有効な全ターゲット）
+    pub target_filter: Option<&'a str>,
+    /// 明示指定されたコンポーネント種別（`--only`）
+    pub only: Option<&'a [ComponentKind]>,
+}
+
+/// 再デプロイするコンポーネント種別を決める
+///
+/// 優先順位は 明示指定 > 選択インストールの記録 > 全種別（`None`）。
+///
+/// # Arguments
+///
+/// * `explicit` - Kinds given with `--only`.
+/// * `recorded` - Kinds recorded in `.plm-meta.json` by a selective install.
+pub fn redeploy_kinds(
+    explicit: Option<&[ComponentKind]>,
+    recorded: Option<&[ComponentKind]>,
+) -> Option<Vec<ComponentKind>> {
+    explicit.or(recorded).map(<[ComponentKind]>::to_vec)
+}
+
+/// 部分 disable 中のターゲットでは、再デプロイする種別を残している種別に絞る
+///
+/// # Arguments
+///
+/// * `kinds` - Kinds chosen by [`redeploy_kinds`] (`None` means all kinds).
+/// * `kept` - Kinds kept on the target by a partial disable, if any.
+fn target_kinds(
+    kinds: Option<&[ComponentKind]>,
+    kept: Option<&[ComponentKind]>,
+) -> Option<Vec<ComponentKind>> {
+    match (kinds, kept) {
+        (Some(kinds), Some(kept)) => {
+            Some(kinds.iter().copied().filter(|k| kept.contains(k)).collect())
+        }
+        (kinds, kept) => kinds.or(kept).map(<[ComponentKind]>::to_vec),
+    }
+}
+
+/// 単一プラグインを fetch せずに再デプロイする
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor for the plugin.
+/// * `plugin_input` - Cache ID or display name of the plugin.
+/// * `marketplace_hint` - Optional marketplace hint to disambiguate.
+/// * `project_root` - Project root path used for redeployment.
+/// * `options` - Target and component kind filters.
+pub fn redeploy_plugin(
+    cache: &dyn PackageCacheAccess,
+    plugin_input: &str,
+    marketplace_hint: Option<&str>,
+    project_root: &Path,
+    options: RedeployOptions<'_>,
+) -> UpdateOutcome {
+    match resolve_or_fail(cache, plugin_input, marketplace_hint) {
+        Ok(resolved) => redeploy_resolved(cache, &resolved, project_root, options),
+        Err(outcome) => outcome,
+    }
+}
+
+/// キャッシュ内の全プラグインを fetch せずに再デプロイする
+///
+/// 1 件の失敗で他のプラグインを巻き戻すことはしない（キャッシュは変更しないため）。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor used to enumerate plugins.
+/// * `project_root` - Project root path used for redeployment.
+/// * `options` - Target and component kind filters.
+pub fn redeploy_all_plugins(
+    cache: &dyn PackageCacheAccess,
+    project_root: &Path,
+    options: RedeployOptions<'_>,
+) -> Vec<UpdateOutcome> {
+    let plugins = match cache.list() {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("Error: Failed to list plugins: {}", e);
+            return vec![];
+        }
+    };
+    if plugins.is_empty() {
+        println!("No plugins installed.");
+        return vec![];
+    }
+
+    plugins
+        .into_iter()
+        .map(
+            |(market, cache_id)| match load_resolved(cache, market, cache_id.clone()) {
+                Ok(resolved) => redeploy_resolved(cache, &resolved, project_root, options),
+                Err(e) => UpdateOutcome::failed(&cache_id, e.to_string()),
+            },
+        )
+        .collect()
+}
+
+/// 解決済みのプラグインを有効なターゲットへ再デプロイする
+///
+/// 再デプロイに失敗したターゲットは update と同じく disabled に変更する。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor for the plugin.
+/// * `resolved` - Resolved plugin to redeploy.
+/// * `project_root` - Project root path used for redeployment.
+/// * `options` - Target and component kind filters.
+fn redeploy_resolved(
+    cache: &dyn PackageCacheAccess,
+    resolved: &ResolvedPlugin,
+    project_root: &Path,
+    options: RedeployOptions<'_>,
+) -> UpdateOutcome {
+    let marketplace = resolved.marketplace.as_deref();
+    let targets: Vec<&str> = resolved
+        .package_meta
+        .deployed_targets()
+        .into_iter()
+        .filter(|t| options.target_filter.is_none_or(|f| f == *t))
+        .collect();
+    if targets.is_empty() {
+        return UpdateOutcome::skipped(
+            &resolved.display_name,
+            "not enabled for any target".to_string(),
+        );
+    }
+
+    let kinds = redeploy_kinds(options.only, resolved.package_meta.only_kinds.as_deref());
+    let mut deployed = Vec::new();
+    let mut failed = Vec::new();
+    let mut conflicts = Vec::new();
+    for target in targets {
+        let target_kinds =
+            target_kinds(kinds.as_deref(), resolved.package_meta.deploy_kinds(target));
+        let filter = DeployFilter {
+            target: Some(target),
+            kinds: target_kinds.as_deref(),
+        };
+        let result =
+            enable_plugin_filtered(cache, &resolved.cache_id, marketplace, project_root, filter);
+        if result.outcome.success {
+            deployed.push(target.to_string());
+        } else {
+            failed.push(target.to_string());
+        }
+        conflicts.extend(result.conflicts);
+    }
+
+    if !failed.is_empty() {
+        // 再デプロイで更新された配置記録を上書きしないよう読み直してから書き込む
+        let plugin_path = cache.plugin_path(marketplace, &resolved.cache_id);
+        let mut new_meta =
+            meta::load_meta(&plugin_path).unwrap_or_else(|| resolved.package_meta.clone());
+        for t in &failed {
+            new_meta.set_status(t, TargetStatus::Disabled);
+        }
+        if let Err(e) = meta::write_meta(&plugin_path, &new_meta) {
+            eprintln!(
+                "Warning: Failed to write metadata for '{}': {}",
+                resolved.display_name, e
+            );
+        }
+    }
+
+    UpdateOutcome::redeployed(&resolved.display_name, deployed, failed).with_conflicts(conflicts)
+}
+
+#[cfg(test)]
+#[path = "redeploy_test.rs"]
+mod tests;
@@ -0,0 +1,111 @@
+use super::*;
+use crate::plugin::PackageCache;
+use crate::plugin::UpdateStatus;
+use std::fs;
+use tempfile::TempDir;
+
+const COMMANDS: &[ComponentKind] = &[ComponentKind::Command];
+const SKILLS: &[ComponentKind] = &[ComponentKind::Skill, ComponentKind::Agent];
+
+// =============================================================================
+// redeploy_kinds（優先順位: 明示指定 > 選択インストールの記録 > 全種別）
+// =============================================================================
+
+#[test]
+fn redeploy_kinds_defaults_to_all_kinds() {
+    assert_eq!(redeploy_kinds(None, None), None);
+}
+
+#[test]
+fn redeploy_kinds_follows_recorded_selection() {
+    assert_eq!(redeploy_kinds(None, Some(SKILLS)), Some(SKILLS.to_vec()));
+}
+
+#[test]
+fn redeploy_kinds_explicit_overrides_recorded_selection() {
+    assert_eq!(
+        redeploy_kinds(Some(COMMANDS), Some(SKILLS)),
+        Some(COMMANDS.to_vec())
+    );
+    assert_eq!(
+        redeploy_kinds(Some(COMMANDS), None),
+        Some(COMMANDS.to_vec())
+    );
+}
+
+#[test]
+fn target_kinds_narrows_to_kinds_kept_by_partial_disable() {
+    let kept = &[ComponentKind::Instruction, ComponentKind::Skill];
+    assert_eq!(target_kinds(None, None), None);
+    assert_eq!(target_kinds(None, Some(kept)), Some(kept.to_vec()));
+    assert_eq!(
+        target_kinds(Some(SKILLS), Some(kept)),
+        Some(vec![ComponentKind::Skill])
+    );
+}
+
+// =============================================================================
+// redeploy_plugin / redeploy_all_plugins
+// =============================================================================
+
+fn cache_with(dir: &TempDir, names: &[&str]) -> PackageCache {
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+    for name in names {
+        let path = cache.plugin_path(Some("mp"), name);
+        fs::create_dir_all(path.join(".claude-plugin")).unwrap();
+        fs::write(
+            path.join(".claude-plugin").join("plugin.json"),
+            format!(r#"{{"name":"{}","version":"1.0.0"}}"#, name),
+        )
+        .unwrap();
+    }
+    cache
+}
+
+#[test]
+fn redeploy_plugin_without_enabled_targets_is_skipped() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &["formatter"]);
+
+    let outcome = redeploy_plugin(
+        &cache,
+        "formatter",
+        Some("mp"),
+        dir.path(),
+        RedeployOptions::default(),
+    );
+
+    assert!(matches!(outcome.status, UpdateStatus::Skipped { .. }));
+}
+
+#[test]
+fn redeploy_plugin_unknown_name_fails() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &["formatter"]);
+
+    let outcome = redeploy_plugin(
+        &cache,
+        "missing",
+        None,
+        dir.path(),
+        RedeployOptions::default(),
+    );
+
+    assert!(matches!(outcome.status, UpdateStatus::Failed));
+    assert_eq!(outcome.error.as_deref(), Some("Plugin not found in cache"));
+}
+
+#[test]
+fn redeploy_all_plugins_returns_one_outcome_per_cached_plugin() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &["formatter", "linter"]);
+
+    let mut names: Vec<String> =
+        redeploy_all_plugins(&cache, dir.path(), RedeployOptions::default())
+            .into_iter()
+            .map(|o| o.plugin_name)
+            .collect();
+    names.sort();
+
+    assert_eq!(names, vec!["formatter", "linter"]);
+}
@@ -0,0 +1,199 @@
+//! マーケットプレイス側のプラグインリネーム追従
+//!
+//! marketplace.json の `renamedFrom` を参照し、旧名のままキャッシュされている
+//! プラグインを検出して新名へ移行する。
+//!
+//! 移行は `旧名で undeploy → キャッシュ rename → 新名で redeploy` の順で行う。
+//! メタデータ（`.plm-meta.json`）はキャッシュディレクトリ内にあるため rename で一緒に移動する。
+//! キャッシュの移動は `rename` 1 回なので、途中で失敗しても旧名・新名の
+//! どちらか一方にだけ存在する状態が保たれる。
+
+use super::update::{
+    meta_after_redeploy, redeploy_to_targets, MarketplaceResolver, Redeployment, RegistryResolver,
+};
+use crate::application::disable_plugin;
+use crate::error::{PlmError, Result};
+use crate::marketplace::{MarketplaceCache, MarketplacePlugin, MarketplaceRef};
+use crate::plugin::{meta, meta::TargetStatus, ComponentConflict, PackageCacheAccess};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 検出されたリネーム 1 件分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginRename {
+    /// マーケットプレイス名
+    pub marketplace: String,
+    /// キャッシュ上の旧名
+    pub old_name: String,
+    /// マーケットプレイス上の新名
+    pub new_name: String,
+}
+
+/// リネーム移行の結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenameMigration {
+    /// 新名で再デプロイに成功したターゲット
+    pub deployed_targets: Vec<String>,
+    /// 新名での再デプロイに失敗したターゲット（disabled に変更済み）
+    pub failed_targets: Vec<String>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
+}
+
+/// `old_name` を `renamedFrom` に持つエントリを探す
+///
+/// `old_name` のエントリがまだ存在する場合はリネームとみなさない。
+///
+/// # Arguments
+///
+/// * `mp_cache` - Marketplace cache to search.
+/// * `old_name` - Cache directory name of the installed plugin.
+pub(crate) fn find_renamed_entry<'a>(
+    mp_cache: &'a MarketplaceCache,
+    old_name: &str,
+) -> Option<&'a MarketplacePlugin> {
+    if mp_cache.plugins.iter().any(|p| p.name == old_name) {
+        return None;
+    }
+    mp_cache
+        .plugins
+        .iter()
+        .find(|p| p.renamed_from.iter().any(|r| r == old_name))
+}
+
+/// 旧名のままキャッシュされているプラグインを検出する
+///
+/// marketplace registry の読み込みに失敗した場合は警告して空を返す。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor used to enumerate installed plugins.
+pub fn detect_renames(cache: &dyn PackageCacheAccess) -> Vec<PluginRename> {
+    detect_renames_with(cache, &RegistryResolver::new()).unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to check for renamed plugins: {}", e);
+        vec![]
+    })
+}
+
+/// 依存注入版本体（テストから直接呼ぶ）
+///
+/// 新名が既にキャッシュにある場合は移行先と衝突するため対象外とする。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor used to enumerate installed plugins.
+/// * `resolver` - Marketplace resolver.
+pub(crate) fn detect_renames_with(
+    cache: &dyn PackageCacheAccess,
+    resolver: &dyn MarketplaceResolver,
+) -> Result<Vec<PluginRename>> {
+    let mut mp_caches: HashMap<String, Option<MarketplaceCache>> = HashMap::new();
+    let mut renames = Vec::new();
+
+    for (marketplace, cache_id) in cache.list()? {
+        let Some(market) = MarketplaceRef::from_option(marketplace.as_deref()).into_named() else {
+            continue;
+        };
+        let resolved = mp_caches
+            .entry(market.clone())
+            .or_insert_with(|| resolver.resolve(&market).ok().flatten());
+        let Some(mp_cache) = resolved.as_ref() else {
+            continue;
+        };
+        let Some(entry) = find_renamed_entry(mp_cache, &cache_id) else {
+            continue;
+        };
+        if cache.is_cached(Some(&market), &entry.name) {
+            continue;
+        }
+        renames.push(PluginRename {
+            marketplace: market,
+            old_name: cache_id,
+            new_name: entry.name.clone(),
+        });
+    }
+
+    renames.sort_by(|a, b| (&a.marketplace, &a.old_name).cmp(&(&b.marketplace, &b.old_name)));
+    Ok(renames)
+}
+
+/// 旧名のプラグインを新名へ移行する
+///
+/// 1. 旧名でデプロイ済みのコンポーネントを撤去
+/// 2. キャッシュディレクトリを rename（アトミック）
+/// 3. 有効だったターゲットへ新名で再デプロイし、失敗したターゲットは disabled にする
+///
+/// 2 が失敗した場合は旧名のまま再デプロイして元の状態へ戻す。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor for the plugin.
+/// * `rename` - Rename to apply.
+/// * `project_root` - Project root path used for (re)deployment.
+pub fn migrate_rename(
+    cache: &dyn PackageCacheAccess,
+    rename: &PluginRename,
+    project_root: &Path,
+) -> Result<RenameMigration> {
+    let mp = Some(rename.marketplace.as_str());
+    if cache.is_cached(mp, &rename.new_name) {
+        return Err(PlmError::Cache(format!(
+            "Cannot migrate '{}' to '{}': '{}' is already installed",
+            rename.old_name, rename.new_name, rename.new_name
+        )));
+    }
+
+    let old_meta = meta::load_meta(&cache.plugin_path(mp, &rename.old_name)).unwrap_or_default();
+    let targets = old_meta.deployed_targets();
+
+    // 1. 旧名のデプロイを撤去
+    if !targets.is_empty() {
+        let outcome = disable_plugin(cache, &rename.old_name, mp, project_root, None);
+        if !outcome.success {
+            redeploy_to_targets(cache, &rename.old_name, mp, &targets, project_root);
+            return Err(PlmError::Cache(format!(
+                "Failed to undeploy '{}': {}",
+                rename.old_name,
+                outcome.error.unwrap_or_default()
+            )));
+        }
+    }
+
+    // 2. キャッシュを rename（失敗時は旧名で再デプロイして元に戻す）
+    let plugin_path = match cache.rename(mp, &rename.old_name, &rename.new_name) {
+        Ok(p) => p,
+        Err(e) => {
+            redeploy_to_targets(cache, &rename.old_name, mp, &targets, project_root);
+            return Err(e);
+        }
+    };
+
+    // 3. 新名で再デプロイ（非アトミック: 失敗 target は disabled）
+    let Redeployment {
+        deployed,
+        failed,
+        conflicts,
+    } = redeploy_to_targets(cache, &rename.new_name, mp, &targets, project_root);
+    if !failed.is_empty() {
+        let mut new_meta = meta_after_redeploy(&old_meta, &plugin_path);
+        for t in &failed {
+            new_meta.set_status(t, TargetStatus::Disabled);
+        }
+        if let Err(e) = meta::write_meta(&plugin_path, &new_meta) {
+            eprintln!(
+                "Warning: Failed to write metadata for '{}': {}",
+                rename.new_name, e
+            );
+        }
+    }
+
+    Ok(RenameMigration {
+        deployed_targets: deployed,
+        failed_targets: failed,
+        conflicts,
+    })
+}
+
+#[cfg(test)]
+#[path = "rename_test.rs"]
+mod tests;
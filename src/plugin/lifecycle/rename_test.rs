@@ -0,0 +1,173 @@
+use super::*;
+use crate::marketplace::PluginSource;
+use crate::plugin::PackageCache;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn entry(name: &str, renamed_from: &[&str]) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source: PluginSource::Local(format!("./plugins/{}", name)),
+        description: None,
+        version: None,
+        renamed_from: renamed_from.iter().map(|s| s.to_string()).collect(),
+        stats: Default::default(),
+    }
+}
+
+fn mp_cache(name: &str, plugins: Vec<MarketplacePlugin>) -> MarketplaceCache {
+    MarketplaceCache {
+        name: name.to_string(),
+        fetched_at: chrono::Utc::now(),
+        source: format!("github:owner/{}", name).parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins,
+    }
+}
+
+/// 固定の MarketplaceCache を返す resolver stub
+struct Fixed(MarketplaceCache);
+
+impl MarketplaceResolver for Fixed {
+    fn resolve(&self, marketplace: &str) -> Result<Option<MarketplaceCache>> {
+        Ok((marketplace == self.0.name).then(|| self.0.clone()))
+    }
+}
+
+fn cache_with(dir: &TempDir, entries: &[(&str, &str)]) -> PackageCache {
+    let cache = PackageCache::with_cache_dir(dir.path().to_path_buf()).unwrap();
+    for (mp, name) in entries {
+        let path: PathBuf = cache.plugin_path(Some(mp), name);
+        fs::create_dir_all(path.join(".claude-plugin")).unwrap();
+        fs::write(
+            path.join(".claude-plugin").join("plugin.json"),
+            format!(r#"{{"name":"{}","version":"1.0.0"}}"#, name),
+        )
+        .unwrap();
+    }
+    cache
+}
+
+// =============================================================================
+// find_renamed_entry
+// =============================================================================
+
+#[test]
+fn find_renamed_entry_matches_renamed_from() {
+    let mp = mp_cache("mp", vec![entry("new-name", &["old-name", "older-name"])]);
+
+    assert_eq!(
+        find_renamed_entry(&mp, "older-name").map(|e| e.name.as_str()),
+        Some("new-name")
+    );
+    assert!(find_renamed_entry(&mp, "unrelated").is_none());
+}
+
+#[test]
+fn find_renamed_entry_ignores_names_still_listed() {
+    let mp = mp_cache(
+        "mp",
+        vec![entry("old-name", &[]), entry("new-name", &["old-name"])],
+    );
+
+    assert!(find_renamed_entry(&mp, "old-name").is_none());
+}
+
+#[test]
+fn renamed_from_deserializes_from_camel_case() {
+    let json = r#"{"name":"new-name","source":"./plugins/new","renamedFrom":["old-name"]}"#;
+    let plugin: MarketplacePlugin = serde_json::from_str(json).unwrap();
+    assert_eq!(plugin.renamed_from, vec!["old-name".to_string()]);
+
+    let json = r#"{"name":"plain","source":"./plugins/plain"}"#;
+    let plugin: MarketplacePlugin = serde_json::from_str(json).unwrap();
+    assert!(plugin.renamed_from.is_empty());
+}
+
+// =============================================================================
+// detect_renames_with
+// =============================================================================
+
+#[test]
+fn detect_renames_finds_plugins_cached_under_old_name() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &[("mp", "old-name"), ("mp", "kept")]);
+    let resolver = Fixed(mp_cache(
+        "mp",
+        vec![entry("new-name", &["old-name"]), entry("kept", &[])],
+    ));
+
+    let renames = detect_renames_with(&cache, &resolver).unwrap();
+
+    assert_eq!(
+        renames,
+        vec![PluginRename {
+            marketplace: "mp".to_string(),
+            old_name: "old-name".to_string(),
+            new_name: "new-name".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn detect_renames_skips_when_new_name_already_cached() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &[("mp", "old-name"), ("mp", "new-name")]);
+    let resolver = Fixed(mp_cache("mp", vec![entry("new-name", &["old-name"])]));
+
+    assert!(detect_renames_with(&cache, &resolver).unwrap().is_empty());
+}
+
+#[test]
+fn detect_renames_ignores_unknown_marketplace() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &[("other", "old-name")]);
+    let resolver = Fixed(mp_cache("mp", vec![entry("new-name", &["old-name"])]));
+
+    assert!(detect_renames_with(&cache, &resolver).unwrap().is_empty());
+}
+
+// =============================================================================
+// migrate_rename
+// =============================================================================
+
+#[test]
+fn migrate_rename_moves_cache_and_meta() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &[("mp", "old-name")]);
+    let mut old_meta = meta::PluginMeta::default();
+    old_meta.set_git_info("main", "abc123");
+    meta::write_meta(&cache.plugin_path(Some("mp"), "old-name"), &old_meta).unwrap();
+    let project = TempDir::new().unwrap();
+
+    let rename = PluginRename {
+        marketplace: "mp".to_string(),
+        old_name: "old-name".to_string(),
+        new_name: "new-name".to_string(),
+    };
+    let migration = migrate_rename(&cache, &rename, project.path()).unwrap();
+
+    assert_eq!(migration, RenameMigration::default());
+    assert!(!cache.is_cached(Some("mp"), "old-name"));
+    assert!(cache.is_cached(Some("mp"), "new-name"));
+    let new_meta = meta::load_meta(&cache.plugin_path(Some("mp"), "new-name")).unwrap();
+    assert_eq!(new_meta.commit_sha.as_deref(), Some("abc123"));
+}
+
+#[test]
+fn migrate_rename_refuses_existing_new_name() {
+    let dir = TempDir::new().unwrap();
+    let cache = cache_with(&dir, &[("mp", "old-name"), ("mp", "new-name")]);
+    let project = TempDir::new().unwrap();
+
+    let rename = PluginRename {
+        marketplace: "mp".to_string(),
+        old_name: "old-name".to_string(),
+        new_name: "new-name".to_string(),
+    };
+
+    assert!(migrate_rename(&cache, &rename, project.path()).is_err());
+    assert!(cache.is_cached(Some("mp"), "old-name"));
+}
@@ -2,20 +2,29 @@
 //!
 //! GitHub APIを使用して最新のコミットSHAを取得し、インストール済みSHAと比較して
 //! 差分がある場合に再ダウンロード・再デプロイを行う。
+//!
+//! 更新チャンネル（`channel`）が記録されているプラグインは、ブランチではなく
+//! そのチャンネルの最新リリースタグに追従する。
 
-use crate::application::enable_plugin;
+use crate::application::{enable_plugin_filtered, project_enabled_targets, DeployFilter};
 use crate::error::{PlmError, Result};
 use crate::host::{HostClient, HostClientFactory, HostKind};
-use crate::http::with_retry;
 use crate::marketplace::{
     MarketplaceCache, MarketplaceRef, MarketplaceRegistry, MarketplaceSourceRef,
     PluginSource as MpPluginSource,
 };
 use crate::plugin::lifecycle::plugin_resolver::{find_by_plugin_name, ResolvedPlugin};
-use crate::plugin::version::needs_update;
-use crate::plugin::{meta, meta::TargetStatus, GithubCacheId, PackageCacheAccess, PluginMeta};
+use crate::plugin::lifecycle::rename::find_renamed_entry;
+use crate::plugin::version::{fetch_channel_tag, needs_update};
+use crate::plugin::{
+    meta, meta::is_downgrade, meta::TargetStatus, Channel, ComponentConflict, GithubCacheId,
+    PackageCacheAccess, PluginLock, PluginMeta, UpdateResult,
+};
 use crate::repo::{self, Repo};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// `plm pin` で固定されたプラグインをスキップする理由
+pub const PINNED_REASON: &str = "pinned";
 
 /// 更新ステータス
 #[derive(Debug, Clone)]
@@ -34,6 +43,10 @@ pub enum UpdateStatus {
     /// 一括更新で他プラグインの失敗により更新前へロールバックされた
     /// (本来は更新成功していたが、batch all-or-nothing により巻き戻された)
     RolledBack,
+    /// チャンネル切り替えでバージョンが下がるため、確認を待って更新していない
+    DowngradeRequired { from_ref: String, to_ref: String },
+    /// fetch せずキャッシュから再デプロイした（`--redeploy-only`）
+    Redeployed,
 }
 
 /// 更新結果
@@ -46,6 +59,8 @@ pub struct UpdateOutcome {
     pub deployed_targets: Vec<String>,
     /// 再デプロイに失敗したターゲット
     pub failed_targets: Vec<String>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 impl UpdateOutcome {
@@ -74,6 +89,7 @@ impl UpdateOutcome {
             error: None,
             deployed_targets: deployed,
             failed_targets: failed,
+            conflicts: vec![],
         }
     }
 
@@ -89,6 +105,7 @@ impl UpdateOutcome {
             error: None,
             deployed_targets: vec![],
             failed_targets: vec![],
+            conflicts: vec![],
         }
     }
 
@@ -105,6 +122,7 @@ impl UpdateOutcome {
             error: Some(error),
             deployed_targets: vec![],
             failed_targets: vec![],
+            conflicts: vec![],
         }
     }
 
@@ -121,9 +139,59 @@ impl UpdateOutcome {
             error: None,
             deployed_targets: vec![],
             failed_targets: vec![],
+            conflicts: vec![],
         }
     }
 
+    /// チャンネル切り替えでバージョンが下がる（未更新）
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Plugin name.
+    /// * `from_ref` - Currently installed release tag.
+    /// * `to_ref` - Latest release tag of the requested channel.
+    pub fn downgrade_required(name: &str, from_ref: &str, to_ref: &str) -> Self {
+        Self {
+            plugin_name: name.to_string(),
+            status: UpdateStatus::DowngradeRequired {
+                from_ref: from_ref.to_string(),
+                to_ref: to_ref.to_string(),
+            },
+            error: None,
+            deployed_targets: vec![],
+            failed_targets: vec![],
+            conflicts: vec![],
+        }
+    }
+
+    /// fetch せずキャッシュから再デプロイした
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Plugin name.
+    /// * `deployed` - Targets that were redeployed successfully.
+    /// * `failed` - Targets that failed to redeploy.
+    pub fn redeployed(name: &str, deployed: Vec<String>, failed: Vec<String>) -> Self {
+        Self {
+            plugin_name: name.to_string(),
+            status: UpdateStatus::Redeployed,
+            error: None,
+            deployed_targets: deployed,
+            failed_targets: failed,
+            conflicts: vec![],
+        }
+    }
+
+    /// 再デプロイで衝突したコンポーネントを結果に添える
+    ///
+    /// # Arguments
+    ///
+    /// * `conflicts` - Components skipped because another plugin owns the destination.
+    pub fn with_conflicts(mut self, conflicts: Vec<ComponentConflict>) -> Self {
+        self.conflicts = conflicts;
+        self
+    }
+
     /// ロールバック済み（バッチ失敗により更新前へ巻き戻された）
     ///
     /// # Arguments
@@ -137,6 +205,7 @@ impl UpdateOutcome {
             error: note,
             deployed_targets: vec![],
             failed_targets: vec![],
+            conflicts: vec![],
         }
     }
 }
@@ -197,7 +266,7 @@ fn parse_repo_from_mp_source(
     // Local は marketplace 自体の repo を参照する。
     let parsed = match plugin_source {
         MpPluginSource::External { repo, .. } => repo::from_url(repo)?,
-        MpPluginSource::Local(_) => mp_source.to_repo(),
+        MpPluginSource::Local(_) => mp_source.to_repo()?,
     };
     Ok(Repo::new(
         parsed.host(),
@@ -209,6 +278,118 @@ fn parse_repo_from_mp_source(
     ))
 }
 
+/// 更新先 ref の決定結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RefPlan {
+    /// この ref の最新コミットと比較して更新する
+    Track(String),
+    /// 現状維持（チャンネルの最新リリースが現在より古い）
+    Keep,
+    /// チャンネル切り替えでバージョンが下がる（確認が必要）
+    Downgrade { from_ref: String, to_ref: String },
+}
+
+/// 更新先の ref を決める
+///
+/// チャンネルが無ければ現在の ref（ブランチ / タグ）をそのまま追う。チャンネルがあれば
+/// その最新リリースタグを使う。記録済みチャンネルへの追従ではバージョンを下げず、
+/// 明示的な切り替えで下がる場合は `allow_downgrade` が無い限り確認を求める。
+///
+/// # Arguments
+///
+/// * `client` - Host client used to list release tags.
+/// * `repo` - Repository of the plugin.
+/// * `current_ref` - Currently installed ref.
+/// * `recorded` - Channel recorded in the plugin metadata.
+/// * `requested` - Channel requested with `plm update --channel`.
+/// * `allow_downgrade` - Whether a downgrading channel switch was already confirmed.
+async fn plan_ref(
+    client: &dyn HostClient,
+    repo: &Repo,
+    current_ref: &str,
+    recorded: Option<Channel>,
+    requested: Option<Channel>,
+    allow_downgrade: bool,
+) -> Result<RefPlan> {
+    let Some(channel) = requested.or(recorded) else {
+        return Ok(RefPlan::Track(current_ref.to_string()));
+    };
+    let tag = fetch_channel_tag(client, repo, channel).await?;
+    if !is_downgrade(current_ref, &tag) {
+        return Ok(RefPlan::Track(tag));
+    }
+    let switching = requested.is_some_and(|c| Some(c) != recorded);
+    Ok(match (switching, allow_downgrade) {
+        (true, true) => RefPlan::Track(tag),
+        (true, false) => RefPlan::Downgrade {
+            from_ref: current_ref.to_string(),
+            to_ref: tag,
+        },
+        (false, _) => RefPlan::Keep,
+    })
+}
+
+/// コミットは変わらないが ref / チャンネルが変わった場合に meta だけ更新する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `marketplace` - Marketplace name (`None` means the default GitHub marketplace).
+/// * `cache_id` - Cache ID of the plugin.
+/// * `meta` - Metadata with the new channel already applied.
+/// * `git_ref` - Ref now being tracked.
+fn record_ref_change(
+    cache: &dyn PackageCacheAccess,
+    marketplace: Option<&str>,
+    cache_id: &str,
+    meta: &PluginMeta,
+    git_ref: &str,
+) {
+    let mut new_meta = meta.clone();
+    new_meta.git_ref = Some(git_ref.to_string());
+    let plugin_path = cache.plugin_path(marketplace, cache_id);
+    if let Err(e) = meta::write_meta(&plugin_path, &new_meta) {
+        eprintln!("Warning: Failed to save plugin metadata: {}", e);
+    }
+}
+
+/// `update_plugin` の入力がインストール済みプラグインに解決できるか
+///
+/// 曖昧（複数一致）な場合は見つかった扱いにする（エラーは `update_plugin` が報告する）。
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_input` - User-supplied plugin identifier (cache_id or display name).
+/// * `marketplace_hint` - Optional marketplace hint to disambiguate.
+pub fn update_target_exists(
+    cache: &dyn PackageCacheAccess,
+    plugin_input: &str,
+    marketplace_hint: Option<&str>,
+) -> bool {
+    !matches!(
+        resolve_update_target(cache, plugin_input, marketplace_hint),
+        Err(PlmError::PluginNotFound(_))
+    )
+}
+
+/// `update_plugin` が更新するプラグインのキャッシュパス（解決できなければ `None`）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_input` - User-supplied plugin identifier (cache_id or display name).
+/// * `marketplace_hint` - Optional marketplace hint to disambiguate.
+pub fn update_target_path(
+    cache: &dyn PackageCacheAccess,
+    plugin_input: &str,
+    marketplace_hint: Option<&str>,
+) -> Option<PathBuf> {
+    resolve_update_target(cache, plugin_input, marketplace_hint)
+        .ok()
+        .map(|r| cache.plugin_path(r.marketplace.as_deref(), &r.cache_id))
+}
+
 /// `update_plugin` の入力 (`plugin_input`, `marketplace_hint`) を `ResolvedPlugin` に解決する。
 ///
 /// 解決順序:
@@ -274,7 +455,7 @@ fn resolve_update_target(
     }
 }
 
-fn load_resolved(
+pub(super) fn load_resolved(
     cache: &dyn PackageCacheAccess,
     market: Option<String>,
     cache_id: String,
@@ -300,80 +481,171 @@ fn load_resolved(
 /// * `marketplace_hint` - Optional marketplace hint to disambiguate.
 /// * `project_root` - Project root path used for redeployment.
 /// * `target_filter` - When `Some`, only redeploy to this single target.
+/// * `channel` - Channel to switch to, or `None` to follow the recorded one.
+/// * `allow_downgrade` - Whether a downgrading channel switch was already confirmed.
+/// * `force` - Fetch and redeploy even when the commit SHA is unchanged (`--force`).
+#[allow(clippy::too_many_arguments)]
 pub async fn update_plugin(
     cache: &dyn PackageCacheAccess,
     plugin_input: &str,
     marketplace_hint: Option<&str>,
     project_root: &Path,
     target_filter: Option<&str>,
+    channel: Option<Channel>,
+    allow_downgrade: bool,
+    force: bool,
 ) -> UpdateOutcome {
-    let resolved = match resolve_update_target(cache, plugin_input, marketplace_hint) {
+    let resolved = match resolve_or_fail(cache, plugin_input, marketplace_hint) {
         Ok(r) => r,
-        Err(PlmError::AmbiguousPluginName { name, candidates }) => {
-            return UpdateOutcome::failed(
-                &name,
-                format!(
-                    "Ambiguous plugin name (matches: {}). \
-                     Specify with marketplace, e.g. `{}@<marketplace>`.",
-                    candidates.join(", "),
-                    name,
-                ),
-            );
-        }
-        Err(PlmError::PluginNotFound(_)) => {
-            return UpdateOutcome::failed(plugin_input, "Plugin not found in cache".to_string());
-        }
-        Err(e) => return UpdateOutcome::failed(plugin_input, e.to_string()),
+        Err(outcome) => return outcome,
+    };
+    if resolved.package_meta.pinned {
+        return UpdateOutcome::skipped(&resolved.display_name, PINNED_REASON.to_string());
+    }
+    let _lock =
+        match PluginLock::acquire(cache, resolved.marketplace.as_deref(), &resolved.cache_id) {
+            Ok(lock) => lock,
+            Err(e) => return UpdateOutcome::failed(&resolved.display_name, e.to_string()),
+        };
+    let request = UpdateRequest {
+        channel,
+        allow_downgrade,
+        force,
     };
 
     // `Some("github")` と `None` の意味揺れを正規化してから経路を分岐する
     match MarketplaceRef::from_option(resolved.marketplace.as_deref()) {
         MarketplaceRef::Named(market) => {
-            update_marketplace_plugin(cache, &market, &resolved, project_root, target_filter).await
+            update_marketplace_plugin(
+                cache,
+                &market,
+                &resolved,
+                project_root,
+                target_filter,
+                request,
+            )
+            .await
         }
         MarketplaceRef::Github => {
-            update_github_plugin(cache, &resolved, project_root, target_filter).await
+            update_github_plugin(cache, &resolved, project_root, target_filter, request).await
         }
     }
 }
 
-/// 直接 GitHub 経路の安全更新
-async fn update_github_plugin(
+/// 更新対象を解決し、解決できなければ失敗結果を返す
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `plugin_input` - User-supplied plugin identifier (cache_id or display name).
+/// * `marketplace_hint` - Optional marketplace hint to disambiguate.
+pub(super) fn resolve_or_fail(
     cache: &dyn PackageCacheAccess,
+    plugin_input: &str,
+    marketplace_hint: Option<&str>,
+) -> std::result::Result<ResolvedPlugin, UpdateOutcome> {
+    resolve_update_target(cache, plugin_input, marketplace_hint).map_err(|e| match e {
+        PlmError::AmbiguousPluginName { name, candidates } => UpdateOutcome::failed(
+            &name,
+            format!(
+                "Ambiguous plugin name (matches: {}). \
+                 Specify with marketplace, e.g. `{}@<marketplace>`.",
+                candidates.join(", "),
+                name,
+            ),
+        ),
+        PlmError::PluginNotFound(_) => {
+            UpdateOutcome::failed(plugin_input, "Plugin not found in cache".to_string())
+        }
+        e => UpdateOutcome::failed(plugin_input, e.to_string()),
+    })
+}
+
+/// 単一更新で指定されたチャンネルと強制更新の指定
+#[derive(Debug, Clone, Copy)]
+struct UpdateRequest {
+    /// 切り替え先（`None` なら記録済みのチャンネルに従う）
+    channel: Option<Channel>,
+    /// バージョンが下がる切り替えを確認済みか
+    allow_downgrade: bool,
+    /// commit SHA が変わっていなくても取得・再デプロイするか（`--force`）
+    force: bool,
+}
+
+/// 単一更新の共通末尾: 更新先 ref を決めて SHA を比較し、必要なら安全更新する
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor for the plugin.
+/// * `marketplace` - Marketplace name (`None` means the default GitHub marketplace).
+/// * `resolved` - Resolved plugin being updated.
+/// * `client` - Host client.
+/// * `repo` - Repository with the currently installed ref.
+/// * `source_path` - Sub-path of Local marketplace plugins.
+/// * `project_root` - Project root path used for redeployment.
+/// * `target_filter` - When `Some`, only redeploy to this single target.
+/// * `request` - Channel and `--force` requested on the command line.
+#[allow(clippy::too_many_arguments)]
+async fn update_resolved(
+    cache: &dyn PackageCacheAccess,
+    marketplace: Option<&str>,
     resolved: &ResolvedPlugin,
+    client: &dyn HostClient,
+    repo: &Repo,
+    source_path: Option<&str>,
     project_root: &Path,
     target_filter: Option<&str>,
+    request: UpdateRequest,
 ) -> UpdateOutcome {
-    let plugin_meta = &resolved.package_meta;
     let cache_id = resolved.cache_id.as_str();
     let display_name = resolved.display_name.as_str();
-
-    if !plugin_meta.is_github() {
-        return UpdateOutcome::skipped(display_name, "Not a GitHub plugin".to_string());
-    }
-
-    let git_ref = plugin_meta.git_ref.as_deref().unwrap_or("HEAD");
-    let current_sha = plugin_meta.commit_sha.clone();
-
-    let repo = match restore_repo(plugin_meta, cache_id, git_ref) {
-        Ok(r) => r,
-        Err(e) => return UpdateOutcome::failed(display_name, e),
+    let current_ref = repo.ref_or_default();
+
+    let mut plugin_meta = resolved.package_meta.clone();
+    let plan = match plan_ref(
+        client,
+        repo,
+        current_ref,
+        plugin_meta.channel,
+        request.channel,
+        request.allow_downgrade,
+    )
+    .await
+    {
+        Ok(plan) => plan,
+        Err(e) => {
+            return UpdateOutcome::failed(
+                display_name,
+                format!("Failed to resolve channel release: {}", e),
+            );
+        }
     };
+    let git_ref = match plan {
+        RefPlan::Track(git_ref) => git_ref,
+        RefPlan::Keep => return UpdateOutcome::up_to_date(display_name),
+        RefPlan::Downgrade { from_ref, to_ref } => {
+            return UpdateOutcome::downgrade_required(display_name, &from_ref, &to_ref);
+        }
+    };
+    if request.channel.is_some() {
+        plugin_meta.channel = request.channel;
+    }
+    let repo = repo.with_ref(git_ref.as_str());
 
-    let factory = HostClientFactory::with_defaults();
-    let client = factory.create(HostKind::GitHub);
-
-    let latest_sha = match with_retry(|| client.get_commit_sha(&repo, git_ref), 3).await {
+    let latest_sha = match client.get_commit_sha(&repo, &git_ref).await {
         Ok(sha) => sha,
         Err(e) => {
             return UpdateOutcome::failed(display_name, format!("Failed to get latest SHA: {}", e));
         }
     };
 
-    if current_sha.as_deref() == Some(&latest_sha) {
+    let current_sha = plugin_meta.commit_sha.clone();
+    if !request.force && current_sha.as_deref() == Some(&latest_sha) {
+        if plugin_meta.channel != resolved.package_meta.channel || git_ref != current_ref {
+            record_ref_change(cache, marketplace, cache_id, &plugin_meta, &git_ref);
+        }
         return UpdateOutcome::up_to_date(display_name);
     }
-
     if current_sha.is_none() {
         eprintln!(
             "Warning: No commit SHA recorded for '{}'. Forcing update.",
@@ -383,15 +655,58 @@ async fn update_github_plugin(
 
     do_safe_update(
         cache,
-        None,
+        marketplace,
         cache_id,
         display_name,
-        plugin_meta,
+        &plugin_meta,
+        client,
+        &repo,
+        source_path,
+        project_root,
+        target_filter,
+    )
+    .await
+}
+
+/// 直接 GitHub 経路の安全更新
+async fn update_github_plugin(
+    cache: &dyn PackageCacheAccess,
+    resolved: &ResolvedPlugin,
+    project_root: &Path,
+    target_filter: Option<&str>,
+    request: UpdateRequest,
+) -> UpdateOutcome {
+    let plugin_meta = &resolved.package_meta;
+    let cache_id = resolved.cache_id.as_str();
+    let display_name = resolved.display_name.as_str();
+
+    if !plugin_meta.is_github() {
+        return UpdateOutcome::skipped(display_name, "Not a GitHub plugin".to_string());
+    }
+
+    let git_ref = plugin_meta.git_ref.as_deref().unwrap_or("HEAD");
+
+    let repo = match restore_repo(plugin_meta, cache_id, git_ref) {
+        Ok(r) => r,
+        Err(e) => return UpdateOutcome::failed(display_name, e),
+    };
+
+    let factory = match HostClientFactory::from_env() {
+        Ok(f) => f,
+        Err(e) => return UpdateOutcome::failed(display_name, e.to_string()),
+    };
+    let client = factory.create(HostKind::GitHub);
+
+    update_resolved(
+        cache,
+        None,
+        resolved,
         client.as_ref(),
         &repo,
         None,
         project_root,
         target_filter,
+        request,
     )
     .await
 }
@@ -403,6 +718,7 @@ async fn update_marketplace_plugin(
     resolved: &ResolvedPlugin,
     project_root: &Path,
     target_filter: Option<&str>,
+    request: UpdateRequest,
 ) -> UpdateOutcome {
     let display_name = resolved.display_name.as_str();
     let cache_id = resolved.cache_id.as_str();
@@ -425,10 +741,15 @@ async fn update_marketplace_plugin(
     let entry = match mp_cache.plugins.iter().find(|p| p.name == cache_id) {
         Some(e) => e.clone(),
         None => {
-            return UpdateOutcome::failed(
-                display_name,
-                format!("Plugin entry not found in marketplace: {}", cache_id),
-            );
+            let msg = match find_renamed_entry(&mp_cache, cache_id) {
+                Some(renamed) => format!(
+                    "'{}' was renamed to '{}' in marketplace '{}'. \
+                     Re-run with --follow-renames to migrate.",
+                    cache_id, renamed.name, marketplace
+                ),
+                None => format!("Plugin entry not found in marketplace: {}", cache_id),
+            };
+            return UpdateOutcome::failed(display_name, msg);
         }
     };
 
@@ -443,43 +764,27 @@ async fn update_marketplace_plugin(
         Err(e) => return UpdateOutcome::failed(display_name, e.to_string()),
     };
 
-    let factory = HostClientFactory::with_defaults();
-    let client = factory.create(HostKind::GitHub);
-
-    let latest_sha = match with_retry(|| client.get_commit_sha(&repo, git_ref), 3).await {
-        Ok(sha) => sha,
-        Err(e) => {
-            return UpdateOutcome::failed(display_name, format!("Failed to get latest SHA: {}", e));
-        }
+    let factory = match HostClientFactory::from_env() {
+        Ok(f) => f,
+        Err(e) => return UpdateOutcome::failed(display_name, e.to_string()),
     };
-
-    let current_sha = plugin_meta.commit_sha.clone();
-    if current_sha.as_deref() == Some(&latest_sha) {
-        return UpdateOutcome::up_to_date(display_name);
-    }
-    if current_sha.is_none() {
-        eprintln!(
-            "Warning: No commit SHA recorded for '{}'. Forcing update.",
-            display_name
-        );
-    }
+    let client = factory.create(HostKind::GitHub);
 
     let source_path = match &entry.source {
         MpPluginSource::Local(p) => Some(p.clone()),
         MpPluginSource::External { .. } => None,
     };
 
-    do_safe_update(
+    update_resolved(
         cache,
         Some(marketplace),
-        cache_id,
-        display_name,
-        plugin_meta,
+        resolved,
         client.as_ref(),
         &repo,
         source_path.as_deref(),
         project_root,
         target_filter,
+        request,
     )
     .await
 }
@@ -502,11 +807,8 @@ async fn do_safe_update(
     target_filter: Option<&str>,
 ) -> UpdateOutcome {
     let current_sha = old_meta.commit_sha.clone();
-    let git_ref_owned = old_meta
-        .git_ref
-        .clone()
-        .unwrap_or_else(|| "HEAD".to_string());
-    let git_ref = git_ref_owned.as_str();
+    let git_ref = repo.ref_or_default();
+    let old_version = installed_version(cache, marketplace, cache_id);
 
     println!("  Creating backup...");
     if let Err(e) = cache.backup(marketplace, cache_id) {
@@ -515,7 +817,7 @@ async fn do_safe_update(
 
     println!("  Downloading...");
     let (archive, _archive_git_ref, archive_sha) =
-        match with_retry(|| client.download_archive_with_sha(repo), 3).await {
+        match client.download_archive_with_sha(repo).await {
             Ok(triple) => triple,
             Err(e) => {
                 let _ = cache.restore(marketplace, cache_id);
@@ -534,19 +836,29 @@ async fn do_safe_update(
         };
 
     println!("  Deploying...");
-    let enabled = old_meta.enabled_targets();
+    let enabled = old_meta.deployed_targets();
     let targets: Vec<&str> = match target_filter {
         Some(f) => enabled.into_iter().filter(|t| *t == f).collect(),
         None => enabled,
     };
-    let (deployed, failed) =
-        redeploy_to_targets(cache, cache_id, marketplace, &targets, project_root);
-
-    let mut new_meta = old_meta.clone();
+    carry_meta_over(&plugin_path, old_meta);
+    let Redeployment {
+        deployed,
+        failed,
+        conflicts,
+    } = redeploy_to_targets(cache, cache_id, marketplace, &targets, project_root);
+
+    let mut new_meta = meta_after_redeploy(old_meta, &plugin_path);
     new_meta.set_git_info(git_ref, &archive_sha);
     for t in &failed {
         new_meta.set_status(t, TargetStatus::Disabled);
     }
+    record_update_history(
+        &mut new_meta,
+        old_version.as_deref(),
+        installed_version(cache, marketplace, cache_id).as_deref(),
+        &failed,
+    );
     if let Err(e) = meta::write_meta(&plugin_path, &new_meta) {
         let _ = cache.restore(marketplace, cache_id);
         return UpdateOutcome::failed(display_name, format!("Failed to write metadata: {}", e));
@@ -555,10 +867,100 @@ async fn do_safe_update(
     let _ = cache.remove_backup(marketplace, cache_id);
 
     UpdateOutcome::updated(display_name, current_sha, archive_sha, deployed, failed)
+        .with_conflicts(conflicts)
+}
+
+/// 差し替えたキャッシュへ旧メタデータを引き継ぐ（best-effort）
+///
+/// 展開し直したキャッシュには `.plm-meta.json` が無いため、再デプロイの前に書き戻して
+/// コマンドのプレフィクスと配置記録（`deployedFiles`）を参照できるようにする。
+/// 記録があれば、新しいバージョンに無くなったコンポーネントの配置物は再デプロイ時に削除される。
+///
+/// # Arguments
+///
+/// * `plugin_path` - Filesystem path of the swapped-in cache.
+/// * `old_meta` - Metadata read before the update.
+fn carry_meta_over(plugin_path: &Path, old_meta: &PluginMeta) {
+    if let Err(e) = meta::write_meta(plugin_path, old_meta) {
+        eprintln!("Warning: Failed to carry over plugin metadata: {}", e);
+    }
+}
+
+/// キャッシュにあるプラグインのバージョン（マニフェストが読めなければ `None`）
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor.
+/// * `marketplace` - Marketplace name (`None` means the default GitHub marketplace).
+/// * `cache_id` - Cache ID of the plugin.
+fn installed_version(
+    cache: &dyn PackageCacheAccess,
+    marketplace: Option<&str>,
+    cache_id: &str,
+) -> Option<String> {
+    cache
+        .load_manifest(marketplace, cache_id)
+        .ok()
+        .map(|manifest| manifest.version)
+}
+
+/// 更新結果を更新履歴へ記録する
+///
+/// 再デプロイに失敗したターゲットがあれば [`UpdateResult::Partial`] として残す。
+///
+/// # Arguments
+///
+/// * `new_meta` - Metadata written back after the update.
+/// * `old_version` - Version before the update, if known.
+/// * `new_version` - Version after the update, if known.
+/// * `failed` - Targets that failed to redeploy.
+fn record_update_history(
+    new_meta: &mut PluginMeta,
+    old_version: Option<&str>,
+    new_version: Option<&str>,
+    failed: &[String],
+) {
+    let result = if failed.is_empty() {
+        UpdateResult::Updated
+    } else {
+        UpdateResult::Partial
+    };
+    new_meta.record_update(old_version, new_version, result);
+}
+
+/// 再デプロイ後に書き戻すメタデータを作る
+///
+/// `old_meta` は再デプロイ前に読み込んだものなので、再デプロイで更新された配置記録
+/// （`deployedFiles`）だけはキャッシュから読み直して引き継ぐ。
+///
+/// # Arguments
+///
+/// * `old_meta` - Metadata read before the redeploy.
+/// * `plugin_path` - Filesystem path of the cached plugin.
+pub(super) fn meta_after_redeploy(old_meta: &PluginMeta, plugin_path: &Path) -> PluginMeta {
+    let mut new_meta = old_meta.clone();
+    if let Some(current) = meta::load_meta(plugin_path) {
+        new_meta.deployed_files = current.deployed_files;
+    }
+    new_meta
+}
+
+/// ターゲットへの再デプロイの結果
+#[derive(Debug, Default)]
+pub(super) struct Redeployment {
+    /// 再デプロイに成功したターゲット
+    pub deployed: Vec<String>,
+    /// 再デプロイに失敗したターゲット
+    pub failed: Vec<String>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 /// ターゲットへの再デプロイ
 ///
+/// 部分 disable（`plm disable --keep`）中のターゲットには残している種別だけを配置する。
+/// プロジェクトで無効なターゲット（`plm target disable`）には配置しない（成功・失敗のどちらにも含めない）。
+///
 /// # Arguments
 ///
 /// * `cache` - Package cache accessor for the plugin.
@@ -566,26 +968,40 @@ async fn do_safe_update(
 /// * `marketplace` - Marketplace name (`None` means the default GitHub marketplace).
 /// * `targets` - Target names to redeploy to.
 /// * `project_root` - Project root path used for redeployment.
-fn redeploy_to_targets(
+pub(super) fn redeploy_to_targets(
     cache: &dyn PackageCacheAccess,
     plugin_name: &str,
     marketplace: Option<&str>,
     targets: &[&str],
     project_root: &Path,
-) -> (Vec<String>, Vec<String>) {
-    let mut deployed = Vec::new();
-    let mut failed = Vec::new();
+) -> Redeployment {
+    let plugin_meta =
+        meta::load_meta(&cache.plugin_path(marketplace, plugin_name)).unwrap_or_default();
+    let enabled = project_enabled_targets(project_root);
+    let mut redeployment = Redeployment::default();
 
     for target in targets {
-        let result = enable_plugin(cache, plugin_name, marketplace, project_root, Some(target));
-        if result.success {
-            deployed.push(target.to_string());
+        if enabled
+            .as_ref()
+            .is_some_and(|enabled| !enabled.iter().any(|k| k.as_str() == *target))
+        {
+            println!("  Skipping disabled target: {}", target);
+            continue;
+        }
+        let filter = DeployFilter {
+            target: Some(target),
+            kinds: plugin_meta.deploy_kinds(target),
+        };
+        let result = enable_plugin_filtered(cache, plugin_name, marketplace, project_root, filter);
+        if result.outcome.success {
+            redeployment.deployed.push(target.to_string());
         } else {
-            failed.push(target.to_string());
+            redeployment.failed.push(target.to_string());
         }
+        redeployment.conflicts.extend(result.conflicts);
     }
 
-    (deployed, failed)
+    redeployment
 }
 
 /// marketplace 解決の抽象（テストで差し替え可能にする）
@@ -631,6 +1047,8 @@ struct UpdateTarget {
     repo: Repo,
     source_path: Option<String>,
     old_meta: PluginMeta,
+    /// 更新前のバージョン（更新履歴用。キャッシュの差し替え前に読む）
+    old_version: Option<String>,
     git_ref: String,
 }
 
@@ -638,6 +1056,8 @@ struct UpdateTarget {
 struct StagedUpdate {
     target: UpdateTarget,
     archive_sha: String,
+    /// commit 完了まで保持する操作ロック
+    _lock: PluginLock,
 }
 
 /// prepare の集約結果
@@ -661,12 +1081,17 @@ enum PrepareOutcome {
 /// * `cache` - Package cache accessor used to enumerate and update plugins.
 /// * `project_root` - Project root path used for redeployment.
 /// * `target_filter` - When `Some`, only redeploy to this single target.
+/// * `force` - Update every plugin even when its commit SHA is unchanged (`--force`).
 pub async fn update_all_plugins(
     cache: &dyn PackageCacheAccess,
     project_root: &Path,
     target_filter: Option<&str>,
+    force: bool,
 ) -> Vec<UpdateOutcome> {
-    let factory = HostClientFactory::with_defaults();
+    let factory = match HostClientFactory::from_env() {
+        Ok(f) => f,
+        Err(e) => return fail_all(cache, &e.to_string()),
+    };
     let client = factory.create(HostKind::GitHub);
     let resolver = RegistryResolver::new();
     update_all_plugins_with_deps(
@@ -675,10 +1100,30 @@ pub async fn update_all_plugins(
         &resolver,
         project_root,
         target_filter,
+        force,
     )
     .await
 }
 
+/// 更新処理を始められなかった場合に、インストール済みの全プラグインを失敗として返す
+///
+/// # Arguments
+///
+/// * `cache` - Package cache accessor used to enumerate plugins.
+/// * `error` - Error message recorded for every plugin.
+fn fail_all(cache: &dyn PackageCacheAccess, error: &str) -> Vec<UpdateOutcome> {
+    match cache.list() {
+        Ok(plugins) => plugins
+            .iter()
+            .map(|(_, cache_id)| UpdateOutcome::failed(cache_id, error.to_string()))
+            .collect(),
+        Err(e) => {
+            eprintln!("Error: Failed to list plugins: {}", e);
+            vec![]
+        }
+    }
+}
+
 /// 依存注入版本体（テストから直接呼ぶ）
 pub(crate) async fn update_all_plugins_with_deps(
     cache: &dyn PackageCacheAccess,
@@ -686,6 +1131,7 @@ pub(crate) async fn update_all_plugins_with_deps(
     resolver: &dyn MarketplaceResolver,
     project_root: &Path,
     target_filter: Option<&str>,
+    force: bool,
 ) -> Vec<UpdateOutcome> {
     let plugins = match cache.list() {
         Ok(p) => p,
@@ -721,7 +1167,7 @@ pub(crate) async fn update_all_plugins_with_deps(
         mut results,
         up_to_date_count,
         error_count,
-    } = check_all(cache, client, &plugins, &mp_caches).await;
+    } = check_all(cache, client, &plugins, &mp_caches, force).await;
 
     let update_count = targets.len();
 
@@ -782,11 +1228,13 @@ struct CheckOutcome {
 /// `repo` / `source_path` の解決方法のみが異なり、SHA 比較・`UpdateTarget` 構築の末尾処理は共通化する。
 /// CHECK で `get_commit_sha` 等が失敗した plugin は従来どおり `error_count` でスキップし、
 /// 他対象のアトミック処理の引き金にはしない。
+/// `force` なら SHA が同じでも更新対象にする（`plm update --all --force`）。
 async fn check_all(
     cache: &dyn PackageCacheAccess,
     client: &dyn HostClient,
     plugins: &[(Option<String>, String)],
     mp_caches: &std::collections::HashMap<String, MarketplaceCache>,
+    force: bool,
 ) -> CheckOutcome {
     let mut results = Vec::new();
     let mut up_to_date_count = 0usize;
@@ -805,6 +1253,13 @@ async fn check_all(
         };
         let display_name = pkg.name.clone();
         let plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
+        if plugin_meta.pinned {
+            results.push(UpdateOutcome::skipped(
+                &display_name,
+                PINNED_REASON.to_string(),
+            ));
+            continue;
+        }
         let git_ref = plugin_meta.git_ref.as_deref().unwrap_or("HEAD");
 
         // 経路ごとに repo / source_path を解決（早期 continue で skip / up_to_date / error を確定）
@@ -863,8 +1318,24 @@ async fn check_all(
             (repo, None)
         };
 
-        // 共通末尾: SHA 取得 → needs_update 判定 → UpdateTarget 構築
-        let latest_sha = match with_retry(|| client.get_commit_sha(&repo, git_ref), 3).await {
+        // 共通末尾: 更新先 ref 決定 → SHA 取得 → needs_update 判定 → UpdateTarget 構築
+        // （一括更新ではチャンネルを切り替えないため、記録済みチャンネルへの追従のみ）
+        let git_ref = match plan_ref(client, &repo, git_ref, plugin_meta.channel, None, false).await
+        {
+            Ok(RefPlan::Track(git_ref)) => git_ref,
+            Ok(RefPlan::Keep | RefPlan::Downgrade { .. }) => {
+                up_to_date_count += 1;
+                results.push(UpdateOutcome::up_to_date(&display_name));
+                continue;
+            }
+            Err(e) => {
+                error_count += 1;
+                eprintln!("  {}: Failed to check ({})", display_name, e);
+                continue;
+            }
+        };
+        let repo = repo.with_ref(git_ref.as_str());
+        let latest_sha = match client.get_commit_sha(&repo, &git_ref).await {
             Ok(s) => s,
             Err(e) => {
                 error_count += 1;
@@ -872,12 +1343,11 @@ async fn check_all(
                 continue;
             }
         };
-        if !needs_update(plugin_meta.commit_sha.as_deref(), &latest_sha) {
+        if !force && !needs_update(plugin_meta.commit_sha.as_deref(), &latest_sha) {
             up_to_date_count += 1;
             results.push(UpdateOutcome::up_to_date(&display_name));
             continue;
         }
-        let git_ref_owned = git_ref.to_string();
         targets.push(UpdateTarget {
             marketplace: marketplace.clone(),
             cache_id: cache_id.clone(),
@@ -885,7 +1355,8 @@ async fn check_all(
             repo,
             source_path,
             old_meta: plugin_meta,
-            git_ref: git_ref_owned,
+            old_version: Some(pkg.manifest.version.clone()),
+            git_ref,
         });
     }
 
@@ -928,6 +1399,14 @@ async fn prepare_all(
     let mut iter = targets.into_iter();
     while let Some(target) = iter.next() {
         let mp = target.marketplace.as_deref();
+        // 0. lock（別プロセスが操作中なら本番を触らずに中止）
+        let lock = match PluginLock::acquire(cache, mp, &target.cache_id) {
+            Ok(lock) => lock,
+            Err(e) => {
+                let failure = failed_for(&target, e.to_string());
+                return abort(cache, staged, failure, iter);
+            }
+        };
         // 1. backup
         if let Err(e) = cache.backup(mp, &target.cache_id) {
             let failure = failed_for(&target, format!("Backup failed: {}", e));
@@ -935,7 +1414,7 @@ async fn prepare_all(
         }
         // 2. download（本番非破壊）
         let (archive, _ref, archive_sha) =
-            match with_retry(|| client.download_archive_with_sha(&target.repo), 3).await {
+            match client.download_archive_with_sha(&target.repo).await {
                 Ok(t) => t,
                 Err(e) => {
                     cleanup_backup(cache, mp, &target.cache_id);
@@ -957,6 +1436,7 @@ async fn prepare_all(
         staged.push(StagedUpdate {
             target,
             archive_sha,
+            _lock: lock,
         });
     }
     PrepareOutcome::AllStaged(staged)
@@ -1027,20 +1507,30 @@ fn commit_all(
         let plugin_path = cache.plugin_path(mp, &s.target.cache_id);
 
         // redeploy（非アトミック: 失敗 target は disabled）
-        let enabled = s.target.old_meta.enabled_targets();
+        let enabled = s.target.old_meta.deployed_targets();
         let targets: Vec<&str> = match target_filter {
             Some(f) => enabled.into_iter().filter(|t| *t == f).collect(),
             None => enabled,
         };
-        let (deployed, failed) =
-            redeploy_to_targets(cache, &s.target.cache_id, mp, &targets, project_root);
+        carry_meta_over(&plugin_path, &s.target.old_meta);
+        let Redeployment {
+            deployed,
+            failed,
+            conflicts,
+        } = redeploy_to_targets(cache, &s.target.cache_id, mp, &targets, project_root);
 
         // meta 更新（best-effort。アトミック境界は swap までのため失敗しても巻き戻さない）
-        let mut new_meta = s.target.old_meta.clone();
+        let mut new_meta = meta_after_redeploy(&s.target.old_meta, &plugin_path);
         new_meta.set_git_info(&s.target.git_ref, &s.archive_sha);
         for t in &failed {
             new_meta.set_status(t, TargetStatus::Disabled);
         }
+        record_update_history(
+            &mut new_meta,
+            s.target.old_version.as_deref(),
+            installed_version(cache, mp, &s.target.cache_id).as_deref(),
+            &failed,
+        );
         // best-effort: 失敗してもロールバックしない（アトミック境界は swap まで）が、
         // commit SHA がメタに反映されない不整合を無言にしないよう警告する。
         // check_all は meta の commit_sha とリモート SHA を比較して更新要否を判定するため、
@@ -1055,13 +1545,16 @@ fn commit_all(
             );
         }
 
-        results.push(UpdateOutcome::updated(
-            &s.target.display_name,
-            s.target.old_meta.commit_sha.clone(),
-            s.archive_sha.clone(),
-            deployed,
-            failed,
-        ));
+        results.push(
+            UpdateOutcome::updated(
+                &s.target.display_name,
+                s.target.old_meta.commit_sha.clone(),
+                s.archive_sha.clone(),
+                deployed,
+                failed,
+            )
+            .with_conflicts(conflicts),
+        );
     }
 
     // 全件成功 → backup 確定削除
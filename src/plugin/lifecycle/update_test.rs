@@ -283,6 +283,8 @@ mod batch {
                         },
                         description: None,
                         version: None,
+                        renamed_from: vec![],
+                        stats: Default::default(),
                     },
                 )
                 .collect();
@@ -290,6 +292,7 @@ mod batch {
                 name: self.market.clone(),
                 fetched_at: chrono::Utc::now(),
                 source: format!("github:owner/{}", self.market).parse().unwrap(),
+                git_ref: None,
                 owner: None,
                 plugins,
             }))
@@ -311,6 +314,8 @@ mod batch {
         sha_fail: HashSet<String>,
         /// name -> download 挙動（未指定は Valid）
         download: HashMap<String, Download>,
+        /// name -> list_tags が返すタグ（未指定は空）
+        tags: HashMap<String, Vec<String>>,
     }
 
     impl MockBatchClient {
@@ -319,6 +324,7 @@ mod batch {
                 latest: HashMap::new(),
                 sha_fail: HashSet::new(),
                 download: HashMap::new(),
+                tags: HashMap::new(),
             }
         }
     }
@@ -389,6 +395,14 @@ mod batch {
         ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
             Box::pin(async { Ok(String::new()) })
         }
+
+        fn list_tags<'a>(
+            &'a self,
+            repo: &'a Repo,
+        ) -> Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>> {
+            let tags = self.tags.get(repo.name()).cloned().unwrap_or_default();
+            Box::pin(async move { Ok(tags) })
+        }
     }
 
     /// commit_staged / restore に障害を注入できるキャッシュデコレータ
@@ -457,6 +471,9 @@ mod batch {
         fn list(&self) -> Result<Vec<(Option<String>, String)>> {
             self.inner.list()
         }
+        fn rename(&self, marketplace: Option<&str>, from: &str, to: &str) -> Result<PathBuf> {
+            self.inner.rename(marketplace, from, to)
+        }
         fn backup(&self, marketplace: Option<&str>, name: &str) -> Result<PathBuf> {
             self.inner.backup(marketplace, name)
         }
@@ -543,7 +560,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 1);
         let a = find(&results, "repoA");
@@ -570,7 +587,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 3);
         assert_eq!(
@@ -601,7 +618,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 2);
         assert_eq!(
@@ -615,6 +632,89 @@ mod batch {
         }
     }
 
+    #[test]
+    fn test_fail_all_reports_every_plugin() {
+        let tmp = TempDir::new().unwrap();
+        let cache_dir = tmp.path().to_path_buf();
+        setup_plugin(&cache_dir, "repoA", OLD_SHA, &[]);
+        setup_plugin(&cache_dir, "repoB", OLD_SHA, &[]);
+        let cache = PackageCache::with_cache_dir(cache_dir.clone()).unwrap();
+
+        let results = fail_all(&cache, "cannot read CA bundle");
+
+        assert_eq!(results.len(), 2);
+        for id in ["repoA", "repoB"] {
+            let outcome = find(&results, id);
+            assert!(matches!(outcome.status, UpdateStatus::Failed));
+            assert_eq!(outcome.error.as_deref(), Some("cannot read CA bundle"));
+            assert_eq!(read_data(&cache_dir, id), "v1");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_force_updates_plugins_with_unchanged_sha() {
+        let tmp = TempDir::new().unwrap();
+        let cache_dir = tmp.path().to_path_buf();
+        setup_plugin(&cache_dir, "repoA", "REMOTE_SHA", &[]);
+        let cache = PackageCache::with_cache_dir(cache_dir.clone()).unwrap();
+        let client = MockBatchClient::new();
+        let resolver = NoMarketplaces;
+
+        let results =
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, true).await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            find(&results, "repoA").status,
+            UpdateStatus::Updated { .. }
+        ));
+        assert_eq!(read_data(&cache_dir, "repoA"), "v2");
+    }
+
+    #[tokio::test]
+    async fn test_pinned_plugin_is_skipped_until_unpinned() {
+        let tmp = TempDir::new().unwrap();
+        let cache_dir = tmp.path().to_path_buf();
+        setup_plugin(&cache_dir, "repoA", OLD_SHA, &[]);
+        setup_plugin(&cache_dir, "repoB", OLD_SHA, &[]);
+        let plugin_dir = cache_dir.join("github").join("repoA");
+        let mut pinned = meta::load_meta(&plugin_dir).unwrap();
+        pinned.pin("1.0.0");
+        meta::write_meta(&plugin_dir, &pinned).unwrap();
+        let cache = PackageCache::with_cache_dir(cache_dir.clone()).unwrap();
+        let client = MockBatchClient::new();
+        let resolver = NoMarketplaces;
+
+        let results =
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
+
+        assert!(matches!(
+            &find(&results, "repoA").status,
+            UpdateStatus::Skipped { reason } if reason == PINNED_REASON
+        ));
+        assert!(matches!(
+            find(&results, "repoB").status,
+            UpdateStatus::Updated { .. }
+        ));
+        assert_eq!(read_data(&cache_dir, "repoA"), "v1");
+        assert_eq!(
+            read_commit_sha(&cache_dir, "repoA").as_deref(),
+            Some(OLD_SHA)
+        );
+
+        pinned.unpin();
+        meta::write_meta(&plugin_dir, &pinned).unwrap();
+
+        let results =
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
+
+        assert!(matches!(
+            find(&results, "repoA").status,
+            UpdateStatus::Updated { .. }
+        ));
+        assert_eq!(read_data(&cache_dir, "repoA"), "v2");
+    }
+
     // ---- prepare 失敗の全件ロールバック ----
 
     #[tokio::test]
@@ -630,7 +730,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         // 全件が結果に出る（review B: 未到達も RolledBack）
         assert_eq!(results.len(), 3);
@@ -672,7 +772,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 3);
         assert!(matches!(
@@ -707,7 +807,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 3);
         // 当該 repoB は Failed ちょうど 1 件、RolledBack で二重計上されない（review A）
@@ -744,7 +844,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 1);
         assert!(matches!(
@@ -778,7 +878,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 2);
         // 1 件目（swap 済み）→ RolledBack + restore 失敗の警告
@@ -834,7 +934,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 3);
         // Failed は 1 件（2 件目）
@@ -869,7 +969,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         let a = find(&results, "repoA");
         assert!(matches!(a.status, UpdateStatus::Failed));
@@ -896,7 +996,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 1);
         // redeploy の成否に関わらず swap は確定し Updated（ロールバックしない）
@@ -919,7 +1019,7 @@ mod batch {
         let resolver = NoMarketplaces;
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         // repoB は error_count でスキップ → results に出ない。repoA のみ Updated。
         assert_eq!(results.len(), 1);
@@ -954,7 +1054,7 @@ mod batch {
         };
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 1);
         assert!(matches!(
@@ -991,7 +1091,7 @@ mod batch {
         };
 
         let results =
-            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None).await;
+            update_all_plugins_with_deps(&cache, &client, &resolver, tmp.path(), None, false).await;
 
         assert_eq!(results.len(), 2);
         // Failed はちょうど 1 件（mymarket/foo = "foo-mp"）。二重計上されない。
@@ -1012,4 +1112,100 @@ mod batch {
         assert_eq!(read_data_in(&cache_dir, "github", "foo"), "v1");
         assert_eq!(read_data_in(&cache_dir, "mymarket", "foo"), "v1");
     }
+
+    /// setup_plugin 済みプラグインの gitRef / channel を書き換える
+    fn set_channel(cache_dir: &Path, cache_id: &str, git_ref: &str, channel: Channel) {
+        let dir = cache_dir.join("github").join(cache_id);
+        let mut m = meta::load_meta(&dir).unwrap();
+        m.git_ref = Some(git_ref.to_string());
+        m.channel = Some(channel);
+        meta::write_meta(&dir, &m).unwrap();
+    }
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_batch_follows_recorded_channel() {
+        let tmp = TempDir::new().unwrap();
+        let cache_dir = tmp.path().to_path_buf();
+        setup_plugin(&cache_dir, "repoA", OLD_SHA, &[]);
+        set_channel(&cache_dir, "repoA", "v1.0.0", Channel::Stable);
+        let cache = PackageCache::with_cache_dir(cache_dir.clone()).unwrap();
+        let mut client = MockBatchClient::new();
+        client.tags.insert(
+            "repoA".to_string(),
+            tags(&["v1.0.0", "v1.1.0", "v2.0.0-beta.1"]),
+        );
+
+        let results =
+            update_all_plugins_with_deps(&cache, &client, &NoMarketplaces, tmp.path(), None, false)
+                .await;
+
+        assert!(matches!(
+            find(&results, "repoA").status,
+            UpdateStatus::Updated { .. }
+        ));
+        let m = meta::load_meta(&cache_dir.join("github").join("repoA")).unwrap();
+        assert_eq!(m.git_ref.as_deref(), Some("v1.1.0"));
+        assert_eq!(m.channel, Some(Channel::Stable));
+    }
+
+    #[tokio::test]
+    async fn test_batch_channel_never_downgrades() {
+        let tmp = TempDir::new().unwrap();
+        let cache_dir = tmp.path().to_path_buf();
+        setup_plugin(&cache_dir, "repoA", OLD_SHA, &[]);
+        // beta を入れた後に stable へ記録だけ変わったケース: stable 最新は現在より古い
+        set_channel(&cache_dir, "repoA", "v2.0.0-beta.1", Channel::Stable);
+        let cache = PackageCache::with_cache_dir(cache_dir.clone()).unwrap();
+        let mut client = MockBatchClient::new();
+        client
+            .tags
+            .insert("repoA".to_string(), tags(&["v1.1.0", "v2.0.0-beta.1"]));
+
+        let results =
+            update_all_plugins_with_deps(&cache, &client, &NoMarketplaces, tmp.path(), None, false)
+                .await;
+
+        assert!(matches!(
+            find(&results, "repoA").status,
+            UpdateStatus::AlreadyUpToDate
+        ));
+        assert_eq!(read_data(&cache_dir, "repoA"), "v1");
+    }
+
+    #[tokio::test]
+    async fn test_plan_ref_switch_to_lower_channel_needs_confirmation() {
+        let mut client = MockBatchClient::new();
+        client
+            .tags
+            .insert("repoA".to_string(), tags(&["v1.4.0", "v2.0.0-beta.1"]));
+        let repo = Repo::new(HostKind::GitHub, "owner", "repoA", None);
+        let current = "v2.0.0-beta.1";
+        let (beta, stable) = (Some(Channel::Beta), Some(Channel::Stable));
+
+        let plan = plan_ref(&client, &repo, current, beta, stable, false).await;
+        assert_eq!(
+            plan.unwrap(),
+            RefPlan::Downgrade {
+                from_ref: "v2.0.0-beta.1".to_string(),
+                to_ref: "v1.4.0".to_string(),
+            }
+        );
+
+        let plan = plan_ref(&client, &repo, current, beta, stable, true).await;
+        assert_eq!(plan.unwrap(), RefPlan::Track("v1.4.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_plan_ref_without_channel_tracks_current_ref() {
+        let client = MockBatchClient::new();
+        let repo = Repo::new(HostKind::GitHub, "owner", "repoA", None);
+
+        let plan = plan_ref(&client, &repo, "main", None, None, false).await;
+
+        assert_eq!(plan.unwrap(), RefPlan::Track("main".to_string()));
+    }
 }
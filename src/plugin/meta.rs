@@ -1,9 +1,13 @@
+mod channel;
+mod dependency;
 pub(crate) mod manifest;
 pub(crate) mod manifest_resolve;
 #[allow(clippy::module_inception)]
 mod meta;
 pub(crate) mod version;
 
+pub use self::channel::{compare_versions, is_downgrade, is_upgrade, latest_tag, Channel};
+pub use self::dependency::Dependency;
 pub use self::meta::*;
 
 pub(crate) use self::manifest_resolve::{has_manifest, resolve_manifest_path};
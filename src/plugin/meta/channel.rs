@@ -0,0 +1,290 @@
+//! 更新チャンネル
+//!
+//! リリースタグ（`v2.0.0` / `v2.0.0-beta.1` など）を Semantic Versioning 2.0.0 に従って
+//! 解釈し、チャンネルごとの最新リリースを選ぶ。
+//!
+//! - `stable`: プレリリース（`-` 付き）を除いたリリースの中で最新
+//! - `beta`: プレリリースを含む全リリースの中で最新
+//!
+//! SemVer として解釈できないタグ（`latest` や `nightly` など）はどちらのチャンネルでも無視する。
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// 更新チャンネル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    /// 正式リリースのみ
+    #[default]
+    Stable,
+    /// プレリリースを含む
+    Beta,
+}
+
+impl Channel {
+    /// Returns the string representation of this channel.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Channel::Stable => "stable",
+            Channel::Beta => "beta",
+        }
+    }
+
+    /// このチャンネルで配布されるバージョンか
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Parsed release version.
+    pub fn accepts(&self, version: &SemVer) -> bool {
+        match self {
+            Channel::Stable => !version.is_prerelease(),
+            Channel::Beta => true,
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// プレリリース識別子の 1 要素
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Identifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+            // 数値のみの識別子は英数字の識別子より常に小さい
+            (Identifier::Numeric(_), Identifier::AlphaNumeric(_)) => Ordering::Less,
+            (Identifier::AlphaNumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+            (Identifier::AlphaNumeric(a), Identifier::AlphaNumeric(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// リリースタグから解釈したセマンティックバージョン
+///
+/// ビルドメタデータ（`+` 以降）は比較に影響しないため保持しない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemVer {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<Identifier>,
+}
+
+impl SemVer {
+    /// タグ名を解釈する（先頭の `v` / `V` は省略可）
+    ///
+    /// 解釈できない場合は `None`。
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Release tag such as `v1.2.3` or `1.2.3-beta.1+build.5`.
+    pub fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.trim();
+        let tag = tag
+            .strip_prefix('v')
+            .or_else(|| tag.strip_prefix('V'))
+            .unwrap_or(tag);
+        let without_build = match tag.split_once('+') {
+            Some((version, build)) => {
+                if !build.split('.').all(is_valid_identifier) {
+                    return None;
+                }
+                version
+            }
+            None => tag,
+        };
+        let (core, pre) = match without_build.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (without_build, None),
+        };
+
+        let mut numbers = core.split('.');
+        let major = parse_numeric(numbers.next()?)?;
+        let minor = parse_numeric(numbers.next()?)?;
+        let patch = parse_numeric(numbers.next()?)?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        let pre = match pre {
+            Some(pre) => pre
+                .split('.')
+                .map(parse_identifier)
+                .collect::<Option<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    /// プレリリースか
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                // 同じバージョン番号ならプレリリースは正式リリースより小さい
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                // 要素ごとに比較し、全て等しければ要素の少ない方が小さい
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// 識別子として使える文字（英数字とハイフン）だけで構成されているか
+///
+/// # Arguments
+///
+/// * `s` - Identifier candidate.
+fn is_valid_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// バージョン番号（先頭 0 は `0` 自体を除き不可）
+///
+/// # Arguments
+///
+/// * `s` - Numeric component.
+fn parse_numeric(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) || (s.len() > 1 && s.starts_with('0'))
+    {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// プレリリース識別子
+///
+/// # Arguments
+///
+/// * `s` - One dot-separated pre-release identifier.
+fn parse_identifier(s: &str) -> Option<Identifier> {
+    if !is_valid_identifier(s) {
+        return None;
+    }
+    if s.chars().all(|c| c.is_ascii_digit()) {
+        return parse_numeric(s).map(Identifier::Numeric);
+    }
+    Some(Identifier::AlphaNumeric(s.to_string()))
+}
+
+/// チャンネル内で最新のリリースタグを選ぶ
+///
+/// SemVer として解釈できないタグは無視する。該当が無ければ `None`。
+///
+/// # Arguments
+///
+/// * `tags` - Tag names of the repository.
+/// * `channel` - Channel to select from.
+pub fn latest_tag<'a>(tags: &'a [String], channel: Channel) -> Option<&'a str> {
+    tags.iter()
+        .filter_map(|tag| SemVer::parse(tag).map(|version| (version, tag)))
+        .filter(|(version, _)| channel.accepts(version))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag.as_str())
+}
+
+/// `from` から `to` へ移るとバージョンが下がるか
+///
+/// どちらかが SemVer として解釈できない（ブランチ名など）場合は判定できないため `false`。
+///
+/// # Arguments
+///
+/// * `from` - Currently installed ref.
+/// * `to` - Ref about to be installed.
+pub fn is_downgrade(from: &str, to: &str) -> bool {
+    match (SemVer::parse(from), SemVer::parse(to)) {
+        (Some(from), Some(to)) => to < from,
+        _ => false,
+    }
+}
+
+/// `from` から `to` へ移るとバージョンが上がるか（更新ありの判定）
+///
+/// 両方が SemVer として解釈できる場合は順序で比較する。どちらかが解釈できない場合は
+/// 順序を判定できないため、前後の空白と `v` 接頭辞を除いた文字列が異なれば更新ありとみなす。
+///
+/// # Arguments
+///
+/// * `from` - Currently installed version.
+/// * `to` - Version offered by the marketplace.
+pub fn is_upgrade(from: &str, to: &str) -> bool {
+    match (SemVer::parse(from), SemVer::parse(to)) {
+        (Some(from), Some(to)) => to > from,
+        _ => {
+            let (from, to) = (strip_version_prefix(from), strip_version_prefix(to));
+            !to.is_empty() && from != to
+        }
+    }
+}
+
+/// バージョン文字列の並び順（一覧のソート用）
+///
+/// SemVer として解釈できるものは順序で比較し、解釈できないものはその後ろに
+/// `v` 接頭辞を除いた文字列順で並べる。
+///
+/// # Arguments
+///
+/// * `a` - Version to compare.
+/// * `b` - Version to compare with.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (SemVer::parse(a), SemVer::parse(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => strip_version_prefix(a).cmp(strip_version_prefix(b)),
+    }
+}
+
+/// 比較用に前後の空白と `v` / `V` 接頭辞を取り除く
+///
+/// # Arguments
+///
+/// * `version` - Version string to normalize.
+fn strip_version_prefix(version: &str) -> &str {
+    let version = version.trim();
+    version
+        .strip_prefix('v')
+        .or_else(|| version.strip_prefix('V'))
+        .unwrap_or(version)
+}
+
+#[cfg(test)]
+#[path = "channel_test.rs"]
+mod tests;
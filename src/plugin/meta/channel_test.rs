@@ -0,0 +1,173 @@
+use super::*;
+
+fn v(tag: &str) -> SemVer {
+    SemVer::parse(tag).unwrap_or_else(|| panic!("failed to parse {}", tag))
+}
+
+fn tags(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn parse_accepts_optional_v_prefix() {
+    assert_eq!(v("v1.2.3"), v("1.2.3"));
+    assert_eq!(v("V1.2.3"), v("1.2.3"));
+    assert!(!v("v1.2.3").is_prerelease());
+}
+
+#[test]
+fn parse_prerelease_and_build_metadata() {
+    assert!(v("v2.0.0-beta.1").is_prerelease());
+    assert!(v("2.0.0-rc.1+build.5").is_prerelease());
+    assert!(!v("2.0.0+build.5").is_prerelease());
+}
+
+#[test]
+fn parse_rejects_invalid_versions() {
+    for tag in [
+        "",
+        "v",
+        "latest",
+        "1",
+        "1.2",
+        "1.2.3.4",
+        "01.2.3",
+        "1.02.3",
+        "1.2.x",
+        "1.2.3-",
+        "1.2.3-beta..1",
+        "1.2.3-01",
+        "1.2.3-beta_1",
+        "1.2.3+",
+        "-1.2.3",
+    ] {
+        assert!(SemVer::parse(tag).is_none(), "{:?} should be rejected", tag);
+    }
+}
+
+#[test]
+fn compare_core_numbers_numerically() {
+    assert!(v("1.10.0") > v("1.9.0"));
+    assert!(v("2.0.0") > v("1.99.99"));
+    assert!(v("1.0.10") > v("1.0.9"));
+}
+
+#[test]
+fn prerelease_is_lower_than_release() {
+    assert!(v("1.0.0-beta.1") < v("1.0.0"));
+    assert!(v("1.0.0-rc.1") < v("1.0.0"));
+    assert!(v("1.0.0-beta.1") > v("0.9.9"));
+}
+
+/// SemVer 2.0.0 仕様 11.4 の例と同じ順序
+#[test]
+fn prerelease_ordering_follows_spec_example() {
+    let ordered = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(v(pair[0]) < v(pair[1]), "{} < {}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn numeric_prerelease_identifiers_compare_numerically() {
+    assert!(v("1.0.0-beta.10") > v("1.0.0-beta.9"));
+    assert!(v("1.0.0-2") > v("1.0.0-1"));
+}
+
+#[test]
+fn numeric_identifier_is_lower_than_alphanumeric() {
+    assert!(v("1.0.0-1") < v("1.0.0-alpha"));
+    assert!(v("1.0.0-alpha.1") < v("1.0.0-alpha.beta"));
+}
+
+#[test]
+fn build_metadata_does_not_affect_ordering() {
+    assert_eq!(v("1.0.0+build.1").cmp(&v("1.0.0+build.2")), Ordering::Equal);
+    assert_eq!(v("1.0.0-beta+exp.sha"), v("1.0.0-beta"));
+}
+
+#[test]
+fn stable_channel_ignores_prereleases() {
+    let tags = tags(&["v1.0.0", "v1.1.0", "v2.0.0-beta.1", "nightly"]);
+    assert_eq!(latest_tag(&tags, Channel::Stable), Some("v1.1.0"));
+}
+
+#[test]
+fn beta_channel_includes_prereleases() {
+    let tags = tags(&["v1.0.0", "v1.1.0", "v2.0.0-beta.1", "v2.0.0-beta.2"]);
+    assert_eq!(latest_tag(&tags, Channel::Beta), Some("v2.0.0-beta.2"));
+}
+
+#[test]
+fn beta_channel_prefers_release_over_its_prerelease() {
+    let tags = tags(&["v2.0.0-rc.1", "v2.0.0", "v2.0.0-beta.3"]);
+    assert_eq!(latest_tag(&tags, Channel::Beta), Some("v2.0.0"));
+}
+
+#[test]
+fn latest_tag_returns_none_without_matching_release() {
+    assert_eq!(latest_tag(&tags(&["v1.0.0-beta.1"]), Channel::Stable), None);
+    assert_eq!(latest_tag(&tags(&["main", "latest"]), Channel::Beta), None);
+    assert_eq!(latest_tag(&[], Channel::Stable), None);
+}
+
+#[test]
+fn is_downgrade_detects_lower_target() {
+    assert!(is_downgrade("v2.0.0-beta.1", "v1.4.0"));
+    assert!(!is_downgrade("v2.0.0-beta.1", "v2.0.0"));
+    assert!(!is_downgrade("v1.4.0", "v1.4.0"));
+    assert!(!is_downgrade("v1.4.0", "v2.0.0-beta.1"));
+}
+
+#[test]
+fn is_downgrade_is_false_for_non_version_refs() {
+    assert!(!is_downgrade("main", "v1.0.0"));
+    assert!(!is_downgrade("v1.0.0", "HEAD"));
+}
+
+#[test]
+fn is_upgrade_compares_semver_versions() {
+    assert!(is_upgrade("1.0.0", "1.2.0"));
+    assert!(is_upgrade("v1.9.0", "1.10.0"));
+    assert!(is_upgrade("1.0.0-beta.1", "1.0.0"));
+    assert!(!is_upgrade("1.2.0", "1.2.0"));
+    assert!(!is_upgrade("1.2.0", "v1.2.0+build.7"));
+    assert!(!is_upgrade("2.0.0", "1.9.9"));
+}
+
+#[test]
+fn is_upgrade_treats_different_non_semver_versions_as_update() {
+    assert!(is_upgrade("2024.09", "2024.10"));
+    assert!(is_upgrade("1.0", "1.0.1"));
+    assert!(is_upgrade("main", "1.0.0"));
+    assert!(!is_upgrade("2024.10", "2024.10"));
+    assert!(!is_upgrade("v1.0", " 1.0 "));
+    assert!(!is_upgrade("1.0", ""));
+}
+
+#[test]
+fn compare_versions_orders_semver_before_other_strings() {
+    use std::cmp::Ordering;
+
+    assert_eq!(compare_versions("1.9.0", "v1.10.0"), Ordering::Less);
+    assert_eq!(compare_versions("1.0.0", "1.0.0-beta.1"), Ordering::Greater);
+    assert_eq!(compare_versions("2.0.0", "main"), Ordering::Less);
+    assert_eq!(compare_versions("2024.10", "2024.09"), Ordering::Greater);
+}
+
+#[test]
+fn channel_serde_uses_lowercase() {
+    assert_eq!(serde_json::to_string(&Channel::Beta).unwrap(), "\"beta\"");
+    let parsed: Channel = serde_json::from_str("\"stable\"").unwrap();
+    assert_eq!(parsed, Channel::Stable);
+    assert_eq!(Channel::default(), Channel::Stable);
+}
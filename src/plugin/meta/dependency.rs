@@ -0,0 +1,171 @@
+//! 依存プラグインの指定
+//!
+//! plugin.json の `dependencies` に `<marketplace>/<plugin>` の形式で書く。
+//! 後ろに単純なバージョン条件（`>=1.2.0` など）を続けると、インストール済み・取得した
+//! バージョンがそれを満たすかを確認する。
+//!
+//! ```json
+//! { "dependencies": ["company-tools/base-plugin", "company-tools/formatter>=1.2.0"] }
+//! ```
+
+use super::channel::SemVer;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// バージョン条件の比較演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionOp {
+    GreaterOrEqual,
+    Greater,
+    LessOrEqual,
+    Less,
+    Equal,
+}
+
+impl VersionOp {
+    /// 表記（`>=` など）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionOp::GreaterOrEqual => ">=",
+            VersionOp::Greater => ">",
+            VersionOp::LessOrEqual => "<=",
+            VersionOp::Less => "<",
+            VersionOp::Equal => "=",
+        }
+    }
+}
+
+/// バージョン条件（`>=1.2.0` など）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    pub op: VersionOp,
+    pub version: String,
+}
+
+impl VersionReq {
+    /// `version` が条件を満たすか
+    ///
+    /// SemVer として解釈できないバージョンは満たさないものとして扱う。
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Installed or downloaded plugin version.
+    pub fn matches(&self, version: &str) -> bool {
+        let (Some(actual), Some(required)) = (SemVer::parse(version), SemVer::parse(&self.version))
+        else {
+            return false;
+        };
+        let ordering = actual.cmp(&required);
+        match self.op {
+            VersionOp::GreaterOrEqual => ordering != Ordering::Less,
+            VersionOp::Greater => ordering == Ordering::Greater,
+            VersionOp::LessOrEqual => ordering != Ordering::Greater,
+            VersionOp::Less => ordering == Ordering::Less,
+            VersionOp::Equal => ordering == Ordering::Equal,
+        }
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op.as_str(), self.version)
+    }
+}
+
+/// 依存プラグイン 1 件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    pub marketplace: String,
+    pub name: String,
+    pub requirement: Option<VersionReq>,
+}
+
+impl Dependency {
+    /// 依存グラフ上で同じプラグインを識別するキー（`<marketplace>/<plugin>`）
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.marketplace, self.name)
+    }
+
+    /// `plm install` に渡すソース（`<plugin>@<marketplace>`）
+    pub fn source(&self) -> String {
+        format!("{}@{}", self.name, self.marketplace)
+    }
+
+    /// `version` が条件を満たすか（条件が無ければ常に `true`）
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Installed or downloaded plugin version.
+    pub fn is_satisfied_by(&self, version: &str) -> bool {
+        self.requirement
+            .as_ref()
+            .is_none_or(|requirement| requirement.matches(version))
+    }
+}
+
+impl FromStr for Dependency {
+    type Err = String;
+
+    /// `<marketplace>/<plugin>[<op><version>]` を解釈する
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid dependency '{}': expected <marketplace>/<plugin>[>=<version>]",
+                s
+            )
+        };
+        let (plugin, requirement) = match s.find(['<', '>', '=']) {
+            Some(index) => (
+                &s[..index],
+                Some(parse_requirement(&s[index..]).ok_or_else(invalid)?),
+            ),
+            None => (s, None),
+        };
+        let (marketplace, name) = plugin.trim().split_once('/').ok_or_else(invalid)?;
+        let is_segment = |part: &str| !part.is_empty() && !part.contains(['/', ' ', '@']);
+        if !is_segment(marketplace) || !is_segment(name) {
+            return Err(invalid());
+        }
+        Ok(Dependency {
+            marketplace: marketplace.to_string(),
+            name: name.to_string(),
+            requirement,
+        })
+    }
+}
+
+impl fmt::Display for Dependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.requirement {
+            Some(requirement) => write!(f, "{}{}", self.key(), requirement),
+            None => f.write_str(&self.key()),
+        }
+    }
+}
+
+/// `>=1.2.0` などのバージョン条件を解釈する（バージョンが SemVer でなければ `None`）
+///
+/// # Arguments
+///
+/// * `s` - Operator followed by a version.
+fn parse_requirement(s: &str) -> Option<VersionReq> {
+    const OPS: &[VersionOp] = &[
+        VersionOp::GreaterOrEqual,
+        VersionOp::LessOrEqual,
+        VersionOp::Greater,
+        VersionOp::Less,
+        VersionOp::Equal,
+    ];
+    let op = *OPS.iter().find(|op| s.starts_with(op.as_str()))?;
+    let version = s[op.as_str().len()..].trim();
+    SemVer::parse(version)?;
+    Some(VersionReq {
+        op,
+        version: version.to_string(),
+    })
+}
+
+#[cfg(test)]
+#[path = "dependency_test.rs"]
+mod tests;
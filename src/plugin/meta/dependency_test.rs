@@ -0,0 +1,65 @@
+use super::*;
+
+fn dep(s: &str) -> Dependency {
+    s.parse().unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[test]
+fn parse_marketplace_and_plugin() {
+    let dependency = dep("company-tools/base-plugin");
+    assert_eq!(dependency.marketplace, "company-tools");
+    assert_eq!(dependency.name, "base-plugin");
+    assert_eq!(dependency.requirement, None);
+    assert_eq!(dependency.key(), "company-tools/base-plugin");
+    assert_eq!(dependency.source(), "base-plugin@company-tools");
+}
+
+#[test]
+fn parse_version_requirement() {
+    let dependency = dep("company-tools/formatter >= 1.2.0");
+    assert_eq!(
+        dependency.requirement,
+        Some(VersionReq {
+            op: VersionOp::GreaterOrEqual,
+            version: "1.2.0".to_string(),
+        })
+    );
+    assert_eq!(dependency.to_string(), "company-tools/formatter>=1.2.0");
+}
+
+#[test]
+fn parse_rejects_malformed_specs() {
+    for spec in [
+        "",
+        "base-plugin",
+        "/base-plugin",
+        "company-tools/",
+        "a/b/c",
+        "company-tools/base@1.0.0",
+        "company-tools/base>=latest",
+        "company-tools/base~1.0.0",
+    ] {
+        assert!(spec.parse::<Dependency>().is_err(), "{:?}", spec);
+    }
+}
+
+#[test]
+fn requirement_compares_semver() {
+    let at_least = dep("mp/base>=1.2.0");
+    assert!(at_least.is_satisfied_by("1.2.0"));
+    assert!(at_least.is_satisfied_by("v1.10.0"));
+    assert!(!at_least.is_satisfied_by("1.1.9"));
+    assert!(!at_least.is_satisfied_by("1.2.0-beta.1"));
+
+    assert!(dep("mp/base>1.2.0").is_satisfied_by("1.2.1"));
+    assert!(!dep("mp/base>1.2.0").is_satisfied_by("1.2.0"));
+    assert!(dep("mp/base<2.0.0").is_satisfied_by("1.9.9"));
+    assert!(dep("mp/base<=2.0.0").is_satisfied_by("2.0.0"));
+    assert!(dep("mp/base=2.0.0").is_satisfied_by("v2.0.0"));
+}
+
+#[test]
+fn unparsable_versions_do_not_satisfy_a_requirement() {
+    assert!(!dep("mp/base>=1.0.0").is_satisfied_by("main"));
+    assert!(dep("mp/base").is_satisfied_by("main"));
+}
@@ -1,14 +1,19 @@
 use crate::error::{PlmError, Result};
 use crate::path_ext::PathExt;
+use crate::plugin::meta::Dependency;
 use crate::scan::{
-    DEFAULT_AGENTS_DIR, DEFAULT_COMMANDS_DIR, DEFAULT_HOOKS_DIR, DEFAULT_INSTRUCTIONS_DIR,
-    DEFAULT_INSTRUCTIONS_FILE, DEFAULT_SKILLS_DIR,
+    ExcludeRules, DEFAULT_AGENTS_DIR, DEFAULT_COMMANDS_DIR, DEFAULT_HOOKS_DIR,
+    DEFAULT_INSTRUCTIONS_DIR, DEFAULT_INSTRUCTIONS_FILE, DEFAULT_SKILLS_DIR,
 };
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 /// プラグイン作者情報
+///
+/// オブジェクト形式に加え、npm 互換の文字列形式（`"Name <email> (url)"`）も受け付ける。
+/// 書き出しは常にオブジェクト形式。
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "AuthorRepr")]
 pub struct Author {
     pub name: String,
     #[serde(default)]
@@ -17,6 +22,91 @@ pub struct Author {
     pub url: Option<String>,
 }
 
+impl Author {
+    /// npm 互換の文字列形式をパースする
+    ///
+    /// `<email>` と `(url)` は省略可能で、空の部分は `None` になる。
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Author string such as `"Jane Doe <jane@example.com> (https://example.com)"`.
+    pub fn parse(text: &str) -> Self {
+        let (rest, url) = take_delimited(text, '(', ')');
+        let (name, email) = take_delimited(&rest, '<', '>');
+        Self {
+            name: name.trim().to_string(),
+            email,
+            url,
+        }
+    }
+}
+
+/// `open`〜`close` で囲まれた部分を取り出し、残りの文字列と合わせて返す
+///
+/// # Arguments
+///
+/// * `text` - Text to search.
+/// * `open` - Opening delimiter.
+/// * `close` - Closing delimiter.
+fn take_delimited(text: &str, open: char, close: char) -> (String, Option<String>) {
+    let Some(start) = text.find(open) else {
+        return (text.to_string(), None);
+    };
+    let Some(len) = text[start..].find(close) else {
+        return (text.to_string(), None);
+    };
+    let inner = text[start + 1..start + len].trim();
+    let rest = format!("{}{}", &text[..start], &text[start + len + 1..]);
+    (rest, (!inner.is_empty()).then(|| inner.to_string()))
+}
+
+/// `author` の入力形式（文字列 / オブジェクト）
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AuthorRepr {
+    Text(String),
+    Object {
+        name: String,
+        #[serde(default)]
+        email: Option<String>,
+        #[serde(default)]
+        url: Option<String>,
+    },
+}
+
+impl From<AuthorRepr> for Author {
+    fn from(repr: AuthorRepr) -> Self {
+        match repr {
+            AuthorRepr::Text(text) => Author::parse(&text),
+            AuthorRepr::Object { name, email, url } => Author { name, email, url },
+        }
+    }
+}
+
+/// `repository` の入力形式（npm 互換: 文字列 / `{ "type": "git", "url": ... }`）
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RepositoryRepr {
+    Url(String),
+    Object { url: String },
+}
+
+/// `repository` を URL 文字列として読み込む
+///
+/// # Arguments
+///
+/// * `deserializer` - Deserializer positioned at the `repository` value.
+fn deserialize_repository<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        Option::<RepositoryRepr>::deserialize(deserializer)?.map(|repr| match repr {
+            RepositoryRepr::Url(url) | RepositoryRepr::Object { url } => url,
+        }),
+    )
+}
+
 /// plugin.json のスキーマ
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PluginManifest {
@@ -28,7 +118,7 @@ pub struct PluginManifest {
     pub author: Option<Author>,
     #[serde(default)]
     pub homepage: Option<String>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_repository")]
     pub repository: Option<String>,
     #[serde(default)]
     pub license: Option<String>,
@@ -50,6 +140,18 @@ pub struct PluginManifest {
     pub mcp_servers: Option<String>,
     #[serde(default, rename = "lspServers")]
     pub lsp_servers: Option<String>,
+
+    /// 配布除外パターン（プラグインルートからの相対グロブ）
+    ///
+    /// 組み込みの除外リスト（`.git` / `node_modules` / `.github/workflows`）に追加される。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
+
+    /// 依存プラグイン（`<marketplace>/<plugin>[>=<version>]`）
+    ///
+    /// install 時に未インストールのものを先にインストールする。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<String>>,
 }
 
 impl PluginManifest {
@@ -126,6 +228,23 @@ impl PluginManifest {
     pub fn hooks_dir(&self, base: &Path) -> PathBuf {
         base.join_or(self.hooks.as_deref(), DEFAULT_HOOKS_DIR)
     }
+
+    /// 配布除外ルールを構築（組み込みリスト + `exclude`）
+    pub fn exclude_rules(&self) -> Result<ExcludeRules> {
+        ExcludeRules::new(self.exclude.as_deref().unwrap_or_default())
+    }
+
+    /// 依存プラグインを解釈する（形式が不正な指定があればエラー）
+    pub fn parsed_dependencies(&self) -> std::result::Result<Vec<Dependency>, String> {
+        self.dependencies
+            .iter()
+            .flatten()
+            .map(|spec| {
+                spec.parse()
+                    .map_err(|e| format!("{} in plugin.json of '{}'", e, self.name))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
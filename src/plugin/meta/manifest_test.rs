@@ -193,3 +193,154 @@ fn parse_legacy_manifest_with_installed_at_ignores_unknown_field() {
     assert_eq!(manifest.name, "legacy-plugin");
     assert_eq!(manifest.version, "1.0.0");
 }
+
+// === exclude ===
+
+#[test]
+fn test_parse_exclude() {
+    let json = r#"{"name": "test", "version": "1.0.0", "exclude": ["docs/**", "examples"]}"#;
+    let manifest = PluginManifest::parse(json).unwrap();
+    assert_eq!(
+        manifest.exclude,
+        Some(vec!["docs/**".to_string(), "examples".to_string()])
+    );
+    let rules = manifest.exclude_rules().unwrap();
+    assert!(rules.is_excluded(Path::new("docs/guide.md")));
+    assert!(rules.is_excluded(Path::new("examples/demo/SKILL.md")));
+}
+
+#[test]
+fn test_exclude_rules_default_to_builtin() {
+    let json = r#"{"name": "test", "version": "1.0.0"}"#;
+    let rules = PluginManifest::parse(json)
+        .unwrap()
+        .exclude_rules()
+        .unwrap();
+    assert!(rules.is_excluded(Path::new("node_modules/pkg")));
+    assert!(!rules.is_excluded(Path::new("docs/guide.md")));
+}
+
+#[test]
+fn test_parse_exclude_wrong_type() {
+    let json = r#"{"name": "test", "version": "1.0.0", "exclude": "docs/**"}"#;
+    assert!(PluginManifest::parse(json).is_err());
+}
+
+// === dependencies ===
+
+#[test]
+fn test_parsed_dependencies() {
+    let json =
+        r#"{"name": "app", "version": "1.0.0", "dependencies": ["mp/base", "mp/fmt>=1.2.0"]}"#;
+    let dependencies = PluginManifest::parse(json)
+        .unwrap()
+        .parsed_dependencies()
+        .unwrap();
+    let keys: Vec<String> = dependencies.iter().map(|d| d.to_string()).collect();
+    assert_eq!(keys, ["mp/base", "mp/fmt>=1.2.0"]);
+}
+
+#[test]
+fn test_parsed_dependencies_rejects_malformed_spec() {
+    let json = r#"{"name": "app", "version": "1.0.0", "dependencies": ["base"]}"#;
+    let error = PluginManifest::parse(json)
+        .unwrap()
+        .parsed_dependencies()
+        .unwrap_err();
+    assert!(error.contains("Invalid dependency 'base'"));
+    assert!(error.ends_with("in plugin.json of 'app'"));
+}
+
+// === メタデータフィールド（author / repository / homepage / keywords） ===
+
+#[test]
+fn test_parse_metadata_object_forms() {
+    let json = r#"{
+        "name": "p",
+        "version": "1.0.0",
+        "author": { "name": "Jane Doe", "email": "jane@example.com", "url": "https://jane.dev" },
+        "homepage": "https://example.com/p",
+        "repository": { "type": "git", "url": "https://github.com/owner/p.git" },
+        "keywords": ["lint", "format"]
+    }"#;
+    let manifest = PluginManifest::parse(json).unwrap();
+
+    let author = manifest.author.unwrap();
+    assert_eq!(author.name, "Jane Doe");
+    assert_eq!(author.email.as_deref(), Some("jane@example.com"));
+    assert_eq!(author.url.as_deref(), Some("https://jane.dev"));
+    assert_eq!(manifest.homepage.as_deref(), Some("https://example.com/p"));
+    assert_eq!(
+        manifest.repository.as_deref(),
+        Some("https://github.com/owner/p.git")
+    );
+    assert_eq!(manifest.keywords.unwrap(), ["lint", "format"]);
+}
+
+#[test]
+fn test_parse_author_npm_string_variants() {
+    let cases = [
+        ("Jane Doe", "Jane Doe", None, None),
+        (
+            "Jane Doe <jane@example.com>",
+            "Jane Doe",
+            Some("jane@example.com"),
+            None,
+        ),
+        (
+            "Jane Doe (https://jane.dev)",
+            "Jane Doe",
+            None,
+            Some("https://jane.dev"),
+        ),
+        (
+            "Jane Doe <jane@example.com> (https://jane.dev)",
+            "Jane Doe",
+            Some("jane@example.com"),
+            Some("https://jane.dev"),
+        ),
+        (
+            "Jane Doe (https://jane.dev) <jane@example.com>",
+            "Jane Doe",
+            Some("jane@example.com"),
+            Some("https://jane.dev"),
+        ),
+        ("  Jane Doe  <>  ", "Jane Doe", None, None),
+        ("Jane <unterminated", "Jane <unterminated", None, None),
+    ];
+    for (input, name, email, url) in cases {
+        let json = format!(
+            r#"{{"name": "p", "version": "1.0.0", "author": {}}}"#,
+            serde_json::to_string(input).unwrap()
+        );
+        let author = PluginManifest::parse(&json).unwrap().author.unwrap();
+        assert_eq!(author.name, name, "{}", input);
+        assert_eq!(author.email.as_deref(), email, "{}", input);
+        assert_eq!(author.url.as_deref(), url, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_repository_string_and_missing_metadata() {
+    let json = r#"{"name": "p", "version": "1.0.0", "repository": "owner/p"}"#;
+    let manifest = PluginManifest::parse(json).unwrap();
+    assert_eq!(manifest.repository.as_deref(), Some("owner/p"));
+    assert!(manifest.author.is_none());
+    assert!(manifest.homepage.is_none());
+    assert!(manifest.keywords.is_none());
+}
+
+#[test]
+fn test_parse_rejects_invalid_author_type() {
+    let json = r#"{"name": "p", "version": "1.0.0", "author": 42}"#;
+    assert!(PluginManifest::parse(json).is_err());
+}
+
+#[test]
+fn test_author_serializes_as_object() {
+    let json = r#"{"name": "p", "version": "1.0.0", "author": "Jane <jane@example.com>"}"#;
+    let manifest = PluginManifest::parse(json).unwrap();
+    let value = serde_json::to_value(&manifest).unwrap();
+    assert_eq!(value["author"]["name"], "Jane");
+    assert_eq!(value["author"]["email"], "jane@example.com");
+}
@@ -3,9 +3,11 @@
 //! プラグインのインストール日時などPLM固有のメタデータを `.plm-meta.json` で管理する。
 //! `plugin.json` は上流成果物として改変しない設計。
 
+use super::Channel;
+use crate::component::{ComponentKind, Scope};
 use crate::error::Result;
 use crate::fs::{FileSystem, RealFs};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
@@ -18,6 +20,8 @@ use tempfile::NamedTempFile;
 #[serde(rename_all = "lowercase")]
 pub enum TargetStatus {
     Enabled,
+    /// `plm disable --keep` で一部の種別だけ配置を残している
+    Partial,
     Disabled,
 }
 
@@ -26,6 +30,7 @@ impl TargetStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             TargetStatus::Enabled => "enabled",
+            TargetStatus::Partial => "partial",
             TargetStatus::Disabled => "disabled",
         }
     }
@@ -38,7 +43,122 @@ impl fmt::Display for TargetStatus {
 }
 
 /// メタデータファイル名
-const META_FILE: &str = ".plm-meta.json";
+pub(crate) const META_FILE: &str = ".plm-meta.json";
+
+/// この日数を過ぎた sandbox は prune 対象として警告する
+pub const SANDBOX_STALE_DAYS: i64 = 7;
+
+/// 試用インストール（`plm install --sandbox`）の記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxInfo {
+    /// 隔離デプロイ先ディレクトリ（絶対パス）
+    pub path: String,
+
+    /// 作成日時（RFC3339形式）
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+
+    /// 配置に成功したターゲット（`--promote` 時の既定ターゲット）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<String>,
+}
+
+impl SandboxInfo {
+    /// 作成からの経過日数（`createdAt` が解釈できない場合は `None`）
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current time.
+    pub fn age_days(&self, now: DateTime<Utc>) -> Option<i64> {
+        DateTime::parse_from_rfc3339(&self.created_at)
+            .ok()
+            .map(|created| (now - created.with_timezone(&Utc)).num_days())
+    }
+
+    /// prune 対象か（作成から [`SANDBOX_STALE_DAYS`] 日以上経過）
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current time.
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.age_days(now)
+            .is_some_and(|days| days >= SANDBOX_STALE_DAYS)
+    }
+}
+
+/// `updateHistory` に残すインストール・更新履歴の件数
+pub const UPDATE_HISTORY_LIMIT: usize = 5;
+
+/// インストール・更新履歴 1 件の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateResult {
+    /// 新規インストール
+    Installed,
+    /// 更新（全ターゲットへ再デプロイ済み）
+    Updated,
+    /// 更新したが、一部のターゲットへの再デプロイに失敗した
+    Partial,
+}
+
+impl UpdateResult {
+    /// Returns the string representation of this result.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateResult::Installed => "installed",
+            UpdateResult::Updated => "updated",
+            UpdateResult::Partial => "partial",
+        }
+    }
+}
+
+impl fmt::Display for UpdateResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// インストール・更新履歴 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateRecord {
+    /// 実行日時（RFC3339形式）
+    pub at: String,
+
+    /// 更新前のバージョン（新規インストールでは `None`）
+    #[serde(
+        default,
+        rename = "fromVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_version: Option<String>,
+
+    /// 更新後のバージョン
+    #[serde(default, rename = "toVersion", skip_serializing_if = "Option::is_none")]
+    pub to_version: Option<String>,
+
+    /// 結果
+    pub result: UpdateResult,
+}
+
+/// デプロイ時に実際に書き込んだ配置物 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedFile {
+    /// 配置先の絶対パス
+    pub path: String,
+
+    /// コンポーネント種別（Skill はディレクトリ、それ以外はファイル）
+    pub kind: ComponentKind,
+}
+
+/// コンポーネント単位で無効化したコンポーネント 1 件（`plm disable --component`）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisabledComponent {
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+
+    /// コンポーネント名（フラット化済み識別子）
+    pub name: String,
+}
 
 /// PLMが管理するプラグインメタデータ
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -100,6 +220,94 @@ pub struct PluginMeta {
         skip_serializing_if = "HashMap::is_empty"
     )]
     pub managed_files: HashMap<String, Vec<String>>,
+
+    /// コマンド呼び出し名のプレフィクス（設定値）
+    #[serde(
+        default,
+        rename = "commandPrefix",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub command_prefix: Option<String>,
+
+    /// 現在デプロイ済みのコマンドに付いているプレフィクス
+    ///
+    /// `commandPrefix` と異なる場合、次回の enable / update で旧名のコマンドを撤去して
+    /// 新しい名前で配置し直す。disable / uninstall はこの値で配置先を解決する。
+    #[serde(
+        default,
+        rename = "deployedCommandPrefix",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deployed_command_prefix: Option<String>,
+
+    /// 試用インストールの配置先と状態（sandbox が無い場合は省略）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<SandboxInfo>,
+
+    /// 追従する更新チャンネル（未指定なら `gitRef` のブランチ / タグをそのまま追う）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<Channel>,
+
+    /// ターゲット別に実際に配置した Skill / Agent / Command（絶対パス）
+    ///
+    /// disable / uninstall はこの記録を正として削除し、記録の無いターゲット（古い
+    /// バージョンで配置したプラグイン）だけキャッシュのスキャン結果から配置先を解決する。
+    /// enable / update のたびに今回配置しなかった古いパスを外し、削除に成功したパスも外す。
+    #[serde(
+        default,
+        rename = "deployedFiles",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub deployed_files: HashMap<String, Vec<DeployedFile>>,
+
+    /// 選択インストール（`plm install --type`）で配置対象にしたコンポーネント種別
+    ///
+    /// `None` なら全種別を配置する。`plm update --redeploy-only` は明示指定が無ければこの記録に従う。
+    #[serde(default, rename = "onlyKinds", skip_serializing_if = "Option::is_none")]
+    pub only_kinds: Option<Vec<ComponentKind>>,
+
+    /// 部分 disable（`plm disable --keep`）で配置を残しているコンポーネント種別（ターゲット別）
+    ///
+    /// `statusByTarget` が `partial` のターゲットだけが持つ。enable / disable で全量を
+    /// 操作したターゲットからは外す。
+    #[serde(
+        default,
+        rename = "keptKinds",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub kept_kinds: HashMap<String, Vec<ComponentKind>>,
+
+    /// バージョン固定（`plm pin`）。固定中は `plm update` と TUI の更新でスキップする
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+
+    /// 固定した時点のバージョン
+    #[serde(
+        default,
+        rename = "pinnedVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pinned_version: Option<String>,
+
+    /// コンポーネント単位で無効化しているコンポーネント
+    ///
+    /// プラグイン全体の enable / update でもこれらは配置しない（`plm enable --all` で解除）。
+    #[serde(
+        default,
+        rename = "disabledComponents",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub disabled_components: Vec<DisabledComponent>,
+
+    /// 直近のインストール・更新履歴（新しい順、最大 [`UPDATE_HISTORY_LIMIT`] 件）
+    ///
+    /// 履歴を持たない旧形式のメタデータは空として読み込む。
+    #[serde(
+        default,
+        rename = "updateHistory",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub update_history: Vec<UpdateRecord>,
 }
 
 impl PluginMeta {
@@ -118,8 +326,60 @@ impl PluginMeta {
     ///
     /// * `target` - Target name.
     /// * `status` - Status value (`TargetStatus::Enabled` or `TargetStatus::Disabled`).
+    ///
+    /// 部分 disable の記録（`keptKinds`）は外す。部分 disable は [`PluginMeta::set_partial`] で記録する。
     pub fn set_status(&mut self, target: &str, status: TargetStatus) {
         self.status_by_target.insert(target.to_string(), status);
+        self.kept_kinds.remove(target);
+    }
+
+    /// 指定ターゲットを部分 disable（`kinds` の種別だけ配置を残す）として記録
+    ///
+    /// 既に部分 disable 中のターゲットでは、前回残した種別との共通部分だけが残る。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target name.
+    /// * `kinds` - Component kinds left deployed on the target.
+    pub fn set_partial(&mut self, target: &str, kinds: &[ComponentKind]) {
+        let kept = match self.deploy_kinds(target) {
+            Some(previous) => kinds
+                .iter()
+                .copied()
+                .filter(|k| previous.contains(k))
+                .collect(),
+            None => kinds.to_vec(),
+        };
+        self.status_by_target
+            .insert(target.to_string(), TargetStatus::Partial);
+        self.kept_kinds.insert(target.to_string(), kept);
+    }
+
+    /// 部分 disable で残している種別（全ターゲットの和集合）
+    ///
+    /// いずれかのターゲットが enabled の場合はプラグインとして有効扱いのため、部分 disable
+    /// 中のターゲットが無い場合と同じく `None` を返す。
+    pub fn partial_kinds(&self) -> Option<Vec<ComponentKind>> {
+        if self.any_enabled() || self.kept_kinds.is_empty() {
+            return None;
+        }
+        let kinds = ComponentKind::all()
+            .iter()
+            .copied()
+            .filter(|k| self.kept_kinds.values().any(|kept| kept.contains(k)))
+            .collect();
+        Some(kinds)
+    }
+
+    /// 指定ターゲットへ再配置するコンポーネント種別（`None` で全種別）
+    ///
+    /// 部分 disable 中のターゲットは残している種別だけを配置する。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target name.
+    pub fn deploy_kinds(&self, target: &str) -> Option<&[ComponentKind]> {
+        self.kept_kinds.get(target).map(Vec::as_slice)
     }
 
     /// 指定ターゲットが有効化されているか
@@ -150,6 +410,40 @@ impl PluginMeta {
         self.updated_at = Some(Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string());
     }
 
+    /// インストール・更新を履歴の先頭に記録し、`updatedAt` を同じ日時にそろえる
+    ///
+    /// 履歴は新しいものから [`UPDATE_HISTORY_LIMIT`] 件だけ残す。
+    ///
+    /// # Arguments
+    ///
+    /// * `from_version` - Version before the update (`None` for a fresh install).
+    /// * `to_version` - Version after the update, if known.
+    /// * `result` - Result of the install or update.
+    pub fn record_update(
+        &mut self,
+        from_version: Option<&str>,
+        to_version: Option<&str>,
+        result: UpdateResult,
+    ) {
+        let at = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        self.updated_at = Some(at.clone());
+        self.update_history.insert(
+            0,
+            UpdateRecord {
+                at,
+                from_version: from_version.map(str::to_string),
+                to_version: to_version.map(str::to_string),
+                result,
+            },
+        );
+        self.update_history.truncate(UPDATE_HISTORY_LIMIT);
+    }
+
+    /// 直近のインストール・更新（履歴が無ければ `None`）
+    pub fn last_update(&self) -> Option<&UpdateRecord> {
+        self.update_history.first()
+    }
+
     /// 有効なターゲット一覧を取得
     pub fn enabled_targets(&self) -> Vec<&str> {
         self.status_by_target
@@ -159,6 +453,53 @@ impl PluginMeta {
             .collect()
     }
 
+    /// 配置物が残っているターゲット一覧（enabled と部分 disable）を取得
+    pub fn deployed_targets(&self) -> Vec<&str> {
+        self.status_by_target
+            .iter()
+            .filter(|(_, status)| **status != TargetStatus::Disabled)
+            .map(|(target, _)| target.as_str())
+            .collect()
+    }
+
+    /// 配置記録（`deployedFiles`）から配置先のスコープを求める
+    ///
+    /// `project_root` 配下のパスは Project、それ以外（ホーム配下のターゲットディレクトリ）は
+    /// Personal とみなす。記録が無い場合は空を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root the plugin is viewed from.
+    pub fn deployed_scopes(&self, project_root: &Path) -> Vec<Scope> {
+        let in_project: Vec<bool> = self
+            .deployed_files
+            .values()
+            .flatten()
+            .map(|file| Path::new(&file.path).starts_with(project_root))
+            .collect();
+        [(Scope::Project, true), (Scope::Personal, false)]
+            .into_iter()
+            .filter(|(_, project)| in_project.contains(project))
+            .map(|(scope, _)| scope)
+            .collect()
+    }
+
+    /// 現在のバージョンで固定する
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - Version installed at the time of pinning.
+    pub fn pin(&mut self, version: &str) {
+        self.pinned = true;
+        self.pinned_version = Some(version.to_string());
+    }
+
+    /// 固定を解除する
+    pub fn unpin(&mut self) {
+        self.pinned = false;
+        self.pinned_version = None;
+    }
+
     /// ソースリポジトリを設定
     ///
     /// # Arguments
@@ -213,6 +554,103 @@ impl PluginMeta {
             .get(target)
             .is_some_and(|paths| paths.iter().any(|p| p.as_str() == path_str.as_ref()))
     }
+
+    /// `target` の配置記録に `path` を追加する。
+    ///
+    /// # Returns
+    /// 記録が変わった場合は `true`（同じパスが同じ種別で記録済みなら `false`）。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target name.
+    /// * `kind` - Kind of the deployed component.
+    /// * `path` - Absolute destination path that was written.
+    pub fn record_deployed_file(&mut self, target: &str, kind: ComponentKind, path: &Path) -> bool {
+        let path = path.to_string_lossy().into_owned();
+        let entry = self.deployed_files.entry(target.to_string()).or_default();
+        match entry.iter_mut().find(|f| f.path == path) {
+            Some(file) if file.kind == kind => false,
+            Some(file) => {
+                file.kind = kind;
+                true
+            }
+            None => {
+                entry.push(DeployedFile { path, kind });
+                true
+            }
+        }
+    }
+
+    /// `target` の配置記録から `path` を外す。記録が空になったターゲットは記録ごと消す。
+    ///
+    /// # Returns
+    /// 記録から外した場合は `true`。
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target name.
+    /// * `path` - Absolute destination path that was removed or replaced.
+    pub fn forget_deployed_file(&mut self, target: &str, path: &Path) -> bool {
+        let path = path.to_string_lossy();
+        let Some(entry) = self.deployed_files.get_mut(target) else {
+            return false;
+        };
+        let before = entry.len();
+        entry.retain(|f| f.path != path);
+        let removed = entry.len() != before;
+        if entry.is_empty() {
+            self.deployed_files.remove(target);
+        }
+        removed
+    }
+
+    /// 指定コンポーネントをコンポーネント単位で無効化しているか
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind.
+    /// * `name` - Component name (flattened identifier).
+    pub fn is_component_disabled(&self, kind: ComponentKind, name: &str) -> bool {
+        self.disabled_components
+            .iter()
+            .any(|c| c.kind == kind && c.name == name)
+    }
+
+    /// 指定コンポーネントを無効化済みとして記録する
+    ///
+    /// # Returns
+    /// 新たに記録した場合は `true`（記録済みなら `false`）。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind.
+    /// * `name` - Component name (flattened identifier).
+    pub fn disable_component(&mut self, kind: ComponentKind, name: &str) -> bool {
+        if self.is_component_disabled(kind, name) {
+            return false;
+        }
+        self.disabled_components.push(DisabledComponent {
+            kind,
+            name: name.to_string(),
+        });
+        true
+    }
+
+    /// 指定コンポーネントの無効化の記録を外す
+    ///
+    /// # Returns
+    /// 記録から外した場合は `true`。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind.
+    /// * `name` - Component name (flattened identifier).
+    pub fn enable_component(&mut self, kind: ComponentKind, name: &str) -> bool {
+        let before = self.disabled_components.len();
+        self.disabled_components
+            .retain(|c| !(c.kind == kind && c.name == name));
+        self.disabled_components.len() != before
+    }
 }
 
 /// installedAt の正規化
@@ -381,12 +819,32 @@ pub fn is_enabled_indexed(
     plugin_name: &str,
     deployed_plugins: &HashSet<String>,
 ) -> bool {
-    if let Some(plugin_meta) = load_meta(cache_path) {
-        if !plugin_meta.status_by_target.is_empty() {
-            return plugin_meta.any_enabled();
-        }
+    is_enabled_with_meta(
+        load_meta(cache_path).as_ref(),
+        plugin_name,
+        deployed_plugins,
+    )
+}
+
+/// 読み込み済みのメタデータで [`is_enabled_indexed`] と同じ判定を行う
+///
+/// メタデータを他の用途（更新日時など）にも使う経路で、`.plm-meta.json` を
+/// 二重に読まないために使う。
+///
+/// # Arguments
+///
+/// * `plugin_meta` - Metadata loaded by [`load_meta`], if any.
+/// * `plugin_name` - `PluginManifest.name`
+/// * `deployed_plugins` - 配置済みコンポーネントを持つ plugin_name の集合
+pub fn is_enabled_with_meta(
+    plugin_meta: Option<&PluginMeta>,
+    plugin_name: &str,
+    deployed_plugins: &HashSet<String>,
+) -> bool {
+    match plugin_meta {
+        Some(m) if !m.status_by_target.is_empty() => m.any_enabled(),
+        _ => deployed_plugins.contains(plugin_name),
     }
-    deployed_plugins.contains(plugin_name)
 }
 
 /// `deployed` の `flattened_name` 集合と既知の `plugin_names` から、
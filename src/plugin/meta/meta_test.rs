@@ -635,6 +635,25 @@ fn test_is_enabled_indexed_falls_back_to_index() {
     ));
 }
 
+#[test]
+fn test_is_enabled_with_meta_ignores_index_when_status_present() {
+    let mut meta = PluginMeta::default();
+    meta.set_status("codex", TargetStatus::Disabled);
+    let deployed_plugins: HashSet<String> = ["test-plugin".to_string()].into();
+
+    assert!(!is_enabled_with_meta(
+        Some(&meta),
+        "test-plugin",
+        &deployed_plugins
+    ));
+    assert!(is_enabled_with_meta(
+        Some(&PluginMeta::default()),
+        "test-plugin",
+        &deployed_plugins
+    ));
+    assert!(is_enabled_with_meta(None, "test-plugin", &deployed_plugins));
+}
+
 // =============================================================================
 // managed_files tests
 // =============================================================================
@@ -660,3 +679,270 @@ fn add_managed_file_returns_false_when_path_already_present() {
         "重複時は配列が増えない"
     );
 }
+
+// =============================================================================
+// sandbox tests
+// =============================================================================
+
+fn sandbox_created_at(created_at: &str) -> SandboxInfo {
+    SandboxInfo {
+        path: "/project/.plm/sandbox/formatter".to_string(),
+        created_at: created_at.to_string(),
+        targets: vec!["codex".to_string()],
+    }
+}
+
+#[test]
+fn sandbox_round_trips_with_camel_case_keys() {
+    let meta = PluginMeta {
+        sandbox: Some(sandbox_created_at("2026-01-01T00:00:00Z")),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains("\"sandbox\""));
+    assert!(json.contains("\"createdAt\""));
+
+    let parsed: PluginMeta = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.sandbox, meta.sandbox);
+}
+
+#[test]
+fn sandbox_omitted_when_absent() {
+    let json = serde_json::to_string(&PluginMeta::default()).unwrap();
+    assert!(!json.contains("sandbox"));
+}
+
+#[test]
+fn sandbox_is_stale_after_seven_days() {
+    let now = DateTime::parse_from_rfc3339("2026-01-08T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    assert!(sandbox_created_at("2026-01-01T00:00:00Z").is_stale(now));
+    assert!(!sandbox_created_at("2026-01-01T00:00:01Z").is_stale(now));
+    assert!(!sandbox_created_at("not a date").is_stale(now));
+}
+
+// =============================================================================
+// channel tests
+// =============================================================================
+
+#[test]
+fn channel_round_trips_and_is_omitted_when_absent() {
+    let meta = PluginMeta {
+        channel: Some(Channel::Beta),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains("\"channel\":\"beta\""));
+    let parsed: PluginMeta = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.channel, Some(Channel::Beta));
+
+    let json = serde_json::to_string(&PluginMeta::default()).unwrap();
+    assert!(!json.contains("channel"));
+}
+
+// =============================================================================
+// deployed_files tests
+// =============================================================================
+
+#[test]
+fn deployed_files_round_trip_and_are_omitted_when_empty() {
+    let mut meta = PluginMeta::default();
+    meta.record_deployed_file(
+        "codex",
+        ComponentKind::Skill,
+        Path::new("/p/.codex/skills/review"),
+    );
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains("\"deployedFiles\""));
+    assert!(json.contains("\"kind\":\"skill\""));
+    let parsed: PluginMeta = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.deployed_files, meta.deployed_files);
+
+    let json = serde_json::to_string(&PluginMeta::default()).unwrap();
+    assert!(!json.contains("deployedFiles"));
+}
+
+#[test]
+fn record_deployed_file_reports_changes_only() {
+    let mut meta = PluginMeta::default();
+    let path = Path::new("/p/.codex/prompts/deploy.md");
+
+    assert!(meta.record_deployed_file("codex", ComponentKind::Command, path));
+    assert!(!meta.record_deployed_file("codex", ComponentKind::Command, path));
+    assert!(meta.record_deployed_file("codex", ComponentKind::Agent, path));
+    assert_eq!(meta.deployed_files["codex"].len(), 1);
+    assert_eq!(meta.deployed_files["codex"][0].kind, ComponentKind::Agent);
+}
+
+#[test]
+fn forget_deployed_file_drops_empty_targets() {
+    let mut meta = PluginMeta::default();
+    let review = Path::new("/p/.cursor/skills/review");
+    let lint = Path::new("/p/.cursor/skills/lint");
+    meta.record_deployed_file("cursor", ComponentKind::Skill, review);
+    meta.record_deployed_file("cursor", ComponentKind::Skill, lint);
+
+    assert!(meta.forget_deployed_file("cursor", review));
+    assert!(!meta.forget_deployed_file("cursor", review));
+    assert_eq!(meta.deployed_files["cursor"].len(), 1);
+    assert!(meta.forget_deployed_file("cursor", lint));
+    assert!(meta.deployed_files.is_empty());
+    assert!(!meta.forget_deployed_file("codex", lint));
+}
+
+#[test]
+fn disabled_components_round_trip_and_toggle() {
+    let mut meta = PluginMeta::default();
+
+    assert!(meta.disable_component(ComponentKind::Skill, "tools_review"));
+    assert!(!meta.disable_component(ComponentKind::Skill, "tools_review"));
+    assert!(meta.is_component_disabled(ComponentKind::Skill, "tools_review"));
+    assert!(!meta.is_component_disabled(ComponentKind::Agent, "tools_review"));
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(
+        json.contains("\"disabledComponents\":[{\"kind\":\"skill\",\"name\":\"tools_review\"}]")
+    );
+    let parsed: PluginMeta = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.disabled_components, meta.disabled_components);
+
+    assert!(meta.enable_component(ComponentKind::Skill, "tools_review"));
+    assert!(!meta.enable_component(ComponentKind::Skill, "tools_review"));
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(!json.contains("disabledComponents"));
+}
+
+#[test]
+fn pin_persists_as_json_and_unpin_drops_keys() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut meta = PluginMeta::default();
+
+    meta.pin("1.2.0");
+    write_meta(temp_dir.path(), &meta).unwrap();
+
+    let json = fs::read_to_string(temp_dir.path().join(".plm-meta.json")).unwrap();
+    assert!(json.contains(r#""pinned": true"#), "{}", json);
+    assert!(json.contains(r#""pinnedVersion": "1.2.0""#), "{}", json);
+    let mut loaded = load_meta(temp_dir.path()).unwrap();
+    assert!(loaded.pinned);
+    assert_eq!(loaded.pinned_version.as_deref(), Some("1.2.0"));
+
+    loaded.unpin();
+    let json = serde_json::to_string(&loaded).unwrap();
+    assert!(!json.contains("pinned"), "{}", json);
+}
+
+// =============================================================================
+// partial disable tests
+// =============================================================================
+
+#[test]
+fn set_partial_records_kept_kinds_until_status_is_reset() {
+    let mut meta = PluginMeta::default();
+    meta.set_partial("codex", &[ComponentKind::Instruction, ComponentKind::Skill]);
+    meta.set_partial(
+        "codex",
+        &[ComponentKind::Instruction, ComponentKind::Command],
+    );
+    meta.set_status("copilot", TargetStatus::Disabled);
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains(r#""codex":"partial""#), "{}", json);
+    assert!(
+        json.contains(r#""keptKinds":{"codex":["instruction"]}"#),
+        "{}",
+        json
+    );
+    assert_eq!(
+        meta.deploy_kinds("codex"),
+        Some(&[ComponentKind::Instruction][..])
+    );
+    assert_eq!(meta.deploy_kinds("copilot"), None);
+    assert_eq!(meta.partial_kinds(), Some(vec![ComponentKind::Instruction]));
+    assert_eq!(meta.deployed_targets(), vec!["codex"]);
+
+    meta.set_status("codex", TargetStatus::Enabled);
+    assert!(meta.kept_kinds.is_empty());
+    assert_eq!(meta.partial_kinds(), None);
+}
+
+#[test]
+fn deployed_scopes_classifies_recorded_paths_by_project_root() {
+    let mut meta = PluginMeta::default();
+    let project = Path::new("/work/app");
+    assert!(meta.deployed_scopes(project).is_empty());
+
+    meta.record_deployed_file(
+        "codex",
+        ComponentKind::Skill,
+        Path::new("/work/app/.codex/skills/lint"),
+    );
+    assert_eq!(meta.deployed_scopes(project), vec![Scope::Project]);
+
+    meta.record_deployed_file(
+        "copilot",
+        ComponentKind::Agent,
+        Path::new("/home/me/.copilot/agents/review.agent.md"),
+    );
+    assert_eq!(
+        meta.deployed_scopes(project),
+        vec![Scope::Project, Scope::Personal]
+    );
+}
+
+// =============================================================================
+// update_history tests
+// =============================================================================
+
+#[test]
+fn update_history_defaults_to_empty_for_old_meta_format() {
+    let json = r#"{"installedAt":"2025-01-15T10:30:00Z","updatedAt":"2025-02-01T00:00:00Z"}"#;
+
+    let meta: PluginMeta = serde_json::from_str(json).unwrap();
+
+    assert!(meta.update_history.is_empty());
+    assert!(meta.last_update().is_none());
+    assert_eq!(meta.updated_at.as_deref(), Some("2025-02-01T00:00:00Z"));
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(!json.contains("updateHistory"));
+}
+
+#[test]
+fn record_update_keeps_latest_five_newest_first() {
+    let mut meta = PluginMeta::default();
+    meta.record_update(None, Some("1.0.0"), UpdateResult::Installed);
+    for minor in 1..=5 {
+        let from = format!("1.{}.0", minor - 1);
+        let to = format!("1.{}.0", minor);
+        meta.record_update(Some(&from), Some(&to), UpdateResult::Updated);
+    }
+
+    assert_eq!(meta.update_history.len(), UPDATE_HISTORY_LIMIT);
+    let latest = meta.last_update().unwrap();
+    assert_eq!(latest.from_version.as_deref(), Some("1.4.0"));
+    assert_eq!(latest.to_version.as_deref(), Some("1.5.0"));
+    assert_eq!(meta.updated_at.as_deref(), Some(latest.at.as_str()));
+    assert!(meta
+        .update_history
+        .iter()
+        .all(|r| r.result == UpdateResult::Updated));
+}
+
+#[test]
+fn update_history_round_trips_with_camel_case_keys() {
+    let mut meta = PluginMeta::default();
+    meta.record_update(Some("1.1.0"), Some("1.2.0"), UpdateResult::Partial);
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains("\"updateHistory\""));
+    assert!(json.contains("\"fromVersion\":\"1.1.0\""));
+    assert!(json.contains("\"toVersion\":\"1.2.0\""));
+    assert!(json.contains("\"result\":\"partial\""));
+    let parsed: PluginMeta = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.update_history, meta.update_history);
+}
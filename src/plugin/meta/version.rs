@@ -3,8 +3,9 @@
 //! GitHub APIを使用して最新のコミットSHAを取得する。
 //! `plm update` と `plm list --outdated` の両方で使用される。
 
-use crate::error::PlmError;
+use crate::error::{PlmError, Result};
 use crate::host::{HostClient, HostKind};
+use crate::plugin::meta::{latest_tag, Channel};
 use crate::plugin::PluginMeta;
 use crate::repo::Repo;
 use serde::Serialize;
@@ -193,9 +194,32 @@ fn error_message(error: &PlmError) -> String {
     }
 }
 
+/// チャンネルの最新リリースタグを取得
+///
+/// # Arguments
+///
+/// * `client` - Host client used to list tags.
+/// * `repo` - Repository whose tags are listed (its ref is ignored).
+/// * `channel` - Channel to select the release from.
+pub async fn fetch_channel_tag(
+    client: &dyn HostClient,
+    repo: &Repo,
+    channel: Channel,
+) -> Result<String> {
+    let tags = client.list_tags(repo).await?;
+    latest_tag(&tags, channel).map(String::from).ok_or_else(|| {
+        PlmError::InvalidSource(format!(
+            "no {} release tag (e.g. v1.2.3) found in {}",
+            channel,
+            repo.full_name()
+        ))
+    })
+}
+
 /// 単一プラグインのリモートバージョンを取得
 ///
-/// 1. `meta.git_ref` を取得（未記録時は `client.get_default_branch()` を使用）
+/// 1. `meta.channel` があればそのチャンネルの最新リリースタグ、なければ `meta.git_ref` を
+///    取得（未記録時は `client.get_default_branch()` を使用）
 /// 2. `client.get_commit_sha()` で最新 SHA を取得
 ///
 /// # Arguments
@@ -217,9 +241,17 @@ pub async fn fetch_remote_version(
 
     // git_ref 未記録時はリモートのデフォルトブランチにフォールバックする
     let repo_for_default = Repo::new(HostKind::GitHub, owner, name, None);
-    let git_ref = match &meta.git_ref {
-        Some(r) => r.clone(),
-        None => match client.get_default_branch(&repo_for_default).await {
+    let git_ref = match (meta.channel, &meta.git_ref) {
+        (Some(channel), _) => match fetch_channel_tag(client, &repo_for_default, channel).await {
+            Ok(tag) => tag,
+            Err(e) => {
+                return VersionQueryOutcome::Failed {
+                    message: error_message(&e),
+                }
+            }
+        },
+        (None, Some(r)) => r.clone(),
+        (None, None) => match client.get_default_branch(&repo_for_default).await {
             Ok(branch) => branch,
             Err(e) => {
                 return VersionQueryOutcome::Failed {
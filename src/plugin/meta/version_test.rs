@@ -13,6 +13,7 @@ enum MockResult {
 struct MockHostClient {
     default_branch: String,
     commit_sha_result: MockResult,
+    tags: Vec<String>,
 }
 
 impl MockHostClient {
@@ -20,9 +21,15 @@ impl MockHostClient {
         Self {
             default_branch: "main".to_string(),
             commit_sha_result: MockResult::Ok(sha.to_string()),
+            tags: vec![],
         }
     }
 
+    fn with_tags(mut self, tags: &[&str]) -> Self {
+        self.tags = tags.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
     fn with_error(status: u16, message: &str) -> Self {
         Self {
             default_branch: "main".to_string(),
@@ -30,6 +37,7 @@ impl MockHostClient {
                 status,
                 message: message.to_string(),
             },
+            tags: vec![],
         }
     }
 }
@@ -80,6 +88,14 @@ impl HostClient for MockHostClient {
     ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
         Box::pin(async { Ok(String::new()) })
     }
+
+    fn list_tags<'a>(
+        &'a self,
+        _repo: &'a Repo,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<String>>> + Send + 'a>> {
+        let tags = self.tags.clone();
+        Box::pin(async move { Ok(tags) })
+    }
 }
 
 fn create_meta(
@@ -156,6 +172,29 @@ async fn test_fetch_remote_version_not_found() {
     assert_eq!(result.error_message(), Some("Repository or ref not found"));
 }
 
+#[tokio::test]
+async fn test_fetch_remote_version_follows_channel_tag() {
+    let mut meta = create_meta(Some("owner/repo"), Some("v1.0.0"), Some("abc123"));
+    meta.channel = Some(Channel::Beta);
+    let client = MockHostClient::success("def456").with_tags(&["v1.0.0", "v1.1.0-beta.1"]);
+
+    let result = fetch_remote_version(&meta, &client).await;
+
+    assert_eq!(result.as_found().unwrap().git_ref, "v1.1.0-beta.1");
+}
+
+#[tokio::test]
+async fn test_fetch_channel_tag_without_release_fails() {
+    let repo = Repo::new(HostKind::GitHub, "owner", "repo", None);
+    let client = MockHostClient::success("abc123").with_tags(&["v2.0.0-rc.1", "main"]);
+
+    let err = fetch_channel_tag(&client, &repo, Channel::Stable)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("no stable release tag"));
+}
+
 // ========================================
 // UpgradeState unit tests
 // ========================================
@@ -0,0 +1,312 @@
+//! 配置記録（`~/.plm/placements.json`）
+//!
+//! plm が配置したファイル／ディレクトリの絶対パスと、所有するプラグインを記録する。
+//! プラグインのキャッシュは手動で消されることがあるため、`.plm-meta.json` ではなく
+//! キャッシュ外の状態ディレクトリに保存し、孤立ファイル（所有プラグインが消えた
+//! 配置物）の検出に使う。
+//!
+//! 記録の無いファイルは plm 管理外として扱い、孤立ファイルの削除対象にしない。
+//! 共有ファイル（`AGENTS.md` や `hooks.json`）は複数の所有者を持ちうるため記録しない。
+//! 記録を更新するたびに、プロジェクト内の配置物を `.plm/deployed.json` にも書き出す
+//! （[`super::DeployedManifest`]）。
+
+use super::DeployedManifest;
+use crate::component::ComponentKind;
+use crate::error::{PlmError, Result};
+use crate::marketplace::MarketplaceRef;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+
+/// 配置記録 1 件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlacementRecord {
+    /// 配置先の絶対パス
+    pub path: PathBuf,
+    /// ターゲット名（例: `copilot`）
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// マーケットプレイス名（GitHub 直接インストールは `github`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marketplace: Option<String>,
+    /// プラグインの id（キャッシュディレクトリ名）
+    pub plugin: String,
+}
+
+impl PlacementRecord {
+    /// 配置記録の対象となる種別か
+    ///
+    /// Skill / Agent / Command はプラグインごとに専用のパスへ配置されるため記録する。
+    /// Instruction / Hook は共有ファイルへマージされうるので対象外。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind.
+    pub fn is_tracked(kind: ComponentKind) -> bool {
+        matches!(
+            kind,
+            ComponentKind::Skill | ComponentKind::Agent | ComponentKind::Command
+        )
+    }
+}
+
+impl PlacementRecord {
+    /// 同じプラグインの配置記録か（マーケットプレイス未指定は `github` とみなす）
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - Marketplace name of the plugin being deployed.
+    /// * `plugin` - Plugin id (cache directory name).
+    pub fn is_owned_by(&self, marketplace: Option<&str>, plugin: &str) -> bool {
+        self.plugin == plugin
+            && MarketplaceRef::from_option(self.marketplace.as_deref()).dir_name()
+                == MarketplaceRef::from_option(marketplace).dir_name()
+    }
+
+    /// 所有プラグインの表示名（`<marketplace>/<plugin>`）
+    pub fn owner_label(&self) -> String {
+        format!(
+            "{}/{}",
+            MarketplaceRef::from_option(self.marketplace.as_deref()).dir_name(),
+            self.plugin
+        )
+    }
+}
+
+/// 別プラグインの配置物との衝突（配置をスキップしたコンポーネント）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentConflict {
+    /// ターゲット名（例: `copilot`）
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// コンポーネント名
+    pub name: String,
+    /// 衝突した配置先
+    pub path: PathBuf,
+    /// 既存の配置物を所有するプラグイン（`<marketplace>/<plugin>`）
+    pub owner: String,
+}
+
+impl ComponentConflict {
+    /// 警告メッセージ（1 行）
+    pub fn message(&self) -> String {
+        format!(
+            "{} {} '{}' skipped: {} is owned by {} (use --overwrite to replace it)",
+            self.target,
+            self.kind.as_str(),
+            self.name,
+            self.path.display(),
+            self.owner
+        )
+    }
+    /// 衝突の件数をまとめた 1 行（TUI 用、衝突が無ければ `None`）
+    ///
+    /// # Arguments
+    ///
+    /// * `conflicts` - Conflicts reported by a deploy.
+    pub fn summary(conflicts: &[ComponentConflict]) -> Option<String> {
+        if conflicts.is_empty() {
+            return None;
+        }
+        let owners: BTreeSet<&str> = conflicts.iter().map(|c| c.owner.as_str()).collect();
+        Some(format!(
+            "{} component(s) skipped: already deployed by {} (use --overwrite to replace)",
+            conflicts.len(),
+            owners.into_iter().collect::<Vec<_>>().join(", ")
+        ))
+    }
+}
+
+/// 永続化形式
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PlacementsFile {
+    #[serde(default)]
+    placements: Vec<PlacementRecord>,
+}
+
+/// 配置記録の集合
+#[derive(Debug)]
+pub struct PlacementLedger {
+    path: PathBuf,
+    records: Vec<PlacementRecord>,
+}
+
+impl PlacementLedger {
+    /// デフォルトパス（`~/.plm/placements.json`）から読み込む
+    pub fn load() -> Result<Self> {
+        let paths = crate::env::PlmPaths::new()?;
+        Self::load_from(paths.placements_json())
+    }
+
+    /// 指定パスから読み込む（ファイルが無ければ空）
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Ledger file path.
+    pub fn load_from(path: PathBuf) -> Result<Self> {
+        let records = match fs::read_to_string(&path) {
+            Ok(content) => {
+                serde_json::from_str::<PlacementsFile>(&content)
+                    .map_err(|e| {
+                        PlmError::General(format!("Failed to parse placements.json: {}", e))
+                    })?
+                    .placements
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(PlmError::Io(e)),
+        };
+        Ok(Self { path, records })
+    }
+
+    /// 記録一覧
+    pub fn records(&self) -> &[PlacementRecord] {
+        &self.records
+    }
+
+    /// 配置先が既に存在し、別のプラグインが所有している場合にその記録を返す
+    ///
+    /// 記録の無い既存ファイルは plm 管理外として扱い、衝突とはみなさない。
+    /// 同じプラグインの再デプロイも衝突ではない。
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path about to be written.
+    /// * `marketplace` - Marketplace name of the plugin being deployed.
+    /// * `plugin` - Plugin id (cache directory name).
+    pub fn foreign_owner(
+        &self,
+        path: &Path,
+        marketplace: Option<&str>,
+        plugin: &str,
+    ) -> Option<&PlacementRecord> {
+        if !path.exists() {
+            return None;
+        }
+        self.records
+            .iter()
+            .find(|r| r.path == path)
+            .filter(|r| !r.is_owned_by(marketplace, plugin))
+    }
+
+    /// 記録を追加する（同じパスの記録は置き換える）
+    ///
+    /// 内容が変わった場合は `true` を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - Placement to record.
+    pub fn record(&mut self, record: PlacementRecord) -> bool {
+        match self.records.iter_mut().find(|r| r.path == record.path) {
+            Some(existing) if *existing == record => false,
+            Some(existing) => {
+                *existing = record;
+                true
+            }
+            None => {
+                self.records.push(record);
+                true
+            }
+        }
+    }
+
+    /// パスの記録を削除する
+    ///
+    /// 削除した場合は `true` を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path whose record is removed.
+    pub fn forget(&mut self, path: &Path) -> bool {
+        let before = self.records.len();
+        self.records.retain(|r| r.path != path);
+        self.records.len() != before
+    }
+
+    /// 配置先が既に存在しない記録を削除し、削除件数を返す
+    pub fn prune_missing(&mut self) -> usize {
+        let before = self.records.len();
+        self.records.retain(|r| r.path.exists());
+        before - self.records.len()
+    }
+
+    /// 保存する（アトミック書き込み）
+    pub fn save(&self) -> Result<()> {
+        let parent = self.path.parent().unwrap_or(Path::new("."));
+        fs::create_dir_all(parent)?;
+
+        let file = PlacementsFile {
+            placements: self.records.clone(),
+        };
+        let mut temp_file = NamedTempFile::new_in(parent)?;
+        temp_file.write_all(serde_json::to_string_pretty(&file)?.as_bytes())?;
+        temp_file
+            .persist(&self.path)
+            .map_err(|e| PlmError::Io(e.error))?;
+        Ok(())
+    }
+}
+
+/// 配置記録を更新し、プロジェクトのデプロイマニフェストを作り直す（best-effort）
+///
+/// 記録の失敗で配置自体を失敗扱いにはしない（警告のみ）。記録が欠けたファイルは
+/// 孤立ファイル検出の対象外になるだけで、誤って削除されることはない。
+///
+/// # Arguments
+///
+/// * `placed` - Placements to add.
+/// * `removed` - Destination paths that were removed.
+/// * `project_root` - Project root the deploy targeted (where `.plm/deployed.json` is rebuilt).
+pub fn update_placements(placed: Vec<PlacementRecord>, removed: &[PathBuf], project_root: &Path) {
+    if placed.is_empty() && removed.is_empty() {
+        return;
+    }
+    update_ledger(PlacementLedger::load(), placed, removed, project_root);
+}
+
+/// 読み込んだ配置記録を更新し、デプロイマニフェストを作り直す（[`update_placements`] の本体）
+///
+/// # Arguments
+///
+/// * `ledger` - Loaded placement ledger, or the error from loading it.
+/// * `placed` - Placements to add.
+/// * `removed` - Destination paths that were removed.
+/// * `project_root` - Project root where `.plm/deployed.json` is rebuilt.
+fn update_ledger(
+    ledger: Result<PlacementLedger>,
+    placed: Vec<PlacementRecord>,
+    removed: &[PathBuf],
+    project_root: &Path,
+) {
+    let result = ledger.and_then(|mut ledger| {
+        let mut changed = false;
+        for record in placed {
+            changed |= ledger.record(record);
+        }
+        for path in removed {
+            changed |= ledger.forget(path);
+        }
+        if changed {
+            ledger.save()?;
+        }
+        Ok(ledger)
+    });
+    let ledger = match result {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Warning: Failed to update placements.json: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = DeployedManifest::sync(project_root, ledger.records()) {
+        eprintln!("Warning: Failed to update .plm/deployed.json: {}", e);
+    }
+}
+
+#[cfg(test)]
+#[path = "placements_test.rs"]
+mod tests;
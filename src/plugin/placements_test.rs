@@ -0,0 +1,121 @@
+use super::*;
+use tempfile::TempDir;
+
+fn record(path: &Path, plugin: &str) -> PlacementRecord {
+    PlacementRecord {
+        path: path.to_path_buf(),
+        target: "copilot".to_string(),
+        kind: ComponentKind::Command,
+        marketplace: Some("company-tools".to_string()),
+        plugin: plugin.to_string(),
+    }
+}
+
+#[test]
+fn load_from_missing_file_is_empty() {
+    let temp = TempDir::new().unwrap();
+    let ledger = PlacementLedger::load_from(temp.path().join("placements.json")).unwrap();
+    assert!(ledger.records().is_empty());
+}
+
+#[test]
+fn record_replaces_owner_of_same_path() {
+    let temp = TempDir::new().unwrap();
+    let mut ledger = PlacementLedger::load_from(temp.path().join("placements.json")).unwrap();
+    let path = temp.path().join("deploy.prompt.md");
+
+    assert!(ledger.record(record(&path, "old")));
+    assert!(!ledger.record(record(&path, "old")));
+    assert!(ledger.record(record(&path, "new")));
+
+    assert_eq!(ledger.records().len(), 1);
+    assert_eq!(ledger.records()[0].plugin, "new");
+}
+
+#[test]
+fn save_and_reload_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let ledger_path = temp.path().join(".plm").join("placements.json");
+    let mut ledger = PlacementLedger::load_from(ledger_path.clone()).unwrap();
+    ledger.record(record(&temp.path().join("a.md"), "plugin-a"));
+    ledger.save().unwrap();
+
+    let reloaded = PlacementLedger::load_from(ledger_path).unwrap();
+    assert_eq!(reloaded.records(), ledger.records());
+}
+
+#[test]
+fn forget_and_prune_missing() {
+    let temp = TempDir::new().unwrap();
+    let mut ledger = PlacementLedger::load_from(temp.path().join("placements.json")).unwrap();
+    let existing = temp.path().join("exists.md");
+    fs::write(&existing, "# Command").unwrap();
+    ledger.record(record(&existing, "plugin-a"));
+    ledger.record(record(&temp.path().join("gone.md"), "plugin-a"));
+    ledger.record(record(&temp.path().join("other.md"), "plugin-a"));
+
+    assert!(ledger.forget(&temp.path().join("other.md")));
+    assert!(!ledger.forget(&temp.path().join("other.md")));
+    assert_eq!(ledger.prune_missing(), 1);
+    assert_eq!(ledger.records().len(), 1);
+    assert_eq!(ledger.records()[0].path, existing);
+}
+
+#[test]
+fn shared_kinds_are_not_tracked() {
+    assert!(PlacementRecord::is_tracked(ComponentKind::Skill));
+    assert!(!PlacementRecord::is_tracked(ComponentKind::Instruction));
+    assert!(!PlacementRecord::is_tracked(ComponentKind::Hook));
+}
+
+#[test]
+fn foreign_owner_reports_existing_path_of_another_plugin() {
+    let temp = TempDir::new().unwrap();
+    let mut ledger = PlacementLedger::load_from(temp.path().join("placements.json")).unwrap();
+    let path = temp.path().join("commit.prompt.md");
+    ledger.record(record(&path, "plugin-a"));
+
+    // 配置先がまだ無ければ衝突しない
+    assert!(ledger
+        .foreign_owner(&path, Some("company-tools"), "plugin-b")
+        .is_none());
+
+    fs::write(&path, "# commit").unwrap();
+    let owner = ledger
+        .foreign_owner(&path, Some("company-tools"), "plugin-b")
+        .unwrap();
+    assert_eq!(owner.owner_label(), "company-tools/plugin-a");
+
+    // 同じプラグインの再デプロイ・記録の無いファイルは衝突ではない
+    assert!(ledger
+        .foreign_owner(&path, Some("company-tools"), "plugin-a")
+        .is_none());
+    let unmanaged = temp.path().join("unmanaged.prompt.md");
+    fs::write(&unmanaged, "# mine").unwrap();
+    assert!(ledger
+        .foreign_owner(&unmanaged, Some("company-tools"), "plugin-b")
+        .is_none());
+}
+
+#[test]
+fn is_owned_by_treats_missing_marketplace_as_github() {
+    let mut github = record(Path::new("/tmp/a.md"), "owner--repo");
+    github.marketplace = None;
+    assert!(github.is_owned_by(Some("github"), "owner--repo"));
+    assert!(github.is_owned_by(None, "owner--repo"));
+    assert!(!github.is_owned_by(Some("company-tools"), "owner--repo"));
+}
+
+#[test]
+fn update_ledger_rebuilds_manifest_in_the_deployed_project() {
+    let temp = TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    let placed = project.join(".github/prompts/review.prompt.md");
+    fs::create_dir_all(placed.parent().unwrap()).unwrap();
+    fs::write(&placed, "review").unwrap();
+    let ledger = PlacementLedger::load_from(temp.path().join("placements.json"));
+
+    update_ledger(ledger, vec![record(&placed, "reviewer")], &[], &project);
+
+    assert!(project.join(".plm/deployed.json").is_file());
+}
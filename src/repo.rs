@@ -17,7 +17,8 @@
 //! ## 対応フォーマット
 //!
 //! - `owner/repo` - 短縮記法（GitHub デフォルト）
-//! - `owner/repo@ref` - ref指定
+//! - `owner/repo@ref` / `owner/repo#branch` - ref指定
+//! - `owner/repo/path/to/dir` - サブディレクトリ指定（短縮記法のみ。ref と併用可）
 //! - `https://github.com/owner/repo` - HTTP URL
 //! - `ssh://git@github.com/owner/repo` - SSH URL
 //! - `git@github.com:owner/repo` - SCP形式
@@ -35,6 +36,7 @@ pub struct Repo {
     owner: String,
     name: String,
     git_ref: Option<String>,
+    subdir: Option<String>,
 }
 
 impl Repo {
@@ -57,6 +59,7 @@ impl Repo {
             owner: owner.into(),
             name: name.into(),
             git_ref,
+            subdir: None,
         }
     }
 
@@ -80,11 +83,28 @@ impl Repo {
         self.git_ref.as_deref()
     }
 
+    /// リポジトリ内のサブディレクトリ（`owner/repo/path/to/dir` 形式で指定されたもの）
+    pub fn subdir(&self) -> Option<&str> {
+        self.subdir.as_deref()
+    }
+
     /// デフォルトのgit ref（指定がなければ "HEAD"）
     pub fn ref_or_default(&self) -> &str {
         self.git_ref().unwrap_or("HEAD")
     }
 
+    /// Git ref だけを差し替えたRepoを返す
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - Branch, tag, or commit SHA to use instead.
+    pub fn with_ref(&self, git_ref: impl Into<String>) -> Self {
+        Self {
+            git_ref: Some(git_ref.into()),
+            ..self.clone()
+        }
+    }
+
     /// フルパス形式 (owner/repo)
     pub fn full_name(&self) -> String {
         format!("{}/{}", self.owner, self.name)
@@ -109,15 +129,15 @@ enum SourceLocatorKind {
 /// 共通パイプライン:
 /// 1. 入力形式を判定 (HTTP URL / SSH URL / SCP / 短縮記法)
 /// 2. ホストとパスを抽出
-/// 3. `@ref` を分離
+/// 3. `@ref` / `#branch` を分離（短縮記法は `owner/repo` より後ろをサブディレクトリとして分離）
 /// 4. ホスト別パース: `host::<name>::parse_repo_path()` に委譲
-/// 5. 正規化: `Repo { host, owner, name, git_ref }` を生成
+/// 5. 正規化: `Repo { host, owner, name, git_ref, subdir }` を生成
 ///
 /// # Arguments
 ///
 /// * `input` - Source locator in shorthand, HTTP URL, SSH URL, or SCP format.
 pub fn from_url(input: &str) -> Result<Repo> {
-    let (host, path, git_ref) = parse_input(input)?;
+    let (host, path, git_ref, subdir) = parse_input(input)?;
 
     let (owner, name) = match host {
         HostKind::GitHub => host::github::parse_repo_path(&path)?,
@@ -129,7 +149,10 @@ pub fn from_url(input: &str) -> Result<Repo> {
         }
     };
 
-    Ok(Repo::new(host, owner, name, git_ref))
+    Ok(Repo {
+        subdir,
+        ..Repo::new(host, owner, name, git_ref)
+    })
 }
 
 /// 入力形式を判定
@@ -156,18 +179,22 @@ fn detect_source_locator_kind(input: &str) -> Result<SourceLocatorKind> {
     Ok(SourceLocatorKind::Shorthand)
 }
 
-/// 入力をパースしてホスト、パス、git refを抽出
+/// 入力をパースしてホスト、パス、git ref、サブディレクトリを抽出
+///
+/// URL 形式の余剰パス（`/tree/main` など）は従来どおり無視し、サブディレクトリとして
+/// 扱うのは短縮記法のみ。
 ///
 /// # Arguments
 ///
 /// * `input` - Raw source locator string.
-fn parse_input(input: &str) -> Result<(HostKind, String, Option<String>)> {
+fn parse_input(input: &str) -> Result<(HostKind, String, Option<String>, Option<String>)> {
     let input = input.trim();
     if input.is_empty() {
         return Err(PlmError::InvalidRepoFormat(input.to_string()));
     }
 
-    let (host_hint, raw_path) = match detect_source_locator_kind(input)? {
+    let kind = detect_source_locator_kind(input)?;
+    let (host_hint, raw_path) = match kind {
         SourceLocatorKind::HttpUrl => parse_http_url(input)?,
         SourceLocatorKind::SshUrl => parse_ssh_url(input)?,
         SourceLocatorKind::Scp => parse_scp_url(input)?,
@@ -175,30 +202,68 @@ fn parse_input(input: &str) -> Result<(HostKind, String, Option<String>)> {
     };
 
     let (path, git_ref) = split_ref(&raw_path)?;
+    let (path, subdir) = match kind {
+        SourceLocatorKind::Shorthand => split_subdir(&path)?,
+        _ => (path, None),
+    };
     let host = host_hint.unwrap_or(HostKind::GitHub);
 
-    Ok((host, path, git_ref))
+    Ok((host, path, git_ref, subdir))
 }
 
-/// パスから `@ref` を分離
+/// パスから `@ref` / `#branch` を分離
+///
+/// 最初に現れた `@` または `#` で分割する（ref 側にスラッシュを含んでもよい）。
 ///
 /// # Arguments
 ///
-/// * `path` - Path portion of a source locator, potentially containing `@ref`.
+/// * `path` - Path portion of a source locator, potentially containing `@ref` or `#branch`.
 fn split_ref(path: &str) -> Result<(String, Option<String>)> {
     let path = path.strip_suffix(".git").unwrap_or(path);
 
-    if let Some((left, right)) = path.split_once('@') {
-        if right.is_empty() {
-            return Err(PlmError::InvalidRepoFormat(format!(
-                "Empty ref after @: {}",
-                path
-            )));
+    let Some(pos) = path.find(['@', '#']) else {
+        return Ok((path.to_string(), None));
+    };
+    let (left, right) = (&path[..pos], &path[pos + 1..]);
+    if right.is_empty() {
+        return Err(PlmError::InvalidRepoFormat(format!(
+            "Empty ref after {}: {}",
+            &path[pos..=pos],
+            path
+        )));
+    }
+    Ok((left.to_string(), Some(right.to_string())))
+}
+
+/// 短縮記法のパスから `owner/repo` より後ろをサブディレクトリとして分離
+///
+/// 空セグメント（`owner/repo/` の末尾スラッシュなど）と `.` は無視し、`..` はエラーにする。
+///
+/// # Arguments
+///
+/// * `path` - Shorthand path with the ref already removed (`owner/repo[/sub/dir]`).
+fn split_subdir(path: &str) -> Result<(String, Option<String>)> {
+    let mut segments = path.splitn(3, '/');
+    let repo_path = match (segments.next(), segments.next()) {
+        (Some(owner), Some(name)) => format!("{}/{}", owner, name),
+        _ => return Ok((path.to_string(), None)),
+    };
+
+    let mut parts = Vec::new();
+    for segment in segments.next().unwrap_or_default().split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                return Err(PlmError::InvalidRepoFormat(format!(
+                    "Subdirectory must not contain '..': {}",
+                    path
+                )))
+            }
+            _ => parts.push(segment),
         }
-        Ok((left.to_string(), Some(right.to_string())))
-    } else {
-        Ok((path.to_string(), None))
     }
+    let subdir = (!parts.is_empty()).then(|| parts.join("/"));
+    Ok((repo_path, subdir))
 }
 
 /// HTTP/HTTPS URLをパース
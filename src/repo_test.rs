@@ -291,9 +291,67 @@ fn test_from_url_ref_with_slash() {
     assert_eq!(repo.git_ref(), Some("feature/branch"));
 }
 
+#[test]
+fn test_from_url_hash_branch() {
+    let repo = from_url("owner/repo#v2").unwrap();
+    assert_eq!(repo.full_name(), "owner/repo");
+    assert_eq!(repo.git_ref(), Some("v2"));
+    assert_eq!(repo.subdir(), None);
+}
+
+#[test]
+fn test_from_url_empty_hash_ref() {
+    let err = from_url("owner/repo#").unwrap_err().to_string();
+    assert!(err.contains("Empty ref after #"));
+}
+
+#[test]
+fn test_from_url_shorthand_subdir() {
+    let repo = from_url("owner/repo/plugins/marketplace").unwrap();
+    assert_eq!(repo.full_name(), "owner/repo");
+    assert_eq!(repo.git_ref(), None);
+    assert_eq!(repo.subdir(), Some("plugins/marketplace"));
+}
+
+#[test]
+fn test_from_url_shorthand_subdir_with_ref() {
+    let repo = from_url("owner/repo/plugins#v2").unwrap();
+    assert_eq!(repo.subdir(), Some("plugins"));
+    assert_eq!(repo.git_ref(), Some("v2"));
+
+    let tagged = from_url("owner/repo/plugins/@v1.0.0").unwrap();
+    assert_eq!(tagged.subdir(), Some("plugins"));
+    assert_eq!(tagged.git_ref(), Some("v1.0.0"));
+    // with_ref はサブディレクトリを保持する
+    assert_eq!(tagged.with_ref("main").subdir(), Some("plugins"));
+}
+
+#[test]
+fn test_from_url_subdir_rejects_parent_dir() {
+    let err = from_url("owner/repo/../other").unwrap_err().to_string();
+    assert!(err.contains("'..'"));
+}
+
+#[test]
+fn test_from_url_url_extra_path_is_not_subdir() {
+    // URL 形式の余剰パス（/tree/main など）はサブディレクトリとして扱わない
+    let repo = from_url("https://github.com/owner/repo/tree/main").unwrap();
+    assert_eq!(repo.subdir(), None);
+    assert_eq!(from_url("owner/repo/").unwrap().subdir(), None);
+}
+
 #[test]
 fn test_split_ref_with_multiple_dots() {
     // refに複数ドットを含む場合
     let repo = from_url("owner/repo@v1.2.3").unwrap();
     assert_eq!(repo.git_ref(), Some("v1.2.3"));
 }
+
+#[test]
+fn test_with_ref_replaces_only_ref() {
+    let repo = from_url("owner/repo@main").unwrap();
+    let tagged = repo.with_ref("v1.2.0");
+    assert_eq!(tagged.full_name(), "owner/repo");
+    assert_eq!(tagged.git_ref(), Some("v1.2.0"));
+    assert_eq!(repo.git_ref(), Some("main"));
+}
@@ -8,12 +8,22 @@
 //! - [`list_placed_components`]: `target.list_placed()` の戻り値から
 //!   Instruction ファイルを除外した `flattened_name` 集合（`HashSet<String>`）を返す
 //!
+//! ## 除外ルール
+//!
+//! - [`ExcludeRules`]: `plugin.json` の `exclude` と組み込みリストによる配布除外判定
+//!
+//! ## フック定義
+//!
+//! - [`scan_hooks_config`]: `hooks/hooks.json` を読み、イベント + matcher 単位のフック一覧を返す
+//!
 //! ## 低レベル関数
 //!
 //! - [`list_skill_names`], [`list_agent_names`], etc.: 個別コンポーネントのスキャン
 
 mod components;
 mod constants;
+mod exclude;
+mod hooks;
 mod placement;
 
 pub use components::{
@@ -21,7 +31,10 @@ pub use components::{
     list_skill_names,
 };
 pub use constants::{
-    DEFAULT_AGENTS_DIR, DEFAULT_COMMANDS_DIR, DEFAULT_HOOKS_DIR, DEFAULT_INSTRUCTIONS_DIR,
-    DEFAULT_INSTRUCTIONS_FILE, DEFAULT_SKILLS_DIR,
+    ASSETS_DIR_SUFFIX, DEFAULT_AGENTS_DIR, DEFAULT_COMMANDS_DIR, DEFAULT_HOOKS_DIR,
+    DEFAULT_INSTRUCTIONS_DIR, DEFAULT_INSTRUCTIONS_FILE, DEFAULT_SKILLS_DIR, MARKDOWN_SUFFIX,
+    PROMPT_SUFFIX,
 };
+pub use exclude::ExcludeRules;
+pub use hooks::{scan_hooks_config, HookConfigScan};
 pub use placement::{is_instruction_file, list_placed_components};
@@ -2,8 +2,15 @@
 //!
 //! 各コンポーネント種別ごとのスキャン実装。
 //! `scan_components` から内部的に呼び出される。
+//!
+//! ## 戻り値の順序
+//!
+//! すべての `list_*` 関数は [`sort_entries`] により名前の辞書順（大文字小文字を
+//! 区別しない安定ソート）で返す。ファイルシステムの列挙順には依存しない。
 
-use super::constants::{AGENT_SUFFIX, MARKDOWN_SUFFIX, PROMPT_SUFFIX, SKILL_MANIFEST};
+use super::constants::{
+    AGENT_SUFFIX, ASSETS_DIR_SUFFIX, MARKDOWN_SUFFIX, PROMPT_SUFFIX, SKILL_MANIFEST,
+};
 use crate::path_ext::PathExt;
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
@@ -20,7 +27,7 @@ use std::path::{Path, PathBuf};
 ///
 /// # Returns
 /// `(SKILL.md があるディレクトリ名, そのディレクトリの絶対パス)` の一覧。
-/// 順序は保証されない。
+/// 名前順（[`sort_entries`] 参照）。
 ///
 /// # Behavior
 /// - `skills_dir` がディレクトリでない場合は空配列を返す
@@ -33,6 +40,7 @@ pub fn list_skill_names(skills_dir: &Path) -> Vec<(String, PathBuf)> {
     }
     let mut out = Vec::new();
     collect_skills_recursive(skills_dir, &mut out);
+    sort_entries(&mut out);
     out
 }
 
@@ -91,7 +99,7 @@ fn has_exact_skill_manifest(dir: &Path) -> bool {
 /// * `agents_path` - エージェントファイルまたはディレクトリのパス
 ///
 /// # Returns
-/// `(エージェント名, ファイルパス)` の一覧。
+/// `(エージェント名, ファイルパス)` の一覧。名前順（[`sort_entries`] 参照）。
 /// 単一ファイルで名前を導出できない場合や、`agents_path` がファイル/ディレクトリの
 /// いずれでもない場合は空配列を返す。
 pub fn list_agent_names(agents_path: &Path) -> Vec<(String, PathBuf)> {
@@ -116,6 +124,7 @@ pub fn list_agent_names(agents_path: &Path) -> Vec<(String, PathBuf)> {
 
     let mut out = Vec::new();
     collect_component_files_recursive(agents_path, AGENT_SUFFIX, true, &mut out);
+    sort_entries(&mut out);
     out
 }
 
@@ -127,6 +136,7 @@ pub fn list_agent_names(agents_path: &Path) -> Vec<(String, PathBuf)> {
 ///   ネスト先の `.md` は誤検出回避のため対象外とする。
 /// - その他のファイルは無視する。
 /// - サブディレクトリは再帰的に走査する（descended into のため `is_root = false`）。
+///   ただし添付アセット（`<名前>.assets/`）の中はコンポーネントとして扱わないため潜らない。
 fn collect_component_files_recursive(
     current: &Path,
     primary_suffix: &str,
@@ -154,12 +164,24 @@ fn collect_component_files_recursive(
             }
             continue;
         }
-        if entry.is_dir() {
+        if entry.is_dir() && !is_assets_dir(&entry) {
             collect_component_files_recursive(&entry, primary_suffix, false, out);
         }
     }
 }
 
+/// Command / Agent の添付アセットディレクトリ（`<名前>.assets`）か
+///
+/// # Arguments
+///
+/// * `dir` - Directory found while scanning a component directory.
+fn is_assets_dir(dir: &Path) -> bool {
+    dir.file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| n.strip_suffix(ASSETS_DIR_SUFFIX))
+        .is_some_and(|stem| !stem.is_empty())
+}
+
 /// コマンド名一覧を取得（再帰）
 ///
 /// `commands_dir` 配下を再帰的に走査し、`.prompt.md` / `.md` ファイルを列挙する。
@@ -170,7 +192,7 @@ fn collect_component_files_recursive(
 /// * `commands_dir` - コマンドディレクトリのパス
 ///
 /// # Returns
-/// `(コマンド名, ファイルパス)` の一覧。
+/// `(コマンド名, ファイルパス)` の一覧。名前順（[`sort_entries`] 参照）。
 pub fn list_command_names(commands_dir: &Path) -> Vec<(String, PathBuf)> {
     if !commands_dir.is_dir() {
         return Vec::new();
@@ -178,25 +200,27 @@ pub fn list_command_names(commands_dir: &Path) -> Vec<(String, PathBuf)> {
 
     let mut out = Vec::new();
     collect_component_files_recursive(commands_dir, PROMPT_SUFFIX, true, &mut out);
+    sort_entries(&mut out);
     out
 }
 
 /// フック名一覧を取得
 ///
 /// ディレクトリ内のファイルを列挙し、拡張子を除去した名前を返す。
+/// hooks.json の中身（イベント単位の定義）は解釈しない（[`super::scan_hooks_config`] 参照）。
 ///
 /// # Arguments
 ///
 /// * `hooks_dir` - フックディレクトリのパス
 ///
 /// # Returns
-/// フック名の一覧。
+/// フック名の一覧。名前順（[`sort_entries`] 参照）。
 pub fn list_hook_names(hooks_dir: &Path) -> Vec<(String, PathBuf)> {
     if !hooks_dir.is_dir() {
         return Vec::new();
     }
 
-    hooks_dir
+    let mut out: Vec<_> = hooks_dir
         .read_dir_entries()
         .into_iter()
         .filter(|path| path.is_file())
@@ -215,7 +239,9 @@ pub fn list_hook_names(hooks_dir: &Path) -> Vec<(String, PathBuf)> {
             }
             Some((hook_name, path))
         })
-        .collect()
+        .collect();
+    sort_entries(&mut out);
+    out
 }
 
 /// Markdown ファイル名一覧を取得
@@ -227,13 +253,14 @@ pub fn list_hook_names(hooks_dir: &Path) -> Vec<(String, PathBuf)> {
 /// * `dir` - 対象ディレクトリのパス
 ///
 /// # Returns
-/// Markdown ファイル名（拡張子除去済み）の一覧。
+/// Markdown ファイル名（拡張子除去済み）の一覧。名前順（[`sort_entries`] 参照）。
 pub fn list_markdown_names(dir: &Path) -> Vec<(String, PathBuf)> {
     if !dir.is_dir() {
         return Vec::new();
     }
 
-    dir.read_dir_entries()
+    let mut out: Vec<_> = dir
+        .read_dir_entries()
         .into_iter()
         .filter(|path| path.is_file())
         .filter_map(|path| {
@@ -245,7 +272,44 @@ pub fn list_markdown_names(dir: &Path) -> Vec<(String, PathBuf)> {
                 None
             }
         })
-        .collect()
+        .collect();
+    sort_entries(&mut out);
+    out
+}
+
+/// `list_*` の戻り値を決定的な順序に並べ替える
+///
+/// 比較キーは次の順:
+/// 1. 名前（大文字小文字を区別しない）
+/// 2. 名前（大文字小文字を区別する。`Foo` と `foo` の並びを固定するため）
+/// 3. 同名の場合は専用サフィックス（`.prompt.md` / `.agent.md`）を `.md` より前に置く
+/// 4. それでも同順位ならパス（ネスト先の同名スキル等）
+///
+/// # Arguments
+///
+/// * `entries` - `(name, path)` pairs to sort in place.
+fn sort_entries(entries: &mut [(String, PathBuf)]) {
+    entries.sort_by(|(a_name, a_path), (b_name, b_path)| {
+        a_name
+            .to_lowercase()
+            .cmp(&b_name.to_lowercase())
+            .then_with(|| a_name.cmp(b_name))
+            .then_with(|| suffix_rank(a_path).cmp(&suffix_rank(b_path)))
+            .then_with(|| a_path.cmp(b_path))
+    });
+}
+
+/// 同名エントリの並び順に使うサフィックス順位（専用サフィックスが 0、それ以外が 1）
+fn suffix_rank(path: &Path) -> u8 {
+    let is_dedicated = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.ends_with(PROMPT_SUFFIX) || n.ends_with(AGENT_SUFFIX));
+    if is_dedicated {
+        0
+    } else {
+        1
+    }
 }
 
 /// パスからファイル名（拡張子除去）を取得
@@ -14,6 +14,27 @@ fn valid_name_strategy() -> impl Strategy<Value = String> {
     "[a-zA-Z][a-zA-Z0-9_-]{0,15}".prop_map(|s| s)
 }
 
+/// 重複しない名前の集合（大文字小文字違いの衝突も除外する）
+///
+/// case-insensitive なファイルシステムでも同一ファイルにならないようにする。
+fn distinct_names_strategy() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(valid_name_strategy(), 0..12).prop_map(|names| {
+        let mut seen = std::collections::HashSet::new();
+        names
+            .into_iter()
+            .filter(|n| seen.insert(n.to_lowercase()))
+            .collect()
+    })
+}
+
+/// `sort_entries` の規約どおりに名前順になっているか
+fn is_sorted_by_name(items: &[(String, PathBuf)]) -> bool {
+    items.windows(2).all(|w| {
+        let (a, b) = (&w[0].0, &w[1].0);
+        (a.to_lowercase(), a) <= (b.to_lowercase(), b)
+    })
+}
+
 proptest! {
     /// list_agent_names は .agent.md サフィックスを除去する
     #[test]
@@ -146,4 +167,74 @@ proptest! {
         prop_assert_eq!(result.len(), 1);
         prop_assert_eq!(&result[0], &name);
     }
+
+    /// list_skill_names は生成したディレクトリ群に対して常に名前順で返す
+    #[test]
+    fn prop_list_skill_names_sorted(names_in in distinct_names_strategy()) {
+        let temp_dir = TempDir::new().unwrap();
+        for name in &names_in {
+            let dir = temp_dir.path().join(name);
+            fs::create_dir(&dir).unwrap();
+            fs::write(dir.join("SKILL.md"), "# Skill").unwrap();
+        }
+
+        let result = list_skill_names(temp_dir.path());
+
+        prop_assert_eq!(result.len(), names_in.len());
+        prop_assert!(is_sorted_by_name(&result));
+    }
+
+    /// list_agent_names は .agent.md / .md 混在でも常に名前順で返す
+    #[test]
+    fn prop_list_agent_names_sorted(
+        names_in in distinct_names_strategy(),
+        use_agent_suffix in prop::collection::vec(any::<bool>(), 12)
+    ) {
+        let temp_dir = TempDir::new().unwrap();
+        for (name, agent) in names_in.iter().zip(&use_agent_suffix) {
+            let suffix = if *agent { ".agent.md" } else { ".md" };
+            fs::write(temp_dir.path().join(format!("{}{}", name, suffix)), "# Agent").unwrap();
+        }
+
+        let result = list_agent_names(temp_dir.path());
+
+        prop_assert_eq!(result.len(), names_in.len());
+        prop_assert!(is_sorted_by_name(&result));
+    }
+
+    /// list_command_names はネストしたファイルを含めて常に名前順で返す
+    #[test]
+    fn prop_list_command_names_sorted(
+        names_in in distinct_names_strategy(),
+        nested in prop::collection::vec(any::<bool>(), 12)
+    ) {
+        let temp_dir = TempDir::new().unwrap();
+        let nested_dir = temp_dir.path().join("nested");
+        fs::create_dir(&nested_dir).unwrap();
+        for (name, in_nested) in names_in.iter().zip(&nested) {
+            let dir = if *in_nested { &nested_dir } else { temp_dir.path() };
+            fs::write(dir.join(format!("{}.prompt.md", name)), "# Command").unwrap();
+        }
+
+        let result = list_command_names(temp_dir.path());
+
+        prop_assert_eq!(result.len(), names_in.len());
+        prop_assert!(is_sorted_by_name(&result));
+    }
+
+    /// list_hook_names / list_markdown_names は常に名前順で返す
+    #[test]
+    fn prop_list_hook_and_markdown_names_sorted(names_in in distinct_names_strategy()) {
+        let temp_dir = TempDir::new().unwrap();
+        for name in &names_in {
+            fs::write(temp_dir.path().join(format!("{}.md", name)), "x").unwrap();
+        }
+
+        let hooks = list_hook_names(temp_dir.path());
+        let markdown = list_markdown_names(temp_dir.path());
+
+        prop_assert_eq!(hooks.len(), names_in.len());
+        prop_assert!(is_sorted_by_name(&hooks));
+        prop_assert!(is_sorted_by_name(&markdown));
+    }
 }
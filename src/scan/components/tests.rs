@@ -160,6 +160,24 @@ fn test_list_command_names_with_md_fallback() {
     assert_eq!(result, vec!["cmd1", "cmd2"]);
 }
 
+#[test]
+fn test_list_command_and_agent_names_skip_attached_assets() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+
+    fs::write(dir.join("deploy.md"), "# Deploy").unwrap();
+    let assets = dir.join("deploy.assets");
+    fs::create_dir(&assets).unwrap();
+    fs::write(assets.join("template.prompt.md"), "# Template").unwrap();
+    fs::write(assets.join("helper.agent.md"), "# Helper").unwrap();
+    let nested = dir.join("nested");
+    fs::create_dir(&nested).unwrap();
+    fs::write(nested.join("release.prompt.md"), "# Release").unwrap();
+
+    assert_eq!(names(list_command_names(dir)), vec!["deploy", "release"]);
+    assert_eq!(names(list_agent_names(dir)), vec!["deploy"]);
+}
+
 #[test]
 fn test_list_command_names_returns_empty_for_nonexistent() {
     let temp_dir = TempDir::new().unwrap();
@@ -805,3 +823,98 @@ fn test_list_command_names_multi_level_nested() {
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].0, "foo");
 }
+
+// =========================================================================
+// 戻り値の順序
+// =========================================================================
+
+#[test]
+fn test_list_skill_names_sorted_case_insensitively() {
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path();
+
+    for name in ["beta", "Alpha", "gamma", "alpha"] {
+        let dir = skills_dir.join(name);
+        fs::create_dir(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), "# Skill").unwrap();
+    }
+
+    assert_eq!(
+        names(list_skill_names(skills_dir)),
+        vec!["Alpha", "alpha", "beta", "gamma"]
+    );
+}
+
+#[test]
+fn test_list_skill_names_duplicate_basename_ordered_by_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let skills_dir = temp_dir.path();
+
+    for parent in ["b", "a"] {
+        let dir = skills_dir.join(parent).join("dup");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("SKILL.md"), "# Skill").unwrap();
+    }
+
+    let result = list_skill_names(skills_dir);
+
+    assert_eq!(result[0].1, skills_dir.join("a/dup"));
+    assert_eq!(result[1].1, skills_dir.join("b/dup"));
+}
+
+#[test]
+fn test_list_agent_names_duplicate_puts_agent_suffix_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let agents_dir = temp_dir.path();
+
+    fs::write(agents_dir.join("test.md"), "# Agent").unwrap();
+    fs::write(agents_dir.join("test.agent.md"), "# Agent").unwrap();
+
+    let result = list_agent_names(agents_dir);
+
+    assert_eq!(result[0].1, agents_dir.join("test.agent.md"));
+    assert_eq!(result[1].1, agents_dir.join("test.md"));
+}
+
+#[test]
+fn test_list_command_names_duplicate_puts_prompt_suffix_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let commands_dir = temp_dir.path();
+
+    fs::write(commands_dir.join("cmd.md"), "# Command").unwrap();
+    fs::write(commands_dir.join("cmd.prompt.md"), "# Command").unwrap();
+
+    let result = list_command_names(commands_dir);
+
+    assert_eq!(result[0].1, commands_dir.join("cmd.prompt.md"));
+    assert_eq!(result[1].1, commands_dir.join("cmd.md"));
+}
+
+#[test]
+fn test_list_command_names_sorted_across_nesting() {
+    let temp_dir = TempDir::new().unwrap();
+    let commands_dir = temp_dir.path();
+
+    fs::create_dir(commands_dir.join("nested")).unwrap();
+    fs::write(commands_dir.join("nested/alpha.prompt.md"), "# Command").unwrap();
+    fs::write(commands_dir.join("Zeta.md"), "# Command").unwrap();
+    fs::write(commands_dir.join("beta.prompt.md"), "# Command").unwrap();
+
+    assert_eq!(
+        names(list_command_names(commands_dir)),
+        vec!["alpha", "beta", "Zeta"]
+    );
+}
+
+#[test]
+fn test_list_hook_and_markdown_names_sorted() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+
+    for file in ["c.md", "B.md", "a.md"] {
+        fs::write(dir.join(file), "x").unwrap();
+    }
+
+    assert_eq!(names(list_hook_names(dir)), vec!["a", "B", "c"]);
+    assert_eq!(names(list_markdown_names(dir)), vec!["a", "B", "c"]);
+}
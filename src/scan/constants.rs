@@ -8,12 +8,18 @@ pub const AGENT_SUFFIX: &str = ".agent.md";
 pub const PROMPT_SUFFIX: &str = ".prompt.md";
 pub const MARKDOWN_SUFFIX: &str = ".md";
 
+/// Command / Agent の添付アセットディレクトリのサフィックス（`deploy.assets/` 等）
+pub const ASSETS_DIR_SUFFIX: &str = ".assets";
+
 /// デフォルトのコンポーネントディレクトリパス
 pub const DEFAULT_SKILLS_DIR: &str = "skills";
 pub const DEFAULT_AGENTS_DIR: &str = "agents";
 pub const DEFAULT_COMMANDS_DIR: &str = "commands";
 pub const DEFAULT_HOOKS_DIR: &str = "hooks";
 
+/// フック定義ファイル名（Claude Code 標準形式）
+pub const HOOKS_CONFIG_FILE: &str = "hooks.json";
+
 /// デフォルトのインストラクション設定
 pub const DEFAULT_INSTRUCTIONS_FILE: &str = "instructions.md";
 pub const DEFAULT_INSTRUCTIONS_DIR: &str = "instructions";
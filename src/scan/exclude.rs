@@ -0,0 +1,118 @@
+//! 配布除外パターン
+//!
+//! `plugin.json` の `exclude` に書かれたグロブパターンと組み込みの除外リストから、
+//! スキャン・デプロイ対象外のパスを判定する。
+//!
+//! ## マッチ規則
+//!
+//! - パターンはプラグインルートからの相対パス（区切りは `/`）に対してマッチする
+//! - `*` / `?` / `[...]` は `/` をまたがない。`**` は 0 個以上のディレクトリにマッチする
+//! - パス自身または祖先ディレクトリのいずれかがマッチすれば除外（`docs` と `docs/**` は同義）
+//! - 先頭・末尾の `/` は無視する（`/docs/` は `docs` と同じ）
+
+use crate::error::{PlmError, Result};
+use glob::{MatchOptions, Pattern};
+use std::path::{Component, Path};
+
+/// 常に除外するパターン（`plugin.json` の `exclude` に関係なく適用）
+pub const BUILTIN_EXCLUDES: &[&str] = &["**/.git", "**/node_modules", ".github/workflows"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// 除外ルール（組み込みリスト + ユーザー指定パターン）
+#[derive(Debug, Clone)]
+pub struct ExcludeRules {
+    patterns: Vec<Pattern>,
+}
+
+impl Default for ExcludeRules {
+    /// 組み込みリストのみのルール
+    fn default() -> Self {
+        Self {
+            patterns: BUILTIN_EXCLUDES
+                .iter()
+                .map(|p| Pattern::new(p).expect("builtin exclude pattern must be valid"))
+                .collect(),
+        }
+    }
+}
+
+impl ExcludeRules {
+    /// 組み込みリストにユーザー指定パターンを加えたルールを構築する
+    ///
+    /// 不正なグロブや空パターンは `PlmError::InvalidManifest` を返す。
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - Glob patterns from `plugin.json` `exclude`.
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut rules = Self::default();
+        for raw in patterns {
+            let normalized = raw.trim().trim_matches('/');
+            if normalized.is_empty() {
+                return Err(PlmError::InvalidManifest(format!(
+                    "invalid exclude pattern '{}': pattern must not be empty",
+                    raw
+                )));
+            }
+            let pattern = Pattern::new(normalized).map_err(|e| {
+                PlmError::InvalidManifest(format!("invalid exclude pattern '{}': {}", raw, e))
+            })?;
+            rules.patterns.push(pattern);
+        }
+        Ok(rules)
+    }
+
+    /// プラグインルートからの相対パスが除外対象か判定する
+    ///
+    /// `..` やルート指定を含むパス（プラグイン外）は除外対象としない。
+    ///
+    /// # Arguments
+    ///
+    /// * `relative` - Path relative to the plugin root.
+    pub fn is_excluded(&self, relative: &Path) -> bool {
+        let mut prefix = String::new();
+        for component in relative.components() {
+            let Component::Normal(segment) = component else {
+                if matches!(component, Component::CurDir) {
+                    continue;
+                }
+                return false;
+            };
+            if !prefix.is_empty() {
+                prefix.push('/');
+            }
+            prefix.push_str(&segment.to_string_lossy());
+            if self
+                .patterns
+                .iter()
+                .any(|p| p.matches_with(&prefix, MATCH_OPTIONS))
+            {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// プラグインルート配下の絶対パスが除外対象か判定する
+    ///
+    /// `path` が `root` 配下にない場合は `false`。
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Plugin root directory.
+    /// * `path` - Path to test.
+    pub fn is_excluded_under(&self, root: &Path, path: &Path) -> bool {
+        path.strip_prefix(root)
+            .map(|relative| self.is_excluded(relative))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+#[path = "exclude_test.rs"]
+mod tests;
@@ -0,0 +1,165 @@
+use super::*;
+use std::path::PathBuf;
+
+fn rules(patterns: &[&str]) -> ExcludeRules {
+    let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+    ExcludeRules::new(&patterns).unwrap()
+}
+
+fn excluded(rules: &ExcludeRules, path: &str) -> bool {
+    rules.is_excluded(Path::new(path))
+}
+
+// =========================================================================
+// 組み込みリスト
+// =========================================================================
+
+#[test]
+fn builtin_excludes_git_at_any_depth() {
+    let rules = ExcludeRules::default();
+    assert!(excluded(&rules, ".git"));
+    assert!(excluded(&rules, ".git/HEAD"));
+    assert!(excluded(&rules, "skills/foo/.git/config"));
+}
+
+#[test]
+fn builtin_excludes_node_modules_at_any_depth() {
+    let rules = ExcludeRules::default();
+    assert!(excluded(&rules, "node_modules/pkg/index.js"));
+    assert!(excluded(&rules, "skills/foo/node_modules/pkg/SKILL.md"));
+}
+
+#[test]
+fn builtin_excludes_github_workflows_only_at_root() {
+    let rules = ExcludeRules::default();
+    assert!(excluded(&rules, ".github/workflows/ci.yml"));
+    assert!(!excluded(&rules, ".github/copilot-instructions.md"));
+    assert!(!excluded(&rules, "skills/foo/.github/workflows/ci.yml"));
+}
+
+#[test]
+fn builtin_does_not_exclude_similar_names() {
+    let rules = ExcludeRules::default();
+    assert!(!excluded(&rules, ".gitignore"));
+    assert!(!excluded(&rules, "skills/git/SKILL.md"));
+    assert!(!excluded(&rules, "skills/node_modules_guide/SKILL.md"));
+}
+
+// =========================================================================
+// ユーザー指定パターン
+// =========================================================================
+
+#[test]
+fn double_star_suffix_excludes_directory_contents() {
+    let rules = rules(&["docs/**"]);
+    assert!(excluded(&rules, "docs/guide.md"));
+    assert!(excluded(&rules, "docs/a/b/SKILL.md"));
+    assert!(!excluded(&rules, "skills/docs.md"));
+}
+
+#[test]
+fn bare_directory_name_excludes_its_subtree() {
+    let rules = rules(&["examples"]);
+    assert!(excluded(&rules, "examples"));
+    assert!(excluded(&rules, "examples/demo/SKILL.md"));
+    assert!(!excluded(&rules, "skills/examples/SKILL.md"));
+}
+
+#[test]
+fn leading_and_trailing_slashes_are_ignored() {
+    let rules = rules(&["/docs/"]);
+    assert!(excluded(&rules, "docs/guide.md"));
+}
+
+#[test]
+fn single_star_does_not_cross_separator() {
+    let rules = rules(&["skills/*-draft"]);
+    assert!(excluded(&rules, "skills/foo-draft/SKILL.md"));
+    assert!(!excluded(&rules, "skills/nested/foo-draft/SKILL.md"));
+}
+
+#[test]
+fn double_star_prefix_matches_any_depth() {
+    let rules = rules(&["**/*.draft.md"]);
+    assert!(excluded(&rules, "agents/review.draft.md"));
+    assert!(excluded(&rules, "commands/a/b/x.draft.md"));
+    assert!(!excluded(&rules, "agents/review.md"));
+}
+
+#[test]
+fn double_star_in_middle_matches_zero_or_more_directories() {
+    let rules = rules(&["skills/**/internal"]);
+    assert!(excluded(&rules, "skills/internal/SKILL.md"));
+    assert!(excluded(&rules, "skills/a/b/internal/SKILL.md"));
+    assert!(!excluded(&rules, "agents/internal.md"));
+}
+
+#[test]
+fn question_mark_and_character_class() {
+    let rules = rules(&["agents/v?.md", "commands/[ab]*.md"]);
+    assert!(excluded(&rules, "agents/v1.md"));
+    assert!(!excluded(&rules, "agents/v10.md"));
+    assert!(excluded(&rules, "commands/alpha.md"));
+    assert!(excluded(&rules, "commands/beta.md"));
+    assert!(!excluded(&rules, "commands/gamma.md"));
+}
+
+#[test]
+fn matching_is_case_sensitive() {
+    let rules = rules(&["docs/**"]);
+    assert!(!excluded(&rules, "Docs/guide.md"));
+}
+
+#[test]
+fn dotfiles_are_matched_by_wildcards() {
+    let rules = rules(&["skills/*"]);
+    assert!(excluded(&rules, "skills/.hidden/SKILL.md"));
+}
+
+#[test]
+fn multiple_patterns_are_combined() {
+    let rules = rules(&["docs/**", "examples/**"]);
+    assert!(excluded(&rules, "docs/a.md"));
+    assert!(excluded(&rules, "examples/b/SKILL.md"));
+    assert!(!excluded(&rules, "skills/c/SKILL.md"));
+}
+
+#[test]
+fn user_patterns_keep_builtin_excludes() {
+    let rules = rules(&["docs/**"]);
+    assert!(excluded(&rules, ".git/HEAD"));
+}
+
+#[test]
+fn current_dir_components_are_skipped() {
+    let rules = rules(&["docs/**"]);
+    assert!(excluded(&rules, "./docs/a.md"));
+}
+
+#[test]
+fn paths_escaping_root_are_not_excluded() {
+    let rules = rules(&["**"]);
+    assert!(!excluded(&rules, "../outside.md"));
+    assert!(!excluded(&rules, "/abs/path.md"));
+}
+
+#[test]
+fn invalid_pattern_is_rejected() {
+    let err = ExcludeRules::new(&["docs/[".to_string()]).unwrap_err();
+    assert!(matches!(err, PlmError::InvalidManifest(msg) if msg.contains("docs/[")));
+}
+
+#[test]
+fn empty_pattern_is_rejected() {
+    assert!(ExcludeRules::new(&["  ".to_string()]).is_err());
+    assert!(ExcludeRules::new(&["/".to_string()]).is_err());
+}
+
+#[test]
+fn is_excluded_under_strips_root() {
+    let rules = rules(&["docs/**"]);
+    let root = PathBuf::from("/plugins/foo");
+    assert!(rules.is_excluded_under(&root, &root.join("docs/a.md")));
+    assert!(!rules.is_excluded_under(&root, &root.join("skills/a/SKILL.md")));
+    assert!(!rules.is_excluded_under(&root, Path::new("/elsewhere/docs/a.md")));
+}
@@ -0,0 +1,175 @@
+//! hooks.json（Claude Code 標準形式）のスキャン
+//!
+//! `hooks/hooks.json` の「イベント名 → matcher → command」構造を読み、
+//! イベント + matcher 単位の人間が読めるフック一覧を返す。
+//!
+//! ```json
+//! { "hooks": { "PreToolUse": [ { "matcher": "Bash", "hooks": [ { "type": "command", "command": "..." } ] } ] } }
+//! ```
+//!
+//! 壊れた hooks.json はエラーにせず、パース失敗を [`HookConfigScan::warnings`] に積む。
+
+use super::constants::HOOKS_CONFIG_FILE;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// hooks.json の 1 コマンドぶんのフック定義
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookEntry {
+    /// イベント名（`PreToolUse` など）
+    pub event: String,
+    /// ツール名などの matcher（未指定・空文字なら `None`）
+    pub matcher: Option<String>,
+    /// 実行するコマンド（`type: "prompt"` などコマンドを持たない定義は空文字）
+    pub command: String,
+}
+
+impl HookEntry {
+    /// 一覧表示用の名前（`PreToolUse: Bash`、matcher 無しなら `SessionStart`）
+    pub fn display_name(&self) -> String {
+        match &self.matcher {
+            Some(matcher) => format!("{}: {}", self.event, matcher),
+            None => self.event.clone(),
+        }
+    }
+}
+
+/// hooks.json のスキャン結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookConfigScan {
+    /// 読み取ったフック定義（イベント名順、同一イベント内は定義順）
+    pub entries: Vec<HookEntry>,
+    /// パース失敗・構造の不一致（スキャン自体は失敗させない）
+    pub warnings: Vec<String>,
+}
+
+impl HookConfigScan {
+    /// イベント + matcher 単位のフック名（重複を除き、出現順）
+    pub fn hook_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for name in self.entries.iter().map(HookEntry::display_name) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// hooks.json を読み、イベント単位のフック定義を返す
+///
+/// `path` がファイルならそれを、ディレクトリなら直下の `hooks.json` を読む。
+/// hooks.json が無ければ `None`（呼び出し側はファイル名ベースの一覧にフォールバックする）。
+///
+/// # Arguments
+///
+/// * `path` - Hooks directory, or the hooks config file itself (`plugin.json` の `hooks` 指定).
+pub fn scan_hooks_config(path: &Path) -> Option<HookConfigScan> {
+    let file = hooks_config_file(path)?;
+    let mut scan = HookConfigScan::default();
+    let content = match std::fs::read_to_string(&file) {
+        Ok(content) => content,
+        Err(e) => {
+            scan.warnings
+                .push(format!("Failed to read {}: {}", file.display(), e));
+            return Some(scan);
+        }
+    };
+    match serde_json::from_str::<Value>(&content) {
+        Ok(value) => parse_hooks_config(&value, &mut scan),
+        Err(e) => scan
+            .warnings
+            .push(format!("Failed to parse {}: {}", file.display(), e)),
+    }
+    Some(scan)
+}
+
+/// 読み込む hooks.json のパス（存在しなければ `None`）
+///
+/// # Arguments
+///
+/// * `path` - Hooks directory or hooks config file.
+fn hooks_config_file(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let file = path.join(HOOKS_CONFIG_FILE);
+    file.is_file().then_some(file)
+}
+
+/// パース済みの hooks.json からフック定義を取り出す
+///
+/// トップレベルの `hooks` オブジェクト（無ければトップレベル自体）をイベント名の
+/// マップとして扱う。各イベントは matcher グループの配列で、グループ内の `hooks`
+/// 配列の `command` を 1 件ずつ [`HookEntry`] にする。
+///
+/// # Arguments
+///
+/// * `value` - Parsed hooks.json.
+/// * `scan` - Scan result that receives entries and warnings.
+fn parse_hooks_config(value: &Value, scan: &mut HookConfigScan) {
+    let events = match value.get("hooks").unwrap_or(value) {
+        Value::Object(events) => events,
+        _ => {
+            scan.warnings
+                .push("hooks.json: 'hooks' must be an object of event names".to_string());
+            return;
+        }
+    };
+
+    for (event, groups) in events {
+        let Some(groups) = groups.as_array() else {
+            scan.warnings
+                .push(format!("hooks.json: event '{}' must be an array", event));
+            continue;
+        };
+        for group in groups {
+            let matcher = group
+                .get("matcher")
+                .and_then(Value::as_str)
+                .filter(|m| !m.is_empty())
+                .map(str::to_string);
+            let commands = group_commands(group);
+            if commands.is_empty() {
+                scan.entries.push(HookEntry {
+                    event: event.clone(),
+                    matcher,
+                    command: String::new(),
+                });
+                continue;
+            }
+            for command in commands {
+                scan.entries.push(HookEntry {
+                    event: event.clone(),
+                    matcher: matcher.clone(),
+                    command,
+                });
+            }
+        }
+    }
+}
+
+/// matcher グループ内のコマンド一覧
+///
+/// Claude Code 形式（`hooks: [{ command }]`）に加え、グループ直下に
+/// `command` / `bash` を書く形式（Copilot 形式）も受け付ける。
+///
+/// # Arguments
+///
+/// * `group` - One element of an event's array.
+fn group_commands(group: &Value) -> Vec<String> {
+    let command_of = |hook: &Value| {
+        ["command", "bash"]
+            .iter()
+            .find_map(|key| hook.get(*key).and_then(Value::as_str))
+            .map(str::to_string)
+    };
+    match group.get("hooks").and_then(Value::as_array) {
+        Some(hooks) => hooks.iter().filter_map(command_of).collect(),
+        None => command_of(group).into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+#[path = "hooks_test.rs"]
+mod tests;
@@ -0,0 +1,154 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_hooks_json(dir: &Path, content: &str) {
+    fs::write(dir.join(HOOKS_CONFIG_FILE), content).unwrap();
+}
+
+#[test]
+fn scan_hooks_config_returns_none_without_hooks_json() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("pre-commit.sh"), "echo hi").unwrap();
+
+    assert!(scan_hooks_config(temp.path()).is_none());
+    assert!(scan_hooks_config(&temp.path().join("missing")).is_none());
+}
+
+#[test]
+fn scan_hooks_config_reads_claude_code_format() {
+    let temp = TempDir::new().unwrap();
+    write_hooks_json(
+        temp.path(),
+        r#"{
+            "description": "sample",
+            "hooks": {
+                "PreToolUse": [
+                    { "matcher": "Bash", "hooks": [{ "type": "command", "command": "./check.sh" }] },
+                    { "matcher": "Edit|Write", "hooks": [{ "type": "command", "command": "./fmt.sh" }] }
+                ],
+                "SessionStart": [
+                    { "hooks": [{ "type": "command", "command": "./start.sh" }] }
+                ]
+            }
+        }"#,
+    );
+
+    let scan = scan_hooks_config(temp.path()).unwrap();
+
+    assert!(scan.warnings.is_empty());
+    assert_eq!(
+        scan.entries,
+        vec![
+            HookEntry {
+                event: "PreToolUse".to_string(),
+                matcher: Some("Bash".to_string()),
+                command: "./check.sh".to_string(),
+            },
+            HookEntry {
+                event: "PreToolUse".to_string(),
+                matcher: Some("Edit|Write".to_string()),
+                command: "./fmt.sh".to_string(),
+            },
+            HookEntry {
+                event: "SessionStart".to_string(),
+                matcher: None,
+                command: "./start.sh".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        scan.hook_names(),
+        vec!["PreToolUse: Bash", "PreToolUse: Edit|Write", "SessionStart"]
+    );
+}
+
+#[test]
+fn hook_names_deduplicates_commands_under_same_matcher() {
+    let temp = TempDir::new().unwrap();
+    write_hooks_json(
+        temp.path(),
+        r#"{ "hooks": { "PostToolUse": [
+            { "matcher": "Write", "hooks": [
+                { "type": "command", "command": "a" },
+                { "type": "command", "command": "b" }
+            ] }
+        ] } }"#,
+    );
+
+    let scan = scan_hooks_config(temp.path()).unwrap();
+
+    assert_eq!(scan.entries.len(), 2);
+    assert_eq!(scan.hook_names(), vec!["PostToolUse: Write"]);
+}
+
+#[test]
+fn scan_hooks_config_accepts_config_file_path() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("my-hooks.json");
+    fs::write(
+        &file,
+        r#"{ "hooks": { "Stop": [{ "matcher": "", "hooks": [{ "type": "command", "command": "x" }] }] } }"#,
+    )
+    .unwrap();
+
+    let scan = scan_hooks_config(&file).unwrap();
+
+    // 空の matcher は matcher 無しとして扱う
+    assert_eq!(scan.hook_names(), vec!["Stop"]);
+}
+
+#[test]
+fn scan_hooks_config_reads_commands_written_directly_in_group() {
+    let temp = TempDir::new().unwrap();
+    write_hooks_json(
+        temp.path(),
+        r#"{ "version": 1, "hooks": { "preToolUse": [{ "type": "command", "bash": "./guard.sh" }] } }"#,
+    );
+
+    let scan = scan_hooks_config(temp.path()).unwrap();
+
+    assert_eq!(scan.entries[0].command, "./guard.sh");
+    assert_eq!(scan.hook_names(), vec!["preToolUse"]);
+}
+
+#[test]
+fn scan_hooks_config_reports_broken_json_as_warning() {
+    let temp = TempDir::new().unwrap();
+    write_hooks_json(temp.path(), r#"{ "hooks": { "PreToolUse": [ "#);
+
+    let scan = scan_hooks_config(temp.path()).unwrap();
+
+    assert!(scan.entries.is_empty());
+    assert_eq!(scan.warnings.len(), 1);
+    assert!(
+        scan.warnings[0].starts_with("Failed to parse"),
+        "{}",
+        scan.warnings[0]
+    );
+}
+
+#[test]
+fn scan_hooks_config_warns_on_unexpected_structure() {
+    let temp = TempDir::new().unwrap();
+    write_hooks_json(
+        temp.path(),
+        r#"{ "hooks": { "PreToolUse": { "matcher": "Bash" }, "Stop": [{ "hooks": [{ "command": "x" }] }] } }"#,
+    );
+
+    let scan = scan_hooks_config(temp.path()).unwrap();
+
+    // 壊れたイベントだけを読み飛ばし、他のイベントは返す
+    assert_eq!(scan.hook_names(), vec!["Stop"]);
+    assert_eq!(scan.warnings.len(), 1);
+    assert!(
+        scan.warnings[0].contains("PreToolUse"),
+        "{}",
+        scan.warnings[0]
+    );
+
+    write_hooks_json(temp.path(), r#"{ "hooks": [] }"#);
+    let scan = scan_hooks_config(temp.path()).unwrap();
+    assert!(scan.entries.is_empty());
+    assert_eq!(scan.warnings.len(), 1);
+}
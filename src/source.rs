@@ -8,16 +8,18 @@
 //! let plugin = source.download(&cache, false).await?;
 //! ```
 
+mod archive_source;
 mod github_source;
 mod marketplace_source;
 mod search_source;
 
+pub use archive_source::ArchiveSource;
 pub use github_source::GitHubSource;
 pub use marketplace_source::MarketplaceSource;
 pub use search_source::SearchSource;
 
-use crate::error::Result;
-use crate::plugin::{CachedPackage, PackageCacheAccess};
+use crate::error::{PlmError, Result};
+use crate::plugin::{CachedPackage, Channel, PackageCacheAccess, PluginLock};
 use crate::repo;
 use std::future::Future;
 use std::pin::Pin;
@@ -38,29 +40,73 @@ pub trait PackageSource: Send + Sync {
         cache: &'a dyn PackageCacheAccess,
         force: bool,
     ) -> Pin<Box<dyn Future<Output = Result<CachedPackage>> + Send + 'a>>;
+
+    /// ダウンロード先のキャッシュエントリの操作ロックを取る
+    ///
+    /// ダウンロードはキャッシュを書き換えるため、`download()` より前に取って
+    /// 配置が終わるまで保持する。
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Package cache the plugin will be downloaded into.
+    fn lock(&self, cache: &dyn PackageCacheAccess) -> Result<PluginLock>;
 }
 
 /// 入力文字列をパースして適切な PackageSource を返す
 ///
 /// # Arguments
 ///
-/// * `input` - Source specifier such as `owner/repo`, `owner/repo@ref`, `plugin@marketplace`, or a bare plugin name.
+/// * `input` - Source specifier such as `owner/repo`, `owner/repo@ref`, `plugin@marketplace`, a local `.zip` archive, or a bare plugin name.
 pub fn parse_source(input: &str) -> Result<Box<dyn PackageSource>> {
+    parse_source_with_channel(input, None)
+}
+
+/// 更新チャンネルを指定して PackageSource を返す
+///
+/// チャンネル指定時はそのチャンネルの最新リリースタグを取得する。
+/// `owner/repo@ref` のように ref を明示した入力とは併用できない。
+///
+/// # Arguments
+///
+/// * `input` - Source specifier (see [`parse_source`]).
+/// * `channel` - Update channel to install from, or `None` to use the default branch / given ref.
+pub fn parse_source_with_channel(
+    input: &str,
+    channel: Option<Channel>,
+) -> Result<Box<dyn PackageSource>> {
+    if ArchiveSource::matches(input) {
+        if let Some(channel) = channel {
+            return Err(PlmError::InvalidArgument(format!(
+                "cannot combine a local archive ('{}') with --channel {}",
+                input, channel
+            )));
+        }
+        return Ok(Box::new(ArchiveSource::new(input)));
+    }
+
     if let Some((left, right)) = input.split_once('@') {
         if left.contains('/') {
+            if let Some(channel) = channel {
+                return Err(PlmError::InvalidArgument(format!(
+                    "cannot combine an explicit ref ('{}') with --channel {}",
+                    input, channel
+                )));
+            }
             let repo = repo::from_url(input)?;
             return Ok(Box::new(GitHubSource::new(repo)));
         }
 
-        return Ok(Box::new(MarketplaceSource::new(left, right)));
+        return Ok(Box::new(
+            MarketplaceSource::new(left, right).with_channel(channel),
+        ));
     }
 
     if input.contains('/') {
         let repo = repo::from_url(input)?;
-        return Ok(Box::new(GitHubSource::new(repo)));
+        return Ok(Box::new(GitHubSource::new(repo).with_channel(channel)));
     }
 
-    Ok(Box::new(SearchSource::new(input)))
+    Ok(Box::new(SearchSource::new(input).with_channel(channel)))
 }
 
 #[cfg(test)]
@@ -0,0 +1,109 @@
+//! ローカルのプラグインアーカイブ（`plm pack` の出力）からのインストール
+
+use crate::error::{PlmError, Result};
+use crate::marketplace::validate_plugin_name;
+use crate::plugin::{
+    CachedPackage, PackageCacheAccess, PluginLock, PluginManifest, CHECKSUMS_FILE,
+};
+use std::future::Future;
+use std::io::{Cursor, Read};
+use std::path::PathBuf;
+use std::pin::Pin;
+use zip::ZipArchive;
+
+use super::PackageSource;
+
+/// アーカイブ内でマニフェストを探すパス（トップディレクトリからの相対）
+const MANIFEST_ENTRIES: &[&str] = &[".claude-plugin/plugin.json", "plugin.json"];
+
+/// ローカルの zip アーカイブからプラグインを展開するソース
+///
+/// キャッシュ上は直接 GitHub install と同じ `github` 配下に、マニフェストの
+/// `name` をキーとして置く。
+pub struct ArchiveSource {
+    path: PathBuf,
+}
+
+impl ArchiveSource {
+    /// Create a source that installs from a local plugin archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the `.zip` archive.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 入力がローカルのアーカイブを指しているか（`.zip` で終わる既存ファイル）
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Source specifier given on the command line.
+    pub fn matches(input: &str) -> bool {
+        input.to_ascii_lowercase().ends_with(".zip") && std::path::Path::new(input).is_file()
+    }
+}
+
+impl PackageSource for ArchiveSource {
+    fn download<'a>(
+        &'a self,
+        cache: &'a dyn PackageCacheAccess,
+        _force: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<CachedPackage>> + Send + 'a>> {
+        Box::pin(async move {
+            let archive = std::fs::read(&self.path)?;
+            let manifest = read_manifest(&archive)?;
+
+            println!("Extracting {} to cache...", self.path.display());
+            let plugin_path = cache.store_from_archive(None, &manifest.name, &archive, None)?;
+            if !plugin_path.join(CHECKSUMS_FILE).exists() {
+                eprintln!(
+                    "Warning: {} has no {}; its contents were not verified",
+                    self.path.display(),
+                    CHECKSUMS_FILE
+                );
+            }
+
+            cache.load_package(None, &manifest.name)
+        })
+    }
+
+    fn lock(&self, cache: &dyn PackageCacheAccess) -> Result<PluginLock> {
+        let manifest = read_manifest(&std::fs::read(&self.path)?)?;
+        PluginLock::acquire(cache, None, &manifest.name)
+    }
+}
+
+/// アーカイブ内の `plugin.json` を読む（展開前にキャッシュのキーを決めるため）
+///
+/// `name` はそのままキャッシュのディレクトリ名になるため、パスとして安全な名前か検証する。
+///
+/// # Arguments
+///
+/// * `archive` - Zip archive bytes.
+pub(crate) fn read_manifest(archive: &[u8]) -> Result<PluginManifest> {
+    let mut zip = ZipArchive::new(Cursor::new(archive))?;
+    for candidate in MANIFEST_ENTRIES {
+        let entry = zip
+            .file_names()
+            .find(|name| {
+                name.split_once('/')
+                    .is_some_and(|(_, rest)| rest == *candidate)
+            })
+            .map(str::to_string);
+        if let Some(entry) = entry {
+            let mut content = String::new();
+            zip.by_name(&entry)?.read_to_string(&mut content)?;
+            let manifest = PluginManifest::parse(&content)?;
+            validate_plugin_name(&manifest.name)?;
+            return Ok(manifest);
+        }
+    }
+    Err(PlmError::InvalidSource(
+        "plugin.json not found in archive".to_string(),
+    ))
+}
+
+#[cfg(test)]
+#[path = "archive_source_test.rs"]
+mod tests;
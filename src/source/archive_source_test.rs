@@ -0,0 +1,72 @@
+use super::*;
+use std::io::Write;
+use tempfile::TempDir;
+
+fn archive(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let options = zip::write::SimpleFileOptions::default();
+        for (path, content) in entries {
+            zip.start_file(*path, options).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+    buf
+}
+
+#[test]
+fn read_manifest_prefers_claude_plugin_dir() {
+    let bytes = archive(&[
+        (
+            "demo/plugin.json",
+            r#"{"name":"fallback","version":"0.1.0"}"#,
+        ),
+        (
+            "demo/.claude-plugin/plugin.json",
+            r#"{"name":"demo","version":"1.0.0"}"#,
+        ),
+    ]);
+
+    let manifest = read_manifest(&bytes).unwrap();
+
+    assert_eq!(manifest.name, "demo");
+    assert_eq!(manifest.version, "1.0.0");
+}
+
+#[test]
+fn read_manifest_without_plugin_json_fails() {
+    let bytes = archive(&[("demo/README.md", "readme")]);
+
+    assert!(matches!(
+        read_manifest(&bytes),
+        Err(PlmError::InvalidSource(_))
+    ));
+}
+
+#[test]
+fn read_manifest_rejects_names_that_escape_the_cache() {
+    for name in ["../escape", "a/b", "..", "/abs"] {
+        let manifest = format!(r#"{{"name":{:?},"version":"1.0.0"}}"#, name);
+        let bytes = archive(&[("demo/plugin.json", manifest.as_str())]);
+
+        assert!(
+            matches!(read_manifest(&bytes), Err(PlmError::InvalidPluginName(_))),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn matches_only_existing_zip_files() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("demo.zip");
+    std::fs::write(&path, archive(&[])).unwrap();
+
+    assert!(ArchiveSource::matches(path.to_str().unwrap()));
+    assert!(!ArchiveSource::matches(
+        temp.path().join("missing.zip").to_str().unwrap()
+    ));
+    assert!(!ArchiveSource::matches("owner/repo"));
+}
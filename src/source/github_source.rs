@@ -2,7 +2,10 @@
 
 use crate::error::Result;
 use crate::host::HostClientFactory;
-use crate::plugin::{meta, CachedPackage, GithubCacheId, PackageCacheAccess};
+use crate::plugin::version::fetch_channel_tag;
+use crate::plugin::{
+    meta, CachedPackage, Channel, GithubCacheId, PackageCacheAccess, PluginLock, UpdateResult,
+};
 use crate::repo::Repo;
 use std::future::Future;
 use std::pin::Pin;
@@ -33,6 +36,8 @@ enum SourceContext {
 pub struct GitHubSource {
     repo: Repo,
     context: SourceContext,
+    /// 指定時はこのチャンネルの最新リリースタグを取得し、meta に記録する
+    channel: Option<Channel>,
 }
 
 impl GitHubSource {
@@ -45,6 +50,7 @@ impl GitHubSource {
         Self {
             repo,
             context: SourceContext::Direct,
+            channel: None,
         }
     }
 
@@ -69,9 +75,20 @@ impl GitHubSource {
                 plugin_identifier,
                 source_path,
             },
+            channel: None,
         }
     }
 
+    /// 更新チャンネルを指定する
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Update channel to install from (`None` keeps the repo's ref / default branch).
+    pub fn with_channel(mut self, channel: Option<Channel>) -> Self {
+        self.channel = channel;
+        self
+    }
+
     /// marketplace 名（直接 GitHub の場合は `None`）
     fn marketplace_name(&self) -> Option<&str> {
         match &self.context {
@@ -109,7 +126,7 @@ impl PackageSource for GitHubSource {
         force: bool,
     ) -> Pin<Box<dyn Future<Output = Result<CachedPackage>> + Send + 'a>> {
         Box::pin(async move {
-            let factory = HostClientFactory::with_defaults();
+            let factory = HostClientFactory::from_env()?;
             let client = factory.create(self.repo.host());
             let display_name = self.repo.name();
             let marketplace = self.marketplace_name();
@@ -122,7 +139,11 @@ impl PackageSource for GitHubSource {
                 } => plugin_identifier,
             };
 
-            if !force && cache.is_cached(marketplace, &cache_name) {
+            // 別チャンネルでキャッシュ済みなら使い回さずに取得し直す
+            let channel_matches = self.channel.is_none()
+                || meta::load_meta(&cache.plugin_path(marketplace, &cache_name))
+                    .is_some_and(|m| m.channel == self.channel);
+            if !force && channel_matches && cache.is_cached(marketplace, &cache_name) {
                 println!(
                     "Using cached plugin: {} (cache key: {})",
                     log_label, cache_name
@@ -130,13 +151,21 @@ impl PackageSource for GitHubSource {
                 return cache.load_package(marketplace, &cache_name);
             }
 
+            let repo = match self.channel {
+                Some(channel) => {
+                    let tag = fetch_channel_tag(client.as_ref(), &self.repo, channel).await?;
+                    println!("Selected {} release: {}", channel, tag);
+                    self.repo.with_ref(tag)
+                }
+                None => self.repo.clone(),
+            };
+
             println!(
                 "Downloading plugin from {}/{}...",
-                self.repo.owner(),
-                self.repo.name()
+                repo.owner(),
+                repo.name()
             );
-            let (archive, git_ref, commit_sha) =
-                client.download_archive_with_sha(&self.repo).await?;
+            let (archive, git_ref, commit_sha) = client.download_archive_with_sha(&repo).await?;
 
             println!("Extracting to cache...");
             let plugin_path =
@@ -148,7 +177,9 @@ impl PackageSource for GitHubSource {
             let mut plugin_meta = meta::load_meta(&plugin_path).unwrap_or_default();
             plugin_meta.set_source_repo(self.repo.owner(), self.repo.name());
             plugin_meta.set_git_info(&git_ref, &commit_sha);
+            plugin_meta.record_update(None, Some(&manifest.version), UpdateResult::Installed);
             plugin_meta.marketplace = Some(crate::marketplace::DEFAULT_MARKETPLACE.to_string());
+            plugin_meta.channel = self.channel;
             if let Err(e) = meta::write_meta(&plugin_path, &plugin_meta) {
                 eprintln!("Warning: Failed to save plugin metadata: {}", e);
             }
@@ -165,4 +196,8 @@ impl PackageSource for GitHubSource {
             })
         })
     }
+
+    fn lock(&self, cache: &dyn PackageCacheAccess) -> Result<PluginLock> {
+        PluginLock::acquire(cache, self.marketplace_name(), &self.compute_cache_name())
+    }
 }
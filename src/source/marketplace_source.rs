@@ -5,7 +5,7 @@ use crate::marketplace::{
     validate_plugin_names, MarketplaceManifest, MarketplaceRegistry,
     PluginSource as MpPluginSource, PluginSourcePath,
 };
-use crate::plugin::{CachedPackage, LegacyCacheCleaner, PackageCacheAccess};
+use crate::plugin::{CachedPackage, Channel, LegacyCacheCleaner, PackageCacheAccess, PluginLock};
 use crate::repo;
 use std::future::Future;
 use std::pin::Pin;
@@ -16,6 +16,8 @@ use super::{GitHubSource, PackageSource};
 pub struct MarketplaceSource {
     plugin: String,
     marketplace: String,
+    channel: Option<Channel>,
+    git_ref: Option<String>,
 }
 
 impl MarketplaceSource {
@@ -29,6 +31,40 @@ impl MarketplaceSource {
         Self {
             plugin: plugin.to_string(),
             marketplace: marketplace.to_string(),
+            channel: None,
+            git_ref: None,
+        }
+    }
+
+    /// 更新チャンネルを指定する
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Update channel to install from (`None` keeps the default behavior).
+    pub fn with_channel(mut self, channel: Option<Channel>) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// 取得するブランチ・タグ・コミット SHA を指定する
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - Git ref to download instead of the repository default (`None` keeps it).
+    pub fn with_ref(mut self, git_ref: Option<String>) -> Self {
+        self.git_ref = git_ref;
+        self
+    }
+
+    /// `with_ref` で指定した ref を取得元リポジトリに適用する
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - Repository resolved from the marketplace entry.
+    fn apply_ref(&self, repo: repo::Repo) -> repo::Repo {
+        match &self.git_ref {
+            Some(git_ref) => repo.with_ref(git_ref.as_str()),
+            None => repo,
         }
     }
 }
@@ -69,7 +105,8 @@ impl PackageSource for MarketplaceSource {
 
             let mut cached = match &plugin_entry.source {
                 MpPluginSource::Local(path) => {
-                    let repo = mp_cache.source.to_repo();
+                    // ローカルディレクトリのマーケットプレイスは取得元リポジトリを持たない
+                    let repo = self.apply_ref(mp_cache.source_repo()?);
                     let source_path: PluginSourcePath = path.parse()?;
 
                     GitHubSource::with_marketplace_plugin(
@@ -78,17 +115,19 @@ impl PackageSource for MarketplaceSource {
                         Some(source_path.into()),
                         plugin_identifier.clone(),
                     )
+                    .with_channel(self.channel)
                     .download(cache, force)
                     .await?
                 }
                 MpPluginSource::External { repo: repo_url, .. } => {
-                    let repo = repo::from_url(repo_url)?;
+                    let repo = self.apply_ref(repo::from_url(repo_url)?);
                     GitHubSource::with_marketplace_plugin(
                         repo,
                         self.marketplace.clone(),
                         None,
                         plugin_identifier.clone(),
                     )
+                    .with_channel(self.channel)
                     .download(cache, force)
                     .await?
                 }
@@ -98,4 +137,9 @@ impl PackageSource for MarketplaceSource {
             Ok(cached)
         })
     }
+
+    fn lock(&self, cache: &dyn PackageCacheAccess) -> Result<PluginLock> {
+        // キャッシュキーはマーケットプレイス内のプラグイン名（plugin_identifier）
+        PluginLock::acquire(cache, Some(&self.marketplace), &self.plugin)
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::error::{PlmError, Result};
 use crate::marketplace::{MarketplaceConfig, MarketplaceRegistry};
-use crate::plugin::{CachedPackage, PackageCacheAccess};
+use crate::plugin::{CachedPackage, Channel, PackageCacheAccess, PluginLock};
 use std::future::Future;
 use std::pin::Pin;
 
@@ -11,6 +11,7 @@ use super::{MarketplaceSource, PackageSource};
 /// 全 Marketplace を検索してプラグインをダウンロードするソース
 pub struct SearchSource {
     query: String,
+    channel: Option<Channel>,
 }
 
 impl SearchSource {
@@ -22,72 +23,94 @@ impl SearchSource {
     pub fn new(query: &str) -> Self {
         Self {
             query: query.to_string(),
+            channel: None,
         }
     }
-}
 
-impl PackageSource for SearchSource {
-    fn download<'a>(
-        &'a self,
-        cache: &'a dyn PackageCacheAccess,
-        force: bool,
-    ) -> Pin<Box<dyn Future<Output = Result<CachedPackage>> + Send + 'a>> {
-        Box::pin(async move {
-            let registry = MarketplaceRegistry::new()?;
+    /// 更新チャンネルを指定する
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Update channel to install from (`None` keeps the default behavior).
+    pub fn with_channel(mut self, channel: Option<Channel>) -> Self {
+        self.channel = channel;
+        self
+    }
 
-            let config = MarketplaceConfig::load().map_err(|e| {
-                PlmError::Cache(format!("Failed to load marketplace config: {}", e))
-            })?;
-            let registered_names: Vec<&str> =
-                config.list().iter().map(|e| e.name.as_str()).collect();
+    /// 登録済みマーケットプレイスからプラグインを探し、取得元のソースを決める
+    ///
+    /// 見つからない・複数のマーケットプレイスに存在する場合はエラー。
+    fn resolve(&self) -> Result<MarketplaceSource> {
+        let registry = MarketplaceRegistry::new()?;
 
-            if registered_names.is_empty() {
-                return Err(PlmError::PluginNotFound(format!(
-                    "{} (no marketplaces registered; use 'plm marketplace add <owner/repo>' to add one)",
-                    self.query
-                )));
-            }
+        let config = MarketplaceConfig::load()
+            .map_err(|e| PlmError::Cache(format!("Failed to load marketplace config: {}", e)))?;
+        let registered_names: Vec<&str> = config.list().iter().map(|e| e.name.as_str()).collect();
 
-            let matches = registry.find_plugins(&self.query)?;
-            let matches: Vec<_> = matches
-                .into_iter()
-                .filter(|m| registered_names.contains(&m.marketplace.as_str()))
-                .collect();
-
-            if matches.is_empty() {
-                let mut uncached: Vec<&str> = Vec::new();
-                for name in &registered_names {
-                    if registry.get(name)?.is_none() {
-                        uncached.push(name);
-                    }
-                }
+        if registered_names.is_empty() {
+            return Err(PlmError::PluginNotFound(format!(
+                "{} (no marketplaces registered; use 'plm marketplace add <owner/repo>' to add one)",
+                self.query
+            )));
+        }
 
-                if !uncached.is_empty() {
-                    return Err(PlmError::PluginNotFound(format!(
-                        "{}; some marketplaces have no cache: {}. Run 'plm marketplace update' to fetch plugin information.",
-                        self.query,
-                        uncached.join(", ")
-                    )));
-                }
+        let matches = registry.find_plugins(&self.query)?;
+        let matches: Vec<_> = matches
+            .into_iter()
+            .filter(|m| registered_names.contains(&m.marketplace.as_str()))
+            .collect();
 
-                return Err(PlmError::PluginNotFound(self.query.clone()));
+        if matches.is_empty() {
+            let mut uncached: Vec<&str> = Vec::new();
+            for name in &registered_names {
+                if registry.get(name)?.is_none() {
+                    uncached.push(name);
+                }
             }
 
-            if matches.len() > 1 {
-                let marketplace_names: Vec<_> =
-                    matches.iter().map(|m| m.marketplace.as_str()).collect();
-                return Err(PlmError::InvalidArgument(format!(
-                    "Plugin '{}' found in multiple marketplaces: {}. Use '{}@<marketplace>' to specify which one.",
+            if !uncached.is_empty() {
+                return Err(PlmError::PluginNotFound(format!(
+                    "{}; some marketplaces have no cache: {}. Run 'plm marketplace update' to fetch plugin information.",
                     self.query,
-                    marketplace_names.join(", "),
-                    self.query
+                    uncached.join(", ")
                 )));
             }
 
-            let plugin_match = &matches[0];
+            return Err(PlmError::PluginNotFound(self.query.clone()));
+        }
+
+        if matches.len() > 1 {
+            let marketplace_names: Vec<_> =
+                matches.iter().map(|m| m.marketplace.as_str()).collect();
+            return Err(PlmError::InvalidArgument(format!(
+                "Plugin '{}' found in multiple marketplaces: {}. Use '{}@<marketplace>' to specify which one.",
+                self.query,
+                marketplace_names.join(", "),
+                self.query
+            )));
+        }
+
+        let plugin_match = &matches[0];
+        Ok(
             MarketplaceSource::new(&self.query, &plugin_match.marketplace)
-                .download(cache, force)
-                .await
+                .with_channel(self.channel),
+        )
+    }
+}
+
+impl PackageSource for SearchSource {
+    fn download<'a>(
+        &'a self,
+        cache: &'a dyn PackageCacheAccess,
+        force: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<CachedPackage>> + Send + 'a>> {
+        Box::pin(async move {
+            let source = self.resolve()?;
+            source.download(cache, force).await
         })
     }
+
+    fn lock(&self, cache: &dyn PackageCacheAccess) -> Result<PluginLock> {
+        self.resolve()?.lock(cache)
+    }
 }
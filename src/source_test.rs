@@ -107,3 +107,16 @@ fn test_parse_slash_repo() {
     let source = parse_source("/repo");
     assert!(source.is_err());
 }
+
+#[test]
+fn test_parse_explicit_ref_with_channel_is_rejected() {
+    // ref を明示した入力にチャンネルは併用できない
+    let source = parse_source_with_channel("owner/repo@v1.0.0", Some(Channel::Beta));
+    assert!(source.is_err());
+}
+
+#[test]
+fn test_parse_with_channel_accepts_repo_and_marketplace() {
+    assert!(parse_source_with_channel("owner/repo", Some(Channel::Beta)).is_ok());
+    assert!(parse_source_with_channel("plugin@marketplace", Some(Channel::Stable)).is_ok());
+}
@@ -0,0 +1,114 @@
+//! 編集距離による名前の候補提示（did-you-mean）
+//!
+//! 入力と候補の Levenshtein 距離を大文字小文字を区別せずに計算し、近いものを返す。
+//! 候補にするかどうかのしきい値は入力の長さで変える（短い名前ほど厳しくする）。
+
+/// 提示する候補の最大件数
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// 確認のうえそのまま続行してよい距離の上限
+pub const CONFIRM_MAX_DISTANCE: usize = 2;
+
+/// 候補となった名前
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub name: String,
+    /// 入力との編集距離
+    pub distance: usize,
+}
+
+/// 2 つの文字列の Levenshtein 距離（文字単位）
+///
+/// # Arguments
+///
+/// * `a` - First string.
+/// * `b` - Second string.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// 入力の長さに応じた、候補として扱う距離の上限
+///
+/// 3 文字以下は 1、4〜7 文字は 2、それ以上は 3。
+///
+/// # Arguments
+///
+/// * `input` - Name the user typed.
+pub fn max_distance(input: &str) -> usize {
+    match input.chars().count() {
+        0..=3 => 1,
+        4..=7 => 2,
+        _ => 3,
+    }
+}
+
+/// 入力に近い候補を距離の近い順（同距離は名前順）に最大 [`MAX_SUGGESTIONS`] 件返す
+///
+/// 入力と完全一致する候補と重複は除く。
+///
+/// # Arguments
+///
+/// * `input` - Name the user typed.
+/// * `candidates` - Known names to compare against.
+pub fn suggest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<Suggestion> {
+    let input_lower = input.to_lowercase();
+    let limit = max_distance(input);
+
+    let mut suggestions: Vec<Suggestion> = Vec::new();
+    for name in candidates {
+        if name == input || suggestions.iter().any(|s| s.name == name) {
+            continue;
+        }
+        let distance = levenshtein(&input_lower, &name.to_lowercase());
+        if distance <= limit {
+            suggestions.push(Suggestion {
+                name: name.to_string(),
+                distance,
+            });
+        }
+    }
+    suggestions.sort_by(|a, b| a.distance.cmp(&b.distance).then(a.name.cmp(&b.name)));
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+/// 確認のうえ置き換えてよい唯一の候補（候補が 1 件で十分近い場合のみ）
+///
+/// # Arguments
+///
+/// * `suggestions` - Result of [`suggest`].
+pub fn confirmable(suggestions: &[Suggestion]) -> Option<&Suggestion> {
+    match suggestions {
+        [only] if only.distance <= CONFIRM_MAX_DISTANCE => Some(only),
+        _ => None,
+    }
+}
+
+/// `did you mean: a, b?` の 1 行（候補が無ければ `None`）
+///
+/// # Arguments
+///
+/// * `suggestions` - Result of [`suggest`].
+pub fn did_you_mean(suggestions: &[Suggestion]) -> Option<String> {
+    if suggestions.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = suggestions.iter().map(|s| s.name.as_str()).collect();
+    Some(format!("did you mean: {}?", names.join(", ")))
+}
+
+#[cfg(test)]
+#[path = "suggest_test.rs"]
+mod tests;
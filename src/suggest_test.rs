@@ -0,0 +1,85 @@
+use super::*;
+
+fn names(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.name.as_str()).collect()
+}
+
+#[test]
+fn levenshtein_counts_insertions_deletions_and_substitutions() {
+    assert_eq!(levenshtein("", ""), 0);
+    assert_eq!(levenshtein("abc", ""), 3);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("my-plugn", "my-plugin"), 1);
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("formatter", "formatter"), 0);
+}
+
+#[test]
+fn levenshtein_counts_characters_not_bytes() {
+    assert_eq!(levenshtein("プラグイン", "プラグン"), 1);
+}
+
+#[test]
+fn max_distance_grows_with_input_length() {
+    assert_eq!(max_distance("abc"), 1);
+    assert_eq!(max_distance("abcd"), 2);
+    assert_eq!(max_distance("abcdefg"), 2);
+    assert_eq!(max_distance("abcdefgh"), 3);
+}
+
+#[test]
+fn suggest_keeps_candidates_within_threshold() {
+    // "lint" (4 文字) のしきい値は 2
+    let candidates = ["linter", "lint-x", "list", "formatter"];
+    assert_eq!(
+        names(&suggest("lint", candidates)),
+        vec!["list", "lint-x", "linter"]
+    );
+    // 3 文字以下はしきい値 1
+    assert!(suggest("abc", ["abxy"]).is_empty());
+    assert_eq!(names(&suggest("abc", ["abx"])), vec!["abx"]);
+}
+
+#[test]
+fn suggest_orders_by_distance_and_caps_at_three() {
+    let candidates = ["formattera", "formatters", "formater", "formatter-x", "xx"];
+    let suggestions = suggest("formatter", candidates);
+    assert_eq!(suggestions.len(), MAX_SUGGESTIONS);
+    assert_eq!(
+        names(&suggestions),
+        vec!["formater", "formattera", "formatters"]
+    );
+}
+
+#[test]
+fn suggest_ignores_case_and_skips_exact_and_duplicate_names() {
+    let suggestions = suggest("My-Plugn", ["my-plugin", "my-plugin", "My-Plugn"]);
+    assert_eq!(names(&suggestions), vec!["my-plugin"]);
+    assert_eq!(suggestions[0].distance, 1);
+}
+
+#[test]
+fn confirmable_requires_single_close_candidate() {
+    let close = suggest("my-plugn", ["my-plugin"]);
+    assert_eq!(
+        confirmable(&close).map(|s| s.name.as_str()),
+        Some("my-plugin")
+    );
+
+    // 距離 3 は候補にはなるが確認対象にはしない
+    let far = suggest("formatter", ["formxxxer"]);
+    assert_eq!(far.len(), 1);
+    assert!(confirmable(&far).is_none());
+
+    let many = suggest("my-plugn", ["my-plugin", "my-plugins"]);
+    assert!(confirmable(&many).is_none());
+}
+
+#[test]
+fn did_you_mean_lists_names() {
+    assert_eq!(did_you_mean(&[]), None);
+    assert_eq!(
+        did_you_mean(&suggest("my-plugn", ["my-plugin", "my-plugins"])).as_deref(),
+        Some("did you mean: my-plugin, my-plugins?")
+    );
+}
@@ -27,7 +27,8 @@ mod model;
 pub use crate::fs::{FileSystem, RealFs};
 pub use endpoint::{SyncDestination, SyncSource};
 pub use model::{
-    PlacedComponent, PlacedRef, SyncAction, SyncFailure, SyncOptions, SyncOutcome, SyncableKind,
+    PlacedComponent, PlacedRef, SyncAction, SyncDiff, SyncFailure, SyncOptions, SyncOutcome,
+    SyncableKind,
 };
 
 use crate::component::{convert, ComponentKind};
@@ -67,6 +68,46 @@ pub(crate) fn sync_with_fs(
     options: &SyncOptions,
     fs: &dyn FileSystem,
 ) -> Result<SyncOutcome> {
+    let diff = diff_with_fs(source, dest, options, fs)?;
+    if options.dry_run {
+        return Ok(diff.into_dry_run());
+    }
+    execute_sync(source, dest, diff, fs)
+}
+
+/// 同期元と同期先の差分を計算する（ファイルは変更しない）
+///
+/// `sync` と同じ判定で、作成・更新・削除が必要なコンポーネントを返す。
+///
+/// # Arguments
+///
+/// * `source` - Source target to read placed components from.
+/// * `dest` - Destination target to compare against.
+/// * `options` - Options controlling scope and component type (`dry_run` is ignored).
+pub fn diff(
+    source: &SyncSource,
+    dest: &SyncDestination,
+    options: &SyncOptions,
+) -> Result<SyncDiff> {
+    diff_with_fs(source, dest, options, &RealFs)
+}
+
+/// 差分計算（FileSystem を注入）
+///
+/// 各一覧は種別・名前・スコープ順に並べる。
+///
+/// # Arguments
+///
+/// * `source` - Source target to read placed components from.
+/// * `dest` - Destination target to compare against.
+/// * `options` - Options controlling scope and component type.
+/// * `fs` - File system abstraction used for existence, mtime and content-hash checks.
+pub(crate) fn diff_with_fs(
+    source: &SyncSource,
+    dest: &SyncDestination,
+    options: &SyncOptions,
+    fs: &dyn FileSystem,
+) -> Result<SyncDiff> {
     let source_components = collect_components(Endpoint::Source(source), options)?;
     let dest_components = collect_components(Endpoint::Destination(dest), options)?;
 
@@ -79,51 +120,44 @@ pub(crate) fn sync_with_fs(
         .map(|c| (c.placed_ref(), c))
         .collect();
 
-    let mut to_create = Vec::new();
-    let mut to_update = Vec::new();
-    let mut skipped = Vec::new();
-    let mut unsupported = Vec::new();
-
+    let mut diff = SyncDiff::default();
     for (placed_ref, src_component) in &source_map {
-        if !dest.supports(placed_ref) {
-            unsupported.push((*src_component).clone());
-            continue;
-        }
-
-        match dest_map.get(placed_ref) {
-            None => to_create.push((*src_component).clone()),
-            Some(dest_component) => {
-                if needs_update(src_component, dest_component, fs)? {
-                    to_update.push((*src_component).clone());
-                } else {
-                    skipped.push((*src_component).clone());
+        let group = if !dest.supports(placed_ref) {
+            &mut diff.unsupported
+        } else {
+            match dest_map.get(placed_ref) {
+                None => &mut diff.to_create,
+                Some(dest_component) if needs_update(src_component, dest_component, fs)? => {
+                    &mut diff.to_update
                 }
+                Some(_) => &mut diff.unchanged,
             }
-        }
+        };
+        group.push((*src_component).clone());
     }
 
-    let to_delete: Vec<PlacedComponent> = dest_map
+    diff.to_delete = dest_map
         .iter()
         .filter(|(id, _)| !source_map.contains_key(*id))
         .map(|(_, c)| (*c).clone())
         .collect();
 
-    if options.dry_run {
-        return Ok(SyncOutcome::dry_run(
-            to_create,
-            to_update,
-            to_delete,
-            skipped,
-            unsupported,
-        ));
+    for group in [
+        &mut diff.to_create,
+        &mut diff.to_update,
+        &mut diff.to_delete,
+        &mut diff.unchanged,
+        &mut diff.unsupported,
+    ] {
+        group.sort_by(|a, b| {
+            (a.kind().as_str(), a.name(), a.scope().as_str()).cmp(&(
+                b.kind().as_str(),
+                b.name(),
+                b.scope().as_str(),
+            ))
+        });
     }
-
-    let plan = SyncPlan {
-        to_create,
-        to_update,
-        to_delete,
-    };
-    execute_sync(source, dest, plan, fs)
+    Ok(diff)
 }
 
 fn collect_components(
@@ -161,25 +195,18 @@ fn needs_update(
     Ok(fs.content_hash(&src.path)? != fs.content_hash(&dest.path)?)
 }
 
-/// 同期の実行計画
-struct SyncPlan {
-    to_create: Vec<PlacedComponent>,
-    to_update: Vec<PlacedComponent>,
-    to_delete: Vec<PlacedComponent>,
-}
-
 /// 同期を実行
 ///
 /// # Arguments
 ///
 /// * `source` - Source target used to resolve source paths.
 /// * `dest` - Destination target used to resolve destination paths.
-/// * `plan` - Precomputed plan containing create/update/delete component sets.
+/// * `plan` - Precomputed diff; only the create/update/delete sets are applied.
 /// * `fs` - File system abstraction used to perform copy, remove and directory operations.
 fn execute_sync(
     source: &SyncSource,
     dest: &SyncDestination,
-    plan: SyncPlan,
+    plan: SyncDiff,
     fs: &dyn FileSystem,
 ) -> Result<SyncOutcome> {
     let mut result = SyncOutcome::default();
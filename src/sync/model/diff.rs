@@ -0,0 +1,38 @@
+//! 同期元と同期先の差分の定義
+
+use super::placed::PlacedComponent;
+use super::result::SyncOutcome;
+
+/// 同期元と同期先の差分（同期で行う操作の一覧）
+///
+/// `plm sync` の実行・dry-run と `--backup` の退避対象は同じ差分から組み立てる。
+#[derive(Debug, Clone, Default)]
+pub struct SyncDiff {
+    /// 同期先に無い（作成が必要な）コンポーネント
+    pub to_create: Vec<PlacedComponent>,
+    /// 内容が異なる（更新が必要な）コンポーネント
+    pub to_update: Vec<PlacedComponent>,
+    /// 同期元に無い（同期先から削除される）コンポーネント
+    pub to_delete: Vec<PlacedComponent>,
+    /// 変更なしのコンポーネント
+    pub unchanged: Vec<PlacedComponent>,
+    /// 同期先がサポートしないコンポーネント
+    pub unsupported: Vec<PlacedComponent>,
+}
+
+impl SyncDiff {
+    /// dry-run の結果に変換する
+    pub fn into_dry_run(self) -> SyncOutcome {
+        SyncOutcome::dry_run(
+            self.to_create,
+            self.to_update,
+            self.to_delete,
+            self.unchanged,
+            self.unsupported,
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "diff_test.rs"]
+mod tests;
@@ -0,0 +1,25 @@
+use super::*;
+use crate::component::{ComponentKind, Scope};
+
+fn make_component(name: &str) -> PlacedComponent {
+    PlacedComponent::new(ComponentKind::Skill, name, Scope::Project, "/path")
+}
+
+#[test]
+fn test_sync_diff_into_dry_run_keeps_every_group() {
+    let outcome = SyncDiff {
+        to_create: vec![make_component("c1")],
+        to_update: vec![make_component("u1")],
+        to_delete: vec![make_component("d1")],
+        unchanged: vec![make_component("s1")],
+        unsupported: vec![make_component("n1")],
+    }
+    .into_dry_run();
+
+    assert!(outcome.dry_run);
+    assert_eq!(outcome.created[0].name(), "c1");
+    assert_eq!(outcome.updated[0].name(), "u1");
+    assert_eq!(outcome.deleted[0].name(), "d1");
+    assert_eq!(outcome.skipped[0].name(), "s1");
+    assert_eq!(outcome.unsupported[0].name(), "n1");
+}
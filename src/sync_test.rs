@@ -264,3 +264,35 @@ fn test_sync_with_fs_uses_destination_supports_for_unsupported_dry_run() {
     assert!(result.created.is_empty());
     assert!(result.failed.is_empty());
 }
+
+#[test]
+fn test_diff_with_fs_matches_dry_run_plan() {
+    let source = fake_source(FakeTarget::with_placed(vec!["beta", "alpha"]));
+    let dest = fake_destination(FakeTarget::with_placed(vec!["gamma", "beta"]));
+    let fs = MockFs::new();
+
+    let diff = diff_with_fs(&source, &dest, &skill_project_options(), &fs).unwrap();
+    let names = |components: &[PlacedComponent]| {
+        components
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&diff.to_create), ["alpha"]);
+    assert_eq!(names(&diff.to_update), ["beta"]);
+    assert_eq!(names(&diff.to_delete), ["gamma"]);
+
+    let dry_run = sync_with_fs(
+        &source,
+        &dest,
+        &SyncOptions {
+            dry_run: true,
+            ..skill_project_options()
+        },
+        &fs,
+    )
+    .unwrap();
+    assert_eq!(names(&dry_run.created), names(&diff.to_create));
+    assert_eq!(names(&dry_run.updated), names(&diff.to_update));
+    assert_eq!(names(&dry_run.deleted), names(&diff.to_delete));
+}
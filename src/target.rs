@@ -23,6 +23,7 @@ mod core;
 mod effect;
 mod env;
 mod placed;
+mod reserved;
 
 pub(crate) use core::paths;
 pub use core::{AddOutcome, RemoveOutcome, TargetRegistry};
@@ -33,6 +34,7 @@ pub use env::{
 };
 pub use placed::scanner;
 pub(crate) use placed::{filter, list_all_placed, list_helpers, placement_helpers, scope_support};
+pub use reserved::{reserved_name_conflicts, ReservedNameConflict};
 // PluginOrigin はモジュール内で定義されているのでここでは再エクスポート不要
 
 use crate::component::{
@@ -200,6 +202,92 @@ impl TargetKind {
             TargetKind::GeminiCli => AgentFormat::ClaudeCode, // Gemini CLI は Agent 非サポート
         }
     }
+
+    /// ターゲット固有の痕跡（プロジェクトルートからの相対パス）
+    ///
+    /// いずれかが存在すれば、そのプロジェクトはこのターゲットを使っているとみなす。
+    pub fn detect_markers(&self) -> &'static [&'static str] {
+        match self {
+            TargetKind::Antigravity => &[".agent"],
+            TargetKind::Codex => &[".codex"],
+            // `.github/` 自体は CI 等でも使われるため Copilot 専用のパスのみ
+            TargetKind::Copilot => &[
+                ".github/copilot-instructions.md",
+                ".github/agents",
+                ".github/prompts",
+                ".github/skills",
+                ".github/hooks",
+            ],
+            TargetKind::Cursor => &[".cursor", ".cursorrules"],
+            TargetKind::GeminiCli => &[".gemini"],
+        }
+    }
+
+    /// ターゲット組み込みの予約名（同名のコンポーネントは無視される・紛らわしい）
+    ///
+    /// 予約名が分かっていない組み合わせは空。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind to look up.
+    pub fn reserved_names(&self, kind: ComponentKind) -> &'static [&'static str] {
+        match (self, kind) {
+            (TargetKind::Codex, ComponentKind::Command) => reserved::CODEX_COMMANDS,
+            (TargetKind::Copilot, ComponentKind::Command) => reserved::COPILOT_COMMANDS,
+            _ => &[],
+        }
+    }
+
+    /// Claude Code の設定ディレクトリ（`.claude/`）の同種コンポーネントも互換パスとして読むか
+    ///
+    /// 読むターゲットへ配置すると、`.claude/` に残った元ファイルと二重に読み込まれる。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind to check.
+    pub fn reads_claude_dir(&self, kind: ComponentKind) -> bool {
+        matches!(
+            (self, kind),
+            (
+                TargetKind::Cursor,
+                ComponentKind::Skill | ComponentKind::Agent
+            )
+        )
+    }
+
+    /// 配置後、開いたままのセッション / ウィンドウに変更を反映させるための操作の案内
+    ///
+    /// 新しいコマンドやスキルを起動中のツールが読み込まないターゲットだけが返す。
+    pub fn post_deploy_hint(&self) -> Option<&'static str> {
+        match self {
+            TargetKind::Antigravity => Some("restart the Antigravity session"),
+            TargetKind::Codex => Some("restart the Codex session"),
+            TargetKind::Copilot => Some("reload the VS Code window (Developer: Reload Window)"),
+            TargetKind::Cursor => Some("reload the Cursor window (Developer: Reload Window)"),
+            TargetKind::GeminiCli => Some("restart the Gemini CLI session"),
+        }
+    }
+
+    /// 複数ターゲットが読む共有の痕跡（プロジェクトルートからの相対パス）
+    ///
+    /// これだけではどのターゲットか特定できないため `DetectResult::Ambiguous` になる。
+    pub fn shared_markers(&self) -> &'static [&'static str] {
+        match self {
+            TargetKind::Codex | TargetKind::Copilot | TargetKind::Cursor => &["AGENTS.md"],
+            TargetKind::Antigravity | TargetKind::GeminiCli => &["GEMINI.md"],
+        }
+    }
+}
+
+/// プロジェクト内のターゲット痕跡の判定結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectResult {
+    /// ターゲット固有の痕跡がある
+    Present,
+    /// 痕跡がない
+    Absent,
+    /// 複数ターゲットで共有される痕跡（`AGENTS.md` など）だけがある
+    Ambiguous,
 }
 
 #[derive(Debug, Default)]
@@ -239,6 +327,42 @@ pub trait Target: Send + Sync {
     /// サポートするコンポーネント種別
     fn supported_components(&self) -> &[ComponentKind];
 
+    /// 組み込みの予約名（`/help` など、プラグインが同名で配置すべきでない名前）
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Component kind to look up.
+    fn reserved_names(&self, kind: ComponentKind) -> &[&str] {
+        self.kind().reserved_names(kind)
+    }
+
+    /// 配置後に変更を反映させるための操作の案内（不要なら `None`）
+    fn post_deploy_hint(&self) -> Option<&str> {
+        self.kind().post_deploy_hint()
+    }
+
+    /// プロジェクトがこのターゲットを使っているかを痕跡から判定する
+    ///
+    /// 固有の痕跡（`TargetKind::detect_markers`）があれば `Present`、
+    /// 共有の痕跡（`TargetKind::shared_markers`）だけなら `Ambiguous`、
+    /// どちらも無ければ `Absent`。
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Project root directory to inspect.
+    fn detect(&self, project_root: &Path) -> DetectResult {
+        let fs = RealFs;
+        let kind = self.kind();
+        let exists = |markers: &[&str]| markers.iter().any(|m| fs.exists(&project_root.join(m)));
+        if exists(kind.detect_markers()) {
+            DetectResult::Present
+        } else if exists(kind.shared_markers()) {
+            DetectResult::Ambiguous
+        } else {
+            DetectResult::Absent
+        }
+    }
+
     /// 指定コンポーネント種別をサポートするか
     ///
     /// # Arguments
@@ -387,6 +511,71 @@ pub fn all_targets() -> Vec<Box<dyn Target>> {
     ]
 }
 
+/// 配置先ターゲットごとの反映手順の案内を `(ターゲット名, 案内)` で返す
+///
+/// 案内を持たないターゲットと未知のターゲット名は含めない。順序は `target_names` 順で、
+/// 重複は除く。
+///
+/// # Arguments
+///
+/// * `target_names` - Names of the targets components were deployed to.
+pub fn post_deploy_hints<S: AsRef<str>>(target_names: &[S]) -> Vec<(&'static str, String)> {
+    let mut hints: Vec<(&'static str, String)> = Vec::new();
+    for target in target_names
+        .iter()
+        .filter_map(|name| parse_target(name.as_ref()).ok())
+    {
+        if hints.iter().any(|(name, _)| *name == target.name()) {
+            continue;
+        }
+        if let Some(hint) = target.post_deploy_hint() {
+            hints.push((target.name(), hint.to_string()));
+        }
+    }
+    hints
+}
+
+/// 全ターゲットの検出結果を `all_targets()` 順に返す
+///
+/// # Arguments
+///
+/// * `project_root` - Project root directory to inspect.
+pub fn detect_targets(project_root: &Path) -> Vec<(TargetKind, DetectResult)> {
+    all_targets()
+        .iter()
+        .map(|t| (t.kind(), t.detect(project_root)))
+        .collect()
+}
+
+/// auto モードで使うターゲットを決める
+///
+/// 優先順位:
+/// 1. `Present` のターゲットが 1 つ以上あれば、それらすべて
+/// 2. 無ければ `Ambiguous` のターゲットすべて（共有の痕跡を読む全ターゲット）
+/// 3. どちらも無ければ空
+///
+/// 順序は `all_targets()` 順。
+///
+/// # Arguments
+///
+/// * `project_root` - Project root directory to inspect.
+pub fn auto_targets(project_root: &Path) -> Vec<TargetKind> {
+    let detected = detect_targets(project_root);
+    let with = |result: DetectResult| -> Vec<TargetKind> {
+        detected
+            .iter()
+            .filter(|(_, r)| *r == result)
+            .map(|(k, _)| *k)
+            .collect()
+    };
+    let present = with(DetectResult::Present);
+    if present.is_empty() {
+        with(DetectResult::Ambiguous)
+    } else {
+        present
+    }
+}
+
 #[cfg(test)]
 #[path = "target_test.rs"]
 mod tests;
@@ -0,0 +1,107 @@
+//! ターゲット組み込みの予約名
+//!
+//! 各ターゲットには組み込みのスラッシュコマンド（`/help`、`/clear` など）があり、
+//! プラグインが同名のコマンドを配置すると無視されたり紛らわしくなったりする。
+//! 予約名はターゲットごとの定数で管理し、`TargetKind::reserved_names` の表から参照する。
+//! 予約名が分かっているターゲットだけ定数を持つ（追加時は定数と表の 1 行を足す）。
+
+use super::Target;
+use crate::component::{CommandPrefix, Component, ComponentKind};
+
+/// Codex CLI の組み込みスラッシュコマンド
+pub(super) const CODEX_COMMANDS: &[&str] = &[
+    "approvals",
+    "compact",
+    "diff",
+    "exit",
+    "init",
+    "logout",
+    "mcp",
+    "mention",
+    "model",
+    "new",
+    "quit",
+    "review",
+    "status",
+    "undo",
+];
+
+/// GitHub Copilot Chat の組み込みスラッシュコマンド
+pub(super) const COPILOT_COMMANDS: &[&str] = &[
+    "clear",
+    "explain",
+    "fix",
+    "fixTestFailure",
+    "help",
+    "new",
+    "newNotebook",
+    "search",
+    "setupTests",
+    "startDebugging",
+    "tests",
+];
+
+/// 予約名と衝突するコンポーネント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservedNameConflict {
+    /// ターゲット名
+    pub target: String,
+    /// コンポーネント種別
+    pub kind: ComponentKind,
+    /// ターゲットでの呼び出し名
+    pub name: String,
+}
+
+impl ReservedNameConflict {
+    /// 衝突内容と回避策（リネーム / `--prefix`）の案内
+    pub fn message(&self) -> String {
+        format!(
+            "{} '{}' has the same name as a built-in {} command; rename it or namespace it with `plm install --prefix <name>:`",
+            self.kind.as_str(),
+            self.name,
+            self.target
+        )
+    }
+}
+
+/// 予約名と衝突するコンポーネントを列挙する
+///
+/// 比較はターゲットでの呼び出し名（プレフィクス適用後）で行い、大文字小文字は区別しない。
+///
+/// # Arguments
+///
+/// * `target` - Target the components are deployed to.
+/// * `components` - Scanned components of the plugin.
+/// * `prefix` - Command prefix applied at deployment, if any.
+pub fn reserved_name_conflicts(
+    target: &dyn Target,
+    components: &[Component],
+    prefix: Option<&CommandPrefix>,
+) -> Vec<ReservedNameConflict> {
+    components
+        .iter()
+        .filter(|c| target.supports(c.kind))
+        .filter_map(|c| {
+            let original = c.original_name.as_deref().unwrap_or(&c.name);
+            let name = match prefix {
+                Some(p) if c.kind == ComponentKind::Command => {
+                    p.invocation_name(target.kind(), original)
+                }
+                _ => original.to_string(),
+            };
+            target
+                .reserved_names(c.kind)
+                .iter()
+                .any(|r| r.eq_ignore_ascii_case(&name))
+                .then(|| ReservedNameConflict {
+                    target: target.name().to_string(),
+                    kind: c.kind,
+                    name,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "reserved_test.rs"]
+mod tests;
@@ -0,0 +1,54 @@
+use super::*;
+use crate::target::parse_target;
+use crate::target::TargetKind;
+use std::path::PathBuf;
+
+fn command(original: &str) -> Component {
+    Component::flattened(
+        ComponentKind::Command,
+        "demo",
+        original,
+        PathBuf::from(format!("commands/{}.md", original)),
+    )
+}
+
+#[test]
+fn reserved_names_are_listed_per_target_and_kind() {
+    assert!(TargetKind::Codex
+        .reserved_names(ComponentKind::Command)
+        .contains(&"init"));
+    assert!(TargetKind::Copilot
+        .reserved_names(ComponentKind::Command)
+        .contains(&"help"));
+    assert!(TargetKind::Codex
+        .reserved_names(ComponentKind::Skill)
+        .is_empty());
+}
+
+#[test]
+fn conflicts_report_commands_with_builtin_names() {
+    let target = parse_target("copilot").unwrap();
+    let components = vec![command("Help"), command("deploy")];
+
+    let conflicts = reserved_name_conflicts(target.as_ref(), &components, None);
+
+    assert_eq!(
+        conflicts,
+        vec![ReservedNameConflict {
+            target: "copilot".to_string(),
+            kind: ComponentKind::Command,
+            name: "Help".to_string(),
+        }]
+    );
+    assert!(conflicts[0].message().contains("--prefix"));
+}
+
+#[test]
+fn prefixed_commands_do_not_conflict() {
+    let target = parse_target("codex").unwrap();
+    let prefix = CommandPrefix::parse("demo:").unwrap();
+
+    let conflicts = reserved_name_conflicts(target.as_ref(), &[command("init")], prefix.as_ref());
+
+    assert!(conflicts.is_empty());
+}
@@ -62,6 +62,22 @@ fn test_target_kind_cursor_as_str_and_formats() {
     );
 }
 
+#[test]
+fn test_reads_claude_dir_only_for_cursor_skills_and_agents() {
+    assert!(TargetKind::Cursor.reads_claude_dir(ComponentKind::Skill));
+    assert!(TargetKind::Cursor.reads_claude_dir(ComponentKind::Agent));
+    assert!(!TargetKind::Cursor.reads_claude_dir(ComponentKind::Command));
+    for kind in [
+        TargetKind::Antigravity,
+        TargetKind::Codex,
+        TargetKind::Copilot,
+        TargetKind::GeminiCli,
+    ] {
+        assert!(!kind.reads_claude_dir(ComponentKind::Skill));
+        assert!(!kind.reads_claude_dir(ComponentKind::Agent));
+    }
+}
+
 #[test]
 fn test_name_is_derived_from_kind() {
     // `Target::name()` は `kind().as_str()` から導出される単一の真実源。
@@ -165,3 +181,154 @@ fn test_plugin_origin_from_cached_plugin_named_marketplace() {
 fn test_plugin_origin_unknown_has_no_dir_names() {
     assert_eq!(PluginOrigin::Unknown.dir_names(), None);
 }
+
+// =============================================================================
+// detect
+// =============================================================================
+
+fn detect_in(files: &[&str], kind: TargetKind) -> DetectResult {
+    let dir = tempfile::TempDir::new().unwrap();
+    for f in files {
+        let path = dir.path().join(f);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        if f.ends_with(".md") || f.ends_with("rules") {
+            std::fs::write(&path, "").unwrap();
+        } else {
+            std::fs::create_dir_all(&path).unwrap();
+        }
+    }
+    parse_target(kind.as_str()).unwrap().detect(dir.path())
+}
+
+#[test]
+fn test_detect_absent_in_empty_project() {
+    for target in all_targets() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(target.detect(dir.path()), DetectResult::Absent);
+    }
+}
+
+#[test]
+fn test_detect_codex_markers() {
+    assert_eq!(
+        detect_in(&[".codex"], TargetKind::Codex),
+        DetectResult::Present
+    );
+    assert_eq!(
+        detect_in(&["AGENTS.md"], TargetKind::Codex),
+        DetectResult::Ambiguous
+    );
+}
+
+#[test]
+fn test_detect_copilot_markers() {
+    assert_eq!(
+        detect_in(&[".github/copilot-instructions.md"], TargetKind::Copilot),
+        DetectResult::Present
+    );
+    assert_eq!(
+        detect_in(&[".github/agents"], TargetKind::Copilot),
+        DetectResult::Present
+    );
+    // `.github/` だけ（CI 設定など）では Copilot とみなさない
+    assert_eq!(
+        detect_in(&[".github/workflows"], TargetKind::Copilot),
+        DetectResult::Absent
+    );
+    assert_eq!(
+        detect_in(&["AGENTS.md"], TargetKind::Copilot),
+        DetectResult::Ambiguous
+    );
+}
+
+#[test]
+fn test_detect_cursor_markers() {
+    assert_eq!(
+        detect_in(&[".cursor"], TargetKind::Cursor),
+        DetectResult::Present
+    );
+    assert_eq!(
+        detect_in(&[".cursorrules"], TargetKind::Cursor),
+        DetectResult::Present
+    );
+    assert_eq!(
+        detect_in(&["AGENTS.md"], TargetKind::Cursor),
+        DetectResult::Ambiguous
+    );
+}
+
+#[test]
+fn test_detect_antigravity_and_gemini_markers() {
+    assert_eq!(
+        detect_in(&[".agent"], TargetKind::Antigravity),
+        DetectResult::Present
+    );
+    assert_eq!(
+        detect_in(&[".gemini"], TargetKind::GeminiCli),
+        DetectResult::Present
+    );
+    // `.gemini` は Gemini CLI 固有で Antigravity の痕跡ではない
+    assert_eq!(
+        detect_in(&[".gemini"], TargetKind::Antigravity),
+        DetectResult::Absent
+    );
+    assert_eq!(
+        detect_in(&["GEMINI.md"], TargetKind::Antigravity),
+        DetectResult::Ambiguous
+    );
+    assert_eq!(
+        detect_in(&["GEMINI.md"], TargetKind::GeminiCli),
+        DetectResult::Ambiguous
+    );
+}
+
+#[test]
+fn test_detect_specific_marker_wins_over_shared() {
+    assert_eq!(
+        detect_in(&[".codex", "AGENTS.md"], TargetKind::Codex),
+        DetectResult::Present
+    );
+}
+
+#[test]
+fn test_detect_targets_follows_all_targets_order() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join(".codex")).unwrap();
+
+    let detected = detect_targets(dir.path());
+    let kinds: Vec<TargetKind> = detected.iter().map(|(k, _)| *k).collect();
+    let expected: Vec<TargetKind> = all_targets().iter().map(|t| t.kind()).collect();
+    assert_eq!(kinds, expected);
+    assert!(detected.contains(&(TargetKind::Codex, DetectResult::Present)));
+    assert!(detected.contains(&(TargetKind::Copilot, DetectResult::Absent)));
+}
+
+#[test]
+fn test_auto_targets_prefers_present_over_ambiguous() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join(".codex")).unwrap();
+    std::fs::create_dir_all(dir.path().join(".cursor")).unwrap();
+    std::fs::write(dir.path().join("AGENTS.md"), "").unwrap();
+
+    assert_eq!(
+        auto_targets(dir.path()),
+        vec![TargetKind::Codex, TargetKind::Cursor]
+    );
+}
+
+#[test]
+fn test_auto_targets_falls_back_to_ambiguous() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(dir.path().join("AGENTS.md"), "").unwrap();
+
+    assert_eq!(
+        auto_targets(dir.path()),
+        vec![TargetKind::Codex, TargetKind::Copilot, TargetKind::Cursor]
+    );
+}
+
+#[test]
+fn test_auto_targets_empty_without_markers() {
+    let dir = tempfile::TempDir::new().unwrap();
+    assert!(auto_targets(dir.path()).is_empty());
+}
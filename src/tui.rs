@@ -5,6 +5,7 @@
 mod dialog;
 pub mod manager;
 pub(crate) mod output_suppress;
+pub mod picker;
 mod scope_select;
 mod target_select;
 
@@ -2,6 +2,7 @@
 //!
 //! multi_select と single_select の共通機能を提供する。
 
+use crate::tui::manager::Theme;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::ExecutableCommand;
@@ -142,6 +143,7 @@ pub fn multi_select<T: Clone>(
     title: &str,
     items: &mut [SelectItem<T>],
 ) -> io::Result<MultiSelectOutcome<T>> {
+    let theme = Theme::current();
     terminal::enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
 
@@ -166,7 +168,7 @@ pub fn multi_select<T: Clone>(
                     let style = if item.enabled {
                         Style::default()
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     };
 
                     let text = match &item.description {
@@ -185,7 +187,7 @@ pub fn multi_select<T: Clone>(
             f.render_stateful_widget(list, chunks[0], &mut state);
 
             let help = Paragraph::new("↑/↓: move  space: toggle  enter: confirm  q/esc: cancel")
-                .style(Style::default().fg(Color::DarkGray));
+                .style(Style::default().fg(theme.muted));
             f.render_widget(help, chunks[1]);
         })?;
 
@@ -239,6 +241,7 @@ pub fn single_select<T: Clone>(
     title: &str,
     items: &[SelectItem<T>],
 ) -> io::Result<SingleSelectOutcome<T>> {
+    let theme = Theme::current();
     terminal::enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
 
@@ -266,7 +269,7 @@ pub fn single_select<T: Clone>(
                     let style = if item.enabled {
                         Style::default()
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        Style::default().fg(theme.muted)
                     };
 
                     let text = match &item.description {
@@ -285,7 +288,7 @@ pub fn single_select<T: Clone>(
             f.render_stateful_widget(list, chunks[0], &mut state);
 
             let help = Paragraph::new("↑/↓: move  enter: select  q/esc: cancel")
-                .style(Style::default().fg(Color::DarkGray));
+                .style(Style::default().fg(theme.muted));
             f.render_widget(help, chunks[1]);
         })?;
 
@@ -10,12 +10,28 @@
 //! - `update`: Msg に応じて Model を更新
 //! - `view`: Model から画面を描画
 //!
+//! ## 起動シーケンス
+//!
+//! 1. ローディング画面を表示し、DataStore をバックグラウンドスレッドでロードする
+//!    （ロード中も `q` で終了でき、失敗時は `r` で再試行できる）
+//! 2. ロード結果から `Model` を組み立て、通常画面のイベントループに入る
+//!
+//! イベントループは入力を `EventSource` から受け取る。マーケットプレイスの更新・削除は
+//! バックグラウンドで実行し、完了メッセージをチャネル経由で受け取って反映する
+//! （実行中も入力を受け付け、スピナーを進める）。`--replay` では操作スクリプトを
+//! 注入し、実ターミナルの代わりに `TestBackend` へ描画して終了時の状態をダンプする。
+//!
 //! ## モジュール構成
 //!
 //! - `core/`: コアモジュール
 //!   - `app`: Model/Screen/Msg/update/view のトップレベル定義
 //!   - `data`: 共有データストア（DataStore）
+//!   - `loading`: 起動時のローディング画面
+//!   - `event_source` / `script`: 入力元の抽象化と操作スクリプトの記録・再生
+//!   - `task` / `spinner`: 時間のかかる操作のバックグラウンド実行と処理中表示
 //!   - `common`: 共通 UI ユーティリティ
+//!   - `theme`: カラーテーマ（Theme）
+//!   - `tui_state`: 表示設定の永続化（`~/.plm/tui_state.json`）
 //! - `screens/`: 画面モジュール
 //!   - `installed`: Installed タブ
 //!   - `discover`: Discover タブ
@@ -25,55 +41,290 @@
 mod core;
 pub mod screens;
 
-use core::{update, view, Model};
-use crossterm::event::{self, Event, KeyEventKind};
+pub use core::theme::COLORFGBG_ENV;
+pub use core::{Theme, ThemeName};
+
+use core::event_source::{EventSource, TerminalEvents};
+use core::loading::{self, LoadingModel};
+use core::script::{self, RecordingEvents, ScriptEvents, ScriptStep};
+use core::{snapshot, update, view, DataStore, LoadProgress, Model, Msg, TuiState};
+use crossterm::event::{Event, KeyEventKind};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::ExecutableCommand;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
 use ratatui::prelude::*;
-use std::io::{self, stdout};
+use std::fmt::Write as _;
+use std::io::{self, stdout, Stdout};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// dev モードでファイル変更をポーリングする間隔
+const DEV_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// ローディング画面のスピナーを進める間隔
+const LOADING_TICK: Duration = Duration::from_millis(100);
+
+/// バックグラウンド操作の実行中にスピナーを進める間隔
+const SPINNER_TICK: Duration = Duration::from_millis(100);
+
+/// 再生時に描画する画面の幅
+const REPLAY_WIDTH: u16 = 100;
+
+/// 再生時に描画する画面の高さ
+const REPLAY_HEIGHT: u16 = 30;
 
 /// TUI を実行
-pub fn run() -> io::Result<()> {
+///
+/// # Arguments
+///
+/// * `dev_root` - plugin directory to watch in dev mode (`plm managed --dev <PATH>`)
+/// * `theme` - color theme used for rendering
+/// * `record` - file to write the pressed keys to as a script (`--record <FILE>`)
+pub fn run(dev_root: Option<PathBuf>, theme: Theme, record: Option<&Path>) -> io::Result<()> {
+    theme.install();
     terminal::enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
 
     let backend = CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    let mut model = Model::new()?;
+    let mut terminal_events = TerminalEvents;
+    let mut recorder = record.map(|_| RecordingEvents::new(TerminalEvents));
+
+    // ロード中に `q` で終了した場合は通常画面に入らない
+    if let Some(data) = wait_for_data(&mut terminal, &mut terminal_events)? {
+        let events: &mut dyn EventSource = match recorder.as_mut() {
+            Some(recorder) => recorder,
+            None => &mut terminal_events,
+        };
+        // 表示設定は best-effort（読めなければ既定値、保存の失敗は無視する）
+        let saved = TuiState::load();
+        let mut model = Model::from_data(data, dev_root).with_tui_state(saved);
+        run_model(&mut terminal, &mut model, events)?;
+        if model.tui_state != saved {
+            let _ = model.tui_state.save();
+        }
+    }
+
+    terminal::disable_raw_mode()?;
+    stdout().execute(LeaveAlternateScreen)?;
+
+    if let (Some(path), Some(recorder)) = (record, recorder) {
+        script::save(path, &recorder.into_steps())?;
+    }
+
+    Ok(())
+}
+
+/// 操作スクリプトを実ターミナルなしで再生し、終了時の状態と画面のダンプを返す
+///
+/// データは同期的にロードし、描画は `TestBackend` に行う（CI の headless 環境向け）。
+/// スクリプト中の操作（更新・削除など）は通常どおり実行される。
+///
+/// # Arguments
+///
+/// * `script_path` - script file to replay (`--replay <FILE>`)
+/// * `dev_root` - plugin directory to watch in dev mode (`plm managed --dev <PATH>`)
+/// * `theme` - color theme used for rendering
+pub fn replay(script_path: &Path, dev_root: Option<PathBuf>, theme: Theme) -> io::Result<String> {
+    theme.install();
+    let steps = script::load(script_path)?;
+    let data = DataStore::load(&LoadProgress::default())?;
+    replay_model(&mut Model::from_data(data, dev_root), steps)
+}
+
+/// モデルにスクリプトを流し込み、終了時の状態と画面のダンプを返す
+///
+/// スクリプトの途中で終了（`q`）した場合は残りのステップを実行しない。
+///
+/// # Arguments
+///
+/// * `model` - the application model to drive
+/// * `steps` - script steps to inject into the event loop
+fn replay_model(model: &mut Model, steps: Vec<ScriptStep>) -> io::Result<String> {
+    let mut terminal =
+        Terminal::new(TestBackend::new(REPLAY_WIDTH, REPLAY_HEIGHT)).map_err(io::Error::other)?;
+    run_model(&mut terminal, model, &mut ScriptEvents::new(steps))?;
+    // 実行中の操作の完了を待って結果を反映する
+    while let Some(msg) = model.tasks.recv_pending() {
+        dispatch(&mut terminal, model, msg)?;
+    }
+    // 最後の入力による変化を反映する
+    draw(&mut terminal, model)?;
+    Ok(dump(model, terminal.backend().buffer()))
+}
+
+/// 終了時のモデル状態と描画バッファを比較用テキストにする
+///
+/// # Arguments
+///
+/// * `model` - the application model after replay
+/// * `buffer` - the last rendered buffer
+fn dump(model: &Model, buffer: &Buffer) -> String {
+    let mut out = String::from("--- model ---\n");
+    let _ = writeln!(out, "tab: {}", model.screen.tab().title());
+    let _ = writeln!(out, "top_level: {}", model.screen.is_top_level());
+    let _ = writeln!(out, "filter: {:?}", model.filter_text);
+    let _ = writeln!(out, "filter_focused: {}", model.filter_focused);
+    let _ = writeln!(out, "quit: {}", model.should_quit);
+    out.push_str("--- screen ---\n");
+    out.push_str(&snapshot::serialize(buffer));
+    out
+}
+
+/// モデルを描画する（バックエンドのエラーは `io::Error` にまとめる）
+///
+/// # Arguments
+///
+/// * `terminal` - the terminal to draw on
+/// * `model` - the application model to render
+fn draw<B: Backend>(terminal: &mut Terminal<B>, model: &Model) -> io::Result<()>
+where
+    B::Error: Send + Sync + 'static,
+{
+    terminal
+        .draw(|f| view(f, model))
+        .map(|_| ())
+        .map_err(io::Error::other)
+}
+
+/// ローディング画面を表示しながら DataStore のロード完了を待つ
+///
+/// 終了が選ばれた場合は `None` を返す。
+///
+/// # Arguments
+///
+/// * `terminal` - the terminal to draw the loading screen on
+/// * `events` - input source for the loading screen
+fn wait_for_data(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    events: &mut dyn EventSource,
+) -> io::Result<Option<DataStore>> {
+    let mut model = LoadingModel::start();
 
     while !model.should_quit {
-        terminal.draw(|f| view(f, &model))?;
+        if let Some(data) = model.poll() {
+            return Ok(Some(data));
+        }
+        terminal.draw(|f| loading::view(f, &model))?;
 
-        if let Event::Key(key) = event::read()? {
+        if !events.poll(LOADING_TICK)? {
+            loading::update(&mut model, loading::Msg::Tick);
+            continue;
+        }
+        if let Event::Key(key) = events.read()? {
             if key.kind == KeyEventKind::Press {
                 if let Some(msg) = model.key_to_msg(key.code) {
-                    let effect = update(&mut model, msg);
-
-                    // 2段階方式: Phase 1 後に描画してから Phase 2 メッセージを実行
-                    // バッチ更新中のキー入力はキューに溜まるが、完了後に破棄する
-                    //
-                    // Note: バッチ更新中は入力がブロックされるため、'q' を押しても終了しない。
-                    // 将来的な改善案:
-                    // - 更新中であることを示すメッセージを表示
-                    // - 'q' や Escape でキャンセル可能にする
-                    // - 進捗インジケータ（例: "Updating plugin 3 of 10..."）を表示
-                    if let Some(phase2_msg) = effect.phase2_msg {
-                        terminal.draw(|f| view(f, &model))?;
-                        update(&mut model, phase2_msg);
-                        // バッチ更新中にキューされたキー入力を破棄
-                        while event::poll(std::time::Duration::ZERO)? {
-                            let _ = event::read()?;
-                        }
-                        terminal.draw(|f| view(f, &model))?;
-                    }
+                    loading::update(&mut model, msg);
                 }
             }
         }
     }
+    Ok(None)
+}
 
-    terminal::disable_raw_mode()?;
-    stdout().execute(LeaveAlternateScreen)?;
+/// 通常画面のイベントループ
+///
+/// 終了するか、入力元が終端に達する（スクリプトの再生完了）まで続ける。
+///
+/// # Arguments
+///
+/// * `terminal` - the terminal to draw on
+/// * `model` - the application model built from the loaded data
+/// * `events` - input source (terminal, recorder or replayed script)
+fn run_model<B: Backend>(
+    terminal: &mut Terminal<B>,
+    model: &mut Model,
+    events: &mut dyn EventSource,
+) -> io::Result<()>
+where
+    B::Error: Send + Sync + 'static,
+{
+    let mut last_dev_poll = Instant::now();
+    while !model.should_quit && !events.is_finished() {
+        // 完了したバックグラウンド操作の結果を反映する
+        while let Some(msg) = model.tasks.try_recv() {
+            dispatch(terminal, model, msg)?;
+        }
+        draw(terminal, model)?;
+
+        // 操作の実行中や dev モード時はキー入力を待つ間も一定間隔で処理を進める
+        if let Some(interval) = tick_interval(model) {
+            if !events.poll(interval)? {
+                if model.tasks.is_busy() {
+                    update(model, Msg::SpinnerTick);
+                }
+                if model.data.dev_plugin.is_some() && last_dev_poll.elapsed() >= DEV_POLL_INTERVAL {
+                    last_dev_poll = Instant::now();
+                    update(model, Msg::DevTick);
+                }
+                continue;
+            }
+        }
+
+        if let Event::Key(key) = events.read()? {
+            if key.kind == KeyEventKind::Press {
+                // 同期のまま実行する操作（インストール・プラグインの一括更新）の間に
+                // キューされたキー入力は完了後に破棄する
+                if let Some(msg) = model.key_event_to_msg(key) {
+                    if dispatch(terminal, model, msg)? {
+                        events.discard_pending()?;
+                    }
+                }
+            }
+        }
+    }
 
     Ok(())
 }
+
+/// キー入力を待つ間に処理を進める間隔（待つだけでよければ `None`）
+///
+/// # Arguments
+///
+/// * `model` - the application model
+fn tick_interval(model: &Model) -> Option<Duration> {
+    if model.tasks.is_busy() {
+        Some(SPINNER_TICK)
+    } else if model.data.dev_plugin.is_some() {
+        Some(DEV_POLL_INTERVAL)
+    } else {
+        None
+    }
+}
+
+/// メッセージを適用し、返された Phase 2 メッセージを描画を挟みながら実行する
+///
+/// Phase 2 を実行した場合は `true` を返す。
+///
+/// # Arguments
+///
+/// * `terminal` - the terminal to draw on
+/// * `model` - the application model to update
+/// * `msg` - message from a key press or a finished background operation
+fn dispatch<B: Backend>(terminal: &mut Terminal<B>, model: &mut Model, msg: Msg) -> io::Result<bool>
+where
+    B::Error: Send + Sync + 'static,
+{
+    // 2段階方式: Phase 1 後に描画してから Phase 2 メッセージを実行
+    // Phase 2 がさらに Phase 2 を返す間は描画を挟んで続ける（1 件ずつ処理して
+    // 進捗を表示する一括操作用）
+    // マーケットプレイスの更新・削除は Phase 2 でバックグラウンドに渡すだけなのですぐ戻る
+    let mut phase2 = update(model, msg).phase2_msg;
+    if phase2.is_none() {
+        return Ok(false);
+    }
+    while let Some(phase2_msg) = phase2 {
+        // 同期のまま実行する一括操作でもステップごとにスピナーを進める
+        update(model, Msg::SpinnerTick);
+        draw(terminal, model)?;
+        phase2 = update(model, phase2_msg).phase2_msg;
+    }
+    draw(terminal, model)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+#[path = "manager_test.rs"]
+mod tests;
@@ -4,15 +4,40 @@
 //!
 //! - `app`: Model/Screen/Msg/update/view
 //! - `data`: DataStore（共有データ）
-//! - `common`: 共通 UI ユーティリティ
+//! - `common`: 共通 UI ユーティリティ（タブバー・フィルタバー・空結果表示）
+//! - `filter`: フィルタテキストによる一覧の絞り込み
+//! - `sort`: Installed タブの一覧の並び順
+//! - `dev`: dev モードの開発中プラグイン
+//! - `event_source`: イベントループへの入力元（実ターミナル / スクリプト）
+//! - `history`: 画面遷移の履歴スタック（Back で直前の状態を復元）
+//! - `loading`: 起動時のローディング画面（DataStore をバックグラウンドでロード）
+//! - `preview`: 破壊的アクションの確認ダイアログと影響プレビュー
+//! - `script`: 操作スクリプトの記録と再生（`--record` / `--replay`）
+//! - `snapshot`: 描画バッファのテキスト化（スナップショットテストと再生結果のダンプ）
+//! - `spinner`: 処理中表示のスピナー
+//! - `task`: 時間のかかる操作のバックグラウンド実行（完了をチャネルで受け取る）
+//! - `theme`: カラーテーマ
+//! - `tui_state`: 表示設定の永続化（`~/.plm/tui_state.json`）
 
 mod app;
 mod common;
 mod data;
+pub mod dev;
+pub mod event_source;
 pub mod filter;
+pub mod history;
 pub mod layout;
+pub mod loading;
+pub mod preview;
+pub mod script;
 mod selection_state;
+pub(crate) mod snapshot;
+pub mod sort;
+pub mod spinner;
 pub mod style;
+pub mod task;
+pub mod theme;
+pub mod tui_state;
 
 #[cfg(test)]
 mod app_test;
@@ -20,18 +45,33 @@ mod app_test;
 mod data_test;
 #[cfg(test)]
 mod filter_test;
+#[cfg(test)]
+mod selection_state_test;
+#[cfg(test)]
+mod sort_test;
+#[cfg(test)]
+mod tui_state_test;
 
-pub use app::{update, view, Model, Tab};
+pub use app::{update, view, Model, Msg, Tab};
 // `LIST_HIGHLIGHT_WIDTH` / `BLOCK_BORDER_WIDTH` は現状クレート内で直接参照していないが、
 // 装飾幅の内訳定数として公開 API を維持する（List 装飾構成や Paragraph 系切り詰め予算を
 // 外部から再構成できる参照点として残す）。単独利用がないため `unused_imports` lint を
 // 抑制する。他の re-export 項目には影響しない。
 pub use common::{
-    render_filter_bar, truncate_for_list, truncate_for_paragraph, truncate_to_width,
-    LIST_DECORATION_WIDTH, MIN_CONTENT_WIDTH,
+    render_empty_state, render_filter_bar, render_tab_bar, truncate_for_list,
+    truncate_for_paragraph, truncate_to_width, wrap_to_width, TabBadges, LIST_DECORATION_WIDTH,
+    MIN_CONTENT_WIDTH,
 };
 #[allow(unused_imports)]
 pub use common::{BLOCK_BORDER_WIDTH, LIST_HIGHLIGHT_WIDTH};
-pub use data::{DataStore, MarketplaceItem, PluginId, PluginKey};
-pub use filter::filter_plugins;
-pub use selection_state::SelectionState;
+pub use data::{DataStore, ErrorEntry, LoadProgress, MarketplaceItem, PluginId};
+// Re-exported for tests
+#[cfg(test)]
+pub use data::ManifestWarning;
+pub use dev::{DevPlugin, DevStatus};
+pub use filter::{filter_marketplaces, filter_names, filter_plugins};
+pub use history::NavigationHistory;
+pub use selection_state::{clamp_index, SelectionState};
+pub use sort::SortKey;
+pub use theme::{Theme, ThemeName};
+pub use tui_state::{ListMode, TuiState};
@@ -4,12 +4,19 @@
 //! - `Screen`: アクティブ画面の状態
 //! - `Msg`: アプリケーションへのメッセージ
 //! - `ScreenCache`: タブ切替時に保持する軽量な状態
+//! - `ScreenHistory`: タブ内の画面遷移履歴（Back で直前の状態を復元）
 
+use super::common::TabBadges;
 use super::data::DataStore;
-use super::filter::filter_plugins;
+use super::dev::DevPlugin;
+use super::history::NavigationHistory;
+use super::spinner;
+use super::task::{Task, TaskRunner};
+use super::tui_state::TuiState;
 use crate::tui::manager::screens::{discover, errors, installed, marketplaces};
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
+use std::path::PathBuf;
 
 /// タブ切替時に保持する軽量な状態
 #[derive(Debug, Default)]
@@ -19,6 +26,15 @@ pub struct ScreenCache {
     pub marketplaces: marketplaces::CacheState,
 }
 
+/// タブ内の画面遷移履歴
+///
+/// タブ切替はトップレベル（履歴が空）でのみ可能なため、タブをまたいで保持しない。
+#[derive(Default)]
+pub struct ScreenHistory {
+    pub installed: NavigationHistory<installed::InstalledScreenModel>,
+    pub marketplaces: NavigationHistory<marketplaces::MarketplacesScreenModel>,
+}
+
 /// タブ種別
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Tab {
@@ -101,9 +117,9 @@ impl Screen {
     pub fn is_top_level(&self) -> bool {
         match self {
             Screen::Installed(m) => m.is_top_level(),
-            Screen::Discover(_) => true,
+            Screen::Discover(m) => m.is_top_level(),
             Screen::Marketplaces(m) => m.is_top_level(),
-            Screen::Errors(_) => true,
+            Screen::Errors(m) => m.is_top_level(),
         }
     }
 }
@@ -134,6 +150,10 @@ pub enum Msg {
     Marketplaces(marketplaces::Msg),
     /// Errors タブのメッセージ
     Errors(errors::Msg),
+    /// dev モードの変更検知ポーリング（キー入力なしで一定間隔ごとに発行）
+    DevTick,
+    /// スピナーを進める（バックグラウンド操作の実行中、キー入力なしで一定間隔ごとに発行）
+    SpinnerTick,
 }
 
 /// アプリケーション全体の状態
@@ -144,28 +164,84 @@ pub struct Model {
     pub screen: Screen,
     /// タブキャッシュ
     pub cache: ScreenCache,
+    /// 画面遷移履歴
+    pub history: ScreenHistory,
     /// 終了フラグ
     pub should_quit: bool,
     /// フィルタテキスト（全タブ共通）
     pub filter_text: String,
     /// フィルタ入力欄にフォーカスしているか
     pub filter_focused: bool,
+    /// バックグラウンド操作の実行と完了メッセージの受け取り
+    pub tasks: TaskRunner<Msg>,
+    /// 最後に Errors タブを閲覧した時点のエラー数（これより増えたら未読として強調する）
+    pub errors_seen: usize,
+    /// 次回起動時に引き継ぐ表示設定（終了時に保存する）
+    pub tui_state: TuiState,
 }
 
 impl Model {
-    /// 新しいモデルを作成
-    pub fn new() -> std::io::Result<Self> {
-        let data = DataStore::new()?;
-        let screen = Screen::Installed(installed::InstalledScreenModel::new(&data));
+    /// ロード済みのデータストアからモデルを作成
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data store loaded at startup (see `loading`)
+    /// * `dev_root` - plugin directory given via `--dev` (enables dev mode)
+    pub fn from_data(mut data: DataStore, dev_root: Option<PathBuf>) -> Self {
+        if let Some(root) = dev_root {
+            data = data.with_dev_plugin(DevPlugin::open(root));
+        }
+        data.ensure_available_updates();
+        let screen = initial_screen(&data);
 
-        Ok(Self {
+        Self {
             data,
             screen,
             cache: ScreenCache::default(),
+            history: ScreenHistory::default(),
             should_quit: false,
             filter_text: String::new(),
             filter_focused: false,
-        })
+            tasks: TaskRunner::new(),
+            errors_seen: 0,
+            tui_state: TuiState::default(),
+        }
+    }
+
+    /// 保存済みの表示設定を適用する
+    ///
+    /// Installed タブの一覧（表示中ならその画面、そうでなければタブ切替時のキャッシュ）に
+    /// 表示モードを反映する。
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - the display settings loaded from `tui_state.json`
+    pub fn with_tui_state(mut self, state: TuiState) -> Self {
+        self.tui_state = state;
+        self.cache.installed.list_mode = state.list_mode;
+        if let Screen::Installed(installed::InstalledScreenModel::PluginList {
+            list_mode, ..
+        }) = &mut self.screen
+        {
+            *list_mode = state.list_mode;
+        }
+        self
+    }
+
+    /// 修飾キーを含むキー入力をメッセージに変換
+    ///
+    /// Ctrl-U はどの画面・フォーカスでもフィルタをクリアする（空結果表示の案内と対応）。
+    /// それ以外は [`Model::key_to_msg`] に委譲する。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key event received from the terminal
+    pub fn key_event_to_msg(&self, key: KeyEvent) -> Option<Msg> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('u') {
+            // 'u'（マーケットプレイス更新など）として扱わない
+            return (!self.filter_text.is_empty()).then_some(Msg::FilterClear);
+        }
+        self.key_to_msg(key.code)
     }
 
     /// キー入力をメッセージに変換
@@ -200,7 +276,7 @@ impl Model {
                 // 画面固有のキー処理に委譲
                 // フィルタへのフォーカス移動は installed::update の返り値で処理される
                 _ => match &self.screen {
-                    Screen::Installed(_) => installed::key_to_msg(key).map(Msg::Installed),
+                    Screen::Installed(m) => installed::key_to_msg(key, m).map(Msg::Installed),
                     Screen::Discover(_) => discover::key_to_msg(key).map(Msg::Discover),
                     Screen::Marketplaces(m) => {
                         marketplaces::key_to_msg(key, m).map(Msg::Marketplaces)
@@ -271,16 +347,24 @@ pub fn update(model: &mut Model, msg: Msg) -> AppUpdateEffect {
         }
         Msg::Installed(msg) => {
             if let Screen::Installed(m) = &mut model.screen {
-                let effect = installed::update(m, msg, &mut model.data, &model.filter_text);
+                let effect = installed::update(
+                    m,
+                    &mut model.history.installed,
+                    msg,
+                    &mut model.data,
+                    &model.filter_text,
+                );
                 if effect.should_focus_filter {
                     model.filter_focused = true;
                 }
+                if let Some(filter_text) = effect.restore_filter {
+                    model.filter_text = filter_text;
+                }
+                if let Some(list_mode) = effect.list_mode {
+                    model.tui_state.list_mode = list_mode;
+                }
                 AppUpdateEffect {
-                    phase2_msg: if effect.needs_execute_batch {
-                        Some(Msg::Installed(installed::Msg::ExecuteBatch))
-                    } else {
-                        None
-                    },
+                    phase2_msg: effect.phase2_msg.map(Msg::Installed),
                 }
             } else {
                 AppUpdateEffect::none()
@@ -288,55 +372,127 @@ pub fn update(model: &mut Model, msg: Msg) -> AppUpdateEffect {
         }
         Msg::Discover(msg) => {
             if let Screen::Discover(m) = &mut model.screen {
-                discover::update(m, msg, &model.data);
+                let effect = discover::update(m, msg, &mut model.data, &model.filter_text);
+                if effect.should_focus_filter {
+                    model.filter_focused = true;
+                }
+                AppUpdateEffect {
+                    phase2_msg: effect.phase2_msg.map(Msg::Discover),
+                }
+            } else {
+                AppUpdateEffect::none()
             }
-            AppUpdateEffect::none()
         }
         Msg::Marketplaces(msg) => {
             if let Screen::Marketplaces(m) = &mut model.screen {
-                let effect = marketplaces::update(m, msg, &mut model.data);
+                let effect = marketplaces::update(
+                    m,
+                    &mut model.history.marketplaces,
+                    msg,
+                    &mut model.data,
+                    &model.filter_text,
+                );
                 if effect.should_focus_filter {
                     model.filter_focused = true;
                 }
+                if let Some(filter_text) = effect.restore_filter {
+                    model.filter_text = filter_text;
+                }
+                spawn_marketplaces_task(&mut model.tasks, effect.task);
                 AppUpdateEffect {
                     phase2_msg: effect.phase2_msg.map(Msg::Marketplaces),
                 }
+            } else if msg.is_task_result() {
+                update_cached_marketplaces(model, msg)
             } else {
                 AppUpdateEffect::none()
             }
         }
         Msg::Errors(msg) => {
             if let Screen::Errors(m) = &mut model.screen {
-                errors::update(m, msg, &model.data);
+                errors::update(m, msg, &mut model.data);
             }
             AppUpdateEffect::none()
         }
+        Msg::DevTick => {
+            if let Some(dev) = model.data.dev_plugin.as_mut() {
+                dev.poll_changes();
+            }
+            AppUpdateEffect::none()
+        }
+        Msg::SpinnerTick => {
+            spinner::advance();
+            AppUpdateEffect::none()
+        }
     }
 }
 
+/// Marketplaces タブの操作をバックグラウンドで開始する
+///
+/// # Arguments
+///
+/// * `tasks` - Task runner delivering the completion message to the event loop.
+/// * `task` - Operation returned by `marketplaces::update`, if any.
+fn spawn_marketplaces_task(tasks: &mut TaskRunner<Msg>, task: Option<Task<marketplaces::Msg>>) {
+    if let Some(task) = task {
+        tasks.spawn(Box::new(move || Msg::Marketplaces(task())));
+    }
+}
+
+/// 他タブにいる間に届いた Marketplaces の完了メッセージを、保存済みの一覧状態に反映する
+///
+/// 一括操作の続き（phase2 の `ExecuteBatch`）もその場で開始し、タブに戻ったときに
+/// 最新の進捗・結果が表示されるようにする。
+///
+/// # Arguments
+///
+/// * `model` - the application model whose marketplaces cache is updated
+/// * `msg` - completion message of a background operation
+fn update_cached_marketplaces(model: &mut Model, msg: marketplaces::Msg) -> AppUpdateEffect {
+    let mut screen =
+        marketplaces::MarketplacesScreenModel::from_cache(&model.data, &model.cache.marketplaces);
+    // 一覧画面の状態だけを扱うため、履歴は使い捨てる
+    let mut history = NavigationHistory::default();
+    let mut next = Some(msg);
+    while let Some(msg) = next.take() {
+        let effect = marketplaces::update(&mut screen, &mut history, msg, &mut model.data, "");
+        spawn_marketplaces_task(&mut model.tasks, effect.task);
+        next = effect.phase2_msg;
+    }
+    model.cache.marketplaces = screen.to_cache();
+    AppUpdateEffect::none()
+}
+
 /// フィルタ変更後に選択状態を整合させる
 ///
 /// # Arguments
 ///
-/// * `model` - the application model whose Installed screen selection is clamped
+/// * `model` - the application model whose active screen selection is clamped
 fn clamp_selection(model: &mut Model) {
-    if let Screen::Installed(m) = &mut model.screen {
-        let filtered = filter_plugins(&model.data.plugins, &model.filter_text);
-        if let installed::InstalledScreenModel::PluginList { selection, .. } = m {
-            if let Some(id) = selection.selected_id() {
-                if let Some(idx) = filtered.iter().position(|p| p.id() == id.as_str()) {
-                    selection.select_index(Some(idx));
-                } else if !filtered.is_empty() {
-                    selection.set(Some(filtered[0].id().to_string()), Some(0));
-                } else {
-                    selection.set(None, None);
-                }
-            } else if !filtered.is_empty() {
-                selection.set(Some(filtered[0].id().to_string()), Some(0));
-            } else {
-                selection.select_index(None);
-            }
+    match &mut model.screen {
+        Screen::Installed(m) => installed::clamp_selection(m, &model.data, &model.filter_text),
+        Screen::Marketplaces(m) => {
+            marketplaces::clamp_selection(m, &model.data, &model.filter_text)
         }
+        Screen::Discover(m) => discover::clamp_selection(m, &model.filter_text),
+        Screen::Errors(_) => {}
+    }
+}
+
+/// 起動直後に表示する画面
+///
+/// マーケットプレイスが 1 つも登録されていない初回状態では、Marketplaces タブの
+/// オンボーディング表示（Enter で追加フォームへ進む）から始める。
+/// それ以外は Installed タブから始める。
+///
+/// # Arguments
+///
+/// * `data` - the data store loaded at startup
+pub(super) fn initial_screen(data: &DataStore) -> Screen {
+    if data.marketplaces.is_empty() {
+        Screen::Marketplaces(marketplaces::MarketplacesScreenModel::new(data))
+    } else {
+        Screen::Installed(installed::InstalledScreenModel::new(data))
     }
 }
 
@@ -347,6 +503,11 @@ fn clamp_selection(model: &mut Model) {
 /// * `model` - the application model whose screen and cache are updated
 /// * `new_tab` - the tab to activate
 fn switch_tab(model: &mut Model, new_tab: Tab) {
+    model.history = ScreenHistory::default();
+    // Errors タブを開いている間に増えたエラーも表示済みとして扱う
+    if model.screen.tab() == Tab::Errors || new_tab == Tab::Errors {
+        model.errors_seen = model.data.error_count();
+    }
     match &model.screen {
         Screen::Installed(m) => {
             model.cache.installed = m.to_cache();
@@ -363,10 +524,14 @@ fn switch_tab(model: &mut Model, new_tab: Tab) {
     }
 
     model.screen = match new_tab {
-        Tab::Installed => Screen::Installed(installed::InstalledScreenModel::from_cache(
-            &model.data,
-            &model.cache.installed,
-        )),
+        Tab::Installed => {
+            // 更新有無の比較はタブ表示時に 1 回だけ行う（比較済みなら何もしない）
+            model.data.ensure_available_updates();
+            Screen::Installed(installed::InstalledScreenModel::from_cache(
+                &model.data,
+                &model.cache.installed,
+            ))
+        }
         Tab::Discover => Screen::Discover(discover::DiscoverScreenModel::from_cache(
             &model.data,
             &model.cache.discover,
@@ -380,10 +545,8 @@ fn switch_tab(model: &mut Model, new_tab: Tab) {
         Tab::Errors => Screen::Errors(errors::ErrorsScreenModel::new(&model.data)),
     };
 
-    // Installed タブ復元後にフィルタ済みリストと選択状態を整合
-    if new_tab == Tab::Installed {
-        clamp_selection(model);
-    }
+    // タブ復元後にフィルタ済みリストと選択状態を整合
+    clamp_selection(model);
 }
 
 /// 画面を描画
@@ -393,18 +556,14 @@ fn switch_tab(model: &mut Model, new_tab: Tab) {
 /// * `f` - the `ratatui` frame to draw into
 /// * `model` - the application model providing screen and data to render
 pub fn view(f: &mut Frame, model: &Model) {
+    let badges = TabBadges::new(&model.data, model.errors_seen);
+    let data = &model.data;
+    let filter_text = &model.filter_text;
+    let focused = model.filter_focused;
     match &model.screen {
-        Screen::Installed(m) => {
-            installed::view(f, m, &model.data, &model.filter_text, model.filter_focused)
-        }
-        Screen::Discover(m) => {
-            discover::view(f, m, &model.data, &model.filter_text, model.filter_focused)
-        }
-        Screen::Marketplaces(m) => {
-            marketplaces::view(f, m, &model.data, &model.filter_text, model.filter_focused)
-        }
-        Screen::Errors(m) => {
-            errors::view(f, m, &model.data, &model.filter_text, model.filter_focused)
-        }
+        Screen::Installed(m) => installed::view(f, m, data, &badges, filter_text, focused),
+        Screen::Discover(m) => discover::view(f, m, data, &badges, filter_text, focused),
+        Screen::Marketplaces(m) => marketplaces::view(f, m, data, &badges, filter_text, focused),
+        Screen::Errors(m) => errors::view(f, m, data, &badges, filter_text, focused),
     }
 }
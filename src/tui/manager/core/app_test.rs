@@ -1,8 +1,15 @@
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::cell::RefCell;
+use std::rc::Rc;
 
-use super::app::{Model, Msg, Screen, ScreenCache};
+use super::app::{initial_screen, update, Model, Msg, Screen, ScreenCache, ScreenHistory, Tab};
+use super::common::TabBadges;
 use super::data::DataStore;
-use crate::tui::manager::screens::installed;
+use super::data::MarketplaceItem;
+use super::data::PluginId;
+use super::task::{Job, TaskRunner};
+use super::tui_state::{ListMode, TuiState};
+use crate::tui::manager::screens::{installed, marketplaces};
 
 /// テスト用の最小構成 Model を構築するヘルパー
 ///
@@ -16,10 +23,8 @@ fn make_model(filter_focused: bool, top_level: bool) -> (tempfile::TempDir, Mode
     } else {
         // サブ画面: PluginDetail は is_top_level == false
         Screen::Installed(installed::InstalledScreenModel::PluginDetail {
-            plugin_id: "dummy".to_string(),
+            plugin_id: PluginId::new(None, "dummy"),
             state: ratatui::widgets::ListState::default(),
-            saved_marked_ids: Default::default(),
-            saved_update_statuses: Default::default(),
         })
     };
 
@@ -29,9 +34,14 @@ fn make_model(filter_focused: bool, top_level: bool) -> (tempfile::TempDir, Mode
             data,
             screen,
             cache: ScreenCache::default(),
+            history: ScreenHistory::default(),
             should_quit: false,
             filter_text: String::new(),
             filter_focused,
+            // 実際の操作は走らせない（必要なテストで差し替える）
+            tasks: TaskRunner::with_spawner(Box::new(drop)),
+            errors_seen: 0,
+            tui_state: TuiState::default(),
         },
     )
 }
@@ -107,3 +117,272 @@ fn backtab_key_still_returns_prev_tab() {
     let msg = model.key_to_msg(KeyCode::BackTab);
     assert!(matches!(msg, Some(Msg::PrevTab)));
 }
+
+// ============================================================================
+// Ctrl-U — フィルタクリア
+// ============================================================================
+
+fn ctrl_u() -> KeyEvent {
+    KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)
+}
+
+#[test]
+fn ctrl_u_clears_filter_in_list_and_filter_focus() {
+    for filter_focused in [false, true] {
+        let (_temp_dir, mut model) = make_model(filter_focused, true);
+        model.filter_text = "foo".to_string();
+        let msg = model.key_event_to_msg(ctrl_u());
+        assert!(matches!(msg, Some(Msg::FilterClear)));
+    }
+}
+
+#[test]
+fn ctrl_u_is_not_treated_as_plain_u_without_filter() {
+    let (_temp_dir, model) = make_model(false, true);
+    assert!(model.key_event_to_msg(ctrl_u()).is_none());
+}
+
+#[test]
+fn filter_input_clamps_marketplace_selection() {
+    let marketplace = |name: &str| MarketplaceItem {
+        name: name.to_string(),
+        source: format!("owner/{}", name),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    };
+    let (_temp_dir, mut model) = make_model(true, true);
+    model.data.marketplaces = vec![marketplace("alpha"), marketplace("beta")];
+    model.screen = Screen::Marketplaces(marketplaces::MarketplacesScreenModel::new(&model.data));
+
+    update(&mut model, Msg::FilterInput('b'));
+
+    let Screen::Marketplaces(marketplaces::MarketplacesScreenModel::MarketList {
+        selection, ..
+    }) = &model.screen
+    else {
+        panic!("expected MarketList");
+    };
+    assert_eq!(selection.selected_id().map(String::as_str), Some("beta"));
+    assert_eq!(selection.selected_index(), Some(0));
+}
+
+// ============================================================================
+// 初期画面 / オンボーディング
+// ============================================================================
+
+#[test]
+fn initial_screen_is_onboarding_without_marketplaces() {
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![], None);
+
+    let screen = initial_screen(&data);
+
+    assert!(matches!(
+        screen,
+        Screen::Marketplaces(marketplaces::MarketplacesScreenModel::MarketList { .. })
+    ));
+}
+
+#[test]
+fn initial_screen_is_installed_with_marketplaces() {
+    let marketplace = MarketplaceItem {
+        name: "official".to_string(),
+        source: "owner/plugins".to_string(),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    };
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![marketplace], None);
+
+    assert!(matches!(initial_screen(&data), Screen::Installed(_)));
+}
+
+#[test]
+fn enter_on_onboarding_opens_add_form() {
+    let (_temp_dir, mut model) = make_model(false, true);
+    model.screen = initial_screen(&model.data);
+
+    let msg = model.key_to_msg(KeyCode::Enter).expect("Enter is handled");
+    update(&mut model, msg);
+
+    assert!(matches!(
+        &model.screen,
+        Screen::Marketplaces(m) if m.is_form_active()
+    ));
+}
+
+// ============================================================================
+// バックグラウンド操作
+// ============================================================================
+
+/// Marketplaces タブを開き、開始されたジョブを実行せずに溜める Model を構築する
+fn make_marketplaces_model() -> (tempfile::TempDir, Model, Rc<RefCell<Vec<Job>>>) {
+    let (temp_dir, mut model) = make_model(false, true);
+    model.data.marketplaces = vec![MarketplaceItem {
+        name: "alpha".to_string(),
+        source: "owner/alpha".to_string(),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    }];
+    model.screen = Screen::Marketplaces(marketplaces::MarketplacesScreenModel::new(&model.data));
+    let jobs: Rc<RefCell<Vec<Job>>> = Rc::default();
+    let store = Rc::clone(&jobs);
+    model.tasks = TaskRunner::with_spawner(Box::new(move |job| store.borrow_mut().push(job)));
+    (temp_dir, model, jobs)
+}
+
+/// phase2 まで含めて 1 メッセージを処理する
+fn dispatch(model: &mut Model, msg: Msg) {
+    let mut next = Some(msg);
+    while let Some(msg) = next.take() {
+        next = update(model, msg).phase2_msg;
+    }
+}
+
+fn offline_update_result() -> Msg {
+    Msg::Marketplaces(marketplaces::Msg::UpdateFinished(
+        marketplaces::UpdateResult::Single("alpha".to_string(), Err("offline".to_string())),
+    ))
+}
+
+#[test]
+fn update_runs_in_background_and_blocks_second_operation() {
+    let (_temp_dir, mut model, jobs) = make_marketplaces_model();
+
+    dispatch(
+        &mut model,
+        Msg::Marketplaces(marketplaces::Msg::UpdateMarket),
+    );
+
+    assert_eq!(
+        jobs.borrow().len(),
+        1,
+        "update is handed to the task runner"
+    );
+    assert!(model.tasks.is_busy());
+    assert!(model.screen.is_top_level(), "tabs stay switchable");
+
+    dispatch(&mut model, Msg::Marketplaces(marketplaces::Msg::UpdateAll));
+    assert_eq!(jobs.borrow().len(), 1, "second operation must not start");
+
+    dispatch(&mut model, offline_update_result());
+    let Screen::Marketplaces(marketplaces::MarketplacesScreenModel::MarketList {
+        operation_status,
+        error_message,
+        ..
+    }) = &model.screen
+    else {
+        panic!("expected MarketList");
+    };
+    assert!(operation_status.is_none());
+    assert_eq!(
+        error_message.as_deref(),
+        Some("Failed to update 'alpha': offline")
+    );
+}
+
+#[test]
+fn update_completion_after_tab_switch_is_kept_for_marketplaces_tab() {
+    let (_temp_dir, mut model, jobs) = make_marketplaces_model();
+    dispatch(
+        &mut model,
+        Msg::Marketplaces(marketplaces::Msg::UpdateMarket),
+    );
+    assert_eq!(jobs.borrow().len(), 1);
+
+    dispatch(&mut model, Msg::NextTab);
+    assert!(matches!(model.screen, Screen::Errors(_)));
+    assert!(model.cache.marketplaces.operation_status.is_some());
+
+    dispatch(&mut model, offline_update_result());
+    assert!(model.cache.marketplaces.operation_status.is_none());
+    assert_eq!(
+        model.cache.marketplaces.error_message.as_deref(),
+        Some("Failed to update 'alpha': offline")
+    );
+
+    dispatch(&mut model, Msg::PrevTab);
+    let Screen::Marketplaces(marketplaces::MarketplacesScreenModel::MarketList {
+        operation_status,
+        error_message,
+        ..
+    }) = &model.screen
+    else {
+        panic!("expected MarketList");
+    };
+    assert!(operation_status.is_none());
+    assert_eq!(
+        error_message.as_deref(),
+        Some("Failed to update 'alpha': offline")
+    );
+}
+
+// ============================================================================
+// タブバーの未読エラー
+// ============================================================================
+
+#[test]
+fn errors_are_unread_until_errors_tab_is_viewed() {
+    let (_temp_dir, mut model) = make_model(false, true);
+    model
+        .data
+        .push_error(Tab::Marketplaces, "Failed to load marketplace 'alpha'");
+    assert_eq!(
+        TabBadges::new(&model.data, model.errors_seen).unread_errors,
+        1
+    );
+
+    // Installed → Marketplaces → Errors
+    update(&mut model, Msg::NextTab);
+    update(&mut model, Msg::NextTab);
+    assert!(matches!(model.screen, Screen::Errors(_)));
+    assert_eq!(
+        TabBadges::new(&model.data, model.errors_seen).unread_errors,
+        0
+    );
+
+    // 閲覧後に追加されたエラーは再び未読になる
+    update(&mut model, Msg::NextTab);
+    model
+        .data
+        .push_error(Tab::Marketplaces, "Failed to load 'beta'");
+    model
+        .data
+        .push_error(Tab::Installed, "Failed to update 'gamma'");
+    assert_eq!(
+        TabBadges::new(&model.data, model.errors_seen).unread_errors,
+        2
+    );
+}
+
+// ============================================================================
+// 表示設定（tui_state.json）
+// ============================================================================
+
+#[test]
+fn with_tui_state_applies_list_mode_to_installed_list_and_cache() {
+    let (_temp_dir, model) = make_model(false, true);
+    let model = model.with_tui_state(TuiState {
+        list_mode: ListMode::Detailed,
+    });
+
+    assert_eq!(model.cache.installed.list_mode, ListMode::Detailed);
+    assert!(matches!(
+        &model.screen,
+        Screen::Installed(installed::InstalledScreenModel::PluginList {
+            list_mode: ListMode::Detailed,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn toggling_list_mode_updates_the_state_saved_on_exit() {
+    let (_temp_dir, mut model) = make_model(false, true);
+
+    let msg = model.key_to_msg(KeyCode::Char('v')).unwrap();
+    update(&mut model, msg);
+
+    assert_eq!(model.tui_state.list_mode, ListMode::Detailed);
+}
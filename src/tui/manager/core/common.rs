@@ -2,10 +2,89 @@
 //!
 //! 複数タブで共有される描画ユーティリティ。
 
+use super::app::Tab;
+use super::data::DataStore;
+use super::style::bordered_block;
+use super::theme::Theme;
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, Paragraph, Tabs};
 use std::borrow::Cow;
 
+/// タブ名の横に表示する件数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TabBadges {
+    pub installed: usize,
+    pub marketplaces: usize,
+    /// 最後に Errors タブを開いた後に増えたエラー・警告の数（未読数）
+    pub unread_errors: usize,
+}
+
+impl TabBadges {
+    /// データストアから件数を導出する
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the data store the counts are derived from
+    /// * `errors_seen` - error count at the time the Errors tab was last viewed
+    pub fn new(data: &DataStore, errors_seen: usize) -> Self {
+        Self {
+            installed: data.plugins.len(),
+            marketplaces: data.marketplaces.len(),
+            unread_errors: data.error_count().saturating_sub(errors_seen),
+        }
+    }
+}
+
+/// タブバーの各タブ名（件数付き）
+///
+/// `badges` が `None`（データ未ロード）の場合はタブ名のみ。
+/// Errors は未読数を赤色で表示し、未読が無ければタブ名のみ。
+///
+/// # Arguments
+///
+/// * `badges` - counts shown next to the tab names
+pub fn tab_titles(badges: Option<&TabBadges>) -> Vec<Line<'static>> {
+    let theme = Theme::current();
+    Tab::all()
+        .iter()
+        .map(|tab| {
+            let Some(badges) = badges else {
+                return Line::from(tab.title());
+            };
+            match tab {
+                Tab::Discover => Line::from(tab.title()),
+                Tab::Installed => Line::from(format!("{} ({})", tab.title(), badges.installed)),
+                Tab::Marketplaces => {
+                    Line::from(format!("{} ({})", tab.title(), badges.marketplaces))
+                }
+                Tab::Errors if badges.unread_errors > 0 => {
+                    Line::from(format!("{} ({})", tab.title(), badges.unread_errors))
+                        .style(Style::default().fg(theme.error))
+                }
+                Tab::Errors => Line::from(tab.title()),
+            }
+        })
+        .collect()
+}
+
+/// タブバーを描画（高さ1行）
+///
+/// # Arguments
+///
+/// * `f` - the `ratatui` frame to draw into
+/// * `area` - the rectangle to render the tab bar in
+/// * `active` - the tab to highlight
+/// * `badges` - counts shown next to the tab names (`None` before the data is loaded)
+pub fn render_tab_bar(f: &mut Frame, area: Rect, active: Tab, badges: Option<&TabBadges>) {
+    let theme = Theme::current();
+    let tabs = Tabs::new(tab_titles(badges))
+        .select(active.index())
+        .style(Style::default().fg(theme.muted))
+        .highlight_style(Style::default().fg(theme.text).add_modifier(Modifier::BOLD))
+        .divider(" | ");
+    f.render_widget(tabs, area);
+}
+
 /// フィルタ入力欄を描画（ボーダー付き、高さ3行）
 ///
 /// # Arguments
@@ -15,6 +94,7 @@ use std::borrow::Cow;
 /// * `filter_text` - the current filter query text
 /// * `focused` - `true` when the filter input currently has focus
 pub fn render_filter_bar(f: &mut Frame, area: Rect, filter_text: &str, focused: bool) {
+    let theme = Theme::current();
     let (border_color, text_content) = if focused {
         let cursor = "\u{2502}"; // │ カーソル
         let text = if filter_text.is_empty() {
@@ -23,19 +103,19 @@ pub fn render_filter_bar(f: &mut Frame, area: Rect, filter_text: &str, focused:
             format!(" \u{1f50e} {}{}", filter_text, cursor)
         };
         (
-            Color::White,
-            Paragraph::new(text).style(Style::default().fg(Color::White)),
+            theme.text,
+            Paragraph::new(text).style(Style::default().fg(theme.text)),
         )
     } else if filter_text.is_empty() {
         (
-            Color::DarkGray,
-            Paragraph::new(" \u{1f50e} Search...").style(Style::default().fg(Color::DarkGray)),
+            theme.muted,
+            Paragraph::new(" \u{1f50e} Search...").style(Style::default().fg(theme.muted)),
         )
     } else {
         (
-            Color::DarkGray,
+            theme.muted,
             Paragraph::new(format!(" \u{1f50e} {}", filter_text))
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(theme.text)),
         )
     };
 
@@ -47,6 +127,49 @@ pub fn render_filter_bar(f: &mut Frame, area: Rect, filter_text: &str, focused:
     f.render_widget(content, area);
 }
 
+/// フィルタ結果が 0 件のときのメッセージ
+///
+/// # Arguments
+///
+/// * `filter_text` - the current filter query text
+pub fn no_match_message(filter_text: &str) -> String {
+    format!(
+        "No items match '{}' \u{2014} press Ctrl-U to clear filter",
+        filter_text
+    )
+}
+
+/// 一覧が空のときの表示（タイトル付きの枠 + メッセージ）
+///
+/// フィルタ適用中は [`no_match_message`] を、そうでなければ `empty_text` を表示する。
+/// 各画面の空結果表示はこの関数に揃える。
+///
+/// # Arguments
+///
+/// * `f` - the `ratatui` frame to draw into
+/// * `area` - the rectangle the list would have occupied
+/// * `title` - the list block title
+/// * `filter_text` - the current filter query text
+/// * `empty_text` - the message shown when the list is empty without a filter
+pub fn render_empty_state(
+    f: &mut Frame,
+    area: Rect,
+    title: &str,
+    filter_text: &str,
+    empty_text: &str,
+) {
+    let theme = Theme::current();
+    let message = if filter_text.is_empty() {
+        empty_text.to_string()
+    } else {
+        no_match_message(filter_text)
+    };
+    let paragraph = Paragraph::new(format!("\n  {}", message))
+        .block(bordered_block(title))
+        .style(Style::default().fg(theme.muted));
+    f.render_widget(paragraph, area);
+}
+
 /// フレーム全体の左右パディング（cells）。タブバー・フィルタ・コンテンツ・ヘルプ全てに適用。
 pub const HORIZONTAL_PADDING: u16 = 2;
 
@@ -117,6 +240,57 @@ pub fn truncate_to_width(text: &str, max_width: u16) -> String {
     format!("{head}{ELLIPSIS}")
 }
 
+/// 文字列を単語単位で `max_width` cells ごとに折り返し、最大 `max_lines` 行に収める純粋関数。
+///
+/// 収まらない場合は最終行の末尾を `ELLIPSIS` に置き換える。1 語が `max_width` を超える行は
+/// `truncate_to_width` で切り詰める。幅の扱いは `truncate_to_width` と同じく文字数基準。
+pub fn wrap_to_width(text: &str, max_width: u16, max_lines: usize) -> Vec<String> {
+    let width = max_width as usize;
+    if width == 0 || max_lines == 0 {
+        return Vec::new();
+    }
+    let mut lines = wrap_words(text, width);
+    let overflow = lines.len() > max_lines;
+    lines.truncate(max_lines);
+    if overflow {
+        if let Some(last) = lines.last_mut() {
+            let keep = width.saturating_sub(ELLIPSIS_LEN as usize);
+            let head: String = last.chars().take(keep).collect();
+            *last = format!("{}{ELLIPSIS}", head.trim_end());
+        }
+    }
+    lines
+        .iter()
+        .map(|line| truncate_to_width(line, max_width))
+        .collect()
+}
+
+/// 空白区切りの単語を `width` 文字以内の行へ詰める（行数は制限しない）
+///
+/// # Arguments
+///
+/// * `text` - Text to wrap.
+/// * `width` - Maximum characters per line (greater than zero).
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let needed =
+            current.chars().count() + word.chars().count() + usize::from(!current.is_empty());
+        if !current.is_empty() && needed > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
 /// `content_width` が `MIN_CONTENT_WIDTH` 未満のときだけ `text` を装飾幅 `decoration_width` 引きで切り詰める。
 ///
 /// それ以外（通常幅）は入力をそのまま返す。
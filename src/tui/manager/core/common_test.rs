@@ -1,4 +1,4 @@
-//! `content_rect` と `truncate_to_width` の境界値テスト
+//! `content_rect` と `truncate_to_width` の境界値テスト、タブ名の件数表示のテスト
 
 use super::*;
 use ratatui::layout::Rect;
@@ -201,3 +201,65 @@ fn truncate_for_list_owns_truncated_text_when_narrow() {
         Cow::Owned(_)
     ));
 }
+
+#[test]
+fn wrap_to_width_wraps_words_and_truncates_overflowing_lines() {
+    assert_eq!(
+        wrap_to_width("alpha beta gamma", 11, 2),
+        vec!["alpha beta", "gamma"]
+    );
+    assert_eq!(
+        wrap_to_width("alpha beta gamma delta epsilon", 11, 2),
+        vec!["alpha beta", "gamma de..."]
+    );
+    assert!(wrap_to_width("alpha", 0, 2).is_empty());
+}
+
+// =============================================================================
+// tab_titles — 件数バッジと未読エラー
+// =============================================================================
+
+fn title_texts(titles: &[Line<'_>]) -> Vec<String> {
+    titles.iter().map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn tab_titles_without_badges_are_plain_names() {
+    assert_eq!(
+        title_texts(&tab_titles(None)),
+        ["Discover", "Installed", "Marketplaces", "Errors"]
+    );
+}
+
+#[test]
+fn tab_titles_show_counts_and_omit_zero_errors() {
+    let badges = TabBadges {
+        installed: 12,
+        marketplaces: 3,
+        unread_errors: 0,
+    };
+    assert_eq!(
+        title_texts(&tab_titles(Some(&badges))),
+        ["Discover", "Installed (12)", "Marketplaces (3)", "Errors"]
+    );
+}
+
+#[test]
+fn tab_titles_highlight_unread_errors() {
+    let badges = TabBadges {
+        installed: 1,
+        marketplaces: 1,
+        unread_errors: 3,
+    };
+    let titles = tab_titles(Some(&badges));
+    assert_eq!(titles[3].to_string(), "Errors (3)");
+    assert_eq!(titles[3].style.fg, Some(Theme::current().error));
+
+    let read = TabBadges {
+        unread_errors: 0,
+        ..badges
+    };
+    let titles = tab_titles(Some(&read));
+    assert_eq!(titles[3].to_string(), "Errors");
+    assert_eq!(titles[3].style.fg, None);
+}
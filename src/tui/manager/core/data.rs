@@ -3,46 +3,111 @@
 //! 全タブで共有されるデータを一元管理する。
 //! Application層のDTOとパッケージキャッシュを保持する。
 
-use crate::application::{list_installed_plugins, InstalledPlugin};
+use super::app::Tab;
+use super::dev::DevPlugin;
+use super::filter::filter_plugins;
+use super::sort::{sort_plugins, SortKey};
+use crate::application::{list_installed_plugins, InstalledPlugin, Warning};
 use crate::component::ComponentKind;
-use crate::marketplace::{MarketplaceConfig, MarketplaceRegistry};
+use crate::marketplace::{
+    source_key, MarketplaceCache, MarketplaceCacheMeta, MarketplaceConfig, MarketplaceRef,
+    MarketplaceRegistry, ValidationIssue,
+};
+use crate::plugin::meta::is_upgrade;
 use crate::plugin::PackageCache;
+use chrono::{DateTime, Local, Utc};
+use std::collections::HashMap;
+use std::fmt;
 use std::io;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-/// プラグインID（`InstalledPlugin::id()` の値で識別。リポジトリ名と異なる場合あり）
+/// プラグインID（マーケットプレイス + `InstalledPlugin::id()`）
 ///
-/// 注: 同名 plugin が複数 marketplace に同居するケースを区別できない。
-/// 内部識別が必要な場合は `PluginKey` を使う。
-pub type PluginId = String;
-
-/// プラグインの内部識別キー
-///
-/// 同名 plugin が複数 marketplace に同居するケースを区別するために使用する。
-/// `marketplace == None` は直接 GitHub install。
+/// 同名 plugin が複数 marketplace に同居しても一意になる。マーケットプレイスは生成時に
+/// 正規化する（`Some("github")` は直接 GitHub install と同じ `None`）。
+/// 文字列表現は `"<marketplace>/<name>"`（直接 GitHub install は `github/<name>`）。
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PluginKey {
-    pub marketplace: Option<String>,
-    pub cache_id: String,
+pub struct PluginId {
+    marketplace: Option<String>,
+    name: String,
 }
 
-impl PluginKey {
-    /// `InstalledPlugin` から `PluginKey` を構築する
-    ///
+impl PluginId {
     /// # Arguments
     ///
-    /// * `plugin` - The installed plugin to build a key from.
-    pub fn from_installed(plugin: &InstalledPlugin) -> Self {
+    /// * `marketplace` - Marketplace name, `None` for direct GitHub installs.
+    /// * `name` - Plugin cache id (`InstalledPlugin::id()`).
+    pub fn new(marketplace: Option<&str>, name: impl Into<String>) -> Self {
         Self {
-            marketplace: plugin.marketplace().map(|s| s.to_string()),
-            cache_id: plugin.id().to_string(),
+            marketplace: MarketplaceRef::from_option(marketplace).into_named(),
+            name: name.into(),
         }
     }
 
-    /// ユーザー表示用ラベル（曖昧時の候補表示などに使用）
+    /// `InstalledPlugin` のプラグインID
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - The installed plugin.
+    pub fn of(plugin: &InstalledPlugin) -> Self {
+        Self::new(plugin.marketplace(), plugin.id())
+    }
+
+    /// マーケットプレイス名（直接 GitHub install は `None`）
+    pub fn marketplace(&self) -> Option<&str> {
+        self.marketplace.as_deref()
+    }
+
+    /// プラグイン名（`InstalledPlugin::id()` の値）
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// `plugin` がこのIDのプラグインか
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - The installed plugin to compare with.
+    pub fn matches(&self, plugin: &InstalledPlugin) -> bool {
+        self.name == plugin.id()
+            && MarketplaceRef::from_option(plugin.marketplace())
+                == MarketplaceRef::from_option(self.marketplace())
+    }
+
+    /// ユーザー表示用ラベル（`name@marketplace`、直接 GitHub install は `name`）
     pub fn display_label(&self) -> String {
         match &self.marketplace {
-            Some(m) => format!("{}@{}", self.cache_id, m),
-            None => self.cache_id.clone(),
+            Some(m) => format!("{}@{}", self.name, m),
+            None => self.name.clone(),
+        }
+    }
+}
+
+impl fmt::Display for PluginId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            MarketplaceRef::from_option(self.marketplace()).dir_name(),
+            self.name
+        )
+    }
+}
+
+impl FromStr for PluginId {
+    type Err = String;
+
+    /// `"<marketplace>/<name>"` 形式をパースする
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((marketplace, name)) if !marketplace.is_empty() && !name.is_empty() => {
+                Ok(Self::new(Some(marketplace), name))
+            }
+            _ => Err(format!(
+                "invalid plugin id '{}' (expected <marketplace>/<name>)",
+                s
+            )),
         }
     }
 }
@@ -54,7 +119,71 @@ pub struct MarketplaceItem {
     pub source: String,
     pub source_path: Option<String>,
     pub plugin_count: Option<usize>,
-    pub last_updated: Option<String>,
+    /// キャッシュを取得した日時（未取得なら `None`。表示形式は view 側で決める）
+    pub fetched_at: Option<DateTime<Utc>>,
+}
+
+/// TUI の操作中に発生したエラー（Errors タブの履歴 1 件）
+#[derive(Debug, Clone)]
+pub struct ErrorEntry {
+    /// 発生日時
+    pub timestamp: DateTime<Local>,
+    /// 発生したタブ
+    pub source_tab: Tab,
+    /// エラーメッセージ（複数行のこともある）
+    pub message: String,
+}
+
+impl ErrorEntry {
+    /// 現在時刻のエラーを作成
+    ///
+    /// # Arguments
+    ///
+    /// * `source_tab` - the tab where the error occurred
+    /// * `message` - the error message
+    pub fn now(source_tab: Tab, message: impl Into<String>) -> Self {
+        Self {
+            timestamp: Local::now(),
+            source_tab,
+            message: message.into(),
+        }
+    }
+}
+
+/// マーケットプレイスキャッシュの marketplace.json で見つかった警告
+///
+/// エラーではないため `errors` とは分けて保持し、Errors タブに表示する。
+#[derive(Debug, Clone)]
+pub struct ManifestWarning {
+    pub marketplace: String,
+    pub issue: ValidationIssue,
+}
+
+impl fmt::Display for ManifestWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.marketplace, self.issue)
+    }
+}
+
+/// 初期ロードの進捗（ローディング画面の件数表示用）
+///
+/// ロードスレッドが書き込み、描画スレッドが読む。
+#[derive(Debug, Default)]
+pub struct LoadProgress {
+    plugins: AtomicUsize,
+    marketplaces: AtomicUsize,
+}
+
+impl LoadProgress {
+    /// 読み込み済みのプラグイン数
+    pub fn plugins(&self) -> usize {
+        self.plugins.load(Ordering::Relaxed)
+    }
+
+    /// 読み込み済みのマーケットプレイス数
+    pub fn marketplaces(&self) -> usize {
+        self.marketplaces.load(Ordering::Relaxed)
+    }
 }
 
 /// 共有データストア
@@ -65,48 +194,126 @@ pub struct DataStore {
     pub plugins: Vec<InstalledPlugin>,
     /// マーケットプレイス一覧
     pub marketplaces: Vec<MarketplaceItem>,
-    /// 最後のエラー
-    pub last_error: Option<String>,
+    /// エラー履歴（古い順、最新は [`DataStore::last_error`]）
+    pub errors: Vec<ErrorEntry>,
+    /// marketplace.json の警告（マーケットプレイスの読み込みごとに置き換える）
+    pub warnings: Vec<ManifestWarning>,
+    /// 最後の操作の補足案内（配置後の反映手順など、1 行）
+    pub last_notice: Option<String>,
+    /// 直前のインストールで出た警告（Errors タブに表示し、次のインストールで置き換える）
+    pub last_warnings: Vec<Warning>,
+    /// dev モードの開発中プラグイン（`--dev` 指定時のみ）
+    pub dev_plugin: Option<DevPlugin>,
+    /// 更新ありのプラグイン（正規化ID → マーケットプレイス側のバージョン）
+    ///
+    /// `None` は未比較。Installed タブ表示時に [`DataStore::ensure_available_updates`] で
+    /// 1 回だけ比較し、リロードで破棄する。
+    available_updates: Option<HashMap<PluginId, String>>,
 }
 
 impl DataStore {
-    /// 新しいデータストアを作成
-    pub fn new() -> io::Result<Self> {
+    /// 新しいデータストアを作成（進捗を `progress` に報告する）
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - counters updated as plugins and marketplaces are loaded
+    pub fn load(progress: &LoadProgress) -> io::Result<Self> {
         let cache = PackageCache::new().map_err(|e| io::Error::other(e.to_string()))?;
         let plugins =
             list_installed_plugins(&cache).map_err(|e| io::Error::other(e.to_string()))?;
-        let LoadMarketplacesOutcome { items, error } = load_marketplaces();
+        progress.plugins.store(plugins.len(), Ordering::Relaxed);
+        let LoadMarketplacesOutcome {
+            items,
+            error,
+            warnings,
+        } = load_marketplaces();
+        progress.marketplaces.store(items.len(), Ordering::Relaxed);
 
         Ok(Self {
             cache,
             plugins,
             marketplaces: items,
-            last_error: error,
+            errors: error
+                .map(|e| vec![ErrorEntry::now(Tab::Marketplaces, e)])
+                .unwrap_or_default(),
+            warnings,
+            last_notice: None,
+            last_warnings: Vec::new(),
+            dev_plugin: None,
+            available_updates: None,
         })
     }
 
+    /// dev モードの開発中プラグインを設定
+    ///
+    /// # Arguments
+    ///
+    /// * `dev_plugin` - the development plugin opened via `--dev`
+    pub fn with_dev_plugin(mut self, dev_plugin: DevPlugin) -> Self {
+        self.dev_plugin = Some(dev_plugin);
+        self
+    }
+
     /// データストアをリロード（list_installed_plugins() で全体再取得）
     pub fn reload(&mut self) -> io::Result<()> {
         self.plugins =
             list_installed_plugins(&self.cache).map_err(|e| io::Error::other(e.to_string()))?;
         let result = load_marketplaces();
         self.marketplaces = result.items;
-        // 既存の last_error を上書きせず、マーケットプレイス読み込みエラーを追記/保存する
-        self.last_error = merge_errors(self.last_error.take(), result.error);
+        if let Some(error) = result.error {
+            self.push_error(Tab::Marketplaces, error);
+        }
+        self.warnings = result.warnings;
+        self.available_updates = None;
         Ok(())
     }
 
+    /// キャッシュ済みマーケットプレイスマニフェストとバージョンを比較し、結果を保持する
+    ///
+    /// 比較済みなら何もしない（描画のたびに比較しない）。
+    /// マーケットプレイスキャッシュを読めない場合は更新なしとして扱う。
+    pub fn ensure_available_updates(&mut self) {
+        if self.available_updates.is_none() {
+            let caches = load_marketplace_caches(&self.plugins);
+            self.available_updates = Some(find_available_updates(&self.plugins, &caches));
+        }
+    }
+
+    /// マーケットプレイス側の新しいバージョン（更新が無い・未比較なら `None`）
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - the installed plugin to look up
+    pub fn available_update(&self, plugin: &InstalledPlugin) -> Option<&str> {
+        self.available_updates
+            .as_ref()?
+            .get(&PluginId::of(plugin))
+            .map(String::as_str)
+    }
+
     /// プラグインIDでプラグインを検索
     ///
     /// # Arguments
     ///
     /// * `id` - the plugin id to look up
     pub fn find_plugin(&self, id: &PluginId) -> Option<&InstalledPlugin> {
-        self.plugins.iter().find(|p| p.id() == id.as_str())
+        self.plugins.iter().find(|p| id.matches(p))
+    }
+
+    /// フィルタで絞り込み、ソートキーの順に並べたプラグイン一覧（Installed タブの表示順）
+    ///
+    /// # Arguments
+    ///
+    /// * `sort_key` - the sort key selected in the Installed tab
+    /// * `filter_text` - the filter text applied before sorting
+    pub fn sorted_plugins(&self, sort_key: SortKey, filter_text: &str) -> Vec<&InstalledPlugin> {
+        let mut plugins = filter_plugins(&self.plugins, filter_text);
+        sort_plugins(&mut plugins, sort_key);
+        plugins
     }
 
     /// `plugin_id` は `InstalledPlugin.id()`（= 操作用キー）と完全一致で比較される。
-    /// marketplace の区別はしない（既存の `find_plugin` と同じ設計）。
+    /// `find_plugin` と異なり marketplace の区別はしない（名前だけで判定する）。
     /// `enabled` の状態に関わらず、`plugins` に存在すれば `true` を返す。
     ///
     /// # Arguments
@@ -116,13 +323,14 @@ impl DataStore {
         self.plugins.iter().any(|p| p.id() == plugin_id)
     }
 
-    /// プラグインIDでインデックスを検索
+    /// プラグインIDでインデックスを検索（ソート前の `plugins` 上の位置）
     ///
     /// # Arguments
     ///
     /// * `id` - the plugin id to look up
+    #[cfg(test)]
     pub fn plugin_index(&self, id: &PluginId) -> Option<usize> {
-        self.plugins.iter().position(|p| p.id() == id.as_str())
+        self.plugins.iter().position(|p| id.matches(p))
     }
 
     /// プラグインの空でないコンポーネント種別を取得
@@ -139,35 +347,53 @@ impl DataStore {
 
     /// コンポーネント種別に応じたコンポーネント名一覧を取得
     ///
+    /// Hook は hooks.json を解釈できればイベント + matcher 単位（`PreToolUse: Bash`）で返し、
+    /// hooks.json が無い・壊れている場合はファイル名ベースのコンポーネント名に戻す。
+    ///
     /// # Arguments
     ///
     /// * `plugin` - the plugin whose components are enumerated
     /// * `kind` - the component kind to filter by
     pub fn component_names(&self, plugin: &InstalledPlugin, kind: ComponentKind) -> Vec<String> {
+        if kind == ComponentKind::Hook {
+            if let Some(scan) = plugin.hooks_config().filter(|s| !s.entries.is_empty()) {
+                return scan.hook_names();
+            }
+        }
         plugin.component_names(kind)
     }
 
+    /// hooks.json のパース失敗など、フック一覧に添える警告
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin` - the plugin whose hooks config is inspected
+    pub fn hook_warnings(&self, plugin: &InstalledPlugin) -> Vec<String> {
+        plugin
+            .hooks_config()
+            .map(|scan| scan.warnings)
+            .unwrap_or_default()
+    }
+
     /// プラグインを一覧から削除
     ///
     /// # Arguments
     ///
     /// * `plugin_id` - the id of the plugin to remove
     pub fn remove_plugin(&mut self, plugin_id: &PluginId) {
-        self.plugins.retain(|p| p.id() != plugin_id.as_str());
+        self.plugins.retain(|p| !plugin_id.matches(p));
     }
 
     /// プラグインの有効状態を更新
     ///
+    /// enable / disable は全種別を操作するため、部分 disable の状態は解除される。
+    ///
     /// # Arguments
     ///
     /// * `plugin_id` - the id of the plugin to update
     /// * `enabled` - the new enabled state to apply
     pub fn set_plugin_enabled(&mut self, plugin_id: &PluginId, enabled: bool) {
-        if let Some(plugin) = self
-            .plugins
-            .iter_mut()
-            .find(|p| p.id() == plugin_id.as_str())
-        {
+        if let Some(plugin) = self.plugins.iter_mut().find(|p| plugin_id.matches(p)) {
             plugin.set_enabled(enabled);
         }
     }
@@ -176,8 +402,11 @@ impl DataStore {
     pub fn reload_marketplaces(&mut self) {
         let result = load_marketplaces();
         self.marketplaces = result.items;
-        // 既存の last_error を上書きせず、マーケットプレイス読み込みエラーを追記/保存する
-        self.last_error = merge_errors(self.last_error.take(), result.error);
+        if let Some(error) = result.error {
+            self.push_error(Tab::Marketplaces, error);
+        }
+        self.warnings = result.warnings;
+        self.available_updates = None;
     }
 
     /// マーケットプレイス名で検索
@@ -189,6 +418,25 @@ impl DataStore {
         self.marketplaces.iter().find(|m| m.name == name)
     }
 
+    /// ソース（owner/repo + path）でマーケットプレイスを逆引き
+    ///
+    /// 大文字小文字と `.git` suffix は `marketplace::source_key` で正規化して比較する。
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - the source repository in `owner/repo` form
+    /// * `source_path` - the subdirectory where `marketplace.json` is located
+    pub fn find_marketplace_by_source(
+        &self,
+        source: &str,
+        source_path: Option<&str>,
+    ) -> Option<&MarketplaceItem> {
+        let key = source_key(source, source_path);
+        self.marketplaces
+            .iter()
+            .find(|m| source_key(&m.source, m.source_path.as_deref()) == key)
+    }
+
     /// マーケットプレイス名でインデックスを検索
     ///
     /// # Arguments
@@ -206,12 +454,50 @@ impl DataStore {
     pub fn remove_marketplace(&mut self, name: &str) {
         self.marketplaces.retain(|m| m.name != name);
     }
+
+    /// marketplace.json の警告の件数
+    ///
+    /// # Arguments
+    ///
+    /// * `marketplace` - the marketplace name to count, or `None` for all
+    pub fn warning_count(&self, marketplace: Option<&str>) -> usize {
+        self.warnings
+            .iter()
+            .filter(|w| marketplace.is_none_or(|name| w.marketplace == name))
+            .count()
+    }
+
+    /// エラーを履歴に追加する
+    ///
+    /// # Arguments
+    ///
+    /// * `source_tab` - the tab where the error occurred
+    /// * `message` - the error message
+    pub fn push_error(&mut self, source_tab: Tab, message: impl Into<String>) {
+        self.errors.push(ErrorEntry::now(source_tab, message));
+    }
+
+    /// 最新のエラーメッセージ（エラー履歴の末尾から導出する）
+    pub fn last_error(&self) -> Option<&str> {
+        self.errors.last().map(|e| e.message.as_str())
+    }
+
+    /// エラー履歴を消去する（marketplace.json の警告は残す）
+    pub fn clear_errors(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Errors タブに表示する件数（エラー履歴の件数 + marketplace.json の警告数）
+    pub fn error_count(&self) -> usize {
+        self.errors.len() + self.warnings.len()
+    }
 }
 
 /// マーケットプレイスデータ読み込み結果
 struct LoadMarketplacesOutcome {
     items: Vec<MarketplaceItem>,
     error: Option<String>,
+    warnings: Vec<ManifestWarning>,
 }
 
 /// マーケットプレイスデータを読み込み
@@ -222,6 +508,7 @@ fn load_marketplaces() -> LoadMarketplacesOutcome {
             return LoadMarketplacesOutcome {
                 items: Vec::new(),
                 error: Some(format!("Failed to load marketplace config: {}", e)),
+                warnings: Vec::new(),
             }
         }
     };
@@ -238,52 +525,101 @@ fn load_marketplaces() -> LoadMarketplacesOutcome {
                     source: entry.source.full_name(),
                     source_path: entry.source_path.clone(),
                     plugin_count: None,
-                    last_updated: None,
+                    fetched_at: None,
                 })
                 .collect();
             return LoadMarketplacesOutcome {
                 items,
                 error: Some(format!("Failed to load marketplace registry: {}", e)),
+                warnings: Vec::new(),
             };
         }
     };
 
+    let mut warnings = Vec::new();
     let items = config
         .list()
         .iter()
         .map(|entry| {
-            let (plugin_count, last_updated) = match registry.get(&entry.name) {
-                Ok(Some(cache)) => (
-                    Some(cache.plugins.len()),
-                    Some(cache.fetched_at.format("%Y-%m-%d %H:%M").to_string()),
-                ),
-                _ => (None, None),
-            };
+            let cache = registry.get(&entry.name).ok().flatten();
+            if let Some(cache) = &cache {
+                warnings.extend(cache.validation_warnings().into_iter().map(|issue| {
+                    ManifestWarning {
+                        marketplace: entry.name.clone(),
+                        issue,
+                    }
+                }));
+            }
+            // meta.json が無い旧キャッシュはキャッシュ本体から求める
+            let meta = registry
+                .get_meta(&entry.name)
+                .ok()
+                .flatten()
+                .or_else(|| cache.as_ref().map(MarketplaceCacheMeta::from_cache));
             MarketplaceItem {
                 name: entry.name.clone(),
                 source: entry.source.full_name(),
                 source_path: entry.source_path.clone(),
-                plugin_count,
-                last_updated,
+                plugin_count: meta.as_ref().map(|m| m.plugin_count),
+                fetched_at: meta.map(|m| m.fetched_at),
             }
         })
         .collect();
 
-    LoadMarketplacesOutcome { items, error: None }
+    LoadMarketplacesOutcome {
+        items,
+        error: None,
+        warnings,
+    }
+}
+
+/// インストール済みプラグインが属するマーケットプレイスのキャッシュを読み込む
+///
+/// 読み込めないキャッシュは無視する。
+///
+/// # Arguments
+///
+/// * `plugins` - installed plugins whose marketplaces are loaded
+fn load_marketplace_caches(plugins: &[InstalledPlugin]) -> Vec<MarketplaceCache> {
+    let Ok(registry) = MarketplaceRegistry::new() else {
+        return Vec::new();
+    };
+    let mut names: Vec<&str> = plugins.iter().filter_map(|p| p.marketplace()).collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| registry.get(name).ok().flatten())
+        .collect()
 }
 
-/// 2つのエラーをマージする（既存エラーを保持しつつ新しいエラーを追記）
+/// マーケットプレイスキャッシュのバージョンが新しいプラグインを集める
+///
+/// マーケットプレイスのエントリは名前（`InstalledPlugin::id()` または `name()`）で照合し、
+/// バージョンの比較は [`is_upgrade`] に従う。バージョン未記載のエントリは比較しない。
 ///
 /// # Arguments
 ///
-/// * `existing` - the previously recorded error message, if any
-/// * `new` - the newly encountered error message to append, if any
-fn merge_errors(existing: Option<String>, new: Option<String>) -> Option<String> {
-    match (existing, new) {
-        (Some(prev), Some(next)) => Some(format!("{}\n{}", prev, next)),
-        (Some(prev), None) => Some(prev),
-        (None, next) => next,
-    }
+/// * `plugins` - installed plugins to compare
+/// * `caches` - cached marketplace manifests
+pub fn find_available_updates(
+    plugins: &[InstalledPlugin],
+    caches: &[MarketplaceCache],
+) -> HashMap<PluginId, String> {
+    plugins
+        .iter()
+        .filter_map(|plugin| {
+            let marketplace = plugin.marketplace()?;
+            let cache = caches.iter().find(|c| c.name == marketplace)?;
+            let latest = cache
+                .plugins
+                .iter()
+                .find(|entry| entry.name == plugin.id() || entry.name == plugin.name())?
+                .version
+                .as_deref()?;
+            is_upgrade(plugin.version(), latest).then(|| (PluginId::of(plugin), latest.to_string()))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -307,8 +643,24 @@ impl DataStore {
                 cache,
                 plugins,
                 marketplaces,
-                last_error,
+                errors: last_error
+                    .map(|e| vec![ErrorEntry::now(Tab::default(), e)])
+                    .unwrap_or_default(),
+                warnings: Vec::new(),
+                last_notice: None,
+                last_warnings: Vec::new(),
+                dev_plugin: None,
+                available_updates: Some(HashMap::new()),
             },
         )
     }
+
+    /// 指定したマーケットプレイスキャッシュと比較した結果で更新情報を置き換える
+    ///
+    /// # Arguments
+    ///
+    /// * `caches` - cached marketplace manifests to compare against
+    pub fn set_available_updates_from(&mut self, caches: &[MarketplaceCache]) {
+        self.available_updates = Some(find_available_updates(&self.plugins, caches));
+    }
 }
@@ -1,5 +1,7 @@
 use crate::application::InstalledPlugin;
-use crate::tui::manager::core::DataStore;
+use crate::marketplace::MarketplaceCache;
+use crate::tui::manager::core::data::find_available_updates;
+use crate::tui::manager::core::{DataStore, MarketplaceItem, PluginId, SortKey};
 
 fn make_plugin(name: &str) -> InstalledPlugin {
     InstalledPlugin::new_for_test(name, "1.0.0", Vec::new(), None, None, true)
@@ -89,7 +91,7 @@ fn is_plugin_installed_does_not_match_by_display_name_when_id_set() {
 fn find_plugin_matches_by_id() {
     let plugin = make_plugin_with_id("Display Name", "owner--repo");
     let (_tmp, store) = DataStore::for_test(vec![plugin], vec![], None);
-    let found = store.find_plugin(&"owner--repo".to_string());
+    let found = store.find_plugin(&PluginId::new(None, "owner--repo"));
     assert!(found.is_some());
     assert_eq!(found.unwrap().name(), "Display Name");
 }
@@ -98,22 +100,30 @@ fn find_plugin_matches_by_id() {
 fn find_plugin_does_not_match_by_display_name_when_id_set() {
     let plugin = make_plugin_with_id("Display Name", "owner--repo");
     let (_tmp, store) = DataStore::for_test(vec![plugin], vec![], None);
-    assert!(store.find_plugin(&"Display Name".to_string()).is_none());
+    assert!(store
+        .find_plugin(&PluginId::new(None, "Display Name"))
+        .is_none());
 }
 
 #[test]
 fn plugin_index_matches_by_id() {
     let plugin = make_plugin_with_id("Display Name", "owner--repo");
     let (_tmp, store) = DataStore::for_test(vec![plugin], vec![], None);
-    assert_eq!(store.plugin_index(&"owner--repo".to_string()), Some(0));
-    assert_eq!(store.plugin_index(&"Display Name".to_string()), None);
+    assert_eq!(
+        store.plugin_index(&PluginId::new(None, "owner--repo")),
+        Some(0)
+    );
+    assert_eq!(
+        store.plugin_index(&PluginId::new(None, "Display Name")),
+        None
+    );
 }
 
 #[test]
 fn remove_plugin_works_by_id() {
     let plugin = make_plugin_with_id("Display Name", "owner--repo");
     let (_tmp, mut store) = DataStore::for_test(vec![plugin], vec![], None);
-    store.remove_plugin(&"owner--repo".to_string());
+    store.remove_plugin(&PluginId::new(None, "owner--repo"));
     assert!(!store.is_plugin_installed("owner--repo"));
 }
 
@@ -121,7 +131,225 @@ fn remove_plugin_works_by_id() {
 fn set_plugin_enabled_works_by_id() {
     let plugin = make_plugin_with_id("Display Name", "owner--repo");
     let (_tmp, mut store) = DataStore::for_test(vec![plugin], vec![], None);
-    store.set_plugin_enabled(&"owner--repo".to_string(), false);
-    let found = store.find_plugin(&"owner--repo".to_string()).unwrap();
+    store.set_plugin_enabled(&PluginId::new(None, "owner--repo"), false);
+    let found = store
+        .find_plugin(&PluginId::new(None, "owner--repo"))
+        .unwrap();
     assert!(!found.enabled());
 }
+
+#[test]
+fn plugin_id_displays_marketplace_and_name() {
+    assert_eq!(PluginId::new(None, "foo").to_string(), "github/foo");
+    assert_eq!(
+        PluginId::new(Some("official"), "foo").to_string(),
+        "official/foo"
+    );
+}
+
+#[test]
+fn plugin_id_normalizes_github_marketplace() {
+    assert_eq!(
+        PluginId::new(Some("github"), "foo"),
+        PluginId::new(None, "foo")
+    );
+    assert_eq!(PluginId::new(Some("github"), "foo").marketplace(), None);
+}
+
+#[test]
+fn plugin_id_parses_display_form() {
+    let id: PluginId = "official/foo".parse().unwrap();
+    assert_eq!(id, PluginId::new(Some("official"), "foo"));
+    assert_eq!(id.to_string().parse::<PluginId>().unwrap(), id);
+    assert_eq!(
+        "github/foo".parse::<PluginId>().unwrap(),
+        PluginId::new(None, "foo")
+    );
+    assert!("foo".parse::<PluginId>().is_err());
+    assert!("/foo".parse::<PluginId>().is_err());
+}
+
+#[test]
+fn plugin_id_of_matches_installed_plugin() {
+    let plugin = InstalledPlugin::new_for_test(
+        "foo",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        true,
+    );
+    let id = PluginId::of(&plugin);
+    assert_eq!(id, PluginId::new(Some("official"), "foo"));
+    assert!(id.matches(&plugin));
+    assert!(!PluginId::new(None, "foo").matches(&plugin));
+}
+
+#[test]
+fn find_plugin_distinguishes_marketplaces() {
+    let official = InstalledPlugin::new_for_test(
+        "foo",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        true,
+    );
+    let (_tmp, store) = DataStore::for_test(vec![make_plugin("foo"), official], vec![], None);
+    let found = store
+        .find_plugin(&PluginId::new(Some("official"), "foo"))
+        .unwrap();
+    assert_eq!(found.marketplace(), Some("official"));
+    let found = store.find_plugin(&PluginId::new(None, "foo")).unwrap();
+    assert_eq!(found.marketplace(), None);
+    assert!(store
+        .find_plugin(&PluginId::new(Some("other"), "foo"))
+        .is_none());
+}
+
+#[test]
+fn same_name_plugins_are_updated_independently() {
+    let official = InstalledPlugin::new_for_test(
+        "foo",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        true,
+    );
+    let (_tmp, mut store) = DataStore::for_test(vec![make_plugin("foo"), official], vec![], None);
+    let official_id = PluginId::new(Some("official"), "foo");
+    let direct_id = PluginId::new(None, "foo");
+
+    store.set_plugin_enabled(&official_id, false);
+    assert!(!store.find_plugin(&official_id).unwrap().enabled());
+    assert!(store.find_plugin(&direct_id).unwrap().enabled());
+    assert_eq!(store.plugin_index(&official_id), Some(1));
+
+    store.remove_plugin(&official_id);
+    assert!(store.find_plugin(&official_id).is_none());
+    assert!(store.find_plugin(&direct_id).is_some());
+}
+
+#[test]
+fn find_marketplace_by_source_normalizes_case_and_git_suffix() {
+    let item = MarketplaceItem {
+        name: "mp-a".to_string(),
+        source: "owner/repo".to_string(),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    };
+    let (_tmp, store) = DataStore::for_test(vec![], vec![item], None);
+
+    let found = store.find_marketplace_by_source("Owner/Repo.git", None);
+    assert_eq!(found.map(|m| m.name.as_str()), Some("mp-a"));
+    assert!(store
+        .find_marketplace_by_source("owner/repo", Some("sub"))
+        .is_none());
+    assert!(store
+        .find_marketplace_by_source("owner/other", None)
+        .is_none());
+}
+
+// =============================================================================
+// find_available_updates
+// =============================================================================
+
+fn marketplace_cache(name: &str, plugins: serde_json::Value) -> MarketplaceCache {
+    serde_json::from_value(serde_json::json!({
+        "name": name,
+        "fetched_at": "2025-01-15T10:30:00Z",
+        "source": "github:owner/repo",
+        "plugins": plugins
+    }))
+    .unwrap()
+}
+
+fn make_marketplace_plugin(
+    name: &str,
+    version: &str,
+    marketplace: Option<&str>,
+) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(
+        name,
+        version,
+        Vec::new(),
+        None,
+        marketplace.map(str::to_string),
+        true,
+    )
+}
+
+#[test]
+fn find_available_updates_reports_newer_marketplace_versions() {
+    let plugins = vec![
+        make_marketplace_plugin("newer", "1.0.0", Some("mp")),
+        make_marketplace_plugin("same", "1.0.0", Some("mp")),
+        make_marketplace_plugin("unversioned", "1.0.0", Some("mp")),
+        make_marketplace_plugin("dated", "2024.09", Some("mp")),
+        make_marketplace_plugin("direct", "1.0.0", None),
+    ];
+    let caches = vec![marketplace_cache(
+        "mp",
+        serde_json::json!([
+            { "name": "newer", "source": "./newer", "version": "1.2.0" },
+            { "name": "same", "source": "./same", "version": "v1.0.0" },
+            { "name": "unversioned", "source": "./unversioned" },
+            { "name": "dated", "source": "./dated", "version": "2024.10" },
+            { "name": "direct", "source": "./direct", "version": "9.9.9" }
+        ]),
+    )];
+
+    let updates = find_available_updates(&plugins, &caches);
+
+    assert_eq!(updates.len(), 2, "{:?}", updates);
+    assert_eq!(updates[&PluginId::new(Some("mp"), "newer")], "1.2.0");
+    assert_eq!(updates[&PluginId::new(Some("mp"), "dated")], "2024.10");
+}
+
+#[test]
+fn find_available_updates_ignores_other_marketplaces() {
+    let plugins = vec![make_marketplace_plugin("alpha", "1.0.0", Some("mp"))];
+    let caches = vec![marketplace_cache(
+        "other",
+        serde_json::json!([{ "name": "alpha", "source": "./alpha", "version": "2.0.0" }]),
+    )];
+
+    assert!(find_available_updates(&plugins, &caches).is_empty());
+}
+
+#[test]
+fn available_update_reads_cached_check_result() {
+    let plugin = make_marketplace_plugin("alpha", "1.0.0", Some("mp"));
+    let (_tmp, mut store) = DataStore::for_test(vec![plugin.clone()], vec![], None);
+    assert_eq!(store.available_update(&plugin), None);
+
+    store.set_available_updates_from(&[marketplace_cache(
+        "mp",
+        serde_json::json!([{ "name": "alpha", "source": "./alpha", "version": "1.1.0" }]),
+    )]);
+
+    assert_eq!(store.available_update(&plugin), Some("1.1.0"));
+}
+
+#[test]
+fn sorted_plugins_filters_then_sorts() {
+    let (_tmp, store) = DataStore::for_test(
+        vec![
+            make_plugin("lint-b"),
+            make_plugin("formatter"),
+            make_plugin("lint-a"),
+        ],
+        vec![],
+        None,
+    );
+
+    let names: Vec<&str> = store
+        .sorted_plugins(SortKey::Name, "lint")
+        .iter()
+        .map(|p| p.name())
+        .collect();
+
+    assert_eq!(names, vec!["lint-a", "lint-b"]);
+}
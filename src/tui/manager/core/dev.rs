@@ -0,0 +1,217 @@
+//! dev モード（ローカル開発プラグイン）
+//!
+//! `plm managed --dev <PATH>` で指定されたディレクトリを「開発中プラグイン」として保持する。
+//! キャッシュには入れず、通常の install/uninstall フローとは独立して
+//! 再スキャン（reload）と変更検知（ポーリング）を行う。
+
+use crate::application::InstalledPlugin;
+use crate::plugin::meta::resolve_manifest_path;
+use crate::plugin::{Plugin, PluginManifest};
+use crate::target::PluginOrigin;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// dev プラグインのデプロイ階層に使うマーケットプレイス名
+pub const DEV_MARKETPLACE: &str = "dev";
+
+/// 変更検知の対象外とするディレクトリ名
+const IGNORED_DIRS: &[&str] = &[".git", "node_modules", "target"];
+
+/// dev プラグインのステータス行表示
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DevStatus {
+    /// 通常の通知（リロード完了・デプロイ結果など）
+    Info(String),
+    /// 監視・読み込み・デプロイのエラー（TUI は継続する）
+    Error(String),
+}
+
+/// 開発中プラグイン
+///
+/// 読み込みに失敗しても値自体は保持し、エラーは `status` に記録する。
+/// 次回のリロードまたは変更検知で復帰できる。
+#[derive(Debug)]
+pub struct DevPlugin {
+    root: PathBuf,
+    manifest: Option<PluginManifest>,
+    plugin: Option<InstalledPlugin>,
+    fingerprint: Option<u64>,
+    status: Option<DevStatus>,
+}
+
+impl DevPlugin {
+    /// 指定ディレクトリを dev プラグインとして開く
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Plugin root directory given via `--dev`.
+    pub fn open(root: PathBuf) -> Self {
+        let mut dev = Self {
+            root,
+            manifest: None,
+            plugin: None,
+            fingerprint: None,
+            status: None,
+        };
+        dev.fingerprint = fingerprint(&dev.root).ok();
+        dev.load();
+        dev
+    }
+
+    /// プラグインのルートディレクトリ
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 読み込み済みのプラグイン（読み込み失敗時は `None`）
+    pub fn plugin(&self) -> Option<&InstalledPlugin> {
+        self.plugin.as_ref()
+    }
+
+    /// 読み込み済みのマニフェスト（読み込み失敗時は `None`）
+    pub fn manifest(&self) -> Option<&PluginManifest> {
+        self.manifest.as_ref()
+    }
+
+    /// ステータス行の内容
+    pub fn status(&self) -> Option<&DevStatus> {
+        self.status.as_ref()
+    }
+
+    /// ステータス行を更新
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - New status line content.
+    pub fn set_status(&mut self, status: DevStatus) {
+        self.status = Some(status);
+    }
+
+    /// 手動リロード（再スキャン）
+    pub fn reload(&mut self) {
+        self.fingerprint = fingerprint(&self.root).ok();
+        if self.load() {
+            self.status = Some(DevStatus::Info("Reloaded".to_string()));
+        }
+    }
+
+    /// ファイル変更をポーリングで検知し、変更があれば再スキャンする
+    ///
+    /// 変更を検知してリロードした場合は `true` を返す。
+    /// ディレクトリ削除などの監視エラーはステータス行に記録して `false` を返す。
+    pub fn poll_changes(&mut self) -> bool {
+        match fingerprint(&self.root) {
+            Ok(current) => {
+                if self.fingerprint == Some(current) {
+                    return false;
+                }
+                self.fingerprint = Some(current);
+                if self.load() {
+                    self.status = Some(DevStatus::Info("Change detected, reloaded".to_string()));
+                }
+                true
+            }
+            Err(e) => {
+                let message = format!("Watch error: {}: {}", self.root.display(), e);
+                // 同じエラーを毎 tick 書き直さないよう、直前と同じなら何もしない
+                if self.status.as_ref() != Some(&DevStatus::Error(message.clone())) {
+                    self.status = Some(DevStatus::Error(message));
+                }
+                self.fingerprint = None;
+                false
+            }
+        }
+    }
+
+    /// マニフェストとコンポーネントを読み直す。成功時は `true`。
+    fn load(&mut self) -> bool {
+        match load_dev_plugin(&self.root) {
+            Ok((manifest, plugin)) => {
+                self.manifest = Some(manifest);
+                self.plugin = Some(plugin);
+                true
+            }
+            Err(e) => {
+                self.manifest = None;
+                self.plugin = None;
+                self.status = Some(DevStatus::Error(e));
+                false
+            }
+        }
+    }
+}
+
+/// dev プラグインのマニフェストを解決してコンポーネントをスキャンする
+///
+/// # Arguments
+///
+/// * `root` - Plugin root directory.
+fn load_dev_plugin(root: &Path) -> Result<(PluginManifest, InstalledPlugin), String> {
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+    let manifest_path = resolve_manifest_path(root)
+        .ok_or_else(|| format!("plugin.json not found in {}", root.display()))?;
+    let manifest = PluginManifest::load(&manifest_path)
+        .map_err(|e| format!("Failed to load manifest: {}", e))?;
+    let origin = PluginOrigin::from_marketplace(DEV_MARKETPLACE, &manifest.name);
+    let plugin = Plugin::new(manifest.clone(), root.to_path_buf(), origin)
+        .map_err(|e| format!("Failed to scan plugin: {}", e))?;
+    let installed =
+        InstalledPlugin::from_cached_package(plugin, None, Some(DEV_MARKETPLACE.to_string()), true);
+    Ok((manifest, installed))
+}
+
+/// ディレクトリ配下のファイル構成（相対パス・サイズ・更新時刻）からハッシュ値を計算する
+///
+/// 追加・削除・リネーム・内容変更（サイズまたは mtime）を検知するためのもの。
+///
+/// # Arguments
+///
+/// * `root` - Directory to fingerprint.
+pub(crate) fn fingerprint(root: &Path) -> std::io::Result<u64> {
+    if !root.is_dir() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "directory not found",
+        ));
+    }
+
+    let mut hasher = DefaultHasher::new();
+    let walker = WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            !(e.file_type().is_dir()
+                && e.file_name()
+                    .to_str()
+                    .is_some_and(|n| IGNORED_DIRS.contains(&n)))
+        });
+    for entry in walker {
+        let entry = entry.map_err(std::io::Error::other)?;
+        entry
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(entry.path())
+            .hash(&mut hasher);
+        // ディレクトリ自身の mtime は無視対象（.git 等）の変更でも動くため、ファイルのみ見る
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = entry.metadata().map_err(std::io::Error::other)?;
+        metadata.len().hash(&mut hasher);
+        metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .hash(&mut hasher);
+    }
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+#[path = "dev_test.rs"]
+mod dev_test;
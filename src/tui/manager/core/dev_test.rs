@@ -0,0 +1,140 @@
+use super::*;
+use crate::component::ComponentKind;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_plugin(root: &Path, name: &str) {
+    fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+    fs::write(
+        root.join(".claude-plugin/plugin.json"),
+        format!(r#"{{"name":"{}","version":"0.1.0"}}"#, name),
+    )
+    .unwrap();
+}
+
+fn write_skill(root: &Path, skill: &str) {
+    let dir = root.join("skills").join(skill);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("SKILL.md"), "---\nname: s\n---\n").unwrap();
+}
+
+#[test]
+fn open_loads_manifest_and_components() {
+    let tmp = TempDir::new().unwrap();
+    write_plugin(tmp.path(), "my-plugin");
+    write_skill(tmp.path(), "alpha");
+
+    let dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    let plugin = dev.plugin().expect("plugin should load");
+    assert_eq!(plugin.name(), "my-plugin");
+    assert_eq!(plugin.marketplace(), Some(DEV_MARKETPLACE));
+    assert_eq!(plugin.component_names(ComponentKind::Skill).len(), 1);
+    assert!(dev.status().is_none());
+}
+
+#[test]
+fn open_without_manifest_records_error_status() {
+    let tmp = TempDir::new().unwrap();
+
+    let dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    assert!(dev.plugin().is_none());
+    assert!(matches!(dev.status(), Some(DevStatus::Error(msg)) if msg.contains("plugin.json")));
+}
+
+#[test]
+fn poll_changes_returns_false_when_nothing_changed() {
+    let tmp = TempDir::new().unwrap();
+    write_plugin(tmp.path(), "my-plugin");
+
+    let mut dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    assert!(!dev.poll_changes());
+}
+
+#[test]
+fn poll_changes_rescans_when_file_added() {
+    let tmp = TempDir::new().unwrap();
+    write_plugin(tmp.path(), "my-plugin");
+    let mut dev = DevPlugin::open(tmp.path().to_path_buf());
+    assert_eq!(
+        dev.plugin()
+            .unwrap()
+            .component_names(ComponentKind::Skill)
+            .len(),
+        0
+    );
+
+    write_skill(tmp.path(), "alpha");
+
+    assert!(dev.poll_changes());
+    assert_eq!(
+        dev.plugin()
+            .unwrap()
+            .component_names(ComponentKind::Skill)
+            .len(),
+        1
+    );
+    assert!(matches!(dev.status(), Some(DevStatus::Info(_))));
+}
+
+#[test]
+fn poll_changes_reports_watch_error_when_directory_removed() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path().join("plugin");
+    write_plugin(&root, "my-plugin");
+    let mut dev = DevPlugin::open(root.clone());
+
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(!dev.poll_changes());
+    assert!(matches!(dev.status(), Some(DevStatus::Error(msg)) if msg.starts_with("Watch error")));
+}
+
+#[test]
+fn poll_changes_recovers_after_directory_recreated() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path().join("plugin");
+    write_plugin(&root, "my-plugin");
+    let mut dev = DevPlugin::open(root.clone());
+    fs::remove_dir_all(&root).unwrap();
+    dev.poll_changes();
+
+    write_plugin(&root, "my-plugin");
+
+    assert!(dev.poll_changes());
+    assert!(dev.plugin().is_some());
+    assert!(matches!(dev.status(), Some(DevStatus::Info(_))));
+}
+
+#[test]
+fn reload_picks_up_manifest_changes() {
+    let tmp = TempDir::new().unwrap();
+    write_plugin(tmp.path(), "before");
+    let mut dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    write_plugin(tmp.path(), "after");
+    dev.reload();
+
+    assert_eq!(dev.plugin().unwrap().name(), "after");
+    assert_eq!(dev.status(), Some(&DevStatus::Info("Reloaded".to_string())));
+}
+
+#[test]
+fn fingerprint_ignores_git_directory() {
+    let tmp = TempDir::new().unwrap();
+    write_plugin(tmp.path(), "my-plugin");
+    let before = fingerprint(tmp.path()).unwrap();
+
+    fs::create_dir_all(tmp.path().join(".git")).unwrap();
+    fs::write(tmp.path().join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+
+    assert_eq!(fingerprint(tmp.path()).unwrap(), before);
+}
+
+#[test]
+fn fingerprint_fails_for_missing_directory() {
+    let tmp = TempDir::new().unwrap();
+    assert!(fingerprint(&tmp.path().join("missing")).is_err());
+}
@@ -0,0 +1,50 @@
+//! イベント取得の抽象化
+//!
+//! イベントループはターミナル（crossterm）から直接読まず、[`EventSource`] 経由で
+//! キー入力を受け取る。実ターミナルのほか、操作スクリプトの再生（`--replay`）と
+//! 記録（`--record`）を同じループに差し込める（`script` モジュール）。
+
+use crossterm::event::{self, Event};
+use std::io;
+use std::time::Duration;
+
+/// イベントループへの入力元
+pub trait EventSource {
+    /// `timeout` 以内に読めるイベントがあるか確認する（`event::poll` 相当）
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - how long to wait for an event
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool>;
+
+    /// 次のイベントを読む（`event::read` 相当。届くまでブロックする）
+    fn read(&mut self) -> io::Result<Event>;
+
+    /// 一括処理中に溜まった入力を破棄する
+    fn discard_pending(&mut self) -> io::Result<()>;
+
+    /// これ以上入力が来ないか（スクリプトの終端）
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
+
+/// 実ターミナルからの入力
+pub struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        event::poll(timeout)
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        while event::poll(Duration::ZERO)? {
+            let _ = event::read()?;
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,49 @@
 //! フィルタロジック
 //!
-//! プラグイン一覧をフィルタテキストで絞り込む共通モジュール。
+//! 各画面の一覧をフィルタテキストで絞り込む共通モジュール。
 
+use super::data::{MarketplaceItem, PluginId};
 use crate::application::InstalledPlugin;
 
+/// フィルタテキストで任意の一覧を絞り込む
+///
+/// - `filter_text` が空なら全件返却
+/// - `matches` には小文字化したクエリが渡される（[`contains_query`] で部分一致を判定する）
+///
+/// # Arguments
+///
+/// * `items` - 絞り込み対象の一覧
+/// * `filter_text` - 検索クエリ文字列。空なら全件をそのまま返す
+/// * `matches` - 項目が小文字化済みクエリに一致するかを返す関数
+pub fn filter_items<'a, T>(
+    items: &'a [T],
+    filter_text: &str,
+    matches: impl Fn(&T, &str) -> bool,
+) -> Vec<&'a T> {
+    if filter_text.is_empty() {
+        return items.iter().collect();
+    }
+
+    let query = filter_text.to_lowercase();
+    items.iter().filter(|item| matches(item, &query)).collect()
+}
+
+/// case-insensitive な部分一致
+///
+/// # Arguments
+///
+/// * `text` - 検索対象の文字列
+/// * `query` - 小文字化済みのクエリ
+pub fn contains_query(text: &str, query: &str) -> bool {
+    text.to_lowercase().contains(query)
+}
+
 /// フィルタテキストでプラグインを絞り込む
 ///
 /// - `filter_text` が空なら全件返却
-/// - case-insensitive な部分一致で `name` と `marketplace` を検索
+/// - case-insensitive な部分一致で `name` と `marketplace`、プラグインIDの文字列表現
+///   （`"<marketplace>/<cache_id>"`）を検索する（`official/foo` のような絞り込みが可能）
+/// - plugin.json の `keywords` にも部分一致する
 ///
 /// # Arguments
 ///
@@ -17,17 +53,39 @@ pub fn filter_plugins<'a>(
     plugins: &'a [InstalledPlugin],
     filter_text: &str,
 ) -> Vec<&'a InstalledPlugin> {
-    if filter_text.is_empty() {
-        return plugins.iter().collect();
-    }
+    filter_items(plugins, filter_text, |p, query| {
+        contains_query(p.name(), query)
+            || p.marketplace().is_some_and(|m| contains_query(m, query))
+            || contains_query(&PluginId::of(p).to_string(), query)
+            || p.keywords().iter().any(|k| contains_query(k, query))
+    })
+}
 
-    let query = filter_text.to_lowercase();
-    plugins
-        .iter()
-        .filter(|p| {
-            p.name().to_lowercase().contains(&query)
-                || p.marketplace()
-                    .is_some_and(|m| m.to_lowercase().contains(&query))
-        })
-        .collect()
+/// フィルタテキストでマーケットプレイスを絞り込む
+///
+/// case-insensitive な部分一致で `name` と `source` を検索する。
+///
+/// # Arguments
+///
+/// * `marketplaces` - 絞り込み対象のマーケットプレイス一覧
+/// * `filter_text` - 検索クエリ文字列。空なら全件をそのまま返す
+pub fn filter_marketplaces<'a>(
+    marketplaces: &'a [MarketplaceItem],
+    filter_text: &str,
+) -> Vec<&'a MarketplaceItem> {
+    filter_items(marketplaces, filter_text, |m, query| {
+        contains_query(&m.name, query) || contains_query(&m.source, query)
+    })
+}
+
+/// フィルタテキストで名前の一覧（コンポーネント名など）を絞り込む
+///
+/// # Arguments
+///
+/// * `names` - 絞り込み対象の名前一覧
+/// * `filter_text` - 検索クエリ文字列。空なら全件をそのまま返す
+pub fn filter_names<'a>(names: &'a [String], filter_text: &str) -> Vec<&'a String> {
+    filter_items(names, filter_text, |name, query| {
+        contains_query(name, query)
+    })
 }
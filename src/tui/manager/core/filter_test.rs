@@ -1,5 +1,6 @@
 use crate::application::InstalledPlugin;
-use crate::tui::manager::core::filter::filter_plugins;
+use crate::tui::manager::core::filter::{filter_marketplaces, filter_names, filter_plugins};
+use crate::tui::manager::core::MarketplaceItem;
 
 fn make_plugin(name: &str, marketplace: Option<&str>) -> InstalledPlugin {
     InstalledPlugin::new_for_test(
@@ -62,3 +63,60 @@ fn filter_with_none_marketplace() {
     let result = filter_plugins(&plugins, "market");
     assert!(result.is_empty());
 }
+
+fn make_marketplace(name: &str, source: &str) -> MarketplaceItem {
+    MarketplaceItem {
+        name: name.to_string(),
+        source: source.to_string(),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    }
+}
+
+#[test]
+fn filter_marketplaces_by_name_or_source() {
+    let marketplaces = vec![
+        make_marketplace("official", "anthropics/plugins"),
+        make_marketplace("company", "acme/claude-tools"),
+    ];
+    let by_name = filter_marketplaces(&marketplaces, "OFFI");
+    assert_eq!(by_name.len(), 1);
+    assert_eq!(by_name[0].name, "official");
+
+    let by_source = filter_marketplaces(&marketplaces, "acme/");
+    assert_eq!(by_source.len(), 1);
+    assert_eq!(by_source[0].name, "company");
+}
+
+#[test]
+fn filter_names_partial_match() {
+    let names = vec!["formatter".to_string(), "linter".to_string()];
+    assert_eq!(filter_names(&names, "").len(), 2);
+    assert_eq!(filter_names(&names, "Lint"), vec!["linter"]);
+    assert!(filter_names(&names, "xyz").is_empty());
+}
+
+#[test]
+fn filter_plugins_matches_keywords() {
+    let manifest = serde_json::from_value(serde_json::json!({
+        "name": "formatter",
+        "version": "1.0.0",
+        "keywords": ["Linting", "style"]
+    }))
+    .unwrap();
+    let tagged = InstalledPlugin::new_for_test_full(
+        manifest,
+        std::path::PathBuf::from("/test"),
+        Vec::new(),
+        None,
+        None,
+        true,
+    );
+    let plugins = vec![tagged, make_plugin("other", None)];
+
+    let result = filter_plugins(&plugins, "lint");
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name(), "formatter");
+}
@@ -0,0 +1,91 @@
+//! 画面遷移の履歴スタック
+//!
+//! 各タブの update は前の画面へ進むたびに遷移元の画面状態とフィルタ文字列を積み、
+//! Back で取り出してそのまま復元する。ListState は選択位置とスクロールオフセットを
+//! 両方保持しているため、スナップショットを戻すだけでカーソル位置も元に戻る。
+
+/// 履歴スタックの最大深さ（超えた分は最も古いものから捨てる）
+pub const MAX_HISTORY_DEPTH: usize = 10;
+
+/// 遷移元画面のスナップショット
+#[derive(Debug, Clone)]
+pub struct HistoryEntry<T> {
+    /// 遷移直前の画面状態
+    pub screen: T,
+    /// 遷移直前のフィルタ文字列
+    pub filter_text: String,
+}
+
+/// 画面遷移の履歴スタック
+#[derive(Debug)]
+pub struct NavigationHistory<T> {
+    entries: Vec<HistoryEntry<T>>,
+}
+
+impl<T> Default for NavigationHistory<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T> NavigationHistory<T> {
+    /// 遷移元の画面を積む
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - Screen state being left.
+    /// * `filter_text` - Filter text at the time of the transition.
+    pub fn push(&mut self, screen: T, filter_text: &str) {
+        if self.entries.len() >= MAX_HISTORY_DEPTH {
+            self.entries.remove(0);
+        }
+        self.entries.push(HistoryEntry {
+            screen,
+            filter_text: filter_text.to_string(),
+        });
+    }
+
+    /// 直前の画面を取り出す
+    pub fn pop(&mut self) -> Option<HistoryEntry<T>> {
+        self.entries.pop()
+    }
+
+    /// 最も古い画面（通常はタブのルート画面）を取り出し、残りを捨てる
+    pub fn take_root(&mut self) -> Option<HistoryEntry<T>> {
+        self.entries.drain(..).next()
+    }
+
+    /// 先頭が条件に合う間、履歴を捨てる
+    ///
+    /// # Arguments
+    ///
+    /// * `discard` - Returns `true` for entries that should be dropped.
+    pub fn discard_while(&mut self, mut discard: impl FnMut(&T) -> bool) {
+        while self
+            .entries
+            .last()
+            .is_some_and(|entry| discard(&entry.screen))
+        {
+            self.entries.pop();
+        }
+    }
+
+    /// 履歴を空にする
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[path = "history_test.rs"]
+mod tests;
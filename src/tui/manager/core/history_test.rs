@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn pop_returns_entries_in_reverse_order_with_filter() {
+    let mut history = NavigationHistory::default();
+    history.push("list", "foo");
+    history.push("detail", "");
+
+    let detail = history.pop().unwrap();
+    assert_eq!(detail.screen, "detail");
+    assert_eq!(detail.filter_text, "");
+
+    let list = history.pop().unwrap();
+    assert_eq!(list.screen, "list");
+    assert_eq!(list.filter_text, "foo");
+
+    assert!(history.pop().is_none());
+}
+
+#[test]
+fn push_drops_oldest_entry_beyond_max_depth() {
+    let mut history = NavigationHistory::default();
+    for i in 0..MAX_HISTORY_DEPTH + 2 {
+        history.push(i, "");
+    }
+
+    assert_eq!(history.len(), MAX_HISTORY_DEPTH);
+    assert_eq!(history.take_root().unwrap().screen, 2);
+    assert!(history.is_empty());
+}
+
+#[test]
+fn take_root_returns_oldest_and_clears() {
+    let mut history = NavigationHistory::default();
+    history.push("root", "x");
+    history.push("child", "");
+
+    let root = history.take_root().unwrap();
+    assert_eq!(root.screen, "root");
+    assert_eq!(root.filter_text, "x");
+    assert!(history.is_empty());
+}
+
+#[test]
+fn discard_while_stops_at_first_kept_entry() {
+    let mut history = NavigationHistory::default();
+    history.push(1, "");
+    history.push(2, "");
+    history.push(3, "");
+
+    history.discard_while(|n| *n >= 2);
+
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.pop().unwrap().screen, 1);
+}
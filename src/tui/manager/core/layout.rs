@@ -149,6 +149,64 @@ pub fn split_horizontal(area: Rect, ratio: (u32, u32)) -> [Rect; 2] {
     [chunks[0], chunks[1]]
 }
 
+/// 一覧の列間に挟む空白幅（cells）
+pub const COLUMN_GAP: usize = 2;
+
+/// 一覧の各列の幅（全行での最大文字数）を返す。
+///
+/// 幅は `truncate_to_width` と同じく文字数で数える（ASCII 主体の一覧を想定）。
+///
+/// # Arguments
+///
+/// * `rows` - Cells of every row, in column order.
+pub fn column_widths<R, S>(rows: &[R]) -> Vec<usize>
+where
+    R: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        for (i, cell) in row.as_ref().iter().enumerate() {
+            let width = cell.as_ref().chars().count();
+            match widths.get_mut(i) {
+                Some(w) => *w = (*w).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    widths
+}
+
+/// 1 行分のセルを列幅に揃える。
+///
+/// 各セルを列幅まで空白で埋め、2 列目以降は先頭に `COLUMN_GAP` の空白を付ける。
+/// 全行で空の列（幅 0）は列間の空白ごと詰め、最終列は埋めない（空なら空白も付けない）。
+/// 戻り値はセルと同じ並びなので、呼び出し側で列ごとにスタイルを付けられる。
+///
+/// # Arguments
+///
+/// * `cells` - Cells of one row, in column order.
+/// * `widths` - Column widths from [`column_widths`].
+pub fn align_columns<S: AsRef<str>>(cells: &[S], widths: &[usize]) -> Vec<String> {
+    let last = widths.iter().rposition(|w| *w > 0);
+    let mut started = false;
+    cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            let cell = cell.as_ref();
+            let width = widths.get(i).copied().unwrap_or(0);
+            if width == 0 || (Some(i) == last && cell.is_empty()) {
+                return String::new();
+            }
+            let gap = if started { COLUMN_GAP } else { 0 };
+            started = true;
+            let pad = if Some(i) == last { 0 } else { width };
+            format!("{:gap$}{:<pad$}", "", cell)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[path = "layout_test.rs"]
 mod layout_test;
@@ -193,3 +193,33 @@ fn split_horizontal_with_zero_ratio_does_not_panic() {
     assert_eq!(chunks[0].width, 0);
     assert_eq!(chunks[1].width, 100);
 }
+
+#[test]
+fn column_widths_takes_max_chars_per_column() {
+    let rows = [["alpha", "", "1.0.0"], ["b", "", "1.0.0 → 1.2.0"]];
+    assert_eq!(column_widths(&rows), vec![5, 0, 13]);
+}
+
+#[test]
+fn align_columns_pads_cells_and_skips_empty_columns() {
+    let rows = [
+        ["alpha", "", "1.0.0", ""],
+        ["b", "", "10.0.0", "[disabled]"],
+    ];
+    let widths = column_widths(&rows);
+    assert_eq!(align_columns(&rows[0], &widths).concat(), "alpha  1.0.0 ");
+    assert_eq!(
+        align_columns(&rows[1], &widths).concat(),
+        "b      10.0.0  [disabled]"
+    );
+}
+
+#[test]
+fn align_columns_keeps_one_entry_per_cell() {
+    let rows = [["a", "", "b"]];
+    let aligned = align_columns(&rows[0], &column_widths(&rows));
+    assert_eq!(
+        aligned,
+        vec!["a".to_string(), String::new(), "  b".to_string()]
+    );
+}
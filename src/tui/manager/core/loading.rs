@@ -0,0 +1,209 @@
+//! 起動時のローディング画面
+//!
+//! DataStore の初期ロードをバックグラウンドスレッドで実行し、完了するまで
+//! スピナーと読み込み済み件数を表示する。ロード結果はチャネルで受け取る。
+//! 失敗時は Errors タブ相当の画面でエラーを表示し、`r` で再試行できる。
+
+use super::data::{DataStore, LoadProgress};
+use super::layout::{framed_layout, outer_rect};
+use super::spinner::SPINNER_FRAMES;
+use super::style::bordered_block;
+use super::{render_tab_bar, Tab, Theme};
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+use ratatui::widgets::{Clear, Paragraph};
+use std::io;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+
+/// バックグラウンドで実行するロード処理
+type Loader = Arc<dyn Fn(&LoadProgress) -> io::Result<DataStore> + Send + Sync>;
+
+/// ロードの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadState {
+    /// ロード中
+    Loading,
+    /// ロード失敗（エラーメッセージ）
+    Failed(String),
+}
+
+/// ローディング画面の状態
+pub struct LoadingModel {
+    /// ロードの状態
+    pub state: LoadState,
+    /// 終了フラグ
+    pub should_quit: bool,
+    /// スピナーのフレーム番号
+    frame: usize,
+    /// 実行中のロードの進捗
+    progress: Arc<LoadProgress>,
+    /// ロード結果の受信側
+    receiver: Receiver<io::Result<DataStore>>,
+    /// 再試行用に保持するロード処理
+    loader: Loader,
+}
+
+impl LoadingModel {
+    /// DataStore のロードを開始する
+    pub fn start() -> Self {
+        Self::spawn(Arc::new(|progress: &LoadProgress| {
+            let mut data = DataStore::load(progress)?;
+            // 更新有無の比較もキャッシュを読むため、ロードスレッドで済ませておく
+            data.ensure_available_updates();
+            Ok(data)
+        }))
+    }
+
+    /// 指定したロード処理をバックグラウンドスレッドで開始する
+    ///
+    /// # Arguments
+    ///
+    /// * `loader` - load routine run on the background thread (re-run on retry)
+    pub(crate) fn spawn(loader: Loader) -> Self {
+        let (progress, receiver) = run_loader(&loader);
+        Self {
+            state: LoadState::Loading,
+            should_quit: false,
+            frame: 0,
+            progress,
+            receiver,
+            loader,
+        }
+    }
+
+    /// ロード結果を確認する
+    ///
+    /// 成功していればデータストアを返す。失敗していれば [`LoadState::Failed`] に遷移する。
+    pub fn poll(&mut self) -> Option<DataStore> {
+        if self.state != LoadState::Loading {
+            return None;
+        }
+        match self.receiver.try_recv() {
+            Ok(Ok(data)) => Some(data),
+            Ok(Err(e)) => {
+                self.state = LoadState::Failed(e.to_string());
+                None
+            }
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                self.state = LoadState::Failed("Loading stopped unexpectedly".to_string());
+                None
+            }
+        }
+    }
+
+    /// キー入力をメッセージに変換
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key code received from the terminal
+    pub fn key_to_msg(&self, key: KeyCode) -> Option<Msg> {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => Some(Msg::Quit),
+            KeyCode::Char('r') if matches!(self.state, LoadState::Failed(_)) => Some(Msg::Retry),
+            _ => None,
+        }
+    }
+}
+
+/// ロード処理を新しいスレッドで実行し、進捗と結果の受信側を返す
+///
+/// # Arguments
+///
+/// * `loader` - load routine to run
+fn run_loader(loader: &Loader) -> (Arc<LoadProgress>, Receiver<io::Result<DataStore>>) {
+    let progress = Arc::new(LoadProgress::default());
+    let (sender, receiver) = mpsc::channel();
+    let thread_progress = Arc::clone(&progress);
+    let thread_loader = Arc::clone(loader);
+    thread::spawn(move || {
+        // 受信側が先に終了（ロード中の `q`）していれば結果は捨てる
+        let _ = sender.send(thread_loader(&thread_progress));
+    });
+    (progress, receiver)
+}
+
+/// ローディング画面へのメッセージ
+pub enum Msg {
+    /// スピナーを進める（キー入力のない一定間隔ごとに発行）
+    Tick,
+    /// 失敗したロードをやり直す
+    Retry,
+    /// 終了
+    Quit,
+}
+
+/// メッセージに応じて状態を更新
+///
+/// # Arguments
+///
+/// * `model` - the loading screen state to mutate in place
+/// * `msg` - the message produced by a key press or tick
+pub fn update(model: &mut LoadingModel, msg: Msg) {
+    match msg {
+        Msg::Tick => {
+            model.frame = (model.frame + 1) % SPINNER_FRAMES.len();
+        }
+        Msg::Retry => {
+            let (progress, receiver) = run_loader(&model.loader);
+            model.progress = progress;
+            model.receiver = receiver;
+            model.state = LoadState::Loading;
+        }
+        Msg::Quit => {
+            model.should_quit = true;
+        }
+    }
+}
+
+/// 画面を描画
+///
+/// # Arguments
+///
+/// * `f` - the `ratatui` frame to draw into
+/// * `model` - the loading screen state to render
+pub fn view(f: &mut Frame, model: &LoadingModel) {
+    let theme = Theme::current();
+    let outer = outer_rect(f.area());
+    f.render_widget(Clear, f.area());
+
+    let [tabs_area, _, content_area, help_area] = framed_layout(outer);
+
+    let (title, message, color, help) = match &model.state {
+        LoadState::Loading => (
+            " Loading ",
+            format!(
+                "\n  {} Loading plugins…\n\n  {} plugins, {} marketplaces loaded",
+                SPINNER_FRAMES[model.frame],
+                model.progress.plugins(),
+                model.progress.marketplaces()
+            ),
+            theme.text,
+            " q: quit",
+        ),
+        LoadState::Failed(error) => {
+            // Errors タブと同じ見た目で失敗を示す
+            render_tab_bar(f, tabs_area, Tab::Errors, None);
+            (
+                " Errors ",
+                format!("\n  Failed to load plugins: {}", error),
+                theme.error,
+                " r: retry | q: quit",
+            )
+        }
+    };
+
+    let content = Paragraph::new(message)
+        .block(bordered_block(title))
+        .style(Style::default().fg(color));
+    f.render_widget(content, content_area);
+
+    let help = Paragraph::new(help).style(Style::default().fg(theme.muted));
+    f.render_widget(help, help_area);
+}
+
+#[cfg(test)]
+#[path = "loading_test.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+use crate::tui::manager::core::snapshot;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// テスト用のデータストアを返すロード処理
+fn ok_loader() -> Loader {
+    Arc::new(|_: &LoadProgress| {
+        let (_temp_dir, data) = DataStore::for_test(vec![], vec![], None);
+        Ok(data)
+    })
+}
+
+/// ロード結果が届くか失敗に遷移するまで待つ
+fn wait_settled(model: &mut LoadingModel) -> Option<DataStore> {
+    for _ in 0..500 {
+        if let Some(data) = model.poll() {
+            return Some(data);
+        }
+        if model.state != LoadState::Loading {
+            return None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!("loader did not finish");
+}
+
+fn rendered_text(model: &LoadingModel) -> String {
+    let buffer = snapshot::render(60, 12, |f| view(f, model));
+    snapshot::serialize(&buffer)
+}
+
+#[test]
+fn successful_load_returns_data_store() {
+    let mut model = LoadingModel::spawn(ok_loader());
+
+    assert!(wait_settled(&mut model).is_some());
+}
+
+#[test]
+fn failed_load_shows_error_and_retry_reruns_loader() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut model = LoadingModel::spawn(Arc::new(move |progress: &LoadProgress| {
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Err(io::Error::other("cache unreadable"));
+        }
+        ok_loader()(progress)
+    }));
+
+    assert!(wait_settled(&mut model).is_none());
+    assert_eq!(
+        model.state,
+        LoadState::Failed("cache unreadable".to_string())
+    );
+    let text = rendered_text(&model);
+    assert!(
+        text.contains("Failed to load plugins: cache unreadable"),
+        "{}",
+        text
+    );
+    assert!(text.contains("r: retry | q: quit"), "{}", text);
+
+    update(&mut model, Msg::Retry);
+    assert_eq!(model.state, LoadState::Loading);
+    assert!(wait_settled(&mut model).is_some());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn quit_is_available_while_loading() {
+    let mut model = LoadingModel::spawn(Arc::new(|_: &LoadProgress| {
+        thread::sleep(Duration::from_millis(200));
+        Err(io::Error::other("slow"))
+    }));
+
+    assert!(matches!(
+        model.key_to_msg(KeyCode::Char('q')),
+        Some(Msg::Quit)
+    ));
+    // 再試行はロード失敗後のみ
+    assert!(model.key_to_msg(KeyCode::Char('r')).is_none());
+    assert!(rendered_text(&model).contains("Loading plugins…"));
+
+    update(&mut model, Msg::Quit);
+    assert!(model.should_quit);
+}
@@ -0,0 +1,82 @@
+//! 確認ダイアログの影響プレビュー
+//!
+//! 破壊的アクション（Uninstall / 一括更新 / マーケットプレイス削除）の確認時に、
+//! 実行すると何が起きるか（[`ImpactPreview`]）を箇条書きで表示する。
+//! 計算は確認を開いた直後の描画を挟んで phase2 で行い、それまでは「calculating…」を出す。
+
+use super::layout::outer_rect;
+use super::spinner;
+use super::style::bordered_block;
+use super::theme::Theme;
+use crate::application::ImpactPreview;
+use ratatui::prelude::*;
+use ratatui::widgets::{Clear, Paragraph};
+
+/// 箇条書きの行頭
+pub const PREVIEW_BULLET: &str = "• ";
+
+/// 影響プレビューの計算状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewState {
+    /// phase2 で計算中
+    Calculating,
+    /// 計算済み
+    Ready(ImpactPreview),
+}
+
+/// プレビューを表示用の行にする（計算中はスピナー付きの 1 行）
+///
+/// # Arguments
+///
+/// * `preview` - Preview state of the confirmation.
+pub fn preview_lines(preview: &PreviewState) -> Vec<Line<'static>> {
+    let theme = Theme::current();
+    match preview {
+        PreviewState::Calculating => vec![Line::from(Span::styled(
+            format!("   {} calculating…", spinner::frame()),
+            Style::default().fg(theme.muted),
+        ))],
+        PreviewState::Ready(preview) => preview
+            .lines()
+            .into_iter()
+            .map(|line| Line::from(format!("   {}{}", PREVIEW_BULLET, line)))
+            .collect(),
+    }
+}
+
+/// 画面下部（ヘルプ行の上）に確認ダイアログを重ねて描画する
+///
+/// 高さはプレビューの行数に合わせて広げ、画面に収まらない分は切り詰める。
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to draw into (the screen behind is already drawn).
+/// * `title` - Dialog title.
+/// * `question` - Confirmation question including the key hints.
+/// * `preview` - Preview state of the confirmation.
+pub fn render_confirm_dialog(f: &mut Frame, title: &str, question: &str, preview: &PreviewState) {
+    let theme = Theme::current();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(" {}", question),
+            Style::default().fg(theme.warning),
+        )),
+        Line::from(" Impact preview:"),
+    ];
+    lines.extend(preview_lines(preview));
+
+    let outer = outer_rect(f.area());
+    // ヘルプ行（最下行）は隠さない
+    let available = outer.height.saturating_sub(1);
+    let height = (lines.len() as u16 + 2).min(available);
+    let area = Rect::new(outer.x, outer.y + available - height, outer.width, height);
+    f.render_widget(Clear, area);
+    let block = bordered_block(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+#[path = "preview_test.rs"]
+mod preview_test;
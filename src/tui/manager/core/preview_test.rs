@@ -0,0 +1,55 @@
+use super::*;
+use crate::application::{preview_update, VersionChange};
+use crate::tui::manager::core::snapshot;
+
+fn line_text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+fn ready() -> PreviewState {
+    PreviewState::Ready(preview_update(vec![VersionChange {
+        plugin: "alpha".to_string(),
+        current: "1.0.0".to_string(),
+        latest: Some("1.1.0".to_string()),
+    }]))
+}
+
+#[test]
+fn calculating_preview_is_a_single_line() {
+    let lines = preview_lines(&PreviewState::Calculating);
+
+    assert_eq!(lines.len(), 1);
+    assert!(line_text(&lines[0]).ends_with("calculating…"));
+}
+
+#[test]
+fn ready_preview_is_rendered_as_bullets() {
+    let lines: Vec<String> = preview_lines(&ready()).iter().map(line_text).collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            "   • alpha: 1.0.0 -> 1.1.0",
+            "   • Estimated time: about 3s"
+        ]
+    );
+}
+
+#[test]
+fn confirm_dialog_shows_question_and_preview_above_help_line() {
+    let buffer = snapshot::render(60, 16, |f| {
+        render_confirm_dialog(
+            f,
+            " Update ",
+            "Update 1 plugin? y: yes | n: cancel",
+            &ready(),
+        )
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(text.contains("Update 1 plugin? y: yes | n: cancel"));
+    assert!(text.contains("• alpha: 1.0.0 -> 1.1.0"));
+    // 枠の下端はヘルプ行（外周パディングの内側の最下行 = 14 行目）の直上
+    let bottom = text.lines().position(|l| l.contains('└')).unwrap();
+    assert_eq!(bottom, 13, "{}", text);
+}
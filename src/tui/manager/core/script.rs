@@ -0,0 +1,252 @@
+//! 操作スクリプト（キー入力列）の記録と再生
+//!
+//! `plm managed --record <FILE>` は実操作のキー入力を JSON に書き出し、
+//! `plm managed --replay <FILE>` はそれを [`EventSource`] としてイベントループへ注入する。
+//! 再生は実ターミナルを使わないため、CI の headless 環境でも回帰テストに使える。
+//!
+//! ```json
+//! [{"key": "Down"}, {"key": "Enter"}, {"wait_ms": 100}, {"key": "q"}]
+//! ```
+//!
+//! キーは 1 文字（`"q"` など）か [`KEY_NAMES`] の名前（スペースは `"Space"`）で書き、
+//! Ctrl 併用は `"Ctrl+u"` と書く。
+
+use super::event_source::EventSource;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+/// 名前で書くキー（スクリプト上の表記とキーコードの対応）
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Tab", KeyCode::Tab),
+    ("BackTab", KeyCode::BackTab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Space", KeyCode::Char(' ')),
+];
+
+/// Ctrl 併用キーの接頭辞
+const CTRL_PREFIX: &str = "Ctrl+";
+
+/// スクリプトの 1 ステップ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ScriptStep {
+    /// キー入力
+    Key { key: String },
+    /// 待機（dev モードのポーリングなど時間経過を待つ処理用）
+    Wait { wait_ms: u64 },
+}
+
+/// スクリプト表記のキーをキーイベントに変換する
+///
+/// # Arguments
+///
+/// * `notation` - Key as written in the script (e.g. `"Down"`, `"q"`, `"Ctrl+u"`).
+pub fn parse_key(notation: &str) -> Option<KeyEvent> {
+    let (modifiers, name) = match notation.strip_prefix(CTRL_PREFIX) {
+        Some(rest) => (KeyModifiers::CONTROL, rest),
+        None => (KeyModifiers::NONE, notation),
+    };
+    let code = match KEY_NAMES.iter().find(|(n, _)| *n == name) {
+        Some((_, code)) => *code,
+        None => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// キーイベントをスクリプト表記に変換する（表記できないキーは `None`）
+///
+/// # Arguments
+///
+/// * `key` - Key event read from the terminal.
+pub fn format_key(key: KeyEvent) -> Option<String> {
+    let name = match KEY_NAMES.iter().find(|(_, code)| *code == key.code) {
+        Some((name, _)) => (*name).to_string(),
+        None => match key.code {
+            KeyCode::Char(c) => c.to_string(),
+            _ => return None,
+        },
+    };
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        Some(format!("{}{}", CTRL_PREFIX, name))
+    } else {
+        Some(name)
+    }
+}
+
+/// スクリプトを読み込む
+///
+/// 表記できないキーを含む場合は何番目のステップかを示すエラーを返す。
+///
+/// # Arguments
+///
+/// * `path` - Script file (JSON array of steps).
+pub fn load(path: &Path) -> io::Result<Vec<ScriptStep>> {
+    let content = fs::read_to_string(path)?;
+    let steps: Vec<ScriptStep> = serde_json::from_str(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })?;
+    for (index, step) in steps.iter().enumerate() {
+        if let ScriptStep::Key { key } = step {
+            if parse_key(key).is_none() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: step {}: unknown key '{}'", path.display(), index, key),
+                ));
+            }
+        }
+    }
+    Ok(steps)
+}
+
+/// スクリプトを書き出す
+///
+/// # Arguments
+///
+/// * `path` - Destination file.
+/// * `steps` - Recorded steps.
+pub fn save(path: &Path, steps: &[ScriptStep]) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(steps).map_err(io::Error::other)?;
+    fs::write(path, json + "\n")
+}
+
+/// スクリプトを再生する入力元
+pub struct ScriptEvents {
+    steps: VecDeque<ScriptStep>,
+}
+
+impl ScriptEvents {
+    /// # Arguments
+    ///
+    /// * `steps` - Steps to replay in order.
+    pub fn new(steps: Vec<ScriptStep>) -> Self {
+        Self {
+            steps: steps.into(),
+        }
+    }
+}
+
+impl EventSource for ScriptEvents {
+    /// 次がキーなら `true`。待機ならその時間だけ待って消費し、タイムアウト扱い（`false`）にする
+    fn poll(&mut self, _timeout: Duration) -> io::Result<bool> {
+        match self.steps.front() {
+            Some(ScriptStep::Key { .. }) => Ok(true),
+            Some(ScriptStep::Wait { wait_ms }) => {
+                thread::sleep(Duration::from_millis(*wait_ms));
+                self.steps.pop_front();
+                Ok(false)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        while let Some(step) = self.steps.pop_front() {
+            match step {
+                ScriptStep::Wait { wait_ms } => thread::sleep(Duration::from_millis(wait_ms)),
+                ScriptStep::Key { key } => {
+                    return parse_key(&key).map(Event::Key).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, format!("unknown key '{}'", key))
+                    });
+                }
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "script has no more keys",
+        ))
+    }
+
+    /// スクリプトの入力は意図した操作なので破棄しない
+    fn discard_pending(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// 残りが待機だけなら終端とみなす
+    fn is_finished(&self) -> bool {
+        !self
+            .steps
+            .iter()
+            .any(|step| matches!(step, ScriptStep::Key { .. }))
+    }
+}
+
+/// 読み取ったキー入力を記録する入力元
+///
+/// 破棄された入力（一括処理中に溜まったキー）は操作に反映されないため記録しない。
+pub struct RecordingEvents<S> {
+    inner: S,
+    steps: Vec<ScriptStep>,
+}
+
+impl<S: EventSource> RecordingEvents<S> {
+    /// # Arguments
+    ///
+    /// * `inner` - Source whose key presses are recorded.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            steps: Vec::new(),
+        }
+    }
+
+    /// 記録したステップを取り出す
+    pub fn into_steps(self) -> Vec<ScriptStep> {
+        self.steps
+    }
+}
+
+impl<S: EventSource> EventSource for RecordingEvents<S> {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        self.inner.poll(timeout)
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        let event = self.inner.read()?;
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press {
+                if let Some(key) = format_key(key) {
+                    self.steps.push(ScriptStep::Key { key });
+                }
+            }
+        }
+        Ok(event)
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.inner.discard_pending()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.inner.is_finished()
+    }
+}
+
+#[cfg(test)]
+#[path = "script_test.rs"]
+mod tests;
@@ -0,0 +1,145 @@
+use super::*;
+use tempfile::TempDir;
+
+/// 決まったイベント列を返すテスト用の入力元
+struct FixedKeys(VecDeque<Event>);
+
+impl EventSource for FixedKeys {
+    fn poll(&mut self, _timeout: Duration) -> io::Result<bool> {
+        Ok(!self.0.is_empty())
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        self.0
+            .pop_front()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
+
+    fn discard_pending(&mut self) -> io::Result<()> {
+        self.0.clear();
+        Ok(())
+    }
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn parse_key_accepts_names_chars_and_ctrl() {
+    assert_eq!(parse_key("Down").map(|k| k.code), Some(KeyCode::Down));
+    assert_eq!(parse_key("q").map(|k| k.code), Some(KeyCode::Char('q')));
+    assert_eq!(parse_key("Space").map(|k| k.code), Some(KeyCode::Char(' ')));
+    assert_eq!(
+        parse_key("Ctrl+u"),
+        Some(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+    );
+    assert_eq!(parse_key("Dwon"), None);
+    assert_eq!(parse_key(""), None);
+}
+
+#[test]
+fn format_key_round_trips_through_parse_key() {
+    for notation in ["Enter", "BackTab", "Space", "x", "Ctrl+u"] {
+        let event = parse_key(notation).unwrap();
+        assert_eq!(format_key(event).as_deref(), Some(notation));
+    }
+    assert_eq!(
+        format_key(KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE)),
+        None
+    );
+}
+
+#[test]
+fn load_reads_keys_and_waits() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("script.json");
+    fs::write(
+        &path,
+        r#"[{"key": "Down"}, {"key": "Enter"}, {"wait_ms": 100}, {"key": "q"}]"#,
+    )
+    .unwrap();
+
+    let steps = load(&path).unwrap();
+
+    assert_eq!(
+        steps,
+        vec![
+            ScriptStep::Key {
+                key: "Down".to_string()
+            },
+            ScriptStep::Key {
+                key: "Enter".to_string()
+            },
+            ScriptStep::Wait { wait_ms: 100 },
+            ScriptStep::Key {
+                key: "q".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn load_rejects_unknown_key_with_step_index() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("script.json");
+    fs::write(&path, r#"[{"key": "Down"}, {"key": "Dwon"}]"#).unwrap();
+
+    let err = load(&path).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(
+        err.to_string().contains("step 1: unknown key 'Dwon'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn script_events_skip_waits_and_finish_after_last_key() {
+    let mut events = ScriptEvents::new(vec![
+        ScriptStep::Wait { wait_ms: 0 },
+        ScriptStep::Key {
+            key: "Down".to_string(),
+        },
+        ScriptStep::Wait { wait_ms: 0 },
+    ]);
+
+    // 待機はタイムアウトとして消費される
+    assert!(!events.poll(Duration::ZERO).unwrap());
+    assert!(events.poll(Duration::ZERO).unwrap());
+    assert!(!events.is_finished());
+    assert_eq!(events.read().unwrap(), key(KeyCode::Down));
+    // 残りが待機だけなら終端
+    assert!(events.is_finished());
+}
+
+#[test]
+fn recording_events_record_read_keys_and_save_as_script() {
+    let mut recorder = RecordingEvents::new(FixedKeys(VecDeque::from([
+        key(KeyCode::Down),
+        key(KeyCode::Char('q')),
+        key(KeyCode::Enter),
+    ])));
+
+    recorder.read().unwrap();
+    recorder.read().unwrap();
+    // 破棄された入力は記録しない
+    recorder.discard_pending().unwrap();
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("recorded.json");
+    save(&path, &recorder.into_steps()).unwrap();
+
+    assert_eq!(
+        load(&path).unwrap(),
+        vec![
+            ScriptStep::Key {
+                key: "Down".to_string()
+            },
+            ScriptStep::Key {
+                key: "q".to_string()
+            },
+        ]
+    );
+}
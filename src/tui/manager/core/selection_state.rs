@@ -38,3 +38,37 @@ impl<K> SelectionState<K> {
         self.state.select(selected_index);
     }
 }
+
+impl<K: PartialEq + Clone> SelectionState<K> {
+    /// 表示中の一覧（フィルタ適用後）に合わせて選択を整合させる。
+    ///
+    /// 選択中の ID が一覧に残っていればその位置へ移し、残っていなければ先頭を選ぶ。
+    /// 一覧が空なら選択を外す。
+    ///
+    /// # Arguments
+    ///
+    /// * `visible` - IDs of the items currently shown, in display order.
+    pub fn sync_to(&mut self, visible: &[K]) {
+        let idx = self
+            .selected_id
+            .as_ref()
+            .and_then(|id| visible.iter().position(|v| v == id))
+            .or(if visible.is_empty() { None } else { Some(0) });
+        self.set(idx.map(|i| visible[i].clone()), idx);
+    }
+}
+
+/// ID を持たない一覧のカーソルを長さ `len` に収める。
+///
+/// 一覧が空なら選択を外し、そうでなければ末尾を超えないようにする（未選択なら先頭）。
+///
+/// # Arguments
+///
+/// * `state` - List cursor to clamp.
+/// * `len` - Number of items currently shown.
+pub fn clamp_index(state: &mut ListState, len: usize) {
+    let selected = len
+        .checked_sub(1)
+        .map(|last| state.selected().unwrap_or(0).min(last));
+    state.select(selected);
+}
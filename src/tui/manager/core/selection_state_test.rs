@@ -0,0 +1,44 @@
+use super::selection_state::{clamp_index, SelectionState};
+use ratatui::widgets::ListState;
+
+fn ids(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn sync_to_follows_selected_id_to_new_position() {
+    let mut selection = SelectionState::new(Some("b".to_string()), Some(1));
+    selection.sync_to(&ids(&["x", "y", "b"]));
+    assert_eq!(selection.selected_id().map(String::as_str), Some("b"));
+    assert_eq!(selection.selected_index(), Some(2));
+}
+
+#[test]
+fn sync_to_selects_first_when_selected_is_hidden() {
+    let mut selection = SelectionState::new(Some("b".to_string()), Some(1));
+    selection.sync_to(&ids(&["a", "c"]));
+    assert_eq!(selection.selected_id().map(String::as_str), Some("a"));
+    assert_eq!(selection.selected_index(), Some(0));
+}
+
+#[test]
+fn sync_to_clears_selection_on_empty_list() {
+    let mut selection = SelectionState::new(Some("b".to_string()), Some(1));
+    selection.sync_to(&[]);
+    assert_eq!(selection.selected_id(), None);
+    assert_eq!(selection.selected_index(), None);
+}
+
+#[test]
+fn clamp_index_keeps_cursor_within_len() {
+    let mut state = ListState::default();
+    state.select(Some(5));
+    clamp_index(&mut state, 3);
+    assert_eq!(state.selected(), Some(2));
+
+    clamp_index(&mut state, 0);
+    assert_eq!(state.selected(), None);
+
+    clamp_index(&mut state, 2);
+    assert_eq!(state.selected(), Some(0));
+}
@@ -0,0 +1,121 @@
+//! 描画スナップショット
+//!
+//! `TestBackend` のバッファを「文字列 + スタイル区間」のテキストに直列化し、
+//! `src/tui/manager/snapshots/<name>.snap` と比較する。
+//! `PLM_UPDATE_SNAPSHOTS=1` を指定して実行するとスナップショットを書き換える。
+//! 直列化は `plm managed --replay` の結果ダンプでも使う。
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Style};
+use ratatui::{Frame, Terminal};
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// スナップショット更新を指示する環境変数
+const UPDATE_ENV: &str = "PLM_UPDATE_SNAPSHOTS";
+
+/// 指定サイズで描画してバッファを返す
+///
+/// # Arguments
+///
+/// * `width` - Terminal width.
+/// * `height` - Terminal height.
+/// * `draw` - Render callback.
+pub(crate) fn render(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("terminal");
+    terminal.draw(draw).expect("draw");
+    terminal.backend().buffer().clone()
+}
+
+/// バッファを比較用テキストに直列化する
+///
+/// 前半は各行の文字列（行末空白は除去）、後半は既定以外のスタイルを持つ
+/// 同一スタイルの連続区間（`y x0..x1 fg bg modifier`）。
+///
+/// # Arguments
+///
+/// * `buffer` - Rendered buffer.
+pub(crate) fn serialize(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in 0..area.height {
+        let line: String = (0..area.width).map(|x| buffer[(x, y)].symbol()).collect();
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    out.push_str("--- styles ---\n");
+    for y in 0..area.height {
+        let mut x = 0;
+        while x < area.width {
+            let style = buffer[(x, y)].style();
+            let start = x;
+            while x < area.width && buffer[(x, y)].style() == style {
+                x += 1;
+            }
+            if is_styled(style) {
+                let _ = writeln!(
+                    out,
+                    "{:>2} {:>2}..{:<2} fg={:?} bg={:?} mod={:?}",
+                    y,
+                    start,
+                    x,
+                    style.fg.unwrap_or_default(),
+                    style.bg.unwrap_or_default(),
+                    style.add_modifier
+                );
+            }
+        }
+    }
+    out
+}
+
+/// 既定（Reset 色・修飾なし）以外のスタイルか
+///
+/// # Arguments
+///
+/// * `style` - Cell style.
+fn is_styled(style: Style) -> bool {
+    style.fg.is_some_and(|c| c != Color::Reset)
+        || style.bg.is_some_and(|c| c != Color::Reset)
+        || !style.add_modifier.is_empty()
+}
+
+/// 直列化結果をスナップショットファイルと比較する
+///
+/// # Arguments
+///
+/// * `name` - Snapshot file stem.
+/// * `buffer` - Rendered buffer.
+pub(crate) fn assert_snapshot(name: &str, buffer: &Buffer) {
+    let actual = serialize(buffer);
+    let path = snapshot_path(name);
+    if std::env::var(UPDATE_ENV).is_ok_and(|v| v == "1") {
+        std::fs::create_dir_all(path.parent().expect("snapshot dir")).expect("create dir");
+        std::fs::write(&path, &actual).expect("write snapshot");
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "snapshot '{}' not found; run with {}=1 to create it\n{}",
+            path.display(),
+            UPDATE_ENV,
+            actual
+        )
+    });
+    assert_eq!(
+        expected,
+        actual,
+        "snapshot '{}' differs; run with {}=1 to update",
+        path.display(),
+        UPDATE_ENV
+    );
+}
+
+/// # Arguments
+///
+/// * `name` - Snapshot file stem.
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tui/manager/snapshots")
+        .join(format!("{}.snap", name))
+}
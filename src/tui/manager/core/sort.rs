@@ -0,0 +1,98 @@
+//! ソートロジック
+//!
+//! Installed タブのプラグイン一覧の並び順。フィルタ（[`super::filter`]）で絞り込んだ
+//! 結果に適用する。
+
+use crate::application::InstalledPlugin;
+use crate::marketplace::MarketplaceRef;
+use crate::plugin::meta::compare_versions;
+use chrono::{DateTime, FixedOffset};
+use std::cmp::Ordering;
+
+/// プラグイン一覧のソートキー
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortKey {
+    /// 名前順（既定）
+    #[default]
+    Name,
+    /// マーケットプレイス順（同じマーケットプレイス内は名前順）
+    Marketplace,
+    /// バージョンの新しい順
+    Version,
+    /// 更新日時の新しい順（日時の記録が無いものは末尾）
+    LastUpdated,
+}
+
+impl SortKey {
+    /// 切替順で次のソートキー（`s` キーで循環する）
+    pub fn next(self) -> Self {
+        match self {
+            SortKey::Name => SortKey::Marketplace,
+            SortKey::Marketplace => SortKey::Version,
+            SortKey::Version => SortKey::LastUpdated,
+            SortKey::LastUpdated => SortKey::Name,
+        }
+    }
+
+    /// ヘルプ行に表示するラベル
+    pub fn label(self) -> &'static str {
+        match self {
+            SortKey::Name => "name",
+            SortKey::Marketplace => "marketplace",
+            SortKey::Version => "version",
+            SortKey::LastUpdated => "updated",
+        }
+    }
+}
+
+/// プラグインをソートキーの順に並べ替える
+///
+/// 同順位は名前 → マーケットプレイスの順で並べ、表示順を決定的にする。
+///
+/// # Arguments
+///
+/// * `plugins` - Plugins to sort (typically the filtered list).
+/// * `key` - Sort key to apply.
+pub fn sort_plugins(plugins: &mut [&InstalledPlugin], key: SortKey) {
+    plugins.sort_by(|a, b| compare(a, b, key).then_with(|| compare(a, b, SortKey::Name)));
+}
+
+/// # Arguments
+///
+/// * `a` - Plugin to compare.
+/// * `b` - Plugin to compare with.
+/// * `key` - Sort key to compare by.
+fn compare(a: &InstalledPlugin, b: &InstalledPlugin, key: SortKey) -> Ordering {
+    match key {
+        SortKey::Name => a
+            .name()
+            .to_lowercase()
+            .cmp(&b.name().to_lowercase())
+            .then_with(|| marketplace_name(a).cmp(&marketplace_name(b))),
+        SortKey::Marketplace => marketplace_name(a).cmp(&marketplace_name(b)),
+        SortKey::Version => compare_versions(b.version(), a.version()),
+        // 新しい順。日時の無いもの（None）は末尾
+        SortKey::LastUpdated => match (last_updated(a), last_updated(b)) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+    }
+}
+
+/// # Arguments
+///
+/// * `plugin` - Plugin whose marketplace directory name is returned.
+fn marketplace_name(plugin: &InstalledPlugin) -> String {
+    MarketplaceRef::from_option(plugin.marketplace())
+        .dir_name()
+        .to_string()
+}
+
+/// # Arguments
+///
+/// * `plugin` - Plugin whose last update time is parsed.
+fn last_updated(plugin: &InstalledPlugin) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(plugin.last_updated()?).ok()
+}
@@ -0,0 +1,90 @@
+use crate::application::InstalledPlugin;
+use crate::tui::manager::core::sort::{sort_plugins, SortKey};
+
+fn make_plugin(
+    name: &str,
+    version: &str,
+    marketplace: Option<&str>,
+    updated_at: Option<&str>,
+) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(
+        name,
+        version,
+        Vec::new(),
+        None,
+        marketplace.map(|m| m.to_string()),
+        true,
+    )
+    .with_timestamps(None, updated_at.map(|t| t.to_string()))
+}
+
+fn sorted_names(plugins: &[InstalledPlugin], key: SortKey) -> Vec<&str> {
+    let mut refs: Vec<&InstalledPlugin> = plugins.iter().collect();
+    sort_plugins(&mut refs, key);
+    refs.iter().map(|p| p.name()).collect()
+}
+
+fn fixtures() -> Vec<InstalledPlugin> {
+    vec![
+        make_plugin("linter", "1.10.0", Some("tools"), None),
+        make_plugin("Formatter", "2.0.0", None, Some("2024-03-01T00:00:00Z")),
+        make_plugin(
+            "reviewer",
+            "1.9.0",
+            Some("company"),
+            Some("2024-05-01T00:00:00Z"),
+        ),
+    ]
+}
+
+#[test]
+fn sort_key_cycles_through_all_keys() {
+    let mut key = SortKey::default();
+    let mut seen = vec![key];
+    for _ in 0..3 {
+        key = key.next();
+        seen.push(key);
+    }
+    assert_eq!(
+        seen,
+        vec![
+            SortKey::Name,
+            SortKey::Marketplace,
+            SortKey::Version,
+            SortKey::LastUpdated
+        ]
+    );
+    assert_eq!(key.next(), SortKey::Name);
+}
+
+#[test]
+fn sort_by_name_ignores_case() {
+    assert_eq!(
+        sorted_names(&fixtures(), SortKey::Name),
+        vec!["Formatter", "linter", "reviewer"]
+    );
+}
+
+#[test]
+fn sort_by_marketplace_groups_then_names() {
+    assert_eq!(
+        sorted_names(&fixtures(), SortKey::Marketplace),
+        vec!["reviewer", "Formatter", "linter"]
+    );
+}
+
+#[test]
+fn sort_by_version_puts_newest_first() {
+    assert_eq!(
+        sorted_names(&fixtures(), SortKey::Version),
+        vec!["Formatter", "linter", "reviewer"]
+    );
+}
+
+#[test]
+fn sort_by_last_updated_puts_missing_dates_last() {
+    assert_eq!(
+        sorted_names(&fixtures(), SortKey::LastUpdated),
+        vec!["reviewer", "Formatter", "linter"]
+    );
+}
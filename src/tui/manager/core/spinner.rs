@@ -0,0 +1,24 @@
+//! 処理中表示のスピナー
+//!
+//! バックグラウンド操作の実行中、イベントループは一定間隔で [`advance`] を呼んで
+//! フレームを進める。描画側は [`frame`] で現在のフレームを取得する（テーマと同じく
+//! スレッドローカルに保持し、各画面の view へ引き回さない）。
+
+use std::cell::Cell;
+
+/// スピナーのフレーム
+pub const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+thread_local! {
+    static CURRENT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 現在のスレッドで表示するフレーム
+pub fn frame() -> &'static str {
+    SPINNER_FRAMES[CURRENT.with(Cell::get) % SPINNER_FRAMES.len()]
+}
+
+/// 現在のスレッドのフレームを 1 つ進める
+pub fn advance() {
+    CURRENT.with(|c| c.set((c.get() + 1) % SPINNER_FRAMES.len()));
+}
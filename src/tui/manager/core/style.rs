@@ -2,7 +2,8 @@
 //!
 //! TUI 各画面で共有するスタイル（タイトル装飾、選択行強調、リストアイコン）。
 
-use ratatui::style::{Color, Modifier, Style};
+use super::theme::Theme;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem};
 
@@ -44,16 +45,14 @@ pub fn bordered_block<'a>(title: &'a str) -> Block<'a> {
         .borders(Borders::ALL)
 }
 
-/// 選択行の **内容セル** にだけ適用する Style（fg=Black + bg=Green + BOLD）。
+/// 選択行の **内容セル** にだけ適用する Style（現在のテーマの highlight 色 + BOLD、
+/// dark テーマでは fg=Black + bg=Green）。
 ///
 /// 直接 `List::highlight_style` には設定しない。`List::highlight_style` は ListItem
 /// 全体に塗布されるため、空行も緑背景になってしまう。代わりに各画面の builder が
 /// 内容行の Span にだけ `highlight_line` 経由でこのスタイルを適用する。
 pub fn highlight_style() -> Style {
-    Style::default()
-        .add_modifier(Modifier::BOLD)
-        .fg(Color::Black)
-        .bg(Color::Green)
+    Theme::current().highlight()
 }
 
 /// 選択行用の `Line` を組み立てる。
@@ -0,0 +1,111 @@
+//! 時間のかかる操作のバックグラウンド実行
+//!
+//! マーケットプレイスの更新・削除のように GitHub への fetch を伴う操作は、イベントループを
+//! 止めないよう別スレッド（Tokio のブロッキングプール）で実行する。操作は完了時に
+//! メッセージを返すクロージャ（[`Task`]）として渡し、結果はチャネル経由でイベントループに届く。
+//! イベントループは [`TaskRunner::try_recv`] で完了メッセージを受け取り、通常のキー入力と
+//! 同じように `update` に流す。
+//!
+//! 実行方法（[`Spawner`]）は差し替えられるため、テストでは同期実行や実行の保留を
+//! 注入してメッセージの配送と状態遷移を検証できる。
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+/// バックグラウンドで実行する操作（完了時のメッセージを返す）
+pub type Task<M> = Box<dyn FnOnce() -> M + Send>;
+
+/// 操作を実行するスレッドへ渡すジョブ（完了メッセージの送信まで含む）
+pub type Job = Box<dyn FnOnce() + Send>;
+
+/// ジョブの実行方法
+pub type Spawner = Box<dyn Fn(Job)>;
+
+/// バックグラウンド操作の実行と完了メッセージの受け取り
+pub struct TaskRunner<M> {
+    /// 完了メッセージの送信側（ジョブごとに複製する）
+    sender: Sender<M>,
+    /// 完了メッセージの受信側
+    receiver: Receiver<M>,
+    /// ジョブの実行方法
+    spawner: Spawner,
+    /// 完了メッセージをまだ受け取っていない操作の数
+    pending: usize,
+}
+
+impl<M: Send + 'static> TaskRunner<M> {
+    /// Tokio ランタイム上ではブロッキングプール、ランタイムが無ければ新しいスレッドで実行する
+    pub fn new() -> Self {
+        Self::with_spawner(Box::new(|job: Job| {
+            match tokio::runtime::Handle::try_current() {
+                // ブロッキングプールのスレッドでも Handle::try_current() が使えるため、
+                // 操作側の `block_in_place` + `block_on` はそのまま動く
+                Ok(handle) => drop(handle.spawn_blocking(job)),
+                Err(_) => drop(thread::spawn(job)),
+            }
+        }))
+    }
+
+    /// ジョブの実行方法を指定して構築する（テスト用）
+    ///
+    /// # Arguments
+    ///
+    /// * `spawner` - Runs a job, e.g. inline or by storing it for later.
+    pub fn with_spawner(spawner: Spawner) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver,
+            spawner,
+            pending: 0,
+        }
+    }
+
+    /// 操作をバックグラウンドで開始する
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - Operation to run; its return value is delivered as the completion message.
+    pub fn spawn(&mut self, task: Task<M>) {
+        let sender = self.sender.clone();
+        self.pending += 1;
+        (self.spawner)(Box::new(move || {
+            // 受信側が先に終了（TUI の終了）していれば結果は捨てる
+            let _ = sender.send(task());
+        }));
+    }
+
+    /// 完了した操作のメッセージを 1 件取り出す（無ければ待たずに `None`）
+    pub fn try_recv(&mut self) -> Option<M> {
+        let msg = self.receiver.try_recv().ok()?;
+        self.pending = self.pending.saturating_sub(1);
+        Some(msg)
+    }
+
+    /// 実行中の操作があれば、その完了を待ってメッセージを取り出す
+    ///
+    /// 実行中の操作が無ければ `None`（スクリプト再生の終了時に残りの結果を反映する用途）。
+    pub fn recv_pending(&mut self) -> Option<M> {
+        if self.pending == 0 {
+            return None;
+        }
+        let msg = self.receiver.recv().ok()?;
+        self.pending -= 1;
+        Some(msg)
+    }
+
+    /// 完了メッセージをまだ受け取っていない操作があるか
+    pub fn is_busy(&self) -> bool {
+        self.pending > 0
+    }
+}
+
+impl<M: Send + 'static> Default for TaskRunner<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "task_test.rs"]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// 渡されたジョブを実行せずに溜める実行方法と、その保管先を返す
+fn deferred() -> (Spawner, Rc<RefCell<Vec<Job>>>) {
+    let jobs: Rc<RefCell<Vec<Job>>> = Rc::default();
+    let store = Rc::clone(&jobs);
+    (Box::new(move |job| store.borrow_mut().push(job)), jobs)
+}
+
+#[test]
+fn completion_message_is_delivered_after_the_job_runs() {
+    let (spawner, jobs) = deferred();
+    let mut runner: TaskRunner<String> = TaskRunner::with_spawner(spawner);
+
+    runner.spawn(Box::new(|| "done".to_string()));
+
+    assert!(runner.is_busy());
+    assert_eq!(runner.try_recv(), None, "job has not run yet");
+
+    let job = jobs.borrow_mut().pop().unwrap();
+    job();
+
+    assert_eq!(runner.try_recv().as_deref(), Some("done"));
+    assert!(!runner.is_busy());
+}
+
+#[test]
+fn inline_spawner_delivers_messages_in_order() {
+    let mut runner: TaskRunner<u32> = TaskRunner::with_spawner(Box::new(|job| job()));
+
+    runner.spawn(Box::new(|| 1));
+    runner.spawn(Box::new(|| 2));
+
+    assert_eq!(runner.try_recv(), Some(1));
+    assert_eq!(runner.try_recv(), Some(2));
+    assert_eq!(runner.try_recv(), None);
+}
+
+#[test]
+fn recv_pending_waits_for_background_thread() {
+    let mut runner: TaskRunner<&'static str> = TaskRunner::new();
+
+    runner.spawn(Box::new(|| {
+        thread::sleep(std::time::Duration::from_millis(20));
+        "finished"
+    }));
+
+    assert_eq!(runner.recv_pending(), Some("finished"));
+    assert_eq!(runner.recv_pending(), None, "nothing left to wait for");
+}
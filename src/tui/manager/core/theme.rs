@@ -0,0 +1,213 @@
+//! カラーテーマ
+//!
+//! TUI の配色を一元管理する。各 view は `Color::*` を直書きせず、
+//! `Theme::current()` の役割別の色（accent / muted / error / success / highlight など）を使う。
+//!
+//! テーマは TUI 起動時に `Theme::install` で描画スレッドに設定する。
+//! 未設定の場合は `dark`（従来の配色）になる。
+
+use clap::ValueEnum;
+use ratatui::style::{Color, Modifier, Style};
+use std::cell::Cell;
+
+/// `COLORFGBG` 環境変数（`fg;bg` 形式、端末が設定する）
+pub const COLORFGBG_ENV: &str = "COLORFGBG";
+
+/// テーマ名（`--theme` / config の `tui.theme`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ThemeName {
+    /// 暗い背景向け（従来の配色）
+    #[default]
+    Dark,
+    /// 明るい背景向け
+    Light,
+    /// `COLORFGBG` から背景色を推定して dark / light を選ぶ（判定不能なら dark）
+    Auto,
+    /// 高コントラスト（暗い背景向け）
+    HighContrast,
+}
+
+impl ThemeName {
+    /// 設定ファイルの値をパースする
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Theme name such as `light` or `high-contrast`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        Self::from_str(value.trim(), true).map_err(|_| {
+            let expected: Vec<String> = Self::value_variants()
+                .iter()
+                .filter_map(|v| v.to_possible_value())
+                .map(|v| v.get_name().to_string())
+                .collect();
+            format!(
+                "invalid theme '{}': expected one of {}",
+                value,
+                expected.join(", ")
+            )
+        })
+    }
+}
+
+/// 役割別の配色定義
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// 通常のテキスト（値・名前など）
+    pub text: Color,
+    /// 補助テキスト（ヘルプ・枠外の説明・無効状態）
+    pub muted: Color,
+    /// 操作を促す項目（「+ Add new」など）
+    pub accent: Color,
+    /// 処理中・選択中のチェック
+    pub warning: Color,
+    /// 成功・有効
+    pub success: Color,
+    /// エラー・破壊的操作
+    pub error: Color,
+    /// dev モードなど特殊な項目
+    pub special: Color,
+    /// 選択行の文字色
+    pub highlight_fg: Color,
+    /// 選択行の背景色
+    pub highlight_bg: Color,
+}
+
+thread_local! {
+    static CURRENT: Cell<Theme> = const { Cell::new(Theme::dark()) };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// 暗い背景向け（従来の配色）
+    pub const fn dark() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::DarkGray,
+            accent: Color::Cyan,
+            warning: Color::Yellow,
+            success: Color::Green,
+            error: Color::Red,
+            special: Color::Magenta,
+            highlight_fg: Color::Black,
+            highlight_bg: Color::Green,
+        }
+    }
+
+    /// 明るい背景向け
+    ///
+    /// 白背景で沈みやすい Yellow / Cyan / DarkGray を、256 色パレットの濃い色に置き換える。
+    pub const fn light() -> Self {
+        Self {
+            text: Color::Black,
+            muted: Color::Indexed(240),
+            accent: Color::Blue,
+            warning: Color::Indexed(130),
+            success: Color::Indexed(28),
+            error: Color::Indexed(160),
+            special: Color::Indexed(90),
+            highlight_fg: Color::White,
+            highlight_bg: Color::Indexed(28),
+        }
+    }
+
+    /// 高コントラスト（暗い背景向け、補助テキストも減光しない）
+    pub const fn high_contrast() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::Gray,
+            accent: Color::LightCyan,
+            warning: Color::LightYellow,
+            success: Color::LightGreen,
+            error: Color::LightRed,
+            special: Color::LightMagenta,
+            highlight_fg: Color::Black,
+            highlight_bg: Color::LightYellow,
+        }
+    }
+
+    /// テーマ名から配色を決める
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Selected theme name.
+    /// * `colorfgbg` - Value of `COLORFGBG` (used only for `auto`).
+    pub fn from_name(name: ThemeName, colorfgbg: Option<&str>) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
+            ThemeName::Auto => match colorfgbg.and_then(is_light_background) {
+                Some(true) => Self::light(),
+                _ => Self::dark(),
+            },
+        }
+    }
+
+    /// 現在のスレッドで有効なテーマ
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// 現在のスレッドのテーマとして設定する
+    pub fn install(self) {
+        CURRENT.with(|c| c.set(self));
+    }
+
+    /// 色を前景色にした Style
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Foreground color.
+    pub fn fg(color: Color) -> Style {
+        Style::default().fg(color)
+    }
+
+    /// 選択行の内容セルに適用する Style（BOLD + highlight 色）
+    pub fn highlight(&self) -> Style {
+        Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(self.highlight_fg)
+            .bg(self.highlight_bg)
+    }
+}
+
+/// `COLORFGBG` の値から背景が明るいか判定する
+///
+/// 最後のフィールドを背景色の ANSI 番号として扱う（`15;0` / `0;default;15` 形式）。
+/// 0-6 と 8 は暗い色、7 と 9-15 は明るい色。数値でなければ判定不能（`None`）。
+///
+/// # Arguments
+///
+/// * `value` - Value of `COLORFGBG`.
+pub fn is_light_background(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    match bg {
+        0..=6 | 8 => Some(false),
+        7 | 9..=15 => Some(true),
+        _ => None,
+    }
+}
+
+/// テストで一時的にテーマを切り替える（終了時に元へ戻す）
+///
+/// # Arguments
+///
+/// * `theme` - Theme active while `f` runs.
+/// * `f` - Closure to run.
+#[cfg(test)]
+pub(crate) fn with_theme<R>(theme: Theme, f: impl FnOnce() -> R) -> R {
+    let previous = Theme::current();
+    theme.install();
+    let result = f();
+    previous.install();
+    result
+}
+
+#[cfg(test)]
+#[path = "theme_test.rs"]
+mod tests;
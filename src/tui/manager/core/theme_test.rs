@@ -0,0 +1,107 @@
+use super::*;
+
+#[test]
+fn default_theme_is_dark() {
+    assert_eq!(Theme::default(), Theme::dark());
+    assert_eq!(ThemeName::default(), ThemeName::Dark);
+}
+
+#[test]
+fn dark_theme_keeps_legacy_colors() {
+    let theme = Theme::dark();
+    assert_eq!(theme.muted, Color::DarkGray);
+    assert_eq!(theme.accent, Color::Cyan);
+    assert_eq!(theme.highlight_fg, Color::Black);
+    assert_eq!(theme.highlight_bg, Color::Green);
+}
+
+#[test]
+fn light_theme_avoids_low_contrast_colors() {
+    let theme = Theme::light();
+    for color in [theme.text, theme.muted, theme.accent, theme.warning] {
+        assert!(!matches!(
+            color,
+            Color::White | Color::DarkGray | Color::Cyan | Color::Yellow
+        ));
+    }
+}
+
+#[test]
+fn parse_accepts_kebab_case_names() {
+    assert_eq!(ThemeName::parse("dark"), Ok(ThemeName::Dark));
+    assert_eq!(ThemeName::parse("Light"), Ok(ThemeName::Light));
+    assert_eq!(ThemeName::parse(" auto "), Ok(ThemeName::Auto));
+    assert_eq!(
+        ThemeName::parse("high-contrast"),
+        Ok(ThemeName::HighContrast)
+    );
+}
+
+#[test]
+fn parse_lists_expected_values_on_error() {
+    let err = ThemeName::parse("neon").unwrap_err();
+    assert_eq!(
+        err,
+        "invalid theme 'neon': expected one of dark, light, auto, high-contrast"
+    );
+}
+
+#[test]
+fn is_light_background_reads_last_field() {
+    assert_eq!(is_light_background("15;0"), Some(false));
+    assert_eq!(is_light_background("0;15"), Some(true));
+    assert_eq!(is_light_background("0;default;7"), Some(true));
+    assert_eq!(is_light_background("15;8"), Some(false));
+}
+
+#[test]
+fn is_light_background_returns_none_when_unknown() {
+    assert_eq!(is_light_background(""), None);
+    assert_eq!(is_light_background("15;default"), None);
+    assert_eq!(is_light_background("0;200"), None);
+}
+
+#[test]
+fn auto_selects_light_for_light_background() {
+    assert_eq!(
+        Theme::from_name(ThemeName::Auto, Some("0;15")),
+        Theme::light()
+    );
+}
+
+#[test]
+fn auto_falls_back_to_dark() {
+    assert_eq!(Theme::from_name(ThemeName::Auto, None), Theme::dark());
+    assert_eq!(
+        Theme::from_name(ThemeName::Auto, Some("garbage")),
+        Theme::dark()
+    );
+}
+
+#[test]
+fn from_name_maps_explicit_themes() {
+    assert_eq!(
+        Theme::from_name(ThemeName::Dark, Some("0;15")),
+        Theme::dark()
+    );
+    assert_eq!(Theme::from_name(ThemeName::Light, None), Theme::light());
+    assert_eq!(
+        Theme::from_name(ThemeName::HighContrast, None),
+        Theme::high_contrast()
+    );
+}
+
+#[test]
+fn with_theme_restores_previous_theme() {
+    let inside = with_theme(Theme::light(), Theme::current);
+    assert_eq!(inside, Theme::light());
+    assert_eq!(Theme::current(), Theme::dark());
+}
+
+#[test]
+fn highlight_uses_theme_colors() {
+    let style = Theme::high_contrast().highlight();
+    assert_eq!(style.fg, Some(Color::Black));
+    assert_eq!(style.bg, Some(Color::LightYellow));
+    assert!(style.add_modifier.contains(Modifier::BOLD));
+}
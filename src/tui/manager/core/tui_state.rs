@@ -0,0 +1,82 @@
+//! TUI の表示設定の永続化（`~/.plm/tui_state.json`）
+//!
+//! 次回起動時にも引き継ぎたい表示上の選択（Installed タブの一覧表示モードなど）を保存する。
+//! 読み書きは best-effort で、ファイルが無い・壊れている場合は既定値で起動する。
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Installed タブのプラグイン一覧の表示モード
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListMode {
+    /// 1 プラグイン 1 行（既定）
+    #[default]
+    Compact,
+    /// 2 行目に説明（description）を表示する
+    Detailed,
+}
+
+impl ListMode {
+    /// 切替先の表示モード（`v` キーで交互に切り替える）
+    pub fn next(self) -> Self {
+        match self {
+            ListMode::Compact => ListMode::Detailed,
+            ListMode::Detailed => ListMode::Compact,
+        }
+    }
+
+    /// ヘルプ行に表示するラベル
+    pub fn label(self) -> &'static str {
+        match self {
+            ListMode::Compact => "compact",
+            ListMode::Detailed => "detailed",
+        }
+    }
+}
+
+/// `tui_state.json` のスキーマ
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TuiState {
+    #[serde(default)]
+    pub list_mode: ListMode,
+}
+
+impl TuiState {
+    /// デフォルトパス（`~/.plm/tui_state.json`）から読み込む
+    pub fn load() -> Self {
+        crate::env::PlmPaths::new()
+            .map(|paths| Self::load_from(&paths.tui_state_json()))
+            .unwrap_or_default()
+    }
+
+    /// 指定パスから読み込む（ファイルが無い・壊れている場合は既定値）
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of `tui_state.json`.
+    pub fn load_from(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// デフォルトパスへ保存する
+    pub fn save(&self) -> Result<()> {
+        let paths = crate::env::PlmPaths::new()?;
+        self.save_to(&paths.tui_state_json())
+    }
+
+    /// 指定パスへ保存する（一時ファイル → rename）
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of `tui_state.json`.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        crate::component::convert::atomic_write(path, &json)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::tui::manager::core::tui_state::{ListMode, TuiState};
+use tempfile::TempDir;
+
+#[test]
+fn list_mode_toggles_between_compact_and_detailed() {
+    assert_eq!(ListMode::default(), ListMode::Compact);
+    assert_eq!(ListMode::Compact.next(), ListMode::Detailed);
+    assert_eq!(ListMode::Detailed.next(), ListMode::Compact);
+}
+
+#[test]
+fn saved_state_is_restored() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join(".plm").join("tui_state.json");
+    let state = TuiState {
+        list_mode: ListMode::Detailed,
+    };
+
+    state.save_to(&path).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains(r#""listMode": "detailed""#));
+    assert_eq!(TuiState::load_from(&path), state);
+}
+
+#[test]
+fn missing_or_corrupt_state_falls_back_to_default() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("tui_state.json");
+    assert_eq!(TuiState::load_from(&path), TuiState::default());
+
+    std::fs::write(&path, "{ not json").unwrap();
+    assert_eq!(TuiState::load_from(&path), TuiState::default());
+
+    std::fs::write(&path, "{}").unwrap();
+    assert_eq!(TuiState::load_from(&path).list_mode, ListMode::Compact);
+}
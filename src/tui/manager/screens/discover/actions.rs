@@ -0,0 +1,126 @@
+//! Discover タブのアクション実行
+//!
+//! マーケットプレイスキャッシュからのカタログ構築と、その場インストールを実行する。
+
+use super::model::CatalogPlugin;
+use crate::application::{InstalledPlugin, Warning};
+use crate::component::Scope;
+use crate::marketplace::{MarketplaceCache, MarketplaceRegistry};
+use crate::target::auto_targets;
+use crate::tui::manager::core::{DataStore, PluginId};
+use crate::tui::manager::screens::marketplaces::actions::install_plugins;
+
+/// 登録済みマーケットプレイスのキャッシュからカタログを読み込む
+///
+/// キャッシュが無い・読めないマーケットプレイスは読み飛ばす。
+///
+/// # Arguments
+///
+/// * `data` - Shared data store listing the registered marketplaces and installed plugins.
+pub fn load_catalog(data: &DataStore) -> Vec<CatalogPlugin> {
+    let Ok(registry) = MarketplaceRegistry::new() else {
+        return Vec::new();
+    };
+    let caches: Vec<MarketplaceCache> = data
+        .marketplaces
+        .iter()
+        .filter_map(|m| registry.get(&m.name).ok().flatten())
+        .collect();
+    build_catalog(&caches, &data.plugins)
+}
+
+/// 純粋変換: MarketplaceCache 群 -> 名前・マーケットプレイス順のカタログ
+///
+/// # Arguments
+///
+/// * `caches` - Cached manifests of the registered marketplaces.
+/// * `installed_plugins` - Currently installed plugins used to flag `installed`.
+///
+/// # Note
+///
+/// `installed` はマーケットプレイスとプラグイン名（キャッシュディレクトリ名）が
+/// 両方一致する場合だけ立てる。別のマーケットプレイスから入れた同名プラグインは
+/// インストール済みとみなさない。
+pub(super) fn build_catalog(
+    caches: &[MarketplaceCache],
+    installed_plugins: &[InstalledPlugin],
+) -> Vec<CatalogPlugin> {
+    let mut catalog: Vec<CatalogPlugin> = caches
+        .iter()
+        .flat_map(|cache| {
+            cache.plugins.iter().map(|p| {
+                let id = PluginId::new(Some(&cache.name), &p.name);
+                CatalogPlugin {
+                    marketplace: cache.name.clone(),
+                    name: p.name.clone(),
+                    description: p.description.clone(),
+                    version: p.version.clone(),
+                    installed: installed_plugins.iter().any(|ip| id.matches(ip)),
+                    stats: p.stats.clone(),
+                }
+            })
+        })
+        .collect();
+    catalog.sort_by(|a, b| (&a.name, &a.marketplace).cmp(&(&b.name, &b.marketplace)));
+    catalog
+}
+
+/// インストール済みフラグをインストール済みプラグイン一覧に合わせて更新する
+///
+/// # Arguments
+///
+/// * `catalog` - Catalog to update in place.
+/// * `installed_plugins` - Currently installed plugins.
+pub(super) fn refresh_installed(
+    catalog: &mut [CatalogPlugin],
+    installed_plugins: &[InstalledPlugin],
+) {
+    for entry in catalog {
+        let id = entry.id();
+        entry.installed = installed_plugins.iter().any(|ip| id.matches(ip));
+    }
+}
+
+/// プラグインをその場でインストールする
+///
+/// 配置先はプロジェクトで検出されたターゲット（`--target auto` と同じ判定）、
+/// スコープは Project。成功時は配置したターゲット名と配置中に出た警告を返す。
+///
+/// # Arguments
+///
+/// * `marketplace` - Marketplace the plugin is downloaded from.
+/// * `plugin_name` - Plugin to install.
+pub fn install_plugin(
+    marketplace: &str,
+    plugin_name: &str,
+) -> Result<(Vec<String>, Vec<Warning>), String> {
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
+    let target_names: Vec<String> = auto_targets(&project_root)
+        .into_iter()
+        .map(|kind| kind.as_str().to_string())
+        .collect();
+    if target_names.is_empty() {
+        return Err(
+            "No target detected in this project; use the Marketplaces tab to choose targets"
+                .to_string(),
+        );
+    }
+
+    let summary = install_plugins(
+        marketplace,
+        &[plugin_name.to_string()],
+        &target_names,
+        Scope::Project,
+    );
+    match summary.results.into_iter().next() {
+        Some(result) if result.success => Ok((target_names, summary.warnings)),
+        Some(result) => Err(result
+            .error
+            .unwrap_or_else(|| "Installation failed".to_string())),
+        None => Err("Installation failed".to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "actions_test.rs"]
+mod actions_test;
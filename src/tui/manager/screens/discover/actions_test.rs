@@ -0,0 +1,84 @@
+use super::*;
+use crate::marketplace::{MarketplacePlugin, PluginSource};
+
+fn make_plugin_in(marketplace: &str, name: &str) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(
+        name,
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some(marketplace.to_string()),
+        true,
+    )
+}
+
+fn make_marketplace_plugin(name: &str) -> MarketplacePlugin {
+    MarketplacePlugin {
+        name: name.to_string(),
+        source: PluginSource::Local(format!("./plugins/{}", name)),
+        description: Some(format!("{} description", name)),
+        version: Some("1.0.0".to_string()),
+        renamed_from: vec![],
+        stats: Default::default(),
+    }
+}
+
+fn make_cache(name: &str, plugins: &[&str]) -> MarketplaceCache {
+    MarketplaceCache {
+        name: name.to_string(),
+        fetched_at: chrono::Utc::now(),
+        source: "owner/repo".parse().unwrap(),
+        git_ref: None,
+        owner: None,
+        plugins: plugins.iter().map(|p| make_marketplace_plugin(p)).collect(),
+    }
+}
+
+#[test]
+fn build_catalog_lists_plugins_of_all_marketplaces_sorted_by_name() {
+    let caches = vec![
+        make_cache("team", &["linter", "formatter"]),
+        make_cache("company", &["reviewer"]),
+    ];
+
+    let catalog = build_catalog(&caches, &[]);
+
+    let labels: Vec<String> = catalog.iter().map(|p| p.id().display_label()).collect();
+    assert_eq!(
+        labels,
+        vec!["formatter@team", "linter@team", "reviewer@company"]
+    );
+    assert_eq!(
+        catalog[0].description.as_deref(),
+        Some("formatter description")
+    );
+    assert_eq!(catalog[0].version.as_deref(), Some("1.0.0"));
+}
+
+#[test]
+fn build_catalog_keeps_same_name_plugins_from_different_marketplaces() {
+    let caches = vec![
+        make_cache("team", &["linter"]),
+        make_cache("company", &["linter"]),
+    ];
+    let installed = vec![make_plugin_in("team", "linter")];
+
+    let catalog = build_catalog(&caches, &installed);
+
+    assert_eq!(catalog.len(), 2);
+    assert_eq!(catalog[0].marketplace, "company");
+    assert!(!catalog[0].installed);
+    assert_eq!(catalog[1].marketplace, "team");
+    assert!(catalog[1].installed);
+}
+
+#[test]
+fn refresh_installed_follows_installed_plugins() {
+    let caches = vec![make_cache("team", &["linter", "formatter"])];
+    let mut catalog = build_catalog(&caches, &[make_plugin_in("team", "formatter")]);
+
+    refresh_installed(&mut catalog, &[make_plugin_in("team", "linter")]);
+
+    assert!(!catalog[0].installed);
+    assert!(catalog[1].installed);
+}
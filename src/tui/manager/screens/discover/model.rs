@@ -0,0 +1,204 @@
+//! Discover タブの Model/Msg 定義
+//!
+//! 画面状態とメッセージ型を定義。
+
+use super::actions;
+use crate::marketplace::{sort_plugins, PluginSortKey, PluginStats};
+use crate::tui::manager::core::filter::{contains_query, filter_items};
+use crate::tui::manager::core::{DataStore, PluginId, SelectionState};
+use crossterm::event::KeyCode;
+
+/// キャッシュ状態（タブ切替時に保持）
+#[derive(Debug, Default)]
+pub struct CacheState {
+    pub selected_id: Option<PluginId>,
+    pub sort_key: PluginSortKey,
+}
+
+/// マーケットプレイスが提供するプラグイン（マーケットプレイスごとに 1 件）
+///
+/// 同名のプラグインが複数のマーケットプレイスにある場合は別々の項目になる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogPlugin {
+    pub marketplace: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub version: Option<String>,
+    /// このマーケットプレイスからインストール済みか
+    pub installed: bool,
+    /// 並び替え用のメタデータ（downloads / stars / updatedAt）
+    pub stats: PluginStats,
+}
+
+impl CatalogPlugin {
+    /// 選択・インストール状態のキーにするプラグインID
+    pub fn id(&self) -> PluginId {
+        PluginId::new(Some(&self.marketplace), &self.name)
+    }
+}
+
+/// その場インストールの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallStatus {
+    /// インストール中（phase1 でセットし、描画後の phase2 で実行する）
+    Installing,
+    /// インストール完了（配置したターゲット名）
+    Installed(Vec<String>),
+    /// 失敗
+    Failed(String),
+}
+
+/// Discover タブの画面状態
+pub struct DiscoverScreenModel {
+    /// 全マーケットプレイスのプラグイン（名前 → マーケットプレイスの順）
+    pub catalog: Vec<CatalogPlugin>,
+    /// 一覧の選択状態
+    pub selection: SelectionState<PluginId>,
+    /// 一覧のソートキー
+    pub sort_key: PluginSortKey,
+    /// 詳細を表示中のプラグイン（`None` は一覧）
+    pub detail: Option<PluginId>,
+    /// 直近のその場インストールの対象と状態
+    pub install: Option<(PluginId, InstallStatus)>,
+}
+
+impl DiscoverScreenModel {
+    /// 新しいモデルを作成
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Shared data store listing the registered marketplaces.
+    pub fn new(data: &DataStore) -> Self {
+        Self::with_catalog(actions::load_catalog(data), None)
+    }
+
+    /// キャッシュから復元
+    ///
+    /// カタログはタブを開くたびにマーケットプレイスのキャッシュから読み直す。
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Shared data store listing the registered marketplaces.
+    /// * `cache` - Cached state carried across tab switches.
+    pub fn from_cache(data: &DataStore, cache: &CacheState) -> Self {
+        let mut model = Self::with_catalog(actions::load_catalog(data), cache.selected_id.clone());
+        model.sort_key = cache.sort_key;
+        let ids: Vec<PluginId> = model
+            .filtered("")
+            .into_iter()
+            .map(CatalogPlugin::id)
+            .collect();
+        model.selection.sync_to(&ids);
+        model
+    }
+
+    /// 読み込み済みのカタログからモデルを作成（名前順）
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - Plugins offered by the registered marketplaces.
+    /// * `selected_id` - Plugin to select if it is listed; otherwise the first item.
+    pub fn with_catalog(catalog: Vec<CatalogPlugin>, selected_id: Option<PluginId>) -> Self {
+        let mut selection = SelectionState::new(selected_id, None);
+        let ids: Vec<PluginId> = catalog.iter().map(CatalogPlugin::id).collect();
+        selection.sync_to(&ids);
+        Self {
+            catalog,
+            selection,
+            sort_key: PluginSortKey::default(),
+            detail: None,
+            install: None,
+        }
+    }
+
+    /// キャッシュ状態を取得
+    pub fn to_cache(&self) -> CacheState {
+        CacheState {
+            selected_id: self.selection.selected_id().cloned(),
+            sort_key: self.sort_key,
+        }
+    }
+
+    /// トップレベル（タブ切替可能）かどうか
+    pub fn is_top_level(&self) -> bool {
+        self.detail.is_none()
+    }
+
+    /// プラグインIDでカタログを検索
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Plugin id to look up.
+    pub fn find(&self, id: &PluginId) -> Option<&CatalogPlugin> {
+        self.catalog.iter().find(|p| &p.id() == id)
+    }
+
+    /// フィルタ適用後の一覧（表示順）
+    ///
+    /// case-insensitive な部分一致で名前・マーケットプレイス・説明を検索し、
+    /// ソートキーの順に並べる。
+    ///
+    /// # Arguments
+    ///
+    /// * `filter_text` - Current filter input text.
+    pub fn filtered(&self, filter_text: &str) -> Vec<&CatalogPlugin> {
+        let mut plugins = filter_items(&self.catalog, filter_text, |p, query| {
+            contains_query(&p.name, query)
+                || contains_query(&p.marketplace, query)
+                || p.description
+                    .as_deref()
+                    .is_some_and(|d| contains_query(d, query))
+        });
+        sort_plugins(&mut plugins, self.sort_key, |p| (p.name.as_str(), &p.stats));
+        plugins
+    }
+
+    /// 指定したプラグインのインストール状態
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Plugin id to look up.
+    pub fn install_status(&self, id: &PluginId) -> Option<&InstallStatus> {
+        self.install
+            .as_ref()
+            .filter(|(target, _)| target == id)
+            .map(|(_, status)| status)
+    }
+}
+
+/// Discover タブへのメッセージ
+pub enum Msg {
+    Up,
+    Down,
+    Enter,
+    Back,
+    /// 選択中（詳細表示中）のプラグインをインストールする（phase1）
+    Install,
+    /// インストールを実行する（phase2）
+    ExecuteInstall,
+    /// ソートキーを次へ切り替える
+    CycleSort,
+}
+
+/// キーコードをメッセージに変換
+///
+/// フィルタ非フォーカス時にのみ app.rs から呼ばれるため、j/k/i/s はフィルタ入力と競合しない。
+///
+/// # Arguments
+///
+/// * `key` - Pressed key code.
+pub fn key_to_msg(key: KeyCode) -> Option<Msg> {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => Some(Msg::Up),
+        KeyCode::Down | KeyCode::Char('j') => Some(Msg::Down),
+        KeyCode::Enter => Some(Msg::Enter),
+        KeyCode::Esc => Some(Msg::Back),
+        KeyCode::Char('i') => Some(Msg::Install),
+        KeyCode::Char('s') => Some(Msg::CycleSort),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "model_test.rs"]
+mod model_test;
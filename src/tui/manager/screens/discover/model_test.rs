@@ -0,0 +1,74 @@
+use super::*;
+
+fn entry(marketplace: &str, name: &str, description: Option<&str>) -> CatalogPlugin {
+    CatalogPlugin {
+        marketplace: marketplace.to_string(),
+        name: name.to_string(),
+        description: description.map(str::to_string),
+        version: Some("1.0.0".to_string()),
+        installed: false,
+        stats: Default::default(),
+    }
+}
+
+fn catalog() -> Vec<CatalogPlugin> {
+    vec![
+        entry("company", "formatter", Some("Formats source code")),
+        entry("team", "linter", None),
+        entry("company", "reviewer", Some("Reviews pull requests")),
+    ]
+}
+
+#[test]
+fn with_catalog_restores_cached_selection() {
+    let selected = PluginId::new(Some("team"), "linter");
+
+    let model = DiscoverScreenModel::with_catalog(catalog(), Some(selected.clone()));
+
+    assert_eq!(model.selection.selected_id(), Some(&selected));
+    assert_eq!(model.selection.selected_index(), Some(1));
+    assert!(model.is_top_level());
+}
+
+#[test]
+fn with_catalog_selects_first_when_cached_plugin_is_gone() {
+    let model =
+        DiscoverScreenModel::with_catalog(catalog(), Some(PluginId::new(Some("team"), "removed")));
+
+    assert_eq!(
+        model.selection.selected_id(),
+        Some(&PluginId::new(Some("company"), "formatter"))
+    );
+    assert_eq!(model.selection.selected_index(), Some(0));
+}
+
+#[test]
+fn filtered_matches_name_marketplace_and_description() {
+    let model = DiscoverScreenModel::with_catalog(catalog(), None);
+
+    let names = |query: &str| -> Vec<String> {
+        model
+            .filtered(query)
+            .iter()
+            .map(|p| p.name.clone())
+            .collect()
+    };
+
+    assert_eq!(names("LINT"), vec!["linter"]);
+    assert_eq!(names("company"), vec!["formatter", "reviewer"]);
+    assert_eq!(names("pull"), vec!["reviewer"]);
+    assert_eq!(names(""), vec!["formatter", "linter", "reviewer"]);
+}
+
+#[test]
+fn key_to_msg_maps_install_and_navigation_keys() {
+    assert!(matches!(key_to_msg(KeyCode::Char('i')), Some(Msg::Install)));
+    assert!(matches!(key_to_msg(KeyCode::Enter), Some(Msg::Enter)));
+    assert!(matches!(key_to_msg(KeyCode::Esc), Some(Msg::Back)));
+    assert!(matches!(key_to_msg(KeyCode::Char('j')), Some(Msg::Down)));
+    assert!(matches!(
+        key_to_msg(KeyCode::Char('s')),
+        Some(Msg::CycleSort)
+    ));
+    assert!(key_to_msg(KeyCode::Char('x')).is_none());
+}
@@ -0,0 +1,222 @@
+//! Discover タブの update（状態更新）
+//!
+//! メッセージに応じた画面状態の更新ロジック。
+
+use super::actions;
+use super::model::{CatalogPlugin, DiscoverScreenModel, InstallStatus, Msg};
+use crate::application::Warning;
+use crate::output::hint::post_deploy_hint_line;
+use crate::tui::manager::core::{DataStore, PluginId, Tab};
+
+/// update() の戻り値
+pub struct UpdateEffect {
+    /// フィルタ入力欄へフォーカス移動すべき
+    pub should_focus_filter: bool,
+    /// 描画後に実行すべきフォローアップメッセージ（2段階方式の Phase 2）
+    pub phase2_msg: Option<Msg>,
+}
+
+impl UpdateEffect {
+    fn none() -> Self {
+        Self {
+            should_focus_filter: false,
+            phase2_msg: None,
+        }
+    }
+
+    fn focus_filter() -> Self {
+        Self {
+            should_focus_filter: true,
+            ..Self::none()
+        }
+    }
+
+    fn phase2(msg: Msg) -> Self {
+        Self {
+            phase2_msg: Some(msg),
+            ..Self::none()
+        }
+    }
+}
+
+/// メッセージに応じて状態を更新
+///
+/// # Arguments
+///
+/// * `model` - Discover tab model to mutate.
+/// * `msg` - Incoming message to apply.
+/// * `data` - Shared data store for plugins.
+/// * `filter_text` - Current filter input text.
+pub fn update(
+    model: &mut DiscoverScreenModel,
+    msg: Msg,
+    data: &mut DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
+    match msg {
+        Msg::Up => {
+            if select_prev(model, filter_text) {
+                UpdateEffect::focus_filter()
+            } else {
+                UpdateEffect::none()
+            }
+        }
+        Msg::Down => {
+            select_next(model, filter_text);
+            UpdateEffect::none()
+        }
+        Msg::Enter => {
+            if model.detail.is_none() {
+                model.detail = model.selection.selected_id().cloned();
+            }
+            UpdateEffect::none()
+        }
+        Msg::Back => {
+            model.detail = None;
+            UpdateEffect::none()
+        }
+        Msg::Install => install(model),
+        Msg::ExecuteInstall => {
+            execute_install(model, data);
+            UpdateEffect::none()
+        }
+        Msg::CycleSort => {
+            cycle_sort(model, filter_text);
+            UpdateEffect::none()
+        }
+    }
+}
+
+/// 上へ移動。先頭（またはリストが空）ならフィルタへフォーカスを移す
+///
+/// 詳細表示中は何もしない。
+fn select_prev(model: &mut DiscoverScreenModel, filter_text: &str) -> bool {
+    if model.detail.is_some() {
+        return false;
+    }
+    let ids = visible_ids(model, filter_text);
+    let current = model.selection.selected_index().unwrap_or(0);
+    if ids.is_empty() || current == 0 {
+        return true;
+    }
+    let prev = current - 1;
+    model.selection.set(ids.get(prev).cloned(), Some(prev));
+    false
+}
+
+/// 下へ移動（詳細表示中は何もしない）
+fn select_next(model: &mut DiscoverScreenModel, filter_text: &str) {
+    if model.detail.is_some() {
+        return;
+    }
+    let ids = visible_ids(model, filter_text);
+    if ids.is_empty() {
+        return;
+    }
+    let current = model.selection.selected_index().unwrap_or(0);
+    let next = (current + 1).min(ids.len() - 1);
+    model.selection.set(ids.get(next).cloned(), Some(next));
+}
+
+/// ソートキーを次へ切り替える（詳細表示中は何もしない）
+///
+/// 選択中のプラグインは維持し、並べ替え後の位置へカーソルを移す。
+fn cycle_sort(model: &mut DiscoverScreenModel, filter_text: &str) {
+    if model.detail.is_some() {
+        return;
+    }
+    model.sort_key = model.sort_key.next();
+    clamp_selection(model, filter_text);
+}
+
+/// インストール対象のプラグイン（詳細表示中ならそのプラグイン、一覧なら選択中）
+fn install_target(model: &DiscoverScreenModel) -> Option<PluginId> {
+    model
+        .detail
+        .clone()
+        .or_else(|| model.selection.selected_id().cloned())
+}
+
+/// Phase 1: インストール中の状態をセットし、描画後に ExecuteInstall を実行させる
+///
+/// インストール済み・インストール中のプラグインは対象にしない。
+fn install(model: &mut DiscoverScreenModel) -> UpdateEffect {
+    let Some(id) = install_target(model) else {
+        return UpdateEffect::none();
+    };
+    if !model.find(&id).is_some_and(|p| !p.installed) {
+        return UpdateEffect::none();
+    }
+    if matches!(model.install_status(&id), Some(InstallStatus::Installing)) {
+        return UpdateEffect::none();
+    }
+    model.install = Some((id, InstallStatus::Installing));
+    UpdateEffect::phase2(Msg::ExecuteInstall)
+}
+
+/// Phase 2: インストールを実行
+fn execute_install(model: &mut DiscoverScreenModel, data: &mut DataStore) {
+    execute_install_with(model, data, actions::install_plugin, |d| d.reload());
+}
+
+/// Phase 2 の実装本体（依存関数を注入可能）
+///
+/// テストでは `run_install` と `reload` にスタブを注入して
+/// ファイルシステムアクセスなしで密閉的にテストできる。
+fn execute_install_with(
+    model: &mut DiscoverScreenModel,
+    data: &mut DataStore,
+    run_install: impl FnOnce(&str, &str) -> Result<(Vec<String>, Vec<Warning>), String>,
+    reload: impl FnOnce(&mut DataStore) -> std::io::Result<()>,
+) {
+    let Some((id, InstallStatus::Installing)) = model.install.clone() else {
+        return;
+    };
+    let Some(marketplace) = id.marketplace() else {
+        return;
+    };
+
+    match run_install(marketplace, id.name()) {
+        Ok((targets, warnings)) => {
+            data.last_warnings = warnings;
+            if let Err(e) = reload(data) {
+                data.push_error(Tab::Discover, format!("Failed to reload plugins: {}", e));
+            }
+            actions::refresh_installed(&mut model.catalog, &data.plugins);
+            data.last_notice = post_deploy_hint_line(&targets);
+            model.install = Some((id, InstallStatus::Installed(targets)));
+        }
+        Err(e) => {
+            data.last_warnings.clear();
+            data.push_error(
+                Tab::Discover,
+                format!("Install failed for {}: {}", id.display_label(), e),
+            );
+            model.install = Some((id, InstallStatus::Failed(e)));
+        }
+    }
+}
+
+/// フィルタ適用後の一覧のプラグインID（表示順）
+fn visible_ids(model: &DiscoverScreenModel, filter_text: &str) -> Vec<PluginId> {
+    model
+        .filtered(filter_text)
+        .into_iter()
+        .map(CatalogPlugin::id)
+        .collect()
+}
+
+/// フィルタ変更後に選択状態を整合させる
+///
+/// # Arguments
+///
+/// * `model` - Discover tab model whose selection is synced.
+/// * `filter_text` - Current filter input text.
+pub fn clamp_selection(model: &mut DiscoverScreenModel, filter_text: &str) {
+    let ids = visible_ids(model, filter_text);
+    model.selection.sync_to(&ids);
+}
+
+#[cfg(test)]
+#[path = "update_test.rs"]
+mod update_test;
@@ -0,0 +1,266 @@
+use super::{clamp_selection, execute_install_with, update};
+use crate::application::InstalledPlugin;
+use crate::marketplace::PluginSortKey;
+use crate::tui::manager::core::{DataStore, PluginId, Tab};
+use crate::tui::manager::screens::discover::model::{
+    CatalogPlugin, DiscoverScreenModel, InstallStatus, Msg,
+};
+
+/// スタブ: reload を何もしない（プラグインリストを維持）
+fn stub_reload(_data: &mut DataStore) -> std::io::Result<()> {
+    Ok(())
+}
+
+fn make_plugin_in(marketplace: &str, name: &str) -> InstalledPlugin {
+    InstalledPlugin::new_for_test(
+        name,
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some(marketplace.to_string()),
+        true,
+    )
+}
+
+fn entry(marketplace: &str, name: &str, installed: bool) -> CatalogPlugin {
+    CatalogPlugin {
+        marketplace: marketplace.to_string(),
+        name: name.to_string(),
+        description: None,
+        version: Some("1.0.0".to_string()),
+        installed,
+        stats: Default::default(),
+    }
+}
+
+fn id(marketplace: &str, name: &str) -> PluginId {
+    PluginId::new(Some(marketplace), name)
+}
+
+fn make_model() -> DiscoverScreenModel {
+    DiscoverScreenModel::with_catalog(
+        vec![
+            entry("company", "formatter", false),
+            entry("company", "linter", true),
+            entry("team", "linter", false),
+        ],
+        None,
+    )
+}
+
+fn make_data() -> (tempfile::TempDir, DataStore) {
+    DataStore::for_test(vec![make_plugin_in("company", "linter")], vec![], None)
+}
+
+// ============================================================================
+// 選択・詳細表示
+// ============================================================================
+
+#[test]
+fn up_at_top_focuses_filter() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+
+    let effect = update(&mut model, Msg::Up, &mut data, "");
+
+    assert!(effect.should_focus_filter);
+    assert_eq!(model.selection.selected_index(), Some(0));
+}
+
+#[test]
+fn down_moves_within_filtered_list() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    clamp_selection(&mut model, "linter");
+
+    update(&mut model, Msg::Down, &mut data, "linter");
+    update(&mut model, Msg::Down, &mut data, "linter");
+
+    assert_eq!(model.selection.selected_id(), Some(&id("team", "linter")));
+    assert_eq!(model.selection.selected_index(), Some(1));
+}
+
+#[test]
+fn enter_opens_detail_and_back_returns_to_list() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    update(&mut model, Msg::Down, &mut data, "");
+
+    update(&mut model, Msg::Enter, &mut data, "");
+    assert_eq!(model.detail, Some(id("company", "linter")));
+    assert!(!model.is_top_level());
+
+    update(&mut model, Msg::Back, &mut data, "");
+    assert!(model.detail.is_none());
+    assert!(model.is_top_level());
+}
+
+#[test]
+fn clamp_selection_keeps_selected_plugin_when_still_visible() {
+    let mut model = make_model();
+    model.selection.set(Some(id("team", "linter")), Some(2));
+
+    clamp_selection(&mut model, "team");
+
+    assert_eq!(model.selection.selected_id(), Some(&id("team", "linter")));
+    assert_eq!(model.selection.selected_index(), Some(0));
+}
+
+#[test]
+fn cycle_sort_reorders_by_downloads_and_keeps_selection() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    model.catalog[2].stats.downloads = Some(120);
+    model.catalog[0].stats.downloads = Some(40);
+    update(&mut model, Msg::Down, &mut data, "");
+
+    update(&mut model, Msg::CycleSort, &mut data, "");
+
+    let names: Vec<String> = model
+        .filtered("")
+        .iter()
+        .map(|p| p.id().display_label())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["linter@team", "formatter@company", "linter@company"]
+    );
+    assert_eq!(
+        model.selection.selected_id(),
+        Some(&id("company", "linter"))
+    );
+    assert_eq!(model.selection.selected_index(), Some(2));
+}
+
+#[test]
+fn cycle_sort_is_ignored_in_detail() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    update(&mut model, Msg::Enter, &mut data, "");
+
+    update(&mut model, Msg::CycleSort, &mut data, "");
+
+    assert_eq!(model.sort_key, PluginSortKey::Name);
+}
+
+// ============================================================================
+// その場インストール
+// ============================================================================
+
+#[test]
+fn install_phase1_sets_installing_and_returns_execute_install() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+
+    let effect = update(&mut model, Msg::Install, &mut data, "");
+
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteInstall)));
+    assert_eq!(
+        model.install_status(&id("company", "formatter")),
+        Some(&InstallStatus::Installing)
+    );
+}
+
+#[test]
+fn install_skips_already_installed_plugin() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    update(&mut model, Msg::Down, &mut data, "");
+
+    let effect = update(&mut model, Msg::Install, &mut data, "");
+
+    assert!(effect.phase2_msg.is_none());
+    assert!(model.install.is_none());
+}
+
+#[test]
+fn install_from_detail_targets_the_shown_plugin() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    model.detail = Some(id("team", "linter"));
+
+    update(&mut model, Msg::Install, &mut data, "");
+
+    assert_eq!(
+        model.install_status(&id("team", "linter")),
+        Some(&InstallStatus::Installing)
+    );
+}
+
+#[test]
+fn execute_install_success_marks_plugin_installed() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    model.install = Some((id("team", "linter"), InstallStatus::Installing));
+    let mut called_with = None;
+
+    execute_install_with(
+        &mut model,
+        &mut data,
+        |marketplace, name| {
+            called_with = Some((marketplace.to_string(), name.to_string()));
+            Ok((vec!["codex".to_string()], Vec::new()))
+        },
+        |d| {
+            d.plugins.push(make_plugin_in("team", "linter"));
+            Ok(())
+        },
+    );
+
+    assert_eq!(
+        called_with,
+        Some(("team".to_string(), "linter".to_string()))
+    );
+    assert_eq!(
+        model.install_status(&id("team", "linter")),
+        Some(&InstallStatus::Installed(vec!["codex".to_string()]))
+    );
+    assert!(model.find(&id("team", "linter")).unwrap().installed);
+    // 同名でも別マーケットプレイスの項目には影響しない
+    assert!(!model.find(&id("company", "formatter")).unwrap().installed);
+    assert!(data.errors.is_empty());
+}
+
+#[test]
+fn execute_install_failure_records_error() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    model.install = Some((id("company", "formatter"), InstallStatus::Installing));
+
+    execute_install_with(
+        &mut model,
+        &mut data,
+        |_, _| Err("network down".to_string()),
+        stub_reload,
+    );
+
+    assert_eq!(
+        model.install_status(&id("company", "formatter")),
+        Some(&InstallStatus::Failed("network down".to_string()))
+    );
+    assert!(!model.find(&id("company", "formatter")).unwrap().installed);
+    let error = data.last_error().expect("error should be recorded");
+    assert_eq!(data.errors[0].source_tab, Tab::Discover);
+    assert!(error.contains("formatter@company"), "{}", error);
+    assert!(error.contains("network down"), "{}", error);
+}
+
+#[test]
+fn execute_install_without_pending_install_is_noop() {
+    let (_tmp, mut data) = make_data();
+    let mut model = make_model();
+    let mut called = false;
+
+    execute_install_with(
+        &mut model,
+        &mut data,
+        |_, _| {
+            called = true;
+            Ok((Vec::new(), Vec::new()))
+        },
+        stub_reload,
+    );
+
+    assert!(!called);
+    assert!(model.install.is_none());
+}
@@ -0,0 +1,255 @@
+//! Discover タブの view（描画）
+
+use super::model::{CatalogPlugin, DiscoverScreenModel, InstallStatus};
+use crate::tui::manager::core::layout::{align_columns, column_widths, framed_layout, outer_rect};
+use crate::tui::manager::core::spinner;
+use crate::tui::manager::core::style::{
+    bordered_block, highlight_line, selectable_list, LIST_ITEM_INDENT,
+};
+use crate::tui::manager::core::{
+    render_empty_state, render_filter_bar, render_tab_bar, truncate_for_list, DataStore, Tab,
+    TabBadges, Theme,
+};
+use ratatui::prelude::*;
+use ratatui::widgets::{Clear, ListItem, Paragraph, Wrap};
+
+/// 画面を描画
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to render into.
+/// * `model` - Discover tab model to render.
+/// * `data` - Shared data store (for the last error).
+/// * `badges` - Counts shown in the tab bar.
+/// * `filter_text` - Current filter input text.
+/// * `filter_focused` - Whether the filter bar has focus.
+pub fn view(
+    f: &mut Frame,
+    model: &DiscoverScreenModel,
+    data: &DataStore,
+    badges: &TabBadges,
+    filter_text: &str,
+    filter_focused: bool,
+) {
+    let theme = Theme::current();
+    let outer = outer_rect(f.area());
+    f.render_widget(Clear, f.area());
+
+    let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
+
+    render_tab_bar(f, tabs_area, Tab::Discover, Some(badges));
+
+    render_filter_bar(f, filter_area, filter_text, filter_focused);
+
+    let detail = model.detail.as_ref().and_then(|id| model.find(id));
+    let help_text = match detail {
+        Some(plugin) => {
+            view_detail(f, model, plugin, content_area);
+            if plugin.installed {
+                " Esc: back | q: quit"
+            } else {
+                " i: install | Esc: back | q: quit"
+            }
+        }
+        None => {
+            view_list(f, model, data, filter_text, content_area);
+            " i: install | Enter: details | s: sort | Tab: switch | ↑↓: move | q: quit"
+        }
+    };
+
+    let help = Paragraph::new(help_text).style(Style::default().fg(theme.muted));
+    f.render_widget(help, help_area);
+}
+
+/// カタログ一覧（名前 / @マーケットプレイス / バージョン）を描画
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to render into.
+/// * `model` - Discover tab model to render.
+/// * `data` - Shared data store (for the last error).
+/// * `filter_text` - Current filter input text.
+/// * `area` - Content area below the filter bar.
+fn view_list(
+    f: &mut Frame,
+    model: &DiscoverScreenModel,
+    data: &DataStore,
+    filter_text: &str,
+    area: Rect,
+) {
+    let theme = Theme::current();
+    let filtered = model.filtered(filter_text);
+    let count = if filter_text.is_empty() {
+        model.catalog.len().to_string()
+    } else {
+        format!("{}/{}", filtered.len(), model.catalog.len())
+    };
+    let title = format!(" Discover ({}) · sort: {} ", count, model.sort_key.label());
+
+    let has_error = data.last_error().is_some();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(if has_error { 1 } else { 0 }),
+        ])
+        .split(area);
+    let (list_area, error_area) = (chunks[0], chunks[1]);
+
+    if filtered.is_empty() {
+        render_empty_state(
+            f,
+            list_area,
+            &title,
+            filter_text,
+            "No plugins found in registered marketplaces",
+        );
+    } else {
+        let cells: Vec<[String; 3]> = filtered.iter().map(|p| catalog_row_cells(p)).collect();
+        let widths = column_widths(&cells);
+        let selected_idx = model.selection.selected_index();
+        let items: Vec<ListItem> = cells
+            .iter()
+            .zip(&filtered)
+            .enumerate()
+            .map(|(i, (row, plugin))| {
+                let columns = align_columns(row, &widths);
+                build_catalog_list_item(
+                    &columns,
+                    plugin,
+                    model.install_status(&plugin.id()),
+                    list_area.width,
+                    Some(i) == selected_idx,
+                )
+            })
+            .collect();
+        let list = selectable_list(items, &title);
+        let mut state = model.selection.list_state().clone();
+        f.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    if let Some(error) = data.last_error() {
+        let line = error.lines().next().unwrap_or_default();
+        let para = Paragraph::new(format!(" {}", line)).style(Style::default().fg(theme.error));
+        f.render_widget(para, error_area);
+    }
+}
+
+/// プラグイン詳細（説明・バージョン・マーケットプレイス・インストール状態）を描画
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to render into.
+/// * `model` - Discover tab model (for the install status).
+/// * `plugin` - Plugin to describe.
+/// * `area` - Content area below the filter bar.
+fn view_detail(f: &mut Frame, model: &DiscoverScreenModel, plugin: &CatalogPlugin, area: Rect) {
+    let theme = Theme::current();
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::raw(format!("  {}: ", label)),
+            Span::styled(value, Style::default().fg(theme.text)),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::raw(""),
+        field("Marketplace", plugin.marketplace.clone()),
+        field(
+            "Version",
+            plugin.version.clone().unwrap_or_else(|| "N/A".to_string()),
+        ),
+        field(
+            "Installed",
+            if plugin.installed { "Yes" } else { "No" }.to_string(),
+        ),
+        Line::raw(""),
+        field(
+            "Description",
+            plugin
+                .description
+                .clone()
+                .unwrap_or_else(|| "N/A".to_string()),
+        ),
+    ];
+    if let Some(status) = model.install_status(&plugin.id()) {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw(" "),
+            install_status_span(status),
+        ]));
+    }
+
+    let title = format!(" {} ", plugin.id().display_label());
+    let detail = Paragraph::new(lines)
+        .block(bordered_block(&title))
+        .wrap(Wrap { trim: false });
+    f.render_widget(detail, area);
+}
+
+/// カタログ一覧の name / @marketplace / version の 3 列を組み立てる。
+///
+/// 列幅は呼び出し側で一覧全体から `column_widths` で求めて揃える。
+///
+/// # Arguments
+///
+/// * `plugin` - Catalog entry to format.
+fn catalog_row_cells(plugin: &CatalogPlugin) -> [String; 3] {
+    [
+        plugin.name.clone(),
+        format!("@{}", plugin.marketplace),
+        plugin.version.clone().unwrap_or_default(),
+    ]
+}
+
+/// カタログ一覧の 1 行ぶんを 2 行 ListItem (内容 + 空行) として構築する。
+///
+/// インストール済みなら `[installed]` バッジ、その場インストールの状態があればそれを後置する。
+fn build_catalog_list_item(
+    columns: &[String],
+    plugin: &CatalogPlugin,
+    status: Option<&InstallStatus>,
+    outer_width: u16,
+    is_selected: bool,
+) -> ListItem<'static> {
+    let theme = Theme::current();
+    let raw = format!("{}{}", LIST_ITEM_INDENT, columns.concat());
+    let mut spans = vec![Span::raw(truncate_for_list(outer_width, raw).into_owned())];
+    match status {
+        // 直前にインストールしたものは結果表示を優先する
+        Some(status @ (InstallStatus::Installing | InstallStatus::Failed(_))) => {
+            spans.push(install_status_span(status));
+        }
+        _ if plugin.installed => {
+            spans.push(Span::styled(
+                " [installed]",
+                Style::default().fg(theme.success),
+            ));
+        }
+        _ => {}
+    }
+    ListItem::new(vec![highlight_line(spans, is_selected), Line::raw("")])
+}
+
+/// インストール状態の表示文字列とスタイルを取得
+///
+/// # Arguments
+///
+/// * `status` - Install status to format as a `Span`.
+fn install_status_span(status: &InstallStatus) -> Span<'static> {
+    let theme = Theme::current();
+    match status {
+        InstallStatus::Installing => Span::styled(
+            format!(" {} Installing...", spinner::frame()),
+            Style::default().fg(theme.warning),
+        ),
+        InstallStatus::Installed(targets) => Span::styled(
+            format!(" Installed to {}", targets.join(", ")),
+            Style::default().fg(theme.success),
+        ),
+        InstallStatus::Failed(reason) => Span::styled(
+            format!(" Failed: {}", reason.lines().next().unwrap_or_default()),
+            Style::default().fg(theme.error),
+        ),
+    }
+}
@@ -0,0 +1,80 @@
+//! Errors タブの Model/Msg 定義
+//!
+//! 画面状態とメッセージ型を定義。
+
+use crate::tui::manager::core::{DataStore, ErrorEntry};
+use crossterm::event::KeyCode;
+use ratatui::widgets::ListState;
+
+/// Errors タブの画面状態
+///
+/// エラー一覧は `DataStore.errors` を新しい順に並べたもの。
+pub struct ErrorsScreenModel {
+    /// 一覧のカーソル（新しい順での位置）
+    pub state: ListState,
+    /// 選択中のエラーの全文を表示中か
+    pub detail: bool,
+}
+
+impl ErrorsScreenModel {
+    /// 新しいモデルを作成（最新のエラーを選択）
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Shared data store holding the error history.
+    pub fn new(data: &DataStore) -> Self {
+        let mut state = ListState::default();
+        state.select((!data.errors.is_empty()).then_some(0));
+        Self {
+            state,
+            detail: false,
+        }
+    }
+
+    /// トップレベル（タブ切替可能）かどうか
+    pub fn is_top_level(&self) -> bool {
+        !self.detail
+    }
+
+    /// 選択中のエラー
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Shared data store holding the error history.
+    pub fn selected<'a>(&self, data: &'a DataStore) -> Option<&'a ErrorEntry> {
+        let idx = self.state.selected()?;
+        data.errors.iter().rev().nth(idx)
+    }
+}
+
+/// Errors タブへのメッセージ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    Up,
+    Down,
+    /// 選択中のエラーの全文を表示
+    Enter,
+    Back,
+    /// エラー履歴をすべて消去
+    Clear,
+}
+
+/// キーコードをメッセージに変換
+///
+/// # Arguments
+///
+/// * `key` - Pressed key code.
+pub fn key_to_msg(key: KeyCode) -> Option<Msg> {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => Some(Msg::Up),
+        KeyCode::Down | KeyCode::Char('j') => Some(Msg::Down),
+        KeyCode::Enter => Some(Msg::Enter),
+        KeyCode::Esc => Some(Msg::Back),
+        KeyCode::Char('c') => Some(Msg::Clear),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "model_test.rs"]
+mod model_test;
@@ -0,0 +1,35 @@
+use super::*;
+use crate::tui::manager::core::Tab;
+
+#[test]
+fn new_selects_newest_error() {
+    let (_tmp, mut data) = DataStore::for_test(vec![], vec![], None);
+    data.push_error(Tab::Installed, "older");
+    data.push_error(Tab::Marketplaces, "newer");
+
+    let model = ErrorsScreenModel::new(&data);
+
+    assert_eq!(model.state.selected(), Some(0));
+    assert_eq!(model.selected(&data).unwrap().message, "newer");
+    assert!(model.is_top_level());
+}
+
+#[test]
+fn new_without_errors_has_no_selection() {
+    let (_tmp, data) = DataStore::for_test(vec![], vec![], None);
+
+    let model = ErrorsScreenModel::new(&data);
+
+    assert_eq!(model.state.selected(), None);
+    assert!(model.selected(&data).is_none());
+}
+
+#[test]
+fn key_to_msg_maps_clear_and_navigation() {
+    assert_eq!(key_to_msg(KeyCode::Char('c')), Some(Msg::Clear));
+    assert_eq!(key_to_msg(KeyCode::Char('j')), Some(Msg::Down));
+    assert_eq!(key_to_msg(KeyCode::Up), Some(Msg::Up));
+    assert_eq!(key_to_msg(KeyCode::Enter), Some(Msg::Enter));
+    assert_eq!(key_to_msg(KeyCode::Esc), Some(Msg::Back));
+    assert_eq!(key_to_msg(KeyCode::Char('x')), None);
+}
@@ -0,0 +1,45 @@
+//! Errors タブの update（状態更新）
+//!
+//! メッセージに応じた画面状態の更新ロジック。
+
+use super::model::{ErrorsScreenModel, Msg};
+use crate::tui::manager::core::{clamp_index, DataStore};
+
+/// メッセージに応じて状態を更新
+///
+/// # Arguments
+///
+/// * `model` - Errors tab model to mutate.
+/// * `msg` - Incoming message to apply.
+/// * `data` - Shared data store holding the error history.
+pub fn update(model: &mut ErrorsScreenModel, msg: Msg, data: &mut DataStore) {
+    let len = data.errors.len();
+    match msg {
+        Msg::Up if !model.detail => {
+            let idx = model.state.selected().unwrap_or(0);
+            model.state.select(Some(idx.saturating_sub(1)));
+            clamp_index(&mut model.state, len);
+        }
+        Msg::Down if !model.detail => {
+            let idx = model.state.selected().map_or(0, |i| i + 1);
+            model.state.select(Some(idx));
+            clamp_index(&mut model.state, len);
+        }
+        Msg::Enter => {
+            model.detail = model.selected(data).is_some();
+        }
+        Msg::Back => {
+            model.detail = false;
+        }
+        Msg::Clear => {
+            data.clear_errors();
+            model.detail = false;
+            model.state.select(None);
+        }
+        Msg::Up | Msg::Down => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "update_test.rs"]
+mod update_test;
@@ -0,0 +1,72 @@
+use super::update;
+use crate::tui::manager::core::{DataStore, Tab};
+use crate::tui::manager::screens::errors::model::{ErrorsScreenModel, Msg};
+
+fn make_data(messages: &[&str]) -> (tempfile::TempDir, DataStore) {
+    let (tmp, mut data) = DataStore::for_test(vec![], vec![], None);
+    for message in messages {
+        data.push_error(Tab::Installed, *message);
+    }
+    (tmp, data)
+}
+
+#[test]
+fn down_and_up_move_within_the_list() {
+    let (_tmp, mut data) = make_data(&["first", "second", "third"]);
+    let mut model = ErrorsScreenModel::new(&data);
+
+    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, Msg::Down, &mut data);
+    assert_eq!(model.state.selected(), Some(2));
+    assert_eq!(model.selected(&data).unwrap().message, "first");
+
+    update(&mut model, Msg::Up, &mut data);
+    assert_eq!(model.selected(&data).unwrap().message, "second");
+}
+
+#[test]
+fn enter_opens_detail_and_back_returns_to_list() {
+    let (_tmp, mut data) = make_data(&["line 1\nline 2"]);
+    let mut model = ErrorsScreenModel::new(&data);
+
+    update(&mut model, Msg::Enter, &mut data);
+    assert!(model.detail);
+    assert!(!model.is_top_level());
+
+    // 全文表示中はカーソルを動かさない
+    update(&mut model, Msg::Down, &mut data);
+    assert_eq!(model.state.selected(), Some(0));
+
+    update(&mut model, Msg::Back, &mut data);
+    assert!(model.is_top_level());
+}
+
+#[test]
+fn enter_without_errors_stays_on_list() {
+    let (_tmp, mut data) = make_data(&[]);
+    let mut model = ErrorsScreenModel::new(&data);
+
+    update(&mut model, Msg::Enter, &mut data);
+
+    assert!(!model.detail);
+}
+
+#[test]
+fn clear_removes_all_errors_but_keeps_warnings() {
+    let (_tmp, mut data) = make_data(&["first", "second"]);
+    data.warnings = vec![crate::tui::manager::core::ManifestWarning {
+        marketplace: "catalog".to_string(),
+        issue: crate::marketplace::ValidationIssue::warning("plugins[0]", "unsupported"),
+    }];
+    let mut model = ErrorsScreenModel::new(&data);
+    update(&mut model, Msg::Enter, &mut data);
+
+    update(&mut model, Msg::Clear, &mut data);
+
+    assert!(data.errors.is_empty());
+    assert_eq!(data.last_error(), None);
+    assert_eq!(data.error_count(), 1);
+    assert!(!model.detail);
+    assert_eq!(model.state.selected(), None);
+}
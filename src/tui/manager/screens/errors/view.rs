@@ -0,0 +1,170 @@
+//! Errors タブの view（描画）
+
+use super::model::ErrorsScreenModel;
+use crate::application::render_warnings;
+use crate::tui::manager::core::layout::{framed_layout, outer_rect};
+use crate::tui::manager::core::style::{bordered_block, highlight_line, selectable_list};
+use crate::tui::manager::core::{
+    render_empty_state, render_filter_bar, render_tab_bar, truncate_for_list, DataStore,
+    ErrorEntry, Tab, TabBadges, Theme,
+};
+use ratatui::prelude::*;
+use ratatui::widgets::{Clear, ListItem, Paragraph, Wrap};
+
+/// 一覧の日時表示形式
+const TIME_FORMAT: &str = "%H:%M:%S";
+
+/// 全文表示の日時表示形式
+const DETAIL_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 画面を描画
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to render into.
+/// * `model` - Errors tab model to render.
+/// * `data` - Shared data store providing the error history and warnings.
+/// * `badges` - Counts shown in the tab bar.
+/// * `filter_text` - Current filter input text.
+/// * `filter_focused` - Whether the filter bar has focus.
+pub fn view(
+    f: &mut Frame,
+    model: &ErrorsScreenModel,
+    data: &DataStore,
+    badges: &TabBadges,
+    filter_text: &str,
+    filter_focused: bool,
+) {
+    let theme = Theme::current();
+    let outer = outer_rect(f.area());
+    f.render_widget(Clear, f.area());
+
+    let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
+
+    render_tab_bar(f, tabs_area, Tab::Errors, Some(badges));
+
+    // フィルタバー（Errors タブではフィルタ機能は未対応、UI のみ表示）
+    render_filter_bar(f, filter_area, filter_text, filter_focused);
+
+    let help_text = match model.selected(data).filter(|_| model.detail) {
+        Some(entry) => {
+            let content = Paragraph::new(detail_text(entry))
+                .block(bordered_block(" Error "))
+                .wrap(Wrap { trim: false });
+            f.render_widget(content, content_area);
+            " c: clear | Esc: back | q: quit"
+        }
+        None => {
+            view_list(f, model, data, content_area);
+            " Enter: details | c: clear | Tab: switch | ↑↓: move | q: quit"
+        }
+    };
+
+    let help = Paragraph::new(help_text).style(Style::default().fg(theme.muted));
+    f.render_widget(help, help_area);
+}
+
+/// エラー一覧（新しい順）と警告（marketplace.json・直前のインストール）を描画
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to render into.
+/// * `model` - Errors tab model providing the cursor.
+/// * `data` - Shared data store providing the error history and warnings.
+/// * `area` - Content area.
+fn view_list(f: &mut Frame, model: &ErrorsScreenModel, data: &DataStore, area: Rect) {
+    let theme = Theme::current();
+    let warning_text = warning_text(data);
+    let warnings_height = if warning_text.is_empty() {
+        0
+    } else {
+        (warning_text.lines().count() as u16 + 2).min(area.height / 2)
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(warnings_height)])
+        .split(area);
+    let (list_area, warnings_area) = (chunks[0], chunks[1]);
+
+    let title = format!(" Errors ({}) ", data.errors.len());
+    if data.errors.is_empty() {
+        render_empty_state(f, list_area, &title, "", "No errors");
+    } else {
+        let selected = model.state.selected();
+        let items: Vec<ListItem> = data
+            .errors
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, entry)| {
+                let row = truncate_for_list(list_area.width, error_row(entry));
+                let span = Span::styled(row, Style::default().fg(theme.error));
+                ListItem::new(highlight_line(vec![span], Some(i) == selected))
+            })
+            .collect();
+        let list = selectable_list(items, &title);
+        let mut state = model.state.clone();
+        f.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    if warnings_height > 0 {
+        let count = data.warnings.len() + data.last_warnings.len();
+        let title = format!(" Warnings ({}) ", count);
+        let warnings = Paragraph::new(warning_text)
+            .block(bordered_block(&title))
+            .style(Style::default().fg(theme.muted));
+        f.render_widget(warnings, warnings_area);
+    }
+}
+
+/// 一覧の 1 行（時刻・発生タブ・1 行目、複数行なら残りの行数）
+///
+/// # Arguments
+///
+/// * `entry` - Error to describe.
+pub(super) fn error_row(entry: &ErrorEntry) -> String {
+    let mut lines = entry.message.lines();
+    let first = lines.next().unwrap_or_default();
+    let rest = lines.count();
+    let more = if rest > 0 {
+        format!(" (+{} lines)", rest)
+    } else {
+        String::new()
+    };
+    format!(
+        "{}  [{}]  {}{}",
+        entry.timestamp.format(TIME_FORMAT),
+        entry.source_tab.title(),
+        first,
+        more
+    )
+}
+
+/// 全文表示の本文（日時・発生タブの後にメッセージ全文）
+///
+/// # Arguments
+///
+/// * `entry` - Error to show.
+pub(super) fn detail_text(entry: &ErrorEntry) -> String {
+    format!(
+        "{}  [{}]\n\n{}",
+        entry.timestamp.format(DETAIL_TIME_FORMAT),
+        entry.source_tab.title(),
+        entry.message
+    )
+}
+
+/// marketplace.json の警告（1 行 1 件）の後に、直前のインストールの警告を種別ごとにまとめて続ける
+///
+/// # Arguments
+///
+/// * `data` - Shared data store providing the warnings.
+pub(super) fn warning_text(data: &DataStore) -> String {
+    let mut lines: Vec<String> = data.warnings.iter().map(|w| w.to_string()).collect();
+    lines.extend(render_warnings("Last install", &data.last_warnings));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "view_test.rs"]
+mod view_test;
@@ -0,0 +1,76 @@
+use super::*;
+use crate::application::{Warning, WarningCategory};
+use crate::marketplace::ValidationIssue;
+use crate::tui::manager::core::ManifestWarning;
+use chrono::{Local, TimeZone};
+
+fn entry(message: &str) -> ErrorEntry {
+    ErrorEntry {
+        timestamp: Local.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+        source_tab: Tab::Marketplaces,
+        message: message.to_string(),
+    }
+}
+
+fn warning(marketplace: &str, location: &str) -> ManifestWarning {
+    ManifestWarning {
+        marketplace: marketplace.to_string(),
+        issue: ValidationIssue::warning(location, "unsupported source type 'gitlab'"),
+    }
+}
+
+#[test]
+fn error_row_shows_time_tab_and_first_line() {
+    assert_eq!(
+        error_row(&entry("Failed to update 'alpha': offline")),
+        "03:04:05  [Marketplaces]  Failed to update 'alpha': offline"
+    );
+}
+
+#[test]
+fn error_row_counts_remaining_lines() {
+    assert_eq!(
+        error_row(&entry("2 plugins failed during batch update:\na: x\nb: y")),
+        "03:04:05  [Marketplaces]  2 plugins failed during batch update: (+2 lines)"
+    );
+}
+
+#[test]
+fn detail_text_shows_full_message() {
+    assert_eq!(
+        detail_text(&entry("first\nsecond")),
+        "2026-01-02 03:04:05  [Marketplaces]\n\nfirst\nsecond"
+    );
+}
+
+#[test]
+fn warning_text_lists_manifest_warnings() {
+    let (_tmp, mut data) = DataStore::for_test(vec![], vec![], None);
+    data.warnings = vec![
+        warning("catalog", "plugins[1].source.source"),
+        warning("tools", "plugins[0].source.source"),
+    ];
+
+    assert_eq!(
+        warning_text(&data),
+        "catalog: plugins[1].source.source: unsupported source type 'gitlab'\ntools: plugins[0].source.source: unsupported source type 'gitlab'"
+    );
+}
+
+#[test]
+fn warning_text_groups_last_install_warnings_after_manifest_warnings() {
+    let (_tmp, mut data) = DataStore::for_test(vec![], vec![], None);
+    data.warnings = vec![warning("catalog", "plugins[1].source.source")];
+    data.last_warnings = vec![Warning {
+        category: WarningCategory::Symlink,
+        message: "codex Skill: lint: skipped 1 symlink".to_string(),
+    }];
+
+    assert_eq!(
+        warning_text(&data),
+        "catalog: plugins[1].source.source: unsupported source type 'gitlab'\n\
+         Last install (1):\n\
+         \x20 Symlinks (1):\n\
+         \x20   - codex Skill: lint: skipped 1 symlink"
+    );
+}
@@ -8,5 +8,5 @@ mod update;
 mod view;
 
 pub use model::{key_to_msg, CacheState, InstalledScreenModel, Msg};
-pub use update::update;
+pub use update::{clamp_selection, update};
 pub use view::view;
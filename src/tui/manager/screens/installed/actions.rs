@@ -5,10 +5,17 @@
 
 use super::model::UpdateStatusDisplay;
 use crate::application;
-use crate::plugin::{update_plugin, PackageCache, UpdateStatus};
-use crate::tui::manager::core::PluginKey;
+use crate::component::Scope;
+use crate::install::{self, PlaceRequest};
+use crate::output::hint::post_deploy_hint_line;
+use crate::plugin::{
+    update_plugin, CachedPackage, ComponentConflict, MarketplaceContent, PackageCache,
+    UpdateStatus, UNKNOWN_GIT_VALUE,
+};
+use crate::target::{parse_target, Target, TargetRegistry};
+use crate::tui::manager::core::dev::DEV_MARKETPLACE;
+use crate::tui::manager::core::{DevPlugin, PluginId};
 use crate::tui::output_suppress::OutputSuppressGuard;
-use std::env;
 use std::path::Path;
 
 /// アクション実行結果
@@ -39,45 +46,87 @@ fn new_cache() -> Result<PackageCache, String> {
 ///
 /// # Arguments
 ///
-/// * `plugin_name` - Target plugin id.
-/// * `marketplace` - Optional marketplace name the plugin belongs to.
-pub fn disable_plugin(plugin_name: &str, marketplace: Option<&str>) -> ActionOutcome {
+/// * `id` - Target plugin id.
+pub fn disable_plugin(id: &PluginId) -> ActionOutcome {
     let cache = match new_cache() {
         Ok(c) => c,
         Err(e) => return ActionOutcome::Error(e),
     };
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
-    application::disable_plugin(&cache, plugin_name, marketplace, &project_root, None).into()
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
+    application::disable_plugin(&cache, id.name(), id.marketplace(), &project_root, None).into()
 }
 
 /// プラグインを Uninstall（デプロイ先 + キャッシュ削除）
 ///
 /// # Arguments
 ///
-/// * `plugin_name` - Target plugin id.
-/// * `marketplace` - Optional marketplace name the plugin belongs to.
-pub fn uninstall_plugin(plugin_name: &str, marketplace: Option<&str>) -> ActionOutcome {
+/// * `id` - Target plugin id.
+pub fn uninstall_plugin(id: &PluginId) -> ActionOutcome {
     let cache = match new_cache() {
         Ok(c) => c,
         Err(e) => return ActionOutcome::Error(e),
     };
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
-    application::uninstall_plugin(&cache, plugin_name, marketplace, &project_root).into()
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
+    application::uninstall_plugin(&cache, id.name(), id.marketplace(), &project_root).into()
 }
 
 /// プラグインを Enable（キャッシュからデプロイ先に配置）
 ///
+/// 別プラグインとの衝突でスキップしたコンポーネントの件数と、配置先ターゲットの
+/// 反映手順の案内（1 行）を併せて返す。
+///
 /// # Arguments
 ///
-/// * `plugin_name` - Target plugin id.
-/// * `marketplace` - Optional marketplace name the plugin belongs to.
-pub fn enable_plugin(plugin_name: &str, marketplace: Option<&str>) -> ActionOutcome {
+/// * `id` - Target plugin id.
+pub fn enable_plugin(id: &PluginId) -> (ActionOutcome, Option<String>) {
     let cache = match new_cache() {
         Ok(c) => c,
-        Err(e) => return ActionOutcome::Error(e),
+        Err(e) => return (ActionOutcome::Error(e), None),
     };
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
-    application::enable_plugin(&cache, plugin_name, marketplace, &project_root, None).into()
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
+    let result = application::enable_plugin_checked(
+        &cache,
+        id.name(),
+        id.marketplace(),
+        &project_root,
+        application::DeployFilter::default(),
+        false,
+    );
+    let notice = deploy_notice(
+        &result.conflicts,
+        &result.outcome.affected_targets.target_names(),
+    );
+    (result.outcome.into(), notice)
+}
+
+/// 配置後に表示する 1 行（衝突でスキップした件数と反映手順の案内）
+///
+/// # Arguments
+///
+/// * `conflicts` - Components skipped because another plugin owns the destination.
+/// * `deployed_targets` - Names of the targets components were deployed to.
+pub fn deploy_notice<S: AsRef<str>>(
+    conflicts: &[ComponentConflict],
+    deployed_targets: &[S],
+) -> Option<String> {
+    match (
+        ComponentConflict::summary(conflicts),
+        post_deploy_hint_line(deployed_targets),
+    ) {
+        (Some(conflicts), Some(hint)) => Some(format!("{} / {}", conflicts, hint)),
+        (conflicts, hint) => conflicts.or(hint),
+    }
+}
+
+/// バッチ更新の結果
+#[derive(Debug, Default)]
+pub struct BatchUpdateOutcome {
+    /// プラグインごとの更新結果（入力順）
+    pub statuses: Vec<(PluginId, UpdateStatusDisplay)>,
+    /// 再デプロイしたターゲット名（全プラグイン分、重複あり）
+    pub deployed_targets: Vec<String>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント（全プラグイン分）
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 /// バッチ更新を実行
@@ -87,56 +136,69 @@ pub fn enable_plugin(plugin_name: &str, marketplace: Option<&str>) -> ActionOutc
 ///
 /// # Arguments
 ///
-/// * `keys` - Plugin keys to update in order.
-pub fn batch_update_plugins(keys: &[PluginKey]) -> Vec<(PluginKey, UpdateStatusDisplay)> {
-    let project_root = env::current_dir().unwrap_or_else(|_| ".".into());
+/// * `ids` - Plugins to update in order.
+pub fn batch_update_plugins(ids: &[PluginId]) -> BatchUpdateOutcome {
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
 
     // stdout/stderr をリダイレクト
     // Note: ガード作成失敗時は抑制なしで続行する（TUI 画面が乱れる可能性あり）。
     // TUI 代替スクリーン上では eprintln! が表示されないため、ログ出力は行わない。
     let _guard = OutputSuppressGuard::new();
 
-    keys.iter()
-        .map(|key| {
-            let status = run_update_plugin(key, &project_root);
-            (key.clone(), status)
-        })
-        .collect()
+    let mut outcome = BatchUpdateOutcome::default();
+    for id in ids {
+        let (status, deployed_targets, conflicts) = run_update_plugin(id, &project_root);
+        outcome.statuses.push((id.clone(), status));
+        outcome.deployed_targets.extend(deployed_targets);
+        outcome.conflicts.extend(conflicts);
+    }
+    outcome
 }
 
-/// 単一プラグインの更新を同期的に実行
+/// 単一プラグインの更新を同期的に実行し、結果と再デプロイしたターゲット名、衝突を返す
 ///
 /// # Arguments
 ///
-/// * `key` - Target plugin key (`marketplace` + `cache_id`).
+/// * `id` - Target plugin id.
 /// * `project_root` - Project root directory used for deployment paths.
-fn run_update_plugin(key: &PluginKey, project_root: &Path) -> UpdateStatusDisplay {
+fn run_update_plugin(
+    id: &PluginId,
+    project_root: &Path,
+) -> (UpdateStatusDisplay, Vec<String>, Vec<ComponentConflict>) {
     let handle = match tokio::runtime::Handle::try_current() {
         Ok(h) => h,
         Err(_) => {
-            return UpdateStatusDisplay::Failed("No Tokio runtime available".to_string());
+            let status = UpdateStatusDisplay::Failed("No Tokio runtime available".to_string());
+            return (status, Vec::new(), Vec::new());
         }
     };
 
     let cache = match new_cache() {
         Ok(c) => c,
-        Err(msg) => return UpdateStatusDisplay::Failed(msg),
+        Err(msg) => return (UpdateStatusDisplay::Failed(msg), Vec::new(), Vec::new()),
     };
 
     let result = tokio::task::block_in_place(|| {
         handle.block_on(update_plugin(
             &cache,
-            &key.cache_id,
-            key.marketplace.as_deref(),
+            id.name(),
+            id.marketplace(),
             project_root,
             None,
+            None,
+            false,
+            false,
         ))
     });
 
-    match result.status {
-        UpdateStatus::Updated { .. } => UpdateStatusDisplay::Updated,
+    let status = match result.status {
+        UpdateStatus::Updated { .. } | UpdateStatus::Redeployed => UpdateStatusDisplay::Updated,
         UpdateStatus::AlreadyUpToDate => UpdateStatusDisplay::AlreadyUpToDate,
         UpdateStatus::Skipped { reason } => UpdateStatusDisplay::Skipped(reason),
+        // TUI からはチャンネルを切り替えないため発生しないが、match 網羅のため対応
+        UpdateStatus::DowngradeRequired { from_ref, to_ref } => {
+            UpdateStatusDisplay::Skipped(format!("would downgrade {} -> {}", from_ref, to_ref))
+        }
         UpdateStatus::Failed => {
             UpdateStatusDisplay::Failed(result.error.unwrap_or_else(|| "Unknown error".to_string()))
         }
@@ -144,5 +206,97 @@ fn run_update_plugin(key: &PluginKey, project_root: &Path) -> UpdateStatusDispla
         UpdateStatus::RolledBack => {
             UpdateStatusDisplay::Failed(result.error.unwrap_or_else(|| "Rolled back".to_string()))
         }
+    };
+    (status, result.deployed_targets, result.conflicts)
+}
+
+/// dev プラグイン再デプロイの結果
+#[derive(Debug)]
+pub enum DevDeployOutcome {
+    /// 配置を実行した（コンポーネント単位の失敗を含みうる）
+    Deployed {
+        /// 配置に成功したコンポーネント数
+        placed: usize,
+        /// 失敗したコンポーネントの説明（`target kind: name - error`）
+        failures: Vec<String>,
+    },
+    /// 配置前に失敗した
+    Error(String),
+}
+
+/// dev プラグインをプロジェクトスコープへ再デプロイ
+///
+/// キャッシュを経由せず、dev プラグインのディレクトリから直接
+/// `targets.json` に登録済みの全ターゲットへ配置する。
+/// `.plm-meta.json` は書き込まない（通常の install/uninstall フローと独立させるため）。
+///
+/// # Arguments
+///
+/// * `dev` - Development plugin opened via `--dev`.
+pub fn deploy_dev_plugin(dev: &DevPlugin) -> DevDeployOutcome {
+    let Some(manifest) = dev.manifest() else {
+        return DevDeployOutcome::Error("dev plugin is not loaded".to_string());
+    };
+
+    let target_kinds = match TargetRegistry::new().and_then(|mut r| r.list()) {
+        Ok(kinds) => kinds,
+        Err(e) => return DevDeployOutcome::Error(format!("Failed to load targets: {}", e)),
+    };
+    if target_kinds.is_empty() {
+        return DevDeployOutcome::Error(
+            "No targets configured. Run 'plm target add <target>' first".to_string(),
+        );
+    }
+    let targets: Vec<Box<dyn Target>> = match target_kinds
+        .iter()
+        .map(|k| parse_target(k.as_str()))
+        .collect::<crate::error::Result<Vec<_>>>()
+    {
+        Ok(t) => t,
+        Err(e) => return DevDeployOutcome::Error(e.to_string()),
+    };
+
+    let package = CachedPackage {
+        name: manifest.name.clone(),
+        id: None,
+        marketplace: Some(DEV_MARKETPLACE.to_string()),
+        path: dev.root().to_path_buf(),
+        manifest: manifest.clone(),
+        git_ref: UNKNOWN_GIT_VALUE.to_string(),
+        commit_sha: UNKNOWN_GIT_VALUE.to_string(),
+        marketplace_manifest: None,
+    };
+    let content = match MarketplaceContent::try_from(package) {
+        Ok(c) => c,
+        Err(e) => return DevDeployOutcome::Error(e.to_string()),
+    };
+    let scanned = match install::scan_plugin(&content, None) {
+        Ok(s) => s,
+        Err(e) => return DevDeployOutcome::Error(e),
+    };
+
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
+    let _guard = OutputSuppressGuard::new();
+    let result = install::place_plugin(&PlaceRequest {
+        scanned: &scanned,
+        targets: &targets,
+        scope: Scope::Project,
+        project_root: &project_root,
+        enable_codex_hooks_flag: false,
+        overwrite: false,
+    });
+
+    DevDeployOutcome::Deployed {
+        placed: result.successes.len(),
+        failures: result
+            .failures
+            .iter()
+            .map(|f| {
+                format!(
+                    "{} {}: {} - {}",
+                    f.target, f.component_kind, f.component_name, f.error
+                )
+            })
+            .collect(),
     }
 }
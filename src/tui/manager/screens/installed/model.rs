@@ -3,7 +3,8 @@
 //! 画面状態とメッセージ型を定義。
 
 use crate::component::ComponentKind;
-use crate::tui::manager::core::{DataStore, PluginId, SelectionState};
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{DataStore, ListMode, PluginId, SelectionState, SortKey, Theme};
 use crossterm::event::KeyCode;
 use ratatui::prelude::*;
 use ratatui::widgets::ListState;
@@ -29,6 +30,8 @@ pub enum UpdateStatusDisplay {
 pub struct CacheState {
     pub selected_plugin_id: Option<PluginId>,
     pub marked_ids: HashSet<PluginId>,
+    pub sort_key: SortKey,
+    pub list_mode: ListMode,
 }
 
 /// プラグイン詳細画面のアクション
@@ -93,41 +96,52 @@ impl DetailAction {
     }
 
     pub fn style(&self) -> Style {
+        let theme = Theme::current();
         match self {
-            DetailAction::UpdateNow => Style::default().fg(Color::Green),
-            DetailAction::EnablePlugin => Style::default().fg(Color::Green),
-            DetailAction::Uninstall => Style::default().fg(Color::Red),
+            DetailAction::UpdateNow => Style::default().fg(theme.success),
+            DetailAction::EnablePlugin => Style::default().fg(theme.success),
+            DetailAction::Uninstall => Style::default().fg(theme.error),
             _ => Style::default(),
         }
     }
 }
 
+/// 確認ダイアログで実行を待つ破壊的アクション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfirmAction {
+    /// 詳細画面からのアンインストール
+    Uninstall(PluginId),
+    /// マーク済みプラグインの一括更新（`U`）
+    BatchUpdate,
+    /// 全プラグインの更新（`A`）
+    UpdateAll,
+}
+
 /// Installed タブの画面状態
+///
+/// 下位画面へ進むときは遷移元を丸ごと履歴スタックに積むため `Clone` にしている。
+#[derive(Clone)]
 pub enum InstalledScreenModel {
     /// プラグイン一覧画面
     PluginList {
         selection: SelectionState<PluginId>,
         marked_ids: HashSet<PluginId>,
         update_statuses: HashMap<PluginId, UpdateStatusDisplay>,
+        /// 一覧の並び順（`s` キーで切替）
+        sort_key: SortKey,
+        /// 一覧の表示モード（`v` キーで切替）
+        list_mode: ListMode,
     },
     /// プラグイン詳細画面
     PluginDetail {
         plugin_id: PluginId,
         state: ListState,
-        /// PluginList から遷移時のマーク状態（戻るときに復元）
-        saved_marked_ids: HashSet<PluginId>,
-        /// PluginList から遷移時の更新ステータス（戻るときに復元）
-        saved_update_statuses: HashMap<PluginId, UpdateStatusDisplay>,
     },
     /// コンポーネント種別選択画面
     ComponentTypes {
         plugin_id: PluginId,
         selected_kind_idx: usize,
         state: ListState,
-        /// PluginList から遷移チェーンで引き継いだマーク状態
-        saved_marked_ids: HashSet<PluginId>,
-        /// PluginList から遷移チェーンで引き継いだ更新ステータス
-        saved_update_statuses: HashMap<PluginId, UpdateStatusDisplay>,
     },
     /// コンポーネント一覧画面
     ComponentList {
@@ -135,10 +149,13 @@ pub enum InstalledScreenModel {
         kind: ComponentKind,
         selected_idx: usize,
         state: ListState,
-        /// PluginList から遷移チェーンで引き継いだマーク状態
-        saved_marked_ids: HashSet<PluginId>,
-        /// PluginList から遷移チェーンで引き継いだ更新ステータス
-        saved_update_statuses: HashMap<PluginId, UpdateStatusDisplay>,
+    },
+    /// 破壊的アクションの確認ダイアログ（影響プレビュー付き）
+    Confirm {
+        action: ConfirmAction,
+        preview: PreviewState,
+        /// 確認を開いた画面（キャンセル・実行時にここへ戻る）
+        parent: Box<InstalledScreenModel>,
     },
 }
 
@@ -149,12 +166,18 @@ impl InstalledScreenModel {
     ///
     /// * `data` - Data store providing the installed plugin list.
     pub fn new(data: &DataStore) -> Self {
-        let selected_id = data.plugins.first().map(|p| p.id().to_string());
+        let sort_key = SortKey::default();
+        let selected_id = data
+            .sorted_plugins(sort_key, "")
+            .first()
+            .map(|p| PluginId::of(p));
         let selected_index = selected_id.as_ref().map(|_| 0);
         InstalledScreenModel::PluginList {
             selection: SelectionState::new(selected_id, selected_index),
             marked_ids: HashSet::new(),
             update_statuses: HashMap::new(),
+            sort_key,
+            list_mode: ListMode::default(),
         }
     }
 
@@ -165,20 +188,17 @@ impl InstalledScreenModel {
     /// * `data` - Data store providing the installed plugin list.
     /// * `cache` - Previously saved cache state to restore from.
     pub fn from_cache(data: &DataStore, cache: &CacheState) -> Self {
+        let sorted = data.sorted_plugins(cache.sort_key, "");
         let selected_id = cache
             .selected_plugin_id
             .clone()
             .filter(|id| data.find_plugin(id).is_some())
-            .or_else(|| data.plugins.first().map(|p| p.id().to_string()));
+            .or_else(|| sorted.first().map(|p| PluginId::of(p)));
 
         let index = selected_id
             .as_ref()
-            .and_then(|id| data.plugin_index(id))
-            .or(if data.plugins.is_empty() {
-                None
-            } else {
-                Some(0)
-            });
+            .and_then(|id| sorted.iter().position(|p| id.matches(p)))
+            .or(if sorted.is_empty() { None } else { Some(0) });
 
         // マーク状態を復元（DataStore に存在しないプラグインIDは除外）
         let marked_ids = cache
@@ -192,6 +212,8 @@ impl InstalledScreenModel {
             selection: SelectionState::new(selected_id, index),
             marked_ids,
             update_statuses: HashMap::new(),
+            sort_key: cache.sort_key,
+            list_mode: cache.list_mode,
         }
     }
 
@@ -201,39 +223,31 @@ impl InstalledScreenModel {
             InstalledScreenModel::PluginList {
                 selection,
                 marked_ids,
+                sort_key,
+                list_mode,
                 ..
             } => CacheState {
                 selected_plugin_id: selection.selected_id().cloned(),
                 marked_ids: marked_ids.clone(),
+                sort_key: *sort_key,
+                list_mode: *list_mode,
             },
-            InstalledScreenModel::PluginDetail {
-                plugin_id,
-                saved_marked_ids,
-                ..
-            } => CacheState {
+            // タブ切替はトップレベルでのみ可能なため、下位画面ではマーク状態を持たない
+            InstalledScreenModel::PluginDetail { plugin_id, .. }
+            | InstalledScreenModel::ComponentTypes { plugin_id, .. }
+            | InstalledScreenModel::ComponentList { plugin_id, .. } => CacheState {
                 selected_plugin_id: Some(plugin_id.clone()),
-                marked_ids: saved_marked_ids.clone(),
-            },
-            InstalledScreenModel::ComponentTypes {
-                plugin_id,
-                saved_marked_ids,
-                ..
-            } => CacheState {
-                selected_plugin_id: Some(plugin_id.clone()),
-                marked_ids: saved_marked_ids.clone(),
-            },
-            InstalledScreenModel::ComponentList {
-                plugin_id,
-                saved_marked_ids,
-                ..
-            } => CacheState {
-                selected_plugin_id: Some(plugin_id.clone()),
-                marked_ids: saved_marked_ids.clone(),
+                marked_ids: HashSet::new(),
+                sort_key: SortKey::default(),
+                list_mode: ListMode::default(),
             },
+            InstalledScreenModel::Confirm { parent, .. } => parent.to_cache(),
         }
     }
 
     /// トップレベル（タブ切替可能）かどうか
+    ///
+    /// 確認ダイアログ中は一覧から開いた場合でもタブを切り替えない。
     pub fn is_top_level(&self) -> bool {
         matches!(self, InstalledScreenModel::PluginList { .. })
     }
@@ -245,6 +259,7 @@ impl InstalledScreenModel {
             InstalledScreenModel::PluginDetail { state, .. } => Some(state),
             InstalledScreenModel::ComponentTypes { state, .. } => Some(state),
             InstalledScreenModel::ComponentList { state, .. } => Some(state),
+            InstalledScreenModel::Confirm { .. } => None,
         }
     }
 }
@@ -260,6 +275,20 @@ pub enum Msg {
     BatchUpdate,
     UpdateAll,
     ExecuteBatch,
+    /// 確認ダイアログの影響プレビューを計算する（確認を開いた後の phase2）
+    CalculatePreview,
+    /// 確認ダイアログで実行する
+    Confirm,
+    /// 確認ダイアログを閉じる
+    CancelConfirm,
+    /// ソートキーを切り替える（名前 → マーケットプレイス → バージョン → 更新日時）
+    CycleSort,
+    /// 一覧の表示モードを切り替える（コンパクト ⇔ 説明付きの詳細表示）
+    ToggleListMode,
+    /// dev プラグインを再スキャン（dev モード時のみ有効）
+    DevReload,
+    /// dev プラグインをプロジェクトへ再デプロイ（dev モード時のみ有効）
+    DevRedeploy,
 }
 
 /// キーコードをメッセージに変換
@@ -268,11 +297,20 @@ pub enum Msg {
 /// j/k キーバインドはフィルタ入力と競合しない。
 /// Esc はフィルタフォーカス中は app.rs 側で FilterClear として処理されるが、
 /// フィルタがフォーカスされていない通常状態では、トップレベルか否かに関わらず Back を返す。
+/// 確認ダイアログ中は y / Enter で実行、n / Esc で閉じる以外のキーを受け付けない。
 ///
 /// # Arguments
 ///
 /// * `key` - Raw key code received from crossterm.
-pub fn key_to_msg(key: KeyCode) -> Option<Msg> {
+/// * `model` - Current Installed tab model used to disambiguate bindings.
+pub fn key_to_msg(key: KeyCode, model: &InstalledScreenModel) -> Option<Msg> {
+    if let InstalledScreenModel::Confirm { .. } = model {
+        return match key {
+            KeyCode::Char('y') | KeyCode::Enter => Some(Msg::Confirm),
+            KeyCode::Char('n') | KeyCode::Esc => Some(Msg::CancelConfirm),
+            _ => None,
+        };
+    }
     match key {
         KeyCode::Up | KeyCode::Char('k') => Some(Msg::Up),
         KeyCode::Down | KeyCode::Char('j') => Some(Msg::Down),
@@ -282,6 +320,10 @@ pub fn key_to_msg(key: KeyCode) -> Option<Msg> {
         KeyCode::Char('a') => Some(Msg::ToggleAllMarks),
         KeyCode::Char('U') => Some(Msg::BatchUpdate),
         KeyCode::Char('A') => Some(Msg::UpdateAll),
+        KeyCode::Char('s') => Some(Msg::CycleSort),
+        KeyCode::Char('v') => Some(Msg::ToggleListMode),
+        KeyCode::Char('r') => Some(Msg::DevReload),
+        KeyCode::Char('d') => Some(Msg::DevRedeploy),
         _ => None,
     }
 }
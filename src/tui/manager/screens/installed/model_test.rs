@@ -1,8 +1,9 @@
 use crossterm::event::KeyCode;
 
-use super::{key_to_msg, CacheState, InstalledScreenModel, Msg};
+use super::{key_to_msg, CacheState, ConfirmAction, InstalledScreenModel, Msg};
 use crate::application::InstalledPlugin;
-use crate::tui::manager::core::DataStore;
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{DataStore, ListMode, PluginId, SortKey};
 use std::collections::HashSet;
 
 fn make_plugin(name: &str) -> InstalledPlugin {
@@ -16,6 +17,11 @@ fn make_plugin(name: &str) -> InstalledPlugin {
     )
 }
 
+/// 直接 GitHub install（`github` マーケットプレイス）のプラグインID
+fn id(name: &str) -> PluginId {
+    PluginId::new(None, name)
+}
+
 fn make_data(names: &[&str]) -> (tempfile::TempDir, DataStore) {
     DataStore::for_test(names.iter().map(|n| make_plugin(n)).collect(), vec![], None)
 }
@@ -24,36 +30,106 @@ fn make_data(names: &[&str]) -> (tempfile::TempDir, DataStore) {
 // key_to_msg テスト
 // ============================================================================
 
+/// キー割り当ての判定に使う一覧画面
+fn list_model() -> InstalledScreenModel {
+    let (_temp_dir, data) = make_data(&["plugin-a"]);
+    InstalledScreenModel::new(&data)
+}
+
 #[test]
 fn space_key_returns_toggle_mark() {
-    let msg = key_to_msg(KeyCode::Char(' '));
+    let msg = key_to_msg(KeyCode::Char(' '), &list_model());
     assert!(matches!(msg, Some(Msg::ToggleMark)));
 }
 
+#[test]
+fn r_key_returns_dev_reload() {
+    let msg = key_to_msg(KeyCode::Char('r'), &list_model());
+    assert!(matches!(msg, Some(Msg::DevReload)));
+}
+
+#[test]
+fn d_key_returns_dev_redeploy() {
+    let msg = key_to_msg(KeyCode::Char('d'), &list_model());
+    assert!(matches!(msg, Some(Msg::DevRedeploy)));
+}
+
+#[test]
+fn s_key_returns_cycle_sort() {
+    let msg = key_to_msg(KeyCode::Char('s'), &list_model());
+    assert!(matches!(msg, Some(Msg::CycleSort)));
+}
+
+#[test]
+fn v_key_returns_toggle_list_mode() {
+    let msg = key_to_msg(KeyCode::Char('v'), &list_model());
+    assert!(matches!(msg, Some(Msg::ToggleListMode)));
+}
+
 #[test]
 fn a_key_returns_toggle_all_marks() {
-    let msg = key_to_msg(KeyCode::Char('a'));
+    let msg = key_to_msg(KeyCode::Char('a'), &list_model());
     assert!(matches!(msg, Some(Msg::ToggleAllMarks)));
 }
 
 #[test]
 fn shift_u_key_returns_batch_update() {
-    let msg = key_to_msg(KeyCode::Char('U'));
+    let msg = key_to_msg(KeyCode::Char('U'), &list_model());
     assert!(matches!(msg, Some(Msg::BatchUpdate)));
 }
 
 #[test]
 fn shift_a_key_returns_update_all() {
-    let msg = key_to_msg(KeyCode::Char('A'));
+    let msg = key_to_msg(KeyCode::Char('A'), &list_model());
     assert!(matches!(msg, Some(Msg::UpdateAll)));
 }
 
 #[test]
 fn existing_keys_still_work() {
-    assert!(matches!(key_to_msg(KeyCode::Up), Some(Msg::Up)));
-    assert!(matches!(key_to_msg(KeyCode::Down), Some(Msg::Down)));
-    assert!(matches!(key_to_msg(KeyCode::Enter), Some(Msg::Enter)));
-    assert!(matches!(key_to_msg(KeyCode::Esc), Some(Msg::Back)));
+    assert!(matches!(
+        key_to_msg(KeyCode::Up, &list_model()),
+        Some(Msg::Up)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Down, &list_model()),
+        Some(Msg::Down)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Enter, &list_model()),
+        Some(Msg::Enter)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Esc, &list_model()),
+        Some(Msg::Back)
+    ));
+}
+
+#[test]
+fn confirm_dialog_only_accepts_yes_and_no() {
+    let confirm = InstalledScreenModel::Confirm {
+        action: ConfirmAction::UpdateAll,
+        preview: PreviewState::Calculating,
+        parent: Box::new(list_model()),
+    };
+
+    assert!(matches!(
+        key_to_msg(KeyCode::Char('y'), &confirm),
+        Some(Msg::Confirm)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Enter, &confirm),
+        Some(Msg::Confirm)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Char('n'), &confirm),
+        Some(Msg::CancelConfirm)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Esc, &confirm),
+        Some(Msg::CancelConfirm)
+    ));
+    assert!(key_to_msg(KeyCode::Char('U'), &confirm).is_none());
+    assert!(!confirm.is_top_level());
 }
 
 // ============================================================================
@@ -67,29 +143,30 @@ fn to_cache_preserves_marked_ids() {
 
     // マーク状態を設定
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &mut model {
-        marked_ids.insert("plugin-a".to_string());
+        marked_ids.insert(id("plugin-a"));
     }
 
     let cache = model.to_cache();
-    assert!(cache.marked_ids.contains("plugin-a"));
-    assert!(!cache.marked_ids.contains("plugin-b"));
+    assert!(cache.marked_ids.contains(&id("plugin-a")));
+    assert!(!cache.marked_ids.contains(&id("plugin-b")));
 }
 
 #[test]
 fn from_cache_restores_marked_ids() {
     let (_temp_dir, data) = make_data(&["plugin-a", "plugin-b"]);
     let mut marked = HashSet::new();
-    marked.insert("plugin-a".to_string());
+    marked.insert(id("plugin-a"));
 
     let cache = CacheState {
         selected_plugin_id: None,
         marked_ids: marked,
+        ..Default::default()
     };
 
     let model = InstalledScreenModel::from_cache(&data, &cache);
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
-        assert!(marked_ids.contains("plugin-a"));
-        assert!(!marked_ids.contains("plugin-b"));
+        assert!(marked_ids.contains(&id("plugin-a")));
+        assert!(!marked_ids.contains(&id("plugin-b")));
     } else {
         panic!("Expected PluginList");
     }
@@ -103,22 +180,44 @@ fn from_cache_restores_marked_ids() {
 fn from_cache_excludes_missing_plugins_from_marked_ids() {
     let (_temp_dir, data) = make_data(&["plugin-a"]);
     let mut marked = HashSet::new();
-    marked.insert("plugin-a".to_string());
-    marked.insert("deleted-plugin".to_string());
+    marked.insert(id("plugin-a"));
+    marked.insert(id("deleted-plugin"));
 
     let cache = CacheState {
         selected_plugin_id: None,
         marked_ids: marked,
+        ..Default::default()
     };
 
     let model = InstalledScreenModel::from_cache(&data, &cache);
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
-        assert!(marked_ids.contains("plugin-a"));
+        assert!(marked_ids.contains(&id("plugin-a")));
         assert!(
-            !marked_ids.contains("deleted-plugin"),
+            !marked_ids.contains(&id("deleted-plugin")),
             "Deleted plugin should be excluded from restored marks"
         );
     } else {
         panic!("Expected PluginList");
     }
 }
+
+#[test]
+fn from_cache_restores_sort_key_and_selected_position() {
+    let (_temp_dir, data) = make_data(&["plugin-a", "plugin-b"]);
+    let cache = CacheState {
+        selected_plugin_id: Some(id("plugin-b")),
+        marked_ids: HashSet::new(),
+        sort_key: SortKey::Version,
+        list_mode: ListMode::Detailed,
+    };
+
+    let model = InstalledScreenModel::from_cache(&data, &cache);
+
+    assert_eq!(model.to_cache().sort_key, SortKey::Version);
+    assert_eq!(model.to_cache().list_mode, ListMode::Detailed);
+    if let InstalledScreenModel::PluginList { selection, .. } = &model {
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected PluginList");
+    }
+}
@@ -3,40 +3,75 @@
 //! メッセージに応じた画面状態の更新ロジック。
 
 use super::actions;
-use super::model::{DetailAction, InstalledScreenModel, Msg, UpdateStatusDisplay};
-use crate::tui::manager::core::{filter_plugins, DataStore, PluginKey, SelectionState};
+use super::model::{ConfirmAction, DetailAction, InstalledScreenModel, Msg, UpdateStatusDisplay};
+use crate::application::{
+    preview_uninstall, preview_update, ImpactPreview, InstalledPlugin, VersionChange,
+};
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{
+    clamp_index, filter_names, filter_plugins, DataStore, DevStatus, ListMode, NavigationHistory,
+    PluginId, SelectionState, SortKey, Tab,
+};
 use ratatui::widgets::ListState;
 use std::collections::{HashMap, HashSet};
 
 /// update() の戻り値
 ///
-/// フィルタフォーカス移動とバッチ更新実行の2つの副作用を伝える。
+/// フィルタフォーカス移動・Phase 2 の実行・フィルタ復元・表示モード変更の副作用を伝える。
 pub struct UpdateEffect {
     /// フィルタ入力欄へフォーカス移動すべき
     pub should_focus_filter: bool,
-    /// 描画後にバッチ更新を実行すべき（2段階方式の Phase 1 完了後）
-    pub needs_execute_batch: bool,
+    /// 描画後に実行すべきメッセージ（2段階方式の Phase 2: バッチ更新・影響プレビューの計算）
+    pub phase2_msg: Option<Msg>,
+    /// 履歴から画面を復元した場合、そのときのフィルタ文字列
+    pub restore_filter: Option<String>,
+    /// 一覧の表示モードを切り替えた場合、切替後のモード（終了時に保存する）
+    pub list_mode: Option<ListMode>,
 }
 
 impl UpdateEffect {
     fn none() -> Self {
         Self {
             should_focus_filter: false,
-            needs_execute_batch: false,
+            phase2_msg: None,
+            restore_filter: None,
+            list_mode: None,
         }
     }
 
     fn focus_filter() -> Self {
         Self {
             should_focus_filter: true,
-            needs_execute_batch: false,
+            phase2_msg: None,
+            restore_filter: None,
+            list_mode: None,
+        }
+    }
+
+    fn phase2(msg: Msg) -> Self {
+        Self {
+            phase2_msg: Some(msg),
+            ..Self::none()
         }
     }
 
     fn execute_batch() -> Self {
+        Self::phase2(Msg::ExecuteBatch)
+    }
+
+    fn restored(filter_text: Option<String>) -> Self {
         Self {
             should_focus_filter: false,
-            needs_execute_batch: true,
+            phase2_msg: None,
+            restore_filter: filter_text,
+            list_mode: None,
+        }
+    }
+
+    fn list_mode_changed(list_mode: Option<ListMode>) -> Self {
+        Self {
+            list_mode,
+            ..Self::none()
         }
     }
 }
@@ -46,16 +81,18 @@ impl UpdateEffect {
 /// # Arguments
 ///
 /// * `model` - Installed tab model to mutate.
+/// * `history` - Navigation history of the Installed tab.
 /// * `msg` - Incoming message to apply.
 /// * `data` - Shared data store for plugins.
 /// * `filter_text` - Current filter input text.
 pub fn update(
     model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
     msg: Msg,
     data: &mut DataStore,
     filter_text: &str,
 ) -> UpdateEffect {
-    match msg {
+    let effect = match msg {
         Msg::Up => {
             if select_prev(model, data, filter_text) {
                 UpdateEffect::focus_filter()
@@ -67,46 +104,130 @@ pub fn update(
             select_next(model, data, filter_text);
             UpdateEffect::none()
         }
-        Msg::Enter => enter(model, data, filter_text),
-        Msg::Back => {
-            back(model, filter_text, data);
-            UpdateEffect::none()
-        }
+        Msg::Enter => enter(model, history, data, filter_text),
+        Msg::Back => back(model, history, data, filter_text),
         Msg::ToggleMark => {
-            toggle_mark(model);
+            toggle_mark(model, data);
             UpdateEffect::none()
         }
         Msg::ToggleAllMarks => {
             toggle_all_marks(model, data, filter_text);
             UpdateEffect::none()
         }
-        Msg::BatchUpdate => batch_update(model),
-        Msg::UpdateAll => update_all(model, data),
+        Msg::BatchUpdate => request_batch_update(model),
+        Msg::UpdateAll => request_update_all(model, data),
         Msg::ExecuteBatch => {
             execute_batch(model, data, filter_text);
             UpdateEffect::none()
         }
+        Msg::CalculatePreview => {
+            calculate_preview(model, data);
+            UpdateEffect::none()
+        }
+        Msg::Confirm => confirm(model, history, data, filter_text),
+        Msg::CancelConfirm => {
+            close_confirm(model);
+            UpdateEffect::none()
+        }
+        Msg::CycleSort => {
+            cycle_sort(model, data, filter_text);
+            UpdateEffect::none()
+        }
+        Msg::ToggleListMode => UpdateEffect::list_mode_changed(toggle_list_mode(model)),
+        Msg::DevReload => {
+            if let Some(dev) = data.dev_plugin.as_mut() {
+                dev.reload();
+            }
+            UpdateEffect::none()
+        }
+        Msg::DevRedeploy => {
+            redeploy_dev_plugin(data, actions::deploy_dev_plugin);
+            UpdateEffect::none()
+        }
+    };
+
+    // ルート画面に戻ったら履歴は不要
+    if model.is_top_level() {
+        history.clear();
+    }
+    effect
+}
+
+/// dev プラグインをプロジェクトへ再デプロイし、結果をステータス行に記録する
+///
+/// dev モードでない場合は何もしない。デプロイ関数は注入可能にしてテストで差し替える。
+fn redeploy_dev_plugin(
+    data: &mut DataStore,
+    deploy: impl FnOnce(&crate::tui::manager::core::DevPlugin) -> actions::DevDeployOutcome,
+) {
+    let Some(dev) = data.dev_plugin.as_mut() else {
+        return;
+    };
+    if dev.plugin().is_none() {
+        dev.set_status(DevStatus::Error(
+            "Nothing to deploy: dev plugin failed to load".to_string(),
+        ));
+        return;
     }
+    let status = match deploy(dev) {
+        actions::DevDeployOutcome::Deployed { placed, failures } if failures.is_empty() => {
+            DevStatus::Info(format!("Deployed {} component(s)", placed))
+        }
+        actions::DevDeployOutcome::Deployed { placed, failures } => DevStatus::Error(format!(
+            "Deployed {} component(s), {} failed: {}",
+            placed,
+            failures.len(),
+            failures.join("; ")
+        )),
+        actions::DevDeployOutcome::Error(e) => DevStatus::Error(e),
+    };
+    dev.set_status(status);
 }
 
 /// 個別プラグインのマークをトグル
-fn toggle_mark(model: &mut InstalledScreenModel) {
+fn toggle_mark(model: &mut InstalledScreenModel, data: &DataStore) {
     if let InstalledScreenModel::PluginList {
         selection,
         marked_ids,
         ..
     } = model
     {
-        if let Some(id) = selection.selected_id() {
-            if marked_ids.contains(id) {
-                marked_ids.remove(id);
-            } else {
-                marked_ids.insert(id.clone());
+        if let Some(plugin) = selection.selected_id().and_then(|id| data.find_plugin(id)) {
+            let id = PluginId::of(plugin);
+            if !marked_ids.remove(&id) {
+                marked_ids.insert(id);
             }
         }
     }
 }
 
+/// ソートキーを次へ切り替える
+///
+/// 選択中のプラグインは維持し、並べ替え後の位置へカーソルを移す。
+fn cycle_sort(model: &mut InstalledScreenModel, data: &DataStore, filter_text: &str) {
+    if let InstalledScreenModel::PluginList {
+        selection,
+        sort_key,
+        ..
+    } = model
+    {
+        *sort_key = sort_key.next();
+        selection.sync_to(&visible_plugin_ids(data, *sort_key, filter_text));
+    }
+}
+
+/// 一覧の表示モードを切り替え、切替後のモードを返す
+///
+/// 行の高さが変わるだけで並び順は変わらないため、選択位置はそのまま維持する。
+fn toggle_list_mode(model: &mut InstalledScreenModel) -> Option<ListMode> {
+    if let InstalledScreenModel::PluginList { list_mode, .. } = model {
+        *list_mode = list_mode.next();
+        Some(*list_mode)
+    } else {
+        None
+    }
+}
+
 /// フィルタ済みプラグインの一括マークトグル
 fn toggle_all_marks(model: &mut InstalledScreenModel, data: &DataStore, filter_text: &str) {
     if let InstalledScreenModel::PluginList { marked_ids, .. } = model {
@@ -116,21 +237,185 @@ fn toggle_all_marks(model: &mut InstalledScreenModel, data: &DataStore, filter_t
         let all_marked = !filtered.is_empty()
             && filtered
                 .iter()
-                .all(|plugin| marked_ids.contains(plugin.id()));
+                .all(|plugin| marked_ids.contains(&PluginId::of(plugin)));
 
         if all_marked {
             for plugin in &filtered {
-                marked_ids.remove(plugin.id());
+                marked_ids.remove(&PluginId::of(plugin));
             }
         } else {
             for plugin in &filtered {
-                marked_ids.insert(plugin.id().to_string());
+                marked_ids.insert(PluginId::of(plugin));
+            }
+        }
+    }
+}
+
+/// `U`: マーク済みプラグインの一括更新を確認する
+fn request_batch_update(model: &mut InstalledScreenModel) -> UpdateEffect {
+    let has_marks = matches!(
+        model,
+        InstalledScreenModel::PluginList { marked_ids, .. } if !marked_ids.is_empty()
+    );
+    if !has_marks {
+        return UpdateEffect::none();
+    }
+    open_confirm(model, ConfirmAction::BatchUpdate)
+}
+
+/// `A`: 全プラグインの更新を確認する
+fn request_update_all(model: &mut InstalledScreenModel, data: &DataStore) -> UpdateEffect {
+    if !model.is_top_level() || data.plugins.is_empty() {
+        return UpdateEffect::none();
+    }
+    open_confirm(model, ConfirmAction::UpdateAll)
+}
+
+/// 確認ダイアログを開き、描画後の Phase 2 で影響プレビューを計算させる
+///
+/// # Arguments
+///
+/// * `model` - Screen the confirmation is opened from (kept as its parent).
+/// * `action` - Destructive action waiting for confirmation.
+fn open_confirm(model: &mut InstalledScreenModel, action: ConfirmAction) -> UpdateEffect {
+    let parent = Box::new(model.clone());
+    *model = InstalledScreenModel::Confirm {
+        action,
+        preview: PreviewState::Calculating,
+        parent,
+    };
+    UpdateEffect::phase2(Msg::CalculatePreview)
+}
+
+/// Phase 2: 確認ダイアログの影響プレビューを計算する
+fn calculate_preview(model: &mut InstalledScreenModel, data: &mut DataStore) {
+    if let InstalledScreenModel::Confirm {
+        action,
+        preview,
+        parent,
+    } = model
+    {
+        data.ensure_available_updates();
+        *preview = PreviewState::Ready(impact_preview(action, parent, data));
+    }
+}
+
+/// アクションの影響を application 層の dry-run 相当の処理で見積もる
+///
+/// # Arguments
+///
+/// * `action` - Action waiting for confirmation.
+/// * `parent` - Screen the confirmation was opened from.
+/// * `data` - Shared data store (update check already done).
+fn impact_preview(
+    action: &ConfirmAction,
+    parent: &InstalledScreenModel,
+    data: &DataStore,
+) -> ImpactPreview {
+    match action {
+        ConfirmAction::Uninstall(plugin_id) => data
+            .find_plugin(plugin_id)
+            .map(|plugin| preview_uninstall(plugin.cache_path()))
+            .unwrap_or_default(),
+        ConfirmAction::BatchUpdate => {
+            let marked = match parent {
+                InstalledScreenModel::PluginList { marked_ids, .. } => marked_ids.clone(),
+                _ => HashSet::new(),
+            };
+            let plugins = data
+                .plugins
+                .iter()
+                .filter(|p| marked.contains(&PluginId::of(p)));
+            preview_update(version_changes(data, plugins))
+        }
+        ConfirmAction::UpdateAll => preview_update(version_changes(data, data.plugins.iter())),
+    }
+}
+
+/// 更新対象の現在のバージョンと、更新チェックで見つかった新しいバージョン
+fn version_changes<'a>(
+    data: &DataStore,
+    plugins: impl Iterator<Item = &'a InstalledPlugin>,
+) -> Vec<VersionChange> {
+    plugins
+        .map(|plugin| VersionChange {
+            plugin: PluginId::of(plugin).display_label(),
+            current: plugin.version().to_string(),
+            latest: data.available_update(plugin).map(str::to_string),
+        })
+        .collect()
+}
+
+/// 確認ダイアログを閉じて開いた画面に戻る（確認中でなければ何もしない）
+///
+/// 閉じた場合は実行を待っていたアクションを返す。
+fn close_confirm(model: &mut InstalledScreenModel) -> Option<ConfirmAction> {
+    let InstalledScreenModel::Confirm { action, parent, .. } = model else {
+        return None;
+    };
+    let (action, parent) = (action.clone(), parent.as_ref().clone());
+    *model = parent;
+    Some(action)
+}
+
+/// 確認ダイアログで 'y' / Enter: 開いた画面に戻ってアクションを実行する
+fn confirm(
+    model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    data: &mut DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
+    match close_confirm(model) {
+        Some(ConfirmAction::Uninstall(plugin_id)) => {
+            uninstall(model, history, data, filter_text, &plugin_id)
+        }
+        Some(ConfirmAction::BatchUpdate) => batch_update(model),
+        Some(ConfirmAction::UpdateAll) => update_all(model, data),
+        None => UpdateEffect::none(),
+    }
+}
+
+/// アンインストールを実行し、成功したら一覧へ戻る（失敗時は詳細画面に留まる）
+///
+/// # Arguments
+///
+/// * `model` - Plugin detail screen the uninstall was confirmed on.
+/// * `history` - Navigation history of the Installed tab.
+/// * `data` - Shared data store.
+/// * `filter_text` - Current filter input text.
+/// * `plugin_id` - Plugin to uninstall.
+fn uninstall(
+    model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    data: &mut DataStore,
+    filter_text: &str,
+    plugin_id: &PluginId,
+) -> UpdateEffect {
+    // Uninstall: デプロイ先 + キャッシュ削除
+    match actions::uninstall_plugin(plugin_id) {
+        actions::ActionOutcome::Success => {
+            data.remove_plugin(plugin_id);
+            let (mut list, restored_filter) = return_to_list(history, data, filter_text, plugin_id);
+            if let InstalledScreenModel::PluginList {
+                marked_ids,
+                update_statuses,
+                ..
+            } = &mut list
+            {
+                marked_ids.remove(plugin_id);
+                update_statuses.remove(plugin_id);
             }
+            *model = list;
+            UpdateEffect::restored(restored_filter)
+        }
+        actions::ActionOutcome::Error(e) => {
+            data.push_error(Tab::Installed, e);
+            UpdateEffect::none()
         }
     }
 }
 
-/// Phase 1: マーク済みプラグインのステータスを Updating にセット
+/// 確認後の Phase 1: マーク済みプラグインのステータスを Updating にセット
 fn batch_update(model: &mut InstalledScreenModel) -> UpdateEffect {
     if let InstalledScreenModel::PluginList {
         marked_ids,
@@ -154,7 +439,7 @@ fn batch_update(model: &mut InstalledScreenModel) -> UpdateEffect {
     UpdateEffect::none()
 }
 
-/// Phase 1: 全プラグインのステータスを Updating にセット
+/// 確認後の Phase 1: 全プラグインのステータスを Updating にセット
 fn update_all(model: &mut InstalledScreenModel, data: &DataStore) -> UpdateEffect {
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
@@ -167,7 +452,7 @@ fn update_all(model: &mut InstalledScreenModel, data: &DataStore) -> UpdateEffec
         // 前回の古いステータスをクリアしてから全プラグインに Updating をセット
         update_statuses.clear();
         for plugin in &data.plugins {
-            update_statuses.insert(plugin.id().to_string(), UpdateStatusDisplay::Updating);
+            update_statuses.insert(PluginId::of(plugin), UpdateStatusDisplay::Updating);
         }
 
         return UpdateEffect::execute_batch();
@@ -177,14 +462,25 @@ fn update_all(model: &mut InstalledScreenModel, data: &DataStore) -> UpdateEffec
 }
 
 /// Phase 2: 実際のバッチ更新処理を実行
+///
+/// 衝突でスキップしたコンポーネントの件数と再デプロイしたターゲットの反映手順を
+/// `last_notice` に残す。
 fn execute_batch(model: &mut InstalledScreenModel, data: &mut DataStore, filter_text: &str) {
+    let mut deployed_targets = Vec::new();
+    let mut conflicts = Vec::new();
     execute_batch_with(
         model,
         data,
         filter_text,
-        actions::batch_update_plugins,
-        |d| d.reload(),
+        |ids| {
+            let outcome = actions::batch_update_plugins(ids);
+            deployed_targets = outcome.deployed_targets;
+            conflicts = outcome.conflicts;
+            outcome.statuses
+        },
+        |d| d.reload().map(|()| d.ensure_available_updates()),
     );
+    data.last_notice = actions::deploy_notice(&conflicts, &deployed_targets);
 }
 
 /// Phase 2 の実装本体（依存関数を注入可能）
@@ -195,40 +491,38 @@ fn execute_batch_with(
     model: &mut InstalledScreenModel,
     data: &mut DataStore,
     filter_text: &str,
-    run_updates: impl FnOnce(&[PluginKey]) -> Vec<(PluginKey, UpdateStatusDisplay)>,
+    run_updates: impl FnOnce(&[PluginId]) -> Vec<(PluginId, UpdateStatusDisplay)>,
     reload: impl FnOnce(&mut DataStore) -> std::io::Result<()>,
 ) {
     if let InstalledScreenModel::PluginList {
         marked_ids,
         update_statuses,
         selection,
+        sort_key,
+        ..
     } = model
     {
-        // update_statuses から Updating のプラグイン ID を収集
-        // O(n) の HashSet で存在チェックし、find_plugin の線形探索 O(n^2) を回避
-        let existing_ids: HashSet<&str> = data.plugins.iter().map(|p| p.id()).collect();
-        let plugin_keys: Vec<PluginKey> = update_statuses
+        // update_statuses から Updating のプラグインを収集する（一覧から消えたものは除く）
+        let plugin_ids: Vec<PluginId> = update_statuses
             .iter()
             .filter(|(_, status)| matches!(status, UpdateStatusDisplay::Updating))
-            .filter(|(id, _)| existing_ids.contains(id.as_str()))
-            .filter_map(|(id, _)| {
-                data.plugins
-                    .iter()
-                    .find(|p| p.id() == id.as_str())
-                    .map(PluginKey::from_installed)
-            })
+            .filter(|(id, _)| data.find_plugin(id).is_some())
+            .map(|(id, _)| id.clone())
             .collect();
 
-        let results = run_updates(&plugin_keys);
+        let results = run_updates(&plugin_ids);
 
         let mut new_statuses = HashMap::new();
         let mut batch_errors: Vec<String> = Vec::new();
-        for (key, status) in results {
-            let name = key.cache_id;
+        for (id, status) in results {
             if let UpdateStatusDisplay::Failed(ref reason) = status {
-                batch_errors.push(format!("Update failed for {}: {}", name, reason));
+                batch_errors.push(format!(
+                    "Update failed for {}: {}",
+                    id.display_label(),
+                    reason
+                ));
             }
-            new_statuses.insert(name, status);
+            new_statuses.insert(id, status);
         }
 
         if !batch_errors.is_empty() {
@@ -241,16 +535,12 @@ fn execute_batch_with(
                     batch_errors.join("\n")
                 )
             };
-            data.last_error = Some(aggregated);
+            data.push_error(Tab::Installed, aggregated);
         }
         *update_statuses = new_statuses;
 
         if let Err(e) = reload(data) {
-            let reload_msg = format!("Failed to reload plugins: {}", e);
-            data.last_error = Some(match data.last_error.take() {
-                Some(prev) => format!("{prev}\n{reload_msg}"),
-                None => reload_msg,
-            });
+            data.push_error(Tab::Installed, format!("Failed to reload plugins: {}", e));
         }
 
         // reload 後に存在しなくなったプラグインをマークから除去
@@ -263,16 +553,7 @@ fn execute_batch_with(
         }
 
         // reload 後にフィルタ済みリストに対して選択状態を再同期
-        let filtered = filter_plugins(&data.plugins, filter_text);
-        let current_selected = selection.selected_id();
-        let new_idx = current_selected
-            .and_then(|id| filtered.iter().position(|p| p.id() == id.as_str()))
-            .or(if filtered.is_empty() { None } else { Some(0) });
-
-        selection.set(
-            new_idx.and_then(|idx| filtered.get(idx).map(|p| p.id().to_string())),
-            new_idx,
-        );
+        selection.sync_to(&visible_plugin_ids(data, *sort_key, filter_text));
     }
 }
 
@@ -338,45 +619,27 @@ fn select_next(model: &mut InstalledScreenModel, data: &DataStore, filter_text:
 /// 次の階層へ遷移
 fn enter(
     model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
     data: &mut DataStore,
     filter_text: &str,
 ) -> UpdateEffect {
     match model {
-        InstalledScreenModel::PluginList {
-            selection,
-            marked_ids,
-            update_statuses,
-            ..
-        } => {
-            // PluginList → PluginDetail へ遷移（マーク状態を保存）
+        InstalledScreenModel::PluginList { selection, .. } => {
+            // PluginList → PluginDetail へ遷移（一覧は履歴に積んで Back で復元）
             if let Some(plugin_id) = selection.selected_id().cloned() {
                 if data.find_plugin(&plugin_id).is_some() {
-                    let saved_marked = std::mem::take(marked_ids);
-                    let saved_statuses = std::mem::take(update_statuses);
-                    let mut new_state = ListState::default();
-                    new_state.select(Some(0));
-                    *model = InstalledScreenModel::PluginDetail {
+                    let next = InstalledScreenModel::PluginDetail {
                         plugin_id,
-                        state: new_state,
-                        saved_marked_ids: saved_marked,
-                        saved_update_statuses: saved_statuses,
+                        state: first_item_state(),
                     };
+                    navigate(model, history, next, filter_text);
                 }
             }
             UpdateEffect::none()
         }
-        InstalledScreenModel::PluginDetail {
-            plugin_id,
-            state,
-            saved_marked_ids,
-            saved_update_statuses,
-        } => {
+        InstalledScreenModel::PluginDetail { plugin_id, state } => {
             // プラグイン情報を取得
-            let plugin = data.find_plugin(plugin_id).cloned();
-            let marketplace = plugin
-                .as_ref()
-                .and_then(|p| p.marketplace().map(str::to_string));
-            let enabled = plugin.as_ref().is_some_and(|p| p.enabled());
+            let enabled = data.find_plugin(plugin_id).is_some_and(|p| p.enabled());
 
             // enabled 状態に応じたアクション一覧を取得
             let detail_actions = DetailAction::for_plugin(enabled);
@@ -385,123 +648,64 @@ fn enter(
             match detail_actions.get(selected) {
                 Some(DetailAction::DisablePlugin) => {
                     // Disable: デプロイ先から削除、キャッシュは残す
-                    let result = actions::disable_plugin(plugin_id, marketplace.as_deref());
+                    let result = actions::disable_plugin(plugin_id);
                     match result {
                         actions::ActionOutcome::Success => {
                             data.set_plugin_enabled(plugin_id, false);
                         }
                         actions::ActionOutcome::Error(e) => {
-                            data.last_error = Some(e);
+                            data.push_error(Tab::Installed, e);
                         }
                     }
                 }
                 Some(DetailAction::EnablePlugin) => {
                     // Enable: キャッシュからデプロイ先に配置
-                    let result = actions::enable_plugin(plugin_id, marketplace.as_deref());
+                    let (result, hint) = actions::enable_plugin(plugin_id);
                     match result {
                         actions::ActionOutcome::Success => {
                             data.set_plugin_enabled(plugin_id, true);
+                            data.last_notice = hint;
                         }
                         actions::ActionOutcome::Error(e) => {
-                            data.last_error = Some(e);
+                            data.push_error(Tab::Installed, e);
                         }
                     }
                 }
                 Some(DetailAction::Uninstall) => {
-                    // Uninstall: デプロイ先 + キャッシュ削除
-                    let result = actions::uninstall_plugin(plugin_id, marketplace.as_deref());
-                    match result {
-                        actions::ActionOutcome::Success => {
-                            let uninstalled_id = plugin_id.clone();
-                            let mut restored_marks = std::mem::take(saved_marked_ids);
-                            let mut restored_statuses = std::mem::take(saved_update_statuses);
-                            restored_marks.remove(&uninstalled_id);
-                            restored_statuses.remove(&uninstalled_id);
-                            data.remove_plugin(&uninstalled_id);
-                            // フィルタ済みリストに対して選択を同期
-                            let filtered = filter_plugins(&data.plugins, filter_text);
-                            let mut new_state = ListState::default();
-                            let selected_id = if !filtered.is_empty() {
-                                new_state.select(Some(0));
-                                Some(filtered[0].id().to_string())
-                            } else {
-                                None
-                            };
-                            *model = InstalledScreenModel::PluginList {
-                                selection: SelectionState::new(selected_id, new_state.selected()),
-                                marked_ids: restored_marks,
-                                update_statuses: restored_statuses,
-                            };
-                        }
-                        actions::ActionOutcome::Error(e) => {
-                            data.last_error = Some(e);
-                        }
-                    }
+                    // 影響プレビュー付きの確認を挟む（実行は confirm で行う）
+                    let action = ConfirmAction::Uninstall(plugin_id.clone());
+                    return open_confirm(model, action);
                 }
                 Some(DetailAction::ViewComponents) => {
-                    // ComponentTypes に遷移（マーク状態を引き継ぐ）
-                    let restored_marks = std::mem::take(saved_marked_ids);
-                    let restored_statuses = std::mem::take(saved_update_statuses);
-                    let mut new_state = ListState::default();
-                    new_state.select(Some(0));
-                    *model = InstalledScreenModel::ComponentTypes {
+                    // ComponentTypes に遷移
+                    let next = InstalledScreenModel::ComponentTypes {
                         plugin_id: plugin_id.clone(),
                         selected_kind_idx: 0,
-                        state: new_state,
-                        saved_marked_ids: restored_marks,
-                        saved_update_statuses: restored_statuses,
+                        state: first_item_state(),
                     };
+                    navigate(model, history, next, filter_text);
                 }
                 Some(DetailAction::Back) => {
-                    // PluginList に戻る（マーク状態を復元、選択をフィルタ済みリストと同期）
-                    let id = plugin_id.clone();
-                    let restored_marks = std::mem::take(saved_marked_ids);
-                    let restored_statuses = std::mem::take(saved_update_statuses);
-                    let filtered = filter_plugins(&data.plugins, filter_text);
-                    let mut new_state = ListState::default();
-                    let idx = filtered.iter().position(|p| p.id() == id);
-                    let selected_id = if let Some(idx) = idx {
-                        new_state.select(Some(idx));
-                        Some(id)
-                    } else if !filtered.is_empty() {
-                        new_state.select(Some(0));
-                        Some(filtered[0].id().to_string())
-                    } else {
-                        None
-                    };
-                    *model = InstalledScreenModel::PluginList {
-                        selection: SelectionState::new(selected_id, new_state.selected()),
-                        marked_ids: restored_marks,
-                        update_statuses: restored_statuses,
-                    };
+                    return back(model, history, data, filter_text);
                 }
                 Some(DetailAction::UpdateNow) => {
-                    // UpdateNow: 単一プラグインを即時更新
+                    // UpdateNow: 単一プラグインを即時更新（一覧へ戻ってから Phase 2 を実行）
                     let target_id = plugin_id.clone();
-                    let restored_marks = std::mem::take(saved_marked_ids);
-                    let mut restored_statuses = std::mem::take(saved_update_statuses);
-                    // 古いステータスをクリアして対象プラグインに Updating をセット
-                    restored_statuses.clear();
-                    restored_statuses.insert(target_id.clone(), UpdateStatusDisplay::Updating);
-                    // PluginList に遷移（フィルタ済みリストで選択位置を同期）
-                    let filtered = filter_plugins(&data.plugins, filter_text);
-                    let mut new_state = ListState::default();
-                    let idx = filtered.iter().position(|p| p.id() == target_id);
-                    let selected_id = if let Some(idx) = idx {
-                        new_state.select(Some(idx));
-                        Some(target_id)
-                    } else if !filtered.is_empty() {
-                        new_state.select(Some(0));
-                        Some(filtered[0].id().to_string())
-                    } else {
-                        None
-                    };
-                    *model = InstalledScreenModel::PluginList {
-                        selection: SelectionState::new(selected_id, new_state.selected()),
-                        marked_ids: restored_marks,
-                        update_statuses: restored_statuses,
+                    let (mut list, restored_filter) =
+                        return_to_list(history, data, filter_text, &target_id);
+                    if let InstalledScreenModel::PluginList {
+                        update_statuses, ..
+                    } = &mut list
+                    {
+                        // 古いステータスをクリアして対象プラグインに Updating をセット
+                        update_statuses.clear();
+                        update_statuses.insert(target_id, UpdateStatusDisplay::Updating);
+                    }
+                    *model = list;
+                    return UpdateEffect {
+                        restore_filter: restored_filter,
+                        ..UpdateEffect::execute_batch()
                     };
-                    return UpdateEffect::execute_batch();
                 }
                 _ => {
                     // MarkForUpdate は UI 表示のみ（BatchUpdate 経由で処理）
@@ -510,11 +714,7 @@ fn enter(
             UpdateEffect::none()
         }
         InstalledScreenModel::ComponentTypes {
-            plugin_id,
-            state,
-            saved_marked_ids,
-            saved_update_statuses,
-            ..
+            plugin_id, state, ..
         } => {
             if let Some(plugin) = data.find_plugin(plugin_id) {
                 let counts = data.available_component_kinds(plugin);
@@ -523,18 +723,13 @@ fn enter(
                     let kind = count.0;
                     let components = data.component_names(plugin, kind);
                     if !components.is_empty() {
-                        let restored_marks = std::mem::take(saved_marked_ids);
-                        let restored_statuses = std::mem::take(saved_update_statuses);
-                        let mut new_state = ListState::default();
-                        new_state.select(Some(0));
-                        *model = InstalledScreenModel::ComponentList {
+                        let next = InstalledScreenModel::ComponentList {
                             plugin_id: plugin_id.clone(),
                             kind,
                             selected_idx: 0,
-                            state: new_state,
-                            saved_marked_ids: restored_marks,
-                            saved_update_statuses: restored_statuses,
+                            state: first_item_state(),
                         };
+                        navigate(model, history, next, filter_text);
                     }
                 }
             }
@@ -544,85 +739,181 @@ fn enter(
             // 最下層なので何もしない
             UpdateEffect::none()
         }
+        // 確認ダイアログの Enter は Msg::Confirm として届く
+        InstalledScreenModel::Confirm { .. } => UpdateEffect::none(),
     }
 }
 
+/// 遷移元を履歴に積んで次の画面へ進む
+fn navigate(
+    model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    next: InstalledScreenModel,
+    filter_text: &str,
+) {
+    let prev = std::mem::replace(model, next);
+    history.push(prev, filter_text);
+}
+
 /// 前の階層へ戻る
-fn back(model: &mut InstalledScreenModel, filter_text: &str, data: &DataStore) {
-    match model {
-        InstalledScreenModel::PluginList { .. } => {
-            // PluginList での Back は app.rs で Quit 処理される
+///
+/// 履歴の直前の画面を選択位置・スクロール位置・フィルタごと復元する。
+/// 対象プラグインが消えていて復元できない画面は読み飛ばし、履歴が尽きた場合は
+/// 階層上の親画面を初期状態で開く。
+fn back(
+    model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    data: &DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
+    if model.is_top_level() {
+        // PluginList での Back は app.rs で Quit 処理される
+        return UpdateEffect::none();
+    }
+    if close_confirm(model).is_some() {
+        return UpdateEffect::none();
+    }
+
+    while let Some(entry) = history.pop() {
+        if let Some(screen) = revalidate(entry.screen, data, &entry.filter_text) {
+            *model = screen;
+            return UpdateEffect::restored(Some(entry.filter_text));
         }
-        InstalledScreenModel::PluginDetail {
-            plugin_id,
-            saved_marked_ids,
-            saved_update_statuses,
-            ..
-        } => {
-            // PluginDetail → PluginList へ戻る（マーク状態を復元）
-            let id = plugin_id.clone();
-            let restored_marks = std::mem::take(saved_marked_ids);
-            let restored_statuses = std::mem::take(saved_update_statuses);
-            let filtered = filter_plugins(&data.plugins, filter_text);
-            let mut new_state = ListState::default();
-            let idx = filtered.iter().position(|p| p.id() == id);
-            let selected_id = if let Some(idx) = idx {
-                new_state.select(Some(idx));
-                Some(id)
-            } else if !filtered.is_empty() {
-                new_state.select(Some(0));
-                Some(filtered[0].id().to_string())
-            } else {
-                None
-            };
-            *model = InstalledScreenModel::PluginList {
-                selection: SelectionState::new(selected_id, new_state.selected()),
-                marked_ids: restored_marks,
-                update_statuses: restored_statuses,
-            };
+    }
+
+    *model = match model {
+        InstalledScreenModel::PluginList { .. } => return UpdateEffect::none(),
+        InstalledScreenModel::PluginDetail { plugin_id, .. } => {
+            fresh_list(data, filter_text, Some(plugin_id))
         }
-        InstalledScreenModel::ComponentTypes {
-            plugin_id,
-            saved_marked_ids,
-            saved_update_statuses,
-            ..
-        } => {
-            // ComponentTypes → PluginDetail へ戻る（マーク状態を復元）
-            let plugin_id = plugin_id.clone();
-            let restored_marks = std::mem::take(saved_marked_ids);
-            let restored_statuses = std::mem::take(saved_update_statuses);
-            let mut new_state = ListState::default();
-            new_state.select(Some(0));
-            *model = InstalledScreenModel::PluginDetail {
-                plugin_id,
-                state: new_state,
-                saved_marked_ids: restored_marks,
-                saved_update_statuses: restored_statuses,
-            };
+        InstalledScreenModel::ComponentTypes { plugin_id, .. } => {
+            InstalledScreenModel::PluginDetail {
+                plugin_id: plugin_id.clone(),
+                state: first_item_state(),
+            }
         }
-        InstalledScreenModel::ComponentList {
-            plugin_id,
-            saved_marked_ids,
-            saved_update_statuses,
-            ..
-        } => {
-            // ComponentList → ComponentTypes へ戻る（マーク状態を復元）
-            let plugin_id = plugin_id.clone();
-            let restored_marks = std::mem::take(saved_marked_ids);
-            let restored_statuses = std::mem::take(saved_update_statuses);
-            let mut new_state = ListState::default();
-            new_state.select(Some(0));
-            *model = InstalledScreenModel::ComponentTypes {
-                plugin_id,
+        InstalledScreenModel::ComponentList { plugin_id, .. } => {
+            InstalledScreenModel::ComponentTypes {
+                plugin_id: plugin_id.clone(),
                 selected_kind_idx: 0,
-                state: new_state,
-                saved_marked_ids: restored_marks,
-                saved_update_statuses: restored_statuses,
-            };
+                state: first_item_state(),
+            }
         }
+        InstalledScreenModel::Confirm { .. } => return UpdateEffect::none(),
+    };
+    UpdateEffect::none()
+}
+
+/// 履歴のルート（PluginList）まで一気に戻る
+///
+/// 戻り値は復元した一覧と、そのとき有効だったフィルタ文字列。
+/// 履歴にルートが残っていなければ `plugin_id` を選択した新しい一覧を返す。
+///
+/// # Arguments
+///
+/// * `history` - Navigation history of the Installed tab.
+/// * `data` - Shared data store (already updated by the action).
+/// * `filter_text` - Current filter input text.
+/// * `plugin_id` - Plugin to select when no root snapshot is available.
+fn return_to_list(
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    data: &DataStore,
+    filter_text: &str,
+    plugin_id: &PluginId,
+) -> (InstalledScreenModel, Option<String>) {
+    match history.take_root() {
+        Some(entry) if entry.screen.is_top_level() => {
+            let list = revalidate(entry.screen, data, &entry.filter_text)
+                .unwrap_or_else(|| fresh_list(data, &entry.filter_text, Some(plugin_id)));
+            (list, Some(entry.filter_text))
+        }
+        _ => (fresh_list(data, filter_text, Some(plugin_id)), None),
+    }
+}
+
+/// 履歴から取り出した画面を現在のデータに合わせて補正する
+///
+/// 対象プラグインが消えた下位画面は復元できないため `None` を返す。
+/// 一覧は選択中のプラグインが消えていれば元の位置に最も近い項目を選び直す。
+///
+/// # Arguments
+///
+/// * `screen` - Screen state taken from the history.
+/// * `data` - Shared data store.
+/// * `filter_text` - Filter text recorded with the screen.
+fn revalidate(
+    mut screen: InstalledScreenModel,
+    data: &DataStore,
+    filter_text: &str,
+) -> Option<InstalledScreenModel> {
+    match &mut screen {
+        InstalledScreenModel::PluginList {
+            selection,
+            marked_ids,
+            update_statuses,
+            sort_key,
+            ..
+        } => {
+            marked_ids.retain(|id| data.find_plugin(id).is_some());
+            update_statuses.retain(|id, _| data.find_plugin(id).is_some());
+            let filtered = data.sorted_plugins(*sort_key, filter_text);
+            let idx = selection
+                .selected_id()
+                .and_then(|id| filtered.iter().position(|p| id.matches(p)))
+                .or_else(|| {
+                    let last = filtered.len().checked_sub(1)?;
+                    Some(selection.selected_index().unwrap_or(0).min(last))
+                });
+            selection.set(idx.map(|i| PluginId::of(filtered[i])), idx);
+            return Some(screen);
+        }
+        InstalledScreenModel::PluginDetail { plugin_id, .. }
+        | InstalledScreenModel::ComponentTypes { plugin_id, .. }
+        | InstalledScreenModel::ComponentList { plugin_id, .. } => {
+            data.find_plugin(plugin_id)?;
+        }
+        // 確認ダイアログは履歴に積まない
+        InstalledScreenModel::Confirm { .. } => return None,
+    }
+
+    let len = list_len(&screen, data, filter_text);
+    if let Some(state) = screen.current_state_mut() {
+        clamp_index(state, len);
+    }
+    Some(screen)
+}
+
+/// 履歴を使わずに作る PluginList（マークなし、既定のソート順・表示モード）
+///
+/// # Arguments
+///
+/// * `data` - Shared data store.
+/// * `filter_text` - Filter text applied to the list.
+/// * `preferred_id` - Plugin to select if it is visible; otherwise the first item.
+fn fresh_list(
+    data: &DataStore,
+    filter_text: &str,
+    preferred_id: Option<&PluginId>,
+) -> InstalledScreenModel {
+    let sort_key = SortKey::default();
+    let mut selection = SelectionState::new(preferred_id.cloned(), None);
+    selection.sync_to(&visible_plugin_ids(data, sort_key, filter_text));
+    InstalledScreenModel::PluginList {
+        selection,
+        marked_ids: HashSet::new(),
+        update_statuses: HashMap::new(),
+        sort_key,
+        list_mode: ListMode::default(),
     }
 }
 
+/// 先頭項目を選択した ListState
+fn first_item_state() -> ListState {
+    let mut state = ListState::default();
+    state.select(Some(0));
+    state
+}
+
 /// 現在の画面のリスト長を取得
 fn list_len(model: &InstalledScreenModel, data: &DataStore, filter_text: &str) -> usize {
     match model {
@@ -642,20 +933,64 @@ fn list_len(model: &InstalledScreenModel, data: &DataStore, filter_text: &str) -
             plugin_id, kind, ..
         } => {
             if let Some(plugin) = data.find_plugin(plugin_id) {
-                data.component_names(plugin, *kind).len()
+                filter_names(&data.component_names(plugin, *kind), filter_text).len()
             } else {
                 0
             }
         }
+        InstalledScreenModel::Confirm { .. } => 0,
+    }
+}
+
+/// フィルタ・ソート適用後の一覧に表示されるプラグイン ID（表示順）
+///
+/// # Arguments
+///
+/// * `data` - Shared data store.
+/// * `sort_key` - Sort key of the list.
+/// * `filter_text` - Filter text applied to the list.
+fn visible_plugin_ids(data: &DataStore, sort_key: SortKey, filter_text: &str) -> Vec<PluginId> {
+    data.sorted_plugins(sort_key, filter_text)
+        .iter()
+        .map(|p| PluginId::of(p))
+        .collect()
+}
+
+/// フィルタ変更後に選択状態を整合させる
+///
+/// プラグイン一覧は選択中のプラグインを追従し、コンポーネント一覧はカーソルを件数内に収める。
+///
+/// # Arguments
+///
+/// * `model` - Installed tab model to adjust.
+/// * `data` - Shared data store.
+/// * `filter_text` - Filter text now in effect.
+pub fn clamp_selection(model: &mut InstalledScreenModel, data: &DataStore, filter_text: &str) {
+    let len = list_len(model, data, filter_text);
+    match model {
+        InstalledScreenModel::PluginList {
+            selection,
+            sort_key,
+            ..
+        } => {
+            selection.sync_to(&visible_plugin_ids(data, *sort_key, filter_text));
+        }
+        InstalledScreenModel::ComponentList { state, .. } => clamp_index(state, len),
+        _ => {}
     }
 }
 
 /// selected_id を現在のインデックスから更新
 fn update_selected_id(model: &mut InstalledScreenModel, data: &DataStore, filter_text: &str) {
-    if let InstalledScreenModel::PluginList { selection, .. } = model {
+    if let InstalledScreenModel::PluginList {
+        selection,
+        sort_key,
+        ..
+    } = model
+    {
         if let Some(idx) = selection.selected_index() {
-            let filtered = filter_plugins(&data.plugins, filter_text);
-            selection.set(filtered.get(idx).map(|p| p.id().to_string()), Some(idx));
+            let filtered = data.sorted_plugins(*sort_key, filter_text);
+            selection.set(filtered.get(idx).map(|p| PluginId::of(p)), Some(idx));
         }
     }
 }
@@ -1,14 +1,18 @@
-use super::{execute_batch_with, update};
+use super::{execute_batch_with, redeploy_dev_plugin, update, UpdateEffect};
 use crate::application::InstalledPlugin;
-use crate::tui::manager::core::{DataStore, PluginKey};
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{
+    DataStore, DevPlugin, DevStatus, ListMode, NavigationHistory, PluginId, SortKey,
+};
+use crate::tui::manager::screens::installed::actions::DevDeployOutcome;
 use crate::tui::manager::screens::installed::model::{
-    InstalledScreenModel, Msg, UpdateStatusDisplay,
+    ConfirmAction, InstalledScreenModel, Msg, UpdateStatusDisplay,
 };
 
 /// スタブ: 全プラグインを Updated として返す
-fn stub_run_updates(keys: &[PluginKey]) -> Vec<(PluginKey, UpdateStatusDisplay)> {
-    keys.iter()
-        .map(|k| (k.clone(), UpdateStatusDisplay::Updated))
+fn stub_run_updates(ids: &[PluginId]) -> Vec<(PluginId, UpdateStatusDisplay)> {
+    ids.iter()
+        .map(|id| (id.clone(), UpdateStatusDisplay::Updated))
         .collect()
 }
 
@@ -18,20 +22,47 @@ fn stub_reload(_data: &mut DataStore) -> std::io::Result<()> {
 }
 
 fn make_plugin(name: &str) -> InstalledPlugin {
+    make_plugin_in("github", name)
+}
+
+fn make_plugin_in(marketplace: &str, name: &str) -> InstalledPlugin {
     InstalledPlugin::new_for_test(
         name,
         "1.0.0",
         Vec::new(),
         None,
-        Some("github".to_string()),
+        Some(marketplace.to_string()),
         true,
     )
 }
 
+/// 直接 GitHub install（`github` マーケットプレイス）のプラグインID
+fn id(name: &str) -> PluginId {
+    PluginId::new(None, name)
+}
+
 fn make_data(names: &[&str]) -> (tempfile::TempDir, DataStore) {
     DataStore::for_test(names.iter().map(|n| make_plugin(n)).collect(), vec![], None)
 }
 
+/// 確認ダイアログを開くメッセージを送り、影響プレビューの計算後に 'y' で実行する
+///
+/// 確認ダイアログが開かなかった場合は最初の update の結果を返す。
+fn update_confirmed(
+    model: &mut InstalledScreenModel,
+    history: &mut NavigationHistory<InstalledScreenModel>,
+    msg: Msg,
+    data: &mut DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
+    let effect = update(model, history, msg, data, filter_text);
+    if !matches!(effect.phase2_msg, Some(Msg::CalculatePreview)) {
+        return effect;
+    }
+    update(model, history, Msg::CalculatePreview, data, filter_text);
+    update(model, history, Msg::Confirm, data, filter_text)
+}
+
 // ============================================================================
 // ToggleMark テスト
 // ============================================================================
@@ -40,13 +71,14 @@ fn make_data(names: &[&str]) -> (tempfile::TempDir, DataStore) {
 fn toggle_mark_adds_selected_plugin() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
-    let effect = update(&mut model, Msg::ToggleMark, &mut data, "");
+    let mut history = NavigationHistory::default();
+    let effect = update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
 
     assert!(!effect.should_focus_filter);
-    assert!(!effect.needs_execute_batch);
+    assert!(effect.phase2_msg.is_none());
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
-        assert!(marked_ids.contains("plugin-a"));
+        assert!(marked_ids.contains(&id("plugin-a")));
     } else {
         panic!("Expected PluginList");
     }
@@ -56,14 +88,15 @@ fn toggle_mark_adds_selected_plugin() {
 fn toggle_mark_removes_already_marked_plugin() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // 1回目: マーク
-    update(&mut model, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
     // 2回目: マーク解除
-    update(&mut model, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
-        assert!(!marked_ids.contains("plugin-a"));
+        assert!(!marked_ids.contains(&id("plugin-a")));
     } else {
         panic!("Expected PluginList");
     }
@@ -77,13 +110,14 @@ fn toggle_mark_removes_already_marked_plugin() {
 fn toggle_all_marks_selects_all_filtered_plugins() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleAllMarks, &mut data, "");
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
-        assert!(marked_ids.contains("plugin-a"));
-        assert!(marked_ids.contains("plugin-b"));
-        assert!(marked_ids.contains("plugin-c"));
+        assert!(marked_ids.contains(&id("plugin-a")));
+        assert!(marked_ids.contains(&id("plugin-b")));
+        assert!(marked_ids.contains(&id("plugin-c")));
     } else {
         panic!("Expected PluginList");
     }
@@ -93,11 +127,12 @@ fn toggle_all_marks_selects_all_filtered_plugins() {
 fn toggle_all_marks_deselects_when_all_marked() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // 全選択
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleAllMarks, &mut data, "");
     // 全解除
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleAllMarks, &mut data, "");
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
         assert!(marked_ids.is_empty());
@@ -110,24 +145,31 @@ fn toggle_all_marks_deselects_when_all_marked() {
 fn toggle_all_marks_preserves_marks_outside_filter() {
     let (_temp_dir, mut data) = make_data(&["alpha", "beta", "gamma"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // "alpha" を個別にマーク
-    update(&mut model, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
 
     // "beta" でフィルタして全選択（alpha はフィルタ外）
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "beta");
+    update(
+        &mut model,
+        &mut history,
+        Msg::ToggleAllMarks,
+        &mut data,
+        "beta",
+    );
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
         assert!(
-            marked_ids.contains("alpha"),
+            marked_ids.contains(&id("alpha")),
             "alpha should remain marked (outside filter)"
         );
         assert!(
-            marked_ids.contains("beta"),
+            marked_ids.contains(&id("beta")),
             "beta should be marked (in filter)"
         );
         assert!(
-            !marked_ids.contains("gamma"),
+            !marked_ids.contains(&id("gamma")),
             "gamma should not be marked (outside filter, was not previously marked)"
         );
     } else {
@@ -139,24 +181,31 @@ fn toggle_all_marks_preserves_marks_outside_filter() {
 fn toggle_all_marks_with_filter_only_deselects_filtered() {
     let (_temp_dir, mut data) = make_data(&["alpha", "beta", "gamma"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // 全てマーク（フィルタなし）
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleAllMarks, &mut data, "");
 
     // "beta" でフィルタして全解除
-    update(&mut model, Msg::ToggleAllMarks, &mut data, "beta");
+    update(
+        &mut model,
+        &mut history,
+        Msg::ToggleAllMarks,
+        &mut data,
+        "beta",
+    );
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
         assert!(
-            marked_ids.contains("alpha"),
+            marked_ids.contains(&id("alpha")),
             "alpha should remain marked (outside filter)"
         );
         assert!(
-            !marked_ids.contains("beta"),
+            !marked_ids.contains(&id("beta")),
             "beta should be unmarked (in filter, was toggled off)"
         );
         assert!(
-            marked_ids.contains("gamma"),
+            marked_ids.contains(&id("gamma")),
             "gamma should remain marked (outside filter)"
         );
     } else {
@@ -172,11 +221,12 @@ fn toggle_all_marks_with_filter_only_deselects_filtered() {
 fn batch_update_with_no_marks_is_noop() {
     let (_temp_dir, mut data) = make_data(&["plugin-a"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::BatchUpdate, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
 
     assert!(
-        !effect.needs_execute_batch,
+        effect.phase2_msg.is_none(),
         "Should not trigger batch when no marks"
     );
     assert!(!effect.should_focus_filter);
@@ -195,18 +245,19 @@ fn batch_update_with_no_marks_is_noop() {
 fn batch_update_phase1_sets_updating_and_returns_execute_batch() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // plugin-a と plugin-c をマーク
-    update(&mut model, Msg::ToggleMark, &mut data, ""); // mark plugin-a
-    update(&mut model, Msg::Down, &mut data, ""); // move to plugin-b
-    update(&mut model, Msg::Down, &mut data, ""); // move to plugin-c
-    update(&mut model, Msg::ToggleMark, &mut data, ""); // mark plugin-c
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, ""); // mark plugin-a
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // move to plugin-b
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // move to plugin-c
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, ""); // mark plugin-c
 
     // Phase 1: BatchUpdate
-    let effect = update(&mut model, Msg::BatchUpdate, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
 
     assert!(
-        effect.needs_execute_batch,
+        matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)),
         "BatchUpdate with marks should request execute_batch"
     );
     assert!(!effect.should_focus_filter);
@@ -217,18 +268,18 @@ fn batch_update_phase1_sets_updating_and_returns_execute_batch() {
     {
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-a should be Updating"
         );
         assert!(
-            !update_statuses.contains_key("plugin-b"),
+            !update_statuses.contains_key(&id("plugin-b")),
             "plugin-b should have no status (not marked)"
         );
         assert!(
             matches!(
-                update_statuses.get("plugin-c"),
+                update_statuses.get(&id("plugin-c")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-c should be Updating"
@@ -238,14 +289,85 @@ fn batch_update_phase1_sets_updating_and_returns_execute_batch() {
     }
 }
 
+#[test]
+fn batch_update_asks_for_confirmation_with_version_preview() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, ""); // mark plugin-a
+
+    // 確認を開いた直後はプレビュー未計算（描画後の Phase 2 で計算する）
+    let effect = update(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
+    assert!(matches!(effect.phase2_msg, Some(Msg::CalculatePreview)));
+    assert!(matches!(
+        &model,
+        InstalledScreenModel::Confirm {
+            action: ConfirmAction::BatchUpdate,
+            preview: PreviewState::Calculating,
+            ..
+        }
+    ));
+
+    update(
+        &mut model,
+        &mut history,
+        Msg::CalculatePreview,
+        &mut data,
+        "",
+    );
+    let InstalledScreenModel::Confirm {
+        preview: PreviewState::Ready(preview),
+        ..
+    } = &model
+    else {
+        panic!("Expected a calculated preview");
+    };
+    assert_eq!(preview.versions.len(), 1);
+    assert_eq!(preview.versions[0].plugin, "plugin-a");
+    assert_eq!(preview.versions[0].current, "1.0.0");
+    assert_eq!(preview.versions[0].latest, None);
+}
+
+#[test]
+fn batch_update_cancel_keeps_marks_without_updating() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
+    update(
+        &mut model,
+        &mut history,
+        Msg::CalculatePreview,
+        &mut data,
+        "",
+    );
+
+    let effect = update(&mut model, &mut history, Msg::CancelConfirm, &mut data, "");
+
+    assert!(effect.phase2_msg.is_none());
+    if let InstalledScreenModel::PluginList {
+        marked_ids,
+        update_statuses,
+        ..
+    } = &model
+    {
+        assert!(marked_ids.contains(&id("plugin-a")));
+        assert!(update_statuses.is_empty(), "nothing should be updated");
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
 #[test]
 fn batch_update_phase1_clears_stale_statuses() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // plugin-a をマークして Phase 1 実行（stale ステータスを作る）
-    update(&mut model, Msg::ToggleMark, &mut data, "");
-    update(&mut model, Msg::BatchUpdate, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    update_confirmed(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
 
     // 手動で stale ステータスを残す（Phase 2 後の状態をシミュレート）
     if let InstalledScreenModel::PluginList {
@@ -254,28 +376,28 @@ fn batch_update_phase1_clears_stale_statuses() {
         ..
     } = &mut model
     {
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updated);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updated);
         marked_ids.clear();
         // plugin-b のみマーク
-        marked_ids.insert("plugin-b".to_string());
+        marked_ids.insert(id("plugin-b"));
     }
 
     // 新しい BatchUpdate: plugin-a の stale ステータスがクリアされるべき
-    let effect = update(&mut model, Msg::BatchUpdate, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::BatchUpdate, &mut data, "");
 
-    assert!(effect.needs_execute_batch);
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)));
 
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
     } = &model
     {
         assert!(
-            !update_statuses.contains_key("plugin-a"),
+            !update_statuses.contains_key(&id("plugin-a")),
             "Stale status for plugin-a should be cleared"
         );
         assert!(
             matches!(
-                update_statuses.get("plugin-b"),
+                update_statuses.get(&id("plugin-b")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-b should be Updating"
@@ -293,19 +415,20 @@ fn batch_update_phase1_clears_stale_statuses() {
 fn update_now_transitions_to_plugin_list_with_updating_status() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // PluginList → PluginDetail に遷移
-    update(&mut model, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     assert!(matches!(model, InstalledScreenModel::PluginDetail { .. }));
 
     // PluginDetail で UpdateNow のインデックスに移動
     // for_enabled(): [Disable, MarkForUpdate, UpdateNow, Uninstall, ViewComponents, Back]
     // UpdateNow はインデックス 2
-    update(&mut model, Msg::Down, &mut data, ""); // index 1
-    update(&mut model, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 1
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
 
     // Enter で UpdateNow を実行
-    let effect = update(&mut model, Msg::Enter, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // PluginList に遷移していること
     assert!(
@@ -315,7 +438,7 @@ fn update_now_transitions_to_plugin_list_with_updating_status() {
 
     // needs_execute_batch が true であること
     assert!(
-        effect.needs_execute_batch,
+        matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)),
         "UpdateNow should request execute_batch"
     );
 
@@ -326,7 +449,7 @@ fn update_now_transitions_to_plugin_list_with_updating_status() {
     {
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-a should be Updating"
@@ -345,24 +468,25 @@ fn update_now_transitions_to_plugin_list_with_updating_status() {
 fn update_now_restores_saved_marks() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // plugin-a をマーク
-    update(&mut model, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
 
     // PluginList → PluginDetail (plugin-a) に遷移
-    update(&mut model, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // UpdateNow のインデックスに移動 (index 2)
-    update(&mut model, Msg::Down, &mut data, ""); // index 1
-    update(&mut model, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 1
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
 
     // Enter で UpdateNow を実行
-    update(&mut model, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // マーク状態が復元されていること
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
         assert!(
-            marked_ids.contains("plugin-a"),
+            marked_ids.contains(&id("plugin-a")),
             "plugin-a mark should be restored"
         );
     } else {
@@ -374,57 +498,105 @@ fn update_now_restores_saved_marks() {
 fn update_now_returns_execute_batch_effect() {
     let (_temp_dir, mut data) = make_data(&["plugin-a"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // PluginList → PluginDetail に遷移
-    update(&mut model, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // UpdateNow のインデックスに移動 (index 2)
-    update(&mut model, Msg::Down, &mut data, ""); // index 1
-    update(&mut model, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 1
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
 
-    let effect = update(&mut model, Msg::Enter, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
-        effect.needs_execute_batch,
+        matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)),
         "UpdateNow should return execute_batch effect"
     );
     assert!(!effect.should_focus_filter);
 }
 
+#[test]
+fn uninstall_asks_for_confirmation_and_back_returns_to_detail() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for _ in 0..3 {
+        update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 3 (Uninstall)
+    }
+
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    assert!(matches!(effect.phase2_msg, Some(Msg::CalculatePreview)));
+    assert!(matches!(
+        &model,
+        InstalledScreenModel::Confirm {
+            action: ConfirmAction::Uninstall(plugin_id),
+            ..
+        } if *plugin_id == id("plugin-a")
+    ));
+    assert!(data.find_plugin(&id("plugin-a")).is_some());
+
+    update(
+        &mut model,
+        &mut history,
+        Msg::CalculatePreview,
+        &mut data,
+        "",
+    );
+    assert!(matches!(
+        &model,
+        InstalledScreenModel::Confirm {
+            preview: PreviewState::Ready(preview),
+            ..
+        } if preview.removed_files.as_ref().is_some_and(|files| files.is_empty())
+    ));
+
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
+    match &model {
+        InstalledScreenModel::PluginDetail { plugin_id, state } => {
+            assert_eq!(*plugin_id, id("plugin-a"));
+            assert_eq!(state.selected(), Some(3));
+        }
+        _ => panic!("Expected PluginDetail"),
+    }
+}
+
 #[test]
 fn update_now_clears_stale_statuses() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    // PluginList → PluginDetail に遷移
-    update(&mut model, Msg::Enter, &mut data, "");
-
-    // saved_update_statuses に stale ステータスを注入
-    if let InstalledScreenModel::PluginDetail {
-        saved_update_statuses,
-        ..
+    // 前回バッチの stale ステータスを注入
+    if let InstalledScreenModel::PluginList {
+        update_statuses, ..
     } = &mut model
     {
-        saved_update_statuses.insert("plugin-b".to_string(), UpdateStatusDisplay::Updated);
+        update_statuses.insert(id("plugin-b"), UpdateStatusDisplay::Updated);
     }
 
+    // PluginList → PluginDetail に遷移
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
     // UpdateNow のインデックスに移動 (index 2)
-    update(&mut model, Msg::Down, &mut data, ""); // index 1
-    update(&mut model, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 1
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 2 (UpdateNow)
 
-    update(&mut model, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
     } = &model
     {
         assert!(
-            !update_statuses.contains_key("plugin-b"),
+            !update_statuses.contains_key(&id("plugin-b")),
             "Stale status for plugin-b should be cleared"
         );
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-a should be Updating"
@@ -434,6 +606,104 @@ fn update_now_clears_stale_statuses() {
     }
 }
 
+// ============================================================================
+// 履歴スタックによる Back テスト
+// ============================================================================
+
+#[test]
+fn back_restores_list_selection_and_filter() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "plugin");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "plugin");
+    assert_eq!(history.len(), 1);
+
+    let effect = update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    assert_eq!(effect.restore_filter.as_deref(), Some("plugin"));
+    assert!(history.is_empty());
+    if let InstalledScreenModel::PluginList { selection, .. } = &model {
+        assert_eq!(
+            selection.selected_id().map(PluginId::name),
+            Some("plugin-b")
+        );
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
+#[test]
+fn back_restores_detail_cursor_from_component_types() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    // for_enabled(): ViewComponents はインデックス 4
+    for _ in 0..4 {
+        update(&mut model, &mut history, Msg::Down, &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    assert!(matches!(model, InstalledScreenModel::ComponentTypes { .. }));
+
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    if let InstalledScreenModel::PluginDetail { state, .. } = &model {
+        assert_eq!(state.selected(), Some(4));
+    } else {
+        panic!("Expected PluginDetail");
+    }
+}
+
+#[test]
+fn back_selects_nearest_item_when_restored_plugin_is_gone() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    data.remove_plugin(&id("plugin-c"));
+
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    if let InstalledScreenModel::PluginList { selection, .. } = &model {
+        assert_eq!(
+            selection.selected_id().map(PluginId::name),
+            Some("plugin-b")
+        );
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
+#[test]
+fn back_without_history_falls_back_to_parent_screen() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
+    let mut model = InstalledScreenModel::PluginDetail {
+        plugin_id: id("plugin-b"),
+        state: ratatui::widgets::ListState::default(),
+    };
+    let mut history = NavigationHistory::default();
+
+    let effect = update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    assert!(effect.restore_filter.is_none());
+    if let InstalledScreenModel::PluginList { selection, .. } = &model {
+        assert_eq!(
+            selection.selected_id().map(PluginId::name),
+            Some("plugin-b")
+        );
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
 // ============================================================================
 // UpdateAll テスト
 // ============================================================================
@@ -442,11 +712,12 @@ fn update_now_clears_stale_statuses() {
 fn update_all_sets_all_plugins_to_updating() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(
-        effect.needs_execute_batch,
+        matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)),
         "UpdateAll should request execute_batch"
     );
 
@@ -456,15 +727,15 @@ fn update_all_sets_all_plugins_to_updating() {
     {
         assert_eq!(update_statuses.len(), 3);
         assert!(matches!(
-            update_statuses.get("plugin-a"),
+            update_statuses.get(&id("plugin-a")),
             Some(UpdateStatusDisplay::Updating)
         ));
         assert!(matches!(
-            update_statuses.get("plugin-b"),
+            update_statuses.get(&id("plugin-b")),
             Some(UpdateStatusDisplay::Updating)
         ));
         assert!(matches!(
-            update_statuses.get("plugin-c"),
+            update_statuses.get(&id("plugin-c")),
             Some(UpdateStatusDisplay::Updating)
         ));
     } else {
@@ -476,11 +747,12 @@ fn update_all_sets_all_plugins_to_updating() {
 fn update_all_ignores_filter() {
     let (_temp_dir, mut data) = make_data(&["alpha", "beta", "gamma"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // フィルタありでも全プラグインが Updating になること
-    let effect = update(&mut model, Msg::UpdateAll, &mut data, "alpha");
+    let effect = update_confirmed(&mut model, &mut history, Msg::UpdateAll, &mut data, "alpha");
 
-    assert!(effect.needs_execute_batch);
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)));
 
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
@@ -491,9 +763,9 @@ fn update_all_ignores_filter() {
             3,
             "All plugins should be Updating regardless of filter"
         );
-        assert!(update_statuses.contains_key("alpha"));
-        assert!(update_statuses.contains_key("beta"));
-        assert!(update_statuses.contains_key("gamma"));
+        assert!(update_statuses.contains_key(&id("alpha")));
+        assert!(update_statuses.contains_key(&id("beta")));
+        assert!(update_statuses.contains_key(&id("gamma")));
     } else {
         panic!("Expected PluginList");
     }
@@ -503,11 +775,12 @@ fn update_all_ignores_filter() {
 fn update_all_on_empty_list_does_nothing() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(
-        !effect.needs_execute_batch,
+        effect.phase2_msg.is_none(),
         "UpdateAll on empty list should not trigger execute_batch"
     );
 }
@@ -516,18 +789,19 @@ fn update_all_on_empty_list_does_nothing() {
 fn update_all_clears_stale_statuses() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b"]);
     let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // stale ステータスを手動でセット
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
     } = &mut model
     {
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updated);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updated);
     }
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data, "");
+    let effect = update_confirmed(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
-    assert!(effect.needs_execute_batch);
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)));
 
     if let InstalledScreenModel::PluginList {
         update_statuses, ..
@@ -536,14 +810,14 @@ fn update_all_clears_stale_statuses() {
         // stale ステータスがクリアされ、全プラグインが Updating であること
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-a should be Updating, not stale Updated"
         );
         assert!(
             matches!(
-                update_statuses.get("plugin-b"),
+                update_statuses.get(&id("plugin-b")),
                 Some(UpdateStatusDisplay::Updating)
             ),
             "plugin-b should be Updating"
@@ -570,16 +844,16 @@ fn execute_batch_removes_stale_marks_for_nonexistent_plugins() {
         ..
     } = &mut model
     {
-        marked_ids.insert("plugin-a".to_string());
-        marked_ids.insert("plugin-removed".to_string());
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
+        marked_ids.insert(id("plugin-a"));
+        marked_ids.insert(id("plugin-removed"));
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
     }
 
     execute_batch_with(&mut model, &mut data, "", stub_run_updates, stub_reload);
 
     if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
         assert!(
-            !marked_ids.contains("plugin-removed"),
+            !marked_ids.contains(&id("plugin-removed")),
             "Stale mark for nonexistent plugin should be removed after reload"
         );
     } else {
@@ -601,8 +875,8 @@ fn execute_batch_collects_from_update_statuses() {
         update_statuses, ..
     } = &mut model
     {
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
-        update_statuses.insert("plugin-c".to_string(), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-c"), UpdateStatusDisplay::Updating);
     }
 
     execute_batch_with(&mut model, &mut data, "", stub_run_updates, stub_reload);
@@ -614,20 +888,20 @@ fn execute_batch_collects_from_update_statuses() {
     {
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Updated)
             ),
             "plugin-a should be Updated"
         );
         assert!(
             matches!(
-                update_statuses.get("plugin-c"),
+                update_statuses.get(&id("plugin-c")),
                 Some(UpdateStatusDisplay::Updated)
             ),
             "plugin-c should be Updated"
         );
         assert!(
-            !update_statuses.contains_key("plugin-b"),
+            !update_statuses.contains_key(&id("plugin-b")),
             "plugin-b should have no status (was not Updating)"
         );
     } else {
@@ -635,6 +909,123 @@ fn execute_batch_collects_from_update_statuses() {
     }
 }
 
+#[test]
+fn execute_batch_passes_marketplace_for_same_name_plugins() {
+    let official = InstalledPlugin::new_for_test(
+        "shared",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        true,
+    );
+    let direct = InstalledPlugin::new_for_test("shared", "1.0.0", Vec::new(), None, None, true);
+    let (_temp_dir, mut data) = DataStore::for_test(vec![official, direct], vec![], None);
+    let mut model = InstalledScreenModel::new(&data);
+
+    // official 側のみ Updating
+    if let InstalledScreenModel::PluginList {
+        update_statuses, ..
+    } = &mut model
+    {
+        update_statuses.insert(
+            PluginId::new(Some("official"), "shared"),
+            UpdateStatusDisplay::Updating,
+        );
+    }
+
+    let mut received: Vec<PluginId> = Vec::new();
+    execute_batch_with(
+        &mut model,
+        &mut data,
+        "",
+        |ids| {
+            received = ids.to_vec();
+            stub_run_updates(ids)
+        },
+        stub_reload,
+    );
+
+    assert_eq!(received, vec![PluginId::new(Some("official"), "shared")]);
+    if let InstalledScreenModel::PluginList {
+        update_statuses, ..
+    } = &model
+    {
+        assert_eq!(
+            update_statuses.get(&PluginId::new(Some("official"), "shared")),
+            Some(&UpdateStatusDisplay::Updated)
+        );
+        assert!(
+            !update_statuses.contains_key(&id("shared")),
+            "same-name plugin without marketplace should not be updated"
+        );
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
+#[test]
+fn update_all_keys_same_name_plugins_separately() {
+    let official = InstalledPlugin::new_for_test(
+        "shared",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        true,
+    );
+    let direct = InstalledPlugin::new_for_test("shared", "1.0.0", Vec::new(), None, None, true);
+    let (_temp_dir, mut data) = DataStore::for_test(vec![official, direct], vec![], None);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update_confirmed(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
+
+    if let InstalledScreenModel::PluginList {
+        update_statuses, ..
+    } = &model
+    {
+        assert_eq!(update_statuses.len(), 2);
+        assert!(update_statuses.contains_key(&PluginId::new(Some("official"), "shared")));
+        assert!(update_statuses.contains_key(&id("shared")));
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
+#[test]
+fn same_name_plugins_are_marked_and_opened_independently() {
+    let (_temp_dir, mut data) = DataStore::for_test(
+        vec![make_plugin("shared"), make_plugin_in("official", "shared")],
+        vec![],
+        None,
+    );
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    let official = PluginId::new(Some("official"), "shared");
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+
+    if let InstalledScreenModel::PluginList { marked_ids, .. } = &model {
+        assert!(marked_ids.contains(&official));
+        assert!(
+            !marked_ids.contains(&id("shared")),
+            "same-name plugin from another marketplace should stay unmarked"
+        );
+    } else {
+        panic!("Expected PluginList");
+    }
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let InstalledScreenModel::PluginDetail { plugin_id, .. } = &model {
+        assert_eq!(plugin_id, &official);
+    } else {
+        panic!("Expected PluginDetail");
+    }
+}
+
 #[test]
 fn execute_batch_preserves_marks_when_not_all_marked_are_updated() {
     let (_temp_dir, mut data) = make_data(&["plugin-a", "plugin-b", "plugin-c"]);
@@ -647,10 +1038,10 @@ fn execute_batch_preserves_marks_when_not_all_marked_are_updated() {
         ..
     } = &mut model
     {
-        marked_ids.insert("plugin-a".to_string());
-        marked_ids.insert("plugin-b".to_string());
+        marked_ids.insert(id("plugin-a"));
+        marked_ids.insert(id("plugin-b"));
         // UpdateNow 経由: plugin-a のみ Updating（marked_ids は 2 つ）
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
     }
 
     execute_batch_with(&mut model, &mut data, "", stub_run_updates, stub_reload);
@@ -661,7 +1052,7 @@ fn execute_batch_preserves_marks_when_not_all_marked_are_updated() {
             "Marks should be preserved when not all marked plugins are updated"
         );
         assert!(
-            marked_ids.contains("plugin-b"),
+            marked_ids.contains(&id("plugin-b")),
             "plugin-b mark should be preserved"
         );
     } else {
@@ -681,10 +1072,10 @@ fn execute_batch_clears_marks_when_all_marked_are_updated() {
         ..
     } = &mut model
     {
-        marked_ids.insert("plugin-a".to_string());
-        marked_ids.insert("plugin-b".to_string());
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
-        update_statuses.insert("plugin-b".to_string(), UpdateStatusDisplay::Updating);
+        marked_ids.insert(id("plugin-a"));
+        marked_ids.insert(id("plugin-b"));
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-b"), UpdateStatusDisplay::Updating);
     }
 
     execute_batch_with(&mut model, &mut data, "", stub_run_updates, stub_reload);
@@ -708,15 +1099,15 @@ fn execute_batch_sets_error_on_failed_updates() {
         update_statuses, ..
     } = &mut model
     {
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
     }
 
     // スタブ: 全プラグインを Failed として返す
-    let fail_updates = |keys: &[PluginKey]| -> Vec<(PluginKey, UpdateStatusDisplay)> {
-        keys.iter()
-            .map(|k| {
+    let fail_updates = |ids: &[PluginId]| -> Vec<(PluginId, UpdateStatusDisplay)> {
+        ids.iter()
+            .map(|id| {
                 (
-                    k.clone(),
+                    id.clone(),
                     UpdateStatusDisplay::Failed("test error".to_string()),
                 )
             })
@@ -726,11 +1117,11 @@ fn execute_batch_sets_error_on_failed_updates() {
     execute_batch_with(&mut model, &mut data, "", fail_updates, stub_reload);
 
     assert!(
-        data.last_error.is_some(),
+        data.last_error().is_some(),
         "last_error should be set on failed update"
     );
     assert!(
-        data.last_error.as_ref().unwrap().contains("test error"),
+        data.last_error().unwrap().contains("test error"),
         "Error message should contain the failure reason"
     );
 
@@ -740,7 +1131,7 @@ fn execute_batch_sets_error_on_failed_updates() {
     {
         assert!(
             matches!(
-                update_statuses.get("plugin-a"),
+                update_statuses.get(&id("plugin-a")),
                 Some(UpdateStatusDisplay::Failed(_))
             ),
             "plugin-a should be Failed"
@@ -759,7 +1150,7 @@ fn execute_batch_handles_reload_error() {
         update_statuses, ..
     } = &mut model
     {
-        update_statuses.insert("plugin-a".to_string(), UpdateStatusDisplay::Updating);
+        update_statuses.insert(id("plugin-a"), UpdateStatusDisplay::Updating);
     }
 
     // スタブ: reload がエラーを返す
@@ -770,11 +1161,175 @@ fn execute_batch_handles_reload_error() {
     execute_batch_with(&mut model, &mut data, "", stub_run_updates, fail_reload);
 
     assert!(
-        data.last_error.is_some(),
+        data.last_error().is_some(),
         "last_error should be set on reload failure"
     );
     assert!(
-        data.last_error.as_ref().unwrap().contains("reload failed"),
+        data.last_error().unwrap().contains("reload failed"),
         "Error message should contain the reload failure reason"
     );
 }
+
+// ============================================================================
+// dev モード テスト
+// ============================================================================
+
+fn make_dev_data(root: &std::path::Path) -> (tempfile::TempDir, DataStore) {
+    std::fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+    std::fs::write(
+        root.join(".claude-plugin/plugin.json"),
+        r#"{"name":"dev-plugin","version":"0.1.0"}"#,
+    )
+    .unwrap();
+    let (temp_dir, data) = make_data(&[]);
+    (
+        temp_dir,
+        data.with_dev_plugin(DevPlugin::open(root.to_path_buf())),
+    )
+}
+
+#[test]
+fn dev_reload_without_dev_mode_is_noop() {
+    let (_temp_dir, mut data) = make_data(&["plugin-a"]);
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::DevReload, &mut data, "");
+
+    assert!(data.dev_plugin.is_none());
+    assert!(data.errors.is_empty());
+}
+
+#[test]
+fn dev_reload_sets_reloaded_status() {
+    let dev_dir = tempfile::TempDir::new().unwrap();
+    let (_temp_dir, mut data) = make_dev_data(dev_dir.path());
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::DevReload, &mut data, "");
+
+    let dev = data.dev_plugin.as_ref().unwrap();
+    assert_eq!(dev.status(), Some(&DevStatus::Info("Reloaded".to_string())));
+}
+
+#[test]
+fn redeploy_dev_plugin_reports_placed_count() {
+    let dev_dir = tempfile::TempDir::new().unwrap();
+    let (_temp_dir, mut data) = make_dev_data(dev_dir.path());
+
+    redeploy_dev_plugin(&mut data, |_| DevDeployOutcome::Deployed {
+        placed: 3,
+        failures: vec![],
+    });
+
+    let dev = data.dev_plugin.as_ref().unwrap();
+    assert_eq!(
+        dev.status(),
+        Some(&DevStatus::Info("Deployed 3 component(s)".to_string()))
+    );
+}
+
+#[test]
+fn redeploy_dev_plugin_reports_partial_failures_as_error() {
+    let dev_dir = tempfile::TempDir::new().unwrap();
+    let (_temp_dir, mut data) = make_dev_data(dev_dir.path());
+
+    redeploy_dev_plugin(&mut data, |_| DevDeployOutcome::Deployed {
+        placed: 1,
+        failures: vec!["codex skill: s - boom".to_string()],
+    });
+
+    let dev = data.dev_plugin.as_ref().unwrap();
+    assert!(matches!(dev.status(), Some(DevStatus::Error(msg)) if msg.contains("1 failed")));
+}
+
+#[test]
+fn redeploy_dev_plugin_skips_deploy_when_not_loaded() {
+    let dev_dir = tempfile::TempDir::new().unwrap();
+    let (_temp_dir, data) = make_data(&[]);
+    let mut data = data.with_dev_plugin(DevPlugin::open(dev_dir.path().to_path_buf()));
+
+    redeploy_dev_plugin(&mut data, |_| panic!("deploy must not run"));
+
+    let dev = data.dev_plugin.as_ref().unwrap();
+    assert!(
+        matches!(dev.status(), Some(DevStatus::Error(msg)) if msg.contains("Nothing to deploy"))
+    );
+}
+
+// ============================================================================
+// CycleSort テスト
+// ============================================================================
+
+#[test]
+fn cycle_sort_keeps_selected_plugin_at_its_new_position() {
+    let (_temp_dir, mut data) = DataStore::for_test(
+        vec![
+            make_plugin_in("zeta", "alpha"),
+            make_plugin_in("beta-mp", "beta"),
+            make_plugin_in("alpha-mp", "gamma"),
+        ],
+        vec![],
+        None,
+    );
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::CycleSort, &mut data, "");
+
+    if let InstalledScreenModel::PluginList {
+        selection,
+        sort_key,
+        ..
+    } = &model
+    {
+        assert_eq!(*sort_key, SortKey::Marketplace);
+        assert_eq!(selection.selected_id().map(PluginId::name), Some("alpha"));
+        // マーケットプレイス順（alpha-mp / beta-mp / zeta）では alpha は末尾に移る
+        assert_eq!(selection.selected_index(), Some(2));
+    } else {
+        panic!("Expected PluginList");
+    }
+
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
+    if let InstalledScreenModel::PluginList { selection, .. } = &model {
+        assert_eq!(selection.selected_id().map(PluginId::name), Some("beta"));
+    } else {
+        panic!("Expected PluginList");
+    }
+}
+
+// ============================================================================
+// ToggleListMode テスト
+// ============================================================================
+
+#[test]
+fn toggle_list_mode_switches_mode_and_reports_it() {
+    let (_temp_dir, mut data) = DataStore::for_test(
+        vec![make_plugin("alpha"), make_plugin("beta")],
+        vec![],
+        None,
+    );
+    let mut model = InstalledScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+
+    let effect = update(&mut model, &mut history, Msg::ToggleListMode, &mut data, "");
+
+    assert_eq!(effect.list_mode, Some(ListMode::Detailed));
+    if let InstalledScreenModel::PluginList {
+        selection,
+        list_mode,
+        ..
+    } = &model
+    {
+        assert_eq!(*list_mode, ListMode::Detailed);
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected PluginList");
+    }
+
+    let effect = update(&mut model, &mut history, Msg::ToggleListMode, &mut data, "");
+    assert_eq!(effect.list_mode, Some(ListMode::Compact));
+}
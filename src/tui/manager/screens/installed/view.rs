@@ -2,25 +2,33 @@
 //!
 //! 各画面状態に応じた描画ロジック。
 
-use super::model::{DetailAction, InstalledScreenModel, UpdateStatusDisplay};
-use crate::application::InstalledPlugin;
+use super::model::{ConfirmAction, DetailAction, InstalledScreenModel, UpdateStatusDisplay};
+use crate::application::{summarize_plugins, InstalledPlugin};
 use crate::component::ComponentKind;
-use crate::tui::manager::core::layout::{detail_layout, framed_layout, outer_rect};
+use crate::output::time::last_updated_summary;
+use crate::tui::manager::core::layout::{
+    align_columns, column_widths, detail_layout, framed_layout, outer_rect,
+};
+use crate::tui::manager::core::preview::render_confirm_dialog;
+use crate::tui::manager::core::spinner;
 use crate::tui::manager::core::style::{
     bordered_block, highlight_line, menu_list, selectable_list, ICON_DISABLED, ICON_ENABLED,
     LIST_ITEM_INDENT, MARK_MARKED, MARK_UNMARKED,
 };
 use crate::tui::manager::core::{
-    filter_plugins, render_filter_bar, truncate_to_width, DataStore, PluginId, Tab,
-    LIST_DECORATION_WIDTH, MIN_CONTENT_WIDTH,
+    filter_names, render_empty_state, render_filter_bar, render_tab_bar, truncate_to_width,
+    wrap_to_width, DataStore, DevPlugin, DevStatus, ListMode, PluginId, SortKey, Tab, TabBadges,
+    Theme, LIST_DECORATION_WIDTH, MIN_CONTENT_WIDTH,
 };
+use chrono::Utc;
 use ratatui::prelude::*;
-use ratatui::widgets::{Clear, ListItem, ListState, Paragraph, Tabs};
+use ratatui::widgets::{Clear, ListItem, ListState, Paragraph};
 use std::collections::{HashMap, HashSet};
 
 /// 描画用共通コンテキスト
 struct ViewCtx<'a> {
     data: &'a DataStore,
+    badges: &'a TabBadges,
     filter_text: &'a str,
     filter_focused: bool,
 }
@@ -47,17 +55,20 @@ fn component_kind_title(kind: ComponentKind) -> &'static str {
 /// * `f` - Ratatui frame to draw into.
 /// * `model` - Installed tab model to render.
 /// * `data` - Shared data store for plugins.
+/// * `badges` - Counts shown in the tab bar.
 /// * `filter_text` - Current filter input text.
 /// * `filter_focused` - Whether the filter bar currently has focus.
 pub fn view(
     f: &mut Frame,
     model: &InstalledScreenModel,
     data: &DataStore,
+    badges: &TabBadges,
     filter_text: &str,
     filter_focused: bool,
 ) {
     let ctx = ViewCtx {
         data,
+        badges,
         filter_text,
         filter_focused,
     };
@@ -66,7 +77,8 @@ pub fn view(
             selection,
             marked_ids,
             update_statuses,
-            ..
+            sort_key,
+            list_mode,
         } => {
             view_plugin_list(
                 f,
@@ -74,6 +86,8 @@ pub fn view(
                 &ctx,
                 marked_ids,
                 update_statuses,
+                *sort_key,
+                *list_mode,
             );
         }
         InstalledScreenModel::PluginDetail {
@@ -94,6 +108,51 @@ pub fn view(
         } => {
             view_component_list(f, plugin_id, *kind, *state, &ctx);
         }
+        InstalledScreenModel::Confirm {
+            action,
+            preview,
+            parent,
+        } => {
+            // 確認を開いた画面の上にダイアログを重ねる
+            view(f, parent, data, badges, filter_text, filter_focused);
+            let (title, question) = confirm_text(action, parent, data);
+            render_confirm_dialog(f, title, &question, preview);
+        }
+    }
+}
+
+/// 確認ダイアログのタイトルと質問文
+///
+/// # Arguments
+///
+/// * `action` - Action waiting for confirmation.
+/// * `parent` - Screen the confirmation was opened from.
+/// * `data` - Shared data store for plugins.
+pub(super) fn confirm_text(
+    action: &ConfirmAction,
+    parent: &InstalledScreenModel,
+    data: &DataStore,
+) -> (&'static str, String) {
+    const KEYS: &str = "y: yes | n: cancel";
+    match action {
+        ConfirmAction::Uninstall(plugin_id) => (
+            " Uninstall ",
+            format!("Uninstall {}? {}", plugin_id.display_label(), KEYS),
+        ),
+        ConfirmAction::BatchUpdate => {
+            let marked = match parent {
+                InstalledScreenModel::PluginList { marked_ids, .. } => marked_ids.len(),
+                _ => 0,
+            };
+            (
+                " Update marked ",
+                format!("Update {} marked plugin(s)? {}", marked, KEYS),
+            )
+        }
+        ConfirmAction::UpdateAll => (
+            " Update all ",
+            format!("Update all {} plugin(s)? {}", data.plugins.len(), KEYS),
+        ),
     }
 }
 
@@ -119,10 +178,11 @@ fn sanitize_reason(reason: &str) -> String {
 
 /// マーク状態 / 有効状態に応じた行のベーススタイルを返す。
 fn plugin_row_style(is_marked: bool, enabled: bool) -> Style {
+    let theme = Theme::current();
     match (is_marked, enabled) {
-        (true, _) => Style::default().fg(Color::Yellow),
+        (true, _) => Style::default().fg(theme.warning),
         (false, true) => Style::default(),
-        (false, false) => Style::default().fg(Color::DarkGray),
+        (false, false) => Style::default().fg(theme.muted),
     }
 }
 
@@ -139,53 +199,123 @@ fn plugin_row_prefix(is_marked: bool, enabled: bool) -> String {
     format!("{}{} {} ", LIST_ITEM_INDENT, mark, icon)
 }
 
-/// 名前 + (marketplace) + バージョン + (disabled) を組み立てる。
-fn plugin_row_name_text(plugin: &InstalledPlugin) -> String {
-    let marketplace_str = plugin
+/// `plugin_row_cells` の version 列の位置
+const VERSION_COLUMN: usize = 2;
+
+/// プラグイン一覧 1 行分の表示内容（列幅に揃え済み）
+pub(super) struct PluginRow<'a> {
+    plugin: &'a InstalledPlugin,
+    /// name / marketplace / version / status の各セル
+    columns: Vec<String>,
+    /// マーケットプレイス側に新しいバージョンがあるか
+    has_update: bool,
+}
+
+/// name / marketplace / version / status の 4 列を組み立てる。
+///
+/// 新しいバージョンがある場合、version 列は `"1.0.0 → 1.2.0"` になる。
+///
+/// # Arguments
+///
+/// * `plugin` - Installed plugin to format.
+/// * `latest` - Newer version offered by the marketplace, if any.
+fn plugin_row_cells(plugin: &InstalledPlugin, latest: Option<&str>) -> [String; 4] {
+    let marketplace = plugin
         .marketplace()
-        .map(|m| format!(" @{}", m))
+        .map(|m| format!("@{}", m))
         .unwrap_or_default();
-    let status_str = if plugin.enabled() { "" } else { " [disabled]" };
-    format!(
-        "{}{}  v{}{}",
-        plugin.name(),
-        marketplace_str,
-        plugin.version(),
-        status_str
-    )
+    let version = match latest {
+        Some(latest) => format!("{} → {}", plugin.version(), latest),
+        None => plugin.version().to_string(),
+    };
+    let mut markers = Vec::new();
+    if plugin.kept_kinds().is_some() {
+        markers.push("[partial]");
+    } else if !plugin.enabled() {
+        markers.push("[disabled]");
+    }
+    if plugin.pinned() {
+        markers.push("[pinned]");
+    }
+    [
+        plugin.name().to_string(),
+        marketplace,
+        version,
+        markers.join(" "),
+    ]
+}
+
+/// 表示するプラグインの行を列幅を揃えて組み立てる。
+///
+/// 更新有無は `DataStore` にキャッシュ済みの比較結果を参照する（ここでは比較しない）。
+///
+/// # Arguments
+///
+/// * `plugins` - Plugins shown in the list (after filtering), in display order.
+/// * `data` - Shared data store holding the cached update check.
+pub(super) fn build_plugin_rows<'a>(
+    plugins: &[&'a InstalledPlugin],
+    data: &DataStore,
+) -> Vec<PluginRow<'a>> {
+    let cells: Vec<[String; 4]> = plugins
+        .iter()
+        .map(|p| plugin_row_cells(p, data.available_update(p)))
+        .collect();
+    let widths = column_widths(&cells);
+    plugins
+        .iter()
+        .zip(&cells)
+        .map(|(&plugin, cells)| PluginRow {
+            plugin,
+            columns: align_columns(cells, &widths),
+            has_update: data.available_update(plugin).is_some(),
+        })
+        .collect()
 }
 
 /// 狭幅 (`content_width < MIN_CONTENT_WIDTH`) フォールバック用の Span 列。
 ///
 /// 更新ステータス Span を落とし、`prefix` のスタイルを保ったまま
-/// 残り幅 (`list_inner_width - prefix_w`) で `name_text` を切り詰めた最大 2 Span 構成。
+/// 残り幅 (`list_inner_width - prefix_w`) で本文を切り詰めた最大 2 Span 構成。
 fn narrow_plugin_row_spans<'a>(
     prefix: String,
-    name_text: &str,
+    row: &PluginRow<'_>,
     content_width: u16,
     base_style: Style,
 ) -> Vec<Span<'a>> {
     let list_inner_width = content_width.saturating_sub(LIST_DECORATION_WIDTH);
     let prefix_w = prefix.chars().count() as u16;
     let body_budget = list_inner_width.saturating_sub(prefix_w);
-    let body = truncate_to_width(name_text, body_budget);
+    let body = truncate_to_width(row.columns.concat().trim_end(), body_budget);
     vec![
         Span::styled(prefix, base_style),
         Span::styled(body, base_style),
     ]
 }
 
-/// 通常幅用の Span 列。`prefix + name_text` に更新ステータス Span を追記する。
+/// 通常幅用の Span 列。`prefix + 各列` に更新ステータス Span を追記する。
+///
+/// 新しいバージョンがある場合は version 列だけを warning 色（黄）にする。
 fn wide_plugin_row_spans<'a>(
     prefix: String,
-    name_text: String,
+    row: &PluginRow<'_>,
     update_status: Option<&'a UpdateStatusDisplay>,
     base_style: Style,
 ) -> Vec<Span<'a>> {
-    let mut spans = vec![
-        Span::styled(prefix, base_style),
-        Span::styled(name_text, base_style),
-    ];
+    let version_style = if row.has_update {
+        base_style.fg(Theme::current().warning)
+    } else {
+        base_style
+    };
+    let mut spans = vec![Span::styled(prefix, base_style)];
+    for (i, column) in row.columns.iter().enumerate() {
+        let style = if i == VERSION_COLUMN {
+            version_style
+        } else {
+            base_style
+        };
+        spans.push(Span::styled(column.clone(), style));
+    }
     if let Some(status) = update_status {
         spans.push(update_status_span(status));
     }
@@ -194,39 +324,83 @@ fn wide_plugin_row_spans<'a>(
 
 /// プラグイン 1 行分の Span 列を構築する。
 ///
-/// 通常時は `[prefix + name/version/disabled + update_status]` の複数 Span を返し、
+/// 通常時は `[prefix + name/marketplace/version/status + update_status]` の複数 Span を返し、
 /// `content_width < MIN_CONTENT_WIDTH` の狭幅時は更新ステータス Span を落とし、
 /// `prefix + body` の最大 2 Span に切り詰める。`content_width` には `outer.width` を渡す。
 pub(super) fn build_plugin_row_spans<'a>(
-    plugin: &'a InstalledPlugin,
+    row: &PluginRow<'_>,
     is_marked: bool,
     update_status: Option<&'a UpdateStatusDisplay>,
     content_width: u16,
 ) -> Vec<Span<'a>> {
-    let prefix = plugin_row_prefix(is_marked, plugin.enabled());
-    let name_text = plugin_row_name_text(plugin);
-    let base_style = plugin_row_style(is_marked, plugin.enabled());
+    let enabled = row.plugin.enabled();
+    let prefix = plugin_row_prefix(is_marked, enabled);
+    let base_style = plugin_row_style(is_marked, enabled);
 
     if content_width < MIN_CONTENT_WIDTH {
-        narrow_plugin_row_spans(prefix, &name_text, content_width, base_style)
+        narrow_plugin_row_spans(prefix, row, content_width, base_style)
     } else {
-        wide_plugin_row_spans(prefix, name_text, update_status, base_style)
+        wide_plugin_row_spans(prefix, row, update_status, base_style)
     }
 }
 
 /// プラグイン 1 行分の `ListItem` を構築する。
 ///
 /// `is_selected = true` のときは内容行の Span に `highlight_style()` を patch する。
-/// 空行 (2 行目) には適用しないため、選択時に空行が緑背景にならない。
+/// 空行 (末尾) には適用しないため、選択時に空行が緑背景にならない。
+/// 詳細表示では内容行と空行の間に説明行を挟む（説明文が無いプラグインは挟まない）。
 pub(super) fn build_plugin_row<'a>(
-    plugin: &'a InstalledPlugin,
+    row: &PluginRow<'_>,
     is_marked: bool,
     update_status: Option<&'a UpdateStatusDisplay>,
     content_width: u16,
     is_selected: bool,
+    list_mode: ListMode,
 ) -> ListItem<'a> {
-    let spans = build_plugin_row_spans(plugin, is_marked, update_status, content_width);
-    ListItem::new(vec![highlight_line(spans, is_selected), Line::raw("")])
+    let spans = build_plugin_row_spans(row, is_marked, update_status, content_width);
+    let mut lines = vec![highlight_line(spans, is_selected)];
+    if list_mode == ListMode::Detailed {
+        lines.extend(plugin_description_line(
+            row.plugin,
+            is_marked,
+            content_width,
+        ));
+    }
+    lines.push(Line::raw(""));
+    ListItem::new(lines)
+}
+
+/// 詳細表示の説明行（名前の列に揃えて 1 行に切り詰め、muted 色で表示する）
+///
+/// 説明文が無い・空の場合は `None`。
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin whose description is shown.
+/// * `is_marked` - Whether the row is marked (affects the prefix width).
+/// * `content_width` - Outer width of the list (`outer.width`).
+fn plugin_description_line(
+    plugin: &InstalledPlugin,
+    is_marked: bool,
+    content_width: u16,
+) -> Option<Line<'static>> {
+    let description = plugin.description()?.lines().next()?.trim();
+    if description.is_empty() {
+        return None;
+    }
+    let indent = plugin_row_prefix(is_marked, plugin.enabled())
+        .chars()
+        .count();
+    let budget = content_width
+        .saturating_sub(LIST_DECORATION_WIDTH)
+        .saturating_sub(indent as u16);
+    Some(Line::from(vec![
+        Span::raw(" ".repeat(indent)),
+        Span::styled(
+            truncate_to_width(description, budget),
+            Style::default().fg(Theme::current().muted),
+        ),
+    ]))
 }
 
 /// 詳細画面の action メニュー項目を 1 行 ListItem (内容のみ) として構築する。
@@ -278,9 +452,20 @@ fn build_component_types_item(
 /// `view_component_list` のリスト項目を 1 行 ListItem (内容のみ) として構築する。
 ///
 /// `is_selected = true` のとき内容行の Span に `highlight_style()` を patch する。
-fn build_component_list_item(component_name: &str, is_selected: bool) -> ListItem<'static> {
+/// コンポーネント単位で無効化されている場合は末尾に `(disabled)` を付ける。
+fn build_component_list_item(
+    component_name: &str,
+    disabled: bool,
+    is_selected: bool,
+) -> ListItem<'static> {
     let line_text = format!("{}{}", LIST_ITEM_INDENT, component_name);
-    let spans = vec![Span::raw(line_text)];
+    let mut spans = vec![Span::raw(line_text)];
+    if disabled {
+        spans.push(Span::styled(
+            " (disabled)",
+            Style::default().fg(Theme::current().muted),
+        ));
+    }
     ListItem::new(vec![highlight_line(spans, is_selected)])
 }
 
@@ -290,25 +475,110 @@ fn build_component_list_item(component_name: &str, is_selected: bool) -> ListIte
 ///
 /// * `status` - Update status to format as a `Span`.
 fn update_status_span(status: &UpdateStatusDisplay) -> Span<'_> {
+    let theme = Theme::current();
     match status {
-        UpdateStatusDisplay::Updating => {
-            Span::styled(" Updating...", Style::default().fg(Color::Yellow))
+        UpdateStatusDisplay::Updating => Span::styled(
+            format!(" {} Updating...", spinner::frame()),
+            Style::default().fg(theme.warning),
+        ),
+        UpdateStatusDisplay::Updated => {
+            Span::styled(" Updated", Style::default().fg(theme.success))
         }
-        UpdateStatusDisplay::Updated => Span::styled(" Updated", Style::default().fg(Color::Green)),
         UpdateStatusDisplay::AlreadyUpToDate => {
-            Span::styled(" Up to date", Style::default().fg(Color::DarkGray))
+            Span::styled(" Up to date", Style::default().fg(theme.muted))
         }
         UpdateStatusDisplay::Skipped(reason) => {
             let text = format!(" Skipped: {}", sanitize_reason(reason));
-            Span::styled(text, Style::default().fg(Color::DarkGray))
+            Span::styled(text, Style::default().fg(theme.muted))
         }
         UpdateStatusDisplay::Failed(reason) => {
             let text = format!(" Failed: {}", sanitize_reason(reason));
-            Span::styled(text, Style::default().fg(Color::Red))
+            Span::styled(text, Style::default().fg(theme.error))
         }
     }
 }
 
+/// dev パネルの高さ（枠 2 行 + プラグイン行 + ステータス行）
+const DEV_PANEL_HEIGHT: u16 = 4;
+
+/// dev プラグイン行（`[dev]` マーク + 名前 + バージョン + コンポーネント件数）を構築する。
+///
+/// 読み込みに失敗している場合はディレクトリパスのみを表示する。
+pub(super) fn build_dev_plugin_line(dev: &DevPlugin) -> Line<'static> {
+    let theme = Theme::current();
+    let mark = Span::styled(
+        "[dev] ",
+        Style::default()
+            .fg(theme.special)
+            .add_modifier(Modifier::BOLD),
+    );
+    let Some(plugin) = dev.plugin() else {
+        return Line::from(vec![
+            mark,
+            Span::styled(
+                format!("{} (not loaded)", dev.root().display()),
+                Style::default().fg(theme.muted),
+            ),
+        ]);
+    };
+    let counts: Vec<String> = plugin
+        .component_type_counts()
+        .iter()
+        .map(|(kind, count)| format!("{} {}", count, component_kind_title(*kind)))
+        .collect();
+    let counts_text = if counts.is_empty() {
+        "no components".to_string()
+    } else {
+        counts.join(", ")
+    };
+    Line::from(vec![
+        mark,
+        Span::raw(format!("{}  v{}", plugin.name(), plugin.version())),
+        Span::styled(
+            format!("  ({})", counts_text),
+            Style::default().fg(theme.muted),
+        ),
+    ])
+}
+
+/// dev プラグインのステータス行を構築する。
+///
+/// ステータス未設定時は監視中のディレクトリを表示する。
+pub(super) fn build_dev_status_line(dev: &DevPlugin) -> Line<'static> {
+    let theme = Theme::current();
+    match dev.status() {
+        Some(DevStatus::Info(msg)) => Line::from(Span::styled(
+            format!("{}{}", LIST_ITEM_INDENT, sanitize_reason(msg)),
+            Style::default().fg(theme.success),
+        )),
+        Some(DevStatus::Error(msg)) => Line::from(Span::styled(
+            format!("{}{}", LIST_ITEM_INDENT, sanitize_reason(msg)),
+            Style::default().fg(theme.error),
+        )),
+        None => Line::from(Span::styled(
+            format!("{}Watching {}", LIST_ITEM_INDENT, dev.root().display()),
+            Style::default().fg(theme.muted),
+        )),
+    }
+}
+
+/// dev パネルを描画し、残りのコンテンツ領域を返す。
+///
+/// dev モードでない場合は何も描画せず `area` をそのまま返す。
+fn render_dev_panel(f: &mut Frame, area: Rect, data: &DataStore) -> Rect {
+    let Some(dev) = data.dev_plugin.as_ref() else {
+        return area;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(DEV_PANEL_HEIGHT), Constraint::Min(1)])
+        .split(area);
+    let panel = Paragraph::new(vec![build_dev_plugin_line(dev), build_dev_status_line(dev)])
+        .block(bordered_block(" Dev Plugin "));
+    f.render_widget(panel, chunks[0]);
+    chunks[1]
+}
+
 /// プラグイン一覧画面を描画
 ///
 /// # Arguments
@@ -316,33 +586,28 @@ fn update_status_span(status: &UpdateStatusDisplay) -> Span<'_> {
 /// * `f` - Ratatui frame to draw into.
 /// * `state` - List state used for highlight/selection.
 /// * `ctx` - Shared view context (data store + filter state).
-/// * `marked_ids` - Plugin ids currently marked for batch actions.
-/// * `update_statuses` - Per-plugin update status for indicator display.
+/// * `marked_ids` - Normalized plugin ids currently marked for batch actions.
+/// * `update_statuses` - Per-plugin update status keyed by normalized plugin id.
+/// * `sort_key` - Sort order of the list, shown in the help line.
+/// * `list_mode` - Whether each row also shows the plugin description.
 fn view_plugin_list(
     f: &mut Frame,
     mut state: ListState,
     ctx: &ViewCtx<'_>,
     marked_ids: &HashSet<PluginId>,
     update_statuses: &HashMap<PluginId, UpdateStatusDisplay>,
+    sort_key: SortKey,
+    list_mode: ListMode,
 ) {
-    let filtered = filter_plugins(&ctx.data.plugins, ctx.filter_text);
+    let theme = Theme::current();
+    let filtered = ctx.data.sorted_plugins(sort_key, ctx.filter_text);
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
 
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    let tab_titles: Vec<&str> = Tab::all().iter().map(|t| t.title()).collect();
-    let tabs = Tabs::new(tab_titles)
-        .select(Tab::Installed.index())
-        .style(Style::default().fg(Color::DarkGray))
-        .highlight_style(
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .divider(" | ");
-    f.render_widget(tabs, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Installed, Some(ctx.badges));
 
     // フィルタバー
     render_filter_bar(f, filter_area, ctx.filter_text, ctx.filter_focused);
@@ -364,22 +629,44 @@ fn view_plugin_list(
         )
     };
 
+    // リスト枠の下に集計フッターを常時表示
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(content_area);
+    let (content_area, summary_area) = (chunks[0], chunks[1]);
+
+    // dev モード時は一覧の上に dev プラグイン行を表示
+    let content_area = render_dev_panel(f, content_area, ctx.data);
+
     // プラグインリスト（フィルタ済み）
     if filtered.is_empty() {
-        let no_match = Paragraph::new("  No matching plugins")
-            .block(bordered_block(&title))
-            .style(Style::default().fg(Color::DarkGray));
-        f.render_widget(no_match, content_area);
+        render_empty_state(
+            f,
+            content_area,
+            &title,
+            ctx.filter_text,
+            "No plugins installed",
+        );
     } else {
         let selected_idx = state.selected();
-        let items: Vec<ListItem> = filtered
+        let rows = build_plugin_rows(&filtered, ctx.data);
+        let items: Vec<ListItem> = rows
             .iter()
             .enumerate()
-            .map(|(i, p)| {
-                let is_marked = marked_ids.contains(p.id());
-                let update_status = update_statuses.get(p.id());
+            .map(|(i, row)| {
+                let uid = PluginId::of(row.plugin);
+                let is_marked = marked_ids.contains(&uid);
+                let update_status = update_statuses.get(&uid);
                 let is_selected = Some(i) == selected_idx;
-                build_plugin_row(p, is_marked, update_status, outer.width, is_selected)
+                build_plugin_row(
+                    row,
+                    is_marked,
+                    update_status,
+                    outer.width,
+                    is_selected,
+                    list_mode,
+                )
             })
             .collect();
 
@@ -388,14 +675,44 @@ fn view_plugin_list(
         f.render_stateful_widget(list, content_area, &mut state);
     }
 
-    // ヘルプ
-    let help = Paragraph::new(
-        " Space: mark | a: all | U: update | A: update all | Tab: switch | ↑↓: move | Enter: details | q: quit",
-    )
-    .style(Style::default().fg(Color::DarkGray));
+    // 集計（フィルタ適用中は showing N of M を併記）
+    let totals = summarize_plugins(&ctx.data.plugins);
+    let footer = totals.footer(filtered.len());
+    let summary = Paragraph::new(summary_text(&footer, ctx.data.last_notice.as_deref()))
+        .style(Style::default().fg(theme.muted));
+    f.render_widget(summary, summary_area);
+
+    // ヘルプ（現在のソートキーと表示モードを表示）
+    let help_text = if ctx.data.dev_plugin.is_some() {
+        format!(
+            " r: reload dev | d: deploy dev | Space: mark | s: sort ({}) | v: view ({}) | U: update | Tab: switch | ↑↓: move | Enter: details | q: quit",
+            sort_key.label(),
+            list_mode.label()
+        )
+    } else {
+        format!(
+            " Space: mark | a: all | s: sort ({}) | v: view ({}) | U: update | A: update all | Tab: switch | ↑↓: move | Enter: details | q: quit",
+            sort_key.label(),
+            list_mode.label()
+        )
+    };
+    let help = Paragraph::new(help_text).style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
+/// 一覧下部の集計行（直前の操作の案内があれば `|` 区切りで併記）
+///
+/// # Arguments
+///
+/// * `footer` - Plugin totals footer.
+/// * `notice` - Follow-up notice from the last action, if any.
+pub(super) fn summary_text(footer: &str, notice: Option<&str>) -> String {
+    match notice {
+        Some(notice) => format!(" {} | {}", footer, notice),
+        None => format!(" {}", footer),
+    }
+}
+
 /// プラグイン詳細画面を描画
 ///
 /// # Arguments
@@ -410,6 +727,7 @@ fn view_plugin_detail(
     mut state: ListState,
     ctx: &ViewCtx<'_>,
 ) {
+    let theme = Theme::current();
     let Some(plugin) = ctx.data.find_plugin(plugin_id) else {
         return;
     };
@@ -420,17 +738,7 @@ fn view_plugin_detail(
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    let tab_titles: Vec<&str> = Tab::all().iter().map(|t| t.title()).collect();
-    let tabs = Tabs::new(tab_titles)
-        .select(Tab::Installed.index())
-        .style(Style::default().fg(Color::DarkGray))
-        .highlight_style(
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .divider(" | ");
-    f.render_widget(tabs, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Installed, Some(ctx.badges));
 
     // フィルタバー（read-only）
     render_filter_bar(f, filter_area, ctx.filter_text, ctx.filter_focused);
@@ -442,27 +750,6 @@ fn view_plugin_detail(
         .unwrap_or_default();
     let title = format!(" {}{} ", plugin.name(), marketplace_str);
 
-    let (status_text, status_color) = if plugin.enabled() {
-        ("Enabled", Color::Green)
-    } else {
-        ("Disabled", Color::DarkGray)
-    };
-
-    let info_lines = vec![
-        Line::from(vec![
-            Span::raw("Scope: "),
-            Span::styled("project", Style::default().fg(Color::White)),
-            Span::raw("    Version: "),
-            Span::styled(plugin.version(), Style::default().fg(Color::White)),
-        ]),
-        Line::from(vec![
-            Span::raw("Author: "),
-            Span::styled("N/A", Style::default().fg(Color::DarkGray)),
-            Span::raw("    Status: "),
-            Span::styled(status_text, Style::default().fg(status_color)),
-        ]),
-    ];
-
     // アクションメニュー（enabled 状態に応じて動的に切り替え）
     let actions = DetailAction::for_plugin(plugin.enabled());
     let (items, action_menu_rows) = build_detail_action_menu(&actions, state.selected());
@@ -474,6 +761,14 @@ fn view_plugin_detail(
 
     let [info_area, menu_area] = detail_layout(inner_area, action_menu_rows);
 
+    let mut info_lines = plugin_info_lines(plugin, info_area.width);
+    if let Some(notice) = &ctx.data.last_notice {
+        info_lines.push(Line::from(""));
+        info_lines.push(Line::from(Span::styled(
+            notice.clone(),
+            Style::default().fg(theme.muted),
+        )));
+    }
     let info_para = Paragraph::new(info_lines);
     f.render_widget(info_para, info_area);
 
@@ -482,10 +777,132 @@ fn view_plugin_detail(
 
     // ヘルプ
     let help = Paragraph::new(" Navigate: ↑↓ • Select: Enter • Back: Esc")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
+/// 先頭の 1 文字を大文字にする（詳細画面の Status 表示用）
+///
+/// # Arguments
+///
+/// * `text` - Label to capitalize.
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// 詳細画面で表示する説明文の最大行数
+const DESCRIPTION_MAX_LINES: usize = 2;
+
+/// 説明文の 2 行目以降のインデント（`"Description: "` の幅）
+const DESCRIPTION_INDENT: &str = "             ";
+
+/// プラグイン詳細の情報行を組み立てる
+///
+/// Description / Homepage / Repository / Keywords は plugin.json に値がある場合のみ表示する。
+/// Last updated は meta にインストール・更新日時が記録されている場合のみ表示する。
+/// Description は `width` で折り返し、2 行を超える分は切り詰める。
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin whose metadata is shown.
+/// * `width` - Width of the info area in cells.
+fn plugin_info_lines(plugin: &InstalledPlugin, width: u16) -> Vec<Line<'static>> {
+    let theme = Theme::current();
+    let (status_text, status_color) = match (plugin.enabled(), plugin.kept_kinds()) {
+        (true, _) => ("Enabled".to_string(), theme.success),
+        (false, Some(_)) => (capitalize(&plugin.status_label()), theme.warning),
+        (false, None) => ("Disabled".to_string(), theme.muted),
+    };
+    let author = match plugin.author() {
+        Some(author) => Span::styled(author.name.clone(), Style::default().fg(theme.text)),
+        None => Span::styled("N/A", Style::default().fg(theme.muted)),
+    };
+    let scopes: Vec<&str> = plugin.scopes().iter().map(|s| s.as_str()).collect();
+    let scope = if scopes.is_empty() {
+        Span::styled("N/A", Style::default().fg(theme.muted))
+    } else {
+        Span::styled(scopes.join(", "), Style::default().fg(theme.text))
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("Scope: "),
+            scope,
+            Span::raw("    Version: "),
+            Span::styled(
+                plugin.version().to_string(),
+                Style::default().fg(theme.text),
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("Author: "),
+            author,
+            Span::raw("    Status: "),
+            Span::styled(status_text, Style::default().fg(status_color)),
+        ]),
+    ];
+
+    if let Some(summary) =
+        last_updated_summary(plugin.last_updated(), plugin.last_update(), Utc::now())
+    {
+        lines.push(Line::from(vec![
+            Span::raw("Last updated: "),
+            Span::styled(summary, Style::default().fg(theme.text)),
+        ]));
+    }
+
+    lines.extend(description_lines(plugin, width));
+
+    let keywords = plugin.keywords().join(", ");
+    let optional = [
+        ("Homepage", plugin.homepage().map(str::to_string)),
+        ("Repository", plugin.repository().map(str::to_string)),
+        ("Keywords", Some(keywords).filter(|k| !k.is_empty())),
+    ];
+    for (label, value) in optional {
+        if let Some(value) = value {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{}: ", label)),
+                Span::styled(value, Style::default().fg(theme.text)),
+            ]));
+        }
+    }
+    lines
+}
+
+/// 説明文の行（幅で折り返し、最大 [`DESCRIPTION_MAX_LINES`] 行。説明文が無ければ空）
+///
+/// # Arguments
+///
+/// * `plugin` - Plugin whose description is shown.
+/// * `width` - Width of the info area in cells.
+fn description_lines(plugin: &InstalledPlugin, width: u16) -> Vec<Line<'static>> {
+    let theme = Theme::current();
+    let Some(description) = plugin.description() else {
+        return Vec::new();
+    };
+    let width = width.saturating_sub(DESCRIPTION_INDENT.len() as u16);
+    wrap_to_width(description, width, DESCRIPTION_MAX_LINES)
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| {
+            let label = if i == 0 {
+                "Description: "
+            } else {
+                DESCRIPTION_INDENT
+            };
+            Line::from(vec![
+                Span::raw(label),
+                Span::styled(text, Style::default().fg(theme.text)),
+            ])
+        })
+        .collect()
+}
+
 /// コンポーネント種別選択画面を描画
 ///
 /// # Arguments
@@ -500,6 +917,7 @@ fn view_component_types(
     mut state: ListState,
     ctx: &ViewCtx<'_>,
 ) {
+    let theme = Theme::current();
     let Some(plugin) = ctx.data.find_plugin(plugin_id) else {
         return;
     };
@@ -557,7 +975,7 @@ fn view_component_types(
     } else {
         " ↑↓: move | Enter: open | Esc: back | q: quit"
     };
-    let help = Paragraph::new(help_text).style(Style::default().fg(Color::DarkGray));
+    let help = Paragraph::new(help_text).style(Style::default().fg(theme.muted));
     f.render_widget(help, chunks[2]);
 }
 
@@ -577,16 +995,24 @@ fn view_component_list(
     mut state: ListState,
     ctx: &ViewCtx<'_>,
 ) {
+    let theme = Theme::current();
     let Some(plugin) = ctx.data.find_plugin(plugin_id) else {
         return;
     };
 
     let components = ctx.data.component_names(plugin, kind);
+    let filtered = filter_names(&components, ctx.filter_text);
     let selected_idx = state.selected();
-    let items: Vec<ListItem> = components
+    let items: Vec<ListItem> = filtered
         .iter()
         .enumerate()
-        .map(|(i, c)| build_component_list_item(c, Some(i) == selected_idx))
+        .map(|(i, c)| {
+            build_component_list_item(
+                c,
+                plugin.is_component_disabled(kind, c),
+                Some(i) == selected_idx,
+            )
+        })
         .collect();
 
     let outer = outer_rect(f.area());
@@ -605,18 +1031,31 @@ fn view_component_list(
     render_filter_bar(f, chunks[0], ctx.filter_text, ctx.filter_focused);
 
     let title = format!(
-        " {} > {} ({}) ",
+        " {} > {} ({}/{}) ",
         plugin.name(),
         component_kind_title(kind),
+        filtered.len(),
         components.len()
     );
-    let list = selectable_list(items, &title);
-
-    f.render_stateful_widget(list, chunks[1], &mut state);
+    if filtered.is_empty() {
+        render_empty_state(f, chunks[1], &title, ctx.filter_text, "No components");
+    } else {
+        let list = selectable_list(items, &title);
+        f.render_stateful_widget(list, chunks[1], &mut state);
+    }
 
-    // ヘルプ
-    let help = Paragraph::new(" ↑↓: move | Esc: back | q: quit")
-        .style(Style::default().fg(Color::DarkGray));
+    // ヘルプ（壊れた hooks.json などの警告があればそちらを優先して表示）
+    let warnings = if kind == ComponentKind::Hook {
+        ctx.data.hook_warnings(plugin)
+    } else {
+        Vec::new()
+    };
+    let help = match warnings.first() {
+        Some(warning) => Paragraph::new(format!(" Warning: {}", warning))
+            .style(Style::default().fg(theme.warning)),
+        None => Paragraph::new(" ↑↓: move | Esc: back | q: quit")
+            .style(Style::default().fg(theme.muted)),
+    };
     f.render_widget(help, chunks[2]);
 }
 
@@ -2,12 +2,38 @@
 
 use super::*;
 use crate::application::InstalledPlugin;
+use crate::component::Scope;
 use crate::tui::manager::core::LIST_DECORATION_WIDTH;
 
 fn make_test_plugin(name: &str) -> InstalledPlugin {
     InstalledPlugin::new_for_test(name, "1.0.0", Vec::new(), None, None, true)
 }
 
+/// 単独行として列幅を揃えた `PluginRow`
+fn row_of(plugin: &InstalledPlugin) -> PluginRow<'_> {
+    build_rows(&[plugin]).remove(0)
+}
+
+fn build_rows<'a>(plugins: &[&'a InstalledPlugin]) -> Vec<PluginRow<'a>> {
+    let (_temp_dir, data) = crate::tui::manager::core::DataStore::for_test(vec![], vec![], None);
+    build_plugin_rows(plugins, &data)
+}
+
+/// `plugin` のバージョンを `version` として載せたマーケットプレイスキャッシュ
+fn marketplace_cache(
+    name: &str,
+    plugin: &str,
+    version: &str,
+) -> crate::marketplace::MarketplaceCache {
+    serde_json::from_value(serde_json::json!({
+        "name": name,
+        "fetched_at": "2025-01-15T10:30:00Z",
+        "source": "github:owner/repo",
+        "plugins": [{ "name": plugin, "source": "./plugin", "version": version }]
+    }))
+    .unwrap()
+}
+
 fn span_texts(spans: &[Span<'_>]) -> Vec<String> {
     spans.iter().map(|s| s.content.to_string()).collect()
 }
@@ -16,7 +42,7 @@ fn span_texts(spans: &[Span<'_>]) -> Vec<String> {
 fn build_plugin_row_uses_multi_span_when_wide() {
     let plugin = make_test_plugin("plugin-name");
     let status = UpdateStatusDisplay::AlreadyUpToDate;
-    let spans = build_plugin_row_spans(&plugin, false, Some(&status), 80);
+    let spans = build_plugin_row_spans(&row_of(&plugin), false, Some(&status), 80);
     assert!(
         spans.len() >= 3,
         "wide path should keep multiple spans, got {}",
@@ -35,7 +61,7 @@ fn build_plugin_row_falls_back_to_single_span_when_narrow() {
     let plugin = make_test_plugin("very-long-plugin-name-that-exceeds-width");
     let status = UpdateStatusDisplay::AlreadyUpToDate;
     let content_width = 30u16;
-    let spans = build_plugin_row_spans(&plugin, false, Some(&status), content_width);
+    let spans = build_plugin_row_spans(&row_of(&plugin), false, Some(&status), content_width);
     assert!(
         spans.len() <= 2,
         "narrow path should collapse to <=2 spans, got {}",
@@ -60,28 +86,159 @@ fn build_plugin_row_falls_back_to_single_span_when_narrow() {
 #[test]
 fn build_plugin_row_does_not_panic_at_zero_width() {
     let plugin = make_test_plugin("p");
-    let _ = build_plugin_row_spans(&plugin, false, None, 0);
+    let _ = build_plugin_row_spans(&row_of(&plugin), false, None, 0);
 }
 
 #[test]
 fn build_plugin_row_returns_2_line_list_item() {
     let plugin = make_test_plugin("p");
-    let item = build_plugin_row(&plugin, false, None, 80, false);
+    let item = build_plugin_row(&row_of(&plugin), false, None, 80, false, ListMode::Compact);
     assert_eq!(item.height(), 2);
 }
 
 #[test]
 fn build_plugin_row_returns_2_line_list_item_when_marked() {
     let plugin = make_test_plugin("p");
-    let item = build_plugin_row(&plugin, true, None, 80, false);
+    let item = build_plugin_row(&row_of(&plugin), true, None, 80, false, ListMode::Compact);
     assert_eq!(item.height(), 2);
 }
 
 #[test]
 fn build_plugin_row_returns_2_line_list_item_when_narrow() {
     let plugin = make_test_plugin("very-long-plugin-name");
-    let item = build_plugin_row(&plugin, false, None, 30, false);
+    let item = build_plugin_row(&row_of(&plugin), false, None, 30, false, ListMode::Compact);
+    assert_eq!(item.height(), 2);
+}
+
+fn plugin_with_description(description: &str) -> InstalledPlugin {
+    let manifest = serde_json::from_value(serde_json::json!({
+        "name": "formatter",
+        "version": "1.0.0",
+        "description": description
+    }))
+    .unwrap();
+    InstalledPlugin::new_for_test_full(
+        manifest,
+        std::path::PathBuf::from("/test"),
+        Vec::new(),
+        None,
+        None,
+        true,
+    )
+}
+
+#[test]
+fn detailed_row_shows_description_between_content_and_spacer() {
+    let plugin = plugin_with_description("Formats source files");
+    let row = row_of(&plugin);
+
+    let compact = build_plugin_row(&row, false, None, 80, false, ListMode::Compact);
+    let detailed = build_plugin_row(&row, false, None, 80, false, ListMode::Detailed);
+
+    assert_eq!(compact.height(), 2);
+    assert_eq!(detailed.height(), 3);
+    let line = plugin_description_line(&plugin, false, 80).unwrap();
+    let text = span_texts(&line.spans).concat();
+    let indent = plugin_row_prefix(false, true).chars().count();
+    assert_eq!(text, format!("{}Formats source files", " ".repeat(indent)));
+    assert_eq!(line.spans[1].style.fg, Some(Theme::current().muted));
+}
+
+#[test]
+fn detailed_row_without_description_keeps_compact_height() {
+    let plugin = make_test_plugin("p");
+    let item = build_plugin_row(&row_of(&plugin), false, None, 80, false, ListMode::Detailed);
     assert_eq!(item.height(), 2);
+
+    let blank = plugin_with_description("   ");
+    assert!(plugin_description_line(&blank, false, 80).is_none());
+}
+
+#[test]
+fn description_line_is_truncated_to_the_list_width() {
+    let plugin = plugin_with_description(&"long description ".repeat(10));
+    let line = plugin_description_line(&plugin, false, 40).unwrap();
+    let text = span_texts(&line.spans).concat();
+    assert!(
+        text.chars().count() as u16 <= 40 - LIST_DECORATION_WIDTH,
+        "{:?}",
+        text
+    );
+    assert!(text.ends_with("..."), "{:?}", text);
+}
+
+#[test]
+fn build_plugin_rows_aligns_columns_across_rows() {
+    let short = InstalledPlugin::new_for_test(
+        "a",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("official".to_string()),
+        false,
+    );
+    let long = InstalledPlugin::new_for_test(
+        "long-name",
+        "10.2.0",
+        Vec::new(),
+        None,
+        Some("mp".to_string()),
+        true,
+    );
+
+    let rows = build_rows(&[&short, &long]);
+
+    assert_eq!(
+        rows[0].columns.concat(),
+        "a          @official  1.0.0   [disabled]"
+    );
+    assert_eq!(rows[1].columns.concat(), "long-name  @mp        10.2.0");
+}
+
+#[test]
+fn build_plugin_rows_marks_pinned_plugins() {
+    let pinned =
+        InstalledPlugin::new_for_test("a", "1.0.0", Vec::new(), None, None, true).with_pinned(true);
+    let disabled = InstalledPlugin::new_for_test("b", "1.0.0", Vec::new(), None, None, false)
+        .with_pinned(true);
+
+    let rows = build_rows(&[&pinned, &disabled]);
+
+    assert_eq!(rows[0].columns.concat(), "a  1.0.0  [pinned]");
+    assert_eq!(rows[1].columns.concat(), "b  1.0.0  [disabled] [pinned]");
+}
+
+#[test]
+fn build_plugin_rows_marks_partially_disabled_plugins() {
+    use crate::component::ComponentKind;
+
+    let partial = InstalledPlugin::new_for_test("a", "1.0.0", Vec::new(), None, None, false)
+        .with_kept_kinds(Some(vec![ComponentKind::Instruction]));
+
+    let rows = build_rows(&[&partial]);
+
+    assert_eq!(rows[0].columns.concat(), "a  1.0.0  [partial]");
+}
+
+#[test]
+fn build_plugin_rows_shows_available_update_from_cached_check() {
+    use crate::tui::manager::core::DataStore;
+
+    let plugin = InstalledPlugin::new_for_test(
+        "alpha",
+        "1.0.0",
+        Vec::new(),
+        None,
+        Some("mp".to_string()),
+        true,
+    );
+    let (_temp_dir, mut data) = DataStore::for_test(vec![plugin.clone()], vec![], None);
+    data.set_available_updates_from(&[marketplace_cache("mp", "alpha", "1.2.0")]);
+
+    let rows = build_plugin_rows(&[&plugin], &data);
+
+    assert!(rows[0].has_update);
+    assert_eq!(rows[0].columns[VERSION_COLUMN], "  1.0.0 → 1.2.0");
 }
 
 #[test]
@@ -129,6 +286,365 @@ fn build_component_types_item_returns_height_1() {
 
 #[test]
 fn build_component_list_item_returns_height_1() {
-    let item = build_component_list_item("my-component", false);
+    let item = build_component_list_item("my-component", false, false);
     assert_eq!(item.height(), 1);
 }
+
+#[test]
+fn build_component_list_item_marks_disabled_component() {
+    let item = build_component_list_item("my-component", true, false);
+    let text = format!("{:?}", item);
+    assert!(text.contains("(disabled)"));
+}
+
+#[test]
+fn build_dev_plugin_line_shows_dev_mark_and_component_counts() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir_all(tmp.path().join(".claude-plugin")).unwrap();
+    std::fs::write(
+        tmp.path().join(".claude-plugin/plugin.json"),
+        r#"{"name":"dev-plugin","version":"0.2.0"}"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(tmp.path().join("skills/alpha")).unwrap();
+    std::fs::write(tmp.path().join("skills/alpha/SKILL.md"), "").unwrap();
+    let dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    let text = build_dev_plugin_line(&dev).to_string();
+
+    assert!(text.starts_with("[dev] dev-plugin  v0.2.0"), "{text}");
+    assert!(text.contains("1 Skills"), "{text}");
+}
+
+#[test]
+fn build_dev_plugin_line_shows_path_when_not_loaded() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dev = DevPlugin::open(tmp.path().to_path_buf());
+
+    let text = build_dev_plugin_line(&dev).to_string();
+
+    assert!(text.contains("(not loaded)"), "{text}");
+}
+
+#[test]
+fn build_dev_status_line_uses_red_for_errors() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut dev = DevPlugin::open(tmp.path().to_path_buf());
+    dev.set_status(DevStatus::Error("Watch error: gone".to_string()));
+
+    let line = build_dev_status_line(&dev);
+
+    assert_eq!(line.spans[0].style.fg, Some(Color::Red));
+    assert!(line.to_string().contains("Watch error: gone"));
+}
+
+// =========================================================================
+// テーマ別スナップショット
+// =========================================================================
+
+fn render_plugin_list_snapshot(name: &str, theme: crate::tui::manager::core::Theme) {
+    use crate::tui::manager::core::theme::with_theme;
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let plugins = vec![
+        InstalledPlugin::new_for_test("alpha", "1.0.0", Vec::new(), None, None, true),
+        InstalledPlugin::new_for_test("beta", "2.1.0", Vec::new(), None, None, false),
+    ];
+    let (_temp_dir, data) = DataStore::for_test(plugins, vec![], None);
+    let model = InstalledScreenModel::new(&data);
+
+    let buffer = with_theme(theme, || {
+        snapshot::render(60, 14, |f| {
+            super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+        })
+    });
+    snapshot::assert_snapshot(name, &buffer);
+}
+
+#[test]
+fn snapshot_plugin_list_dark() {
+    render_plugin_list_snapshot(
+        "installed_plugin_list_dark",
+        crate::tui::manager::core::Theme::dark(),
+    );
+}
+
+#[test]
+fn snapshot_plugin_list_light() {
+    render_plugin_list_snapshot(
+        "installed_plugin_list_light",
+        crate::tui::manager::core::Theme::light(),
+    );
+}
+
+#[test]
+fn snapshot_plugin_list_high_contrast() {
+    render_plugin_list_snapshot(
+        "installed_plugin_list_high_contrast",
+        crate::tui::manager::core::Theme::high_contrast(),
+    );
+}
+
+#[test]
+fn plugin_list_footer_shows_filtered_count() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let plugins = vec![
+        InstalledPlugin::new_for_test("alpha", "1.0.0", Vec::new(), None, None, true),
+        InstalledPlugin::new_for_test("beta", "2.1.0", Vec::new(), None, None, false),
+    ];
+    let (_temp_dir, data) = DataStore::for_test(plugins, vec![], None);
+    let model = InstalledScreenModel::new(&data);
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "alp", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(
+        text.contains("Total: 2 plugins (1 enabled, 1 disabled), 0 components, showing 1 of 2"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn summary_text_appends_last_notice() {
+    assert_eq!(summary_text("Total: 1 plugin", None), " Total: 1 plugin");
+    assert_eq!(
+        summary_text(
+            "Total: 1 plugin",
+            Some("To apply the changes: codex: restart the Codex session")
+        ),
+        " Total: 1 plugin | To apply the changes: codex: restart the Codex session"
+    );
+}
+
+#[test]
+fn plugin_list_shows_empty_state_when_filter_matches_nothing() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let plugins = vec![make_test_plugin("alpha")];
+    let (_temp_dir, data) = DataStore::for_test(plugins, vec![], None);
+    let model = InstalledScreenModel::new(&data);
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "zzz", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(
+        text.contains("No items match 'zzz'") && text.contains("press Ctrl-U to clear filter"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn component_list_applies_filter_and_shows_empty_state() {
+    use crate::component::Component;
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let components = vec![
+        Component::new(ComponentKind::Skill, "formatter", "/test/skills/formatter"),
+        Component::new(ComponentKind::Skill, "linter", "/test/skills/linter"),
+    ];
+    let plugin = InstalledPlugin::new_for_test("alpha", "1.0.0", components, None, None, true);
+    let (_temp_dir, data) = DataStore::for_test(vec![plugin], vec![], None);
+    let model = InstalledScreenModel::ComponentList {
+        plugin_id: PluginId::new(None, "alpha"),
+        kind: ComponentKind::Skill,
+        selected_idx: 0,
+        state: ListState::default(),
+    };
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "lint", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("Skills (1/2)"), "{}", text);
+    assert!(
+        text.contains("linter") && !text.contains("formatter"),
+        "{}",
+        text
+    );
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "zzz", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("No items match 'zzz'"), "{}", text);
+}
+
+#[test]
+fn plugin_list_highlights_available_update_in_version_column() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let plugins = vec![
+        InstalledPlugin::new_for_test(
+            "alpha",
+            "1.0.0",
+            Vec::new(),
+            None,
+            Some("mp".to_string()),
+            true,
+        ),
+        InstalledPlugin::new_for_test(
+            "beta",
+            "2.1.0",
+            Vec::new(),
+            None,
+            Some("mp".to_string()),
+            true,
+        ),
+    ];
+    let (_temp_dir, mut data) = DataStore::for_test(plugins, vec![], None);
+    data.set_available_updates_from(&[marketplace_cache("mp", "beta", "2.2.0")]);
+    let model = InstalledScreenModel::new(&data);
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(text.contains("alpha  @mp  1.0.0"), "{}", text);
+    assert!(text.contains("beta   @mp  2.1.0 → 2.2.0"), "{}", text);
+    let (y, line) = text
+        .lines()
+        .enumerate()
+        .find(|(_, l)| l.contains("2.1.0 → 2.2.0"))
+        .unwrap();
+    let x = line.chars().position(|c| c == '2').unwrap() as u16;
+    assert_eq!(buffer[(x, y as u16)].style().fg, Some(Color::Yellow));
+}
+
+fn plugin_with_metadata() -> InstalledPlugin {
+    let manifest = serde_json::from_value(serde_json::json!({
+        "name": "formatter",
+        "version": "1.2.0",
+        "author": "Jane Doe <jane@example.com>",
+        "homepage": "https://example.com/formatter",
+        "repository": { "type": "git", "url": "https://github.com/owner/formatter" },
+        "keywords": ["lint", "format"]
+    }))
+    .unwrap();
+    InstalledPlugin::new_for_test_full(
+        manifest,
+        std::path::PathBuf::from("/test"),
+        Vec::new(),
+        None,
+        None,
+        true,
+    )
+}
+
+fn line_texts(lines: &[Line<'_>]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|l| span_texts(&l.spans).concat())
+        .collect()
+}
+
+#[test]
+fn plugin_info_lines_show_manifest_metadata() {
+    let lines = line_texts(&plugin_info_lines(&plugin_with_metadata(), 80));
+
+    assert!(lines[1].starts_with("Author: Jane Doe"), "{:?}", lines);
+    assert!(lines.contains(&"Homepage: https://example.com/formatter".to_string()));
+    assert!(lines.contains(&"Repository: https://github.com/owner/formatter".to_string()));
+    assert!(lines.contains(&"Keywords: lint, format".to_string()));
+}
+
+#[test]
+fn plugin_info_lines_omit_missing_metadata() {
+    let lines = line_texts(&plugin_info_lines(&make_test_plugin("plain"), 80));
+
+    assert_eq!(lines.len(), 2, "{:?}", lines);
+    assert!(lines[0].starts_with("Scope: N/A"), "{:?}", lines);
+    assert!(lines[1].starts_with("Author: N/A"), "{:?}", lines);
+}
+
+#[test]
+fn plugin_info_lines_show_deployed_scopes() {
+    let plugin = make_test_plugin("plain").with_scopes(vec![Scope::Project, Scope::Personal]);
+
+    let lines = line_texts(&plugin_info_lines(&plugin, 80));
+
+    assert!(
+        lines[0].starts_with("Scope: project, personal"),
+        "{:?}",
+        lines
+    );
+}
+
+#[test]
+fn plugin_info_lines_show_last_updated_with_version_change() {
+    let mut meta = crate::plugin::PluginMeta::default();
+    meta.record_update(
+        Some("1.1.0"),
+        Some("1.2.0"),
+        crate::plugin::UpdateResult::Updated,
+    );
+    let plugin = make_test_plugin("plain")
+        .with_timestamps(None, meta.updated_at.clone())
+        .with_update_history(meta.update_history);
+
+    let lines = line_texts(&plugin_info_lines(&plugin, 80));
+
+    assert_eq!(lines.len(), 3, "{:?}", lines);
+    assert_eq!(lines[2], "Last updated: just now (1.1.0 → 1.2.0)");
+}
+
+#[test]
+fn plugin_info_lines_wrap_long_description_to_two_lines() {
+    let manifest = serde_json::from_value(serde_json::json!({
+        "name": "formatter",
+        "version": "1.2.0",
+        "description": "Formats source files consistently across many languages \
+            with configurable rules and editor integration for every team"
+    }))
+    .unwrap();
+    let plugin = InstalledPlugin::new_for_test_full(
+        manifest,
+        std::path::PathBuf::from("/test"),
+        Vec::new(),
+        None,
+        None,
+        true,
+    );
+
+    let lines = line_texts(&plugin_info_lines(&plugin, 40));
+
+    assert_eq!(lines.len(), 4, "{:?}", lines);
+    assert!(lines[2].starts_with("Description: Formats"), "{:?}", lines);
+    assert!(lines[3].starts_with(DESCRIPTION_INDENT), "{:?}", lines);
+    assert!(lines[3].ends_with("..."), "{:?}", lines);
+    assert!(lines.iter().all(|l| l.chars().count() <= 40), "{:?}", lines);
+}
+
+#[test]
+fn confirm_dialog_is_drawn_over_the_plugin_list() {
+    use crate::tui::manager::core::preview::PreviewState;
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let plugins = vec![make_test_plugin("alpha"), make_test_plugin("beta")];
+    let (_temp_dir, data) = DataStore::for_test(plugins, vec![], None);
+    let model = InstalledScreenModel::Confirm {
+        action: ConfirmAction::UpdateAll,
+        preview: PreviewState::Calculating,
+        parent: Box::new(InstalledScreenModel::new(&data)),
+    };
+
+    let buffer = snapshot::render(80, 16, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(text.contains("Installed Plugins (2/2)"), "{}", text);
+    assert!(
+        text.contains("Update all 2 plugin(s)? y: yes | n: cancel"),
+        "{}",
+        text
+    );
+    assert!(text.contains("calculating…"), "{}", text);
+}
@@ -8,5 +8,8 @@ mod update;
 mod view;
 
 pub use model::{key_to_msg, CacheState, MarketplacesScreenModel, Msg};
-pub use update::update;
+// Re-exported for tests
+#[cfg(test)]
+pub use model::UpdateResult;
+pub use update::{clamp_selection, update};
 pub use view::view;
@@ -3,16 +3,16 @@
 //! マーケットプレイスの追加・削除・更新操作を実行する。
 
 use super::model::{BrowsePlugin, InstallSummary, PluginInstallOutcome};
-use crate::application::InstalledPlugin;
+use crate::application::{InstalledPlugin, Warning, WarningCollector};
 use crate::component::Scope;
-use crate::host::HostClientFactory;
 use crate::install::{self, PlaceRequest};
 use crate::marketplace::{
-    download_marketplace_plugin_with_cache, MarketplaceCache, MarketplaceConfig,
-    MarketplaceRegistration, MarketplaceRegistry, MarketplaceSourceRef,
+    download_marketplace_plugin_with_cache, normalize_source_path, MarketplaceCache,
+    MarketplaceConfig, MarketplaceRegistration, MarketplaceRegistry, MarketplaceSourceRef,
+    SourceLocator, UpdateDiff,
 };
 use crate::plugin::{PackageCache, PackageCacheAccess};
-use crate::repo;
+use crate::source::{MarketplaceSource, PackageSource};
 use crate::target::parse_target;
 use crate::tui::manager::core::MarketplaceItem;
 use crate::tui::output_suppress::OutputSuppressGuard;
@@ -23,13 +23,22 @@ pub struct MarketplaceAddOutcome {
     pub marketplace: MarketplaceItem,
 }
 
+/// マーケットプレイス更新の結果
+pub struct MarketplaceUpdateOutcome {
+    pub marketplace: MarketplaceItem,
+    /// 更新前のキャッシュとのプラグイン差分
+    pub diff: UpdateDiff,
+}
+
 /// マーケットプレイスを追加
 ///
 /// # Arguments
 ///
-/// * `source` - Marketplace source URL or `owner/repo` spec.
+/// * `source` - Marketplace source URL, `owner/repo[/subdir][#branch|@tag]` spec, or local
+///   directory path.
 /// * `name` - Local name used to reference the marketplace.
-/// * `source_path` - Optional subdirectory path inside the source repository.
+/// * `source_path` - Optional subdirectory path inside the source repository (overrides the
+///   subdirectory given in `source`).
 pub fn add_marketplace(
     source: &str,
     name: &str,
@@ -38,8 +47,15 @@ pub fn add_marketplace(
     let handle = tokio::runtime::Handle::try_current()
         .map_err(|_| "No Tokio runtime available".to_string())?;
 
-    let repo = repo::from_url(source).map_err(|e| e.to_string())?;
-    let source_ref = MarketplaceSourceRef::from_repo(&repo);
+    let SourceLocator {
+        source: source_ref,
+        git_ref,
+        subdir,
+    } = MarketplaceSourceRef::parse_locator(source).map_err(|e| e.to_string())?;
+    let source_path = match source_path.or(subdir.as_deref()) {
+        Some(path) => normalize_source_path(path)?,
+        None => None,
+    };
 
     let mut config = MarketplaceConfig::load()?;
 
@@ -50,16 +66,20 @@ pub fn add_marketplace(
     let entry = MarketplaceRegistration {
         name: name.to_string(),
         source: source_ref.clone(),
-        source_path: source_path.map(|s| s.to_string()),
+        source_path: source_path.clone(),
+        git_ref: git_ref.clone(),
     };
 
     config.add(entry)?;
 
-    let factory = HostClientFactory::with_defaults();
-    let client = factory.create(repo.host());
     let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
     let cache = tokio::task::block_in_place(|| {
-        handle.block_on(registry.fetch_cache(&*client, name, &repo, source_path))
+        handle.block_on(registry.fetch_source(
+            name,
+            &source_ref,
+            source_path.as_deref(),
+            git_ref.as_deref(),
+        ))
     })
     .map_err(|e| e.to_string())?;
 
@@ -72,9 +92,9 @@ pub fn add_marketplace(
         marketplace: MarketplaceItem {
             name: name.to_string(),
             source: source_ref.full_name(),
-            source_path: source_path.map(|s| s.to_string()),
+            source_path,
             plugin_count: Some(cache.plugins.len()),
-            last_updated: Some(cache.fetched_at.format("%Y-%m-%d %H:%M").to_string()),
+            fetched_at: Some(cache.fetched_at),
         },
     })
 }
@@ -102,7 +122,7 @@ pub fn remove_marketplace(name: &str) -> Result<(), String> {
 /// # Arguments
 ///
 /// * `name` - Local marketplace name to refresh.
-pub fn update_marketplace(name: &str) -> Result<MarketplaceItem, String> {
+pub fn update_marketplace(name: &str) -> Result<MarketplaceUpdateOutcome, String> {
     let config = MarketplaceConfig::load()?;
     let entry = config
         .get(name)
@@ -114,43 +134,46 @@ pub fn update_marketplace(name: &str) -> Result<MarketplaceItem, String> {
 
 /// マーケットプレイス登録情報を更新（config再読み込み不要）
 ///
+/// 更新前のキャッシュが無い・読めない場合は、全プラグインを追加として差分を返す。
+///
 /// # Arguments
 ///
 /// * `entry` - Registration entry to re-fetch and store.
 fn update_marketplace_registration(
     entry: &MarketplaceRegistration,
-) -> Result<MarketplaceItem, String> {
+) -> Result<MarketplaceUpdateOutcome, String> {
     let handle = tokio::runtime::Handle::try_current()
         .map_err(|_| "No Tokio runtime available".to_string())?;
 
     let display_source = entry.source.full_name();
-    let repo = entry.source.to_repo();
-    let factory = HostClientFactory::with_defaults();
-    let client = factory.create(repo.host());
     let registry = MarketplaceRegistry::new().map_err(|e| e.to_string())?;
+    let previous = registry.get(&entry.name).ok().flatten();
     let cache = tokio::task::block_in_place(|| {
-        handle.block_on(registry.fetch_cache(
-            &*client,
+        handle.block_on(registry.fetch_source(
             &entry.name,
-            &repo,
+            &entry.source,
             entry.source_path.as_deref(),
+            entry.git_ref.as_deref(),
         ))
     })
     .map_err(|e| e.to_string())?;
 
     registry.store(&cache).map_err(|e| e.to_string())?;
 
-    Ok(MarketplaceItem {
-        name: entry.name.clone(),
-        source: display_source,
-        source_path: entry.source_path.clone(),
-        plugin_count: Some(cache.plugins.len()),
-        last_updated: Some(cache.fetched_at.format("%Y-%m-%d %H:%M").to_string()),
+    Ok(MarketplaceUpdateOutcome {
+        marketplace: MarketplaceItem {
+            name: entry.name.clone(),
+            source: display_source,
+            source_path: entry.source_path.clone(),
+            plugin_count: Some(cache.plugins.len()),
+            fetched_at: Some(cache.fetched_at),
+        },
+        diff: UpdateDiff::between(previous.as_ref(), &cache),
     })
 }
 
 /// 全マーケットプレイスを更新
-pub fn update_all_marketplaces() -> Vec<(String, Result<MarketplaceItem, String>)> {
+pub fn update_all_marketplaces() -> Vec<(String, Result<MarketplaceUpdateOutcome, String>)> {
     let config = match MarketplaceConfig::load() {
         Ok(c) => c,
         Err(e) => return vec![("(config)".to_string(), Err(e))],
@@ -269,9 +292,10 @@ fn make_all_failed_summary(plugin_names: &[String], error: &str) -> InstallSumma
             plugin_name: name.clone(),
             success: false,
             error: Some(error.to_string()),
+            conflicts: Vec::new(),
         })
         .collect();
-    build_install_summary(results)
+    build_install_summary(results, Vec::new())
 }
 
 /// インストール処理のコンテキスト
@@ -281,6 +305,7 @@ struct InstallCtx<'a> {
     scope: Scope,
     project_root: &'a Path,
     cache: &'a dyn PackageCacheAccess,
+    warnings: &'a WarningCollector,
 }
 
 /// 個別プラグインの download -> scan -> place パイプライン
@@ -295,6 +320,19 @@ fn install_single_plugin(
     marketplace_name: &str,
     plugin_name: &str,
 ) -> PluginInstallOutcome {
+    // ダウンロードもキャッシュを書き換えるため、先に操作ロックを取る
+    let _lock = match MarketplaceSource::new(plugin_name, marketplace_name).lock(ctx.cache) {
+        Ok(lock) => lock,
+        Err(e) => {
+            return PluginInstallOutcome {
+                plugin_name: plugin_name.to_string(),
+                success: false,
+                error: Some(e.to_string()),
+                conflicts: Vec::new(),
+            }
+        }
+    };
+
     // Download (async -> sync bridge)
     let package = match tokio::task::block_in_place(|| {
         ctx.handle.block_on(download_marketplace_plugin_with_cache(
@@ -310,6 +348,7 @@ fn install_single_plugin(
                 plugin_name: plugin_name.to_string(),
                 success: false,
                 error: Some(e.to_string()),
+                conflicts: Vec::new(),
             }
         }
     };
@@ -321,6 +360,7 @@ fn install_single_plugin(
                 plugin_name: plugin_name.to_string(),
                 success: false,
                 error: Some(e),
+                conflicts: Vec::new(),
             }
         }
     };
@@ -332,10 +372,16 @@ fn install_single_plugin(
         project_root: ctx.project_root,
         // TUI 経由インストールでも CLI と同じデフォルト挙動（自動追記）に揃える。
         enable_codex_hooks_flag: true,
+        overwrite: false,
     });
 
     install::update_meta_after_place(scanned.plugin_root(), &place_result);
+    install::record_placements(&scanned, &place_result, ctx.project_root);
+    for success in &place_result.successes {
+        ctx.warnings.record_placement(success);
+    }
 
+    let conflicts = place_result.conflicts.clone();
     if !place_result.failures.is_empty() {
         let errors: Vec<String> = place_result
             .failures
@@ -346,6 +392,7 @@ fn install_single_plugin(
             plugin_name: plugin_name.to_string(),
             success: false,
             error: Some(errors.join("; ")),
+            conflicts,
         }
     } else if place_result.successes.is_empty() {
         PluginInstallOutcome {
@@ -356,12 +403,14 @@ fn install_single_plugin(
                  or none of its components are supported by the selected targets."
                     .to_string(),
             ),
+            conflicts,
         }
     } else {
         PluginInstallOutcome {
             plugin_name: plugin_name.to_string(),
             success: true,
             error: None,
+            conflicts,
         }
     }
 }
@@ -382,7 +431,7 @@ pub fn install_plugins(
 ) -> InstallSummary {
     // プラグイン名の空チェック（Tokio不要で早期リターン）
     if plugin_names.is_empty() {
-        return build_install_summary(Vec::new());
+        return build_install_summary(Vec::new(), Vec::new());
     }
 
     // ターゲット名の空チェック（Tokio不要で早期リターン）
@@ -401,7 +450,7 @@ pub fn install_plugins(
     };
 
     // project_root 取得（他の箇所と同様に失敗時は "." にフォールバック）
-    let project_root = std::env::current_dir().unwrap_or_else(|_| ".".into());
+    let project_root = crate::env::project_root().unwrap_or_else(|_| ".".into());
 
     // stdout/stderr 抑制（TUI代替スクリーンの保護）
     let _guard = OutputSuppressGuard::new();
@@ -420,19 +469,21 @@ pub fn install_plugins(
     };
 
     // 各プラグインに対して download -> scan -> place
+    let warnings = WarningCollector::default();
     let install_ctx = InstallCtx {
         handle: &handle,
         targets: &targets,
         scope,
         project_root: &project_root,
         cache: &cache,
+        warnings: &warnings,
     };
     let results: Vec<PluginInstallOutcome> = plugin_names
         .iter()
         .map(|plugin_name| install_single_plugin(&install_ctx, marketplace_name, plugin_name))
         .collect();
 
-    build_install_summary(results)
+    build_install_summary(results, warnings.snapshot())
 }
 
 /// 純粋変換: Vec<PluginInstallOutcome> -> InstallSummary
@@ -440,7 +491,11 @@ pub fn install_plugins(
 /// # Arguments
 ///
 /// * `results` - Individual install results to aggregate.
-fn build_install_summary(results: Vec<PluginInstallOutcome>) -> InstallSummary {
+/// * `warnings` - Warnings collected while placing the plugins.
+fn build_install_summary(
+    results: Vec<PluginInstallOutcome>,
+    warnings: Vec<Warning>,
+) -> InstallSummary {
     let total = results.len();
     let succeeded = results.iter().filter(|r| r.success).count();
     let failed = total - succeeded;
@@ -449,6 +504,7 @@ fn build_install_summary(results: Vec<PluginInstallOutcome>) -> InstallSummary {
         total,
         succeeded,
         failed,
+        warnings,
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::application::InstalledPlugin;
+use crate::application::{InstalledPlugin, Warning, WarningCategory};
 use crate::marketplace::{MarketplaceCache, MarketplacePlugin, MarketplaceRegistry, PluginSource};
 use crate::tui::manager::screens::marketplaces::model::PluginInstallOutcome;
 
@@ -11,6 +11,7 @@ fn make_success_outcome(name: &str) -> PluginInstallOutcome {
         plugin_name: name.to_string(),
         success: true,
         error: None,
+        conflicts: Vec::new(),
     }
 }
 
@@ -19,6 +20,7 @@ fn make_failure_outcome(name: &str, error: &str) -> PluginInstallOutcome {
         plugin_name: name.to_string(),
         success: false,
         error: Some(error.to_string()),
+        conflicts: Vec::new(),
     }
 }
 
@@ -32,6 +34,8 @@ fn make_marketplace_plugin(name: &str) -> MarketplacePlugin {
         source: PluginSource::Local(format!("./plugins/{}", name)),
         description: Some(format!("{} description", name)),
         version: Some("1.0.0".to_string()),
+        renamed_from: vec![],
+        stats: Default::default(),
     }
 }
 
@@ -40,6 +44,7 @@ fn make_cache(name: &str, plugins: Vec<MarketplacePlugin>) -> MarketplaceCache {
         name: name.to_string(),
         fetched_at: chrono::Utc::now(),
         source: "owner/repo".parse().unwrap(),
+        git_ref: None,
         owner: None,
         plugins,
     }
@@ -154,6 +159,8 @@ fn maps_all_fields_correctly() {
         source: PluginSource::Local("./plugins/test".to_string()),
         description: Some("A test plugin".to_string()),
         version: Some("2.0.0".to_string()),
+        renamed_from: vec![],
+        stats: Default::default(),
     };
     let cache = make_cache("test-mp", vec![mp]);
     let installed: Vec<InstalledPlugin> = vec![];
@@ -267,7 +274,7 @@ fn returns_plugins_via_registry() {
 #[test]
 fn build_summary_all_success() {
     let results = vec![make_success_outcome("a"), make_success_outcome("b")];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 2);
     assert_eq!(summary.succeeded, 2);
     assert_eq!(summary.failed, 0);
@@ -279,7 +286,7 @@ fn build_summary_all_failure() {
         make_failure_outcome("a", "err1"),
         make_failure_outcome("b", "err2"),
     ];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 2);
     assert_eq!(summary.succeeded, 0);
     assert_eq!(summary.failed, 2);
@@ -288,7 +295,7 @@ fn build_summary_all_failure() {
 #[test]
 fn build_summary_mixed() {
     let results = vec![make_success_outcome("a"), make_failure_outcome("b", "err")];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 2);
     assert_eq!(summary.succeeded, 1);
     assert_eq!(summary.failed, 1);
@@ -297,7 +304,7 @@ fn build_summary_mixed() {
 #[test]
 fn build_summary_empty() {
     let results: Vec<PluginInstallOutcome> = vec![];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 0);
     assert_eq!(summary.succeeded, 0);
     assert_eq!(summary.failed, 0);
@@ -306,7 +313,7 @@ fn build_summary_empty() {
 #[test]
 fn build_summary_single_success() {
     let results = vec![make_success_outcome("a")];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 1);
     assert_eq!(summary.succeeded, 1);
     assert_eq!(summary.failed, 0);
@@ -315,7 +322,7 @@ fn build_summary_single_success() {
 #[test]
 fn build_summary_single_failure() {
     let results = vec![make_failure_outcome("a", "err")];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 1);
     assert_eq!(summary.succeeded, 0);
     assert_eq!(summary.failed, 1);
@@ -328,7 +335,7 @@ fn build_summary_preserves_results() {
         make_failure_outcome("y", "fail"),
         make_success_outcome("z"),
     ];
-    let summary = super::build_install_summary(results);
+    let summary = super::build_install_summary(results, Vec::new());
     assert_eq!(summary.total, 3);
     assert_eq!(summary.succeeded, 2);
     assert_eq!(summary.failed, 1);
@@ -341,6 +348,16 @@ fn build_summary_preserves_results() {
     assert!(summary.results[2].success);
 }
 
+#[test]
+fn build_summary_keeps_collected_warnings() {
+    let warnings = vec![Warning {
+        category: WarningCategory::LargeAssets,
+        message: "codex Skill: big: 12 MB of assets".to_string(),
+    }];
+    let summary = super::build_install_summary(vec![make_success_outcome("a")], warnings.clone());
+    assert_eq!(summary.warnings, warnings);
+}
+
 // ============================================================================
 // make_all_failed_summary テスト (T-08 -- T-10)
 // ============================================================================
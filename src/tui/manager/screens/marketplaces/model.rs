@@ -2,25 +2,104 @@
 //!
 //! 画面状態とメッセージ型を定義。
 
+use super::actions::MarketplaceUpdateOutcome;
+use crate::application::Warning;
 use crate::component::Scope;
 use crate::marketplace::PluginSource;
-use crate::tui::manager::core::{DataStore, SelectionState};
+use crate::plugin::ComponentConflict;
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{DataStore, SelectionState, Theme};
 use crossterm::event::KeyCode;
 use ratatui::widgets::ListState;
 use std::collections::HashSet;
 
 /// 非同期操作の状態
+#[derive(Debug, Clone)]
 pub enum OperationStatus {
     Updating(String),
     UpdatingAll,
     Removing(String),
     Adding(String),
+    /// マーク済みマーケットプレイスの削除確認中（対象名と影響プレビュー）
+    ConfirmRemove {
+        names: Vec<String>,
+        preview: PreviewState,
+    },
+    /// マーク済みマーケットプレイスの一括更新中
+    BatchUpdating(BatchProgress),
+    /// マーク済みマーケットプレイスの一括削除中
+    BatchRemoving(BatchProgress),
+}
+
+/// 一括操作の進捗
+///
+/// phase2 の 1 ステップで 1 件ずつ処理し、`done` が `total()` に達したら完了。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// 処理対象のマーケットプレイス名（処理順）
+    pub names: Vec<String>,
+    /// 処理済みの件数
+    pub done: usize,
+    /// 失敗したマーケットプレイス名とエラー
+    pub failures: Vec<(String, String)>,
+}
+
+impl BatchProgress {
+    /// 新しい進捗を作成
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - Marketplace names to process, in order.
+    pub fn new(names: Vec<String>) -> Self {
+        Self {
+            names,
+            done: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    /// 処理対象の総数
+    pub fn total(&self) -> usize {
+        self.names.len()
+    }
+}
+
+impl OperationStatus {
+    /// バックグラウンドで実行中の操作か（実行中もタブ切替・スクロールができる）
+    ///
+    /// 削除確認（`ConfirmRemove`）は入力待ちのため含まない。
+    pub fn runs_in_background(&self) -> bool {
+        matches!(
+            self,
+            OperationStatus::Updating(_)
+                | OperationStatus::UpdatingAll
+                | OperationStatus::Removing(_)
+                | OperationStatus::BatchUpdating(_)
+                | OperationStatus::BatchRemoving(_)
+        )
+    }
+}
+
+/// バックグラウンドの更新処理の結果
+pub enum UpdateResult {
+    /// 1 件の更新（`Updating`）
+    Single(String, Result<MarketplaceUpdateOutcome, String>),
+    /// 全件の更新（`UpdatingAll`）
+    All(Vec<(String, Result<MarketplaceUpdateOutcome, String>)>),
 }
 
 /// キャッシュ状態（タブ切替時に保持）
+///
+/// 一覧画面で実行中の操作と結果表示も保持し、他タブにいる間に届いた完了メッセージは
+/// この状態に反映する。
 #[derive(Debug, Default)]
 pub struct CacheState {
     pub selected_id: Option<String>,
+    /// バックグラウンドで実行中の操作
+    pub operation_status: Option<OperationStatus>,
+    pub error_message: Option<String>,
+    pub marked_ids: HashSet<String>,
+    pub notice: Option<String>,
 }
 
 /// マーケットプレイス詳細画面のアクション
@@ -55,25 +134,33 @@ impl DetailAction {
     }
 
     pub fn style(&self) -> ratatui::prelude::Style {
+        let theme = Theme::current();
         use ratatui::prelude::*;
         match self {
-            DetailAction::Update => Style::default().fg(Color::Green),
-            DetailAction::Remove => Style::default().fg(Color::Red),
+            DetailAction::Update => Style::default().fg(theme.success),
+            DetailAction::Remove => Style::default().fg(theme.error),
             _ => Style::default(),
         }
     }
 }
 
 /// AddForm の内部状態
+#[derive(Clone)]
 pub enum AddFormModel {
     /// source（owner/repo）入力画面
     Source {
         source_input: String,
         error_message: Option<String>,
+        /// 同じソースを登録済みのマーケットプレイス名（警告表示中。Enter 再押下で続行）
+        duplicate_of: Option<String>,
     },
     /// name 入力画面
     Name {
         source: String,
+        /// ブランチ/タグ（`owner/repo#branch` / `owner/repo@tag`）
+        git_ref: Option<String>,
+        /// marketplace.json のあるサブディレクトリ（`owner/repo/path/to/dir`）
+        subdir: Option<String>,
         name_input: String,
         default_name: String,
         error_message: Option<String>,
@@ -81,12 +168,15 @@ pub enum AddFormModel {
     /// 確認画面
     Confirm {
         source: String,
+        git_ref: Option<String>,
+        subdir: Option<String>,
         name: String,
         error_message: Option<String>,
     },
 }
 
 /// マーケットプレイスのプラグイン情報（ブラウズ画面用）
+#[derive(Clone)]
 pub struct BrowsePlugin {
     pub name: String,
     pub description: Option<String>,
@@ -96,21 +186,30 @@ pub struct BrowsePlugin {
 }
 
 /// プラグインインストール結果（1件分）
+#[derive(Clone)]
 pub struct PluginInstallOutcome {
     pub plugin_name: String,
     pub success: bool,
     pub error: Option<String>,
+    /// 別プラグインの配置物と衝突して配置しなかったコンポーネント
+    pub conflicts: Vec<ComponentConflict>,
 }
 
 /// インストールサマリー
+#[derive(Clone)]
 pub struct InstallSummary {
     pub results: Vec<PluginInstallOutcome>,
     pub total: usize,
     pub succeeded: usize,
     pub failed: usize,
+    /// 配置中に出た警告（Hook 変換・巨大アセットなど）
+    pub warnings: Vec<Warning>,
 }
 
 /// Marketplaces タブの画面状態
+///
+/// 下位画面へ進むときは遷移元を丸ごと履歴スタックに積むため `Clone` にしている。
+#[derive(Clone)]
 pub enum MarketplacesScreenModel {
     /// マーケットプレイス一覧
     MarketList {
@@ -120,6 +219,10 @@ pub enum MarketplacesScreenModel {
         /// AddForm Confirm → MarketList(Adding) 遷移時に保持する source。
         /// phase2 (ExecuteAdd) で参照され、完了時にクリアされる。
         pending_add_source: Option<String>,
+        /// 一括更新/削除の対象としてマークしたマーケットプレイス名
+        marked_ids: HashSet<String>,
+        /// 直近の更新結果（追加/削除/更新プラグイン数）。次の更新まで表示する
+        notice: Option<String>,
     },
     /// マーケットプレイス詳細（アクションメニュー）
     MarketDetail {
@@ -199,6 +302,8 @@ impl MarketplacesScreenModel {
             operation_status: None,
             error_message: None,
             pending_add_source: None,
+            marked_ids: HashSet::new(),
+            notice: None,
         }
     }
 
@@ -222,29 +327,46 @@ impl MarketplacesScreenModel {
 
         MarketplacesScreenModel::MarketList {
             selection: SelectionState::new(selected_id, Some(index)),
-            operation_status: None,
-            error_message: None,
+            operation_status: cache.operation_status.clone(),
+            error_message: cache.error_message.clone(),
             pending_add_source: None,
+            marked_ids: cache.marked_ids.clone(),
+            notice: cache.notice.clone(),
         }
     }
 
     /// キャッシュ状態を取得
     pub fn to_cache(&self) -> CacheState {
         match self {
-            MarketplacesScreenModel::MarketList { selection, .. } => CacheState {
+            MarketplacesScreenModel::MarketList {
+                selection,
+                operation_status,
+                error_message,
+                marked_ids,
+                notice,
+                ..
+            } => CacheState {
                 selected_id: selection.selected_id().cloned(),
+                operation_status: operation_status
+                    .clone()
+                    .filter(OperationStatus::runs_in_background),
+                error_message: error_message.clone(),
+                marked_ids: marked_ids.clone(),
+                notice: notice.clone(),
             },
             MarketplacesScreenModel::MarketDetail {
                 marketplace_name, ..
             } => CacheState {
                 selected_id: Some(marketplace_name.clone()),
+                ..CacheState::default()
             },
             MarketplacesScreenModel::PluginList {
                 marketplace_name, ..
             } => CacheState {
                 selected_id: Some(marketplace_name.clone()),
+                ..CacheState::default()
             },
-            MarketplacesScreenModel::AddForm(_) => CacheState { selected_id: None },
+            MarketplacesScreenModel::AddForm(_) => CacheState::default(),
             MarketplacesScreenModel::PluginBrowse {
                 marketplace_name, ..
             }
@@ -261,19 +383,23 @@ impl MarketplacesScreenModel {
                 marketplace_name, ..
             } => CacheState {
                 selected_id: Some(marketplace_name.clone()),
+                ..CacheState::default()
             },
         }
     }
 
     /// トップレベル（タブ切替可能）かどうか
+    ///
+    /// バックグラウンドの操作中も一覧画面はトップレベルとして扱う。
     pub fn is_top_level(&self) -> bool {
-        matches!(
-            self,
+        match self {
             MarketplacesScreenModel::MarketList {
-                operation_status: None,
-                ..
-            }
-        )
+                operation_status, ..
+            } => operation_status
+                .as_ref()
+                .is_none_or(OperationStatus::runs_in_background),
+            _ => false,
+        }
     }
 
     /// フォームがアクティブかどうか
@@ -282,6 +408,18 @@ impl MarketplacesScreenModel {
     }
 }
 
+/// マーケットプレイス一覧の末尾に "+ Add new" 項目を表示するか
+///
+/// フィルタに一致するマーケットプレイスが無いときは空結果表示にするため表示しない。
+///
+/// # Arguments
+///
+/// * `visible` - Number of marketplaces matching the filter.
+/// * `filter_text` - Current filter input text.
+pub(super) fn shows_add_new(visible: usize, filter_text: &str) -> bool {
+    visible > 0 || filter_text.is_empty()
+}
+
 /// Marketplaces タブへのメッセージ
 pub enum Msg {
     Up,
@@ -292,6 +430,13 @@ pub enum Msg {
     FormBackspace,
     UpdateMarket,
     UpdateAll,
+    ToggleMark,
+    RemoveMarked,
+    ConfirmRemove,
+    CancelRemove,
+    /// 削除確認の影響プレビューを計算する（確認を開いた後の phase2）
+    CalculateRemovePreview,
+    ExecuteBatch,
     ExecuteUpdate,
     ExecuteRemove,
     ExecuteAdd,
@@ -301,6 +446,22 @@ pub enum Msg {
     ConfirmTargets,
     ConfirmScope,
     BackToPluginBrowse,
+    /// バックグラウンドの更新が完了した
+    UpdateFinished(UpdateResult),
+    /// バックグラウンドの削除が完了した（対象名と結果）
+    RemoveFinished(String, Result<(), String>),
+    /// 一括更新/削除の 1 件が完了した（対象名と結果）
+    BatchStepFinished(String, Result<(), String>),
+}
+
+impl Msg {
+    /// バックグラウンド操作の完了メッセージか（タブを離れていても反映する）
+    pub fn is_task_result(&self) -> bool {
+        matches!(
+            self,
+            Msg::UpdateFinished(_) | Msg::RemoveFinished(..) | Msg::BatchStepFinished(..)
+        )
+    }
 }
 
 /// キーコードをメッセージに変換
@@ -346,24 +507,46 @@ pub fn key_to_msg(key: KeyCode, model: &MarketplacesScreenModel) -> Option<Msg>
                 KeyCode::Esc => Some(Msg::Back),
                 _ => None,
             },
+            MarketplacesScreenModel::MarketList {
+                operation_status: Some(OperationStatus::ConfirmRemove { .. }),
+                ..
+            } => match key {
+                KeyCode::Char('y') | KeyCode::Enter => Some(Msg::ConfirmRemove),
+                KeyCode::Char('n') | KeyCode::Esc => Some(Msg::CancelRemove),
+                _ => None,
+            },
+            MarketplacesScreenModel::MarketList { .. } => match key {
+                KeyCode::Char(' ') => Some(Msg::ToggleMark),
+                KeyCode::Char('x') => Some(Msg::RemoveMarked),
+                _ => default_key_to_msg(key),
+            },
             MarketplacesScreenModel::Installing { .. } => None,
             MarketplacesScreenModel::InstallOutcome { .. } => match key {
                 KeyCode::Enter | KeyCode::Esc => Some(Msg::BackToPluginBrowse),
                 _ => None,
             },
-            _ => match key {
-                KeyCode::Up | KeyCode::Char('k') => Some(Msg::Up),
-                KeyCode::Down | KeyCode::Char('j') => Some(Msg::Down),
-                KeyCode::Enter => Some(Msg::Enter),
-                KeyCode::Esc => Some(Msg::Back),
-                KeyCode::Char('u') => Some(Msg::UpdateMarket),
-                KeyCode::Char('U') => Some(Msg::UpdateAll),
-                _ => None,
-            },
+            _ => default_key_to_msg(key),
         }
     }
 }
 
+/// 一覧・詳細画面で共通のキー割り当て
+///
+/// # Arguments
+///
+/// * `key` - Raw key code received from crossterm.
+fn default_key_to_msg(key: KeyCode) -> Option<Msg> {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => Some(Msg::Up),
+        KeyCode::Down | KeyCode::Char('j') => Some(Msg::Down),
+        KeyCode::Enter => Some(Msg::Enter),
+        KeyCode::Esc => Some(Msg::Back),
+        KeyCode::Char('u') => Some(Msg::UpdateMarket),
+        KeyCode::Char('U') => Some(Msg::UpdateAll),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 #[path = "model_test.rs"]
 mod model_test;
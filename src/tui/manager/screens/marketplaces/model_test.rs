@@ -1,5 +1,6 @@
 use super::*;
 use crate::tui::manager::core::{DataStore, MarketplaceItem};
+use chrono::Utc;
 use crossterm::event::KeyCode;
 
 fn make_marketplace(name: &str) -> MarketplaceItem {
@@ -8,7 +9,7 @@ fn make_marketplace(name: &str) -> MarketplaceItem {
         source: format!("owner/{}", name),
         source_path: None,
         plugin_count: Some(3),
-        last_updated: Some("2026-01-01 12:00".to_string()),
+        fetched_at: Some(Utc::now()),
     }
 }
 
@@ -79,10 +80,24 @@ fn is_top_level_add_form() {
     let model = MarketplacesScreenModel::AddForm(AddFormModel::Source {
         source_input: String::new(),
         error_message: None,
+        duplicate_of: None,
     });
     assert!(!model.is_top_level());
 }
 
+#[test]
+fn is_top_level_during_background_operation() {
+    let (_temp_dir, data) = make_data(&["market-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    if let MarketplacesScreenModel::MarketList {
+        operation_status, ..
+    } = &mut model
+    {
+        *operation_status = Some(OperationStatus::Updating("market-a".to_string()));
+    }
+    assert!(model.is_top_level(), "tabs can be switched while updating");
+}
+
 // ============================================================================
 // is_form_active テスト
 // ============================================================================
@@ -92,6 +107,7 @@ fn is_form_active_add_form() {
     let model = MarketplacesScreenModel::AddForm(AddFormModel::Source {
         source_input: String::new(),
         error_message: None,
+        duplicate_of: None,
     });
     assert!(model.is_form_active());
 }
@@ -137,11 +153,57 @@ fn key_to_msg_market_list_navigation() {
     ));
 }
 
+#[test]
+fn key_to_msg_market_list_space_and_x_return_mark_actions() {
+    let (_temp_dir, data) = make_data(&["market-a"]);
+    let model = MarketplacesScreenModel::new(&data);
+
+    assert!(matches!(
+        key_to_msg(KeyCode::Char(' '), &model),
+        Some(Msg::ToggleMark)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Char('x'), &model),
+        Some(Msg::RemoveMarked)
+    ));
+}
+
+#[test]
+fn key_to_msg_confirm_remove_accepts_only_yes_or_no() {
+    let (_temp_dir, data) = make_data(&["market-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    if let MarketplacesScreenModel::MarketList {
+        operation_status, ..
+    } = &mut model
+    {
+        *operation_status = Some(OperationStatus::ConfirmRemove {
+            names: vec!["market-a".to_string()],
+            preview: PreviewState::Calculating,
+        });
+    }
+
+    assert!(matches!(
+        key_to_msg(KeyCode::Char('y'), &model),
+        Some(Msg::ConfirmRemove)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Enter, &model),
+        Some(Msg::ConfirmRemove)
+    ));
+    assert!(matches!(
+        key_to_msg(KeyCode::Esc, &model),
+        Some(Msg::CancelRemove)
+    ));
+    assert!(key_to_msg(KeyCode::Char('u'), &model).is_none());
+    assert!(!model.is_top_level());
+}
+
 #[test]
 fn key_to_msg_add_form_input() {
     let model = MarketplacesScreenModel::AddForm(AddFormModel::Source {
         source_input: String::new(),
         error_message: None,
+        duplicate_of: None,
     });
 
     assert!(matches!(
@@ -343,6 +405,7 @@ fn key_to_msg_install_result_enter_returns_back_to_browse() {
             total: 0,
             succeeded: 0,
             failed: 0,
+            warnings: Vec::new(),
         },
     };
 
@@ -362,6 +425,7 @@ fn key_to_msg_install_result_esc_returns_back_to_browse() {
             total: 0,
             succeeded: 0,
             failed: 0,
+            warnings: Vec::new(),
         },
     };
 
@@ -444,11 +508,51 @@ fn to_cache_and_from_cache_round_trip() {
     }
 }
 
+#[test]
+fn cache_keeps_background_operation_but_drops_confirmation() {
+    let (_temp_dir, data) = make_data(&["market-a", "market-b"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    if let MarketplacesScreenModel::MarketList {
+        operation_status,
+        marked_ids,
+        ..
+    } = &mut model
+    {
+        *operation_status = Some(OperationStatus::Updating("market-a".to_string()));
+        marked_ids.insert("market-b".to_string());
+    }
+
+    let restored = MarketplacesScreenModel::from_cache(&data, &model.to_cache());
+    match &restored {
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::Updating(name)),
+            marked_ids,
+            ..
+        } => {
+            assert_eq!(name, "market-a");
+            assert!(marked_ids.contains("market-b"));
+        }
+        _ => panic!("Expected Updating MarketList"),
+    }
+
+    if let MarketplacesScreenModel::MarketList {
+        operation_status, ..
+    } = &mut model
+    {
+        *operation_status = Some(OperationStatus::ConfirmRemove {
+            names: vec!["market-a".to_string()],
+            preview: PreviewState::Calculating,
+        });
+    }
+    assert!(model.to_cache().operation_status.is_none());
+}
+
 #[test]
 fn from_cache_with_stale_id() {
     let (_temp_dir, data) = make_data(&["market-a"]);
     let cache = CacheState {
         selected_id: Some("deleted-market".to_string()),
+        ..CacheState::default()
     };
     let model = MarketplacesScreenModel::from_cache(&data, &cache);
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
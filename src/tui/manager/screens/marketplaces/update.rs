@@ -1,12 +1,19 @@
 //! Marketplaces タブの update（状態遷移ロジック）
 
-use super::actions;
+use super::actions::{self, MarketplaceUpdateOutcome};
 use super::model::{
-    AddFormModel, BrowsePlugin, DetailAction, MarketplacesScreenModel, Msg, OperationStatus,
+    shows_add_new, AddFormModel, BatchProgress, BrowsePlugin, DetailAction,
+    MarketplacesScreenModel, Msg, OperationStatus, UpdateResult,
+};
+use crate::application::preview_marketplace_removal;
+use crate::marketplace::{
+    normalize_name, normalize_source_path, MarketplaceSourceRef, SourceLocator, UpdateDiff,
+};
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::task::Task;
+use crate::tui::manager::core::{
+    filter_marketplaces, DataStore, NavigationHistory, SelectionState, Tab,
 };
-use crate::marketplace::normalize_name;
-use crate::repo;
-use crate::tui::manager::core::{DataStore, MarketplaceItem, SelectionState};
 use ratatui::widgets::ListState;
 use std::collections::HashSet;
 
@@ -17,6 +24,10 @@ const NO_CACHE_MESSAGE: &str = "No cached data — try update";
 pub struct UpdateEffect {
     pub should_focus_filter: bool,
     pub phase2_msg: Option<Msg>,
+    /// 履歴から画面を復元した場合、そのときのフィルタ文字列
+    pub restore_filter: Option<String>,
+    /// バックグラウンドで実行する操作（完了時に `*Finished` メッセージを返す）
+    pub task: Option<Task<Msg>>,
 }
 
 impl UpdateEffect {
@@ -24,13 +35,34 @@ impl UpdateEffect {
         Self {
             should_focus_filter: false,
             phase2_msg: None,
+            restore_filter: None,
+            task: None,
         }
     }
 
     fn phase2(msg: Msg) -> Self {
         Self {
-            should_focus_filter: false,
             phase2_msg: Some(msg),
+            ..Self::none()
+        }
+    }
+
+    fn restored(filter_text: String) -> Self {
+        Self {
+            restore_filter: Some(filter_text),
+            ..Self::none()
+        }
+    }
+
+    /// 操作をバックグラウンドで開始する
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - Operation returning its completion message.
+    fn background(task: impl FnOnce() -> Msg + Send + 'static) -> Self {
+        Self {
+            task: Some(Box::new(task)),
+            ..Self::none()
         }
     }
 }
@@ -40,28 +72,35 @@ impl UpdateEffect {
 /// # Arguments
 ///
 /// * `model` - Marketplaces tab model to mutate.
+/// * `history` - Navigation history of the Marketplaces tab.
 /// * `msg` - Incoming message to apply.
 /// * `data` - Shared data store for marketplaces and plugins.
-pub fn update(model: &mut MarketplacesScreenModel, msg: Msg, data: &mut DataStore) -> UpdateEffect {
-    match msg {
+/// * `filter_text` - Current filter input text (recorded with history entries).
+pub fn update(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    msg: Msg,
+    data: &mut DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
+    let effect = match msg {
         Msg::Up => {
             clear_error(model);
-            select_prev(model, data);
+            select_prev(model, data, filter_text);
             UpdateEffect::none()
         }
         Msg::Down => {
             clear_error(model);
-            select_next(model, data);
+            select_next(model, data, filter_text);
             UpdateEffect::none()
         }
         Msg::Enter => {
             clear_error(model);
-            enter(model, data)
+            enter(model, history, data, filter_text)
         }
         Msg::Back => {
             clear_error(model);
-            back(model, data);
-            UpdateEffect::none()
+            back(model, history, data)
         }
         Msg::FormInput(c) => {
             form_input(model, c);
@@ -71,17 +110,69 @@ pub fn update(model: &mut MarketplacesScreenModel, msg: Msg, data: &mut DataStor
             form_backspace(model);
             UpdateEffect::none()
         }
-        Msg::UpdateMarket => update_market(model),
+        Msg::UpdateMarket => update_market(model, data),
         Msg::UpdateAll => update_all(model, data),
-        Msg::ExecuteUpdate => execute_update(model, data),
-        Msg::ExecuteRemove => execute_remove(model, data),
+        Msg::ToggleMark => toggle_mark(model),
+        Msg::RemoveMarked => remove_marked(model, data),
+        Msg::ConfirmRemove => confirm_remove(model),
+        Msg::CancelRemove => cancel_remove(model),
+        Msg::CalculateRemovePreview => {
+            calculate_remove_preview(model, data);
+            UpdateEffect::none()
+        }
+        Msg::ExecuteBatch => execute_batch(model),
+        Msg::ExecuteUpdate => execute_update(model),
+        Msg::ExecuteRemove => execute_remove(model),
+        Msg::UpdateFinished(result) => finish_update(model, data, result),
+        Msg::RemoveFinished(name, result) => finish_remove(model, data, name, result),
+        Msg::BatchStepFinished(name, result) => finish_batch_step(model, data, name, result),
         Msg::ExecuteAdd => execute_add(model, data),
         Msg::ToggleSelect => toggle_select(model),
-        Msg::StartInstall => start_install(model),
-        Msg::ConfirmTargets => confirm_targets(model),
+        Msg::StartInstall => start_install(model, history, filter_text),
+        Msg::ConfirmTargets => confirm_targets(model, history, filter_text),
         Msg::ConfirmScope => confirm_scope(model),
         Msg::ExecuteInstall => execute_install(model, data),
-        Msg::BackToPluginBrowse => back_to_plugin_browse(model, data),
+        Msg::BackToPluginBrowse => back_to_plugin_browse(model, history, data),
+    };
+
+    // ルート画面（MarketList）に戻ったら履歴は不要
+    if matches!(model, MarketplacesScreenModel::MarketList { .. }) {
+        history.clear();
+    }
+    // 操作後の選択位置をフィルタ済みの一覧に合わせる（履歴復元時は復元後のフィルタで）
+    let effective_filter = effect.restore_filter.as_deref().unwrap_or(filter_text);
+    clamp_selection(model, data, effective_filter);
+    effect
+}
+
+/// フィルタ変更後に選択状態を整合させる
+///
+/// 選択中のマーケットプレイスがフィルタで隠れたら先頭を選び直す。"+ Add new" を選択中なら
+/// その位置を追従し、一致するマーケットプレイスが無ければ選択を外す（空結果表示）。
+/// 追加・更新などの操作中は対象を選択したまま保つため何もしない。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model to adjust.
+/// * `data` - Shared data store.
+/// * `filter_text` - Filter text now in effect.
+pub fn clamp_selection(model: &mut MarketplacesScreenModel, data: &DataStore, filter_text: &str) {
+    let MarketplacesScreenModel::MarketList {
+        selection,
+        operation_status: None,
+        ..
+    } = model
+    else {
+        return;
+    };
+    let names = visible_market_names(data, filter_text);
+    let on_add_new = selection.selected_id().is_none() && selection.selected_index().is_some();
+    if !on_add_new {
+        selection.sync_to(&names);
+    }
+    if selection.selected_id().is_none() {
+        let add_new_idx = shows_add_new(names.len(), filter_text).then_some(names.len());
+        selection.set(None, add_new_idx);
     }
 }
 
@@ -94,9 +185,31 @@ fn clear_error(model: &mut MarketplacesScreenModel) {
     }
 }
 
-/// マーケットプレイスリストの長さ（+ Add new を含む）
-fn market_list_len(data: &DataStore) -> usize {
-    data.marketplaces.len() + 1
+/// フィルタ適用後に表示されるマーケットプレイス名（表示順）
+///
+/// # Arguments
+///
+/// * `data` - Shared data store.
+/// * `filter_text` - Current filter input text.
+fn visible_market_names(data: &DataStore, filter_text: &str) -> Vec<String> {
+    filter_marketplaces(&data.marketplaces, filter_text)
+        .iter()
+        .map(|m| m.name.clone())
+        .collect()
+}
+
+/// マーケットプレイスリストの長さ（表示中なら + Add new を含む）
+///
+/// # Arguments
+///
+/// * `visible` - Marketplace names matching the filter.
+/// * `filter_text` - Current filter input text.
+fn market_list_len(visible: &[String], filter_text: &str) -> usize {
+    if shows_add_new(visible.len(), filter_text) {
+        visible.len() + 1
+    } else {
+        0
+    }
 }
 
 fn market_selection(
@@ -107,7 +220,7 @@ fn market_selection(
 }
 
 /// 選択を上に移動
-fn select_prev(model: &mut MarketplacesScreenModel, data: &DataStore) {
+fn select_prev(model: &mut MarketplacesScreenModel, data: &DataStore, filter_text: &str) {
     match model {
         MarketplacesScreenModel::MarketList { selection, .. } => {
             let current = selection.selected_index().unwrap_or(0);
@@ -115,10 +228,8 @@ fn select_prev(model: &mut MarketplacesScreenModel, data: &DataStore) {
                 return;
             }
             let prev = current - 1;
-            selection.set(
-                data.marketplaces.get(prev).map(|m| m.name.clone()),
-                Some(prev),
-            );
+            let names = visible_market_names(data, filter_text);
+            selection.set(names.get(prev).cloned(), Some(prev));
         }
         MarketplacesScreenModel::MarketDetail { state, .. } => {
             let current = state.selected().unwrap_or(0);
@@ -181,16 +292,17 @@ fn select_prev(model: &mut MarketplacesScreenModel, data: &DataStore) {
 }
 
 /// 選択を下に移動
-fn select_next(model: &mut MarketplacesScreenModel, data: &DataStore) {
+fn select_next(model: &mut MarketplacesScreenModel, data: &DataStore, filter_text: &str) {
     match model {
         MarketplacesScreenModel::MarketList { selection, .. } => {
-            let len = market_list_len(data);
+            let names = visible_market_names(data, filter_text);
+            let len = market_list_len(&names, filter_text);
+            if len == 0 {
+                return;
+            }
             let current = selection.selected_index().unwrap_or(0);
             let next = (current + 1).min(len - 1);
-            selection.set(
-                data.marketplaces.get(next).map(|m| m.name.clone()),
-                Some(next),
-            );
+            selection.set(names.get(next).cloned(), Some(next));
         }
         MarketplacesScreenModel::MarketDetail { state, .. } => {
             let len = DetailAction::all().len();
@@ -252,36 +364,43 @@ fn select_next(model: &mut MarketplacesScreenModel, data: &DataStore) {
 }
 
 /// Enter 処理
-fn enter(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEffect {
+fn enter(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    data: &mut DataStore,
+    filter_text: &str,
+) -> UpdateEffect {
     match model {
         MarketplacesScreenModel::MarketList {
             selection,
             operation_status,
             ..
         } => {
-            // 二重実行防止
-            if operation_status.is_some() {
+            // 二重実行防止 / フィルタ結果が空（選択なし）なら何もしない
+            if operation_status.is_some() || selection.selected_index().is_none() {
                 return UpdateEffect::none();
             }
 
-            if let Some(name) = selection.selected_id().cloned() {
+            let next = if let Some(name) = selection.selected_id().cloned() {
                 // 通常項目 -> MarketDetail
                 let mut state = ListState::default();
                 state.select(Some(0));
-                *model = MarketplacesScreenModel::MarketDetail {
+                MarketplacesScreenModel::MarketDetail {
                     marketplace_name: name,
                     state,
                     error_message: None,
                     browse_plugins: None,
                     browse_selected: None,
-                };
+                }
             } else {
                 // "+ Add new" -> AddForm
-                *model = MarketplacesScreenModel::AddForm(AddFormModel::Source {
+                MarketplacesScreenModel::AddForm(AddFormModel::Source {
                     source_input: String::new(),
                     error_message: None,
-                });
-            }
+                    duplicate_of: None,
+                })
+            };
+            navigate(model, history, next, filter_text);
             UpdateEffect::none()
         }
         MarketplacesScreenModel::MarketDetail {
@@ -301,6 +420,8 @@ fn enter(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEff
                         operation_status: Some(OperationStatus::Updating(name)),
                         error_message: None,
                         pending_add_source: None,
+                        marked_ids: HashSet::new(),
+                        notice: None,
                     };
                     UpdateEffect::phase2(Msg::ExecuteUpdate)
                 }
@@ -313,6 +434,8 @@ fn enter(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEff
                         operation_status: Some(OperationStatus::Removing(name)),
                         error_message: None,
                         pending_add_source: None,
+                        marked_ids: HashSet::new(),
+                        notice: None,
                     };
                     UpdateEffect::phase2(Msg::ExecuteRemove)
                 }
@@ -352,13 +475,14 @@ fn enter(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEff
                     if !plugins.is_empty() {
                         new_state.select(Some(0));
                     }
-                    *model = MarketplacesScreenModel::PluginBrowse {
+                    let next = MarketplacesScreenModel::PluginBrowse {
                         marketplace_name: name,
                         plugins,
                         selected_plugins,
                         highlighted_idx: 0,
                         state: new_state,
                     };
+                    navigate(model, history, next, filter_text);
                     UpdateEffect::none()
                 }
                 Some(DetailAction::ShowPlugins) => {
@@ -368,19 +492,16 @@ fn enter(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEff
                     if !plugins.is_empty() {
                         new_state.select(Some(0));
                     }
-                    *model = MarketplacesScreenModel::PluginList {
+                    let next = MarketplacesScreenModel::PluginList {
                         marketplace_name: name,
                         selected_idx: 0,
                         state: new_state,
                         plugins,
                     };
+                    navigate(model, history, next, filter_text);
                     UpdateEffect::none()
                 }
-                Some(DetailAction::Back) => {
-                    let name = marketplace_name.clone();
-                    back_to_market_list(model, data, name);
-                    UpdateEffect::none()
-                }
+                Some(DetailAction::Back) => back(model, history, data),
                 None => UpdateEffect::none(),
             }
         }
@@ -399,23 +520,37 @@ fn enter_form(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> Upda
         MarketplacesScreenModel::AddForm(AddFormModel::Source {
             source_input,
             error_message,
+            duplicate_of,
         }) => {
             if source_input.is_empty() {
                 *error_message = Some("Source is required".to_string());
                 return UpdateEffect::none();
             }
-            match repo::from_url(source_input) {
-                Ok(parsed) => {
+            // GitHub の owner/repo・URL（#branch / @tag / サブディレクトリ付き）に加え、
+            // ローカルパス（絶対 / ./相対 / file://）を受け付ける
+            match parse_add_source(source_input) {
+                Ok((parsed, git_ref, subdir)) => {
                     let source = parsed.full_name();
                     let default_name = match normalize_name(parsed.name()) {
                         Ok(name) => name,
                         Err(e) => {
-                            *error_message = Some(format!("Invalid repository name: {}", e));
+                            *error_message = Some(format!("Invalid source name: {}", e));
                             return UpdateEffect::none();
                         }
                     };
+                    // 同じソースの登録済みマーケットプレイスがあれば 1 回目は警告に留める
+                    if duplicate_of.is_none() {
+                        if let Some(existing) =
+                            data.find_marketplace_by_source(&source, subdir.as_deref())
+                        {
+                            *duplicate_of = Some(existing.name.clone());
+                            return UpdateEffect::none();
+                        }
+                    }
                     *model = MarketplacesScreenModel::AddForm(AddFormModel::Name {
                         source,
+                        git_ref,
+                        subdir,
                         name_input: String::new(),
                         default_name,
                         error_message: None,
@@ -423,7 +558,7 @@ fn enter_form(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> Upda
                 }
                 Err(e) => {
                     *error_message = Some(format!(
-                        "Invalid source. Use owner/repo or GitHub URL. ({})",
+                        "Invalid source. Use owner/repo[/path/to/dir][#branch|@tag], GitHub URL, or local path. ({})",
                         e
                     ));
                 }
@@ -432,6 +567,8 @@ fn enter_form(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> Upda
         }
         MarketplacesScreenModel::AddForm(AddFormModel::Name {
             source,
+            git_ref,
+            subdir,
             name_input,
             default_name,
             error_message,
@@ -454,16 +591,24 @@ fn enter_form(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> Upda
                 return UpdateEffect::none();
             }
 
-            let source = source.clone();
+            let (source, git_ref, subdir) = (source.clone(), git_ref.clone(), subdir.clone());
             *model = MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
                 source,
+                git_ref,
+                subdir,
                 name,
                 error_message: None,
             });
             UpdateEffect::none()
         }
-        MarketplacesScreenModel::AddForm(AddFormModel::Confirm { source, name, .. }) => {
-            let source = source.clone();
+        MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
+            source,
+            git_ref,
+            subdir,
+            name,
+            ..
+        }) => {
+            let source = source_locator(source, git_ref.as_deref(), subdir.as_deref());
             let name = name.clone();
             execute_add_phase1(model, data, source, name)
         }
@@ -471,6 +616,48 @@ fn enter_form(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> Upda
     }
 }
 
+/// AddForm Source ステップの入力をパースする（参照, ブランチ/タグ, サブディレクトリ）
+///
+/// サブディレクトリは `plm marketplace add --path` と同じ規則で正規化する。
+///
+/// # Arguments
+///
+/// * `input` - Text typed into the Source field.
+fn parse_add_source(
+    input: &str,
+) -> Result<(MarketplaceSourceRef, Option<String>, Option<String>), String> {
+    let SourceLocator {
+        source,
+        git_ref,
+        subdir,
+    } = MarketplaceSourceRef::parse_locator(input).map_err(|e| e.to_string())?;
+    let subdir = match subdir {
+        Some(path) => normalize_source_path(&path)?,
+        None => None,
+    };
+    Ok((source, git_ref, subdir))
+}
+
+/// Confirm から phase2 へ渡すソース指定（`owner/repo[/subdir][#ref]`）を組み立てる
+///
+/// # Arguments
+///
+/// * `source` - Display form of the source (`owner/repo` or a local path).
+/// * `git_ref` - Branch or tag, if any.
+/// * `subdir` - Subdirectory containing `marketplace.json`, if any.
+fn source_locator(source: &str, git_ref: Option<&str>, subdir: Option<&str>) -> String {
+    let mut locator = source.to_string();
+    if let Some(subdir) = subdir {
+        locator.push('/');
+        locator.push_str(subdir);
+    }
+    if let Some(git_ref) = git_ref {
+        locator.push('#');
+        locator.push_str(git_ref);
+    }
+    locator
+}
+
 /// Phase 1: AddForm::Confirm → MarketList(Adding) への即時遷移。
 ///
 /// 副作用は伴わない。実 fetch は phase2 (`execute_add`) で行う。
@@ -488,6 +675,8 @@ fn execute_add_phase1(
         operation_status: Some(OperationStatus::Adding(name)),
         error_message: None,
         pending_add_source: Some(source),
+        marked_ids: HashSet::new(),
+        notice: None,
     };
     UpdateEffect::phase2(Msg::ExecuteAdd)
 }
@@ -548,7 +737,7 @@ pub(super) fn execute_add_with(
             {
                 let idx = data.marketplace_index(&name);
                 selection.set(idx.map(|_| name.clone()), idx);
-                *error_message = Some(e);
+                *error_message = Some(record_error(data, e));
             }
         }
     }
@@ -583,8 +772,154 @@ fn take_add_request(model: &mut MarketplacesScreenModel) -> Option<(String, Stri
     Some((name, source))
 }
 
+/// 遷移元を履歴に積んで次の画面へ進む
+fn navigate(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    next: MarketplacesScreenModel,
+    filter_text: &str,
+) {
+    let prev = std::mem::replace(model, next);
+    history.push(prev, filter_text);
+}
+
 /// Back 処理
-fn back(model: &mut MarketplacesScreenModel, data: &DataStore) {
+///
+/// 履歴の直前の画面を選択位置・スクロール位置・フィルタごと復元する。
+/// マーケットプレイスが削除されて復元できない画面は読み飛ばし、履歴が尽きた場合は
+/// 階層上の親画面を開く。
+fn back(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    data: &DataStore,
+) -> UpdateEffect {
+    // MarketList での Back は app.rs で処理
+    if matches!(model, MarketplacesScreenModel::MarketList { .. }) {
+        return UpdateEffect::none();
+    }
+
+    while let Some(entry) = history.pop() {
+        if let Some(mut screen) = revalidate(entry.screen, data) {
+            let current = std::mem::replace(model, MarketplacesScreenModel::new(data));
+            carry_selection(current, &mut screen);
+            *model = screen;
+            return UpdateEffect::restored(entry.filter_text);
+        }
+    }
+
+    back_to_parent(model, data);
+    UpdateEffect::none()
+}
+
+/// 履歴から取り出した画面を現在のデータに合わせて補正する
+///
+/// 対象のマーケットプレイスが消えた画面は復元できないため `None` を返す。
+/// MarketList は選択中の項目が消えていれば元の位置に最も近い項目を選び直す。
+///
+/// # Arguments
+///
+/// * `screen` - Screen state taken from the history.
+/// * `data` - Shared data store.
+fn revalidate(
+    mut screen: MarketplacesScreenModel,
+    data: &DataStore,
+) -> Option<MarketplacesScreenModel> {
+    match &mut screen {
+        MarketplacesScreenModel::MarketList {
+            selection,
+            error_message,
+            ..
+        } => {
+            *error_message = None;
+            let idx = match selection.selected_id() {
+                Some(id) => data.marketplace_index(id).or_else(|| {
+                    let last = data.marketplaces.len().checked_sub(1)?;
+                    Some(selection.selected_index().unwrap_or(0).min(last))
+                }),
+                // "+ Add new"（リスト末尾）
+                None => Some(data.marketplaces.len()),
+            };
+            let idx = idx.unwrap_or(0);
+            selection.set(
+                data.marketplaces.get(idx).map(|m| m.name.clone()),
+                Some(idx),
+            );
+        }
+        MarketplacesScreenModel::MarketDetail {
+            marketplace_name, ..
+        }
+        | MarketplacesScreenModel::PluginList {
+            marketplace_name, ..
+        }
+        | MarketplacesScreenModel::PluginBrowse {
+            marketplace_name, ..
+        }
+        | MarketplacesScreenModel::TargetSelect {
+            marketplace_name, ..
+        }
+        | MarketplacesScreenModel::ScopeSelect {
+            marketplace_name, ..
+        } => {
+            data.find_marketplace(marketplace_name)?;
+        }
+        MarketplacesScreenModel::AddForm(_)
+        | MarketplacesScreenModel::Installing { .. }
+        | MarketplacesScreenModel::InstallOutcome { .. } => return None,
+    }
+    Some(screen)
+}
+
+/// 戻り先の画面へ、戻る前の画面で確定した選択を引き継ぐ
+///
+/// スナップショットは遷移前の状態なので、下位画面で選んだプラグインやターゲットは
+/// 現在の画面から引き継ぐ（カーソル位置とスクロール位置はスナップショットのまま）。
+///
+/// # Arguments
+///
+/// * `current` - Screen being left.
+/// * `restored` - Screen restored from the history.
+fn carry_selection(current: MarketplacesScreenModel, restored: &mut MarketplacesScreenModel) {
+    match (current, restored) {
+        (
+            MarketplacesScreenModel::PluginBrowse {
+                plugins,
+                selected_plugins,
+                ..
+            },
+            MarketplacesScreenModel::MarketDetail {
+                browse_plugins,
+                browse_selected,
+                ..
+            },
+        ) => {
+            *browse_plugins = Some(plugins);
+            *browse_selected = Some(selected_plugins);
+        }
+        (
+            MarketplacesScreenModel::TargetSelect {
+                selected_plugins, ..
+            },
+            MarketplacesScreenModel::PluginBrowse {
+                selected_plugins: restored_plugins,
+                ..
+            },
+        ) => {
+            *restored_plugins = selected_plugins;
+        }
+        (
+            MarketplacesScreenModel::ScopeSelect { target_names, .. },
+            MarketplacesScreenModel::TargetSelect { targets, .. },
+        ) => {
+            for (name, _, selected) in targets.iter_mut() {
+                *selected = target_names.contains(name);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 履歴がない場合の Back（階層上の親画面へ戻る）
+fn back_to_parent(model: &mut MarketplacesScreenModel, data: &DataStore) {
     match model {
         MarketplacesScreenModel::MarketList { .. } => {
             // MarketList での Back は app.rs で処理
@@ -622,6 +957,8 @@ fn back(model: &mut MarketplacesScreenModel, data: &DataStore) {
                 operation_status: None,
                 error_message: None,
                 pending_add_source: None,
+                marked_ids: HashSet::new(),
+                notice: None,
             };
         }
         MarketplacesScreenModel::PluginBrowse { .. } => {
@@ -633,6 +970,8 @@ fn back(model: &mut MarketplacesScreenModel, data: &DataStore) {
                     operation_status: None,
                     error_message: None,
                     pending_add_source: None,
+                    marked_ids: HashSet::new(),
+                    notice: None,
                 },
             );
             if let MarketplacesScreenModel::PluginBrowse {
@@ -662,6 +1001,8 @@ fn back(model: &mut MarketplacesScreenModel, data: &DataStore) {
                     operation_status: None,
                     error_message: None,
                     pending_add_source: None,
+                    marked_ids: HashSet::new(),
+                    notice: None,
                 },
             );
             if let MarketplacesScreenModel::TargetSelect {
@@ -693,6 +1034,8 @@ fn back(model: &mut MarketplacesScreenModel, data: &DataStore) {
                     operation_status: None,
                     error_message: None,
                     pending_add_source: None,
+                    marked_ids: HashSet::new(),
+                    notice: None,
                 },
             );
             if let MarketplacesScreenModel::ScopeSelect {
@@ -747,53 +1090,41 @@ fn back_to_market_list(
         operation_status: None,
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 }
 
 /// StartInstall: PluginBrowse -> TargetSelect
-fn start_install(model: &mut MarketplacesScreenModel) -> UpdateEffect {
-    if let MarketplacesScreenModel::PluginBrowse {
+fn start_install(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    filter_text: &str,
+) -> UpdateEffect {
+    let MarketplacesScreenModel::PluginBrowse {
+        marketplace_name,
         plugins,
         selected_plugins,
         highlighted_idx,
         ..
     } = model
-    {
-        if plugins.is_empty() {
-            return UpdateEffect::none();
-        }
-        if selected_plugins.is_empty() {
-            let idx = (*highlighted_idx).min(plugins.len() - 1);
-            match plugins.get(idx) {
-                Some(plugin) => {
-                    selected_plugins.insert(plugin.name.clone());
-                }
-                None => return UpdateEffect::none(),
+    else {
+        return UpdateEffect::none();
+    };
+    if plugins.is_empty() {
+        return UpdateEffect::none();
+    }
+    let mut selected_plugins = selected_plugins.clone();
+    if selected_plugins.is_empty() {
+        let idx = (*highlighted_idx).min(plugins.len() - 1);
+        match plugins.get(idx) {
+            Some(plugin) => {
+                selected_plugins.insert(plugin.name.clone());
             }
+            None => return UpdateEffect::none(),
         }
-    } else {
-        return UpdateEffect::none();
     }
 
-    // Take ownership of fields
-    let (marketplace_name, plugins, selected_plugins) = match std::mem::replace(
-        model,
-        MarketplacesScreenModel::MarketList {
-            selection: SelectionState::default(),
-            operation_status: None,
-            error_message: None,
-            pending_add_source: None,
-        },
-    ) {
-        MarketplacesScreenModel::PluginBrowse {
-            marketplace_name,
-            plugins,
-            selected_plugins,
-            ..
-        } => (marketplace_name, plugins, selected_plugins),
-        _ => unreachable!(),
-    };
-
     let targets: Vec<(String, String, bool)> = crate::target::all_targets()
         .iter()
         .map(|t| (t.name().to_string(), t.display_name().to_string(), false))
@@ -804,14 +1135,15 @@ fn start_install(model: &mut MarketplacesScreenModel) -> UpdateEffect {
         state.select(Some(0));
     }
 
-    *model = MarketplacesScreenModel::TargetSelect {
-        marketplace_name,
-        plugins,
+    let next = MarketplacesScreenModel::TargetSelect {
+        marketplace_name: marketplace_name.clone(),
+        plugins: plugins.clone(),
         selected_plugins,
         targets,
         highlighted_idx: 0,
         state,
     };
+    navigate(model, history, next, filter_text);
     UpdateEffect::none()
 }
 
@@ -828,6 +1160,8 @@ fn confirm_scope(model: &mut MarketplacesScreenModel) -> UpdateEffect {
             operation_status: None,
             error_message: None,
             pending_add_source: None,
+            marked_ids: HashSet::new(),
+            notice: None,
         },
     );
 
@@ -872,11 +1206,24 @@ fn confirm_scope(model: &mut MarketplacesScreenModel) -> UpdateEffect {
 }
 
 /// BackToPluginBrowse: InstallOutcome -> PluginBrowse (refresh)
-fn back_to_plugin_browse(model: &mut MarketplacesScreenModel, data: &DataStore) -> UpdateEffect {
+fn back_to_plugin_browse(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    data: &DataStore,
+) -> UpdateEffect {
     if !matches!(model, MarketplacesScreenModel::InstallOutcome { .. }) {
         return UpdateEffect::none();
     }
 
+    // インストールフロー（PluginBrowse 以降）の履歴を捨て、Back で MarketDetail へ戻れるようにする
+    history.discard_while(|screen| {
+        !matches!(
+            screen,
+            MarketplacesScreenModel::MarketList { .. }
+                | MarketplacesScreenModel::MarketDetail { .. }
+        )
+    });
+
     let old = std::mem::replace(
         model,
         MarketplacesScreenModel::MarketList {
@@ -884,6 +1231,8 @@ fn back_to_plugin_browse(model: &mut MarketplacesScreenModel, data: &DataStore)
             operation_status: None,
             error_message: None,
             pending_add_source: None,
+            marked_ids: HashSet::new(),
+            notice: None,
         },
     );
 
@@ -944,6 +1293,8 @@ pub(super) fn execute_install_with(
             operation_status: None,
             error_message: None,
             pending_add_source: None,
+            marked_ids: HashSet::new(),
+            notice: None,
         },
     );
 
@@ -957,9 +1308,10 @@ pub(super) fn execute_install_with(
     } = old
     {
         let summary = run_install(&marketplace_name, &plugin_names, &target_names, scope);
+        data.last_warnings = summary.warnings.clone();
 
         if let Err(e) = reload(data) {
-            data.last_error = Some(format!("Failed to reload: {}", e));
+            data.push_error(Tab::Marketplaces, format!("Failed to reload: {}", e));
         }
 
         *model = MarketplacesScreenModel::InstallOutcome {
@@ -973,63 +1325,50 @@ pub(super) fn execute_install_with(
 }
 
 /// ConfirmTargets: TargetSelect -> ScopeSelect
-fn confirm_targets(model: &mut MarketplacesScreenModel) -> UpdateEffect {
-    if let MarketplacesScreenModel::TargetSelect {
+fn confirm_targets(
+    model: &mut MarketplacesScreenModel,
+    history: &mut NavigationHistory<MarketplacesScreenModel>,
+    filter_text: &str,
+) -> UpdateEffect {
+    let MarketplacesScreenModel::TargetSelect {
+        marketplace_name,
+        plugins,
+        selected_plugins,
         targets,
         highlighted_idx,
         ..
     } = model
-    {
-        if targets.is_empty() {
-            return UpdateEffect::none();
-        }
-        if !targets.iter().any(|(_, _, sel)| *sel) {
-            let idx = (*highlighted_idx).min(targets.len() - 1);
-            match targets.get_mut(idx) {
-                Some(target) => target.2 = true,
-                None => return UpdateEffect::none(),
-            }
-        }
-    } else {
+    else {
+        return UpdateEffect::none();
+    };
+    if targets.is_empty() {
         return UpdateEffect::none();
     }
+    let mut target_names: Vec<String> = targets
+        .iter()
+        .filter(|(_, _, sel)| *sel)
+        .map(|(name, _, _)| name.clone())
+        .collect();
+    if target_names.is_empty() {
+        let idx = (*highlighted_idx).min(targets.len() - 1);
+        match targets.get(idx) {
+            Some((name, _, _)) => target_names.push(name.clone()),
+            None => return UpdateEffect::none(),
+        }
+    }
 
-    let old = std::mem::replace(
-        model,
-        MarketplacesScreenModel::MarketList {
-            selection: SelectionState::default(),
-            operation_status: None,
-            error_message: None,
-            pending_add_source: None,
-        },
-    );
-
-    if let MarketplacesScreenModel::TargetSelect {
-        marketplace_name,
-        plugins,
-        selected_plugins,
-        targets,
-        ..
-    } = old
-    {
-        let target_names: Vec<String> = targets
-            .iter()
-            .filter(|(_, _, sel)| *sel)
-            .map(|(name, _, _)| name.clone())
-            .collect();
-
-        let mut state = ListState::default();
-        state.select(Some(0));
+    let mut state = ListState::default();
+    state.select(Some(0));
 
-        *model = MarketplacesScreenModel::ScopeSelect {
-            marketplace_name,
-            plugins,
-            selected_plugins,
-            target_names,
-            highlighted_idx: 0,
-            state,
-        };
-    }
+    let next = MarketplacesScreenModel::ScopeSelect {
+        marketplace_name: marketplace_name.clone(),
+        plugins: plugins.clone(),
+        selected_plugins: selected_plugins.clone(),
+        target_names,
+        highlighted_idx: 0,
+        state,
+    };
+    navigate(model, history, next, filter_text);
     UpdateEffect::none()
 }
 
@@ -1077,8 +1416,10 @@ fn form_input(model: &mut MarketplacesScreenModel, c: char) {
         MarketplacesScreenModel::AddForm(AddFormModel::Source {
             source_input,
             error_message,
+            duplicate_of,
         }) => {
             *error_message = None;
+            *duplicate_of = None;
             source_input.push(c);
         }
         MarketplacesScreenModel::AddForm(AddFormModel::Name {
@@ -1096,7 +1437,12 @@ fn form_input(model: &mut MarketplacesScreenModel, c: char) {
 /// FormBackspace 処理
 fn form_backspace(model: &mut MarketplacesScreenModel) {
     match model {
-        MarketplacesScreenModel::AddForm(AddFormModel::Source { source_input, .. }) => {
+        MarketplacesScreenModel::AddForm(AddFormModel::Source {
+            source_input,
+            duplicate_of,
+            ..
+        }) => {
+            *duplicate_of = None;
             source_input.pop();
         }
         MarketplacesScreenModel::AddForm(AddFormModel::Name { name_input, .. }) => {
@@ -1106,18 +1452,25 @@ fn form_backspace(model: &mut MarketplacesScreenModel) {
     }
 }
 
-/// 'u' キー: 選択中のマーケットプレイスを更新
-fn update_market(model: &mut MarketplacesScreenModel) -> UpdateEffect {
+/// 'u' キー: 選択中のマーケットプレイスを更新（マークがあればマーク分を一括更新）
+fn update_market(model: &mut MarketplacesScreenModel, data: &DataStore) -> UpdateEffect {
     if let MarketplacesScreenModel::MarketList {
         selection,
         operation_status,
         error_message,
+        marked_ids,
         ..
     } = model
     {
         if operation_status.is_some() {
             return UpdateEffect::none();
         }
+        let names = marked_names(marked_ids, data);
+        if !names.is_empty() {
+            *error_message = None;
+            *operation_status = Some(OperationStatus::BatchUpdating(BatchProgress::new(names)));
+            return UpdateEffect::phase2(Msg::ExecuteBatch);
+        }
         if let Some(name) = selection.selected_id().cloned() {
             *error_message = None;
             *operation_status = Some(OperationStatus::Updating(name));
@@ -1149,112 +1502,491 @@ fn update_all(model: &mut MarketplacesScreenModel, data: &DataStore) -> UpdateEf
 }
 
 /// Phase 2: ExecuteUpdate
-fn execute_update(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEffect {
+fn execute_update(model: &MarketplacesScreenModel) -> UpdateEffect {
     execute_update_with(
         model,
-        data,
         actions::update_marketplace,
         actions::update_all_marketplaces,
-        |d| d.reload_marketplaces(),
     )
 }
 
-/// ExecuteUpdate の実装本体
-fn execute_update_with(
+/// ExecuteUpdate の実装本体（依存関数注入パターン）
+///
+/// 更新（GitHub からの fetch）をバックグラウンドで開始する。`operation_status` は
+/// 完了メッセージ（`UpdateFinished`）が届くまで残し、二重実行を防ぐ。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model holding the operation status.
+/// * `run_update` - Injected single marketplace update routine.
+/// * `run_update_all` - Injected routine updating every marketplace.
+pub(super) fn execute_update_with(
+    model: &MarketplacesScreenModel,
+    run_update: impl FnOnce(&str) -> Result<MarketplaceUpdateOutcome, String> + Send + 'static,
+    run_update_all: impl FnOnce() -> Vec<(String, Result<MarketplaceUpdateOutcome, String>)>
+        + Send
+        + 'static,
+) -> UpdateEffect {
+    match model {
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::Updating(name)),
+            ..
+        } => {
+            let name = name.clone();
+            UpdateEffect::background(move || {
+                let result = run_update(&name);
+                Msg::UpdateFinished(UpdateResult::Single(name, result))
+            })
+        }
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::UpdatingAll),
+            ..
+        } => UpdateEffect::background(move || {
+            Msg::UpdateFinished(UpdateResult::All(run_update_all()))
+        }),
+        _ => UpdateEffect::none(),
+    }
+}
+
+/// UpdateFinished: 更新結果を反映する
+fn finish_update(
     model: &mut MarketplacesScreenModel,
     data: &mut DataStore,
-    run_update: impl FnOnce(&str) -> Result<MarketplaceItem, String>,
-    run_update_all: impl FnOnce() -> Vec<(String, Result<MarketplaceItem, String>)>,
+    result: UpdateResult,
+) -> UpdateEffect {
+    finish_update_with(model, data, result, |d| d.reload_marketplaces())
+}
+
+/// 操作の失敗を Errors タブの履歴に残し、画面に表示するメッセージとして返す
+///
+/// # Arguments
+///
+/// * `data` - Shared data store holding the error history.
+/// * `message` - Error message shown on this tab.
+fn record_error(data: &mut DataStore, message: String) -> String {
+    data.push_error(Tab::Marketplaces, message.clone());
+    message
+}
+
+/// 更新後に残る marketplace.json の警告の案内（notice の末尾に付ける）
+///
+/// # Arguments
+///
+/// * `count` - Number of manifest warnings after the reload.
+fn warnings_suffix(count: usize) -> String {
+    if count == 0 {
+        String::new()
+    } else {
+        format!(" ({} warning(s), see Errors tab)", count)
+    }
+}
+
+/// UpdateFinished の実装本体
+///
+/// 成功時は更新前後のプラグイン差分（`+3 new, 1 removed, 2 updated`）を `notice` に残す。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model to mutate.
+/// * `data` - Shared data store for marketplaces.
+/// * `result` - Result delivered by the background update.
+/// * `reload` - Injected marketplace reload routine.
+pub(super) fn finish_update_with(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    result: UpdateResult,
     reload: impl FnOnce(&mut DataStore),
 ) -> UpdateEffect {
-    if let MarketplacesScreenModel::MarketList {
+    let MarketplacesScreenModel::MarketList {
         operation_status,
         error_message,
         selection,
+        notice,
         ..
     } = model
-    {
-        match operation_status.take() {
-            Some(OperationStatus::Updating(name)) => match run_update(&name) {
-                Ok(_) => {
-                    reload(data);
-                    let idx = data.marketplace_index(&name).unwrap_or(0);
-                    selection.set(Some(name), Some(idx));
-                    *error_message = None;
-                }
-                Err(e) => {
-                    *error_message = Some(format!("Failed to update '{}': {}", name, e));
-                }
-            },
-            Some(OperationStatus::UpdatingAll) => {
-                let results = run_update_all();
-                let mut errors = Vec::new();
-                for (name, result) in &results {
-                    if let Err(e) = result {
-                        errors.push(format!("{}: {}", name, e));
+    else {
+        reload(data);
+        return UpdateEffect::none();
+    };
+    *operation_status = None;
+    match result {
+        UpdateResult::Single(name, Ok(outcome)) => {
+            reload(data);
+            *notice = Some(format!(
+                "Updated '{}': {}{}",
+                name,
+                outcome.diff.summary(),
+                warnings_suffix(data.warning_count(Some(&name)))
+            ));
+            let idx = data.marketplace_index(&name).unwrap_or(0);
+            selection.set(Some(name), Some(idx));
+            *error_message = None;
+        }
+        UpdateResult::Single(name, Err(e)) => {
+            *notice = None;
+            *error_message = Some(record_error(
+                data,
+                format!("Failed to update '{}': {}", name, e),
+            ));
+        }
+        UpdateResult::All(results) => {
+            let mut errors = Vec::new();
+            let mut diff = UpdateDiff::default();
+            let mut updated = 0;
+            for (name, result) in results {
+                match result {
+                    Ok(outcome) => {
+                        updated += 1;
+                        diff.merge(outcome.diff);
                     }
-                }
-                reload(data);
-                if errors.is_empty() {
-                    *error_message = None;
-                } else {
-                    *error_message = Some(format!("Failed to update: {}", errors.join(", ")));
-                }
-                // 選択状態を維持
-                if let Some(id) = selection.selected_id() {
-                    let idx = data.marketplace_index(id).unwrap_or(0);
-                    selection.select_index(Some(idx));
+                    Err(e) => errors.push(format!("{}: {}", name, e)),
                 }
             }
-            other => {
-                // 元に戻す
-                *operation_status = other;
+            reload(data);
+            *notice = (updated > 0).then(|| {
+                format!(
+                    "Updated {} marketplace(s): {}{}",
+                    updated,
+                    diff.summary(),
+                    warnings_suffix(data.warning_count(None))
+                )
+            });
+            if errors.is_empty() {
+                *error_message = None;
+            } else {
+                *error_message = Some(record_error(
+                    data,
+                    format!("Failed to update: {}", errors.join(", ")),
+                ));
+            }
+            // 選択状態を維持
+            if let Some(id) = selection.selected_id() {
+                let idx = data.marketplace_index(id).unwrap_or(0);
+                selection.select_index(Some(idx));
             }
         }
     }
     UpdateEffect::none()
 }
 
-/// Phase 2: ExecuteRemove
-fn execute_remove(model: &mut MarketplacesScreenModel, data: &mut DataStore) -> UpdateEffect {
-    execute_remove_with(model, data, actions::remove_marketplace, |d| {
-        d.reload_marketplaces()
+/// Space キー: 選択中のマーケットプレイスのマークをトグル
+fn toggle_mark(model: &mut MarketplacesScreenModel) -> UpdateEffect {
+    if let MarketplacesScreenModel::MarketList {
+        selection,
+        operation_status: None,
+        marked_ids,
+        ..
+    } = model
+    {
+        // "+ Add new" 選択中はマーク対象がない
+        if let Some(name) = selection.selected_id() {
+            if !marked_ids.remove(name) {
+                marked_ids.insert(name.clone());
+            }
+        }
+    }
+    UpdateEffect::none()
+}
+
+/// マーク済みのマーケットプレイス名を一覧の表示順で返す（削除済みの名前は除く）
+///
+/// # Arguments
+///
+/// * `marked_ids` - Marked marketplace names.
+/// * `data` - Shared data store.
+fn marked_names(marked_ids: &HashSet<String>, data: &DataStore) -> Vec<String> {
+    data.marketplaces
+        .iter()
+        .filter(|m| marked_ids.contains(&m.name))
+        .map(|m| m.name.clone())
+        .collect()
+}
+
+/// 'x' キー: マーク済みマーケットプレイスの削除確認へ
+///
+/// 影響プレビューは確認を描画した後の phase2 で計算する。
+fn remove_marked(model: &mut MarketplacesScreenModel, data: &DataStore) -> UpdateEffect {
+    if let MarketplacesScreenModel::MarketList {
+        operation_status,
+        error_message,
+        marked_ids,
+        ..
+    } = model
+    {
+        if operation_status.is_some() {
+            return UpdateEffect::none();
+        }
+        let names = marked_names(marked_ids, data);
+        if !names.is_empty() {
+            *error_message = None;
+            *operation_status = Some(OperationStatus::ConfirmRemove {
+                names,
+                preview: PreviewState::Calculating,
+            });
+            return UpdateEffect::phase2(Msg::CalculateRemovePreview);
+        }
+    }
+    UpdateEffect::none()
+}
+
+/// Phase 2: 削除確認の影響プレビューを計算する
+///
+/// 登録とキャッシュを消すだけなので、そのマーケットプレイスからインストールした
+/// プラグインは残ることを示す。
+fn calculate_remove_preview(model: &mut MarketplacesScreenModel, data: &DataStore) {
+    if let MarketplacesScreenModel::MarketList {
+        operation_status: Some(OperationStatus::ConfirmRemove { names, preview }),
+        ..
+    } = model
+    {
+        let kept_plugins: Vec<String> = data
+            .plugins
+            .iter()
+            .filter_map(|p| {
+                let marketplace = p.marketplace()?;
+                names
+                    .iter()
+                    .any(|n| n == marketplace)
+                    .then(|| format!("{}@{}", p.name(), marketplace))
+            })
+            .collect();
+        *preview = PreviewState::Ready(preview_marketplace_removal(names.len(), &kept_plugins));
+    }
+}
+
+/// 削除確認で 'y' / Enter: 一括削除を開始
+fn confirm_remove(model: &mut MarketplacesScreenModel) -> UpdateEffect {
+    if let MarketplacesScreenModel::MarketList {
+        operation_status, ..
+    } = model
+    {
+        if let Some(OperationStatus::ConfirmRemove { names, .. }) = operation_status.take() {
+            *operation_status = Some(OperationStatus::BatchRemoving(BatchProgress::new(names)));
+            return UpdateEffect::phase2(Msg::ExecuteBatch);
+        }
+    }
+    UpdateEffect::none()
+}
+
+/// 削除確認で 'n' / Esc: 確認を閉じる（マークは残す）
+fn cancel_remove(model: &mut MarketplacesScreenModel) -> UpdateEffect {
+    if let MarketplacesScreenModel::MarketList {
+        operation_status, ..
+    } = model
+    {
+        if matches!(
+            operation_status,
+            Some(OperationStatus::ConfirmRemove { .. })
+        ) {
+            *operation_status = None;
+        }
+    }
+    UpdateEffect::none()
+}
+
+/// Phase 2: ExecuteBatch
+fn execute_batch(model: &MarketplacesScreenModel) -> UpdateEffect {
+    execute_batch_with(
+        model,
+        actions::update_marketplace,
+        actions::remove_marketplace,
+    )
+}
+
+/// ExecuteBatch の実装本体（依存関数注入パターン）
+///
+/// 未処理の先頭 1 件をバックグラウンドで開始する。完了（`BatchStepFinished`）ごとに
+/// 進捗を進め、残りがあれば再び `ExecuteBatch` を phase2 として返す（1 件ずつ処理して
+/// `done/total` の進捗を表示するため）。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model holding the batch progress.
+/// * `run_update` - Injected single marketplace update routine.
+/// * `run_remove` - Injected single marketplace removal routine.
+pub(super) fn execute_batch_with(
+    model: &MarketplacesScreenModel,
+    run_update: impl FnOnce(&str) -> Result<MarketplaceUpdateOutcome, String> + Send + 'static,
+    run_remove: impl FnOnce(&str) -> Result<(), String> + Send + 'static,
+) -> UpdateEffect {
+    let MarketplacesScreenModel::MarketList {
+        operation_status: Some(status),
+        ..
+    } = model
+    else {
+        return UpdateEffect::none();
+    };
+    let (progress, is_update) = match status {
+        OperationStatus::BatchUpdating(progress) => (progress, true),
+        OperationStatus::BatchRemoving(progress) => (progress, false),
+        _ => return UpdateEffect::none(),
+    };
+    let Some(name) = progress.names.get(progress.done).cloned() else {
+        return UpdateEffect::none();
+    };
+    UpdateEffect::background(move || {
+        let result = if is_update {
+            run_update(&name).map(|_| ())
+        } else {
+            run_remove(&name)
+        };
+        Msg::BatchStepFinished(name, result)
     })
 }
 
-/// ExecuteRemove の実装本体
-fn execute_remove_with(
+/// BatchStepFinished: 1 件分の結果を反映する
+fn finish_batch_step(
     model: &mut MarketplacesScreenModel,
     data: &mut DataStore,
-    run_remove: impl FnOnce(&str) -> Result<(), String>,
+    name: String,
+    result: Result<(), String>,
+) -> UpdateEffect {
+    finish_batch_step_with(model, data, name, result, |d| d.reload_marketplaces())
+}
+
+/// BatchStepFinished の実装本体
+///
+/// 全件処理後に一覧を再読込し、失敗した分だけマークを残してエラーをまとめて表示する。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model to mutate.
+/// * `data` - Shared data store for marketplaces.
+/// * `name` - Marketplace the finished step processed.
+/// * `result` - Result of the step.
+/// * `reload` - Injected marketplace reload routine.
+pub(super) fn finish_batch_step_with(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    name: String,
+    result: Result<(), String>,
     reload: impl FnOnce(&mut DataStore),
 ) -> UpdateEffect {
-    if let MarketplacesScreenModel::MarketList {
+    let MarketplacesScreenModel::MarketList {
+        operation_status,
+        error_message,
+        marked_ids,
+        ..
+    } = model
+    else {
+        return UpdateEffect::none();
+    };
+    let (progress, is_update) = match operation_status {
+        Some(OperationStatus::BatchUpdating(progress)) => (progress, true),
+        Some(OperationStatus::BatchRemoving(progress)) => (progress, false),
+        _ => return UpdateEffect::none(),
+    };
+
+    if let Err(e) = result {
+        progress.failures.push((name, e));
+    }
+    progress.done += 1;
+    if progress.done < progress.total() {
+        return UpdateEffect::phase2(Msg::ExecuteBatch);
+    }
+
+    let failures = std::mem::take(&mut progress.failures);
+    *operation_status = None;
+    reload(data);
+    *error_message = (!failures.is_empty()).then(|| {
+        let details: Vec<String> = failures
+            .iter()
+            .map(|(name, e)| format!("{}: {}", name, e))
+            .collect();
+        let verb = if is_update { "update" } else { "remove" };
+        record_error(data, format!("Failed to {}: {}", verb, details.join(", ")))
+    });
+    // 失敗した分だけマークを残して再試行しやすくする（削除済みの名前は捨てる）
+    *marked_ids = failures
+        .into_iter()
+        .map(|(name, _)| name)
+        .filter(|name| data.find_marketplace(name).is_some())
+        .collect();
+    UpdateEffect::none()
+}
+
+/// Phase 2: ExecuteRemove
+fn execute_remove(model: &MarketplacesScreenModel) -> UpdateEffect {
+    execute_remove_with(model, actions::remove_marketplace)
+}
+
+/// ExecuteRemove の実装本体（依存関数注入パターン）
+///
+/// 削除をバックグラウンドで開始する。`operation_status` は完了まで残す。
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model holding the operation status.
+/// * `run_remove` - Injected single marketplace removal routine.
+pub(super) fn execute_remove_with(
+    model: &MarketplacesScreenModel,
+    run_remove: impl FnOnce(&str) -> Result<(), String> + Send + 'static,
+) -> UpdateEffect {
+    match model {
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::Removing(name)),
+            ..
+        } => {
+            let name = name.clone();
+            UpdateEffect::background(move || {
+                let result = run_remove(&name);
+                Msg::RemoveFinished(name, result)
+            })
+        }
+        _ => UpdateEffect::none(),
+    }
+}
+
+/// RemoveFinished: 削除結果を反映する
+fn finish_remove(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    name: String,
+    result: Result<(), String>,
+) -> UpdateEffect {
+    finish_remove_with(model, data, name, result, |d| d.reload_marketplaces())
+}
+
+/// RemoveFinished の実装本体
+///
+/// # Arguments
+///
+/// * `model` - Marketplaces tab model to mutate.
+/// * `data` - Shared data store for marketplaces.
+/// * `name` - Removed marketplace.
+/// * `result` - Result delivered by the background removal.
+/// * `reload` - Injected marketplace reload routine.
+pub(super) fn finish_remove_with(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    name: String,
+    result: Result<(), String>,
+    reload: impl FnOnce(&mut DataStore),
+) -> UpdateEffect {
+    let MarketplacesScreenModel::MarketList {
         operation_status,
         error_message,
         selection,
         ..
     } = model
-    {
-        match operation_status.take() {
-            Some(OperationStatus::Removing(name)) => {
-                match run_remove(&name) {
-                    Ok(()) => {
-                        reload(data);
-                        // 先頭にクランプ
-                        let new_selected = data.marketplaces.first().map(|m| m.name.clone());
-                        selection.set(new_selected, Some(0));
-                        *error_message = None;
-                    }
-                    Err(e) => {
-                        *error_message = Some(format!("Failed to remove '{}': {}", name, e));
-                    }
-                }
-            }
-            other => {
-                // Removing 以外の状態だった場合は元に戻す
-                *operation_status = other;
-            }
+    else {
+        reload(data);
+        return UpdateEffect::none();
+    };
+    *operation_status = None;
+    match result {
+        Ok(()) => {
+            reload(data);
+            // 先頭にクランプ
+            let new_selected = data.marketplaces.first().map(|m| m.name.clone());
+            selection.set(new_selected, Some(0));
+            *error_message = None;
+        }
+        Err(e) => {
+            *error_message = Some(record_error(
+                data,
+                format!("Failed to remove '{}': {}", name, e),
+            ));
         }
     }
     UpdateEffect::none()
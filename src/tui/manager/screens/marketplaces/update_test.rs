@@ -1,13 +1,24 @@
 use super::{
-    execute_add_phase1, execute_add_with, execute_remove_with, execute_update_with, update,
+    clamp_selection, execute_add_phase1, execute_add_with, execute_batch_with, execute_remove_with,
+    execute_update_with, finish_batch_step_with, finish_remove_with, finish_update_with, update,
+};
+use crate::application::InstalledPlugin;
+use crate::marketplace::{PluginSource, UpdateDiff, ValidationIssue};
+use crate::tui::manager::core::preview::PreviewState;
+use crate::tui::manager::core::{
+    DataStore, ManifestWarning, MarketplaceItem, NavigationHistory, SelectionState,
+};
+use crate::tui::manager::screens::marketplaces::actions::{
+    MarketplaceAddOutcome, MarketplaceUpdateOutcome,
 };
-use crate::marketplace::PluginSource;
-use crate::tui::manager::core::{DataStore, MarketplaceItem, SelectionState};
-use crate::tui::manager::screens::marketplaces::actions::MarketplaceAddOutcome;
 use crate::tui::manager::screens::marketplaces::model::{
-    AddFormModel, BrowsePlugin, DetailAction, MarketplacesScreenModel, Msg, OperationStatus,
+    AddFormModel, BatchProgress, BrowsePlugin, DetailAction, MarketplacesScreenModel, Msg,
+    OperationStatus,
 };
+use chrono::Utc;
 use ratatui::widgets::ListState;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 
 // ============================================================================
 // ヘルパー関数
@@ -19,7 +30,7 @@ fn make_marketplace(name: &str) -> MarketplaceItem {
         source: format!("owner/{}", name),
         source_path: None,
         plugin_count: Some(3),
-        last_updated: Some("2026-01-01 00:00".to_string()),
+        fetched_at: Some(Utc::now()),
     }
 }
 
@@ -31,6 +42,13 @@ fn make_data(names: &[&str]) -> (tempfile::TempDir, DataStore) {
     )
 }
 
+fn make_update_outcome(name: &str) -> MarketplaceUpdateOutcome {
+    MarketplaceUpdateOutcome {
+        marketplace: make_marketplace(name),
+        diff: UpdateDiff::default(),
+    }
+}
+
 fn make_add_outcome(name: &str) -> MarketplaceAddOutcome {
     MarketplaceAddOutcome {
         marketplace: make_marketplace(name),
@@ -44,6 +62,45 @@ fn market_selection(
     SelectionState::new(selected_id.map(str::to_string), selected_index)
 }
 
+/// バックグラウンド操作をその場で実行し、完了メッセージを返す
+fn run_task(effect: super::UpdateEffect) -> Msg {
+    let task = effect
+        .task
+        .expect("operation should start a background task");
+    task()
+}
+
+/// ExecuteUpdate を開始し、完了メッセージ（UpdateFinished）まで反映する
+fn update_to_end(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    run_update: impl FnOnce(&str) -> Result<MarketplaceUpdateOutcome, String> + Send + 'static,
+    run_update_all: impl FnOnce() -> Vec<(String, Result<MarketplaceUpdateOutcome, String>)>
+        + Send
+        + 'static,
+    reload: impl FnOnce(&mut DataStore),
+) {
+    let effect = execute_update_with(model, run_update, run_update_all);
+    let Msg::UpdateFinished(result) = run_task(effect) else {
+        panic!("Expected UpdateFinished");
+    };
+    finish_update_with(model, data, result, reload);
+}
+
+/// ExecuteRemove を開始し、完了メッセージ（RemoveFinished）まで反映する
+fn remove_to_end(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    run_remove: impl FnOnce(&str) -> Result<(), String> + Send + 'static,
+    reload: impl FnOnce(&mut DataStore),
+) {
+    let effect = execute_remove_with(model, run_remove);
+    let Msg::RemoveFinished(name, result) = run_task(effect) else {
+        panic!("Expected RemoveFinished");
+    };
+    finish_remove_with(model, data, name, result, reload);
+}
+
 // ============================================================================
 // Up/Down ナビゲーション
 // ============================================================================
@@ -52,6 +109,7 @@ fn market_selection(
 fn down_moves_selection_in_market_list() {
     let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // 初期状態: index 0 (mp-a), selected_id = Some("mp-a")
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
@@ -59,7 +117,7 @@ fn down_moves_selection_in_market_list() {
         assert_eq!(selection.selected_id().map(String::as_str), Some("mp-a"));
     }
 
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_index(), Some(1));
@@ -73,9 +131,10 @@ fn down_moves_selection_in_market_list() {
 fn down_past_last_marketplace_selects_add_new() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Move past mp-a to "+ Add new" (index 1)
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_index(), Some(1));
@@ -93,10 +152,11 @@ fn down_past_last_marketplace_selects_add_new() {
 fn down_does_not_go_past_add_new() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // list len = 2 (mp-a + Add new)
-    update(&mut model, Msg::Down, &mut data); // index 1 (Add new)
-    update(&mut model, Msg::Down, &mut data); // should stay at 1
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // index 1 (Add new)
+    update(&mut model, &mut history, Msg::Down, &mut data, ""); // should stay at 1
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_index(), Some(1));
@@ -109,8 +169,9 @@ fn down_does_not_go_past_add_new() {
 fn up_does_not_go_past_zero() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Up, &mut data);
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_index(), Some(0));
@@ -123,13 +184,14 @@ fn up_does_not_go_past_zero() {
 fn up_from_add_new_returns_to_last_marketplace() {
     let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Move to Add new (index 2)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     // Move back up
-    update(&mut model, Msg::Up, &mut data);
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_index(), Some(1));
@@ -143,15 +205,16 @@ fn up_from_add_new_returns_to_last_marketplace() {
 fn down_in_detail_moves_action_selection() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::MarketDetail { state, .. } = &model {
         assert_eq!(state.selected(), Some(0));
     }
 
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::MarketDetail { state, .. } = &model {
         assert_eq!(state.selected(), Some(1));
@@ -164,13 +227,14 @@ fn down_in_detail_moves_action_selection() {
 fn down_in_detail_does_not_exceed_action_count() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     let action_count = DetailAction::all().len();
     for _ in 0..action_count + 5 {
-        update(&mut model, Msg::Down, &mut data);
+        update(&mut model, &mut history, Msg::Down, &mut data, "");
     }
 
     if let MarketplacesScreenModel::MarketDetail { state, .. } = &model {
@@ -180,6 +244,85 @@ fn down_in_detail_does_not_exceed_action_count() {
     }
 }
 
+// ============================================================================
+// フィルタ
+// ============================================================================
+
+#[test]
+fn down_walks_only_filtered_marketplaces() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "other", "mp-b"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "mp-");
+
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_index(), Some(1));
+        assert_eq!(selection.selected_id().map(String::as_str), Some("mp-b"));
+    } else {
+        panic!("Expected MarketList");
+    }
+
+    // 次は "+ Add new"（フィルタ済み 2 件の直後）
+    update(&mut model, &mut history, Msg::Down, &mut data, "mp-");
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_index(), Some(2));
+        assert_eq!(selection.selected_id(), None);
+    }
+}
+
+#[test]
+fn clamp_selection_moves_to_first_match_when_selected_is_hidden() {
+    let (_temp_dir, data) = make_data(&["mp-a", "other"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+
+    clamp_selection(&mut model, &data, "other");
+
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id().map(String::as_str), Some("other"));
+        assert_eq!(selection.selected_index(), Some(0));
+    } else {
+        panic!("Expected MarketList");
+    }
+}
+
+#[test]
+fn clamp_selection_clears_selection_when_nothing_matches() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    clamp_selection(&mut model, &data, "zzz");
+
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id(), None);
+        assert_eq!(selection.selected_index(), None);
+    }
+
+    // 空結果では Enter で AddForm へ進まない
+    update(&mut model, &mut history, Msg::Enter, &mut data, "zzz");
+    assert!(matches!(model, MarketplacesScreenModel::MarketList { .. }));
+
+    // フィルタを外すと先頭が選ばれる
+    clamp_selection(&mut model, &data, "");
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id().map(String::as_str), Some("mp-a"));
+        assert_eq!(selection.selected_index(), Some(0));
+    }
+}
+
+#[test]
+fn filter_matches_marketplace_source() {
+    let (_temp_dir, data) = make_data(&["mp-a", "mp-b"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+
+    clamp_selection(&mut model, &data, "OWNER/MP-B");
+
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id().map(String::as_str), Some("mp-b"));
+    }
+}
+
 // ============================================================================
 // Enter ナビゲーション
 // ============================================================================
@@ -188,8 +331,9 @@ fn down_in_detail_does_not_exceed_action_count() {
 fn enter_on_marketplace_transitions_to_detail() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::MarketDetail {
         marketplace_name,
@@ -208,10 +352,11 @@ fn enter_on_marketplace_transitions_to_detail() {
 fn enter_on_add_new_transitions_to_add_form() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Move to Add new
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
         matches!(
@@ -226,9 +371,10 @@ fn enter_on_add_new_transitions_to_add_form() {
 fn enter_on_empty_list_transitions_to_add_form() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // With 0 marketplaces, index 0 is Add new (selected_id = None)
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
         matches!(
@@ -243,6 +389,7 @@ fn enter_on_empty_list_transitions_to_add_form() {
 fn enter_ignored_when_operation_in_progress() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Set operation_status
     if let MarketplacesScreenModel::MarketList {
@@ -252,7 +399,7 @@ fn enter_ignored_when_operation_in_progress() {
         *operation_status = Some(OperationStatus::Updating("mp-a".to_string()));
     }
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Should still be MarketList (not transitioned)
     assert!(
@@ -269,12 +416,13 @@ fn enter_ignored_when_operation_in_progress() {
 fn detail_update_action_returns_execute_batch() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // First action is Update (index 0)
-    let effect = update(&mut model, Msg::Enter, &mut data);
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
         effect.phase2_msg.is_some(),
@@ -298,13 +446,14 @@ fn detail_update_action_returns_execute_batch() {
 fn detail_remove_action_returns_execute_batch() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Move to Remove (index 1)
-    update(&mut model, Msg::Down, &mut data);
-    let effect = update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
         effect.phase2_msg.is_some(),
@@ -328,14 +477,15 @@ fn detail_remove_action_returns_execute_batch() {
 fn detail_show_plugins_transitions_to_plugin_list() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Move to ShowPlugins (index 2)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    let effect = update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(effect.phase2_msg.is_none());
 
@@ -353,16 +503,17 @@ fn detail_show_plugins_transitions_to_plugin_list() {
 fn detail_back_action_returns_to_market_list() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Move to Back (index 4: Update, Remove, ShowPlugins, BrowsePlugins, Back)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_id().map(String::as_str), Some("mp-a"));
@@ -406,8 +557,9 @@ fn make_plugin_browse(name: &str, plugin_count: usize) -> MarketplacesScreenMode
 fn down_in_plugin_browse_moves_highlight() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::PluginBrowse {
         highlighted_idx,
@@ -426,8 +578,9 @@ fn down_in_plugin_browse_moves_highlight() {
 fn up_in_plugin_browse_does_not_go_below_zero() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Up, &mut data);
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
 
     if let MarketplacesScreenModel::PluginBrowse {
         highlighted_idx, ..
@@ -443,11 +596,12 @@ fn up_in_plugin_browse_does_not_go_below_zero() {
 fn down_in_plugin_browse_does_not_exceed_len() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 2);
+    let mut history = NavigationHistory::default();
 
     // Move to last
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
     // Try to go beyond
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::PluginBrowse {
         highlighted_idx, ..
@@ -506,8 +660,9 @@ fn make_scope_select(name: &str) -> MarketplacesScreenModel {
 fn down_in_target_select_moves_highlight() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::TargetSelect {
         highlighted_idx,
@@ -526,9 +681,10 @@ fn down_in_target_select_moves_highlight() {
 fn up_down_in_scope_select_moves_highlight() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_scope_select("mp-a");
+    let mut history = NavigationHistory::default();
 
     // Down from 0 -> 1
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
     if let MarketplacesScreenModel::ScopeSelect {
         highlighted_idx, ..
     } = &model
@@ -537,7 +693,7 @@ fn up_down_in_scope_select_moves_highlight() {
     }
 
     // Down again -> still 1 (clamped, only 2 options)
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
     if let MarketplacesScreenModel::ScopeSelect {
         highlighted_idx, ..
     } = &model
@@ -546,7 +702,7 @@ fn up_down_in_scope_select_moves_highlight() {
     }
 
     // Up from 1 -> 0
-    update(&mut model, Msg::Up, &mut data);
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
     if let MarketplacesScreenModel::ScopeSelect {
         highlighted_idx, ..
     } = &model
@@ -563,13 +719,14 @@ fn up_down_in_scope_select_moves_highlight() {
 fn confirm_targets_transitions_to_scope_select() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
 
     // Select first target
     if let MarketplacesScreenModel::TargetSelect { targets, .. } = &mut model {
         targets[0].2 = true;
     }
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -594,9 +751,10 @@ fn confirm_targets_transitions_to_scope_select() {
 fn confirm_targets_promotes_cursor_when_no_target_selected() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
     // All targets unselected; highlighted_idx defaults to 0
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -619,6 +777,7 @@ fn confirm_targets_promotes_cursor_when_no_target_selected() {
 fn confirm_targets_promotes_cursor_at_highlighted_idx() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::TargetSelect {
         highlighted_idx, ..
     } = &mut model
@@ -626,7 +785,7 @@ fn confirm_targets_promotes_cursor_at_highlighted_idx() {
         *highlighted_idx = 2;
     }
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     if let MarketplacesScreenModel::ScopeSelect { target_names, .. } = &model {
         assert_eq!(target_names, &vec!["antigravity".to_string()]);
@@ -639,6 +798,7 @@ fn confirm_targets_promotes_cursor_at_highlighted_idx() {
 fn confirm_targets_keeps_existing_truthy_targets() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::TargetSelect {
         targets,
         highlighted_idx,
@@ -649,7 +809,7 @@ fn confirm_targets_keeps_existing_truthy_targets() {
         *highlighted_idx = 2; // cursor on antigravity
     }
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     if let MarketplacesScreenModel::ScopeSelect { target_names, .. } = &model {
         assert_eq!(
@@ -666,11 +826,12 @@ fn confirm_targets_keeps_existing_truthy_targets() {
 fn confirm_targets_noop_when_targets_empty() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::TargetSelect { targets, .. } = &mut model {
         targets.clear();
     }
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -683,6 +844,7 @@ fn confirm_targets_noop_when_targets_empty() {
 fn confirm_targets_clamps_cursor_when_idx_out_of_range() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::TargetSelect {
         targets,
         highlighted_idx,
@@ -693,7 +855,7 @@ fn confirm_targets_clamps_cursor_when_idx_out_of_range() {
         *highlighted_idx = 10;
     }
 
-    update(&mut model, Msg::ConfirmTargets, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmTargets, &mut data, "");
 
     if let MarketplacesScreenModel::ScopeSelect { target_names, .. } = &model {
         assert_eq!(
@@ -714,9 +876,10 @@ fn confirm_targets_clamps_cursor_when_idx_out_of_range() {
 fn toggle_select_in_target_select_toggles_target() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
 
     // Toggle on
-    update(&mut model, Msg::ToggleSelect, &mut data);
+    update(&mut model, &mut history, Msg::ToggleSelect, &mut data, "");
     if let MarketplacesScreenModel::TargetSelect { targets, .. } = &model {
         assert!(targets[0].2, "Target 0 should be selected after toggle");
     } else {
@@ -724,7 +887,7 @@ fn toggle_select_in_target_select_toggles_target() {
     }
 
     // Toggle off
-    update(&mut model, Msg::ToggleSelect, &mut data);
+    update(&mut model, &mut history, Msg::ToggleSelect, &mut data, "");
     if let MarketplacesScreenModel::TargetSelect { targets, .. } = &model {
         assert!(
             !targets[0].2,
@@ -743,6 +906,7 @@ fn toggle_select_in_target_select_toggles_target() {
 fn start_install_transitions_to_target_select() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
     // Select plugin-0
     if let MarketplacesScreenModel::PluginBrowse {
@@ -752,7 +916,7 @@ fn start_install_transitions_to_target_select() {
         selected_plugins.insert("plugin-0".to_string());
     }
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -788,9 +952,10 @@ fn start_install_transitions_to_target_select() {
 fn start_install_promotes_cursor_when_no_selection() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
     // selected_plugins is empty; highlighted_idx defaults to 0
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -820,6 +985,7 @@ fn start_install_promotes_cursor_when_no_selection() {
 fn start_install_promotes_cursor_at_highlighted_idx() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::PluginBrowse {
         highlighted_idx, ..
     } = &mut model
@@ -827,7 +993,7 @@ fn start_install_promotes_cursor_at_highlighted_idx() {
         *highlighted_idx = 1;
     }
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     if let MarketplacesScreenModel::TargetSelect {
         selected_plugins, ..
@@ -846,6 +1012,7 @@ fn start_install_promotes_cursor_at_highlighted_idx() {
 fn start_install_keeps_existing_selection() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::PluginBrowse {
         selected_plugins,
         highlighted_idx,
@@ -856,7 +1023,7 @@ fn start_install_keeps_existing_selection() {
         *highlighted_idx = 0;
     }
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     if let MarketplacesScreenModel::TargetSelect {
         selected_plugins, ..
@@ -877,8 +1044,9 @@ fn start_install_keeps_existing_selection() {
 fn start_install_noop_when_plugin_list_empty() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 0);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -897,6 +1065,7 @@ fn start_install_noop_when_plugin_list_empty() {
 fn start_install_clamps_cursor_when_idx_out_of_range() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 2);
+    let mut history = NavigationHistory::default();
     if let MarketplacesScreenModel::PluginBrowse {
         highlighted_idx, ..
     } = &mut model
@@ -904,7 +1073,7 @@ fn start_install_clamps_cursor_when_idx_out_of_range() {
         *highlighted_idx = 99;
     }
 
-    update(&mut model, Msg::StartInstall, &mut data);
+    update(&mut model, &mut history, Msg::StartInstall, &mut data, "");
 
     if let MarketplacesScreenModel::TargetSelect {
         selected_plugins, ..
@@ -927,8 +1096,9 @@ fn start_install_clamps_cursor_when_idx_out_of_range() {
 fn toggle_select_in_plugin_browse_adds_to_selected() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::ToggleSelect, &mut data);
+    update(&mut model, &mut history, Msg::ToggleSelect, &mut data, "");
 
     if let MarketplacesScreenModel::PluginBrowse {
         selected_plugins, ..
@@ -947,6 +1117,7 @@ fn toggle_select_in_plugin_browse_adds_to_selected() {
 fn toggle_select_in_plugin_browse_removes_from_selected() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
     // Pre-add plugin-0 to selected
     if let MarketplacesScreenModel::PluginBrowse {
@@ -956,7 +1127,7 @@ fn toggle_select_in_plugin_browse_removes_from_selected() {
         selected_plugins.insert("plugin-0".to_string());
     }
 
-    update(&mut model, Msg::ToggleSelect, &mut data);
+    update(&mut model, &mut history, Msg::ToggleSelect, &mut data, "");
 
     if let MarketplacesScreenModel::PluginBrowse {
         selected_plugins, ..
@@ -979,15 +1150,16 @@ fn toggle_select_in_plugin_browse_removes_from_selected() {
 fn enter_browse_plugins_transitions_to_plugin_browse() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Move to BrowsePlugins (index 3: Update=0, Remove=1, ShowPlugins=2, BrowsePlugins=3)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -1015,15 +1187,16 @@ fn browse_plugins_guard_failure_sets_error_message_on_market_detail() {
     data.marketplaces[0].plugin_count = None;
 
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Move to BrowsePlugins (index 3)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::MarketDetail { error_message, .. } = &model {
         assert_eq!(
@@ -1041,12 +1214,13 @@ fn browse_plugins_guard_success_still_transitions_to_plugin_browse() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     // Default fixture has plugin_count = Some(3)
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert_eq!(model_variant(&model), "PluginBrowse");
 }
@@ -1078,14 +1252,23 @@ fn back_to_plugin_browse_from_result_refreshes_plugins() {
                 plugin_name: "p1".to_string(),
                 success: true,
                 error: None,
+                conflicts: Vec::new(),
             }],
             total: 1,
             succeeded: 1,
             failed: 0,
+            warnings: Vec::new(),
         },
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::BackToPluginBrowse, &mut data);
+    update(
+        &mut model,
+        &mut history,
+        Msg::BackToPluginBrowse,
+        &mut data,
+        "",
+    );
 
     assert_eq!(model_variant(&model), "PluginBrowse");
 
@@ -1152,16 +1335,19 @@ fn execute_install_transitions_to_install_result() {
                     plugin_name: "p1".to_string(),
                     success: true,
                     error: None,
+                    conflicts: Vec::new(),
                 },
                 PluginInstallOutcome {
                     plugin_name: "p2".to_string(),
                     success: true,
                     error: None,
+                    conflicts: Vec::new(),
                 },
             ],
             total: 2,
             succeeded: 2,
             failed: 0,
+            warnings: Vec::new(),
         },
         |_d| {
             reload_called = true;
@@ -1194,16 +1380,19 @@ fn execute_install_with_failure_shows_in_summary() {
                     plugin_name: "p1".to_string(),
                     success: true,
                     error: None,
+                    conflicts: Vec::new(),
                 },
                 PluginInstallOutcome {
                     plugin_name: "p2".to_string(),
                     success: false,
                     error: Some("install failed".to_string()),
+                    conflicts: Vec::new(),
                 },
             ],
             total: 2,
             succeeded: 1,
             failed: 1,
+            warnings: Vec::new(),
         },
         |_d| Ok(()),
     );
@@ -1216,6 +1405,39 @@ fn execute_install_with_failure_shows_in_summary() {
     }
 }
 
+#[test]
+fn execute_install_with_keeps_warnings_for_the_errors_tab() {
+    use super::execute_install_with;
+    use crate::application::{Warning, WarningCategory};
+
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = make_installing("mp-a", &["p1"]);
+    let warning = Warning {
+        category: WarningCategory::Conversion,
+        message: "codex Hook: guard: unsupported event".to_string(),
+    };
+
+    execute_install_with(
+        &mut model,
+        &mut data,
+        |_mp, _plugins, _targets, _scope| InstallSummary {
+            results: vec![PluginInstallOutcome {
+                plugin_name: "p1".to_string(),
+                success: true,
+                error: None,
+                conflicts: Vec::new(),
+            }],
+            total: 1,
+            succeeded: 1,
+            failed: 0,
+            warnings: vec![warning.clone()],
+        },
+        |_d| Ok(()),
+    );
+
+    assert_eq!(data.last_warnings, vec![warning]);
+}
+
 // ============================================================================
 // ConfirmScope (ScopeSelect -> Installing)
 // ============================================================================
@@ -1254,8 +1476,9 @@ fn make_scope_select_with_plugins(name: &str, plugin_names: &[&str]) -> Marketpl
 fn confirm_scope_transitions_to_installing_with_phase2() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_scope_select_with_plugins("mp-a", &["p1", "p2"]);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::ConfirmScope, &mut data);
+    let effect = update(&mut model, &mut history, Msg::ConfirmScope, &mut data, "");
 
     assert_eq!(model_variant(&model), "Installing");
     assert!(
@@ -1284,9 +1507,10 @@ fn confirm_scope_transitions_to_installing_with_phase2() {
 fn confirm_scope_personal_sets_scope_personal() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_scope_select_with_plugins("mp-a", &["p1"]);
+    let mut history = NavigationHistory::default();
 
     // highlighted_idx = 0 -> Personal
-    update(&mut model, Msg::ConfirmScope, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmScope, &mut data, "");
 
     if let MarketplacesScreenModel::Installing { scope, .. } = &model {
         assert_eq!(*scope, crate::component::Scope::Personal);
@@ -1299,6 +1523,7 @@ fn confirm_scope_personal_sets_scope_personal() {
 fn confirm_scope_project_sets_scope_project() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_scope_select_with_plugins("mp-a", &["p1"]);
+    let mut history = NavigationHistory::default();
 
     // Move to Project (index 1)
     if let MarketplacesScreenModel::ScopeSelect {
@@ -1311,7 +1536,7 @@ fn confirm_scope_project_sets_scope_project() {
         state.select(Some(1));
     }
 
-    update(&mut model, Msg::ConfirmScope, &mut data);
+    update(&mut model, &mut history, Msg::ConfirmScope, &mut data, "");
 
     if let MarketplacesScreenModel::Installing { scope, .. } = &model {
         assert_eq!(*scope, crate::component::Scope::Project);
@@ -1328,6 +1553,7 @@ fn confirm_scope_project_sets_scope_project() {
 fn back_from_plugin_browse_returns_to_detail() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_plugin_browse("mp-a", 3);
+    let mut history = NavigationHistory::default();
 
     // Select a plugin before going back
     if let MarketplacesScreenModel::PluginBrowse {
@@ -1337,7 +1563,7 @@ fn back_from_plugin_browse_returns_to_detail() {
         selected_plugins.insert("plugin-0".to_string());
     }
 
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     assert_eq!(
         model_variant(&model),
@@ -1371,6 +1597,7 @@ fn back_from_plugin_browse_returns_to_detail() {
 fn back_from_target_select_returns_to_plugin_browse() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_target_select("mp-a");
+    let mut history = NavigationHistory::default();
 
     // Set selected_plugins
     if let MarketplacesScreenModel::TargetSelect {
@@ -1380,7 +1607,7 @@ fn back_from_target_select_returns_to_plugin_browse() {
         selected_plugins.insert("p1".to_string());
     }
 
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     assert_eq!(model_variant(&model), "PluginBrowse");
 
@@ -1399,8 +1626,9 @@ fn back_from_target_select_returns_to_plugin_browse() {
 fn back_from_scope_select_returns_to_target_select() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = make_scope_select("mp-a");
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     assert_eq!(model_variant(&model), "TargetSelect");
 
@@ -1428,8 +1656,9 @@ fn back_from_scope_select_preserves_target_selection() {
         highlighted_idx: 0,
         state,
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     assert_eq!(model_variant(&model), "TargetSelect");
 
@@ -1484,8 +1713,9 @@ fn enter_browse_plugins_restores_selection() {
         browse_plugins: Some(preserved_plugins),
         browse_selected: Some(preserved_selected),
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert_eq!(model_variant(&model), "PluginBrowse");
     if let MarketplacesScreenModel::PluginBrowse {
@@ -1510,16 +1740,17 @@ fn enter_browse_plugins_restores_selection() {
 fn back_from_detail_returns_to_market_list() {
     let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     assert!(matches!(
         model,
         MarketplacesScreenModel::MarketDetail { .. }
     ));
 
     // Back -> MarketList
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
         assert_eq!(selection.selected_id().map(String::as_str), Some("mp-a"));
@@ -1532,24 +1763,28 @@ fn back_from_detail_returns_to_market_list() {
 fn back_from_plugin_list_returns_to_detail() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter -> MarketDetail
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // ShowPlugins (index 2)
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     assert!(matches!(model, MarketplacesScreenModel::PluginList { .. }));
 
-    // Back -> MarketDetail
-    update(&mut model, Msg::Back, &mut data);
+    // Back -> MarketDetail（カーソルは ShowPlugins のまま）
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     if let MarketplacesScreenModel::MarketDetail {
-        marketplace_name, ..
+        marketplace_name,
+        state,
+        ..
     } = &model
     {
         assert_eq!(marketplace_name, "mp-a");
+        assert_eq!(state.selected(), Some(2));
     } else {
         panic!("Expected MarketDetail");
     }
@@ -1559,14 +1794,15 @@ fn back_from_plugin_list_returns_to_detail() {
 fn back_from_add_form_returns_to_market_list() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Move to Add new and enter
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     assert!(matches!(model, MarketplacesScreenModel::AddForm(_)));
 
     // Back -> MarketList
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     assert!(
         matches!(model, MarketplacesScreenModel::MarketList { .. }),
@@ -1574,6 +1810,97 @@ fn back_from_add_form_returns_to_market_list() {
     );
 }
 
+#[test]
+fn back_restores_add_new_selection_from_history() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    assert_eq!(effect.restore_filter.as_deref(), Some(""));
+    assert!(history.is_empty());
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id(), None);
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected MarketList");
+    }
+}
+
+#[test]
+fn back_selects_nearest_marketplace_when_restored_one_is_gone() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b", "mp-c"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    data.marketplaces.retain(|m| m.name != "mp-c");
+
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    if let MarketplacesScreenModel::MarketList { selection, .. } = &model {
+        assert_eq!(selection.selected_id().map(String::as_str), Some("mp-b"));
+        assert_eq!(selection.selected_index(), Some(1));
+    } else {
+        panic!("Expected MarketList");
+    }
+}
+
+#[test]
+fn back_after_install_outcome_returns_to_detail_via_history() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut history = NavigationHistory::default();
+    let mut detail_state = ListState::default();
+    detail_state.select(Some(3));
+    history.push(MarketplacesScreenModel::new(&data), "");
+    history.push(
+        MarketplacesScreenModel::MarketDetail {
+            marketplace_name: "mp-a".to_string(),
+            state: detail_state,
+            error_message: None,
+            browse_plugins: None,
+            browse_selected: None,
+        },
+        "",
+    );
+    history.push(make_plugin_browse("mp-a", 2), "");
+    history.push(make_target_select("mp-a"), "");
+    let mut model = MarketplacesScreenModel::InstallOutcome {
+        marketplace_name: "mp-a".to_string(),
+        plugins: vec![],
+        summary: InstallSummary {
+            results: vec![],
+            total: 0,
+            succeeded: 0,
+            failed: 0,
+            warnings: Vec::new(),
+        },
+    };
+
+    update(
+        &mut model,
+        &mut history,
+        Msg::BackToPluginBrowse,
+        &mut data,
+        "",
+    );
+    assert_eq!(model_variant(&model), "PluginBrowse");
+    assert_eq!(history.len(), 2);
+
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
+
+    if let MarketplacesScreenModel::MarketDetail { state, .. } = &model {
+        assert_eq!(state.selected(), Some(3));
+    } else {
+        panic!("Expected MarketDetail");
+    }
+}
+
 // ============================================================================
 // FormInput / FormBackspace
 // ============================================================================
@@ -1582,12 +1909,13 @@ fn back_from_add_form_returns_to_market_list() {
 fn form_input_appends_to_source() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Enter on Add new
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
-    update(&mut model, Msg::FormInput('a'), &mut data);
-    update(&mut model, Msg::FormInput('b'), &mut data);
+    update(&mut model, &mut history, Msg::FormInput('a'), &mut data, "");
+    update(&mut model, &mut history, Msg::FormInput('b'), &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { source_input, .. }) = &model {
         assert_eq!(source_input, "ab");
@@ -1600,11 +1928,12 @@ fn form_input_appends_to_source() {
 fn form_backspace_removes_from_source() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
-    update(&mut model, Msg::FormInput('a'), &mut data);
-    update(&mut model, Msg::FormInput('b'), &mut data);
-    update(&mut model, Msg::FormBackspace, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::FormInput('a'), &mut data, "");
+    update(&mut model, &mut history, Msg::FormInput('b'), &mut data, "");
+    update(&mut model, &mut history, Msg::FormBackspace, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { source_input, .. }) = &model {
         assert_eq!(source_input, "a");
@@ -1617,8 +1946,9 @@ fn form_backspace_removes_from_source() {
 fn form_input_clears_error_message() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Set error manually
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { error_message, .. }) = &mut model
@@ -1626,7 +1956,7 @@ fn form_input_clears_error_message() {
         *error_message = Some("test error".to_string());
     }
 
-    update(&mut model, Msg::FormInput('x'), &mut data);
+    update(&mut model, &mut history, Msg::FormInput('x'), &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { error_message, .. }) = &model {
         assert!(
@@ -1646,10 +1976,11 @@ fn form_input_clears_error_message() {
 fn enter_empty_source_shows_error() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     // Enter with empty input
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { error_message, .. }) = &model {
         assert!(
@@ -1665,14 +1996,15 @@ fn enter_empty_source_shows_error() {
 fn enter_invalid_source_shows_error() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Type invalid source (no slash)
     for c in "invalid".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Source { error_message, .. }) = &model {
         assert!(
@@ -1692,14 +2024,15 @@ fn enter_invalid_source_shows_error() {
 fn enter_valid_source_transitions_to_name_step() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Type valid source
     for c in "owner/repo".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Name {
         source,
@@ -1714,20 +2047,109 @@ fn enter_valid_source_transitions_to_name_step() {
     }
 }
 
+#[test]
+fn enter_local_path_source_transitions_to_name_step() {
+    let (_temp_dir, mut data) = make_data(&[]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    let local = tempfile::TempDir::new().unwrap();
+    let dir = local.path().join("local-mp");
+    std::fs::create_dir(&dir).unwrap();
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for c in format!("file://{}", dir.display()).chars() {
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let MarketplacesScreenModel::AddForm(AddFormModel::Name {
+        source,
+        default_name,
+        ..
+    }) = &model
+    {
+        assert_eq!(source, &dir.canonicalize().unwrap().display().to_string());
+        assert_eq!(default_name, "local-mp");
+    } else {
+        panic!("Expected AddForm Name");
+    }
+}
+
+#[test]
+fn enter_duplicate_source_warns_then_continues_on_second_enter() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    // Move to Add new
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for c in "Owner/mp-a.git".chars() {
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let MarketplacesScreenModel::AddForm(AddFormModel::Source { duplicate_of, .. }) = &model {
+        assert_eq!(duplicate_of.as_deref(), Some("mp-a"));
+    } else {
+        panic!("Expected AddForm Source with duplicate warning");
+    }
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    assert!(
+        matches!(
+            model,
+            MarketplacesScreenModel::AddForm(AddFormModel::Name { .. })
+        ),
+        "Second Enter should continue to Name step"
+    );
+}
+
+#[test]
+fn form_input_clears_duplicate_warning() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for c in "owner/mp-a".chars() {
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::FormBackspace, &mut data, "");
+    update(&mut model, &mut history, Msg::FormInput('b'), &mut data, "");
+
+    if let MarketplacesScreenModel::AddForm(AddFormModel::Source { duplicate_of, .. }) = &model {
+        assert!(duplicate_of.is_none());
+    } else {
+        panic!("Expected AddForm Source");
+    }
+
+    // 別ソース（owner/mp-b）になったので警告なしで進む
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    assert!(matches!(
+        model,
+        MarketplacesScreenModel::AddForm(AddFormModel::Name { .. })
+    ));
+}
+
 #[test]
 fn enter_name_step_with_empty_input_uses_default() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Source step
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     for c in "owner/my-repo".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Name step - enter with empty input
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Confirm { source, name, .. }) = &model {
         assert_eq!(source, "owner/my-repo");
@@ -1741,19 +2163,20 @@ fn enter_name_step_with_empty_input_uses_default() {
 fn enter_name_step_with_custom_name() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Source step
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
     for c in "owner/repo".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Name step - type custom name
     for c in "custom-name".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Confirm { name, .. }) = &model {
         assert_eq!(name, "custom-name");
@@ -1762,26 +2185,77 @@ fn enter_name_step_with_custom_name() {
     }
 }
 
+#[test]
+fn enter_source_step_parses_ref_and_subdir() {
+    let (_temp_dir, mut data) = make_data(&[]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for c in "owner/mono/plugins/#v2".chars() {
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
+        source,
+        git_ref,
+        subdir,
+        name,
+        ..
+    }) = &model
+    {
+        assert_eq!(source, "owner/mono");
+        assert_eq!(git_ref.as_deref(), Some("v2"));
+        assert_eq!(subdir.as_deref(), Some("plugins"));
+        assert_eq!(name, "mono");
+    } else {
+        panic!("Expected AddForm Confirm");
+    }
+}
+
+#[test]
+fn enter_source_step_invalid_source_mentions_ref_syntax() {
+    let (_temp_dir, mut data) = make_data(&[]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+    for c in "owner/repo#".chars() {
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
+    }
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let MarketplacesScreenModel::AddForm(AddFormModel::Source { error_message, .. }) = &model {
+        let error = error_message.as_deref().expect("expected validation error");
+        assert!(error.contains("#branch|@tag"), "got: {}", error);
+    } else {
+        panic!("Expected AddForm Source with error");
+    }
+}
+
 #[test]
 fn enter_name_step_duplicate_shows_error() {
     let (_temp_dir, mut data) = make_data(&["existing"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Navigate to Add new
-    update(&mut model, Msg::Down, &mut data);
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Source step
     for c in "owner/repo".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Name step - type duplicate name
     for c in "existing".chars() {
-        update(&mut model, Msg::FormInput(c), &mut data);
+        update(&mut model, &mut history, Msg::FormInput(c), &mut data, "");
     }
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     if let MarketplacesScreenModel::AddForm(AddFormModel::Name { error_message, .. }) = &model {
         assert!(
@@ -1806,11 +2280,14 @@ fn enter_on_confirm_transitions_to_market_list_with_adding_status() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
         source: "owner/repo".to_string(),
+        git_ref: None,
+        subdir: None,
         name: "my-repo".to_string(),
         error_message: None,
     });
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::Enter, &mut data);
+    let effect = update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     assert!(
         matches!(effect.phase2_msg, Some(Msg::ExecuteAdd)),
@@ -1822,6 +2299,7 @@ fn enter_on_confirm_transitions_to_market_list_with_adding_status() {
         operation_status,
         error_message,
         pending_add_source,
+        ..
     } = &model
     {
         assert!(
@@ -1836,11 +2314,37 @@ fn enter_on_confirm_transitions_to_market_list_with_adding_status() {
     }
 }
 
+#[test]
+fn enter_on_confirm_keeps_ref_and_subdir_in_pending_source() {
+    let (_temp_dir, mut data) = make_data(&[]);
+    let mut model = MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
+        source: "owner/mono".to_string(),
+        git_ref: Some("v2".to_string()),
+        subdir: Some("plugins".to_string()),
+        name: "mono".to_string(),
+        error_message: None,
+    });
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
+
+    if let MarketplacesScreenModel::MarketList {
+        pending_add_source, ..
+    } = &model
+    {
+        assert_eq!(pending_add_source.as_deref(), Some("owner/mono/plugins#v2"));
+    } else {
+        panic!("expected MarketList after AddForm Confirm Enter");
+    }
+}
+
 #[test]
 fn execute_add_phase1_uses_existing_index_when_name_already_present() {
     let (_temp_dir, data) = make_data(&["mp-a", "mp-b"]);
     let mut model = MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
         source: "owner/mp-a".to_string(),
+        git_ref: None,
+        subdir: None,
         name: "mp-a".to_string(),
         error_message: None,
     });
@@ -1877,6 +2381,8 @@ fn execute_add_phase2_success_transitions_to_plugin_browse() {
         operation_status: Some(OperationStatus::Adding("my-repo".to_string())),
         error_message: None,
         pending_add_source: Some("owner/repo".to_string()),
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
     execute_add_with(
@@ -1923,6 +2429,8 @@ fn execute_add_phase2_success_with_empty_plugins_still_transitions_to_plugin_bro
         operation_status: Some(OperationStatus::Adding("my-repo".to_string())),
         error_message: None,
         pending_add_source: Some("owner/repo".to_string()),
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
     execute_add_with(
@@ -1951,6 +2459,8 @@ fn execute_add_phase2_failure_clears_status_and_sets_error_message() {
         operation_status: Some(OperationStatus::Adding("my-repo".to_string())),
         error_message: None,
         pending_add_source: Some("owner/repo".to_string()),
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
     execute_add_with(
@@ -1990,6 +2500,8 @@ fn execute_add_phase2_noop_when_not_in_adding_state() {
         operation_status: None,
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
     let call_count = std::cell::Cell::new(0);
@@ -2046,12 +2558,14 @@ fn execute_update_success_clears_status() {
         operation_status: Some(OperationStatus::Updating("mp-a".to_string())),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
-        |_name| Ok(make_marketplace("mp-a")),
+        |_name| Ok(make_update_outcome("mp-a")),
         || vec![],
         |_d| {},
     );
@@ -2071,6 +2585,126 @@ fn execute_update_success_clears_status() {
     }
 }
 
+#[test]
+fn execute_update_success_shows_plugin_diff() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-a"), Some(0)),
+        operation_status: Some(OperationStatus::Updating("mp-a".to_string())),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
+    };
+
+    update_to_end(
+        &mut model,
+        &mut data,
+        |name| {
+            Ok(MarketplaceUpdateOutcome {
+                diff: UpdateDiff {
+                    added: vec!["a".into(), "b".into(), "c".into()],
+                    removed: vec!["old".into()],
+                    changed: vec![
+                        ("x".into(), "1.0.0".into(), "1.1.0".into()),
+                        ("y".into(), "-".into(), "0.2.0".into()),
+                    ],
+                },
+                ..make_update_outcome(name)
+            })
+        },
+        || vec![],
+        |_d| {},
+    );
+
+    let MarketplacesScreenModel::MarketList { notice, .. } = &model else {
+        panic!("Expected MarketList");
+    };
+    assert_eq!(
+        notice.as_deref(),
+        Some("Updated 'mp-a': +3 new, 1 removed, 2 updated")
+    );
+}
+
+#[test]
+fn execute_update_success_mentions_manifest_warnings() {
+    let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-a"), Some(0)),
+        operation_status: Some(OperationStatus::Updating("mp-a".to_string())),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
+    };
+
+    update_to_end(
+        &mut model,
+        &mut data,
+        |name| Ok(make_update_outcome(name)),
+        || vec![],
+        |d| {
+            d.warnings = vec![ManifestWarning {
+                marketplace: "mp-a".to_string(),
+                issue: ValidationIssue::warning(
+                    "plugins[1].source",
+                    "source_path must be relative",
+                ),
+            }];
+        },
+    );
+
+    let MarketplacesScreenModel::MarketList { notice, .. } = &model else {
+        panic!("Expected MarketList");
+    };
+    assert_eq!(
+        notice.as_deref(),
+        Some("Updated 'mp-a': no changes (1 warning(s), see Errors tab)")
+    );
+}
+
+#[test]
+fn execute_update_all_sums_plugin_diffs() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-a"), Some(0)),
+        operation_status: Some(OperationStatus::UpdatingAll),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: Some("Updated 'mp-a': no changes".to_string()),
+    };
+    let added = |name: &str| MarketplaceUpdateOutcome {
+        diff: UpdateDiff {
+            added: vec![format!("{}-plugin", name)],
+            ..UpdateDiff::default()
+        },
+        ..make_update_outcome(name)
+    };
+
+    update_to_end(
+        &mut model,
+        &mut data,
+        |_name| Ok(make_update_outcome("unused")),
+        move || {
+            vec![
+                ("mp-a".to_string(), Ok(added("mp-a"))),
+                ("mp-b".to_string(), Ok(added("mp-b"))),
+                ("mp-c".to_string(), Err("fetch failed".to_string())),
+            ]
+        },
+        |_d| {},
+    );
+
+    let MarketplacesScreenModel::MarketList { notice, .. } = &model else {
+        panic!("Expected MarketList");
+    };
+    assert_eq!(
+        notice.as_deref(),
+        Some("Updated 2 marketplace(s): +2 new, 0 removed, 0 updated")
+    );
+}
+
 #[test]
 fn execute_update_failure_sets_error_message() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
@@ -2081,9 +2715,11 @@ fn execute_update_failure_sets_error_message() {
         operation_status: Some(OperationStatus::Updating("mp-a".to_string())),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
         |_name| Err("network error".to_string()),
@@ -2118,16 +2754,18 @@ fn execute_update_all_success() {
         operation_status: Some(OperationStatus::UpdatingAll),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
-        |_name| Ok(make_marketplace("unused")),
+        |_name| Ok(make_update_outcome("unused")),
         || {
             vec![
-                ("mp-a".to_string(), Ok(make_marketplace("mp-a"))),
-                ("mp-b".to_string(), Ok(make_marketplace("mp-b"))),
+                ("mp-a".to_string(), Ok(make_update_outcome("mp-a"))),
+                ("mp-b".to_string(), Ok(make_update_outcome("mp-b"))),
             ]
         },
         |_d| {},
@@ -2156,15 +2794,17 @@ fn execute_update_all_partial_failure_sets_error() {
         operation_status: Some(OperationStatus::UpdatingAll),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
-        |_name| Ok(make_marketplace("unused")),
+        |_name| Ok(make_update_outcome("unused")),
         || {
             vec![
-                ("mp-a".to_string(), Ok(make_marketplace("mp-a"))),
+                ("mp-a".to_string(), Ok(make_update_outcome("mp-a"))),
                 ("mp-b".to_string(), Err("fetch failed".to_string())),
             ]
         },
@@ -2203,9 +2843,11 @@ fn execute_remove_success_reloads_and_clamps() {
         operation_status: Some(OperationStatus::Removing("mp-a".to_string())),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_remove_with(
+    remove_to_end(
         &mut model,
         &mut data,
         |_name| Ok(()),
@@ -2238,9 +2880,11 @@ fn execute_remove_failure_sets_error() {
         operation_status: Some(OperationStatus::Removing("mp-a".to_string())),
         error_message: None,
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_remove_with(
+    remove_to_end(
         &mut model,
         &mut data,
         |_name| Err("permission denied".to_string()),
@@ -2275,8 +2919,9 @@ fn execute_remove_failure_sets_error() {
 fn update_market_sets_updating_and_returns_execute_batch() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateMarket, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateMarket, &mut data, "");
 
     assert!(effect.phase2_msg.is_some());
 
@@ -2296,10 +2941,11 @@ fn update_market_sets_updating_and_returns_execute_batch() {
 fn update_market_ignored_on_add_new() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     // Move to Add new
-    update(&mut model, Msg::Down, &mut data);
-    let effect = update(&mut model, Msg::UpdateMarket, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::UpdateMarket, &mut data, "");
 
     assert!(effect.phase2_msg.is_none(), "Should not trigger on Add new");
 }
@@ -2308,6 +2954,7 @@ fn update_market_ignored_on_add_new() {
 fn update_market_ignored_when_operation_in_progress() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     if let MarketplacesScreenModel::MarketList {
         operation_status, ..
@@ -2316,7 +2963,7 @@ fn update_market_ignored_when_operation_in_progress() {
         *operation_status = Some(OperationStatus::Updating("mp-a".to_string()));
     }
 
-    let effect = update(&mut model, Msg::UpdateMarket, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateMarket, &mut data, "");
 
     assert!(effect.phase2_msg.is_none(), "Should not double-trigger");
 }
@@ -2325,8 +2972,9 @@ fn update_market_ignored_when_operation_in_progress() {
 fn update_all_sets_updating_all_and_returns_execute_batch() {
     let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(effect.phase2_msg.is_some());
 
@@ -2347,8 +2995,9 @@ fn update_all_sets_updating_all_and_returns_execute_batch() {
 fn update_all_on_empty_list_does_nothing() {
     let (_temp_dir, mut data) = make_data(&[]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(effect.phase2_msg.is_none());
 }
@@ -2357,6 +3006,7 @@ fn update_all_on_empty_list_does_nothing() {
 fn update_all_ignored_when_operation_in_progress() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
     let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
 
     if let MarketplacesScreenModel::MarketList {
         operation_status, ..
@@ -2365,7 +3015,7 @@ fn update_all_ignored_when_operation_in_progress() {
         *operation_status = Some(OperationStatus::Updating("mp-a".to_string()));
     }
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(effect.phase2_msg.is_none(), "Should not double-trigger");
 }
@@ -2384,9 +3034,12 @@ fn error_message_cleared_on_up() {
         operation_status: None,
         error_message: Some("some error".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Up, &mut data);
+    update(&mut model, &mut history, Msg::Up, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
         assert!(error_message.is_none(), "Error should be cleared on Up");
@@ -2401,9 +3054,12 @@ fn error_message_cleared_on_down() {
         operation_status: None,
         error_message: Some("some error".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Down, &mut data);
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
         assert!(error_message.is_none(), "Error should be cleared on Down");
@@ -2418,9 +3074,12 @@ fn error_message_cleared_on_enter() {
         operation_status: None,
         error_message: Some("some error".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
+    let mut history = NavigationHistory::default();
 
-    update(&mut model, Msg::Enter, &mut data);
+    update(&mut model, &mut history, Msg::Enter, &mut data, "");
 
     // Should transition to MarketDetail (error was cleared)
     assert!(matches!(
@@ -2435,6 +3094,7 @@ fn error_message_cleared_on_enter() {
 #[test]
 fn error_message_cleared_on_back() {
     let (_temp_dir, mut data) = make_data(&["mp-a"]);
+    let mut history = NavigationHistory::default();
     let mut model = MarketplacesScreenModel::MarketDetail {
         marketplace_name: "mp-a".to_string(),
         state: {
@@ -2447,7 +3107,7 @@ fn error_message_cleared_on_back() {
         browse_selected: None,
     };
 
-    update(&mut model, Msg::Back, &mut data);
+    update(&mut model, &mut history, Msg::Back, &mut data, "");
 
     if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
         assert!(
@@ -2465,9 +3125,12 @@ fn stale_error_cleared_on_update_market_start() {
         operation_status: None,
         error_message: Some("previous error".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateMarket, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateMarket, &mut data, "");
 
     assert!(effect.phase2_msg.is_some());
     if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
@@ -2486,9 +3149,12 @@ fn stale_error_cleared_on_update_all_start() {
         operation_status: None,
         error_message: Some("previous error".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
+    let mut history = NavigationHistory::default();
 
-    let effect = update(&mut model, Msg::UpdateAll, &mut data);
+    let effect = update(&mut model, &mut history, Msg::UpdateAll, &mut data, "");
 
     assert!(effect.phase2_msg.is_some());
     if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
@@ -2509,12 +3175,14 @@ fn stale_error_cleared_on_successful_update_retry() {
         operation_status: Some(OperationStatus::Updating("mp-a".to_string())),
         error_message: Some("previous failure".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
-        |_name| Ok(make_marketplace("mp-a")),
+        |_name| Ok(make_update_outcome("mp-a")),
         || vec![],
         |_d| {},
     );
@@ -2539,9 +3207,11 @@ fn stale_error_cleared_on_successful_remove_retry() {
         operation_status: Some(OperationStatus::Removing("mp-a".to_string())),
         error_message: Some("previous failure".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_remove_with(
+    remove_to_end(
         &mut model,
         &mut data,
         |_name| Ok(()),
@@ -2570,16 +3240,18 @@ fn stale_error_cleared_on_successful_update_all_retry() {
         operation_status: Some(OperationStatus::UpdatingAll),
         error_message: Some("previous failure".to_string()),
         pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
-    execute_update_with(
+    update_to_end(
         &mut model,
         &mut data,
-        |_name| Ok(make_marketplace("unused")),
+        |_name| Ok(make_update_outcome("unused")),
         || {
             vec![
-                ("mp-a".to_string(), Ok(make_marketplace("mp-a"))),
-                ("mp-b".to_string(), Ok(make_marketplace("mp-b"))),
+                ("mp-a".to_string(), Ok(make_update_outcome("mp-a"))),
+                ("mp-b".to_string(), Ok(make_update_outcome("mp-b"))),
             ]
         },
         |_d| {},
@@ -2595,6 +3267,327 @@ fn stale_error_cleared_on_successful_update_all_retry() {
     }
 }
 
+// ============================================================================
+// マークと一括更新/一括削除 (ExecuteBatch, 依存関数注入パターン)
+// ============================================================================
+
+fn marked_ids(model: &MarketplacesScreenModel) -> Vec<String> {
+    match model {
+        MarketplacesScreenModel::MarketList { marked_ids, .. } => {
+            let mut ids: Vec<String> = marked_ids.iter().cloned().collect();
+            ids.sort();
+            ids
+        }
+        _ => panic!("Expected MarketList"),
+    }
+}
+
+/// phase2 の ExecuteBatch が返らなくなるまで 1 件ずつ開始・完了させ、実行回数を返す
+fn run_batch_to_end(
+    model: &mut MarketplacesScreenModel,
+    data: &mut DataStore,
+    failing: &[&str],
+    calls: &Arc<Mutex<Vec<String>>>,
+) -> usize {
+    let failing: Vec<String> = failing.iter().map(|s| s.to_string()).collect();
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        let (update_calls, update_failing) = (Arc::clone(calls), failing.clone());
+        let (remove_calls, remove_failing) = (Arc::clone(calls), failing.clone());
+        let effect = execute_batch_with(
+            model,
+            move |name| {
+                update_calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("update {}", name));
+                if update_failing.iter().any(|f| f == name) {
+                    Err("network error".to_string())
+                } else {
+                    Ok(make_update_outcome(name))
+                }
+            },
+            move |name| {
+                remove_calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("remove {}", name));
+                if remove_failing.iter().any(|f| f == name) {
+                    Err("permission denied".to_string())
+                } else {
+                    Ok(())
+                }
+            },
+        );
+        let Msg::BatchStepFinished(name, result) = run_task(effect) else {
+            panic!("Expected BatchStepFinished");
+        };
+        let effect = finish_batch_step_with(model, data, name, result, |d| {
+            let removed: Vec<String> = calls
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|c| c.strip_prefix("remove "))
+                .filter(|name| !failing.iter().any(|f| f == name))
+                .map(str::to_string)
+                .collect();
+            d.marketplaces.retain(|m| !removed.contains(&m.name));
+        });
+        if !matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)) {
+            return steps;
+        }
+    }
+}
+
+#[test]
+fn toggle_mark_marks_and_unmarks_selected_marketplace() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    assert_eq!(marked_ids(&model), vec!["mp-a", "mp-b"]);
+
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    assert_eq!(marked_ids(&model), vec!["mp-a"]);
+
+    // "+ Add new" はマークできない
+    update(&mut model, &mut history, Msg::Down, &mut data, "");
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    assert_eq!(marked_ids(&model), vec!["mp-a"]);
+}
+
+#[test]
+fn update_with_marks_starts_batch_update_in_list_order() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b", "mp-c"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-a"), Some(0)),
+        operation_status: None,
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::from(["mp-c".to_string(), "mp-a".to_string()]),
+        notice: None,
+    };
+    let mut history = NavigationHistory::default();
+
+    let effect = update(&mut model, &mut history, Msg::UpdateMarket, &mut data, "");
+
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)));
+    match &model {
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::BatchUpdating(progress)),
+            ..
+        } => {
+            assert_eq!(progress.names, vec!["mp-a", "mp-c"]);
+            assert_eq!((progress.done, progress.total()), (0, 2));
+        }
+        _ => panic!("Expected BatchUpdating"),
+    }
+}
+
+#[test]
+fn execute_batch_update_success_clears_marks() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b", "mp-c"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-b"), Some(1)),
+        operation_status: Some(OperationStatus::BatchUpdating(BatchProgress::new(vec![
+            "mp-a".to_string(),
+            "mp-c".to_string(),
+        ]))),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::from(["mp-a".to_string(), "mp-c".to_string()]),
+        notice: None,
+    };
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let steps = run_batch_to_end(&mut model, &mut data, &[], &calls);
+
+    assert_eq!(steps, 2, "one marketplace per phase2 step");
+    assert_eq!(*calls.lock().unwrap(), vec!["update mp-a", "update mp-c"]);
+    assert!(marked_ids(&model).is_empty());
+    if let MarketplacesScreenModel::MarketList {
+        operation_status,
+        error_message,
+        ..
+    } = &model
+    {
+        assert!(operation_status.is_none());
+        assert!(error_message.is_none());
+    }
+}
+
+#[test]
+fn execute_batch_update_partial_failure_keeps_failed_marked() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-a"), Some(0)),
+        operation_status: Some(OperationStatus::BatchUpdating(BatchProgress::new(vec![
+            "mp-a".to_string(),
+            "mp-b".to_string(),
+        ]))),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::from(["mp-a".to_string(), "mp-b".to_string()]),
+        notice: None,
+    };
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    run_batch_to_end(&mut model, &mut data, &["mp-b"], &calls);
+
+    assert_eq!(*calls.lock().unwrap(), vec!["update mp-a", "update mp-b"]);
+    assert_eq!(marked_ids(&model), vec!["mp-b"]);
+    if let MarketplacesScreenModel::MarketList { error_message, .. } = &model {
+        assert_eq!(
+            error_message.as_deref(),
+            Some("Failed to update: mp-b: network error")
+        );
+    }
+}
+
+#[test]
+fn remove_preview_lists_installed_plugins_that_are_kept() {
+    let (_temp_dir, mut data) = DataStore::for_test(
+        vec![
+            InstalledPlugin::new_for_test(
+                "lint",
+                "1.0.0",
+                Vec::new(),
+                None,
+                Some("mp-a".to_string()),
+                true,
+            ),
+            InstalledPlugin::new_for_test(
+                "other",
+                "1.0.0",
+                Vec::new(),
+                None,
+                Some("mp-b".to_string()),
+                true,
+            ),
+        ],
+        vec![make_marketplace("mp-a"), make_marketplace("mp-b")],
+        None,
+    );
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    update(&mut model, &mut history, Msg::RemoveMarked, &mut data, "");
+
+    update(
+        &mut model,
+        &mut history,
+        Msg::CalculateRemovePreview,
+        &mut data,
+        "",
+    );
+
+    let MarketplacesScreenModel::MarketList {
+        operation_status:
+            Some(OperationStatus::ConfirmRemove {
+                preview: PreviewState::Ready(preview),
+                ..
+            }),
+        ..
+    } = &model
+    else {
+        panic!("Expected a calculated remove preview");
+    };
+    assert_eq!(preview.removed_file_count(), 0);
+    assert_eq!(
+        preview.notes,
+        vec!["1 installed plugin(s) stay installed but can no longer be updated: lint@mp-a"]
+    );
+}
+
+#[test]
+fn remove_marked_requires_confirmation_and_cancel_keeps_marks() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b"]);
+    let mut model = MarketplacesScreenModel::new(&data);
+    let mut history = NavigationHistory::default();
+
+    // マークなしでは何もしない
+    update(&mut model, &mut history, Msg::RemoveMarked, &mut data, "");
+    assert!(model.is_top_level());
+
+    update(&mut model, &mut history, Msg::ToggleMark, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::RemoveMarked, &mut data, "");
+    assert!(
+        matches!(effect.phase2_msg, Some(Msg::CalculateRemovePreview)),
+        "must not remove before confirm"
+    );
+    assert!(matches!(
+        &model,
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::ConfirmRemove {
+                names,
+                preview: PreviewState::Calculating,
+            }),
+            ..
+        } if names == &vec!["mp-a".to_string()]
+    ));
+
+    update(&mut model, &mut history, Msg::CancelRemove, &mut data, "");
+    assert!(model.is_top_level());
+    assert_eq!(marked_ids(&model), vec!["mp-a"]);
+
+    update(&mut model, &mut history, Msg::RemoveMarked, &mut data, "");
+    let effect = update(&mut model, &mut history, Msg::ConfirmRemove, &mut data, "");
+    assert!(matches!(effect.phase2_msg, Some(Msg::ExecuteBatch)));
+    assert!(matches!(
+        &model,
+        MarketplacesScreenModel::MarketList {
+            operation_status: Some(OperationStatus::BatchRemoving(_)),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn execute_batch_remove_partial_failure_clamps_selection() {
+    let (_temp_dir, mut data) = make_data(&["mp-a", "mp-b", "mp-c"]);
+    let mut model = MarketplacesScreenModel::MarketList {
+        selection: market_selection(Some("mp-c"), Some(2)),
+        operation_status: Some(OperationStatus::BatchRemoving(BatchProgress::new(vec![
+            "mp-b".to_string(),
+            "mp-c".to_string(),
+        ]))),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::from(["mp-b".to_string(), "mp-c".to_string()]),
+        notice: None,
+    };
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    run_batch_to_end(&mut model, &mut data, &["mp-b"], &calls);
+    clamp_selection(&mut model, &data, "");
+
+    let names: Vec<&str> = data.marketplaces.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["mp-a", "mp-b"]);
+    assert_eq!(marked_ids(&model), vec!["mp-b"]);
+    if let MarketplacesScreenModel::MarketList {
+        selection,
+        error_message,
+        ..
+    } = &model
+    {
+        assert_eq!(
+            error_message.as_deref(),
+            Some("Failed to remove: mp-b: permission denied")
+        );
+        assert_eq!(
+            data.last_error(),
+            Some("Failed to remove: mp-b: permission denied")
+        );
+        // 削除された mp-c の位置から一覧内に収まる
+        assert!(selection.selected_index().unwrap() < 2);
+        assert!(selection.selected_id().is_some());
+    }
+}
+
 // ============================================================================
 // Helper
 // ============================================================================
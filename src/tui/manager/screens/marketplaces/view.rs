@@ -1,38 +1,55 @@
 //! Marketplaces タブの view（描画）
 
 use super::model::{
-    AddFormModel, BrowsePlugin, DetailAction, InstallSummary, MarketplacesScreenModel,
-    OperationStatus, PluginInstallOutcome,
+    shows_add_new, AddFormModel, BrowsePlugin, DetailAction, InstallSummary,
+    MarketplacesScreenModel, OperationStatus, PluginInstallOutcome,
 };
 use crate::component::Scope;
-use crate::marketplace::PluginSource;
+use crate::marketplace::{duplicate_source_warning, PluginSource};
 use crate::tui::manager::core::layout::{
-    framed_layout, modal_layout, outer_rect, split_horizontal,
+    align_columns, column_widths, framed_layout, modal_layout, outer_rect, split_horizontal,
 };
+use crate::tui::manager::core::preview::preview_lines;
+use crate::tui::manager::core::spinner;
 use crate::tui::manager::core::style::{
     bordered_block, highlight_line, menu_list, selectable_list, CHECKBOX_SELECTED,
     CHECKBOX_UNSELECTED, LIST_ITEM_INDENT, MARK_MARKED, RADIO_SELECTED, RADIO_UNSELECTED,
 };
 use crate::tui::manager::core::{
-    render_filter_bar, truncate_for_list, truncate_for_paragraph, DataStore, MarketplaceItem, Tab,
+    filter_marketplaces, render_empty_state, render_filter_bar, render_tab_bar, truncate_for_list,
+    truncate_for_paragraph, DataStore, MarketplaceItem, Tab, TabBadges, Theme,
 };
+use chrono::{DateTime, Local, Utc};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Clear, Gauge, ListItem, ListState, Paragraph, Tabs};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, ListItem, ListState, Paragraph, Wrap};
 use std::collections::HashSet;
 
-/// 描画用共通コンテキスト（DataStore + フィルタ情報）
+/// キャッシュ取得日時の表示形式（ローカルタイム）
+const FETCHED_AT_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// 描画用共通コンテキスト（DataStore + タブバーの件数 + フィルタ情報）
 struct ViewCtx<'a> {
     data: &'a DataStore,
+    badges: &'a TabBadges,
     filter_text: &'a str,
     filter_focused: bool,
 }
 
-/// フィルタ情報のみのコンテキスト
+/// タブバーの件数とフィルタ情報のみのコンテキスト
 struct FilterCtx<'a> {
+    badges: &'a TabBadges,
     text: &'a str,
     focused: bool,
 }
 
+/// マーケットプレイス一覧の操作状態（ステータス行・マーク表示用）
+struct MarketListStatus<'a> {
+    operation_status: &'a Option<OperationStatus>,
+    error_message: &'a Option<String>,
+    marked_ids: &'a HashSet<String>,
+    notice: &'a Option<String>,
+}
+
 /// ブラウズ画面のデータ
 struct BrowseData<'a> {
     plugins: &'a [BrowsePlugin],
@@ -47,21 +64,25 @@ struct BrowseData<'a> {
 /// * `f` - Ratatui frame to draw into.
 /// * `model` - Marketplaces tab model to render.
 /// * `data` - Shared data store for marketplaces.
+/// * `badges` - Counts shown in the tab bar.
 /// * `filter_text` - Current filter input text.
 /// * `filter_focused` - Whether the filter bar currently has focus.
 pub fn view(
     f: &mut Frame,
     model: &MarketplacesScreenModel,
     data: &DataStore,
+    badges: &TabBadges,
     filter_text: &str,
     filter_focused: bool,
 ) {
     let ctx = ViewCtx {
         data,
+        badges,
         filter_text,
         filter_focused,
     };
     let filter = FilterCtx {
+        badges,
         text: filter_text,
         focused: filter_focused,
     };
@@ -70,15 +91,17 @@ pub fn view(
             selection,
             operation_status,
             error_message,
+            marked_ids,
+            notice,
             ..
         } => {
-            view_market_list(
-                f,
-                *selection.list_state(),
-                &ctx,
+            let status = MarketListStatus {
                 operation_status,
                 error_message,
-            );
+                marked_ids,
+                notice,
+            };
+            view_market_list(f, *selection.list_state(), &ctx, &status);
         }
         MarketplacesScreenModel::MarketDetail {
             marketplace_name,
@@ -97,7 +120,7 @@ pub fn view(
             view_plugin_list(f, marketplace_name, *state, plugins, &filter);
         }
         MarketplacesScreenModel::AddForm(form) => {
-            view_add_form(f, form, filter_text, filter_focused);
+            view_add_form(f, form, &filter);
         }
         MarketplacesScreenModel::PluginBrowse {
             marketplace_name,
@@ -142,26 +165,6 @@ pub fn view(
     }
 }
 
-/// タブバーを描画
-///
-/// # Arguments
-///
-/// * `f` - Ratatui frame to draw into.
-/// * `area` - Target rectangle for the tab bar.
-fn render_tab_bar(f: &mut Frame, area: Rect) {
-    let tab_titles: Vec<&str> = Tab::all().iter().map(|t| t.title()).collect();
-    let tabs = Tabs::new(tab_titles)
-        .select(Tab::Marketplaces.index())
-        .style(Style::default().fg(Color::DarkGray))
-        .highlight_style(
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .divider(" | ");
-    f.render_widget(tabs, area);
-}
-
 /// マーケットプレイス一覧画面を描画
 ///
 /// # Arguments
@@ -169,18 +172,31 @@ fn render_tab_bar(f: &mut Frame, area: Rect) {
 /// * `f` - Ratatui frame to draw into.
 /// * `state` - List state used for highlight/selection.
 /// * `ctx` - Shared view context (data store + filter state).
-/// * `operation_status` - In-flight operation status shown below the list.
-/// * `error_message` - Latest error message shown below the list.
+/// * `status` - Operation status, error, notice and marks shown with the list.
 fn view_market_list(
     f: &mut Frame,
     mut state: ListState,
     ctx: &ViewCtx<'_>,
-    operation_status: &Option<OperationStatus>,
-    error_message: &Option<String>,
+    status: &MarketListStatus<'_>,
 ) {
-    let has_status = operation_status.is_some();
+    let theme = Theme::current();
+    let MarketListStatus {
+        operation_status,
+        error_message,
+        marked_ids,
+        notice,
+    } = *status;
+    // 操作中はその表示を優先し、直近の更新結果は隠す
+    let notice = notice.as_ref().filter(|_| operation_status.is_none());
+    let has_status = operation_status.is_some() || notice.is_some();
     let has_error = error_message.is_some();
-    let extra_lines = if has_status { 1 } else { 0 } + if has_error { 1 } else { 0 };
+    // 削除確認中は影響プレビューの箇条書きの分だけステータス領域を広げる
+    let impact_lines = match operation_status {
+        Some(OperationStatus::ConfirmRemove { preview, .. }) => preview_lines(preview),
+        _ => Vec::new(),
+    };
+    let extra_lines =
+        if has_status { 1 } else { 0 } + if has_error { 1 } else { 0 } + impact_lines.len() as u16;
 
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
@@ -188,7 +204,7 @@ fn view_market_list(
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    render_tab_bar(f, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Marketplaces, Some(ctx.badges));
 
     // フィルタバー
     render_filter_bar(f, filter_area, ctx.filter_text, ctx.filter_focused);
@@ -202,45 +218,73 @@ fn view_market_list(
         ])
         .split(content_area);
 
-    // マーケットプレイスリスト
-    let title = format!(" Marketplaces ({}) ", ctx.data.marketplaces.len());
-    let selected_idx = state.selected();
-    let total_items = ctx.data.marketplaces.len() + 1; // +1 = "+ Add new marketplace"
-    let mut items: Vec<ListItem> = ctx
-        .data
-        .marketplaces
-        .iter()
-        .enumerate()
-        .map(|(i, m)| build_marketplaces_list_item(m, outer.width, Some(i) == selected_idx))
-        .collect();
+    // マーケットプレイスリスト（フィルタ済み）
+    let filtered = filter_marketplaces(&ctx.data.marketplaces, ctx.filter_text);
+    let title = if ctx.filter_text.is_empty() {
+        format!(" Marketplaces ({}) ", ctx.data.marketplaces.len())
+    } else {
+        format!(
+            " Marketplaces ({}/{}) ",
+            filtered.len(),
+            ctx.data.marketplaces.len()
+        )
+    };
+    if is_onboarding(ctx) {
+        render_onboarding(f, content_chunks[0]);
+    } else if shows_add_new(filtered.len(), ctx.filter_text) {
+        let selected_idx = state.selected();
+        let cells: Vec<[String; 4]> = filtered.iter().map(|m| marketplace_row_cells(m)).collect();
+        let widths = column_widths(&cells);
+        let mut items: Vec<ListItem> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut columns = align_columns(row, &widths);
+                // マークがあるときだけ行頭にマーク列を出す
+                if !marked_ids.is_empty() {
+                    columns.insert(0, mark_cell(marked_ids.contains(&filtered[i].name)));
+                }
+                build_marketplaces_list_item(&columns, outer.width, Some(i) == selected_idx)
+            })
+            .collect();
 
-    // "+ Add new marketplace" 項目を末尾に追加
-    let add_idx = total_items - 1;
-    items.push(build_add_marketplace_item(Some(add_idx) == selected_idx));
+        // "+ Add new marketplace" 項目を末尾に追加
+        let add_idx = filtered.len();
+        items.push(build_add_marketplace_item(Some(add_idx) == selected_idx));
 
-    let list = selectable_list(items, &title);
+        let list = selectable_list(items, &title);
 
-    f.render_stateful_widget(list, content_chunks[0], &mut state);
+        f.render_stateful_widget(list, content_chunks[0], &mut state);
+    } else {
+        render_empty_state(
+            f,
+            content_chunks[0],
+            &title,
+            ctx.filter_text,
+            "No marketplaces registered",
+        );
+    }
 
     // ステータス/エラー表示
     if has_status || has_error {
         let mut lines = Vec::new();
         if let Some(status) = operation_status {
-            let status_text = match status {
-                OperationStatus::Updating(name) => format!(" Updating marketplace '{}'...", name),
-                OperationStatus::UpdatingAll => " Updating all marketplaces...".to_string(),
-                OperationStatus::Removing(name) => format!(" Removing marketplace '{}'...", name),
-                OperationStatus::Adding(name) => format!(" Adding marketplace '{}'...", name),
-            };
             lines.push(Line::from(Span::styled(
-                status_text,
-                Style::default().fg(Color::Yellow),
+                operation_status_text(status),
+                Style::default().fg(theme.warning),
+            )));
+            lines.extend(impact_lines);
+        }
+        if let Some(notice) = notice {
+            lines.push(Line::from(Span::styled(
+                format!(" {}", notice),
+                Style::default().fg(theme.success),
             )));
         }
         if let Some(error) = error_message {
             lines.push(Line::from(Span::styled(
                 format!(" {}", error),
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.error),
             )));
         }
         let status_para = Paragraph::new(lines);
@@ -248,13 +292,101 @@ fn view_market_list(
     }
 
     // ヘルプ
-    let help = Paragraph::new(
-        " u: update | U: update all | Tab: switch | ↑↓: move | Enter: select | q: quit",
-    )
-    .style(Style::default().fg(Color::DarkGray));
+    let help_text = if is_onboarding(ctx) {
+        " Enter: add marketplace | Tab: switch | q: quit"
+    } else if !marked_ids.is_empty() {
+        " Space: mark | u: update marked | x: remove marked | U: update all | ↑↓: move | q: quit"
+    } else {
+        " u: update | U: update all | Tab: switch | ↑↓: move | Enter: select | q: quit"
+    };
+    let help = Paragraph::new(help_text).style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
+/// 操作中ステータス行の文言
+///
+/// # Arguments
+///
+/// * `status` - Operation in progress on the marketplace list.
+fn operation_status_text(status: &OperationStatus) -> String {
+    let text = match status {
+        OperationStatus::Updating(name) => format!(" Updating marketplace '{}'...", name),
+        OperationStatus::UpdatingAll => " Updating all marketplaces...".to_string(),
+        OperationStatus::Removing(name) => format!(" Removing marketplace '{}'...", name),
+        OperationStatus::Adding(name) => format!(" Adding marketplace '{}'...", name),
+        OperationStatus::ConfirmRemove { names, .. } => format!(
+            " Remove {} marked marketplace(s)? y: yes | n: cancel",
+            names.len()
+        ),
+        OperationStatus::BatchUpdating(progress) => format!(
+            " Updating marketplaces ({}/{})...",
+            progress.done,
+            progress.total()
+        ),
+        OperationStatus::BatchRemoving(progress) => format!(
+            " Removing marketplaces ({}/{})...",
+            progress.done,
+            progress.total()
+        ),
+    };
+    // バックグラウンドで実行中の操作はスピナーを前置する
+    if status.runs_in_background() {
+        format!(" {}{}", spinner::frame(), text)
+    } else {
+        text
+    }
+}
+
+/// マーク列のセル（`[x] ` / `[ ] `）
+fn mark_cell(marked: bool) -> String {
+    let mark = if marked {
+        CHECKBOX_SELECTED
+    } else {
+        CHECKBOX_UNSELECTED
+    };
+    format!("{} ", mark)
+}
+
+/// マーケットプレイス未登録の初回状態（オンボーディング表示）か
+///
+/// フィルタ入力中は通常の空結果表示を優先する。
+///
+/// # Arguments
+///
+/// * `ctx` - Shared view context (data store + filter state).
+fn is_onboarding(ctx: &ViewCtx<'_>) -> bool {
+    ctx.data.marketplaces.is_empty() && ctx.filter_text.is_empty()
+}
+
+/// 初回のオンボーディング表示（Enter で AddForm へ進む）
+///
+/// 一覧の "+ Add new marketplace" と同じく選択は index 0 のまま保持されるため、
+/// Enter はそのまま AddForm への遷移になる。
+///
+/// # Arguments
+///
+/// * `f` - Ratatui frame to draw into.
+/// * `area` - Area the marketplace list would have occupied.
+fn render_onboarding(f: &mut Frame, area: Rect) {
+    let theme = Theme::current();
+    let lines = vec![
+        Line::raw(""),
+        Line::from(Span::styled(
+            "  No marketplaces configured. Press Enter to add your first marketplace.",
+            Style::default().fg(theme.text),
+        )),
+        Line::raw(""),
+        Line::from(Span::styled(
+            "  A marketplace is a GitHub repository (owner/repo) that lists installable plugins.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    let paragraph = Paragraph::new(lines)
+        .block(bordered_block(" Welcome to plm "))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
 /// マーケットプレイス詳細画面を描画
 ///
 /// # Arguments
@@ -271,6 +403,7 @@ fn view_market_detail(
     ctx: &ViewCtx<'_>,
     error_message: &Option<String>,
 ) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
 
@@ -280,7 +413,7 @@ fn view_market_detail(
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    render_tab_bar(f, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Marketplaces, Some(ctx.badges));
 
     // フィルタバー（read-only）
     render_filter_bar(f, filter_area, ctx.filter_text, ctx.filter_focused);
@@ -307,12 +440,12 @@ fn view_market_detail(
     let info_lines = if let Some(m) = marketplace {
         let mut lines = vec![Line::from(vec![
             Span::raw("  Source: "),
-            Span::styled(&m.source, Style::default().fg(Color::White)),
+            Span::styled(&m.source, Style::default().fg(theme.text)),
         ])];
         if let Some(path) = &m.source_path {
             lines.push(Line::from(vec![
                 Span::raw("  Path: "),
-                Span::styled(path, Style::default().fg(Color::White)),
+                Span::styled(path, Style::default().fg(theme.text)),
             ]));
         }
         lines.push(Line::from(vec![
@@ -321,21 +454,24 @@ fn view_market_detail(
                 m.plugin_count
                     .map(|c| c.to_string())
                     .unwrap_or_else(|| "N/A".to_string()),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.text),
             ),
         ]));
         lines.push(Line::from(vec![
             Span::raw("  Last updated: "),
             Span::styled(
-                m.last_updated.as_deref().unwrap_or("never"),
-                Style::default().fg(Color::White),
+                m.fetched_at
+                    .as_ref()
+                    .map(format_fetched_at)
+                    .unwrap_or_else(|| "never".to_string()),
+                Style::default().fg(theme.text),
             ),
         ]));
         lines
     } else {
         vec![Line::from(Span::styled(
             "  Marketplace not found",
-            Style::default().fg(Color::Red),
+            Style::default().fg(theme.error),
         ))]
     };
 
@@ -361,13 +497,13 @@ fn view_market_detail(
     // エラー表示
     if let Some(error) = error_message {
         let error_para =
-            Paragraph::new(format!(" {}", error)).style(Style::default().fg(Color::Red));
+            Paragraph::new(format!(" {}", error)).style(Style::default().fg(theme.error));
         f.render_widget(error_para, error_area);
     }
 
     // ヘルプ
     let help = Paragraph::new(" ↑↓: move | Enter: select | Esc: back")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
@@ -379,7 +515,7 @@ fn view_market_detail(
 /// * `marketplace_name` - Marketplace owning the plugins.
 /// * `state` - List state used for plugin selection.
 /// * `plugins` - Cached plugin name/description pairs.
-/// * `filter` - Filter input context for the read-only filter bar.
+/// * `filter` - Tab bar counts and filter input context for the read-only filter bar.
 fn view_plugin_list(
     f: &mut Frame,
     marketplace_name: &str,
@@ -387,13 +523,14 @@ fn view_plugin_list(
     plugins: &[(String, Option<String>)],
     filter: &FilterCtx<'_>,
 ) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
 
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    render_tab_bar(f, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Marketplaces, Some(filter.badges));
 
     // フィルタバー（read-only）
     render_filter_bar(f, filter_area, filter.text, filter.focused);
@@ -406,7 +543,7 @@ fn view_plugin_list(
         let msg = "  No cached data. Run update to fetch plugins.";
         let content = Paragraph::new(msg)
             .block(bordered_block(&title))
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.muted));
         f.render_widget(content, content_area);
     } else {
         let selected_idx = state.selected();
@@ -424,7 +561,7 @@ fn view_plugin_list(
     }
 
     // ヘルプ
-    let help = Paragraph::new(" ↑↓: move | Esc: back").style(Style::default().fg(Color::DarkGray));
+    let help = Paragraph::new(" ↑↓: move | Esc: back").style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
@@ -434,25 +571,26 @@ fn view_plugin_list(
 ///
 /// * `f` - Ratatui frame to draw into.
 /// * `form` - Current add-form sub-state.
-/// * `filter_text` - Current filter input text.
-/// * `filter_focused` - Whether the filter bar currently has focus.
-fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_focused: bool) {
+/// * `filter` - Tab bar counts and filter bar state.
+fn view_add_form(f: &mut Frame, form: &AddFormModel, filter: &FilterCtx<'_>) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
 
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    render_tab_bar(f, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Marketplaces, Some(filter.badges));
 
     // フィルタバー（read-only）
-    render_filter_bar(f, filter_area, filter_text, filter_focused);
+    render_filter_bar(f, filter_area, filter.text, filter.focused);
 
     // フォームコンテンツ
     match form {
         AddFormModel::Source {
             source_input,
             error_message,
+            duplicate_of,
         } => {
             let mut lines = vec![
                 Line::raw(""),
@@ -462,15 +600,30 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
                     Span::raw("  Source (owner/repo): "),
                     Span::styled(
                         format!("{}|", source_input),
-                        Style::default().fg(Color::White),
+                        Style::default().fg(theme.text),
                     ),
                 ]),
+                Line::from(vec![Span::styled(
+                    "  (owner/repo[/path/to/dir][#branch|@tag], GitHub URL, or local path)",
+                    Style::default().fg(theme.muted),
+                )]),
             ];
+            if let Some(existing) = duplicate_of {
+                lines.push(Line::raw(""));
+                lines.push(Line::from(Span::styled(
+                    format!("  {}", duplicate_source_warning(existing)),
+                    Style::default().fg(theme.warning),
+                )));
+                lines.push(Line::from(Span::styled(
+                    "  Press Enter again to continue",
+                    Style::default().fg(theme.warning),
+                )));
+            }
             if let Some(error) = error_message {
                 lines.push(Line::raw(""));
                 lines.push(Line::from(Span::styled(
                     format!("  {}", error),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.error),
                 )));
             }
             let content = Paragraph::new(lines).block(bordered_block(" Add Marketplace "));
@@ -481,6 +634,7 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
             name_input,
             default_name,
             error_message,
+            ..
         } => {
             let mut lines = vec![
                 Line::raw(""),
@@ -488,25 +642,22 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
                 Line::raw(""),
                 Line::from(vec![
                     Span::raw("  Source: "),
-                    Span::styled(source, Style::default().fg(Color::White)),
+                    Span::styled(source, Style::default().fg(theme.text)),
                 ]),
                 Line::from(vec![
                     Span::raw("  Name: "),
-                    Span::styled(
-                        format!("{}|", name_input),
-                        Style::default().fg(Color::White),
-                    ),
+                    Span::styled(format!("{}|", name_input), Style::default().fg(theme.text)),
                 ]),
                 Line::from(vec![Span::styled(
                     format!("  (Enter for default: {})", default_name),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
             ];
             if let Some(error) = error_message {
                 lines.push(Line::raw(""));
                 lines.push(Line::from(Span::styled(
                     format!("  {}", error),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.error),
                 )));
             }
             let content = Paragraph::new(lines).block(bordered_block(" Add Marketplace "));
@@ -514,6 +665,8 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
         }
         AddFormModel::Confirm {
             source,
+            git_ref,
+            subdir,
             name,
             error_message,
         } => {
@@ -523,23 +676,37 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
                 Line::raw(""),
                 Line::from(vec![
                     Span::raw("  Source: "),
-                    Span::styled(source, Style::default().fg(Color::White)),
+                    Span::styled(source, Style::default().fg(theme.text)),
                 ]),
+            ];
+            if let Some(git_ref) = git_ref {
+                lines.push(Line::from(vec![
+                    Span::raw("  Ref: "),
+                    Span::styled(git_ref, Style::default().fg(theme.text)),
+                ]));
+            }
+            if let Some(subdir) = subdir {
+                lines.push(Line::from(vec![
+                    Span::raw("  Subdir: "),
+                    Span::styled(subdir, Style::default().fg(theme.text)),
+                ]));
+            }
+            lines.extend([
                 Line::from(vec![
                     Span::raw("  Name: "),
-                    Span::styled(name, Style::default().fg(Color::White)),
+                    Span::styled(name, Style::default().fg(theme.text)),
                 ]),
                 Line::raw(""),
                 Line::from(vec![Span::styled(
                     "  Press Enter to add, Esc to cancel",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )]),
-            ];
+            ]);
             if let Some(error) = error_message {
                 lines.push(Line::raw(""));
                 lines.push(Line::from(Span::styled(
                     format!("  {}", error),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.error),
                 )));
             }
             let content = Paragraph::new(lines).block(bordered_block(" Add Marketplace "));
@@ -549,7 +716,7 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
 
     // ヘルプ
     let help = Paragraph::new(" Type to input | Enter: next | Esc: cancel")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
@@ -561,7 +728,7 @@ fn view_add_form(f: &mut Frame, form: &AddFormModel, filter_text: &str, filter_f
 /// * `marketplace_name` - Marketplace being browsed.
 /// * `browse` - Browse data (plugin list, current selection, highlight).
 /// * `state` - List state used for plugin highlight.
-/// * `filter` - Filter input context for the filter bar.
+/// * `filter` - Tab bar counts and filter input context for the filter bar.
 fn view_plugin_browse(
     f: &mut Frame,
     marketplace_name: &str,
@@ -569,13 +736,14 @@ fn view_plugin_browse(
     mut state: ListState,
     filter: &FilterCtx<'_>,
 ) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     f.render_widget(Clear, f.area());
 
     let [tabs_area, filter_area, content_area, help_area] = framed_layout(outer);
 
     // タブバー
-    render_tab_bar(f, tabs_area);
+    render_tab_bar(f, tabs_area, Tab::Marketplaces, Some(filter.badges));
 
     // フィルタバー
     render_filter_bar(f, filter_area, filter.text, filter.focused);
@@ -584,9 +752,9 @@ fn view_plugin_browse(
     let title = format!(" {} > Browse ({}) ", marketplace_name, browse.plugins.len());
 
     if browse.plugins.is_empty() {
-        let msg = Paragraph::new("  No plugins available.")
+        let msg = Paragraph::new("  No plugins found in this marketplace.")
             .block(bordered_block(&title))
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme.muted));
         f.render_widget(msg, content_area);
     } else if !should_split_layout(content_area.width) {
         // 狭い端末: リストのみ描画
@@ -619,7 +787,7 @@ fn view_plugin_browse(
 
     // ヘルプ
     let help = Paragraph::new(" Space: select | Enter/i: install | Esc: back | q: quit")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, help_area);
 }
 
@@ -637,6 +805,7 @@ fn view_target_select(
     _highlighted_idx: usize,
     mut state: ListState,
 ) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     let modal_area = modal_layout(outer, 100, 100);
     f.render_widget(Clear, f.area());
@@ -656,7 +825,7 @@ fn view_target_select(
     f.render_stateful_widget(list, chunks[0], &mut state);
 
     let help = Paragraph::new(" ↑↓: move  space: toggle  enter: ok  esc: back")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, chunks[1]);
 }
 
@@ -668,6 +837,7 @@ fn view_target_select(
 /// * `highlighted_idx` - Currently highlighted scope index.
 /// * `state` - List state used for scope highlight.
 fn view_scope_select(f: &mut Frame, highlighted_idx: usize, mut state: ListState) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     let modal_area = modal_layout(outer, 100, 100);
     f.render_widget(Clear, f.area());
@@ -687,7 +857,7 @@ fn view_scope_select(f: &mut Frame, highlighted_idx: usize, mut state: ListState
     f.render_stateful_widget(list, chunks[0], &mut state);
 
     let help = Paragraph::new(" ↑↓: move  enter: select  esc: back")
-        .style(Style::default().fg(Color::DarkGray));
+        .style(Style::default().fg(theme.muted));
     f.render_widget(help, chunks[1]);
 }
 
@@ -700,6 +870,7 @@ fn view_scope_select(f: &mut Frame, highlighted_idx: usize, mut state: ListState
 /// * `current_idx` - Index of the plugin currently being processed.
 /// * `total` - Total number of plugins in this install batch.
 fn view_installing(f: &mut Frame, plugin_names: &[String], current_idx: usize, total: usize) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     let modal_area = modal_layout(outer, 100, 100);
     f.render_widget(Clear, f.area());
@@ -742,7 +913,7 @@ fn view_installing(f: &mut Frame, plugin_names: &[String], current_idx: usize, t
 
     let gauge = Gauge::default()
         .block(Block::default().borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT))
-        .gauge_style(Style::default().fg(Color::Green))
+        .gauge_style(Style::default().fg(theme.success))
         .ratio(ratio)
         .label(format!("{}/{}", display_idx, total));
     f.render_widget(gauge, inner_chunks[1]);
@@ -769,13 +940,14 @@ fn installing_inner_layout(modal_area: Rect) -> [Rect; 3] {
 /// 成功・失敗で接頭辞・色が異なるが、組み立て・切り詰め・スタイル付与の経路を
 /// 共通化するため、ここでは色とテキストの導出だけを担う。
 fn format_install_result_line(result: &PluginInstallOutcome) -> (String, Color) {
+    let theme = Theme::current();
     if result.success {
-        (format!("  ✓ {}", result.plugin_name), Color::Green)
+        (format!("  ✓ {}", result.plugin_name), theme.success)
     } else {
         let error_msg = result.error.as_deref().unwrap_or("Unknown error");
         (
             format!("  ✗ {}: {}", result.plugin_name, error_msg),
-            Color::Red,
+            theme.error,
         )
     }
 }
@@ -787,6 +959,7 @@ fn format_install_result_line(result: &PluginInstallOutcome) -> (String, Color)
 /// * `f` - Ratatui frame to draw into.
 /// * `summary` - Aggregated install summary to display.
 fn view_install_result(f: &mut Frame, summary: &InstallSummary) {
+    let theme = Theme::current();
     let outer = outer_rect(f.area());
     let modal_area = modal_layout(outer, 100, 100);
     f.render_widget(Clear, f.area());
@@ -814,6 +987,20 @@ fn view_install_result(f: &mut Frame, summary: &InstallSummary) {
             truncate_for_paragraph(modal_area.width, raw),
             Style::default().fg(color),
         )));
+        for conflict in &result.conflicts {
+            lines.push(Line::from(Span::styled(
+                truncate_for_paragraph(modal_area.width, format!("      ! {}", conflict.message())),
+                Style::default().fg(theme.warning),
+            )));
+        }
+    }
+
+    if !summary.warnings.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            install_warnings_line(summary.warnings.len()),
+            Style::default().fg(theme.warning),
+        )));
     }
 
     lines.push(Line::raw(""));
@@ -821,11 +1008,20 @@ fn view_install_result(f: &mut Frame, summary: &InstallSummary) {
     let content = Paragraph::new(lines).block(bordered_block(" Install Result "));
     f.render_widget(content, chunks[0]);
 
-    let help =
-        Paragraph::new(" enter/esc: back to browse").style(Style::default().fg(Color::DarkGray));
+    let help = Paragraph::new(" enter/esc: back to browse").style(Style::default().fg(theme.muted));
     f.render_widget(help, chunks[1]);
 }
 
+/// インストール中に出た警告の案内（詳細は Errors タブで見る）
+///
+/// # Arguments
+///
+/// * `count` - Number of warnings collected during the install.
+pub(super) fn install_warnings_line(count: usize) -> String {
+    let noun = if count == 1 { "warning" } else { "warnings" };
+    format!("  {} {} (see the Errors tab)", count, noun)
+}
+
 /// 右パネルにプラグイン詳細を描画
 ///
 /// # Arguments
@@ -840,6 +1036,7 @@ fn render_plugin_detail(
     highlighted_idx: usize,
     area: Rect,
 ) {
+    let theme = Theme::current();
     let detail_block = bordered_block(" Detail ");
 
     if let Some(plugin) = plugins.get(highlighted_idx) {
@@ -854,14 +1051,14 @@ fn render_plugin_detail(
             Line::raw(""),
             Line::from(vec![
                 Span::raw("  Name: "),
-                Span::styled(&*plugin.name, Style::default().fg(Color::White)),
+                Span::styled(&*plugin.name, Style::default().fg(theme.text)),
             ]),
             Line::raw(""),
             Line::from(vec![
                 Span::raw("  Description: "),
                 Span::styled(
                     plugin.description.as_deref().unwrap_or("N/A"),
-                    Style::default().fg(Color::White),
+                    Style::default().fg(theme.text),
                 ),
             ]),
             Line::raw(""),
@@ -869,18 +1066,18 @@ fn render_plugin_detail(
                 Span::raw("  Version: "),
                 Span::styled(
                     plugin.version.as_deref().unwrap_or("N/A"),
-                    Style::default().fg(Color::White),
+                    Style::default().fg(theme.text),
                 ),
             ]),
             Line::raw(""),
             Line::from(vec![
                 Span::raw("  Source: "),
-                Span::styled(source_text, Style::default().fg(Color::White)),
+                Span::styled(source_text, Style::default().fg(theme.text)),
             ]),
             Line::raw(""),
             Line::from(vec![
                 Span::raw("  Installed: "),
-                Span::styled(installed_text, Style::default().fg(Color::White)),
+                Span::styled(installed_text, Style::default().fg(theme.text)),
             ]),
         ];
 
@@ -908,8 +1105,9 @@ fn should_split_layout(width: u16) -> bool {
 ///
 /// * `selected` - Whether the target is currently selected.
 fn target_checkbox(selected: bool) -> (&'static str, Style) {
+    let theme = Theme::current();
     if selected {
-        (CHECKBOX_SELECTED, Style::default().fg(Color::Yellow))
+        (CHECKBOX_SELECTED, Style::default().fg(theme.warning))
     } else {
         (CHECKBOX_UNSELECTED, Style::default())
     }
@@ -942,8 +1140,9 @@ fn build_target_list_items(
 ///
 /// * `is_current` - Whether this scope is the currently highlighted one.
 fn scope_radio(is_current: bool) -> (&'static str, Style) {
+    let theme = Theme::current();
     if is_current {
-        (RADIO_SELECTED, Style::default().fg(Color::Yellow))
+        (RADIO_SELECTED, Style::default().fg(theme.warning))
     } else {
         (RADIO_UNSELECTED, Style::default())
     }
@@ -1018,10 +1217,11 @@ fn build_market_action_menu(
 /// * `installed` - Whether the plugin is already installed.
 /// * `selected` - Whether the plugin is currently selected for install.
 fn browse_state_block(installed: bool, selected: bool) -> (&'static str, Style) {
+    let theme = Theme::current();
     if installed {
-        (MARK_MARKED, Style::default().fg(Color::DarkGray))
+        (MARK_MARKED, Style::default().fg(theme.muted))
     } else if selected {
-        (CHECKBOX_SELECTED, Style::default().fg(Color::Yellow))
+        (CHECKBOX_SELECTED, Style::default().fg(theme.warning))
     } else {
         (CHECKBOX_UNSELECTED, Style::default())
     }
@@ -1061,42 +1261,66 @@ fn build_browse_list_items(
 /// マーケットプレイス一覧の 1 行ぶんを 2 行 ListItem (内容 + 空行) として構築する。
 ///
 /// 一覧系は spacing を残す方針（`PR #239` で導入）。
-fn build_marketplaces_list_item<'a>(
-    marketplace: &'a MarketplaceItem,
+fn build_marketplaces_list_item(
+    columns: &[String],
     outer_width: u16,
     is_selected: bool,
-) -> ListItem<'a> {
+) -> ListItem<'static> {
+    let raw = format!("{}{}", LIST_ITEM_INDENT, columns.concat());
+    let line_text = truncate_for_list(outer_width, raw).into_owned();
+    let line = highlight_line(vec![Span::raw(line_text)], is_selected);
+    ListItem::new(vec![line, Line::raw("")])
+}
+
+/// マーケットプレイス一覧の name / source / plugins / updated の 4 列を組み立てる。
+///
+/// 列幅は呼び出し側で一覧全体から `column_widths` で求めて揃える。
+///
+/// # Arguments
+///
+/// * `marketplace` - Marketplace to format.
+fn marketplace_row_cells(marketplace: &MarketplaceItem) -> [String; 4] {
     let plugin_info = marketplace
         .plugin_count
         .map(|c| format!("{} plugins", c))
         .unwrap_or_else(|| "no cache".to_string());
-    let updated_info = marketplace.last_updated.as_deref().unwrap_or("-");
+    let updated_info = marketplace
+        .fetched_at
+        .as_ref()
+        .map(format_fetched_at)
+        .unwrap_or_else(|| "-".to_string());
     let source_path_info = marketplace
         .source_path
         .as_ref()
         .map(|p| format!(" ({})", p))
         .unwrap_or_default();
-
-    let raw = format!(
-        "{}{}    {}{}    {}    {}",
-        LIST_ITEM_INDENT,
-        marketplace.name,
-        marketplace.source,
-        source_path_info,
+    [
+        marketplace.name.clone(),
+        format!("{}{}", marketplace.source, source_path_info),
         plugin_info,
-        updated_info
-    );
-    let line_text = truncate_for_list(outer_width, raw).into_owned();
-    let line = highlight_line(vec![Span::raw(line_text)], is_selected);
-    ListItem::new(vec![line, Line::raw("")])
+        updated_info,
+    ]
+}
+
+/// キャッシュの取得日時をローカルタイムの `2026-01-01 12:34` 形式にする
+///
+/// # Arguments
+///
+/// * `fetched_at` - When the marketplace cache was fetched.
+fn format_fetched_at(fetched_at: &DateTime<Utc>) -> String {
+    fetched_at
+        .with_timezone(&Local)
+        .format(FETCHED_AT_FORMAT)
+        .to_string()
 }
 
 /// "+ Add new marketplace" 行を 2 行 ListItem (内容 + 空行) として構築する。
 fn build_add_marketplace_item(is_selected: bool) -> ListItem<'static> {
+    let theme = Theme::current();
     let line = highlight_line(
         vec![Span::styled(
             format!("{}+ Add new marketplace", LIST_ITEM_INDENT),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(theme.accent),
         )],
         is_selected,
     );
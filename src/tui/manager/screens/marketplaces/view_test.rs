@@ -344,30 +344,229 @@ fn build_market_action_menu_reserves_full_height() {
 // build_marketplaces_list_item / build_add_marketplace_item
 // =============================================================================
 
+/// ローカルタイムの日時（表示はローカルタイムで行うため、テストもローカルタイムで組み立てる）
+fn local_time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    use chrono::TimeZone;
+    Local
+        .with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn format_fetched_at_uses_local_time() {
+    assert_eq!(
+        format_fetched_at(&local_time(2026, 1, 1, 12, 34)),
+        "2026-01-01 12:34"
+    );
+}
+
+#[test]
+fn marketplace_row_without_cache_shows_placeholders() {
+    let mut m = make_marketplace("alpha");
+    m.plugin_count = None;
+    m.fetched_at = None;
+    let cells = marketplace_row_cells(&m);
+    assert_eq!(cells[2], "no cache");
+    assert_eq!(cells[3], "-");
+}
+
 fn make_marketplace(name: &str) -> MarketplaceItem {
     MarketplaceItem {
         name: name.to_string(),
         source: "github".to_string(),
         source_path: None,
         plugin_count: Some(3),
-        last_updated: Some("2026-04-29".to_string()),
+        fetched_at: Some(Utc::now()),
     }
 }
 
 #[test]
 fn build_marketplaces_list_item_returns_height_2() {
     let m = make_marketplace("alpha");
-    let item = build_marketplaces_list_item(&m, 80, false);
+    let item = build_marketplaces_list_item(&marketplace_row_cells(&m), 80, false);
     assert_eq!(item.height(), 2);
 }
 
 #[test]
 fn build_marketplaces_list_item_returns_height_2_when_selected() {
     let m = make_marketplace("alpha");
-    let item = build_marketplaces_list_item(&m, 80, true);
+    let item = build_marketplaces_list_item(&marketplace_row_cells(&m), 80, true);
     assert_eq!(item.height(), 2);
 }
 
+#[test]
+fn market_list_aligns_columns_across_rows() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let marketplaces = vec![
+        MarketplaceItem {
+            name: "official".to_string(),
+            source: "owner/plugins".to_string(),
+            source_path: None,
+            plugin_count: Some(12),
+            fetched_at: Some(local_time(2026, 4, 29, 10, 0)),
+        },
+        MarketplaceItem {
+            name: "co".to_string(),
+            source: "company/tools".to_string(),
+            source_path: Some("market".to_string()),
+            plugin_count: None,
+            fetched_at: None,
+        },
+    ];
+    let (_temp_dir, data) = DataStore::for_test(vec![], marketplaces, None);
+    let model = MarketplacesScreenModel::new(&data);
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(
+        text.contains("official  owner/plugins           12 plugins  2026-04-29 10:00"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("co        company/tools (market)  no cache    -"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn market_list_shows_marks_and_batch_progress() {
+    use crate::tui::manager::core::{snapshot, DataStore, SelectionState};
+    use crate::tui::manager::screens::marketplaces::model::BatchProgress;
+
+    let (_temp_dir, data) = DataStore::for_test(
+        vec![],
+        vec![make_marketplace("alpha"), make_marketplace("beta")],
+        None,
+    );
+    let mut progress = BatchProgress::new(vec!["alpha".to_string(), "beta".to_string()]);
+    progress.done = 1;
+    let model = MarketplacesScreenModel::MarketList {
+        selection: SelectionState::new(Some("alpha".to_string()), Some(0)),
+        operation_status: Some(OperationStatus::BatchUpdating(progress)),
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::from(["beta".to_string()]),
+        notice: None,
+    };
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(text.contains("[ ] alpha"), "{}", text);
+    assert!(text.contains("[x] beta"), "{}", text);
+    assert!(text.contains("Updating marketplaces (1/2)..."), "{}", text);
+    assert!(text.contains("x: remove marked"), "{}", text);
+}
+
+#[test]
+fn market_list_shows_update_notice() {
+    use crate::tui::manager::core::{snapshot, DataStore, SelectionState};
+
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![make_marketplace("alpha")], None);
+    let model = MarketplacesScreenModel::MarketList {
+        selection: SelectionState::new(Some("alpha".to_string()), Some(0)),
+        operation_status: None,
+        error_message: None,
+        pending_add_source: None,
+        marked_ids: HashSet::new(),
+        notice: Some("Updated 'alpha': +3 new, 1 removed, 2 updated".to_string()),
+    };
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+
+    assert!(
+        text.contains("Updated 'alpha': +3 new, 1 removed, 2 updated"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn market_list_shows_onboarding_without_marketplaces() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![], None);
+    let model = MarketplacesScreenModel::new(&data);
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(
+        text.contains("No marketplaces configured. Press Enter to add your first marketplace."),
+        "{}",
+        text
+    );
+    assert!(text.contains("Enter: add marketplace"), "{}", text);
+    assert!(!text.contains("+ Add new marketplace"), "{}", text);
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "zzz", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("No items match 'zzz'"), "{}", text);
+}
+
+#[test]
+fn add_form_confirm_shows_ref_and_subdir() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![], None);
+    let model = MarketplacesScreenModel::AddForm(AddFormModel::Confirm {
+        source: "owner/mono".to_string(),
+        git_ref: Some("v2".to_string()),
+        subdir: Some("plugins".to_string()),
+        name: "mono".to_string(),
+        error_message: None,
+    });
+
+    let buffer = snapshot::render(100, 16, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("Source: owner/mono"), "{}", text);
+    assert!(text.contains("Ref: v2"), "{}", text);
+    assert!(text.contains("Subdir: plugins"), "{}", text);
+}
+
+#[test]
+fn plugin_browse_without_plugins_says_marketplace_is_empty() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+    use ratatui::widgets::ListState;
+
+    let (_temp_dir, data) = DataStore::for_test(vec![], vec![], None);
+    let model = MarketplacesScreenModel::PluginBrowse {
+        marketplace_name: "mp-a".to_string(),
+        plugins: vec![],
+        selected_plugins: HashSet::new(),
+        highlighted_idx: 0,
+        state: ListState::default(),
+    };
+
+    let buffer = snapshot::render(100, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("mp-a > Browse (0)"), "{}", text);
+    assert!(
+        text.contains("No plugins found in this marketplace."),
+        "{}",
+        text
+    );
+}
+
 #[test]
 fn build_add_marketplace_item_returns_height_2() {
     let item = build_add_marketplace_item(false);
@@ -527,10 +726,12 @@ fn modal_clears_outer_frame_cells_at_normal_size_for_install_result() {
             plugin_name: "alpha".to_string(),
             success: true,
             error: None,
+            conflicts: Vec::new(),
         }],
         total: 1,
         succeeded: 1,
         failed: 0,
+        warnings: Vec::new(),
     };
     terminal
         .draw(|frame| {
@@ -564,13 +765,15 @@ fn renders_adding_status_line() {
         operation_status: Some(OperationStatus::Adding("my-repo".to_string())),
         error_message: None,
         pending_add_source: Some("owner/repo".to_string()),
+        marked_ids: HashSet::new(),
+        notice: None,
     };
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).expect("terminal");
     terminal
         .draw(|frame| {
-            super::view(frame, &model, &data, "", false);
+            super::view(frame, &model, &data, &TabBadges::new(&data, 0), "", false);
         })
         .expect("render adding status");
 
@@ -612,3 +815,95 @@ fn modal_renders_without_panic_at_tiny_size() {
         })
         .expect("render installing tiny");
 }
+
+// =========================================================================
+// テーマ別スナップショット
+// =========================================================================
+
+fn render_market_list_snapshot(name: &str, theme: crate::tui::manager::core::Theme) {
+    use crate::tui::manager::core::theme::with_theme;
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let marketplaces = vec![MarketplaceItem {
+        name: "official".to_string(),
+        source: "owner/plugins".to_string(),
+        source_path: None,
+        plugin_count: Some(3),
+        fetched_at: None,
+    }];
+    let (_temp_dir, data) = DataStore::for_test(vec![], marketplaces, None);
+    let model = MarketplacesScreenModel::new(&data);
+
+    let buffer = with_theme(theme, || {
+        snapshot::render(60, 14, |f| {
+            super::view(f, &model, &data, &TabBadges::new(&data, 0), "", false)
+        })
+    });
+    snapshot::assert_snapshot(name, &buffer);
+}
+
+#[test]
+fn snapshot_market_list_dark() {
+    render_market_list_snapshot(
+        "marketplaces_market_list_dark",
+        crate::tui::manager::core::Theme::dark(),
+    );
+}
+
+#[test]
+fn snapshot_market_list_light() {
+    render_market_list_snapshot(
+        "marketplaces_market_list_light",
+        crate::tui::manager::core::Theme::light(),
+    );
+}
+
+#[test]
+fn market_list_applies_filter_and_shows_empty_state() {
+    use crate::tui::manager::core::{snapshot, DataStore};
+
+    let marketplace = |name: &str| MarketplaceItem {
+        name: name.to_string(),
+        source: format!("owner/{}", name),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    };
+    let (_temp_dir, data) = DataStore::for_test(
+        vec![],
+        vec![marketplace("official"), marketplace("company")],
+        None,
+    );
+    let model = MarketplacesScreenModel::new(&data);
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "comp", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(text.contains("Marketplaces (1/2)"), "{}", text);
+    assert!(
+        text.contains("company") && !text.contains("official"),
+        "{}",
+        text
+    );
+    assert!(text.contains("+ Add new marketplace"), "{}", text);
+
+    let buffer = snapshot::render(80, 14, |f| {
+        super::view(f, &model, &data, &TabBadges::new(&data, 0), "zzz", false)
+    });
+    let text = snapshot::serialize(&buffer);
+    assert!(
+        text.contains("No items match 'zzz'") && !text.contains("+ Add new marketplace"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn install_warnings_line_points_to_the_errors_tab() {
+    assert_eq!(install_warnings_line(1), "  1 warning (see the Errors tab)");
+    assert_eq!(
+        install_warnings_line(3),
+        "  3 warnings (see the Errors tab)"
+    );
+}
@@ -0,0 +1,58 @@
+use super::core::MarketplaceItem;
+use super::*;
+
+/// マーケットプレイスを 1 件持つ（初期画面が Installed になる）モデル
+fn model_with_marketplace() -> (tempfile::TempDir, Model) {
+    let marketplace = MarketplaceItem {
+        name: "official".to_string(),
+        source: "owner/plugins".to_string(),
+        source_path: None,
+        plugin_count: None,
+        fetched_at: None,
+    };
+    let (temp_dir, data) = DataStore::for_test(vec![], vec![marketplace], None);
+    (temp_dir, Model::from_data(data, None))
+}
+
+fn keys(names: &[&str]) -> Vec<ScriptStep> {
+    names
+        .iter()
+        .map(|name| ScriptStep::Key {
+            key: name.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn replay_runs_script_to_end_and_dumps_final_state() {
+    let (_temp_dir, mut model) = model_with_marketplace();
+
+    let dump = replay_model(&mut model, keys(&["Tab"])).unwrap();
+
+    assert!(dump.contains("tab: Marketplaces\n"), "{}", dump);
+    assert!(dump.contains("quit: false\n"), "{}", dump);
+    // 最後の入力後の画面が描画されている
+    assert!(dump.contains("official"), "{}", dump);
+}
+
+#[test]
+fn replay_stops_at_quit_and_ignores_remaining_steps() {
+    let (_temp_dir, mut model) = model_with_marketplace();
+
+    let dump = replay_model(&mut model, keys(&["q", "Tab"])).unwrap();
+
+    assert!(dump.contains("tab: Installed\n"), "{}", dump);
+    assert!(dump.contains("quit: true\n"), "{}", dump);
+}
+
+#[test]
+fn replay_is_deterministic_for_the_same_script() {
+    let script = keys(&["Tab", "Tab", "BackTab"]);
+    let (_a, mut first) = model_with_marketplace();
+    let (_b, mut second) = model_with_marketplace();
+
+    assert_eq!(
+        replay_model(&mut first, script.clone()).unwrap(),
+        replay_model(&mut second, script).unwrap()
+    );
+}
@@ -0,0 +1,249 @@
+//! インクリメンタル検索付きの軽量ピッカー
+//!
+//! 入力行と候補リストだけを描画する最小限の選択 UI。フル TUI（`plm managed`）を
+//! 起動するほどではない 1 件選択に使う。キー処理は [`PickerState`] に閉じており、
+//! 端末なしでテストできる。
+
+use crossterm::cursor::MoveTo;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{queue, ExecutableCommand};
+use std::io::{self, Write};
+
+/// キー入力 1 回分の処理結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerStep {
+    /// 選択を続ける
+    Continue,
+    /// 候補を確定した（候補リスト上のインデックス）
+    Selected(usize),
+    /// キャンセルされた
+    Cancelled,
+}
+
+/// ピッカーの状態（検索語・絞り込み結果・カーソル）
+#[derive(Debug)]
+pub struct PickerState {
+    query: String,
+    candidates: Vec<String>,
+    /// 検索語にマッチした候補のインデックス（候補リストの順）
+    matches: Vec<usize>,
+    /// `matches` 上のカーソル位置
+    cursor: usize,
+}
+
+impl PickerState {
+    /// 候補リストから作成する（検索語は空で、全候補がマッチする）
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - Labels shown in the list and matched against the query.
+    pub fn new(candidates: Vec<String>) -> Self {
+        let matches = (0..candidates.len()).collect();
+        Self {
+            query: String::new(),
+            candidates,
+            matches,
+            cursor: 0,
+        }
+    }
+
+    /// 現在の検索語
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// 検索語にマッチした候補のラベル
+    pub fn matches(&self) -> Vec<&str> {
+        self.matches
+            .iter()
+            .map(|&i| self.candidates[i].as_str())
+            .collect()
+    }
+
+    /// マッチした候補上のカーソル位置
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// 候補の総数
+    pub fn total(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// キー入力を処理する
+    ///
+    /// ↑/↓（Ctrl+P / Ctrl+N）で移動、Enter で確定、Esc / Ctrl+C でキャンセル。
+    /// それ以外の文字は検索語に追加し、Backspace で 1 文字削除する。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key event read from the terminal.
+    pub fn handle_key(&mut self, key: KeyEvent) -> PickerStep {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => PickerStep::Cancelled,
+            KeyCode::Char('c') if ctrl => PickerStep::Cancelled,
+            KeyCode::Enter => self
+                .matches
+                .get(self.cursor)
+                .map_or(PickerStep::Continue, |&i| PickerStep::Selected(i)),
+            KeyCode::Up => self.move_up(),
+            KeyCode::Char('p') if ctrl => self.move_up(),
+            KeyCode::Down => self.move_down(),
+            KeyCode::Char('n') if ctrl => self.move_down(),
+            KeyCode::Backspace => {
+                if self.query.pop().is_some() {
+                    self.refilter();
+                }
+                PickerStep::Continue
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.refilter();
+                PickerStep::Continue
+            }
+            _ => PickerStep::Continue,
+        }
+    }
+
+    fn move_up(&mut self) -> PickerStep {
+        self.cursor = self.cursor.saturating_sub(1);
+        PickerStep::Continue
+    }
+
+    fn move_down(&mut self) -> PickerStep {
+        self.cursor = (self.cursor + 1).min(self.matches.len().saturating_sub(1));
+        PickerStep::Continue
+    }
+
+    /// 検索語で候補を絞り込み直し、カーソルを先頭に戻す
+    fn refilter(&mut self) {
+        self.matches = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, label)| fuzzy_match(&self.query, label))
+            .map(|(i, _)| i)
+            .collect();
+        self.cursor = 0;
+    }
+}
+
+/// 検索語の文字が候補に順番通り現れるか（大文字小文字は区別しない）
+///
+/// # Arguments
+///
+/// * `query` - Search query typed by the user.
+/// * `candidate` - Candidate label.
+pub fn fuzzy_match(query: &str, candidate: &str) -> bool {
+    let mut chars = candidate.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// ピッカーを表示し、選ばれた候補のインデックスを返す（キャンセル時は `None`）
+///
+/// 描画は stderr に行うため、確定後にコマンドが stdout へ書く出力とは混ざらない。
+///
+/// # Arguments
+///
+/// * `prompt` - Prompt shown before the query input.
+/// * `candidates` - Labels to choose from.
+pub fn pick(prompt: &str, candidates: Vec<String>) -> io::Result<Option<usize>> {
+    let mut state = PickerState::new(candidates);
+    let mut out = io::stderr();
+    terminal::enable_raw_mode()?;
+    out.execute(EnterAlternateScreen)?;
+
+    let result = run_loop(&mut state, prompt, &mut out);
+
+    out.execute(LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+/// # Arguments
+///
+/// * `state` - Picker state updated by key presses.
+/// * `prompt` - Prompt shown before the query input.
+/// * `out` - Terminal output to draw on.
+fn run_loop(
+    state: &mut PickerState,
+    prompt: &str,
+    out: &mut impl Write,
+) -> io::Result<Option<usize>> {
+    loop {
+        draw(state, prompt, out)?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match state.handle_key(key) {
+            PickerStep::Continue => {}
+            PickerStep::Selected(index) => return Ok(Some(index)),
+            PickerStep::Cancelled => return Ok(None),
+        }
+    }
+}
+
+/// 入力行・候補リスト・ヘルプ行を描画する
+///
+/// # Arguments
+///
+/// * `state` - Picker state to draw.
+/// * `prompt` - Prompt shown before the query input.
+/// * `out` - Terminal output to draw on.
+fn draw(state: &PickerState, prompt: &str, out: &mut impl Write) -> io::Result<()> {
+    let (_, rows) = terminal::size()?;
+    let visible = usize::from(rows.saturating_sub(2)).max(1);
+    // カーソル行が常に見えるようにスクロールする
+    let offset = (state.cursor() + 1).saturating_sub(visible);
+    let matches = state.matches();
+
+    queue!(
+        out,
+        Clear(ClearType::All),
+        MoveTo(0, rows.saturating_sub(1)),
+        Print(format!(
+            "{}/{}  ↑/↓: move  enter: select  esc: cancel",
+            matches.len(),
+            state.total()
+        ))
+    )?;
+    for (row, (index, label)) in matches
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .enumerate()
+    {
+        queue!(out, MoveTo(0, row as u16 + 1))?;
+        if index == state.cursor() {
+            queue!(
+                out,
+                SetAttribute(Attribute::Reverse),
+                Print(format!("> {}", label)),
+                SetAttribute(Attribute::Reset)
+            )?;
+        } else {
+            queue!(out, Print(format!("  {}", label)))?;
+        }
+    }
+    queue!(
+        out,
+        MoveTo(0, 0),
+        Print(format!("{}> {}", prompt, state.query()))
+    )?;
+    out.flush()
+}
+
+#[cfg(test)]
+#[path = "picker_test.rs"]
+mod tests;
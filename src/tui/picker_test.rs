@@ -0,0 +1,99 @@
+use super::*;
+
+fn state(labels: &[&str]) -> PickerState {
+    PickerState::new(labels.iter().map(|s| s.to_string()).collect())
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+fn type_query(state: &mut PickerState, query: &str) {
+    for c in query.chars() {
+        assert_eq!(
+            state.handle_key(key(KeyCode::Char(c))),
+            PickerStep::Continue
+        );
+    }
+}
+
+#[test]
+fn fuzzy_match_accepts_subsequence_ignoring_case() {
+    assert!(fuzzy_match("", "formatter"));
+    assert!(fuzzy_match("fmt", "formatter"));
+    assert!(fuzzy_match("FMT", "formatter@company"));
+    assert!(fuzzy_match("fmt co", "formatter@company"));
+    assert!(!fuzzy_match("tmf", "formatter"));
+}
+
+#[test]
+fn typing_narrows_candidates_and_resets_cursor() {
+    let mut state = state(&["code-review", "formatter", "fmt-lint"]);
+    state.handle_key(key(KeyCode::Down));
+    assert_eq!(state.cursor(), 1);
+
+    type_query(&mut state, "fmt");
+
+    assert_eq!(state.query(), "fmt");
+    assert_eq!(state.matches(), vec!["formatter", "fmt-lint"]);
+    assert_eq!(state.cursor(), 0);
+}
+
+#[test]
+fn backspace_widens_candidates() {
+    let mut state = state(&["code-review", "formatter"]);
+    type_query(&mut state, "fz");
+    assert!(state.matches().is_empty());
+
+    state.handle_key(key(KeyCode::Backspace));
+
+    assert_eq!(state.query(), "f");
+    assert_eq!(state.matches(), vec!["formatter"]);
+}
+
+#[test]
+fn cursor_moves_within_matches() {
+    let mut state = state(&["a", "b", "c"]);
+    state.handle_key(key(KeyCode::Up));
+    assert_eq!(state.cursor(), 0);
+
+    state.handle_key(key(KeyCode::Down));
+    state.handle_key(ctrl('n'));
+    state.handle_key(key(KeyCode::Down));
+    assert_eq!(state.cursor(), 2);
+
+    state.handle_key(ctrl('p'));
+    assert_eq!(state.cursor(), 1);
+}
+
+#[test]
+fn enter_selects_original_index_of_filtered_candidate() {
+    let mut state = state(&["code-review", "formatter", "fmt-lint"]);
+    type_query(&mut state, "fmt");
+    state.handle_key(key(KeyCode::Down));
+
+    assert_eq!(
+        state.handle_key(key(KeyCode::Enter)),
+        PickerStep::Selected(2)
+    );
+}
+
+#[test]
+fn enter_without_matches_keeps_picking() {
+    let mut state = state(&["formatter"]);
+    type_query(&mut state, "zzz");
+
+    assert_eq!(state.handle_key(key(KeyCode::Enter)), PickerStep::Continue);
+}
+
+#[test]
+fn esc_and_ctrl_c_cancel() {
+    let mut state = state(&["formatter"]);
+    assert_eq!(state.handle_key(key(KeyCode::Esc)), PickerStep::Cancelled);
+    assert_eq!(state.handle_key(ctrl('c')), PickerStep::Cancelled);
+    assert_eq!(state.query(), "");
+}